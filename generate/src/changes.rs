@@ -0,0 +1,164 @@
+//! Report the changes to the emoji data between two versions.
+//!
+//! The changes are written as a report and as tables that map names and
+//! encodings from the previous version to the current emojis.
+
+use std::fs;
+use std::io;
+use std::io::Write as _;
+use std::path::Path;
+
+use anyhow::Result;
+use indexmap::IndexMap;
+
+use crate::{github, unicode};
+
+/// A version of the emoji data.
+pub struct Version<'a> {
+    pub unicode: &'a str,
+    pub gemoji: &'a str,
+    pub unicode_data: &'a unicode::ParsedData,
+    pub github_data: &'a github::ParsedData,
+}
+
+struct Entry<'a> {
+    name: &'a str,
+    aliases: &'a [String],
+}
+
+fn entries<'a>(v: &Version<'a>) -> IndexMap<&'a str, Entry<'a>> {
+    v.unicode_data
+        .values()
+        .flat_map(|subgroups| subgroups.values())
+        .flatten()
+        .map(|emoji| {
+            let entry = Entry {
+                name: emoji.name(),
+                aliases: v
+                    .github_data
+                    .get(emoji.as_str())
+                    .map(|github| github.aliases())
+                    .unwrap_or_default(),
+            };
+            (emoji.as_str(), entry)
+        })
+        .collect()
+}
+
+/// Write a report of the changes from the previous to the current version.
+///
+/// The report starts with a comment line describing the versions, followed
+/// by one tab separated line per change. Each line is one of the following.
+///
+/// ```text
+/// added    <emoji> <name>
+/// removed  <emoji> <name>
+/// renamed  <emoji> <old name> <new name>
+/// aliases  <emoji> <old aliases> <new aliases>
+/// ```
+///
+/// Aliases are separated by spaces.
+pub fn write_report(path: &Path, prev: &Version<'_>, curr: &Version<'_>) -> Result<()> {
+    let mut w = io::BufWriter::new(fs::File::create(path)?);
+    let prev_entries = entries(prev);
+    let curr_entries = entries(curr);
+
+    writeln!(
+        w,
+        "# Changes from Unicode {} and gemoji {} to Unicode {} and gemoji {}",
+        prev.unicode, prev.gemoji, curr.unicode, curr.gemoji
+    )?;
+    for (emoji, curr) in &curr_entries {
+        match prev_entries.get(emoji) {
+            None => writeln!(w, "added\t{emoji}\t{}", curr.name)?,
+            Some(prev) => {
+                if prev.name != curr.name {
+                    writeln!(w, "renamed\t{emoji}\t{}\t{}", prev.name, curr.name)?;
+                }
+                if prev.aliases != curr.aliases {
+                    writeln!(
+                        w,
+                        "aliases\t{emoji}\t{}\t{}",
+                        prev.aliases.join(" "),
+                        curr.aliases.join(" ")
+                    )?;
+                }
+            }
+        }
+    }
+    for (emoji, prev) in &prev_entries {
+        if !curr_entries.contains_key(emoji) {
+            writeln!(w, "removed\t{emoji}\t{}", prev.name)?;
+        }
+    }
+    Ok(())
+}
+
+/// Write the tables that map renamed names and changed encodings from the
+/// previous version to the index of the emoji in the current version.
+///
+/// When there is no previous version, pass the current version as both
+/// versions and the tables will be empty.
+pub fn write_migrations(path: &Path, prev: &Version<'_>, curr: &Version<'_>) -> Result<()> {
+    let mut w = io::BufWriter::new(fs::File::create(path)?);
+    let prev_entries = entries(prev);
+    let curr_entries = entries(curr);
+
+    let mut names = Vec::new();
+    let mut encodings = Vec::new();
+    for (emoji, prev) in &prev_entries {
+        match curr_entries.get_full(emoji) {
+            Some((i, _, curr)) => {
+                if prev.name != curr.name {
+                    names.push((prev.name, i));
+                }
+            }
+            None => {
+                if let Some(i) = curr_entries.values().position(|e| e.name == prev.name) {
+                    encodings.push((*emoji, i));
+                }
+            }
+        }
+    }
+    names.sort_unstable();
+    encodings.sort_unstable();
+
+    let version: unicode::UnicodeVersion = format!("E{}", prev.unicode).parse()?;
+    writeln!(w, "{}", crate::tables::HEADER)?;
+    writeln!(w, "use crate::UnicodeVersion;\n")?;
+    writeln!(w, "/// The Unicode version of the previous release.")?;
+    writeln!(w, "pub const PREVIOUS: UnicodeVersion = {version:?};\n")?;
+    write_migration_table(
+        &mut w,
+        "Names from the previous version that were renamed, sorted by name.",
+        "NAMES",
+        &names,
+    )?;
+    writeln!(w)?;
+    write_migration_table(
+        &mut w,
+        "Emojis from the previous version whose encoding changed, sorted.",
+        "ENCODINGS",
+        &encodings,
+    )?;
+    Ok(())
+}
+
+fn write_migration_table<W: io::Write>(
+    w: &mut W,
+    doc: &str,
+    name: &str,
+    entries: &[(&str, usize)],
+) -> Result<()> {
+    writeln!(w, "/// {doc}")?;
+    if entries.is_empty() {
+        writeln!(w, "pub static {name}: &[(&str, usize)] = &[];")?;
+        return Ok(());
+    }
+    writeln!(w, "pub static {name}: &[(&str, usize)] = &[")?;
+    for (key, i) in entries {
+        writeln!(w, "    ({key:?}, {i}),")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Parse command line arguments.
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use anyhow::{bail, Context, Result};
+
+use crate::{github, unicode};
+
+const HELP: &str = "\
+Generate the emoji tables in `src/gen/v<VERSION>`.
+
+Usage: generate [OPTIONS]
+
+Options:
+      --unicode <VERSION>  The Unicode emoji version to use [default: 16.0]
+      --gemoji <TAG>       The gemoji release tag to use [default: v4.1.0]
+      --offline            Read local copies of the data files from the data
+                           directory instead of downloading them
+      --previous-unicode <VERSION>
+                           The Unicode emoji version to report changes from
+                           [default: the previous supported version]
+      --previous-gemoji <TAG>
+                           The gemoji release tag to report changes from
+                           [default: the current tag]
+      --data-dir <PATH>    The directory containing local copies of the data
+                           files, named `emoji-test-<VERSION>.txt` and
+                           `gemoji-<TAG>.json` [default: generate/data]
+      --json <PATH>        Also write the merged dataset as JSON to this path
+      --csv <PATH>         Also write the merged dataset as CSV to this path
+  -h, --help               Print this help message
+";
+
+#[derive(Debug)]
+pub struct Opts {
+    /// The Unicode emoji version, e.g. "15.1".
+    pub unicode: String,
+    /// The gemoji release tag, e.g. "v4.1.0".
+    pub gemoji: String,
+    /// The Unicode emoji version to report changes from.
+    pub previous_unicode: Option<String>,
+    /// The gemoji release tag to report changes from.
+    pub previous_gemoji: Option<String>,
+    /// Whether to read local copies of the data files instead of downloading
+    /// them.
+    pub offline: bool,
+    /// The directory containing local copies of the data files.
+    pub data_dir: PathBuf,
+    /// Where to write the merged dataset as JSON, if at all.
+    pub json: Option<PathBuf>,
+    /// Where to write the merged dataset as CSV, if at all.
+    pub csv: Option<PathBuf>,
+}
+
+impl Opts {
+    pub fn from_env() -> Result<Self> {
+        let mut opts = Self {
+            unicode: unicode::DEFAULT_VERSION.to_owned(),
+            gemoji: github::DEFAULT_TAG.to_owned(),
+            previous_unicode: None,
+            previous_gemoji: None,
+            offline: false,
+            data_dir: [env!("CARGO_MANIFEST_DIR"), "data"].iter().collect(),
+            json: None,
+            csv: None,
+        };
+
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .with_context(|| format!("expected a value for `{arg}`"))
+            };
+            match arg.as_str() {
+                "--unicode" => opts.unicode = value()?,
+                "--gemoji" => opts.gemoji = value()?,
+                "--previous-unicode" => opts.previous_unicode = Some(value()?),
+                "--previous-gemoji" => opts.previous_gemoji = Some(value()?),
+                "--offline" => opts.offline = true,
+                "--data-dir" => opts.data_dir = value()?.into(),
+                "--json" => opts.json = Some(value()?.into()),
+                "--csv" => opts.csv = Some(value()?.into()),
+                "-h" | "--help" => {
+                    print!("{HELP}");
+                    process::exit(0);
+                }
+                _ => bail!("unexpected argument `{arg}`\n\n{HELP}"),
+            }
+        }
+
+        Ok(opts)
+    }
+}
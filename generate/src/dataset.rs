@@ -0,0 +1,101 @@
+//! Write the merged emoji dataset in machine-readable formats.
+
+use std::fs;
+use std::io;
+use std::io::Write as _;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{github, unicode};
+
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    emoji: &'a str,
+    name: &'a str,
+    group: &'a str,
+    subgroup: &'a str,
+    unicode_version: String,
+    skin_tone: Option<String>,
+    aliases: &'a [String],
+    variations: &'a [String],
+}
+
+fn records<'a>(
+    unicode_data: &'a unicode::ParsedData,
+    github_data: &'a github::ParsedData,
+) -> Vec<Record<'a>> {
+    let mut records = Vec::new();
+    for (group, subgroups) in unicode_data {
+        for (subgroup, emojis) in subgroups {
+            for emoji in emojis {
+                records.push(Record {
+                    emoji: emoji.as_str(),
+                    name: emoji.name(),
+                    group,
+                    subgroup,
+                    unicode_version: emoji.unicode_version().to_string(),
+                    skin_tone: emoji.skin_tone().map(|st| format!("{st:?}")),
+                    aliases: github_data
+                        .get(emoji.as_str())
+                        .map(|github| github.aliases())
+                        .unwrap_or_default(),
+                    variations: emoji.variations(),
+                });
+            }
+        }
+    }
+    records
+}
+
+/// Write the dataset as a JSON array of objects.
+pub fn write_json(
+    path: &Path,
+    unicode_data: &unicode::ParsedData,
+    github_data: &github::ParsedData,
+) -> Result<()> {
+    let mut w = io::BufWriter::new(fs::File::create(path)?);
+    serde_json::to_writer_pretty(&mut w, &records(unicode_data, github_data))?;
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Write the dataset as CSV with a header row.
+///
+/// Fields with multiple values, i.e. aliases and variations, are separated
+/// by spaces.
+pub fn write_csv(
+    path: &Path,
+    unicode_data: &unicode::ParsedData,
+    github_data: &github::ParsedData,
+) -> Result<()> {
+    let mut w = io::BufWriter::new(fs::File::create(path)?);
+    writeln!(
+        w,
+        "emoji,name,group,subgroup,unicode_version,skin_tone,aliases,variations"
+    )?;
+    for r in records(unicode_data, github_data) {
+        let fields = [
+            r.emoji.to_owned(),
+            r.name.to_owned(),
+            r.group.to_owned(),
+            r.subgroup.to_owned(),
+            r.unicode_version,
+            r.skin_tone.unwrap_or_default(),
+            r.aliases.join(" "),
+            r.variations.join(" "),
+        ];
+        let fields: Vec<_> = fields.iter().map(|f| csv_field(f)).collect();
+        writeln!(w, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    }
+}
@@ -5,7 +5,8 @@ use std::collections::HashMap;
 use anyhow::Result;
 use serde::Deserialize;
 
-const URL: &str = "https://github.com/github/gemoji/raw/v4.1.0/db/emoji.json";
+/// The default gemoji tag.
+pub const DEFAULT_TAG: &str = "v4.1.0";
 
 #[derive(Debug, Deserialize)]
 pub struct Emoji {
@@ -15,22 +16,18 @@ pub struct Emoji {
 
 pub type ParsedData = HashMap<String, Emoji>;
 
-pub fn fetch_and_parse_emoji_data() -> Result<ParsedData> {
-    let mut buf = Vec::new();
-    let mut easy = curl::easy::Easy::new();
-    easy.fail_on_error(true)?;
-    easy.follow_location(true)?;
-    easy.url(URL)?;
-    {
-        let mut transfer = easy.transfer();
-        transfer.write_function(|data| {
-            buf.extend_from_slice(data);
-            Ok(data.len())
-        })?;
-        transfer.perform()?;
-    }
+/// Returns the URL of the gemoji database for the given tag.
+pub fn url(tag: &str) -> String {
+    format!("https://github.com/github/gemoji/raw/{tag}/db/emoji.json")
+}
+
+/// Returns the file name to use for a local copy of the gemoji database.
+pub fn file_name(tag: &str) -> String {
+    format!("gemoji-{tag}.json")
+}
 
-    let emojis: Vec<Emoji> = serde_json::from_slice(&buf)?;
+pub fn parse_emoji_data(data: &[u8]) -> Result<ParsedData> {
+    let emojis: Vec<Emoji> = serde_json::from_slice(data)?;
     Ok(emojis
         .into_iter()
         .map(|emoji| (emoji.emoji.clone(), emoji))
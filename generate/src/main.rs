@@ -1,163 +1,121 @@
+mod changes;
+mod cli;
+mod dataset;
 mod github;
+mod tables;
 mod unicode;
 
-use std::collections::HashMap;
 use std::fs;
-use std::io;
-use std::io::Write as _;
 use std::path::PathBuf;
 
-use anyhow::Result;
-
-use crate::unicode::SkinTone;
-
-fn write_group_enum<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
-    writeln!(w, "/// A category for an emoji.")?;
-    writeln!(w, "///")?;
-    writeln!(w, "/// Based on Unicode CLDR data.")?;
-    writeln!(
-        w,
-        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]"
-    )?;
-    writeln!(w, "pub enum Group {{")?;
-    for name in unicode_data.keys() {
-        if name == "Component" {
-            continue;
-        }
-        writeln!(w, "   {name},")?;
+use anyhow::{Context, Result};
+
+use crate::cli::Opts;
+use crate::tables::Tables;
+
+fn fetch(url: &str) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    let mut easy = curl::easy::Easy::new();
+    easy.fail_on_error(true)?;
+    easy.follow_location(true)?;
+    easy.url(url)?;
+    {
+        let mut transfer = easy.transfer();
+        transfer.write_function(|data| {
+            buf.extend_from_slice(data);
+            Ok(data.len())
+        })?;
+        transfer.perform()?;
     }
-    writeln!(w, "}}")?;
-    Ok(())
+    Ok(buf)
 }
 
-fn write_emoji_struct<W: io::Write>(
-    w: &mut W,
-    github_data: &github::ParsedData,
-    group: &str,
-    emoji: &unicode::Emoji,
-    default_skin_tone_index: usize,
-    skin_tone_count: usize,
-) -> Result<()> {
-    let e = emoji.as_str();
-    let name = emoji.name();
-    let uv = emoji.unicode_version();
-    write!(
-        w,
-        "Emoji {{ emoji: \"{e}\", name: \"{name}\", unicode_version: {uv:?}, group: Group::{group}",
-    )?;
-    match emoji.skin_tone() {
-        Some(tone) => write!(
-            w,
-            ", skin_tone: Some(({default_skin_tone_index}, {skin_tone_count}, SkinTone::{tone:?}))",
-        )?,
-        None => write!(w, ", skin_tone: None")?,
+/// Returns the contents of a data file.
+///
+/// When offline a local copy of the file is read from the data directory,
+/// otherwise the file is downloaded.
+fn load(opts: &Opts, url: &str, file_name: &str) -> Result<Vec<u8>> {
+    if opts.offline {
+        let path = opts.data_dir.join(file_name);
+        return fs::read(&path)
+            .with_context(|| format!("failed to read local file `{}`", path.display()));
     }
-    match &github_data.get(e) {
-        Some(github) => write!(w, ", aliases: Some(&{:?}) }}", github.aliases())?,
-        None => write!(w, ", aliases: None }}")?,
-    }
-    Ok(())
+    fetch(url).with_context(|| format!("failed to fetch `{url}`"))
 }
 
-fn write_emojis_slice<W: io::Write>(
-    w: &mut W,
-    unicode_data: &unicode::ParsedData,
-    github_data: &github::ParsedData,
-    unicode_map: &mut HashMap<String, String>,
-    shortcode_map: &mut HashMap<String, String>,
-) -> Result<()> {
-    let mut i = 0;
-    let mut default_skin_tone_index = 0;
-    let mut skin_tone_count = 0;
-
-    writeln!(w, "pub const EMOJIS: &[Emoji] = &[")?;
-    for (group, subgroups) in unicode_data {
-        for subgroup in subgroups.values() {
-            for emoji in subgroup {
-                if matches!(emoji.skin_tone(), Some(SkinTone::Default)) {
-                    default_skin_tone_index = i;
-                    skin_tone_count = emoji.skin_tones();
-                }
-                write!(w, "    ")?;
-                write_emoji_struct(
-                    w,
-                    github_data,
-                    group,
-                    emoji,
-                    default_skin_tone_index,
-                    skin_tone_count,
-                )?;
-                writeln!(w, ",")?;
-
-                unicode_map.insert(emoji.as_str().to_owned(), i.to_string());
-                for v in emoji.variations() {
-                    assert!(unicode_map.insert(v.to_owned(), i.to_string()).is_none());
-                }
-
-                if let Some(github) = &github_data.get(emoji.as_str()) {
-                    for alias in github.aliases() {
-                        assert!(shortcode_map
-                            .insert(alias.to_owned(), i.to_string())
-                            .is_none());
-                    }
-                }
-                i += 1;
-            }
-        }
-    }
-    writeln!(w, "];")?;
-    Ok(())
+fn load_unicode_data(opts: &Opts, version: &str) -> Result<unicode::ParsedData> {
+    let data = load(opts, &unicode::url(version), &unicode::file_name(version))?;
+    unicode::parse_emoji_data(&String::from_utf8(data)?)
 }
 
-fn write_phf_map<W: io::Write>(w: &mut W, map: HashMap<String, String>) -> Result<()> {
-    write!(w, "pub static MAP: ::phf::Map<&'static str, usize> = ")?;
-    let mut gen = phf_codegen::Map::new();
-    for (key, value) in &map {
-        gen.entry(key, value);
-    }
-    writeln!(w, "{};", gen.build())?;
-    Ok(())
+fn load_github_data(opts: &Opts, tag: &str) -> Result<github::ParsedData> {
+    let data = load(opts, &github::url(tag), &github::file_name(tag))?;
+    github::parse_emoji_data(&data)
 }
 
-const HEADER: &str = "// Code generated by `cargo run --package generate`. DO NOT EDIT.\n";
-
 fn main() -> Result<()> {
-    let dir: PathBuf = [env!("CARGO_MANIFEST_DIR"), "..", "src", "gen"]
-        .iter()
-        .collect();
-
-    let unicode_data = unicode::fetch_and_parse_emoji_data()?;
-    let github_data = github::fetch_and_parse_emoji_data()?;
-    let mut unicode_map = HashMap::new();
-    let mut shortcode_map = HashMap::new();
+    let opts = Opts::from_env()?;
+    let dir: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "..",
+        "src",
+        "gen",
+        &unicode::module_name(&opts.unicode),
+    ]
+    .iter()
+    .collect();
+
+    let unicode_data = load_unicode_data(&opts, &opts.unicode)?;
+    let github_data = load_github_data(&opts, &opts.gemoji)?;
+    if let Some(path) = &opts.json {
+        dataset::write_json(path, &unicode_data, &github_data)?;
+    }
+    if let Some(path) = &opts.csv {
+        dataset::write_csv(path, &unicode_data, &github_data)?;
+    }
 
     fs::remove_dir_all(&dir).ok();
     fs::create_dir_all(&dir)?;
-
-    let mut f = fs::File::create(dir.join("mod.rs"))?;
-    writeln!(f, "{}", HEADER)?;
-    writeln!(f, "#![cfg_attr(rustfmt, rustfmt::skip)]\n")?;
-    writeln!(f, "pub mod shortcode;")?;
-    writeln!(f, "pub mod unicode;\n")?;
-    writeln!(f, "use crate::{{Emoji, SkinTone, UnicodeVersion}};\n")?;
-
-    write_group_enum(&mut f, &unicode_data)?;
-    writeln!(f)?;
-    write_emojis_slice(
-        &mut f,
-        &unicode_data,
-        &github_data,
-        &mut unicode_map,
-        &mut shortcode_map,
-    )?;
-
-    let mut f = fs::File::create(dir.join("unicode.rs"))?;
-    writeln!(f, "{}", HEADER)?;
-    write_phf_map(&mut f, unicode_map)?;
-
-    let mut f = fs::File::create(dir.join("shortcode.rs"))?;
-    writeln!(f, "{}", HEADER)?;
-    write_phf_map(&mut f, shortcode_map)?;
+    Tables::new(&unicode_data, &github_data)?.write_dir(&dir)?;
+
+    let curr = changes::Version {
+        unicode: &opts.unicode,
+        gemoji: &opts.gemoji,
+        unicode_data: &unicode_data,
+        github_data: &github_data,
+    };
+    let prev_unicode = opts
+        .previous_unicode
+        .as_deref()
+        .or_else(|| unicode::previous_version(&opts.unicode));
+    if prev_unicode.is_none() && opts.previous_gemoji.is_none() {
+        // This is the oldest supported version so there are no changes to
+        // report or migrate.
+        changes::write_migrations(&dir.join("migrate.rs"), &curr, &curr)?;
+        return Ok(());
+    }
+    let prev_unicode = prev_unicode.unwrap_or(&opts.unicode);
+    let prev_gemoji = opts.previous_gemoji.as_deref().unwrap_or(&opts.gemoji);
+    let prev_unicode_data;
+    let prev_github_data;
+    let prev = changes::Version {
+        unicode: prev_unicode,
+        gemoji: prev_gemoji,
+        unicode_data: if prev_unicode == opts.unicode {
+            &unicode_data
+        } else {
+            prev_unicode_data = load_unicode_data(&opts, prev_unicode)?;
+            &prev_unicode_data
+        },
+        github_data: if prev_gemoji == opts.gemoji {
+            &github_data
+        } else {
+            prev_github_data = load_github_data(&opts, prev_gemoji)?;
+            &prev_github_data
+        },
+    };
+    changes::write_report(&dir.join("changes.txt"), &prev, &curr)?;
+    changes::write_migrations(&dir.join("migrate.rs"), &prev, &curr)?;
 
     Ok(())
 }
@@ -0,0 +1,744 @@
+//! Write the generated emoji tables.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::io::Write as _;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use heck::CamelCase;
+use indexmap::IndexMap;
+
+use crate::unicode::SkinTone;
+use crate::{github, unicode};
+
+/// The groups in order of their stable discriminants.
+const GROUPS: &[&str] = &[
+    "SmileysAndEmotion",
+    "PeopleAndBody",
+    "AnimalsAndNature",
+    "FoodAndDrink",
+    "TravelAndPlaces",
+    "Activities",
+    "Objects",
+    "Symbols",
+    "Flags",
+];
+
+/// Sets of emojis that look alike on common platforms.
+///
+/// There is no Unicode data for this so the sets are curated by hand. The
+/// first emoji in each set is the most common one.
+const CONFUSABLES: &[&[&str]] = &[
+    &["✅", "✔️", "☑️"],
+    &["❌", "✖️", "❎"],
+    &["❤️", "♥️", "🧡"],
+    &["⭐", "🌟"],
+    &["😀", "😃"],
+    &["🙁", "☹️"],
+    &["😊", "☺️"],
+    &["💬", "🗨️"],
+    &["👪", "👨‍👩‍👦"],
+    &["💏", "👩‍❤️‍💋‍👨"],
+    &["💑", "👩‍❤️‍👨"],
+    &["⬛", "◼️", "◾", "▪️"],
+    &["⬜", "◻️", "◽", "▫️"],
+    &["🇦🇺", "🇳🇿", "🇭🇲"],
+    &["🇨🇴", "🇪🇨", "🇻🇪"],
+    &["🇩🇬", "🇮🇴"],
+    &["🇪🇸", "🇪🇦"],
+    &["🇫🇷", "🇲🇫", "🇨🇵"],
+    &["🇮🇩", "🇲🇨"],
+    &["🇮🇪", "🇨🇮"],
+    &["🇳🇱", "🇱🇺"],
+    &["🇳🇴", "🇸🇯", "🇧🇻"],
+    &["🇷🇴", "🇹🇩"],
+    &["🇺🇸", "🇺🇲"],
+];
+
+/// Plain ASCII fallbacks for common emojis.
+///
+/// There is no Unicode data for this so the fallbacks are curated by hand.
+/// Skin tone variants use the fallback of the default skin tone.
+const ASCII_FALLBACKS: &[(&str, &str)] = &[
+    ("😀", ":D"),
+    ("😆", "XD"),
+    ("😂", ":'D"),
+    ("🙂", ":)"),
+    ("😉", ";)"),
+    ("😇", "O:)"),
+    ("😘", ":*"),
+    ("😛", ":P"),
+    ("😜", ";P"),
+    ("😐", ":|"),
+    ("😎", "B)"),
+    ("😕", ":/"),
+    ("🙁", ":("),
+    ("☹️", ":("),
+    ("😮", ":O"),
+    ("😢", ":'("),
+    ("😭", ":'("),
+    ("😠", ">:("),
+    ("😈", ">:)"),
+    ("💔", "</3"),
+    ("❤️", "<3"),
+    ("💯", "100"),
+    ("👍", "+1"),
+    ("👎", "-1"),
+    ("➡️", "->"),
+    ("⬅️", "<-"),
+    ("↔️", "<->"),
+    ("‼️", "!!"),
+    ("⁉️", "!?"),
+    ("❓", "?"),
+    ("❗", "!"),
+    ("©️", "(c)"),
+    ("®️", "(R)"),
+    ("™️", "TM"),
+    ("🆗", "OK"),
+    ("➕", "+"),
+    ("➖", "-"),
+    ("✖️", "x"),
+    ("➗", "/"),
+];
+
+/// Curated sets of emojis that are commonly used together.
+///
+/// Each preset is written as a constant with the given name and documentation.
+const PRESETS: &[(&str, &str, &[&str])] = &[
+    (
+        "REACTIONS",
+        "The standard six message reactions.",
+        &["👍", "❤️", "😂", "😮", "😢", "😡"],
+    ),
+    (
+        "STATUS",
+        "Emojis commonly used to show the status of a task.",
+        &["✅", "❌", "⚠️", "⏳", "🚧", "ℹ️"],
+    ),
+];
+
+pub const HEADER: &str = "// Code generated by `cargo run --package generate`. DO NOT EDIT.\n";
+
+/// The generated emoji tables.
+pub struct Tables {
+    emojis: Vec<u8>,
+    unicode_map: HashMap<String, String>,
+    shortcode_map: HashMap<String, String>,
+}
+
+impl Tables {
+    pub fn new(
+        unicode_data: &unicode::ParsedData,
+        github_data: &github::ParsedData,
+    ) -> Result<Self> {
+        let mut emojis = Vec::new();
+        let mut unicode_map = HashMap::new();
+        let mut shortcode_map = HashMap::new();
+        writeln!(
+            emojis,
+            "use crate::{{Color, Emoji, EmojiId, SkinTone, UnicodeVersion}};\n"
+        )?;
+        write_group_enum(&mut emojis, unicode_data)?;
+        writeln!(emojis)?;
+        let derived = Derived::new(unicode_data);
+        write_emojis_slice(
+            &mut emojis,
+            unicode_data,
+            github_data,
+            &derived,
+            &mut unicode_map,
+            &mut shortcode_map,
+        )?;
+        writeln!(emojis)?;
+        let max_len = unicode_map.keys().map(String::len).max().unwrap_or(0);
+        writeln!(
+            emojis,
+            "/// The maximum length in bytes of any emoji, including variations."
+        )?;
+        writeln!(emojis, "pub const MAX_LEN: usize = {max_len};")?;
+        writeln!(emojis)?;
+        write_start_ranges(&mut emojis, &unicode_map)?;
+        writeln!(emojis)?;
+        write_by_name_index(&mut emojis, unicode_data)?;
+        writeln!(emojis)?;
+        write_by_version_index(&mut emojis, unicode_data)?;
+        writeln!(emojis)?;
+        write_counts(&mut emojis, unicode_data)?;
+        writeln!(emojis)?;
+        write_subgroups(&mut emojis, unicode_data)?;
+        writeln!(emojis)?;
+        derived.colors.write(&mut emojis)?;
+        writeln!(emojis)?;
+        write_confusables(&mut emojis, unicode_data)?;
+        writeln!(emojis)?;
+        write_ascii_fallbacks(&mut emojis, unicode_data)?;
+        writeln!(emojis)?;
+        write_presets(&mut emojis, unicode_data)?;
+        writeln!(emojis)?;
+        write_index(
+            &mut emojis,
+            "The indices into `EMOJIS` of the emojis ordered by compact id.",
+            "BY_COMPACT_ID",
+            derived.by_compact_id.iter().copied(),
+        )?;
+        writeln!(emojis)?;
+        write_soa(&mut emojis, unicode_data)?;
+        Ok(Self {
+            emojis,
+            unicode_map,
+            shortcode_map,
+        })
+    }
+
+    /// Write the tables as `mod.rs`, `unicode.rs`, and `shortcode.rs` files in
+    /// the given directory.
+    pub fn write_dir(self, dir: &Path) -> Result<()> {
+        let mut f = fs::File::create(dir.join("mod.rs"))?;
+        writeln!(f, "{}", HEADER)?;
+        writeln!(f, "#![cfg_attr(rustfmt, rustfmt::skip)]\n")?;
+        writeln!(f, "pub mod migrate;")?;
+        writeln!(f, "pub mod shortcode;")?;
+        writeln!(f, "pub mod unicode;\n")?;
+        f.write_all(&self.emojis)?;
+
+        let mut f = fs::File::create(dir.join("unicode.rs"))?;
+        writeln!(f, "{}", HEADER)?;
+        writeln!(f, "use crate::EmojiId;\n")?;
+        write_phf_map(&mut f, "(EmojiId, EmojiId)", self.unicode_map)?;
+
+        let mut f = fs::File::create(dir.join("shortcode.rs"))?;
+        writeln!(f, "{}", HEADER)?;
+        write_sorted_shortcodes(&mut f, &self.shortcode_map)?;
+        writeln!(f)?;
+        write_phf_map(&mut f, "usize", self.shortcode_map)?;
+
+        Ok(())
+    }
+}
+
+fn write_group_enum<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
+    writeln!(w, "/// A category for an emoji.")?;
+    writeln!(w, "///")?;
+    writeln!(w, "/// Based on Unicode CLDR data.")?;
+    writeln!(
+        w,
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]"
+    )?;
+    writeln!(w, "#[repr(u8)]")?;
+    writeln!(w, "pub enum Group {{")?;
+    let names = unicode_data.keys().filter(|name| *name != "Component");
+    for (i, name) in names.enumerate() {
+        // The discriminants are part of the public API so the groups must
+        // never be reordered.
+        if GROUPS.get(i) != Some(&name.as_str()) {
+            bail!("group `{name}` is not at position {i} in `GROUPS`");
+        }
+        writeln!(w, "   {name} = {i},")?;
+    }
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+fn write_emoji_struct<W: io::Write>(
+    w: &mut W,
+    github_data: &github::ParsedData,
+    derived: &Derived,
+    group: &str,
+    subgroup: &str,
+    emoji: &unicode::Emoji,
+    (index, default_skin_tone_index, skin_tone_count): (usize, usize, usize),
+) -> Result<()> {
+    let e = emoji.as_str();
+    let name = emoji.name();
+    let uv = emoji.unicode_version();
+    write!(
+        w,
+        "Emoji {{ emoji: \"{e}\", id: EmojiId({index}), name: \"{name}\", name_words: &{:?}, folded_name: {:?}, unicode_name: {:?}, unicode_version: {uv:?}, group: Group::{group}",
+        name_words(name),
+        emoji.folded_name(),
+        emoji.unicode_name(),
+    )?;
+    match emoji.skin_tone() {
+        Some(tone) => write!(
+            w,
+            ", skin_tone: Some((EmojiId({default_skin_tone_index}), {skin_tone_count}, SkinTone::{tone:?}))",
+        )?,
+        None => write!(w, ", skin_tone: None")?,
+    }
+    write!(w, ", person_count: {}", emoji.person_count(subgroup))?;
+    write!(w, ", utf16_len: {}", e.encode_utf16().count())?;
+    write!(w, ", compact_id: {}", derived.compact_ids[e])?;
+    match derived.colors.by_emoji.get(e) {
+        Some((color, family)) => write!(w, ", color: Some((Color::{color}, {family}))")?,
+        None => write!(w, ", color: None")?,
+    }
+    match &github_data.get(e) {
+        Some(github) => write!(w, ", aliases: Some(&{:?}) }}", github.aliases())?,
+        None => write!(w, ", aliases: None }}")?,
+    }
+    Ok(())
+}
+
+/// Splits the name into words, dropping surrounding punctuation.
+///
+/// For example "flag: Côte d’Ivoire" is split into "flag", "Côte", and
+/// "d’Ivoire".
+fn name_words(name: &str) -> Vec<&str> {
+    name.split_whitespace()
+        .map(|w| w.trim_matches(&[':', ',', '(', ')', '“', '”', '!'][..]))
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+fn write_emojis_slice<W: io::Write>(
+    w: &mut W,
+    unicode_data: &unicode::ParsedData,
+    github_data: &github::ParsedData,
+    derived: &Derived,
+    unicode_map: &mut HashMap<String, String>,
+    shortcode_map: &mut HashMap<String, String>,
+) -> Result<()> {
+    let mut i = 0;
+    let mut default_skin_tone_index = 0;
+    let mut skin_tone_count = 0;
+
+    writeln!(w, "pub const EMOJIS: &[Emoji] = &[")?;
+    for (group, subgroups) in unicode_data {
+        for (subgroup_name, subgroup) in subgroups {
+            for emoji in subgroup {
+                if matches!(emoji.skin_tone(), Some(SkinTone::Default)) {
+                    default_skin_tone_index = i;
+                    skin_tone_count = emoji.skin_tones();
+                }
+                write!(w, "    ")?;
+                write_emoji_struct(
+                    w,
+                    github_data,
+                    derived,
+                    group,
+                    subgroup_name,
+                    emoji,
+                    (i, default_skin_tone_index, skin_tone_count),
+                )?;
+                writeln!(w, ",")?;
+
+                // Each emoji maps to its index and the index of its default
+                // skin tone emoji.
+                let base = match emoji.skin_tone() {
+                    Some(_) => default_skin_tone_index,
+                    None => i,
+                };
+                let value = format!("(EmojiId({i}), EmojiId({base}))");
+                unicode_map.insert(emoji.as_str().to_owned(), value.clone());
+                for v in emoji.variations() {
+                    assert!(unicode_map.insert(v.to_owned(), value.clone()).is_none());
+                }
+
+                if let Some(github) = &github_data.get(emoji.as_str()) {
+                    for alias in github.aliases() {
+                        assert!(shortcode_map
+                            .insert(alias.to_owned(), i.to_string())
+                            .is_none());
+                    }
+                }
+                i += 1;
+            }
+        }
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_sorted_shortcodes<W: io::Write>(w: &mut W, map: &HashMap<String, String>) -> Result<()> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort();
+    writeln!(
+        w,
+        "/// All shortcodes and their index into `EMOJIS` ordered by shortcode."
+    )?;
+    writeln!(w, "pub const SORTED: &[(&str, u16)] = &[")?;
+    for (shortcode, i) in entries {
+        writeln!(w, "    ({shortcode:?}, {i}),")?;
+    }
+    writeln!(w, "];")?;
+    writeln!(w)?;
+    let max_len = map.keys().map(String::len).max().unwrap_or(0);
+    writeln!(w, "/// The maximum length in bytes of any shortcode.")?;
+    writeln!(w, "pub const MAX_LEN: usize = {max_len};")?;
+    Ok(())
+}
+
+fn write_phf_map<W: io::Write>(w: &mut W, ty: &str, map: HashMap<String, String>) -> Result<()> {
+    write!(w, "pub static MAP: ::phf::Map<&'static str, {ty}> = ")?;
+    let mut gen = phf_codegen::Map::new();
+    for (key, value) in &map {
+        gen.entry(key, value);
+    }
+    writeln!(w, "{};", gen.build())?;
+    Ok(())
+}
+
+/// Returns an iterator over the emojis in the same order as `EMOJIS`.
+fn emojis(unicode_data: &unicode::ParsedData) -> impl Iterator<Item = &unicode::Emoji> {
+    unicode_data
+        .values()
+        .flat_map(|subgroups| subgroups.values())
+        .flatten()
+}
+
+fn write_index<W: io::Write>(
+    w: &mut W,
+    doc: &str,
+    name: &str,
+    indices: impl Iterator<Item = usize>,
+) -> Result<()> {
+    writeln!(w, "/// {doc}")?;
+    writeln!(w, "pub const {name}: &[u16] = &[")?;
+    let indices: Vec<_> = indices.collect();
+    for chunk in indices.chunks(16) {
+        let line: Vec<_> = chunk.iter().map(|i| i.to_string()).collect();
+        writeln!(w, "    {},", line.join(", "))?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_by_name_index<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
+    let mut entries: Vec<_> = emojis(unicode_data)
+        .enumerate()
+        .filter(|(_, e)| matches!(e.skin_tone(), None | Some(SkinTone::Default)))
+        .collect();
+    entries.sort_by_cached_key(|(i, e)| (e.name().to_lowercase(), *i));
+    write_index(
+        w,
+        "Indices into `EMOJIS` of the default skin tone emojis ordered by name.",
+        "BY_NAME",
+        entries.into_iter().map(|(i, _)| i),
+    )
+}
+
+fn write_by_version_index<W: io::Write>(
+    w: &mut W,
+    unicode_data: &unicode::ParsedData,
+) -> Result<()> {
+    let mut entries: Vec<_> = emojis(unicode_data)
+        .enumerate()
+        .filter(|(_, e)| matches!(e.skin_tone(), None | Some(SkinTone::Default)))
+        .collect();
+    // stable sort so that ties stay in CLDR order
+    entries.sort_by_key(|(_, e)| *e.unicode_version());
+    write_index(
+        w,
+        "Indices into `EMOJIS` of the default skin tone emojis ordered by Unicode version.",
+        "BY_VERSION",
+        entries.into_iter().map(|(i, _)| i),
+    )
+}
+
+fn write_counts<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
+    let count = |emojis: &mut dyn Iterator<Item = &unicode::Emoji>| {
+        emojis.fold((0, 0), |(n, m), e| {
+            let default = matches!(e.skin_tone(), None | Some(SkinTone::Default));
+            (n + usize::from(default), m + 1)
+        })
+    };
+    let (n, m) = count(&mut emojis(unicode_data));
+    writeln!(
+        w,
+        "/// The number of emojis, not including skin tone variants."
+    )?;
+    writeln!(w, "pub const COUNT: usize = {n};")?;
+    writeln!(w)?;
+    writeln!(w, "/// The number of emojis, including skin tone variants.")?;
+    writeln!(w, "pub const COUNT_WITH_SKIN_TONES: usize = {m};")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "/// The number of emojis in each group, without and with skin tone variants."
+    )?;
+    writeln!(w, "pub const GROUP_COUNTS: &[(usize, usize)] = &[")?;
+    for (name, subgroups) in unicode_data {
+        if name == "Component" {
+            continue;
+        }
+        let (n, m) = count(&mut subgroups.values().flatten());
+        writeln!(w, "    ({n}, {m}),")?;
+    }
+    writeln!(w, "];")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "/// The number of emojis in each group introduced in each Unicode version, not including skin tone variants."
+    )?;
+    writeln!(
+        w,
+        "pub const GROUP_VERSIONS: &[&[(UnicodeVersion, usize)]] = &["
+    )?;
+    for (name, subgroups) in unicode_data {
+        if name == "Component" {
+            continue;
+        }
+        let mut versions = BTreeMap::new();
+        for e in subgroups.values().flatten() {
+            if matches!(e.skin_tone(), None | Some(SkinTone::Default)) {
+                *versions.entry(*e.unicode_version()).or_insert(0) += 1;
+            }
+        }
+        let entries: Vec<_> = versions
+            .iter()
+            .map(|(v, n)| format!("({v:?}, {n})"))
+            .collect();
+        writeln!(w, "    &[{}],", entries.join(", "))?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_subgroups<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
+    writeln!(
+        w,
+        "/// The group, name, and range in `EMOJIS` of each subgroup in order."
+    )?;
+    writeln!(
+        w,
+        "pub const SUBGROUPS: &[(Group, &str, EmojiId, u16)] = &["
+    )?;
+    let mut start = 0;
+    for (group, subgroups) in unicode_data {
+        for (subgroup, emojis) in subgroups {
+            let len = emojis.len();
+            writeln!(
+                w,
+                "    (Group::{group}, {subgroup:?}, EmojiId({start}), {len}),"
+            )?;
+            start += len;
+        }
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+/// Write the emoji tables as parallel slices for the `soa` feature.
+fn write_soa<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
+    let emojis: Vec<_> = unicode_data
+        .iter()
+        .flat_map(|(group, subgroups)| subgroups.values().flatten().map(move |e| (group, e)))
+        .collect();
+    writeln!(
+        w,
+        "/// The emoji tables as parallel slices in the same order as `EMOJIS`."
+    )?;
+    writeln!(w, "#[cfg(feature = \"soa\")]")?;
+    writeln!(w, "pub mod soa {{")?;
+    writeln!(w, "    use super::Group;")?;
+    writeln!(w, "    use crate::UnicodeVersion;\n")?;
+    writeln!(w, "    pub static AS_STR: &[&str] = &[")?;
+    for (_, e) in &emojis {
+        writeln!(w, "        \"{}\",", e.as_str())?;
+    }
+    writeln!(w, "    ];\n")?;
+    writeln!(w, "    pub static NAMES: &[&str] = &[")?;
+    for (_, e) in &emojis {
+        writeln!(w, "        \"{}\",", e.name())?;
+    }
+    writeln!(w, "    ];\n")?;
+    writeln!(w, "    pub static GROUPS: &[Group] = &[")?;
+    for (group, _) in &emojis {
+        writeln!(w, "        Group::{group},")?;
+    }
+    writeln!(w, "    ];\n")?;
+    writeln!(w, "    pub static UNICODE_VERSIONS: &[UnicodeVersion] = &[")?;
+    for (_, e) in &emojis {
+        writeln!(w, "        {:?},", e.unicode_version())?;
+    }
+    writeln!(w, "    ];")?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+fn write_start_ranges<W: io::Write>(
+    w: &mut W,
+    unicode_map: &HashMap<String, String>,
+) -> Result<()> {
+    let mut starts: Vec<u32> = unicode_map
+        .keys()
+        .filter_map(|e| e.chars().next())
+        .map(u32::from)
+        .collect();
+    starts.sort_unstable();
+    starts.dedup();
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for c in starts {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == c => *end = c,
+            _ => ranges.push((c, c)),
+        }
+    }
+    writeln!(
+        w,
+        "/// The ranges of characters that an emoji, including variations, can start with."
+    )?;
+    writeln!(w, "pub const START_RANGES: &[(char, char)] = &[")?;
+    for (start, end) in ranges {
+        writeln!(w, "    ('\\u{{{start:x}}}', '\\u{{{end:x}}}'),")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_confusables<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
+    let index: HashMap<_, _> = emojis(unicode_data)
+        .enumerate()
+        .map(|(i, e)| (e.as_str(), i))
+        .collect();
+    writeln!(
+        w,
+        "/// Indices into `EMOJIS` of the emojis in each set of confusables."
+    )?;
+    writeln!(w, "pub const CONFUSABLES: &[&[u16]] = &[")?;
+    for set in CONFUSABLES {
+        let mut line = Vec::new();
+        for e in *set {
+            match index.get(e) {
+                Some(i) => line.push(i.to_string()),
+                None => bail!("confusable `{e}` is not a fully qualified emoji"),
+            }
+        }
+        writeln!(w, "    &[{}],", line.join(", "))?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_ascii_fallbacks<W: io::Write>(
+    w: &mut W,
+    unicode_data: &unicode::ParsedData,
+) -> Result<()> {
+    let index: HashMap<_, _> = emojis(unicode_data)
+        .enumerate()
+        .map(|(i, e)| (e.as_str(), i))
+        .collect();
+    let mut fallbacks = Vec::new();
+    for (e, fallback) in ASCII_FALLBACKS {
+        if !fallback.is_ascii() {
+            bail!("fallback `{fallback}` for `{e}` is not ASCII");
+        }
+        match index.get(e) {
+            Some(i) => fallbacks.push((*i, *fallback)),
+            None => bail!("fallback emoji `{e}` is not a fully qualified emoji"),
+        }
+    }
+    fallbacks.sort_unstable();
+    writeln!(
+        w,
+        "/// Indices into `EMOJIS` and the ASCII fallback of each emoji, ordered by index."
+    )?;
+    writeln!(w, "pub const ASCII_FALLBACKS: &[(u16, &str)] = &[")?;
+    for (i, fallback) in fallbacks {
+        writeln!(w, "    ({i}, {fallback:?}),")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_presets<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
+    let index: HashMap<_, _> = emojis(unicode_data)
+        .enumerate()
+        .map(|(i, e)| (e.as_str(), i))
+        .collect();
+    writeln!(w, "pub mod presets {{")?;
+    writeln!(w, "    use super::EMOJIS;")?;
+    writeln!(w, "    use crate::Emoji;")?;
+    for (name, doc, preset) in PRESETS {
+        let mut entries = Vec::new();
+        for e in *preset {
+            match index.get(e) {
+                Some(i) => entries.push(format!("&EMOJIS[{i}]")),
+                None => bail!("preset emoji `{e}` is not a fully qualified emoji"),
+            }
+        }
+        writeln!(w)?;
+        writeln!(w, "    /// {doc}")?;
+        writeln!(
+            w,
+            "    pub const {name}: &[&Emoji] = &[{}];",
+            entries.join(", ")
+        )?;
+    }
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+/// Data about each emoji that depends on the whole emoji set.
+struct Derived {
+    colors: ColorFamilies,
+    /// The compact id of each emoji.
+    compact_ids: HashMap<String, usize>,
+    /// The indices into `EMOJIS` ordered by compact id.
+    by_compact_id: Vec<usize>,
+}
+
+impl Derived {
+    fn new(unicode_data: &unicode::ParsedData) -> Self {
+        // Compact ids are assigned in order of Unicode version and then code
+        // points. New releases only add emojis with a newer version so this
+        // never changes the ids of existing emojis.
+        let mut by_compact_id: Vec<_> = emojis(unicode_data).enumerate().collect();
+        by_compact_id.sort_by_key(|(_, e)| (*e.unicode_version(), e.as_str()));
+        let compact_ids = by_compact_id
+            .iter()
+            .enumerate()
+            .map(|(id, (_, e))| (e.as_str().to_owned(), id))
+            .collect();
+        Self {
+            colors: ColorFamilies::new(unicode_data),
+            compact_ids,
+            by_compact_id: by_compact_id.into_iter().map(|(i, _)| i).collect(),
+        }
+    }
+}
+
+/// Emojis that are the same thing in different colors, e.g. the hearts.
+struct ColorFamilies {
+    /// The color and family of each emoji in a family.
+    by_emoji: HashMap<String, (String, usize)>,
+    /// The indices into `EMOJIS` of the emojis in each family.
+    families: Vec<Vec<usize>>,
+}
+
+impl ColorFamilies {
+    fn new(unicode_data: &unicode::ParsedData) -> Self {
+        let mut things: IndexMap<String, Vec<(usize, &unicode::Emoji, &str)>> = IndexMap::new();
+        for (i, emoji) in emojis(unicode_data).enumerate() {
+            if let Some((color, thing)) = emoji.color() {
+                things.entry(thing).or_default().push((i, emoji, color));
+            }
+        }
+        let mut by_emoji = HashMap::new();
+        let mut families = Vec::new();
+        for members in things.into_values().filter(|m| m.len() > 1) {
+            for (_, emoji, color) in &members {
+                let color = color.to_camel_case();
+                by_emoji.insert(emoji.as_str().to_owned(), (color, families.len()));
+            }
+            families.push(members.into_iter().map(|(i, _, _)| i).collect());
+        }
+        Self { by_emoji, families }
+    }
+
+    fn write<W: io::Write>(&self, w: &mut W) -> Result<()> {
+        writeln!(
+            w,
+            "/// Indices into `EMOJIS` of the emojis in each color family."
+        )?;
+        writeln!(w, "pub const COLOR_FAMILIES: &[&[u16]] = &[")?;
+        for family in &self.families {
+            let line: Vec<_> = family.iter().map(|i| i.to_string()).collect();
+            writeln!(w, "    &[{}],", line.join(", "))?;
+        }
+        writeln!(w, "];")?;
+        Ok(())
+    }
+}
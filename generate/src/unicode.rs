@@ -1,5 +1,6 @@
-//! Fetch and parse raw emoji data from Unicode.org.
+//! Parse raw emoji data from Unicode.org.
 
+use std::fmt;
 use std::iter;
 use std::str;
 use std::str::FromStr;
@@ -9,7 +10,11 @@ use heck::CamelCase;
 use indexmap::IndexMap;
 use then::Some;
 
-const URL: &str = "https://unicode.org/Public/emoji/15.1/emoji-test.txt";
+/// The default Unicode emoji version.
+pub const DEFAULT_VERSION: &str = "16.0";
+
+/// The Unicode emoji versions that tables are generated for, oldest first.
+pub const VERSIONS: &[&str] = &["15.1", "16.0"];
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Status {
@@ -19,7 +24,7 @@ pub enum Status {
     Component,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct UnicodeVersion {
     major: u32,
     minor: u32,
@@ -70,21 +75,42 @@ pub type ParsedData = IndexMap<String, IndexMap<String, Vec<Emoji>>>;
 
 type Lines<'a> = iter::Peekable<str::Lines<'a>>;
 
-fn fetch_emoji_data() -> Result<String> {
-    let mut buf = Vec::new();
-    let mut easy = curl::easy::Easy::new();
-    easy.fail_on_error(true)?;
-    easy.follow_location(true)?;
-    easy.url(URL)?;
-    {
-        let mut transfer = easy.transfer();
-        transfer.write_function(|data| {
-            buf.extend_from_slice(data);
-            Ok(data.len())
-        })?;
-        transfer.perform()?;
-    }
-    Ok(String::from_utf8(buf)?)
+/// The colors that emojis come in, longest first so that "light blue" is
+/// matched before "blue".
+const COLORS: &[&str] = &[
+    "light blue",
+    "red",
+    "orange",
+    "yellow",
+    "green",
+    "blue",
+    "purple",
+    "brown",
+    "black",
+    "white",
+    "grey",
+    "pink",
+];
+
+/// Returns the URL of the emoji test data for the given Unicode version.
+pub fn url(version: &str) -> String {
+    format!("https://unicode.org/Public/emoji/{version}/emoji-test.txt")
+}
+
+/// Returns the supported Unicode version before the given one, if any.
+pub fn previous_version(version: &str) -> Option<&'static str> {
+    let i = VERSIONS.iter().position(|&v| v == version)?;
+    i.checked_sub(1).map(|i| VERSIONS[i])
+}
+
+/// Returns the name of the generated module for the given Unicode version.
+pub fn module_name(version: &str) -> String {
+    format!("v{}", version.replace('.', "_"))
+}
+
+/// Returns the file name to use for a local copy of the emoji test data.
+pub fn file_name(version: &str) -> String {
+    format!("emoji-test-{version}.txt")
 }
 
 trait LinesExt {
@@ -139,6 +165,12 @@ impl FromStr for UnicodeVersion {
     }
 }
 
+impl fmt::Display for UnicodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
 fn skin_tones() -> impl Iterator<Item = SkinTone> {
     [
         SkinTone::Light,
@@ -257,9 +289,70 @@ impl Emoji {
     pub fn variations(&self) -> &[String] {
         &self.variations
     }
+
+    /// Returns the name folded to lowercase ASCII if it is different from
+    /// the name, e.g. "piñata" is folded to "pinata".
+    pub fn folded_name(&self) -> Option<String> {
+        let folded = deunicode::deunicode(&self.name).to_lowercase();
+        (folded != self.name).some(folded)
+    }
+
+    /// Returns the color of this emoji and its name with the color removed.
+    ///
+    /// The color must be at the start of the name or follow a size, e.g. "red
+    /// heart" or "large blue diamond".
+    pub fn color(&self) -> Option<(&'static str, String)> {
+        let (size, rest) = match self.name.split_once(' ') {
+            Some((size @ ("large" | "small"), rest)) => (Some(size), rest),
+            _ => (None, self.name.as_str()),
+        };
+        COLORS.iter().find_map(|&color| {
+            let thing = rest.strip_prefix(color)?.strip_prefix(' ')?;
+            let thing = match size {
+                Some(size) => format!("{size} {thing}"),
+                None => thing.to_owned(),
+            };
+            Some((color, thing))
+        })
+    }
+
+    /// Returns the formal Unicode character name if this emoji is a single
+    /// character, ignoring any emoji presentation selector.
+    pub fn unicode_name(&self) -> Option<String> {
+        let mut chars = self.emoji.trim_end_matches('\u{fe0f}').chars();
+        let c = chars.next()?;
+        if chars.next().is_some() {
+            return None;
+        }
+        unicode_names2::name(c).map(|name| name.to_string())
+    }
+
+    /// Returns the number of people depicted by this emoji.
+    ///
+    /// This is the number of person components in the ZWJ sequence, taking
+    /// into account single code point emojis that depict several people, like
+    /// 👫. Other emojis in a person subgroup depict a single person.
+    pub fn person_count(&self, subgroup: &str) -> usize {
+        let count: usize = self
+            .emoji
+            .split('\u{200d}')
+            .filter_map(|component| component.chars().next())
+            .map(|c| match c {
+                '👨' | '👩' | '🧑' | '👦' | '👧' | '🧒' | '👶' | '🗣' | '👤' => 1,
+                '👫' | '👬' | '👭' | '💏' | '💑' | '👯' | '🤼' | '🫂' | '👥' => 2,
+                '👪' => 3,
+                _ => 0,
+            })
+            .sum();
+        match subgroup {
+            "person" | "person-gesture" | "person-role" | "person-fantasy" | "person-activity"
+            | "person-sport" | "person-resting" | "family" => count.max(1),
+            _ => count,
+        }
+    }
 }
 
-fn parse_emoji_data(data: &str) -> Result<ParsedData> {
+pub fn parse_emoji_data(data: &str) -> Result<ParsedData> {
     let mut parsed_data = ParsedData::default();
     let mut lines = data.lines().peekable();
     while let Some(group) = lines.next_group() {
@@ -339,9 +432,3 @@ fn parse_emoji_data(data: &str) -> Result<ParsedData> {
 
     Ok(parsed_data)
 }
-
-pub fn fetch_and_parse_emoji_data() -> Result<ParsedData> {
-    let data = fetch_emoji_data()?;
-    let parsed_data = parse_emoji_data(&data)?;
-    Ok(parsed_data)
-}
@@ -0,0 +1,145 @@
+#ifndef EMOJIS_H
+#define EMOJIS_H
+
+/* Generated by cbindgen. DO NOT EDIT. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Represents an emoji.
+ *
+ * See [Unicode.org](https://unicode.org/emoji/charts/full-emoji-list.html) for
+ * more information.
+ */
+typedef struct Emoji Emoji;
+
+/**
+ * A borrowed UTF-8 string.
+ *
+ * The data is not NUL-terminated. If the string is not present then `ptr`
+ * will be `NULL` and `len` will be zero.
+ */
+typedef struct EmojisStr {
+  const uint8_t *ptr;
+  size_t len;
+} EmojisStr;
+
+/**
+ * Lookup an emoji by Unicode value.
+ *
+ * Returns `NULL` if the emoji is not found or the string is not valid UTF-8.
+ *
+ * # Safety
+ *
+ * `ptr` must be valid for reads of `len` bytes.
+ */
+const struct Emoji *emojis_get(const uint8_t *ptr, size_t len);
+
+/**
+ * Lookup an emoji by GitHub shortcode.
+ *
+ * Returns `NULL` if the emoji is not found or the string is not valid UTF-8.
+ *
+ * # Safety
+ *
+ * `ptr` must be valid for reads of `len` bytes.
+ */
+const struct Emoji *emojis_get_by_shortcode(const uint8_t *ptr, size_t len);
+
+/**
+ * Returns the next emoji in the iteration, see [`iter()`][crate::iter].
+ *
+ * `state` should point to an index initialized to zero, it is updated on
+ * each call. Returns `NULL` when the iteration is complete.
+ *
+ * ```c
+ * size_t state = 0;
+ * const Emoji *emoji;
+ * while ((emoji = emojis_next(&state)) != NULL) {
+ *     // ...
+ * }
+ * ```
+ *
+ * # Safety
+ *
+ * `state` must be a valid pointer.
+ */
+const struct Emoji *emojis_next(size_t *state);
+
+/**
+ * Returns the emoji as a string.
+ *
+ * # Safety
+ *
+ * `emoji` must be a pointer returned from this API.
+ */
+struct EmojisStr emojis_emoji_as_str(const struct Emoji *emoji);
+
+/**
+ * Returns the CLDR name of the emoji.
+ *
+ * # Safety
+ *
+ * `emoji` must be a pointer returned from this API.
+ */
+struct EmojisStr emojis_emoji_name(const struct Emoji *emoji);
+
+/**
+ * Returns the major part of the Unicode version the emoji first appeared in.
+ *
+ * # Safety
+ *
+ * `emoji` must be a pointer returned from this API.
+ */
+uint32_t emojis_emoji_unicode_version_major(const struct Emoji *emoji);
+
+/**
+ * Returns the minor part of the Unicode version the emoji first appeared in.
+ *
+ * # Safety
+ *
+ * `emoji` must be a pointer returned from this API.
+ */
+uint32_t emojis_emoji_unicode_version_minor(const struct Emoji *emoji);
+
+/**
+ * Returns the group the emoji belongs to.
+ *
+ * The value is the position of the group in the order given by
+ * [`Group::iter()`][crate::Group::iter], starting at zero.
+ *
+ * # Safety
+ *
+ * `emoji` must be a pointer returned from this API.
+ */
+uint8_t emojis_emoji_group(const struct Emoji *emoji);
+
+/**
+ * Returns the skin tone of the emoji.
+ *
+ * The value is the position of the skin tone in the [`SkinTone`] enum,
+ * starting at zero, or `-1` if skin tones are not applicable to this emoji.
+ *
+ * # Safety
+ *
+ * `emoji` must be a pointer returned from this API.
+ */
+int32_t emojis_emoji_skin_tone(const struct Emoji *emoji);
+
+/**
+ * Returns the first GitHub shortcode for the emoji.
+ *
+ * If the emoji does not have a shortcode then the returned string will be
+ * `NULL`.
+ *
+ * # Safety
+ *
+ * `emoji` must be a pointer returned from this API.
+ */
+struct EmojisStr emojis_emoji_shortcode(const struct Emoji *emoji);
+
+#endif  /* EMOJIS_H */
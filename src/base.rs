@@ -0,0 +1,275 @@
+//! Resolve sequences that are not exactly an RGI emoji.
+
+use crate::components::{VS16, ZWJ};
+use crate::Emoji;
+
+fn is_skin_tone(c: char) -> bool {
+    matches!(c, '\u{1f3fb}'..='\u{1f3ff}')
+}
+
+/// Returns whether a ZWJ sequence component only modifies the hair or gender
+/// of the preceding person.
+fn is_modifier_component(component: &str) -> bool {
+    matches!(
+        component.trim_end_matches(VS16),
+        "♀" | "♂" | "🦰" | "🦱" | "🦳" | "🦲"
+    )
+}
+
+/// A fixed size buffer that a sequence is built in.
+pub(crate) struct Buf {
+    buf: [u8; crate::gen::MAX_LEN],
+    len: usize,
+}
+
+impl Buf {
+    pub(crate) fn new() -> Self {
+        Self {
+            buf: [0; crate::gen::MAX_LEN],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, c: char) -> Option<()> {
+        let end = self.len + c.len_utf8();
+        c.encode_utf8(self.buf.get_mut(self.len..end)?);
+        self.len = end;
+        Some(())
+    }
+
+    pub(crate) fn push_opt(&mut self, c: Option<char>) -> Option<()> {
+        match c {
+            Some(c) => self.push(c),
+            None => Some(()),
+        }
+    }
+
+    pub(crate) fn get(&self) -> Option<&'static Emoji> {
+        crate::get(core::str::from_utf8(&self.buf[..self.len]).ok()?)
+    }
+}
+
+/// Lookup the emoji with the skin tones removed and optionally the hair and
+/// gender components removed.
+fn strip(s: &str, components: bool) -> Option<&'static Emoji> {
+    let mut buf = Buf::new();
+    let mut first = true;
+    for component in s.split(ZWJ) {
+        if components && !first && is_modifier_component(component) {
+            continue;
+        }
+        if !first {
+            buf.push(ZWJ)?;
+        }
+        for c in component.chars().filter(|&c| !is_skin_tone(c)) {
+            buf.push(c)?;
+        }
+        first = false;
+    }
+    buf.get()
+}
+
+/// Returns the gender-neutral version of a person character.
+fn neutral_char(c: char) -> char {
+    match c {
+        '👨' | '👩' => '🧑',
+        '👦' | '👧' => '🧒',
+        '👴' | '👵' => '🧓',
+        '🤴' | '👸' => '🫅',
+        '🤰' | '🫃' => '🫄',
+        c => c,
+    }
+}
+
+/// Lookup the single character couple emoji for a couple with the same skin
+/// tone, e.g. 👩‍❤️‍👨 is 💑 and 👨🏻‍❤️‍💋‍👨🏻 is 💏🏻.
+fn neutral_couple(s: &str) -> Option<&'static Emoji> {
+    let mut components = s.split(ZWJ);
+    let first = components.next()?;
+    if components.next()? != "❤\u{fe0f}" {
+        return None;
+    }
+    let (couple, last) = match (components.next()?, components.next()) {
+        ("💋", Some(last)) => ('💏', last),
+        (last, None) => ('💑', last),
+        _ => return None,
+    };
+    if components.next().is_some() {
+        return None;
+    }
+    let skin_tone_of = |person: &str| {
+        let rest = person.strip_prefix(|c| matches!(c, '👨' | '👩' | '🧑'))?;
+        let mut chars = rest.chars();
+        match (chars.next(), chars.next()) {
+            (None, _) => Some(None),
+            (Some(c), None) if is_skin_tone(c) => Some(Some(c)),
+            _ => None,
+        }
+    };
+    let skin_tone = skin_tone_of(first)?;
+    if skin_tone != skin_tone_of(last)? {
+        return None;
+    }
+    let mut buf = Buf::new();
+    buf.push(couple)?;
+    buf.push_opt(skin_tone)?;
+    buf.get()
+}
+
+/// Lookup the gender-neutral version of an emoji.
+///
+/// Gendered people are replaced with their neutral version and gender signs
+/// are removed, keeping any skin tones.
+pub(crate) fn neutral_form(s: &str) -> Option<&'static Emoji> {
+    if let Some(emoji) = neutral_couple(s) {
+        return Some(emoji);
+    }
+    let mut buf = Buf::new();
+    let mut first = true;
+    for component in s.split(ZWJ) {
+        if !first && matches!(component.trim_end_matches(VS16), "♀" | "♂") {
+            continue;
+        }
+        if !first {
+            buf.push(ZWJ)?;
+        }
+        let mut chars = component.chars();
+        match chars.next()? {
+            // People holding hands are single characters, their neutral
+            // version is a ZWJ sequence.
+            '👫' | '👬' | '👭' => {
+                let skin_tone = chars.next().filter(|&c| is_skin_tone(c));
+                for c in ['🧑', ZWJ, '🤝', ZWJ, '🧑'] {
+                    buf.push(c)?;
+                    if let (Some(t), '🧑') = (skin_tone, c) {
+                        buf.push(t)?;
+                    }
+                }
+            }
+            // Santa and Mrs. Claus are single characters, their neutral
+            // version is a ZWJ sequence.
+            '🎅' | '🤶' => {
+                buf.push('🧑')?;
+                for c in chars {
+                    buf.push(c)?;
+                }
+                buf.push(ZWJ)?;
+                buf.push('🎄')?;
+            }
+            c => {
+                buf.push(neutral_char(c))?;
+                for c in chars {
+                    buf.push(c)?;
+                }
+            }
+        }
+        first = false;
+    }
+    buf.get()
+}
+
+/// Lookup the base emoji of an emoji with any skin tone, hair, or gender
+/// modifiers.
+///
+/// The modifiers are removed and the default skin tone of the remaining emoji
+/// is returned. This accepts skin tone combinations that are not part of the
+/// RGI set, which makes it useful for example to deduplicate reactions by
+/// their base emoji.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::get_base("👍🏽").unwrap(), "👍");
+/// assert_eq!(emojis::get_base("👩🏿‍🦰").unwrap(), "👩");
+/// assert_eq!(emojis::get_base("🏃🏻‍♀️").unwrap(), "🏃");
+/// assert_eq!(emojis::get_base("🧑🏻‍🤝‍🧑🏿").unwrap(), "🧑‍🤝‍🧑");
+/// assert!(emojis::get_base("rocket").is_none());
+/// ```
+pub fn get_base(s: &str) -> Option<&'static Emoji> {
+    let emoji = strip(s, true).or_else(|| strip(s, false))?;
+    Some(
+        emoji
+            .skin_tones()
+            .and_then(|mut e| e.next())
+            .unwrap_or(emoji),
+    )
+}
+
+/// The result of [`get_closest()`].
+#[derive(Debug, Clone)]
+pub enum Closest<'t> {
+    /// The sequence is exactly this emoji.
+    Exact(&'static Emoji),
+    /// The sequence is this emoji with modifiers that are not part of the RGI
+    /// set, see [`get_base()`].
+    Base(&'static Emoji),
+    /// The sequence is not an emoji but its ZWJ components are.
+    Components(Components<'t>),
+}
+
+/// An iterator over the emojis that make up a ZWJ sequence.
+///
+/// This struct is created by [`get_closest()`]. Components that are not
+/// emojis are skipped.
+#[derive(Debug, Clone)]
+pub struct Components<'t> {
+    components: core::str::Split<'t, char>,
+}
+
+fn get_component(component: &str) -> Option<&'static Emoji> {
+    crate::get(component).or_else(|| get_base(component))
+}
+
+impl Iterator for Components<'_> {
+    type Item = &'static Emoji;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.components.by_ref().find_map(get_component)
+    }
+}
+
+/// Lookup the closest emoji to a sequence that may not be an RGI emoji.
+///
+/// The following are tried in order:
+///
+/// - The sequence is exactly an emoji, the same as [`get()`][crate::get].
+/// - The sequence is an emoji with modifiers, the same as [`get_base()`].
+/// - The sequence is made up of emojis joined with a ZWJ, for example a family
+///   combination that is not part of the RGI set. Renderers commonly display
+///   these emojis next to each other instead.
+///
+/// Returns `None` if none of the above apply.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Closest;
+///
+/// assert!(matches!(emojis::get_closest("🚀"), Some(Closest::Exact(e)) if e == "🚀"));
+/// assert!(matches!(emojis::get_closest("👍🏽🏿"), Some(Closest::Base(e)) if e == "👍"));
+///
+/// match emojis::get_closest("👨‍👨‍👧‍👦‍👦").unwrap() {
+///     Closest::Components(components) => {
+///         let components: Vec<_> = components.map(|e| e.as_str()).collect();
+///         assert_eq!(components, ["👨", "👨", "👧", "👦", "👦"]);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// assert!(emojis::get_closest("rocket").is_none());
+/// ```
+pub fn get_closest(s: &str) -> Option<Closest<'_>> {
+    if let Some(emoji) = crate::get(s) {
+        return Some(Closest::Exact(emoji));
+    }
+    if let Some(emoji) = get_base(s) {
+        return Some(Closest::Base(emoji));
+    }
+    let components = Components {
+        components: s.split(ZWJ),
+    };
+    components
+        .clone()
+        .next()
+        .map(|_| Closest::Components(components))
+}
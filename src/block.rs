@@ -0,0 +1,127 @@
+//! Unicode blocks of emoji code points.
+
+use core::cmp::Ordering;
+use core::ops::RangeInclusive;
+
+/// Every Unicode block containing a code point used by an emoji, including
+/// components such as variation selectors, ordered by code point.
+const BLOCKS: &[(char, char, &str)] = &[
+    ('\u{0000}', '\u{007F}', "Basic Latin"),
+    ('\u{0080}', '\u{00FF}', "Latin-1 Supplement"),
+    ('\u{2000}', '\u{206F}', "General Punctuation"),
+    (
+        '\u{20D0}',
+        '\u{20FF}',
+        "Combining Diacritical Marks for Symbols",
+    ),
+    ('\u{2100}', '\u{214F}', "Letterlike Symbols"),
+    ('\u{2190}', '\u{21FF}', "Arrows"),
+    ('\u{2300}', '\u{23FF}', "Miscellaneous Technical"),
+    ('\u{2460}', '\u{24FF}', "Enclosed Alphanumerics"),
+    ('\u{25A0}', '\u{25FF}', "Geometric Shapes"),
+    ('\u{2600}', '\u{26FF}', "Miscellaneous Symbols"),
+    ('\u{2700}', '\u{27BF}', "Dingbats"),
+    ('\u{2900}', '\u{297F}', "Supplemental Arrows-B"),
+    ('\u{2B00}', '\u{2BFF}', "Miscellaneous Symbols and Arrows"),
+    ('\u{3000}', '\u{303F}', "CJK Symbols and Punctuation"),
+    ('\u{3200}', '\u{32FF}', "Enclosed CJK Letters and Months"),
+    ('\u{FE00}', '\u{FE0F}', "Variation Selectors"),
+    ('\u{1F000}', '\u{1F02F}', "Mahjong Tiles"),
+    ('\u{1F0A0}', '\u{1F0FF}', "Playing Cards"),
+    ('\u{1F100}', '\u{1F1FF}', "Enclosed Alphanumeric Supplement"),
+    ('\u{1F200}', '\u{1F2FF}', "Enclosed Ideographic Supplement"),
+    (
+        '\u{1F300}',
+        '\u{1F5FF}',
+        "Miscellaneous Symbols and Pictographs",
+    ),
+    ('\u{1F600}', '\u{1F64F}', "Emoticons"),
+    ('\u{1F680}', '\u{1F6FF}', "Transport and Map Symbols"),
+    ('\u{1F780}', '\u{1F7FF}', "Geometric Shapes Extended"),
+    (
+        '\u{1F900}',
+        '\u{1F9FF}',
+        "Supplemental Symbols and Pictographs",
+    ),
+    (
+        '\u{1FA70}',
+        '\u{1FAFF}',
+        "Symbols and Pictographs Extended-A",
+    ),
+    ('\u{E0000}', '\u{E007F}', "Tags"),
+];
+
+/// A Unicode block containing code points used by emojis, e.g. "Emoticons".
+///
+/// Only the blocks used by emojis in this crate are known, see
+/// [`Emoji::blocks()`][crate::Emoji::blocks].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Block;
+///
+/// let block = Block::of('😀').unwrap();
+/// assert_eq!(block.name(), "Emoticons");
+/// assert_eq!(block.range(), '\u{1F600}'..='\u{1F64F}');
+/// assert_eq!(block.plane(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Block {
+    index: usize,
+}
+
+impl Block {
+    /// Returns an iterator over all known blocks ordered by code point.
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Block> {
+        (0..BLOCKS.len()).map(|index| Self { index })
+    }
+
+    /// Returns the block containing the code point.
+    ///
+    /// Returns `None` if the code point is not in a block used by emojis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Block;
+    ///
+    /// assert_eq!(Block::of('\u{200D}').unwrap().name(), "General Punctuation");
+    /// assert!(Block::of('Ж').is_none());
+    /// ```
+    pub fn of(c: char) -> Option<Self> {
+        let index = BLOCKS
+            .binary_search_by(|&(start, end, _)| {
+                if c < start {
+                    Ordering::Greater
+                } else if c > end {
+                    Ordering::Less
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()?;
+        Some(Self { index })
+    }
+
+    /// Returns the Unicode name of this block.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        BLOCKS[self.index].2
+    }
+
+    /// Returns the code points in this block.
+    #[inline]
+    pub fn range(self) -> RangeInclusive<char> {
+        let (start, end, _) = BLOCKS[self.index];
+        start..=end
+    }
+
+    /// Returns the Unicode plane of this block, e.g. 0 for the Basic
+    /// Multilingual Plane and 1 for the Supplementary Multilingual Plane.
+    #[inline]
+    pub fn plane(self) -> u8 {
+        (BLOCKS[self.index].0 as u32 >> 16) as u8
+    }
+}
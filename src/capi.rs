@@ -0,0 +1,198 @@
+//! A C-compatible API.
+//!
+//! All functions are prefixed with `emojis_` and operate on pointers to the
+//! static emoji data compiled into this crate, so nothing returned from this
+//! API ever needs to be freed. Strings passed to and returned from this API
+//! are UTF-8 encoded and are *not* NUL-terminated, instead they are always
+//! passed along with their length in bytes.
+//!
+//! A C header for this API is available in `include/emojis.h`, it can be
+//! regenerated using [cbindgen] and the `cbindgen.toml` configuration in the
+//! root of this repository.
+//!
+//! ```sh
+//! cbindgen --config cbindgen.toml --output include/emojis.h
+//! ```
+//!
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+
+use core::ptr;
+use core::slice;
+use core::str;
+
+use crate::{Emoji, SkinTone};
+
+/// A borrowed UTF-8 string.
+///
+/// The data is not NUL-terminated. If the string is not present then `ptr`
+/// will be `NULL` and `len` will be zero.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct EmojisStr {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+impl EmojisStr {
+    const NULL: Self = Self {
+        ptr: ptr::null(),
+        len: 0,
+    };
+
+    fn new(s: &str) -> Self {
+        Self {
+            ptr: s.as_ptr(),
+            len: s.len(),
+        }
+    }
+}
+
+/// Converts a C string and length into a `&str`.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes.
+unsafe fn to_str<'a>(ptr: *const u8, len: usize) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    str::from_utf8(slice::from_raw_parts(ptr, len)).ok()
+}
+
+fn to_ptr(emoji: Option<&'static Emoji>) -> *const Emoji {
+    emoji.map_or(ptr::null(), |e| e as *const Emoji)
+}
+
+/// Lookup an emoji by Unicode value.
+///
+/// Returns `NULL` if the emoji is not found or the string is not valid UTF-8.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_get(ptr: *const u8, len: usize) -> *const Emoji {
+    to_ptr(to_str(ptr, len).and_then(crate::get))
+}
+
+/// Lookup an emoji by GitHub shortcode.
+///
+/// Returns `NULL` if the emoji is not found or the string is not valid UTF-8.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_get_by_shortcode(ptr: *const u8, len: usize) -> *const Emoji {
+    to_ptr(to_str(ptr, len).and_then(crate::get_by_shortcode))
+}
+
+/// Returns the next emoji in the iteration, see [`iter()`][crate::iter].
+///
+/// `state` should point to an index initialized to zero, it is updated on
+/// each call. Returns `NULL` when the iteration is complete.
+///
+/// ```c
+/// size_t state = 0;
+/// const Emoji *emoji;
+/// while ((emoji = emojis_next(&state)) != NULL) {
+///     // ...
+/// }
+/// ```
+///
+/// # Safety
+///
+/// `state` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_next(state: *mut usize) -> *const Emoji {
+    let emojis = crate::gen::EMOJIS;
+    let mut i = *state;
+    while let Some(emoji) = emojis.get(i) {
+        i += 1;
+        if matches!(emoji.skin_tone(), Some(SkinTone::Default) | None) {
+            *state = i;
+            return emoji;
+        }
+    }
+    *state = i;
+    ptr::null()
+}
+
+/// Returns the emoji as a string.
+///
+/// # Safety
+///
+/// `emoji` must be a pointer returned from this API.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_emoji_as_str(emoji: *const Emoji) -> EmojisStr {
+    EmojisStr::new((*emoji).as_str())
+}
+
+/// Returns the CLDR name of the emoji.
+///
+/// # Safety
+///
+/// `emoji` must be a pointer returned from this API.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_emoji_name(emoji: *const Emoji) -> EmojisStr {
+    EmojisStr::new((*emoji).name())
+}
+
+/// Returns the major part of the Unicode version the emoji first appeared in.
+///
+/// # Safety
+///
+/// `emoji` must be a pointer returned from this API.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_emoji_unicode_version_major(emoji: *const Emoji) -> u32 {
+    (*emoji).unicode_version().major()
+}
+
+/// Returns the minor part of the Unicode version the emoji first appeared in.
+///
+/// # Safety
+///
+/// `emoji` must be a pointer returned from this API.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_emoji_unicode_version_minor(emoji: *const Emoji) -> u32 {
+    (*emoji).unicode_version().minor()
+}
+
+/// Returns the group the emoji belongs to.
+///
+/// The value is the position of the group in the order given by
+/// [`Group::iter()`][crate::Group::iter], starting at zero.
+///
+/// # Safety
+///
+/// `emoji` must be a pointer returned from this API.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_emoji_group(emoji: *const Emoji) -> u8 {
+    (*emoji).group() as u8
+}
+
+/// Returns the skin tone of the emoji.
+///
+/// The value is the position of the skin tone in the [`SkinTone`] enum,
+/// starting at zero, or `-1` if skin tones are not applicable to this emoji.
+///
+/// # Safety
+///
+/// `emoji` must be a pointer returned from this API.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_emoji_skin_tone(emoji: *const Emoji) -> i32 {
+    (*emoji).skin_tone().map_or(-1, |st| st as i32)
+}
+
+/// Returns the first GitHub shortcode for the emoji.
+///
+/// If the emoji does not have a shortcode then the returned string will be
+/// `NULL`.
+///
+/// # Safety
+///
+/// `emoji` must be a pointer returned from this API.
+#[no_mangle]
+pub unsafe extern "C" fn emojis_emoji_shortcode(emoji: *const Emoji) -> EmojisStr {
+    (*emoji).shortcode().map_or(EmojisStr::NULL, EmojisStr::new)
+}
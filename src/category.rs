@@ -0,0 +1,89 @@
+//! Semantic categories of emojis.
+
+use crate::gen::SUBGROUPS;
+use crate::Emoji;
+
+/// A semantic category of an emoji.
+///
+/// Categories are derived from the Unicode CLDR subgroups but unlike the
+/// subgroups, which are meant for display, they are stable and only cover
+/// emojis that depict a kind of living thing or natural phenomenon.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Category;
+///
+/// assert_eq!(emojis::get("🦉").unwrap().category(), Some(Category::Bird));
+/// assert!(Category::Bird.emojis().any(|e| e == "🐧"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Category {
+    Mammal,
+    Bird,
+    Amphibian,
+    Reptile,
+    Marine,
+    Bug,
+    Flower,
+    Plant,
+    Fruit,
+    Vegetable,
+    Weather,
+}
+
+impl Category {
+    const ALL: &'static [Self] = &[
+        Self::Mammal,
+        Self::Bird,
+        Self::Amphibian,
+        Self::Reptile,
+        Self::Marine,
+        Self::Bug,
+        Self::Flower,
+        Self::Plant,
+        Self::Fruit,
+        Self::Vegetable,
+        Self::Weather,
+    ];
+
+    /// Returns the CLDR subgroup this category is derived from.
+    const fn subgroup(self) -> &'static str {
+        match self {
+            Self::Mammal => "animal-mammal",
+            Self::Bird => "animal-bird",
+            Self::Amphibian => "animal-amphibian",
+            Self::Reptile => "animal-reptile",
+            Self::Marine => "animal-marine",
+            Self::Bug => "animal-bug",
+            Self::Flower => "plant-flower",
+            Self::Plant => "plant-other",
+            Self::Fruit => "food-fruit",
+            Self::Vegetable => "food-vegetable",
+            Self::Weather => "sky & weather",
+        }
+    }
+
+    /// Returns an iterator over all the categories.
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Returns an iterator over the emojis in this category.
+    ///
+    /// The emojis are in Unicode CLDR order.
+    pub fn emojis(self) -> impl Iterator<Item = &'static Emoji> {
+        SUBGROUPS
+            .iter()
+            .find(|(_, name, _, _)| *name == self.subgroup())
+            .map_or(&[][..], |&(_, _, start, len)| start.slice(len as usize))
+            .iter()
+    }
+}
+
+pub(crate) fn category(emoji: &Emoji) -> Option<Category> {
+    let name = emoji.subgroup().name();
+    Category::iter().find(|c| c.subgroup() == name)
+}
@@ -0,0 +1,136 @@
+//! Characters that are used to build emoji sequences.
+//!
+//! These are not emojis themselves and so are not returned by
+//! [`get()`][crate::get] or [`iter()`][crate::iter].
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::components::{self, Kind};
+//!
+//! let astronaut = "🧑🏽‍🚀";
+//! let kinds: Vec<_> = astronaut
+//!     .chars()
+//!     .filter_map(components::get)
+//!     .map(|c| c.kind())
+//!     .collect();
+//! assert_eq!(kinds, [Kind::SkinTone, Kind::Joiner]);
+//! ```
+
+use crate::UnicodeVersion;
+
+/// The zero width joiner used to join emojis into a ZWJ sequence.
+pub const ZWJ: char = '\u{200d}';
+
+/// The variation selector that requests text presentation.
+pub const VS15: char = '\u{fe0e}';
+
+/// The variation selector that requests emoji presentation.
+pub const VS16: char = '\u{fe0f}';
+
+/// The kind of a [`Component`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A skin tone modifier, e.g. 🏽.
+    SkinTone,
+    /// A hair style, e.g. 🦰.
+    Hair,
+    /// The zero width joiner.
+    Joiner,
+    /// A variation selector.
+    VariationSelector,
+}
+
+/// A character used to build emoji sequences.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Component {
+    ch: char,
+    name: &'static str,
+    unicode_version: UnicodeVersion,
+    kind: Kind,
+}
+
+impl Component {
+    const fn new(ch: char, name: &'static str, major: u32, minor: u32, kind: Kind) -> Self {
+        Self {
+            ch,
+            name,
+            unicode_version: UnicodeVersion::new(major, minor),
+            kind,
+        }
+    }
+
+    /// Returns the character for this component.
+    #[inline]
+    pub const fn as_char(&self) -> char {
+        self.ch
+    }
+
+    /// Returns the name of this component.
+    ///
+    /// Skin tones and hair styles use their CLDR name.
+    #[inline]
+    pub const fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Returns the Unicode emoji version this component was first used in.
+    #[inline]
+    pub const fn unicode_version(&self) -> UnicodeVersion {
+        self.unicode_version
+    }
+
+    /// Returns the kind of this component.
+    #[inline]
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
+}
+
+static COMPONENTS: &[Component] = &[
+    Component::new('\u{1f3fb}', "light skin tone", 1, 0, Kind::SkinTone),
+    Component::new('\u{1f3fc}', "medium-light skin tone", 1, 0, Kind::SkinTone),
+    Component::new('\u{1f3fd}', "medium skin tone", 1, 0, Kind::SkinTone),
+    Component::new('\u{1f3fe}', "medium-dark skin tone", 1, 0, Kind::SkinTone),
+    Component::new('\u{1f3ff}', "dark skin tone", 1, 0, Kind::SkinTone),
+    Component::new('\u{1f9b0}', "red hair", 11, 0, Kind::Hair),
+    Component::new('\u{1f9b1}', "curly hair", 11, 0, Kind::Hair),
+    Component::new('\u{1f9b3}', "white hair", 11, 0, Kind::Hair),
+    Component::new('\u{1f9b2}', "bald", 11, 0, Kind::Hair),
+    Component::new(ZWJ, "zero width joiner", 1, 0, Kind::Joiner),
+    Component::new(VS15, "variation selector-15", 1, 0, Kind::VariationSelector),
+    Component::new(VS16, "variation selector-16", 1, 0, Kind::VariationSelector),
+];
+
+/// Returns an iterator over all the components.
+///
+/// The skin tones and hair styles are in Unicode CLDR order.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::components::{self, Kind};
+///
+/// let hair: Vec<_> = components::iter()
+///     .filter(|c| c.kind() == Kind::Hair)
+///     .map(|c| c.name())
+///     .collect();
+/// assert_eq!(hair, ["red hair", "curly hair", "white hair", "bald"]);
+/// ```
+pub fn iter() -> impl Iterator<Item = &'static Component> {
+    COMPONENTS.iter()
+}
+
+/// Lookup a component by character.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::components;
+///
+/// assert_eq!(components::get('🏽').unwrap().name(), "medium skin tone");
+/// assert!(components::get('🚀').is_none());
+/// ```
+pub fn get(c: char) -> Option<&'static Component> {
+    COMPONENTS.iter().find(|component| component.ch == c)
+}
@@ -0,0 +1,34 @@
+//! The error type for fallible lookups.
+
+use core::fmt;
+
+/// An error returned when an emoji could not be resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input is well formed but is not an emoji.
+    NotAnEmoji,
+    /// The input is not a valid code point.
+    InvalidCodepoint,
+    /// The input is not a known GitHub shortcode.
+    UnknownShortcode,
+    /// The buffer is too small for the emoji.
+    BufferTooSmall,
+    /// The number is not the code of any value.
+    UnknownCode,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAnEmoji => f.write_str("not an emoji"),
+            Self::InvalidCodepoint => f.write_str("invalid code point"),
+            Self::UnknownShortcode => f.write_str("unknown shortcode"),
+            Self::BufferTooSmall => f.write_str("buffer too small"),
+            Self::UnknownCode => f.write_str("unknown code"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
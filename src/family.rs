@@ -0,0 +1,177 @@
+//! Compose RGI family emojis.
+
+use crate::components::ZWJ;
+use crate::Emoji;
+
+/// A member of a family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Member {
+    // The order of the variants is the order the members appear in an RGI
+    // family sequence.
+    Man,
+    Woman,
+    Adult,
+    Girl,
+    Boy,
+    Child,
+}
+
+impl Member {
+    const fn as_char(self) -> char {
+        match self {
+            Self::Man => '👨',
+            Self::Woman => '👩',
+            Self::Adult => '🧑',
+            Self::Girl => '👧',
+            Self::Boy => '👦',
+            Self::Child => '🧒',
+        }
+    }
+}
+
+/// Up to two family members of the same generation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct Members {
+    members: [Option<Member>; 2],
+    // Set if more than two members were added.
+    overflow: bool,
+}
+
+impl Members {
+    fn push(mut self, member: Member) -> Self {
+        match self.members.iter_mut().find(|m| m.is_none()) {
+            Some(slot) => *slot = Some(member),
+            None => self.overflow = true,
+        }
+        self
+    }
+
+    fn sorted(self) -> impl Iterator<Item = Member> {
+        let [a, b] = self.members;
+        let (a, b) = match (a, b) {
+            (Some(a), Some(b)) if b < a => (Some(b), Some(a)),
+            _ => (a, b),
+        };
+        a.into_iter().chain(b)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.members[0].is_none()
+    }
+}
+
+/// A builder for the RGI family emojis.
+///
+/// Add one or two adults and one or two children, then use
+/// [`build()`][Family::build] to lookup the emoji. Members may be added in any
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Family;
+///
+/// let family = Family::new().woman().man().boy().build().unwrap();
+/// assert_eq!(family, "👨‍👩‍👦");
+///
+/// let family = Family::new().adult().child().child().build().unwrap();
+/// assert_eq!(family, "🧑‍🧒‍🧒");
+/// ```
+///
+/// Combinations that are not standard return `None`.
+///
+/// ```
+/// use emojis::Family;
+///
+/// assert!(Family::new().man().child().build().is_none());
+/// assert!(Family::new().woman().build().is_none());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Family {
+    adults: Members,
+    children: Members,
+}
+
+impl Family {
+    /// Returns a new empty family.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a man to the family.
+    #[inline]
+    pub fn man(self) -> Self {
+        self.adult_member(Member::Man)
+    }
+
+    /// Adds a woman to the family.
+    #[inline]
+    pub fn woman(self) -> Self {
+        self.adult_member(Member::Woman)
+    }
+
+    /// Adds a gender-neutral adult to the family.
+    #[inline]
+    pub fn adult(self) -> Self {
+        self.adult_member(Member::Adult)
+    }
+
+    /// Adds a girl to the family.
+    #[inline]
+    pub fn girl(self) -> Self {
+        self.child_member(Member::Girl)
+    }
+
+    /// Adds a boy to the family.
+    #[inline]
+    pub fn boy(self) -> Self {
+        self.child_member(Member::Boy)
+    }
+
+    /// Adds a gender-neutral child to the family.
+    #[inline]
+    pub fn child(self) -> Self {
+        self.child_member(Member::Child)
+    }
+
+    fn adult_member(mut self, member: Member) -> Self {
+        self.adults = self.adults.push(member);
+        self
+    }
+
+    fn child_member(mut self, member: Member) -> Self {
+        self.children = self.children.push(member);
+        self
+    }
+
+    /// Builds the family emoji.
+    ///
+    /// Returns `None` if this combination of family members is not an RGI
+    /// family emoji.
+    pub fn build(&self) -> Option<&'static Emoji> {
+        if self.adults.overflow
+            || self.children.overflow
+            || self.adults.is_empty()
+            || self.children.is_empty()
+        {
+            return None;
+        }
+        // At most four members of four bytes each joined by three ZWJs of
+        // three bytes each.
+        let mut buf = [0; 25];
+        let mut len = 0;
+        for (i, member) in self
+            .adults
+            .sorted()
+            .chain(self.children.sorted())
+            .enumerate()
+        {
+            if i > 0 {
+                len += ZWJ.encode_utf8(&mut buf[len..]).len();
+            }
+            len += member.as_char().encode_utf8(&mut buf[len..]).len();
+        }
+        crate::get(core::str::from_utf8(&buf[..len]).ok()?)
+    }
+}
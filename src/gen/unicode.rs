@@ -1,6039 +0,0 @@
-// Code generated by `cargo run --package generate`. DO NOT EDIT.
-
-pub static MAP: ::phf::Map<&'static str, usize> = ::phf::Map {
-    key: 12913932095322966823,
-    disps: &[
-        (0, 38),
-        (0, 1),
-        (0, 0),
-        (0, 42),
-        (0, 67),
-        (0, 155),
-        (0, 522),
-        (0, 576),
-        (0, 37),
-        (0, 235),
-        (0, 5),
-        (0, 481),
-        (0, 265),
-        (0, 2),
-        (0, 103),
-        (0, 1),
-        (0, 0),
-        (0, 157),
-        (0, 6),
-        (0, 441),
-        (0, 2),
-        (0, 154),
-        (0, 359),
-        (0, 0),
-        (0, 312),
-        (0, 5),
-        (0, 1564),
-        (0, 0),
-        (0, 4),
-        (0, 42),
-        (0, 767),
-        (0, 335),
-        (0, 340),
-        (0, 17),
-        (0, 3),
-        (0, 1),
-        (0, 419),
-        (0, 1467),
-        (0, 364),
-        (0, 6),
-        (0, 7),
-        (0, 6),
-        (0, 70),
-        (0, 60),
-        (0, 0),
-        (0, 2197),
-        (0, 539),
-        (0, 940),
-        (0, 304),
-        (0, 118),
-        (0, 657),
-        (0, 3),
-        (0, 227),
-        (0, 1),
-        (0, 523),
-        (0, 230),
-        (0, 27),
-        (0, 801),
-        (0, 0),
-        (0, 29),
-        (0, 79),
-        (0, 0),
-        (0, 0),
-        (0, 51),
-        (0, 580),
-        (0, 3),
-        (0, 195),
-        (0, 303),
-        (0, 1648),
-        (0, 8),
-        (0, 115),
-        (0, 1569),
-        (0, 4213),
-        (0, 986),
-        (0, 1374),
-        (0, 0),
-        (0, 49),
-        (0, 10),
-        (0, 0),
-        (0, 498),
-        (0, 241),
-        (0, 230),
-        (0, 128),
-        (0, 0),
-        (0, 992),
-        (0, 3236),
-        (0, 13),
-        (0, 53),
-        (0, 362),
-        (0, 4),
-        (0, 1),
-        (0, 25),
-        (0, 8),
-        (0, 0),
-        (0, 444),
-        (0, 136),
-        (0, 622),
-        (1, 573),
-        (0, 0),
-        (0, 7),
-        (0, 248),
-        (0, 9),
-        (0, 360),
-        (0, 1),
-        (0, 25),
-        (0, 18),
-        (0, 171),
-        (0, 274),
-        (0, 1075),
-        (0, 131),
-        (0, 83),
-        (0, 200),
-        (0, 2211),
-        (0, 239),
-        (0, 14),
-        (0, 0),
-        (0, 266),
-        (0, 1),
-        (0, 105),
-        (0, 374),
-        (0, 711),
-        (0, 4),
-        (0, 8),
-        (0, 81),
-        (0, 2),
-        (0, 17),
-        (0, 17),
-        (0, 73),
-        (0, 0),
-        (0, 1018),
-        (0, 1105),
-        (0, 63),
-        (0, 173),
-        (0, 4),
-        (0, 468),
-        (0, 6),
-        (0, 1181),
-        (0, 1),
-        (0, 1732),
-        (0, 704),
-        (0, 4732),
-        (0, 0),
-        (0, 387),
-        (0, 53),
-        (0, 0),
-        (0, 5),
-        (0, 66),
-        (0, 61),
-        (0, 33),
-        (0, 376),
-        (0, 24),
-        (0, 21),
-        (0, 588),
-        (0, 0),
-        (0, 0),
-        (0, 650),
-        (0, 30),
-        (0, 194),
-        (0, 103),
-        (0, 101),
-        (0, 36),
-        (0, 117),
-        (0, 338),
-        (0, 1089),
-        (0, 67),
-        (0, 466),
-        (0, 0),
-        (0, 153),
-        (0, 105),
-        (0, 595),
-        (0, 340),
-        (0, 0),
-        (0, 329),
-        (0, 1),
-        (0, 0),
-        (0, 627),
-        (0, 181),
-        (0, 23),
-        (0, 149),
-        (0, 8),
-        (0, 64),
-        (0, 27),
-        (0, 14),
-        (0, 0),
-        (0, 493),
-        (0, 0),
-        (0, 1),
-        (0, 4),
-        (0, 1),
-        (0, 12),
-        (0, 1),
-        (0, 574),
-        (0, 226),
-        (0, 50),
-        (0, 106),
-        (0, 131),
-        (0, 56),
-        (0, 1375),
-        (0, 53),
-        (0, 5),
-        (0, 13),
-        (0, 1),
-        (0, 1515),
-        (0, 12),
-        (0, 1574),
-        (0, 5),
-        (0, 136),
-        (0, 88),
-        (0, 1),
-        (0, 33),
-        (0, 408),
-        (0, 2),
-        (0, 110),
-        (0, 1112),
-        (0, 151),
-        (0, 1536),
-        (0, 3),
-        (0, 0),
-        (0, 369),
-        (0, 75),
-        (0, 1),
-        (0, 3),
-        (0, 977),
-        (0, 8),
-        (0, 20),
-        (0, 453),
-        (0, 3),
-        (0, 1),
-        (0, 1),
-        (0, 1584),
-        (0, 0),
-        (0, 79),
-        (0, 0),
-        (0, 136),
-        (0, 1017),
-        (0, 0),
-        (0, 446),
-        (0, 98),
-        (0, 4),
-        (0, 90),
-        (0, 1309),
-        (1, 2984),
-        (0, 678),
-        (0, 1),
-        (0, 3),
-        (0, 398),
-        (0, 18),
-        (0, 865),
-        (0, 1357),
-        (0, 57),
-        (0, 5),
-        (0, 3),
-        (0, 1864),
-        (0, 73),
-        (0, 55),
-        (0, 92),
-        (0, 11),
-        (0, 68),
-        (0, 542),
-        (0, 2),
-        (0, 0),
-        (0, 189),
-        (0, 0),
-        (0, 29),
-        (0, 3),
-        (0, 4),
-        (0, 626),
-        (0, 1946),
-        (0, 1315),
-        (0, 465),
-        (0, 1887),
-        (0, 1),
-        (0, 11),
-        (0, 7),
-        (0, 2),
-        (0, 2540),
-        (0, 0),
-        (0, 92),
-        (0, 0),
-        (0, 740),
-        (0, 232),
-        (0, 285),
-        (0, 11),
-        (0, 148),
-        (0, 2),
-        (0, 1),
-        (0, 0),
-        (0, 167),
-        (0, 204),
-        (0, 792),
-        (0, 870),
-        (0, 25),
-        (0, 1),
-        (0, 56),
-        (0, 10),
-        (0, 13),
-        (0, 411),
-        (0, 1),
-        (0, 5),
-        (0, 13),
-        (0, 313),
-        (0, 0),
-        (0, 6),
-        (0, 0),
-        (0, 6),
-        (0, 2),
-        (0, 50),
-        (0, 230),
-        (0, 222),
-        (0, 68),
-        (0, 14),
-        (0, 13),
-        (0, 9),
-        (0, 2),
-        (0, 63),
-        (0, 106),
-        (0, 31),
-        (0, 2),
-        (0, 243),
-        (0, 49),
-        (0, 509),
-        (0, 70),
-        (0, 115),
-        (0, 8),
-        (0, 1460),
-        (0, 564),
-        (0, 838),
-        (0, 1266),
-        (0, 41),
-        (0, 0),
-        (0, 6),
-        (0, 20),
-        (0, 275),
-        (0, 2),
-        (0, 8),
-        (0, 199),
-        (0, 307),
-        (0, 10),
-        (0, 21),
-        (0, 0),
-        (0, 646),
-        (0, 579),
-        (0, 8),
-        (0, 22),
-        (0, 0),
-        (0, 675),
-        (0, 8),
-        (0, 1298),
-        (0, 10),
-        (0, 421),
-        (0, 55),
-        (0, 18),
-        (0, 6),
-        (0, 961),
-        (0, 1191),
-        (0, 86),
-        (0, 2061),
-        (0, 61),
-        (0, 653),
-        (0, 3),
-        (0, 118),
-        (0, 336),
-        (0, 0),
-        (0, 40),
-        (0, 161),
-        (0, 1),
-        (0, 11),
-        (0, 67),
-        (0, 29),
-        (0, 648),
-        (0, 196),
-        (0, 2),
-        (0, 1507),
-        (0, 759),
-        (0, 1),
-        (0, 863),
-        (0, 441),
-        (0, 3),
-        (0, 217),
-        (0, 98),
-        (0, 2493),
-        (0, 276),
-        (0, 61),
-        (0, 8),
-        (0, 192),
-        (0, 51),
-        (0, 1),
-        (0, 116),
-        (0, 39),
-        (0, 17),
-        (0, 2608),
-        (0, 30),
-        (0, 1),
-        (0, 34),
-        (0, 337),
-        (0, 745),
-        (0, 4784),
-        (0, 0),
-        (0, 88),
-        (0, 6),
-        (0, 357),
-        (0, 0),
-        (0, 0),
-        (0, 23),
-        (0, 637),
-        (0, 1),
-        (0, 134),
-        (0, 2466),
-        (0, 4060),
-        (0, 73),
-        (0, 3069),
-        (0, 138),
-        (0, 1614),
-        (0, 65),
-        (0, 12),
-        (0, 1),
-        (0, 1560),
-        (0, 87),
-        (0, 65),
-        (0, 1136),
-        (0, 0),
-        (0, 2528),
-        (0, 34),
-        (2, 4673),
-        (0, 252),
-        (1, 833),
-        (0, 5),
-        (0, 0),
-        (0, 247),
-        (0, 25),
-        (0, 0),
-        (0, 634),
-        (0, 651),
-        (0, 1),
-        (0, 8),
-        (0, 23),
-        (0, 12),
-        (0, 159),
-        (0, 6),
-        (0, 231),
-        (0, 27),
-        (0, 15),
-        (0, 238),
-        (0, 677),
-        (0, 1311),
-        (0, 15),
-        (0, 24),
-        (0, 7),
-        (1, 4313),
-        (0, 334),
-        (0, 31),
-        (0, 1105),
-        (0, 153),
-        (0, 5),
-        (0, 23),
-        (0, 52),
-        (0, 13),
-        (0, 4519),
-        (0, 37),
-        (0, 132),
-        (0, 2),
-        (0, 28),
-        (0, 16),
-        (0, 53),
-        (0, 52),
-        (0, 1),
-        (0, 1392),
-        (0, 4),
-        (1, 54),
-        (0, 948),
-        (0, 139),
-        (0, 14),
-        (0, 167),
-        (0, 5),
-        (0, 160),
-        (0, 1103),
-        (0, 151),
-        (0, 7),
-        (0, 222),
-        (0, 128),
-        (0, 11),
-        (0, 21),
-        (0, 189),
-        (0, 54),
-        (0, 2),
-        (0, 3811),
-        (0, 0),
-        (0, 1339),
-        (0, 1142),
-        (0, 22),
-        (0, 99),
-        (0, 134),
-        (0, 16),
-        (0, 83),
-        (0, 4),
-        (0, 2749),
-        (0, 172),
-        (0, 16),
-        (0, 1010),
-        (0, 2950),
-        (0, 36),
-        (0, 2),
-        (0, 228),
-        (0, 286),
-        (0, 1162),
-        (0, 2),
-        (0, 457),
-        (0, 4),
-        (0, 64),
-        (0, 141),
-        (0, 216),
-        (0, 1),
-        (0, 21),
-        (0, 321),
-        (0, 1746),
-        (1, 4747),
-        (0, 53),
-        (0, 1136),
-        (0, 61),
-        (0, 1),
-        (0, 22),
-        (0, 8),
-        (0, 43),
-        (0, 40),
-        (0, 0),
-        (1, 1),
-        (0, 3244),
-        (0, 1389),
-        (0, 63),
-        (0, 2692),
-        (0, 125),
-        (0, 55),
-        (0, 1289),
-        (0, 90),
-        (0, 17),
-        (0, 194),
-        (1, 914),
-        (0, 179),
-        (0, 987),
-        (0, 8),
-        (0, 157),
-        (0, 11),
-        (0, 65),
-        (0, 0),
-        (0, 0),
-        (0, 1356),
-        (0, 124),
-        (0, 4),
-        (0, 858),
-        (0, 210),
-        (0, 11),
-        (0, 224),
-        (0, 41),
-        (1, 2317),
-        (0, 0),
-        (0, 333),
-        (0, 8),
-        (0, 3087),
-        (0, 22),
-        (0, 493),
-        (0, 154),
-        (0, 7),
-        (0, 129),
-        (0, 372),
-        (0, 14),
-        (0, 77),
-        (0, 138),
-        (1, 3769),
-        (0, 50),
-        (0, 40),
-        (0, 182),
-        (0, 2636),
-        (0, 51),
-        (0, 27),
-        (0, 1),
-        (0, 2121),
-        (0, 0),
-        (0, 3876),
-        (0, 9),
-        (0, 11),
-        (0, 4),
-        (0, 53),
-        (0, 2326),
-        (0, 1178),
-        (0, 3656),
-        (0, 328),
-        (0, 6),
-        (0, 55),
-        (1, 768),
-        (0, 27),
-        (0, 661),
-        (0, 430),
-        (0, 524),
-        (0, 175),
-        (0, 31),
-        (0, 3),
-        (0, 82),
-        (0, 364),
-        (0, 53),
-        (0, 2),
-        (0, 507),
-        (0, 790),
-        (0, 1017),
-        (0, 939),
-        (0, 3004),
-        (0, 23),
-        (0, 40),
-        (0, 3358),
-        (0, 355),
-        (0, 1),
-        (0, 3),
-        (0, 0),
-        (1, 12),
-        (0, 2448),
-        (0, 843),
-        (0, 1617),
-        (0, 140),
-        (0, 2243),
-        (0, 75),
-        (0, 836),
-        (0, 416),
-        (0, 300),
-        (0, 677),
-        (0, 3766),
-        (0, 2),
-        (0, 0),
-        (0, 143),
-        (0, 74),
-        (0, 151),
-        (0, 1249),
-        (0, 1493),
-        (0, 8),
-        (0, 244),
-        (0, 0),
-        (0, 1171),
-        (0, 134),
-        (1, 126),
-        (0, 11),
-        (0, 1620),
-        (0, 196),
-        (0, 9),
-        (0, 12),
-        (0, 2),
-        (0, 7),
-        (0, 3),
-        (0, 0),
-        (0, 0),
-        (0, 3470),
-        (0, 110),
-        (0, 468),
-        (0, 1979),
-        (0, 12),
-        (0, 3781),
-        (0, 3),
-        (0, 976),
-        (0, 6),
-        (0, 2),
-        (0, 25),
-        (0, 7),
-        (0, 601),
-        (0, 7),
-        (0, 102),
-        (0, 2100),
-        (0, 91),
-        (0, 175),
-        (0, 1179),
-        (0, 828),
-        (0, 31),
-        (0, 1),
-        (0, 6),
-        (0, 0),
-        (0, 122),
-        (0, 1462),
-        (0, 139),
-        (0, 6),
-        (0, 1322),
-        (0, 2102),
-        (0, 204),
-        (0, 2070),
-        (0, 5),
-        (0, 31),
-        (0, 14),
-        (0, 145),
-        (0, 15),
-        (0, 348),
-        (0, 166),
-        (0, 16),
-        (0, 1473),
-        (1, 2539),
-        (0, 476),
-        (0, 18),
-        (0, 23),
-        (0, 218),
-        (0, 15),
-        (0, 0),
-        (1, 311),
-        (0, 2521),
-        (0, 10),
-        (0, 290),
-        (0, 2606),
-        (0, 783),
-        (0, 2634),
-        (0, 2444),
-        (0, 258),
-        (2, 3748),
-        (0, 288),
-        (0, 2715),
-        (0, 308),
-        (1, 2273),
-        (0, 1944),
-        (0, 106),
-        (0, 28),
-        (0, 101),
-        (0, 130),
-        (0, 2),
-        (0, 44),
-        (0, 20),
-        (0, 2),
-        (0, 945),
-        (0, 518),
-        (0, 184),
-        (0, 9),
-        (0, 7),
-        (0, 161),
-        (0, 859),
-        (0, 2031),
-        (0, 0),
-        (0, 34),
-        (0, 49),
-        (0, 2373),
-        (0, 433),
-        (0, 502),
-        (2, 4314),
-        (0, 1006),
-        (0, 0),
-        (0, 662),
-        (0, 1531),
-        (0, 75),
-        (0, 0),
-        (0, 85),
-        (0, 1),
-        (0, 47),
-        (0, 83),
-        (0, 367),
-        (0, 105),
-        (0, 2),
-        (0, 2401),
-        (0, 621),
-        (0, 249),
-        (0, 8),
-        (0, 1131),
-        (0, 40),
-        (0, 0),
-        (0, 0),
-        (0, 1208),
-        (0, 253),
-        (0, 76),
-        (0, 78),
-        (0, 241),
-        (0, 66),
-        (0, 5),
-        (0, 1154),
-        (0, 18),
-        (0, 1211),
-        (0, 3),
-        (2, 1688),
-        (1, 2020),
-        (0, 0),
-        (0, 46),
-        (0, 758),
-        (0, 405),
-        (0, 527),
-        (0, 58),
-        (0, 1414),
-        (0, 1319),
-        (0, 120),
-        (0, 111),
-        (0, 2218),
-        (0, 32),
-        (0, 10),
-        (0, 0),
-        (0, 253),
-        (0, 1410),
-        (0, 52),
-        (0, 148),
-        (0, 0),
-        (0, 2),
-        (2, 2805),
-        (0, 239),
-        (1, 2559),
-        (0, 0),
-        (0, 11),
-        (0, 23),
-        (1, 213),
-        (0, 0),
-        (0, 4955),
-        (0, 1),
-        (0, 43),
-        (0, 5),
-        (1, 3810),
-        (0, 346),
-        (0, 3331),
-        (0, 3021),
-        (0, 3065),
-        (1, 2448),
-        (1, 2543),
-        (0, 322),
-        (0, 30),
-        (0, 10),
-        (0, 201),
-        (0, 153),
-        (0, 6),
-        (0, 1388),
-        (0, 8),
-        (0, 47),
-        (0, 1),
-        (2, 4260),
-        (0, 86),
-        (0, 28),
-        (0, 970),
-        (0, 516),
-        (0, 7),
-        (0, 4),
-        (0, 4),
-        (4, 3637),
-        (0, 4860),
-        (0, 56),
-        (0, 1),
-        (0, 642),
-        (1, 4482),
-        (0, 802),
-        (0, 5),
-        (0, 1242),
-        (0, 26),
-        (0, 1158),
-        (0, 928),
-        (0, 6),
-        (0, 23),
-        (0, 3565),
-        (0, 218),
-        (0, 4723),
-        (0, 2402),
-        (0, 414),
-        (0, 1482),
-        (0, 15),
-        (0, 9),
-        (0, 44),
-        (0, 2020),
-        (0, 2188),
-        (0, 0),
-        (0, 40),
-        (6, 2180),
-        (0, 807),
-        (0, 31),
-        (0, 59),
-        (0, 4070),
-        (0, 0),
-        (0, 0),
-        (0, 13),
-        (2, 1226),
-        (0, 405),
-        (0, 632),
-        (0, 35),
-        (0, 22),
-        (0, 10),
-        (2, 2304),
-        (0, 0),
-        (0, 3332),
-        (0, 9),
-        (0, 2),
-        (0, 5),
-        (0, 356),
-        (0, 0),
-        (0, 98),
-        (0, 79),
-        (0, 94),
-        (0, 123),
-        (0, 1614),
-        (0, 426),
-        (0, 56),
-        (0, 447),
-        (1, 1096),
-        (0, 0),
-        (0, 41),
-        (0, 2936),
-        (0, 1316),
-        (0, 522),
-        (0, 459),
-        (0, 63),
-        (0, 2737),
-        (0, 108),
-        (1, 465),
-        (0, 3),
-        (0, 6),
-        (0, 150),
-        (5, 4061),
-        (0, 2),
-        (3, 3920),
-        (0, 1008),
-        (0, 2),
-        (0, 151),
-        (0, 1605),
-        (0, 1564),
-        (0, 56),
-        (0, 7),
-        (0, 6),
-        (0, 1),
-        (0, 1140),
-        (0, 365),
-        (0, 3841),
-        (4, 3820),
-        (0, 3504),
-        (0, 1001),
-        (0, 1077),
-        (2, 3539),
-        (1, 4479),
-        (0, 823),
-        (0, 390),
-        (0, 2),
-        (0, 0),
-        (0, 119),
-        (0, 820),
-        (0, 26),
-        (0, 1057),
-        (2, 2294),
-        (0, 3802),
-        (0, 4512),
-        (0, 66),
-        (0, 7),
-        (0, 9),
-        (0, 165),
-        (0, 1492),
-        (1, 2142),
-        (0, 0),
-        (0, 0),
-        (0, 62),
-        (0, 117),
-        (3, 3584),
-        (0, 2043),
-        (0, 1156),
-        (0, 284),
-        (0, 1412),
-        (0, 8),
-        (0, 1356),
-        (0, 88),
-        (1, 4225),
-        (0, 1013),
-        (0, 83),
-        (0, 10),
-        (4, 1738),
-        (1, 37),
-        (0, 225),
-        (0, 6),
-        (0, 533),
-        (1, 1737),
-        (0, 180),
-        (0, 11),
-        (0, 22),
-        (0, 1),
-        (0, 2),
-        (1, 184),
-        (0, 3699),
-        (2, 2137),
-        (1, 777),
-        (5, 4520),
-        (0, 18),
-        (5, 3010),
-        (0, 3015),
-        (0, 869),
-        (0, 2),
-        (0, 6),
-        (1, 101),
-        (8, 382),
-        (0, 1112),
-        (0, 0),
-        (0, 2632),
-        (0, 3224),
-        (0, 1569),
-        (3, 350),
-        (0, 139),
-        (0, 97),
-        (0, 287),
-        (0, 0),
-        (0, 4318),
-        (5, 674),
-        (0, 2128),
-        (4, 3932),
-        (0, 0),
-        (0, 1447),
-        (0, 3084),
-        (0, 0),
-        (0, 4796),
-        (1, 4104),
-        (12, 1702),
-        (0, 1568),
-        (0, 89),
-        (2, 628),
-        (0, 1),
-        (0, 1725),
-        (0, 0),
-        (1, 1142),
-        (0, 2107),
-        (0, 4),
-        (0, 8),
-        (0, 194),
-    ],
-    entries: &[
-        ("👰🏿\u{200d}♀", 1291),
-        ("🏋🏻\u{200d}♂", 1929),
-        ("🥭", 2589),
-        ("🇦🇷", 3522),
-        ("🧘🏻\u{200d}♀\u{fe0f}", 2064),
-        ("🏌🏽\u{200d}♂\u{fe0f}", 1841),
-        ("🍟", 2633),
-        ("🧑🏼\u{200d}🎄", 1354),
-        ("7\u{fe0f}\u{20e3}", 3427),
-        ("🤲🏾", 394),
-        ("🚶🏻\u{200d}♂", 1534),
-        ("🏝", 2730),
-        ("⏮", 3363),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", 2282),
-        ("🤙🏻", 283),
-        ("🇬🇦", 3592),
-        ("💇🏾", 1513),
-        ("🕵\u{fe0f}\u{200d}♀\u{fe0f}", 1160),
-        ("🧚🏻\u{200d}♂", 1419),
-        ("⛵", 2834),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏼", 2273),
-        ("🥷🏾", 1188),
-        ("🧟", 1487),
-        ("🤹🏼\u{200d}♀", 2047),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", 2287),
-        ("🧦", 3030),
-        ("💆🏽\u{200d}♀\u{fe0f}", 1506),
-        ("🤸🏾\u{200d}♂\u{fe0f}", 1986),
-        ("🇺🇿", 3754),
-        ("🧙🏻\u{200d}♀\u{fe0f}", 1407),
-        ("🚶🏾\u{200d}♂\u{fe0f}\u{200d}➡", 1561),
-        ("🍍", 2588),
-        ("🧑🏾\u{200d}🤝\u{200d}🧑🏼", 2100),
-        ("🤸🏾\u{200d}♂", 1986),
-        ("ℹ\u{fe0f}", 3442),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2258),
-        ("👰🏼\u{200d}♀\u{fe0f}", 1288),
-        ("🤷🏽\u{200d}♂\u{fe0f}", 833),
-        ("💁\u{200d}♀\u{fe0f}", 746),
-        ("🐻\u{200d}❄\u{fe0f}", 2485),
-        ("🤼\u{200d}♂", 1995),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2226),
-        ("👩\u{200d}🦱", 596),
-        ("💇\u{200d}♂\u{fe0f}", 1515),
-        ("👩\u{200d}🔧", 962),
-        ("🕹", 2996),
-        ("🛩", 2842),
-        ("👰🏽\u{200d}♂\u{fe0f}", 1283),
-        ("🚏", 2821),
-        ("🥲", 22),
-        ("🥁", 3091),
-        ("👨🏿\u{200d}🌾", 925),
-        ("👶🏽", 497),
-        ("🏃\u{200d}♀\u{200d}➡", 1749),
-        ("🧑🏾\u{200d}🦯\u{200d}➡", 1627),
-        ("👳🏻\u{200d}♀\u{fe0f}", 1239),
-        ("🧜🏾\u{200d}♂", 1458),
-        ("🚴🏽\u{200d}♀", 1955),
-        ("🇨🇴", 3561),
-        ("🤳", 440),
-        ("👁\u{fe0f}\u{200d}🗨\u{fe0f}", 163),
-        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏼", 2308),
-        ("👩🏾\u{200d}⚖\u{fe0f}", 912),
-        ("🔛", 3325),
-        ("🧎🏻\u{200d}➡", 1600),
-        ("☄", 2930),
-        ("🐊", 2517),
-        ("🤏", 246),
-        ("🐫", 2467),
-        ("⚠", 3294),
-        ("🤛🏿", 359),
-        ("🧑🏻\u{200d}🏭", 969),
-        ("💁🏾\u{200d}♀\u{fe0f}", 750),
-        ("🇨🇫", 3553),
-        ("👱🏻\u{200d}♂\u{fe0f}", 639),
-        ("👩🏾\u{200d}🦯\u{200d}➡", 1651),
-        ("🛡\u{fe0f}", 3217),
-        ("👡", 3051),
-        ("🖊", 3174),
-        ("🙇🏿\u{200d}♂", 799),
-        ("🇸🇳", 3722),
-        ("💪🏾", 450),
-        ("🧔🏾\u{200d}♂\u{fe0f}", 546),
-        ("🫶🏿", 383),
-        ("🕳", 161),
-        ("🧍\u{200d}♂", 1569),
-        ("🦖", 2524),
-        ("💸", 3154),
-        ("🤹🏽", 2036),
-        ("🙎🏼\u{200d}♂", 688),
-        ("🚴🏿\u{200d}♀\u{fe0f}", 1957),
-        ("👏🏻", 367),
-        ("👩🏼\u{200d}🎤", 1054),
-        ("👩🏽\u{200d}🦳", 611),
-        ("🌮", 2637),
-        ("🕦", 2886),
-        ("👩🏾\u{200d}❤\u{200d}👩🏻", 2385),
-        ("🫧", 3267),
-        ("✍", 428),
-        ("👨🏿\u{200d}✈", 1087),
-        ("🧎\u{200d}♀\u{200d}➡\u{fe0f}", 1605),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏽", 2283),
-        ("🪱", 2551),
-        ("🏋🏾\u{200d}♂", 1932),
-        ("😆", 4),
-        ("🙆🏾\u{200d}♂\u{fe0f}", 726),
-        ("🤾🏾\u{200d}♀", 2031),
-        ("🙇🏾\u{200d}♀", 804),
-        ("🇬🇶", 3604),
-        ("👇🏼", 314),
-        ("👩🏿\u{200d}✈\u{fe0f}", 1093),
-        ("👳🏽\u{200d}♂", 1235),
-        ("🎿", 2985),
-        ("🇩🇲", 3574),
-        ("🚵🏾\u{200d}♂\u{fe0f}", 1968),
-        ("🤺", 1818),
-        ("🧜🏻\u{200d}♀", 1461),
-        ("♥", 3005),
-        ("🧕🏼", 1252),
-        ("🥂", 2701),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", 2265),
-        ("🏃🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1758),
-        ("♋", 3344),
-        ("🩵", 148),
-        ("👨🏿\u{200d}🔬", 1015),
-        ("🇻🇮", 3759),
-        ("👳", 1226),
-        ("👩🏽\u{200d}🌾", 929),
-        ("👁\u{fe0f}\u{200d}🗨", 163),
-        ("📙", 3136),
-        ("🧜🏻", 1449),
-        ("🦾", 452),
-        ("👷🏾\u{200d}♀\u{fe0f}", 1206),
-        ("🏜\u{fe0f}", 2729),
-        ("🚨", 2827),
-        ("🦹\u{200d}♀", 1388),
-        ("🇬🇵", 3603),
-        ("🇪🇺", 3585),
-        ("🪄", 2994),
-        ("😀", 0),
-        ("🚘", 2805),
-        ("⚗\u{fe0f}", 3233),
-        ("🏺", 2715),
-        ("🦻", 472),
-        ("🆙", 3451),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2340),
-        ("🤚🏾", 178),
-        ("⬆\u{fe0f}", 3307),
-        ("❎", 3408),
-        ("🧎🏿\u{200d}♀\u{200d}➡\u{fe0f}", 1610),
-        ("🏃\u{200d}♂", 1731),
-        ("👩🏾\u{200d}🦲", 624),
-        ("🛩\u{fe0f}", 2842),
-        ("🚶🏽\u{200d}♀\u{fe0f}\u{200d}➡", 1554),
-        ("🚮", 3282),
-        ("💆🏿\u{200d}♂\u{fe0f}", 1502),
-        ("🧖🏼\u{200d}♂", 1790),
-        ("👨🏽\u{200d}🌾", 923),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2361),
-        ("🧎\u{200d}♂", 1587),
-        ("🪆", 3003),
-        ("🏇🏽", 1822),
-        ("🙍🏿\u{200d}♂\u{fe0f}", 673),
-        ("🚢", 2840),
-        ("👎🏾", 340),
-        ("🏃🏿\u{200d}♂", 1736),
-        ("🧔\u{200d}♀", 548),
-        ("🏃\u{200d}♀", 1737),
-        ("🇾🇹", 3766),
-        ("👩🏼\u{200d}⚕", 856),
-        ("👼", 1334),
-        ("🤘🏽", 279),
-        ("🇨🇬", 3554),
-        ("🖐🏿", 185),
-        ("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", 2211),
-        ("🧎🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1612),
-        ("⛩\u{fe0f}", 2763),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2346),
-        ("🚵🏼\u{200d}♀\u{fe0f}", 1972),
-        ("🏌🏻\u{200d}♀\u{fe0f}", 1845),
-        ("🧟\u{200d}♂", 1488),
-        ("👩🏼\u{200d}🔧", 964),
-        ("🏃🏽\u{200d}♂\u{fe0f}\u{200d}➡", 1758),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏾", 2275),
-        ("⛑\u{fe0f}", 3061),
-        ("👰\u{200d}♂\u{fe0f}", 1280),
-        ("💁🏾", 738),
-        ("🇬🇫", 3596),
-        ("🇨🇮", 3556),
-        ("🧑🏼\u{200d}🦼\u{200d}➡\u{fe0f}", 1661),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2236),
-        ("🧑🏽\u{200d}❤\u{200d}🧑🏿", 2306),
-        ("💁🏾\u{200d}♂", 744),
-        ("♏", 3348),
-        ("🏋", 1922),
-        ("🧎\u{200d}♂\u{200d}➡", 1611),
-        ("💂🏽\u{200d}♂\u{fe0f}", 1175),
-        ("🧩", 2999),
-        ("💅🏿", 439),
-        ("🏃🏿\u{200d}♂\u{fe0f}\u{200d}➡", 1760),
-        ("🤽🏿\u{200d}♀\u{fe0f}", 2014),
-        ("💇🏽\u{200d}♂\u{fe0f}", 1518),
-        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2243),
-        ("📬", 3167),
-        ("🫱🏽\u{200d}🫲🏾", 412),
-        ("🚶🏻\u{200d}♀\u{200d}➡", 1552),
-        ("🤹🏻", 2034),
-        ("👩🏻\u{200d}🤝\u{200d}👨🏾", 2141),
-        ("🛻", 2807),
-        ("🧗🏼", 1802),
-        ("👆🏽", 303),
-        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏼", 2200),
-        ("🫶🏼", 380),
-        ("🤹🏻\u{200d}♂", 2040),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2219),
-        ("🧑🏻\u{200d}🚀", 1095),
-        ("🩻", 3246),
-        ("👵🏿", 661),
-        ("🇯🇵", 3631),
-        ("🧑🏾\u{200d}🔬", 1008),
-        ("🧞\u{200d}♀", 1486),
-        ("🤾🏿\u{200d}♀", 2032),
-        ("🇧🇸", 3544),
-        ("👨\u{200d}❤\u{200d}👨", 2341),
-        ("👨🏽\u{200d}🦼", 1668),
-        ("🇹🇬", 3735),
-        ("🏌🏿\u{200d}♀", 1849),
-        ("🐦\u{200d}⬛", 2513),
-        ("👩\u{200d}✈\u{fe0f}", 1088),
-        ("🧑🏿\u{200d}🏭", 973),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2347),
-        ("👩🏾\u{200d}🌾", 930),
-        ("👋🏼", 170),
-        ("🪰", 2550),
-        ("🏌🏿\u{200d}♀\u{fe0f}", 1849),
-        ("👨\u{200d}❤\u{fe0f}\u{200d}👨", 2341),
-        ("🧑🏼\u{200d}🎨", 1060),
-        ("🧗🏿", 1805),
-        ("🇹🇱", 3739),
-        ("🤸🏽\u{200d}♀", 1991),
-        ("🚶🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1554),
-        ("🙋\u{200d}♂", 758),
-        ("🇱🇰", 3647),
-        ("🚵🏽\u{200d}♀", 1973),
-        ("🧛\u{200d}♂", 1436),
-        ("🧑🏾\u{200d}🎨", 1062),
-        ("❓", 3390),
-        ("🦹🏾\u{200d}♀", 1392),
-        ("👨🏿\u{200d}✈\u{fe0f}", 1087),
-        ("👴🏾", 654),
-        ("🧑🏾\u{200d}⚕\u{fe0f}", 846),
-        ("🤲🏼", 392),
-        ("🧑\u{200d}🦼", 1653),
-        ("🧜🏼\u{200d}♀", 1462),
-        ("🇦🇲", 3519),
-        ("🇹🇫", 3734),
-        ("🇩🇬", 3571),
-        ("🧎🏻\u{200d}♀\u{200d}➡\u{fe0f}", 1606),
-        ("🍏", 2591),
-        ("🏋\u{fe0f}\u{200d}♀", 1934),
-        ("😳", 83),
-        ("😢", 91),
-        ("🥖", 2621),
-        ("👨🏻\u{200d}🚀", 1101),
-        ("🧑🏾\u{200d}🤝\u{200d}🧑🏽", 2101),
-        ("🔼", 3364),
-        ("👩🏽\u{200d}❤\u{200d}👩🏻", 2381),
-        ("👨🏻\u{200d}⚕\u{fe0f}", 849),
-        ("👨🏼\u{200d}⚕", 850),
-        ("↩", 3317),
-        ("😼", 121),
-        ("📪", 3166),
-        ("👷🏽", 1193),
-        ("🚂", 2782),
-        ("⛹🏼\u{200d}♀", 1918),
-        ("🙋🏻", 753),
-        ("👩🏻\u{200d}🍼", 1317),
-        ("👨\u{200d}🍼", 1322),
-        ("🤷🏻\u{200d}♂\u{fe0f}", 831),
-        ("👩🏿\u{200d}🦱", 601),
-        ("🛌🏿", 2080),
-        ("🐂", 2456),
-        ("💑🏾", 2293),
-        ("🇦🇬", 3516),
-        ("🤦🏼\u{200d}♂\u{fe0f}", 814),
-        ("👩\u{200d}🦰", 584),
-        ("ℹ", 3442),
-        ("🤾🏻\u{200d}♂\u{fe0f}", 2022),
-        ("🇺🇬", 3749),
-        ("🤛", 354),
-        ("🤾🏿\u{200d}♂\u{fe0f}", 2026),
-        ("✳", 3412),
-        ("🤦🏿\u{200d}♀", 823),
-        ("🧙🏼\u{200d}♂", 1402),
-        ("🤽🏻\u{200d}♂", 2004),
-        ("👮🏽\u{200d}♀", 1145),
-        ("🧛🏻\u{200d}♀", 1443),
-        ("🦸\u{200d}♂", 1364),
-        ("👁\u{200d}🗨", 163),
-        ("🏄🏾\u{200d}♀", 1866),
-        ("🍷", 2696),
-        ("㊗\u{fe0f}", 3466),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2230),
-        ("🧑🏽\u{200d}🤝\u{200d}🧑🏿", 2098),
-        ("🧚🏻", 1413),
-        ("👨🏻\u{200d}🎨", 1065),
-        ("♊", 3343),
-        ("👩🏾\u{200d}🦯", 1645),
-        ("🚶🏾\u{200d}♂", 1537),
-        ("🥗", 2648),
-        ("♉", 3342),
-        ("👰🏽\u{200d}♀", 1289),
-        ("🍲", 2645),
-        ("👩🏼\u{200d}🦽\u{200d}➡\u{fe0f}", 1721),
-        ("🧍🏼\u{200d}♂\u{fe0f}", 1571),
-        ("👳🏽\u{200d}♀\u{fe0f}", 1241),
-        ("📭", 3168),
-        ("👩\u{200d}❤\u{200d}👨", 2315),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2233),
-        ("👷🏼\u{200d}♂", 1198),
-        ("👳🏼\u{200d}♀\u{fe0f}", 1240),
-        ("🚴🏾\u{200d}♂\u{fe0f}", 1950),
-        ("🦹🏾\u{200d}♂\u{fe0f}", 1386),
-        ("👩🏼\u{200d}🦱", 598),
-        ("🍄\u{200d}🟫", 2618),
-        ("🎟", 2953),
-        ("🏋🏽", 1925),
-        ("🧚🏽\u{200d}♂", 1421),
-        ("😌", 52),
-        ("💅🏻", 435),
-        ("🚶\u{200d}♀\u{fe0f}\u{200d}➡", 1551),
-        ("🎀", 2950),
-        ("🦸\u{200d}♀\u{fe0f}", 1370),
-        ("👨🏻\u{200d}🌾", 921),
-        ("👰🏼", 1276),
-        ("🖕🏽", 309),
-        ("👩🏾\u{200d}🎨", 1074),
-        ("💆🏼\u{200d}♀", 1505),
-        ("🚵🏽", 1961),
-        ("♓", 3352),
-        ("🖐🏼", 182),
-        ("🦸🏽\u{200d}♀", 1373),
-        ("☂", 2923),
-        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", 2191),
-        ("🚶🏽\u{200d}♀\u{fe0f}", 1542),
-        ("👨🏼\u{200d}✈\u{fe0f}", 1084),
-        ("🪯", 3340),
-        ("🧏🏼\u{200d}♂", 778),
-        ("🧘🏿\u{200d}♂\u{fe0f}", 2062),
-        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏿", 2298),
-        ("🇻🇦", 3755),
-        ("🇸🇭", 3716),
-        ("🧎\u{200d}♀\u{fe0f}", 1593),
-        ("🙆🏼", 718),
-        ("🕉\u{fe0f}", 3330),
-        ("🚬", 3272),
-        ("🛏\u{fe0f}", 3251),
-        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏾", 2210),
-        ("📦", 3164),
-        ("🧛🏼\u{200d}♂\u{fe0f}", 1438),
-        ("🧛🏻\u{200d}♂", 1437),
-        ("⛹\u{fe0f}\u{200d}♀\u{fe0f}", 1916),
-        ("🥣", 2647),
-        ("💩", 109),
-        ("🧖🏽\u{200d}♀", 1797),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏼", 2282),
-        ("🏊🏻", 1887),
-        ("🇬🇲", 3601),
-        ("🐳", 2525),
-        ("🧖🏿", 1787),
-        ("🏭", 2753),
-        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", 2198),
-        ("👨🏼\u{200d}🦼", 1667),
-        ("🤨", 37),
-        ("👨\u{200d}👨\u{200d}👧", 2399),
-        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏽", 2313),
-        ("👍🏿", 335),
-        ("🧑🏿\u{200d}⚕", 847),
-        ("👨🏾\u{200d}🚒", 1122),
-        ("🇦🇱", 3518),
-        ("🤟", 270),
-        ("🗒\u{fe0f}", 3184),
-        ("🧔🏻\u{200d}♀", 549),
-        ("🙍🏼\u{200d}♀", 676),
-        ("👩🏿\u{200d}🤝\u{200d}👩🏼", 2130),
-        ("👨🏿\u{200d}❤\u{200d}👨🏾", 2366),
-        ("🏴\u{200d}☠", 3511),
-        ("🏋🏿", 1927),
-        ("🛶", 2835),
-        ("🏄🏽\u{200d}♂", 1859),
-        ("👋🏿", 173),
-        ("🇨🇷", 3563),
-        ("👨\u{200d}👧\u{200d}👧", 2412),
-        ("👇🏿", 317),
-        ("🏋🏽\u{200d}♀", 1937),
-        ("😅", 5),
-        ("👨🏿\u{200d}⚖\u{fe0f}", 907),
-        ("1\u{20e3}", 3421),
-        ("👩🏾\u{200d}🍳", 948),
-        ("👩🏻\u{200d}✈", 1089),
-        ("🔰", 3402),
-        ("⛷\u{fe0f}", 1825),
-        ("❤\u{200d}🔥", 140),
-        ("🫑", 2607),
-        ("👁\u{fe0f}", 490),
-        ("↘", 3310),
-        ("🈹", 3459),
-        ("🇨🇨", 3551),
-        ("🚶🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1556),
-        ("👨🏾\u{200d}🦯\u{200d}➡", 1639),
-        ("🖐🏽", 183),
-        ("🫱🏼\u{200d}🫲🏾", 408),
-        ("🦊", 2438),
-        ("🇿🇲", 3768),
-        ("💈", 2780),
-        ("🐼", 2487),
-        ("🚵🏼\u{200d}♂\u{fe0f}", 1966),
-        ("🙎🏻\u{200d}♂", 687),
-        ("🫱", 198),
-        ("🐏", 2463),
-        ("🏊🏽\u{200d}♀\u{fe0f}", 1901),
-        ("🚆", 2786),
-        ("🙇", 788),
-        ("🪡", 3016),
-        ("👩🏼\u{200d}🎨", 1072),
-        ("🇮🇨", 3617),
-        ("🏃🏼\u{200d}♂", 1733),
-        ("🏃🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1756),
-        ("👨🏾\u{200d}🦼\u{200d}➡", 1675),
-        ("👨🏻\u{200d}🤝\u{200d}👨🏿", 2168),
-        ("🚡", 2850),
-        ("🤦🏿\u{200d}♂\u{fe0f}", 817),
-        ("🇰🇵", 3638),
-        ("👨🏼\u{200d}❤\u{200d}👨🏾", 2353),
-        ("🎤", 3081),
-        ("📹", 3122),
-        ("💆🏿\u{200d}♀\u{fe0f}", 1508),
-        ("👨🏻\u{200d}🦲", 573),
-        ("🕣", 2880),
-        ("🧳", 2855),
-        ("🙂\u{200d}↕\u{fe0f}", 51),
-        ("⛹\u{200d}♀", 1916),
-        ("⚔", 3213),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2222),
-        ("♟", 3008),
-        ("👨🏼\u{200d}❤\u{200d}👨🏻", 2351),
-        ("🏃🏼\u{200d}♂\u{200d}➡", 1757),
-        ("🚶🏽", 1530),
-        ("🇱🇧", 3644),
-        ("◀", 3361),
-        ("👩\u{200d}🎓", 872),
-        ("❤\u{fe0f}", 142),
-        ("👩🏻\u{200d}🦼\u{200d}➡\u{fe0f}", 1684),
-        ("👨\u{200d}❤\u{200d}💋\u{200d}👨", 2237),
-        ("🏃🏽\u{200d}♂\u{fe0f}", 1734),
-        ("🇧🇿", 3549),
-        ("👨🏻\u{200d}🦽\u{200d}➡\u{fe0f}", 1708),
-        ("👨🏿\u{200d}🍳", 943),
-        ("🦈", 2532),
-        ("🏃🏻\u{200d}♂\u{200d}➡", 1756),
-        ("🏊🏿\u{200d}♂", 1897),
-        ("👋", 168),
-        ("🚴🏽\u{200d}♂", 1949),
-        ("🧑\u{200d}🔬", 1004),
-        ("🐄", 2458),
-        ("🔉", 3068),
-        ("🧑🏼\u{200d}🦳", 616),
-        ("🦸🏻\u{200d}♀\u{fe0f}", 1371),
-        ("🚴🏿\u{200d}♂", 1951),
-        ("👩🏻\u{200d}🦰", 585),
-        ("💁🏽\u{200d}♀", 749),
-        ("🤸🏻\u{200d}♂", 1983),
-        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", 2209),
-        ("🐺", 2437),
-        ("🤽\u{200d}♂", 2003),
-        ("🧸", 3000),
-        ("🏎", 2811),
-        ("🖇\u{fe0f}", 3194),
-        ("🧓🏾", 648),
-        ("🧍🏿\u{200d}♂\u{fe0f}", 1574),
-        ("🤽🏾\u{200d}♀", 2013),
-        ("🎎", 2945),
-        ("⛹🏽\u{200d}♀", 1919),
-        ("🎭", 3012),
-        ("🍒", 2594),
-        ("👲🏿", 1249),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2212),
-        ("🚒", 2799),
-        ("🐮", 2455),
-        ("🥨", 2623),
-        ("🕵🏻\u{200d}♂", 1155),
-        ("🧎🏾\u{200d}♂\u{200d}➡\u{fe0f}", 1615),
-        ("🙆🏻\u{200d}♀\u{fe0f}", 729),
-        ("🎷", 3084),
-        ("🧖\u{200d}♂\u{fe0f}", 1788),
-        ("👬", 2159),
-        ("🏌🏻\u{200d}♂\u{fe0f}", 1839),
-        ("🚣🏼\u{200d}♀", 1882),
-        ("♑", 3350),
-        ("🧎🏻\u{200d}♀\u{fe0f}\u{200d}➡", 1606),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2261),
-        ("🤱🏾", 1314),
-        ("🫲🏾", 208),
-        ("🏊\u{200d}♀\u{fe0f}", 1898),
-        ("⛹🏽\u{200d}♀\u{fe0f}", 1919),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2221),
-        ("🔜", 3326),
-        ("🧑🏽\u{200d}🍼", 1331),
-        ("👨🏿\u{200d}🦽\u{200d}➡", 1712),
-        ("🎺", 3088),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", 2285),
-        ("🫗", 2703),
-        ("🦸🏾\u{200d}♀\u{fe0f}", 1374),
-        ("👨🏻", 531),
-        ("🖋\u{fe0f}", 3173),
-        ("📎", 3193),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2334),
-        ("🏴\u{200d}☠\u{fe0f}", 3511),
-        ("🤵🏼", 1258),
-        ("🕵\u{fe0f}\u{200d}♂\u{fe0f}", 1154),
-        ("🙅🏿\u{200d}♀\u{fe0f}", 715),
-        ("👨🏿\u{200d}🦯\u{200d}➡", 1640),
-        ("👨🏻\u{200d}🦯", 1630),
-        ("🚞", 2792),
-        ("🕢", 2878),
-        ("🧪", 3234),
-        ("👱🏽\u{200d}♀", 635),
-        ("🙅🏿\u{200d}♀", 715),
-        ("🧈", 2650),
-        ("👨🏿\u{200d}⚕\u{fe0f}", 853),
-        ("👨🏿\u{200d}🦽", 1706),
-        ("🧙🏻\u{200d}♂", 1401),
-        ("🤵🏼\u{200d}♀", 1270),
-        ("👱🏻\u{200d}♀", 633),
-        ("💂\u{200d}♀", 1178),
-        ("👨\u{200d}👦\u{200d}👦", 2409),
-        ("🅱\u{fe0f}", 3438),
-        ("👩🏿\u{200d}🦯", 1646),
-        ("♻\u{fe0f}", 3398),
-        ("🚶🏻\u{200d}♂\u{200d}➡\u{fe0f}", 1558),
-        ("💜", 149),
-        ("👩🏿\u{200d}❤\u{200d}👩🏼", 2390),
-        ("👨🏽\u{200d}🏫", 887),
-        ("👷🏻\u{200d}♀", 1203),
-        ("🏊🏿\u{200d}♀\u{fe0f}", 1903),
-        ("🏋🏽\u{200d}♂\u{fe0f}", 1931),
-        ("👩🏿\u{200d}❤\u{200d}👨🏾", 2340),
-        ("😤", 101),
-        ("🕙", 2883),
-        ("🧍🏽\u{200d}♀", 1578),
-        ("🦹🏾\u{200d}♀\u{fe0f}", 1392),
-        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏽", 2300),
-        ("🤵", 1256),
-        ("🤝🏾", 400),
-        ("🐥", 2499),
-        ("🦹🏼", 1378),
-        ("🧑🏿\u{200d}🎓", 865),
-        ("🧑🏽\u{200d}🌾", 917),
-        ("👨🏽\u{200d}🦯", 1632),
-        ("🦸🏽\u{200d}♀\u{fe0f}", 1373),
-        ("🧑🏻\u{200d}❤\u{200d}🧑🏿", 2298),
-        ("🚑", 2798),
-        ("👂🏿", 471),
-        ("🫱🏻\u{200d}🫲🏾", 404),
-        ("⛓", 3228),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏽", 2378),
-        ("🔔", 3073),
-        ("🏋🏼\u{200d}♂", 1930),
-        ("🧑🏻\u{200d}🦼\u{200d}➡", 1660),
-        ("🚶🏿\u{200d}♀\u{fe0f}", 1544),
-        ("❣", 138),
-        ("👦🏻", 507),
-        ("🥛", 2690),
-        ("❇", 3414),
-        ("🧗\u{200d}♀\u{fe0f}", 1812),
-        ("⛹🏻\u{200d}♂\u{fe0f}", 1911),
-        ("👨🏻\u{200d}🦯\u{200d}➡", 1636),
-        ("👳🏿\u{200d}♂\u{fe0f}", 1237),
-        ("👨🏼", 532),
-        ("🤰🏾", 1296),
-        ("📞", 3098),
-        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2245),
-        ("🏌🏾\u{200d}♂\u{fe0f}", 1842),
-        ("🇬🇭", 3598),
-        ("🕰", 2862),
-        ("🤵🏼\u{200d}♂\u{fe0f}", 1264),
-        ("🤸🏿\u{200d}♀", 1993),
-        ("🗡", 3212),
-        ("🏋\u{fe0f}\u{200d}♂", 1928),
-        ("👩🏼\u{200d}❤\u{200d}👨🏽", 2326),
-        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏽", 2205),
-        ("🤽🏿", 2002),
-        ("🧑\u{200d}🦳", 614),
-        ("🇬🇩", 3594),
-        ("😠", 103),
-        ("🎅🏽", 1343),
-        ("🏄🏼\u{200d}♀\u{fe0f}", 1864),
-        ("👨🏾\u{200d}🎓", 870),
-        ("🦸🏼\u{200d}♀", 1372),
-        ("🕓", 2871),
-        ("🔫", 2991),
-        ("🇻🇪", 3757),
-        ("👩🏽\u{200d}❤\u{200d}👨🏻", 2329),
-        ("👰\u{200d}♀\u{fe0f}", 1286),
-        ("➿", 3410),
-        ("🐈", 2441),
-        ("🧒🏼", 502),
-        ("🇲🇦", 3654),
-        ("🥎", 2963),
-        ("🧏🏻", 771),
-        ("👧🏽", 515),
-        ("👴🏻", 651),
-        ("🚣🏼\u{200d}♀\u{fe0f}", 1882),
-        ("💘", 130),
-        ("👈🏽", 291),
-        ("🏟", 2732),
-        ("🐛", 2539),
-        ("⚱\u{fe0f}", 3275),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2242),
-        ("🤽🏿\u{200d}♀", 2014),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏻", 2277),
-        ("⏫", 3365),
-        ("🧖🏽\u{200d}♂", 1791),
-        ("⚛", 3329),
-        ("☃\u{fe0f}", 2928),
-        ("🧑🏽\u{200d}🦽\u{200d}➡\u{fe0f}", 1698),
-        ("🧒🏽", 503),
-        ("🧝🏽\u{200d}♀", 1481),
-        ("🚴🏻", 1941),
-        ("🧛\u{200d}♂\u{fe0f}", 1436),
-        ("📄", 3142),
-        ("👨🏽\u{200d}💼", 995),
-        ("🫡", 35),
-        ("🏃🏿\u{200d}➡\u{fe0f}", 1748),
-        ("🧍\u{200d}♀", 1575),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2325),
-        ("🫴", 216),
-        ("🌶\u{fe0f}", 2606),
-        ("👩🏻\u{200d}❤\u{200d}👩🏻", 2368),
-        ("🏗\u{fe0f}", 2734),
-        ("🧎🏻\u{200d}♂\u{fe0f}\u{200d}➡", 1612),
-        ("🎸", 3086),
-        ("🤌🏿", 245),
-        ("🇧🇯", 3537),
-        ("💇🏻\u{200d}♀\u{fe0f}", 1522),
-        ("🏖\u{fe0f}", 2728),
-        ("👩🏽\u{200d}🤝\u{200d}👨🏿", 2150),
-        ("☁", 2908),
-        ("🟠", 3471),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2363),
-        ("🐹", 2477),
-        ("🇫🇮", 3586),
-        ("🇧🇦", 3529),
-        ("🤾🏾\u{200d}♂\u{fe0f}", 2025),
-        ("😛", 24),
-        ("🚣🏿\u{200d}♀\u{fe0f}", 1885),
-        ("🇹🇴", 3742),
-        ("✅", 3404),
-        ("👲🏾", 1248),
-        ("🕵🏼\u{200d}♂\u{fe0f}", 1156),
-        ("🪺", 2579),
-        ("🤸🏿\u{200d}♂", 1987),
-        ("🤱🏼", 1312),
-        ("🧎🏽\u{200d}♀\u{200d}➡\u{fe0f}", 1608),
-        ("🧓🏻", 645),
-        ("🕖", 2877),
-        ("👮\u{200d}♂", 1136),
-        ("💣", 3214),
-        ("👮🏿\u{200d}♂\u{fe0f}", 1141),
-        ("🐜", 2540),
-        ("🔘", 3501),
-        ("🌤\u{fe0f}", 2911),
-        ("💛", 145),
-        ("👨🏽\u{200d}🤝\u{200d}👨🏾", 2175),
-        ("🤾\u{200d}♀\u{fe0f}", 2027),
-        ("👩🏽\u{200d}🤝\u{200d}👨🏾", 2149),
-        ("👎🏻", 337),
-        ("🚶🏾\u{200d}♂\u{fe0f}", 1537),
-        ("🚕", 2802),
-        ("👾", 115),
-        ("8\u{20e3}", 3428),
-        ("🧚🏾\u{200d}♀", 1428),
-        ("🇸🇯", 3718),
-        ("🤾🏿\u{200d}♂", 2026),
-        ("🚶🏻\u{200d}♀\u{200d}➡\u{fe0f}", 1552),
-        ("🎽", 2984),
-        ("👨\u{200d}🦼", 1665),
-        ("📚", 3137),
-        ("👨🏼\u{200d}🦱", 562),
-        ("🦶", 460),
-        ("🧔🏼\u{200d}♂\u{fe0f}", 544),
-        ("🧑\u{200d}💼", 986),
-        ("👩🏼\u{200d}🏭", 982),
-        ("🧎🏼\u{200d}♂\u{200d}➡\u{fe0f}", 1613),
-        ("👨🏻\u{200d}🦰", 555),
-        ("👳\u{200d}♀\u{fe0f}", 1238),
-        ("⏭", 3359),
-        ("🍦", 2675),
-        ("🖋", 3173),
-        ("🇨🇻", 3565),
-        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏽", 2209),
-        ("🧑\u{200d}🧒\u{200d}🧒", 2426),
-        ("👂🏽", 469),
-        ("🇵🇾", 3703),
-        ("👰🏿\u{200d}♀\u{fe0f}", 1291),
-        ("🧢", 3059),
-        ("🐵", 2428),
-        ("🧎", 1581),
-        ("🏊🏾\u{200d}♂\u{fe0f}", 1896),
-        ("🧑🏼\u{200d}❤\u{200d}🧑🏽", 2300),
-        ("⚡", 2926),
-        ("⛹\u{fe0f}", 1904),
-        ("🗃\u{fe0f}", 3198),
-        ("⛹🏿\u{200d}♂\u{fe0f}", 1915),
-        ("👩\u{200d}🦽", 1713),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2262),
-        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2258),
-        ("🕍", 2762),
-        ("🚶\u{200d}♂\u{200d}➡\u{fe0f}", 1557),
-        ("📊", 3189),
-        ("🔌", 3103),
-        ("👨\u{200d}🦰", 554),
-        ("👮🏻", 1131),
-        ("🧍🏽\u{200d}♂", 1572),
-        ("👨\u{200d}🎤", 1046),
-        ("🛹", 2819),
-        ("🧒🏻", 501),
-        ("🏃🏿\u{200d}♂\u{200d}➡\u{fe0f}", 1760),
-        ("🙅🏻\u{200d}♀\u{fe0f}", 711),
-        ("⚖", 3224),
-        ("👩🏻\u{200d}❤\u{200d}👨🏿", 2324),
-        ("🧍🏼", 1565),
-        ("↘\u{fe0f}", 3310),
-        ("🧚🏻\u{200d}♀\u{fe0f}", 1425),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏾", 2379),
-        ("5\u{fe0f}\u{20e3}", 3425),
-        ("👨🏽\u{200d}⚖\u{fe0f}", 905),
-        ("🏄🏽\u{200d}♂\u{fe0f}", 1859),
-        ("🤶🏿", 1351),
-        ("🧑🏽\u{200d}🚀", 1097),
-        ("🤚🏽", 177),
-        ("🧑🏿\u{200d}⚖\u{fe0f}", 901),
-        ("🧚🏿\u{200d}♀", 1429),
-        ("👨🏻\u{200d}🦼\u{200d}➡", 1672),
-        ("👳🏿\u{200d}♀", 1243),
-        ("🧘🏽\u{200d}♀\u{fe0f}", 2066),
-        ("👱🏾\u{200d}♀\u{fe0f}", 636),
-        ("🇧🇼", 3547),
-        ("🚿", 3256),
-        ("👭🏽", 2110),
-        ("🩴", 3045),
-        ("👰🏻\u{200d}♀", 1287),
-        ("🚴\u{200d}♀\u{fe0f}", 1952),
-        ("🤹🏻\u{200d}♂\u{fe0f}", 2040),
-        ("🧑🏿\u{200d}❤\u{200d}🧑🏻", 2311),
-        ("👳🏼\u{200d}♂", 1234),
-        ("👏🏿", 371),
-        ("🕵🏽\u{200d}♂\u{fe0f}", 1157),
-        ("🧑🏻\u{200d}🌾", 915),
-        ("🙆\u{200d}♀", 728),
-        ("🚶🏼\u{200d}♀", 1541),
-        ("🛐", 3328),
-        ("☢\u{fe0f}", 3305),
-        ("✝", 3334),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", 2286),
-        ("🤼", 1994),
-        ("🍽\u{fe0f}", 2710),
-        ("🧍🏿", 1568),
-        ("👦🏼", 508),
-        ("♂", 3380),
-        ("👩🏽\u{200d}🦯\u{200d}➡\u{fe0f}", 1650),
-        ("🙍🏼\u{200d}♂\u{fe0f}", 670),
-        ("👳🏿", 1231),
-        ("🤾🏻\u{200d}♀", 2028),
-        ("👩\u{200d}🚒", 1124),
-        ("♀\u{fe0f}", 3379),
-        ("👸🏽", 1223),
-        ("🔂", 3356),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2324),
-        ("🇨🇿", 3569),
-        ("👼🏼", 1336),
-        ("🚴🏼\u{200d}♀\u{fe0f}", 1954),
-        ("👨\u{200d}⚖", 902),
-        ("🧑🏼\u{200d}🦯", 1619),
-        ("🇧🇭", 3535),
-        ("🛏", 3251),
-        ("🦒", 2469),
-        ("🩺", 3245),
-        ("🤎", 150),
-        ("👵🏻", 657),
-        ("🧘🏽", 2054),
-        ("🦸🏾", 1362),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2365),
-        ("🙅\u{200d}♀", 710),
-        ("🇲🇳", 3664),
-        ("👩🏻\u{200d}⚖\u{fe0f}", 909),
-        ("3\u{20e3}", 3423),
-        ("👨🏼\u{200d}🦲", 574),
-        ("👐🏿", 389),
-        ("🕴\u{fe0f}", 1773),
-        ("☦\u{fe0f}", 3335),
-        ("🧙\u{200d}♀", 1406),
-        ("🤡", 110),
-        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏿", 2206),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2244),
-        ("🧑\u{200d}🎄", 1352),
-        ("❤", 142),
-        ("👩🏾\u{200d}🤝\u{200d}👩🏿", 2128),
-        ("🎩", 3057),
-        ("👩🏿\u{200d}🤝\u{200d}👨🏻", 2155),
-        ("🧑🏿\u{200d}🦰", 595),
-        ("🧏🏼\u{200d}♀\u{fe0f}", 784),
-        ("🤸🏿", 1981),
-        ("🧎\u{200d}♂\u{200d}➡\u{fe0f}", 1611),
-        ("🧑🏻\u{200d}🎄", 1353),
-        ("👩🏽\u{200d}🎤", 1055),
-        ("🫅🏼", 1210),
-        ("🦻🏿", 477),
-        ("🤾\u{200d}♀", 2027),
-        ("👩🏿\u{200d}❤\u{200d}👨🏽", 2339),
-        ("👩🏻\u{200d}❤\u{200d}👩🏽", 2374),
-        ("🚇", 2787),
-        ("🏞", 2731),
-        ("🤽🏻\u{200d}♀\u{fe0f}", 2010),
-        ("🗞", 3144),
-        ("🏳\u{fe0f}\u{200d}🌈", 3509),
-        ("🚶🏽\u{200d}♀\u{200d}➡", 1554),
-        ("🗨\u{fe0f}", 164),
-        ("🕵🏽\u{200d}♀", 1163),
-        ("👩🏼\u{200d}🦼\u{200d}➡\u{fe0f}", 1685),
-        ("🤦\u{200d}♀", 818),
-        ("🙀", 123),
-        ("🤹🏾", 2037),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏾", 2279),
-        ("🪔", 3130),
-        ("🧙🏿\u{200d}♀", 1411),
-        ("🇰🇮", 3635),
-        ("🔪", 2713),
-        ("🦓", 2452),
-        ("👵🏽", 659),
-        ("🤦🏽", 809),
-        ("🏃🏻\u{200d}♂", 1732),
-        ("👨🏼\u{200d}🦯\u{200d}➡", 1637),
-        ("🧎🏿\u{200d}♂", 1592),
-        ("🧔🏽", 539),
-        ("🧂", 2651),
-        ("🧖🏼\u{200d}♀\u{fe0f}", 1796),
-        ("↕\u{fe0f}", 3315),
-        ("🤹🏻\u{200d}♀", 2046),
-        ("🚶🏼\u{200d}➡\u{fe0f}", 1547),
-        ("👨🏾\u{200d}❤\u{200d}👨🏿", 2362),
-        ("🦭", 2528),
-        ("🤜🏼", 362),
-        ("💀", 107),
-        ("🙋🏿\u{200d}♀", 769),
-        ("🧎🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1616),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", 2272),
-        ("🧏🏾", 774),
-        ("🫁", 486),
-        ("🔸", 3496),
-        ("👩🏻\u{200d}⚕", 855),
-        ("♈", 3341),
-        ("🙎🏽\u{200d}♀", 695),
-        ("👨\u{200d}🍳", 938),
-        ("🐤", 2498),
-        ("👨🏾\u{200d}🦼", 1669),
-        ("🤷🏻\u{200d}♀", 837),
-        ("🧍🏻\u{200d}♀\u{fe0f}", 1576),
-        ("🤤", 55),
-        ("💂🏼", 1168),
-        ("🧗🏽\u{200d}♂\u{fe0f}", 1809),
-        ("🦶🏾", 464),
-        ("🤏🏼", 248),
-        ("🦤", 2507),
-        ("🧗🏻\u{200d}♂", 1807),
-        ("🦑", 2673),
-        ("🍨", 2677),
-        ("🤹🏿\u{200d}♀", 2050),
-        ("🧑🏿\u{200d}🚀", 1099),
-        ("☢", 3305),
-        ("👤", 2419),
-        ("🦹🏻", 1377),
-        ("▫\u{fe0f}", 3493),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏿", 2272),
-        ("🧑🏽\u{200d}🦼\u{200d}➡\u{fe0f}", 1662),
-        ("🧑\u{200d}⚖", 896),
-        ("🧤", 3028),
-        ("🌖", 2892),
-        ("🦝", 2439),
-        ("🛎\u{fe0f}", 2854),
-        ("👨🏻\u{200d}❤\u{200d}👨🏻", 2342),
-        ("👸", 1220),
-        ("🚶🏿\u{200d}♂\u{200d}➡\u{fe0f}", 1562),
-        ("🧍🏻\u{200d}♀", 1576),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏽", 2270),
-        ("🚴🏽\u{200d}♂\u{fe0f}", 1949),
-        ("🐭", 2474),
-        ("👺", 112),
-        ("❤\u{fe0f}\u{200d}🩹", 141),
-        ("🆘", 3450),
-        ("👰🏿\u{200d}♂", 1285),
-        ("👩🏼\u{200d}❤\u{200d}👩🏼", 2377),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2319),
-        ("🙅\u{200d}♂\u{fe0f}", 704),
-        ("🤌", 240),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏼", 2386),
-        ("👨🏿\u{200d}🤝\u{200d}👨🏽", 2183),
-        ("😔", 53),
-        ("🏃🏾\u{200d}➡", 1747),
-        ("🕥", 2884),
-        ("🙇🏻\u{200d}♂\u{fe0f}", 795),
-        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", 2194),
-        ("🧍🏽", 1566),
-        ("🏋🏿\u{200d}♂\u{fe0f}", 1933),
-        ("👫🏿", 2138),
-        ("👩🏿\u{200d}🦯\u{200d}➡\u{fe0f}", 1652),
-        ("💇🏾\u{200d}♂\u{fe0f}", 1519),
-        ("🧛", 1430),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2223),
-        ("🧡", 144),
-        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2246),
-        ("⏏\u{fe0f}", 3371),
-        ("🏔\u{fe0f}", 2723),
-        ("🟦", 3483),
-        ("🧏🏻\u{200d}♀", 783),
-        ("🧏\u{200d}♂", 776),
-        ("⤵\u{fe0f}", 3320),
-        ("🍎", 2590),
-        ("🤷🏿\u{200d}♂\u{fe0f}", 835),
-        ("👨🏼\u{200d}🎤", 1048),
-        ("🫰🏼", 266),
-        ("🙎", 680),
-        ("🤳🏻", 441),
-        ("💁🏽\u{200d}♂", 743),
-        ("🎳", 2970),
-        ("🙎🏼\u{200d}♀\u{fe0f}", 694),
-        ("🐝", 2541),
-        ("🏌\u{fe0f}", 1832),
-        ("👮🏿", 1135),
-        ("🙍\u{200d}♂", 668),
-        ("↪\u{fe0f}", 3318),
-        ("⚧", 3381),
-        ("👞", 3046),
-        ("👨🏻\u{200d}🦽", 1702),
-        ("🧛🏾\u{200d}♀\u{fe0f}", 1446),
-        ("🦹🏽\u{200d}♀", 1391),
-        ("🏄🏾\u{200d}♂", 1860),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2359),
-        ("🤸", 1976),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2230),
-        ("🕵🏾", 1152),
-        ("✈", 2841),
-        ("👩🏿\u{200d}🎤", 1057),
-        ("🧗🏼\u{200d}♀\u{fe0f}", 1814),
-        ("🏃", 1725),
-        ("👨🏻\u{200d}❤\u{200d}👨🏽", 2348),
-        ("🏌🏼\u{200d}♂", 1840),
-        ("🦹🏾\u{200d}♂", 1386),
-        ("🚶🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1553),
-        ("🏄🏿", 1855),
-        ("👷🏼\u{200d}♀\u{fe0f}", 1204),
-        ("🧍🏼\u{200d}♀", 1577),
-        ("☎", 3097),
-        ("🎪", 2781),
-        ("👨🏼\u{200d}🎨", 1066),
-        ("🎂", 2680),
-        ("🙎🏾\u{200d}♂", 690),
-        ("📅", 3182),
-        ("🏂", 1826),
-        ("🇹🇹", 3744),
-        ("🙍🏻\u{200d}♀\u{fe0f}", 675),
-        ("🏃\u{200d}♀\u{fe0f}\u{200d}➡", 1749),
-        ("🌥\u{fe0f}", 2912),
-        ("🧙🏼\u{200d}♂\u{fe0f}", 1402),
-        ("🙆\u{200d}♂\u{fe0f}", 722),
-        ("⁉", 3389),
-        ("👩🏼\u{200d}🦲", 622),
-        ("🐋", 2526),
-        ("®\u{fe0f}", 3416),
-        ("👨🏾\u{200d}🔧", 960),
-        ("🥚", 2642),
-        ("👨\u{200d}👩\u{200d}👧", 2394),
-        ("💺", 2846),
-        ("💁🏿", 739),
-        ("🤜🏿", 365),
-        ("👧🏼", 514),
-        ("👌🏿", 239),
-        ("🤸🏿\u{200d}♀\u{fe0f}", 1993),
-        ("🈶", 3456),
-        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", 2193),
-        ("📐", 3196),
-        ("🤱🏽", 1313),
-        ("🦙", 2468),
-        ("🏈", 2966),
-        ("👴", 650),
-        ("👨🏽\u{200d}🦽\u{200d}➡", 1710),
-        ("👩🏼\u{200d}🦼", 1679),
-        ("🏃🏻\u{200d}♂\u{fe0f}", 1732),
-        ("👨🏾\u{200d}🍼", 1326),
-        ("👧", 512),
-        ("🧎🏾\u{200d}➡\u{fe0f}", 1603),
-        ("🧚\u{200d}♂\u{fe0f}", 1418),
-        ("🛗", 3248),
-        ("🧎🏻\u{200d}♀\u{fe0f}", 1594),
-        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏼", 2295),
-        ("👩🏾\u{200d}❤\u{200d}👩🏿", 2388),
-        ("🤦", 806),
-        ("👩\u{200d}🏭", 980),
-        ("🧑🏽\u{200d}🍳", 935),
-        ("🙏", 422),
-        ("👨🏽\u{200d}🍳", 941),
-        ("⚫", 3477),
-        ("👰🏼\u{200d}♀", 1288),
-        ("🇪🇸", 3583),
-        ("🧑🏼\u{200d}🦽", 1691),
-        ("✨", 2939),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2248),
-        ("👨🏾\u{200d}🦽\u{200d}➡", 1711),
-        ("🏇🏿", 1824),
-        ("🥒", 2608),
-        ("💂🏽\u{200d}♂", 1175),
-        ("🪫", 3102),
-        ("⛱", 2925),
-        ("🙊", 128),
-        ("🙎🏼", 682),
-        ("👳🏻\u{200d}♂", 1233),
-        ("🤸🏾\u{200d}♀", 1992),
-        ("‼\u{fe0f}", 3388),
-        ("⛏\u{fe0f}", 3209),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2213),
-        ("🧹", 3262),
-        ("🖐🏻", 181),
-        ("🫵🏾", 328),
-        ("👄", 492),
-        ("🧑🏻\u{200d}🤝\u{200d}🧑🏽", 2088),
-        ("💉", 3240),
-        ("🎖\u{fe0f}", 2955),
-        ("🏄🏼\u{200d}♂", 1858),
-        ("🕉", 3330),
-        ("🌩\u{fe0f}", 2916),
-        ("🇸🇮", 3717),
-        ("👳🏽", 1229),
-        ("🚴🏽\u{200d}♀\u{fe0f}", 1955),
-        ("👯\u{200d}♂", 1780),
-        ("🚐", 2797),
-        ("📂", 3180),
-        ("🚵🏿\u{200d}♀\u{fe0f}", 1975),
-        ("🍥", 2664),
-        ("🚵🏽\u{200d}♂\u{fe0f}", 1967),
-        ("♾\u{fe0f}", 3387),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2356),
-        ("👨🏽\u{200d}💻", 1031),
-        ("🧝🏿\u{200d}♀", 1483),
-        ("👩🏾\u{200d}🎓", 876),
-        ("🧎🏽\u{200d}♂", 1590),
-        ("🤾\u{200d}♂", 2021),
-        ("👩🏿\u{200d}🚒", 1129),
-        ("⛹🏽\u{200d}♂", 1913),
-        ("👨🏻\u{200d}⚖", 903),
-        ("🫴🏿", 221),
-        ("🚤", 2836),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2326),
-        ("🧑🏾\u{200d}❤\u{200d}🧑🏻", 2307),
-        ("🧑🏾\u{200d}🏭", 972),
-        ("◾", 3490),
-        ("™", 3417),
-        ("👆🏻", 301),
-        ("💂🏿\u{200d}♂\u{fe0f}", 1177),
-        ("🧝🏽\u{200d}♂\u{fe0f}", 1475),
-        ("🏄🏼\u{200d}♂\u{fe0f}", 1858),
-        ("🧑\u{200d}🤝\u{200d}🧑", 2081),
-        ("🧑🏾\u{200d}🦰", 594),
-        ("🧑🏾\u{200d}🦼\u{200d}➡", 1663),
-        ("🧑🏻\u{200d}🦼", 1654),
-        ("🧙🏼\u{200d}♀", 1408),
-        ("👩🏼\u{200d}⚖\u{fe0f}", 910),
-        ("👩🏿\u{200d}💼", 1003),
-        ("💂🏾\u{200d}♂", 1176),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2345),
-        ("🚣🏼\u{200d}♂\u{fe0f}", 1876),
-        ("♥\u{fe0f}", 3005),
-        ("🦸🏿\u{200d}♂\u{fe0f}", 1369),
-        ("👨\u{200d}👧\u{200d}👦", 2411),
-        ("🧑\u{200d}🚒", 1112),
-        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏻", 2299),
-        ("👨\u{200d}✈", 1082),
-        ("🏩", 2749),
-        ("🧑🏿\u{200d}🦯\u{200d}➡", 1628),
-        ("👨🏿\u{200d}🚒", 1123),
-        ("🧖🏾\u{200d}♀\u{fe0f}", 1798),
-        ("🍜", 2658),
-        ("🦌", 2453),
-        ("👯\u{200d}♀\u{fe0f}", 1781),
-        ("🧝🏾\u{200d}♀", 1482),
-        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏾", 2193),
-        ("🤙🏽", 285),
-        ("🧙🏿\u{200d}♂\u{fe0f}", 1405),
-        ("💏🏻", 2186),
-        ("👩🏿\u{200d}🦲", 625),
-        ("⛹🏽", 1907),
-        ("🤹🏾\u{200d}♂", 2043),
-        ("🧚🏿\u{200d}♂\u{fe0f}", 1423),
-        ("💄", 3063),
-        ("🧍🏽\u{200d}♂\u{fe0f}", 1572),
-        ("◽", 3491),
-        ("⏮\u{fe0f}", 3363),
-        ("☮\u{fe0f}", 3337),
-        ("🤹\u{200d}♀\u{fe0f}", 2045),
-        ("🚶🏼\u{200d}➡", 1547),
-        ("🖖🏽", 195),
-        ("🙆🏾\u{200d}♀", 732),
-        ("🤽\u{200d}♀", 2009),
-        ("❣\u{fe0f}", 138),
-        ("👩🏻\u{200d}⚕\u{fe0f}", 855),
-        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏻", 2311),
-        ("🙆🏻\u{200d}♀", 729),
-        ("🧑🏻\u{200d}🍼", 1329),
-        ("☝🏻", 319),
-        ("🚵🏾\u{200d}♀", 1974),
-        ("👰\u{200d}♂", 1280),
-        ("🤽🏻\u{200d}♂\u{fe0f}", 2004),
-        ("🙍🏿", 667),
-        ("🙅🏾\u{200d}♂", 708),
-        ("🏟\u{fe0f}", 2732),
-        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏾", 2305),
-        ("🚹", 3285),
-        ("👩🏿", 583),
-        ("🤢", 60),
-        ("🛑", 2830),
-        ("👩\u{200d}🚀", 1106),
-        ("🧑🏼\u{200d}🦰", 592),
-        ("⭐", 2904),
-        ("🍚", 2656),
-        ("👱🏻", 525),
-        ("🦟", 2549),
-        ("🚶🏻\u{200d}♀", 1540),
-        ("✉\u{fe0f}", 3158),
-        ("👊🏿", 353),
-        ("🫱🏼\u{200d}🫲🏻", 406),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2366),
-        ("🌽", 2605),
-        ("🙍🏾\u{200d}♂", 672),
-        ("™\u{fe0f}", 3417),
-        ("🦻🏼", 474),
-        ("🚶🏾\u{200d}♀\u{200d}➡", 1555),
-        ("🚶🏽\u{200d}➡", 1548),
-        ("🧑\u{200d}🦱", 602),
-        ("🧎🏻\u{200d}♂\u{200d}➡\u{fe0f}", 1612),
-        ("🙋🏻\u{200d}♂", 759),
-        ("🇪🇨", 3578),
-        ("🚶🏽\u{200d}♀", 1542),
-        ("👨🏿\u{200d}🏫", 889),
-        ("🏊🏾\u{200d}♀\u{fe0f}", 1902),
-        ("🏄🏾", 1854),
-        ("🏃🏽\u{200d}♂", 1734),
-        ("🇲🇾", 3675),
-        ("👩🏽\u{200d}🦱", 599),
-        ("🤵🏾\u{200d}♀", 1272),
-        ("🧘🏻\u{200d}♂\u{fe0f}", 2058),
-        ("👊🏾", 352),
-        ("🥹", 85),
-        ("🧜🏿\u{200d}♀\u{fe0f}", 1465),
-        ("🤸🏽", 1979),
-        ("🚴🏿", 1945),
-        ("🧘🏼\u{200d}♂\u{fe0f}", 2059),
-        ("🧖🏾\u{200d}♂\u{fe0f}", 1792),
-        ("🏍", 2812),
-        ("🦸", 1358),
-        ("🫱🏽", 201),
-        ("🏌\u{fe0f}\u{200d}♂\u{fe0f}", 1838),
-        ("8\u{fe0f}\u{20e3}", 3428),
-        ("🥅", 2979),
-        ("🫔", 2639),
-        ("🧘🏾\u{200d}♀\u{fe0f}", 2067),
-        ("💂\u{200d}♀\u{fe0f}", 1178),
-        ("🧗🏻\u{200d}♀\u{fe0f}", 1813),
-        ("🏊\u{200d}♀", 1898),
-        ("👨🏽\u{200d}⚖", 905),
-        ("🇲🇰", 3661),
-        ("🦻🏻", 473),
-        ("🧚🏼", 1414),
-        ("🤐", 36),
-        ("🧎🏼\u{200d}♂\u{fe0f}\u{200d}➡", 1613),
-        ("👩🏽\u{200d}🦯", 1644),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2229),
-        ("🎐", 2947),
-        ("👨🏾\u{200d}🚀", 1104),
-        ("👨🏿\u{200d}💻", 1033),
-        ("✌🏻", 253),
-        ("🦻🏾", 476),
-        ("🇨🇽", 3567),
-        ("🇬🇼", 3609),
-        ("👨🏻\u{200d}🔧", 957),
-        ("👩\u{200d}💼", 998),
-        ("👌🏽", 237),
-        ("🏜", 2729),
-        ("🪢", 3018),
-        ("🙍🏽\u{200d}♀\u{fe0f}", 677),
-        ("👩🏿\u{200d}❤\u{200d}👩🏾", 2392),
-        ("👨🏾\u{200d}🏫", 888),
-        ("🪅", 3001),
-        ("🚣🏿\u{200d}♂", 1879),
-        ("👩🏻\u{200d}🤝\u{200d}👩🏿", 2116),
-        ("🙅🏻", 699),
-        ("🏌\u{fe0f}\u{200d}♀", 1844),
-        ("💇🏿\u{200d}♂\u{fe0f}", 1520),
-        ("🤦🏾\u{200d}♂\u{fe0f}", 816),
-        ("👏", 366),
-        ("🚓", 2800),
-        ("🦹\u{200d}♀\u{fe0f}", 1388),
-        ("🤶", 1346),
-        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2262),
-        ("🧚", 1412),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏽", 2287),
-        ("👨\u{200d}🚀", 1100),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏽", 2391),
-        ("9\u{20e3}", 3429),
-        ("🗯\u{fe0f}", 165),
-        ("✍\u{fe0f}", 428),
-        ("🧏🏽\u{200d}♀", 785),
-        ("👩🏼\u{200d}❤\u{200d}👩🏾", 2379),
-        ("🇸🇸", 3725),
-        ("🏠", 2741),
-        ("🤵🏼\u{200d}♂", 1264),
-        ("👎🏽", 339),
-        ("↔\u{fe0f}", 3316),
-        ("🇿🇼", 3769),
-        ("👢", 3053),
-        ("🥾", 3048),
-        ("📡", 3239),
-        ("🃏", 3009),
-        ("👩🏼\u{200d}🦯\u{200d}➡", 1649),
-        ("🧣", 3027),
-        ("🛴", 2818),
-        ("🏊🏿\u{200d}♀", 1903),
-        ("🧗🏼\u{200d}♀", 1814),
-        ("👨🏼\u{200d}❤\u{200d}👨🏿", 2354),
-        ("🔁", 3355),
-        ("🧑🏾\u{200d}🦼\u{200d}➡\u{fe0f}", 1663),
-        ("🪭", 3039),
-        ("➡\u{fe0f}", 3309),
-        ("💆", 1491),
-        ("👨🏻\u{200d}🚒", 1119),
-        ("🤦🏽\u{200d}♂", 815),
-        ("🪃", 3215),
-        ("🚵🏾", 1962),
-        ("🤙", 282),
-        ("🧚🏿", 1417),
-        ("🥌", 2987),
-        ("💵", 3151),
-        ("🥍", 2974),
-        ("🧘🏼", 2053),
-        ("🧑\u{200d}🏫", 878),
-        ("🛢", 2824),
-        ("🎱", 2992),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2234),
-        ("😂", 7),
-        ("👈🏾", 292),
-        ("🧑🏻\u{200d}🏫", 879),
-        ("⛹\u{200d}♀\u{fe0f}", 1916),
-        ("👨🏼\u{200d}🤝\u{200d}👨🏽", 2170),
-        ("👨🏿\u{200d}🏭", 979),
-        ("🫐", 2596),
-        ("🤳🏿", 445),
-        ("🌏", 2718),
-        ("👨\u{200d}👨\u{200d}👦", 2398),
-        ("⌨", 3107),
-        ("🦹🏻\u{200d}♂\u{fe0f}", 1383),
-        ("🇦🇴", 3520),
-        ("👨🏿\u{200d}🦲", 577),
-        ("🤽🏼\u{200d}♀\u{fe0f}", 2011),
-        ("🤝🏽", 399),
-        ("🧚🏿\u{200d}♂", 1423),
-        ("👩🏾\u{200d}❤\u{200d}👩🏽", 2387),
-        ("🙅🏾\u{200d}♀", 714),
-        ("🪖", 3060),
-        ("🙌🏽", 375),
-        ("🕵🏼\u{200d}♀", 1162),
-        ("🧘\u{200d}♂\u{fe0f}", 2057),
-        ("🏃🏾\u{200d}♂\u{200d}➡\u{fe0f}", 1759),
-        ("🪓", 3208),
-        ("🚎", 2796),
-        ("🚣🏿", 1873),
-        ("🏌🏾\u{200d}♀", 1848),
-        ("👩\u{200d}🎤", 1052),
-        ("👷\u{200d}♀", 1202),
-        ("👨🏻\u{200d}❤\u{200d}👨🏼", 2347),
-        ("👩\u{200d}👦\u{200d}👦", 2414),
-        ("🖕🏼", 308),
-        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", 2195),
-        ("🫄🏽", 1307),
-        ("🐚", 2534),
-        ("🤷🏻\u{200d}♀\u{fe0f}", 837),
-        ("👨🏿\u{200d}🦽\u{200d}➡\u{fe0f}", 1712),
-        ("🙍🏽\u{200d}♂", 671),
-        ("🗺", 2720),
-        ("🧑🏾\u{200d}🎓", 864),
-        ("🖊\u{fe0f}", 3174),
-        ("🕹\u{fe0f}", 2996),
-        ("⚕", 3397),
-        ("😨", 88),
-        ("🤾\u{200d}♂\u{fe0f}", 2021),
-        ("⛸\u{fe0f}", 2981),
-        ("⚧\u{fe0f}", 3381),
-        ("👩🏾\u{200d}🏫", 894),
-        ("👮🏾\u{200d}♀", 1146),
-        ("🙂\u{200d}↔", 50),
-        ("🧑🏾\u{200d}🏫", 882),
-        ("🔇", 3066),
-        ("🫱🏼\u{200d}🫲🏿", 409),
-        ("🅿", 3449),
-        ("🧑🏿\u{200d}🦼", 1658),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2344),
-        ("💍", 3064),
-        ("🍔", 2632),
-        ("🇨🇭", 3555),
-        ("🎛\u{fe0f}", 3080),
-        ("👩\u{200d}🦯\u{200d}➡", 1647),
-        ("👨🏾\u{200d}🤝\u{200d}👨🏻", 2177),
-        ("🙏🏻", 423),
-        ("🍩", 2678),
-        ("🦛", 2473),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏿", 2276),
-        ("📤", 3162),
-        ("🥱", 100),
-        ("🚉", 2789),
-        ("💇🏽\u{200d}♂", 1518),
-        ("🕴", 1773),
-        ("🇨🇱", 3558),
-        ("🙎🏿\u{200d}♂\u{fe0f}", 691),
-        ("🍋\u{200d}🟩", 2586),
-        ("👰🏻\u{200d}♂", 1281),
-        ("🇧🇹", 3545),
-        ("🏃🏾\u{200d}♀\u{200d}➡\u{fe0f}", 1753),
-        ("🌶", 2606),
-        ("🕷\u{fe0f}", 2546),
-        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2259),
-        ("🔽", 3366),
-        ("🧍🏿\u{200d}♀", 1580),
-        ("🏃🏼\u{200d}♂\u{fe0f}", 1733),
-        ("💂🏼\u{200d}♀\u{fe0f}", 1180),
-        ("🫸🏼", 230),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2240),
-        ("🪐", 2903),
-        ("🏃🏽\u{200d}♀\u{200d}➡", 1752),
-        ("👨🏿\u{200d}🤝\u{200d}👨🏼", 2182),
-        ("🗞\u{fe0f}", 3144),
-        ("☎\u{fe0f}", 3097),
-        ("🚶🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1562),
-        ("🔤", 3435),
-        ("✂\u{fe0f}", 3197),
-        ("🙎🏽", 683),
-        ("🤾🏾", 2019),
-        ("🏬", 2752),
-        ("🌸", 2554),
-        ("🙆🏽", 719),
-        ("💥", 157),
-        ("👩🏾\u{200d}⚕\u{fe0f}", 858),
-        ("🧎🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1610),
-        ("🥪", 2636),
-        ("🇸🇩", 3713),
-        ("🇺🇦", 3748),
-        ("🤰🏼", 1294),
-        ("👷\u{200d}♂", 1196),
-        ("❄\u{fe0f}", 2927),
-        ("🌦\u{fe0f}", 2913),
-        ("🈂\u{fe0f}", 3454),
-        ("🧘🏿\u{200d}♀\u{fe0f}", 2068),
-        ("🫅🏿", 1213),
-        ("✏", 3171),
-        ("✈\u{fe0f}", 2841),
-        ("🏔", 2723),
-        ("🧑🏿\u{200d}🤝\u{200d}🧑🏻", 2103),
-        ("🚧", 2831),
-        ("🏃\u{200d}♀\u{fe0f}", 1737),
-        ("❄", 2927),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏽", 2370),
-        ("😐", 38),
-        ("👨\u{200d}🦯", 1629),
-        ("🚶🏿\u{200d}➡\u{fe0f}", 1550),
-        ("🚶🏿\u{200d}➡", 1550),
-        ("🧝🏼", 1468),
-        ("✋🏾", 190),
-        ("🪁", 2990),
-        ("🚶🏻\u{200d}♂\u{fe0f}", 1534),
-        ("🧑🏿\u{200d}🏫", 883),
-        ("👆🏼", 302),
-        ("🏃🏻\u{200d}♀\u{fe0f}\u{200d}➡", 1750),
-        ("👩🏿\u{200d}🍳", 949),
-        ("🤸\u{200d}♂", 1982),
-        ("🤞🏼", 260),
-        ("🫨", 49),
-        ("🩳", 3036),
-        ("👩🏼\u{200d}🚒", 1126),
-        ("🤾🏻\u{200d}♀\u{fe0f}", 2028),
-        ("🇦🇿", 3528),
-        ("🏋🏾\u{200d}♂\u{fe0f}", 1932),
-        ("👩🏼\u{200d}🦼\u{200d}➡", 1685),
-        ("🐒", 2429),
-        ("👩🏾\u{200d}❤\u{200d}👨🏽", 2335),
-        ("🦸🏾\u{200d}♂\u{fe0f}", 1368),
-        ("🧵", 3015),
-        ("👩🏻\u{200d}🤝\u{200d}👨🏿", 2142),
-        ("🈚", 3460),
-        ("🐽", 2462),
-        ("🕵\u{200d}♀\u{fe0f}", 1160),
-        ("✏\u{fe0f}", 3171),
-        ("🏄🏾\u{200d}♀\u{fe0f}", 1866),
-        ("🌦", 2913),
-        ("🚶🏻\u{200d}➡", 1546),
-        ("🤵🏿", 1261),
-        ("🙎🏽\u{200d}♂\u{fe0f}", 689),
-        ("🦹🏽\u{200d}♂", 1385),
-        ("🌷", 2563),
-        ("🤪", 26),
-        ("💁🏿\u{200d}♀", 751),
-        ("🧍🏾\u{200d}♀\u{fe0f}", 1579),
-        ("🎁", 2951),
-        ("🤑", 28),
-        ("🚝", 2791),
-        ("🧘\u{200d}♀", 2063),
-        ("🧎🏽\u{200d}➡", 1602),
-        ("👨🏻\u{200d}🦱", 561),
-        ("🎨", 3014),
-        ("🇧🇮", 3536),
-        ("🧑🏽\u{200d}❤\u{200d}🧑🏼", 2304),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2231),
-        ("🧎🏼", 1583),
-        ("👶🏼", 496),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2217),
-        ("🇪🇭", 3581),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", 2266),
-        ("🧑🏼\u{200d}💼", 988),
-        ("👩🏻\u{200d}🦱", 597),
-        ("🚶🏾\u{200d}♀\u{200d}➡\u{fe0f}", 1555),
-        ("👩🏾\u{200d}🤝\u{200d}👨🏽", 2153),
-        ("📩", 3161),
-        ("🚵\u{200d}♂\u{fe0f}", 1964),
-        ("🆗", 3448),
-        ("🏝\u{fe0f}", 2730),
-        ("🧑🏿\u{200d}🦲", 631),
-        ("🫱🏾\u{200d}🫲🏽", 416),
-        ("🪿", 2514),
-        ("😄", 2),
-        ("🤷🏾\u{200d}♂\u{fe0f}", 834),
-        ("🚃", 2783),
-        ("🎦", 3372),
-        ("🤹🏿\u{200d}♀\u{fe0f}", 2050),
-        ("🎠", 2776),
-        ("🛣\u{fe0f}", 2822),
-        ("🤭", 30),
-        ("🙍🏻", 663),
-        ("➕", 3383),
-        ("🧑🏿\u{200d}🎄", 1357),
-        ("🕗", 2879),
-        ("👨🏾\u{200d}🦳", 570),
-        ("🧑🏻\u{200d}🤝\u{200d}🧑🏿", 2090),
-        ("🧔🏼", 538),
-        ("👩\u{200d}👩\u{200d}👧", 2404),
-        ("🦜", 2511),
-        ("🆎", 3437),
-        ("☝\u{fe0f}", 318),
-        ("💤", 167),
-        ("🏉", 2967),
-        ("🦧", 2431),
-        ("🧘🏿\u{200d}♀", 2068),
-        ("👩🏼\u{200d}🦳", 610),
-        ("🏊🏼\u{200d}♂\u{fe0f}", 1894),
-        ("🇲🇨", 3655),
-        ("👩🏻\u{200d}❤\u{200d}👨🏾", 2323),
-        ("🀄", 3010),
-        ("🖨", 3106),
-        ("🛸", 2853),
-        ("📠", 3100),
-        ("🏌\u{200d}♀\u{fe0f}", 1844),
-        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏻", 2307),
-        ("🏌🏾\u{200d}♂", 1842),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2231),
-        ("🏃🏽", 1728),
-        ("🧇", 2626),
-        ("👮🏼\u{200d}♂\u{fe0f}", 1138),
-        ("👩🏽\u{200d}🤝\u{200d}👩🏾", 2123),
-        ("🏊🏽", 1889),
-        ("👩🏿\u{200d}🚀", 1111),
-        ("😦", 86),
-        ("🦵🏾", 458),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2318),
-        ("🧑🏻\u{200d}🦽", 1690),
-        ("🧘🏿", 2056),
-        ("🧑🏼\u{200d}🦼\u{200d}➡", 1661),
-        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏻", 2199),
-        ("👰🏻", 1275),
-        ("😋", 23),
-        ("🧎🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1609),
-        ("👨\u{200d}🌾", 920),
-        ("👨\u{200d}🚒", 1118),
-        ("🤽🏼\u{200d}♀", 2011),
-        ("🧑🏾\u{200d}🦯", 1621),
-        ("🏋🏻\u{200d}♀\u{fe0f}", 1935),
-        ("🛠\u{fe0f}", 3211),
-        ("🧍\u{200d}♀\u{fe0f}", 1575),
-        ("🙆🏽\u{200d}♂\u{fe0f}", 725),
-        ("👹", 111),
-        ("🙇🏼", 790),
-        ("⚛\u{fe0f}", 3329),
-        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2262),
-        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2252),
-        ("🧜🏻\u{200d}♂", 1455),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏽", 2374),
-        ("🇭🇺", 3616),
-        ("🧑🏿\u{200d}🦽\u{200d}➡", 1700),
-        ("🚵🏾\u{200d}♀\u{fe0f}", 1974),
-        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", 2206),
-        ("🕝", 2868),
-        ("😹", 119),
-        ("♒", 3351),
-        ("👨🏼\u{200d}⚕\u{fe0f}", 850),
-        ("🌒", 2888),
-        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", 2201),
-        ("🚵🏼", 1960),
-        ("🏘\u{fe0f}", 2739),
-        ("😇", 13),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏿", 2288),
-        ("👈🏻", 289),
-        ("®", 3416),
-        ("🛅", 3293),
-        ("🎍", 2944),
-        ("👱🏼\u{200d}♀\u{fe0f}", 634),
-        ("🚶🏾\u{200d}♂\u{200d}➡", 1561),
-        ("📃", 3140),
-        ("💁🏻\u{200d}♂", 741),
-        ("💼", 3178),
-        ("🥷🏻", 1185),
-        ("🐢", 2518),
-        ("🦻🏽", 475),
-        ("🛣", 2822),
-        ("👩🏼\u{200d}🔬", 1018),
-        ("🏙", 2769),
-        ("🇲🇭", 3660),
-        ("👩🏽\u{200d}🚒", 1127),
-        ("💑🏻", 2290),
-        ("👩\u{200d}⚖\u{fe0f}", 908),
-        ("🎉", 2941),
-        ("👮🏿\u{200d}♀", 1147),
-        ("🗑", 3200),
-        ("🖥\u{fe0f}", 3105),
-        ("🫲🏽", 207),
-        ("💏🏽", 2188),
-        ("🤫", 33),
-        ("🧑🏼\u{200d}🤝\u{200d}🧑🏻", 2091),
-        ("🚣🏻\u{200d}♀", 1881),
-        ("🌨", 2915),
-        ("👨🏿\u{200d}🎤", 1051),
-        ("🇳🇷", 3686),
-        ("🙎\u{200d}♂\u{fe0f}", 686),
-        ("🙅🏻\u{200d}♂\u{fe0f}", 705),
-        ("👊", 348),
-        ("🫱🏻", 199),
-        ("🧗\u{200d}♂\u{fe0f}", 1806),
-        ("😽", 122),
-        ("👷🏾\u{200d}♂\u{fe0f}", 1200),
-        ("🧑🏿\u{200d}💼", 991),
-        ("👦🏿", 511),
-        ("🫕", 2646),
-        ("🍧", 2676),
-        ("2\u{20e3}", 3422),
-        ("🏴", 3507),
-        ("👨🏼\u{200d}💻", 1030),
-        ("🧎🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1613),
-        ("🙍", 662),
-        ("👨🏿", 535),
-        ("🕴🏾", 1777),
-        ("🌰", 2615),
-        ("👨🏾\u{200d}❤\u{200d}👨🏾", 2361),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2223),
-        ("👩🏻\u{200d}🦼\u{200d}➡", 1684),
-        ("👳🏻", 1227),
-        ("👃🏼", 480),
-        ("👮\u{200d}♀\u{fe0f}", 1142),
-        ("🍭", 2686),
-        ("💁🏽\u{200d}♀\u{fe0f}", 749),
-        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏻", 2207),
-        ("💟", 137),
-        ("👩🏼", 580),
-        ("🧗🏾\u{200d}♀\u{fe0f}", 1816),
-        ("🤸🏽\u{200d}♂", 1985),
-        ("💆🏼", 1493),
-        ("🧜\u{200d}♀", 1460),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2249),
-        ("🧑🏽\u{200d}⚖\u{fe0f}", 899),
-        ("👩🏿\u{200d}🎨", 1075),
-        ("🙇🏼\u{200d}♀\u{fe0f}", 802),
-        ("🩸", 3241),
-        ("🇱🇾", 3653),
-        ("㊙\u{fe0f}", 3467),
-        ("🏘", 2739),
-        ("🧑🏿\u{200d}🦼\u{200d}➡", 1664),
-        ("🐧", 2501),
-        ("🏌\u{fe0f}\u{200d}♂", 1838),
-        ("🦘", 2491),
-        ("🫲🏻", 205),
-        ("🧓🏽", 647),
-        ("👨🏾\u{200d}⚕", 852),
-        ("🎶", 3077),
-        ("🚶🏼", 1529),
-        ("🌀", 2920),
-        ("🏃🏿\u{200d}♂\u{fe0f}", 1736),
-        ("🪒", 3259),
-        ("🚙", 2806),
-        ("🕺🏽", 1770),
-        ("❌", 3407),
-        ("👩🏽\u{200d}🎨", 1073),
-        ("©", 3415),
-        ("🤟🏼", 272),
-        ("🙇🏽\u{200d}♂", 797),
-        ("🤾🏼", 2017),
-        ("⌚", 2858),
-        ("🚶🏾\u{200d}➡\u{fe0f}", 1549),
-        ("🪬", 3277),
-        ("👨\u{200d}👨\u{200d}👧\u{200d}👦", 2400),
-        ("🏌", 1832),
-        ("🇲🇿", 3676),
-        ("👩\u{200d}✈", 1088),
-        ("🙇🏾\u{200d}♂", 798),
-        ("🤼\u{200d}♀\u{fe0f}", 1996),
-        ("🛌", 2075),
-        ("🧑🏼\u{200d}❤\u{200d}🧑🏾", 2301),
-        ("🧙🏿", 1399),
-        ("🧿", 3276),
-        ("🙇🏻", 789),
-        ("👨\u{200d}⚖\u{fe0f}", 902),
-        ("🕺", 1767),
-        ("🙎🏻\u{200d}♀", 693),
-        ("🇲🇵", 3666),
-        ("🚶🏽\u{200d}♂\u{200d}➡", 1560),
-        ("💂🏾\u{200d}♂\u{fe0f}", 1176),
-        ("🚣", 1868),
-        ("🧑🏾\u{200d}💼", 990),
-        ("🧏🏿", 775),
-        ("🏄", 1850),
-        ("🧏", 770),
-        ("📔", 3131),
-        ("👨\u{200d}👩\u{200d}👧\u{200d}👧", 2397),
-        ("🇧🇪", 3532),
-        ("👈🏿", 293),
-        ("🇧🇾", 3548),
-        ("➗", 3385),
-        ("👍🏾", 334),
-        ("⛴\u{fe0f}", 2838),
-        ("🦹🏽", 1379),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2255),
-        ("🖐", 180),
-        ("🇵🇳", 3698),
-        ("🪸", 2535),
-        ("🙆🏽\u{200d}♂", 725),
-        ("👨\u{200d}👩\u{200d}👦", 2393),
-        ("👨🏼\u{200d}✈", 1084),
-        ("⛹🏻\u{200d}♀\u{fe0f}", 1917),
-        ("👩🏽\u{200d}❤\u{200d}👨🏽", 2330),
-        ("🦸🏾\u{200d}♂", 1368),
-        ("🚵🏽\u{200d}♀\u{fe0f}", 1973),
-        ("🎥", 3115),
-        ("🦸🏻\u{200d}♀", 1371),
-        ("🫅🏾", 1212),
-        ("🚶\u{200d}♀", 1539),
-        ("🇰🇼", 3640),
-        ("👨🏿\u{200d}🤝\u{200d}👨🏾", 2184),
-        ("👨🏼\u{200d}🚀", 1102),
-        ("🙎🏾", 684),
-        ("🤷🏽\u{200d}♀", 839),
-        ("🧜🏽", 1451),
-        ("📀", 3113),
-        ("🇧🇧", 3530),
-        ("👨🏼\u{200d}🏫", 886),
-        ("🧎🏼\u{200d}♂\u{fe0f}", 1589),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2331),
-        ("🚵🏿\u{200d}♂\u{fe0f}", 1969),
-        ("🧘", 2051),
-        ("🧝\u{200d}♀", 1478),
-        ("↗\u{fe0f}", 3308),
-        ("🇹🇿", 3747),
-        ("🙋", 752),
-        ("👬🏾", 2163),
-        ("🙌🏻", 373),
-        ("👩\u{200d}⚕\u{fe0f}", 854),
-        ("🇲🇽", 3674),
-        ("🏃\u{200d}♂\u{fe0f}\u{200d}➡", 1755),
-        ("☠", 108),
-        ("🏄🏿\u{200d}♀\u{fe0f}", 1867),
-        ("🚶🏽\u{200d}♂\u{200d}➡\u{fe0f}", 1560),
-        ("👩🏻\u{200d}🎓", 873),
-        ("👩🏿\u{200d}✈", 1093),
-        ("👨🏽\u{200d}❤\u{200d}👨🏼", 2356),
-        ("🇼🇸", 3763),
-        ("📷", 3120),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2247),
-        ("🇶🇦", 3704),
-        ("🕌", 2760),
-        ("🍠", 2660),
-        ("👩🏿\u{200d}⚖", 913),
-        ("🤽🏻", 1998),
-        ("🏳", 3508),
-        ("🍰", 2681),
-        ("🚥", 2828),
-        ("🌆", 2772),
-        ("👮🏽\u{200d}♂", 1139),
-        ("🦹🏻\u{200d}♂", 1383),
-        ("🧍\u{200d}♂\u{fe0f}", 1569),
-        ("🇲🇬", 3659),
-        ("🌾", 2571),
-        ("👩🏿\u{200d}🦳", 613),
-        ("🪝", 3229),
-        ("🫏", 2449),
-        ("👩🏼\u{200d}🤝\u{200d}👨🏽", 2144),
-        ("🇺🇸", 3752),
-        ("🧄", 2611),
-        ("📋", 3190),
-        ("🈷\u{fe0f}", 3455),
-        ("🖲", 3109),
-        ("💇🏿\u{200d}♂", 1520),
-        ("🧔\u{200d}♂\u{fe0f}", 542),
-        ("🥧", 2683),
-        ("🧑🏾\u{200d}🎄", 1356),
-        ("🤰🏽", 1295),
-        ("😰", 89),
-        ("👩🏿\u{200d}🔧", 967),
-        ("🤽🏽\u{200d}♀", 2012),
-        ("🧑🏿\u{200d}🦯", 1622),
-        ("🇸🇴", 3723),
-        ("👩🏽\u{200d}🦼\u{200d}➡", 1686),
-        ("🔱", 3400),
-        ("🧘🏾", 2055),
-        ("🐸", 2516),
-        ("⏹", 3369),
-        ("🍂", 2576),
-        ("🎖", 2955),
-        ("💆🏻\u{200d}♂", 1498),
-        ("👿", 106),
-        ("🧔🏽\u{200d}♀\u{fe0f}", 551),
-        ("🦶🏿", 465),
-        ("😮\u{200d}💨", 47),
-        ("👮🏽\u{200d}♂\u{fe0f}", 1139),
-        ("🇹🇭", 3736),
-        ("🧏🏼\u{200d}♂\u{fe0f}", 778),
-        ("👲", 1244),
-        ("🧑🏽\u{200d}🏭", 971),
-        ("🟫", 3485),
-        ("👨🏼\u{200d}🦼\u{200d}➡", 1673),
-        ("🇾🇪", 3765),
-        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", 2205),
-        ("🚳", 3298),
-        ("🖼\u{fe0f}", 3013),
-        ("🌍", 2716),
-        ("🔃", 3321),
-        ("🧑🏿\u{200d}🤝\u{200d}🧑🏾", 2106),
-        ("🧞\u{200d}♂", 1485),
-        ("💏", 2185),
-        ("🫱🏻\u{200d}🫲🏽", 403),
-        ("🫅🏻", 1209),
-        ("👯\u{200d}♀", 1781),
-        ("🇿🇦", 3767),
-        ("🦶🏼", 462),
-        ("👩🏿\u{200d}❤\u{200d}👩🏽", 2391),
-        ("🛃", 3291),
-        ("😴", 56),
-        ("👉🏻", 295),
-        ("👩🏻\u{200d}❤\u{200d}👩🏼", 2373),
-        ("👩\u{200d}👦", 2413),
-        ("🤦🏻\u{200d}♀\u{fe0f}", 819),
-        ("🚶🏻\u{200d}♀\u{fe0f}\u{200d}➡", 1552),
-        ("👩🏻\u{200d}🏭", 981),
-        ("👨🏼\u{200d}🦽", 1703),
-        ("🥀", 2559),
-        ("📨", 3160),
-        ("🥊", 2977),
-        ("🫱🏾\u{200d}🫲🏻", 414),
-        ("🙋🏽\u{200d}♂", 761),
-        ("💳", 3155),
-        ("🧑🏽\u{200d}✈\u{fe0f}", 1079),
-        ("🧑🏾\u{200d}🍼", 1332),
-        ("🫸", 228),
-        ("🧑🏿\u{200d}🦽\u{200d}➡\u{fe0f}", 1700),
-        ("🛳\u{fe0f}", 2837),
-        ("⛴", 2838),
-        ("🌋", 2725),
-        ("🐻\u{200d}❄", 2485),
-        ("🕵🏽\u{200d}♂", 1157),
-        ("🏄🏻", 1851),
-        ("👰🏾", 1278),
-        ("🫱🏾\u{200d}🫲🏼", 415),
-        ("🕴🏿", 1778),
-        ("🧑🏽\u{200d}🦼", 1656),
-        ("👱🏿\u{200d}♀", 637),
-        ("🧑🏻\u{200d}⚖", 897),
-        ("🇫🇷", 3591),
-        ("🙋\u{200d}♀\u{fe0f}", 764),
-        ("🚣🏽\u{200d}♂", 1877),
-        ("👩🏽\u{200d}⚕", 857),
-        ("🛤", 2823),
-        ("👩🏼\u{200d}🏫", 892),
-        ("🧑🏻\u{200d}🦱", 603),
-        ("👨🏽\u{200d}🎨", 1067),
-        ("💇🏿", 1514),
-        ("🐶", 2432),
-        ("🙆", 716),
-        ("👩🏾\u{200d}🤝\u{200d}👩🏼", 2126),
-        ("🧏🏾\u{200d}♂\u{fe0f}", 780),
-        ("👨🏽\u{200d}🦼\u{200d}➡", 1674),
-        ("🥈", 2959),
-        ("👩\u{200d}🦼\u{200d}➡", 1683),
-        ("🍣", 2662),
-        ("🦵🏻", 455),
-        ("🍿", 2649),
-        ("🌊", 2933),
-        ("♻", 3398),
-        ("🙅🏻\u{200d}♂", 705),
-        ("💙", 147),
-        ("🙍🏽", 665),
-        ("📿", 3062),
-        ("📱", 3095),
-        ("🙏🏽", 425),
-        ("🧔🏻\u{200d}♂\u{fe0f}", 543),
-        ("😚", 20),
-        ("🤦🏾\u{200d}♀\u{fe0f}", 822),
-        ("➰", 3409),
-        ("🛷", 2986),
-        ("🫱🏻\u{200d}🫲🏿", 405),
-        ("🚴\u{200d}♀", 1952),
-        ("🥐", 2620),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2229),
-        ("🧎🏽", 1584),
-        ("🧎🏾\u{200d}♂\u{200d}➡", 1615),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2335),
-        ("👩🏽", 581),
-        ("📈", 3187),
-        ("🧚🏼\u{200d}♂\u{fe0f}", 1420),
-        ("🧔🏾\u{200d}♀", 552),
-        ("🦣", 2471),
-        ("🦸🏽\u{200d}♂", 1367),
-        ("🙅🏽", 701),
-        ("🇲🇹", 3670),
-        ("🔏", 3203),
-        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏽", 2196),
-        ("👴🏿", 655),
-        ("🫶", 378),
-        ("🧑🏽\u{200d}🦰", 593),
-        ("🧁", 2682),
-        ("⬜", 3487),
-        ("5\u{20e3}", 3425),
-        ("🥏", 2969),
-        ("🧙", 1394),
-        ("👩🏽\u{200d}🦼", 1680),
-        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2256),
-        ("🧑🏽\u{200d}✈", 1079),
-        ("🛀🏽", 2072),
-        ("👰🏾\u{200d}♂\u{fe0f}", 1284),
-        ("🧑🏼\u{200d}💻", 1024),
-        ("🧝🏿\u{200d}♂\u{fe0f}", 1477),
-        ("👷🏼\u{200d}♂\u{fe0f}", 1198),
-        ("👩🏽\u{200d}🤝\u{200d}👨🏻", 2147),
-        ("🏄🏿\u{200d}♂", 1861),
-        ("❇\u{fe0f}", 3414),
-        ("🧑🏼\u{200d}🚀", 1096),
-        ("🇭🇲", 3612),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2225),
-        ("🏄🏼", 1852),
-        ("💇🏻\u{200d}♀", 1522),
-        ("🦯", 3225),
-        ("🤞🏻", 259),
-        ("🤝🏿", 401),
-        ("🐦\u{200d}🔥", 2515),
-        ("💂🏻\u{200d}♂", 1173),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏻", 2281),
-        ("🧑🏽\u{200d}🎄", 1355),
-        ("🚴🏽", 1943),
-        ("🦦", 2489),
-        ("🤴🏽", 1217),
-        ("🏃🏿\u{200d}♀\u{200d}➡", 1754),
-        ("🌫", 2918),
-        ("👨🏽\u{200d}🔧", 959),
-        ("🙇🏼\u{200d}♀", 802),
-        ("🈸", 3463),
-        ("🤽\u{200d}♂\u{fe0f}", 2003),
-        ("🇲🇺", 3671),
-        ("🍻", 2700),
-        ("⛹🏼\u{200d}♂", 1912),
-        ("↙", 3312),
-        ("👨🏾\u{200d}🏭", 978),
-        ("👨🏽\u{200d}🦰", 557),
-        ("🙎🏿\u{200d}♀\u{fe0f}", 697),
-        ("🏋🏼\u{200d}♂\u{fe0f}", 1930),
-        ("🤧", 62),
-        ("🤽🏾", 2001),
-        ("🧍🏼\u{200d}♂", 1571),
-        ("✍🏾", 432),
-        ("🧑\u{200d}🔧", 950),
-        ("🎰", 2997),
-        ("🧎🏾\u{200d}♀\u{fe0f}\u{200d}➡", 1609),
-        ("👨🏾\u{200d}🦽", 1705),
-        ("👓", 3019),
-        ("👍🏼", 332),
-        ("👩\u{200d}❤\u{fe0f}\u{200d}👨", 2315),
-        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2257),
-        ("🤷🏻\u{200d}♂", 831),
-        ("📫", 3165),
-        ("👦🏾", 510),
-        ("🧎🏻\u{200d}➡\u{fe0f}", 1600),
-        ("🙎\u{200d}♂", 686),
-        ("🆒", 3440),
-        ("🤷🏿\u{200d}♀", 841),
-        ("🤵\u{200d}♂\u{fe0f}", 1262),
-        ("👩🏽\u{200d}🍳", 947),
-        ("🧑🏾\u{200d}⚖\u{fe0f}", 900),
-        ("🙆\u{200d}♂", 722),
-        ("🅾", 3447),
-        ("🤴🏾", 1218),
-        ("🇵🇼", 3702),
-        ("🕵🏿\u{200d}♂", 1159),
-        ("🙉", 127),
-        ("😵", 66),
-        ("🗜\u{fe0f}", 3223),
-        ("👥", 2420),
-        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", 2204),
-        ("🧏🏿\u{200d}♀", 787),
-        ("👐🏼", 386),
-        ("🪇", 3093),
-        ("🧜🏼", 1450),
-        ("👨🏾\u{200d}🎤", 1050),
-        ("🧑\u{200d}⚖\u{fe0f}", 896),
-        ("🗄", 3199),
-        ("👨🏿\u{200d}🦱", 565),
-        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", 2192),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", 2288),
-        ("0\u{fe0f}\u{20e3}", 3420),
-        ("🏃🏼\u{200d}♀", 1739),
-        ("🕷", 2546),
-        ("🙇\u{200d}♀", 800),
-        ("👱🏽\u{200d}♂", 641),
-        ("🧚🏾\u{200d}♂", 1422),
-        ("🤹🏼\u{200d}♂\u{fe0f}", 2041),
-        ("🧘🏾\u{200d}♀", 2067),
-        ("⏩", 3358),
-        ("⚰", 3273),
-        ("🔨", 3207),
-        ("🧍🏿\u{200d}♂", 1574),
-        ("👨🏻\u{200d}❤\u{200d}👨🏾", 2349),
-        ("👨🏻\u{200d}🤝\u{200d}👨🏽", 2166),
-        ("🇨🇼", 3566),
-        ("💆🏽\u{200d}♀", 1506),
-        ("🧔", 536),
-        ("⛅", 2909),
-        ("🧑\u{200d}🦽\u{200d}➡\u{fe0f}", 1695),
-        ("🪈", 3094),
-        ("🇲🇼", 3673),
-        ("👨🏽\u{200d}🍼", 1325),
-        ("👩🏿\u{200d}❤\u{200d}👨🏿", 2340),
-        ("🚪", 3247),
-        ("🇲🇴", 3665),
-        ("🚣\u{200d}♀", 1880),
-        ("🏃🏾\u{200d}♂\u{fe0f}\u{200d}➡", 1759),
-        ("㊙", 3467),
-        ("🤜🏾", 364),
-        ("🐡", 2531),
-        ("🇬🇮", 3599),
-        ("1\u{fe0f}\u{20e3}", 3421),
-        ("💇🏻", 1510),
-        ("🧎🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1607),
-        ("🤵🏿\u{200d}♀", 1273),
-        ("⛹", 1904),
-        ("🐿\u{fe0f}", 2480),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2224),
-        ("👨🏽\u{200d}🏭", 977),
-        ("🫥", 41),
-        ("🏃🏼\u{200d}♀\u{200d}➡\u{fe0f}", 1751),
-        ("🤵🏽", 1259),
-        ("🤩", 16),
-        ("🫃🏻", 1299),
-        ("👨🏻\u{200d}⚕", 849),
-        ("🧛🏽\u{200d}♀", 1445),
-        ("🤦🏼", 808),
-        ("⚠\u{fe0f}", 3294),
-        ("🙎🏻\u{200d}♂\u{fe0f}", 687),
-        ("🧛🏼", 1432),
-        ("⬛", 3486),
-        ("💆🏼\u{200d}♂\u{fe0f}", 1499),
-        ("👨🏾\u{200d}🦽\u{200d}➡\u{fe0f}", 1711),
-        ("👷🏾\u{200d}♀", 1206),
-        ("👯", 1779),
-        ("🤦🏻\u{200d}♀", 819),
-        ("🧆", 2641),
-        ("🎏", 2946),
-        ("💁\u{200d}♂\u{fe0f}", 740),
-        ("🕶\u{fe0f}", 3020),
-        ("👨🏻\u{200d}💼", 993),
-        ("⛲", 2765),
-        ("🧅", 2612),
-        ("🈷", 3455),
-        ("🧑🏽\u{200d}🦽\u{200d}➡", 1698),
-        ("👨🏻\u{200d}✈", 1083),
-        ("⛪", 2759),
-        ("👛", 3040),
-        ("🧑🏾\u{200d}⚕", 846),
-        ("📖", 3133),
-        ("🤾🏾\u{200d}♀\u{fe0f}", 2031),
-        ("⛈\u{fe0f}", 2910),
-        ("🚶🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1559),
-        ("🙎🏾\u{200d}♀", 696),
-        ("🧌", 1490),
-        ("🕵🏿\u{200d}♂\u{fe0f}", 1159),
-        ("🏊🏻\u{200d}♀", 1899),
-        ("🏴\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}", 3772),
-        ("🇮🇪", 3619),
-        ("🍽", 2710),
-        ("🧑🏽", 521),
-        ("🎃", 2934),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2360),
-        ("🖱\u{fe0f}", 3108),
-        ("🛍", 3043),
-        ("🐗", 2461),
-        ("💇🏾\u{200d}♀\u{fe0f}", 1525),
-        ("🏌\u{fe0f}\u{200d}♀\u{fe0f}", 1844),
-        ("😓", 97),
-        ("🧑\u{200d}🦯\u{200d}➡", 1623),
-        ("3\u{fe0f}\u{20e3}", 3423),
-        ("🙆🏿", 721),
-        ("👳\u{200d}♂", 1232),
-        ("🚶🏽\u{200d}♂\u{fe0f}", 1536),
-        ("🧔🏿\u{200d}♂\u{fe0f}", 547),
-        ("↖", 3314),
-        ("💪🏼", 448),
-        ("🧎\u{200d}♂\u{fe0f}", 1587),
-        ("🧎🏻\u{200d}♂\u{fe0f}", 1588),
-        ("⏲\u{fe0f}", 2861),
-        ("🙇🏽", 791),
-        ("🫚", 2616),
-        ("🧑🏿\u{200d}🎤", 1045),
-        ("🤼\u{200d}♀", 1996),
-        ("🧛🏼\u{200d}♀", 1444),
-        ("🧎🏽\u{200d}♀\u{fe0f}\u{200d}➡", 1608),
-        ("🔹", 3497),
-        ("🤦🏽\u{200d}♀", 821),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2234),
-        ("🎡", 2778),
-        ("🍙", 2655),
-        ("🏄🏻\u{200d}♀\u{fe0f}", 1863),
-        ("🇪🇦", 3577),
-        ("⛑", 3061),
-        ("🧜🏿\u{200d}♂", 1459),
-        ("🧘🏼\u{200d}♀", 2065),
-        ("👮🏼", 1132),
-        ("👭🏻", 2108),
-        ("🙍🏿\u{200d}♀", 679),
-        ("🙇🏻\u{200d}♂", 795),
-        ("🌈", 2921),
-        ("👩🏾\u{200d}🏭", 984),
-        ("😝", 27),
-        ("🧑🏽\u{200d}⚕\u{fe0f}", 845),
-        ("👩🏼\u{200d}🦰", 586),
-        ("👨🏻\u{200d}🤝\u{200d}👨🏾", 2167),
-        ("🤕", 59),
-        ("👨🏽\u{200d}🤝\u{200d}👨🏻", 2173),
-        ("🧫", 3235),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2243),
-        ("🙋🏼\u{200d}♂", 760),
-        ("👨🏼\u{200d}🦽\u{200d}➡", 1709),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏾", 2283),
-        ("🧑🏼\u{200d}⚖", 898),
-        ("⛈", 2910),
-        ("☑\u{fe0f}", 3405),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏼", 2373),
-        ("🏊🏽\u{200d}♂", 1895),
-        ("🧔🏼\u{200d}♀\u{fe0f}", 550),
-        ("👮🏾\u{200d}♂\u{fe0f}", 1140),
-        ("👨🏿\u{200d}🦼\u{200d}➡", 1676),
-        ("🏃🏿\u{200d}♀\u{fe0f}\u{200d}➡", 1754),
-        ("👩\u{200d}⚕", 854),
-        ("🥇", 2958),
-        ("👩\u{200d}👩\u{200d}👦", 2403),
-        ("🤽🏿\u{200d}♂\u{fe0f}", 2008),
-        ("🧖🏾", 1786),
-        ("🗓\u{fe0f}", 3185),
-        ("🩰", 3052),
-        ("🧝🏿", 1471),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏼", 2382),
-        ("🇬🇳", 3602),
-        ("🏃🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1752),
-        ("🧘🏽\u{200d}♀", 2066),
-        ("🙂\u{200d}↔\u{fe0f}", 50),
-        ("🏗", 2734),
-        ("🧑\u{200d}⚕", 842),
-        ("🧥", 3029),
-        ("🪼", 2536),
-        ("🙇🏽\u{200d}♂\u{fe0f}", 797),
-        ("🦵🏼", 456),
-        ("💁🏻\u{200d}♂\u{fe0f}", 741),
-        ("🏊🏻\u{200d}♀\u{fe0f}", 1899),
-        ("🧎🏾\u{200d}♂\u{fe0f}", 1591),
-        ("💇🏽", 1512),
-        ("🧑🏿\u{200d}❤\u{200d}🧑🏾", 2314),
-        ("🧗🏻\u{200d}♀", 1813),
-        ("🫄", 1304),
-        ("🏋🏻\u{200d}♂\u{fe0f}", 1929),
-        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2255),
-        ("🚴🏾", 1944),
-        ("🏃🏾\u{200d}♀\u{fe0f}", 1741),
-        ("🧕🏾", 1254),
-        ("🌂", 2922),
-        ("🇻🇳", 3760),
-        ("🧎\u{200d}➡\u{fe0f}", 1599),
-        ("💻", 3104),
-        ("🧘🏼\u{200d}♂", 2059),
-        ("🧜🏻\u{200d}♂\u{fe0f}", 1455),
-        ("🚶🏾\u{200d}♀", 1543),
-        ("🛢\u{fe0f}", 2824),
-        ("🧰", 3230),
-        ("🤦🏾\u{200d}♂", 816),
-        ("🏋\u{fe0f}\u{200d}♀\u{fe0f}", 1934),
-        ("👱🏼\u{200d}♂", 640),
-        ("👩🏼\u{200d}❤\u{200d}👩🏽", 2378),
-        ("🔓", 3202),
-        ("🗽", 2758),
-        ("🧞", 1484),
-        ("🧑🏿\u{200d}🤝\u{200d}🧑🏼", 2104),
-        ("🥳", 70),
-        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏼", 2208),
-        ("🇵🇱", 3696),
-        ("🛄", 3292),
-        ("🇫🇰", 3588),
-        ("💁🏿\u{200d}♂", 745),
-        ("🏚\u{fe0f}", 2740),
-        ("👇🏻", 313),
-        ("🐁", 2475),
-        ("🤰", 1292),
-        ("🧘🏻", 2052),
-        ("🏊🏼\u{200d}♂", 1894),
-        ("➖", 3384),
-        ("🧑\u{200d}🦯", 1617),
-        ("🧒", 500),
-        ("👮🏾", 1134),
-        ("💆🏼\u{200d}♀\u{fe0f}", 1505),
-        ("🫱🏾\u{200d}🫲🏿", 417),
-        ("🔒", 3201),
-        ("👩\u{200d}🦼", 1677),
-        ("🏃🏾\u{200d}♂\u{fe0f}", 1735),
-        ("🫦", 493),
-        ("👩🏼\u{200d}🌾", 928),
-        ("👝", 3042),
-        ("🖨\u{fe0f}", 3106),
-        ("🐀", 2476),
-        ("🟣", 3475),
-        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏼", 2304),
-        ("👩🏽\u{200d}❤\u{200d}👩🏽", 2382),
-        ("🕴🏼", 1775),
-        ("🧝🏿\u{200d}♂", 1477),
-        ("🧕🏿", 1255),
-        ("👨🏽\u{200d}🚒", 1121),
-        ("🚴🏾\u{200d}♀", 1956),
-        ("🏊🏿", 1891),
-        ("🙃", 9),
-        ("🫱🏽\u{200d}🫲🏿", 413),
-        ("🇬🇪", 3595),
-        ("🧑\u{200d}🎨", 1058),
-        ("🧖🏼", 1784),
-        ("🏌🏿\u{200d}♂", 1843),
-        ("🧖🏽\u{200d}♂\u{fe0f}", 1791),
-        ("🏋\u{200d}♀\u{fe0f}", 1934),
-        ("🤌🏼", 242),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2227),
-        ("🧑\u{200d}🍳", 932),
-        ("🇺🇲", 3750),
-        ("🧑🏽\u{200d}🔬", 1007),
-        ("🌞", 2902),
-        ("🧑🏾\u{200d}🍳", 936),
-        ("👨🏻\u{200d}🦼\u{200d}➡\u{fe0f}", 1672),
-        ("☂\u{fe0f}", 2923),
-        ("🌙", 2895),
-        ("🖐\u{fe0f}", 180),
-        ("🧑🏻\u{200d}🦰", 591),
-        ("🪧", 3279),
-        ("🧜🏾\u{200d}♀\u{fe0f}", 1464),
-        ("🦵🏽", 457),
-        ("🖕🏻", 307),
-        ("👩🏻\u{200d}🦯\u{200d}➡\u{fe0f}", 1648),
-        ("🤽🏿\u{200d}♂", 2008),
-        ("🧖🏿\u{200d}♂\u{fe0f}", 1793),
-        ("🥞", 2625),
-        ("🙎\u{200d}♀\u{fe0f}", 692),
-        ("🇸🇿", 3730),
-        ("🤶🏼", 1348),
-        ("🪜", 3232),
-        ("✌", 252),
-        ("👩🏿\u{200d}❤\u{200d}👩🏿", 2392),
-        ("📮", 3169),
-        ("🫘", 2614),
-        ("👩🏾\u{200d}🤝\u{200d}👩🏽", 2127),
-        ("💇🏼\u{200d}♀", 1523),
-        ("🧎\u{200d}♀\u{200d}➡", 1605),
-        ("🏋\u{200d}♂", 1928),
-        ("💬", 162),
-        ("🦂", 2548),
-        ("0\u{20e3}", 3420),
-        ("🏊\u{200d}♂\u{fe0f}", 1892),
-        ("⌛", 2856),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏼", 2278),
-        ("🧙🏾", 1398),
-        ("🦚", 2510),
-        ("😾", 125),
-        ("🧕🏻", 1251),
-        ("🤹🏾\u{200d}♀\u{fe0f}", 2049),
-        ("🏌🏽", 1835),
-        ("🤦🏾", 810),
-        ("🤦🏿", 811),
-        ("🧞\u{200d}♀\u{fe0f}", 1486),
-        ("💃🏻", 1762),
-        ("🕵🏿\u{200d}♀\u{fe0f}", 1165),
-        ("🥦", 2610),
-        ("☑", 3405),
-        ("💗", 133),
-        ("🗓", 3185),
-        ("🧑🏾\u{200d}🤝\u{200d}🧑🏻", 2099),
-        ("🌹", 2558),
-        ("🤾🏽", 2018),
-        ("👨🏾\u{200d}🌾", 924),
-        ("🤚🏼", 176),
-        ("🦃", 2494),
-        ("👩🏼\u{200d}🤝\u{200d}👩🏾", 2119),
-        ("🧨", 2938),
-        ("🙅🏾", 702),
-        ("👩🏼\u{200d}⚕\u{fe0f}", 856),
-        ("🧝🏾\u{200d}♀\u{fe0f}", 1482),
-        ("🫱🏿\u{200d}🫲🏽", 420),
-        ("👩🏾\u{200d}🤝\u{200d}👩🏻", 2125),
-        ("🚶\u{200d}♀\u{200d}➡\u{fe0f}", 1551),
-        ("🤽🏽\u{200d}♀\u{fe0f}", 2012),
-        ("🧔🏽\u{200d}♂", 545),
-        ("🏳\u{fe0f}\u{200d}⚧", 3510),
-        ("🙋🏽", 755),
-        ("🤰🏿", 1297),
-        ("👨\u{200d}🦲", 572),
-        ("🈯", 3457),
-        ("🚣🏾\u{200d}♀\u{fe0f}", 1884),
-        ("🇧🇩", 3531),
-        ("🤾🏽\u{200d}♂", 2024),
-        ("🕵🏻\u{200d}♂\u{fe0f}", 1155),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2212),
-        ("🕵\u{200d}♀", 1160),
-        ("👩🏻\u{200d}🚒", 1125),
-        ("👩🏻\u{200d}🤝\u{200d}👩🏽", 2114),
-        ("🚶🏿\u{200d}♀", 1544),
-        ("🇯🇪", 3628),
-        ("🫅🏽", 1211),
-        ("🧑🏼\u{200d}🚒", 1114),
-        ("💇\u{200d}♂", 1515),
-        ("🖖🏻", 193),
-        ("🍼", 2689),
-        ("🚣🏻\u{200d}♂", 1875),
-        ("🤙🏿", 287),
-        ("◻\u{fe0f}", 3489),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", 2283),
-        ("🕕", 2875),
-        ("🤱🏿", 1315),
-        ("👨🏼\u{200d}🤝\u{200d}👨🏻", 2169),
-        ("🔠", 3431),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2351),
-        ("🧔🏿", 541),
-        ("🕺🏻", 1768),
-        ("🦸🏼\u{200d}♂\u{fe0f}", 1366),
-        ("🤚🏿", 179),
-        ("🌳", 2568),
-        ("🛟", 2833),
-        ("⛄", 2929),
-        ("🚔", 2801),
-        ("☝🏽", 321),
-        ("🧝🏼\u{200d}♂\u{fe0f}", 1474),
-        ("🧜🏽\u{200d}♂", 1457),
-        ("👩🏾\u{200d}🦼", 1681),
-        ("🧙🏽", 1397),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2354),
-        ("🧎🏽\u{200d}♀\u{200d}➡", 1608),
-        ("🫎", 2448),
-        ("🙋\u{200d}♀", 764),
-        ("🇱🇦", 3643),
-        ("📉", 3188),
-        ("🫃", 1298),
-        ("👩🏼\u{200d}✈", 1090),
-        ("👱🏿", 529),
-        ("🧖🏾\u{200d}♀", 1798),
-        ("🏃🏼\u{200d}♂\u{200d}➡\u{fe0f}", 1757),
-        ("🕊", 2502),
-        ("⛩", 2763),
-        ("👨🏻\u{200d}❤\u{200d}👨🏿", 2350),
-        ("👏🏽", 369),
-        ("🧖🏾\u{200d}♂", 1792),
-        ("🧑🏾\u{200d}🦳", 618),
-        ("🇹🇯", 3737),
-        ("👩🏾\u{200d}❤\u{200d}👩🏾", 2387),
-        ("🧙🏽\u{200d}♀\u{fe0f}", 1409),
-        ("👩🏻\u{200d}❤\u{200d}👨🏽", 2322),
-        ("🇷🇼", 3709),
-        ("😭", 92),
-        ("👮🏼\u{200d}♂", 1138),
-        ("🧑🏻\u{200d}🤝\u{200d}🧑🏼", 2087),
-        ("⛓\u{fe0f}", 3228),
-        ("🧏🏻\u{200d}♀\u{fe0f}", 783),
-        ("🚣🏽", 1871),
-        ("🦹\u{200d}♂", 1382),
-        ("👭🏼", 2109),
-        ("🧑🏽\u{200d}🦯\u{200d}➡", 1626),
-        ("👨🏼\u{200d}💼", 994),
-        ("👦🏽", 509),
-        ("👨\u{200d}👩\u{200d}👦\u{200d}👦", 2396),
-        ("〽", 3411),
-        ("🛀🏻", 2070),
-        ("👩🏽\u{200d}❤\u{200d}👩🏿", 2384),
-        ("🧑🏿\u{200d}🤝\u{200d}🧑🏽", 2105),
-        ("🧔🏿\u{200d}♂", 547),
-        ("🈲", 3461),
-        ("🙏🏼", 424),
-        ("🧎🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1608),
-        ("👩🏼\u{200d}❤\u{200d}👨🏻", 2325),
-        ("🗻", 2726),
-        ("👧🏾", 516),
-        ("👨🏿\u{200d}🦼\u{200d}➡\u{fe0f}", 1676),
-        ("🧑🏿\u{200d}🔬", 1009),
-        ("👶🏻", 495),
-        ("🔩", 3221),
-        ("🇫🇲", 3589),
-        ("📝", 3177),
-        ("🥽", 3021),
-        ("😩", 98),
-        ("🇧🇲", 3539),
-        ("🧑🏿", 523),
-        ("🖖🏾", 196),
-        ("🫃🏿", 1303),
-        ("🇵🇷", 3699),
-        ("⏏", 3371),
-        ("🦹🏽\u{200d}♀\u{fe0f}", 1391),
-        ("🥮", 2665),
-        ("🧖", 1782),
-        ("🙌", 372),
-        ("🧔🏻", 537),
-        ("👨🏽", 533),
-        ("🧑🏿\u{200d}🎨", 1063),
-        ("🧕🏽", 1253),
-        ("🚣🏾\u{200d}♂\u{fe0f}", 1878),
-        ("👩\u{200d}🎨", 1070),
-        ("🏊🏼\u{200d}♀", 1900),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", 2275),
-        ("🧑🏿\u{200d}🌾", 919),
-        ("✋🏽", 189),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", 2268),
-        ("🧔🏾\u{200d}♀\u{fe0f}", 552),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2236),
-        ("🪩", 3002),
-        ("👆🏾", 304),
-        ("🤽🏽\u{200d}♂", 2006),
-        ("🦶🏻", 461),
-        ("⚙\u{fe0f}", 3222),
-        ("🚶\u{200d}♂\u{fe0f}\u{200d}➡", 1557),
-        ("📓", 3138),
-        ("🍹", 2698),
-        ("👍🏻", 331),
-        ("💁🏼\u{200d}♀", 748),
-        ("🇩🇯", 3572),
-        ("📇", 3186),
-        ("😲", 82),
-        ("🍃", 2577),
-        ("🌤", 2911),
-        ("🙅🏾\u{200d}♂\u{fe0f}", 708),
-        ("🌵", 2570),
-        ("👰🏽", 1277),
-        ("👨🏼\u{200d}🦼\u{200d}➡\u{fe0f}", 1673),
-        ("🧘🏽\u{200d}♂\u{fe0f}", 2060),
-        ("✴", 3413),
-        ("👱🏿\u{200d}♂\u{fe0f}", 643),
-        ("⛹🏿\u{200d}♀\u{fe0f}", 1921),
-        ("⛓\u{200d}💥", 3227),
-        ("💠", 3500),
-        ("🏳\u{200d}🌈", 3509),
-        ("✴\u{fe0f}", 3413),
-        ("🖤", 151),
-        ("🐅", 2445),
-        ("🦵🏿", 459),
-        ("🤛🏾", 358),
-        ("🤚", 174),
-        ("👮🏿\u{200d}♀\u{fe0f}", 1147),
-        ("🚁", 2847),
-        ("🫄🏾", 1308),
-        ("👩🏽\u{200d}🚀", 1109),
-        ("👱🏼", 526),
-        ("❔", 3391),
-        ("🦹🏿\u{200d}♀", 1393),
-        ("🙇🏿\u{200d}♀", 805),
-        ("🚶🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1558),
-        ("⚽", 2961),
-        ("🧖\u{200d}♂", 1788),
-        ("🚊", 2790),
-        ("☺\u{fe0f}", 19),
-        ("🍯", 2688),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", 2271),
-        ("🦸\u{200d}♂\u{fe0f}", 1364),
-        ("💇🏻\u{200d}♂", 1516),
-        ("🐐", 2465),
-        ("🧑🏽\u{200d}🤝\u{200d}🧑🏻", 2095),
-        ("🆕", 3445),
-        ("👱🏻\u{200d}♀\u{fe0f}", 633),
-        ("🗣\u{fe0f}", 2418),
-        ("👷\u{200d}♀\u{fe0f}", 1202),
-        ("👨🏽\u{200d}✈\u{fe0f}", 1085),
-        ("🧝🏿\u{200d}♀\u{fe0f}", 1483),
-        ("🙅🏽\u{200d}♂", 707),
-        ("📒", 3139),
-        ("👍", 330),
-        ("😵\u{200d}💫", 67),
-        ("🕧", 2864),
-        ("‼", 3388),
-        ("🗝\u{fe0f}", 3206),
-        ("🌓", 2889),
-        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2247),
-        ("🧑🏽\u{200d}⚕", 845),
-        ("🤝", 396),
-        ("🧙🏽\u{200d}♂", 1403),
-        ("🧑🏽\u{200d}🤝\u{200d}🧑🏼", 2096),
-        ("🫰🏽", 267),
-        ("🧛🏾\u{200d}♂\u{fe0f}", 1440),
-        ("🤾🏼\u{200d}♀", 2029),
-        ("👩\u{200d}🌾", 926),
-        ("💆🏾\u{200d}♂", 1501),
-        ("👮🏻\u{200d}♀\u{fe0f}", 1143),
-        ("👨🏿\u{200d}🦯", 1634),
-        ("🏊", 1886),
-        ("😧", 87),
-        ("🧚🏼\u{200d}♀\u{fe0f}", 1426),
-        ("🙇🏼\u{200d}♂", 796),
-        ("🇻🇨", 3756),
-        ("🏋\u{fe0f}", 1922),
-        ("🤷🏽\u{200d}♀\u{fe0f}", 839),
-        ("🌎", 2717),
-        ("👨\u{200d}⚕", 848),
-        ("🪮", 3054),
-        ("🎅🏻", 1341),
-        ("🧎\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1611),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2215),
-        ("🖖", 192),
-        ("🌅", 2771),
-        ("👨🏿\u{200d}❤\u{200d}👨🏽", 2365),
-        ("🤸🏼", 1978),
-        ("🚼", 3288),
-        ("👨🏽\u{200d}🦼\u{200d}➡\u{fe0f}", 1674),
-        ("💪🏻", 447),
-        ("👀", 489),
-        ("🔺", 3498),
-        ("♍", 3346),
-        ("🈺", 3468),
-        ("🙌🏼", 374),
-        ("🧑🏼\u{200d}❤\u{200d}🧑🏻", 2299),
-        ("👊🏼", 350),
-        ("🙋🏾\u{200d}♂\u{fe0f}", 762),
-        ("💂🏻\u{200d}♂\u{fe0f}", 1173),
-        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏾", 2201),
-        ("🧜🏿", 1453),
-        ("👩🏼\u{200d}🚀", 1108),
-        ("🚴", 1940),
-        ("🧘\u{200d}♂", 2057),
-        ("🤸\u{200d}♂\u{fe0f}", 1982),
-        ("🫵🏻", 325),
-        ("👨🏽\u{200d}⚕\u{fe0f}", 851),
-        ("🏌🏼\u{200d}♀\u{fe0f}", 1846),
-        ("🇧🇻", 3546),
-        ("👻", 113),
-        ("🦸🏻", 1359),
-        ("🐿", 2480),
-        ("🫱🏻\u{200d}🫲🏼", 402),
-        ("🧑\u{200d}🚀", 1094),
-        ("🤹🏻\u{200d}♀\u{fe0f}", 2046),
-        ("👩\u{200d}🏫", 890),
-        ("📕", 3132),
-        ("🦎", 2519),
-        ("🧑🏼\u{200d}🍳", 934),
-        ("🔎", 3125),
-        ("👩🏾\u{200d}❤\u{200d}👨🏿", 2336),
-        ("🏷\u{fe0f}", 3147),
-        ("🧏🏾\u{200d}♀\u{fe0f}", 786),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏽", 2278),
-        ("🫴🏼", 218),
-        ("🐴", 2447),
-        ("🚶🏼\u{200d}♀\u{200d}➡\u{fe0f}", 1553),
-        ("🇹🇨", 3732),
-        ("✊🏾", 346),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏿", 2376),
-        ("◻", 3489),
-        ("👋🏾", 172),
-        ("🪟", 3250),
-        ("🕵\u{fe0f}\u{200d}♀", 1160),
-        ("🤛🏼", 356),
-        ("👨\u{200d}✈\u{fe0f}", 1082),
-        ("⛹\u{fe0f}\u{200d}♂\u{fe0f}", 1910),
-        ("🇵🇭", 3694),
-        ("🕴🏻", 1774),
-        ("🏌🏿\u{200d}♂\u{fe0f}", 1843),
-        ("🇮🇹", 3627),
-        ("🙋🏽\u{200d}♀\u{fe0f}", 767),
-        ("⛹🏿\u{200d}♀", 1921),
-        ("🏄🏽\u{200d}♀\u{fe0f}", 1865),
-        ("👉", 294),
-        ("🧘\u{200d}♀\u{fe0f}", 2063),
-        ("🧎🏿\u{200d}♂\u{fe0f}", 1592),
-        ("👨🏼\u{200d}⚖", 904),
-        ("✊🏻", 343),
-        ("🐟", 2529),
-        ("🧾", 3156),
-        ("👷🏾\u{200d}♂", 1200),
-        ("🧎🏿\u{200d}➡\u{fe0f}", 1604),
-        ("👩🏻\u{200d}✈\u{fe0f}", 1089),
-        ("🧑🏿\u{200d}🤝\u{200d}🧑🏿", 2086),
-        ("#\u{fe0f}\u{20e3}", 3418),
-        ("🧑🏽\u{200d}💼", 989),
-        ("🧑🏼\u{200d}🦼", 1655),
-        ("🧔🏻\u{200d}♂", 543),
-        ("🤦🏿\u{200d}♂", 817),
-        ("💂🏻", 1167),
-        ("🌄", 2770),
-        ("👩🏻", 579),
-        ("🤜", 360),
-        ("✂", 3197),
-        ("🕺🏿", 1772),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2259),
-        ("🙋🏾\u{200d}♀", 768),
-        ("⛹\u{fe0f}\u{200d}♂", 1910),
-        ("👳🏾\u{200d}♀", 1242),
-        ("👱🏻\u{200d}♂", 639),
-        ("👨\u{200d}🦯\u{200d}➡", 1635),
-        ("👩🏻\u{200d}🦽\u{200d}➡", 1720),
-        ("🧛🏽\u{200d}♀\u{fe0f}", 1445),
-        ("👩🏿\u{200d}🌾", 931),
-        ("👰🏻\u{200d}♀\u{fe0f}", 1287),
-        ("🧗🏽", 1803),
-        ("💁🏿\u{200d}♀\u{fe0f}", 751),
-        ("🗑\u{fe0f}", 3200),
-        ("↩\u{fe0f}", 3317),
-        ("👨🏼\u{200d}🦽\u{200d}➡\u{fe0f}", 1709),
-        ("🧖🏿\u{200d}♂", 1793),
-        ("👰🏿\u{200d}♂\u{fe0f}", 1285),
-        ("🫵🏼", 326),
-        ("🫲🏿", 209),
-        ("🏄🏽", 1853),
-        ("🇷🇸", 3707),
-        ("🔥", 2931),
-        ("🧚🏼\u{200d}♀", 1426),
-        ("↗", 3308),
-        ("🧖🏻", 1783),
-        ("🍡", 2666),
-        ("🛜", 3376),
-        ("👩🏽\u{200d}❤\u{200d}👨🏾", 2331),
-        ("👑", 3055),
-        ("🚶\u{200d}♀\u{200d}➡", 1551),
-        ("♐", 3349),
-        ("🏃🏾\u{200d}♂", 1735),
-        ("🧎🏽\u{200d}♀", 1596),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏾", 2371),
-        ("🌐", 2719),
-        ("✌🏾", 256),
-        ("🐬", 2527),
-        ("☮", 3337),
-        ("🤱", 1310),
-        ("🙆🏼\u{200d}♂", 724),
-        ("🏋🏿\u{200d}♀", 1939),
-        ("☀", 2900),
-        ("👨🏽\u{200d}🎓", 869),
-        ("👨🏾\u{200d}✈", 1086),
-        ("🚰", 3283),
-        ("🐰", 2478),
-        ("🗂\u{fe0f}", 3181),
-        ("🦗", 2544),
-        ("🦵", 454),
-        ("🤵\u{200d}♂", 1262),
-        ("🏀", 2964),
-        ("⚪", 3478),
-        ("🏌🏽\u{200d}♂", 1841),
-        ("👨\u{200d}🎓", 866),
-        ("🧏🏾\u{200d}♀", 786),
-        ("💁\u{200d}♂", 740),
-        ("🎓", 3058),
-        ("💫", 158),
-        ("🐉", 2522),
-        ("⚒", 3210),
-        ("⚙", 3222),
-        ("🪙", 3149),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏿", 2284),
-        ("🚶🏼\u{200d}♂\u{200d}➡", 1559),
-        ("🦸🏻\u{200d}♂", 1365),
-        ("🧝🏼\u{200d}♀", 1480),
-        ("💿", 3112),
-        ("👩🏼\u{200d}⚖", 910),
-        ("⛹🏾", 1908),
-        ("🧘🏿\u{200d}♂", 2062),
-        ("👰\u{200d}♀", 1286),
-        ("👬🏿", 2164),
-        ("🤸🏻\u{200d}♀", 1989),
-        ("👨🏼\u{200d}🍳", 940),
-        ("🚶\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1551),
-        ("🔶", 3494),
-        ("👩🏿\u{200d}🤝\u{200d}👩🏾", 2132),
-        ("🏊\u{200d}♂", 1892),
-        ("👩\u{200d}🦲", 620),
-        ("🏴\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", 3771),
-        ("🐑", 2464),
-        ("👨🏾\u{200d}⚕\u{fe0f}", 852),
-        ("🇧🇫", 3533),
-        ("⛹🏿\u{200d}♂", 1915),
-        ("♠", 3004),
-        ("🤓", 73),
-        ("🧙🏿\u{200d}♂", 1405),
-        ("🧎🏿\u{200d}♂\u{200d}➡", 1616),
-        ("🤌🏾", 244),
-        ("☄\u{fe0f}", 2930),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", 2280),
-        ("🦹🏾", 1380),
-        ("👩🏽\u{200d}🏭", 983),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", 2273),
-        ("👩🏽\u{200d}❤\u{200d}👩🏾", 2383),
-        ("🏄🏻\u{200d}♂", 1857),
-        ("🏃\u{200d}♂\u{fe0f}", 1731),
-        ("👳🏽\u{200d}♀", 1241),
-        ("🤹🏼", 2035),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2260),
-        ("👨🏻\u{200d}⚖\u{fe0f}", 903),
-        ("🫲", 204),
-        ("🧑🏿\u{200d}⚕\u{fe0f}", 847),
-        ("🌨\u{fe0f}", 2915),
-        ("🎙", 3078),
-        ("🕵🏽", 1151),
-        ("🙇🏽\u{200d}♀\u{fe0f}", 803),
-        ("🙎🏿\u{200d}♂", 691),
-        ("👩\u{200d}🍳", 944),
-        ("🧑🏻\u{200d}💼", 987),
-        ("🧎🏼\u{200d}♂\u{200d}➡", 1613),
-        ("🧒🏾", 504),
-        ("🪂", 2845),
-        ("🧑🏻\u{200d}🔧", 951),
-        ("⛰", 2724),
-        ("👨🏻\u{200d}💻", 1029),
-        ("💃🏿", 1766),
-        ("🏄🏽\u{200d}♀", 1865),
-        ("💽", 3110),
-        ("🇳🇺", 3687),
-        ("🧑🏽\u{200d}🦯", 1620),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2214),
-        ("🤛🏻", 355),
-        ("㊗", 3466),
-        ("🙅🏼", 700),
-        ("✒\u{fe0f}", 3172),
-        ("🕵🏾\u{200d}♀", 1164),
-        ("🧙🏼\u{200d}♀\u{fe0f}", 1408),
-        ("🇸🇹", 3726),
-        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", 2202),
-        ("🚣\u{200d}♂\u{fe0f}", 1874),
-        ("💆🏻\u{200d}♀", 1504),
-        ("🛥", 2839),
-        ("🧚\u{200d}♀", 1424),
-        ("↪", 3318),
-        ("◼\u{fe0f}", 3488),
-        ("🏇", 1819),
-        ("📧", 3159),
-        ("👨🏿\u{200d}⚖", 907),
-        ("🙋🏿", 757),
-        ("🧠", 484),
-        ("💃", 1761),
-        ("🚵", 1958),
-        ("👨🏿\u{200d}🎓", 871),
-        ("☸\u{fe0f}", 3332),
-        ("🤜🏻", 361),
-        ("👩\u{200d}👩\u{200d}👧\u{200d}👧", 2407),
-        ("💅🏾", 438),
-        ("👩🏿\u{200d}🎓", 877),
-        ("👊🏽", 351),
-        ("🧜🏻\u{200d}♀\u{fe0f}", 1461),
-        ("👳\u{200d}♀", 1238),
-        ("👨🏽\u{200d}🦳", 569),
-        ("🇰🇾", 3641),
-        ("⏺", 3370),
-        ("💆🏽\u{200d}♂", 1500),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2349),
-        ("🤸🏾", 1980),
-        ("🤲🏿", 395),
-        ("🔝", 3327),
-        ("👏🏼", 368),
-        ("🍴", 2711),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", 2278),
-        ("🚶🏿\u{200d}♂", 1538),
-        ("☺", 19),
-        ("👨🏾\u{200d}💼", 996),
-        ("👔", 3024),
-        ("👩🏻\u{200d}❤\u{200d}👩🏿", 2376),
-        ("🫱🏿\u{200d}🫲🏻", 418),
-        ("👨🏼\u{200d}🍼", 1324),
-        ("🤷🏾", 828),
-        ("🧚🏼\u{200d}♂", 1420),
-        ("🙇🏿", 793),
-        ("🫱🏾", 202),
-        ("🙎\u{200d}♀", 692),
-        ("✊", 342),
-        ("🇪🇬", 3580),
-        ("👩🏽\u{200d}🤝\u{200d}👩🏿", 2124),
-        ("🌫\u{fe0f}", 2918),
-        ("💁", 734),
-        ("👩\u{200d}👧\u{200d}👦", 2416),
-        ("🕛", 2863),
-        ("🧔🏼\u{200d}♂", 544),
-        ("👬🏼", 2161),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", 2269),
-        ("🧚🏻\u{200d}♀", 1425),
-        ("👱🏼\u{200d}♂\u{fe0f}", 640),
-        ("🕵🏻\u{200d}♀", 1161),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", 2281),
-        ("🚶\u{200d}♀\u{fe0f}", 1539),
-        ("🙎🏿", 685),
-        ("🔮", 2993),
-        ("💰", 3148),
-        ("🙆🏾\u{200d}♀\u{fe0f}", 732),
-        ("🧖🏻\u{200d}♂\u{fe0f}", 1789),
-        ("🫱🏿\u{200d}🫲🏼", 419),
-        ("💊", 3242),
-        ("🧛\u{200d}♀", 1442),
-        ("🏃🏼\u{200d}♀\u{fe0f}", 1739),
-        ("🏌\u{200d}♂", 1838),
-        ("👮🏿\u{200d}♂", 1141),
-        ("👷🏽\u{200d}♀", 1205),
-        ("🔟", 3430),
-        ("🦅", 2503),
-        ("🏃\u{200d}♀\u{200d}➡\u{fe0f}", 1749),
-        ("🍉", 2583),
-        ("💋", 154),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2353),
-        ("⛹🏽\u{200d}♂\u{fe0f}", 1913),
-        ("⛹🏿", 1909),
-        ("👋🏽", 171),
-        ("🕵🏼", 1150),
-        ("🟥", 3479),
-        ("👷🏿\u{200d}♂", 1201),
-        ("🏃🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1760),
-        ("📵", 3303),
-        ("✍🏼", 430),
-        ("🤽🏻\u{200d}♀", 2010),
-        ("👩🏽\u{200d}🦼\u{200d}➡\u{fe0f}", 1686),
-        ("🚵🏼\u{200d}♂", 1966),
-        ("🥫", 2652),
-        ("😊", 12),
-        ("👩🏽\u{200d}🏫", 893),
-        ("👷🏼\u{200d}♀", 1204),
-        ("⏪", 3362),
-        ("🅿\u{fe0f}", 3449),
-        ("⛺", 2766),
-        ("🇸🇲", 3721),
-        ("🤹\u{200d}♂\u{fe0f}", 2039),
-        ("🇮🇲", 3621),
-        ("🧑\u{200d}🦽\u{200d}➡", 1695),
-        ("🇮🇩", 3618),
-        ("🤖", 116),
-        ("🍞", 2619),
-        ("🖍\u{fe0f}", 3176),
-        ("🧃", 2706),
-        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏼", 2204),
-        ("👱🏾\u{200d}♂\u{fe0f}", 642),
-        ("🏃🏽\u{200d}♀\u{fe0f}\u{200d}➡", 1752),
-        ("🕵🏻\u{200d}♀\u{fe0f}", 1161),
-        ("🟤", 3476),
-        ("🚄", 2784),
-        ("🧜🏿\u{200d}♂\u{fe0f}", 1459),
-        ("🦸🏽", 1361),
-        ("💁🏾\u{200d}♀", 750),
-        ("🧝🏻\u{200d}♂\u{fe0f}", 1473),
-        ("🐦", 2500),
-        ("🧝🏼\u{200d}♀\u{fe0f}", 1480),
-        ("🤷🏼\u{200d}♂", 832),
-        ("👩🏾\u{200d}🤝\u{200d}👨🏼", 2152),
-        ("👨🏻\u{200d}🍳", 939),
-        ("🌃", 2768),
-        ("♨", 2775),
-        ("🧶", 3017),
-        ("🏮", 3129),
-        ("🧗🏽\u{200d}♀", 1815),
-        ("🪵", 2737),
-        ("🪷", 2556),
-        ("👩🏾\u{200d}❤\u{200d}👩🏼", 2386),
-        ("🧖🏻\u{200d}♀", 1795),
-        ("🌜", 2898),
-        ("👉🏾", 298),
-        ("🏋🏿\u{200d}♂", 1933),
-        ("🤸🏼\u{200d}♂", 1984),
-        ("🙍🏻\u{200d}♀", 675),
-        ("👩🏽\u{200d}🍼", 1319),
-        ("🧎🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1614),
-        ("🦬", 2454),
-        ("📻", 3083),
-        ("🚵🏿", 1963),
-        ("🧑🏽\u{200d}🎤", 1043),
-        ("👩🏼\u{200d}❤\u{200d}👨🏼", 2325),
-        ("🎅", 1340),
-        ("♎", 3347),
-        ("👮🏼\u{200d}♀", 1144),
-        ("🧑🏼\u{200d}🤝\u{200d}🧑🏿", 2094),
-        ("🧗🏼\u{200d}♂\u{fe0f}", 1808),
-        ("🇮🇶", 3624),
-        ("💂🏽\u{200d}♀", 1181),
-        ("🧎🏽\u{200d}♀\u{fe0f}", 1596),
-        ("👩🏼\u{200d}🎓", 874),
-        ("👳🏾", 1230),
-        ("🐖", 2460),
-        ("🐙", 2533),
-        ("🤦🏾\u{200d}♀", 822),
-        ("🇲🇶", 3667),
-        ("🔲", 3503),
-        ("🤘🏻", 277),
-        ("✌\u{fe0f}", 252),
-        ("🧍🏾", 1567),
-        ("👋🏻", 169),
-        ("💆🏻", 1492),
-        ("🤲", 390),
-        ("🦮", 2434),
-        ("🤱🏻", 1311),
-        ("👬🏻", 2160),
-        ("🧎\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1605),
-        ("🦹", 1376),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2321),
-        ("🎗\u{fe0f}", 2952),
-        ("👩🏾\u{200d}🦳", 612),
-        ("🇭🇳", 3613),
-        ("🧔\u{200d}♀\u{fe0f}", 548),
-        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", 2197),
-        ("🤷\u{200d}♀\u{fe0f}", 836),
-        ("🕵🏻", 1149),
-        ("🚶🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1555),
-        ("👩🏾\u{200d}⚖", 912),
-        ("👷🏽\u{200d}♀\u{fe0f}", 1205),
-        ("🅰", 3436),
-        ("🌁", 2767),
-        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏾", 2314),
-        ("⛹\u{200d}♂", 1910),
-        ("🚶🏼\u{200d}♂\u{200d}➡\u{fe0f}", 1559),
-        ("💆🏻\u{200d}♀\u{fe0f}", 1504),
-        ("🇦🇪", 3514),
-        ("♦\u{fe0f}", 3006),
-        ("⛹🏼", 1906),
-        ("🧑🏽\u{200d}🤝\u{200d}🧑🏾", 2097),
-        ("🦇", 2483),
-        ("🧑\u{200d}⚕\u{fe0f}", 842),
-        ("🈁", 3453),
-        ("🧊", 2708),
-        ("👼🏿", 1339),
-        ("🤹\u{200d}♀", 2045),
-        ("🇨🇾", 3568),
-        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏽", 2296),
-        ("🔳", 3502),
-        ("🧐", 74),
-        ("🏄\u{200d}♂\u{fe0f}", 1856),
-        ("😟", 77),
-        ("🤸🏿\u{200d}♂\u{fe0f}", 1987),
-        ("🧎🏾\u{200d}♀\u{fe0f}", 1597),
-        ("🧓", 644),
-        ("🫃🏽", 1301),
-        ("🧗\u{200d}♂", 1806),
-        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", 2210),
-        ("🙍🏿\u{200d}♀\u{fe0f}", 679),
-        ("🎅🏼", 1342),
-        ("🏊🏾", 1890),
-        ("🙇🏿\u{200d}♀\u{fe0f}", 805),
-        ("🤦🏻\u{200d}♂", 813),
-        ("😉", 11),
-        ("🖱", 3108),
-        ("🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}", 3770),
-        ("🥄", 2712),
-        ("☪\u{fe0f}", 3336),
-        ("🏊🏾\u{200d}♂", 1896),
-        ("🕯", 3126),
-        ("🚶🏻\u{200d}♂\u{fe0f}\u{200d}➡", 1558),
-        ("👩🏻\u{200d}🦽", 1714),
-        ("👍🏽", 333),
-        ("🧑🏾\u{200d}🚒", 1116),
-        ("🫙", 2714),
-        ("👩🏻\u{200d}🔧", 963),
-        ("🏋🏾\u{200d}♀\u{fe0f}", 1938),
-        ("🎹", 3087),
-        ("🏥", 2746),
-        ("👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", 2237),
-        ("🦹🏼\u{200d}♂\u{fe0f}", 1384),
-        ("🧗🏿\u{200d}♀", 1817),
-        ("⚔\u{fe0f}", 3213),
-        ("💆🏾\u{200d}♀\u{fe0f}", 1507),
-        ("🧑🏿\u{200d}🦯\u{200d}➡\u{fe0f}", 1628),
-        ("🕺🏾", 1771),
-        ("👩🏼\u{200d}🤝\u{200d}👩🏻", 2117),
-        ("⏬", 3367),
-        ("👮\u{200d}♂\u{fe0f}", 1136),
-        ("🙆🏿\u{200d}♂", 727),
-        ("🧗\u{200d}♀", 1812),
-        ("↔", 3316),
-        ("👦", 506),
-        ("🧑\u{200d}🧑\u{200d}🧒", 2423),
-        ("⛓\u{fe0f}\u{200d}💥", 3227),
-        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏿", 2198),
-        ("🌇", 2773),
-        ("🔐", 3204),
-        ("🚴🏾\u{200d}♂", 1950),
-        ("⛹🏻\u{200d}♂", 1911),
-        ("🐾", 2493),
-        ("💅🏼", 436),
-        ("🇰🇲", 3636),
-        ("🤵\u{200d}♀\u{fe0f}", 1268),
-        ("🧜🏼\u{200d}♀\u{fe0f}", 1462),
-        ("🇱🇮", 3646),
-        ("❤\u{200d}🩹", 141),
-        ("😡", 102),
-        ("👫", 2133),
-        ("🕞", 2870),
-        ("👵🏾", 660),
-        ("🧚🏾\u{200d}♀\u{fe0f}", 1428),
-        ("🦐", 2672),
-        ("🏃🏻\u{200d}♀\u{200d}➡", 1750),
-        ("🏇🏻", 1820),
-        ("🎄", 2935),
-        ("🤸🏼\u{200d}♀\u{fe0f}", 1990),
-        ("🫸🏾", 232),
-        ("🤸🏽\u{200d}♂\u{fe0f}", 1985),
-        ("🇼🇫", 3762),
-        ("🇦🇽", 3527),
-        ("🏌🏼", 1834),
-        ("🧑🏼\u{200d}🔧", 952),
-        ("😻", 120),
-        ("👇", 312),
-        ("🏕", 2727),
-        ("🛋\u{fe0f}", 3252),
-        ("🤽🏼\u{200d}♂", 2005),
-        ("👩🏼\u{200d}🤝\u{200d}👨🏿", 2146),
-        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", 2203),
-        ("🫳🏼", 212),
-        ("🦩", 2509),
-        ("🏃🏼\u{200d}♀\u{fe0f}\u{200d}➡", 1751),
-        ("🚶🏿\u{200d}♀\u{200d}➡", 1556),
-        ("🎛", 3080),
-        ("🚶🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1552),
-        ("👩🏽\u{200d}💼", 1001),
-        ("☦", 3335),
-        ("🇧🇱", 3538),
-        ("💇🏼\u{200d}♂\u{fe0f}", 1517),
-        ("🖼", 3013),
-        ("🆔", 3443),
-        ("🤵🏻", 1257),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", 2274),
-        ("🚣🏻\u{200d}♂\u{fe0f}", 1875),
-        ("🇸🇷", 3724),
-        ("🔣", 3434),
-        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏿", 2310),
-        ("🚴🏼\u{200d}♀", 1954),
-        ("🫠", 10),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2231),
-        ("👨🏻\u{200d}🦯\u{200d}➡\u{fe0f}", 1636),
-        ("🧑🏻\u{200d}🎤", 1041),
-        ("〽\u{fe0f}", 3411),
-        ("🧑🏿\u{200d}🦱", 607),
-        ("💆\u{200d}♀", 1503),
-        ("👉🏽", 297),
-        ("🛝", 2777),
-        ("🤍", 153),
-        ("🏂🏽", 1829),
-        ("🧎🏾", 1585),
-        ("🙋🏻\u{200d}♂\u{fe0f}", 759),
-        ("🏎\u{fe0f}", 2811),
-        ("💦", 159),
-        ("🏋🏻\u{200d}♀", 1935),
-        ("🙆🏼\u{200d}♀\u{fe0f}", 730),
-        ("☝🏼", 320),
-        ("👩🏼\u{200d}💻", 1036),
-        ("🫖", 2692),
-        ("🤦🏻", 807),
-        ("👩🏻\u{200d}🦼", 1678),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2352),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2241),
-        ("🫱🏽\u{200d}🫲🏻", 410),
-        ("🧑🏼\u{200d}🦱", 604),
-        ("🤏🏻", 247),
-        ("🙎🏽\u{200d}♀\u{fe0f}", 695),
-        ("⬇\u{fe0f}", 3311),
-        ("👨🏿\u{200d}🤝\u{200d}👨🏻", 2181),
-        ("👂🏼", 468),
-        ("🧎🏿\u{200d}♀\u{fe0f}", 1598),
-        ("🚴🏻\u{200d}♂\u{fe0f}", 1947),
-        ("👩\u{200d}❤\u{200d}👩", 2367),
-        ("〰", 3394),
-        ("🤸🏻\u{200d}♂\u{fe0f}", 1983),
-        ("🧔🏾\u{200d}♂", 546),
-        ("😞", 96),
-        ("☸", 3332),
-        ("💂🏾", 1170),
-        ("🧗", 1800),
-        ("🏃🏾\u{200d}♀", 1741),
-        ("🙇🏾", 792),
-        ("✍🏿", 433),
-        ("🙅🏽\u{200d}♂\u{fe0f}", 707),
-        ("🧚🏽\u{200d}♂\u{fe0f}", 1421),
-        ("👩🏾", 582),
-        ("👰🏾\u{200d}♀\u{fe0f}", 1290),
-        ("👨\u{200d}🔧", 956),
-        ("🧏🏽\u{200d}♀\u{fe0f}", 785),
-        ("💁🏿\u{200d}♂\u{fe0f}", 745),
-        ("💑🏿", 2294),
-        ("💇🏽\u{200d}♀\u{fe0f}", 1524),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏿", 2380),
-        ("🖲\u{fe0f}", 3109),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2216),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏼", 2286),
-        ("🤠", 69),
-        ("🧝🏽", 1469),
-        ("🪚", 3218),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏻", 2389),
-        ("🦹🏼\u{200d}♀\u{fe0f}", 1390),
-        ("🪥", 3268),
-        ("🏄\u{200d}♂", 1856),
-        ("🏃\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1755),
-        ("👩🏽\u{200d}🔧", 965),
-        ("🪨", 2736),
-        ("👷🏿\u{200d}♂\u{fe0f}", 1201),
-        ("🙇\u{200d}♂", 794),
-        ("📼", 3123),
-        ("😈", 105),
-        ("🚵🏿\u{200d}♀", 1975),
-        ("👩🏾\u{200d}🔧", 966),
-        ("🖍", 3176),
-        ("🕵🏾\u{200d}♂\u{fe0f}", 1158),
-        ("✍🏻", 429),
-        ("🧗🏾\u{200d}♂", 1810),
-        ("🧎🏼\u{200d}♀\u{200d}➡", 1607),
-        ("💆🏾\u{200d}♀", 1507),
-        ("💁🏽\u{200d}♂\u{fe0f}", 743),
-        ("🫤", 76),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2224),
-        ("🕺🏼", 1769),
-        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏼", 2191),
-        ("🫴🏻", 217),
-        ("💇🏾\u{200d}♀", 1525),
-        ("🎆", 2936),
-        ("🌝", 2901),
-        ("🧝🏽\u{200d}♀\u{fe0f}", 1481),
-        ("👨🏾\u{200d}🎨", 1068),
-        ("🟰", 3386),
-        ("🧎🏻\u{200d}♀\u{200d}➡", 1606),
-        ("🙄", 45),
-        ("🧑\u{200d}🌾", 914),
-        ("👸🏿", 1225),
-        ("🚶🏽\u{200d}♀\u{200d}➡\u{fe0f}", 1554),
-        ("🏛\u{fe0f}", 2733),
-        ("🏙\u{fe0f}", 2769),
-        ("🧍🏿\u{200d}♀\u{fe0f}", 1580),
-        ("🧖🏿\u{200d}♀", 1799),
-        ("🇮🇱", 3620),
-        ("🛵", 2813),
-        ("🍪", 2679),
-        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏻", 2203),
-        ("♠\u{fe0f}", 3004),
-        ("🧑🏼\u{200d}🦲", 628),
-        ("🙇🏽\u{200d}♀", 803),
-        ("🧑\u{200d}🧑\u{200d}🧒\u{200d}🧒", 2424),
-        ("💁🏻\u{200d}♀\u{fe0f}", 747),
-        ("👃🏾", 482),
-        ("🤷🏻", 825),
-        ("4\u{fe0f}\u{20e3}", 3424),
-        ("🎈", 2940),
-        ("🚸", 3295),
-        ("🏡", 2742),
-        ("🏄🏾\u{200d}♂\u{fe0f}", 1860),
-        ("🏕\u{fe0f}", 2727),
-        ("🌔", 2890),
-        ("🦸🏿\u{200d}♀", 1375),
-        ("📗", 3134),
-        ("💶", 3152),
-        ("👃🏿", 483),
-        ("🚶🏼\u{200d}♂\u{fe0f}\u{200d}➡", 1559),
-        ("🐔", 2495),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏼", 2390),
-        ("👩🏼\u{200d}❤\u{200d}👨🏾", 2327),
-        ("👂🏻", 467),
-        ("🧎🏼\u{200d}♀\u{fe0f}\u{200d}➡", 1607),
-        ("🈵", 3469),
-        ("☯\u{fe0f}", 3333),
-        ("👲🏻", 1245),
-        ("🍤", 2663),
-        ("🎼", 3075),
-        ("👷🏻", 1191),
-        ("🫵", 324),
-        ("👨🏿\u{200d}❤\u{200d}👨🏻", 2363),
-        ("📣", 3071),
-        ("🏋🏾\u{200d}♀", 1938),
-        ("⛹🏾\u{200d}♀", 1920),
-        ("🧕", 1250),
-        ("🍾", 2695),
-        ("🟨", 3481),
-        ("🧑🏽\u{200d}❤\u{200d}🧑🏻", 2303),
-        ("👩🏼\u{200d}🤝\u{200d}👨🏻", 2143),
-        ("🇩🇴", 3575),
-        ("👩🏻\u{200d}❤\u{200d}👨🏼", 2321),
-        ("👩🏽\u{200d}🦯\u{200d}➡", 1650),
-        ("👩🏿\u{200d}🦽", 1718),
-        ("🩼", 3244),
-        ("🏃🏻\u{200d}♂\u{fe0f}\u{200d}➡", 1756),
-        ("🇺🇾", 3753),
-        ("👙", 3037),
-        ("🧑🏾\u{200d}❤\u{200d}🧑🏼", 2308),
-        ("⤴\u{fe0f}", 3319),
-        ("🧑🏻\u{200d}❤\u{200d}🧑🏼", 2295),
-        ("👘", 3032),
-        ("👨\u{200d}🎨", 1064),
-        ("🇫🇯", 3587),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2330),
-        ("🤹🏽\u{200d}♂", 2042),
-        ("▶\u{fe0f}", 3357),
-        ("🏃🏽\u{200d}♂\u{200d}➡", 1758),
-        ("🧙\u{200d}♂\u{fe0f}", 1400),
-        ("🔵", 3474),
-        ("👮🏾\u{200d}♂", 1140),
-        ("🇬🇧", 3593),
-        ("🩹", 3243),
-        ("🍅", 2598),
-        ("😃", 1),
-        ("🚶🏿\u{200d}♂\u{fe0f}\u{200d}➡", 1562),
-        ("🇰🇿", 3642),
-        ("🧘🏾\u{200d}♂\u{fe0f}", 2061),
-        ("🇸🇽", 3728),
-        ("🫱🏿", 203),
-        ("🇦🇨", 3512),
-        ("🕰\u{fe0f}", 2862),
-        ("🛰", 2851),
-        ("▪", 3492),
-        ("🏌🏽\u{200d}♀", 1847),
-        ("🇨🇵", 3562),
-        ("👳🏾\u{200d}♀\u{fe0f}", 1242),
-        ("🏃🏻\u{200d}➡\u{fe0f}", 1744),
-        ("🧎🏼\u{200d}➡", 1601),
-        ("🫴🏾", 220),
-        ("🏄🏼\u{200d}♀", 1864),
-        ("⛹\u{200d}♂\u{fe0f}", 1910),
-        ("🤽🏾\u{200d}♂\u{fe0f}", 2007),
-        ("🙇🏿\u{200d}♂\u{fe0f}", 799),
-        ("🏷", 3147),
-        ("👩🏾\u{200d}🔬", 1020),
-        ("🌺", 2560),
-        ("👨🏽\u{200d}🦲", 575),
-        ("🧑\u{200d}🎤", 1040),
-        ("👩🏼\u{200d}❤\u{200d}👨🏿", 2328),
-        ("🪛", 3220),
-        ("👩", 578),
-        ("👩\u{200d}🔬", 1016),
-        ("👩🏿\u{200d}🏭", 985),
-        ("🈂", 3454),
-        ("🛌🏾", 2079),
-        ("🥷", 1184),
-        ("🧙🏻\u{200d}♀", 1407),
-        ("🚣🏾\u{200d}♂", 1878),
-        ("♾", 3387),
-        ("🏳\u{200d}⚧\u{fe0f}", 3510),
-        ("🕐", 2865),
-        ("🇰🇪", 3632),
-        ("💅🏽", 437),
-        ("👨🏽\u{200d}🦯\u{200d}➡\u{fe0f}", 1638),
-        ("🌟", 2905),
-        ("👶", 494),
-        ("🏰", 2755),
-        ("🧎🏼\u{200d}♀\u{fe0f}", 1595),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2342),
-        ("👩🏿\u{200d}🤝\u{200d}👨🏽", 2157),
-        ("🚶🏻", 1528),
-        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2250),
-        ("🛀", 2069),
-        ("🏃🏾\u{200d}♀\u{200d}➡", 1753),
-        ("🚴🏿\u{200d}♂\u{fe0f}", 1951),
-        ("🤸\u{200d}♀\u{fe0f}", 1988),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏽", 2387),
-        ("⚖\u{fe0f}", 3224),
-        ("🤵🏾\u{200d}♂\u{fe0f}", 1266),
-        ("👷🏿", 1195),
-        ("💑", 2289),
-        ("🧑🏻\u{200d}🦼\u{200d}➡\u{fe0f}", 1660),
-        ("💂🏾\u{200d}♀", 1182),
-        ("🔡", 3432),
-        ("🦶🏽", 463),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2220),
-        ("😱", 93),
-        ("👎🏿", 341),
-        ("🛀🏾", 2073),
-        ("🧔🏿\u{200d}♀", 553),
-        ("👏🏾", 370),
-        ("🤵🏿\u{200d}♀\u{fe0f}", 1273),
-        ("👩\u{200d}👩\u{200d}👧\u{200d}👦", 2405),
-        ("👩🏿\u{200d}❤\u{200d}👩🏻", 2389),
-        ("🧙\u{200d}♀\u{fe0f}", 1406),
-        ("👩🏻\u{200d}⚖", 909),
-        ("🧑🏿\u{200d}🦳", 619),
-        ("👴🏼", 652),
-        ("🤏🏽", 249),
-        ("💁🏻", 735),
-        ("👭", 2107),
-        ("🇳🇨", 3678),
-        ("🧛🏻\u{200d}♀\u{fe0f}", 1443),
-        ("💂🏿", 1171),
-        ("🙅\u{200d}♂", 704),
-        ("😙", 21),
-        ("👨🏾", 534),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2226),
-        ("🇳🇴", 3684),
-        ("🏃🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1759),
-        ("💁🏼", 736),
-        ("🧑🏽\u{200d}🎓", 863),
-        ("🙋🏾\u{200d}♀\u{fe0f}", 768),
-        ("🧑\u{200d}🦼\u{200d}➡", 1659),
-        ("👨🏼\u{200d}🌾", 922),
-        ("🇹🇦", 3731),
-        ("🇪🇪", 3579),
-        ("🇨🇩", 3552),
-        ("🙆🏼\u{200d}♂\u{fe0f}", 724),
-        ("🫱🏼", 200),
-        ("👨🏿\u{200d}🦰", 559),
-        ("▪\u{fe0f}", 3492),
-        ("🤵🏻\u{200d}♂", 1263),
-        ("👮🏼\u{200d}♀\u{fe0f}", 1144),
-        ("6\u{fe0f}\u{20e3}", 3426),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2338),
-        ("👨🏻\u{200d}🎓", 867),
-        ("🎧", 3082),
-        ("🙆🏾", 720),
-        ("👩🏻\u{200d}💼", 999),
-        ("👸🏾", 1224),
-        ("🔈", 3067),
-        ("🚷", 3302),
-        ("🤣", 6),
-        ("🏃🏾\u{200d}➡\u{fe0f}", 1747),
-        ("🧎🏽\u{200d}♂\u{200d}➡\u{fe0f}", 1614),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", 2264),
-        ("🐓", 2496),
-        ("🇻🇺", 3761),
-        ("🦹🏻\u{200d}♀\u{fe0f}", 1389),
-        ("🏌🏾", 1836),
-        ("🧎🏿\u{200d}♀", 1598),
-        ("🙎🏾\u{200d}♂\u{fe0f}", 690),
-        ("👨🏻\u{200d}✈\u{fe0f}", 1083),
-        ("👨\u{200d}🦳", 566),
-        ("👩🏿\u{200d}🏫", 895),
-        ("🧙🏿\u{200d}♀\u{fe0f}", 1411),
-        ("🧑\u{200d}🦽", 1689),
-        ("🌡\u{fe0f}", 2899),
-        ("💁🏽", 737),
-        ("🛞", 2826),
-        ("🏃🏿", 1730),
-        ("🤾🏼\u{200d}♂\u{fe0f}", 2023),
-        ("💇\u{200d}♀", 1521),
-        ("🚴🏻\u{200d}♀", 1953),
-        ("🤹🏽\u{200d}♂\u{fe0f}", 2042),
-        ("🤷🏼\u{200d}♀", 838),
-        ("🏃🏽\u{200d}♀\u{fe0f}", 1740),
-        ("😪", 54),
-        ("©\u{fe0f}", 3415),
-        ("🐘", 2470),
-        ("🤵🏽\u{200d}♂", 1265),
-        ("👮🏾\u{200d}♀\u{fe0f}", 1146),
-        ("👨🏾\u{200d}🦯", 1633),
-        ("🤶🏽", 1349),
-        ("✡\u{fe0f}", 3331),
-        ("⛹\u{fe0f}\u{200d}♀", 1916),
-        ("👩🏾\u{200d}❤\u{200d}👨🏼", 2334),
-        ("🗜", 3223),
-        ("🕵🏾\u{200d}♀\u{fe0f}", 1164),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2343),
-        ("🦿", 453),
-        ("🧑🏾\u{200d}🌾", 918),
-        ("🫛", 2617),
-        ("🧑🏾", 522),
-        ("👨🏾\u{200d}❤\u{200d}👨🏻", 2359),
-        ("🪞", 3249),
-        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2364),
-        ("🤦🏿\u{200d}♀\u{fe0f}", 823),
-        ("🧯", 3270),
-        ("♟\u{fe0f}", 3008),
-        ("🍓", 2595),
-        ("🇰🇬", 3633),
-        ("😑", 39),
-        ("🖇", 3194),
-        ("🙅🏼\u{200d}♀\u{fe0f}", 712),
-        ("⏱", 2860),
-        ("👩🏾\u{200d}🤝\u{200d}👨🏻", 2151),
-        ("🧛🏾\u{200d}♀", 1446),
-        ("💅", 434),
-        ("💇🏿\u{200d}♀\u{fe0f}", 1526),
-        ("🧑🏻\u{200d}❤\u{200d}🧑🏽", 2296),
-        ("🧑🏾\u{200d}🤝\u{200d}🧑🏾", 2085),
-        ("👨🏽\u{200d}❤\u{200d}👨🏻", 2355),
-        ("💏🏿", 2190),
-        ("😕", 75),
-        ("🏵", 2557),
-        ("🦄", 2451),
-        ("🧜🏾\u{200d}♀", 1464),
-        ("💁🏻\u{200d}♀", 747),
-        ("🐕\u{200d}🦺", 2435),
-        ("🇲🇪", 3657),
-        ("🗿", 3278),
-        ("👨\u{200d}💻", 1028),
-        ("👱🏾\u{200d}♂", 642),
-        ("👷🏻\u{200d}♂", 1197),
-        ("🦁", 2443),
-        ("👩🏼\u{200d}🤝\u{200d}👩🏽", 2118),
-        ("👨🏻\u{200d}🏭", 975),
-        ("😶\u{200d}🌫\u{fe0f}", 42),
-        ("🎻", 3089),
-        ("🤵🏽\u{200d}♂\u{fe0f}", 1265),
-        ("🫢", 31),
-        ("🧑🏻\u{200d}⚖\u{fe0f}", 897),
-        ("🏃🏻\u{200d}♂\u{200d}➡\u{fe0f}", 1756),
-        ("🎬", 3118),
-        ("🧖🏿\u{200d}♀\u{fe0f}", 1799),
-        ("🦸🏼\u{200d}♀\u{fe0f}", 1372),
-        ("🧑🏿\u{200d}🦼\u{200d}➡\u{fe0f}", 1664),
-        ("🇧🇬", 3534),
-        ("👨🏻\u{200d}🦳", 567),
-        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏿", 2306),
-        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2261),
-        ("🏃🏽\u{200d}♀\u{200d}➡\u{fe0f}", 1752),
-        ("🚱", 3301),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", 2277),
-        ("🥵", 63),
-        ("🇰🇭", 3634),
-        ("🏃🏿\u{200d}➡", 1748),
-        ("🇴🇲", 3689),
-        ("🏃\u{200d}➡\u{fe0f}", 1743),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2220),
-        ("👨\u{200d}🦽\u{200d}➡\u{fe0f}", 1707),
-        ("👩🏼\u{200d}🍼", 1318),
-        ("👩🏽\u{200d}🦲", 623),
-        ("👉🏿", 299),
-        ("⬇", 3311),
-        ("😺", 117),
-        ("🧗🏾\u{200d}♂\u{fe0f}", 1810),
-        ("🧑🏻\u{200d}🔬", 1005),
-        ("🧑🏼\u{200d}🦽\u{200d}➡\u{fe0f}", 1697),
-        ("🚴\u{200d}♂\u{fe0f}", 1946),
-        ("🌧", 2914),
-        ("🧑🏻\u{200d}🍳", 933),
-        ("♀", 3379),
-        ("🧬", 3236),
-        ("🫰", 264),
-        ("👁", 490),
-        ("🧑", 518),
-        ("🚵\u{200d}♀\u{fe0f}", 1970),
-        ("🫵🏿", 329),
-        ("☘", 2573),
-        ("🇹🇰", 3738),
-        ("👩🏾\u{200d}🦼\u{200d}➡\u{fe0f}", 1687),
-        ("🌯", 2638),
-        ("🏸", 2976),
-        ("👜", 3041),
-        ("🦼", 2815),
-        ("🏋🏽\u{200d}♂", 1931),
-        ("🩶", 152),
-        ("🤹🏼\u{200d}♀\u{fe0f}", 2047),
-        ("🏃🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1751),
-        ("🧑🏾\u{200d}🚀", 1098),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2316),
-        ("🧝🏻\u{200d}♂", 1473),
-        ("👰🏼\u{200d}♂", 1282),
-        ("💂🏾\u{200d}♀\u{fe0f}", 1182),
-        ("😍", 15),
-        ("🧑🏻\u{200d}🤝\u{200d}🧑🏻", 2082),
-        ("🌩", 2916),
-        ("📲", 3096),
-        ("🚵🏽\u{200d}♂", 1967),
-        ("👩🏾\u{200d}🚒", 1128),
-        ("👩\u{200d}❤\u{200d}💋\u{200d}👨", 2211),
-        ("🎅🏿", 1345),
-        ("🐕", 2433),
-        ("👨🏾\u{200d}🦲", 576),
-        ("🧝🏼\u{200d}♂", 1474),
-        ("🏌🏻\u{200d}♀", 1845),
-        ("👱🏿\u{200d}♀\u{fe0f}", 637),
-        ("🧙🏼", 1396),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2329),
-        ("👨🏾\u{200d}❤\u{200d}👨🏽", 2361),
-        ("🤘🏾", 280),
-        ("🥷🏽", 1187),
-        ("🎌", 3506),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏾", 2392),
-        ("♦", 3006),
-        ("🧏🏿\u{200d}♀\u{fe0f}", 787),
-        ("🧗🏻", 1801),
-        ("🧙🏾\u{200d}♀\u{fe0f}", 1410),
-        ("🧑🏾\u{200d}🎤", 1044),
-        ("📜", 3141),
-        ("🙋🏻\u{200d}♀\u{fe0f}", 765),
-        ("🧝🏽\u{200d}♂", 1475),
-        ("🚶\u{200d}♂\u{fe0f}", 1533),
-        ("🏋\u{fe0f}\u{200d}♂\u{fe0f}", 1928),
-        ("👩\u{200d}🦯\u{200d}➡\u{fe0f}", 1647),
-        ("🏄\u{200d}♀", 1862),
-        ("🧷", 3261),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏿", 2388),
-        ("👨🏾\u{200d}🤝\u{200d}👨🏿", 2180),
-        ("🧝🏻", 1467),
-        ("🏄🏿\u{200d}♀", 1867),
-        ("🪗", 3085),
-        ("💷", 3153),
-        ("🤗", 29),
-        ("🧜🏾", 1452),
-        ("🇳🇦", 3677),
-        ("🧑🏼\u{200d}🍼", 1330),
-        ("🏌🏿", 1837),
-        ("🕴🏽", 1776),
-        ("👨🏾\u{200d}⚖\u{fe0f}", 906),
-        ("👳🏼\u{200d}♀", 1240),
-        ("🇱🇸", 3649),
-        ("🚶🏽\u{200d}♂", 1536),
-        ("🍘", 2654),
-        ("🙍🏻\u{200d}♂", 669),
-        ("👨🏾\u{200d}🔬", 1014),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2336),
-        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏻", 2303),
-        ("💹", 3157),
-        ("🟩", 3482),
-        ("👨🏾\u{200d}🦼\u{200d}➡\u{fe0f}", 1675),
-        ("🤹🏿\u{200d}♂", 2044),
-        ("👩🏼\u{200d}🦯", 1643),
-        ("🧛🏽\u{200d}♂\u{fe0f}", 1439),
-        ("🛌🏼", 2077),
-        ("🇹🇳", 3741),
-        ("🙆🏽\u{200d}♀", 731),
-        ("🤒", 58),
-        ("⏱\u{fe0f}", 2860),
-        ("🔭", 3238),
-        ("🧑🏽\u{200d}🦽", 1692),
-        ("🧍🏾\u{200d}♂\u{fe0f}", 1573),
-        ("🕵🏿\u{200d}♀", 1165),
-        ("🙋🏽\u{200d}♂\u{fe0f}", 761),
-        ("🚗", 2804),
-        ("🚺", 3286),
-        ("🥷🏿", 1189),
-        ("👨🏽\u{200d}🦽\u{200d}➡\u{fe0f}", 1710),
-        ("🤾🏼\u{200d}♀\u{fe0f}", 2029),
-        ("☀\u{fe0f}", 2900),
-        ("👩🏿\u{200d}🦽\u{200d}➡\u{fe0f}", 1724),
-        ("🧖\u{200d}♀\u{fe0f}", 1794),
-        ("🧑🏾\u{200d}⚖", 900),
-        ("🎚\u{fe0f}", 3079),
-        ("🏆", 2956),
-        ("🏃🏽\u{200d}➡", 1746),
-        ("🧑🏼\u{200d}✈\u{fe0f}", 1078),
-        ("🤵🏻\u{200d}♂\u{fe0f}", 1263),
-        ("👨🏾\u{200d}🤝\u{200d}👨🏼", 2178),
-        ("🥿", 3049),
-        ("🤷🏿\u{200d}♂", 835),
-        ("⛽", 2825),
-        ("🚶🏿\u{200d}♀\u{fe0f}\u{200d}➡", 1556),
-        ("👨🏾\u{200d}❤\u{200d}👨🏼", 2360),
-        ("✒", 3172),
-        ("👩🏼\u{200d}🦯\u{200d}➡\u{fe0f}", 1649),
-        ("👰🏿", 1279),
-        ("🙁", 78),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏻", 2285),
-        ("🚶🏿\u{200d}♀\u{200d}➡\u{fe0f}", 1556),
-        ("🌥", 2912),
-        ("🚶🏼\u{200d}♂", 1535),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2350),
-        ("🕳\u{fe0f}", 161),
-        ("🧜🏾\u{200d}♂\u{fe0f}", 1458),
-        ("🧔🏿\u{200d}♀\u{fe0f}", 553),
-        ("👨🏽\u{200d}🤝\u{200d}👨🏿", 2176),
-        ("🧝\u{200d}♂\u{fe0f}", 1472),
-        ("🏂🏾", 1830),
-        ("🏃🏼\u{200d}♀\u{200d}➡", 1751),
-        ("⚜", 3399),
-        ("🦸🏻\u{200d}♂\u{fe0f}", 1365),
-        ("🍖", 2628),
-        ("✌🏼", 254),
-        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2251),
-        ("🏃🏻\u{200d}♀", 1738),
-        ("👨🏿\u{200d}🚀", 1105),
-        ("🏌🏻\u{200d}♂", 1839),
-        ("📺", 3119),
-        ("🧑🏿\u{200d}❤\u{200d}🧑🏽", 2313),
-        ("🇬🇹", 3607),
-        ("🥢", 2709),
-        ("🤾🏽\u{200d}♂\u{fe0f}", 2024),
-        ("🙆🏿\u{200d}♀\u{fe0f}", 733),
-        ("🇧🇷", 3543),
-        ("👨🏾\u{200d}💻", 1032),
-        ("🧒🏿", 505),
-        ("🧑🏽\u{200d}🏫", 881),
-        ("🆖", 3446),
-        ("🧑🏿\u{200d}⚖", 901),
-        ("📽\u{fe0f}", 3117),
-        ("🧍🏻\u{200d}♂\u{fe0f}", 1570),
-        ("👨🏻\u{200d}🔬", 1011),
-        ("🍝", 2659),
-        ("💂🏻\u{200d}♀\u{fe0f}", 1179),
-        ("💇🏻\u{200d}♂\u{fe0f}", 1516),
-        ("😣", 95),
-        ("👩🏼\u{200d}❤\u{200d}👩🏻", 2377),
-        ("🥰", 14),
-        ("🏞\u{fe0f}", 2731),
-        ("🩲", 3035),
-        ("🤚🏻", 175),
-        ("🚋", 2793),
-        ("🚟", 2848),
-        ("👨🏼\u{200d}❤\u{200d}👨🏼", 2351),
-        ("🫰🏻", 265),
-        ("🫒", 2599),
-        ("🌧\u{fe0f}", 2914),
-        ("🧝🏾\u{200d}♂", 1476),
-        ("🎞\u{fe0f}", 3116),
-        ("🧘🏻\u{200d}♀", 2064),
-        ("🐠", 2530),
-        ("🍵", 2693),
-        ("🏄\u{200d}♀\u{fe0f}", 1862),
-        ("☪", 3336),
-        ("🔬", 3237),
-        ("👩🏿\u{200d}🦽\u{200d}➡", 1724),
-        ("🤾🏽\u{200d}♀\u{fe0f}", 2030),
-        ("👨🏿\u{200d}🔧", 961),
-        ("👳🏾\u{200d}♂", 1236),
-        ("🫱🏽\u{200d}🫲🏼", 411),
-        ("🤝🏼", 398),
-        ("🫳🏻", 211),
-        ("🧎🏿\u{200d}♀\u{200d}➡", 1610),
-        ("☔", 2924),
-        ("🏤", 2745),
-        ("🧙🏾\u{200d}♂", 1404),
-        ("💇", 1509),
-        ("⛸", 2981),
-        ("🇰🇷", 3639),
-        ("🪶", 2508),
-        ("🦹🏿\u{200d}♂\u{fe0f}", 1387),
-        ("🇨🇦", 3550),
-        ("🙍🏼", 664),
-        ("🔗", 3226),
-        ("💏🏼", 2187),
-        ("🍆", 2602),
-        ("👰🏽\u{200d}♂", 1283),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2235),
-        ("👩🏻\u{200d}❤\u{200d}👨🏻", 2316),
-        ("🫷🏻", 223),
-        ("🟪", 3484),
-        ("🟢", 3473),
-        ("🫵🏽", 327),
-        ("🤯", 68),
-        ("🏦", 2747),
-        ("👈", 288),
-        ("🫱🏿\u{200d}🫲🏾", 421),
-        ("💮", 2555),
-        ("🍸", 2697),
-        ("👐🏻", 385),
-        ("🧑🏿\u{200d}🦽", 1694),
-        ("🏃🏽\u{200d}♀", 1740),
-        ("🎲", 2998),
-        ("💚", 146),
-        ("✍🏽", 431),
-        ("🪴", 2566),
-        ("🕵\u{200d}♂", 1154),
-        ("🧑🏼\u{200d}🏫", 880),
-        ("👩🏿\u{200d}🤝\u{200d}👨🏾", 2158),
-        ("🕵", 1148),
-        ("🦸🏿\u{200d}♀\u{fe0f}", 1375),
-        ("🫸🏽", 231),
-        ("🛫", 2843),
-        ("👌🏻", 235),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2217),
-        ("🇽🇰", 3764),
-        ("🫂", 2421),
-        ("🧝🏻\u{200d}♀", 1479),
-        ("🇲🇻", 3672),
-        ("👼🏾", 1338),
-        ("🐃", 2457),
-        ("🚶🏿\u{200d}♂\u{fe0f}", 1538),
-        ("👨🏽\u{200d}🚀", 1103),
-        ("🕵\u{fe0f}\u{200d}♂", 1154),
-        ("🧜🏽\u{200d}♀\u{fe0f}", 1463),
-        ("👨🏽\u{200d}⚕", 851),
-        ("🌬", 2919),
-        ("🔯", 3339),
-        ("🧗🏿\u{200d}♀\u{fe0f}", 1817),
-        ("🏹", 3216),
-        ("🤙🏼", 284),
-        ("🕵\u{200d}♂\u{fe0f}", 1154),
-        ("💆\u{200d}♀\u{fe0f}", 1503),
-        ("🐇", 2479),
-        ("🙇\u{200d}♀\u{fe0f}", 800),
-        ("🏄🏻\u{200d}♂\u{fe0f}", 1857),
-        ("👨\u{200d}👨\u{200d}👧\u{200d}👧", 2402),
-        ("👩🏽\u{200d}⚖\u{fe0f}", 911),
-        ("🇹🇲", 3740),
-        ("🚵🏻\u{200d}♀", 1971),
-        ("🤽", 1997),
-        ("🙆🏾\u{200d}♂", 726),
-        ("👱\u{200d}♂", 638),
-        ("🤦\u{200d}♀\u{fe0f}", 818),
-        ("👚", 3038),
-        ("🛌🏻", 2076),
-        ("🇸🇬", 3715),
-        ("💧", 2932),
-        ("👠", 3050),
-        ("🙎🏽\u{200d}♂", 689),
-        ("⛹🏻\u{200d}♀", 1917),
-        ("🏃🏽\u{200d}♂\u{200d}➡\u{fe0f}", 1758),
-        ("🏃🏻\u{200d}➡", 1744),
-        ("🇵🇲", 3697),
-        ("🦹🏽\u{200d}♂\u{fe0f}", 1385),
-        ("🙇\u{200d}♂\u{fe0f}", 794),
-        ("👃", 478),
-        ("🙅🏾\u{200d}♀\u{fe0f}", 714),
-        ("🌪", 2917),
-        ("👨🏼\u{200d}⚖\u{fe0f}", 904),
-        ("🙅🏽\u{200d}♀", 713),
-        ("👨\u{200d}🔬", 1010),
-        ("👨\u{200d}⚕\u{fe0f}", 848),
-        ("🤾🏼\u{200d}♂", 2023),
-        ("🇧🇶", 3542),
-        ("🆑", 3439),
-        ("🙅🏿", 703),
-        ("🚶🏾\u{200d}♂\u{200d}➡\u{fe0f}", 1561),
-        ("👩\u{200d}🦼\u{200d}➡\u{fe0f}", 1683),
-        ("6\u{20e3}", 3426),
-        ("🧑🏻\u{200d}💻", 1023),
-        ("☕", 2691),
-        ("🙎🏻\u{200d}♀\u{fe0f}", 693),
-        ("🧟\u{200d}♂\u{fe0f}", 1488),
-        ("🚭", 3299),
-        ("📍", 3192),
-        ("🤦🏼\u{200d}♂", 814),
-        ("🔧", 3219),
-        ("⏯", 3360),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2235),
-        ("🧺", 3263),
-        ("👩🏻\u{200d}🔬", 1017),
-        ("🎊", 2942),
-        ("🚶🏽\u{200d}♂\u{fe0f}\u{200d}➡", 1560),
-        ("🤞", 258),
-        ("🧛🏼\u{200d}♂", 1438),
-        ("🕚", 2885),
-        ("🧓🏼", 646),
-        ("👩🏻\u{200d}🚀", 1107),
-        ("🇲🇫", 3658),
-        ("🚶🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1560),
-        ("🖕🏾", 310),
-        ("🇦🇮", 3517),
-        ("🫷🏿", 227),
-        ("👩🏿\u{200d}❤\u{200d}👨🏼", 2338),
-        ("🙎🏼\u{200d}♀", 694),
-        ("💎", 3065),
-        ("✡", 3331),
-        ("👷", 1190),
-        ("🧝\u{200d}♂", 1472),
-        ("🇫🇴", 3590),
-        ("👩\u{200d}🦽\u{200d}➡", 1719),
-        ("💞", 135),
-        ("🇯🇲", 3629),
-        ("👮🏽\u{200d}♀\u{fe0f}", 1145),
-        ("🙍🏿\u{200d}♂", 673),
-        ("🚌", 2794),
-        ("👩🏿\u{200d}🤝\u{200d}👩🏽", 2131),
-        ("👨🏼\u{200d}❤\u{200d}👨🏽", 2352),
-        ("👩🏼\u{200d}💼", 1000),
-        ("🇵🇹", 3701),
-        ("🤦🏻\u{200d}♂\u{fe0f}", 813),
-        ("🧼", 3266),
-        ("🤾🏿\u{200d}♀\u{fe0f}", 2032),
-        ("👰🏻\u{200d}♂\u{fe0f}", 1281),
-        ("🤞🏾", 262),
-        ("🧜🏽\u{200d}♂\u{fe0f}", 1457),
-        ("🤴🏿", 1219),
-        ("🪽", 2512),
-        ("🩱", 3034),
-        ("🤾🏻\u{200d}♂", 2022),
-        ("🕵🏽\u{200d}♀\u{fe0f}", 1163),
-        ("💂🏽\u{200d}♀\u{fe0f}", 1181),
-        ("🇪🇷", 3582),
-        ("🌿", 2572),
-        ("🧑🏼\u{200d}⚕", 844),
-        ("🇵🇰", 3695),
-        ("💇🏾\u{200d}♂", 1519),
-        ("💯", 155),
-        ("🧙\u{200d}♂", 1400),
-        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", 2199),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏾", 2383),
-        ("👗", 3031),
-        ("👨\u{200d}💼", 992),
-        ("🤽\u{200d}♀\u{fe0f}", 2009),
-        ("🤥", 48),
-        ("🏇🏼", 1821),
-        ("👨🏻\u{200d}🍼", 1323),
-        ("☘\u{fe0f}", 2573),
-        ("👂", 466),
-        ("🛌🏽", 2078),
-        ("🎗", 2952),
-        ("🏏", 2971),
-        ("🎞", 3116),
-        ("🍕", 2634),
-        ("🙅🏼\u{200d}♂\u{fe0f}", 706),
-        ("🕒", 2869),
-        ("🙋🏿\u{200d}♂\u{fe0f}", 763),
-        ("👩🏾\u{200d}❤\u{200d}👨🏾", 2335),
-        ("🚽", 3254),
-        ("🏇🏾", 1823),
-        ("💆🏻\u{200d}♂\u{fe0f}", 1498),
-        ("👮🏻\u{200d}♀", 1143),
-        ("⚕\u{fe0f}", 3397),
-        ("👨🏽\u{200d}🦱", 563),
-        ("🧎🏻\u{200d}♀", 1594),
-        ("🧑🏾\u{200d}🤝\u{200d}🧑🏿", 2102),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏻", 2381),
-        ("Ⓜ", 3444),
-        ("🧑🏻\u{200d}⚕\u{fe0f}", 843),
-        ("🧑🏼\u{200d}🌾", 916),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2355),
-        ("🤽🏼\u{200d}♂\u{fe0f}", 2005),
-        ("👩🏽\u{200d}💻", 1037),
-        ("🧎\u{200d}♂\u{fe0f}\u{200d}➡", 1611),
-        ("🥟", 2667),
-        ("❕", 3392),
-        ("🥺", 84),
-        ("⤵", 3320),
-        ("🧑🏽\u{200d}🔧", 953),
-        ("🤹🏾\u{200d}♂\u{fe0f}", 2043),
-        ("🇵🇦", 3690),
-        ("🚶🏻\u{200d}♂\u{200d}➡", 1558),
-        ("😶", 40),
-        ("💆🏾", 1495),
-        ("🤴🏼", 1216),
-        ("🏃🏻", 1726),
-        ("🤼\u{200d}♂\u{fe0f}", 1995),
-        ("🧖\u{200d}♀", 1794),
-        ("🪘", 3092),
-        ("🌭", 2635),
-        ("👩🏿\u{200d}⚕", 859),
-        ("👨🏽\u{200d}🦯\u{200d}➡", 1638),
-        ("🙌🏿", 377),
-        ("🏯", 2754),
-        ("👩🏾\u{200d}🦼\u{200d}➡", 1687),
-        ("💡", 3127),
-        ("🎇", 2937),
-        ("📳", 3377),
-        ("💁🏾\u{200d}♂\u{fe0f}", 744),
-        ("👐", 384),
-        ("👩🏻\u{200d}🦯", 1642),
-        ("🇹🇷", 3743),
-        ("🇸🇾", 3729),
-        ("🧎🏽\u{200d}♂\u{fe0f}", 1590),
-        ("🙈", 126),
-        ("🇦🇶", 3521),
-        ("🫷🏽", 225),
-        ("🍄", 2580),
-        ("⛹🏾\u{200d}♂\u{fe0f}", 1914),
-        ("🧑🏽\u{200d}🦼\u{200d}➡", 1662),
-        ("🥻", 3033),
-        ("💭", 166),
-        ("Ⓜ\u{fe0f}", 3444),
-        ("🧛🏻\u{200d}♂\u{fe0f}", 1437),
-        ("👩🏽\u{200d}❤\u{200d}👨🏿", 2332),
-        ("👳🏽\u{200d}♂\u{fe0f}", 1235),
-        ("🔙", 3323),
-        ("🦸🏿\u{200d}♂", 1369),
-        ("👇🏾", 316),
-        ("🌻", 2561),
-        ("🗳", 3170),
-        ("🇭🇰", 3611),
-        ("🧎🏿\u{200d}➡", 1604),
-        ("🧜\u{200d}♀\u{fe0f}", 1460),
-        ("🌘", 2894),
-        ("👩🏼\u{200d}🦽\u{200d}➡", 1721),
-        ("🧋", 2705),
-        ("🖕", 306),
-        ("🗳\u{fe0f}", 3170),
-        ("🤦\u{200d}♂", 812),
-        ("🤟🏾", 274),
-        ("👮", 1130),
-        ("♣\u{fe0f}", 3007),
-        ("👩🏽\u{200d}❤\u{200d}👩🏼", 2382),
-        ("🎑", 2948),
-        ("🧑🏾\u{200d}🦯\u{200d}➡\u{fe0f}", 1627),
-        ("🇱🇹", 3650),
-        ("😮", 80),
-        ("🧙🏾\u{200d}♀", 1410),
-        ("🙅🏿\u{200d}♂\u{fe0f}", 709),
-        ("⁉\u{fe0f}", 3389),
-        ("9\u{fe0f}\u{20e3}", 3429),
-        ("🤵🏾", 1260),
-        ("👱🏾\u{200d}♀", 636),
-        ("🧎🏿", 1586),
-        ("🚣🏼", 1870),
-        ("🏃🏿\u{200d}♀", 1742),
-        ("🫶🏻", 379),
-        ("🧑\u{200d}🦯\u{200d}➡\u{fe0f}", 1623),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", 2317),
-        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2238),
-        ("🦸🏾\u{200d}♀", 1374),
-        ("🦢", 2505),
-        ("✔", 3406),
-        ("📰", 3143),
-        ("🤦\u{200d}♂\u{fe0f}", 812),
-        ("👰", 1274),
-        ("🤰🏻", 1293),
-        ("💂🏻\u{200d}♀", 1179),
-        ("🛀🏼", 2071),
-        ("🧑🏾\u{200d}✈", 1080),
-        ("✔\u{fe0f}", 3406),
-        ("🙇🏻\u{200d}♀\u{fe0f}", 801),
-        ("🏃\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1749),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏿", 2384),
-        ("🏨", 2748),
-        ("🧴", 3260),
-        ("👩🏼\u{200d}🦽", 1715),
-        ("🪕", 3090),
-        ("🧎🏾\u{200d}♂\u{fe0f}\u{200d}➡", 1615),
-        ("🪣", 3265),
-        ("🛂", 3290),
-        ("🧛🏿\u{200d}♂", 1441),
-        ("😷", 57),
-        ("🍶", 2694),
-        ("🧏🏿\u{200d}♂\u{fe0f}", 781),
-        ("🧏🏼", 772),
-        ("🧑🏾\u{200d}🦽", 1693),
-        ("🫄🏿", 1309),
-        ("👨\u{200d}🦱", 560),
-        ("🏃🏼\u{200d}➡", 1745),
-        ("🫳🏿", 215),
-        ("📛", 3401),
-        ("🧑\u{200d}🧒", 2425),
-        ("☝", 318),
-        ("🏃🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1750),
-        ("🙍🏼\u{200d}♀\u{fe0f}", 676),
-        ("🤙🏾", 286),
-        ("📴", 3378),
-        ("🧛🏾", 1434),
-        ("👨🏽\u{200d}🦽", 1704),
-        ("🧝🏾", 1470),
-        ("👭🏾", 2111),
-        ("🤲🏽", 393),
-        ("💆🏿\u{200d}♂", 1502),
-        ("🤦🏼\u{200d}♀\u{fe0f}", 820),
-        ("🧑🏻\u{200d}⚕", 843),
-        ("👭🏿", 2112),
-        ("🧑🏻\u{200d}🚒", 1113),
-        ("🙆🏻\u{200d}♂\u{fe0f}", 723),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2233),
-        ("👨🏼\u{200d}🤝\u{200d}👨🏿", 2172),
-        ("👮\u{200d}♀", 1142),
-        ("🐪", 2466),
-        ("🚶🏾\u{200d}♀\u{fe0f}", 1543),
-        ("🧉", 2707),
-        ("🤵🏾\u{200d}♂", 1266),
-        ("🤸🏽\u{200d}♀\u{fe0f}", 1991),
-        ("👨🏻\u{200d}🦽\u{200d}➡", 1708),
-        ("🇳🇵", 3685),
-        ("🧑\u{200d}🏭", 968),
-        ("✌🏿", 257),
-        ("👩🏻\u{200d}🦯\u{200d}➡", 1648),
-        ("🧜🏼\u{200d}♂\u{fe0f}", 1456),
-        ("🛥\u{fe0f}", 2839),
-        ("🇨🇺", 3564),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2357),
-        ("🙍\u{200d}♂\u{fe0f}", 668),
-        ("🧚🏽", 1415),
-        ("👆", 300),
-        ("🛰\u{fe0f}", 2851),
-        ("🤦🏼\u{200d}♀", 820),
-        ("👨\u{200d}🦼\u{200d}➡", 1671),
-        ("🥋", 2978),
-        ("🍀", 2574),
-        ("🕶", 3020),
-        ("🏊🏽\u{200d}♂\u{fe0f}", 1895),
-        ("🤳🏾", 444),
-        ("🤵🏼\u{200d}♀\u{fe0f}", 1270),
-        ("🚶\u{200d}➡", 1545),
-        ("🦹🏼\u{200d}♂", 1384),
-        ("🚴🏿\u{200d}♀", 1957),
-        ("🕜", 2866),
-        ("🏵\u{fe0f}", 2557),
-        ("🎙\u{fe0f}", 3078),
-        ("😗", 18),
-        ("👕", 3025),
-        ("💂", 1166),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2225),
-        ("🏢", 2743),
-        ("🤷\u{200d}♂", 830),
-        ("🇦🇼", 3526),
-        ("👨🏼\u{200d}🦯\u{200d}➡\u{fe0f}", 1637),
-        ("👩🏻\u{200d}🤝\u{200d}👩🏾", 2115),
-        ("👌🏼", 236),
-        ("🧑\u{200d}🎓", 860),
-        ("👩\u{200d}🦯", 1641),
-        ("🫰🏿", 269),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2226),
-        ("🦨", 2490),
-        ("🌗", 2893),
-        ("🚶🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1561),
-        ("🛎", 2854),
-        ("👱🏽\u{200d}♀\u{fe0f}", 635),
-        ("🌴", 2569),
-        ("🧘🏽\u{200d}♂", 2060),
-        ("🤹", 2033),
-        ("🔆", 3374),
-        ("👩🏾\u{200d}💻", 1038),
-        ("🙎🏻", 681),
-        ("🇪🇹", 3584),
-        ("🐲", 2521),
-        ("👩🏾\u{200d}🦽\u{200d}➡", 1723),
-        ("💂\u{200d}♂\u{fe0f}", 1172),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻", 2221),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2320),
-        ("🏃🏼\u{200d}➡\u{fe0f}", 1745),
-        ("🤷🏽\u{200d}♂", 833),
-        ("🧟\u{200d}♀", 1489),
-        ("🚅", 2785),
-        ("🔅", 3373),
-        ("♿", 3284),
-        ("🟡", 3472),
-        ("🧜\u{200d}♂", 1454),
-        ("🧗🏻\u{200d}♂\u{fe0f}", 1807),
-        ("🇱🇨", 3645),
-        ("📑", 3145),
-        ("🖌", 3175),
-        ("🇵🇬", 3693),
-        ("👨🏽\u{200d}❤\u{200d}👨🏾", 2357),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏻", 2264),
-        ("👩🏿\u{200d}🔬", 1021),
-        ("🎯", 2988),
-        ("🆓", 3441),
-        ("☁\u{fe0f}", 2908),
-        ("✋", 186),
-        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2232),
-        ("🧎🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1606),
-        ("🤽🏾\u{200d}♀\u{fe0f}", 2013),
-        ("🪲", 2542),
-        ("🧑🏾\u{200d}❤\u{200d}🧑🏿", 2310),
-        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", 2207),
-        ("👳🏻\u{200d}♀", 1239),
-        ("🔊", 3069),
-        ("💇🏽\u{200d}♀", 1524),
-        ("👳🏼\u{200d}♂\u{fe0f}", 1234),
-        ("👩🏿\u{200d}🦼\u{200d}➡\u{fe0f}", 1688),
-        ("🧑🏿\u{200d}❤\u{200d}🧑🏼", 2312),
-        ("👧🏻", 513),
-        ("👨🏻\u{200d}🤝\u{200d}👨🏼", 2165),
-        ("👷🏾", 1194),
-        ("🥬", 2609),
-        ("💆🏼\u{200d}♂", 1499),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏻", 2273),
-        ("👌🏾", 238),
-        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏾", 2197),
-        ("🙇🏻\u{200d}♀", 801),
-        ("🤲🏻", 391),
-        ("🤹🏽\u{200d}♀", 2048),
-        ("🥡", 2669),
-        ("🧑🏼\u{200d}🦯\u{200d}➡\u{fe0f}", 1625),
-        ("🧎🏼\u{200d}♀\u{200d}➡\u{fe0f}", 1607),
-        ("🫸🏿", 233),
-        ("🌛", 2897),
-        ("🚴🏼\u{200d}♂\u{fe0f}", 1948),
-        ("👱\u{200d}♂\u{fe0f}", 638),
-        ("🏃🏿\u{200d}♂\u{200d}➡", 1760),
-        ("🤹🏿\u{200d}♂\u{fe0f}", 2044),
-        ("🏫", 2751),
-        ("🪤", 3258),
-        ("🧑🏿\u{200d}✈\u{fe0f}", 1081),
-        ("👩🏾\u{200d}🦽\u{200d}➡\u{fe0f}", 1723),
-        ("🙍🏾\u{200d}♂\u{fe0f}", 672),
-        ("🧏🏻\u{200d}♂\u{fe0f}", 777),
-        ("👩🏼\u{200d}✈\u{fe0f}", 1090),
-        ("👌", 234),
-        ("🚣🏽\u{200d}♀\u{fe0f}", 1883),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2339),
-        ("🇬🇾", 3610),
-        ("🧎🏿\u{200d}♀\u{fe0f}\u{200d}➡", 1610),
-        ("⌨\u{fe0f}", 3107),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2362),
-        ("🐌", 2537),
-        ("🥕", 2604),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2322),
-        ("👩🏽\u{200d}🤝\u{200d}👩🏻", 2121),
-        ("🚴🏼", 1942),
-        ("👩🏾\u{200d}🍼", 1320),
-        ("🐞", 2543),
-        ("🚍", 2795),
-        ("👩🏿\u{200d}🤝\u{200d}👩🏻", 2129),
-        ("🔋", 3101),
-        ("🍈", 2582),
-        ("👨🏾\u{200d}✈\u{fe0f}", 1086),
-        ("🧛🏻", 1431),
-        ("💁🏼\u{200d}♂\u{fe0f}", 742),
-        ("🧔🏼\u{200d}♀", 550),
-        ("👳🏾\u{200d}♂\u{fe0f}", 1236),
-        ("👨🏻\u{200d}🎤", 1047),
-        ("💏🏾", 2189),
-        ("🇧🇳", 3540),
-        ("🏊🏾\u{200d}♀", 1902),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏿", 2372),
-        ("👩🏾\u{200d}✈", 1092),
-        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2247),
-        ("🌌", 2907),
-        ("🤸🏼\u{200d}♀", 1990),
-        ("👫🏽", 2136),
-        ("🤝🏻", 397),
-        ("🦫", 2481),
-        ("🧏\u{200d}♂\u{fe0f}", 776),
-        ("🙇🏾\u{200d}♀\u{fe0f}", 804),
-        ("💆🏿", 1496),
-        ("🧍", 1563),
-        ("🧑🏿\u{200d}💻", 1027),
-        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏾", 2288),
-        ("🚶🏿", 1532),
-        ("🤵🏻\u{200d}♀\u{fe0f}", 1269),
-        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏿", 2302),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", 2279),
-        ("🇦🇩", 3513),
-        ("🍢", 2661),
-        ("🙇🏼\u{200d}♂\u{fe0f}", 796),
-        ("◀\u{fe0f}", 3361),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2219),
-        ("🇳🇬", 3681),
-        ("🏃🏽\u{200d}➡\u{fe0f}", 1746),
-        ("🏃\u{200d}♂\u{200d}➡", 1755),
-        ("🙋🏽\u{200d}♀", 767),
-        ("⛹🏻", 1905),
-        ("👆🏿", 305),
-        ("🕵🏿", 1153),
-        ("🪠", 3255),
-        ("🫄🏻", 1305),
-        ("👨\u{200d}🦯\u{200d}➡\u{fe0f}", 1635),
-        ("🍬", 2685),
-        ("🤟🏽", 273),
-        ("👩🏾\u{200d}🎤", 1056),
-        ("🚚", 2808),
-        ("💔", 139),
-        ("🇸🇻", 3727),
-        ("🧑🏾\u{200d}❤\u{200d}🧑🏽", 2309),
-        ("🇦🇫", 3515),
-        ("🧑🏼\u{200d}🤝\u{200d}🧑🏽", 2092),
-        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏼", 2312),
-        ("🙌🏾", 376),
-        ("👫🏼", 2135),
-        ("👪", 2422),
-        ("👩🏼\u{200d}❤\u{200d}👩🏿", 2380),
-        ("🏊🏼", 1888),
-        ("💑🏼", 2291),
-        ("🧎🏿\u{200d}♂\u{200d}➡\u{fe0f}", 1616),
-        ("🧏🏾\u{200d}♂", 780),
-        ("💲", 3396),
-        ("🏃\u{200d}➡", 1743),
-        ("➡", 3309),
-        ("👶🏿", 499),
-        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2249),
-        ("🧔\u{200d}♂", 542),
-        ("🎚", 3079),
-        ("👩🏽\u{200d}⚕\u{fe0f}", 857),
-        ("😸", 118),
-        ("🧍🏻", 1564),
-        ("⛹🏾\u{200d}♂", 1914),
-        ("🧘🏻\u{200d}♂", 2058),
-        ("👩🏾\u{200d}🦰", 588),
-        ("🇬🇸", 3606),
-        ("👷🏽\u{200d}♂\u{fe0f}", 1199),
-        ("〰\u{fe0f}", 3394),
-        ("🇹🇻", 3745),
-        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2260),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", 2267),
-        ("🚶\u{200d}➡\u{fe0f}", 1545),
-        ("🐎", 2450),
-        ("🇵🇫", 3692),
-        ("🛋", 3252),
-        ("🎟\u{fe0f}", 2953),
-        ("🧑🏻\u{200d}✈\u{fe0f}", 1077),
-        ("🍳", 2643),
-        ("🪀", 2989),
-        ("👩🏻\u{200d}🦳", 609),
-        ("🌱", 2565),
-        ("👨🏽\u{200d}❤\u{200d}👨🏽", 2356),
-        ("🦥", 2488),
-        ("🧽", 3269),
-        ("🤸🏻", 1977),
-        ("🧞\u{200d}♂\u{fe0f}", 1485),
-        ("📽", 3117),
-        ("🤟🏻", 271),
-        ("🧑🏾\u{200d}🦱", 606),
-        ("🧑🏼\u{200d}🔬", 1006),
-        ("🔚", 3324),
-        ("🧑🏾\u{200d}🔧", 954),
-        ("👳🏿\u{200d}♀\u{fe0f}", 1243),
-        ("👩\u{200d}⚖", 908),
-        ("⚰\u{fe0f}", 3273),
-        ("🧑🏽\u{200d}🚒", 1115),
-        ("🤽🏽\u{200d}♂\u{fe0f}", 2006),
-        ("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩", 2263),
-        ("🥸", 71),
-        ("⛹🏼\u{200d}♀\u{fe0f}", 1918),
-        ("🪳", 2545),
-        ("📟", 3099),
-        ("👨🏿\u{200d}🦳", 571),
-        ("👯\u{200d}♂\u{fe0f}", 1780),
-        ("🤾🏽\u{200d}♀", 2030),
-        ("🦔", 2482),
-        ("✝\u{fe0f}", 3334),
-        ("🧑\u{200d}💻", 1022),
-        ("🇧🇴", 3541),
-        ("🥯", 2624),
-        ("👩🏽\u{200d}🦽", 1716),
-        ("♨\u{fe0f}", 2775),
-        ("#\u{20e3}", 3418),
-        ("💆🏽\u{200d}♂\u{fe0f}", 1500),
-        ("🍑", 2593),
-        ("👨\u{200d}👦", 2408),
-        ("🚶🏾\u{200d}➡", 1549),
-        ("👨\u{200d}🦽\u{200d}➡", 1707),
-        ("👱🏿\u{200d}♂", 643),
-        ("🖥", 3105),
-        ("🕎", 3338),
-        ("🚀", 2852),
-        ("🧎🏾\u{200d}♀\u{200d}➡", 1609),
-        ("🧚🏾", 1416),
-        ("👨🏼\u{200d}🚒", 1120),
-        ("📸", 3121),
-        ("4\u{20e3}", 3424),
-        ("🤹\u{200d}♂", 2039),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2227),
-        ("🚵🏾\u{200d}♂", 1968),
-        ("👩🏻\u{200d}🌾", 927),
-        ("🧏🏻\u{200d}♂", 777),
-        ("💖", 132),
-        ("👨🏻\u{200d}🦼", 1666),
-        ("🧎\u{200d}♀\u{fe0f}\u{200d}➡", 1605),
-        ("👂🏾", 470),
-        ("🗺\u{fe0f}", 2720),
-        ("👨🏼\u{200d}🦰", 556),
-        ("🐷", 2459),
-        ("🙆🏽\u{200d}♀\u{fe0f}", 731),
-        ("🏊🏻\u{200d}♂\u{fe0f}", 1893),
-        ("👨🏼\u{200d}🔧", 958),
-        ("🧚\u{200d}♀\u{fe0f}", 1424),
-        ("🧑🏼\u{200d}🎤", 1042),
-        ("🖕🏿", 311),
-        ("⏸\u{fe0f}", 3368),
-        ("🧏🏿\u{200d}♂", 781),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2256),
-        ("🫸🏻", 229),
-        ("🛠", 3211),
-        ("👲🏼", 1246),
-        ("✋🏻", 187),
-        ("👷🏿\u{200d}♀\u{fe0f}", 1207),
-        ("☯", 3333),
-        ("💂🏿\u{200d}♂", 1177),
-        ("💇🏼\u{200d}♂", 1517),
-        ("👐🏾", 388),
-        ("🧑🏿\u{200d}✈", 1081),
-        ("🙋🏼", 754),
-        ("🏧", 3281),
-        ("🚶🏾\u{200d}♀\u{fe0f}\u{200d}➡", 1555),
-        ("🔖", 3146),
-        ("🙅🏿\u{200d}♂", 709),
-        ("🚣🏽\u{200d}♀", 1883),
-        ("👷\u{200d}♂\u{fe0f}", 1196),
-        ("⚒\u{fe0f}", 3210),
-        ("🏃🏾\u{200d}♀\u{fe0f}\u{200d}➡", 1753),
-        ("🚶\u{200d}♂", 1533),
-        ("🦍", 2430),
-        ("⛹🏼\u{200d}♂\u{fe0f}", 1912),
-        ("🚈", 2788),
-        ("🧮", 3114),
-        ("🫅", 1208),
-        ("👱\u{200d}♀\u{fe0f}", 632),
-        ("🇭🇹", 3615),
-        ("🧑🏻\u{200d}❤\u{200d}🧑🏾", 2297),
-        ("🏋\u{200d}♂\u{fe0f}", 1928),
-        ("🧛🏿\u{200d}♀", 1447),
-        ("👨\u{200d}🦼\u{200d}➡\u{fe0f}", 1671),
-        ("*\u{20e3}", 3419),
-        ("🙂", 8),
-        ("🧏\u{200d}♀", 782),
-        ("🤜🏽", 363),
-        ("🏄🏿\u{200d}♂\u{fe0f}", 1861),
-        ("⏯\u{fe0f}", 3360),
-        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2254),
-        ("🧎🏾\u{200d}♂", 1591),
-        ("👮🏽", 1133),
-        ("💃🏼", 1763),
-        ("🧑🏿\u{200d}🔧", 955),
-        ("🚴🏻\u{200d}♂", 1947),
-        ("👩🏻\u{200d}🎨", 1071),
-        ("🐯", 2444),
-        ("🤌🏻", 241),
-        ("🚣🏿\u{200d}♂\u{fe0f}", 1879),
-        ("🤸🏻\u{200d}♀\u{fe0f}", 1989),
-        ("👫🏻", 2134),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2333),
-        ("🚶🏻\u{200d}♀\u{fe0f}", 1540),
-        ("🛤\u{fe0f}", 2823),
-        ("🥙", 2640),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", 2276),
-        ("🤵🏽\u{200d}♀", 1271),
-        ("👩🏽\u{200d}🔬", 1019),
-        ("🤷🏾\u{200d}♀", 840),
-        ("🚶🏼\u{200d}♀\u{200d}➡", 1553),
-        ("🎵", 3076),
-        ("🍇", 2581),
-        ("🤷", 824),
-        ("🧖🏽", 1785),
-        ("2\u{fe0f}\u{20e3}", 3422),
-        ("🉑", 3462),
-        ("👩\u{200d}👩\u{200d}👦\u{200d}👦", 2406),
-        ("🧑🏽\u{200d}🦳", 617),
-        ("🗾", 2721),
-        ("🧑🏼\u{200d}🦽\u{200d}➡", 1697),
-        ("🇨🇰", 3557),
-        ("🐩", 2436),
-        ("🗄\u{fe0f}", 3199),
-        ("📶", 3375),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2232),
-        ("🅱", 3438),
-        ("👰🏽\u{200d}♀\u{fe0f}", 1289),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2250),
-        ("🧑🏻\u{200d}🎓", 861),
-        ("🕤", 2882),
-        ("🌚", 2896),
-        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", 2208),
-        ("🧘🏾\u{200d}♂", 2061),
-        ("⏺\u{fe0f}", 3370),
-        ("🗝", 3206),
-        ("🇷🇴", 3706),
-        ("🉐", 3458),
-        ("👷🏻\u{200d}♂\u{fe0f}", 1197),
-        ("⚾", 2962),
-        ("🌲", 2567),
-        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏻", 2195),
-        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2257),
-        ("🐻", 2484),
-        ("👬🏽", 2162),
-        ("🌡", 2899),
-        ("🚴\u{200d}♂", 1946),
-        ("💂🏿\u{200d}♀", 1183),
-        ("🤽🏽", 2000),
-        ("🐆", 2446),
-        ("🏁", 3504),
-        ("🤴🏻", 1215),
-        ("🍁", 2575),
-        ("◼", 3488),
-        ("🙂\u{200d}↕", 51),
-        ("👩🏾\u{200d}🦯\u{200d}➡\u{fe0f}", 1651),
-        ("🏃🏿\u{200d}♀\u{200d}➡\u{fe0f}", 1754),
-        ("🤟🏿", 275),
-        ("🇬🇬", 3597),
-        ("🛒", 3271),
-        ("🕔", 2873),
-        ("⚱", 3275),
-        ("🕵🏼\u{200d}♂", 1156),
-        ("👩🏻\u{200d}💻", 1035),
-        ("💆🏽", 1494),
-        ("🏄🏻\u{200d}♀", 1863),
-        ("👩🏾\u{200d}✈\u{fe0f}", 1092),
-        ("✉", 3158),
-        ("🧖🏻\u{200d}♂", 1789),
-        ("🏋\u{200d}♀", 1934),
-        ("🤷🏿", 829),
-        ("💂🏽", 1169),
-        ("🤶🏾", 1350),
-        ("🧗🏾\u{200d}♀", 1816),
-        ("💁🏼\u{200d}♂", 742),
-        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏽", 2309),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2228),
-        ("🧙🏻", 1395),
-        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏽", 2192),
-        ("🤵🏾\u{200d}♀\u{fe0f}", 1272),
-        ("👨🏿\u{200d}🦯\u{200d}➡\u{fe0f}", 1640),
-        ("🚣🏿\u{200d}♀", 1885),
-        ("🥶", 64),
-        ("👨🏼\u{200d}🎓", 868),
-        ("🇩🇪", 3570),
-        ("🥠", 2668),
-        ("🧏🏽\u{200d}♂\u{fe0f}", 779),
-        ("🚛", 2809),
-        ("😏", 43),
-        ("🚯", 3300),
-        ("🏂🏻", 1827),
-        ("🍊", 2584),
-        ("💇\u{200d}♀\u{fe0f}", 1521),
-        ("🦹🏻\u{200d}♀", 1389),
-        ("🇰🇳", 3637),
-        ("💆🏾\u{200d}♂\u{fe0f}", 1501),
-        ("🏳\u{fe0f}", 3508),
-        ("🧚🏽\u{200d}♀", 1427),
-        ("🏃🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1753),
-        ("💑🏽", 2292),
-        ("🚶🏻\u{200d}➡\u{fe0f}", 1546),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏾", 2271),
-        ("👶🏾", 498),
-        ("👩\u{200d}👧", 2415),
-        ("🚶\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1557),
-        ("🗡\u{fe0f}", 3212),
-        ("🙋🏿\u{200d}♂", 763),
-        ("👩\u{200d}🦳", 608),
-        ("☝🏿", 323),
-        ("🚴🏼\u{200d}♂", 1948),
-        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2248),
-        ("🚖", 2803),
-        ("🤷🏾\u{200d}♂", 834),
-        ("⛏", 3209),
-        ("🧗🏽\u{200d}♀\u{fe0f}", 1815),
-        ("🏌🏾\u{200d}♀\u{fe0f}", 1848),
-        ("⛱\u{fe0f}", 2925),
-        ("🦏", 2472),
-        ("😖", 94),
-        ("🧑🏾\u{200d}✈\u{fe0f}", 1080),
-        ("👨🏼\u{200d}🏭", 976),
-        ("🕊\u{fe0f}", 2502),
-        ("🕸", 2547),
-        ("👱\u{200d}♀", 632),
-        ("🍗", 2629),
-        ("👃🏻", 479),
-        ("🇸🇱", 3720),
-        ("💱", 3395),
-        ("🗒", 3184),
-        ("🗼", 2757),
-        ("🧑🏽\u{200d}⚖", 899),
-        ("💂🏼\u{200d}♂\u{fe0f}", 1174),
-        ("👼🏽", 1337),
-        ("🇸🇨", 3712),
-        ("📯", 3072),
-        ("🎣", 2982),
-        ("🫴🏽", 219),
-        ("👩🏽\u{200d}🦰", 587),
-        ("🚵🏿\u{200d}♂", 1969),
-        ("🙆🏿\u{200d}♂\u{fe0f}", 727),
-        ("💂🏼\u{200d}♂", 1174),
-        ("🧏\u{200d}♀\u{fe0f}", 782),
-        ("🧎\u{200d}♀", 1593),
-        ("✌🏽", 255),
-        ("🙋🏿\u{200d}♀\u{fe0f}", 769),
-        ("🧔🏽\u{200d}♀", 551),
-        ("🏌🏼\u{200d}♂\u{fe0f}", 1840),
-        ("🧎\u{200d}➡", 1599),
-        ("👩\u{200d}❤\u{fe0f}\u{200d}👩", 2367),
-        ("☹", 79),
-        ("🏋🏾", 1926),
-        ("⏳", 2857),
-        ("🦸🏼\u{200d}♂", 1366),
-        ("🅰\u{fe0f}", 3436),
-        ("🎫", 2954),
-        ("💢", 156),
-        ("🛀🏿", 2074),
-        ("🚶🏿\u{200d}♂\u{200d}➡", 1562),
-        ("🫲🏼", 206),
-        ("🧀", 2627),
-        ("🕵🏾\u{200d}♂", 1158),
-        ("💨", 160),
-        ("🇩🇿", 3576),
-        ("🧝🏾\u{200d}♂\u{fe0f}", 1476),
-        ("🔍", 3124),
-        ("🙆🏻\u{200d}♂", 723),
-        ("🤷\u{200d}♀", 836),
-        ("🇱🇺", 3651),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2327),
-        ("🥓", 2631),
-        ("🏳\u{fe0f}\u{200d}⚧\u{fe0f}", 3510),
-        ("🧑🏼\u{200d}🎓", 862),
-        ("🙅🏼\u{200d}♀", 712),
-        ("🧜", 1448),
-        ("🔕", 3074),
-        ("👩🏻\u{200d}🦲", 621),
-        ("▫", 3493),
-        ("🇳🇱", 3683),
-        ("🥝", 2597),
-        ("🤔", 34),
-        ("🙆🏻", 717),
-        ("🛺", 2816),
-        ("🏃🏾\u{200d}♂\u{200d}➡", 1759),
-        ("🏃🏻\u{200d}♀\u{200d}➡\u{fe0f}", 1750),
-        ("🤞🏿", 263),
-        ("🇯🇴", 3630),
-        ("🕯\u{fe0f}", 3126),
-        ("🕑", 2867),
-        ("🦸🏽\u{200d}♂\u{fe0f}", 1367),
-        ("🤵🏿\u{200d}♂", 1267),
-        ("👩🏽\u{200d}❤\u{200d}👨🏼", 2330),
-        ("👩\u{200d}💻", 1034),
-        ("🗣", 2418),
-        ("🏐", 2965),
-        ("🧑🏼\u{200d}🤝\u{200d}🧑🏾", 2093),
-        ("👨\u{200d}🏭", 974),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2251),
-        ("🧔🏾", 540),
-        ("🤸\u{200d}♀", 1988),
-        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏿", 2202),
-        ("🧱", 2735),
-        ("🤷🏼", 826),
-        ("💪", 446),
-        ("👨\u{200d}🦽", 1701),
-        ("🧗🏿\u{200d}♂", 1811),
-        ("🤿", 2983),
-        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2239),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", 2323),
-        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾", 2253),
-        ("👩🏻\u{200d}🦽\u{200d}➡\u{fe0f}", 1720),
-        ("💃🏾", 1765),
-        ("🧚🏽\u{200d}♀\u{fe0f}", 1427),
-        ("🚶🏼\u{200d}♀\u{fe0f}", 1541),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼", 2221),
-        ("💕", 136),
-        ("🏂🏿", 1831),
-        ("👱", 524),
-        ("👫🏾", 2137),
-        ("😥", 90),
-        ("✋🏿", 191),
-        ("🤏🏿", 251),
-        ("🚜", 2810),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2236),
-        ("🧑🏻\u{200d}🦽\u{200d}➡", 1696),
-        ("🧑🏽\u{200d}❤\u{200d}🧑🏾", 2305),
-        ("🕋", 2764),
-        ("🚵🏻\u{200d}♀\u{fe0f}", 1971),
-        ("🧝🏻\u{200d}♀\u{fe0f}", 1479),
-        ("▶", 3357),
-        ("🪪", 3280),
-        ("👳🏼", 1228),
-        ("🧲", 3231),
-        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", 2337),
-        ("🧏🏽", 773),
-        ("🏊🏽\u{200d}♀", 1901),
-        ("🧑🏼\u{200d}⚖\u{fe0f}", 898),
-        ("⚜\u{fe0f}", 3399),
-        ("👩\u{200d}🦽\u{200d}➡\u{fe0f}", 1719),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2246),
-        ("👨🏼\u{200d}🤝\u{200d}👨🏾", 2171),
-        ("🧑🏼\u{200d}⚕\u{fe0f}", 844),
-        ("🧛🏾\u{200d}♂", 1440),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏿", 2280),
-        ("🐈\u{200d}⬛", 2442),
-        ("🤹🏾\u{200d}♀", 2049),
-        ("🧘🏼\u{200d}♀\u{fe0f}", 2065),
-        ("✖", 3382),
-        ("👨🏾\u{200d}🦰", 558),
-        ("👽", 114),
-        ("🛳", 2837),
-        ("🚵\u{200d}♀", 1970),
-        ("🧑🏾\u{200d}🦽\u{200d}➡\u{fe0f}", 1699),
-        ("🙍🏽\u{200d}♀", 677),
-        ("🙅🏻\u{200d}♀", 711),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏻", 2368),
-        ("👉🏼", 296),
-        ("🫳🏽", 213),
-        ("🥉", 2960),
-        ("🔀", 3354),
-        ("🫣", 32),
-        ("💇🏿\u{200d}♀", 1526),
-        ("👨🏿\u{200d}❤\u{200d}👨🏿", 2366),
-        ("📢", 3070),
-        ("🧍🏻\u{200d}♂", 1570),
-        ("🤘🏿", 281),
-        ("👩🏽\u{200d}🎓", 875),
-        ("☃", 2928),
-        ("🧎🏿\u{200d}♂\u{fe0f}\u{200d}➡", 1616),
-        ("🧧", 2949),
-        ("*\u{fe0f}\u{20e3}", 3419),
-        ("💪🏿", 451),
-        ("🍛", 2657),
-        ("📥", 3163),
-        ("🚠", 2849),
-        ("👩🏿\u{200d}⚖\u{fe0f}", 913),
-        ("🤵\u{200d}♀", 1268),
-        ("🇦🇺", 3525),
-        ("👱🏼\u{200d}♀", 634),
-        ("⏰", 2859),
-        ("🧑🏽\u{200d}🦱", 605),
-        ("🦕", 2523),
-        ("👨🏽\u{200d}🤝\u{200d}👨🏼", 2174),
-        ("🇻🇬", 3758),
-        ("💂\u{200d}♂", 1172),
-        ("🈳", 3465),
-        ("👨🏽\u{200d}❤\u{200d}👨🏿", 2358),
-        ("🤸🏼\u{200d}♂\u{fe0f}", 1984),
-        ("🕘", 2881),
-        ("🏊🏿\u{200d}♂\u{fe0f}", 1897),
-        ("🙋🏾", 756),
-        ("🤷🏼\u{200d}♀\u{fe0f}", 838),
-        ("📌", 3191),
-        ("🤌🏽", 243),
-        ("🏑", 2972),
-        ("👩🏿\u{200d}🤝\u{200d}👨🏼", 2156),
-        ("🧚🏾\u{200d}♂\u{fe0f}", 1422),
-        ("🙎🏼\u{200d}♂\u{fe0f}", 688),
-        ("🫶🏽", 381),
-        ("🇦🇸", 3523),
-        ("🛬", 2844),
-        ("🇭🇷", 3614),
-        ("😘", 17),
-        ("🇬🇺", 3608),
-        ("🏃🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", 1754),
-        ("🧎🏽\u{200d}♂\u{fe0f}\u{200d}➡", 1614),
-        ("🚩", 3505),
-        ("🚶🏽\u{200d}➡\u{fe0f}", 1548),
-        ("🥴", 65),
-        ("⚓", 2832),
-        ("✋🏼", 188),
-        ("🇦🇹", 3524),
-        ("🧖🏻\u{200d}♀\u{fe0f}", 1795),
-        ("🚣🏽\u{200d}♂\u{fe0f}", 1877),
-        ("🇺🇳", 3751),
-        ("🏌🏼\u{200d}♀", 1846),
-        ("👰🏼\u{200d}♂\u{fe0f}", 1282),
-        ("🔑", 3205),
-        ("🤳🏽", 443),
-        ("🌼", 2562),
-        ("🕠", 2874),
-        ("📆", 3183),
-        ("👨🏽\u{200d}✈", 1085),
-        ("💓", 134),
-        ("🥼", 3022),
-        ("🚴🏻\u{200d}♀\u{fe0f}", 1953),
-        ("🚫", 3297),
-        ("👧🏿", 517),
-        ("🦆", 2504),
-        ("🧑\u{200d}✈", 1076),
-        ("🧎🏻\u{200d}♂", 1588),
-        ("🤞🏽", 261),
-        ("👮🏻\u{200d}♂\u{fe0f}", 1137),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2253),
-        ("👨\u{200d}👧", 2410),
-        ("🤏🏾", 250),
-        ("🧎🏾\u{200d}➡", 1603),
-        ("🏋🏿\u{200d}♀\u{fe0f}", 1939),
-        ("👷🏻\u{200d}♀\u{fe0f}", 1203),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", 2245),
-        ("🙋🏼\u{200d}♀", 766),
-        ("☹\u{fe0f}", 79),
-        ("👩🏼\u{200d}🤝\u{200d}👨🏾", 2145),
-        ("✊🏽", 345),
-        ("🇱🇻", 3652),
-        ("↕", 3315),
-        ("🧙🏾\u{200d}♂\u{fe0f}", 1404),
-        ("🏣", 2744),
-        ("⏸", 3368),
-        ("🟧", 3480),
-        ("⚗", 3233),
-        ("🛖", 2738),
-        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏽", 2274),
-        ("🫱🏼\u{200d}🫲🏽", 407),
-        ("🇲🇱", 3662),
-        ("🐍", 2520),
-        ("🗨", 164),
-        ("🧎🏾\u{200d}♀\u{200d}➡\u{fe0f}", 1609),
-        ("💴", 3150),
-        ("🍐", 2592),
-        ("🙎🏿\u{200d}♀", 697),
-        ("⛔", 3296),
-        ("👩🏻\u{200d}🍳", 945),
-        ("🇸🇪", 3714),
-        ("🙅🏼\u{200d}♂", 706),
-        ("🦽", 2814),
-        ("🫷🏼", 224),
-        ("🫰🏾", 268),
-        ("💃🏽", 1764),
-        ("🧎🏾\u{200d}♀", 1597),
-        ("🥔", 2603),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏼", 2269),
-        ("👩🏽\u{200d}🦽\u{200d}➡\u{fe0f}", 1722),
-        ("👨🏾\u{200d}⚖", 906),
-        ("🤹🏽\u{200d}♀\u{fe0f}", 2048),
-        ("🧑🏼\u{200d}🦯\u{200d}➡", 1625),
-        ("🇳🇮", 3682),
-        ("👩🏻\u{200d}🎤", 1053),
-        ("👩\u{200d}🍼", 1316),
-        ("🇵🇪", 3691),
-        ("🔻", 3499),
-        ("⬆", 3307),
-        ("💂🏿\u{200d}♀\u{fe0f}", 1183),
-        ("🇲🇷", 3668),
-        ("👱🏽", 527),
-        ("🙍🏾\u{200d}♀", 678),
-        ("🏌\u{200d}♂\u{fe0f}", 1838),
-        ("🛼", 2820),
-        ("🧻", 3264),
-        ("🥘", 2644),
-        ("🔞", 3304),
-        ("🇹🇩", 3733),
-        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿", 2228),
-        ("🦷", 487),
-        ("🇮🇷", 3625),
-        ("⛰\u{fe0f}", 2724),
-        ("👨🏾\u{200d}🤝\u{200d}👨🏽", 2179),
-        ("🎋", 2943),
-        ("🏋🏼\u{200d}♀", 1936),
-        ("🧎🏻\u{200d}♂\u{200d}➡", 1612),
-        ("↖\u{fe0f}", 3314),
-        ("😒", 44),
-        ("🤷🏾\u{200d}♀\u{fe0f}", 840),
-        ("🧜🏿\u{200d}♀", 1465),
-        ("🫃🏼", 1300),
-        ("😜", 25),
-        ("🪹", 2578),
-        ("🤵🏽\u{200d}♀\u{fe0f}", 1271),
-        ("🍱", 2653),
-        ("🧓🏿", 649),
-        ("🚶\u{200d}♂\u{200d}➡", 1557),
-        ("👎🏼", 338),
-        ("✊🏼", 344),
-        ("👩🏻\u{200d}🤝\u{200d}👨🏼", 2139),
-        ("🙋🏻\u{200d}♀", 765),
-        ("🇮🇳", 3622),
-        ("🧝\u{200d}♀\u{fe0f}", 1478),
-        ("👩🏽\u{200d}⚖", 911),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", 2238),
-        ("🍋", 2585),
-        ("✖\u{fe0f}", 3382),
-        ("🇬🇷", 3605),
-        ("🛁", 3257),
-        ("🦹🏿\u{200d}♀\u{fe0f}", 1393),
-        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2332),
-        ("🚶", 1527),
-        ("💇🏼", 1511),
-        ("⭕", 3403),
-        ("🏃🏾", 1729),
-        ("👨🏿\u{200d}🎨", 1069),
-        ("🖐🏾", 184),
-        ("🇸🇦", 3710),
-        ("🦹🏼\u{200d}♀", 1390),
-        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏿", 2194),
-        ("🚣🏻", 1869),
-        ("🏛", 2733),
-        ("🇷🇺", 3708),
-        ("😶\u{200d}🌫", 42),
-        ("🧖🏽\u{200d}♀\u{fe0f}", 1797),
-        ("🧑🏿\u{200d}🍳", 937),
-        ("🏊🏻\u{200d}♂", 1893),
-        ("👩🏿\u{200d}🦼", 1682),
-        ("🇬🇱", 3600),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2358),
-        ("💁\u{200d}♀", 746),
-        ("🌬\u{fe0f}", 2919),
-        ("🤸🏾\u{200d}♀\u{fe0f}", 1992),
-        ("🗂", 3181),
-        ("👨\u{200d}👩\u{200d}👧\u{200d}👦", 2395),
-        ("❗", 3393),
-        ("♂\u{fe0f}", 3380),
-        ("👩🏻\u{200d}🤝\u{200d}👨🏽", 2140),
-        ("🚵🏻\u{200d}♂", 1965),
-        ("🤘", 276),
-        ("🎒", 3044),
-        ("🧑🏻", 519),
-        ("🔄", 3322),
-        ("👰🏾\u{200d}♀", 1290),
-        ("👈🏼", 290),
-        ("🥩", 2630),
-        ("💁🏼\u{200d}♀\u{fe0f}", 748),
-        ("🤹🏿", 2038),
-        ("👩🏾\u{200d}🦱", 600),
-        ("⤴", 3319),
-        ("🙍🏾\u{200d}♀\u{fe0f}", 678),
-        ("💌", 129),
-        ("🙍🏼\u{200d}♂", 670),
-        ("🇸🇧", 3711),
-        ("🤵🏻\u{200d}♀", 1269),
-        ("👨🏼\u{200d}🔬", 1012),
-        ("🧑\u{200d}🍼", 1328),
-        ("🧑🏻\u{200d}🦽\u{200d}➡\u{fe0f}", 1696),
-        ("🤾", 2015),
-        ("🫷🏾", 226),
-        ("👩🏽\u{200d}🤝\u{200d}👨🏼", 2148),
-        ("🧎🏼\u{200d}♀", 1595),
-        ("🧑🏻\u{200d}🦯", 1618),
-        ("🏖", 2728),
-        ("🚵🏻\u{200d}♂\u{fe0f}", 1965),
-        ("🚵🏼\u{200d}♀", 1972),
-        ("🙍\u{200d}♀\u{fe0f}", 674),
-        ("🧜🏽\u{200d}♀", 1463),
-        ("👃🏽", 481),
-        ("🇱🇷", 3648),
-        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", 2200),
-        ("🫳", 210),
-        ("🖖🏿", 197),
-        ("🚣🏼\u{200d}♂", 1876),
-        ("🏍\u{fe0f}", 2812),
-        ("⏭\u{fe0f}", 3359),
-        ("🔦", 3128),
-        ("🧎🏼\u{200d}♂", 1589),
-        ("🏃🏼\u{200d}♂\u{fe0f}\u{200d}➡", 1757),
-        ("👨🏾\u{200d}🍳", 942),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏼", 2369),
-        ("🇷🇪", 3705),
-        ("🏅", 2957),
-        ("🤳🏼", 442),
-        ("⛷", 1825),
-        ("🙍🏽\u{200d}♂\u{fe0f}", 671),
-        ("🧑🏽\u{200d}🤝\u{200d}🧑🏽", 2084),
-        ("🧑🏻\u{200d}✈", 1077),
-        ("🧛🏿\u{200d}♀\u{fe0f}", 1447),
-        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏾", 2297),
-        ("🗃", 3198),
-        ("🧑🏾\u{200d}🦼", 1657),
-        ("🧑🏽\u{200d}🎨", 1061),
-        ("⏲", 2861),
-        ("👨🏿\u{200d}⚕", 853),
-        ("🎴", 3011),
-        ("🎾", 2968),
-        ("♌", 3345),
-        ("🧏🏽\u{200d}♂", 779),
-        ("👩🏿\u{200d}🦯\u{200d}➡", 1652),
-        ("🫄🏼", 1306),
-        ("👨🏼\u{200d}🦯", 1631),
-        ("👨🏿\u{200d}🦼", 1670),
-        ("🚣🏾\u{200d}♀", 1884),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", 2328),
-        ("🔴", 3470),
-        ("🕸\u{fe0f}", 2547),
-        ("😁", 3),
-        ("🦸🏿", 1363),
-        ("🍫", 2684),
-        ("🧎🏻", 1582),
-        ("🧚\u{200d}♂", 1418),
-        ("♣", 3007),
-        ("🤛🏽", 357),
-        ("🚶🏾", 1531),
-        ("7\u{20e3}", 3427),
-        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2218),
-        ("👇🏽", 315),
-        ("🛕", 2761),
-        ("🦞", 2671),
-        ("🧍🏽\u{200d}♀\u{fe0f}", 1578),
-        ("👩🏽\u{200d}🦽\u{200d}➡", 1722),
-        ("👰🏾\u{200d}♂", 1284),
-        ("🕵🏼\u{200d}♀\u{fe0f}", 1162),
-        ("👐🏽", 387),
-        ("👩🏿\u{200d}🍼", 1321),
-        ("👳🏿\u{200d}♂", 1237),
-        ("🏳\u{200d}⚧", 3510),
-        ("🧑🏿\u{200d}🚒", 1117),
-        ("🧑🏼", 520),
-        ("👩\u{200d}❤\u{200d}💋\u{200d}👩", 2263),
-        ("🤮", 61),
-        ("🇳🇪", 3679),
-        ("🧑🏿\u{200d}🍼", 1333),
-        ("🆚", 3452),
-        ("🏋🏻", 1923),
-        ("🧟\u{200d}♀\u{fe0f}", 1489),
-        ("🦹🏿", 1381),
-        ("🧍🏼\u{200d}♀\u{fe0f}", 1577),
-        ("🦀", 2670),
-        ("🙋🏼\u{200d}♀\u{fe0f}", 766),
-        ("✊🏿", 347),
-        ("👷🏽\u{200d}♂", 1199),
-        ("👱🏽\u{200d}♂\u{fe0f}", 641),
-        ("🧑🏼\u{200d}❤\u{200d}🧑🏿", 2302),
-        ("🇸🇰", 3719),
-        ("🫶🏾", 382),
-        ("🥷🏼", 1186),
-        ("🧛🏽", 1433),
-        ("😎", 72),
-        ("👩🏻\u{200d}❤\u{200d}👩🏾", 2375),
-        ("🙏🏿", 427),
-        ("🚵🏻", 1959),
-        ("🔢", 3433),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2222),
-        ("🤬", 104),
-        ("👨🏽\u{200d}🔬", 1013),
-        ("🫀", 485),
-        ("🕡", 2876),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", 2254),
-        ("👨🏽\u{200d}🎤", 1049),
-        ("👳\u{200d}♂\u{fe0f}", 1232),
-        ("📁", 3179),
-        ("🧑🏼\u{200d}🏭", 970),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", 2218),
-        ("🙍🏾", 666),
-        ("🧙🏽\u{200d}♀", 1409),
-        ("🏋🏼\u{200d}♀\u{fe0f}", 1936),
-        ("📏", 3195),
-        ("🙅\u{200d}♀\u{fe0f}", 710),
-        ("🙋🏾\u{200d}♂", 762),
-        ("👨🏻\u{200d}🏫", 885),
-        ("🇳🇿", 3688),
-        ("🌑", 2887),
-        ("🧗🏽\u{200d}♂", 1809),
-        ("🤦🏽\u{200d}♂\u{fe0f}", 815),
-        ("👨🏾\u{200d}🦱", 564),
-        ("🤽🏼", 1999),
-        ("🇲🇸", 3669),
-        ("🦠", 2552),
-        ("👅", 491),
-        ("🏒", 2973),
-        ("🏌🏽\u{200d}♀\u{fe0f}", 1847),
-        ("↙\u{fe0f}", 3312),
-        ("🐣", 2497),
-        ("🧛🏿", 1435),
-        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2252),
-        ("🙆🏼\u{200d}♀", 730),
-        ("🧗🏼\u{200d}♂", 1808),
-        ("👩\u{200d}👧\u{200d}👧", 2417),
-        ("👨🏿\u{200d}❤\u{200d}👨🏼", 2364),
-        ("🛡", 3217),
-        ("🇩🇰", 3573),
-        ("🥃", 2702),
-        ("🤷\u{200d}♂\u{fe0f}", 830),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", 2270),
-        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", 2252),
-        ("🏃🏻\u{200d}♀\u{fe0f}", 1738),
-        ("☠\u{fe0f}", 108),
-        ("💆🏿\u{200d}♀", 1508),
-        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2257),
-        ("🧑\u{200d}🦰", 590),
-        ("🏃🏼", 1727),
-        ("🧭", 2722),
-        ("🚦", 2829),
-        ("🙇🏾\u{200d}♂\u{fe0f}", 798),
-        ("🎢", 2779),
-        ("✳\u{fe0f}", 3412),
-        ("🧛🏽\u{200d}♂", 1439),
-        ("🙆🏿\u{200d}♀", 733),
-        ("👎", 336),
-        ("👩🏿\u{200d}❤\u{200d}👨🏻", 2337),
-        ("🧜\u{200d}♂\u{fe0f}", 1454),
-        ("🤾🏾\u{200d}♂", 2025),
-        ("🧎🏼\u{200d}➡\u{fe0f}", 1601),
-        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏻", 2377),
-        ("🍌", 2587),
-        ("📘", 3135),
-        ("🧏🏼\u{200d}♀", 784),
-        ("🏃\u{200d}♂\u{200d}➡\u{fe0f}", 1755),
-        ("🧑🏾\u{200d}💻", 1026),
-        ("🧑🏻\u{200d}🦳", 615),
-        ("👷🏿\u{200d}♀", 1207),
-        ("🧑🏻\u{200d}🤝\u{200d}🧑🏾", 2089),
-        ("🧑🏼\u{200d}🤝\u{200d}🧑🏼", 2083),
-        ("🧔🏽\u{200d}♂\u{fe0f}", 545),
-        ("🧑🏾\u{200d}🦲", 630),
-        ("🦹🏿\u{200d}♂", 1387),
-        ("🤾🏻", 2016),
-        ("👵", 656),
-        ("🙋\u{200d}♂\u{fe0f}", 758),
-        ("🐱", 2440),
-        ("🥑", 2601),
-        ("👩🏽\u{200d}✈\u{fe0f}", 1091),
-        ("🏌🏻", 1833),
-        ("💇🏼\u{200d}♀\u{fe0f}", 1523),
-        ("🧎🏽\u{200d}♂\u{200d}➡", 1614),
-        ("🏋🏼", 1924),
-        ("🧛\u{200d}♀\u{fe0f}", 1442),
-        ("⬅\u{fe0f}", 3313),
-        ("🚣🏻\u{200d}♀\u{fe0f}", 1881),
-        ("🪦", 3274),
-        ("👨\u{200d}🏫", 884),
-        ("⛎", 3353),
-        ("👨🏾\u{200d}🦯\u{200d}➡\u{fe0f}", 1639),
-        ("🧙🏽\u{200d}♂\u{fe0f}", 1403),
-        ("👵🏼", 658),
-        ("👨🏿\u{200d}🍼", 1327),
-        ("🇲🇩", 3656),
-        ("🚻", 3287),
-        ("🅾\u{fe0f}", 3447),
-        ("🧚🏿\u{200d}♀\u{fe0f}", 1429),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", 2284),
-        ("🙍🏻\u{200d}♂\u{fe0f}", 669),
-        ("🧑🏻\u{200d}🦯\u{200d}➡", 1624),
-        ("🚣🏾", 1872),
-        ("🫃🏾", 1302),
-        ("🌠", 2906),
-        ("🤷🏿\u{200d}♀\u{fe0f}", 841),
-        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽", 2244),
-        ("👒", 3056),
-        ("🌕", 2891),
-        ("🤽🏾\u{200d}♂", 2007),
-        ("😿", 124),
-        ("🧑\u{200d}✈\u{fe0f}", 1076),
-        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", 2196),
-        ("☣\u{fe0f}", 3306),
-        ("👴🏽", 653),
-        ("🧑🏽\u{200d}🦲", 629),
-        ("🤹🏼\u{200d}♂", 2041),
-        ("🦺", 3023),
-        ("🧑\u{200d}🦼\u{200d}➡\u{fe0f}", 1659),
-        ("🙎🏾\u{200d}♀\u{fe0f}", 696),
-        ("🦉", 2506),
-        ("🇨🇲", 3559),
-        ("🧔🏻\u{200d}♀\u{fe0f}", 549),
-        ("👩🏽\u{200d}🤝\u{200d}👩🏼", 2122),
-        ("🎅🏾", 1344),
-        ("🧜🏼\u{200d}♂", 1456),
-        ("👩🏼\u{200d}🍳", 946),
-        ("🇳🇫", 3680),
-        ("🚲", 2817),
-        ("😯", 81),
-        ("🧍🏾\u{200d}♀", 1579),
-        ("🛍\u{fe0f}", 3043),
-        ("🧑🏽\u{200d}💻", 1025),
-        ("👷🏼", 1192),
-        ("🚴🏾\u{200d}♀\u{fe0f}", 1956),
-        ("🎮", 2995),
-        ("🤘🏼", 278),
-        ("🦸🏼", 1360),
-        ("🧛🏼\u{200d}♀\u{fe0f}", 1444),
-        ("🚣\u{200d}♀\u{fe0f}", 1880),
-        ("👩🏾\u{200d}🚀", 1110),
-        ("👩🏿\u{200d}💻", 1039),
-        ("🪑", 3253),
-        ("👱🏾", 528),
-        ("🚣\u{200d}♂", 1874),
-        ("🚶🏼\u{200d}♀\u{fe0f}\u{200d}➡", 1553),
-        ("🏃🏿\u{200d}♀\u{fe0f}", 1742),
-        ("⛹🏾\u{200d}♀\u{fe0f}", 1920),
-        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏻", 2385),
-        ("🥜", 2613),
-        ("🏋🏽\u{200d}♀\u{fe0f}", 1937),
-        ("👸🏼", 1222),
-        ("👟", 3047),
-        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏾", 2301),
-        ("🧑🏽\u{200d}🦯\u{200d}➡\u{fe0f}", 1626),
-        ("🇲🇲", 3663),
-        ("😫", 99),
-        ("☣", 3306),
-        ("💆\u{200d}♂\u{fe0f}", 1497),
-        ("🦴", 488),
-        ("🪻", 2564),
-        ("🔷", 3495),
-        ("⬅", 3313),
-        ("👖", 3026),
-        ("🚾", 3289),
-        ("🧙🏻\u{200d}♂\u{fe0f}", 1401),
-        ("🧑\u{200d}🦲", 626),
-        ("🌪\u{fe0f}", 2917),
-        ("⏹\u{fe0f}", 3369),
-        ("🧎🏽\u{200d}➡\u{fe0f}", 1602),
-        ("❤\u{fe0f}\u{200d}🔥", 140),
-        ("💪🏽", 449),
-        ("💆\u{200d}♂", 1497),
-        ("🧎🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1615),
-        ("🙏🏾", 426),
-        ("👲🏽", 1247),
-        ("🦪", 2674),
-        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏾", 2375),
-        ("🧑🏻\u{200d}🦲", 627),
-        ("🏌\u{200d}♀", 1844),
-        ("🤴", 1214),
-        ("🧛🏿\u{200d}♂\u{fe0f}", 1441),
-        ("🏓", 2975),
-        ("👩🏾\u{200d}💼", 1002),
-        ("👩🏻\u{200d}🏫", 891),
-        ("👊🏻", 349),
-        ("🤾🏿", 2020),
-        ("👁\u{200d}🗨\u{fe0f}", 163),
-        ("🤷🏽", 827),
-        ("🫓", 2622),
-        ("🦸\u{200d}♀", 1370),
-        ("🐨", 2486),
-        ("🤷🏼\u{200d}♂\u{fe0f}", 832),
-        ("🙋🏼\u{200d}♂\u{fe0f}", 760),
-        ("💂🏼\u{200d}♀", 1180),
-        ("🇹🇼", 3746),
-        ("🇨🇳", 3560),
-        ("🌉", 2774),
-        ("🥤", 2704),
-        ("🧝", 1466),
-        ("👨\u{200d}👨\u{200d}👦\u{200d}👦", 2401),
-        ("🫷", 222),
-        ("🦋", 2538),
-        ("🤶🏻", 1347),
-        ("🖖🏼", 194),
-        ("☝🏾", 322),
-        ("🩷", 143),
-        ("🧗🏿\u{200d}♂\u{fe0f}", 1811),
-        ("🥥", 2600),
-        ("🧚🏻\u{200d}♂\u{fe0f}", 1419),
-        ("🙍\u{200d}♀", 674),
-        ("🕵\u{fe0f}", 1148),
-        ("🏊🏼\u{200d}♀\u{fe0f}", 1900),
-        ("🦹\u{200d}♂\u{fe0f}", 1382),
-        ("💝", 131),
-        ("🙆\u{200d}♀\u{fe0f}", 728),
-        ("👩🏼\u{200d}🤝\u{200d}👩🏿", 2120),
-        ("🧑🏻\u{200d}🎨", 1059),
-        ("🧗🏾", 1804),
-        ("🇵🇸", 3700),
-        ("🏪", 2750),
-        ("🙅🏽\u{200d}♀\u{fe0f}", 713),
-        ("👩🏾\u{200d}⚕", 858),
-        ("🤦🏽\u{200d}♀\u{fe0f}", 821),
-        ("👩🏾\u{200d}🦽", 1717),
-        ("🖌\u{fe0f}", 3175),
-        ("🫳🏾", 214),
-        ("🚵\u{200d}♂", 1964),
-        ("⛳", 2980),
-        ("👩🏾\u{200d}🤝\u{200d}👨🏿", 2154),
-        ("🗯", 165),
-        ("👸🏻", 1221),
-        ("🈴", 3464),
-        ("👮🏻\u{200d}♂", 1137),
-        ("🏂🏼", 1828),
-        ("🍺", 2699),
-        ("🇮🇸", 3626),
-        ("👨", 530),
-        ("💐", 2553),
-        ("🚶🏼\u{200d}♂\u{fe0f}", 1535),
-        ("👨🏿\u{200d}💼", 997),
-        ("👩🏿\u{200d}🦼\u{200d}➡", 1688),
-        ("🏃🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", 1757),
-        ("🧑🏻\u{200d}🦯\u{200d}➡\u{fe0f}", 1624),
-        ("👼🏻", 1335),
-        ("🧍🏾\u{200d}♂", 1573),
-        ("🍮", 2687),
-        ("👩🏻\u{200d}🤝\u{200d}👩🏼", 2113),
-        ("🙅", 698),
-        ("🇮🇴", 3623),
-        ("🧖🏼\u{200d}♂\u{fe0f}", 1790),
-        ("🦡", 2492),
-        ("🧖🏼\u{200d}♀", 1796),
-        ("👩🏿\u{200d}🦰", 589),
-        ("👨🏼\u{200d}🦳", 568),
-        ("👩🏽\u{200d}✈", 1091),
-        ("👩🏿\u{200d}⚕\u{fe0f}", 859),
-        ("👳🏻\u{200d}♂\u{fe0f}", 1233),
-        ("💒", 2756),
-        ("🧑🏾\u{200d}🦽\u{200d}➡", 1699),
-        ("👩🏾\u{200d}❤\u{200d}👨🏻", 2333),
-        ("👣", 2427),
-        ("😬", 46),
-        ("🕟", 2872),
-        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", 2348),
-        ("🧑🏼\u{200d}✈", 1078),
-        ("💾", 3111),
-        ("🤵🏿\u{200d}♂\u{fe0f}", 1267),
-        ("🏚", 2740),
-    ],
-};
@@ -0,0 +1,12 @@
+// Code generated by `cargo run --package generate`. DO NOT EDIT.
+
+use crate::UnicodeVersion;
+
+/// The Unicode version of the previous release.
+pub const PREVIOUS: UnicodeVersion = UnicodeVersion { major: 15, minor: 1 };
+
+/// Names from the previous version that were renamed, sorted by name.
+pub static NAMES: &[(&str, usize)] = &[];
+
+/// Emojis from the previous version whose encoding changed, sorted.
+pub static ENCODINGS: &[(&str, usize)] = &[];
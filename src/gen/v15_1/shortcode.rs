@@ -1,5 +1,1925 @@
 // Code generated by `cargo run --package generate`. DO NOT EDIT.
 
+/// All shortcodes and their index into `EMOJIS` ordered by shortcode.
+pub const SORTED: &[(&str, u16)] = &[
+    ("+1", 330),
+    ("-1", 336),
+    ("100", 155),
+    ("1234", 3433),
+    ("1st_place_medal", 2958),
+    ("2nd_place_medal", 2959),
+    ("3rd_place_medal", 2960),
+    ("8ball", 2992),
+    ("a", 3436),
+    ("ab", 3437),
+    ("abacus", 3114),
+    ("abc", 3435),
+    ("abcd", 3432),
+    ("accept", 3462),
+    ("accordion", 3085),
+    ("adhesive_bandage", 3243),
+    ("adult", 518),
+    ("aerial_tramway", 2850),
+    ("afghanistan", 3515),
+    ("airplane", 2841),
+    ("aland_islands", 3527),
+    ("alarm_clock", 2859),
+    ("albania", 3518),
+    ("alembic", 3233),
+    ("algeria", 3576),
+    ("alien", 114),
+    ("ambulance", 2798),
+    ("american_samoa", 3523),
+    ("amphora", 2715),
+    ("anatomical_heart", 485),
+    ("anchor", 2832),
+    ("andorra", 3513),
+    ("angel", 1334),
+    ("anger", 156),
+    ("angola", 3520),
+    ("angry", 103),
+    ("anguilla", 3517),
+    ("anguished", 87),
+    ("ant", 2540),
+    ("antarctica", 3521),
+    ("antigua_barbuda", 3516),
+    ("apple", 2590),
+    ("aquarius", 3351),
+    ("argentina", 3522),
+    ("aries", 3341),
+    ("armenia", 3519),
+    ("arrow_backward", 3361),
+    ("arrow_double_down", 3367),
+    ("arrow_double_up", 3365),
+    ("arrow_down", 3311),
+    ("arrow_down_small", 3366),
+    ("arrow_forward", 3357),
+    ("arrow_heading_down", 3320),
+    ("arrow_heading_up", 3319),
+    ("arrow_left", 3313),
+    ("arrow_lower_left", 3312),
+    ("arrow_lower_right", 3310),
+    ("arrow_right", 3309),
+    ("arrow_right_hook", 3318),
+    ("arrow_up", 3307),
+    ("arrow_up_down", 3315),
+    ("arrow_up_small", 3364),
+    ("arrow_upper_left", 3314),
+    ("arrow_upper_right", 3308),
+    ("arrows_clockwise", 3321),
+    ("arrows_counterclockwise", 3322),
+    ("art", 3014),
+    ("articulated_lorry", 2809),
+    ("artificial_satellite", 2851),
+    ("artist", 1058),
+    ("aruba", 3526),
+    ("ascension_island", 3512),
+    ("asterisk", 3419),
+    ("astonished", 82),
+    ("astronaut", 1094),
+    ("athletic_shoe", 3047),
+    ("atm", 3281),
+    ("atom_symbol", 3329),
+    ("australia", 3525),
+    ("austria", 3524),
+    ("auto_rickshaw", 2816),
+    ("avocado", 2601),
+    ("axe", 3208),
+    ("azerbaijan", 3528),
+    ("b", 3438),
+    ("baby", 494),
+    ("baby_bottle", 2689),
+    ("baby_chick", 2498),
+    ("baby_symbol", 3288),
+    ("back", 3323),
+    ("bacon", 2631),
+    ("badger", 2492),
+    ("badminton", 2976),
+    ("bagel", 2624),
+    ("baggage_claim", 3292),
+    ("baguette_bread", 2621),
+    ("bahamas", 3544),
+    ("bahrain", 3535),
+    ("balance_scale", 3224),
+    ("bald_man", 572),
+    ("bald_woman", 620),
+    ("ballet_shoes", 3052),
+    ("balloon", 2940),
+    ("ballot_box", 3170),
+    ("ballot_box_with_check", 3405),
+    ("bamboo", 2944),
+    ("banana", 2587),
+    ("bangbang", 3388),
+    ("bangladesh", 3531),
+    ("banjo", 3090),
+    ("bank", 2747),
+    ("bar_chart", 3189),
+    ("barbados", 3530),
+    ("barber", 2780),
+    ("baseball", 2962),
+    ("basket", 3263),
+    ("basketball", 2964),
+    ("basketball_man", 1910),
+    ("basketball_woman", 1916),
+    ("bat", 2483),
+    ("bath", 2069),
+    ("bathtub", 3257),
+    ("battery", 3101),
+    ("beach_umbrella", 2728),
+    ("beans", 2614),
+    ("bear", 2484),
+    ("bearded_person", 536),
+    ("beaver", 2481),
+    ("bed", 3251),
+    ("bee", 2541),
+    ("beer", 2699),
+    ("beers", 2700),
+    ("beetle", 2542),
+    ("beginner", 3402),
+    ("belarus", 3548),
+    ("belgium", 3532),
+    ("belize", 3549),
+    ("bell", 3073),
+    ("bell_pepper", 2607),
+    ("bellhop_bell", 2854),
+    ("benin", 3537),
+    ("bento", 2653),
+    ("bermuda", 3539),
+    ("beverage_box", 2706),
+    ("bhutan", 3545),
+    ("bicyclist", 1940),
+    ("bike", 2817),
+    ("biking_man", 1946),
+    ("biking_woman", 1952),
+    ("bikini", 3037),
+    ("billed_cap", 3059),
+    ("biohazard", 3306),
+    ("bird", 2500),
+    ("birthday", 2680),
+    ("bison", 2454),
+    ("biting_lip", 493),
+    ("black_bird", 2513),
+    ("black_cat", 2442),
+    ("black_circle", 3477),
+    ("black_flag", 3507),
+    ("black_heart", 151),
+    ("black_joker", 3009),
+    ("black_large_square", 3486),
+    ("black_medium_small_square", 3490),
+    ("black_medium_square", 3488),
+    ("black_nib", 3172),
+    ("black_small_square", 3492),
+    ("black_square_button", 3503),
+    ("blond_haired_man", 638),
+    ("blond_haired_person", 524),
+    ("blond_haired_woman", 632),
+    ("blonde_woman", 632),
+    ("blossom", 2562),
+    ("blowfish", 2531),
+    ("blue_book", 3135),
+    ("blue_car", 2806),
+    ("blue_heart", 147),
+    ("blue_square", 3483),
+    ("blueberries", 2596),
+    ("blush", 12),
+    ("boar", 2461),
+    ("boat", 2834),
+    ("bolivia", 3541),
+    ("bomb", 3214),
+    ("bone", 488),
+    ("book", 3133),
+    ("bookmark", 3146),
+    ("bookmark_tabs", 3145),
+    ("books", 3137),
+    ("boom", 157),
+    ("boomerang", 3215),
+    ("boot", 3053),
+    ("bosnia_herzegovina", 3529),
+    ("botswana", 3547),
+    ("bouncing_ball_man", 1910),
+    ("bouncing_ball_person", 1904),
+    ("bouncing_ball_woman", 1916),
+    ("bouquet", 2553),
+    ("bouvet_island", 3546),
+    ("bow", 788),
+    ("bow_and_arrow", 3216),
+    ("bowing_man", 794),
+    ("bowing_woman", 800),
+    ("bowl_with_spoon", 2647),
+    ("bowling", 2970),
+    ("boxing_glove", 2977),
+    ("boy", 506),
+    ("brain", 484),
+    ("brazil", 3543),
+    ("bread", 2619),
+    ("breast_feeding", 1310),
+    ("bricks", 2735),
+    ("bride_with_veil", 1286),
+    ("bridge_at_night", 2774),
+    ("briefcase", 3178),
+    ("british_indian_ocean_territory", 3623),
+    ("british_virgin_islands", 3758),
+    ("broccoli", 2610),
+    ("broken_heart", 139),
+    ("broom", 3262),
+    ("brown_circle", 3476),
+    ("brown_heart", 150),
+    ("brown_square", 3485),
+    ("brunei", 3540),
+    ("bubble_tea", 2705),
+    ("bubbles", 3267),
+    ("bucket", 3265),
+    ("bug", 2539),
+    ("building_construction", 2734),
+    ("bulb", 3127),
+    ("bulgaria", 3534),
+    ("bullettrain_front", 2785),
+    ("bullettrain_side", 2784),
+    ("burkina_faso", 3533),
+    ("burrito", 2638),
+    ("burundi", 3536),
+    ("bus", 2794),
+    ("business_suit_levitating", 1773),
+    ("busstop", 2821),
+    ("bust_in_silhouette", 2419),
+    ("busts_in_silhouette", 2420),
+    ("butter", 2650),
+    ("butterfly", 2538),
+    ("cactus", 2570),
+    ("cake", 2681),
+    ("calendar", 3183),
+    ("call_me_hand", 282),
+    ("calling", 3096),
+    ("cambodia", 3634),
+    ("camel", 2467),
+    ("camera", 3120),
+    ("camera_flash", 3121),
+    ("cameroon", 3559),
+    ("camping", 2727),
+    ("canada", 3550),
+    ("canary_islands", 3617),
+    ("cancer", 3344),
+    ("candle", 3126),
+    ("candy", 2685),
+    ("canned_food", 2652),
+    ("canoe", 2835),
+    ("cape_verde", 3565),
+    ("capital_abcd", 3431),
+    ("capricorn", 3350),
+    ("car", 2804),
+    ("card_file_box", 3198),
+    ("card_index", 3186),
+    ("card_index_dividers", 3181),
+    ("caribbean_netherlands", 3542),
+    ("carousel_horse", 2776),
+    ("carpentry_saw", 3218),
+    ("carrot", 2604),
+    ("cartwheeling", 1976),
+    ("cat", 2440),
+    ("cat2", 2441),
+    ("cayman_islands", 3641),
+    ("cd", 3112),
+    ("central_african_republic", 3553),
+    ("ceuta_melilla", 3577),
+    ("chad", 3733),
+    ("chains", 3228),
+    ("chair", 3253),
+    ("champagne", 2695),
+    ("chart", 3157),
+    ("chart_with_downwards_trend", 3188),
+    ("chart_with_upwards_trend", 3187),
+    ("checkered_flag", 3504),
+    ("cheese", 2627),
+    ("cherries", 2594),
+    ("cherry_blossom", 2554),
+    ("chess_pawn", 3008),
+    ("chestnut", 2615),
+    ("chicken", 2495),
+    ("child", 500),
+    ("children_crossing", 3295),
+    ("chile", 3558),
+    ("chipmunk", 2480),
+    ("chocolate_bar", 2684),
+    ("chopsticks", 2709),
+    ("christmas_island", 3567),
+    ("christmas_tree", 2935),
+    ("church", 2759),
+    ("cinema", 3372),
+    ("circus_tent", 2781),
+    ("city_sunrise", 2773),
+    ("city_sunset", 2772),
+    ("cityscape", 2769),
+    ("cl", 3439),
+    ("clamp", 3223),
+    ("clap", 366),
+    ("clapper", 3118),
+    ("classical_building", 2733),
+    ("climbing", 1800),
+    ("climbing_man", 1806),
+    ("climbing_woman", 1812),
+    ("clinking_glasses", 2701),
+    ("clipboard", 3190),
+    ("clipperton_island", 3562),
+    ("clock1", 2865),
+    ("clock10", 2883),
+    ("clock1030", 2884),
+    ("clock11", 2885),
+    ("clock1130", 2886),
+    ("clock12", 2863),
+    ("clock1230", 2864),
+    ("clock130", 2866),
+    ("clock2", 2867),
+    ("clock230", 2868),
+    ("clock3", 2869),
+    ("clock330", 2870),
+    ("clock4", 2871),
+    ("clock430", 2872),
+    ("clock5", 2873),
+    ("clock530", 2874),
+    ("clock6", 2875),
+    ("clock630", 2876),
+    ("clock7", 2877),
+    ("clock730", 2878),
+    ("clock8", 2879),
+    ("clock830", 2880),
+    ("clock9", 2881),
+    ("clock930", 2882),
+    ("closed_book", 3132),
+    ("closed_lock_with_key", 3204),
+    ("closed_umbrella", 2922),
+    ("cloud", 2908),
+    ("cloud_with_lightning", 2916),
+    ("cloud_with_lightning_and_rain", 2910),
+    ("cloud_with_rain", 2914),
+    ("cloud_with_snow", 2915),
+    ("clown_face", 110),
+    ("clubs", 3007),
+    ("cn", 3560),
+    ("coat", 3029),
+    ("cockroach", 2545),
+    ("cocktail", 2697),
+    ("coconut", 2600),
+    ("cocos_islands", 3551),
+    ("coffee", 2691),
+    ("coffin", 3273),
+    ("coin", 3149),
+    ("cold_face", 64),
+    ("cold_sweat", 89),
+    ("collision", 157),
+    ("colombia", 3561),
+    ("comet", 2930),
+    ("comoros", 3636),
+    ("compass", 2722),
+    ("computer", 3104),
+    ("computer_mouse", 3108),
+    ("confetti_ball", 2942),
+    ("confounded", 94),
+    ("confused", 75),
+    ("congo_brazzaville", 3554),
+    ("congo_kinshasa", 3552),
+    ("congratulations", 3466),
+    ("construction", 2831),
+    ("construction_worker", 1190),
+    ("construction_worker_man", 1196),
+    ("construction_worker_woman", 1202),
+    ("control_knobs", 3080),
+    ("convenience_store", 2750),
+    ("cook", 932),
+    ("cook_islands", 3557),
+    ("cookie", 2679),
+    ("cool", 3440),
+    ("cop", 1130),
+    ("copyright", 3415),
+    ("coral", 2535),
+    ("corn", 2605),
+    ("costa_rica", 3563),
+    ("cote_divoire", 3556),
+    ("couch_and_lamp", 3252),
+    ("couple", 2133),
+    ("couple_with_heart", 2289),
+    ("couple_with_heart_man_man", 2341),
+    ("couple_with_heart_woman_man", 2315),
+    ("couple_with_heart_woman_woman", 2367),
+    ("couplekiss", 2185),
+    ("couplekiss_man_man", 2237),
+    ("couplekiss_man_woman", 2211),
+    ("couplekiss_woman_woman", 2263),
+    ("cow", 2455),
+    ("cow2", 2458),
+    ("cowboy_hat_face", 69),
+    ("crab", 2670),
+    ("crayon", 3176),
+    ("credit_card", 3155),
+    ("crescent_moon", 2895),
+    ("cricket", 2544),
+    ("cricket_game", 2971),
+    ("croatia", 3614),
+    ("crocodile", 2517),
+    ("croissant", 2620),
+    ("crossed_fingers", 258),
+    ("crossed_flags", 3506),
+    ("crossed_swords", 3213),
+    ("crown", 3055),
+    ("crutch", 3244),
+    ("cry", 91),
+    ("crying_cat_face", 124),
+    ("crystal_ball", 2993),
+    ("cuba", 3564),
+    ("cucumber", 2608),
+    ("cup_with_straw", 2704),
+    ("cupcake", 2682),
+    ("cupid", 130),
+    ("curacao", 3566),
+    ("curling_stone", 2987),
+    ("curly_haired_man", 560),
+    ("curly_haired_woman", 596),
+    ("curly_loop", 3409),
+    ("currency_exchange", 3395),
+    ("curry", 2657),
+    ("cursing_face", 104),
+    ("custard", 2687),
+    ("customs", 3291),
+    ("cut_of_meat", 2630),
+    ("cyclone", 2920),
+    ("cyprus", 3568),
+    ("czech_republic", 3569),
+    ("dagger", 3212),
+    ("dancer", 1761),
+    ("dancers", 1779),
+    ("dancing_men", 1780),
+    ("dancing_women", 1781),
+    ("dango", 2666),
+    ("dark_sunglasses", 3020),
+    ("dart", 2988),
+    ("dash", 160),
+    ("date", 3182),
+    ("de", 3570),
+    ("deaf_man", 776),
+    ("deaf_person", 770),
+    ("deaf_woman", 782),
+    ("deciduous_tree", 2568),
+    ("deer", 2453),
+    ("denmark", 3573),
+    ("department_store", 2752),
+    ("derelict_house", 2740),
+    ("desert", 2729),
+    ("desert_island", 2730),
+    ("desktop_computer", 3105),
+    ("detective", 1148),
+    ("diamond_shape_with_a_dot_inside", 3500),
+    ("diamonds", 3006),
+    ("diego_garcia", 3571),
+    ("disappointed", 96),
+    ("disappointed_relieved", 90),
+    ("disguised_face", 71),
+    ("diving_mask", 2983),
+    ("diya_lamp", 3130),
+    ("dizzy", 158),
+    ("dizzy_face", 66),
+    ("djibouti", 3572),
+    ("dna", 3236),
+    ("do_not_litter", 3300),
+    ("dodo", 2507),
+    ("dog", 2432),
+    ("dog2", 2433),
+    ("dollar", 3151),
+    ("dolls", 2945),
+    ("dolphin", 2527),
+    ("dominica", 3574),
+    ("dominican_republic", 3575),
+    ("donkey", 2449),
+    ("door", 3247),
+    ("dotted_line_face", 41),
+    ("doughnut", 2678),
+    ("dove", 2502),
+    ("dragon", 2522),
+    ("dragon_face", 2521),
+    ("dress", 3031),
+    ("dromedary_camel", 2466),
+    ("drooling_face", 55),
+    ("drop_of_blood", 3241),
+    ("droplet", 2932),
+    ("drum", 3091),
+    ("duck", 2504),
+    ("dumpling", 2667),
+    ("dvd", 3113),
+    ("e-mail", 3159),
+    ("eagle", 2503),
+    ("ear", 466),
+    ("ear_of_rice", 2571),
+    ("ear_with_hearing_aid", 472),
+    ("earth_africa", 2716),
+    ("earth_americas", 2717),
+    ("earth_asia", 2718),
+    ("ecuador", 3578),
+    ("egg", 2642),
+    ("eggplant", 2602),
+    ("egypt", 3580),
+    ("eight", 3428),
+    ("eight_pointed_black_star", 3413),
+    ("eight_spoked_asterisk", 3412),
+    ("eject_button", 3371),
+    ("el_salvador", 3727),
+    ("electric_plug", 3103),
+    ("elephant", 2470),
+    ("elevator", 3248),
+    ("elf", 1466),
+    ("elf_man", 1472),
+    ("elf_woman", 1478),
+    ("email", 3159),
+    ("empty_nest", 2578),
+    ("end", 3324),
+    ("england", 3770),
+    ("envelope", 3158),
+    ("envelope_with_arrow", 3161),
+    ("equatorial_guinea", 3604),
+    ("eritrea", 3582),
+    ("es", 3583),
+    ("estonia", 3579),
+    ("ethiopia", 3584),
+    ("eu", 3585),
+    ("euro", 3152),
+    ("european_castle", 2755),
+    ("european_post_office", 2745),
+    ("european_union", 3585),
+    ("evergreen_tree", 2567),
+    ("exclamation", 3393),
+    ("exploding_head", 68),
+    ("expressionless", 39),
+    ("eye", 490),
+    ("eye_speech_bubble", 163),
+    ("eyeglasses", 3019),
+    ("eyes", 489),
+    ("face_exhaling", 47),
+    ("face_holding_back_tears", 85),
+    ("face_in_clouds", 42),
+    ("face_with_diagonal_mouth", 76),
+    ("face_with_head_bandage", 59),
+    ("face_with_open_eyes_and_hand_over_mouth", 31),
+    ("face_with_peeking_eye", 32),
+    ("face_with_spiral_eyes", 67),
+    ("face_with_thermometer", 58),
+    ("facepalm", 806),
+    ("facepunch", 348),
+    ("factory", 2753),
+    ("factory_worker", 968),
+    ("fairy", 1412),
+    ("fairy_man", 1418),
+    ("fairy_woman", 1424),
+    ("falafel", 2641),
+    ("falkland_islands", 3588),
+    ("fallen_leaf", 2576),
+    ("family", 2422),
+    ("family_man_boy", 2408),
+    ("family_man_boy_boy", 2409),
+    ("family_man_girl", 2410),
+    ("family_man_girl_boy", 2411),
+    ("family_man_girl_girl", 2412),
+    ("family_man_man_boy", 2398),
+    ("family_man_man_boy_boy", 2401),
+    ("family_man_man_girl", 2399),
+    ("family_man_man_girl_boy", 2400),
+    ("family_man_man_girl_girl", 2402),
+    ("family_man_woman_boy", 2393),
+    ("family_man_woman_boy_boy", 2396),
+    ("family_man_woman_girl", 2394),
+    ("family_man_woman_girl_boy", 2395),
+    ("family_man_woman_girl_girl", 2397),
+    ("family_woman_boy", 2413),
+    ("family_woman_boy_boy", 2414),
+    ("family_woman_girl", 2415),
+    ("family_woman_girl_boy", 2416),
+    ("family_woman_girl_girl", 2417),
+    ("family_woman_woman_boy", 2403),
+    ("family_woman_woman_boy_boy", 2406),
+    ("family_woman_woman_girl", 2404),
+    ("family_woman_woman_girl_boy", 2405),
+    ("family_woman_woman_girl_girl", 2407),
+    ("farmer", 914),
+    ("faroe_islands", 3590),
+    ("fast_forward", 3358),
+    ("fax", 3100),
+    ("fearful", 88),
+    ("feather", 2508),
+    ("feet", 2493),
+    ("female_detective", 1160),
+    ("female_sign", 3379),
+    ("ferris_wheel", 2778),
+    ("ferry", 2838),
+    ("field_hockey", 2972),
+    ("fiji", 3587),
+    ("file_cabinet", 3199),
+    ("file_folder", 3179),
+    ("film_projector", 3117),
+    ("film_strip", 3116),
+    ("finland", 3586),
+    ("fire", 2931),
+    ("fire_engine", 2799),
+    ("fire_extinguisher", 3270),
+    ("firecracker", 2938),
+    ("firefighter", 1112),
+    ("fireworks", 2936),
+    ("first_quarter_moon", 2889),
+    ("first_quarter_moon_with_face", 2897),
+    ("fish", 2529),
+    ("fish_cake", 2664),
+    ("fishing_pole_and_fish", 2982),
+    ("fist", 342),
+    ("fist_left", 354),
+    ("fist_oncoming", 348),
+    ("fist_raised", 342),
+    ("fist_right", 360),
+    ("five", 3425),
+    ("flags", 2946),
+    ("flamingo", 2509),
+    ("flashlight", 3128),
+    ("flat_shoe", 3049),
+    ("flatbread", 2622),
+    ("fleur_de_lis", 3399),
+    ("flight_arrival", 2844),
+    ("flight_departure", 2843),
+    ("flipper", 2527),
+    ("floppy_disk", 3111),
+    ("flower_playing_cards", 3011),
+    ("flushed", 83),
+    ("flute", 3094),
+    ("fly", 2550),
+    ("flying_disc", 2969),
+    ("flying_saucer", 2853),
+    ("fog", 2918),
+    ("foggy", 2767),
+    ("folding_hand_fan", 3039),
+    ("fondue", 2646),
+    ("foot", 460),
+    ("football", 2966),
+    ("footprints", 2427),
+    ("fork_and_knife", 2711),
+    ("fortune_cookie", 2668),
+    ("fountain", 2765),
+    ("fountain_pen", 3173),
+    ("four", 3424),
+    ("four_leaf_clover", 2574),
+    ("fox_face", 2438),
+    ("fr", 3591),
+    ("framed_picture", 3013),
+    ("free", 3441),
+    ("french_guiana", 3596),
+    ("french_polynesia", 3692),
+    ("french_southern_territories", 3734),
+    ("fried_egg", 2643),
+    ("fried_shrimp", 2663),
+    ("fries", 2633),
+    ("frog", 2516),
+    ("frowning", 86),
+    ("frowning_face", 79),
+    ("frowning_man", 668),
+    ("frowning_person", 662),
+    ("frowning_woman", 674),
+    ("fu", 306),
+    ("fuelpump", 2825),
+    ("full_moon", 2891),
+    ("full_moon_with_face", 2901),
+    ("funeral_urn", 3275),
+    ("gabon", 3592),
+    ("gambia", 3601),
+    ("game_die", 2998),
+    ("garlic", 2611),
+    ("gb", 3593),
+    ("gear", 3222),
+    ("gem", 3065),
+    ("gemini", 3343),
+    ("genie", 1484),
+    ("genie_man", 1485),
+    ("genie_woman", 1486),
+    ("georgia", 3595),
+    ("ghana", 3598),
+    ("ghost", 113),
+    ("gibraltar", 3599),
+    ("gift", 2951),
+    ("gift_heart", 131),
+    ("ginger_root", 2616),
+    ("giraffe", 2469),
+    ("girl", 512),
+    ("globe_with_meridians", 2719),
+    ("gloves", 3028),
+    ("goal_net", 2979),
+    ("goat", 2465),
+    ("goggles", 3021),
+    ("golf", 2980),
+    ("golfing", 1832),
+    ("golfing_man", 1838),
+    ("golfing_woman", 1844),
+    ("goose", 2514),
+    ("gorilla", 2430),
+    ("grapes", 2581),
+    ("greece", 3605),
+    ("green_apple", 2591),
+    ("green_book", 3134),
+    ("green_circle", 3473),
+    ("green_heart", 146),
+    ("green_salad", 2648),
+    ("green_square", 3482),
+    ("greenland", 3600),
+    ("grenada", 3594),
+    ("grey_exclamation", 3392),
+    ("grey_heart", 152),
+    ("grey_question", 3391),
+    ("grimacing", 46),
+    ("grin", 3),
+    ("grinning", 0),
+    ("guadeloupe", 3603),
+    ("guam", 3608),
+    ("guard", 1166),
+    ("guardsman", 1172),
+    ("guardswoman", 1178),
+    ("guatemala", 3607),
+    ("guernsey", 3597),
+    ("guide_dog", 2434),
+    ("guinea", 3602),
+    ("guinea_bissau", 3609),
+    ("guitar", 3086),
+    ("gun", 2991),
+    ("guyana", 3610),
+    ("hair_pick", 3054),
+    ("haircut", 1509),
+    ("haircut_man", 1515),
+    ("haircut_woman", 1521),
+    ("haiti", 3615),
+    ("hamburger", 2632),
+    ("hammer", 3207),
+    ("hammer_and_pick", 3210),
+    ("hammer_and_wrench", 3211),
+    ("hamsa", 3277),
+    ("hamster", 2477),
+    ("hand", 186),
+    ("hand_over_mouth", 30),
+    ("hand_with_index_finger_and_thumb_crossed", 264),
+    ("handbag", 3041),
+    ("handball_person", 2015),
+    ("handshake", 396),
+    ("hankey", 109),
+    ("hash", 3418),
+    ("hatched_chick", 2499),
+    ("hatching_chick", 2497),
+    ("headphones", 3082),
+    ("headstone", 3274),
+    ("health_worker", 842),
+    ("hear_no_evil", 127),
+    ("heard_mcdonald_islands", 3612),
+    ("heart", 142),
+    ("heart_decoration", 137),
+    ("heart_eyes", 15),
+    ("heart_eyes_cat", 120),
+    ("heart_hands", 378),
+    ("heart_on_fire", 140),
+    ("heartbeat", 134),
+    ("heartpulse", 133),
+    ("hearts", 3005),
+    ("heavy_check_mark", 3406),
+    ("heavy_division_sign", 3385),
+    ("heavy_dollar_sign", 3396),
+    ("heavy_equals_sign", 3386),
+    ("heavy_exclamation_mark", 3393),
+    ("heavy_heart_exclamation", 138),
+    ("heavy_minus_sign", 3384),
+    ("heavy_multiplication_x", 3382),
+    ("heavy_plus_sign", 3383),
+    ("hedgehog", 2482),
+    ("helicopter", 2847),
+    ("herb", 2572),
+    ("hibiscus", 2560),
+    ("high_brightness", 3374),
+    ("high_heel", 3050),
+    ("hiking_boot", 3048),
+    ("hindu_temple", 2761),
+    ("hippopotamus", 2473),
+    ("hocho", 2713),
+    ("hole", 161),
+    ("honduras", 3613),
+    ("honey_pot", 2688),
+    ("honeybee", 2541),
+    ("hong_kong", 3611),
+    ("hook", 3229),
+    ("horse", 2447),
+    ("horse_racing", 1819),
+    ("hospital", 2746),
+    ("hot_face", 63),
+    ("hot_pepper", 2606),
+    ("hotdog", 2635),
+    ("hotel", 2748),
+    ("hotsprings", 2775),
+    ("hourglass", 2856),
+    ("hourglass_flowing_sand", 2857),
+    ("house", 2741),
+    ("house_with_garden", 2742),
+    ("houses", 2739),
+    ("hugs", 29),
+    ("hungary", 3616),
+    ("hushed", 81),
+    ("hut", 2738),
+    ("hyacinth", 2564),
+    ("ice_cream", 2677),
+    ("ice_cube", 2708),
+    ("ice_hockey", 2973),
+    ("ice_skate", 2981),
+    ("icecream", 2675),
+    ("iceland", 3626),
+    ("id", 3443),
+    ("identification_card", 3280),
+    ("ideograph_advantage", 3458),
+    ("imp", 106),
+    ("inbox_tray", 3163),
+    ("incoming_envelope", 3160),
+    ("index_pointing_at_the_viewer", 324),
+    ("india", 3622),
+    ("indonesia", 3618),
+    ("infinity", 3387),
+    ("information_desk_person", 734),
+    ("information_source", 3442),
+    ("innocent", 13),
+    ("interrobang", 3389),
+    ("iphone", 3095),
+    ("iran", 3625),
+    ("iraq", 3624),
+    ("ireland", 3619),
+    ("isle_of_man", 3621),
+    ("israel", 3620),
+    ("it", 3627),
+    ("izakaya_lantern", 3129),
+    ("jack_o_lantern", 2934),
+    ("jamaica", 3629),
+    ("japan", 2721),
+    ("japanese_castle", 2754),
+    ("japanese_goblin", 112),
+    ("japanese_ogre", 111),
+    ("jar", 2714),
+    ("jeans", 3026),
+    ("jellyfish", 2536),
+    ("jersey", 3628),
+    ("jigsaw", 2999),
+    ("jordan", 3630),
+    ("joy", 7),
+    ("joy_cat", 119),
+    ("joystick", 2996),
+    ("jp", 3631),
+    ("judge", 896),
+    ("juggling_person", 2033),
+    ("kaaba", 2764),
+    ("kangaroo", 2491),
+    ("kazakhstan", 3642),
+    ("kenya", 3632),
+    ("key", 3205),
+    ("keyboard", 3107),
+    ("keycap_ten", 3430),
+    ("khanda", 3340),
+    ("kick_scooter", 2818),
+    ("kimono", 3032),
+    ("kiribati", 3635),
+    ("kiss", 154),
+    ("kissing", 18),
+    ("kissing_cat", 122),
+    ("kissing_closed_eyes", 20),
+    ("kissing_heart", 17),
+    ("kissing_smiling_eyes", 21),
+    ("kite", 2990),
+    ("kiwi_fruit", 2597),
+    ("kneeling_man", 1587),
+    ("kneeling_person", 1581),
+    ("kneeling_woman", 1593),
+    ("knife", 2713),
+    ("knot", 3018),
+    ("koala", 2486),
+    ("koko", 3453),
+    ("kosovo", 3764),
+    ("kr", 3639),
+    ("kuwait", 3640),
+    ("kyrgyzstan", 3633),
+    ("lab_coat", 3022),
+    ("label", 3147),
+    ("lacrosse", 2974),
+    ("ladder", 3232),
+    ("lady_beetle", 2543),
+    ("lantern", 3129),
+    ("laos", 3643),
+    ("large_blue_circle", 3474),
+    ("large_blue_diamond", 3495),
+    ("large_orange_diamond", 3494),
+    ("last_quarter_moon", 2893),
+    ("last_quarter_moon_with_face", 2898),
+    ("latin_cross", 3334),
+    ("latvia", 3652),
+    ("laughing", 4),
+    ("leafy_green", 2609),
+    ("leaves", 2577),
+    ("lebanon", 3644),
+    ("ledger", 3139),
+    ("left_luggage", 3293),
+    ("left_right_arrow", 3316),
+    ("left_speech_bubble", 164),
+    ("leftwards_arrow_with_hook", 3317),
+    ("leftwards_hand", 204),
+    ("leftwards_pushing_hand", 222),
+    ("leg", 454),
+    ("lemon", 2585),
+    ("leo", 3345),
+    ("leopard", 2446),
+    ("lesotho", 3649),
+    ("level_slider", 3079),
+    ("liberia", 3648),
+    ("libra", 3347),
+    ("libya", 3653),
+    ("liechtenstein", 3646),
+    ("light_blue_heart", 148),
+    ("light_rail", 2788),
+    ("link", 3226),
+    ("lion", 2443),
+    ("lips", 492),
+    ("lipstick", 3063),
+    ("lithuania", 3650),
+    ("lizard", 2519),
+    ("llama", 2468),
+    ("lobster", 2671),
+    ("lock", 3201),
+    ("lock_with_ink_pen", 3203),
+    ("lollipop", 2686),
+    ("long_drum", 3092),
+    ("loop", 3410),
+    ("lotion_bottle", 3260),
+    ("lotus", 2556),
+    ("lotus_position", 2051),
+    ("lotus_position_man", 2057),
+    ("lotus_position_woman", 2063),
+    ("loud_sound", 3069),
+    ("loudspeaker", 3070),
+    ("love_hotel", 2749),
+    ("love_letter", 129),
+    ("love_you_gesture", 270),
+    ("low_battery", 3102),
+    ("low_brightness", 3373),
+    ("luggage", 2855),
+    ("lungs", 486),
+    ("luxembourg", 3651),
+    ("lying_face", 48),
+    ("m", 3444),
+    ("macau", 3665),
+    ("macedonia", 3661),
+    ("madagascar", 3659),
+    ("mag", 3124),
+    ("mag_right", 3125),
+    ("mage", 1394),
+    ("mage_man", 1400),
+    ("mage_woman", 1406),
+    ("magic_wand", 2994),
+    ("magnet", 3231),
+    ("mahjong", 3010),
+    ("mailbox", 3165),
+    ("mailbox_closed", 3166),
+    ("mailbox_with_mail", 3167),
+    ("mailbox_with_no_mail", 3168),
+    ("malawi", 3673),
+    ("malaysia", 3675),
+    ("maldives", 3672),
+    ("male_detective", 1154),
+    ("male_sign", 3380),
+    ("mali", 3662),
+    ("malta", 3670),
+    ("mammoth", 2471),
+    ("man", 530),
+    ("man_artist", 1064),
+    ("man_astronaut", 1100),
+    ("man_beard", 542),
+    ("man_cartwheeling", 1982),
+    ("man_cook", 938),
+    ("man_dancing", 1767),
+    ("man_facepalming", 812),
+    ("man_factory_worker", 974),
+    ("man_farmer", 920),
+    ("man_feeding_baby", 1322),
+    ("man_firefighter", 1118),
+    ("man_health_worker", 848),
+    ("man_in_manual_wheelchair", 1701),
+    ("man_in_motorized_wheelchair", 1665),
+    ("man_in_tuxedo", 1262),
+    ("man_judge", 902),
+    ("man_juggling", 2039),
+    ("man_mechanic", 956),
+    ("man_office_worker", 992),
+    ("man_pilot", 1082),
+    ("man_playing_handball", 2021),
+    ("man_playing_water_polo", 2003),
+    ("man_scientist", 1010),
+    ("man_shrugging", 830),
+    ("man_singer", 1046),
+    ("man_student", 866),
+    ("man_teacher", 884),
+    ("man_technologist", 1028),
+    ("man_with_gua_pi_mao", 1244),
+    ("man_with_probing_cane", 1629),
+    ("man_with_turban", 1232),
+    ("man_with_veil", 1280),
+    ("mandarin", 2584),
+    ("mango", 2589),
+    ("mans_shoe", 3046),
+    ("mantelpiece_clock", 2862),
+    ("manual_wheelchair", 2814),
+    ("maple_leaf", 2575),
+    ("maracas", 3093),
+    ("marshall_islands", 3660),
+    ("martial_arts_uniform", 2978),
+    ("martinique", 3667),
+    ("mask", 57),
+    ("massage", 1491),
+    ("massage_man", 1497),
+    ("massage_woman", 1503),
+    ("mate", 2707),
+    ("mauritania", 3668),
+    ("mauritius", 3671),
+    ("mayotte", 3766),
+    ("meat_on_bone", 2628),
+    ("mechanic", 950),
+    ("mechanical_arm", 452),
+    ("mechanical_leg", 453),
+    ("medal_military", 2955),
+    ("medal_sports", 2957),
+    ("medical_symbol", 3397),
+    ("mega", 3071),
+    ("melon", 2582),
+    ("melting_face", 10),
+    ("memo", 3177),
+    ("men_wrestling", 1995),
+    ("mending_heart", 141),
+    ("menorah", 3338),
+    ("mens", 3285),
+    ("mermaid", 1460),
+    ("merman", 1454),
+    ("merperson", 1448),
+    ("metal", 276),
+    ("metro", 2787),
+    ("mexico", 3674),
+    ("microbe", 2552),
+    ("micronesia", 3589),
+    ("microphone", 3081),
+    ("microscope", 3237),
+    ("middle_finger", 306),
+    ("military_helmet", 3060),
+    ("milk_glass", 2690),
+    ("milky_way", 2907),
+    ("minibus", 2797),
+    ("minidisc", 3110),
+    ("mirror", 3249),
+    ("mirror_ball", 3002),
+    ("mobile_phone_off", 3378),
+    ("moldova", 3656),
+    ("monaco", 3655),
+    ("money_mouth_face", 28),
+    ("money_with_wings", 3154),
+    ("moneybag", 3148),
+    ("mongolia", 3664),
+    ("monkey", 2429),
+    ("monkey_face", 2428),
+    ("monocle_face", 74),
+    ("monorail", 2791),
+    ("montenegro", 3657),
+    ("montserrat", 3669),
+    ("moon", 2890),
+    ("moon_cake", 2665),
+    ("moose", 2448),
+    ("morocco", 3654),
+    ("mortar_board", 3058),
+    ("mosque", 2760),
+    ("mosquito", 2549),
+    ("motor_boat", 2839),
+    ("motor_scooter", 2813),
+    ("motorcycle", 2812),
+    ("motorized_wheelchair", 2815),
+    ("motorway", 2822),
+    ("mount_fuji", 2726),
+    ("mountain", 2724),
+    ("mountain_bicyclist", 1958),
+    ("mountain_biking_man", 1964),
+    ("mountain_biking_woman", 1970),
+    ("mountain_cableway", 2849),
+    ("mountain_railway", 2792),
+    ("mountain_snow", 2723),
+    ("mouse", 2474),
+    ("mouse2", 2475),
+    ("mouse_trap", 3258),
+    ("movie_camera", 3115),
+    ("moyai", 3278),
+    ("mozambique", 3676),
+    ("mrs_claus", 1346),
+    ("muscle", 446),
+    ("mushroom", 2580),
+    ("musical_keyboard", 3087),
+    ("musical_note", 3076),
+    ("musical_score", 3075),
+    ("mute", 3066),
+    ("mx_claus", 1352),
+    ("myanmar", 3663),
+    ("nail_care", 434),
+    ("name_badge", 3401),
+    ("namibia", 3677),
+    ("national_park", 2731),
+    ("nauru", 3686),
+    ("nauseated_face", 60),
+    ("nazar_amulet", 3276),
+    ("necktie", 3024),
+    ("negative_squared_cross_mark", 3408),
+    ("nepal", 3685),
+    ("nerd_face", 73),
+    ("nest_with_eggs", 2579),
+    ("nesting_dolls", 3003),
+    ("netherlands", 3683),
+    ("neutral_face", 38),
+    ("new", 3445),
+    ("new_caledonia", 3678),
+    ("new_moon", 2887),
+    ("new_moon_with_face", 2896),
+    ("new_zealand", 3688),
+    ("newspaper", 3143),
+    ("newspaper_roll", 3144),
+    ("next_track_button", 3359),
+    ("ng", 3446),
+    ("ng_man", 704),
+    ("ng_woman", 710),
+    ("nicaragua", 3682),
+    ("niger", 3679),
+    ("nigeria", 3681),
+    ("night_with_stars", 2768),
+    ("nine", 3429),
+    ("ninja", 1184),
+    ("niue", 3687),
+    ("no_bell", 3074),
+    ("no_bicycles", 3298),
+    ("no_entry", 3296),
+    ("no_entry_sign", 3297),
+    ("no_good", 698),
+    ("no_good_man", 704),
+    ("no_good_woman", 710),
+    ("no_mobile_phones", 3303),
+    ("no_mouth", 40),
+    ("no_pedestrians", 3302),
+    ("no_smoking", 3299),
+    ("non-potable_water", 3301),
+    ("norfolk_island", 3680),
+    ("north_korea", 3638),
+    ("northern_mariana_islands", 3666),
+    ("norway", 3684),
+    ("nose", 478),
+    ("notebook", 3138),
+    ("notebook_with_decorative_cover", 3131),
+    ("notes", 3077),
+    ("nut_and_bolt", 3221),
+    ("o", 3403),
+    ("o2", 3447),
+    ("ocean", 2933),
+    ("octopus", 2533),
+    ("oden", 2661),
+    ("office", 2743),
+    ("office_worker", 986),
+    ("oil_drum", 2824),
+    ("ok", 3448),
+    ("ok_hand", 234),
+    ("ok_man", 722),
+    ("ok_person", 716),
+    ("ok_woman", 728),
+    ("old_key", 3206),
+    ("older_adult", 644),
+    ("older_man", 650),
+    ("older_woman", 656),
+    ("olive", 2599),
+    ("om", 3330),
+    ("oman", 3689),
+    ("on", 3325),
+    ("oncoming_automobile", 2805),
+    ("oncoming_bus", 2795),
+    ("oncoming_police_car", 2801),
+    ("oncoming_taxi", 2803),
+    ("one", 3421),
+    ("one_piece_swimsuit", 3034),
+    ("onion", 2612),
+    ("open_book", 3133),
+    ("open_file_folder", 3180),
+    ("open_hands", 384),
+    ("open_mouth", 80),
+    ("open_umbrella", 2923),
+    ("ophiuchus", 3353),
+    ("orange", 2584),
+    ("orange_book", 3136),
+    ("orange_circle", 3471),
+    ("orange_heart", 144),
+    ("orange_square", 3480),
+    ("orangutan", 2431),
+    ("orthodox_cross", 3335),
+    ("otter", 2489),
+    ("outbox_tray", 3162),
+    ("owl", 2506),
+    ("ox", 2456),
+    ("oyster", 2674),
+    ("package", 3164),
+    ("page_facing_up", 3142),
+    ("page_with_curl", 3140),
+    ("pager", 3099),
+    ("paintbrush", 3175),
+    ("pakistan", 3695),
+    ("palau", 3702),
+    ("palestinian_territories", 3700),
+    ("palm_down_hand", 210),
+    ("palm_tree", 2569),
+    ("palm_up_hand", 216),
+    ("palms_up_together", 390),
+    ("panama", 3690),
+    ("pancakes", 2625),
+    ("panda_face", 2487),
+    ("paperclip", 3193),
+    ("paperclips", 3194),
+    ("papua_new_guinea", 3693),
+    ("parachute", 2845),
+    ("paraguay", 3703),
+    ("parasol_on_ground", 2925),
+    ("parking", 3449),
+    ("parrot", 2511),
+    ("part_alternation_mark", 3411),
+    ("partly_sunny", 2909),
+    ("partying_face", 70),
+    ("passenger_ship", 2837),
+    ("passport_control", 3290),
+    ("pause_button", 3368),
+    ("paw_prints", 2493),
+    ("pea_pod", 2617),
+    ("peace_symbol", 3337),
+    ("peach", 2593),
+    ("peacock", 2510),
+    ("peanuts", 2613),
+    ("pear", 2592),
+    ("pen", 3174),
+    ("pencil", 3177),
+    ("pencil2", 3171),
+    ("penguin", 2501),
+    ("pensive", 53),
+    ("people_holding_hands", 2081),
+    ("people_hugging", 2421),
+    ("performing_arts", 3012),
+    ("persevere", 95),
+    ("person_bald", 626),
+    ("person_curly_hair", 602),
+    ("person_feeding_baby", 1328),
+    ("person_fencing", 1818),
+    ("person_in_manual_wheelchair", 1689),
+    ("person_in_motorized_wheelchair", 1653),
+    ("person_in_tuxedo", 1256),
+    ("person_red_hair", 590),
+    ("person_white_hair", 614),
+    ("person_with_crown", 1208),
+    ("person_with_probing_cane", 1617),
+    ("person_with_turban", 1226),
+    ("person_with_veil", 1274),
+    ("peru", 3691),
+    ("petri_dish", 3235),
+    ("philippines", 3694),
+    ("phone", 3097),
+    ("pick", 3209),
+    ("pickup_truck", 2807),
+    ("pie", 2683),
+    ("pig", 2459),
+    ("pig2", 2460),
+    ("pig_nose", 2462),
+    ("pill", 3242),
+    ("pilot", 1076),
+    ("pinata", 3001),
+    ("pinched_fingers", 240),
+    ("pinching_hand", 246),
+    ("pineapple", 2588),
+    ("ping_pong", 2975),
+    ("pink_heart", 143),
+    ("pirate_flag", 3511),
+    ("pisces", 3352),
+    ("pitcairn_islands", 3698),
+    ("pizza", 2634),
+    ("placard", 3279),
+    ("place_of_worship", 3328),
+    ("plate_with_cutlery", 2710),
+    ("play_or_pause_button", 3360),
+    ("playground_slide", 2777),
+    ("pleading_face", 84),
+    ("plunger", 3255),
+    ("point_down", 312),
+    ("point_left", 288),
+    ("point_right", 294),
+    ("point_up", 318),
+    ("point_up_2", 300),
+    ("poland", 3696),
+    ("polar_bear", 2485),
+    ("police_car", 2800),
+    ("police_officer", 1130),
+    ("policeman", 1136),
+    ("policewoman", 1142),
+    ("poodle", 2436),
+    ("poop", 109),
+    ("popcorn", 2649),
+    ("portugal", 3701),
+    ("post_office", 2744),
+    ("postal_horn", 3072),
+    ("postbox", 3169),
+    ("potable_water", 3283),
+    ("potato", 2603),
+    ("potted_plant", 2566),
+    ("pouch", 3042),
+    ("poultry_leg", 2629),
+    ("pound", 3153),
+    ("pouring_liquid", 2703),
+    ("pout", 102),
+    ("pouting_cat", 125),
+    ("pouting_face", 680),
+    ("pouting_man", 686),
+    ("pouting_woman", 692),
+    ("pray", 422),
+    ("prayer_beads", 3062),
+    ("pregnant_man", 1298),
+    ("pregnant_person", 1304),
+    ("pregnant_woman", 1292),
+    ("pretzel", 2623),
+    ("previous_track_button", 3363),
+    ("prince", 1214),
+    ("princess", 1220),
+    ("printer", 3106),
+    ("probing_cane", 3225),
+    ("puerto_rico", 3699),
+    ("punch", 348),
+    ("purple_circle", 3475),
+    ("purple_heart", 149),
+    ("purple_square", 3484),
+    ("purse", 3040),
+    ("pushpin", 3191),
+    ("put_litter_in_its_place", 3282),
+    ("qatar", 3704),
+    ("question", 3390),
+    ("rabbit", 2478),
+    ("rabbit2", 2479),
+    ("raccoon", 2439),
+    ("racehorse", 2450),
+    ("racing_car", 2811),
+    ("radio", 3083),
+    ("radio_button", 3501),
+    ("radioactive", 3305),
+    ("rage", 102),
+    ("railway_car", 2783),
+    ("railway_track", 2823),
+    ("rainbow", 2921),
+    ("rainbow_flag", 3509),
+    ("raised_back_of_hand", 174),
+    ("raised_eyebrow", 37),
+    ("raised_hand", 186),
+    ("raised_hand_with_fingers_splayed", 180),
+    ("raised_hands", 372),
+    ("raising_hand", 752),
+    ("raising_hand_man", 758),
+    ("raising_hand_woman", 764),
+    ("ram", 2463),
+    ("ramen", 2658),
+    ("rat", 2476),
+    ("razor", 3259),
+    ("receipt", 3156),
+    ("record_button", 3370),
+    ("recycle", 3398),
+    ("red_car", 2804),
+    ("red_circle", 3470),
+    ("red_envelope", 2949),
+    ("red_haired_man", 554),
+    ("red_haired_woman", 584),
+    ("red_square", 3479),
+    ("registered", 3416),
+    ("relaxed", 19),
+    ("relieved", 52),
+    ("reminder_ribbon", 2952),
+    ("repeat", 3355),
+    ("repeat_one", 3356),
+    ("rescue_worker_helmet", 3061),
+    ("restroom", 3287),
+    ("reunion", 3705),
+    ("revolving_hearts", 135),
+    ("rewind", 3362),
+    ("rhinoceros", 2472),
+    ("ribbon", 2950),
+    ("rice", 2656),
+    ("rice_ball", 2655),
+    ("rice_cracker", 2654),
+    ("rice_scene", 2948),
+    ("right_anger_bubble", 165),
+    ("rightwards_hand", 198),
+    ("rightwards_pushing_hand", 228),
+    ("ring", 3064),
+    ("ring_buoy", 2833),
+    ("ringed_planet", 2903),
+    ("robot", 116),
+    ("rock", 2736),
+    ("rocket", 2852),
+    ("rofl", 6),
+    ("roll_eyes", 45),
+    ("roll_of_paper", 3264),
+    ("roller_coaster", 2779),
+    ("roller_skate", 2820),
+    ("romania", 3706),
+    ("rooster", 2496),
+    ("rose", 2558),
+    ("rosette", 2557),
+    ("rotating_light", 2827),
+    ("round_pushpin", 3192),
+    ("rowboat", 1868),
+    ("rowing_man", 1874),
+    ("rowing_woman", 1880),
+    ("ru", 3708),
+    ("rugby_football", 2967),
+    ("runner", 1725),
+    ("running", 1725),
+    ("running_man", 1731),
+    ("running_shirt_with_sash", 2984),
+    ("running_woman", 1737),
+    ("rwanda", 3709),
+    ("sa", 3454),
+    ("safety_pin", 3261),
+    ("safety_vest", 3023),
+    ("sagittarius", 3349),
+    ("sailboat", 2834),
+    ("sake", 2694),
+    ("salt", 2651),
+    ("saluting_face", 35),
+    ("samoa", 3763),
+    ("san_marino", 3721),
+    ("sandal", 3051),
+    ("sandwich", 2636),
+    ("santa", 1340),
+    ("sao_tome_principe", 3726),
+    ("sari", 3033),
+    ("sassy_man", 740),
+    ("sassy_woman", 746),
+    ("satellite", 3239),
+    ("satisfied", 4),
+    ("saudi_arabia", 3710),
+    ("sauna_man", 1788),
+    ("sauna_person", 1782),
+    ("sauna_woman", 1794),
+    ("sauropod", 2523),
+    ("saxophone", 3084),
+    ("scarf", 3027),
+    ("school", 2751),
+    ("school_satchel", 3044),
+    ("scientist", 1004),
+    ("scissors", 3197),
+    ("scorpion", 2548),
+    ("scorpius", 3348),
+    ("scotland", 3771),
+    ("scream", 93),
+    ("scream_cat", 123),
+    ("screwdriver", 3220),
+    ("scroll", 3141),
+    ("seal", 2528),
+    ("seat", 2846),
+    ("secret", 3467),
+    ("see_no_evil", 126),
+    ("seedling", 2565),
+    ("selfie", 440),
+    ("senegal", 3722),
+    ("serbia", 3707),
+    ("service_dog", 2435),
+    ("seven", 3427),
+    ("sewing_needle", 3016),
+    ("seychelles", 3712),
+    ("shaking_face", 49),
+    ("shallow_pan_of_food", 2644),
+    ("shamrock", 2573),
+    ("shark", 2532),
+    ("shaved_ice", 2676),
+    ("sheep", 2464),
+    ("shell", 2534),
+    ("shield", 3217),
+    ("shinto_shrine", 2763),
+    ("ship", 2840),
+    ("shirt", 3025),
+    ("shit", 109),
+    ("shoe", 3046),
+    ("shopping", 3043),
+    ("shopping_cart", 3271),
+    ("shorts", 3036),
+    ("shower", 3256),
+    ("shrimp", 2672),
+    ("shrug", 824),
+    ("shushing_face", 33),
+    ("sierra_leone", 3720),
+    ("signal_strength", 3375),
+    ("singapore", 3715),
+    ("singer", 1040),
+    ("sint_maarten", 3728),
+    ("six", 3426),
+    ("six_pointed_star", 3339),
+    ("skateboard", 2819),
+    ("ski", 2985),
+    ("skier", 1825),
+    ("skull", 107),
+    ("skull_and_crossbones", 108),
+    ("skunk", 2490),
+    ("sled", 2986),
+    ("sleeping", 56),
+    ("sleeping_bed", 2075),
+    ("sleepy", 54),
+    ("slightly_frowning_face", 78),
+    ("slightly_smiling_face", 8),
+    ("slot_machine", 2997),
+    ("sloth", 2488),
+    ("slovakia", 3719),
+    ("slovenia", 3717),
+    ("small_airplane", 2842),
+    ("small_blue_diamond", 3497),
+    ("small_orange_diamond", 3496),
+    ("small_red_triangle", 3498),
+    ("small_red_triangle_down", 3499),
+    ("smile", 2),
+    ("smile_cat", 118),
+    ("smiley", 1),
+    ("smiley_cat", 117),
+    ("smiling_face_with_tear", 22),
+    ("smiling_face_with_three_hearts", 14),
+    ("smiling_imp", 105),
+    ("smirk", 43),
+    ("smirk_cat", 121),
+    ("smoking", 3272),
+    ("snail", 2537),
+    ("snake", 2520),
+    ("sneezing_face", 62),
+    ("snowboarder", 1826),
+    ("snowflake", 2927),
+    ("snowman", 2929),
+    ("snowman_with_snow", 2928),
+    ("soap", 3266),
+    ("sob", 92),
+    ("soccer", 2961),
+    ("socks", 3030),
+    ("softball", 2963),
+    ("solomon_islands", 3711),
+    ("somalia", 3723),
+    ("soon", 3326),
+    ("sos", 3450),
+    ("sound", 3068),
+    ("south_africa", 3767),
+    ("south_georgia_south_sandwich_islands", 3606),
+    ("south_sudan", 3725),
+    ("space_invader", 115),
+    ("spades", 3004),
+    ("spaghetti", 2659),
+    ("sparkle", 3414),
+    ("sparkler", 2937),
+    ("sparkles", 2939),
+    ("sparkling_heart", 132),
+    ("speak_no_evil", 128),
+    ("speaker", 3067),
+    ("speaking_head", 2418),
+    ("speech_balloon", 162),
+    ("speedboat", 2836),
+    ("spider", 2546),
+    ("spider_web", 2547),
+    ("spiral_calendar", 3185),
+    ("spiral_notepad", 3184),
+    ("sponge", 3269),
+    ("spoon", 2712),
+    ("squid", 2673),
+    ("sri_lanka", 3647),
+    ("st_barthelemy", 3538),
+    ("st_helena", 3716),
+    ("st_kitts_nevis", 3637),
+    ("st_lucia", 3645),
+    ("st_martin", 3658),
+    ("st_pierre_miquelon", 3697),
+    ("st_vincent_grenadines", 3756),
+    ("stadium", 2732),
+    ("standing_man", 1569),
+    ("standing_person", 1563),
+    ("standing_woman", 1575),
+    ("star", 2904),
+    ("star2", 2905),
+    ("star_and_crescent", 3336),
+    ("star_of_david", 3331),
+    ("star_struck", 16),
+    ("stars", 2906),
+    ("station", 2789),
+    ("statue_of_liberty", 2758),
+    ("steam_locomotive", 2782),
+    ("stethoscope", 3245),
+    ("stew", 2645),
+    ("stop_button", 3369),
+    ("stop_sign", 2830),
+    ("stopwatch", 2860),
+    ("straight_ruler", 3195),
+    ("strawberry", 2595),
+    ("stuck_out_tongue", 24),
+    ("stuck_out_tongue_closed_eyes", 27),
+    ("stuck_out_tongue_winking_eye", 25),
+    ("student", 860),
+    ("studio_microphone", 3078),
+    ("stuffed_flatbread", 2640),
+    ("sudan", 3713),
+    ("sun_behind_large_cloud", 2912),
+    ("sun_behind_rain_cloud", 2913),
+    ("sun_behind_small_cloud", 2911),
+    ("sun_with_face", 2902),
+    ("sunflower", 2561),
+    ("sunglasses", 72),
+    ("sunny", 2900),
+    ("sunrise", 2771),
+    ("sunrise_over_mountains", 2770),
+    ("superhero", 1358),
+    ("superhero_man", 1364),
+    ("superhero_woman", 1370),
+    ("supervillain", 1376),
+    ("supervillain_man", 1382),
+    ("supervillain_woman", 1388),
+    ("surfer", 1850),
+    ("surfing_man", 1856),
+    ("surfing_woman", 1862),
+    ("suriname", 3724),
+    ("sushi", 2662),
+    ("suspension_railway", 2848),
+    ("svalbard_jan_mayen", 3718),
+    ("swan", 2505),
+    ("swaziland", 3730),
+    ("sweat", 97),
+    ("sweat_drops", 159),
+    ("sweat_smile", 5),
+    ("sweden", 3714),
+    ("sweet_potato", 2660),
+    ("swim_brief", 3035),
+    ("swimmer", 1886),
+    ("swimming_man", 1892),
+    ("swimming_woman", 1898),
+    ("switzerland", 3555),
+    ("symbols", 3434),
+    ("synagogue", 2762),
+    ("syria", 3729),
+    ("syringe", 3240),
+    ("t-rex", 2524),
+    ("taco", 2637),
+    ("tada", 2941),
+    ("taiwan", 3746),
+    ("tajikistan", 3737),
+    ("takeout_box", 2669),
+    ("tamale", 2639),
+    ("tanabata_tree", 2943),
+    ("tangerine", 2584),
+    ("tanzania", 3747),
+    ("taurus", 3342),
+    ("taxi", 2802),
+    ("tea", 2693),
+    ("teacher", 878),
+    ("teapot", 2692),
+    ("technologist", 1022),
+    ("teddy_bear", 3000),
+    ("telephone", 3097),
+    ("telephone_receiver", 3098),
+    ("telescope", 3238),
+    ("tennis", 2968),
+    ("tent", 2766),
+    ("test_tube", 3234),
+    ("thailand", 3736),
+    ("thermometer", 2899),
+    ("thinking", 34),
+    ("thong_sandal", 3045),
+    ("thought_balloon", 166),
+    ("thread", 3015),
+    ("three", 3423),
+    ("thumbsdown", 336),
+    ("thumbsup", 330),
+    ("ticket", 2954),
+    ("tickets", 2953),
+    ("tiger", 2444),
+    ("tiger2", 2445),
+    ("timer_clock", 2861),
+    ("timor_leste", 3739),
+    ("tipping_hand_man", 740),
+    ("tipping_hand_person", 734),
+    ("tipping_hand_woman", 746),
+    ("tired_face", 99),
+    ("tm", 3417),
+    ("togo", 3735),
+    ("toilet", 3254),
+    ("tokelau", 3738),
+    ("tokyo_tower", 2757),
+    ("tomato", 2598),
+    ("tonga", 3742),
+    ("tongue", 491),
+    ("toolbox", 3230),
+    ("tooth", 487),
+    ("toothbrush", 3268),
+    ("top", 3327),
+    ("tophat", 3057),
+    ("tornado", 2917),
+    ("tr", 3743),
+    ("trackball", 3109),
+    ("tractor", 2810),
+    ("traffic_light", 2828),
+    ("train", 2793),
+    ("train2", 2786),
+    ("tram", 2790),
+    ("transgender_flag", 3510),
+    ("transgender_symbol", 3381),
+    ("triangular_flag_on_post", 3505),
+    ("triangular_ruler", 3196),
+    ("trident", 3400),
+    ("trinidad_tobago", 3744),
+    ("tristan_da_cunha", 3731),
+    ("triumph", 101),
+    ("troll", 1490),
+    ("trolleybus", 2796),
+    ("trophy", 2956),
+    ("tropical_drink", 2698),
+    ("tropical_fish", 2530),
+    ("truck", 2808),
+    ("trumpet", 3088),
+    ("tshirt", 3025),
+    ("tulip", 2563),
+    ("tumbler_glass", 2702),
+    ("tunisia", 3741),
+    ("turkey", 2494),
+    ("turkmenistan", 3740),
+    ("turks_caicos_islands", 3732),
+    ("turtle", 2518),
+    ("tuvalu", 3745),
+    ("tv", 3119),
+    ("twisted_rightwards_arrows", 3354),
+    ("two", 3422),
+    ("two_hearts", 136),
+    ("two_men_holding_hands", 2159),
+    ("two_women_holding_hands", 2107),
+    ("u5272", 3459),
+    ("u5408", 3464),
+    ("u55b6", 3468),
+    ("u6307", 3457),
+    ("u6708", 3455),
+    ("u6709", 3456),
+    ("u6e80", 3469),
+    ("u7121", 3460),
+    ("u7533", 3463),
+    ("u7981", 3461),
+    ("u7a7a", 3465),
+    ("uganda", 3749),
+    ("uk", 3593),
+    ("ukraine", 3748),
+    ("umbrella", 2924),
+    ("unamused", 44),
+    ("underage", 3304),
+    ("unicorn", 2451),
+    ("united_arab_emirates", 3514),
+    ("united_nations", 3751),
+    ("unlock", 3202),
+    ("up", 3451),
+    ("upside_down_face", 9),
+    ("uruguay", 3753),
+    ("us", 3752),
+    ("us_outlying_islands", 3750),
+    ("us_virgin_islands", 3759),
+    ("uzbekistan", 3754),
+    ("v", 252),
+    ("vampire", 1430),
+    ("vampire_man", 1436),
+    ("vampire_woman", 1442),
+    ("vanuatu", 3761),
+    ("vatican_city", 3755),
+    ("venezuela", 3757),
+    ("vertical_traffic_light", 2829),
+    ("vhs", 3123),
+    ("vibration_mode", 3377),
+    ("video_camera", 3122),
+    ("video_game", 2995),
+    ("vietnam", 3760),
+    ("violin", 3089),
+    ("virgo", 3346),
+    ("volcano", 2725),
+    ("volleyball", 2965),
+    ("vomiting_face", 61),
+    ("vs", 3452),
+    ("vulcan_salute", 192),
+    ("waffle", 2626),
+    ("wales", 3772),
+    ("walking", 1527),
+    ("walking_man", 1533),
+    ("walking_woman", 1539),
+    ("wallis_futuna", 3762),
+    ("waning_crescent_moon", 2894),
+    ("waning_gibbous_moon", 2892),
+    ("warning", 3294),
+    ("wastebasket", 3200),
+    ("watch", 2858),
+    ("water_buffalo", 2457),
+    ("water_polo", 1997),
+    ("watermelon", 2583),
+    ("wave", 168),
+    ("wavy_dash", 3394),
+    ("waxing_crescent_moon", 2888),
+    ("waxing_gibbous_moon", 2890),
+    ("wc", 3289),
+    ("weary", 98),
+    ("wedding", 2756),
+    ("weight_lifting", 1922),
+    ("weight_lifting_man", 1928),
+    ("weight_lifting_woman", 1934),
+    ("western_sahara", 3581),
+    ("whale", 2525),
+    ("whale2", 2526),
+    ("wheel", 2826),
+    ("wheel_of_dharma", 3332),
+    ("wheelchair", 3284),
+    ("white_check_mark", 3404),
+    ("white_circle", 3478),
+    ("white_flag", 3508),
+    ("white_flower", 2555),
+    ("white_haired_man", 566),
+    ("white_haired_woman", 608),
+    ("white_heart", 153),
+    ("white_large_square", 3487),
+    ("white_medium_small_square", 3491),
+    ("white_medium_square", 3489),
+    ("white_small_square", 3493),
+    ("white_square_button", 3502),
+    ("wilted_flower", 2559),
+    ("wind_chime", 2947),
+    ("wind_face", 2919),
+    ("window", 3250),
+    ("wine_glass", 2696),
+    ("wing", 2512),
+    ("wink", 11),
+    ("wireless", 3376),
+    ("wolf", 2437),
+    ("woman", 578),
+    ("woman_artist", 1070),
+    ("woman_astronaut", 1106),
+    ("woman_beard", 548),
+    ("woman_cartwheeling", 1988),
+    ("woman_cook", 944),
+    ("woman_dancing", 1761),
+    ("woman_facepalming", 818),
+    ("woman_factory_worker", 980),
+    ("woman_farmer", 926),
+    ("woman_feeding_baby", 1316),
+    ("woman_firefighter", 1124),
+    ("woman_health_worker", 854),
+    ("woman_in_manual_wheelchair", 1713),
+    ("woman_in_motorized_wheelchair", 1677),
+    ("woman_in_tuxedo", 1268),
+    ("woman_judge", 908),
+    ("woman_juggling", 2045),
+    ("woman_mechanic", 962),
+    ("woman_office_worker", 998),
+    ("woman_pilot", 1088),
+    ("woman_playing_handball", 2027),
+    ("woman_playing_water_polo", 2009),
+    ("woman_scientist", 1016),
+    ("woman_shrugging", 836),
+    ("woman_singer", 1052),
+    ("woman_student", 872),
+    ("woman_teacher", 890),
+    ("woman_technologist", 1034),
+    ("woman_with_headscarf", 1250),
+    ("woman_with_probing_cane", 1641),
+    ("woman_with_turban", 1238),
+    ("woman_with_veil", 1286),
+    ("womans_clothes", 3038),
+    ("womans_hat", 3056),
+    ("women_wrestling", 1996),
+    ("womens", 3286),
+    ("wood", 2737),
+    ("woozy_face", 65),
+    ("world_map", 2720),
+    ("worm", 2551),
+    ("worried", 77),
+    ("wrench", 3219),
+    ("wrestling", 1994),
+    ("writing_hand", 428),
+    ("x", 3407),
+    ("x_ray", 3246),
+    ("yarn", 3017),
+    ("yawning_face", 100),
+    ("yellow_circle", 3472),
+    ("yellow_heart", 145),
+    ("yellow_square", 3481),
+    ("yemen", 3765),
+    ("yen", 3150),
+    ("yin_yang", 3333),
+    ("yo_yo", 2989),
+    ("yum", 23),
+    ("zambia", 3768),
+    ("zany_face", 26),
+    ("zap", 2926),
+    ("zebra", 2452),
+    ("zero", 3420),
+    ("zimbabwe", 3769),
+    ("zipper_mouth_face", 36),
+    ("zombie", 1487),
+    ("zombie_man", 1488),
+    ("zombie_woman", 1489),
+    ("zzz", 167),
+];
+
+/// The maximum length in bytes of any shortcode.
+pub const MAX_LEN: usize = 40;
+
 pub static MAP: ::phf::Map<&'static str, usize> = ::phf::Map {
     key: 12913932095322966823,
     disps: &[
@@ -0,0 +1,6041 @@
+// Code generated by `cargo run --package generate`. DO NOT EDIT.
+
+use crate::EmojiId;
+
+pub static MAP: ::phf::Map<&'static str, (EmojiId, EmojiId)> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 38),
+        (0, 1),
+        (0, 0),
+        (0, 42),
+        (0, 67),
+        (0, 155),
+        (0, 522),
+        (0, 576),
+        (0, 37),
+        (0, 235),
+        (0, 5),
+        (0, 481),
+        (0, 265),
+        (0, 2),
+        (0, 103),
+        (0, 1),
+        (0, 0),
+        (0, 157),
+        (0, 6),
+        (0, 441),
+        (0, 2),
+        (0, 154),
+        (0, 359),
+        (0, 0),
+        (0, 312),
+        (0, 5),
+        (0, 1564),
+        (0, 0),
+        (0, 4),
+        (0, 42),
+        (0, 767),
+        (0, 335),
+        (0, 340),
+        (0, 17),
+        (0, 3),
+        (0, 1),
+        (0, 419),
+        (0, 1467),
+        (0, 364),
+        (0, 6),
+        (0, 7),
+        (0, 6),
+        (0, 70),
+        (0, 60),
+        (0, 0),
+        (0, 2197),
+        (0, 539),
+        (0, 940),
+        (0, 304),
+        (0, 118),
+        (0, 657),
+        (0, 3),
+        (0, 227),
+        (0, 1),
+        (0, 523),
+        (0, 230),
+        (0, 27),
+        (0, 801),
+        (0, 0),
+        (0, 29),
+        (0, 79),
+        (0, 0),
+        (0, 0),
+        (0, 51),
+        (0, 580),
+        (0, 3),
+        (0, 195),
+        (0, 303),
+        (0, 1648),
+        (0, 8),
+        (0, 115),
+        (0, 1569),
+        (0, 4213),
+        (0, 986),
+        (0, 1374),
+        (0, 0),
+        (0, 49),
+        (0, 10),
+        (0, 0),
+        (0, 498),
+        (0, 241),
+        (0, 230),
+        (0, 128),
+        (0, 0),
+        (0, 992),
+        (0, 3236),
+        (0, 13),
+        (0, 53),
+        (0, 362),
+        (0, 4),
+        (0, 1),
+        (0, 25),
+        (0, 8),
+        (0, 0),
+        (0, 444),
+        (0, 136),
+        (0, 622),
+        (1, 573),
+        (0, 0),
+        (0, 7),
+        (0, 248),
+        (0, 9),
+        (0, 360),
+        (0, 1),
+        (0, 25),
+        (0, 18),
+        (0, 171),
+        (0, 274),
+        (0, 1075),
+        (0, 131),
+        (0, 83),
+        (0, 200),
+        (0, 2211),
+        (0, 239),
+        (0, 14),
+        (0, 0),
+        (0, 266),
+        (0, 1),
+        (0, 105),
+        (0, 374),
+        (0, 711),
+        (0, 4),
+        (0, 8),
+        (0, 81),
+        (0, 2),
+        (0, 17),
+        (0, 17),
+        (0, 73),
+        (0, 0),
+        (0, 1018),
+        (0, 1105),
+        (0, 63),
+        (0, 173),
+        (0, 4),
+        (0, 468),
+        (0, 6),
+        (0, 1181),
+        (0, 1),
+        (0, 1732),
+        (0, 704),
+        (0, 4732),
+        (0, 0),
+        (0, 387),
+        (0, 53),
+        (0, 0),
+        (0, 5),
+        (0, 66),
+        (0, 61),
+        (0, 33),
+        (0, 376),
+        (0, 24),
+        (0, 21),
+        (0, 588),
+        (0, 0),
+        (0, 0),
+        (0, 650),
+        (0, 30),
+        (0, 194),
+        (0, 103),
+        (0, 101),
+        (0, 36),
+        (0, 117),
+        (0, 338),
+        (0, 1089),
+        (0, 67),
+        (0, 466),
+        (0, 0),
+        (0, 153),
+        (0, 105),
+        (0, 595),
+        (0, 340),
+        (0, 0),
+        (0, 329),
+        (0, 1),
+        (0, 0),
+        (0, 627),
+        (0, 181),
+        (0, 23),
+        (0, 149),
+        (0, 8),
+        (0, 64),
+        (0, 27),
+        (0, 14),
+        (0, 0),
+        (0, 493),
+        (0, 0),
+        (0, 1),
+        (0, 4),
+        (0, 1),
+        (0, 12),
+        (0, 1),
+        (0, 574),
+        (0, 226),
+        (0, 50),
+        (0, 106),
+        (0, 131),
+        (0, 56),
+        (0, 1375),
+        (0, 53),
+        (0, 5),
+        (0, 13),
+        (0, 1),
+        (0, 1515),
+        (0, 12),
+        (0, 1574),
+        (0, 5),
+        (0, 136),
+        (0, 88),
+        (0, 1),
+        (0, 33),
+        (0, 408),
+        (0, 2),
+        (0, 110),
+        (0, 1112),
+        (0, 151),
+        (0, 1536),
+        (0, 3),
+        (0, 0),
+        (0, 369),
+        (0, 75),
+        (0, 1),
+        (0, 3),
+        (0, 977),
+        (0, 8),
+        (0, 20),
+        (0, 453),
+        (0, 3),
+        (0, 1),
+        (0, 1),
+        (0, 1584),
+        (0, 0),
+        (0, 79),
+        (0, 0),
+        (0, 136),
+        (0, 1017),
+        (0, 0),
+        (0, 446),
+        (0, 98),
+        (0, 4),
+        (0, 90),
+        (0, 1309),
+        (1, 2984),
+        (0, 678),
+        (0, 1),
+        (0, 3),
+        (0, 398),
+        (0, 18),
+        (0, 865),
+        (0, 1357),
+        (0, 57),
+        (0, 5),
+        (0, 3),
+        (0, 1864),
+        (0, 73),
+        (0, 55),
+        (0, 92),
+        (0, 11),
+        (0, 68),
+        (0, 542),
+        (0, 2),
+        (0, 0),
+        (0, 189),
+        (0, 0),
+        (0, 29),
+        (0, 3),
+        (0, 4),
+        (0, 626),
+        (0, 1946),
+        (0, 1315),
+        (0, 465),
+        (0, 1887),
+        (0, 1),
+        (0, 11),
+        (0, 7),
+        (0, 2),
+        (0, 2540),
+        (0, 0),
+        (0, 92),
+        (0, 0),
+        (0, 740),
+        (0, 232),
+        (0, 285),
+        (0, 11),
+        (0, 148),
+        (0, 2),
+        (0, 1),
+        (0, 0),
+        (0, 167),
+        (0, 204),
+        (0, 792),
+        (0, 870),
+        (0, 25),
+        (0, 1),
+        (0, 56),
+        (0, 10),
+        (0, 13),
+        (0, 411),
+        (0, 1),
+        (0, 5),
+        (0, 13),
+        (0, 313),
+        (0, 0),
+        (0, 6),
+        (0, 0),
+        (0, 6),
+        (0, 2),
+        (0, 50),
+        (0, 230),
+        (0, 222),
+        (0, 68),
+        (0, 14),
+        (0, 13),
+        (0, 9),
+        (0, 2),
+        (0, 63),
+        (0, 106),
+        (0, 31),
+        (0, 2),
+        (0, 243),
+        (0, 49),
+        (0, 509),
+        (0, 70),
+        (0, 115),
+        (0, 8),
+        (0, 1460),
+        (0, 564),
+        (0, 838),
+        (0, 1266),
+        (0, 41),
+        (0, 0),
+        (0, 6),
+        (0, 20),
+        (0, 275),
+        (0, 2),
+        (0, 8),
+        (0, 199),
+        (0, 307),
+        (0, 10),
+        (0, 21),
+        (0, 0),
+        (0, 646),
+        (0, 579),
+        (0, 8),
+        (0, 22),
+        (0, 0),
+        (0, 675),
+        (0, 8),
+        (0, 1298),
+        (0, 10),
+        (0, 421),
+        (0, 55),
+        (0, 18),
+        (0, 6),
+        (0, 961),
+        (0, 1191),
+        (0, 86),
+        (0, 2061),
+        (0, 61),
+        (0, 653),
+        (0, 3),
+        (0, 118),
+        (0, 336),
+        (0, 0),
+        (0, 40),
+        (0, 161),
+        (0, 1),
+        (0, 11),
+        (0, 67),
+        (0, 29),
+        (0, 648),
+        (0, 196),
+        (0, 2),
+        (0, 1507),
+        (0, 759),
+        (0, 1),
+        (0, 863),
+        (0, 441),
+        (0, 3),
+        (0, 217),
+        (0, 98),
+        (0, 2493),
+        (0, 276),
+        (0, 61),
+        (0, 8),
+        (0, 192),
+        (0, 51),
+        (0, 1),
+        (0, 116),
+        (0, 39),
+        (0, 17),
+        (0, 2608),
+        (0, 30),
+        (0, 1),
+        (0, 34),
+        (0, 337),
+        (0, 745),
+        (0, 4784),
+        (0, 0),
+        (0, 88),
+        (0, 6),
+        (0, 357),
+        (0, 0),
+        (0, 0),
+        (0, 23),
+        (0, 637),
+        (0, 1),
+        (0, 134),
+        (0, 2466),
+        (0, 4060),
+        (0, 73),
+        (0, 3069),
+        (0, 138),
+        (0, 1614),
+        (0, 65),
+        (0, 12),
+        (0, 1),
+        (0, 1560),
+        (0, 87),
+        (0, 65),
+        (0, 1136),
+        (0, 0),
+        (0, 2528),
+        (0, 34),
+        (2, 4673),
+        (0, 252),
+        (1, 833),
+        (0, 5),
+        (0, 0),
+        (0, 247),
+        (0, 25),
+        (0, 0),
+        (0, 634),
+        (0, 651),
+        (0, 1),
+        (0, 8),
+        (0, 23),
+        (0, 12),
+        (0, 159),
+        (0, 6),
+        (0, 231),
+        (0, 27),
+        (0, 15),
+        (0, 238),
+        (0, 677),
+        (0, 1311),
+        (0, 15),
+        (0, 24),
+        (0, 7),
+        (1, 4313),
+        (0, 334),
+        (0, 31),
+        (0, 1105),
+        (0, 153),
+        (0, 5),
+        (0, 23),
+        (0, 52),
+        (0, 13),
+        (0, 4519),
+        (0, 37),
+        (0, 132),
+        (0, 2),
+        (0, 28),
+        (0, 16),
+        (0, 53),
+        (0, 52),
+        (0, 1),
+        (0, 1392),
+        (0, 4),
+        (1, 54),
+        (0, 948),
+        (0, 139),
+        (0, 14),
+        (0, 167),
+        (0, 5),
+        (0, 160),
+        (0, 1103),
+        (0, 151),
+        (0, 7),
+        (0, 222),
+        (0, 128),
+        (0, 11),
+        (0, 21),
+        (0, 189),
+        (0, 54),
+        (0, 2),
+        (0, 3811),
+        (0, 0),
+        (0, 1339),
+        (0, 1142),
+        (0, 22),
+        (0, 99),
+        (0, 134),
+        (0, 16),
+        (0, 83),
+        (0, 4),
+        (0, 2749),
+        (0, 172),
+        (0, 16),
+        (0, 1010),
+        (0, 2950),
+        (0, 36),
+        (0, 2),
+        (0, 228),
+        (0, 286),
+        (0, 1162),
+        (0, 2),
+        (0, 457),
+        (0, 4),
+        (0, 64),
+        (0, 141),
+        (0, 216),
+        (0, 1),
+        (0, 21),
+        (0, 321),
+        (0, 1746),
+        (1, 4747),
+        (0, 53),
+        (0, 1136),
+        (0, 61),
+        (0, 1),
+        (0, 22),
+        (0, 8),
+        (0, 43),
+        (0, 40),
+        (0, 0),
+        (1, 1),
+        (0, 3244),
+        (0, 1389),
+        (0, 63),
+        (0, 2692),
+        (0, 125),
+        (0, 55),
+        (0, 1289),
+        (0, 90),
+        (0, 17),
+        (0, 194),
+        (1, 914),
+        (0, 179),
+        (0, 987),
+        (0, 8),
+        (0, 157),
+        (0, 11),
+        (0, 65),
+        (0, 0),
+        (0, 0),
+        (0, 1356),
+        (0, 124),
+        (0, 4),
+        (0, 858),
+        (0, 210),
+        (0, 11),
+        (0, 224),
+        (0, 41),
+        (1, 2317),
+        (0, 0),
+        (0, 333),
+        (0, 8),
+        (0, 3087),
+        (0, 22),
+        (0, 493),
+        (0, 154),
+        (0, 7),
+        (0, 129),
+        (0, 372),
+        (0, 14),
+        (0, 77),
+        (0, 138),
+        (1, 3769),
+        (0, 50),
+        (0, 40),
+        (0, 182),
+        (0, 2636),
+        (0, 51),
+        (0, 27),
+        (0, 1),
+        (0, 2121),
+        (0, 0),
+        (0, 3876),
+        (0, 9),
+        (0, 11),
+        (0, 4),
+        (0, 53),
+        (0, 2326),
+        (0, 1178),
+        (0, 3656),
+        (0, 328),
+        (0, 6),
+        (0, 55),
+        (1, 768),
+        (0, 27),
+        (0, 661),
+        (0, 430),
+        (0, 524),
+        (0, 175),
+        (0, 31),
+        (0, 3),
+        (0, 82),
+        (0, 364),
+        (0, 53),
+        (0, 2),
+        (0, 507),
+        (0, 790),
+        (0, 1017),
+        (0, 939),
+        (0, 3004),
+        (0, 23),
+        (0, 40),
+        (0, 3358),
+        (0, 355),
+        (0, 1),
+        (0, 3),
+        (0, 0),
+        (1, 12),
+        (0, 2448),
+        (0, 843),
+        (0, 1617),
+        (0, 140),
+        (0, 2243),
+        (0, 75),
+        (0, 836),
+        (0, 416),
+        (0, 300),
+        (0, 677),
+        (0, 3766),
+        (0, 2),
+        (0, 0),
+        (0, 143),
+        (0, 74),
+        (0, 151),
+        (0, 1249),
+        (0, 1493),
+        (0, 8),
+        (0, 244),
+        (0, 0),
+        (0, 1171),
+        (0, 134),
+        (1, 126),
+        (0, 11),
+        (0, 1620),
+        (0, 196),
+        (0, 9),
+        (0, 12),
+        (0, 2),
+        (0, 7),
+        (0, 3),
+        (0, 0),
+        (0, 0),
+        (0, 3470),
+        (0, 110),
+        (0, 468),
+        (0, 1979),
+        (0, 12),
+        (0, 3781),
+        (0, 3),
+        (0, 976),
+        (0, 6),
+        (0, 2),
+        (0, 25),
+        (0, 7),
+        (0, 601),
+        (0, 7),
+        (0, 102),
+        (0, 2100),
+        (0, 91),
+        (0, 175),
+        (0, 1179),
+        (0, 828),
+        (0, 31),
+        (0, 1),
+        (0, 6),
+        (0, 0),
+        (0, 122),
+        (0, 1462),
+        (0, 139),
+        (0, 6),
+        (0, 1322),
+        (0, 2102),
+        (0, 204),
+        (0, 2070),
+        (0, 5),
+        (0, 31),
+        (0, 14),
+        (0, 145),
+        (0, 15),
+        (0, 348),
+        (0, 166),
+        (0, 16),
+        (0, 1473),
+        (1, 2539),
+        (0, 476),
+        (0, 18),
+        (0, 23),
+        (0, 218),
+        (0, 15),
+        (0, 0),
+        (1, 311),
+        (0, 2521),
+        (0, 10),
+        (0, 290),
+        (0, 2606),
+        (0, 783),
+        (0, 2634),
+        (0, 2444),
+        (0, 258),
+        (2, 3748),
+        (0, 288),
+        (0, 2715),
+        (0, 308),
+        (1, 2273),
+        (0, 1944),
+        (0, 106),
+        (0, 28),
+        (0, 101),
+        (0, 130),
+        (0, 2),
+        (0, 44),
+        (0, 20),
+        (0, 2),
+        (0, 945),
+        (0, 518),
+        (0, 184),
+        (0, 9),
+        (0, 7),
+        (0, 161),
+        (0, 859),
+        (0, 2031),
+        (0, 0),
+        (0, 34),
+        (0, 49),
+        (0, 2373),
+        (0, 433),
+        (0, 502),
+        (2, 4314),
+        (0, 1006),
+        (0, 0),
+        (0, 662),
+        (0, 1531),
+        (0, 75),
+        (0, 0),
+        (0, 85),
+        (0, 1),
+        (0, 47),
+        (0, 83),
+        (0, 367),
+        (0, 105),
+        (0, 2),
+        (0, 2401),
+        (0, 621),
+        (0, 249),
+        (0, 8),
+        (0, 1131),
+        (0, 40),
+        (0, 0),
+        (0, 0),
+        (0, 1208),
+        (0, 253),
+        (0, 76),
+        (0, 78),
+        (0, 241),
+        (0, 66),
+        (0, 5),
+        (0, 1154),
+        (0, 18),
+        (0, 1211),
+        (0, 3),
+        (2, 1688),
+        (1, 2020),
+        (0, 0),
+        (0, 46),
+        (0, 758),
+        (0, 405),
+        (0, 527),
+        (0, 58),
+        (0, 1414),
+        (0, 1319),
+        (0, 120),
+        (0, 111),
+        (0, 2218),
+        (0, 32),
+        (0, 10),
+        (0, 0),
+        (0, 253),
+        (0, 1410),
+        (0, 52),
+        (0, 148),
+        (0, 0),
+        (0, 2),
+        (2, 2805),
+        (0, 239),
+        (1, 2559),
+        (0, 0),
+        (0, 11),
+        (0, 23),
+        (1, 213),
+        (0, 0),
+        (0, 4955),
+        (0, 1),
+        (0, 43),
+        (0, 5),
+        (1, 3810),
+        (0, 346),
+        (0, 3331),
+        (0, 3021),
+        (0, 3065),
+        (1, 2448),
+        (1, 2543),
+        (0, 322),
+        (0, 30),
+        (0, 10),
+        (0, 201),
+        (0, 153),
+        (0, 6),
+        (0, 1388),
+        (0, 8),
+        (0, 47),
+        (0, 1),
+        (2, 4260),
+        (0, 86),
+        (0, 28),
+        (0, 970),
+        (0, 516),
+        (0, 7),
+        (0, 4),
+        (0, 4),
+        (4, 3637),
+        (0, 4860),
+        (0, 56),
+        (0, 1),
+        (0, 642),
+        (1, 4482),
+        (0, 802),
+        (0, 5),
+        (0, 1242),
+        (0, 26),
+        (0, 1158),
+        (0, 928),
+        (0, 6),
+        (0, 23),
+        (0, 3565),
+        (0, 218),
+        (0, 4723),
+        (0, 2402),
+        (0, 414),
+        (0, 1482),
+        (0, 15),
+        (0, 9),
+        (0, 44),
+        (0, 2020),
+        (0, 2188),
+        (0, 0),
+        (0, 40),
+        (6, 2180),
+        (0, 807),
+        (0, 31),
+        (0, 59),
+        (0, 4070),
+        (0, 0),
+        (0, 0),
+        (0, 13),
+        (2, 1226),
+        (0, 405),
+        (0, 632),
+        (0, 35),
+        (0, 22),
+        (0, 10),
+        (2, 2304),
+        (0, 0),
+        (0, 3332),
+        (0, 9),
+        (0, 2),
+        (0, 5),
+        (0, 356),
+        (0, 0),
+        (0, 98),
+        (0, 79),
+        (0, 94),
+        (0, 123),
+        (0, 1614),
+        (0, 426),
+        (0, 56),
+        (0, 447),
+        (1, 1096),
+        (0, 0),
+        (0, 41),
+        (0, 2936),
+        (0, 1316),
+        (0, 522),
+        (0, 459),
+        (0, 63),
+        (0, 2737),
+        (0, 108),
+        (1, 465),
+        (0, 3),
+        (0, 6),
+        (0, 150),
+        (5, 4061),
+        (0, 2),
+        (3, 3920),
+        (0, 1008),
+        (0, 2),
+        (0, 151),
+        (0, 1605),
+        (0, 1564),
+        (0, 56),
+        (0, 7),
+        (0, 6),
+        (0, 1),
+        (0, 1140),
+        (0, 365),
+        (0, 3841),
+        (4, 3820),
+        (0, 3504),
+        (0, 1001),
+        (0, 1077),
+        (2, 3539),
+        (1, 4479),
+        (0, 823),
+        (0, 390),
+        (0, 2),
+        (0, 0),
+        (0, 119),
+        (0, 820),
+        (0, 26),
+        (0, 1057),
+        (2, 2294),
+        (0, 3802),
+        (0, 4512),
+        (0, 66),
+        (0, 7),
+        (0, 9),
+        (0, 165),
+        (0, 1492),
+        (1, 2142),
+        (0, 0),
+        (0, 0),
+        (0, 62),
+        (0, 117),
+        (3, 3584),
+        (0, 2043),
+        (0, 1156),
+        (0, 284),
+        (0, 1412),
+        (0, 8),
+        (0, 1356),
+        (0, 88),
+        (1, 4225),
+        (0, 1013),
+        (0, 83),
+        (0, 10),
+        (4, 1738),
+        (1, 37),
+        (0, 225),
+        (0, 6),
+        (0, 533),
+        (1, 1737),
+        (0, 180),
+        (0, 11),
+        (0, 22),
+        (0, 1),
+        (0, 2),
+        (1, 184),
+        (0, 3699),
+        (2, 2137),
+        (1, 777),
+        (5, 4520),
+        (0, 18),
+        (5, 3010),
+        (0, 3015),
+        (0, 869),
+        (0, 2),
+        (0, 6),
+        (1, 101),
+        (8, 382),
+        (0, 1112),
+        (0, 0),
+        (0, 2632),
+        (0, 3224),
+        (0, 1569),
+        (3, 350),
+        (0, 139),
+        (0, 97),
+        (0, 287),
+        (0, 0),
+        (0, 4318),
+        (5, 674),
+        (0, 2128),
+        (4, 3932),
+        (0, 0),
+        (0, 1447),
+        (0, 3084),
+        (0, 0),
+        (0, 4796),
+        (1, 4104),
+        (12, 1702),
+        (0, 1568),
+        (0, 89),
+        (2, 628),
+        (0, 1),
+        (0, 1725),
+        (0, 0),
+        (1, 1142),
+        (0, 2107),
+        (0, 4),
+        (0, 8),
+        (0, 194),
+    ],
+    entries: &[
+        ("👰🏿\u{200d}♀", (EmojiId(1291), EmojiId(1286))),
+        ("🏋🏻\u{200d}♂", (EmojiId(1929), EmojiId(1928))),
+        ("🥭", (EmojiId(2589), EmojiId(2589))),
+        ("🇦🇷", (EmojiId(3522), EmojiId(3522))),
+        ("🧘🏻\u{200d}♀\u{fe0f}", (EmojiId(2064), EmojiId(2063))),
+        ("🏌🏽\u{200d}♂\u{fe0f}", (EmojiId(1841), EmojiId(1838))),
+        ("🍟", (EmojiId(2633), EmojiId(2633))),
+        ("🧑🏼\u{200d}🎄", (EmojiId(1354), EmojiId(1352))),
+        ("7\u{fe0f}\u{20e3}", (EmojiId(3427), EmojiId(3427))),
+        ("🤲🏾", (EmojiId(394), EmojiId(390))),
+        ("🚶🏻\u{200d}♂", (EmojiId(1534), EmojiId(1533))),
+        ("🏝", (EmojiId(2730), EmojiId(2730))),
+        ("⏮", (EmojiId(3363), EmojiId(3363))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2282), EmojiId(2263))),
+        ("🤙🏻", (EmojiId(283), EmojiId(282))),
+        ("🇬🇦", (EmojiId(3592), EmojiId(3592))),
+        ("💇🏾", (EmojiId(1513), EmojiId(1509))),
+        ("🕵\u{fe0f}\u{200d}♀\u{fe0f}", (EmojiId(1160), EmojiId(1160))),
+        ("🧚🏻\u{200d}♂", (EmojiId(1419), EmojiId(1418))),
+        ("⛵", (EmojiId(2834), EmojiId(2834))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2273), EmojiId(2263))),
+        ("🥷🏾", (EmojiId(1188), EmojiId(1184))),
+        ("🧟", (EmojiId(1487), EmojiId(1487))),
+        ("🤹🏼\u{200d}♀", (EmojiId(2047), EmojiId(2045))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2287), EmojiId(2263))),
+        ("🧦", (EmojiId(3030), EmojiId(3030))),
+        ("💆🏽\u{200d}♀\u{fe0f}", (EmojiId(1506), EmojiId(1503))),
+        ("🤸🏾\u{200d}♂\u{fe0f}", (EmojiId(1986), EmojiId(1982))),
+        ("🇺🇿", (EmojiId(3754), EmojiId(3754))),
+        ("🧙🏻\u{200d}♀\u{fe0f}", (EmojiId(1407), EmojiId(1406))),
+        ("🚶🏾\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1561), EmojiId(1557))),
+        ("🍍", (EmojiId(2588), EmojiId(2588))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2100), EmojiId(2081))),
+        ("🤸🏾\u{200d}♂", (EmojiId(1986), EmojiId(1982))),
+        ("ℹ\u{fe0f}", (EmojiId(3442), EmojiId(3442))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2258), EmojiId(2237))),
+        ("👰🏼\u{200d}♀\u{fe0f}", (EmojiId(1288), EmojiId(1286))),
+        ("🤷🏽\u{200d}♂\u{fe0f}", (EmojiId(833), EmojiId(830))),
+        ("💁\u{200d}♀\u{fe0f}", (EmojiId(746), EmojiId(746))),
+        ("🐻\u{200d}❄\u{fe0f}", (EmojiId(2485), EmojiId(2485))),
+        ("🤼\u{200d}♂", (EmojiId(1995), EmojiId(1995))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2226), EmojiId(2211))),
+        ("👩\u{200d}🦱", (EmojiId(596), EmojiId(596))),
+        ("💇\u{200d}♂\u{fe0f}", (EmojiId(1515), EmojiId(1515))),
+        ("👩\u{200d}🔧", (EmojiId(962), EmojiId(962))),
+        ("🕹", (EmojiId(2996), EmojiId(2996))),
+        ("🛩", (EmojiId(2842), EmojiId(2842))),
+        ("👰🏽\u{200d}♂\u{fe0f}", (EmojiId(1283), EmojiId(1280))),
+        ("🚏", (EmojiId(2821), EmojiId(2821))),
+        ("🥲", (EmojiId(22), EmojiId(22))),
+        ("🥁", (EmojiId(3091), EmojiId(3091))),
+        ("👨🏿\u{200d}🌾", (EmojiId(925), EmojiId(920))),
+        ("👶🏽", (EmojiId(497), EmojiId(494))),
+        ("🏃\u{200d}♀\u{200d}➡", (EmojiId(1749), EmojiId(1749))),
+        ("🧑🏾\u{200d}🦯\u{200d}➡", (EmojiId(1627), EmojiId(1623))),
+        ("👳🏻\u{200d}♀\u{fe0f}", (EmojiId(1239), EmojiId(1238))),
+        ("🧜🏾\u{200d}♂", (EmojiId(1458), EmojiId(1454))),
+        ("🚴🏽\u{200d}♀", (EmojiId(1955), EmojiId(1952))),
+        ("🇨🇴", (EmojiId(3561), EmojiId(3561))),
+        ("🤳", (EmojiId(440), EmojiId(440))),
+        ("👁\u{fe0f}\u{200d}🗨\u{fe0f}", (EmojiId(163), EmojiId(163))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏼", (EmojiId(2308), EmojiId(2289))),
+        ("👩🏾\u{200d}⚖\u{fe0f}", (EmojiId(912), EmojiId(908))),
+        ("🔛", (EmojiId(3325), EmojiId(3325))),
+        ("🧎🏻\u{200d}➡", (EmojiId(1600), EmojiId(1599))),
+        ("☄", (EmojiId(2930), EmojiId(2930))),
+        ("🐊", (EmojiId(2517), EmojiId(2517))),
+        ("🤏", (EmojiId(246), EmojiId(246))),
+        ("🐫", (EmojiId(2467), EmojiId(2467))),
+        ("⚠", (EmojiId(3294), EmojiId(3294))),
+        ("🤛🏿", (EmojiId(359), EmojiId(354))),
+        ("🧑🏻\u{200d}🏭", (EmojiId(969), EmojiId(968))),
+        ("💁🏾\u{200d}♀\u{fe0f}", (EmojiId(750), EmojiId(746))),
+        ("🇨🇫", (EmojiId(3553), EmojiId(3553))),
+        ("👱🏻\u{200d}♂\u{fe0f}", (EmojiId(639), EmojiId(638))),
+        ("👩🏾\u{200d}🦯\u{200d}➡", (EmojiId(1651), EmojiId(1647))),
+        ("🛡\u{fe0f}", (EmojiId(3217), EmojiId(3217))),
+        ("👡", (EmojiId(3051), EmojiId(3051))),
+        ("🖊", (EmojiId(3174), EmojiId(3174))),
+        ("🙇🏿\u{200d}♂", (EmojiId(799), EmojiId(794))),
+        ("🇸🇳", (EmojiId(3722), EmojiId(3722))),
+        ("💪🏾", (EmojiId(450), EmojiId(446))),
+        ("🧔🏾\u{200d}♂\u{fe0f}", (EmojiId(546), EmojiId(542))),
+        ("🫶🏿", (EmojiId(383), EmojiId(378))),
+        ("🕳", (EmojiId(161), EmojiId(161))),
+        ("🧍\u{200d}♂", (EmojiId(1569), EmojiId(1569))),
+        ("🦖", (EmojiId(2524), EmojiId(2524))),
+        ("💸", (EmojiId(3154), EmojiId(3154))),
+        ("🤹🏽", (EmojiId(2036), EmojiId(2033))),
+        ("🙎🏼\u{200d}♂", (EmojiId(688), EmojiId(686))),
+        ("🚴🏿\u{200d}♀\u{fe0f}", (EmojiId(1957), EmojiId(1952))),
+        ("👏🏻", (EmojiId(367), EmojiId(366))),
+        ("👩🏼\u{200d}🎤", (EmojiId(1054), EmojiId(1052))),
+        ("👩🏽\u{200d}🦳", (EmojiId(611), EmojiId(608))),
+        ("🌮", (EmojiId(2637), EmojiId(2637))),
+        ("🕦", (EmojiId(2886), EmojiId(2886))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏻", (EmojiId(2385), EmojiId(2367))),
+        ("🫧", (EmojiId(3267), EmojiId(3267))),
+        ("✍", (EmojiId(428), EmojiId(428))),
+        ("👨🏿\u{200d}✈", (EmojiId(1087), EmojiId(1082))),
+        ("🧎\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1605), EmojiId(1605))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2283), EmojiId(2263))),
+        ("🪱", (EmojiId(2551), EmojiId(2551))),
+        ("🏋🏾\u{200d}♂", (EmojiId(1932), EmojiId(1928))),
+        ("😆", (EmojiId(4), EmojiId(4))),
+        ("🙆🏾\u{200d}♂\u{fe0f}", (EmojiId(726), EmojiId(722))),
+        ("🤾🏾\u{200d}♀", (EmojiId(2031), EmojiId(2027))),
+        ("🙇🏾\u{200d}♀", (EmojiId(804), EmojiId(800))),
+        ("🇬🇶", (EmojiId(3604), EmojiId(3604))),
+        ("👇🏼", (EmojiId(314), EmojiId(312))),
+        ("👩🏿\u{200d}✈\u{fe0f}", (EmojiId(1093), EmojiId(1088))),
+        ("👳🏽\u{200d}♂", (EmojiId(1235), EmojiId(1232))),
+        ("🎿", (EmojiId(2985), EmojiId(2985))),
+        ("🇩🇲", (EmojiId(3574), EmojiId(3574))),
+        ("🚵🏾\u{200d}♂\u{fe0f}", (EmojiId(1968), EmojiId(1964))),
+        ("🤺", (EmojiId(1818), EmojiId(1818))),
+        ("🧜🏻\u{200d}♀", (EmojiId(1461), EmojiId(1460))),
+        ("♥", (EmojiId(3005), EmojiId(3005))),
+        ("🧕🏼", (EmojiId(1252), EmojiId(1250))),
+        ("🥂", (EmojiId(2701), EmojiId(2701))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2265), EmojiId(2263))),
+        ("🏃🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1758), EmojiId(1755))),
+        ("♋", (EmojiId(3344), EmojiId(3344))),
+        ("🩵", (EmojiId(148), EmojiId(148))),
+        ("👨🏿\u{200d}🔬", (EmojiId(1015), EmojiId(1010))),
+        ("🇻🇮", (EmojiId(3759), EmojiId(3759))),
+        ("👳", (EmojiId(1226), EmojiId(1226))),
+        ("👩🏽\u{200d}🌾", (EmojiId(929), EmojiId(926))),
+        ("👁\u{fe0f}\u{200d}🗨", (EmojiId(163), EmojiId(163))),
+        ("📙", (EmojiId(3136), EmojiId(3136))),
+        ("🧜🏻", (EmojiId(1449), EmojiId(1448))),
+        ("🦾", (EmojiId(452), EmojiId(452))),
+        ("👷🏾\u{200d}♀\u{fe0f}", (EmojiId(1206), EmojiId(1202))),
+        ("🏜\u{fe0f}", (EmojiId(2729), EmojiId(2729))),
+        ("🚨", (EmojiId(2827), EmojiId(2827))),
+        ("🦹\u{200d}♀", (EmojiId(1388), EmojiId(1388))),
+        ("🇬🇵", (EmojiId(3603), EmojiId(3603))),
+        ("🇪🇺", (EmojiId(3585), EmojiId(3585))),
+        ("🪄", (EmojiId(2994), EmojiId(2994))),
+        ("😀", (EmojiId(0), EmojiId(0))),
+        ("🚘", (EmojiId(2805), EmojiId(2805))),
+        ("⚗\u{fe0f}", (EmojiId(3233), EmojiId(3233))),
+        ("🏺", (EmojiId(2715), EmojiId(2715))),
+        ("🦻", (EmojiId(472), EmojiId(472))),
+        ("🆙", (EmojiId(3451), EmojiId(3451))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2340), EmojiId(2315))),
+        ("🤚🏾", (EmojiId(178), EmojiId(174))),
+        ("⬆\u{fe0f}", (EmojiId(3307), EmojiId(3307))),
+        ("❎", (EmojiId(3408), EmojiId(3408))),
+        ("🧎🏿\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1610), EmojiId(1605))),
+        ("🏃\u{200d}♂", (EmojiId(1731), EmojiId(1731))),
+        ("👩🏾\u{200d}🦲", (EmojiId(624), EmojiId(620))),
+        ("🛩\u{fe0f}", (EmojiId(2842), EmojiId(2842))),
+        ("🚶🏽\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1554), EmojiId(1551))),
+        ("🚮", (EmojiId(3282), EmojiId(3282))),
+        ("💆🏿\u{200d}♂\u{fe0f}", (EmojiId(1502), EmojiId(1497))),
+        ("🧖🏼\u{200d}♂", (EmojiId(1790), EmojiId(1788))),
+        ("👨🏽\u{200d}🌾", (EmojiId(923), EmojiId(920))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2361), EmojiId(2341))),
+        ("🧎\u{200d}♂", (EmojiId(1587), EmojiId(1587))),
+        ("🪆", (EmojiId(3003), EmojiId(3003))),
+        ("🏇🏽", (EmojiId(1822), EmojiId(1819))),
+        ("🙍🏿\u{200d}♂\u{fe0f}", (EmojiId(673), EmojiId(668))),
+        ("🚢", (EmojiId(2840), EmojiId(2840))),
+        ("👎🏾", (EmojiId(340), EmojiId(336))),
+        ("🏃🏿\u{200d}♂", (EmojiId(1736), EmojiId(1731))),
+        ("🧔\u{200d}♀", (EmojiId(548), EmojiId(548))),
+        ("🏃\u{200d}♀", (EmojiId(1737), EmojiId(1737))),
+        ("🇾🇹", (EmojiId(3766), EmojiId(3766))),
+        ("👩🏼\u{200d}⚕", (EmojiId(856), EmojiId(854))),
+        ("👼", (EmojiId(1334), EmojiId(1334))),
+        ("🤘🏽", (EmojiId(279), EmojiId(276))),
+        ("🇨🇬", (EmojiId(3554), EmojiId(3554))),
+        ("🖐🏿", (EmojiId(185), EmojiId(180))),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", (EmojiId(2211), EmojiId(2211))),
+        ("🧎🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1612), EmojiId(1611))),
+        ("⛩\u{fe0f}", (EmojiId(2763), EmojiId(2763))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2346), EmojiId(2341))),
+        ("🚵🏼\u{200d}♀\u{fe0f}", (EmojiId(1972), EmojiId(1970))),
+        ("🏌🏻\u{200d}♀\u{fe0f}", (EmojiId(1845), EmojiId(1844))),
+        ("🧟\u{200d}♂", (EmojiId(1488), EmojiId(1488))),
+        ("👩🏼\u{200d}🔧", (EmojiId(964), EmojiId(962))),
+        ("🏃🏽\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1758), EmojiId(1755))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2275), EmojiId(2263))),
+        ("⛑\u{fe0f}", (EmojiId(3061), EmojiId(3061))),
+        ("👰\u{200d}♂\u{fe0f}", (EmojiId(1280), EmojiId(1280))),
+        ("💁🏾", (EmojiId(738), EmojiId(734))),
+        ("🇬🇫", (EmojiId(3596), EmojiId(3596))),
+        ("🇨🇮", (EmojiId(3556), EmojiId(3556))),
+        ("🧑🏼\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1661), EmojiId(1659))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2236), EmojiId(2211))),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏿", (EmojiId(2306), EmojiId(2289))),
+        ("💁🏾\u{200d}♂", (EmojiId(744), EmojiId(740))),
+        ("♏", (EmojiId(3348), EmojiId(3348))),
+        ("🏋", (EmojiId(1922), EmojiId(1922))),
+        ("🧎\u{200d}♂\u{200d}➡", (EmojiId(1611), EmojiId(1611))),
+        ("💂🏽\u{200d}♂\u{fe0f}", (EmojiId(1175), EmojiId(1172))),
+        ("🧩", (EmojiId(2999), EmojiId(2999))),
+        ("💅🏿", (EmojiId(439), EmojiId(434))),
+        ("🏃🏿\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1760), EmojiId(1755))),
+        ("🤽🏿\u{200d}♀\u{fe0f}", (EmojiId(2014), EmojiId(2009))),
+        ("💇🏽\u{200d}♂\u{fe0f}", (EmojiId(1518), EmojiId(1515))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2243), EmojiId(2237))),
+        ("📬", (EmojiId(3167), EmojiId(3167))),
+        ("🫱🏽\u{200d}🫲🏾", (EmojiId(412), EmojiId(396))),
+        ("🚶🏻\u{200d}♀\u{200d}➡", (EmojiId(1552), EmojiId(1551))),
+        ("🤹🏻", (EmojiId(2034), EmojiId(2033))),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏾", (EmojiId(2141), EmojiId(2133))),
+        ("🛻", (EmojiId(2807), EmojiId(2807))),
+        ("🧗🏼", (EmojiId(1802), EmojiId(1800))),
+        ("👆🏽", (EmojiId(303), EmojiId(300))),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏼", (EmojiId(2200), EmojiId(2185))),
+        ("🫶🏼", (EmojiId(380), EmojiId(378))),
+        ("🤹🏻\u{200d}♂", (EmojiId(2040), EmojiId(2039))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2219), EmojiId(2211))),
+        ("🧑🏻\u{200d}🚀", (EmojiId(1095), EmojiId(1094))),
+        ("🩻", (EmojiId(3246), EmojiId(3246))),
+        ("👵🏿", (EmojiId(661), EmojiId(656))),
+        ("🇯🇵", (EmojiId(3631), EmojiId(3631))),
+        ("🧑🏾\u{200d}🔬", (EmojiId(1008), EmojiId(1004))),
+        ("🧞\u{200d}♀", (EmojiId(1486), EmojiId(1486))),
+        ("🤾🏿\u{200d}♀", (EmojiId(2032), EmojiId(2027))),
+        ("🇧🇸", (EmojiId(3544), EmojiId(3544))),
+        ("👨\u{200d}❤\u{200d}👨", (EmojiId(2341), EmojiId(2341))),
+        ("👨🏽\u{200d}🦼", (EmojiId(1668), EmojiId(1665))),
+        ("🇹🇬", (EmojiId(3735), EmojiId(3735))),
+        ("🏌🏿\u{200d}♀", (EmojiId(1849), EmojiId(1844))),
+        ("🐦\u{200d}⬛", (EmojiId(2513), EmojiId(2513))),
+        ("👩\u{200d}✈\u{fe0f}", (EmojiId(1088), EmojiId(1088))),
+        ("🧑🏿\u{200d}🏭", (EmojiId(973), EmojiId(968))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2347), EmojiId(2341))),
+        ("👩🏾\u{200d}🌾", (EmojiId(930), EmojiId(926))),
+        ("👋🏼", (EmojiId(170), EmojiId(168))),
+        ("🪰", (EmojiId(2550), EmojiId(2550))),
+        ("🏌🏿\u{200d}♀\u{fe0f}", (EmojiId(1849), EmojiId(1844))),
+        ("👨\u{200d}❤\u{fe0f}\u{200d}👨", (EmojiId(2341), EmojiId(2341))),
+        ("🧑🏼\u{200d}🎨", (EmojiId(1060), EmojiId(1058))),
+        ("🧗🏿", (EmojiId(1805), EmojiId(1800))),
+        ("🇹🇱", (EmojiId(3739), EmojiId(3739))),
+        ("🤸🏽\u{200d}♀", (EmojiId(1991), EmojiId(1988))),
+        ("🚶🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1554), EmojiId(1551))),
+        ("🙋\u{200d}♂", (EmojiId(758), EmojiId(758))),
+        ("🇱🇰", (EmojiId(3647), EmojiId(3647))),
+        ("🚵🏽\u{200d}♀", (EmojiId(1973), EmojiId(1970))),
+        ("🧛\u{200d}♂", (EmojiId(1436), EmojiId(1436))),
+        ("🧑🏾\u{200d}🎨", (EmojiId(1062), EmojiId(1058))),
+        ("❓", (EmojiId(3390), EmojiId(3390))),
+        ("🦹🏾\u{200d}♀", (EmojiId(1392), EmojiId(1388))),
+        ("👨🏿\u{200d}✈\u{fe0f}", (EmojiId(1087), EmojiId(1082))),
+        ("👴🏾", (EmojiId(654), EmojiId(650))),
+        ("🧑🏾\u{200d}⚕\u{fe0f}", (EmojiId(846), EmojiId(842))),
+        ("🤲🏼", (EmojiId(392), EmojiId(390))),
+        ("🧑\u{200d}🦼", (EmojiId(1653), EmojiId(1653))),
+        ("🧜🏼\u{200d}♀", (EmojiId(1462), EmojiId(1460))),
+        ("🇦🇲", (EmojiId(3519), EmojiId(3519))),
+        ("🇹🇫", (EmojiId(3734), EmojiId(3734))),
+        ("🇩🇬", (EmojiId(3571), EmojiId(3571))),
+        ("🧎🏻\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1606), EmojiId(1605))),
+        ("🍏", (EmojiId(2591), EmojiId(2591))),
+        ("🏋\u{fe0f}\u{200d}♀", (EmojiId(1934), EmojiId(1934))),
+        ("😳", (EmojiId(83), EmojiId(83))),
+        ("😢", (EmojiId(91), EmojiId(91))),
+        ("🥖", (EmojiId(2621), EmojiId(2621))),
+        ("👨🏻\u{200d}🚀", (EmojiId(1101), EmojiId(1100))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2101), EmojiId(2081))),
+        ("🔼", (EmojiId(3364), EmojiId(3364))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏻", (EmojiId(2381), EmojiId(2367))),
+        ("👨🏻\u{200d}⚕\u{fe0f}", (EmojiId(849), EmojiId(848))),
+        ("👨🏼\u{200d}⚕", (EmojiId(850), EmojiId(848))),
+        ("↩", (EmojiId(3317), EmojiId(3317))),
+        ("😼", (EmojiId(121), EmojiId(121))),
+        ("📪", (EmojiId(3166), EmojiId(3166))),
+        ("👷🏽", (EmojiId(1193), EmojiId(1190))),
+        ("🚂", (EmojiId(2782), EmojiId(2782))),
+        ("⛹🏼\u{200d}♀", (EmojiId(1918), EmojiId(1916))),
+        ("🙋🏻", (EmojiId(753), EmojiId(752))),
+        ("👩🏻\u{200d}🍼", (EmojiId(1317), EmojiId(1316))),
+        ("👨\u{200d}🍼", (EmojiId(1322), EmojiId(1322))),
+        ("🤷🏻\u{200d}♂\u{fe0f}", (EmojiId(831), EmojiId(830))),
+        ("👩🏿\u{200d}🦱", (EmojiId(601), EmojiId(596))),
+        ("🛌🏿", (EmojiId(2080), EmojiId(2075))),
+        ("🐂", (EmojiId(2456), EmojiId(2456))),
+        ("💑🏾", (EmojiId(2293), EmojiId(2289))),
+        ("🇦🇬", (EmojiId(3516), EmojiId(3516))),
+        ("🤦🏼\u{200d}♂\u{fe0f}", (EmojiId(814), EmojiId(812))),
+        ("👩\u{200d}🦰", (EmojiId(584), EmojiId(584))),
+        ("ℹ", (EmojiId(3442), EmojiId(3442))),
+        ("🤾🏻\u{200d}♂\u{fe0f}", (EmojiId(2022), EmojiId(2021))),
+        ("🇺🇬", (EmojiId(3749), EmojiId(3749))),
+        ("🤛", (EmojiId(354), EmojiId(354))),
+        ("🤾🏿\u{200d}♂\u{fe0f}", (EmojiId(2026), EmojiId(2021))),
+        ("✳", (EmojiId(3412), EmojiId(3412))),
+        ("🤦🏿\u{200d}♀", (EmojiId(823), EmojiId(818))),
+        ("🧙🏼\u{200d}♂", (EmojiId(1402), EmojiId(1400))),
+        ("🤽🏻\u{200d}♂", (EmojiId(2004), EmojiId(2003))),
+        ("👮🏽\u{200d}♀", (EmojiId(1145), EmojiId(1142))),
+        ("🧛🏻\u{200d}♀", (EmojiId(1443), EmojiId(1442))),
+        ("🦸\u{200d}♂", (EmojiId(1364), EmojiId(1364))),
+        ("👁\u{200d}🗨", (EmojiId(163), EmojiId(163))),
+        ("🏄🏾\u{200d}♀", (EmojiId(1866), EmojiId(1862))),
+        ("🍷", (EmojiId(2696), EmojiId(2696))),
+        ("㊗\u{fe0f}", (EmojiId(3466), EmojiId(3466))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2230), EmojiId(2211))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2098), EmojiId(2081))),
+        ("🧚🏻", (EmojiId(1413), EmojiId(1412))),
+        ("👨🏻\u{200d}🎨", (EmojiId(1065), EmojiId(1064))),
+        ("♊", (EmojiId(3343), EmojiId(3343))),
+        ("👩🏾\u{200d}🦯", (EmojiId(1645), EmojiId(1641))),
+        ("🚶🏾\u{200d}♂", (EmojiId(1537), EmojiId(1533))),
+        ("🥗", (EmojiId(2648), EmojiId(2648))),
+        ("♉", (EmojiId(3342), EmojiId(3342))),
+        ("👰🏽\u{200d}♀", (EmojiId(1289), EmojiId(1286))),
+        ("🍲", (EmojiId(2645), EmojiId(2645))),
+        ("👩🏼\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1721), EmojiId(1719))),
+        ("🧍🏼\u{200d}♂\u{fe0f}", (EmojiId(1571), EmojiId(1569))),
+        ("👳🏽\u{200d}♀\u{fe0f}", (EmojiId(1241), EmojiId(1238))),
+        ("📭", (EmojiId(3168), EmojiId(3168))),
+        ("👩\u{200d}❤\u{200d}👨", (EmojiId(2315), EmojiId(2315))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2233), EmojiId(2211))),
+        ("👷🏼\u{200d}♂", (EmojiId(1198), EmojiId(1196))),
+        ("👳🏼\u{200d}♀\u{fe0f}", (EmojiId(1240), EmojiId(1238))),
+        ("🚴🏾\u{200d}♂\u{fe0f}", (EmojiId(1950), EmojiId(1946))),
+        ("🦹🏾\u{200d}♂\u{fe0f}", (EmojiId(1386), EmojiId(1382))),
+        ("👩🏼\u{200d}🦱", (EmojiId(598), EmojiId(596))),
+        ("🍄\u{200d}🟫", (EmojiId(2618), EmojiId(2618))),
+        ("🎟", (EmojiId(2953), EmojiId(2953))),
+        ("🏋🏽", (EmojiId(1925), EmojiId(1922))),
+        ("🧚🏽\u{200d}♂", (EmojiId(1421), EmojiId(1418))),
+        ("😌", (EmojiId(52), EmojiId(52))),
+        ("💅🏻", (EmojiId(435), EmojiId(434))),
+        ("🚶\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1551), EmojiId(1551))),
+        ("🎀", (EmojiId(2950), EmojiId(2950))),
+        ("🦸\u{200d}♀\u{fe0f}", (EmojiId(1370), EmojiId(1370))),
+        ("👨🏻\u{200d}🌾", (EmojiId(921), EmojiId(920))),
+        ("👰🏼", (EmojiId(1276), EmojiId(1274))),
+        ("🖕🏽", (EmojiId(309), EmojiId(306))),
+        ("👩🏾\u{200d}🎨", (EmojiId(1074), EmojiId(1070))),
+        ("💆🏼\u{200d}♀", (EmojiId(1505), EmojiId(1503))),
+        ("🚵🏽", (EmojiId(1961), EmojiId(1958))),
+        ("♓", (EmojiId(3352), EmojiId(3352))),
+        ("🖐🏼", (EmojiId(182), EmojiId(180))),
+        ("🦸🏽\u{200d}♀", (EmojiId(1373), EmojiId(1370))),
+        ("☂", (EmojiId(2923), EmojiId(2923))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", (EmojiId(2191), EmojiId(2185))),
+        ("🚶🏽\u{200d}♀\u{fe0f}", (EmojiId(1542), EmojiId(1539))),
+        ("👨🏼\u{200d}✈\u{fe0f}", (EmojiId(1084), EmojiId(1082))),
+        ("🪯", (EmojiId(3340), EmojiId(3340))),
+        ("🧏🏼\u{200d}♂", (EmojiId(778), EmojiId(776))),
+        ("🧘🏿\u{200d}♂\u{fe0f}", (EmojiId(2062), EmojiId(2057))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏿", (EmojiId(2298), EmojiId(2289))),
+        ("🇻🇦", (EmojiId(3755), EmojiId(3755))),
+        ("🇸🇭", (EmojiId(3716), EmojiId(3716))),
+        ("🧎\u{200d}♀\u{fe0f}", (EmojiId(1593), EmojiId(1593))),
+        ("🙆🏼", (EmojiId(718), EmojiId(716))),
+        ("🕉\u{fe0f}", (EmojiId(3330), EmojiId(3330))),
+        ("🚬", (EmojiId(3272), EmojiId(3272))),
+        ("🛏\u{fe0f}", (EmojiId(3251), EmojiId(3251))),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏾", (EmojiId(2210), EmojiId(2185))),
+        ("📦", (EmojiId(3164), EmojiId(3164))),
+        ("🧛🏼\u{200d}♂\u{fe0f}", (EmojiId(1438), EmojiId(1436))),
+        ("🧛🏻\u{200d}♂", (EmojiId(1437), EmojiId(1436))),
+        ("⛹\u{fe0f}\u{200d}♀\u{fe0f}", (EmojiId(1916), EmojiId(1916))),
+        ("🥣", (EmojiId(2647), EmojiId(2647))),
+        ("💩", (EmojiId(109), EmojiId(109))),
+        ("🧖🏽\u{200d}♀", (EmojiId(1797), EmojiId(1794))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2282), EmojiId(2263))),
+        ("🏊🏻", (EmojiId(1887), EmojiId(1886))),
+        ("🇬🇲", (EmojiId(3601), EmojiId(3601))),
+        ("🐳", (EmojiId(2525), EmojiId(2525))),
+        ("🧖🏿", (EmojiId(1787), EmojiId(1782))),
+        ("🏭", (EmojiId(2753), EmojiId(2753))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", (EmojiId(2198), EmojiId(2185))),
+        ("👨🏼\u{200d}🦼", (EmojiId(1667), EmojiId(1665))),
+        ("🤨", (EmojiId(37), EmojiId(37))),
+        ("👨\u{200d}👨\u{200d}👧", (EmojiId(2399), EmojiId(2399))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏽", (EmojiId(2313), EmojiId(2289))),
+        ("👍🏿", (EmojiId(335), EmojiId(330))),
+        ("🧑🏿\u{200d}⚕", (EmojiId(847), EmojiId(842))),
+        ("👨🏾\u{200d}🚒", (EmojiId(1122), EmojiId(1118))),
+        ("🇦🇱", (EmojiId(3518), EmojiId(3518))),
+        ("🤟", (EmojiId(270), EmojiId(270))),
+        ("🗒\u{fe0f}", (EmojiId(3184), EmojiId(3184))),
+        ("🧔🏻\u{200d}♀", (EmojiId(549), EmojiId(548))),
+        ("🙍🏼\u{200d}♀", (EmojiId(676), EmojiId(674))),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏼", (EmojiId(2130), EmojiId(2107))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏾", (EmojiId(2366), EmojiId(2341))),
+        ("🏴\u{200d}☠", (EmojiId(3511), EmojiId(3511))),
+        ("🏋🏿", (EmojiId(1927), EmojiId(1922))),
+        ("🛶", (EmojiId(2835), EmojiId(2835))),
+        ("🏄🏽\u{200d}♂", (EmojiId(1859), EmojiId(1856))),
+        ("👋🏿", (EmojiId(173), EmojiId(168))),
+        ("🇨🇷", (EmojiId(3563), EmojiId(3563))),
+        ("👨\u{200d}👧\u{200d}👧", (EmojiId(2412), EmojiId(2412))),
+        ("👇🏿", (EmojiId(317), EmojiId(312))),
+        ("🏋🏽\u{200d}♀", (EmojiId(1937), EmojiId(1934))),
+        ("😅", (EmojiId(5), EmojiId(5))),
+        ("👨🏿\u{200d}⚖\u{fe0f}", (EmojiId(907), EmojiId(902))),
+        ("1\u{20e3}", (EmojiId(3421), EmojiId(3421))),
+        ("👩🏾\u{200d}🍳", (EmojiId(948), EmojiId(944))),
+        ("👩🏻\u{200d}✈", (EmojiId(1089), EmojiId(1088))),
+        ("🔰", (EmojiId(3402), EmojiId(3402))),
+        ("⛷\u{fe0f}", (EmojiId(1825), EmojiId(1825))),
+        ("❤\u{200d}🔥", (EmojiId(140), EmojiId(140))),
+        ("🫑", (EmojiId(2607), EmojiId(2607))),
+        ("👁\u{fe0f}", (EmojiId(490), EmojiId(490))),
+        ("↘", (EmojiId(3310), EmojiId(3310))),
+        ("🈹", (EmojiId(3459), EmojiId(3459))),
+        ("🇨🇨", (EmojiId(3551), EmojiId(3551))),
+        ("🚶🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1556), EmojiId(1551))),
+        ("👨🏾\u{200d}🦯\u{200d}➡", (EmojiId(1639), EmojiId(1635))),
+        ("🖐🏽", (EmojiId(183), EmojiId(180))),
+        ("🫱🏼\u{200d}🫲🏾", (EmojiId(408), EmojiId(396))),
+        ("🦊", (EmojiId(2438), EmojiId(2438))),
+        ("🇿🇲", (EmojiId(3768), EmojiId(3768))),
+        ("💈", (EmojiId(2780), EmojiId(2780))),
+        ("🐼", (EmojiId(2487), EmojiId(2487))),
+        ("🚵🏼\u{200d}♂\u{fe0f}", (EmojiId(1966), EmojiId(1964))),
+        ("🙎🏻\u{200d}♂", (EmojiId(687), EmojiId(686))),
+        ("🫱", (EmojiId(198), EmojiId(198))),
+        ("🐏", (EmojiId(2463), EmojiId(2463))),
+        ("🏊🏽\u{200d}♀\u{fe0f}", (EmojiId(1901), EmojiId(1898))),
+        ("🚆", (EmojiId(2786), EmojiId(2786))),
+        ("🙇", (EmojiId(788), EmojiId(788))),
+        ("🪡", (EmojiId(3016), EmojiId(3016))),
+        ("👩🏼\u{200d}🎨", (EmojiId(1072), EmojiId(1070))),
+        ("🇮🇨", (EmojiId(3617), EmojiId(3617))),
+        ("🏃🏼\u{200d}♂", (EmojiId(1733), EmojiId(1731))),
+        ("🏃🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1756), EmojiId(1755))),
+        ("👨🏾\u{200d}🦼\u{200d}➡", (EmojiId(1675), EmojiId(1671))),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏿", (EmojiId(2168), EmojiId(2159))),
+        ("🚡", (EmojiId(2850), EmojiId(2850))),
+        ("🤦🏿\u{200d}♂\u{fe0f}", (EmojiId(817), EmojiId(812))),
+        ("🇰🇵", (EmojiId(3638), EmojiId(3638))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏾", (EmojiId(2353), EmojiId(2341))),
+        ("🎤", (EmojiId(3081), EmojiId(3081))),
+        ("📹", (EmojiId(3122), EmojiId(3122))),
+        ("💆🏿\u{200d}♀\u{fe0f}", (EmojiId(1508), EmojiId(1503))),
+        ("👨🏻\u{200d}🦲", (EmojiId(573), EmojiId(572))),
+        ("🕣", (EmojiId(2880), EmojiId(2880))),
+        ("🧳", (EmojiId(2855), EmojiId(2855))),
+        ("🙂\u{200d}↕\u{fe0f}", (EmojiId(51), EmojiId(51))),
+        ("⛹\u{200d}♀", (EmojiId(1916), EmojiId(1916))),
+        ("⚔", (EmojiId(3213), EmojiId(3213))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2222), EmojiId(2211))),
+        ("♟", (EmojiId(3008), EmojiId(3008))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏻", (EmojiId(2351), EmojiId(2341))),
+        ("🏃🏼\u{200d}♂\u{200d}➡", (EmojiId(1757), EmojiId(1755))),
+        ("🚶🏽", (EmojiId(1530), EmojiId(1527))),
+        ("🇱🇧", (EmojiId(3644), EmojiId(3644))),
+        ("◀", (EmojiId(3361), EmojiId(3361))),
+        ("👩\u{200d}🎓", (EmojiId(872), EmojiId(872))),
+        ("❤\u{fe0f}", (EmojiId(142), EmojiId(142))),
+        ("👩🏻\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1684), EmojiId(1683))),
+        ("👨\u{200d}❤\u{200d}💋\u{200d}👨", (EmojiId(2237), EmojiId(2237))),
+        ("🏃🏽\u{200d}♂\u{fe0f}", (EmojiId(1734), EmojiId(1731))),
+        ("🇧🇿", (EmojiId(3549), EmojiId(3549))),
+        ("👨🏻\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1708), EmojiId(1707))),
+        ("👨🏿\u{200d}🍳", (EmojiId(943), EmojiId(938))),
+        ("🦈", (EmojiId(2532), EmojiId(2532))),
+        ("🏃🏻\u{200d}♂\u{200d}➡", (EmojiId(1756), EmojiId(1755))),
+        ("🏊🏿\u{200d}♂", (EmojiId(1897), EmojiId(1892))),
+        ("👋", (EmojiId(168), EmojiId(168))),
+        ("🚴🏽\u{200d}♂", (EmojiId(1949), EmojiId(1946))),
+        ("🧑\u{200d}🔬", (EmojiId(1004), EmojiId(1004))),
+        ("🐄", (EmojiId(2458), EmojiId(2458))),
+        ("🔉", (EmojiId(3068), EmojiId(3068))),
+        ("🧑🏼\u{200d}🦳", (EmojiId(616), EmojiId(614))),
+        ("🦸🏻\u{200d}♀\u{fe0f}", (EmojiId(1371), EmojiId(1370))),
+        ("🚴🏿\u{200d}♂", (EmojiId(1951), EmojiId(1946))),
+        ("👩🏻\u{200d}🦰", (EmojiId(585), EmojiId(584))),
+        ("💁🏽\u{200d}♀", (EmojiId(749), EmojiId(746))),
+        ("🤸🏻\u{200d}♂", (EmojiId(1983), EmojiId(1982))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", (EmojiId(2209), EmojiId(2185))),
+        ("🐺", (EmojiId(2437), EmojiId(2437))),
+        ("🤽\u{200d}♂", (EmojiId(2003), EmojiId(2003))),
+        ("🧸", (EmojiId(3000), EmojiId(3000))),
+        ("🏎", (EmojiId(2811), EmojiId(2811))),
+        ("🖇\u{fe0f}", (EmojiId(3194), EmojiId(3194))),
+        ("🧓🏾", (EmojiId(648), EmojiId(644))),
+        ("🧍🏿\u{200d}♂\u{fe0f}", (EmojiId(1574), EmojiId(1569))),
+        ("🤽🏾\u{200d}♀", (EmojiId(2013), EmojiId(2009))),
+        ("🎎", (EmojiId(2945), EmojiId(2945))),
+        ("⛹🏽\u{200d}♀", (EmojiId(1919), EmojiId(1916))),
+        ("🎭", (EmojiId(3012), EmojiId(3012))),
+        ("🍒", (EmojiId(2594), EmojiId(2594))),
+        ("👲🏿", (EmojiId(1249), EmojiId(1244))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2212), EmojiId(2211))),
+        ("🚒", (EmojiId(2799), EmojiId(2799))),
+        ("🐮", (EmojiId(2455), EmojiId(2455))),
+        ("🥨", (EmojiId(2623), EmojiId(2623))),
+        ("🕵🏻\u{200d}♂", (EmojiId(1155), EmojiId(1154))),
+        ("🧎🏾\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1615), EmojiId(1611))),
+        ("🙆🏻\u{200d}♀\u{fe0f}", (EmojiId(729), EmojiId(728))),
+        ("🎷", (EmojiId(3084), EmojiId(3084))),
+        ("🧖\u{200d}♂\u{fe0f}", (EmojiId(1788), EmojiId(1788))),
+        ("👬", (EmojiId(2159), EmojiId(2159))),
+        ("🏌🏻\u{200d}♂\u{fe0f}", (EmojiId(1839), EmojiId(1838))),
+        ("🚣🏼\u{200d}♀", (EmojiId(1882), EmojiId(1880))),
+        ("♑", (EmojiId(3350), EmojiId(3350))),
+        ("🧎🏻\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1606), EmojiId(1605))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2261), EmojiId(2237))),
+        ("🤱🏾", (EmojiId(1314), EmojiId(1310))),
+        ("🫲🏾", (EmojiId(208), EmojiId(204))),
+        ("🏊\u{200d}♀\u{fe0f}", (EmojiId(1898), EmojiId(1898))),
+        ("⛹🏽\u{200d}♀\u{fe0f}", (EmojiId(1919), EmojiId(1916))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2221), EmojiId(2211))),
+        ("🔜", (EmojiId(3326), EmojiId(3326))),
+        ("🧑🏽\u{200d}🍼", (EmojiId(1331), EmojiId(1328))),
+        ("👨🏿\u{200d}🦽\u{200d}➡", (EmojiId(1712), EmojiId(1707))),
+        ("🎺", (EmojiId(3088), EmojiId(3088))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2285), EmojiId(2263))),
+        ("🫗", (EmojiId(2703), EmojiId(2703))),
+        ("🦸🏾\u{200d}♀\u{fe0f}", (EmojiId(1374), EmojiId(1370))),
+        ("👨🏻", (EmojiId(531), EmojiId(530))),
+        ("🖋\u{fe0f}", (EmojiId(3173), EmojiId(3173))),
+        ("📎", (EmojiId(3193), EmojiId(3193))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2334), EmojiId(2315))),
+        ("🏴\u{200d}☠\u{fe0f}", (EmojiId(3511), EmojiId(3511))),
+        ("🤵🏼", (EmojiId(1258), EmojiId(1256))),
+        ("🕵\u{fe0f}\u{200d}♂\u{fe0f}", (EmojiId(1154), EmojiId(1154))),
+        ("🙅🏿\u{200d}♀\u{fe0f}", (EmojiId(715), EmojiId(710))),
+        ("👨🏿\u{200d}🦯\u{200d}➡", (EmojiId(1640), EmojiId(1635))),
+        ("👨🏻\u{200d}🦯", (EmojiId(1630), EmojiId(1629))),
+        ("🚞", (EmojiId(2792), EmojiId(2792))),
+        ("🕢", (EmojiId(2878), EmojiId(2878))),
+        ("🧪", (EmojiId(3234), EmojiId(3234))),
+        ("👱🏽\u{200d}♀", (EmojiId(635), EmojiId(632))),
+        ("🙅🏿\u{200d}♀", (EmojiId(715), EmojiId(710))),
+        ("🧈", (EmojiId(2650), EmojiId(2650))),
+        ("👨🏿\u{200d}⚕\u{fe0f}", (EmojiId(853), EmojiId(848))),
+        ("👨🏿\u{200d}🦽", (EmojiId(1706), EmojiId(1701))),
+        ("🧙🏻\u{200d}♂", (EmojiId(1401), EmojiId(1400))),
+        ("🤵🏼\u{200d}♀", (EmojiId(1270), EmojiId(1268))),
+        ("👱🏻\u{200d}♀", (EmojiId(633), EmojiId(632))),
+        ("💂\u{200d}♀", (EmojiId(1178), EmojiId(1178))),
+        ("👨\u{200d}👦\u{200d}👦", (EmojiId(2409), EmojiId(2409))),
+        ("🅱\u{fe0f}", (EmojiId(3438), EmojiId(3438))),
+        ("👩🏿\u{200d}🦯", (EmojiId(1646), EmojiId(1641))),
+        ("♻\u{fe0f}", (EmojiId(3398), EmojiId(3398))),
+        ("🚶🏻\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1558), EmojiId(1557))),
+        ("💜", (EmojiId(149), EmojiId(149))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏼", (EmojiId(2390), EmojiId(2367))),
+        ("👨🏽\u{200d}🏫", (EmojiId(887), EmojiId(884))),
+        ("👷🏻\u{200d}♀", (EmojiId(1203), EmojiId(1202))),
+        ("🏊🏿\u{200d}♀\u{fe0f}", (EmojiId(1903), EmojiId(1898))),
+        ("🏋🏽\u{200d}♂\u{fe0f}", (EmojiId(1931), EmojiId(1928))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏾", (EmojiId(2340), EmojiId(2315))),
+        ("😤", (EmojiId(101), EmojiId(101))),
+        ("🕙", (EmojiId(2883), EmojiId(2883))),
+        ("🧍🏽\u{200d}♀", (EmojiId(1578), EmojiId(1575))),
+        ("🦹🏾\u{200d}♀\u{fe0f}", (EmojiId(1392), EmojiId(1388))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏽", (EmojiId(2300), EmojiId(2289))),
+        ("🤵", (EmojiId(1256), EmojiId(1256))),
+        ("🤝🏾", (EmojiId(400), EmojiId(396))),
+        ("🐥", (EmojiId(2499), EmojiId(2499))),
+        ("🦹🏼", (EmojiId(1378), EmojiId(1376))),
+        ("🧑🏿\u{200d}🎓", (EmojiId(865), EmojiId(860))),
+        ("🧑🏽\u{200d}🌾", (EmojiId(917), EmojiId(914))),
+        ("👨🏽\u{200d}🦯", (EmojiId(1632), EmojiId(1629))),
+        ("🦸🏽\u{200d}♀\u{fe0f}", (EmojiId(1373), EmojiId(1370))),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏿", (EmojiId(2298), EmojiId(2289))),
+        ("🚑", (EmojiId(2798), EmojiId(2798))),
+        ("👂🏿", (EmojiId(471), EmojiId(466))),
+        ("🫱🏻\u{200d}🫲🏾", (EmojiId(404), EmojiId(396))),
+        ("⛓", (EmojiId(3228), EmojiId(3228))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2378), EmojiId(2367))),
+        ("🔔", (EmojiId(3073), EmojiId(3073))),
+        ("🏋🏼\u{200d}♂", (EmojiId(1930), EmojiId(1928))),
+        ("🧑🏻\u{200d}🦼\u{200d}➡", (EmojiId(1660), EmojiId(1659))),
+        ("🚶🏿\u{200d}♀\u{fe0f}", (EmojiId(1544), EmojiId(1539))),
+        ("❣", (EmojiId(138), EmojiId(138))),
+        ("👦🏻", (EmojiId(507), EmojiId(506))),
+        ("🥛", (EmojiId(2690), EmojiId(2690))),
+        ("❇", (EmojiId(3414), EmojiId(3414))),
+        ("🧗\u{200d}♀\u{fe0f}", (EmojiId(1812), EmojiId(1812))),
+        ("⛹🏻\u{200d}♂\u{fe0f}", (EmojiId(1911), EmojiId(1910))),
+        ("👨🏻\u{200d}🦯\u{200d}➡", (EmojiId(1636), EmojiId(1635))),
+        ("👳🏿\u{200d}♂\u{fe0f}", (EmojiId(1237), EmojiId(1232))),
+        ("👨🏼", (EmojiId(532), EmojiId(530))),
+        ("🤰🏾", (EmojiId(1296), EmojiId(1292))),
+        ("📞", (EmojiId(3098), EmojiId(3098))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2245), EmojiId(2237))),
+        ("🏌🏾\u{200d}♂\u{fe0f}", (EmojiId(1842), EmojiId(1838))),
+        ("🇬🇭", (EmojiId(3598), EmojiId(3598))),
+        ("🕰", (EmojiId(2862), EmojiId(2862))),
+        ("🤵🏼\u{200d}♂\u{fe0f}", (EmojiId(1264), EmojiId(1262))),
+        ("🤸🏿\u{200d}♀", (EmojiId(1993), EmojiId(1988))),
+        ("🗡", (EmojiId(3212), EmojiId(3212))),
+        ("🏋\u{fe0f}\u{200d}♂", (EmojiId(1928), EmojiId(1928))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏽", (EmojiId(2326), EmojiId(2315))),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏽", (EmojiId(2205), EmojiId(2185))),
+        ("🤽🏿", (EmojiId(2002), EmojiId(1997))),
+        ("🧑\u{200d}🦳", (EmojiId(614), EmojiId(614))),
+        ("🇬🇩", (EmojiId(3594), EmojiId(3594))),
+        ("😠", (EmojiId(103), EmojiId(103))),
+        ("🎅🏽", (EmojiId(1343), EmojiId(1340))),
+        ("🏄🏼\u{200d}♀\u{fe0f}", (EmojiId(1864), EmojiId(1862))),
+        ("👨🏾\u{200d}🎓", (EmojiId(870), EmojiId(866))),
+        ("🦸🏼\u{200d}♀", (EmojiId(1372), EmojiId(1370))),
+        ("🕓", (EmojiId(2871), EmojiId(2871))),
+        ("🔫", (EmojiId(2991), EmojiId(2991))),
+        ("🇻🇪", (EmojiId(3757), EmojiId(3757))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏻", (EmojiId(2329), EmojiId(2315))),
+        ("👰\u{200d}♀\u{fe0f}", (EmojiId(1286), EmojiId(1286))),
+        ("➿", (EmojiId(3410), EmojiId(3410))),
+        ("🐈", (EmojiId(2441), EmojiId(2441))),
+        ("🧒🏼", (EmojiId(502), EmojiId(500))),
+        ("🇲🇦", (EmojiId(3654), EmojiId(3654))),
+        ("🥎", (EmojiId(2963), EmojiId(2963))),
+        ("🧏🏻", (EmojiId(771), EmojiId(770))),
+        ("👧🏽", (EmojiId(515), EmojiId(512))),
+        ("👴🏻", (EmojiId(651), EmojiId(650))),
+        ("🚣🏼\u{200d}♀\u{fe0f}", (EmojiId(1882), EmojiId(1880))),
+        ("💘", (EmojiId(130), EmojiId(130))),
+        ("👈🏽", (EmojiId(291), EmojiId(288))),
+        ("🏟", (EmojiId(2732), EmojiId(2732))),
+        ("🐛", (EmojiId(2539), EmojiId(2539))),
+        ("⚱\u{fe0f}", (EmojiId(3275), EmojiId(3275))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2242), EmojiId(2237))),
+        ("🤽🏿\u{200d}♀", (EmojiId(2014), EmojiId(2009))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2277), EmojiId(2263))),
+        ("⏫", (EmojiId(3365), EmojiId(3365))),
+        ("🧖🏽\u{200d}♂", (EmojiId(1791), EmojiId(1788))),
+        ("⚛", (EmojiId(3329), EmojiId(3329))),
+        ("☃\u{fe0f}", (EmojiId(2928), EmojiId(2928))),
+        ("🧑🏽\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1698), EmojiId(1695))),
+        ("🧒🏽", (EmojiId(503), EmojiId(500))),
+        ("🧝🏽\u{200d}♀", (EmojiId(1481), EmojiId(1478))),
+        ("🚴🏻", (EmojiId(1941), EmojiId(1940))),
+        ("🧛\u{200d}♂\u{fe0f}", (EmojiId(1436), EmojiId(1436))),
+        ("📄", (EmojiId(3142), EmojiId(3142))),
+        ("👨🏽\u{200d}💼", (EmojiId(995), EmojiId(992))),
+        ("🫡", (EmojiId(35), EmojiId(35))),
+        ("🏃🏿\u{200d}➡\u{fe0f}", (EmojiId(1748), EmojiId(1743))),
+        ("🧍\u{200d}♀", (EmojiId(1575), EmojiId(1575))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2325), EmojiId(2315))),
+        ("🫴", (EmojiId(216), EmojiId(216))),
+        ("🌶\u{fe0f}", (EmojiId(2606), EmojiId(2606))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏻", (EmojiId(2368), EmojiId(2367))),
+        ("🏗\u{fe0f}", (EmojiId(2734), EmojiId(2734))),
+        ("🧎🏻\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1612), EmojiId(1611))),
+        ("🎸", (EmojiId(3086), EmojiId(3086))),
+        ("🤌🏿", (EmojiId(245), EmojiId(240))),
+        ("🇧🇯", (EmojiId(3537), EmojiId(3537))),
+        ("💇🏻\u{200d}♀\u{fe0f}", (EmojiId(1522), EmojiId(1521))),
+        ("🏖\u{fe0f}", (EmojiId(2728), EmojiId(2728))),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏿", (EmojiId(2150), EmojiId(2133))),
+        ("☁", (EmojiId(2908), EmojiId(2908))),
+        ("🟠", (EmojiId(3471), EmojiId(3471))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2363), EmojiId(2341))),
+        ("🐹", (EmojiId(2477), EmojiId(2477))),
+        ("🇫🇮", (EmojiId(3586), EmojiId(3586))),
+        ("🇧🇦", (EmojiId(3529), EmojiId(3529))),
+        ("🤾🏾\u{200d}♂\u{fe0f}", (EmojiId(2025), EmojiId(2021))),
+        ("😛", (EmojiId(24), EmojiId(24))),
+        ("🚣🏿\u{200d}♀\u{fe0f}", (EmojiId(1885), EmojiId(1880))),
+        ("🇹🇴", (EmojiId(3742), EmojiId(3742))),
+        ("✅", (EmojiId(3404), EmojiId(3404))),
+        ("👲🏾", (EmojiId(1248), EmojiId(1244))),
+        ("🕵🏼\u{200d}♂\u{fe0f}", (EmojiId(1156), EmojiId(1154))),
+        ("🪺", (EmojiId(2579), EmojiId(2579))),
+        ("🤸🏿\u{200d}♂", (EmojiId(1987), EmojiId(1982))),
+        ("🤱🏼", (EmojiId(1312), EmojiId(1310))),
+        ("🧎🏽\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1608), EmojiId(1605))),
+        ("🧓🏻", (EmojiId(645), EmojiId(644))),
+        ("🕖", (EmojiId(2877), EmojiId(2877))),
+        ("👮\u{200d}♂", (EmojiId(1136), EmojiId(1136))),
+        ("💣", (EmojiId(3214), EmojiId(3214))),
+        ("👮🏿\u{200d}♂\u{fe0f}", (EmojiId(1141), EmojiId(1136))),
+        ("🐜", (EmojiId(2540), EmojiId(2540))),
+        ("🔘", (EmojiId(3501), EmojiId(3501))),
+        ("🌤\u{fe0f}", (EmojiId(2911), EmojiId(2911))),
+        ("💛", (EmojiId(145), EmojiId(145))),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏾", (EmojiId(2175), EmojiId(2159))),
+        ("🤾\u{200d}♀\u{fe0f}", (EmojiId(2027), EmojiId(2027))),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏾", (EmojiId(2149), EmojiId(2133))),
+        ("👎🏻", (EmojiId(337), EmojiId(336))),
+        ("🚶🏾\u{200d}♂\u{fe0f}", (EmojiId(1537), EmojiId(1533))),
+        ("🚕", (EmojiId(2802), EmojiId(2802))),
+        ("👾", (EmojiId(115), EmojiId(115))),
+        ("8\u{20e3}", (EmojiId(3428), EmojiId(3428))),
+        ("🧚🏾\u{200d}♀", (EmojiId(1428), EmojiId(1424))),
+        ("🇸🇯", (EmojiId(3718), EmojiId(3718))),
+        ("🤾🏿\u{200d}♂", (EmojiId(2026), EmojiId(2021))),
+        ("🚶🏻\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1552), EmojiId(1551))),
+        ("🎽", (EmojiId(2984), EmojiId(2984))),
+        ("👨\u{200d}🦼", (EmojiId(1665), EmojiId(1665))),
+        ("📚", (EmojiId(3137), EmojiId(3137))),
+        ("👨🏼\u{200d}🦱", (EmojiId(562), EmojiId(560))),
+        ("🦶", (EmojiId(460), EmojiId(460))),
+        ("🧔🏼\u{200d}♂\u{fe0f}", (EmojiId(544), EmojiId(542))),
+        ("🧑\u{200d}💼", (EmojiId(986), EmojiId(986))),
+        ("👩🏼\u{200d}🏭", (EmojiId(982), EmojiId(980))),
+        ("🧎🏼\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1613), EmojiId(1611))),
+        ("👨🏻\u{200d}🦰", (EmojiId(555), EmojiId(554))),
+        ("👳\u{200d}♀\u{fe0f}", (EmojiId(1238), EmojiId(1238))),
+        ("⏭", (EmojiId(3359), EmojiId(3359))),
+        ("🍦", (EmojiId(2675), EmojiId(2675))),
+        ("🖋", (EmojiId(3173), EmojiId(3173))),
+        ("🇨🇻", (EmojiId(3565), EmojiId(3565))),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏽", (EmojiId(2209), EmojiId(2185))),
+        ("🧑\u{200d}🧒\u{200d}🧒", (EmojiId(2426), EmojiId(2426))),
+        ("👂🏽", (EmojiId(469), EmojiId(466))),
+        ("🇵🇾", (EmojiId(3703), EmojiId(3703))),
+        ("👰🏿\u{200d}♀\u{fe0f}", (EmojiId(1291), EmojiId(1286))),
+        ("🧢", (EmojiId(3059), EmojiId(3059))),
+        ("🐵", (EmojiId(2428), EmojiId(2428))),
+        ("🧎", (EmojiId(1581), EmojiId(1581))),
+        ("🏊🏾\u{200d}♂\u{fe0f}", (EmojiId(1896), EmojiId(1892))),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏽", (EmojiId(2300), EmojiId(2289))),
+        ("⚡", (EmojiId(2926), EmojiId(2926))),
+        ("⛹\u{fe0f}", (EmojiId(1904), EmojiId(1904))),
+        ("🗃\u{fe0f}", (EmojiId(3198), EmojiId(3198))),
+        ("⛹🏿\u{200d}♂\u{fe0f}", (EmojiId(1915), EmojiId(1910))),
+        ("👩\u{200d}🦽", (EmojiId(1713), EmojiId(1713))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2262), EmojiId(2237))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2258), EmojiId(2237))),
+        ("🕍", (EmojiId(2762), EmojiId(2762))),
+        ("🚶\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1557), EmojiId(1557))),
+        ("📊", (EmojiId(3189), EmojiId(3189))),
+        ("🔌", (EmojiId(3103), EmojiId(3103))),
+        ("👨\u{200d}🦰", (EmojiId(554), EmojiId(554))),
+        ("👮🏻", (EmojiId(1131), EmojiId(1130))),
+        ("🧍🏽\u{200d}♂", (EmojiId(1572), EmojiId(1569))),
+        ("👨\u{200d}🎤", (EmojiId(1046), EmojiId(1046))),
+        ("🛹", (EmojiId(2819), EmojiId(2819))),
+        ("🧒🏻", (EmojiId(501), EmojiId(500))),
+        ("🏃🏿\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1760), EmojiId(1755))),
+        ("🙅🏻\u{200d}♀\u{fe0f}", (EmojiId(711), EmojiId(710))),
+        ("⚖", (EmojiId(3224), EmojiId(3224))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏿", (EmojiId(2324), EmojiId(2315))),
+        ("🧍🏼", (EmojiId(1565), EmojiId(1563))),
+        ("↘\u{fe0f}", (EmojiId(3310), EmojiId(3310))),
+        ("🧚🏻\u{200d}♀\u{fe0f}", (EmojiId(1425), EmojiId(1424))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2379), EmojiId(2367))),
+        ("5\u{fe0f}\u{20e3}", (EmojiId(3425), EmojiId(3425))),
+        ("👨🏽\u{200d}⚖\u{fe0f}", (EmojiId(905), EmojiId(902))),
+        ("🏄🏽\u{200d}♂\u{fe0f}", (EmojiId(1859), EmojiId(1856))),
+        ("🤶🏿", (EmojiId(1351), EmojiId(1346))),
+        ("🧑🏽\u{200d}🚀", (EmojiId(1097), EmojiId(1094))),
+        ("🤚🏽", (EmojiId(177), EmojiId(174))),
+        ("🧑🏿\u{200d}⚖\u{fe0f}", (EmojiId(901), EmojiId(896))),
+        ("🧚🏿\u{200d}♀", (EmojiId(1429), EmojiId(1424))),
+        ("👨🏻\u{200d}🦼\u{200d}➡", (EmojiId(1672), EmojiId(1671))),
+        ("👳🏿\u{200d}♀", (EmojiId(1243), EmojiId(1238))),
+        ("🧘🏽\u{200d}♀\u{fe0f}", (EmojiId(2066), EmojiId(2063))),
+        ("👱🏾\u{200d}♀\u{fe0f}", (EmojiId(636), EmojiId(632))),
+        ("🇧🇼", (EmojiId(3547), EmojiId(3547))),
+        ("🚿", (EmojiId(3256), EmojiId(3256))),
+        ("👭🏽", (EmojiId(2110), EmojiId(2107))),
+        ("🩴", (EmojiId(3045), EmojiId(3045))),
+        ("👰🏻\u{200d}♀", (EmojiId(1287), EmojiId(1286))),
+        ("🚴\u{200d}♀\u{fe0f}", (EmojiId(1952), EmojiId(1952))),
+        ("🤹🏻\u{200d}♂\u{fe0f}", (EmojiId(2040), EmojiId(2039))),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏻", (EmojiId(2311), EmojiId(2289))),
+        ("👳🏼\u{200d}♂", (EmojiId(1234), EmojiId(1232))),
+        ("👏🏿", (EmojiId(371), EmojiId(366))),
+        ("🕵🏽\u{200d}♂\u{fe0f}", (EmojiId(1157), EmojiId(1154))),
+        ("🧑🏻\u{200d}🌾", (EmojiId(915), EmojiId(914))),
+        ("🙆\u{200d}♀", (EmojiId(728), EmojiId(728))),
+        ("🚶🏼\u{200d}♀", (EmojiId(1541), EmojiId(1539))),
+        ("🛐", (EmojiId(3328), EmojiId(3328))),
+        ("☢\u{fe0f}", (EmojiId(3305), EmojiId(3305))),
+        ("✝", (EmojiId(3334), EmojiId(3334))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2286), EmojiId(2263))),
+        ("🤼", (EmojiId(1994), EmojiId(1994))),
+        ("🍽\u{fe0f}", (EmojiId(2710), EmojiId(2710))),
+        ("🧍🏿", (EmojiId(1568), EmojiId(1563))),
+        ("👦🏼", (EmojiId(508), EmojiId(506))),
+        ("♂", (EmojiId(3380), EmojiId(3380))),
+        ("👩🏽\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1650), EmojiId(1647))),
+        ("🙍🏼\u{200d}♂\u{fe0f}", (EmojiId(670), EmojiId(668))),
+        ("👳🏿", (EmojiId(1231), EmojiId(1226))),
+        ("🤾🏻\u{200d}♀", (EmojiId(2028), EmojiId(2027))),
+        ("👩\u{200d}🚒", (EmojiId(1124), EmojiId(1124))),
+        ("♀\u{fe0f}", (EmojiId(3379), EmojiId(3379))),
+        ("👸🏽", (EmojiId(1223), EmojiId(1220))),
+        ("🔂", (EmojiId(3356), EmojiId(3356))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2324), EmojiId(2315))),
+        ("🇨🇿", (EmojiId(3569), EmojiId(3569))),
+        ("👼🏼", (EmojiId(1336), EmojiId(1334))),
+        ("🚴🏼\u{200d}♀\u{fe0f}", (EmojiId(1954), EmojiId(1952))),
+        ("👨\u{200d}⚖", (EmojiId(902), EmojiId(902))),
+        ("🧑🏼\u{200d}🦯", (EmojiId(1619), EmojiId(1617))),
+        ("🇧🇭", (EmojiId(3535), EmojiId(3535))),
+        ("🛏", (EmojiId(3251), EmojiId(3251))),
+        ("🦒", (EmojiId(2469), EmojiId(2469))),
+        ("🩺", (EmojiId(3245), EmojiId(3245))),
+        ("🤎", (EmojiId(150), EmojiId(150))),
+        ("👵🏻", (EmojiId(657), EmojiId(656))),
+        ("🧘🏽", (EmojiId(2054), EmojiId(2051))),
+        ("🦸🏾", (EmojiId(1362), EmojiId(1358))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2365), EmojiId(2341))),
+        ("🙅\u{200d}♀", (EmojiId(710), EmojiId(710))),
+        ("🇲🇳", (EmojiId(3664), EmojiId(3664))),
+        ("👩🏻\u{200d}⚖\u{fe0f}", (EmojiId(909), EmojiId(908))),
+        ("3\u{20e3}", (EmojiId(3423), EmojiId(3423))),
+        ("👨🏼\u{200d}🦲", (EmojiId(574), EmojiId(572))),
+        ("👐🏿", (EmojiId(389), EmojiId(384))),
+        ("🕴\u{fe0f}", (EmojiId(1773), EmojiId(1773))),
+        ("☦\u{fe0f}", (EmojiId(3335), EmojiId(3335))),
+        ("🧙\u{200d}♀", (EmojiId(1406), EmojiId(1406))),
+        ("🤡", (EmojiId(110), EmojiId(110))),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏿", (EmojiId(2206), EmojiId(2185))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2244), EmojiId(2237))),
+        ("🧑\u{200d}🎄", (EmojiId(1352), EmojiId(1352))),
+        ("❤", (EmojiId(142), EmojiId(142))),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏿", (EmojiId(2128), EmojiId(2107))),
+        ("🎩", (EmojiId(3057), EmojiId(3057))),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏻", (EmojiId(2155), EmojiId(2133))),
+        ("🧑🏿\u{200d}🦰", (EmojiId(595), EmojiId(590))),
+        ("🧏🏼\u{200d}♀\u{fe0f}", (EmojiId(784), EmojiId(782))),
+        ("🤸🏿", (EmojiId(1981), EmojiId(1976))),
+        ("🧎\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1611), EmojiId(1611))),
+        ("🧑🏻\u{200d}🎄", (EmojiId(1353), EmojiId(1352))),
+        ("👩🏽\u{200d}🎤", (EmojiId(1055), EmojiId(1052))),
+        ("🫅🏼", (EmojiId(1210), EmojiId(1208))),
+        ("🦻🏿", (EmojiId(477), EmojiId(472))),
+        ("🤾\u{200d}♀", (EmojiId(2027), EmojiId(2027))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏽", (EmojiId(2339), EmojiId(2315))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏽", (EmojiId(2374), EmojiId(2367))),
+        ("🚇", (EmojiId(2787), EmojiId(2787))),
+        ("🏞", (EmojiId(2731), EmojiId(2731))),
+        ("🤽🏻\u{200d}♀\u{fe0f}", (EmojiId(2010), EmojiId(2009))),
+        ("🗞", (EmojiId(3144), EmojiId(3144))),
+        ("🏳\u{fe0f}\u{200d}🌈", (EmojiId(3509), EmojiId(3509))),
+        ("🚶🏽\u{200d}♀\u{200d}➡", (EmojiId(1554), EmojiId(1551))),
+        ("🗨\u{fe0f}", (EmojiId(164), EmojiId(164))),
+        ("🕵🏽\u{200d}♀", (EmojiId(1163), EmojiId(1160))),
+        ("👩🏼\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1685), EmojiId(1683))),
+        ("🤦\u{200d}♀", (EmojiId(818), EmojiId(818))),
+        ("🙀", (EmojiId(123), EmojiId(123))),
+        ("🤹🏾", (EmojiId(2037), EmojiId(2033))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2279), EmojiId(2263))),
+        ("🪔", (EmojiId(3130), EmojiId(3130))),
+        ("🧙🏿\u{200d}♀", (EmojiId(1411), EmojiId(1406))),
+        ("🇰🇮", (EmojiId(3635), EmojiId(3635))),
+        ("🔪", (EmojiId(2713), EmojiId(2713))),
+        ("🦓", (EmojiId(2452), EmojiId(2452))),
+        ("👵🏽", (EmojiId(659), EmojiId(656))),
+        ("🤦🏽", (EmojiId(809), EmojiId(806))),
+        ("🏃🏻\u{200d}♂", (EmojiId(1732), EmojiId(1731))),
+        ("👨🏼\u{200d}🦯\u{200d}➡", (EmojiId(1637), EmojiId(1635))),
+        ("🧎🏿\u{200d}♂", (EmojiId(1592), EmojiId(1587))),
+        ("🧔🏽", (EmojiId(539), EmojiId(536))),
+        ("🧂", (EmojiId(2651), EmojiId(2651))),
+        ("🧖🏼\u{200d}♀\u{fe0f}", (EmojiId(1796), EmojiId(1794))),
+        ("↕\u{fe0f}", (EmojiId(3315), EmojiId(3315))),
+        ("🤹🏻\u{200d}♀", (EmojiId(2046), EmojiId(2045))),
+        ("🚶🏼\u{200d}➡\u{fe0f}", (EmojiId(1547), EmojiId(1545))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏿", (EmojiId(2362), EmojiId(2341))),
+        ("🦭", (EmojiId(2528), EmojiId(2528))),
+        ("🤜🏼", (EmojiId(362), EmojiId(360))),
+        ("💀", (EmojiId(107), EmojiId(107))),
+        ("🙋🏿\u{200d}♀", (EmojiId(769), EmojiId(764))),
+        ("🧎🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1616), EmojiId(1611))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2272), EmojiId(2263))),
+        ("🧏🏾", (EmojiId(774), EmojiId(770))),
+        ("🫁", (EmojiId(486), EmojiId(486))),
+        ("🔸", (EmojiId(3496), EmojiId(3496))),
+        ("👩🏻\u{200d}⚕", (EmojiId(855), EmojiId(854))),
+        ("♈", (EmojiId(3341), EmojiId(3341))),
+        ("🙎🏽\u{200d}♀", (EmojiId(695), EmojiId(692))),
+        ("👨\u{200d}🍳", (EmojiId(938), EmojiId(938))),
+        ("🐤", (EmojiId(2498), EmojiId(2498))),
+        ("👨🏾\u{200d}🦼", (EmojiId(1669), EmojiId(1665))),
+        ("🤷🏻\u{200d}♀", (EmojiId(837), EmojiId(836))),
+        ("🧍🏻\u{200d}♀\u{fe0f}", (EmojiId(1576), EmojiId(1575))),
+        ("🤤", (EmojiId(55), EmojiId(55))),
+        ("💂🏼", (EmojiId(1168), EmojiId(1166))),
+        ("🧗🏽\u{200d}♂\u{fe0f}", (EmojiId(1809), EmojiId(1806))),
+        ("🦶🏾", (EmojiId(464), EmojiId(460))),
+        ("🤏🏼", (EmojiId(248), EmojiId(246))),
+        ("🦤", (EmojiId(2507), EmojiId(2507))),
+        ("🧗🏻\u{200d}♂", (EmojiId(1807), EmojiId(1806))),
+        ("🦑", (EmojiId(2673), EmojiId(2673))),
+        ("🍨", (EmojiId(2677), EmojiId(2677))),
+        ("🤹🏿\u{200d}♀", (EmojiId(2050), EmojiId(2045))),
+        ("🧑🏿\u{200d}🚀", (EmojiId(1099), EmojiId(1094))),
+        ("☢", (EmojiId(3305), EmojiId(3305))),
+        ("👤", (EmojiId(2419), EmojiId(2419))),
+        ("🦹🏻", (EmojiId(1377), EmojiId(1376))),
+        ("▫\u{fe0f}", (EmojiId(3493), EmojiId(3493))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2272), EmojiId(2263))),
+        ("🧑🏽\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1662), EmojiId(1659))),
+        ("🧑\u{200d}⚖", (EmojiId(896), EmojiId(896))),
+        ("🧤", (EmojiId(3028), EmojiId(3028))),
+        ("🌖", (EmojiId(2892), EmojiId(2892))),
+        ("🦝", (EmojiId(2439), EmojiId(2439))),
+        ("🛎\u{fe0f}", (EmojiId(2854), EmojiId(2854))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏻", (EmojiId(2342), EmojiId(2341))),
+        ("👸", (EmojiId(1220), EmojiId(1220))),
+        ("🚶🏿\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1562), EmojiId(1557))),
+        ("🧍🏻\u{200d}♀", (EmojiId(1576), EmojiId(1575))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2270), EmojiId(2263))),
+        ("🚴🏽\u{200d}♂\u{fe0f}", (EmojiId(1949), EmojiId(1946))),
+        ("🐭", (EmojiId(2474), EmojiId(2474))),
+        ("👺", (EmojiId(112), EmojiId(112))),
+        ("❤\u{fe0f}\u{200d}🩹", (EmojiId(141), EmojiId(141))),
+        ("🆘", (EmojiId(3450), EmojiId(3450))),
+        ("👰🏿\u{200d}♂", (EmojiId(1285), EmojiId(1280))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏼", (EmojiId(2377), EmojiId(2367))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2319), EmojiId(2315))),
+        ("🙅\u{200d}♂\u{fe0f}", (EmojiId(704), EmojiId(704))),
+        ("🤌", (EmojiId(240), EmojiId(240))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2386), EmojiId(2367))),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏽", (EmojiId(2183), EmojiId(2159))),
+        ("😔", (EmojiId(53), EmojiId(53))),
+        ("🏃🏾\u{200d}➡", (EmojiId(1747), EmojiId(1743))),
+        ("🕥", (EmojiId(2884), EmojiId(2884))),
+        ("🙇🏻\u{200d}♂\u{fe0f}", (EmojiId(795), EmojiId(794))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", (EmojiId(2194), EmojiId(2185))),
+        ("🧍🏽", (EmojiId(1566), EmojiId(1563))),
+        ("🏋🏿\u{200d}♂\u{fe0f}", (EmojiId(1933), EmojiId(1928))),
+        ("👫🏿", (EmojiId(2138), EmojiId(2133))),
+        ("👩🏿\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1652), EmojiId(1647))),
+        ("💇🏾\u{200d}♂\u{fe0f}", (EmojiId(1519), EmojiId(1515))),
+        ("🧛", (EmojiId(1430), EmojiId(1430))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2223), EmojiId(2211))),
+        ("🧡", (EmojiId(144), EmojiId(144))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2246), EmojiId(2237))),
+        ("⏏\u{fe0f}", (EmojiId(3371), EmojiId(3371))),
+        ("🏔\u{fe0f}", (EmojiId(2723), EmojiId(2723))),
+        ("🟦", (EmojiId(3483), EmojiId(3483))),
+        ("🧏🏻\u{200d}♀", (EmojiId(783), EmojiId(782))),
+        ("🧏\u{200d}♂", (EmojiId(776), EmojiId(776))),
+        ("⤵\u{fe0f}", (EmojiId(3320), EmojiId(3320))),
+        ("🍎", (EmojiId(2590), EmojiId(2590))),
+        ("🤷🏿\u{200d}♂\u{fe0f}", (EmojiId(835), EmojiId(830))),
+        ("👨🏼\u{200d}🎤", (EmojiId(1048), EmojiId(1046))),
+        ("🫰🏼", (EmojiId(266), EmojiId(264))),
+        ("🙎", (EmojiId(680), EmojiId(680))),
+        ("🤳🏻", (EmojiId(441), EmojiId(440))),
+        ("💁🏽\u{200d}♂", (EmojiId(743), EmojiId(740))),
+        ("🎳", (EmojiId(2970), EmojiId(2970))),
+        ("🙎🏼\u{200d}♀\u{fe0f}", (EmojiId(694), EmojiId(692))),
+        ("🐝", (EmojiId(2541), EmojiId(2541))),
+        ("🏌\u{fe0f}", (EmojiId(1832), EmojiId(1832))),
+        ("👮🏿", (EmojiId(1135), EmojiId(1130))),
+        ("🙍\u{200d}♂", (EmojiId(668), EmojiId(668))),
+        ("↪\u{fe0f}", (EmojiId(3318), EmojiId(3318))),
+        ("⚧", (EmojiId(3381), EmojiId(3381))),
+        ("👞", (EmojiId(3046), EmojiId(3046))),
+        ("👨🏻\u{200d}🦽", (EmojiId(1702), EmojiId(1701))),
+        ("🧛🏾\u{200d}♀\u{fe0f}", (EmojiId(1446), EmojiId(1442))),
+        ("🦹🏽\u{200d}♀", (EmojiId(1391), EmojiId(1388))),
+        ("🏄🏾\u{200d}♂", (EmojiId(1860), EmojiId(1856))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2359), EmojiId(2341))),
+        ("🤸", (EmojiId(1976), EmojiId(1976))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2230), EmojiId(2211))),
+        ("🕵🏾", (EmojiId(1152), EmojiId(1148))),
+        ("✈", (EmojiId(2841), EmojiId(2841))),
+        ("👩🏿\u{200d}🎤", (EmojiId(1057), EmojiId(1052))),
+        ("🧗🏼\u{200d}♀\u{fe0f}", (EmojiId(1814), EmojiId(1812))),
+        ("🏃", (EmojiId(1725), EmojiId(1725))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏽", (EmojiId(2348), EmojiId(2341))),
+        ("🏌🏼\u{200d}♂", (EmojiId(1840), EmojiId(1838))),
+        ("🦹🏾\u{200d}♂", (EmojiId(1386), EmojiId(1382))),
+        ("🚶🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1553), EmojiId(1551))),
+        ("🏄🏿", (EmojiId(1855), EmojiId(1850))),
+        ("👷🏼\u{200d}♀\u{fe0f}", (EmojiId(1204), EmojiId(1202))),
+        ("🧍🏼\u{200d}♀", (EmojiId(1577), EmojiId(1575))),
+        ("☎", (EmojiId(3097), EmojiId(3097))),
+        ("🎪", (EmojiId(2781), EmojiId(2781))),
+        ("👨🏼\u{200d}🎨", (EmojiId(1066), EmojiId(1064))),
+        ("🎂", (EmojiId(2680), EmojiId(2680))),
+        ("🙎🏾\u{200d}♂", (EmojiId(690), EmojiId(686))),
+        ("📅", (EmojiId(3182), EmojiId(3182))),
+        ("🏂", (EmojiId(1826), EmojiId(1826))),
+        ("🇹🇹", (EmojiId(3744), EmojiId(3744))),
+        ("🙍🏻\u{200d}♀\u{fe0f}", (EmojiId(675), EmojiId(674))),
+        ("🏃\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1749), EmojiId(1749))),
+        ("🌥\u{fe0f}", (EmojiId(2912), EmojiId(2912))),
+        ("🧙🏼\u{200d}♂\u{fe0f}", (EmojiId(1402), EmojiId(1400))),
+        ("🙆\u{200d}♂\u{fe0f}", (EmojiId(722), EmojiId(722))),
+        ("⁉", (EmojiId(3389), EmojiId(3389))),
+        ("👩🏼\u{200d}🦲", (EmojiId(622), EmojiId(620))),
+        ("🐋", (EmojiId(2526), EmojiId(2526))),
+        ("®\u{fe0f}", (EmojiId(3416), EmojiId(3416))),
+        ("👨🏾\u{200d}🔧", (EmojiId(960), EmojiId(956))),
+        ("🥚", (EmojiId(2642), EmojiId(2642))),
+        ("👨\u{200d}👩\u{200d}👧", (EmojiId(2394), EmojiId(2394))),
+        ("💺", (EmojiId(2846), EmojiId(2846))),
+        ("💁🏿", (EmojiId(739), EmojiId(734))),
+        ("🤜🏿", (EmojiId(365), EmojiId(360))),
+        ("👧🏼", (EmojiId(514), EmojiId(512))),
+        ("👌🏿", (EmojiId(239), EmojiId(234))),
+        ("🤸🏿\u{200d}♀\u{fe0f}", (EmojiId(1993), EmojiId(1988))),
+        ("🈶", (EmojiId(3456), EmojiId(3456))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", (EmojiId(2193), EmojiId(2185))),
+        ("📐", (EmojiId(3196), EmojiId(3196))),
+        ("🤱🏽", (EmojiId(1313), EmojiId(1310))),
+        ("🦙", (EmojiId(2468), EmojiId(2468))),
+        ("🏈", (EmojiId(2966), EmojiId(2966))),
+        ("👴", (EmojiId(650), EmojiId(650))),
+        ("👨🏽\u{200d}🦽\u{200d}➡", (EmojiId(1710), EmojiId(1707))),
+        ("👩🏼\u{200d}🦼", (EmojiId(1679), EmojiId(1677))),
+        ("🏃🏻\u{200d}♂\u{fe0f}", (EmojiId(1732), EmojiId(1731))),
+        ("👨🏾\u{200d}🍼", (EmojiId(1326), EmojiId(1322))),
+        ("👧", (EmojiId(512), EmojiId(512))),
+        ("🧎🏾\u{200d}➡\u{fe0f}", (EmojiId(1603), EmojiId(1599))),
+        ("🧚\u{200d}♂\u{fe0f}", (EmojiId(1418), EmojiId(1418))),
+        ("🛗", (EmojiId(3248), EmojiId(3248))),
+        ("🧎🏻\u{200d}♀\u{fe0f}", (EmojiId(1594), EmojiId(1593))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏼", (EmojiId(2295), EmojiId(2289))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏿", (EmojiId(2388), EmojiId(2367))),
+        ("🤦", (EmojiId(806), EmojiId(806))),
+        ("👩\u{200d}🏭", (EmojiId(980), EmojiId(980))),
+        ("🧑🏽\u{200d}🍳", (EmojiId(935), EmojiId(932))),
+        ("🙏", (EmojiId(422), EmojiId(422))),
+        ("👨🏽\u{200d}🍳", (EmojiId(941), EmojiId(938))),
+        ("⚫", (EmojiId(3477), EmojiId(3477))),
+        ("👰🏼\u{200d}♀", (EmojiId(1288), EmojiId(1286))),
+        ("🇪🇸", (EmojiId(3583), EmojiId(3583))),
+        ("🧑🏼\u{200d}🦽", (EmojiId(1691), EmojiId(1689))),
+        ("✨", (EmojiId(2939), EmojiId(2939))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2248), EmojiId(2237))),
+        ("👨🏾\u{200d}🦽\u{200d}➡", (EmojiId(1711), EmojiId(1707))),
+        ("🏇🏿", (EmojiId(1824), EmojiId(1819))),
+        ("🥒", (EmojiId(2608), EmojiId(2608))),
+        ("💂🏽\u{200d}♂", (EmojiId(1175), EmojiId(1172))),
+        ("🪫", (EmojiId(3102), EmojiId(3102))),
+        ("⛱", (EmojiId(2925), EmojiId(2925))),
+        ("🙊", (EmojiId(128), EmojiId(128))),
+        ("🙎🏼", (EmojiId(682), EmojiId(680))),
+        ("👳🏻\u{200d}♂", (EmojiId(1233), EmojiId(1232))),
+        ("🤸🏾\u{200d}♀", (EmojiId(1992), EmojiId(1988))),
+        ("‼\u{fe0f}", (EmojiId(3388), EmojiId(3388))),
+        ("⛏\u{fe0f}", (EmojiId(3209), EmojiId(3209))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2213), EmojiId(2211))),
+        ("🧹", (EmojiId(3262), EmojiId(3262))),
+        ("🖐🏻", (EmojiId(181), EmojiId(180))),
+        ("🫵🏾", (EmojiId(328), EmojiId(324))),
+        ("👄", (EmojiId(492), EmojiId(492))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2088), EmojiId(2081))),
+        ("💉", (EmojiId(3240), EmojiId(3240))),
+        ("🎖\u{fe0f}", (EmojiId(2955), EmojiId(2955))),
+        ("🏄🏼\u{200d}♂", (EmojiId(1858), EmojiId(1856))),
+        ("🕉", (EmojiId(3330), EmojiId(3330))),
+        ("🌩\u{fe0f}", (EmojiId(2916), EmojiId(2916))),
+        ("🇸🇮", (EmojiId(3717), EmojiId(3717))),
+        ("👳🏽", (EmojiId(1229), EmojiId(1226))),
+        ("🚴🏽\u{200d}♀\u{fe0f}", (EmojiId(1955), EmojiId(1952))),
+        ("👯\u{200d}♂", (EmojiId(1780), EmojiId(1780))),
+        ("🚐", (EmojiId(2797), EmojiId(2797))),
+        ("📂", (EmojiId(3180), EmojiId(3180))),
+        ("🚵🏿\u{200d}♀\u{fe0f}", (EmojiId(1975), EmojiId(1970))),
+        ("🍥", (EmojiId(2664), EmojiId(2664))),
+        ("🚵🏽\u{200d}♂\u{fe0f}", (EmojiId(1967), EmojiId(1964))),
+        ("♾\u{fe0f}", (EmojiId(3387), EmojiId(3387))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2356), EmojiId(2341))),
+        ("👨🏽\u{200d}💻", (EmojiId(1031), EmojiId(1028))),
+        ("🧝🏿\u{200d}♀", (EmojiId(1483), EmojiId(1478))),
+        ("👩🏾\u{200d}🎓", (EmojiId(876), EmojiId(872))),
+        ("🧎🏽\u{200d}♂", (EmojiId(1590), EmojiId(1587))),
+        ("🤾\u{200d}♂", (EmojiId(2021), EmojiId(2021))),
+        ("👩🏿\u{200d}🚒", (EmojiId(1129), EmojiId(1124))),
+        ("⛹🏽\u{200d}♂", (EmojiId(1913), EmojiId(1910))),
+        ("👨🏻\u{200d}⚖", (EmojiId(903), EmojiId(902))),
+        ("🫴🏿", (EmojiId(221), EmojiId(216))),
+        ("🚤", (EmojiId(2836), EmojiId(2836))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2326), EmojiId(2315))),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏻", (EmojiId(2307), EmojiId(2289))),
+        ("🧑🏾\u{200d}🏭", (EmojiId(972), EmojiId(968))),
+        ("◾", (EmojiId(3490), EmojiId(3490))),
+        ("™", (EmojiId(3417), EmojiId(3417))),
+        ("👆🏻", (EmojiId(301), EmojiId(300))),
+        ("💂🏿\u{200d}♂\u{fe0f}", (EmojiId(1177), EmojiId(1172))),
+        ("🧝🏽\u{200d}♂\u{fe0f}", (EmojiId(1475), EmojiId(1472))),
+        ("🏄🏼\u{200d}♂\u{fe0f}", (EmojiId(1858), EmojiId(1856))),
+        ("🧑\u{200d}🤝\u{200d}🧑", (EmojiId(2081), EmojiId(2081))),
+        ("🧑🏾\u{200d}🦰", (EmojiId(594), EmojiId(590))),
+        ("🧑🏾\u{200d}🦼\u{200d}➡", (EmojiId(1663), EmojiId(1659))),
+        ("🧑🏻\u{200d}🦼", (EmojiId(1654), EmojiId(1653))),
+        ("🧙🏼\u{200d}♀", (EmojiId(1408), EmojiId(1406))),
+        ("👩🏼\u{200d}⚖\u{fe0f}", (EmojiId(910), EmojiId(908))),
+        ("👩🏿\u{200d}💼", (EmojiId(1003), EmojiId(998))),
+        ("💂🏾\u{200d}♂", (EmojiId(1176), EmojiId(1172))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2345), EmojiId(2341))),
+        ("🚣🏼\u{200d}♂\u{fe0f}", (EmojiId(1876), EmojiId(1874))),
+        ("♥\u{fe0f}", (EmojiId(3005), EmojiId(3005))),
+        ("🦸🏿\u{200d}♂\u{fe0f}", (EmojiId(1369), EmojiId(1364))),
+        ("👨\u{200d}👧\u{200d}👦", (EmojiId(2411), EmojiId(2411))),
+        ("🧑\u{200d}🚒", (EmojiId(1112), EmojiId(1112))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏻", (EmojiId(2299), EmojiId(2289))),
+        ("👨\u{200d}✈", (EmojiId(1082), EmojiId(1082))),
+        ("🏩", (EmojiId(2749), EmojiId(2749))),
+        ("🧑🏿\u{200d}🦯\u{200d}➡", (EmojiId(1628), EmojiId(1623))),
+        ("👨🏿\u{200d}🚒", (EmojiId(1123), EmojiId(1118))),
+        ("🧖🏾\u{200d}♀\u{fe0f}", (EmojiId(1798), EmojiId(1794))),
+        ("🍜", (EmojiId(2658), EmojiId(2658))),
+        ("🦌", (EmojiId(2453), EmojiId(2453))),
+        ("👯\u{200d}♀\u{fe0f}", (EmojiId(1781), EmojiId(1781))),
+        ("🧝🏾\u{200d}♀", (EmojiId(1482), EmojiId(1478))),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏾", (EmojiId(2193), EmojiId(2185))),
+        ("🤙🏽", (EmojiId(285), EmojiId(282))),
+        ("🧙🏿\u{200d}♂\u{fe0f}", (EmojiId(1405), EmojiId(1400))),
+        ("💏🏻", (EmojiId(2186), EmojiId(2185))),
+        ("👩🏿\u{200d}🦲", (EmojiId(625), EmojiId(620))),
+        ("⛹🏽", (EmojiId(1907), EmojiId(1904))),
+        ("🤹🏾\u{200d}♂", (EmojiId(2043), EmojiId(2039))),
+        ("🧚🏿\u{200d}♂\u{fe0f}", (EmojiId(1423), EmojiId(1418))),
+        ("💄", (EmojiId(3063), EmojiId(3063))),
+        ("🧍🏽\u{200d}♂\u{fe0f}", (EmojiId(1572), EmojiId(1569))),
+        ("◽", (EmojiId(3491), EmojiId(3491))),
+        ("⏮\u{fe0f}", (EmojiId(3363), EmojiId(3363))),
+        ("☮\u{fe0f}", (EmojiId(3337), EmojiId(3337))),
+        ("🤹\u{200d}♀\u{fe0f}", (EmojiId(2045), EmojiId(2045))),
+        ("🚶🏼\u{200d}➡", (EmojiId(1547), EmojiId(1545))),
+        ("🖖🏽", (EmojiId(195), EmojiId(192))),
+        ("🙆🏾\u{200d}♀", (EmojiId(732), EmojiId(728))),
+        ("🤽\u{200d}♀", (EmojiId(2009), EmojiId(2009))),
+        ("❣\u{fe0f}", (EmojiId(138), EmojiId(138))),
+        ("👩🏻\u{200d}⚕\u{fe0f}", (EmojiId(855), EmojiId(854))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏻", (EmojiId(2311), EmojiId(2289))),
+        ("🙆🏻\u{200d}♀", (EmojiId(729), EmojiId(728))),
+        ("🧑🏻\u{200d}🍼", (EmojiId(1329), EmojiId(1328))),
+        ("☝🏻", (EmojiId(319), EmojiId(318))),
+        ("🚵🏾\u{200d}♀", (EmojiId(1974), EmojiId(1970))),
+        ("👰\u{200d}♂", (EmojiId(1280), EmojiId(1280))),
+        ("🤽🏻\u{200d}♂\u{fe0f}", (EmojiId(2004), EmojiId(2003))),
+        ("🙍🏿", (EmojiId(667), EmojiId(662))),
+        ("🙅🏾\u{200d}♂", (EmojiId(708), EmojiId(704))),
+        ("🏟\u{fe0f}", (EmojiId(2732), EmojiId(2732))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏾", (EmojiId(2305), EmojiId(2289))),
+        ("🚹", (EmojiId(3285), EmojiId(3285))),
+        ("👩🏿", (EmojiId(583), EmojiId(578))),
+        ("🤢", (EmojiId(60), EmojiId(60))),
+        ("🛑", (EmojiId(2830), EmojiId(2830))),
+        ("👩\u{200d}🚀", (EmojiId(1106), EmojiId(1106))),
+        ("🧑🏼\u{200d}🦰", (EmojiId(592), EmojiId(590))),
+        ("⭐", (EmojiId(2904), EmojiId(2904))),
+        ("🍚", (EmojiId(2656), EmojiId(2656))),
+        ("👱🏻", (EmojiId(525), EmojiId(524))),
+        ("🦟", (EmojiId(2549), EmojiId(2549))),
+        ("🚶🏻\u{200d}♀", (EmojiId(1540), EmojiId(1539))),
+        ("✉\u{fe0f}", (EmojiId(3158), EmojiId(3158))),
+        ("👊🏿", (EmojiId(353), EmojiId(348))),
+        ("🫱🏼\u{200d}🫲🏻", (EmojiId(406), EmojiId(396))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2366), EmojiId(2341))),
+        ("🌽", (EmojiId(2605), EmojiId(2605))),
+        ("🙍🏾\u{200d}♂", (EmojiId(672), EmojiId(668))),
+        ("™\u{fe0f}", (EmojiId(3417), EmojiId(3417))),
+        ("🦻🏼", (EmojiId(474), EmojiId(472))),
+        ("🚶🏾\u{200d}♀\u{200d}➡", (EmojiId(1555), EmojiId(1551))),
+        ("🚶🏽\u{200d}➡", (EmojiId(1548), EmojiId(1545))),
+        ("🧑\u{200d}🦱", (EmojiId(602), EmojiId(602))),
+        ("🧎🏻\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1612), EmojiId(1611))),
+        ("🙋🏻\u{200d}♂", (EmojiId(759), EmojiId(758))),
+        ("🇪🇨", (EmojiId(3578), EmojiId(3578))),
+        ("🚶🏽\u{200d}♀", (EmojiId(1542), EmojiId(1539))),
+        ("👨🏿\u{200d}🏫", (EmojiId(889), EmojiId(884))),
+        ("🏊🏾\u{200d}♀\u{fe0f}", (EmojiId(1902), EmojiId(1898))),
+        ("🏄🏾", (EmojiId(1854), EmojiId(1850))),
+        ("🏃🏽\u{200d}♂", (EmojiId(1734), EmojiId(1731))),
+        ("🇲🇾", (EmojiId(3675), EmojiId(3675))),
+        ("👩🏽\u{200d}🦱", (EmojiId(599), EmojiId(596))),
+        ("🤵🏾\u{200d}♀", (EmojiId(1272), EmojiId(1268))),
+        ("🧘🏻\u{200d}♂\u{fe0f}", (EmojiId(2058), EmojiId(2057))),
+        ("👊🏾", (EmojiId(352), EmojiId(348))),
+        ("🥹", (EmojiId(85), EmojiId(85))),
+        ("🧜🏿\u{200d}♀\u{fe0f}", (EmojiId(1465), EmojiId(1460))),
+        ("🤸🏽", (EmojiId(1979), EmojiId(1976))),
+        ("🚴🏿", (EmojiId(1945), EmojiId(1940))),
+        ("🧘🏼\u{200d}♂\u{fe0f}", (EmojiId(2059), EmojiId(2057))),
+        ("🧖🏾\u{200d}♂\u{fe0f}", (EmojiId(1792), EmojiId(1788))),
+        ("🏍", (EmojiId(2812), EmojiId(2812))),
+        ("🦸", (EmojiId(1358), EmojiId(1358))),
+        ("🫱🏽", (EmojiId(201), EmojiId(198))),
+        ("🏌\u{fe0f}\u{200d}♂\u{fe0f}", (EmojiId(1838), EmojiId(1838))),
+        ("8\u{fe0f}\u{20e3}", (EmojiId(3428), EmojiId(3428))),
+        ("🥅", (EmojiId(2979), EmojiId(2979))),
+        ("🫔", (EmojiId(2639), EmojiId(2639))),
+        ("🧘🏾\u{200d}♀\u{fe0f}", (EmojiId(2067), EmojiId(2063))),
+        ("💂\u{200d}♀\u{fe0f}", (EmojiId(1178), EmojiId(1178))),
+        ("🧗🏻\u{200d}♀\u{fe0f}", (EmojiId(1813), EmojiId(1812))),
+        ("🏊\u{200d}♀", (EmojiId(1898), EmojiId(1898))),
+        ("👨🏽\u{200d}⚖", (EmojiId(905), EmojiId(902))),
+        ("🇲🇰", (EmojiId(3661), EmojiId(3661))),
+        ("🦻🏻", (EmojiId(473), EmojiId(472))),
+        ("🧚🏼", (EmojiId(1414), EmojiId(1412))),
+        ("🤐", (EmojiId(36), EmojiId(36))),
+        ("🧎🏼\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1613), EmojiId(1611))),
+        ("👩🏽\u{200d}🦯", (EmojiId(1644), EmojiId(1641))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2229), EmojiId(2211))),
+        ("🎐", (EmojiId(2947), EmojiId(2947))),
+        ("👨🏾\u{200d}🚀", (EmojiId(1104), EmojiId(1100))),
+        ("👨🏿\u{200d}💻", (EmojiId(1033), EmojiId(1028))),
+        ("✌🏻", (EmojiId(253), EmojiId(252))),
+        ("🦻🏾", (EmojiId(476), EmojiId(472))),
+        ("🇨🇽", (EmojiId(3567), EmojiId(3567))),
+        ("🇬🇼", (EmojiId(3609), EmojiId(3609))),
+        ("👨🏻\u{200d}🔧", (EmojiId(957), EmojiId(956))),
+        ("👩\u{200d}💼", (EmojiId(998), EmojiId(998))),
+        ("👌🏽", (EmojiId(237), EmojiId(234))),
+        ("🏜", (EmojiId(2729), EmojiId(2729))),
+        ("🪢", (EmojiId(3018), EmojiId(3018))),
+        ("🙍🏽\u{200d}♀\u{fe0f}", (EmojiId(677), EmojiId(674))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏾", (EmojiId(2392), EmojiId(2367))),
+        ("👨🏾\u{200d}🏫", (EmojiId(888), EmojiId(884))),
+        ("🪅", (EmojiId(3001), EmojiId(3001))),
+        ("🚣🏿\u{200d}♂", (EmojiId(1879), EmojiId(1874))),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏿", (EmojiId(2116), EmojiId(2107))),
+        ("🙅🏻", (EmojiId(699), EmojiId(698))),
+        ("🏌\u{fe0f}\u{200d}♀", (EmojiId(1844), EmojiId(1844))),
+        ("💇🏿\u{200d}♂\u{fe0f}", (EmojiId(1520), EmojiId(1515))),
+        ("🤦🏾\u{200d}♂\u{fe0f}", (EmojiId(816), EmojiId(812))),
+        ("👏", (EmojiId(366), EmojiId(366))),
+        ("🚓", (EmojiId(2800), EmojiId(2800))),
+        ("🦹\u{200d}♀\u{fe0f}", (EmojiId(1388), EmojiId(1388))),
+        ("🤶", (EmojiId(1346), EmojiId(1346))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2262), EmojiId(2237))),
+        ("🧚", (EmojiId(1412), EmojiId(1412))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2287), EmojiId(2263))),
+        ("👨\u{200d}🚀", (EmojiId(1100), EmojiId(1100))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2391), EmojiId(2367))),
+        ("9\u{20e3}", (EmojiId(3429), EmojiId(3429))),
+        ("🗯\u{fe0f}", (EmojiId(165), EmojiId(165))),
+        ("✍\u{fe0f}", (EmojiId(428), EmojiId(428))),
+        ("🧏🏽\u{200d}♀", (EmojiId(785), EmojiId(782))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏾", (EmojiId(2379), EmojiId(2367))),
+        ("🇸🇸", (EmojiId(3725), EmojiId(3725))),
+        ("🏠", (EmojiId(2741), EmojiId(2741))),
+        ("🤵🏼\u{200d}♂", (EmojiId(1264), EmojiId(1262))),
+        ("👎🏽", (EmojiId(339), EmojiId(336))),
+        ("↔\u{fe0f}", (EmojiId(3316), EmojiId(3316))),
+        ("🇿🇼", (EmojiId(3769), EmojiId(3769))),
+        ("👢", (EmojiId(3053), EmojiId(3053))),
+        ("🥾", (EmojiId(3048), EmojiId(3048))),
+        ("📡", (EmojiId(3239), EmojiId(3239))),
+        ("🃏", (EmojiId(3009), EmojiId(3009))),
+        ("👩🏼\u{200d}🦯\u{200d}➡", (EmojiId(1649), EmojiId(1647))),
+        ("🧣", (EmojiId(3027), EmojiId(3027))),
+        ("🛴", (EmojiId(2818), EmojiId(2818))),
+        ("🏊🏿\u{200d}♀", (EmojiId(1903), EmojiId(1898))),
+        ("🧗🏼\u{200d}♀", (EmojiId(1814), EmojiId(1812))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏿", (EmojiId(2354), EmojiId(2341))),
+        ("🔁", (EmojiId(3355), EmojiId(3355))),
+        ("🧑🏾\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1663), EmojiId(1659))),
+        ("🪭", (EmojiId(3039), EmojiId(3039))),
+        ("➡\u{fe0f}", (EmojiId(3309), EmojiId(3309))),
+        ("💆", (EmojiId(1491), EmojiId(1491))),
+        ("👨🏻\u{200d}🚒", (EmojiId(1119), EmojiId(1118))),
+        ("🤦🏽\u{200d}♂", (EmojiId(815), EmojiId(812))),
+        ("🪃", (EmojiId(3215), EmojiId(3215))),
+        ("🚵🏾", (EmojiId(1962), EmojiId(1958))),
+        ("🤙", (EmojiId(282), EmojiId(282))),
+        ("🧚🏿", (EmojiId(1417), EmojiId(1412))),
+        ("🥌", (EmojiId(2987), EmojiId(2987))),
+        ("💵", (EmojiId(3151), EmojiId(3151))),
+        ("🥍", (EmojiId(2974), EmojiId(2974))),
+        ("🧘🏼", (EmojiId(2053), EmojiId(2051))),
+        ("🧑\u{200d}🏫", (EmojiId(878), EmojiId(878))),
+        ("🛢", (EmojiId(2824), EmojiId(2824))),
+        ("🎱", (EmojiId(2992), EmojiId(2992))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2234), EmojiId(2211))),
+        ("😂", (EmojiId(7), EmojiId(7))),
+        ("👈🏾", (EmojiId(292), EmojiId(288))),
+        ("🧑🏻\u{200d}🏫", (EmojiId(879), EmojiId(878))),
+        ("⛹\u{200d}♀\u{fe0f}", (EmojiId(1916), EmojiId(1916))),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏽", (EmojiId(2170), EmojiId(2159))),
+        ("👨🏿\u{200d}🏭", (EmojiId(979), EmojiId(974))),
+        ("🫐", (EmojiId(2596), EmojiId(2596))),
+        ("🤳🏿", (EmojiId(445), EmojiId(440))),
+        ("🌏", (EmojiId(2718), EmojiId(2718))),
+        ("👨\u{200d}👨\u{200d}👦", (EmojiId(2398), EmojiId(2398))),
+        ("⌨", (EmojiId(3107), EmojiId(3107))),
+        ("🦹🏻\u{200d}♂\u{fe0f}", (EmojiId(1383), EmojiId(1382))),
+        ("🇦🇴", (EmojiId(3520), EmojiId(3520))),
+        ("👨🏿\u{200d}🦲", (EmojiId(577), EmojiId(572))),
+        ("🤽🏼\u{200d}♀\u{fe0f}", (EmojiId(2011), EmojiId(2009))),
+        ("🤝🏽", (EmojiId(399), EmojiId(396))),
+        ("🧚🏿\u{200d}♂", (EmojiId(1423), EmojiId(1418))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏽", (EmojiId(2387), EmojiId(2367))),
+        ("🙅🏾\u{200d}♀", (EmojiId(714), EmojiId(710))),
+        ("🪖", (EmojiId(3060), EmojiId(3060))),
+        ("🙌🏽", (EmojiId(375), EmojiId(372))),
+        ("🕵🏼\u{200d}♀", (EmojiId(1162), EmojiId(1160))),
+        ("🧘\u{200d}♂\u{fe0f}", (EmojiId(2057), EmojiId(2057))),
+        ("🏃🏾\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1759), EmojiId(1755))),
+        ("🪓", (EmojiId(3208), EmojiId(3208))),
+        ("🚎", (EmojiId(2796), EmojiId(2796))),
+        ("🚣🏿", (EmojiId(1873), EmojiId(1868))),
+        ("🏌🏾\u{200d}♀", (EmojiId(1848), EmojiId(1844))),
+        ("👩\u{200d}🎤", (EmojiId(1052), EmojiId(1052))),
+        ("👷\u{200d}♀", (EmojiId(1202), EmojiId(1202))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏼", (EmojiId(2347), EmojiId(2341))),
+        ("👩\u{200d}👦\u{200d}👦", (EmojiId(2414), EmojiId(2414))),
+        ("🖕🏼", (EmojiId(308), EmojiId(306))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", (EmojiId(2195), EmojiId(2185))),
+        ("🫄🏽", (EmojiId(1307), EmojiId(1304))),
+        ("🐚", (EmojiId(2534), EmojiId(2534))),
+        ("🤷🏻\u{200d}♀\u{fe0f}", (EmojiId(837), EmojiId(836))),
+        ("👨🏿\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1712), EmojiId(1707))),
+        ("🙍🏽\u{200d}♂", (EmojiId(671), EmojiId(668))),
+        ("🗺", (EmojiId(2720), EmojiId(2720))),
+        ("🧑🏾\u{200d}🎓", (EmojiId(864), EmojiId(860))),
+        ("🖊\u{fe0f}", (EmojiId(3174), EmojiId(3174))),
+        ("🕹\u{fe0f}", (EmojiId(2996), EmojiId(2996))),
+        ("⚕", (EmojiId(3397), EmojiId(3397))),
+        ("😨", (EmojiId(88), EmojiId(88))),
+        ("🤾\u{200d}♂\u{fe0f}", (EmojiId(2021), EmojiId(2021))),
+        ("⛸\u{fe0f}", (EmojiId(2981), EmojiId(2981))),
+        ("⚧\u{fe0f}", (EmojiId(3381), EmojiId(3381))),
+        ("👩🏾\u{200d}🏫", (EmojiId(894), EmojiId(890))),
+        ("👮🏾\u{200d}♀", (EmojiId(1146), EmojiId(1142))),
+        ("🙂\u{200d}↔", (EmojiId(50), EmojiId(50))),
+        ("🧑🏾\u{200d}🏫", (EmojiId(882), EmojiId(878))),
+        ("🔇", (EmojiId(3066), EmojiId(3066))),
+        ("🫱🏼\u{200d}🫲🏿", (EmojiId(409), EmojiId(396))),
+        ("🅿", (EmojiId(3449), EmojiId(3449))),
+        ("🧑🏿\u{200d}🦼", (EmojiId(1658), EmojiId(1653))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2344), EmojiId(2341))),
+        ("💍", (EmojiId(3064), EmojiId(3064))),
+        ("🍔", (EmojiId(2632), EmojiId(2632))),
+        ("🇨🇭", (EmojiId(3555), EmojiId(3555))),
+        ("🎛\u{fe0f}", (EmojiId(3080), EmojiId(3080))),
+        ("👩\u{200d}🦯\u{200d}➡", (EmojiId(1647), EmojiId(1647))),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏻", (EmojiId(2177), EmojiId(2159))),
+        ("🙏🏻", (EmojiId(423), EmojiId(422))),
+        ("🍩", (EmojiId(2678), EmojiId(2678))),
+        ("🦛", (EmojiId(2473), EmojiId(2473))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2276), EmojiId(2263))),
+        ("📤", (EmojiId(3162), EmojiId(3162))),
+        ("🥱", (EmojiId(100), EmojiId(100))),
+        ("🚉", (EmojiId(2789), EmojiId(2789))),
+        ("💇🏽\u{200d}♂", (EmojiId(1518), EmojiId(1515))),
+        ("🕴", (EmojiId(1773), EmojiId(1773))),
+        ("🇨🇱", (EmojiId(3558), EmojiId(3558))),
+        ("🙎🏿\u{200d}♂\u{fe0f}", (EmojiId(691), EmojiId(686))),
+        ("🍋\u{200d}🟩", (EmojiId(2586), EmojiId(2586))),
+        ("👰🏻\u{200d}♂", (EmojiId(1281), EmojiId(1280))),
+        ("🇧🇹", (EmojiId(3545), EmojiId(3545))),
+        ("🏃🏾\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1753), EmojiId(1749))),
+        ("🌶", (EmojiId(2606), EmojiId(2606))),
+        ("🕷\u{fe0f}", (EmojiId(2546), EmojiId(2546))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2259), EmojiId(2237))),
+        ("🔽", (EmojiId(3366), EmojiId(3366))),
+        ("🧍🏿\u{200d}♀", (EmojiId(1580), EmojiId(1575))),
+        ("🏃🏼\u{200d}♂\u{fe0f}", (EmojiId(1733), EmojiId(1731))),
+        ("💂🏼\u{200d}♀\u{fe0f}", (EmojiId(1180), EmojiId(1178))),
+        ("🫸🏼", (EmojiId(230), EmojiId(228))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2240), EmojiId(2237))),
+        ("🪐", (EmojiId(2903), EmojiId(2903))),
+        ("🏃🏽\u{200d}♀\u{200d}➡", (EmojiId(1752), EmojiId(1749))),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏼", (EmojiId(2182), EmojiId(2159))),
+        ("🗞\u{fe0f}", (EmojiId(3144), EmojiId(3144))),
+        ("☎\u{fe0f}", (EmojiId(3097), EmojiId(3097))),
+        ("🚶🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1562), EmojiId(1557))),
+        ("🔤", (EmojiId(3435), EmojiId(3435))),
+        ("✂\u{fe0f}", (EmojiId(3197), EmojiId(3197))),
+        ("🙎🏽", (EmojiId(683), EmojiId(680))),
+        ("🤾🏾", (EmojiId(2019), EmojiId(2015))),
+        ("🏬", (EmojiId(2752), EmojiId(2752))),
+        ("🌸", (EmojiId(2554), EmojiId(2554))),
+        ("🙆🏽", (EmojiId(719), EmojiId(716))),
+        ("💥", (EmojiId(157), EmojiId(157))),
+        ("👩🏾\u{200d}⚕\u{fe0f}", (EmojiId(858), EmojiId(854))),
+        ("🧎🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1610), EmojiId(1605))),
+        ("🥪", (EmojiId(2636), EmojiId(2636))),
+        ("🇸🇩", (EmojiId(3713), EmojiId(3713))),
+        ("🇺🇦", (EmojiId(3748), EmojiId(3748))),
+        ("🤰🏼", (EmojiId(1294), EmojiId(1292))),
+        ("👷\u{200d}♂", (EmojiId(1196), EmojiId(1196))),
+        ("❄\u{fe0f}", (EmojiId(2927), EmojiId(2927))),
+        ("🌦\u{fe0f}", (EmojiId(2913), EmojiId(2913))),
+        ("🈂\u{fe0f}", (EmojiId(3454), EmojiId(3454))),
+        ("🧘🏿\u{200d}♀\u{fe0f}", (EmojiId(2068), EmojiId(2063))),
+        ("🫅🏿", (EmojiId(1213), EmojiId(1208))),
+        ("✏", (EmojiId(3171), EmojiId(3171))),
+        ("✈\u{fe0f}", (EmojiId(2841), EmojiId(2841))),
+        ("🏔", (EmojiId(2723), EmojiId(2723))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2103), EmojiId(2081))),
+        ("🚧", (EmojiId(2831), EmojiId(2831))),
+        ("🏃\u{200d}♀\u{fe0f}", (EmojiId(1737), EmojiId(1737))),
+        ("❄", (EmojiId(2927), EmojiId(2927))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2370), EmojiId(2367))),
+        ("😐", (EmojiId(38), EmojiId(38))),
+        ("👨\u{200d}🦯", (EmojiId(1629), EmojiId(1629))),
+        ("🚶🏿\u{200d}➡\u{fe0f}", (EmojiId(1550), EmojiId(1545))),
+        ("🚶🏿\u{200d}➡", (EmojiId(1550), EmojiId(1545))),
+        ("🧝🏼", (EmojiId(1468), EmojiId(1466))),
+        ("✋🏾", (EmojiId(190), EmojiId(186))),
+        ("🪁", (EmojiId(2990), EmojiId(2990))),
+        ("🚶🏻\u{200d}♂\u{fe0f}", (EmojiId(1534), EmojiId(1533))),
+        ("🧑🏿\u{200d}🏫", (EmojiId(883), EmojiId(878))),
+        ("👆🏼", (EmojiId(302), EmojiId(300))),
+        ("🏃🏻\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1750), EmojiId(1749))),
+        ("👩🏿\u{200d}🍳", (EmojiId(949), EmojiId(944))),
+        ("🤸\u{200d}♂", (EmojiId(1982), EmojiId(1982))),
+        ("🤞🏼", (EmojiId(260), EmojiId(258))),
+        ("🫨", (EmojiId(49), EmojiId(49))),
+        ("🩳", (EmojiId(3036), EmojiId(3036))),
+        ("👩🏼\u{200d}🚒", (EmojiId(1126), EmojiId(1124))),
+        ("🤾🏻\u{200d}♀\u{fe0f}", (EmojiId(2028), EmojiId(2027))),
+        ("🇦🇿", (EmojiId(3528), EmojiId(3528))),
+        ("🏋🏾\u{200d}♂\u{fe0f}", (EmojiId(1932), EmojiId(1928))),
+        ("👩🏼\u{200d}🦼\u{200d}➡", (EmojiId(1685), EmojiId(1683))),
+        ("🐒", (EmojiId(2429), EmojiId(2429))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏽", (EmojiId(2335), EmojiId(2315))),
+        ("🦸🏾\u{200d}♂\u{fe0f}", (EmojiId(1368), EmojiId(1364))),
+        ("🧵", (EmojiId(3015), EmojiId(3015))),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏿", (EmojiId(2142), EmojiId(2133))),
+        ("🈚", (EmojiId(3460), EmojiId(3460))),
+        ("🐽", (EmojiId(2462), EmojiId(2462))),
+        ("🕵\u{200d}♀\u{fe0f}", (EmojiId(1160), EmojiId(1160))),
+        ("✏\u{fe0f}", (EmojiId(3171), EmojiId(3171))),
+        ("🏄🏾\u{200d}♀\u{fe0f}", (EmojiId(1866), EmojiId(1862))),
+        ("🌦", (EmojiId(2913), EmojiId(2913))),
+        ("🚶🏻\u{200d}➡", (EmojiId(1546), EmojiId(1545))),
+        ("🤵🏿", (EmojiId(1261), EmojiId(1256))),
+        ("🙎🏽\u{200d}♂\u{fe0f}", (EmojiId(689), EmojiId(686))),
+        ("🦹🏽\u{200d}♂", (EmojiId(1385), EmojiId(1382))),
+        ("🌷", (EmojiId(2563), EmojiId(2563))),
+        ("🤪", (EmojiId(26), EmojiId(26))),
+        ("💁🏿\u{200d}♀", (EmojiId(751), EmojiId(746))),
+        ("🧍🏾\u{200d}♀\u{fe0f}", (EmojiId(1579), EmojiId(1575))),
+        ("🎁", (EmojiId(2951), EmojiId(2951))),
+        ("🤑", (EmojiId(28), EmojiId(28))),
+        ("🚝", (EmojiId(2791), EmojiId(2791))),
+        ("🧘\u{200d}♀", (EmojiId(2063), EmojiId(2063))),
+        ("🧎🏽\u{200d}➡", (EmojiId(1602), EmojiId(1599))),
+        ("👨🏻\u{200d}🦱", (EmojiId(561), EmojiId(560))),
+        ("🎨", (EmojiId(3014), EmojiId(3014))),
+        ("🇧🇮", (EmojiId(3536), EmojiId(3536))),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏼", (EmojiId(2304), EmojiId(2289))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2231), EmojiId(2211))),
+        ("🧎🏼", (EmojiId(1583), EmojiId(1581))),
+        ("👶🏼", (EmojiId(496), EmojiId(494))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2217), EmojiId(2211))),
+        ("🇪🇭", (EmojiId(3581), EmojiId(3581))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2266), EmojiId(2263))),
+        ("🧑🏼\u{200d}💼", (EmojiId(988), EmojiId(986))),
+        ("👩🏻\u{200d}🦱", (EmojiId(597), EmojiId(596))),
+        ("🚶🏾\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1555), EmojiId(1551))),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏽", (EmojiId(2153), EmojiId(2133))),
+        ("📩", (EmojiId(3161), EmojiId(3161))),
+        ("🚵\u{200d}♂\u{fe0f}", (EmojiId(1964), EmojiId(1964))),
+        ("🆗", (EmojiId(3448), EmojiId(3448))),
+        ("🏝\u{fe0f}", (EmojiId(2730), EmojiId(2730))),
+        ("🧑🏿\u{200d}🦲", (EmojiId(631), EmojiId(626))),
+        ("🫱🏾\u{200d}🫲🏽", (EmojiId(416), EmojiId(396))),
+        ("🪿", (EmojiId(2514), EmojiId(2514))),
+        ("😄", (EmojiId(2), EmojiId(2))),
+        ("🤷🏾\u{200d}♂\u{fe0f}", (EmojiId(834), EmojiId(830))),
+        ("🚃", (EmojiId(2783), EmojiId(2783))),
+        ("🎦", (EmojiId(3372), EmojiId(3372))),
+        ("🤹🏿\u{200d}♀\u{fe0f}", (EmojiId(2050), EmojiId(2045))),
+        ("🎠", (EmojiId(2776), EmojiId(2776))),
+        ("🛣\u{fe0f}", (EmojiId(2822), EmojiId(2822))),
+        ("🤭", (EmojiId(30), EmojiId(30))),
+        ("🙍🏻", (EmojiId(663), EmojiId(662))),
+        ("➕", (EmojiId(3383), EmojiId(3383))),
+        ("🧑🏿\u{200d}🎄", (EmojiId(1357), EmojiId(1352))),
+        ("🕗", (EmojiId(2879), EmojiId(2879))),
+        ("👨🏾\u{200d}🦳", (EmojiId(570), EmojiId(566))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2090), EmojiId(2081))),
+        ("🧔🏼", (EmojiId(538), EmojiId(536))),
+        ("👩\u{200d}👩\u{200d}👧", (EmojiId(2404), EmojiId(2404))),
+        ("🦜", (EmojiId(2511), EmojiId(2511))),
+        ("🆎", (EmojiId(3437), EmojiId(3437))),
+        ("☝\u{fe0f}", (EmojiId(318), EmojiId(318))),
+        ("💤", (EmojiId(167), EmojiId(167))),
+        ("🏉", (EmojiId(2967), EmojiId(2967))),
+        ("🦧", (EmojiId(2431), EmojiId(2431))),
+        ("🧘🏿\u{200d}♀", (EmojiId(2068), EmojiId(2063))),
+        ("👩🏼\u{200d}🦳", (EmojiId(610), EmojiId(608))),
+        ("🏊🏼\u{200d}♂\u{fe0f}", (EmojiId(1894), EmojiId(1892))),
+        ("🇲🇨", (EmojiId(3655), EmojiId(3655))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏾", (EmojiId(2323), EmojiId(2315))),
+        ("🀄", (EmojiId(3010), EmojiId(3010))),
+        ("🖨", (EmojiId(3106), EmojiId(3106))),
+        ("🛸", (EmojiId(2853), EmojiId(2853))),
+        ("📠", (EmojiId(3100), EmojiId(3100))),
+        ("🏌\u{200d}♀\u{fe0f}", (EmojiId(1844), EmojiId(1844))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏻", (EmojiId(2307), EmojiId(2289))),
+        ("🏌🏾\u{200d}♂", (EmojiId(1842), EmojiId(1838))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2231), EmojiId(2211))),
+        ("🏃🏽", (EmojiId(1728), EmojiId(1725))),
+        ("🧇", (EmojiId(2626), EmojiId(2626))),
+        ("👮🏼\u{200d}♂\u{fe0f}", (EmojiId(1138), EmojiId(1136))),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏾", (EmojiId(2123), EmojiId(2107))),
+        ("🏊🏽", (EmojiId(1889), EmojiId(1886))),
+        ("👩🏿\u{200d}🚀", (EmojiId(1111), EmojiId(1106))),
+        ("😦", (EmojiId(86), EmojiId(86))),
+        ("🦵🏾", (EmojiId(458), EmojiId(454))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2318), EmojiId(2315))),
+        ("🧑🏻\u{200d}🦽", (EmojiId(1690), EmojiId(1689))),
+        ("🧘🏿", (EmojiId(2056), EmojiId(2051))),
+        ("🧑🏼\u{200d}🦼\u{200d}➡", (EmojiId(1661), EmojiId(1659))),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏻", (EmojiId(2199), EmojiId(2185))),
+        ("👰🏻", (EmojiId(1275), EmojiId(1274))),
+        ("😋", (EmojiId(23), EmojiId(23))),
+        ("🧎🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1609), EmojiId(1605))),
+        ("👨\u{200d}🌾", (EmojiId(920), EmojiId(920))),
+        ("👨\u{200d}🚒", (EmojiId(1118), EmojiId(1118))),
+        ("🤽🏼\u{200d}♀", (EmojiId(2011), EmojiId(2009))),
+        ("🧑🏾\u{200d}🦯", (EmojiId(1621), EmojiId(1617))),
+        ("🏋🏻\u{200d}♀\u{fe0f}", (EmojiId(1935), EmojiId(1934))),
+        ("🛠\u{fe0f}", (EmojiId(3211), EmojiId(3211))),
+        ("🧍\u{200d}♀\u{fe0f}", (EmojiId(1575), EmojiId(1575))),
+        ("🙆🏽\u{200d}♂\u{fe0f}", (EmojiId(725), EmojiId(722))),
+        ("👹", (EmojiId(111), EmojiId(111))),
+        ("🙇🏼", (EmojiId(790), EmojiId(788))),
+        ("⚛\u{fe0f}", (EmojiId(3329), EmojiId(3329))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2262), EmojiId(2237))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2252), EmojiId(2237))),
+        ("🧜🏻\u{200d}♂", (EmojiId(1455), EmojiId(1454))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2374), EmojiId(2367))),
+        ("🇭🇺", (EmojiId(3616), EmojiId(3616))),
+        ("🧑🏿\u{200d}🦽\u{200d}➡", (EmojiId(1700), EmojiId(1695))),
+        ("🚵🏾\u{200d}♀\u{fe0f}", (EmojiId(1974), EmojiId(1970))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", (EmojiId(2206), EmojiId(2185))),
+        ("🕝", (EmojiId(2868), EmojiId(2868))),
+        ("😹", (EmojiId(119), EmojiId(119))),
+        ("♒", (EmojiId(3351), EmojiId(3351))),
+        ("👨🏼\u{200d}⚕\u{fe0f}", (EmojiId(850), EmojiId(848))),
+        ("🌒", (EmojiId(2888), EmojiId(2888))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", (EmojiId(2201), EmojiId(2185))),
+        ("🚵🏼", (EmojiId(1960), EmojiId(1958))),
+        ("🏘\u{fe0f}", (EmojiId(2739), EmojiId(2739))),
+        ("😇", (EmojiId(13), EmojiId(13))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2288), EmojiId(2263))),
+        ("👈🏻", (EmojiId(289), EmojiId(288))),
+        ("®", (EmojiId(3416), EmojiId(3416))),
+        ("🛅", (EmojiId(3293), EmojiId(3293))),
+        ("🎍", (EmojiId(2944), EmojiId(2944))),
+        ("👱🏼\u{200d}♀\u{fe0f}", (EmojiId(634), EmojiId(632))),
+        ("🚶🏾\u{200d}♂\u{200d}➡", (EmojiId(1561), EmojiId(1557))),
+        ("📃", (EmojiId(3140), EmojiId(3140))),
+        ("💁🏻\u{200d}♂", (EmojiId(741), EmojiId(740))),
+        ("💼", (EmojiId(3178), EmojiId(3178))),
+        ("🥷🏻", (EmojiId(1185), EmojiId(1184))),
+        ("🐢", (EmojiId(2518), EmojiId(2518))),
+        ("🦻🏽", (EmojiId(475), EmojiId(472))),
+        ("🛣", (EmojiId(2822), EmojiId(2822))),
+        ("👩🏼\u{200d}🔬", (EmojiId(1018), EmojiId(1016))),
+        ("🏙", (EmojiId(2769), EmojiId(2769))),
+        ("🇲🇭", (EmojiId(3660), EmojiId(3660))),
+        ("👩🏽\u{200d}🚒", (EmojiId(1127), EmojiId(1124))),
+        ("💑🏻", (EmojiId(2290), EmojiId(2289))),
+        ("👩\u{200d}⚖\u{fe0f}", (EmojiId(908), EmojiId(908))),
+        ("🎉", (EmojiId(2941), EmojiId(2941))),
+        ("👮🏿\u{200d}♀", (EmojiId(1147), EmojiId(1142))),
+        ("🗑", (EmojiId(3200), EmojiId(3200))),
+        ("🖥\u{fe0f}", (EmojiId(3105), EmojiId(3105))),
+        ("🫲🏽", (EmojiId(207), EmojiId(204))),
+        ("💏🏽", (EmojiId(2188), EmojiId(2185))),
+        ("🤫", (EmojiId(33), EmojiId(33))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2091), EmojiId(2081))),
+        ("🚣🏻\u{200d}♀", (EmojiId(1881), EmojiId(1880))),
+        ("🌨", (EmojiId(2915), EmojiId(2915))),
+        ("👨🏿\u{200d}🎤", (EmojiId(1051), EmojiId(1046))),
+        ("🇳🇷", (EmojiId(3686), EmojiId(3686))),
+        ("🙎\u{200d}♂\u{fe0f}", (EmojiId(686), EmojiId(686))),
+        ("🙅🏻\u{200d}♂\u{fe0f}", (EmojiId(705), EmojiId(704))),
+        ("👊", (EmojiId(348), EmojiId(348))),
+        ("🫱🏻", (EmojiId(199), EmojiId(198))),
+        ("🧗\u{200d}♂\u{fe0f}", (EmojiId(1806), EmojiId(1806))),
+        ("😽", (EmojiId(122), EmojiId(122))),
+        ("👷🏾\u{200d}♂\u{fe0f}", (EmojiId(1200), EmojiId(1196))),
+        ("🧑🏿\u{200d}💼", (EmojiId(991), EmojiId(986))),
+        ("👦🏿", (EmojiId(511), EmojiId(506))),
+        ("🫕", (EmojiId(2646), EmojiId(2646))),
+        ("🍧", (EmojiId(2676), EmojiId(2676))),
+        ("2\u{20e3}", (EmojiId(3422), EmojiId(3422))),
+        ("🏴", (EmojiId(3507), EmojiId(3507))),
+        ("👨🏼\u{200d}💻", (EmojiId(1030), EmojiId(1028))),
+        ("🧎🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1613), EmojiId(1611))),
+        ("🙍", (EmojiId(662), EmojiId(662))),
+        ("👨🏿", (EmojiId(535), EmojiId(530))),
+        ("🕴🏾", (EmojiId(1777), EmojiId(1773))),
+        ("🌰", (EmojiId(2615), EmojiId(2615))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏾", (EmojiId(2361), EmojiId(2341))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2223), EmojiId(2211))),
+        ("👩🏻\u{200d}🦼\u{200d}➡", (EmojiId(1684), EmojiId(1683))),
+        ("👳🏻", (EmojiId(1227), EmojiId(1226))),
+        ("👃🏼", (EmojiId(480), EmojiId(478))),
+        ("👮\u{200d}♀\u{fe0f}", (EmojiId(1142), EmojiId(1142))),
+        ("🍭", (EmojiId(2686), EmojiId(2686))),
+        ("💁🏽\u{200d}♀\u{fe0f}", (EmojiId(749), EmojiId(746))),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏻", (EmojiId(2207), EmojiId(2185))),
+        ("💟", (EmojiId(137), EmojiId(137))),
+        ("👩🏼", (EmojiId(580), EmojiId(578))),
+        ("🧗🏾\u{200d}♀\u{fe0f}", (EmojiId(1816), EmojiId(1812))),
+        ("🤸🏽\u{200d}♂", (EmojiId(1985), EmojiId(1982))),
+        ("💆🏼", (EmojiId(1493), EmojiId(1491))),
+        ("🧜\u{200d}♀", (EmojiId(1460), EmojiId(1460))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2249), EmojiId(2237))),
+        ("🧑🏽\u{200d}⚖\u{fe0f}", (EmojiId(899), EmojiId(896))),
+        ("👩🏿\u{200d}🎨", (EmojiId(1075), EmojiId(1070))),
+        ("🙇🏼\u{200d}♀\u{fe0f}", (EmojiId(802), EmojiId(800))),
+        ("🩸", (EmojiId(3241), EmojiId(3241))),
+        ("🇱🇾", (EmojiId(3653), EmojiId(3653))),
+        ("㊙\u{fe0f}", (EmojiId(3467), EmojiId(3467))),
+        ("🏘", (EmojiId(2739), EmojiId(2739))),
+        ("🧑🏿\u{200d}🦼\u{200d}➡", (EmojiId(1664), EmojiId(1659))),
+        ("🐧", (EmojiId(2501), EmojiId(2501))),
+        ("🏌\u{fe0f}\u{200d}♂", (EmojiId(1838), EmojiId(1838))),
+        ("🦘", (EmojiId(2491), EmojiId(2491))),
+        ("🫲🏻", (EmojiId(205), EmojiId(204))),
+        ("🧓🏽", (EmojiId(647), EmojiId(644))),
+        ("👨🏾\u{200d}⚕", (EmojiId(852), EmojiId(848))),
+        ("🎶", (EmojiId(3077), EmojiId(3077))),
+        ("🚶🏼", (EmojiId(1529), EmojiId(1527))),
+        ("🌀", (EmojiId(2920), EmojiId(2920))),
+        ("🏃🏿\u{200d}♂\u{fe0f}", (EmojiId(1736), EmojiId(1731))),
+        ("🪒", (EmojiId(3259), EmojiId(3259))),
+        ("🚙", (EmojiId(2806), EmojiId(2806))),
+        ("🕺🏽", (EmojiId(1770), EmojiId(1767))),
+        ("❌", (EmojiId(3407), EmojiId(3407))),
+        ("👩🏽\u{200d}🎨", (EmojiId(1073), EmojiId(1070))),
+        ("©", (EmojiId(3415), EmojiId(3415))),
+        ("🤟🏼", (EmojiId(272), EmojiId(270))),
+        ("🙇🏽\u{200d}♂", (EmojiId(797), EmojiId(794))),
+        ("🤾🏼", (EmojiId(2017), EmojiId(2015))),
+        ("⌚", (EmojiId(2858), EmojiId(2858))),
+        ("🚶🏾\u{200d}➡\u{fe0f}", (EmojiId(1549), EmojiId(1545))),
+        ("🪬", (EmojiId(3277), EmojiId(3277))),
+        ("👨\u{200d}👨\u{200d}👧\u{200d}👦", (EmojiId(2400), EmojiId(2400))),
+        ("🏌", (EmojiId(1832), EmojiId(1832))),
+        ("🇲🇿", (EmojiId(3676), EmojiId(3676))),
+        ("👩\u{200d}✈", (EmojiId(1088), EmojiId(1088))),
+        ("🙇🏾\u{200d}♂", (EmojiId(798), EmojiId(794))),
+        ("🤼\u{200d}♀\u{fe0f}", (EmojiId(1996), EmojiId(1996))),
+        ("🛌", (EmojiId(2075), EmojiId(2075))),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏾", (EmojiId(2301), EmojiId(2289))),
+        ("🧙🏿", (EmojiId(1399), EmojiId(1394))),
+        ("🧿", (EmojiId(3276), EmojiId(3276))),
+        ("🙇🏻", (EmojiId(789), EmojiId(788))),
+        ("👨\u{200d}⚖\u{fe0f}", (EmojiId(902), EmojiId(902))),
+        ("🕺", (EmojiId(1767), EmojiId(1767))),
+        ("🙎🏻\u{200d}♀", (EmojiId(693), EmojiId(692))),
+        ("🇲🇵", (EmojiId(3666), EmojiId(3666))),
+        ("🚶🏽\u{200d}♂\u{200d}➡", (EmojiId(1560), EmojiId(1557))),
+        ("💂🏾\u{200d}♂\u{fe0f}", (EmojiId(1176), EmojiId(1172))),
+        ("🚣", (EmojiId(1868), EmojiId(1868))),
+        ("🧑🏾\u{200d}💼", (EmojiId(990), EmojiId(986))),
+        ("🧏🏿", (EmojiId(775), EmojiId(770))),
+        ("🏄", (EmojiId(1850), EmojiId(1850))),
+        ("🧏", (EmojiId(770), EmojiId(770))),
+        ("📔", (EmojiId(3131), EmojiId(3131))),
+        ("👨\u{200d}👩\u{200d}👧\u{200d}👧", (EmojiId(2397), EmojiId(2397))),
+        ("🇧🇪", (EmojiId(3532), EmojiId(3532))),
+        ("👈🏿", (EmojiId(293), EmojiId(288))),
+        ("🇧🇾", (EmojiId(3548), EmojiId(3548))),
+        ("➗", (EmojiId(3385), EmojiId(3385))),
+        ("👍🏾", (EmojiId(334), EmojiId(330))),
+        ("⛴\u{fe0f}", (EmojiId(2838), EmojiId(2838))),
+        ("🦹🏽", (EmojiId(1379), EmojiId(1376))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2255), EmojiId(2237))),
+        ("🖐", (EmojiId(180), EmojiId(180))),
+        ("🇵🇳", (EmojiId(3698), EmojiId(3698))),
+        ("🪸", (EmojiId(2535), EmojiId(2535))),
+        ("🙆🏽\u{200d}♂", (EmojiId(725), EmojiId(722))),
+        ("👨\u{200d}👩\u{200d}👦", (EmojiId(2393), EmojiId(2393))),
+        ("👨🏼\u{200d}✈", (EmojiId(1084), EmojiId(1082))),
+        ("⛹🏻\u{200d}♀\u{fe0f}", (EmojiId(1917), EmojiId(1916))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏽", (EmojiId(2330), EmojiId(2315))),
+        ("🦸🏾\u{200d}♂", (EmojiId(1368), EmojiId(1364))),
+        ("🚵🏽\u{200d}♀\u{fe0f}", (EmojiId(1973), EmojiId(1970))),
+        ("🎥", (EmojiId(3115), EmojiId(3115))),
+        ("🦸🏻\u{200d}♀", (EmojiId(1371), EmojiId(1370))),
+        ("🫅🏾", (EmojiId(1212), EmojiId(1208))),
+        ("🚶\u{200d}♀", (EmojiId(1539), EmojiId(1539))),
+        ("🇰🇼", (EmojiId(3640), EmojiId(3640))),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏾", (EmojiId(2184), EmojiId(2159))),
+        ("👨🏼\u{200d}🚀", (EmojiId(1102), EmojiId(1100))),
+        ("🙎🏾", (EmojiId(684), EmojiId(680))),
+        ("🤷🏽\u{200d}♀", (EmojiId(839), EmojiId(836))),
+        ("🧜🏽", (EmojiId(1451), EmojiId(1448))),
+        ("📀", (EmojiId(3113), EmojiId(3113))),
+        ("🇧🇧", (EmojiId(3530), EmojiId(3530))),
+        ("👨🏼\u{200d}🏫", (EmojiId(886), EmojiId(884))),
+        ("🧎🏼\u{200d}♂\u{fe0f}", (EmojiId(1589), EmojiId(1587))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2331), EmojiId(2315))),
+        ("🚵🏿\u{200d}♂\u{fe0f}", (EmojiId(1969), EmojiId(1964))),
+        ("🧘", (EmojiId(2051), EmojiId(2051))),
+        ("🧝\u{200d}♀", (EmojiId(1478), EmojiId(1478))),
+        ("↗\u{fe0f}", (EmojiId(3308), EmojiId(3308))),
+        ("🇹🇿", (EmojiId(3747), EmojiId(3747))),
+        ("🙋", (EmojiId(752), EmojiId(752))),
+        ("👬🏾", (EmojiId(2163), EmojiId(2159))),
+        ("🙌🏻", (EmojiId(373), EmojiId(372))),
+        ("👩\u{200d}⚕\u{fe0f}", (EmojiId(854), EmojiId(854))),
+        ("🇲🇽", (EmojiId(3674), EmojiId(3674))),
+        ("🏃\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1755), EmojiId(1755))),
+        ("☠", (EmojiId(108), EmojiId(108))),
+        ("🏄🏿\u{200d}♀\u{fe0f}", (EmojiId(1867), EmojiId(1862))),
+        ("🚶🏽\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1560), EmojiId(1557))),
+        ("👩🏻\u{200d}🎓", (EmojiId(873), EmojiId(872))),
+        ("👩🏿\u{200d}✈", (EmojiId(1093), EmojiId(1088))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏼", (EmojiId(2356), EmojiId(2341))),
+        ("🇼🇸", (EmojiId(3763), EmojiId(3763))),
+        ("📷", (EmojiId(3120), EmojiId(3120))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2247), EmojiId(2237))),
+        ("🇶🇦", (EmojiId(3704), EmojiId(3704))),
+        ("🕌", (EmojiId(2760), EmojiId(2760))),
+        ("🍠", (EmojiId(2660), EmojiId(2660))),
+        ("👩🏿\u{200d}⚖", (EmojiId(913), EmojiId(908))),
+        ("🤽🏻", (EmojiId(1998), EmojiId(1997))),
+        ("🏳", (EmojiId(3508), EmojiId(3508))),
+        ("🍰", (EmojiId(2681), EmojiId(2681))),
+        ("🚥", (EmojiId(2828), EmojiId(2828))),
+        ("🌆", (EmojiId(2772), EmojiId(2772))),
+        ("👮🏽\u{200d}♂", (EmojiId(1139), EmojiId(1136))),
+        ("🦹🏻\u{200d}♂", (EmojiId(1383), EmojiId(1382))),
+        ("🧍\u{200d}♂\u{fe0f}", (EmojiId(1569), EmojiId(1569))),
+        ("🇲🇬", (EmojiId(3659), EmojiId(3659))),
+        ("🌾", (EmojiId(2571), EmojiId(2571))),
+        ("👩🏿\u{200d}🦳", (EmojiId(613), EmojiId(608))),
+        ("🪝", (EmojiId(3229), EmojiId(3229))),
+        ("🫏", (EmojiId(2449), EmojiId(2449))),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏽", (EmojiId(2144), EmojiId(2133))),
+        ("🇺🇸", (EmojiId(3752), EmojiId(3752))),
+        ("🧄", (EmojiId(2611), EmojiId(2611))),
+        ("📋", (EmojiId(3190), EmojiId(3190))),
+        ("🈷\u{fe0f}", (EmojiId(3455), EmojiId(3455))),
+        ("🖲", (EmojiId(3109), EmojiId(3109))),
+        ("💇🏿\u{200d}♂", (EmojiId(1520), EmojiId(1515))),
+        ("🧔\u{200d}♂\u{fe0f}", (EmojiId(542), EmojiId(542))),
+        ("🥧", (EmojiId(2683), EmojiId(2683))),
+        ("🧑🏾\u{200d}🎄", (EmojiId(1356), EmojiId(1352))),
+        ("🤰🏽", (EmojiId(1295), EmojiId(1292))),
+        ("😰", (EmojiId(89), EmojiId(89))),
+        ("👩🏿\u{200d}🔧", (EmojiId(967), EmojiId(962))),
+        ("🤽🏽\u{200d}♀", (EmojiId(2012), EmojiId(2009))),
+        ("🧑🏿\u{200d}🦯", (EmojiId(1622), EmojiId(1617))),
+        ("🇸🇴", (EmojiId(3723), EmojiId(3723))),
+        ("👩🏽\u{200d}🦼\u{200d}➡", (EmojiId(1686), EmojiId(1683))),
+        ("🔱", (EmojiId(3400), EmojiId(3400))),
+        ("🧘🏾", (EmojiId(2055), EmojiId(2051))),
+        ("🐸", (EmojiId(2516), EmojiId(2516))),
+        ("⏹", (EmojiId(3369), EmojiId(3369))),
+        ("🍂", (EmojiId(2576), EmojiId(2576))),
+        ("🎖", (EmojiId(2955), EmojiId(2955))),
+        ("💆🏻\u{200d}♂", (EmojiId(1498), EmojiId(1497))),
+        ("👿", (EmojiId(106), EmojiId(106))),
+        ("🧔🏽\u{200d}♀\u{fe0f}", (EmojiId(551), EmojiId(548))),
+        ("🦶🏿", (EmojiId(465), EmojiId(460))),
+        ("😮\u{200d}💨", (EmojiId(47), EmojiId(47))),
+        ("👮🏽\u{200d}♂\u{fe0f}", (EmojiId(1139), EmojiId(1136))),
+        ("🇹🇭", (EmojiId(3736), EmojiId(3736))),
+        ("🧏🏼\u{200d}♂\u{fe0f}", (EmojiId(778), EmojiId(776))),
+        ("👲", (EmojiId(1244), EmojiId(1244))),
+        ("🧑🏽\u{200d}🏭", (EmojiId(971), EmojiId(968))),
+        ("🟫", (EmojiId(3485), EmojiId(3485))),
+        ("👨🏼\u{200d}🦼\u{200d}➡", (EmojiId(1673), EmojiId(1671))),
+        ("🇾🇪", (EmojiId(3765), EmojiId(3765))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", (EmojiId(2205), EmojiId(2185))),
+        ("🚳", (EmojiId(3298), EmojiId(3298))),
+        ("🖼\u{fe0f}", (EmojiId(3013), EmojiId(3013))),
+        ("🌍", (EmojiId(2716), EmojiId(2716))),
+        ("🔃", (EmojiId(3321), EmojiId(3321))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2106), EmojiId(2081))),
+        ("🧞\u{200d}♂", (EmojiId(1485), EmojiId(1485))),
+        ("💏", (EmojiId(2185), EmojiId(2185))),
+        ("🫱🏻\u{200d}🫲🏽", (EmojiId(403), EmojiId(396))),
+        ("🫅🏻", (EmojiId(1209), EmojiId(1208))),
+        ("👯\u{200d}♀", (EmojiId(1781), EmojiId(1781))),
+        ("🇿🇦", (EmojiId(3767), EmojiId(3767))),
+        ("🦶🏼", (EmojiId(462), EmojiId(460))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏽", (EmojiId(2391), EmojiId(2367))),
+        ("🛃", (EmojiId(3291), EmojiId(3291))),
+        ("😴", (EmojiId(56), EmojiId(56))),
+        ("👉🏻", (EmojiId(295), EmojiId(294))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏼", (EmojiId(2373), EmojiId(2367))),
+        ("👩\u{200d}👦", (EmojiId(2413), EmojiId(2413))),
+        ("🤦🏻\u{200d}♀\u{fe0f}", (EmojiId(819), EmojiId(818))),
+        ("🚶🏻\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1552), EmojiId(1551))),
+        ("👩🏻\u{200d}🏭", (EmojiId(981), EmojiId(980))),
+        ("👨🏼\u{200d}🦽", (EmojiId(1703), EmojiId(1701))),
+        ("🥀", (EmojiId(2559), EmojiId(2559))),
+        ("📨", (EmojiId(3160), EmojiId(3160))),
+        ("🥊", (EmojiId(2977), EmojiId(2977))),
+        ("🫱🏾\u{200d}🫲🏻", (EmojiId(414), EmojiId(396))),
+        ("🙋🏽\u{200d}♂", (EmojiId(761), EmojiId(758))),
+        ("💳", (EmojiId(3155), EmojiId(3155))),
+        ("🧑🏽\u{200d}✈\u{fe0f}", (EmojiId(1079), EmojiId(1076))),
+        ("🧑🏾\u{200d}🍼", (EmojiId(1332), EmojiId(1328))),
+        ("🫸", (EmojiId(228), EmojiId(228))),
+        ("🧑🏿\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1700), EmojiId(1695))),
+        ("🛳\u{fe0f}", (EmojiId(2837), EmojiId(2837))),
+        ("⛴", (EmojiId(2838), EmojiId(2838))),
+        ("🌋", (EmojiId(2725), EmojiId(2725))),
+        ("🐻\u{200d}❄", (EmojiId(2485), EmojiId(2485))),
+        ("🕵🏽\u{200d}♂", (EmojiId(1157), EmojiId(1154))),
+        ("🏄🏻", (EmojiId(1851), EmojiId(1850))),
+        ("👰🏾", (EmojiId(1278), EmojiId(1274))),
+        ("🫱🏾\u{200d}🫲🏼", (EmojiId(415), EmojiId(396))),
+        ("🕴🏿", (EmojiId(1778), EmojiId(1773))),
+        ("🧑🏽\u{200d}🦼", (EmojiId(1656), EmojiId(1653))),
+        ("👱🏿\u{200d}♀", (EmojiId(637), EmojiId(632))),
+        ("🧑🏻\u{200d}⚖", (EmojiId(897), EmojiId(896))),
+        ("🇫🇷", (EmojiId(3591), EmojiId(3591))),
+        ("🙋\u{200d}♀\u{fe0f}", (EmojiId(764), EmojiId(764))),
+        ("🚣🏽\u{200d}♂", (EmojiId(1877), EmojiId(1874))),
+        ("👩🏽\u{200d}⚕", (EmojiId(857), EmojiId(854))),
+        ("🛤", (EmojiId(2823), EmojiId(2823))),
+        ("👩🏼\u{200d}🏫", (EmojiId(892), EmojiId(890))),
+        ("🧑🏻\u{200d}🦱", (EmojiId(603), EmojiId(602))),
+        ("👨🏽\u{200d}🎨", (EmojiId(1067), EmojiId(1064))),
+        ("💇🏿", (EmojiId(1514), EmojiId(1509))),
+        ("🐶", (EmojiId(2432), EmojiId(2432))),
+        ("🙆", (EmojiId(716), EmojiId(716))),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏼", (EmojiId(2126), EmojiId(2107))),
+        ("🧏🏾\u{200d}♂\u{fe0f}", (EmojiId(780), EmojiId(776))),
+        ("👨🏽\u{200d}🦼\u{200d}➡", (EmojiId(1674), EmojiId(1671))),
+        ("🥈", (EmojiId(2959), EmojiId(2959))),
+        ("👩\u{200d}🦼\u{200d}➡", (EmojiId(1683), EmojiId(1683))),
+        ("🍣", (EmojiId(2662), EmojiId(2662))),
+        ("🦵🏻", (EmojiId(455), EmojiId(454))),
+        ("🍿", (EmojiId(2649), EmojiId(2649))),
+        ("🌊", (EmojiId(2933), EmojiId(2933))),
+        ("♻", (EmojiId(3398), EmojiId(3398))),
+        ("🙅🏻\u{200d}♂", (EmojiId(705), EmojiId(704))),
+        ("💙", (EmojiId(147), EmojiId(147))),
+        ("🙍🏽", (EmojiId(665), EmojiId(662))),
+        ("📿", (EmojiId(3062), EmojiId(3062))),
+        ("📱", (EmojiId(3095), EmojiId(3095))),
+        ("🙏🏽", (EmojiId(425), EmojiId(422))),
+        ("🧔🏻\u{200d}♂\u{fe0f}", (EmojiId(543), EmojiId(542))),
+        ("😚", (EmojiId(20), EmojiId(20))),
+        ("🤦🏾\u{200d}♀\u{fe0f}", (EmojiId(822), EmojiId(818))),
+        ("➰", (EmojiId(3409), EmojiId(3409))),
+        ("🛷", (EmojiId(2986), EmojiId(2986))),
+        ("🫱🏻\u{200d}🫲🏿", (EmojiId(405), EmojiId(396))),
+        ("🚴\u{200d}♀", (EmojiId(1952), EmojiId(1952))),
+        ("🥐", (EmojiId(2620), EmojiId(2620))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2229), EmojiId(2211))),
+        ("🧎🏽", (EmojiId(1584), EmojiId(1581))),
+        ("🧎🏾\u{200d}♂\u{200d}➡", (EmojiId(1615), EmojiId(1611))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2335), EmojiId(2315))),
+        ("👩🏽", (EmojiId(581), EmojiId(578))),
+        ("📈", (EmojiId(3187), EmojiId(3187))),
+        ("🧚🏼\u{200d}♂\u{fe0f}", (EmojiId(1420), EmojiId(1418))),
+        ("🧔🏾\u{200d}♀", (EmojiId(552), EmojiId(548))),
+        ("🦣", (EmojiId(2471), EmojiId(2471))),
+        ("🦸🏽\u{200d}♂", (EmojiId(1367), EmojiId(1364))),
+        ("🙅🏽", (EmojiId(701), EmojiId(698))),
+        ("🇲🇹", (EmojiId(3670), EmojiId(3670))),
+        ("🔏", (EmojiId(3203), EmojiId(3203))),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏽", (EmojiId(2196), EmojiId(2185))),
+        ("👴🏿", (EmojiId(655), EmojiId(650))),
+        ("🫶", (EmojiId(378), EmojiId(378))),
+        ("🧑🏽\u{200d}🦰", (EmojiId(593), EmojiId(590))),
+        ("🧁", (EmojiId(2682), EmojiId(2682))),
+        ("⬜", (EmojiId(3487), EmojiId(3487))),
+        ("5\u{20e3}", (EmojiId(3425), EmojiId(3425))),
+        ("🥏", (EmojiId(2969), EmojiId(2969))),
+        ("🧙", (EmojiId(1394), EmojiId(1394))),
+        ("👩🏽\u{200d}🦼", (EmojiId(1680), EmojiId(1677))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2256), EmojiId(2237))),
+        ("🧑🏽\u{200d}✈", (EmojiId(1079), EmojiId(1076))),
+        ("🛀🏽", (EmojiId(2072), EmojiId(2069))),
+        ("👰🏾\u{200d}♂\u{fe0f}", (EmojiId(1284), EmojiId(1280))),
+        ("🧑🏼\u{200d}💻", (EmojiId(1024), EmojiId(1022))),
+        ("🧝🏿\u{200d}♂\u{fe0f}", (EmojiId(1477), EmojiId(1472))),
+        ("👷🏼\u{200d}♂\u{fe0f}", (EmojiId(1198), EmojiId(1196))),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏻", (EmojiId(2147), EmojiId(2133))),
+        ("🏄🏿\u{200d}♂", (EmojiId(1861), EmojiId(1856))),
+        ("❇\u{fe0f}", (EmojiId(3414), EmojiId(3414))),
+        ("🧑🏼\u{200d}🚀", (EmojiId(1096), EmojiId(1094))),
+        ("🇭🇲", (EmojiId(3612), EmojiId(3612))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2225), EmojiId(2211))),
+        ("🏄🏼", (EmojiId(1852), EmojiId(1850))),
+        ("💇🏻\u{200d}♀", (EmojiId(1522), EmojiId(1521))),
+        ("🦯", (EmojiId(3225), EmojiId(3225))),
+        ("🤞🏻", (EmojiId(259), EmojiId(258))),
+        ("🤝🏿", (EmojiId(401), EmojiId(396))),
+        ("🐦\u{200d}🔥", (EmojiId(2515), EmojiId(2515))),
+        ("💂🏻\u{200d}♂", (EmojiId(1173), EmojiId(1172))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2281), EmojiId(2263))),
+        ("🧑🏽\u{200d}🎄", (EmojiId(1355), EmojiId(1352))),
+        ("🚴🏽", (EmojiId(1943), EmojiId(1940))),
+        ("🦦", (EmojiId(2489), EmojiId(2489))),
+        ("🤴🏽", (EmojiId(1217), EmojiId(1214))),
+        ("🏃🏿\u{200d}♀\u{200d}➡", (EmojiId(1754), EmojiId(1749))),
+        ("🌫", (EmojiId(2918), EmojiId(2918))),
+        ("👨🏽\u{200d}🔧", (EmojiId(959), EmojiId(956))),
+        ("🙇🏼\u{200d}♀", (EmojiId(802), EmojiId(800))),
+        ("🈸", (EmojiId(3463), EmojiId(3463))),
+        ("🤽\u{200d}♂\u{fe0f}", (EmojiId(2003), EmojiId(2003))),
+        ("🇲🇺", (EmojiId(3671), EmojiId(3671))),
+        ("🍻", (EmojiId(2700), EmojiId(2700))),
+        ("⛹🏼\u{200d}♂", (EmojiId(1912), EmojiId(1910))),
+        ("↙", (EmojiId(3312), EmojiId(3312))),
+        ("👨🏾\u{200d}🏭", (EmojiId(978), EmojiId(974))),
+        ("👨🏽\u{200d}🦰", (EmojiId(557), EmojiId(554))),
+        ("🙎🏿\u{200d}♀\u{fe0f}", (EmojiId(697), EmojiId(692))),
+        ("🏋🏼\u{200d}♂\u{fe0f}", (EmojiId(1930), EmojiId(1928))),
+        ("🤧", (EmojiId(62), EmojiId(62))),
+        ("🤽🏾", (EmojiId(2001), EmojiId(1997))),
+        ("🧍🏼\u{200d}♂", (EmojiId(1571), EmojiId(1569))),
+        ("✍🏾", (EmojiId(432), EmojiId(428))),
+        ("🧑\u{200d}🔧", (EmojiId(950), EmojiId(950))),
+        ("🎰", (EmojiId(2997), EmojiId(2997))),
+        ("🧎🏾\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1609), EmojiId(1605))),
+        ("👨🏾\u{200d}🦽", (EmojiId(1705), EmojiId(1701))),
+        ("👓", (EmojiId(3019), EmojiId(3019))),
+        ("👍🏼", (EmojiId(332), EmojiId(330))),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}👨", (EmojiId(2315), EmojiId(2315))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2257), EmojiId(2237))),
+        ("🤷🏻\u{200d}♂", (EmojiId(831), EmojiId(830))),
+        ("📫", (EmojiId(3165), EmojiId(3165))),
+        ("👦🏾", (EmojiId(510), EmojiId(506))),
+        ("🧎🏻\u{200d}➡\u{fe0f}", (EmojiId(1600), EmojiId(1599))),
+        ("🙎\u{200d}♂", (EmojiId(686), EmojiId(686))),
+        ("🆒", (EmojiId(3440), EmojiId(3440))),
+        ("🤷🏿\u{200d}♀", (EmojiId(841), EmojiId(836))),
+        ("🤵\u{200d}♂\u{fe0f}", (EmojiId(1262), EmojiId(1262))),
+        ("👩🏽\u{200d}🍳", (EmojiId(947), EmojiId(944))),
+        ("🧑🏾\u{200d}⚖\u{fe0f}", (EmojiId(900), EmojiId(896))),
+        ("🙆\u{200d}♂", (EmojiId(722), EmojiId(722))),
+        ("🅾", (EmojiId(3447), EmojiId(3447))),
+        ("🤴🏾", (EmojiId(1218), EmojiId(1214))),
+        ("🇵🇼", (EmojiId(3702), EmojiId(3702))),
+        ("🕵🏿\u{200d}♂", (EmojiId(1159), EmojiId(1154))),
+        ("🙉", (EmojiId(127), EmojiId(127))),
+        ("😵", (EmojiId(66), EmojiId(66))),
+        ("🗜\u{fe0f}", (EmojiId(3223), EmojiId(3223))),
+        ("👥", (EmojiId(2420), EmojiId(2420))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", (EmojiId(2204), EmojiId(2185))),
+        ("🧏🏿\u{200d}♀", (EmojiId(787), EmojiId(782))),
+        ("👐🏼", (EmojiId(386), EmojiId(384))),
+        ("🪇", (EmojiId(3093), EmojiId(3093))),
+        ("🧜🏼", (EmojiId(1450), EmojiId(1448))),
+        ("👨🏾\u{200d}🎤", (EmojiId(1050), EmojiId(1046))),
+        ("🧑\u{200d}⚖\u{fe0f}", (EmojiId(896), EmojiId(896))),
+        ("🗄", (EmojiId(3199), EmojiId(3199))),
+        ("👨🏿\u{200d}🦱", (EmojiId(565), EmojiId(560))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", (EmojiId(2192), EmojiId(2185))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2288), EmojiId(2263))),
+        ("0\u{fe0f}\u{20e3}", (EmojiId(3420), EmojiId(3420))),
+        ("🏃🏼\u{200d}♀", (EmojiId(1739), EmojiId(1737))),
+        ("🕷", (EmojiId(2546), EmojiId(2546))),
+        ("🙇\u{200d}♀", (EmojiId(800), EmojiId(800))),
+        ("👱🏽\u{200d}♂", (EmojiId(641), EmojiId(638))),
+        ("🧚🏾\u{200d}♂", (EmojiId(1422), EmojiId(1418))),
+        ("🤹🏼\u{200d}♂\u{fe0f}", (EmojiId(2041), EmojiId(2039))),
+        ("🧘🏾\u{200d}♀", (EmojiId(2067), EmojiId(2063))),
+        ("⏩", (EmojiId(3358), EmojiId(3358))),
+        ("⚰", (EmojiId(3273), EmojiId(3273))),
+        ("🔨", (EmojiId(3207), EmojiId(3207))),
+        ("🧍🏿\u{200d}♂", (EmojiId(1574), EmojiId(1569))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏾", (EmojiId(2349), EmojiId(2341))),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏽", (EmojiId(2166), EmojiId(2159))),
+        ("🇨🇼", (EmojiId(3566), EmojiId(3566))),
+        ("💆🏽\u{200d}♀", (EmojiId(1506), EmojiId(1503))),
+        ("🧔", (EmojiId(536), EmojiId(536))),
+        ("⛅", (EmojiId(2909), EmojiId(2909))),
+        ("🧑\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1695), EmojiId(1695))),
+        ("🪈", (EmojiId(3094), EmojiId(3094))),
+        ("🇲🇼", (EmojiId(3673), EmojiId(3673))),
+        ("👨🏽\u{200d}🍼", (EmojiId(1325), EmojiId(1322))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏿", (EmojiId(2340), EmojiId(2315))),
+        ("🚪", (EmojiId(3247), EmojiId(3247))),
+        ("🇲🇴", (EmojiId(3665), EmojiId(3665))),
+        ("🚣\u{200d}♀", (EmojiId(1880), EmojiId(1880))),
+        ("🏃🏾\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1759), EmojiId(1755))),
+        ("㊙", (EmojiId(3467), EmojiId(3467))),
+        ("🤜🏾", (EmojiId(364), EmojiId(360))),
+        ("🐡", (EmojiId(2531), EmojiId(2531))),
+        ("🇬🇮", (EmojiId(3599), EmojiId(3599))),
+        ("1\u{fe0f}\u{20e3}", (EmojiId(3421), EmojiId(3421))),
+        ("💇🏻", (EmojiId(1510), EmojiId(1509))),
+        ("🧎🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1607), EmojiId(1605))),
+        ("🤵🏿\u{200d}♀", (EmojiId(1273), EmojiId(1268))),
+        ("⛹", (EmojiId(1904), EmojiId(1904))),
+        ("🐿\u{fe0f}", (EmojiId(2480), EmojiId(2480))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2224), EmojiId(2211))),
+        ("👨🏽\u{200d}🏭", (EmojiId(977), EmojiId(974))),
+        ("🫥", (EmojiId(41), EmojiId(41))),
+        ("🏃🏼\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1751), EmojiId(1749))),
+        ("🤵🏽", (EmojiId(1259), EmojiId(1256))),
+        ("🤩", (EmojiId(16), EmojiId(16))),
+        ("🫃🏻", (EmojiId(1299), EmojiId(1298))),
+        ("👨🏻\u{200d}⚕", (EmojiId(849), EmojiId(848))),
+        ("🧛🏽\u{200d}♀", (EmojiId(1445), EmojiId(1442))),
+        ("🤦🏼", (EmojiId(808), EmojiId(806))),
+        ("⚠\u{fe0f}", (EmojiId(3294), EmojiId(3294))),
+        ("🙎🏻\u{200d}♂\u{fe0f}", (EmojiId(687), EmojiId(686))),
+        ("🧛🏼", (EmojiId(1432), EmojiId(1430))),
+        ("⬛", (EmojiId(3486), EmojiId(3486))),
+        ("💆🏼\u{200d}♂\u{fe0f}", (EmojiId(1499), EmojiId(1497))),
+        ("👨🏾\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1711), EmojiId(1707))),
+        ("👷🏾\u{200d}♀", (EmojiId(1206), EmojiId(1202))),
+        ("👯", (EmojiId(1779), EmojiId(1779))),
+        ("🤦🏻\u{200d}♀", (EmojiId(819), EmojiId(818))),
+        ("🧆", (EmojiId(2641), EmojiId(2641))),
+        ("🎏", (EmojiId(2946), EmojiId(2946))),
+        ("💁\u{200d}♂\u{fe0f}", (EmojiId(740), EmojiId(740))),
+        ("🕶\u{fe0f}", (EmojiId(3020), EmojiId(3020))),
+        ("👨🏻\u{200d}💼", (EmojiId(993), EmojiId(992))),
+        ("⛲", (EmojiId(2765), EmojiId(2765))),
+        ("🧅", (EmojiId(2612), EmojiId(2612))),
+        ("🈷", (EmojiId(3455), EmojiId(3455))),
+        ("🧑🏽\u{200d}🦽\u{200d}➡", (EmojiId(1698), EmojiId(1695))),
+        ("👨🏻\u{200d}✈", (EmojiId(1083), EmojiId(1082))),
+        ("⛪", (EmojiId(2759), EmojiId(2759))),
+        ("👛", (EmojiId(3040), EmojiId(3040))),
+        ("🧑🏾\u{200d}⚕", (EmojiId(846), EmojiId(842))),
+        ("📖", (EmojiId(3133), EmojiId(3133))),
+        ("🤾🏾\u{200d}♀\u{fe0f}", (EmojiId(2031), EmojiId(2027))),
+        ("⛈\u{fe0f}", (EmojiId(2910), EmojiId(2910))),
+        ("🚶🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1559), EmojiId(1557))),
+        ("🙎🏾\u{200d}♀", (EmojiId(696), EmojiId(692))),
+        ("🧌", (EmojiId(1490), EmojiId(1490))),
+        ("🕵🏿\u{200d}♂\u{fe0f}", (EmojiId(1159), EmojiId(1154))),
+        ("🏊🏻\u{200d}♀", (EmojiId(1899), EmojiId(1898))),
+        ("🏴\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}", (EmojiId(3772), EmojiId(3772))),
+        ("🇮🇪", (EmojiId(3619), EmojiId(3619))),
+        ("🍽", (EmojiId(2710), EmojiId(2710))),
+        ("🧑🏽", (EmojiId(521), EmojiId(518))),
+        ("🎃", (EmojiId(2934), EmojiId(2934))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2360), EmojiId(2341))),
+        ("🖱\u{fe0f}", (EmojiId(3108), EmojiId(3108))),
+        ("🛍", (EmojiId(3043), EmojiId(3043))),
+        ("🐗", (EmojiId(2461), EmojiId(2461))),
+        ("💇🏾\u{200d}♀\u{fe0f}", (EmojiId(1525), EmojiId(1521))),
+        ("🏌\u{fe0f}\u{200d}♀\u{fe0f}", (EmojiId(1844), EmojiId(1844))),
+        ("😓", (EmojiId(97), EmojiId(97))),
+        ("🧑\u{200d}🦯\u{200d}➡", (EmojiId(1623), EmojiId(1623))),
+        ("3\u{fe0f}\u{20e3}", (EmojiId(3423), EmojiId(3423))),
+        ("🙆🏿", (EmojiId(721), EmojiId(716))),
+        ("👳\u{200d}♂", (EmojiId(1232), EmojiId(1232))),
+        ("🚶🏽\u{200d}♂\u{fe0f}", (EmojiId(1536), EmojiId(1533))),
+        ("🧔🏿\u{200d}♂\u{fe0f}", (EmojiId(547), EmojiId(542))),
+        ("↖", (EmojiId(3314), EmojiId(3314))),
+        ("💪🏼", (EmojiId(448), EmojiId(446))),
+        ("🧎\u{200d}♂\u{fe0f}", (EmojiId(1587), EmojiId(1587))),
+        ("🧎🏻\u{200d}♂\u{fe0f}", (EmojiId(1588), EmojiId(1587))),
+        ("⏲\u{fe0f}", (EmojiId(2861), EmojiId(2861))),
+        ("🙇🏽", (EmojiId(791), EmojiId(788))),
+        ("🫚", (EmojiId(2616), EmojiId(2616))),
+        ("🧑🏿\u{200d}🎤", (EmojiId(1045), EmojiId(1040))),
+        ("🤼\u{200d}♀", (EmojiId(1996), EmojiId(1996))),
+        ("🧛🏼\u{200d}♀", (EmojiId(1444), EmojiId(1442))),
+        ("🧎🏽\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1608), EmojiId(1605))),
+        ("🔹", (EmojiId(3497), EmojiId(3497))),
+        ("🤦🏽\u{200d}♀", (EmojiId(821), EmojiId(818))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2234), EmojiId(2211))),
+        ("🎡", (EmojiId(2778), EmojiId(2778))),
+        ("🍙", (EmojiId(2655), EmojiId(2655))),
+        ("🏄🏻\u{200d}♀\u{fe0f}", (EmojiId(1863), EmojiId(1862))),
+        ("🇪🇦", (EmojiId(3577), EmojiId(3577))),
+        ("⛑", (EmojiId(3061), EmojiId(3061))),
+        ("🧜🏿\u{200d}♂", (EmojiId(1459), EmojiId(1454))),
+        ("🧘🏼\u{200d}♀", (EmojiId(2065), EmojiId(2063))),
+        ("👮🏼", (EmojiId(1132), EmojiId(1130))),
+        ("👭🏻", (EmojiId(2108), EmojiId(2107))),
+        ("🙍🏿\u{200d}♀", (EmojiId(679), EmojiId(674))),
+        ("🙇🏻\u{200d}♂", (EmojiId(795), EmojiId(794))),
+        ("🌈", (EmojiId(2921), EmojiId(2921))),
+        ("👩🏾\u{200d}🏭", (EmojiId(984), EmojiId(980))),
+        ("😝", (EmojiId(27), EmojiId(27))),
+        ("🧑🏽\u{200d}⚕\u{fe0f}", (EmojiId(845), EmojiId(842))),
+        ("👩🏼\u{200d}🦰", (EmojiId(586), EmojiId(584))),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏾", (EmojiId(2167), EmojiId(2159))),
+        ("🤕", (EmojiId(59), EmojiId(59))),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏻", (EmojiId(2173), EmojiId(2159))),
+        ("🧫", (EmojiId(3235), EmojiId(3235))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2243), EmojiId(2237))),
+        ("🙋🏼\u{200d}♂", (EmojiId(760), EmojiId(758))),
+        ("👨🏼\u{200d}🦽\u{200d}➡", (EmojiId(1709), EmojiId(1707))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2283), EmojiId(2263))),
+        ("🧑🏼\u{200d}⚖", (EmojiId(898), EmojiId(896))),
+        ("⛈", (EmojiId(2910), EmojiId(2910))),
+        ("☑\u{fe0f}", (EmojiId(3405), EmojiId(3405))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2373), EmojiId(2367))),
+        ("🏊🏽\u{200d}♂", (EmojiId(1895), EmojiId(1892))),
+        ("🧔🏼\u{200d}♀\u{fe0f}", (EmojiId(550), EmojiId(548))),
+        ("👮🏾\u{200d}♂\u{fe0f}", (EmojiId(1140), EmojiId(1136))),
+        ("👨🏿\u{200d}🦼\u{200d}➡", (EmojiId(1676), EmojiId(1671))),
+        ("🏃🏿\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1754), EmojiId(1749))),
+        ("👩\u{200d}⚕", (EmojiId(854), EmojiId(854))),
+        ("🥇", (EmojiId(2958), EmojiId(2958))),
+        ("👩\u{200d}👩\u{200d}👦", (EmojiId(2403), EmojiId(2403))),
+        ("🤽🏿\u{200d}♂\u{fe0f}", (EmojiId(2008), EmojiId(2003))),
+        ("🧖🏾", (EmojiId(1786), EmojiId(1782))),
+        ("🗓\u{fe0f}", (EmojiId(3185), EmojiId(3185))),
+        ("🩰", (EmojiId(3052), EmojiId(3052))),
+        ("🧝🏿", (EmojiId(1471), EmojiId(1466))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2382), EmojiId(2367))),
+        ("🇬🇳", (EmojiId(3602), EmojiId(3602))),
+        ("🏃🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1752), EmojiId(1749))),
+        ("🧘🏽\u{200d}♀", (EmojiId(2066), EmojiId(2063))),
+        ("🙂\u{200d}↔\u{fe0f}", (EmojiId(50), EmojiId(50))),
+        ("🏗", (EmojiId(2734), EmojiId(2734))),
+        ("🧑\u{200d}⚕", (EmojiId(842), EmojiId(842))),
+        ("🧥", (EmojiId(3029), EmojiId(3029))),
+        ("🪼", (EmojiId(2536), EmojiId(2536))),
+        ("🙇🏽\u{200d}♂\u{fe0f}", (EmojiId(797), EmojiId(794))),
+        ("🦵🏼", (EmojiId(456), EmojiId(454))),
+        ("💁🏻\u{200d}♂\u{fe0f}", (EmojiId(741), EmojiId(740))),
+        ("🏊🏻\u{200d}♀\u{fe0f}", (EmojiId(1899), EmojiId(1898))),
+        ("🧎🏾\u{200d}♂\u{fe0f}", (EmojiId(1591), EmojiId(1587))),
+        ("💇🏽", (EmojiId(1512), EmojiId(1509))),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏾", (EmojiId(2314), EmojiId(2289))),
+        ("🧗🏻\u{200d}♀", (EmojiId(1813), EmojiId(1812))),
+        ("🫄", (EmojiId(1304), EmojiId(1304))),
+        ("🏋🏻\u{200d}♂\u{fe0f}", (EmojiId(1929), EmojiId(1928))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2255), EmojiId(2237))),
+        ("🚴🏾", (EmojiId(1944), EmojiId(1940))),
+        ("🏃🏾\u{200d}♀\u{fe0f}", (EmojiId(1741), EmojiId(1737))),
+        ("🧕🏾", (EmojiId(1254), EmojiId(1250))),
+        ("🌂", (EmojiId(2922), EmojiId(2922))),
+        ("🇻🇳", (EmojiId(3760), EmojiId(3760))),
+        ("🧎\u{200d}➡\u{fe0f}", (EmojiId(1599), EmojiId(1599))),
+        ("💻", (EmojiId(3104), EmojiId(3104))),
+        ("🧘🏼\u{200d}♂", (EmojiId(2059), EmojiId(2057))),
+        ("🧜🏻\u{200d}♂\u{fe0f}", (EmojiId(1455), EmojiId(1454))),
+        ("🚶🏾\u{200d}♀", (EmojiId(1543), EmojiId(1539))),
+        ("🛢\u{fe0f}", (EmojiId(2824), EmojiId(2824))),
+        ("🧰", (EmojiId(3230), EmojiId(3230))),
+        ("🤦🏾\u{200d}♂", (EmojiId(816), EmojiId(812))),
+        ("🏋\u{fe0f}\u{200d}♀\u{fe0f}", (EmojiId(1934), EmojiId(1934))),
+        ("👱🏼\u{200d}♂", (EmojiId(640), EmojiId(638))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏽", (EmojiId(2378), EmojiId(2367))),
+        ("🔓", (EmojiId(3202), EmojiId(3202))),
+        ("🗽", (EmojiId(2758), EmojiId(2758))),
+        ("🧞", (EmojiId(1484), EmojiId(1484))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2104), EmojiId(2081))),
+        ("🥳", (EmojiId(70), EmojiId(70))),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏼", (EmojiId(2208), EmojiId(2185))),
+        ("🇵🇱", (EmojiId(3696), EmojiId(3696))),
+        ("🛄", (EmojiId(3292), EmojiId(3292))),
+        ("🇫🇰", (EmojiId(3588), EmojiId(3588))),
+        ("💁🏿\u{200d}♂", (EmojiId(745), EmojiId(740))),
+        ("🏚\u{fe0f}", (EmojiId(2740), EmojiId(2740))),
+        ("👇🏻", (EmojiId(313), EmojiId(312))),
+        ("🐁", (EmojiId(2475), EmojiId(2475))),
+        ("🤰", (EmojiId(1292), EmojiId(1292))),
+        ("🧘🏻", (EmojiId(2052), EmojiId(2051))),
+        ("🏊🏼\u{200d}♂", (EmojiId(1894), EmojiId(1892))),
+        ("➖", (EmojiId(3384), EmojiId(3384))),
+        ("🧑\u{200d}🦯", (EmojiId(1617), EmojiId(1617))),
+        ("🧒", (EmojiId(500), EmojiId(500))),
+        ("👮🏾", (EmojiId(1134), EmojiId(1130))),
+        ("💆🏼\u{200d}♀\u{fe0f}", (EmojiId(1505), EmojiId(1503))),
+        ("🫱🏾\u{200d}🫲🏿", (EmojiId(417), EmojiId(396))),
+        ("🔒", (EmojiId(3201), EmojiId(3201))),
+        ("👩\u{200d}🦼", (EmojiId(1677), EmojiId(1677))),
+        ("🏃🏾\u{200d}♂\u{fe0f}", (EmojiId(1735), EmojiId(1731))),
+        ("🫦", (EmojiId(493), EmojiId(493))),
+        ("👩🏼\u{200d}🌾", (EmojiId(928), EmojiId(926))),
+        ("👝", (EmojiId(3042), EmojiId(3042))),
+        ("🖨\u{fe0f}", (EmojiId(3106), EmojiId(3106))),
+        ("🐀", (EmojiId(2476), EmojiId(2476))),
+        ("🟣", (EmojiId(3475), EmojiId(3475))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏼", (EmojiId(2304), EmojiId(2289))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏽", (EmojiId(2382), EmojiId(2367))),
+        ("🕴🏼", (EmojiId(1775), EmojiId(1773))),
+        ("🧝🏿\u{200d}♂", (EmojiId(1477), EmojiId(1472))),
+        ("🧕🏿", (EmojiId(1255), EmojiId(1250))),
+        ("👨🏽\u{200d}🚒", (EmojiId(1121), EmojiId(1118))),
+        ("🚴🏾\u{200d}♀", (EmojiId(1956), EmojiId(1952))),
+        ("🏊🏿", (EmojiId(1891), EmojiId(1886))),
+        ("🙃", (EmojiId(9), EmojiId(9))),
+        ("🫱🏽\u{200d}🫲🏿", (EmojiId(413), EmojiId(396))),
+        ("🇬🇪", (EmojiId(3595), EmojiId(3595))),
+        ("🧑\u{200d}🎨", (EmojiId(1058), EmojiId(1058))),
+        ("🧖🏼", (EmojiId(1784), EmojiId(1782))),
+        ("🏌🏿\u{200d}♂", (EmojiId(1843), EmojiId(1838))),
+        ("🧖🏽\u{200d}♂\u{fe0f}", (EmojiId(1791), EmojiId(1788))),
+        ("🏋\u{200d}♀\u{fe0f}", (EmojiId(1934), EmojiId(1934))),
+        ("🤌🏼", (EmojiId(242), EmojiId(240))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2227), EmojiId(2211))),
+        ("🧑\u{200d}🍳", (EmojiId(932), EmojiId(932))),
+        ("🇺🇲", (EmojiId(3750), EmojiId(3750))),
+        ("🧑🏽\u{200d}🔬", (EmojiId(1007), EmojiId(1004))),
+        ("🌞", (EmojiId(2902), EmojiId(2902))),
+        ("🧑🏾\u{200d}🍳", (EmojiId(936), EmojiId(932))),
+        ("👨🏻\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1672), EmojiId(1671))),
+        ("☂\u{fe0f}", (EmojiId(2923), EmojiId(2923))),
+        ("🌙", (EmojiId(2895), EmojiId(2895))),
+        ("🖐\u{fe0f}", (EmojiId(180), EmojiId(180))),
+        ("🧑🏻\u{200d}🦰", (EmojiId(591), EmojiId(590))),
+        ("🪧", (EmojiId(3279), EmojiId(3279))),
+        ("🧜🏾\u{200d}♀\u{fe0f}", (EmojiId(1464), EmojiId(1460))),
+        ("🦵🏽", (EmojiId(457), EmojiId(454))),
+        ("🖕🏻", (EmojiId(307), EmojiId(306))),
+        ("👩🏻\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1648), EmojiId(1647))),
+        ("🤽🏿\u{200d}♂", (EmojiId(2008), EmojiId(2003))),
+        ("🧖🏿\u{200d}♂\u{fe0f}", (EmojiId(1793), EmojiId(1788))),
+        ("🥞", (EmojiId(2625), EmojiId(2625))),
+        ("🙎\u{200d}♀\u{fe0f}", (EmojiId(692), EmojiId(692))),
+        ("🇸🇿", (EmojiId(3730), EmojiId(3730))),
+        ("🤶🏼", (EmojiId(1348), EmojiId(1346))),
+        ("🪜", (EmojiId(3232), EmojiId(3232))),
+        ("✌", (EmojiId(252), EmojiId(252))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏿", (EmojiId(2392), EmojiId(2367))),
+        ("📮", (EmojiId(3169), EmojiId(3169))),
+        ("🫘", (EmojiId(2614), EmojiId(2614))),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏽", (EmojiId(2127), EmojiId(2107))),
+        ("💇🏼\u{200d}♀", (EmojiId(1523), EmojiId(1521))),
+        ("🧎\u{200d}♀\u{200d}➡", (EmojiId(1605), EmojiId(1605))),
+        ("🏋\u{200d}♂", (EmojiId(1928), EmojiId(1928))),
+        ("💬", (EmojiId(162), EmojiId(162))),
+        ("🦂", (EmojiId(2548), EmojiId(2548))),
+        ("0\u{20e3}", (EmojiId(3420), EmojiId(3420))),
+        ("🏊\u{200d}♂\u{fe0f}", (EmojiId(1892), EmojiId(1892))),
+        ("⌛", (EmojiId(2856), EmojiId(2856))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2278), EmojiId(2263))),
+        ("🧙🏾", (EmojiId(1398), EmojiId(1394))),
+        ("🦚", (EmojiId(2510), EmojiId(2510))),
+        ("😾", (EmojiId(125), EmojiId(125))),
+        ("🧕🏻", (EmojiId(1251), EmojiId(1250))),
+        ("🤹🏾\u{200d}♀\u{fe0f}", (EmojiId(2049), EmojiId(2045))),
+        ("🏌🏽", (EmojiId(1835), EmojiId(1832))),
+        ("🤦🏾", (EmojiId(810), EmojiId(806))),
+        ("🤦🏿", (EmojiId(811), EmojiId(806))),
+        ("🧞\u{200d}♀\u{fe0f}", (EmojiId(1486), EmojiId(1486))),
+        ("💃🏻", (EmojiId(1762), EmojiId(1761))),
+        ("🕵🏿\u{200d}♀\u{fe0f}", (EmojiId(1165), EmojiId(1160))),
+        ("🥦", (EmojiId(2610), EmojiId(2610))),
+        ("☑", (EmojiId(3405), EmojiId(3405))),
+        ("💗", (EmojiId(133), EmojiId(133))),
+        ("🗓", (EmojiId(3185), EmojiId(3185))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2099), EmojiId(2081))),
+        ("🌹", (EmojiId(2558), EmojiId(2558))),
+        ("🤾🏽", (EmojiId(2018), EmojiId(2015))),
+        ("👨🏾\u{200d}🌾", (EmojiId(924), EmojiId(920))),
+        ("🤚🏼", (EmojiId(176), EmojiId(174))),
+        ("🦃", (EmojiId(2494), EmojiId(2494))),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏾", (EmojiId(2119), EmojiId(2107))),
+        ("🧨", (EmojiId(2938), EmojiId(2938))),
+        ("🙅🏾", (EmojiId(702), EmojiId(698))),
+        ("👩🏼\u{200d}⚕\u{fe0f}", (EmojiId(856), EmojiId(854))),
+        ("🧝🏾\u{200d}♀\u{fe0f}", (EmojiId(1482), EmojiId(1478))),
+        ("🫱🏿\u{200d}🫲🏽", (EmojiId(420), EmojiId(396))),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏻", (EmojiId(2125), EmojiId(2107))),
+        ("🚶\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1551), EmojiId(1551))),
+        ("🤽🏽\u{200d}♀\u{fe0f}", (EmojiId(2012), EmojiId(2009))),
+        ("🧔🏽\u{200d}♂", (EmojiId(545), EmojiId(542))),
+        ("🏳\u{fe0f}\u{200d}⚧", (EmojiId(3510), EmojiId(3510))),
+        ("🙋🏽", (EmojiId(755), EmojiId(752))),
+        ("🤰🏿", (EmojiId(1297), EmojiId(1292))),
+        ("👨\u{200d}🦲", (EmojiId(572), EmojiId(572))),
+        ("🈯", (EmojiId(3457), EmojiId(3457))),
+        ("🚣🏾\u{200d}♀\u{fe0f}", (EmojiId(1884), EmojiId(1880))),
+        ("🇧🇩", (EmojiId(3531), EmojiId(3531))),
+        ("🤾🏽\u{200d}♂", (EmojiId(2024), EmojiId(2021))),
+        ("🕵🏻\u{200d}♂\u{fe0f}", (EmojiId(1155), EmojiId(1154))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2212), EmojiId(2211))),
+        ("🕵\u{200d}♀", (EmojiId(1160), EmojiId(1160))),
+        ("👩🏻\u{200d}🚒", (EmojiId(1125), EmojiId(1124))),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏽", (EmojiId(2114), EmojiId(2107))),
+        ("🚶🏿\u{200d}♀", (EmojiId(1544), EmojiId(1539))),
+        ("🇯🇪", (EmojiId(3628), EmojiId(3628))),
+        ("🫅🏽", (EmojiId(1211), EmojiId(1208))),
+        ("🧑🏼\u{200d}🚒", (EmojiId(1114), EmojiId(1112))),
+        ("💇\u{200d}♂", (EmojiId(1515), EmojiId(1515))),
+        ("🖖🏻", (EmojiId(193), EmojiId(192))),
+        ("🍼", (EmojiId(2689), EmojiId(2689))),
+        ("🚣🏻\u{200d}♂", (EmojiId(1875), EmojiId(1874))),
+        ("🤙🏿", (EmojiId(287), EmojiId(282))),
+        ("◻\u{fe0f}", (EmojiId(3489), EmojiId(3489))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2283), EmojiId(2263))),
+        ("🕕", (EmojiId(2875), EmojiId(2875))),
+        ("🤱🏿", (EmojiId(1315), EmojiId(1310))),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏻", (EmojiId(2169), EmojiId(2159))),
+        ("🔠", (EmojiId(3431), EmojiId(3431))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2351), EmojiId(2341))),
+        ("🧔🏿", (EmojiId(541), EmojiId(536))),
+        ("🕺🏻", (EmojiId(1768), EmojiId(1767))),
+        ("🦸🏼\u{200d}♂\u{fe0f}", (EmojiId(1366), EmojiId(1364))),
+        ("🤚🏿", (EmojiId(179), EmojiId(174))),
+        ("🌳", (EmojiId(2568), EmojiId(2568))),
+        ("🛟", (EmojiId(2833), EmojiId(2833))),
+        ("⛄", (EmojiId(2929), EmojiId(2929))),
+        ("🚔", (EmojiId(2801), EmojiId(2801))),
+        ("☝🏽", (EmojiId(321), EmojiId(318))),
+        ("🧝🏼\u{200d}♂\u{fe0f}", (EmojiId(1474), EmojiId(1472))),
+        ("🧜🏽\u{200d}♂", (EmojiId(1457), EmojiId(1454))),
+        ("👩🏾\u{200d}🦼", (EmojiId(1681), EmojiId(1677))),
+        ("🧙🏽", (EmojiId(1397), EmojiId(1394))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2354), EmojiId(2341))),
+        ("🧎🏽\u{200d}♀\u{200d}➡", (EmojiId(1608), EmojiId(1605))),
+        ("🫎", (EmojiId(2448), EmojiId(2448))),
+        ("🙋\u{200d}♀", (EmojiId(764), EmojiId(764))),
+        ("🇱🇦", (EmojiId(3643), EmojiId(3643))),
+        ("📉", (EmojiId(3188), EmojiId(3188))),
+        ("🫃", (EmojiId(1298), EmojiId(1298))),
+        ("👩🏼\u{200d}✈", (EmojiId(1090), EmojiId(1088))),
+        ("👱🏿", (EmojiId(529), EmojiId(524))),
+        ("🧖🏾\u{200d}♀", (EmojiId(1798), EmojiId(1794))),
+        ("🏃🏼\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1757), EmojiId(1755))),
+        ("🕊", (EmojiId(2502), EmojiId(2502))),
+        ("⛩", (EmojiId(2763), EmojiId(2763))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏿", (EmojiId(2350), EmojiId(2341))),
+        ("👏🏽", (EmojiId(369), EmojiId(366))),
+        ("🧖🏾\u{200d}♂", (EmojiId(1792), EmojiId(1788))),
+        ("🧑🏾\u{200d}🦳", (EmojiId(618), EmojiId(614))),
+        ("🇹🇯", (EmojiId(3737), EmojiId(3737))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏾", (EmojiId(2387), EmojiId(2367))),
+        ("🧙🏽\u{200d}♀\u{fe0f}", (EmojiId(1409), EmojiId(1406))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏽", (EmojiId(2322), EmojiId(2315))),
+        ("🇷🇼", (EmojiId(3709), EmojiId(3709))),
+        ("😭", (EmojiId(92), EmojiId(92))),
+        ("👮🏼\u{200d}♂", (EmojiId(1138), EmojiId(1136))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2087), EmojiId(2081))),
+        ("⛓\u{fe0f}", (EmojiId(3228), EmojiId(3228))),
+        ("🧏🏻\u{200d}♀\u{fe0f}", (EmojiId(783), EmojiId(782))),
+        ("🚣🏽", (EmojiId(1871), EmojiId(1868))),
+        ("🦹\u{200d}♂", (EmojiId(1382), EmojiId(1382))),
+        ("👭🏼", (EmojiId(2109), EmojiId(2107))),
+        ("🧑🏽\u{200d}🦯\u{200d}➡", (EmojiId(1626), EmojiId(1623))),
+        ("👨🏼\u{200d}💼", (EmojiId(994), EmojiId(992))),
+        ("👦🏽", (EmojiId(509), EmojiId(506))),
+        ("👨\u{200d}👩\u{200d}👦\u{200d}👦", (EmojiId(2396), EmojiId(2396))),
+        ("〽", (EmojiId(3411), EmojiId(3411))),
+        ("🛀🏻", (EmojiId(2070), EmojiId(2069))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏿", (EmojiId(2384), EmojiId(2367))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2105), EmojiId(2081))),
+        ("🧔🏿\u{200d}♂", (EmojiId(547), EmojiId(542))),
+        ("🈲", (EmojiId(3461), EmojiId(3461))),
+        ("🙏🏼", (EmojiId(424), EmojiId(422))),
+        ("🧎🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1608), EmojiId(1605))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏻", (EmojiId(2325), EmojiId(2315))),
+        ("🗻", (EmojiId(2726), EmojiId(2726))),
+        ("👧🏾", (EmojiId(516), EmojiId(512))),
+        ("👨🏿\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1676), EmojiId(1671))),
+        ("🧑🏿\u{200d}🔬", (EmojiId(1009), EmojiId(1004))),
+        ("👶🏻", (EmojiId(495), EmojiId(494))),
+        ("🔩", (EmojiId(3221), EmojiId(3221))),
+        ("🇫🇲", (EmojiId(3589), EmojiId(3589))),
+        ("📝", (EmojiId(3177), EmojiId(3177))),
+        ("🥽", (EmojiId(3021), EmojiId(3021))),
+        ("😩", (EmojiId(98), EmojiId(98))),
+        ("🇧🇲", (EmojiId(3539), EmojiId(3539))),
+        ("🧑🏿", (EmojiId(523), EmojiId(518))),
+        ("🖖🏾", (EmojiId(196), EmojiId(192))),
+        ("🫃🏿", (EmojiId(1303), EmojiId(1298))),
+        ("🇵🇷", (EmojiId(3699), EmojiId(3699))),
+        ("⏏", (EmojiId(3371), EmojiId(3371))),
+        ("🦹🏽\u{200d}♀\u{fe0f}", (EmojiId(1391), EmojiId(1388))),
+        ("🥮", (EmojiId(2665), EmojiId(2665))),
+        ("🧖", (EmojiId(1782), EmojiId(1782))),
+        ("🙌", (EmojiId(372), EmojiId(372))),
+        ("🧔🏻", (EmojiId(537), EmojiId(536))),
+        ("👨🏽", (EmojiId(533), EmojiId(530))),
+        ("🧑🏿\u{200d}🎨", (EmojiId(1063), EmojiId(1058))),
+        ("🧕🏽", (EmojiId(1253), EmojiId(1250))),
+        ("🚣🏾\u{200d}♂\u{fe0f}", (EmojiId(1878), EmojiId(1874))),
+        ("👩\u{200d}🎨", (EmojiId(1070), EmojiId(1070))),
+        ("🏊🏼\u{200d}♀", (EmojiId(1900), EmojiId(1898))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2275), EmojiId(2263))),
+        ("🧑🏿\u{200d}🌾", (EmojiId(919), EmojiId(914))),
+        ("✋🏽", (EmojiId(189), EmojiId(186))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2268), EmojiId(2263))),
+        ("🧔🏾\u{200d}♀\u{fe0f}", (EmojiId(552), EmojiId(548))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2236), EmojiId(2211))),
+        ("🪩", (EmojiId(3002), EmojiId(3002))),
+        ("👆🏾", (EmojiId(304), EmojiId(300))),
+        ("🤽🏽\u{200d}♂", (EmojiId(2006), EmojiId(2003))),
+        ("🦶🏻", (EmojiId(461), EmojiId(460))),
+        ("⚙\u{fe0f}", (EmojiId(3222), EmojiId(3222))),
+        ("🚶\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1557), EmojiId(1557))),
+        ("📓", (EmojiId(3138), EmojiId(3138))),
+        ("🍹", (EmojiId(2698), EmojiId(2698))),
+        ("👍🏻", (EmojiId(331), EmojiId(330))),
+        ("💁🏼\u{200d}♀", (EmojiId(748), EmojiId(746))),
+        ("🇩🇯", (EmojiId(3572), EmojiId(3572))),
+        ("📇", (EmojiId(3186), EmojiId(3186))),
+        ("😲", (EmojiId(82), EmojiId(82))),
+        ("🍃", (EmojiId(2577), EmojiId(2577))),
+        ("🌤", (EmojiId(2911), EmojiId(2911))),
+        ("🙅🏾\u{200d}♂\u{fe0f}", (EmojiId(708), EmojiId(704))),
+        ("🌵", (EmojiId(2570), EmojiId(2570))),
+        ("👰🏽", (EmojiId(1277), EmojiId(1274))),
+        ("👨🏼\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1673), EmojiId(1671))),
+        ("🧘🏽\u{200d}♂\u{fe0f}", (EmojiId(2060), EmojiId(2057))),
+        ("✴", (EmojiId(3413), EmojiId(3413))),
+        ("👱🏿\u{200d}♂\u{fe0f}", (EmojiId(643), EmojiId(638))),
+        ("⛹🏿\u{200d}♀\u{fe0f}", (EmojiId(1921), EmojiId(1916))),
+        ("⛓\u{200d}💥", (EmojiId(3227), EmojiId(3227))),
+        ("💠", (EmojiId(3500), EmojiId(3500))),
+        ("🏳\u{200d}🌈", (EmojiId(3509), EmojiId(3509))),
+        ("✴\u{fe0f}", (EmojiId(3413), EmojiId(3413))),
+        ("🖤", (EmojiId(151), EmojiId(151))),
+        ("🐅", (EmojiId(2445), EmojiId(2445))),
+        ("🦵🏿", (EmojiId(459), EmojiId(454))),
+        ("🤛🏾", (EmojiId(358), EmojiId(354))),
+        ("🤚", (EmojiId(174), EmojiId(174))),
+        ("👮🏿\u{200d}♀\u{fe0f}", (EmojiId(1147), EmojiId(1142))),
+        ("🚁", (EmojiId(2847), EmojiId(2847))),
+        ("🫄🏾", (EmojiId(1308), EmojiId(1304))),
+        ("👩🏽\u{200d}🚀", (EmojiId(1109), EmojiId(1106))),
+        ("👱🏼", (EmojiId(526), EmojiId(524))),
+        ("❔", (EmojiId(3391), EmojiId(3391))),
+        ("🦹🏿\u{200d}♀", (EmojiId(1393), EmojiId(1388))),
+        ("🙇🏿\u{200d}♀", (EmojiId(805), EmojiId(800))),
+        ("🚶🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1558), EmojiId(1557))),
+        ("⚽", (EmojiId(2961), EmojiId(2961))),
+        ("🧖\u{200d}♂", (EmojiId(1788), EmojiId(1788))),
+        ("🚊", (EmojiId(2790), EmojiId(2790))),
+        ("☺\u{fe0f}", (EmojiId(19), EmojiId(19))),
+        ("🍯", (EmojiId(2688), EmojiId(2688))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2271), EmojiId(2263))),
+        ("🦸\u{200d}♂\u{fe0f}", (EmojiId(1364), EmojiId(1364))),
+        ("💇🏻\u{200d}♂", (EmojiId(1516), EmojiId(1515))),
+        ("🐐", (EmojiId(2465), EmojiId(2465))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2095), EmojiId(2081))),
+        ("🆕", (EmojiId(3445), EmojiId(3445))),
+        ("👱🏻\u{200d}♀\u{fe0f}", (EmojiId(633), EmojiId(632))),
+        ("🗣\u{fe0f}", (EmojiId(2418), EmojiId(2418))),
+        ("👷\u{200d}♀\u{fe0f}", (EmojiId(1202), EmojiId(1202))),
+        ("👨🏽\u{200d}✈\u{fe0f}", (EmojiId(1085), EmojiId(1082))),
+        ("🧝🏿\u{200d}♀\u{fe0f}", (EmojiId(1483), EmojiId(1478))),
+        ("🙅🏽\u{200d}♂", (EmojiId(707), EmojiId(704))),
+        ("📒", (EmojiId(3139), EmojiId(3139))),
+        ("👍", (EmojiId(330), EmojiId(330))),
+        ("😵\u{200d}💫", (EmojiId(67), EmojiId(67))),
+        ("🕧", (EmojiId(2864), EmojiId(2864))),
+        ("‼", (EmojiId(3388), EmojiId(3388))),
+        ("🗝\u{fe0f}", (EmojiId(3206), EmojiId(3206))),
+        ("🌓", (EmojiId(2889), EmojiId(2889))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2247), EmojiId(2237))),
+        ("🧑🏽\u{200d}⚕", (EmojiId(845), EmojiId(842))),
+        ("🤝", (EmojiId(396), EmojiId(396))),
+        ("🧙🏽\u{200d}♂", (EmojiId(1403), EmojiId(1400))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2096), EmojiId(2081))),
+        ("🫰🏽", (EmojiId(267), EmojiId(264))),
+        ("🧛🏾\u{200d}♂\u{fe0f}", (EmojiId(1440), EmojiId(1436))),
+        ("🤾🏼\u{200d}♀", (EmojiId(2029), EmojiId(2027))),
+        ("👩\u{200d}🌾", (EmojiId(926), EmojiId(926))),
+        ("💆🏾\u{200d}♂", (EmojiId(1501), EmojiId(1497))),
+        ("👮🏻\u{200d}♀\u{fe0f}", (EmojiId(1143), EmojiId(1142))),
+        ("👨🏿\u{200d}🦯", (EmojiId(1634), EmojiId(1629))),
+        ("🏊", (EmojiId(1886), EmojiId(1886))),
+        ("😧", (EmojiId(87), EmojiId(87))),
+        ("🧚🏼\u{200d}♀\u{fe0f}", (EmojiId(1426), EmojiId(1424))),
+        ("🙇🏼\u{200d}♂", (EmojiId(796), EmojiId(794))),
+        ("🇻🇨", (EmojiId(3756), EmojiId(3756))),
+        ("🏋\u{fe0f}", (EmojiId(1922), EmojiId(1922))),
+        ("🤷🏽\u{200d}♀\u{fe0f}", (EmojiId(839), EmojiId(836))),
+        ("🌎", (EmojiId(2717), EmojiId(2717))),
+        ("👨\u{200d}⚕", (EmojiId(848), EmojiId(848))),
+        ("🪮", (EmojiId(3054), EmojiId(3054))),
+        ("🎅🏻", (EmojiId(1341), EmojiId(1340))),
+        ("🧎\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1611), EmojiId(1611))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2215), EmojiId(2211))),
+        ("🖖", (EmojiId(192), EmojiId(192))),
+        ("🌅", (EmojiId(2771), EmojiId(2771))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏽", (EmojiId(2365), EmojiId(2341))),
+        ("🤸🏼", (EmojiId(1978), EmojiId(1976))),
+        ("🚼", (EmojiId(3288), EmojiId(3288))),
+        ("👨🏽\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1674), EmojiId(1671))),
+        ("💪🏻", (EmojiId(447), EmojiId(446))),
+        ("👀", (EmojiId(489), EmojiId(489))),
+        ("🔺", (EmojiId(3498), EmojiId(3498))),
+        ("♍", (EmojiId(3346), EmojiId(3346))),
+        ("🈺", (EmojiId(3468), EmojiId(3468))),
+        ("🙌🏼", (EmojiId(374), EmojiId(372))),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏻", (EmojiId(2299), EmojiId(2289))),
+        ("👊🏼", (EmojiId(350), EmojiId(348))),
+        ("🙋🏾\u{200d}♂\u{fe0f}", (EmojiId(762), EmojiId(758))),
+        ("💂🏻\u{200d}♂\u{fe0f}", (EmojiId(1173), EmojiId(1172))),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏾", (EmojiId(2201), EmojiId(2185))),
+        ("🧜🏿", (EmojiId(1453), EmojiId(1448))),
+        ("👩🏼\u{200d}🚀", (EmojiId(1108), EmojiId(1106))),
+        ("🚴", (EmojiId(1940), EmojiId(1940))),
+        ("🧘\u{200d}♂", (EmojiId(2057), EmojiId(2057))),
+        ("🤸\u{200d}♂\u{fe0f}", (EmojiId(1982), EmojiId(1982))),
+        ("🫵🏻", (EmojiId(325), EmojiId(324))),
+        ("👨🏽\u{200d}⚕\u{fe0f}", (EmojiId(851), EmojiId(848))),
+        ("🏌🏼\u{200d}♀\u{fe0f}", (EmojiId(1846), EmojiId(1844))),
+        ("🇧🇻", (EmojiId(3546), EmojiId(3546))),
+        ("👻", (EmojiId(113), EmojiId(113))),
+        ("🦸🏻", (EmojiId(1359), EmojiId(1358))),
+        ("🐿", (EmojiId(2480), EmojiId(2480))),
+        ("🫱🏻\u{200d}🫲🏼", (EmojiId(402), EmojiId(396))),
+        ("🧑\u{200d}🚀", (EmojiId(1094), EmojiId(1094))),
+        ("🤹🏻\u{200d}♀\u{fe0f}", (EmojiId(2046), EmojiId(2045))),
+        ("👩\u{200d}🏫", (EmojiId(890), EmojiId(890))),
+        ("📕", (EmojiId(3132), EmojiId(3132))),
+        ("🦎", (EmojiId(2519), EmojiId(2519))),
+        ("🧑🏼\u{200d}🍳", (EmojiId(934), EmojiId(932))),
+        ("🔎", (EmojiId(3125), EmojiId(3125))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏿", (EmojiId(2336), EmojiId(2315))),
+        ("🏷\u{fe0f}", (EmojiId(3147), EmojiId(3147))),
+        ("🧏🏾\u{200d}♀\u{fe0f}", (EmojiId(786), EmojiId(782))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2278), EmojiId(2263))),
+        ("🫴🏼", (EmojiId(218), EmojiId(216))),
+        ("🐴", (EmojiId(2447), EmojiId(2447))),
+        ("🚶🏼\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1553), EmojiId(1551))),
+        ("🇹🇨", (EmojiId(3732), EmojiId(3732))),
+        ("✊🏾", (EmojiId(346), EmojiId(342))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2376), EmojiId(2367))),
+        ("◻", (EmojiId(3489), EmojiId(3489))),
+        ("👋🏾", (EmojiId(172), EmojiId(168))),
+        ("🪟", (EmojiId(3250), EmojiId(3250))),
+        ("🕵\u{fe0f}\u{200d}♀", (EmojiId(1160), EmojiId(1160))),
+        ("🤛🏼", (EmojiId(356), EmojiId(354))),
+        ("👨\u{200d}✈\u{fe0f}", (EmojiId(1082), EmojiId(1082))),
+        ("⛹\u{fe0f}\u{200d}♂\u{fe0f}", (EmojiId(1910), EmojiId(1910))),
+        ("🇵🇭", (EmojiId(3694), EmojiId(3694))),
+        ("🕴🏻", (EmojiId(1774), EmojiId(1773))),
+        ("🏌🏿\u{200d}♂\u{fe0f}", (EmojiId(1843), EmojiId(1838))),
+        ("🇮🇹", (EmojiId(3627), EmojiId(3627))),
+        ("🙋🏽\u{200d}♀\u{fe0f}", (EmojiId(767), EmojiId(764))),
+        ("⛹🏿\u{200d}♀", (EmojiId(1921), EmojiId(1916))),
+        ("🏄🏽\u{200d}♀\u{fe0f}", (EmojiId(1865), EmojiId(1862))),
+        ("👉", (EmojiId(294), EmojiId(294))),
+        ("🧘\u{200d}♀\u{fe0f}", (EmojiId(2063), EmojiId(2063))),
+        ("🧎🏿\u{200d}♂\u{fe0f}", (EmojiId(1592), EmojiId(1587))),
+        ("👨🏼\u{200d}⚖", (EmojiId(904), EmojiId(902))),
+        ("✊🏻", (EmojiId(343), EmojiId(342))),
+        ("🐟", (EmojiId(2529), EmojiId(2529))),
+        ("🧾", (EmojiId(3156), EmojiId(3156))),
+        ("👷🏾\u{200d}♂", (EmojiId(1200), EmojiId(1196))),
+        ("🧎🏿\u{200d}➡\u{fe0f}", (EmojiId(1604), EmojiId(1599))),
+        ("👩🏻\u{200d}✈\u{fe0f}", (EmojiId(1089), EmojiId(1088))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2086), EmojiId(2081))),
+        ("#\u{fe0f}\u{20e3}", (EmojiId(3418), EmojiId(3418))),
+        ("🧑🏽\u{200d}💼", (EmojiId(989), EmojiId(986))),
+        ("🧑🏼\u{200d}🦼", (EmojiId(1655), EmojiId(1653))),
+        ("🧔🏻\u{200d}♂", (EmojiId(543), EmojiId(542))),
+        ("🤦🏿\u{200d}♂", (EmojiId(817), EmojiId(812))),
+        ("💂🏻", (EmojiId(1167), EmojiId(1166))),
+        ("🌄", (EmojiId(2770), EmojiId(2770))),
+        ("👩🏻", (EmojiId(579), EmojiId(578))),
+        ("🤜", (EmojiId(360), EmojiId(360))),
+        ("✂", (EmojiId(3197), EmojiId(3197))),
+        ("🕺🏿", (EmojiId(1772), EmojiId(1767))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2259), EmojiId(2237))),
+        ("🙋🏾\u{200d}♀", (EmojiId(768), EmojiId(764))),
+        ("⛹\u{fe0f}\u{200d}♂", (EmojiId(1910), EmojiId(1910))),
+        ("👳🏾\u{200d}♀", (EmojiId(1242), EmojiId(1238))),
+        ("👱🏻\u{200d}♂", (EmojiId(639), EmojiId(638))),
+        ("👨\u{200d}🦯\u{200d}➡", (EmojiId(1635), EmojiId(1635))),
+        ("👩🏻\u{200d}🦽\u{200d}➡", (EmojiId(1720), EmojiId(1719))),
+        ("🧛🏽\u{200d}♀\u{fe0f}", (EmojiId(1445), EmojiId(1442))),
+        ("👩🏿\u{200d}🌾", (EmojiId(931), EmojiId(926))),
+        ("👰🏻\u{200d}♀\u{fe0f}", (EmojiId(1287), EmojiId(1286))),
+        ("🧗🏽", (EmojiId(1803), EmojiId(1800))),
+        ("💁🏿\u{200d}♀\u{fe0f}", (EmojiId(751), EmojiId(746))),
+        ("🗑\u{fe0f}", (EmojiId(3200), EmojiId(3200))),
+        ("↩\u{fe0f}", (EmojiId(3317), EmojiId(3317))),
+        ("👨🏼\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1709), EmojiId(1707))),
+        ("🧖🏿\u{200d}♂", (EmojiId(1793), EmojiId(1788))),
+        ("👰🏿\u{200d}♂\u{fe0f}", (EmojiId(1285), EmojiId(1280))),
+        ("🫵🏼", (EmojiId(326), EmojiId(324))),
+        ("🫲🏿", (EmojiId(209), EmojiId(204))),
+        ("🏄🏽", (EmojiId(1853), EmojiId(1850))),
+        ("🇷🇸", (EmojiId(3707), EmojiId(3707))),
+        ("🔥", (EmojiId(2931), EmojiId(2931))),
+        ("🧚🏼\u{200d}♀", (EmojiId(1426), EmojiId(1424))),
+        ("↗", (EmojiId(3308), EmojiId(3308))),
+        ("🧖🏻", (EmojiId(1783), EmojiId(1782))),
+        ("🍡", (EmojiId(2666), EmojiId(2666))),
+        ("🛜", (EmojiId(3376), EmojiId(3376))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏾", (EmojiId(2331), EmojiId(2315))),
+        ("👑", (EmojiId(3055), EmojiId(3055))),
+        ("🚶\u{200d}♀\u{200d}➡", (EmojiId(1551), EmojiId(1551))),
+        ("♐", (EmojiId(3349), EmojiId(3349))),
+        ("🏃🏾\u{200d}♂", (EmojiId(1735), EmojiId(1731))),
+        ("🧎🏽\u{200d}♀", (EmojiId(1596), EmojiId(1593))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2371), EmojiId(2367))),
+        ("🌐", (EmojiId(2719), EmojiId(2719))),
+        ("✌🏾", (EmojiId(256), EmojiId(252))),
+        ("🐬", (EmojiId(2527), EmojiId(2527))),
+        ("☮", (EmojiId(3337), EmojiId(3337))),
+        ("🤱", (EmojiId(1310), EmojiId(1310))),
+        ("🙆🏼\u{200d}♂", (EmojiId(724), EmojiId(722))),
+        ("🏋🏿\u{200d}♀", (EmojiId(1939), EmojiId(1934))),
+        ("☀", (EmojiId(2900), EmojiId(2900))),
+        ("👨🏽\u{200d}🎓", (EmojiId(869), EmojiId(866))),
+        ("👨🏾\u{200d}✈", (EmojiId(1086), EmojiId(1082))),
+        ("🚰", (EmojiId(3283), EmojiId(3283))),
+        ("🐰", (EmojiId(2478), EmojiId(2478))),
+        ("🗂\u{fe0f}", (EmojiId(3181), EmojiId(3181))),
+        ("🦗", (EmojiId(2544), EmojiId(2544))),
+        ("🦵", (EmojiId(454), EmojiId(454))),
+        ("🤵\u{200d}♂", (EmojiId(1262), EmojiId(1262))),
+        ("🏀", (EmojiId(2964), EmojiId(2964))),
+        ("⚪", (EmojiId(3478), EmojiId(3478))),
+        ("🏌🏽\u{200d}♂", (EmojiId(1841), EmojiId(1838))),
+        ("👨\u{200d}🎓", (EmojiId(866), EmojiId(866))),
+        ("🧏🏾\u{200d}♀", (EmojiId(786), EmojiId(782))),
+        ("💁\u{200d}♂", (EmojiId(740), EmojiId(740))),
+        ("🎓", (EmojiId(3058), EmojiId(3058))),
+        ("💫", (EmojiId(158), EmojiId(158))),
+        ("🐉", (EmojiId(2522), EmojiId(2522))),
+        ("⚒", (EmojiId(3210), EmojiId(3210))),
+        ("⚙", (EmojiId(3222), EmojiId(3222))),
+        ("🪙", (EmojiId(3149), EmojiId(3149))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2284), EmojiId(2263))),
+        ("🚶🏼\u{200d}♂\u{200d}➡", (EmojiId(1559), EmojiId(1557))),
+        ("🦸🏻\u{200d}♂", (EmojiId(1365), EmojiId(1364))),
+        ("🧝🏼\u{200d}♀", (EmojiId(1480), EmojiId(1478))),
+        ("💿", (EmojiId(3112), EmojiId(3112))),
+        ("👩🏼\u{200d}⚖", (EmojiId(910), EmojiId(908))),
+        ("⛹🏾", (EmojiId(1908), EmojiId(1904))),
+        ("🧘🏿\u{200d}♂", (EmojiId(2062), EmojiId(2057))),
+        ("👰\u{200d}♀", (EmojiId(1286), EmojiId(1286))),
+        ("👬🏿", (EmojiId(2164), EmojiId(2159))),
+        ("🤸🏻\u{200d}♀", (EmojiId(1989), EmojiId(1988))),
+        ("👨🏼\u{200d}🍳", (EmojiId(940), EmojiId(938))),
+        ("🚶\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1551), EmojiId(1551))),
+        ("🔶", (EmojiId(3494), EmojiId(3494))),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏾", (EmojiId(2132), EmojiId(2107))),
+        ("🏊\u{200d}♂", (EmojiId(1892), EmojiId(1892))),
+        ("👩\u{200d}🦲", (EmojiId(620), EmojiId(620))),
+        ("🏴\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", (EmojiId(3771), EmojiId(3771))),
+        ("🐑", (EmojiId(2464), EmojiId(2464))),
+        ("👨🏾\u{200d}⚕\u{fe0f}", (EmojiId(852), EmojiId(848))),
+        ("🇧🇫", (EmojiId(3533), EmojiId(3533))),
+        ("⛹🏿\u{200d}♂", (EmojiId(1915), EmojiId(1910))),
+        ("♠", (EmojiId(3004), EmojiId(3004))),
+        ("🤓", (EmojiId(73), EmojiId(73))),
+        ("🧙🏿\u{200d}♂", (EmojiId(1405), EmojiId(1400))),
+        ("🧎🏿\u{200d}♂\u{200d}➡", (EmojiId(1616), EmojiId(1611))),
+        ("🤌🏾", (EmojiId(244), EmojiId(240))),
+        ("☄\u{fe0f}", (EmojiId(2930), EmojiId(2930))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2280), EmojiId(2263))),
+        ("🦹🏾", (EmojiId(1380), EmojiId(1376))),
+        ("👩🏽\u{200d}🏭", (EmojiId(983), EmojiId(980))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2273), EmojiId(2263))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏾", (EmojiId(2383), EmojiId(2367))),
+        ("🏄🏻\u{200d}♂", (EmojiId(1857), EmojiId(1856))),
+        ("🏃\u{200d}♂\u{fe0f}", (EmojiId(1731), EmojiId(1731))),
+        ("👳🏽\u{200d}♀", (EmojiId(1241), EmojiId(1238))),
+        ("🤹🏼", (EmojiId(2035), EmojiId(2033))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2260), EmojiId(2237))),
+        ("👨🏻\u{200d}⚖\u{fe0f}", (EmojiId(903), EmojiId(902))),
+        ("🫲", (EmojiId(204), EmojiId(204))),
+        ("🧑🏿\u{200d}⚕\u{fe0f}", (EmojiId(847), EmojiId(842))),
+        ("🌨\u{fe0f}", (EmojiId(2915), EmojiId(2915))),
+        ("🎙", (EmojiId(3078), EmojiId(3078))),
+        ("🕵🏽", (EmojiId(1151), EmojiId(1148))),
+        ("🙇🏽\u{200d}♀\u{fe0f}", (EmojiId(803), EmojiId(800))),
+        ("🙎🏿\u{200d}♂", (EmojiId(691), EmojiId(686))),
+        ("👩\u{200d}🍳", (EmojiId(944), EmojiId(944))),
+        ("🧑🏻\u{200d}💼", (EmojiId(987), EmojiId(986))),
+        ("🧎🏼\u{200d}♂\u{200d}➡", (EmojiId(1613), EmojiId(1611))),
+        ("🧒🏾", (EmojiId(504), EmojiId(500))),
+        ("🪂", (EmojiId(2845), EmojiId(2845))),
+        ("🧑🏻\u{200d}🔧", (EmojiId(951), EmojiId(950))),
+        ("⛰", (EmojiId(2724), EmojiId(2724))),
+        ("👨🏻\u{200d}💻", (EmojiId(1029), EmojiId(1028))),
+        ("💃🏿", (EmojiId(1766), EmojiId(1761))),
+        ("🏄🏽\u{200d}♀", (EmojiId(1865), EmojiId(1862))),
+        ("💽", (EmojiId(3110), EmojiId(3110))),
+        ("🇳🇺", (EmojiId(3687), EmojiId(3687))),
+        ("🧑🏽\u{200d}🦯", (EmojiId(1620), EmojiId(1617))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2214), EmojiId(2211))),
+        ("🤛🏻", (EmojiId(355), EmojiId(354))),
+        ("㊗", (EmojiId(3466), EmojiId(3466))),
+        ("🙅🏼", (EmojiId(700), EmojiId(698))),
+        ("✒\u{fe0f}", (EmojiId(3172), EmojiId(3172))),
+        ("🕵🏾\u{200d}♀", (EmojiId(1164), EmojiId(1160))),
+        ("🧙🏼\u{200d}♀\u{fe0f}", (EmojiId(1408), EmojiId(1406))),
+        ("🇸🇹", (EmojiId(3726), EmojiId(3726))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", (EmojiId(2202), EmojiId(2185))),
+        ("🚣\u{200d}♂\u{fe0f}", (EmojiId(1874), EmojiId(1874))),
+        ("💆🏻\u{200d}♀", (EmojiId(1504), EmojiId(1503))),
+        ("🛥", (EmojiId(2839), EmojiId(2839))),
+        ("🧚\u{200d}♀", (EmojiId(1424), EmojiId(1424))),
+        ("↪", (EmojiId(3318), EmojiId(3318))),
+        ("◼\u{fe0f}", (EmojiId(3488), EmojiId(3488))),
+        ("🏇", (EmojiId(1819), EmojiId(1819))),
+        ("📧", (EmojiId(3159), EmojiId(3159))),
+        ("👨🏿\u{200d}⚖", (EmojiId(907), EmojiId(902))),
+        ("🙋🏿", (EmojiId(757), EmojiId(752))),
+        ("🧠", (EmojiId(484), EmojiId(484))),
+        ("💃", (EmojiId(1761), EmojiId(1761))),
+        ("🚵", (EmojiId(1958), EmojiId(1958))),
+        ("👨🏿\u{200d}🎓", (EmojiId(871), EmojiId(866))),
+        ("☸\u{fe0f}", (EmojiId(3332), EmojiId(3332))),
+        ("🤜🏻", (EmojiId(361), EmojiId(360))),
+        ("👩\u{200d}👩\u{200d}👧\u{200d}👧", (EmojiId(2407), EmojiId(2407))),
+        ("💅🏾", (EmojiId(438), EmojiId(434))),
+        ("👩🏿\u{200d}🎓", (EmojiId(877), EmojiId(872))),
+        ("👊🏽", (EmojiId(351), EmojiId(348))),
+        ("🧜🏻\u{200d}♀\u{fe0f}", (EmojiId(1461), EmojiId(1460))),
+        ("👳\u{200d}♀", (EmojiId(1238), EmojiId(1238))),
+        ("👨🏽\u{200d}🦳", (EmojiId(569), EmojiId(566))),
+        ("🇰🇾", (EmojiId(3641), EmojiId(3641))),
+        ("⏺", (EmojiId(3370), EmojiId(3370))),
+        ("💆🏽\u{200d}♂", (EmojiId(1500), EmojiId(1497))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2349), EmojiId(2341))),
+        ("🤸🏾", (EmojiId(1980), EmojiId(1976))),
+        ("🤲🏿", (EmojiId(395), EmojiId(390))),
+        ("🔝", (EmojiId(3327), EmojiId(3327))),
+        ("👏🏼", (EmojiId(368), EmojiId(366))),
+        ("🍴", (EmojiId(2711), EmojiId(2711))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2278), EmojiId(2263))),
+        ("🚶🏿\u{200d}♂", (EmojiId(1538), EmojiId(1533))),
+        ("☺", (EmojiId(19), EmojiId(19))),
+        ("👨🏾\u{200d}💼", (EmojiId(996), EmojiId(992))),
+        ("👔", (EmojiId(3024), EmojiId(3024))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏿", (EmojiId(2376), EmojiId(2367))),
+        ("🫱🏿\u{200d}🫲🏻", (EmojiId(418), EmojiId(396))),
+        ("👨🏼\u{200d}🍼", (EmojiId(1324), EmojiId(1322))),
+        ("🤷🏾", (EmojiId(828), EmojiId(824))),
+        ("🧚🏼\u{200d}♂", (EmojiId(1420), EmojiId(1418))),
+        ("🙇🏿", (EmojiId(793), EmojiId(788))),
+        ("🫱🏾", (EmojiId(202), EmojiId(198))),
+        ("🙎\u{200d}♀", (EmojiId(692), EmojiId(692))),
+        ("✊", (EmojiId(342), EmojiId(342))),
+        ("🇪🇬", (EmojiId(3580), EmojiId(3580))),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏿", (EmojiId(2124), EmojiId(2107))),
+        ("🌫\u{fe0f}", (EmojiId(2918), EmojiId(2918))),
+        ("💁", (EmojiId(734), EmojiId(734))),
+        ("👩\u{200d}👧\u{200d}👦", (EmojiId(2416), EmojiId(2416))),
+        ("🕛", (EmojiId(2863), EmojiId(2863))),
+        ("🧔🏼\u{200d}♂", (EmojiId(544), EmojiId(542))),
+        ("👬🏼", (EmojiId(2161), EmojiId(2159))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2269), EmojiId(2263))),
+        ("🧚🏻\u{200d}♀", (EmojiId(1425), EmojiId(1424))),
+        ("👱🏼\u{200d}♂\u{fe0f}", (EmojiId(640), EmojiId(638))),
+        ("🕵🏻\u{200d}♀", (EmojiId(1161), EmojiId(1160))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2281), EmojiId(2263))),
+        ("🚶\u{200d}♀\u{fe0f}", (EmojiId(1539), EmojiId(1539))),
+        ("🙎🏿", (EmojiId(685), EmojiId(680))),
+        ("🔮", (EmojiId(2993), EmojiId(2993))),
+        ("💰", (EmojiId(3148), EmojiId(3148))),
+        ("🙆🏾\u{200d}♀\u{fe0f}", (EmojiId(732), EmojiId(728))),
+        ("🧖🏻\u{200d}♂\u{fe0f}", (EmojiId(1789), EmojiId(1788))),
+        ("🫱🏿\u{200d}🫲🏼", (EmojiId(419), EmojiId(396))),
+        ("💊", (EmojiId(3242), EmojiId(3242))),
+        ("🧛\u{200d}♀", (EmojiId(1442), EmojiId(1442))),
+        ("🏃🏼\u{200d}♀\u{fe0f}", (EmojiId(1739), EmojiId(1737))),
+        ("🏌\u{200d}♂", (EmojiId(1838), EmojiId(1838))),
+        ("👮🏿\u{200d}♂", (EmojiId(1141), EmojiId(1136))),
+        ("👷🏽\u{200d}♀", (EmojiId(1205), EmojiId(1202))),
+        ("🔟", (EmojiId(3430), EmojiId(3430))),
+        ("🦅", (EmojiId(2503), EmojiId(2503))),
+        ("🏃\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1749), EmojiId(1749))),
+        ("🍉", (EmojiId(2583), EmojiId(2583))),
+        ("💋", (EmojiId(154), EmojiId(154))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2353), EmojiId(2341))),
+        ("⛹🏽\u{200d}♂\u{fe0f}", (EmojiId(1913), EmojiId(1910))),
+        ("⛹🏿", (EmojiId(1909), EmojiId(1904))),
+        ("👋🏽", (EmojiId(171), EmojiId(168))),
+        ("🕵🏼", (EmojiId(1150), EmojiId(1148))),
+        ("🟥", (EmojiId(3479), EmojiId(3479))),
+        ("👷🏿\u{200d}♂", (EmojiId(1201), EmojiId(1196))),
+        ("🏃🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1760), EmojiId(1755))),
+        ("📵", (EmojiId(3303), EmojiId(3303))),
+        ("✍🏼", (EmojiId(430), EmojiId(428))),
+        ("🤽🏻\u{200d}♀", (EmojiId(2010), EmojiId(2009))),
+        ("👩🏽\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1686), EmojiId(1683))),
+        ("🚵🏼\u{200d}♂", (EmojiId(1966), EmojiId(1964))),
+        ("🥫", (EmojiId(2652), EmojiId(2652))),
+        ("😊", (EmojiId(12), EmojiId(12))),
+        ("👩🏽\u{200d}🏫", (EmojiId(893), EmojiId(890))),
+        ("👷🏼\u{200d}♀", (EmojiId(1204), EmojiId(1202))),
+        ("⏪", (EmojiId(3362), EmojiId(3362))),
+        ("🅿\u{fe0f}", (EmojiId(3449), EmojiId(3449))),
+        ("⛺", (EmojiId(2766), EmojiId(2766))),
+        ("🇸🇲", (EmojiId(3721), EmojiId(3721))),
+        ("🤹\u{200d}♂\u{fe0f}", (EmojiId(2039), EmojiId(2039))),
+        ("🇮🇲", (EmojiId(3621), EmojiId(3621))),
+        ("🧑\u{200d}🦽\u{200d}➡", (EmojiId(1695), EmojiId(1695))),
+        ("🇮🇩", (EmojiId(3618), EmojiId(3618))),
+        ("🤖", (EmojiId(116), EmojiId(116))),
+        ("🍞", (EmojiId(2619), EmojiId(2619))),
+        ("🖍\u{fe0f}", (EmojiId(3176), EmojiId(3176))),
+        ("🧃", (EmojiId(2706), EmojiId(2706))),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏼", (EmojiId(2204), EmojiId(2185))),
+        ("👱🏾\u{200d}♂\u{fe0f}", (EmojiId(642), EmojiId(638))),
+        ("🏃🏽\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1752), EmojiId(1749))),
+        ("🕵🏻\u{200d}♀\u{fe0f}", (EmojiId(1161), EmojiId(1160))),
+        ("🟤", (EmojiId(3476), EmojiId(3476))),
+        ("🚄", (EmojiId(2784), EmojiId(2784))),
+        ("🧜🏿\u{200d}♂\u{fe0f}", (EmojiId(1459), EmojiId(1454))),
+        ("🦸🏽", (EmojiId(1361), EmojiId(1358))),
+        ("💁🏾\u{200d}♀", (EmojiId(750), EmojiId(746))),
+        ("🧝🏻\u{200d}♂\u{fe0f}", (EmojiId(1473), EmojiId(1472))),
+        ("🐦", (EmojiId(2500), EmojiId(2500))),
+        ("🧝🏼\u{200d}♀\u{fe0f}", (EmojiId(1480), EmojiId(1478))),
+        ("🤷🏼\u{200d}♂", (EmojiId(832), EmojiId(830))),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏼", (EmojiId(2152), EmojiId(2133))),
+        ("👨🏻\u{200d}🍳", (EmojiId(939), EmojiId(938))),
+        ("🌃", (EmojiId(2768), EmojiId(2768))),
+        ("♨", (EmojiId(2775), EmojiId(2775))),
+        ("🧶", (EmojiId(3017), EmojiId(3017))),
+        ("🏮", (EmojiId(3129), EmojiId(3129))),
+        ("🧗🏽\u{200d}♀", (EmojiId(1815), EmojiId(1812))),
+        ("🪵", (EmojiId(2737), EmojiId(2737))),
+        ("🪷", (EmojiId(2556), EmojiId(2556))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏼", (EmojiId(2386), EmojiId(2367))),
+        ("🧖🏻\u{200d}♀", (EmojiId(1795), EmojiId(1794))),
+        ("🌜", (EmojiId(2898), EmojiId(2898))),
+        ("👉🏾", (EmojiId(298), EmojiId(294))),
+        ("🏋🏿\u{200d}♂", (EmojiId(1933), EmojiId(1928))),
+        ("🤸🏼\u{200d}♂", (EmojiId(1984), EmojiId(1982))),
+        ("🙍🏻\u{200d}♀", (EmojiId(675), EmojiId(674))),
+        ("👩🏽\u{200d}🍼", (EmojiId(1319), EmojiId(1316))),
+        ("🧎🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1614), EmojiId(1611))),
+        ("🦬", (EmojiId(2454), EmojiId(2454))),
+        ("📻", (EmojiId(3083), EmojiId(3083))),
+        ("🚵🏿", (EmojiId(1963), EmojiId(1958))),
+        ("🧑🏽\u{200d}🎤", (EmojiId(1043), EmojiId(1040))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏼", (EmojiId(2325), EmojiId(2315))),
+        ("🎅", (EmojiId(1340), EmojiId(1340))),
+        ("♎", (EmojiId(3347), EmojiId(3347))),
+        ("👮🏼\u{200d}♀", (EmojiId(1144), EmojiId(1142))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2094), EmojiId(2081))),
+        ("🧗🏼\u{200d}♂\u{fe0f}", (EmojiId(1808), EmojiId(1806))),
+        ("🇮🇶", (EmojiId(3624), EmojiId(3624))),
+        ("💂🏽\u{200d}♀", (EmojiId(1181), EmojiId(1178))),
+        ("🧎🏽\u{200d}♀\u{fe0f}", (EmojiId(1596), EmojiId(1593))),
+        ("👩🏼\u{200d}🎓", (EmojiId(874), EmojiId(872))),
+        ("👳🏾", (EmojiId(1230), EmojiId(1226))),
+        ("🐖", (EmojiId(2460), EmojiId(2460))),
+        ("🐙", (EmojiId(2533), EmojiId(2533))),
+        ("🤦🏾\u{200d}♀", (EmojiId(822), EmojiId(818))),
+        ("🇲🇶", (EmojiId(3667), EmojiId(3667))),
+        ("🔲", (EmojiId(3503), EmojiId(3503))),
+        ("🤘🏻", (EmojiId(277), EmojiId(276))),
+        ("✌\u{fe0f}", (EmojiId(252), EmojiId(252))),
+        ("🧍🏾", (EmojiId(1567), EmojiId(1563))),
+        ("👋🏻", (EmojiId(169), EmojiId(168))),
+        ("💆🏻", (EmojiId(1492), EmojiId(1491))),
+        ("🤲", (EmojiId(390), EmojiId(390))),
+        ("🦮", (EmojiId(2434), EmojiId(2434))),
+        ("🤱🏻", (EmojiId(1311), EmojiId(1310))),
+        ("👬🏻", (EmojiId(2160), EmojiId(2159))),
+        ("🧎\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1605), EmojiId(1605))),
+        ("🦹", (EmojiId(1376), EmojiId(1376))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2321), EmojiId(2315))),
+        ("🎗\u{fe0f}", (EmojiId(2952), EmojiId(2952))),
+        ("👩🏾\u{200d}🦳", (EmojiId(612), EmojiId(608))),
+        ("🇭🇳", (EmojiId(3613), EmojiId(3613))),
+        ("🧔\u{200d}♀\u{fe0f}", (EmojiId(548), EmojiId(548))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", (EmojiId(2197), EmojiId(2185))),
+        ("🤷\u{200d}♀\u{fe0f}", (EmojiId(836), EmojiId(836))),
+        ("🕵🏻", (EmojiId(1149), EmojiId(1148))),
+        ("🚶🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1555), EmojiId(1551))),
+        ("👩🏾\u{200d}⚖", (EmojiId(912), EmojiId(908))),
+        ("👷🏽\u{200d}♀\u{fe0f}", (EmojiId(1205), EmojiId(1202))),
+        ("🅰", (EmojiId(3436), EmojiId(3436))),
+        ("🌁", (EmojiId(2767), EmojiId(2767))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏾", (EmojiId(2314), EmojiId(2289))),
+        ("⛹\u{200d}♂", (EmojiId(1910), EmojiId(1910))),
+        ("🚶🏼\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1559), EmojiId(1557))),
+        ("💆🏻\u{200d}♀\u{fe0f}", (EmojiId(1504), EmojiId(1503))),
+        ("🇦🇪", (EmojiId(3514), EmojiId(3514))),
+        ("♦\u{fe0f}", (EmojiId(3006), EmojiId(3006))),
+        ("⛹🏼", (EmojiId(1906), EmojiId(1904))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2097), EmojiId(2081))),
+        ("🦇", (EmojiId(2483), EmojiId(2483))),
+        ("🧑\u{200d}⚕\u{fe0f}", (EmojiId(842), EmojiId(842))),
+        ("🈁", (EmojiId(3453), EmojiId(3453))),
+        ("🧊", (EmojiId(2708), EmojiId(2708))),
+        ("👼🏿", (EmojiId(1339), EmojiId(1334))),
+        ("🤹\u{200d}♀", (EmojiId(2045), EmojiId(2045))),
+        ("🇨🇾", (EmojiId(3568), EmojiId(3568))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏽", (EmojiId(2296), EmojiId(2289))),
+        ("🔳", (EmojiId(3502), EmojiId(3502))),
+        ("🧐", (EmojiId(74), EmojiId(74))),
+        ("🏄\u{200d}♂\u{fe0f}", (EmojiId(1856), EmojiId(1856))),
+        ("😟", (EmojiId(77), EmojiId(77))),
+        ("🤸🏿\u{200d}♂\u{fe0f}", (EmojiId(1987), EmojiId(1982))),
+        ("🧎🏾\u{200d}♀\u{fe0f}", (EmojiId(1597), EmojiId(1593))),
+        ("🧓", (EmojiId(644), EmojiId(644))),
+        ("🫃🏽", (EmojiId(1301), EmojiId(1298))),
+        ("🧗\u{200d}♂", (EmojiId(1806), EmojiId(1806))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", (EmojiId(2210), EmojiId(2185))),
+        ("🙍🏿\u{200d}♀\u{fe0f}", (EmojiId(679), EmojiId(674))),
+        ("🎅🏼", (EmojiId(1342), EmojiId(1340))),
+        ("🏊🏾", (EmojiId(1890), EmojiId(1886))),
+        ("🙇🏿\u{200d}♀\u{fe0f}", (EmojiId(805), EmojiId(800))),
+        ("🤦🏻\u{200d}♂", (EmojiId(813), EmojiId(812))),
+        ("😉", (EmojiId(11), EmojiId(11))),
+        ("🖱", (EmojiId(3108), EmojiId(3108))),
+        ("🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}", (EmojiId(3770), EmojiId(3770))),
+        ("🥄", (EmojiId(2712), EmojiId(2712))),
+        ("☪\u{fe0f}", (EmojiId(3336), EmojiId(3336))),
+        ("🏊🏾\u{200d}♂", (EmojiId(1896), EmojiId(1892))),
+        ("🕯", (EmojiId(3126), EmojiId(3126))),
+        ("🚶🏻\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1558), EmojiId(1557))),
+        ("👩🏻\u{200d}🦽", (EmojiId(1714), EmojiId(1713))),
+        ("👍🏽", (EmojiId(333), EmojiId(330))),
+        ("🧑🏾\u{200d}🚒", (EmojiId(1116), EmojiId(1112))),
+        ("🫙", (EmojiId(2714), EmojiId(2714))),
+        ("👩🏻\u{200d}🔧", (EmojiId(963), EmojiId(962))),
+        ("🏋🏾\u{200d}♀\u{fe0f}", (EmojiId(1938), EmojiId(1934))),
+        ("🎹", (EmojiId(3087), EmojiId(3087))),
+        ("🏥", (EmojiId(2746), EmojiId(2746))),
+        ("👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", (EmojiId(2237), EmojiId(2237))),
+        ("🦹🏼\u{200d}♂\u{fe0f}", (EmojiId(1384), EmojiId(1382))),
+        ("🧗🏿\u{200d}♀", (EmojiId(1817), EmojiId(1812))),
+        ("⚔\u{fe0f}", (EmojiId(3213), EmojiId(3213))),
+        ("💆🏾\u{200d}♀\u{fe0f}", (EmojiId(1507), EmojiId(1503))),
+        ("🧑🏿\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1628), EmojiId(1623))),
+        ("🕺🏾", (EmojiId(1771), EmojiId(1767))),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏻", (EmojiId(2117), EmojiId(2107))),
+        ("⏬", (EmojiId(3367), EmojiId(3367))),
+        ("👮\u{200d}♂\u{fe0f}", (EmojiId(1136), EmojiId(1136))),
+        ("🙆🏿\u{200d}♂", (EmojiId(727), EmojiId(722))),
+        ("🧗\u{200d}♀", (EmojiId(1812), EmojiId(1812))),
+        ("↔", (EmojiId(3316), EmojiId(3316))),
+        ("👦", (EmojiId(506), EmojiId(506))),
+        ("🧑\u{200d}🧑\u{200d}🧒", (EmojiId(2423), EmojiId(2423))),
+        ("⛓\u{fe0f}\u{200d}💥", (EmojiId(3227), EmojiId(3227))),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏿", (EmojiId(2198), EmojiId(2185))),
+        ("🌇", (EmojiId(2773), EmojiId(2773))),
+        ("🔐", (EmojiId(3204), EmojiId(3204))),
+        ("🚴🏾\u{200d}♂", (EmojiId(1950), EmojiId(1946))),
+        ("⛹🏻\u{200d}♂", (EmojiId(1911), EmojiId(1910))),
+        ("🐾", (EmojiId(2493), EmojiId(2493))),
+        ("💅🏼", (EmojiId(436), EmojiId(434))),
+        ("🇰🇲", (EmojiId(3636), EmojiId(3636))),
+        ("🤵\u{200d}♀\u{fe0f}", (EmojiId(1268), EmojiId(1268))),
+        ("🧜🏼\u{200d}♀\u{fe0f}", (EmojiId(1462), EmojiId(1460))),
+        ("🇱🇮", (EmojiId(3646), EmojiId(3646))),
+        ("❤\u{200d}🩹", (EmojiId(141), EmojiId(141))),
+        ("😡", (EmojiId(102), EmojiId(102))),
+        ("👫", (EmojiId(2133), EmojiId(2133))),
+        ("🕞", (EmojiId(2870), EmojiId(2870))),
+        ("👵🏾", (EmojiId(660), EmojiId(656))),
+        ("🧚🏾\u{200d}♀\u{fe0f}", (EmojiId(1428), EmojiId(1424))),
+        ("🦐", (EmojiId(2672), EmojiId(2672))),
+        ("🏃🏻\u{200d}♀\u{200d}➡", (EmojiId(1750), EmojiId(1749))),
+        ("🏇🏻", (EmojiId(1820), EmojiId(1819))),
+        ("🎄", (EmojiId(2935), EmojiId(2935))),
+        ("🤸🏼\u{200d}♀\u{fe0f}", (EmojiId(1990), EmojiId(1988))),
+        ("🫸🏾", (EmojiId(232), EmojiId(228))),
+        ("🤸🏽\u{200d}♂\u{fe0f}", (EmojiId(1985), EmojiId(1982))),
+        ("🇼🇫", (EmojiId(3762), EmojiId(3762))),
+        ("🇦🇽", (EmojiId(3527), EmojiId(3527))),
+        ("🏌🏼", (EmojiId(1834), EmojiId(1832))),
+        ("🧑🏼\u{200d}🔧", (EmojiId(952), EmojiId(950))),
+        ("😻", (EmojiId(120), EmojiId(120))),
+        ("👇", (EmojiId(312), EmojiId(312))),
+        ("🏕", (EmojiId(2727), EmojiId(2727))),
+        ("🛋\u{fe0f}", (EmojiId(3252), EmojiId(3252))),
+        ("🤽🏼\u{200d}♂", (EmojiId(2005), EmojiId(2003))),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏿", (EmojiId(2146), EmojiId(2133))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", (EmojiId(2203), EmojiId(2185))),
+        ("🫳🏼", (EmojiId(212), EmojiId(210))),
+        ("🦩", (EmojiId(2509), EmojiId(2509))),
+        ("🏃🏼\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1751), EmojiId(1749))),
+        ("🚶🏿\u{200d}♀\u{200d}➡", (EmojiId(1556), EmojiId(1551))),
+        ("🎛", (EmojiId(3080), EmojiId(3080))),
+        ("🚶🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1552), EmojiId(1551))),
+        ("👩🏽\u{200d}💼", (EmojiId(1001), EmojiId(998))),
+        ("☦", (EmojiId(3335), EmojiId(3335))),
+        ("🇧🇱", (EmojiId(3538), EmojiId(3538))),
+        ("💇🏼\u{200d}♂\u{fe0f}", (EmojiId(1517), EmojiId(1515))),
+        ("🖼", (EmojiId(3013), EmojiId(3013))),
+        ("🆔", (EmojiId(3443), EmojiId(3443))),
+        ("🤵🏻", (EmojiId(1257), EmojiId(1256))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2274), EmojiId(2263))),
+        ("🚣🏻\u{200d}♂\u{fe0f}", (EmojiId(1875), EmojiId(1874))),
+        ("🇸🇷", (EmojiId(3724), EmojiId(3724))),
+        ("🔣", (EmojiId(3434), EmojiId(3434))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏿", (EmojiId(2310), EmojiId(2289))),
+        ("🚴🏼\u{200d}♀", (EmojiId(1954), EmojiId(1952))),
+        ("🫠", (EmojiId(10), EmojiId(10))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2231), EmojiId(2211))),
+        ("👨🏻\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1636), EmojiId(1635))),
+        ("🧑🏻\u{200d}🎤", (EmojiId(1041), EmojiId(1040))),
+        ("〽\u{fe0f}", (EmojiId(3411), EmojiId(3411))),
+        ("🧑🏿\u{200d}🦱", (EmojiId(607), EmojiId(602))),
+        ("💆\u{200d}♀", (EmojiId(1503), EmojiId(1503))),
+        ("👉🏽", (EmojiId(297), EmojiId(294))),
+        ("🛝", (EmojiId(2777), EmojiId(2777))),
+        ("🤍", (EmojiId(153), EmojiId(153))),
+        ("🏂🏽", (EmojiId(1829), EmojiId(1826))),
+        ("🧎🏾", (EmojiId(1585), EmojiId(1581))),
+        ("🙋🏻\u{200d}♂\u{fe0f}", (EmojiId(759), EmojiId(758))),
+        ("🏎\u{fe0f}", (EmojiId(2811), EmojiId(2811))),
+        ("💦", (EmojiId(159), EmojiId(159))),
+        ("🏋🏻\u{200d}♀", (EmojiId(1935), EmojiId(1934))),
+        ("🙆🏼\u{200d}♀\u{fe0f}", (EmojiId(730), EmojiId(728))),
+        ("☝🏼", (EmojiId(320), EmojiId(318))),
+        ("👩🏼\u{200d}💻", (EmojiId(1036), EmojiId(1034))),
+        ("🫖", (EmojiId(2692), EmojiId(2692))),
+        ("🤦🏻", (EmojiId(807), EmojiId(806))),
+        ("👩🏻\u{200d}🦼", (EmojiId(1678), EmojiId(1677))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2352), EmojiId(2341))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2241), EmojiId(2237))),
+        ("🫱🏽\u{200d}🫲🏻", (EmojiId(410), EmojiId(396))),
+        ("🧑🏼\u{200d}🦱", (EmojiId(604), EmojiId(602))),
+        ("🤏🏻", (EmojiId(247), EmojiId(246))),
+        ("🙎🏽\u{200d}♀\u{fe0f}", (EmojiId(695), EmojiId(692))),
+        ("⬇\u{fe0f}", (EmojiId(3311), EmojiId(3311))),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏻", (EmojiId(2181), EmojiId(2159))),
+        ("👂🏼", (EmojiId(468), EmojiId(466))),
+        ("🧎🏿\u{200d}♀\u{fe0f}", (EmojiId(1598), EmojiId(1593))),
+        ("🚴🏻\u{200d}♂\u{fe0f}", (EmojiId(1947), EmojiId(1946))),
+        ("👩\u{200d}❤\u{200d}👩", (EmojiId(2367), EmojiId(2367))),
+        ("〰", (EmojiId(3394), EmojiId(3394))),
+        ("🤸🏻\u{200d}♂\u{fe0f}", (EmojiId(1983), EmojiId(1982))),
+        ("🧔🏾\u{200d}♂", (EmojiId(546), EmojiId(542))),
+        ("😞", (EmojiId(96), EmojiId(96))),
+        ("☸", (EmojiId(3332), EmojiId(3332))),
+        ("💂🏾", (EmojiId(1170), EmojiId(1166))),
+        ("🧗", (EmojiId(1800), EmojiId(1800))),
+        ("🏃🏾\u{200d}♀", (EmojiId(1741), EmojiId(1737))),
+        ("🙇🏾", (EmojiId(792), EmojiId(788))),
+        ("✍🏿", (EmojiId(433), EmojiId(428))),
+        ("🙅🏽\u{200d}♂\u{fe0f}", (EmojiId(707), EmojiId(704))),
+        ("🧚🏽\u{200d}♂\u{fe0f}", (EmojiId(1421), EmojiId(1418))),
+        ("👩🏾", (EmojiId(582), EmojiId(578))),
+        ("👰🏾\u{200d}♀\u{fe0f}", (EmojiId(1290), EmojiId(1286))),
+        ("👨\u{200d}🔧", (EmojiId(956), EmojiId(956))),
+        ("🧏🏽\u{200d}♀\u{fe0f}", (EmojiId(785), EmojiId(782))),
+        ("💁🏿\u{200d}♂\u{fe0f}", (EmojiId(745), EmojiId(740))),
+        ("💑🏿", (EmojiId(2294), EmojiId(2289))),
+        ("💇🏽\u{200d}♀\u{fe0f}", (EmojiId(1524), EmojiId(1521))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2380), EmojiId(2367))),
+        ("🖲\u{fe0f}", (EmojiId(3109), EmojiId(3109))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2216), EmojiId(2211))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2286), EmojiId(2263))),
+        ("🤠", (EmojiId(69), EmojiId(69))),
+        ("🧝🏽", (EmojiId(1469), EmojiId(1466))),
+        ("🪚", (EmojiId(3218), EmojiId(3218))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2389), EmojiId(2367))),
+        ("🦹🏼\u{200d}♀\u{fe0f}", (EmojiId(1390), EmojiId(1388))),
+        ("🪥", (EmojiId(3268), EmojiId(3268))),
+        ("🏄\u{200d}♂", (EmojiId(1856), EmojiId(1856))),
+        ("🏃\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1755), EmojiId(1755))),
+        ("👩🏽\u{200d}🔧", (EmojiId(965), EmojiId(962))),
+        ("🪨", (EmojiId(2736), EmojiId(2736))),
+        ("👷🏿\u{200d}♂\u{fe0f}", (EmojiId(1201), EmojiId(1196))),
+        ("🙇\u{200d}♂", (EmojiId(794), EmojiId(794))),
+        ("📼", (EmojiId(3123), EmojiId(3123))),
+        ("😈", (EmojiId(105), EmojiId(105))),
+        ("🚵🏿\u{200d}♀", (EmojiId(1975), EmojiId(1970))),
+        ("👩🏾\u{200d}🔧", (EmojiId(966), EmojiId(962))),
+        ("🖍", (EmojiId(3176), EmojiId(3176))),
+        ("🕵🏾\u{200d}♂\u{fe0f}", (EmojiId(1158), EmojiId(1154))),
+        ("✍🏻", (EmojiId(429), EmojiId(428))),
+        ("🧗🏾\u{200d}♂", (EmojiId(1810), EmojiId(1806))),
+        ("🧎🏼\u{200d}♀\u{200d}➡", (EmojiId(1607), EmojiId(1605))),
+        ("💆🏾\u{200d}♀", (EmojiId(1507), EmojiId(1503))),
+        ("💁🏽\u{200d}♂\u{fe0f}", (EmojiId(743), EmojiId(740))),
+        ("🫤", (EmojiId(76), EmojiId(76))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2224), EmojiId(2211))),
+        ("🕺🏼", (EmojiId(1769), EmojiId(1767))),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏼", (EmojiId(2191), EmojiId(2185))),
+        ("🫴🏻", (EmojiId(217), EmojiId(216))),
+        ("💇🏾\u{200d}♀", (EmojiId(1525), EmojiId(1521))),
+        ("🎆", (EmojiId(2936), EmojiId(2936))),
+        ("🌝", (EmojiId(2901), EmojiId(2901))),
+        ("🧝🏽\u{200d}♀\u{fe0f}", (EmojiId(1481), EmojiId(1478))),
+        ("👨🏾\u{200d}🎨", (EmojiId(1068), EmojiId(1064))),
+        ("🟰", (EmojiId(3386), EmojiId(3386))),
+        ("🧎🏻\u{200d}♀\u{200d}➡", (EmojiId(1606), EmojiId(1605))),
+        ("🙄", (EmojiId(45), EmojiId(45))),
+        ("🧑\u{200d}🌾", (EmojiId(914), EmojiId(914))),
+        ("👸🏿", (EmojiId(1225), EmojiId(1220))),
+        ("🚶🏽\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1554), EmojiId(1551))),
+        ("🏛\u{fe0f}", (EmojiId(2733), EmojiId(2733))),
+        ("🏙\u{fe0f}", (EmojiId(2769), EmojiId(2769))),
+        ("🧍🏿\u{200d}♀\u{fe0f}", (EmojiId(1580), EmojiId(1575))),
+        ("🧖🏿\u{200d}♀", (EmojiId(1799), EmojiId(1794))),
+        ("🇮🇱", (EmojiId(3620), EmojiId(3620))),
+        ("🛵", (EmojiId(2813), EmojiId(2813))),
+        ("🍪", (EmojiId(2679), EmojiId(2679))),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏻", (EmojiId(2203), EmojiId(2185))),
+        ("♠\u{fe0f}", (EmojiId(3004), EmojiId(3004))),
+        ("🧑🏼\u{200d}🦲", (EmojiId(628), EmojiId(626))),
+        ("🙇🏽\u{200d}♀", (EmojiId(803), EmojiId(800))),
+        ("🧑\u{200d}🧑\u{200d}🧒\u{200d}🧒", (EmojiId(2424), EmojiId(2424))),
+        ("💁🏻\u{200d}♀\u{fe0f}", (EmojiId(747), EmojiId(746))),
+        ("👃🏾", (EmojiId(482), EmojiId(478))),
+        ("🤷🏻", (EmojiId(825), EmojiId(824))),
+        ("4\u{fe0f}\u{20e3}", (EmojiId(3424), EmojiId(3424))),
+        ("🎈", (EmojiId(2940), EmojiId(2940))),
+        ("🚸", (EmojiId(3295), EmojiId(3295))),
+        ("🏡", (EmojiId(2742), EmojiId(2742))),
+        ("🏄🏾\u{200d}♂\u{fe0f}", (EmojiId(1860), EmojiId(1856))),
+        ("🏕\u{fe0f}", (EmojiId(2727), EmojiId(2727))),
+        ("🌔", (EmojiId(2890), EmojiId(2890))),
+        ("🦸🏿\u{200d}♀", (EmojiId(1375), EmojiId(1370))),
+        ("📗", (EmojiId(3134), EmojiId(3134))),
+        ("💶", (EmojiId(3152), EmojiId(3152))),
+        ("👃🏿", (EmojiId(483), EmojiId(478))),
+        ("🚶🏼\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1559), EmojiId(1557))),
+        ("🐔", (EmojiId(2495), EmojiId(2495))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2390), EmojiId(2367))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏾", (EmojiId(2327), EmojiId(2315))),
+        ("👂🏻", (EmojiId(467), EmojiId(466))),
+        ("🧎🏼\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1607), EmojiId(1605))),
+        ("🈵", (EmojiId(3469), EmojiId(3469))),
+        ("☯\u{fe0f}", (EmojiId(3333), EmojiId(3333))),
+        ("👲🏻", (EmojiId(1245), EmojiId(1244))),
+        ("🍤", (EmojiId(2663), EmojiId(2663))),
+        ("🎼", (EmojiId(3075), EmojiId(3075))),
+        ("👷🏻", (EmojiId(1191), EmojiId(1190))),
+        ("🫵", (EmojiId(324), EmojiId(324))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏻", (EmojiId(2363), EmojiId(2341))),
+        ("📣", (EmojiId(3071), EmojiId(3071))),
+        ("🏋🏾\u{200d}♀", (EmojiId(1938), EmojiId(1934))),
+        ("⛹🏾\u{200d}♀", (EmojiId(1920), EmojiId(1916))),
+        ("🧕", (EmojiId(1250), EmojiId(1250))),
+        ("🍾", (EmojiId(2695), EmojiId(2695))),
+        ("🟨", (EmojiId(3481), EmojiId(3481))),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏻", (EmojiId(2303), EmojiId(2289))),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏻", (EmojiId(2143), EmojiId(2133))),
+        ("🇩🇴", (EmojiId(3575), EmojiId(3575))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏼", (EmojiId(2321), EmojiId(2315))),
+        ("👩🏽\u{200d}🦯\u{200d}➡", (EmojiId(1650), EmojiId(1647))),
+        ("👩🏿\u{200d}🦽", (EmojiId(1718), EmojiId(1713))),
+        ("🩼", (EmojiId(3244), EmojiId(3244))),
+        ("🏃🏻\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1756), EmojiId(1755))),
+        ("🇺🇾", (EmojiId(3753), EmojiId(3753))),
+        ("👙", (EmojiId(3037), EmojiId(3037))),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏼", (EmojiId(2308), EmojiId(2289))),
+        ("⤴\u{fe0f}", (EmojiId(3319), EmojiId(3319))),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏼", (EmojiId(2295), EmojiId(2289))),
+        ("👘", (EmojiId(3032), EmojiId(3032))),
+        ("👨\u{200d}🎨", (EmojiId(1064), EmojiId(1064))),
+        ("🇫🇯", (EmojiId(3587), EmojiId(3587))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2330), EmojiId(2315))),
+        ("🤹🏽\u{200d}♂", (EmojiId(2042), EmojiId(2039))),
+        ("▶\u{fe0f}", (EmojiId(3357), EmojiId(3357))),
+        ("🏃🏽\u{200d}♂\u{200d}➡", (EmojiId(1758), EmojiId(1755))),
+        ("🧙\u{200d}♂\u{fe0f}", (EmojiId(1400), EmojiId(1400))),
+        ("🔵", (EmojiId(3474), EmojiId(3474))),
+        ("👮🏾\u{200d}♂", (EmojiId(1140), EmojiId(1136))),
+        ("🇬🇧", (EmojiId(3593), EmojiId(3593))),
+        ("🩹", (EmojiId(3243), EmojiId(3243))),
+        ("🍅", (EmojiId(2598), EmojiId(2598))),
+        ("😃", (EmojiId(1), EmojiId(1))),
+        ("🚶🏿\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1562), EmojiId(1557))),
+        ("🇰🇿", (EmojiId(3642), EmojiId(3642))),
+        ("🧘🏾\u{200d}♂\u{fe0f}", (EmojiId(2061), EmojiId(2057))),
+        ("🇸🇽", (EmojiId(3728), EmojiId(3728))),
+        ("🫱🏿", (EmojiId(203), EmojiId(198))),
+        ("🇦🇨", (EmojiId(3512), EmojiId(3512))),
+        ("🕰\u{fe0f}", (EmojiId(2862), EmojiId(2862))),
+        ("🛰", (EmojiId(2851), EmojiId(2851))),
+        ("▪", (EmojiId(3492), EmojiId(3492))),
+        ("🏌🏽\u{200d}♀", (EmojiId(1847), EmojiId(1844))),
+        ("🇨🇵", (EmojiId(3562), EmojiId(3562))),
+        ("👳🏾\u{200d}♀\u{fe0f}", (EmojiId(1242), EmojiId(1238))),
+        ("🏃🏻\u{200d}➡\u{fe0f}", (EmojiId(1744), EmojiId(1743))),
+        ("🧎🏼\u{200d}➡", (EmojiId(1601), EmojiId(1599))),
+        ("🫴🏾", (EmojiId(220), EmojiId(216))),
+        ("🏄🏼\u{200d}♀", (EmojiId(1864), EmojiId(1862))),
+        ("⛹\u{200d}♂\u{fe0f}", (EmojiId(1910), EmojiId(1910))),
+        ("🤽🏾\u{200d}♂\u{fe0f}", (EmojiId(2007), EmojiId(2003))),
+        ("🙇🏿\u{200d}♂\u{fe0f}", (EmojiId(799), EmojiId(794))),
+        ("🏷", (EmojiId(3147), EmojiId(3147))),
+        ("👩🏾\u{200d}🔬", (EmojiId(1020), EmojiId(1016))),
+        ("🌺", (EmojiId(2560), EmojiId(2560))),
+        ("👨🏽\u{200d}🦲", (EmojiId(575), EmojiId(572))),
+        ("🧑\u{200d}🎤", (EmojiId(1040), EmojiId(1040))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏿", (EmojiId(2328), EmojiId(2315))),
+        ("🪛", (EmojiId(3220), EmojiId(3220))),
+        ("👩", (EmojiId(578), EmojiId(578))),
+        ("👩\u{200d}🔬", (EmojiId(1016), EmojiId(1016))),
+        ("👩🏿\u{200d}🏭", (EmojiId(985), EmojiId(980))),
+        ("🈂", (EmojiId(3454), EmojiId(3454))),
+        ("🛌🏾", (EmojiId(2079), EmojiId(2075))),
+        ("🥷", (EmojiId(1184), EmojiId(1184))),
+        ("🧙🏻\u{200d}♀", (EmojiId(1407), EmojiId(1406))),
+        ("🚣🏾\u{200d}♂", (EmojiId(1878), EmojiId(1874))),
+        ("♾", (EmojiId(3387), EmojiId(3387))),
+        ("🏳\u{200d}⚧\u{fe0f}", (EmojiId(3510), EmojiId(3510))),
+        ("🕐", (EmojiId(2865), EmojiId(2865))),
+        ("🇰🇪", (EmojiId(3632), EmojiId(3632))),
+        ("💅🏽", (EmojiId(437), EmojiId(434))),
+        ("👨🏽\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1638), EmojiId(1635))),
+        ("🌟", (EmojiId(2905), EmojiId(2905))),
+        ("👶", (EmojiId(494), EmojiId(494))),
+        ("🏰", (EmojiId(2755), EmojiId(2755))),
+        ("🧎🏼\u{200d}♀\u{fe0f}", (EmojiId(1595), EmojiId(1593))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2342), EmojiId(2341))),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏽", (EmojiId(2157), EmojiId(2133))),
+        ("🚶🏻", (EmojiId(1528), EmojiId(1527))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2250), EmojiId(2237))),
+        ("🛀", (EmojiId(2069), EmojiId(2069))),
+        ("🏃🏾\u{200d}♀\u{200d}➡", (EmojiId(1753), EmojiId(1749))),
+        ("🚴🏿\u{200d}♂\u{fe0f}", (EmojiId(1951), EmojiId(1946))),
+        ("🤸\u{200d}♀\u{fe0f}", (EmojiId(1988), EmojiId(1988))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2387), EmojiId(2367))),
+        ("⚖\u{fe0f}", (EmojiId(3224), EmojiId(3224))),
+        ("🤵🏾\u{200d}♂\u{fe0f}", (EmojiId(1266), EmojiId(1262))),
+        ("👷🏿", (EmojiId(1195), EmojiId(1190))),
+        ("💑", (EmojiId(2289), EmojiId(2289))),
+        ("🧑🏻\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1660), EmojiId(1659))),
+        ("💂🏾\u{200d}♀", (EmojiId(1182), EmojiId(1178))),
+        ("🔡", (EmojiId(3432), EmojiId(3432))),
+        ("🦶🏽", (EmojiId(463), EmojiId(460))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2220), EmojiId(2211))),
+        ("😱", (EmojiId(93), EmojiId(93))),
+        ("👎🏿", (EmojiId(341), EmojiId(336))),
+        ("🛀🏾", (EmojiId(2073), EmojiId(2069))),
+        ("🧔🏿\u{200d}♀", (EmojiId(553), EmojiId(548))),
+        ("👏🏾", (EmojiId(370), EmojiId(366))),
+        ("🤵🏿\u{200d}♀\u{fe0f}", (EmojiId(1273), EmojiId(1268))),
+        ("👩\u{200d}👩\u{200d}👧\u{200d}👦", (EmojiId(2405), EmojiId(2405))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏻", (EmojiId(2389), EmojiId(2367))),
+        ("🧙\u{200d}♀\u{fe0f}", (EmojiId(1406), EmojiId(1406))),
+        ("👩🏻\u{200d}⚖", (EmojiId(909), EmojiId(908))),
+        ("🧑🏿\u{200d}🦳", (EmojiId(619), EmojiId(614))),
+        ("👴🏼", (EmojiId(652), EmojiId(650))),
+        ("🤏🏽", (EmojiId(249), EmojiId(246))),
+        ("💁🏻", (EmojiId(735), EmojiId(734))),
+        ("👭", (EmojiId(2107), EmojiId(2107))),
+        ("🇳🇨", (EmojiId(3678), EmojiId(3678))),
+        ("🧛🏻\u{200d}♀\u{fe0f}", (EmojiId(1443), EmojiId(1442))),
+        ("💂🏿", (EmojiId(1171), EmojiId(1166))),
+        ("🙅\u{200d}♂", (EmojiId(704), EmojiId(704))),
+        ("😙", (EmojiId(21), EmojiId(21))),
+        ("👨🏾", (EmojiId(534), EmojiId(530))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2226), EmojiId(2211))),
+        ("🇳🇴", (EmojiId(3684), EmojiId(3684))),
+        ("🏃🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1759), EmojiId(1755))),
+        ("💁🏼", (EmojiId(736), EmojiId(734))),
+        ("🧑🏽\u{200d}🎓", (EmojiId(863), EmojiId(860))),
+        ("🙋🏾\u{200d}♀\u{fe0f}", (EmojiId(768), EmojiId(764))),
+        ("🧑\u{200d}🦼\u{200d}➡", (EmojiId(1659), EmojiId(1659))),
+        ("👨🏼\u{200d}🌾", (EmojiId(922), EmojiId(920))),
+        ("🇹🇦", (EmojiId(3731), EmojiId(3731))),
+        ("🇪🇪", (EmojiId(3579), EmojiId(3579))),
+        ("🇨🇩", (EmojiId(3552), EmojiId(3552))),
+        ("🙆🏼\u{200d}♂\u{fe0f}", (EmojiId(724), EmojiId(722))),
+        ("🫱🏼", (EmojiId(200), EmojiId(198))),
+        ("👨🏿\u{200d}🦰", (EmojiId(559), EmojiId(554))),
+        ("▪\u{fe0f}", (EmojiId(3492), EmojiId(3492))),
+        ("🤵🏻\u{200d}♂", (EmojiId(1263), EmojiId(1262))),
+        ("👮🏼\u{200d}♀\u{fe0f}", (EmojiId(1144), EmojiId(1142))),
+        ("6\u{fe0f}\u{20e3}", (EmojiId(3426), EmojiId(3426))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2338), EmojiId(2315))),
+        ("👨🏻\u{200d}🎓", (EmojiId(867), EmojiId(866))),
+        ("🎧", (EmojiId(3082), EmojiId(3082))),
+        ("🙆🏾", (EmojiId(720), EmojiId(716))),
+        ("👩🏻\u{200d}💼", (EmojiId(999), EmojiId(998))),
+        ("👸🏾", (EmojiId(1224), EmojiId(1220))),
+        ("🔈", (EmojiId(3067), EmojiId(3067))),
+        ("🚷", (EmojiId(3302), EmojiId(3302))),
+        ("🤣", (EmojiId(6), EmojiId(6))),
+        ("🏃🏾\u{200d}➡\u{fe0f}", (EmojiId(1747), EmojiId(1743))),
+        ("🧎🏽\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1614), EmojiId(1611))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2264), EmojiId(2263))),
+        ("🐓", (EmojiId(2496), EmojiId(2496))),
+        ("🇻🇺", (EmojiId(3761), EmojiId(3761))),
+        ("🦹🏻\u{200d}♀\u{fe0f}", (EmojiId(1389), EmojiId(1388))),
+        ("🏌🏾", (EmojiId(1836), EmojiId(1832))),
+        ("🧎🏿\u{200d}♀", (EmojiId(1598), EmojiId(1593))),
+        ("🙎🏾\u{200d}♂\u{fe0f}", (EmojiId(690), EmojiId(686))),
+        ("👨🏻\u{200d}✈\u{fe0f}", (EmojiId(1083), EmojiId(1082))),
+        ("👨\u{200d}🦳", (EmojiId(566), EmojiId(566))),
+        ("👩🏿\u{200d}🏫", (EmojiId(895), EmojiId(890))),
+        ("🧙🏿\u{200d}♀\u{fe0f}", (EmojiId(1411), EmojiId(1406))),
+        ("🧑\u{200d}🦽", (EmojiId(1689), EmojiId(1689))),
+        ("🌡\u{fe0f}", (EmojiId(2899), EmojiId(2899))),
+        ("💁🏽", (EmojiId(737), EmojiId(734))),
+        ("🛞", (EmojiId(2826), EmojiId(2826))),
+        ("🏃🏿", (EmojiId(1730), EmojiId(1725))),
+        ("🤾🏼\u{200d}♂\u{fe0f}", (EmojiId(2023), EmojiId(2021))),
+        ("💇\u{200d}♀", (EmojiId(1521), EmojiId(1521))),
+        ("🚴🏻\u{200d}♀", (EmojiId(1953), EmojiId(1952))),
+        ("🤹🏽\u{200d}♂\u{fe0f}", (EmojiId(2042), EmojiId(2039))),
+        ("🤷🏼\u{200d}♀", (EmojiId(838), EmojiId(836))),
+        ("🏃🏽\u{200d}♀\u{fe0f}", (EmojiId(1740), EmojiId(1737))),
+        ("😪", (EmojiId(54), EmojiId(54))),
+        ("©\u{fe0f}", (EmojiId(3415), EmojiId(3415))),
+        ("🐘", (EmojiId(2470), EmojiId(2470))),
+        ("🤵🏽\u{200d}♂", (EmojiId(1265), EmojiId(1262))),
+        ("👮🏾\u{200d}♀\u{fe0f}", (EmojiId(1146), EmojiId(1142))),
+        ("👨🏾\u{200d}🦯", (EmojiId(1633), EmojiId(1629))),
+        ("🤶🏽", (EmojiId(1349), EmojiId(1346))),
+        ("✡\u{fe0f}", (EmojiId(3331), EmojiId(3331))),
+        ("⛹\u{fe0f}\u{200d}♀", (EmojiId(1916), EmojiId(1916))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏼", (EmojiId(2334), EmojiId(2315))),
+        ("🗜", (EmojiId(3223), EmojiId(3223))),
+        ("🕵🏾\u{200d}♀\u{fe0f}", (EmojiId(1164), EmojiId(1160))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2343), EmojiId(2341))),
+        ("🦿", (EmojiId(453), EmojiId(453))),
+        ("🧑🏾\u{200d}🌾", (EmojiId(918), EmojiId(914))),
+        ("🫛", (EmojiId(2617), EmojiId(2617))),
+        ("🧑🏾", (EmojiId(522), EmojiId(518))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏻", (EmojiId(2359), EmojiId(2341))),
+        ("🪞", (EmojiId(3249), EmojiId(3249))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2364), EmojiId(2341))),
+        ("🤦🏿\u{200d}♀\u{fe0f}", (EmojiId(823), EmojiId(818))),
+        ("🧯", (EmojiId(3270), EmojiId(3270))),
+        ("♟\u{fe0f}", (EmojiId(3008), EmojiId(3008))),
+        ("🍓", (EmojiId(2595), EmojiId(2595))),
+        ("🇰🇬", (EmojiId(3633), EmojiId(3633))),
+        ("😑", (EmojiId(39), EmojiId(39))),
+        ("🖇", (EmojiId(3194), EmojiId(3194))),
+        ("🙅🏼\u{200d}♀\u{fe0f}", (EmojiId(712), EmojiId(710))),
+        ("⏱", (EmojiId(2860), EmojiId(2860))),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏻", (EmojiId(2151), EmojiId(2133))),
+        ("🧛🏾\u{200d}♀", (EmojiId(1446), EmojiId(1442))),
+        ("💅", (EmojiId(434), EmojiId(434))),
+        ("💇🏿\u{200d}♀\u{fe0f}", (EmojiId(1526), EmojiId(1521))),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏽", (EmojiId(2296), EmojiId(2289))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2085), EmojiId(2081))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏻", (EmojiId(2355), EmojiId(2341))),
+        ("💏🏿", (EmojiId(2190), EmojiId(2185))),
+        ("😕", (EmojiId(75), EmojiId(75))),
+        ("🏵", (EmojiId(2557), EmojiId(2557))),
+        ("🦄", (EmojiId(2451), EmojiId(2451))),
+        ("🧜🏾\u{200d}♀", (EmojiId(1464), EmojiId(1460))),
+        ("💁🏻\u{200d}♀", (EmojiId(747), EmojiId(746))),
+        ("🐕\u{200d}🦺", (EmojiId(2435), EmojiId(2435))),
+        ("🇲🇪", (EmojiId(3657), EmojiId(3657))),
+        ("🗿", (EmojiId(3278), EmojiId(3278))),
+        ("👨\u{200d}💻", (EmojiId(1028), EmojiId(1028))),
+        ("👱🏾\u{200d}♂", (EmojiId(642), EmojiId(638))),
+        ("👷🏻\u{200d}♂", (EmojiId(1197), EmojiId(1196))),
+        ("🦁", (EmojiId(2443), EmojiId(2443))),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏽", (EmojiId(2118), EmojiId(2107))),
+        ("👨🏻\u{200d}🏭", (EmojiId(975), EmojiId(974))),
+        ("😶\u{200d}🌫\u{fe0f}", (EmojiId(42), EmojiId(42))),
+        ("🎻", (EmojiId(3089), EmojiId(3089))),
+        ("🤵🏽\u{200d}♂\u{fe0f}", (EmojiId(1265), EmojiId(1262))),
+        ("🫢", (EmojiId(31), EmojiId(31))),
+        ("🧑🏻\u{200d}⚖\u{fe0f}", (EmojiId(897), EmojiId(896))),
+        ("🏃🏻\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1756), EmojiId(1755))),
+        ("🎬", (EmojiId(3118), EmojiId(3118))),
+        ("🧖🏿\u{200d}♀\u{fe0f}", (EmojiId(1799), EmojiId(1794))),
+        ("🦸🏼\u{200d}♀\u{fe0f}", (EmojiId(1372), EmojiId(1370))),
+        ("🧑🏿\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1664), EmojiId(1659))),
+        ("🇧🇬", (EmojiId(3534), EmojiId(3534))),
+        ("👨🏻\u{200d}🦳", (EmojiId(567), EmojiId(566))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏿", (EmojiId(2306), EmojiId(2289))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2261), EmojiId(2237))),
+        ("🏃🏽\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1752), EmojiId(1749))),
+        ("🚱", (EmojiId(3301), EmojiId(3301))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2277), EmojiId(2263))),
+        ("🥵", (EmojiId(63), EmojiId(63))),
+        ("🇰🇭", (EmojiId(3634), EmojiId(3634))),
+        ("🏃🏿\u{200d}➡", (EmojiId(1748), EmojiId(1743))),
+        ("🇴🇲", (EmojiId(3689), EmojiId(3689))),
+        ("🏃\u{200d}➡\u{fe0f}", (EmojiId(1743), EmojiId(1743))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2220), EmojiId(2211))),
+        ("👨\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1707), EmojiId(1707))),
+        ("👩🏼\u{200d}🍼", (EmojiId(1318), EmojiId(1316))),
+        ("👩🏽\u{200d}🦲", (EmojiId(623), EmojiId(620))),
+        ("👉🏿", (EmojiId(299), EmojiId(294))),
+        ("⬇", (EmojiId(3311), EmojiId(3311))),
+        ("😺", (EmojiId(117), EmojiId(117))),
+        ("🧗🏾\u{200d}♂\u{fe0f}", (EmojiId(1810), EmojiId(1806))),
+        ("🧑🏻\u{200d}🔬", (EmojiId(1005), EmojiId(1004))),
+        ("🧑🏼\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1697), EmojiId(1695))),
+        ("🚴\u{200d}♂\u{fe0f}", (EmojiId(1946), EmojiId(1946))),
+        ("🌧", (EmojiId(2914), EmojiId(2914))),
+        ("🧑🏻\u{200d}🍳", (EmojiId(933), EmojiId(932))),
+        ("♀", (EmojiId(3379), EmojiId(3379))),
+        ("🧬", (EmojiId(3236), EmojiId(3236))),
+        ("🫰", (EmojiId(264), EmojiId(264))),
+        ("👁", (EmojiId(490), EmojiId(490))),
+        ("🧑", (EmojiId(518), EmojiId(518))),
+        ("🚵\u{200d}♀\u{fe0f}", (EmojiId(1970), EmojiId(1970))),
+        ("🫵🏿", (EmojiId(329), EmojiId(324))),
+        ("☘", (EmojiId(2573), EmojiId(2573))),
+        ("🇹🇰", (EmojiId(3738), EmojiId(3738))),
+        ("👩🏾\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1687), EmojiId(1683))),
+        ("🌯", (EmojiId(2638), EmojiId(2638))),
+        ("🏸", (EmojiId(2976), EmojiId(2976))),
+        ("👜", (EmojiId(3041), EmojiId(3041))),
+        ("🦼", (EmojiId(2815), EmojiId(2815))),
+        ("🏋🏽\u{200d}♂", (EmojiId(1931), EmojiId(1928))),
+        ("🩶", (EmojiId(152), EmojiId(152))),
+        ("🤹🏼\u{200d}♀\u{fe0f}", (EmojiId(2047), EmojiId(2045))),
+        ("🏃🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1751), EmojiId(1749))),
+        ("🧑🏾\u{200d}🚀", (EmojiId(1098), EmojiId(1094))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2316), EmojiId(2315))),
+        ("🧝🏻\u{200d}♂", (EmojiId(1473), EmojiId(1472))),
+        ("👰🏼\u{200d}♂", (EmojiId(1282), EmojiId(1280))),
+        ("💂🏾\u{200d}♀\u{fe0f}", (EmojiId(1182), EmojiId(1178))),
+        ("😍", (EmojiId(15), EmojiId(15))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2082), EmojiId(2081))),
+        ("🌩", (EmojiId(2916), EmojiId(2916))),
+        ("📲", (EmojiId(3096), EmojiId(3096))),
+        ("🚵🏽\u{200d}♂", (EmojiId(1967), EmojiId(1964))),
+        ("👩🏾\u{200d}🚒", (EmojiId(1128), EmojiId(1124))),
+        ("👩\u{200d}❤\u{200d}💋\u{200d}👨", (EmojiId(2211), EmojiId(2211))),
+        ("🎅🏿", (EmojiId(1345), EmojiId(1340))),
+        ("🐕", (EmojiId(2433), EmojiId(2433))),
+        ("👨🏾\u{200d}🦲", (EmojiId(576), EmojiId(572))),
+        ("🧝🏼\u{200d}♂", (EmojiId(1474), EmojiId(1472))),
+        ("🏌🏻\u{200d}♀", (EmojiId(1845), EmojiId(1844))),
+        ("👱🏿\u{200d}♀\u{fe0f}", (EmojiId(637), EmojiId(632))),
+        ("🧙🏼", (EmojiId(1396), EmojiId(1394))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2329), EmojiId(2315))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏽", (EmojiId(2361), EmojiId(2341))),
+        ("🤘🏾", (EmojiId(280), EmojiId(276))),
+        ("🥷🏽", (EmojiId(1187), EmojiId(1184))),
+        ("🎌", (EmojiId(3506), EmojiId(3506))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2392), EmojiId(2367))),
+        ("♦", (EmojiId(3006), EmojiId(3006))),
+        ("🧏🏿\u{200d}♀\u{fe0f}", (EmojiId(787), EmojiId(782))),
+        ("🧗🏻", (EmojiId(1801), EmojiId(1800))),
+        ("🧙🏾\u{200d}♀\u{fe0f}", (EmojiId(1410), EmojiId(1406))),
+        ("🧑🏾\u{200d}🎤", (EmojiId(1044), EmojiId(1040))),
+        ("📜", (EmojiId(3141), EmojiId(3141))),
+        ("🙋🏻\u{200d}♀\u{fe0f}", (EmojiId(765), EmojiId(764))),
+        ("🧝🏽\u{200d}♂", (EmojiId(1475), EmojiId(1472))),
+        ("🚶\u{200d}♂\u{fe0f}", (EmojiId(1533), EmojiId(1533))),
+        ("🏋\u{fe0f}\u{200d}♂\u{fe0f}", (EmojiId(1928), EmojiId(1928))),
+        ("👩\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1647), EmojiId(1647))),
+        ("🏄\u{200d}♀", (EmojiId(1862), EmojiId(1862))),
+        ("🧷", (EmojiId(3261), EmojiId(3261))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2388), EmojiId(2367))),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏿", (EmojiId(2180), EmojiId(2159))),
+        ("🧝🏻", (EmojiId(1467), EmojiId(1466))),
+        ("🏄🏿\u{200d}♀", (EmojiId(1867), EmojiId(1862))),
+        ("🪗", (EmojiId(3085), EmojiId(3085))),
+        ("💷", (EmojiId(3153), EmojiId(3153))),
+        ("🤗", (EmojiId(29), EmojiId(29))),
+        ("🧜🏾", (EmojiId(1452), EmojiId(1448))),
+        ("🇳🇦", (EmojiId(3677), EmojiId(3677))),
+        ("🧑🏼\u{200d}🍼", (EmojiId(1330), EmojiId(1328))),
+        ("🏌🏿", (EmojiId(1837), EmojiId(1832))),
+        ("🕴🏽", (EmojiId(1776), EmojiId(1773))),
+        ("👨🏾\u{200d}⚖\u{fe0f}", (EmojiId(906), EmojiId(902))),
+        ("👳🏼\u{200d}♀", (EmojiId(1240), EmojiId(1238))),
+        ("🇱🇸", (EmojiId(3649), EmojiId(3649))),
+        ("🚶🏽\u{200d}♂", (EmojiId(1536), EmojiId(1533))),
+        ("🍘", (EmojiId(2654), EmojiId(2654))),
+        ("🙍🏻\u{200d}♂", (EmojiId(669), EmojiId(668))),
+        ("👨🏾\u{200d}🔬", (EmojiId(1014), EmojiId(1010))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2336), EmojiId(2315))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏻", (EmojiId(2303), EmojiId(2289))),
+        ("💹", (EmojiId(3157), EmojiId(3157))),
+        ("🟩", (EmojiId(3482), EmojiId(3482))),
+        ("👨🏾\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1675), EmojiId(1671))),
+        ("🤹🏿\u{200d}♂", (EmojiId(2044), EmojiId(2039))),
+        ("👩🏼\u{200d}🦯", (EmojiId(1643), EmojiId(1641))),
+        ("🧛🏽\u{200d}♂\u{fe0f}", (EmojiId(1439), EmojiId(1436))),
+        ("🛌🏼", (EmojiId(2077), EmojiId(2075))),
+        ("🇹🇳", (EmojiId(3741), EmojiId(3741))),
+        ("🙆🏽\u{200d}♀", (EmojiId(731), EmojiId(728))),
+        ("🤒", (EmojiId(58), EmojiId(58))),
+        ("⏱\u{fe0f}", (EmojiId(2860), EmojiId(2860))),
+        ("🔭", (EmojiId(3238), EmojiId(3238))),
+        ("🧑🏽\u{200d}🦽", (EmojiId(1692), EmojiId(1689))),
+        ("🧍🏾\u{200d}♂\u{fe0f}", (EmojiId(1573), EmojiId(1569))),
+        ("🕵🏿\u{200d}♀", (EmojiId(1165), EmojiId(1160))),
+        ("🙋🏽\u{200d}♂\u{fe0f}", (EmojiId(761), EmojiId(758))),
+        ("🚗", (EmojiId(2804), EmojiId(2804))),
+        ("🚺", (EmojiId(3286), EmojiId(3286))),
+        ("🥷🏿", (EmojiId(1189), EmojiId(1184))),
+        ("👨🏽\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1710), EmojiId(1707))),
+        ("🤾🏼\u{200d}♀\u{fe0f}", (EmojiId(2029), EmojiId(2027))),
+        ("☀\u{fe0f}", (EmojiId(2900), EmojiId(2900))),
+        ("👩🏿\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1724), EmojiId(1719))),
+        ("🧖\u{200d}♀\u{fe0f}", (EmojiId(1794), EmojiId(1794))),
+        ("🧑🏾\u{200d}⚖", (EmojiId(900), EmojiId(896))),
+        ("🎚\u{fe0f}", (EmojiId(3079), EmojiId(3079))),
+        ("🏆", (EmojiId(2956), EmojiId(2956))),
+        ("🏃🏽\u{200d}➡", (EmojiId(1746), EmojiId(1743))),
+        ("🧑🏼\u{200d}✈\u{fe0f}", (EmojiId(1078), EmojiId(1076))),
+        ("🤵🏻\u{200d}♂\u{fe0f}", (EmojiId(1263), EmojiId(1262))),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏼", (EmojiId(2178), EmojiId(2159))),
+        ("🥿", (EmojiId(3049), EmojiId(3049))),
+        ("🤷🏿\u{200d}♂", (EmojiId(835), EmojiId(830))),
+        ("⛽", (EmojiId(2825), EmojiId(2825))),
+        ("🚶🏿\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1556), EmojiId(1551))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏼", (EmojiId(2360), EmojiId(2341))),
+        ("✒", (EmojiId(3172), EmojiId(3172))),
+        ("👩🏼\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1649), EmojiId(1647))),
+        ("👰🏿", (EmojiId(1279), EmojiId(1274))),
+        ("🙁", (EmojiId(78), EmojiId(78))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2285), EmojiId(2263))),
+        ("🚶🏿\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1556), EmojiId(1551))),
+        ("🌥", (EmojiId(2912), EmojiId(2912))),
+        ("🚶🏼\u{200d}♂", (EmojiId(1535), EmojiId(1533))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2350), EmojiId(2341))),
+        ("🕳\u{fe0f}", (EmojiId(161), EmojiId(161))),
+        ("🧜🏾\u{200d}♂\u{fe0f}", (EmojiId(1458), EmojiId(1454))),
+        ("🧔🏿\u{200d}♀\u{fe0f}", (EmojiId(553), EmojiId(548))),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏿", (EmojiId(2176), EmojiId(2159))),
+        ("🧝\u{200d}♂\u{fe0f}", (EmojiId(1472), EmojiId(1472))),
+        ("🏂🏾", (EmojiId(1830), EmojiId(1826))),
+        ("🏃🏼\u{200d}♀\u{200d}➡", (EmojiId(1751), EmojiId(1749))),
+        ("⚜", (EmojiId(3399), EmojiId(3399))),
+        ("🦸🏻\u{200d}♂\u{fe0f}", (EmojiId(1365), EmojiId(1364))),
+        ("🍖", (EmojiId(2628), EmojiId(2628))),
+        ("✌🏼", (EmojiId(254), EmojiId(252))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2251), EmojiId(2237))),
+        ("🏃🏻\u{200d}♀", (EmojiId(1738), EmojiId(1737))),
+        ("👨🏿\u{200d}🚀", (EmojiId(1105), EmojiId(1100))),
+        ("🏌🏻\u{200d}♂", (EmojiId(1839), EmojiId(1838))),
+        ("📺", (EmojiId(3119), EmojiId(3119))),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏽", (EmojiId(2313), EmojiId(2289))),
+        ("🇬🇹", (EmojiId(3607), EmojiId(3607))),
+        ("🥢", (EmojiId(2709), EmojiId(2709))),
+        ("🤾🏽\u{200d}♂\u{fe0f}", (EmojiId(2024), EmojiId(2021))),
+        ("🙆🏿\u{200d}♀\u{fe0f}", (EmojiId(733), EmojiId(728))),
+        ("🇧🇷", (EmojiId(3543), EmojiId(3543))),
+        ("👨🏾\u{200d}💻", (EmojiId(1032), EmojiId(1028))),
+        ("🧒🏿", (EmojiId(505), EmojiId(500))),
+        ("🧑🏽\u{200d}🏫", (EmojiId(881), EmojiId(878))),
+        ("🆖", (EmojiId(3446), EmojiId(3446))),
+        ("🧑🏿\u{200d}⚖", (EmojiId(901), EmojiId(896))),
+        ("📽\u{fe0f}", (EmojiId(3117), EmojiId(3117))),
+        ("🧍🏻\u{200d}♂\u{fe0f}", (EmojiId(1570), EmojiId(1569))),
+        ("👨🏻\u{200d}🔬", (EmojiId(1011), EmojiId(1010))),
+        ("🍝", (EmojiId(2659), EmojiId(2659))),
+        ("💂🏻\u{200d}♀\u{fe0f}", (EmojiId(1179), EmojiId(1178))),
+        ("💇🏻\u{200d}♂\u{fe0f}", (EmojiId(1516), EmojiId(1515))),
+        ("😣", (EmojiId(95), EmojiId(95))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏻", (EmojiId(2377), EmojiId(2367))),
+        ("🥰", (EmojiId(14), EmojiId(14))),
+        ("🏞\u{fe0f}", (EmojiId(2731), EmojiId(2731))),
+        ("🩲", (EmojiId(3035), EmojiId(3035))),
+        ("🤚🏻", (EmojiId(175), EmojiId(174))),
+        ("🚋", (EmojiId(2793), EmojiId(2793))),
+        ("🚟", (EmojiId(2848), EmojiId(2848))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏼", (EmojiId(2351), EmojiId(2341))),
+        ("🫰🏻", (EmojiId(265), EmojiId(264))),
+        ("🫒", (EmojiId(2599), EmojiId(2599))),
+        ("🌧\u{fe0f}", (EmojiId(2914), EmojiId(2914))),
+        ("🧝🏾\u{200d}♂", (EmojiId(1476), EmojiId(1472))),
+        ("🎞\u{fe0f}", (EmojiId(3116), EmojiId(3116))),
+        ("🧘🏻\u{200d}♀", (EmojiId(2064), EmojiId(2063))),
+        ("🐠", (EmojiId(2530), EmojiId(2530))),
+        ("🍵", (EmojiId(2693), EmojiId(2693))),
+        ("🏄\u{200d}♀\u{fe0f}", (EmojiId(1862), EmojiId(1862))),
+        ("☪", (EmojiId(3336), EmojiId(3336))),
+        ("🔬", (EmojiId(3237), EmojiId(3237))),
+        ("👩🏿\u{200d}🦽\u{200d}➡", (EmojiId(1724), EmojiId(1719))),
+        ("🤾🏽\u{200d}♀\u{fe0f}", (EmojiId(2030), EmojiId(2027))),
+        ("👨🏿\u{200d}🔧", (EmojiId(961), EmojiId(956))),
+        ("👳🏾\u{200d}♂", (EmojiId(1236), EmojiId(1232))),
+        ("🫱🏽\u{200d}🫲🏼", (EmojiId(411), EmojiId(396))),
+        ("🤝🏼", (EmojiId(398), EmojiId(396))),
+        ("🫳🏻", (EmojiId(211), EmojiId(210))),
+        ("🧎🏿\u{200d}♀\u{200d}➡", (EmojiId(1610), EmojiId(1605))),
+        ("☔", (EmojiId(2924), EmojiId(2924))),
+        ("🏤", (EmojiId(2745), EmojiId(2745))),
+        ("🧙🏾\u{200d}♂", (EmojiId(1404), EmojiId(1400))),
+        ("💇", (EmojiId(1509), EmojiId(1509))),
+        ("⛸", (EmojiId(2981), EmojiId(2981))),
+        ("🇰🇷", (EmojiId(3639), EmojiId(3639))),
+        ("🪶", (EmojiId(2508), EmojiId(2508))),
+        ("🦹🏿\u{200d}♂\u{fe0f}", (EmojiId(1387), EmojiId(1382))),
+        ("🇨🇦", (EmojiId(3550), EmojiId(3550))),
+        ("🙍🏼", (EmojiId(664), EmojiId(662))),
+        ("🔗", (EmojiId(3226), EmojiId(3226))),
+        ("💏🏼", (EmojiId(2187), EmojiId(2185))),
+        ("🍆", (EmojiId(2602), EmojiId(2602))),
+        ("👰🏽\u{200d}♂", (EmojiId(1283), EmojiId(1280))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2235), EmojiId(2211))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏻", (EmojiId(2316), EmojiId(2315))),
+        ("🫷🏻", (EmojiId(223), EmojiId(222))),
+        ("🟪", (EmojiId(3484), EmojiId(3484))),
+        ("🟢", (EmojiId(3473), EmojiId(3473))),
+        ("🫵🏽", (EmojiId(327), EmojiId(324))),
+        ("🤯", (EmojiId(68), EmojiId(68))),
+        ("🏦", (EmojiId(2747), EmojiId(2747))),
+        ("👈", (EmojiId(288), EmojiId(288))),
+        ("🫱🏿\u{200d}🫲🏾", (EmojiId(421), EmojiId(396))),
+        ("💮", (EmojiId(2555), EmojiId(2555))),
+        ("🍸", (EmojiId(2697), EmojiId(2697))),
+        ("👐🏻", (EmojiId(385), EmojiId(384))),
+        ("🧑🏿\u{200d}🦽", (EmojiId(1694), EmojiId(1689))),
+        ("🏃🏽\u{200d}♀", (EmojiId(1740), EmojiId(1737))),
+        ("🎲", (EmojiId(2998), EmojiId(2998))),
+        ("💚", (EmojiId(146), EmojiId(146))),
+        ("✍🏽", (EmojiId(431), EmojiId(428))),
+        ("🪴", (EmojiId(2566), EmojiId(2566))),
+        ("🕵\u{200d}♂", (EmojiId(1154), EmojiId(1154))),
+        ("🧑🏼\u{200d}🏫", (EmojiId(880), EmojiId(878))),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏾", (EmojiId(2158), EmojiId(2133))),
+        ("🕵", (EmojiId(1148), EmojiId(1148))),
+        ("🦸🏿\u{200d}♀\u{fe0f}", (EmojiId(1375), EmojiId(1370))),
+        ("🫸🏽", (EmojiId(231), EmojiId(228))),
+        ("🛫", (EmojiId(2843), EmojiId(2843))),
+        ("👌🏻", (EmojiId(235), EmojiId(234))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2217), EmojiId(2211))),
+        ("🇽🇰", (EmojiId(3764), EmojiId(3764))),
+        ("🫂", (EmojiId(2421), EmojiId(2421))),
+        ("🧝🏻\u{200d}♀", (EmojiId(1479), EmojiId(1478))),
+        ("🇲🇻", (EmojiId(3672), EmojiId(3672))),
+        ("👼🏾", (EmojiId(1338), EmojiId(1334))),
+        ("🐃", (EmojiId(2457), EmojiId(2457))),
+        ("🚶🏿\u{200d}♂\u{fe0f}", (EmojiId(1538), EmojiId(1533))),
+        ("👨🏽\u{200d}🚀", (EmojiId(1103), EmojiId(1100))),
+        ("🕵\u{fe0f}\u{200d}♂", (EmojiId(1154), EmojiId(1154))),
+        ("🧜🏽\u{200d}♀\u{fe0f}", (EmojiId(1463), EmojiId(1460))),
+        ("👨🏽\u{200d}⚕", (EmojiId(851), EmojiId(848))),
+        ("🌬", (EmojiId(2919), EmojiId(2919))),
+        ("🔯", (EmojiId(3339), EmojiId(3339))),
+        ("🧗🏿\u{200d}♀\u{fe0f}", (EmojiId(1817), EmojiId(1812))),
+        ("🏹", (EmojiId(3216), EmojiId(3216))),
+        ("🤙🏼", (EmojiId(284), EmojiId(282))),
+        ("🕵\u{200d}♂\u{fe0f}", (EmojiId(1154), EmojiId(1154))),
+        ("💆\u{200d}♀\u{fe0f}", (EmojiId(1503), EmojiId(1503))),
+        ("🐇", (EmojiId(2479), EmojiId(2479))),
+        ("🙇\u{200d}♀\u{fe0f}", (EmojiId(800), EmojiId(800))),
+        ("🏄🏻\u{200d}♂\u{fe0f}", (EmojiId(1857), EmojiId(1856))),
+        ("👨\u{200d}👨\u{200d}👧\u{200d}👧", (EmojiId(2402), EmojiId(2402))),
+        ("👩🏽\u{200d}⚖\u{fe0f}", (EmojiId(911), EmojiId(908))),
+        ("🇹🇲", (EmojiId(3740), EmojiId(3740))),
+        ("🚵🏻\u{200d}♀", (EmojiId(1971), EmojiId(1970))),
+        ("🤽", (EmojiId(1997), EmojiId(1997))),
+        ("🙆🏾\u{200d}♂", (EmojiId(726), EmojiId(722))),
+        ("👱\u{200d}♂", (EmojiId(638), EmojiId(638))),
+        ("🤦\u{200d}♀\u{fe0f}", (EmojiId(818), EmojiId(818))),
+        ("👚", (EmojiId(3038), EmojiId(3038))),
+        ("🛌🏻", (EmojiId(2076), EmojiId(2075))),
+        ("🇸🇬", (EmojiId(3715), EmojiId(3715))),
+        ("💧", (EmojiId(2932), EmojiId(2932))),
+        ("👠", (EmojiId(3050), EmojiId(3050))),
+        ("🙎🏽\u{200d}♂", (EmojiId(689), EmojiId(686))),
+        ("⛹🏻\u{200d}♀", (EmojiId(1917), EmojiId(1916))),
+        ("🏃🏽\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1758), EmojiId(1755))),
+        ("🏃🏻\u{200d}➡", (EmojiId(1744), EmojiId(1743))),
+        ("🇵🇲", (EmojiId(3697), EmojiId(3697))),
+        ("🦹🏽\u{200d}♂\u{fe0f}", (EmojiId(1385), EmojiId(1382))),
+        ("🙇\u{200d}♂\u{fe0f}", (EmojiId(794), EmojiId(794))),
+        ("👃", (EmojiId(478), EmojiId(478))),
+        ("🙅🏾\u{200d}♀\u{fe0f}", (EmojiId(714), EmojiId(710))),
+        ("🌪", (EmojiId(2917), EmojiId(2917))),
+        ("👨🏼\u{200d}⚖\u{fe0f}", (EmojiId(904), EmojiId(902))),
+        ("🙅🏽\u{200d}♀", (EmojiId(713), EmojiId(710))),
+        ("👨\u{200d}🔬", (EmojiId(1010), EmojiId(1010))),
+        ("👨\u{200d}⚕\u{fe0f}", (EmojiId(848), EmojiId(848))),
+        ("🤾🏼\u{200d}♂", (EmojiId(2023), EmojiId(2021))),
+        ("🇧🇶", (EmojiId(3542), EmojiId(3542))),
+        ("🆑", (EmojiId(3439), EmojiId(3439))),
+        ("🙅🏿", (EmojiId(703), EmojiId(698))),
+        ("🚶🏾\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1561), EmojiId(1557))),
+        ("👩\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1683), EmojiId(1683))),
+        ("6\u{20e3}", (EmojiId(3426), EmojiId(3426))),
+        ("🧑🏻\u{200d}💻", (EmojiId(1023), EmojiId(1022))),
+        ("☕", (EmojiId(2691), EmojiId(2691))),
+        ("🙎🏻\u{200d}♀\u{fe0f}", (EmojiId(693), EmojiId(692))),
+        ("🧟\u{200d}♂\u{fe0f}", (EmojiId(1488), EmojiId(1488))),
+        ("🚭", (EmojiId(3299), EmojiId(3299))),
+        ("📍", (EmojiId(3192), EmojiId(3192))),
+        ("🤦🏼\u{200d}♂", (EmojiId(814), EmojiId(812))),
+        ("🔧", (EmojiId(3219), EmojiId(3219))),
+        ("⏯", (EmojiId(3360), EmojiId(3360))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2235), EmojiId(2211))),
+        ("🧺", (EmojiId(3263), EmojiId(3263))),
+        ("👩🏻\u{200d}🔬", (EmojiId(1017), EmojiId(1016))),
+        ("🎊", (EmojiId(2942), EmojiId(2942))),
+        ("🚶🏽\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1560), EmojiId(1557))),
+        ("🤞", (EmojiId(258), EmojiId(258))),
+        ("🧛🏼\u{200d}♂", (EmojiId(1438), EmojiId(1436))),
+        ("🕚", (EmojiId(2885), EmojiId(2885))),
+        ("🧓🏼", (EmojiId(646), EmojiId(644))),
+        ("👩🏻\u{200d}🚀", (EmojiId(1107), EmojiId(1106))),
+        ("🇲🇫", (EmojiId(3658), EmojiId(3658))),
+        ("🚶🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1560), EmojiId(1557))),
+        ("🖕🏾", (EmojiId(310), EmojiId(306))),
+        ("🇦🇮", (EmojiId(3517), EmojiId(3517))),
+        ("🫷🏿", (EmojiId(227), EmojiId(222))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏼", (EmojiId(2338), EmojiId(2315))),
+        ("🙎🏼\u{200d}♀", (EmojiId(694), EmojiId(692))),
+        ("💎", (EmojiId(3065), EmojiId(3065))),
+        ("✡", (EmojiId(3331), EmojiId(3331))),
+        ("👷", (EmojiId(1190), EmojiId(1190))),
+        ("🧝\u{200d}♂", (EmojiId(1472), EmojiId(1472))),
+        ("🇫🇴", (EmojiId(3590), EmojiId(3590))),
+        ("👩\u{200d}🦽\u{200d}➡", (EmojiId(1719), EmojiId(1719))),
+        ("💞", (EmojiId(135), EmojiId(135))),
+        ("🇯🇲", (EmojiId(3629), EmojiId(3629))),
+        ("👮🏽\u{200d}♀\u{fe0f}", (EmojiId(1145), EmojiId(1142))),
+        ("🙍🏿\u{200d}♂", (EmojiId(673), EmojiId(668))),
+        ("🚌", (EmojiId(2794), EmojiId(2794))),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏽", (EmojiId(2131), EmojiId(2107))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏽", (EmojiId(2352), EmojiId(2341))),
+        ("👩🏼\u{200d}💼", (EmojiId(1000), EmojiId(998))),
+        ("🇵🇹", (EmojiId(3701), EmojiId(3701))),
+        ("🤦🏻\u{200d}♂\u{fe0f}", (EmojiId(813), EmojiId(812))),
+        ("🧼", (EmojiId(3266), EmojiId(3266))),
+        ("🤾🏿\u{200d}♀\u{fe0f}", (EmojiId(2032), EmojiId(2027))),
+        ("👰🏻\u{200d}♂\u{fe0f}", (EmojiId(1281), EmojiId(1280))),
+        ("🤞🏾", (EmojiId(262), EmojiId(258))),
+        ("🧜🏽\u{200d}♂\u{fe0f}", (EmojiId(1457), EmojiId(1454))),
+        ("🤴🏿", (EmojiId(1219), EmojiId(1214))),
+        ("🪽", (EmojiId(2512), EmojiId(2512))),
+        ("🩱", (EmojiId(3034), EmojiId(3034))),
+        ("🤾🏻\u{200d}♂", (EmojiId(2022), EmojiId(2021))),
+        ("🕵🏽\u{200d}♀\u{fe0f}", (EmojiId(1163), EmojiId(1160))),
+        ("💂🏽\u{200d}♀\u{fe0f}", (EmojiId(1181), EmojiId(1178))),
+        ("🇪🇷", (EmojiId(3582), EmojiId(3582))),
+        ("🌿", (EmojiId(2572), EmojiId(2572))),
+        ("🧑🏼\u{200d}⚕", (EmojiId(844), EmojiId(842))),
+        ("🇵🇰", (EmojiId(3695), EmojiId(3695))),
+        ("💇🏾\u{200d}♂", (EmojiId(1519), EmojiId(1515))),
+        ("💯", (EmojiId(155), EmojiId(155))),
+        ("🧙\u{200d}♂", (EmojiId(1400), EmojiId(1400))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", (EmojiId(2199), EmojiId(2185))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2383), EmojiId(2367))),
+        ("👗", (EmojiId(3031), EmojiId(3031))),
+        ("👨\u{200d}💼", (EmojiId(992), EmojiId(992))),
+        ("🤽\u{200d}♀\u{fe0f}", (EmojiId(2009), EmojiId(2009))),
+        ("🤥", (EmojiId(48), EmojiId(48))),
+        ("🏇🏼", (EmojiId(1821), EmojiId(1819))),
+        ("👨🏻\u{200d}🍼", (EmojiId(1323), EmojiId(1322))),
+        ("☘\u{fe0f}", (EmojiId(2573), EmojiId(2573))),
+        ("👂", (EmojiId(466), EmojiId(466))),
+        ("🛌🏽", (EmojiId(2078), EmojiId(2075))),
+        ("🎗", (EmojiId(2952), EmojiId(2952))),
+        ("🏏", (EmojiId(2971), EmojiId(2971))),
+        ("🎞", (EmojiId(3116), EmojiId(3116))),
+        ("🍕", (EmojiId(2634), EmojiId(2634))),
+        ("🙅🏼\u{200d}♂\u{fe0f}", (EmojiId(706), EmojiId(704))),
+        ("🕒", (EmojiId(2869), EmojiId(2869))),
+        ("🙋🏿\u{200d}♂\u{fe0f}", (EmojiId(763), EmojiId(758))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏾", (EmojiId(2335), EmojiId(2315))),
+        ("🚽", (EmojiId(3254), EmojiId(3254))),
+        ("🏇🏾", (EmojiId(1823), EmojiId(1819))),
+        ("💆🏻\u{200d}♂\u{fe0f}", (EmojiId(1498), EmojiId(1497))),
+        ("👮🏻\u{200d}♀", (EmojiId(1143), EmojiId(1142))),
+        ("⚕\u{fe0f}", (EmojiId(3397), EmojiId(3397))),
+        ("👨🏽\u{200d}🦱", (EmojiId(563), EmojiId(560))),
+        ("🧎🏻\u{200d}♀", (EmojiId(1594), EmojiId(1593))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2102), EmojiId(2081))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2381), EmojiId(2367))),
+        ("Ⓜ", (EmojiId(3444), EmojiId(3444))),
+        ("🧑🏻\u{200d}⚕\u{fe0f}", (EmojiId(843), EmojiId(842))),
+        ("🧑🏼\u{200d}🌾", (EmojiId(916), EmojiId(914))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2355), EmojiId(2341))),
+        ("🤽🏼\u{200d}♂\u{fe0f}", (EmojiId(2005), EmojiId(2003))),
+        ("👩🏽\u{200d}💻", (EmojiId(1037), EmojiId(1034))),
+        ("🧎\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1611), EmojiId(1611))),
+        ("🥟", (EmojiId(2667), EmojiId(2667))),
+        ("❕", (EmojiId(3392), EmojiId(3392))),
+        ("🥺", (EmojiId(84), EmojiId(84))),
+        ("⤵", (EmojiId(3320), EmojiId(3320))),
+        ("🧑🏽\u{200d}🔧", (EmojiId(953), EmojiId(950))),
+        ("🤹🏾\u{200d}♂\u{fe0f}", (EmojiId(2043), EmojiId(2039))),
+        ("🇵🇦", (EmojiId(3690), EmojiId(3690))),
+        ("🚶🏻\u{200d}♂\u{200d}➡", (EmojiId(1558), EmojiId(1557))),
+        ("😶", (EmojiId(40), EmojiId(40))),
+        ("💆🏾", (EmojiId(1495), EmojiId(1491))),
+        ("🤴🏼", (EmojiId(1216), EmojiId(1214))),
+        ("🏃🏻", (EmojiId(1726), EmojiId(1725))),
+        ("🤼\u{200d}♂\u{fe0f}", (EmojiId(1995), EmojiId(1995))),
+        ("🧖\u{200d}♀", (EmojiId(1794), EmojiId(1794))),
+        ("🪘", (EmojiId(3092), EmojiId(3092))),
+        ("🌭", (EmojiId(2635), EmojiId(2635))),
+        ("👩🏿\u{200d}⚕", (EmojiId(859), EmojiId(854))),
+        ("👨🏽\u{200d}🦯\u{200d}➡", (EmojiId(1638), EmojiId(1635))),
+        ("🙌🏿", (EmojiId(377), EmojiId(372))),
+        ("🏯", (EmojiId(2754), EmojiId(2754))),
+        ("👩🏾\u{200d}🦼\u{200d}➡", (EmojiId(1687), EmojiId(1683))),
+        ("💡", (EmojiId(3127), EmojiId(3127))),
+        ("🎇", (EmojiId(2937), EmojiId(2937))),
+        ("📳", (EmojiId(3377), EmojiId(3377))),
+        ("💁🏾\u{200d}♂\u{fe0f}", (EmojiId(744), EmojiId(740))),
+        ("👐", (EmojiId(384), EmojiId(384))),
+        ("👩🏻\u{200d}🦯", (EmojiId(1642), EmojiId(1641))),
+        ("🇹🇷", (EmojiId(3743), EmojiId(3743))),
+        ("🇸🇾", (EmojiId(3729), EmojiId(3729))),
+        ("🧎🏽\u{200d}♂\u{fe0f}", (EmojiId(1590), EmojiId(1587))),
+        ("🙈", (EmojiId(126), EmojiId(126))),
+        ("🇦🇶", (EmojiId(3521), EmojiId(3521))),
+        ("🫷🏽", (EmojiId(225), EmojiId(222))),
+        ("🍄", (EmojiId(2580), EmojiId(2580))),
+        ("⛹🏾\u{200d}♂\u{fe0f}", (EmojiId(1914), EmojiId(1910))),
+        ("🧑🏽\u{200d}🦼\u{200d}➡", (EmojiId(1662), EmojiId(1659))),
+        ("🥻", (EmojiId(3033), EmojiId(3033))),
+        ("💭", (EmojiId(166), EmojiId(166))),
+        ("Ⓜ\u{fe0f}", (EmojiId(3444), EmojiId(3444))),
+        ("🧛🏻\u{200d}♂\u{fe0f}", (EmojiId(1437), EmojiId(1436))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏿", (EmojiId(2332), EmojiId(2315))),
+        ("👳🏽\u{200d}♂\u{fe0f}", (EmojiId(1235), EmojiId(1232))),
+        ("🔙", (EmojiId(3323), EmojiId(3323))),
+        ("🦸🏿\u{200d}♂", (EmojiId(1369), EmojiId(1364))),
+        ("👇🏾", (EmojiId(316), EmojiId(312))),
+        ("🌻", (EmojiId(2561), EmojiId(2561))),
+        ("🗳", (EmojiId(3170), EmojiId(3170))),
+        ("🇭🇰", (EmojiId(3611), EmojiId(3611))),
+        ("🧎🏿\u{200d}➡", (EmojiId(1604), EmojiId(1599))),
+        ("🧜\u{200d}♀\u{fe0f}", (EmojiId(1460), EmojiId(1460))),
+        ("🌘", (EmojiId(2894), EmojiId(2894))),
+        ("👩🏼\u{200d}🦽\u{200d}➡", (EmojiId(1721), EmojiId(1719))),
+        ("🧋", (EmojiId(2705), EmojiId(2705))),
+        ("🖕", (EmojiId(306), EmojiId(306))),
+        ("🗳\u{fe0f}", (EmojiId(3170), EmojiId(3170))),
+        ("🤦\u{200d}♂", (EmojiId(812), EmojiId(812))),
+        ("🤟🏾", (EmojiId(274), EmojiId(270))),
+        ("👮", (EmojiId(1130), EmojiId(1130))),
+        ("♣\u{fe0f}", (EmojiId(3007), EmojiId(3007))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏼", (EmojiId(2382), EmojiId(2367))),
+        ("🎑", (EmojiId(2948), EmojiId(2948))),
+        ("🧑🏾\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1627), EmojiId(1623))),
+        ("🇱🇹", (EmojiId(3650), EmojiId(3650))),
+        ("😮", (EmojiId(80), EmojiId(80))),
+        ("🧙🏾\u{200d}♀", (EmojiId(1410), EmojiId(1406))),
+        ("🙅🏿\u{200d}♂\u{fe0f}", (EmojiId(709), EmojiId(704))),
+        ("⁉\u{fe0f}", (EmojiId(3389), EmojiId(3389))),
+        ("9\u{fe0f}\u{20e3}", (EmojiId(3429), EmojiId(3429))),
+        ("🤵🏾", (EmojiId(1260), EmojiId(1256))),
+        ("👱🏾\u{200d}♀", (EmojiId(636), EmojiId(632))),
+        ("🧎🏿", (EmojiId(1586), EmojiId(1581))),
+        ("🚣🏼", (EmojiId(1870), EmojiId(1868))),
+        ("🏃🏿\u{200d}♀", (EmojiId(1742), EmojiId(1737))),
+        ("🫶🏻", (EmojiId(379), EmojiId(378))),
+        ("🧑\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1623), EmojiId(1623))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2317), EmojiId(2315))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2238), EmojiId(2237))),
+        ("🦸🏾\u{200d}♀", (EmojiId(1374), EmojiId(1370))),
+        ("🦢", (EmojiId(2505), EmojiId(2505))),
+        ("✔", (EmojiId(3406), EmojiId(3406))),
+        ("📰", (EmojiId(3143), EmojiId(3143))),
+        ("🤦\u{200d}♂\u{fe0f}", (EmojiId(812), EmojiId(812))),
+        ("👰", (EmojiId(1274), EmojiId(1274))),
+        ("🤰🏻", (EmojiId(1293), EmojiId(1292))),
+        ("💂🏻\u{200d}♀", (EmojiId(1179), EmojiId(1178))),
+        ("🛀🏼", (EmojiId(2071), EmojiId(2069))),
+        ("🧑🏾\u{200d}✈", (EmojiId(1080), EmojiId(1076))),
+        ("✔\u{fe0f}", (EmojiId(3406), EmojiId(3406))),
+        ("🙇🏻\u{200d}♀\u{fe0f}", (EmojiId(801), EmojiId(800))),
+        ("🏃\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1749), EmojiId(1749))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2384), EmojiId(2367))),
+        ("🏨", (EmojiId(2748), EmojiId(2748))),
+        ("🧴", (EmojiId(3260), EmojiId(3260))),
+        ("👩🏼\u{200d}🦽", (EmojiId(1715), EmojiId(1713))),
+        ("🪕", (EmojiId(3090), EmojiId(3090))),
+        ("🧎🏾\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1615), EmojiId(1611))),
+        ("🪣", (EmojiId(3265), EmojiId(3265))),
+        ("🛂", (EmojiId(3290), EmojiId(3290))),
+        ("🧛🏿\u{200d}♂", (EmojiId(1441), EmojiId(1436))),
+        ("😷", (EmojiId(57), EmojiId(57))),
+        ("🍶", (EmojiId(2694), EmojiId(2694))),
+        ("🧏🏿\u{200d}♂\u{fe0f}", (EmojiId(781), EmojiId(776))),
+        ("🧏🏼", (EmojiId(772), EmojiId(770))),
+        ("🧑🏾\u{200d}🦽", (EmojiId(1693), EmojiId(1689))),
+        ("🫄🏿", (EmojiId(1309), EmojiId(1304))),
+        ("👨\u{200d}🦱", (EmojiId(560), EmojiId(560))),
+        ("🏃🏼\u{200d}➡", (EmojiId(1745), EmojiId(1743))),
+        ("🫳🏿", (EmojiId(215), EmojiId(210))),
+        ("📛", (EmojiId(3401), EmojiId(3401))),
+        ("🧑\u{200d}🧒", (EmojiId(2425), EmojiId(2425))),
+        ("☝", (EmojiId(318), EmojiId(318))),
+        ("🏃🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1750), EmojiId(1749))),
+        ("🙍🏼\u{200d}♀\u{fe0f}", (EmojiId(676), EmojiId(674))),
+        ("🤙🏾", (EmojiId(286), EmojiId(282))),
+        ("📴", (EmojiId(3378), EmojiId(3378))),
+        ("🧛🏾", (EmojiId(1434), EmojiId(1430))),
+        ("👨🏽\u{200d}🦽", (EmojiId(1704), EmojiId(1701))),
+        ("🧝🏾", (EmojiId(1470), EmojiId(1466))),
+        ("👭🏾", (EmojiId(2111), EmojiId(2107))),
+        ("🤲🏽", (EmojiId(393), EmojiId(390))),
+        ("💆🏿\u{200d}♂", (EmojiId(1502), EmojiId(1497))),
+        ("🤦🏼\u{200d}♀\u{fe0f}", (EmojiId(820), EmojiId(818))),
+        ("🧑🏻\u{200d}⚕", (EmojiId(843), EmojiId(842))),
+        ("👭🏿", (EmojiId(2112), EmojiId(2107))),
+        ("🧑🏻\u{200d}🚒", (EmojiId(1113), EmojiId(1112))),
+        ("🙆🏻\u{200d}♂\u{fe0f}", (EmojiId(723), EmojiId(722))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2233), EmojiId(2211))),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏿", (EmojiId(2172), EmojiId(2159))),
+        ("👮\u{200d}♀", (EmojiId(1142), EmojiId(1142))),
+        ("🐪", (EmojiId(2466), EmojiId(2466))),
+        ("🚶🏾\u{200d}♀\u{fe0f}", (EmojiId(1543), EmojiId(1539))),
+        ("🧉", (EmojiId(2707), EmojiId(2707))),
+        ("🤵🏾\u{200d}♂", (EmojiId(1266), EmojiId(1262))),
+        ("🤸🏽\u{200d}♀\u{fe0f}", (EmojiId(1991), EmojiId(1988))),
+        ("👨🏻\u{200d}🦽\u{200d}➡", (EmojiId(1708), EmojiId(1707))),
+        ("🇳🇵", (EmojiId(3685), EmojiId(3685))),
+        ("🧑\u{200d}🏭", (EmojiId(968), EmojiId(968))),
+        ("✌🏿", (EmojiId(257), EmojiId(252))),
+        ("👩🏻\u{200d}🦯\u{200d}➡", (EmojiId(1648), EmojiId(1647))),
+        ("🧜🏼\u{200d}♂\u{fe0f}", (EmojiId(1456), EmojiId(1454))),
+        ("🛥\u{fe0f}", (EmojiId(2839), EmojiId(2839))),
+        ("🇨🇺", (EmojiId(3564), EmojiId(3564))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2357), EmojiId(2341))),
+        ("🙍\u{200d}♂\u{fe0f}", (EmojiId(668), EmojiId(668))),
+        ("🧚🏽", (EmojiId(1415), EmojiId(1412))),
+        ("👆", (EmojiId(300), EmojiId(300))),
+        ("🛰\u{fe0f}", (EmojiId(2851), EmojiId(2851))),
+        ("🤦🏼\u{200d}♀", (EmojiId(820), EmojiId(818))),
+        ("👨\u{200d}🦼\u{200d}➡", (EmojiId(1671), EmojiId(1671))),
+        ("🥋", (EmojiId(2978), EmojiId(2978))),
+        ("🍀", (EmojiId(2574), EmojiId(2574))),
+        ("🕶", (EmojiId(3020), EmojiId(3020))),
+        ("🏊🏽\u{200d}♂\u{fe0f}", (EmojiId(1895), EmojiId(1892))),
+        ("🤳🏾", (EmojiId(444), EmojiId(440))),
+        ("🤵🏼\u{200d}♀\u{fe0f}", (EmojiId(1270), EmojiId(1268))),
+        ("🚶\u{200d}➡", (EmojiId(1545), EmojiId(1545))),
+        ("🦹🏼\u{200d}♂", (EmojiId(1384), EmojiId(1382))),
+        ("🚴🏿\u{200d}♀", (EmojiId(1957), EmojiId(1952))),
+        ("🕜", (EmojiId(2866), EmojiId(2866))),
+        ("🏵\u{fe0f}", (EmojiId(2557), EmojiId(2557))),
+        ("🎙\u{fe0f}", (EmojiId(3078), EmojiId(3078))),
+        ("😗", (EmojiId(18), EmojiId(18))),
+        ("👕", (EmojiId(3025), EmojiId(3025))),
+        ("💂", (EmojiId(1166), EmojiId(1166))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2225), EmojiId(2211))),
+        ("🏢", (EmojiId(2743), EmojiId(2743))),
+        ("🤷\u{200d}♂", (EmojiId(830), EmojiId(830))),
+        ("🇦🇼", (EmojiId(3526), EmojiId(3526))),
+        ("👨🏼\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1637), EmojiId(1635))),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏾", (EmojiId(2115), EmojiId(2107))),
+        ("👌🏼", (EmojiId(236), EmojiId(234))),
+        ("🧑\u{200d}🎓", (EmojiId(860), EmojiId(860))),
+        ("👩\u{200d}🦯", (EmojiId(1641), EmojiId(1641))),
+        ("🫰🏿", (EmojiId(269), EmojiId(264))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2226), EmojiId(2211))),
+        ("🦨", (EmojiId(2490), EmojiId(2490))),
+        ("🌗", (EmojiId(2893), EmojiId(2893))),
+        ("🚶🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1561), EmojiId(1557))),
+        ("🛎", (EmojiId(2854), EmojiId(2854))),
+        ("👱🏽\u{200d}♀\u{fe0f}", (EmojiId(635), EmojiId(632))),
+        ("🌴", (EmojiId(2569), EmojiId(2569))),
+        ("🧘🏽\u{200d}♂", (EmojiId(2060), EmojiId(2057))),
+        ("🤹", (EmojiId(2033), EmojiId(2033))),
+        ("🔆", (EmojiId(3374), EmojiId(3374))),
+        ("👩🏾\u{200d}💻", (EmojiId(1038), EmojiId(1034))),
+        ("🙎🏻", (EmojiId(681), EmojiId(680))),
+        ("🇪🇹", (EmojiId(3584), EmojiId(3584))),
+        ("🐲", (EmojiId(2521), EmojiId(2521))),
+        ("👩🏾\u{200d}🦽\u{200d}➡", (EmojiId(1723), EmojiId(1719))),
+        ("💂\u{200d}♂\u{fe0f}", (EmojiId(1172), EmojiId(1172))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2221), EmojiId(2211))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2320), EmojiId(2315))),
+        ("🏃🏼\u{200d}➡\u{fe0f}", (EmojiId(1745), EmojiId(1743))),
+        ("🤷🏽\u{200d}♂", (EmojiId(833), EmojiId(830))),
+        ("🧟\u{200d}♀", (EmojiId(1489), EmojiId(1489))),
+        ("🚅", (EmojiId(2785), EmojiId(2785))),
+        ("🔅", (EmojiId(3373), EmojiId(3373))),
+        ("♿", (EmojiId(3284), EmojiId(3284))),
+        ("🟡", (EmojiId(3472), EmojiId(3472))),
+        ("🧜\u{200d}♂", (EmojiId(1454), EmojiId(1454))),
+        ("🧗🏻\u{200d}♂\u{fe0f}", (EmojiId(1807), EmojiId(1806))),
+        ("🇱🇨", (EmojiId(3645), EmojiId(3645))),
+        ("📑", (EmojiId(3145), EmojiId(3145))),
+        ("🖌", (EmojiId(3175), EmojiId(3175))),
+        ("🇵🇬", (EmojiId(3693), EmojiId(3693))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏾", (EmojiId(2357), EmojiId(2341))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2264), EmojiId(2263))),
+        ("👩🏿\u{200d}🔬", (EmojiId(1021), EmojiId(1016))),
+        ("🎯", (EmojiId(2988), EmojiId(2988))),
+        ("🆓", (EmojiId(3441), EmojiId(3441))),
+        ("☁\u{fe0f}", (EmojiId(2908), EmojiId(2908))),
+        ("✋", (EmojiId(186), EmojiId(186))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2232), EmojiId(2211))),
+        ("🧎🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1606), EmojiId(1605))),
+        ("🤽🏾\u{200d}♀\u{fe0f}", (EmojiId(2013), EmojiId(2009))),
+        ("🪲", (EmojiId(2542), EmojiId(2542))),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏿", (EmojiId(2310), EmojiId(2289))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", (EmojiId(2207), EmojiId(2185))),
+        ("👳🏻\u{200d}♀", (EmojiId(1239), EmojiId(1238))),
+        ("🔊", (EmojiId(3069), EmojiId(3069))),
+        ("💇🏽\u{200d}♀", (EmojiId(1524), EmojiId(1521))),
+        ("👳🏼\u{200d}♂\u{fe0f}", (EmojiId(1234), EmojiId(1232))),
+        ("👩🏿\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1688), EmojiId(1683))),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏼", (EmojiId(2312), EmojiId(2289))),
+        ("👧🏻", (EmojiId(513), EmojiId(512))),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏼", (EmojiId(2165), EmojiId(2159))),
+        ("👷🏾", (EmojiId(1194), EmojiId(1190))),
+        ("🥬", (EmojiId(2609), EmojiId(2609))),
+        ("💆🏼\u{200d}♂", (EmojiId(1499), EmojiId(1497))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2273), EmojiId(2263))),
+        ("👌🏾", (EmojiId(238), EmojiId(234))),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏾", (EmojiId(2197), EmojiId(2185))),
+        ("🙇🏻\u{200d}♀", (EmojiId(801), EmojiId(800))),
+        ("🤲🏻", (EmojiId(391), EmojiId(390))),
+        ("🤹🏽\u{200d}♀", (EmojiId(2048), EmojiId(2045))),
+        ("🥡", (EmojiId(2669), EmojiId(2669))),
+        ("🧑🏼\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1625), EmojiId(1623))),
+        ("🧎🏼\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1607), EmojiId(1605))),
+        ("🫸🏿", (EmojiId(233), EmojiId(228))),
+        ("🌛", (EmojiId(2897), EmojiId(2897))),
+        ("🚴🏼\u{200d}♂\u{fe0f}", (EmojiId(1948), EmojiId(1946))),
+        ("👱\u{200d}♂\u{fe0f}", (EmojiId(638), EmojiId(638))),
+        ("🏃🏿\u{200d}♂\u{200d}➡", (EmojiId(1760), EmojiId(1755))),
+        ("🤹🏿\u{200d}♂\u{fe0f}", (EmojiId(2044), EmojiId(2039))),
+        ("🏫", (EmojiId(2751), EmojiId(2751))),
+        ("🪤", (EmojiId(3258), EmojiId(3258))),
+        ("🧑🏿\u{200d}✈\u{fe0f}", (EmojiId(1081), EmojiId(1076))),
+        ("👩🏾\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1723), EmojiId(1719))),
+        ("🙍🏾\u{200d}♂\u{fe0f}", (EmojiId(672), EmojiId(668))),
+        ("🧏🏻\u{200d}♂\u{fe0f}", (EmojiId(777), EmojiId(776))),
+        ("👩🏼\u{200d}✈\u{fe0f}", (EmojiId(1090), EmojiId(1088))),
+        ("👌", (EmojiId(234), EmojiId(234))),
+        ("🚣🏽\u{200d}♀\u{fe0f}", (EmojiId(1883), EmojiId(1880))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2339), EmojiId(2315))),
+        ("🇬🇾", (EmojiId(3610), EmojiId(3610))),
+        ("🧎🏿\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1610), EmojiId(1605))),
+        ("⌨\u{fe0f}", (EmojiId(3107), EmojiId(3107))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2362), EmojiId(2341))),
+        ("🐌", (EmojiId(2537), EmojiId(2537))),
+        ("🥕", (EmojiId(2604), EmojiId(2604))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2322), EmojiId(2315))),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏻", (EmojiId(2121), EmojiId(2107))),
+        ("🚴🏼", (EmojiId(1942), EmojiId(1940))),
+        ("👩🏾\u{200d}🍼", (EmojiId(1320), EmojiId(1316))),
+        ("🐞", (EmojiId(2543), EmojiId(2543))),
+        ("🚍", (EmojiId(2795), EmojiId(2795))),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏻", (EmojiId(2129), EmojiId(2107))),
+        ("🔋", (EmojiId(3101), EmojiId(3101))),
+        ("🍈", (EmojiId(2582), EmojiId(2582))),
+        ("👨🏾\u{200d}✈\u{fe0f}", (EmojiId(1086), EmojiId(1082))),
+        ("🧛🏻", (EmojiId(1431), EmojiId(1430))),
+        ("💁🏼\u{200d}♂\u{fe0f}", (EmojiId(742), EmojiId(740))),
+        ("🧔🏼\u{200d}♀", (EmojiId(550), EmojiId(548))),
+        ("👳🏾\u{200d}♂\u{fe0f}", (EmojiId(1236), EmojiId(1232))),
+        ("👨🏻\u{200d}🎤", (EmojiId(1047), EmojiId(1046))),
+        ("💏🏾", (EmojiId(2189), EmojiId(2185))),
+        ("🇧🇳", (EmojiId(3540), EmojiId(3540))),
+        ("🏊🏾\u{200d}♀", (EmojiId(1902), EmojiId(1898))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2372), EmojiId(2367))),
+        ("👩🏾\u{200d}✈", (EmojiId(1092), EmojiId(1088))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2247), EmojiId(2237))),
+        ("🌌", (EmojiId(2907), EmojiId(2907))),
+        ("🤸🏼\u{200d}♀", (EmojiId(1990), EmojiId(1988))),
+        ("👫🏽", (EmojiId(2136), EmojiId(2133))),
+        ("🤝🏻", (EmojiId(397), EmojiId(396))),
+        ("🦫", (EmojiId(2481), EmojiId(2481))),
+        ("🧏\u{200d}♂\u{fe0f}", (EmojiId(776), EmojiId(776))),
+        ("🙇🏾\u{200d}♀\u{fe0f}", (EmojiId(804), EmojiId(800))),
+        ("💆🏿", (EmojiId(1496), EmojiId(1491))),
+        ("🧍", (EmojiId(1563), EmojiId(1563))),
+        ("🧑🏿\u{200d}💻", (EmojiId(1027), EmojiId(1022))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2288), EmojiId(2263))),
+        ("🚶🏿", (EmojiId(1532), EmojiId(1527))),
+        ("🤵🏻\u{200d}♀\u{fe0f}", (EmojiId(1269), EmojiId(1268))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏿", (EmojiId(2302), EmojiId(2289))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2279), EmojiId(2263))),
+        ("🇦🇩", (EmojiId(3513), EmojiId(3513))),
+        ("🍢", (EmojiId(2661), EmojiId(2661))),
+        ("🙇🏼\u{200d}♂\u{fe0f}", (EmojiId(796), EmojiId(794))),
+        ("◀\u{fe0f}", (EmojiId(3361), EmojiId(3361))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2219), EmojiId(2211))),
+        ("🇳🇬", (EmojiId(3681), EmojiId(3681))),
+        ("🏃🏽\u{200d}➡\u{fe0f}", (EmojiId(1746), EmojiId(1743))),
+        ("🏃\u{200d}♂\u{200d}➡", (EmojiId(1755), EmojiId(1755))),
+        ("🙋🏽\u{200d}♀", (EmojiId(767), EmojiId(764))),
+        ("⛹🏻", (EmojiId(1905), EmojiId(1904))),
+        ("👆🏿", (EmojiId(305), EmojiId(300))),
+        ("🕵🏿", (EmojiId(1153), EmojiId(1148))),
+        ("🪠", (EmojiId(3255), EmojiId(3255))),
+        ("🫄🏻", (EmojiId(1305), EmojiId(1304))),
+        ("👨\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1635), EmojiId(1635))),
+        ("🍬", (EmojiId(2685), EmojiId(2685))),
+        ("🤟🏽", (EmojiId(273), EmojiId(270))),
+        ("👩🏾\u{200d}🎤", (EmojiId(1056), EmojiId(1052))),
+        ("🚚", (EmojiId(2808), EmojiId(2808))),
+        ("💔", (EmojiId(139), EmojiId(139))),
+        ("🇸🇻", (EmojiId(3727), EmojiId(3727))),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏽", (EmojiId(2309), EmojiId(2289))),
+        ("🇦🇫", (EmojiId(3515), EmojiId(3515))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2092), EmojiId(2081))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏼", (EmojiId(2312), EmojiId(2289))),
+        ("🙌🏾", (EmojiId(376), EmojiId(372))),
+        ("👫🏼", (EmojiId(2135), EmojiId(2133))),
+        ("👪", (EmojiId(2422), EmojiId(2422))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏿", (EmojiId(2380), EmojiId(2367))),
+        ("🏊🏼", (EmojiId(1888), EmojiId(1886))),
+        ("💑🏼", (EmojiId(2291), EmojiId(2289))),
+        ("🧎🏿\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1616), EmojiId(1611))),
+        ("🧏🏾\u{200d}♂", (EmojiId(780), EmojiId(776))),
+        ("💲", (EmojiId(3396), EmojiId(3396))),
+        ("🏃\u{200d}➡", (EmojiId(1743), EmojiId(1743))),
+        ("➡", (EmojiId(3309), EmojiId(3309))),
+        ("👶🏿", (EmojiId(499), EmojiId(494))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2249), EmojiId(2237))),
+        ("🧔\u{200d}♂", (EmojiId(542), EmojiId(542))),
+        ("🎚", (EmojiId(3079), EmojiId(3079))),
+        ("👩🏽\u{200d}⚕\u{fe0f}", (EmojiId(857), EmojiId(854))),
+        ("😸", (EmojiId(118), EmojiId(118))),
+        ("🧍🏻", (EmojiId(1564), EmojiId(1563))),
+        ("⛹🏾\u{200d}♂", (EmojiId(1914), EmojiId(1910))),
+        ("🧘🏻\u{200d}♂", (EmojiId(2058), EmojiId(2057))),
+        ("👩🏾\u{200d}🦰", (EmojiId(588), EmojiId(584))),
+        ("🇬🇸", (EmojiId(3606), EmojiId(3606))),
+        ("👷🏽\u{200d}♂\u{fe0f}", (EmojiId(1199), EmojiId(1196))),
+        ("〰\u{fe0f}", (EmojiId(3394), EmojiId(3394))),
+        ("🇹🇻", (EmojiId(3745), EmojiId(3745))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2260), EmojiId(2237))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2267), EmojiId(2263))),
+        ("🚶\u{200d}➡\u{fe0f}", (EmojiId(1545), EmojiId(1545))),
+        ("🐎", (EmojiId(2450), EmojiId(2450))),
+        ("🇵🇫", (EmojiId(3692), EmojiId(3692))),
+        ("🛋", (EmojiId(3252), EmojiId(3252))),
+        ("🎟\u{fe0f}", (EmojiId(2953), EmojiId(2953))),
+        ("🧑🏻\u{200d}✈\u{fe0f}", (EmojiId(1077), EmojiId(1076))),
+        ("🍳", (EmojiId(2643), EmojiId(2643))),
+        ("🪀", (EmojiId(2989), EmojiId(2989))),
+        ("👩🏻\u{200d}🦳", (EmojiId(609), EmojiId(608))),
+        ("🌱", (EmojiId(2565), EmojiId(2565))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏽", (EmojiId(2356), EmojiId(2341))),
+        ("🦥", (EmojiId(2488), EmojiId(2488))),
+        ("🧽", (EmojiId(3269), EmojiId(3269))),
+        ("🤸🏻", (EmojiId(1977), EmojiId(1976))),
+        ("🧞\u{200d}♂\u{fe0f}", (EmojiId(1485), EmojiId(1485))),
+        ("📽", (EmojiId(3117), EmojiId(3117))),
+        ("🤟🏻", (EmojiId(271), EmojiId(270))),
+        ("🧑🏾\u{200d}🦱", (EmojiId(606), EmojiId(602))),
+        ("🧑🏼\u{200d}🔬", (EmojiId(1006), EmojiId(1004))),
+        ("🔚", (EmojiId(3324), EmojiId(3324))),
+        ("🧑🏾\u{200d}🔧", (EmojiId(954), EmojiId(950))),
+        ("👳🏿\u{200d}♀\u{fe0f}", (EmojiId(1243), EmojiId(1238))),
+        ("👩\u{200d}⚖", (EmojiId(908), EmojiId(908))),
+        ("⚰\u{fe0f}", (EmojiId(3273), EmojiId(3273))),
+        ("🧑🏽\u{200d}🚒", (EmojiId(1115), EmojiId(1112))),
+        ("🤽🏽\u{200d}♂\u{fe0f}", (EmojiId(2006), EmojiId(2003))),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩", (EmojiId(2263), EmojiId(2263))),
+        ("🥸", (EmojiId(71), EmojiId(71))),
+        ("⛹🏼\u{200d}♀\u{fe0f}", (EmojiId(1918), EmojiId(1916))),
+        ("🪳", (EmojiId(2545), EmojiId(2545))),
+        ("📟", (EmojiId(3099), EmojiId(3099))),
+        ("👨🏿\u{200d}🦳", (EmojiId(571), EmojiId(566))),
+        ("👯\u{200d}♂\u{fe0f}", (EmojiId(1780), EmojiId(1780))),
+        ("🤾🏽\u{200d}♀", (EmojiId(2030), EmojiId(2027))),
+        ("🦔", (EmojiId(2482), EmojiId(2482))),
+        ("✝\u{fe0f}", (EmojiId(3334), EmojiId(3334))),
+        ("🧑\u{200d}💻", (EmojiId(1022), EmojiId(1022))),
+        ("🇧🇴", (EmojiId(3541), EmojiId(3541))),
+        ("🥯", (EmojiId(2624), EmojiId(2624))),
+        ("👩🏽\u{200d}🦽", (EmojiId(1716), EmojiId(1713))),
+        ("♨\u{fe0f}", (EmojiId(2775), EmojiId(2775))),
+        ("#\u{20e3}", (EmojiId(3418), EmojiId(3418))),
+        ("💆🏽\u{200d}♂\u{fe0f}", (EmojiId(1500), EmojiId(1497))),
+        ("🍑", (EmojiId(2593), EmojiId(2593))),
+        ("👨\u{200d}👦", (EmojiId(2408), EmojiId(2408))),
+        ("🚶🏾\u{200d}➡", (EmojiId(1549), EmojiId(1545))),
+        ("👨\u{200d}🦽\u{200d}➡", (EmojiId(1707), EmojiId(1707))),
+        ("👱🏿\u{200d}♂", (EmojiId(643), EmojiId(638))),
+        ("🖥", (EmojiId(3105), EmojiId(3105))),
+        ("🕎", (EmojiId(3338), EmojiId(3338))),
+        ("🚀", (EmojiId(2852), EmojiId(2852))),
+        ("🧎🏾\u{200d}♀\u{200d}➡", (EmojiId(1609), EmojiId(1605))),
+        ("🧚🏾", (EmojiId(1416), EmojiId(1412))),
+        ("👨🏼\u{200d}🚒", (EmojiId(1120), EmojiId(1118))),
+        ("📸", (EmojiId(3121), EmojiId(3121))),
+        ("4\u{20e3}", (EmojiId(3424), EmojiId(3424))),
+        ("🤹\u{200d}♂", (EmojiId(2039), EmojiId(2039))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2227), EmojiId(2211))),
+        ("🚵🏾\u{200d}♂", (EmojiId(1968), EmojiId(1964))),
+        ("👩🏻\u{200d}🌾", (EmojiId(927), EmojiId(926))),
+        ("🧏🏻\u{200d}♂", (EmojiId(777), EmojiId(776))),
+        ("💖", (EmojiId(132), EmojiId(132))),
+        ("👨🏻\u{200d}🦼", (EmojiId(1666), EmojiId(1665))),
+        ("🧎\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1605), EmojiId(1605))),
+        ("👂🏾", (EmojiId(470), EmojiId(466))),
+        ("🗺\u{fe0f}", (EmojiId(2720), EmojiId(2720))),
+        ("👨🏼\u{200d}🦰", (EmojiId(556), EmojiId(554))),
+        ("🐷", (EmojiId(2459), EmojiId(2459))),
+        ("🙆🏽\u{200d}♀\u{fe0f}", (EmojiId(731), EmojiId(728))),
+        ("🏊🏻\u{200d}♂\u{fe0f}", (EmojiId(1893), EmojiId(1892))),
+        ("👨🏼\u{200d}🔧", (EmojiId(958), EmojiId(956))),
+        ("🧚\u{200d}♀\u{fe0f}", (EmojiId(1424), EmojiId(1424))),
+        ("🧑🏼\u{200d}🎤", (EmojiId(1042), EmojiId(1040))),
+        ("🖕🏿", (EmojiId(311), EmojiId(306))),
+        ("⏸\u{fe0f}", (EmojiId(3368), EmojiId(3368))),
+        ("🧏🏿\u{200d}♂", (EmojiId(781), EmojiId(776))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2256), EmojiId(2237))),
+        ("🫸🏻", (EmojiId(229), EmojiId(228))),
+        ("🛠", (EmojiId(3211), EmojiId(3211))),
+        ("👲🏼", (EmojiId(1246), EmojiId(1244))),
+        ("✋🏻", (EmojiId(187), EmojiId(186))),
+        ("👷🏿\u{200d}♀\u{fe0f}", (EmojiId(1207), EmojiId(1202))),
+        ("☯", (EmojiId(3333), EmojiId(3333))),
+        ("💂🏿\u{200d}♂", (EmojiId(1177), EmojiId(1172))),
+        ("💇🏼\u{200d}♂", (EmojiId(1517), EmojiId(1515))),
+        ("👐🏾", (EmojiId(388), EmojiId(384))),
+        ("🧑🏿\u{200d}✈", (EmojiId(1081), EmojiId(1076))),
+        ("🙋🏼", (EmojiId(754), EmojiId(752))),
+        ("🏧", (EmojiId(3281), EmojiId(3281))),
+        ("🚶🏾\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1555), EmojiId(1551))),
+        ("🔖", (EmojiId(3146), EmojiId(3146))),
+        ("🙅🏿\u{200d}♂", (EmojiId(709), EmojiId(704))),
+        ("🚣🏽\u{200d}♀", (EmojiId(1883), EmojiId(1880))),
+        ("👷\u{200d}♂\u{fe0f}", (EmojiId(1196), EmojiId(1196))),
+        ("⚒\u{fe0f}", (EmojiId(3210), EmojiId(3210))),
+        ("🏃🏾\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1753), EmojiId(1749))),
+        ("🚶\u{200d}♂", (EmojiId(1533), EmojiId(1533))),
+        ("🦍", (EmojiId(2430), EmojiId(2430))),
+        ("⛹🏼\u{200d}♂\u{fe0f}", (EmojiId(1912), EmojiId(1910))),
+        ("🚈", (EmojiId(2788), EmojiId(2788))),
+        ("🧮", (EmojiId(3114), EmojiId(3114))),
+        ("🫅", (EmojiId(1208), EmojiId(1208))),
+        ("👱\u{200d}♀\u{fe0f}", (EmojiId(632), EmojiId(632))),
+        ("🇭🇹", (EmojiId(3615), EmojiId(3615))),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏾", (EmojiId(2297), EmojiId(2289))),
+        ("🏋\u{200d}♂\u{fe0f}", (EmojiId(1928), EmojiId(1928))),
+        ("🧛🏿\u{200d}♀", (EmojiId(1447), EmojiId(1442))),
+        ("👨\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1671), EmojiId(1671))),
+        ("*\u{20e3}", (EmojiId(3419), EmojiId(3419))),
+        ("🙂", (EmojiId(8), EmojiId(8))),
+        ("🧏\u{200d}♀", (EmojiId(782), EmojiId(782))),
+        ("🤜🏽", (EmojiId(363), EmojiId(360))),
+        ("🏄🏿\u{200d}♂\u{fe0f}", (EmojiId(1861), EmojiId(1856))),
+        ("⏯\u{fe0f}", (EmojiId(3360), EmojiId(3360))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2254), EmojiId(2237))),
+        ("🧎🏾\u{200d}♂", (EmojiId(1591), EmojiId(1587))),
+        ("👮🏽", (EmojiId(1133), EmojiId(1130))),
+        ("💃🏼", (EmojiId(1763), EmojiId(1761))),
+        ("🧑🏿\u{200d}🔧", (EmojiId(955), EmojiId(950))),
+        ("🚴🏻\u{200d}♂", (EmojiId(1947), EmojiId(1946))),
+        ("👩🏻\u{200d}🎨", (EmojiId(1071), EmojiId(1070))),
+        ("🐯", (EmojiId(2444), EmojiId(2444))),
+        ("🤌🏻", (EmojiId(241), EmojiId(240))),
+        ("🚣🏿\u{200d}♂\u{fe0f}", (EmojiId(1879), EmojiId(1874))),
+        ("🤸🏻\u{200d}♀\u{fe0f}", (EmojiId(1989), EmojiId(1988))),
+        ("👫🏻", (EmojiId(2134), EmojiId(2133))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2333), EmojiId(2315))),
+        ("🚶🏻\u{200d}♀\u{fe0f}", (EmojiId(1540), EmojiId(1539))),
+        ("🛤\u{fe0f}", (EmojiId(2823), EmojiId(2823))),
+        ("🥙", (EmojiId(2640), EmojiId(2640))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2276), EmojiId(2263))),
+        ("🤵🏽\u{200d}♀", (EmojiId(1271), EmojiId(1268))),
+        ("👩🏽\u{200d}🔬", (EmojiId(1019), EmojiId(1016))),
+        ("🤷🏾\u{200d}♀", (EmojiId(840), EmojiId(836))),
+        ("🚶🏼\u{200d}♀\u{200d}➡", (EmojiId(1553), EmojiId(1551))),
+        ("🎵", (EmojiId(3076), EmojiId(3076))),
+        ("🍇", (EmojiId(2581), EmojiId(2581))),
+        ("🤷", (EmojiId(824), EmojiId(824))),
+        ("🧖🏽", (EmojiId(1785), EmojiId(1782))),
+        ("2\u{fe0f}\u{20e3}", (EmojiId(3422), EmojiId(3422))),
+        ("🉑", (EmojiId(3462), EmojiId(3462))),
+        ("👩\u{200d}👩\u{200d}👦\u{200d}👦", (EmojiId(2406), EmojiId(2406))),
+        ("🧑🏽\u{200d}🦳", (EmojiId(617), EmojiId(614))),
+        ("🗾", (EmojiId(2721), EmojiId(2721))),
+        ("🧑🏼\u{200d}🦽\u{200d}➡", (EmojiId(1697), EmojiId(1695))),
+        ("🇨🇰", (EmojiId(3557), EmojiId(3557))),
+        ("🐩", (EmojiId(2436), EmojiId(2436))),
+        ("🗄\u{fe0f}", (EmojiId(3199), EmojiId(3199))),
+        ("📶", (EmojiId(3375), EmojiId(3375))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2232), EmojiId(2211))),
+        ("🅱", (EmojiId(3438), EmojiId(3438))),
+        ("👰🏽\u{200d}♀\u{fe0f}", (EmojiId(1289), EmojiId(1286))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2250), EmojiId(2237))),
+        ("🧑🏻\u{200d}🎓", (EmojiId(861), EmojiId(860))),
+        ("🕤", (EmojiId(2882), EmojiId(2882))),
+        ("🌚", (EmojiId(2896), EmojiId(2896))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", (EmojiId(2208), EmojiId(2185))),
+        ("🧘🏾\u{200d}♂", (EmojiId(2061), EmojiId(2057))),
+        ("⏺\u{fe0f}", (EmojiId(3370), EmojiId(3370))),
+        ("🗝", (EmojiId(3206), EmojiId(3206))),
+        ("🇷🇴", (EmojiId(3706), EmojiId(3706))),
+        ("🉐", (EmojiId(3458), EmojiId(3458))),
+        ("👷🏻\u{200d}♂\u{fe0f}", (EmojiId(1197), EmojiId(1196))),
+        ("⚾", (EmojiId(2962), EmojiId(2962))),
+        ("🌲", (EmojiId(2567), EmojiId(2567))),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏻", (EmojiId(2195), EmojiId(2185))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2257), EmojiId(2237))),
+        ("🐻", (EmojiId(2484), EmojiId(2484))),
+        ("👬🏽", (EmojiId(2162), EmojiId(2159))),
+        ("🌡", (EmojiId(2899), EmojiId(2899))),
+        ("🚴\u{200d}♂", (EmojiId(1946), EmojiId(1946))),
+        ("💂🏿\u{200d}♀", (EmojiId(1183), EmojiId(1178))),
+        ("🤽🏽", (EmojiId(2000), EmojiId(1997))),
+        ("🐆", (EmojiId(2446), EmojiId(2446))),
+        ("🏁", (EmojiId(3504), EmojiId(3504))),
+        ("🤴🏻", (EmojiId(1215), EmojiId(1214))),
+        ("🍁", (EmojiId(2575), EmojiId(2575))),
+        ("◼", (EmojiId(3488), EmojiId(3488))),
+        ("🙂\u{200d}↕", (EmojiId(51), EmojiId(51))),
+        ("👩🏾\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1651), EmojiId(1647))),
+        ("🏃🏿\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1754), EmojiId(1749))),
+        ("🤟🏿", (EmojiId(275), EmojiId(270))),
+        ("🇬🇬", (EmojiId(3597), EmojiId(3597))),
+        ("🛒", (EmojiId(3271), EmojiId(3271))),
+        ("🕔", (EmojiId(2873), EmojiId(2873))),
+        ("⚱", (EmojiId(3275), EmojiId(3275))),
+        ("🕵🏼\u{200d}♂", (EmojiId(1156), EmojiId(1154))),
+        ("👩🏻\u{200d}💻", (EmojiId(1035), EmojiId(1034))),
+        ("💆🏽", (EmojiId(1494), EmojiId(1491))),
+        ("🏄🏻\u{200d}♀", (EmojiId(1863), EmojiId(1862))),
+        ("👩🏾\u{200d}✈\u{fe0f}", (EmojiId(1092), EmojiId(1088))),
+        ("✉", (EmojiId(3158), EmojiId(3158))),
+        ("🧖🏻\u{200d}♂", (EmojiId(1789), EmojiId(1788))),
+        ("🏋\u{200d}♀", (EmojiId(1934), EmojiId(1934))),
+        ("🤷🏿", (EmojiId(829), EmojiId(824))),
+        ("💂🏽", (EmojiId(1169), EmojiId(1166))),
+        ("🤶🏾", (EmojiId(1350), EmojiId(1346))),
+        ("🧗🏾\u{200d}♀", (EmojiId(1816), EmojiId(1812))),
+        ("💁🏼\u{200d}♂", (EmojiId(742), EmojiId(740))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏽", (EmojiId(2309), EmojiId(2289))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2228), EmojiId(2211))),
+        ("🧙🏻", (EmojiId(1395), EmojiId(1394))),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏽", (EmojiId(2192), EmojiId(2185))),
+        ("🤵🏾\u{200d}♀\u{fe0f}", (EmojiId(1272), EmojiId(1268))),
+        ("👨🏿\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1640), EmojiId(1635))),
+        ("🚣🏿\u{200d}♀", (EmojiId(1885), EmojiId(1880))),
+        ("🥶", (EmojiId(64), EmojiId(64))),
+        ("👨🏼\u{200d}🎓", (EmojiId(868), EmojiId(866))),
+        ("🇩🇪", (EmojiId(3570), EmojiId(3570))),
+        ("🥠", (EmojiId(2668), EmojiId(2668))),
+        ("🧏🏽\u{200d}♂\u{fe0f}", (EmojiId(779), EmojiId(776))),
+        ("🚛", (EmojiId(2809), EmojiId(2809))),
+        ("😏", (EmojiId(43), EmojiId(43))),
+        ("🚯", (EmojiId(3300), EmojiId(3300))),
+        ("🏂🏻", (EmojiId(1827), EmojiId(1826))),
+        ("🍊", (EmojiId(2584), EmojiId(2584))),
+        ("💇\u{200d}♀\u{fe0f}", (EmojiId(1521), EmojiId(1521))),
+        ("🦹🏻\u{200d}♀", (EmojiId(1389), EmojiId(1388))),
+        ("🇰🇳", (EmojiId(3637), EmojiId(3637))),
+        ("💆🏾\u{200d}♂\u{fe0f}", (EmojiId(1501), EmojiId(1497))),
+        ("🏳\u{fe0f}", (EmojiId(3508), EmojiId(3508))),
+        ("🧚🏽\u{200d}♀", (EmojiId(1427), EmojiId(1424))),
+        ("🏃🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1753), EmojiId(1749))),
+        ("💑🏽", (EmojiId(2292), EmojiId(2289))),
+        ("🚶🏻\u{200d}➡\u{fe0f}", (EmojiId(1546), EmojiId(1545))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2271), EmojiId(2263))),
+        ("👶🏾", (EmojiId(498), EmojiId(494))),
+        ("👩\u{200d}👧", (EmojiId(2415), EmojiId(2415))),
+        ("🚶\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1557), EmojiId(1557))),
+        ("🗡\u{fe0f}", (EmojiId(3212), EmojiId(3212))),
+        ("🙋🏿\u{200d}♂", (EmojiId(763), EmojiId(758))),
+        ("👩\u{200d}🦳", (EmojiId(608), EmojiId(608))),
+        ("☝🏿", (EmojiId(323), EmojiId(318))),
+        ("🚴🏼\u{200d}♂", (EmojiId(1948), EmojiId(1946))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2248), EmojiId(2237))),
+        ("🚖", (EmojiId(2803), EmojiId(2803))),
+        ("🤷🏾\u{200d}♂", (EmojiId(834), EmojiId(830))),
+        ("⛏", (EmojiId(3209), EmojiId(3209))),
+        ("🧗🏽\u{200d}♀\u{fe0f}", (EmojiId(1815), EmojiId(1812))),
+        ("🏌🏾\u{200d}♀\u{fe0f}", (EmojiId(1848), EmojiId(1844))),
+        ("⛱\u{fe0f}", (EmojiId(2925), EmojiId(2925))),
+        ("🦏", (EmojiId(2472), EmojiId(2472))),
+        ("😖", (EmojiId(94), EmojiId(94))),
+        ("🧑🏾\u{200d}✈\u{fe0f}", (EmojiId(1080), EmojiId(1076))),
+        ("👨🏼\u{200d}🏭", (EmojiId(976), EmojiId(974))),
+        ("🕊\u{fe0f}", (EmojiId(2502), EmojiId(2502))),
+        ("🕸", (EmojiId(2547), EmojiId(2547))),
+        ("👱\u{200d}♀", (EmojiId(632), EmojiId(632))),
+        ("🍗", (EmojiId(2629), EmojiId(2629))),
+        ("👃🏻", (EmojiId(479), EmojiId(478))),
+        ("🇸🇱", (EmojiId(3720), EmojiId(3720))),
+        ("💱", (EmojiId(3395), EmojiId(3395))),
+        ("🗒", (EmojiId(3184), EmojiId(3184))),
+        ("🗼", (EmojiId(2757), EmojiId(2757))),
+        ("🧑🏽\u{200d}⚖", (EmojiId(899), EmojiId(896))),
+        ("💂🏼\u{200d}♂\u{fe0f}", (EmojiId(1174), EmojiId(1172))),
+        ("👼🏽", (EmojiId(1337), EmojiId(1334))),
+        ("🇸🇨", (EmojiId(3712), EmojiId(3712))),
+        ("📯", (EmojiId(3072), EmojiId(3072))),
+        ("🎣", (EmojiId(2982), EmojiId(2982))),
+        ("🫴🏽", (EmojiId(219), EmojiId(216))),
+        ("👩🏽\u{200d}🦰", (EmojiId(587), EmojiId(584))),
+        ("🚵🏿\u{200d}♂", (EmojiId(1969), EmojiId(1964))),
+        ("🙆🏿\u{200d}♂\u{fe0f}", (EmojiId(727), EmojiId(722))),
+        ("💂🏼\u{200d}♂", (EmojiId(1174), EmojiId(1172))),
+        ("🧏\u{200d}♀\u{fe0f}", (EmojiId(782), EmojiId(782))),
+        ("🧎\u{200d}♀", (EmojiId(1593), EmojiId(1593))),
+        ("✌🏽", (EmojiId(255), EmojiId(252))),
+        ("🙋🏿\u{200d}♀\u{fe0f}", (EmojiId(769), EmojiId(764))),
+        ("🧔🏽\u{200d}♀", (EmojiId(551), EmojiId(548))),
+        ("🏌🏼\u{200d}♂\u{fe0f}", (EmojiId(1840), EmojiId(1838))),
+        ("🧎\u{200d}➡", (EmojiId(1599), EmojiId(1599))),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}👩", (EmojiId(2367), EmojiId(2367))),
+        ("☹", (EmojiId(79), EmojiId(79))),
+        ("🏋🏾", (EmojiId(1926), EmojiId(1922))),
+        ("⏳", (EmojiId(2857), EmojiId(2857))),
+        ("🦸🏼\u{200d}♂", (EmojiId(1366), EmojiId(1364))),
+        ("🅰\u{fe0f}", (EmojiId(3436), EmojiId(3436))),
+        ("🎫", (EmojiId(2954), EmojiId(2954))),
+        ("💢", (EmojiId(156), EmojiId(156))),
+        ("🛀🏿", (EmojiId(2074), EmojiId(2069))),
+        ("🚶🏿\u{200d}♂\u{200d}➡", (EmojiId(1562), EmojiId(1557))),
+        ("🫲🏼", (EmojiId(206), EmojiId(204))),
+        ("🧀", (EmojiId(2627), EmojiId(2627))),
+        ("🕵🏾\u{200d}♂", (EmojiId(1158), EmojiId(1154))),
+        ("💨", (EmojiId(160), EmojiId(160))),
+        ("🇩🇿", (EmojiId(3576), EmojiId(3576))),
+        ("🧝🏾\u{200d}♂\u{fe0f}", (EmojiId(1476), EmojiId(1472))),
+        ("🔍", (EmojiId(3124), EmojiId(3124))),
+        ("🙆🏻\u{200d}♂", (EmojiId(723), EmojiId(722))),
+        ("🤷\u{200d}♀", (EmojiId(836), EmojiId(836))),
+        ("🇱🇺", (EmojiId(3651), EmojiId(3651))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2327), EmojiId(2315))),
+        ("🥓", (EmojiId(2631), EmojiId(2631))),
+        ("🏳\u{fe0f}\u{200d}⚧\u{fe0f}", (EmojiId(3510), EmojiId(3510))),
+        ("🧑🏼\u{200d}🎓", (EmojiId(862), EmojiId(860))),
+        ("🙅🏼\u{200d}♀", (EmojiId(712), EmojiId(710))),
+        ("🧜", (EmojiId(1448), EmojiId(1448))),
+        ("🔕", (EmojiId(3074), EmojiId(3074))),
+        ("👩🏻\u{200d}🦲", (EmojiId(621), EmojiId(620))),
+        ("▫", (EmojiId(3493), EmojiId(3493))),
+        ("🇳🇱", (EmojiId(3683), EmojiId(3683))),
+        ("🥝", (EmojiId(2597), EmojiId(2597))),
+        ("🤔", (EmojiId(34), EmojiId(34))),
+        ("🙆🏻", (EmojiId(717), EmojiId(716))),
+        ("🛺", (EmojiId(2816), EmojiId(2816))),
+        ("🏃🏾\u{200d}♂\u{200d}➡", (EmojiId(1759), EmojiId(1755))),
+        ("🏃🏻\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1750), EmojiId(1749))),
+        ("🤞🏿", (EmojiId(263), EmojiId(258))),
+        ("🇯🇴", (EmojiId(3630), EmojiId(3630))),
+        ("🕯\u{fe0f}", (EmojiId(3126), EmojiId(3126))),
+        ("🕑", (EmojiId(2867), EmojiId(2867))),
+        ("🦸🏽\u{200d}♂\u{fe0f}", (EmojiId(1367), EmojiId(1364))),
+        ("🤵🏿\u{200d}♂", (EmojiId(1267), EmojiId(1262))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏼", (EmojiId(2330), EmojiId(2315))),
+        ("👩\u{200d}💻", (EmojiId(1034), EmojiId(1034))),
+        ("🗣", (EmojiId(2418), EmojiId(2418))),
+        ("🏐", (EmojiId(2965), EmojiId(2965))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2093), EmojiId(2081))),
+        ("👨\u{200d}🏭", (EmojiId(974), EmojiId(974))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2251), EmojiId(2237))),
+        ("🧔🏾", (EmojiId(540), EmojiId(536))),
+        ("🤸\u{200d}♀", (EmojiId(1988), EmojiId(1988))),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏿", (EmojiId(2202), EmojiId(2185))),
+        ("🧱", (EmojiId(2735), EmojiId(2735))),
+        ("🤷🏼", (EmojiId(826), EmojiId(824))),
+        ("💪", (EmojiId(446), EmojiId(446))),
+        ("👨\u{200d}🦽", (EmojiId(1701), EmojiId(1701))),
+        ("🧗🏿\u{200d}♂", (EmojiId(1811), EmojiId(1806))),
+        ("🤿", (EmojiId(2983), EmojiId(2983))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2239), EmojiId(2237))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2323), EmojiId(2315))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2253), EmojiId(2237))),
+        ("👩🏻\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1720), EmojiId(1719))),
+        ("💃🏾", (EmojiId(1765), EmojiId(1761))),
+        ("🧚🏽\u{200d}♀\u{fe0f}", (EmojiId(1427), EmojiId(1424))),
+        ("🚶🏼\u{200d}♀\u{fe0f}", (EmojiId(1541), EmojiId(1539))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2221), EmojiId(2211))),
+        ("💕", (EmojiId(136), EmojiId(136))),
+        ("🏂🏿", (EmojiId(1831), EmojiId(1826))),
+        ("👱", (EmojiId(524), EmojiId(524))),
+        ("👫🏾", (EmojiId(2137), EmojiId(2133))),
+        ("😥", (EmojiId(90), EmojiId(90))),
+        ("✋🏿", (EmojiId(191), EmojiId(186))),
+        ("🤏🏿", (EmojiId(251), EmojiId(246))),
+        ("🚜", (EmojiId(2810), EmojiId(2810))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2236), EmojiId(2211))),
+        ("🧑🏻\u{200d}🦽\u{200d}➡", (EmojiId(1696), EmojiId(1695))),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏾", (EmojiId(2305), EmojiId(2289))),
+        ("🕋", (EmojiId(2764), EmojiId(2764))),
+        ("🚵🏻\u{200d}♀\u{fe0f}", (EmojiId(1971), EmojiId(1970))),
+        ("🧝🏻\u{200d}♀\u{fe0f}", (EmojiId(1479), EmojiId(1478))),
+        ("▶", (EmojiId(3357), EmojiId(3357))),
+        ("🪪", (EmojiId(3280), EmojiId(3280))),
+        ("👳🏼", (EmojiId(1228), EmojiId(1226))),
+        ("🧲", (EmojiId(3231), EmojiId(3231))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2337), EmojiId(2315))),
+        ("🧏🏽", (EmojiId(773), EmojiId(770))),
+        ("🏊🏽\u{200d}♀", (EmojiId(1901), EmojiId(1898))),
+        ("🧑🏼\u{200d}⚖\u{fe0f}", (EmojiId(898), EmojiId(896))),
+        ("⚜\u{fe0f}", (EmojiId(3399), EmojiId(3399))),
+        ("👩\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1719), EmojiId(1719))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2246), EmojiId(2237))),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏾", (EmojiId(2171), EmojiId(2159))),
+        ("🧑🏼\u{200d}⚕\u{fe0f}", (EmojiId(844), EmojiId(842))),
+        ("🧛🏾\u{200d}♂", (EmojiId(1440), EmojiId(1436))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2280), EmojiId(2263))),
+        ("🐈\u{200d}⬛", (EmojiId(2442), EmojiId(2442))),
+        ("🤹🏾\u{200d}♀", (EmojiId(2049), EmojiId(2045))),
+        ("🧘🏼\u{200d}♀\u{fe0f}", (EmojiId(2065), EmojiId(2063))),
+        ("✖", (EmojiId(3382), EmojiId(3382))),
+        ("👨🏾\u{200d}🦰", (EmojiId(558), EmojiId(554))),
+        ("👽", (EmojiId(114), EmojiId(114))),
+        ("🛳", (EmojiId(2837), EmojiId(2837))),
+        ("🚵\u{200d}♀", (EmojiId(1970), EmojiId(1970))),
+        ("🧑🏾\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1699), EmojiId(1695))),
+        ("🙍🏽\u{200d}♀", (EmojiId(677), EmojiId(674))),
+        ("🙅🏻\u{200d}♀", (EmojiId(711), EmojiId(710))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2368), EmojiId(2367))),
+        ("👉🏼", (EmojiId(296), EmojiId(294))),
+        ("🫳🏽", (EmojiId(213), EmojiId(210))),
+        ("🥉", (EmojiId(2960), EmojiId(2960))),
+        ("🔀", (EmojiId(3354), EmojiId(3354))),
+        ("🫣", (EmojiId(32), EmojiId(32))),
+        ("💇🏿\u{200d}♀", (EmojiId(1526), EmojiId(1521))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏿", (EmojiId(2366), EmojiId(2341))),
+        ("📢", (EmojiId(3070), EmojiId(3070))),
+        ("🧍🏻\u{200d}♂", (EmojiId(1570), EmojiId(1569))),
+        ("🤘🏿", (EmojiId(281), EmojiId(276))),
+        ("👩🏽\u{200d}🎓", (EmojiId(875), EmojiId(872))),
+        ("☃", (EmojiId(2928), EmojiId(2928))),
+        ("🧎🏿\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1616), EmojiId(1611))),
+        ("🧧", (EmojiId(2949), EmojiId(2949))),
+        ("*\u{fe0f}\u{20e3}", (EmojiId(3419), EmojiId(3419))),
+        ("💪🏿", (EmojiId(451), EmojiId(446))),
+        ("🍛", (EmojiId(2657), EmojiId(2657))),
+        ("📥", (EmojiId(3163), EmojiId(3163))),
+        ("🚠", (EmojiId(2849), EmojiId(2849))),
+        ("👩🏿\u{200d}⚖\u{fe0f}", (EmojiId(913), EmojiId(908))),
+        ("🤵\u{200d}♀", (EmojiId(1268), EmojiId(1268))),
+        ("🇦🇺", (EmojiId(3525), EmojiId(3525))),
+        ("👱🏼\u{200d}♀", (EmojiId(634), EmojiId(632))),
+        ("⏰", (EmojiId(2859), EmojiId(2859))),
+        ("🧑🏽\u{200d}🦱", (EmojiId(605), EmojiId(602))),
+        ("🦕", (EmojiId(2523), EmojiId(2523))),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏼", (EmojiId(2174), EmojiId(2159))),
+        ("🇻🇬", (EmojiId(3758), EmojiId(3758))),
+        ("💂\u{200d}♂", (EmojiId(1172), EmojiId(1172))),
+        ("🈳", (EmojiId(3465), EmojiId(3465))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏿", (EmojiId(2358), EmojiId(2341))),
+        ("🤸🏼\u{200d}♂\u{fe0f}", (EmojiId(1984), EmojiId(1982))),
+        ("🕘", (EmojiId(2881), EmojiId(2881))),
+        ("🏊🏿\u{200d}♂\u{fe0f}", (EmojiId(1897), EmojiId(1892))),
+        ("🙋🏾", (EmojiId(756), EmojiId(752))),
+        ("🤷🏼\u{200d}♀\u{fe0f}", (EmojiId(838), EmojiId(836))),
+        ("📌", (EmojiId(3191), EmojiId(3191))),
+        ("🤌🏽", (EmojiId(243), EmojiId(240))),
+        ("🏑", (EmojiId(2972), EmojiId(2972))),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏼", (EmojiId(2156), EmojiId(2133))),
+        ("🧚🏾\u{200d}♂\u{fe0f}", (EmojiId(1422), EmojiId(1418))),
+        ("🙎🏼\u{200d}♂\u{fe0f}", (EmojiId(688), EmojiId(686))),
+        ("🫶🏽", (EmojiId(381), EmojiId(378))),
+        ("🇦🇸", (EmojiId(3523), EmojiId(3523))),
+        ("🛬", (EmojiId(2844), EmojiId(2844))),
+        ("🇭🇷", (EmojiId(3614), EmojiId(3614))),
+        ("😘", (EmojiId(17), EmojiId(17))),
+        ("🇬🇺", (EmojiId(3608), EmojiId(3608))),
+        ("🏃🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1754), EmojiId(1749))),
+        ("🧎🏽\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1614), EmojiId(1611))),
+        ("🚩", (EmojiId(3505), EmojiId(3505))),
+        ("🚶🏽\u{200d}➡\u{fe0f}", (EmojiId(1548), EmojiId(1545))),
+        ("🥴", (EmojiId(65), EmojiId(65))),
+        ("⚓", (EmojiId(2832), EmojiId(2832))),
+        ("✋🏼", (EmojiId(188), EmojiId(186))),
+        ("🇦🇹", (EmojiId(3524), EmojiId(3524))),
+        ("🧖🏻\u{200d}♀\u{fe0f}", (EmojiId(1795), EmojiId(1794))),
+        ("🚣🏽\u{200d}♂\u{fe0f}", (EmojiId(1877), EmojiId(1874))),
+        ("🇺🇳", (EmojiId(3751), EmojiId(3751))),
+        ("🏌🏼\u{200d}♀", (EmojiId(1846), EmojiId(1844))),
+        ("👰🏼\u{200d}♂\u{fe0f}", (EmojiId(1282), EmojiId(1280))),
+        ("🔑", (EmojiId(3205), EmojiId(3205))),
+        ("🤳🏽", (EmojiId(443), EmojiId(440))),
+        ("🌼", (EmojiId(2562), EmojiId(2562))),
+        ("🕠", (EmojiId(2874), EmojiId(2874))),
+        ("📆", (EmojiId(3183), EmojiId(3183))),
+        ("👨🏽\u{200d}✈", (EmojiId(1085), EmojiId(1082))),
+        ("💓", (EmojiId(134), EmojiId(134))),
+        ("🥼", (EmojiId(3022), EmojiId(3022))),
+        ("🚴🏻\u{200d}♀\u{fe0f}", (EmojiId(1953), EmojiId(1952))),
+        ("🚫", (EmojiId(3297), EmojiId(3297))),
+        ("👧🏿", (EmojiId(517), EmojiId(512))),
+        ("🦆", (EmojiId(2504), EmojiId(2504))),
+        ("🧑\u{200d}✈", (EmojiId(1076), EmojiId(1076))),
+        ("🧎🏻\u{200d}♂", (EmojiId(1588), EmojiId(1587))),
+        ("🤞🏽", (EmojiId(261), EmojiId(258))),
+        ("👮🏻\u{200d}♂\u{fe0f}", (EmojiId(1137), EmojiId(1136))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2253), EmojiId(2237))),
+        ("👨\u{200d}👧", (EmojiId(2410), EmojiId(2410))),
+        ("🤏🏾", (EmojiId(250), EmojiId(246))),
+        ("🧎🏾\u{200d}➡", (EmojiId(1603), EmojiId(1599))),
+        ("🏋🏿\u{200d}♀\u{fe0f}", (EmojiId(1939), EmojiId(1934))),
+        ("👷🏻\u{200d}♀\u{fe0f}", (EmojiId(1203), EmojiId(1202))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2245), EmojiId(2237))),
+        ("🙋🏼\u{200d}♀", (EmojiId(766), EmojiId(764))),
+        ("☹\u{fe0f}", (EmojiId(79), EmojiId(79))),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏾", (EmojiId(2145), EmojiId(2133))),
+        ("✊🏽", (EmojiId(345), EmojiId(342))),
+        ("🇱🇻", (EmojiId(3652), EmojiId(3652))),
+        ("↕", (EmojiId(3315), EmojiId(3315))),
+        ("🧙🏾\u{200d}♂\u{fe0f}", (EmojiId(1404), EmojiId(1400))),
+        ("🏣", (EmojiId(2744), EmojiId(2744))),
+        ("⏸", (EmojiId(3368), EmojiId(3368))),
+        ("🟧", (EmojiId(3480), EmojiId(3480))),
+        ("⚗", (EmojiId(3233), EmojiId(3233))),
+        ("🛖", (EmojiId(2738), EmojiId(2738))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2274), EmojiId(2263))),
+        ("🫱🏼\u{200d}🫲🏽", (EmojiId(407), EmojiId(396))),
+        ("🇲🇱", (EmojiId(3662), EmojiId(3662))),
+        ("🐍", (EmojiId(2520), EmojiId(2520))),
+        ("🗨", (EmojiId(164), EmojiId(164))),
+        ("🧎🏾\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1609), EmojiId(1605))),
+        ("💴", (EmojiId(3150), EmojiId(3150))),
+        ("🍐", (EmojiId(2592), EmojiId(2592))),
+        ("🙎🏿\u{200d}♀", (EmojiId(697), EmojiId(692))),
+        ("⛔", (EmojiId(3296), EmojiId(3296))),
+        ("👩🏻\u{200d}🍳", (EmojiId(945), EmojiId(944))),
+        ("🇸🇪", (EmojiId(3714), EmojiId(3714))),
+        ("🙅🏼\u{200d}♂", (EmojiId(706), EmojiId(704))),
+        ("🦽", (EmojiId(2814), EmojiId(2814))),
+        ("🫷🏼", (EmojiId(224), EmojiId(222))),
+        ("🫰🏾", (EmojiId(268), EmojiId(264))),
+        ("💃🏽", (EmojiId(1764), EmojiId(1761))),
+        ("🧎🏾\u{200d}♀", (EmojiId(1597), EmojiId(1593))),
+        ("🥔", (EmojiId(2603), EmojiId(2603))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2269), EmojiId(2263))),
+        ("👩🏽\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1722), EmojiId(1719))),
+        ("👨🏾\u{200d}⚖", (EmojiId(906), EmojiId(902))),
+        ("🤹🏽\u{200d}♀\u{fe0f}", (EmojiId(2048), EmojiId(2045))),
+        ("🧑🏼\u{200d}🦯\u{200d}➡", (EmojiId(1625), EmojiId(1623))),
+        ("🇳🇮", (EmojiId(3682), EmojiId(3682))),
+        ("👩🏻\u{200d}🎤", (EmojiId(1053), EmojiId(1052))),
+        ("👩\u{200d}🍼", (EmojiId(1316), EmojiId(1316))),
+        ("🇵🇪", (EmojiId(3691), EmojiId(3691))),
+        ("🔻", (EmojiId(3499), EmojiId(3499))),
+        ("⬆", (EmojiId(3307), EmojiId(3307))),
+        ("💂🏿\u{200d}♀\u{fe0f}", (EmojiId(1183), EmojiId(1178))),
+        ("🇲🇷", (EmojiId(3668), EmojiId(3668))),
+        ("👱🏽", (EmojiId(527), EmojiId(524))),
+        ("🙍🏾\u{200d}♀", (EmojiId(678), EmojiId(674))),
+        ("🏌\u{200d}♂\u{fe0f}", (EmojiId(1838), EmojiId(1838))),
+        ("🛼", (EmojiId(2820), EmojiId(2820))),
+        ("🧻", (EmojiId(3264), EmojiId(3264))),
+        ("🥘", (EmojiId(2644), EmojiId(2644))),
+        ("🔞", (EmojiId(3304), EmojiId(3304))),
+        ("🇹🇩", (EmojiId(3733), EmojiId(3733))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2228), EmojiId(2211))),
+        ("🦷", (EmojiId(487), EmojiId(487))),
+        ("🇮🇷", (EmojiId(3625), EmojiId(3625))),
+        ("⛰\u{fe0f}", (EmojiId(2724), EmojiId(2724))),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏽", (EmojiId(2179), EmojiId(2159))),
+        ("🎋", (EmojiId(2943), EmojiId(2943))),
+        ("🏋🏼\u{200d}♀", (EmojiId(1936), EmojiId(1934))),
+        ("🧎🏻\u{200d}♂\u{200d}➡", (EmojiId(1612), EmojiId(1611))),
+        ("↖\u{fe0f}", (EmojiId(3314), EmojiId(3314))),
+        ("😒", (EmojiId(44), EmojiId(44))),
+        ("🤷🏾\u{200d}♀\u{fe0f}", (EmojiId(840), EmojiId(836))),
+        ("🧜🏿\u{200d}♀", (EmojiId(1465), EmojiId(1460))),
+        ("🫃🏼", (EmojiId(1300), EmojiId(1298))),
+        ("😜", (EmojiId(25), EmojiId(25))),
+        ("🪹", (EmojiId(2578), EmojiId(2578))),
+        ("🤵🏽\u{200d}♀\u{fe0f}", (EmojiId(1271), EmojiId(1268))),
+        ("🍱", (EmojiId(2653), EmojiId(2653))),
+        ("🧓🏿", (EmojiId(649), EmojiId(644))),
+        ("🚶\u{200d}♂\u{200d}➡", (EmojiId(1557), EmojiId(1557))),
+        ("👎🏼", (EmojiId(338), EmojiId(336))),
+        ("✊🏼", (EmojiId(344), EmojiId(342))),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏼", (EmojiId(2139), EmojiId(2133))),
+        ("🙋🏻\u{200d}♀", (EmojiId(765), EmojiId(764))),
+        ("🇮🇳", (EmojiId(3622), EmojiId(3622))),
+        ("🧝\u{200d}♀\u{fe0f}", (EmojiId(1478), EmojiId(1478))),
+        ("👩🏽\u{200d}⚖", (EmojiId(911), EmojiId(908))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2238), EmojiId(2237))),
+        ("🍋", (EmojiId(2585), EmojiId(2585))),
+        ("✖\u{fe0f}", (EmojiId(3382), EmojiId(3382))),
+        ("🇬🇷", (EmojiId(3605), EmojiId(3605))),
+        ("🛁", (EmojiId(3257), EmojiId(3257))),
+        ("🦹🏿\u{200d}♀\u{fe0f}", (EmojiId(1393), EmojiId(1388))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2332), EmojiId(2315))),
+        ("🚶", (EmojiId(1527), EmojiId(1527))),
+        ("💇🏼", (EmojiId(1511), EmojiId(1509))),
+        ("⭕", (EmojiId(3403), EmojiId(3403))),
+        ("🏃🏾", (EmojiId(1729), EmojiId(1725))),
+        ("👨🏿\u{200d}🎨", (EmojiId(1069), EmojiId(1064))),
+        ("🖐🏾", (EmojiId(184), EmojiId(180))),
+        ("🇸🇦", (EmojiId(3710), EmojiId(3710))),
+        ("🦹🏼\u{200d}♀", (EmojiId(1390), EmojiId(1388))),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏿", (EmojiId(2194), EmojiId(2185))),
+        ("🚣🏻", (EmojiId(1869), EmojiId(1868))),
+        ("🏛", (EmojiId(2733), EmojiId(2733))),
+        ("🇷🇺", (EmojiId(3708), EmojiId(3708))),
+        ("😶\u{200d}🌫", (EmojiId(42), EmojiId(42))),
+        ("🧖🏽\u{200d}♀\u{fe0f}", (EmojiId(1797), EmojiId(1794))),
+        ("🧑🏿\u{200d}🍳", (EmojiId(937), EmojiId(932))),
+        ("🏊🏻\u{200d}♂", (EmojiId(1893), EmojiId(1892))),
+        ("👩🏿\u{200d}🦼", (EmojiId(1682), EmojiId(1677))),
+        ("🇬🇱", (EmojiId(3600), EmojiId(3600))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2358), EmojiId(2341))),
+        ("💁\u{200d}♀", (EmojiId(746), EmojiId(746))),
+        ("🌬\u{fe0f}", (EmojiId(2919), EmojiId(2919))),
+        ("🤸🏾\u{200d}♀\u{fe0f}", (EmojiId(1992), EmojiId(1988))),
+        ("🗂", (EmojiId(3181), EmojiId(3181))),
+        ("👨\u{200d}👩\u{200d}👧\u{200d}👦", (EmojiId(2395), EmojiId(2395))),
+        ("❗", (EmojiId(3393), EmojiId(3393))),
+        ("♂\u{fe0f}", (EmojiId(3380), EmojiId(3380))),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏽", (EmojiId(2140), EmojiId(2133))),
+        ("🚵🏻\u{200d}♂", (EmojiId(1965), EmojiId(1964))),
+        ("🤘", (EmojiId(276), EmojiId(276))),
+        ("🎒", (EmojiId(3044), EmojiId(3044))),
+        ("🧑🏻", (EmojiId(519), EmojiId(518))),
+        ("🔄", (EmojiId(3322), EmojiId(3322))),
+        ("👰🏾\u{200d}♀", (EmojiId(1290), EmojiId(1286))),
+        ("👈🏼", (EmojiId(290), EmojiId(288))),
+        ("🥩", (EmojiId(2630), EmojiId(2630))),
+        ("💁🏼\u{200d}♀\u{fe0f}", (EmojiId(748), EmojiId(746))),
+        ("🤹🏿", (EmojiId(2038), EmojiId(2033))),
+        ("👩🏾\u{200d}🦱", (EmojiId(600), EmojiId(596))),
+        ("⤴", (EmojiId(3319), EmojiId(3319))),
+        ("🙍🏾\u{200d}♀\u{fe0f}", (EmojiId(678), EmojiId(674))),
+        ("💌", (EmojiId(129), EmojiId(129))),
+        ("🙍🏼\u{200d}♂", (EmojiId(670), EmojiId(668))),
+        ("🇸🇧", (EmojiId(3711), EmojiId(3711))),
+        ("🤵🏻\u{200d}♀", (EmojiId(1269), EmojiId(1268))),
+        ("👨🏼\u{200d}🔬", (EmojiId(1012), EmojiId(1010))),
+        ("🧑\u{200d}🍼", (EmojiId(1328), EmojiId(1328))),
+        ("🧑🏻\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1696), EmojiId(1695))),
+        ("🤾", (EmojiId(2015), EmojiId(2015))),
+        ("🫷🏾", (EmojiId(226), EmojiId(222))),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏼", (EmojiId(2148), EmojiId(2133))),
+        ("🧎🏼\u{200d}♀", (EmojiId(1595), EmojiId(1593))),
+        ("🧑🏻\u{200d}🦯", (EmojiId(1618), EmojiId(1617))),
+        ("🏖", (EmojiId(2728), EmojiId(2728))),
+        ("🚵🏻\u{200d}♂\u{fe0f}", (EmojiId(1965), EmojiId(1964))),
+        ("🚵🏼\u{200d}♀", (EmojiId(1972), EmojiId(1970))),
+        ("🙍\u{200d}♀\u{fe0f}", (EmojiId(674), EmojiId(674))),
+        ("🧜🏽\u{200d}♀", (EmojiId(1463), EmojiId(1460))),
+        ("👃🏽", (EmojiId(481), EmojiId(478))),
+        ("🇱🇷", (EmojiId(3648), EmojiId(3648))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", (EmojiId(2200), EmojiId(2185))),
+        ("🫳", (EmojiId(210), EmojiId(210))),
+        ("🖖🏿", (EmojiId(197), EmojiId(192))),
+        ("🚣🏼\u{200d}♂", (EmojiId(1876), EmojiId(1874))),
+        ("🏍\u{fe0f}", (EmojiId(2812), EmojiId(2812))),
+        ("⏭\u{fe0f}", (EmojiId(3359), EmojiId(3359))),
+        ("🔦", (EmojiId(3128), EmojiId(3128))),
+        ("🧎🏼\u{200d}♂", (EmojiId(1589), EmojiId(1587))),
+        ("🏃🏼\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1757), EmojiId(1755))),
+        ("👨🏾\u{200d}🍳", (EmojiId(942), EmojiId(938))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2369), EmojiId(2367))),
+        ("🇷🇪", (EmojiId(3705), EmojiId(3705))),
+        ("🏅", (EmojiId(2957), EmojiId(2957))),
+        ("🤳🏼", (EmojiId(442), EmojiId(440))),
+        ("⛷", (EmojiId(1825), EmojiId(1825))),
+        ("🙍🏽\u{200d}♂\u{fe0f}", (EmojiId(671), EmojiId(668))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2084), EmojiId(2081))),
+        ("🧑🏻\u{200d}✈", (EmojiId(1077), EmojiId(1076))),
+        ("🧛🏿\u{200d}♀\u{fe0f}", (EmojiId(1447), EmojiId(1442))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏾", (EmojiId(2297), EmojiId(2289))),
+        ("🗃", (EmojiId(3198), EmojiId(3198))),
+        ("🧑🏾\u{200d}🦼", (EmojiId(1657), EmojiId(1653))),
+        ("🧑🏽\u{200d}🎨", (EmojiId(1061), EmojiId(1058))),
+        ("⏲", (EmojiId(2861), EmojiId(2861))),
+        ("👨🏿\u{200d}⚕", (EmojiId(853), EmojiId(848))),
+        ("🎴", (EmojiId(3011), EmojiId(3011))),
+        ("🎾", (EmojiId(2968), EmojiId(2968))),
+        ("♌", (EmojiId(3345), EmojiId(3345))),
+        ("🧏🏽\u{200d}♂", (EmojiId(779), EmojiId(776))),
+        ("👩🏿\u{200d}🦯\u{200d}➡", (EmojiId(1652), EmojiId(1647))),
+        ("🫄🏼", (EmojiId(1306), EmojiId(1304))),
+        ("👨🏼\u{200d}🦯", (EmojiId(1631), EmojiId(1629))),
+        ("👨🏿\u{200d}🦼", (EmojiId(1670), EmojiId(1665))),
+        ("🚣🏾\u{200d}♀", (EmojiId(1884), EmojiId(1880))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2328), EmojiId(2315))),
+        ("🔴", (EmojiId(3470), EmojiId(3470))),
+        ("🕸\u{fe0f}", (EmojiId(2547), EmojiId(2547))),
+        ("😁", (EmojiId(3), EmojiId(3))),
+        ("🦸🏿", (EmojiId(1363), EmojiId(1358))),
+        ("🍫", (EmojiId(2684), EmojiId(2684))),
+        ("🧎🏻", (EmojiId(1582), EmojiId(1581))),
+        ("🧚\u{200d}♂", (EmojiId(1418), EmojiId(1418))),
+        ("♣", (EmojiId(3007), EmojiId(3007))),
+        ("🤛🏽", (EmojiId(357), EmojiId(354))),
+        ("🚶🏾", (EmojiId(1531), EmojiId(1527))),
+        ("7\u{20e3}", (EmojiId(3427), EmojiId(3427))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2218), EmojiId(2211))),
+        ("👇🏽", (EmojiId(315), EmojiId(312))),
+        ("🛕", (EmojiId(2761), EmojiId(2761))),
+        ("🦞", (EmojiId(2671), EmojiId(2671))),
+        ("🧍🏽\u{200d}♀\u{fe0f}", (EmojiId(1578), EmojiId(1575))),
+        ("👩🏽\u{200d}🦽\u{200d}➡", (EmojiId(1722), EmojiId(1719))),
+        ("👰🏾\u{200d}♂", (EmojiId(1284), EmojiId(1280))),
+        ("🕵🏼\u{200d}♀\u{fe0f}", (EmojiId(1162), EmojiId(1160))),
+        ("👐🏽", (EmojiId(387), EmojiId(384))),
+        ("👩🏿\u{200d}🍼", (EmojiId(1321), EmojiId(1316))),
+        ("👳🏿\u{200d}♂", (EmojiId(1237), EmojiId(1232))),
+        ("🏳\u{200d}⚧", (EmojiId(3510), EmojiId(3510))),
+        ("🧑🏿\u{200d}🚒", (EmojiId(1117), EmojiId(1112))),
+        ("🧑🏼", (EmojiId(520), EmojiId(518))),
+        ("👩\u{200d}❤\u{200d}💋\u{200d}👩", (EmojiId(2263), EmojiId(2263))),
+        ("🤮", (EmojiId(61), EmojiId(61))),
+        ("🇳🇪", (EmojiId(3679), EmojiId(3679))),
+        ("🧑🏿\u{200d}🍼", (EmojiId(1333), EmojiId(1328))),
+        ("🆚", (EmojiId(3452), EmojiId(3452))),
+        ("🏋🏻", (EmojiId(1923), EmojiId(1922))),
+        ("🧟\u{200d}♀\u{fe0f}", (EmojiId(1489), EmojiId(1489))),
+        ("🦹🏿", (EmojiId(1381), EmojiId(1376))),
+        ("🧍🏼\u{200d}♀\u{fe0f}", (EmojiId(1577), EmojiId(1575))),
+        ("🦀", (EmojiId(2670), EmojiId(2670))),
+        ("🙋🏼\u{200d}♀\u{fe0f}", (EmojiId(766), EmojiId(764))),
+        ("✊🏿", (EmojiId(347), EmojiId(342))),
+        ("👷🏽\u{200d}♂", (EmojiId(1199), EmojiId(1196))),
+        ("👱🏽\u{200d}♂\u{fe0f}", (EmojiId(641), EmojiId(638))),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏿", (EmojiId(2302), EmojiId(2289))),
+        ("🇸🇰", (EmojiId(3719), EmojiId(3719))),
+        ("🫶🏾", (EmojiId(382), EmojiId(378))),
+        ("🥷🏼", (EmojiId(1186), EmojiId(1184))),
+        ("🧛🏽", (EmojiId(1433), EmojiId(1430))),
+        ("😎", (EmojiId(72), EmojiId(72))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏾", (EmojiId(2375), EmojiId(2367))),
+        ("🙏🏿", (EmojiId(427), EmojiId(422))),
+        ("🚵🏻", (EmojiId(1959), EmojiId(1958))),
+        ("🔢", (EmojiId(3433), EmojiId(3433))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2222), EmojiId(2211))),
+        ("🤬", (EmojiId(104), EmojiId(104))),
+        ("👨🏽\u{200d}🔬", (EmojiId(1013), EmojiId(1010))),
+        ("🫀", (EmojiId(485), EmojiId(485))),
+        ("🕡", (EmojiId(2876), EmojiId(2876))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2254), EmojiId(2237))),
+        ("👨🏽\u{200d}🎤", (EmojiId(1049), EmojiId(1046))),
+        ("👳\u{200d}♂\u{fe0f}", (EmojiId(1232), EmojiId(1232))),
+        ("📁", (EmojiId(3179), EmojiId(3179))),
+        ("🧑🏼\u{200d}🏭", (EmojiId(970), EmojiId(968))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2218), EmojiId(2211))),
+        ("🙍🏾", (EmojiId(666), EmojiId(662))),
+        ("🧙🏽\u{200d}♀", (EmojiId(1409), EmojiId(1406))),
+        ("🏋🏼\u{200d}♀\u{fe0f}", (EmojiId(1936), EmojiId(1934))),
+        ("📏", (EmojiId(3195), EmojiId(3195))),
+        ("🙅\u{200d}♀\u{fe0f}", (EmojiId(710), EmojiId(710))),
+        ("🙋🏾\u{200d}♂", (EmojiId(762), EmojiId(758))),
+        ("👨🏻\u{200d}🏫", (EmojiId(885), EmojiId(884))),
+        ("🇳🇿", (EmojiId(3688), EmojiId(3688))),
+        ("🌑", (EmojiId(2887), EmojiId(2887))),
+        ("🧗🏽\u{200d}♂", (EmojiId(1809), EmojiId(1806))),
+        ("🤦🏽\u{200d}♂\u{fe0f}", (EmojiId(815), EmojiId(812))),
+        ("👨🏾\u{200d}🦱", (EmojiId(564), EmojiId(560))),
+        ("🤽🏼", (EmojiId(1999), EmojiId(1997))),
+        ("🇲🇸", (EmojiId(3669), EmojiId(3669))),
+        ("🦠", (EmojiId(2552), EmojiId(2552))),
+        ("👅", (EmojiId(491), EmojiId(491))),
+        ("🏒", (EmojiId(2973), EmojiId(2973))),
+        ("🏌🏽\u{200d}♀\u{fe0f}", (EmojiId(1847), EmojiId(1844))),
+        ("↙\u{fe0f}", (EmojiId(3312), EmojiId(3312))),
+        ("🐣", (EmojiId(2497), EmojiId(2497))),
+        ("🧛🏿", (EmojiId(1435), EmojiId(1430))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2252), EmojiId(2237))),
+        ("🙆🏼\u{200d}♀", (EmojiId(730), EmojiId(728))),
+        ("🧗🏼\u{200d}♂", (EmojiId(1808), EmojiId(1806))),
+        ("👩\u{200d}👧\u{200d}👧", (EmojiId(2417), EmojiId(2417))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏼", (EmojiId(2364), EmojiId(2341))),
+        ("🛡", (EmojiId(3217), EmojiId(3217))),
+        ("🇩🇰", (EmojiId(3573), EmojiId(3573))),
+        ("🥃", (EmojiId(2702), EmojiId(2702))),
+        ("🤷\u{200d}♂\u{fe0f}", (EmojiId(830), EmojiId(830))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2270), EmojiId(2263))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2252), EmojiId(2237))),
+        ("🏃🏻\u{200d}♀\u{fe0f}", (EmojiId(1738), EmojiId(1737))),
+        ("☠\u{fe0f}", (EmojiId(108), EmojiId(108))),
+        ("💆🏿\u{200d}♀", (EmojiId(1508), EmojiId(1503))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2257), EmojiId(2237))),
+        ("🧑\u{200d}🦰", (EmojiId(590), EmojiId(590))),
+        ("🏃🏼", (EmojiId(1727), EmojiId(1725))),
+        ("🧭", (EmojiId(2722), EmojiId(2722))),
+        ("🚦", (EmojiId(2829), EmojiId(2829))),
+        ("🙇🏾\u{200d}♂\u{fe0f}", (EmojiId(798), EmojiId(794))),
+        ("🎢", (EmojiId(2779), EmojiId(2779))),
+        ("✳\u{fe0f}", (EmojiId(3412), EmojiId(3412))),
+        ("🧛🏽\u{200d}♂", (EmojiId(1439), EmojiId(1436))),
+        ("🙆🏿\u{200d}♀", (EmojiId(733), EmojiId(728))),
+        ("👎", (EmojiId(336), EmojiId(336))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏻", (EmojiId(2337), EmojiId(2315))),
+        ("🧜\u{200d}♂\u{fe0f}", (EmojiId(1454), EmojiId(1454))),
+        ("🤾🏾\u{200d}♂", (EmojiId(2025), EmojiId(2021))),
+        ("🧎🏼\u{200d}➡\u{fe0f}", (EmojiId(1601), EmojiId(1599))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2377), EmojiId(2367))),
+        ("🍌", (EmojiId(2587), EmojiId(2587))),
+        ("📘", (EmojiId(3135), EmojiId(3135))),
+        ("🧏🏼\u{200d}♀", (EmojiId(784), EmojiId(782))),
+        ("🏃\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1755), EmojiId(1755))),
+        ("🧑🏾\u{200d}💻", (EmojiId(1026), EmojiId(1022))),
+        ("🧑🏻\u{200d}🦳", (EmojiId(615), EmojiId(614))),
+        ("👷🏿\u{200d}♀", (EmojiId(1207), EmojiId(1202))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2089), EmojiId(2081))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2083), EmojiId(2081))),
+        ("🧔🏽\u{200d}♂\u{fe0f}", (EmojiId(545), EmojiId(542))),
+        ("🧑🏾\u{200d}🦲", (EmojiId(630), EmojiId(626))),
+        ("🦹🏿\u{200d}♂", (EmojiId(1387), EmojiId(1382))),
+        ("🤾🏻", (EmojiId(2016), EmojiId(2015))),
+        ("👵", (EmojiId(656), EmojiId(656))),
+        ("🙋\u{200d}♂\u{fe0f}", (EmojiId(758), EmojiId(758))),
+        ("🐱", (EmojiId(2440), EmojiId(2440))),
+        ("🥑", (EmojiId(2601), EmojiId(2601))),
+        ("👩🏽\u{200d}✈\u{fe0f}", (EmojiId(1091), EmojiId(1088))),
+        ("🏌🏻", (EmojiId(1833), EmojiId(1832))),
+        ("💇🏼\u{200d}♀\u{fe0f}", (EmojiId(1523), EmojiId(1521))),
+        ("🧎🏽\u{200d}♂\u{200d}➡", (EmojiId(1614), EmojiId(1611))),
+        ("🏋🏼", (EmojiId(1924), EmojiId(1922))),
+        ("🧛\u{200d}♀\u{fe0f}", (EmojiId(1442), EmojiId(1442))),
+        ("⬅\u{fe0f}", (EmojiId(3313), EmojiId(3313))),
+        ("🚣🏻\u{200d}♀\u{fe0f}", (EmojiId(1881), EmojiId(1880))),
+        ("🪦", (EmojiId(3274), EmojiId(3274))),
+        ("👨\u{200d}🏫", (EmojiId(884), EmojiId(884))),
+        ("⛎", (EmojiId(3353), EmojiId(3353))),
+        ("👨🏾\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1639), EmojiId(1635))),
+        ("🧙🏽\u{200d}♂\u{fe0f}", (EmojiId(1403), EmojiId(1400))),
+        ("👵🏼", (EmojiId(658), EmojiId(656))),
+        ("👨🏿\u{200d}🍼", (EmojiId(1327), EmojiId(1322))),
+        ("🇲🇩", (EmojiId(3656), EmojiId(3656))),
+        ("🚻", (EmojiId(3287), EmojiId(3287))),
+        ("🅾\u{fe0f}", (EmojiId(3447), EmojiId(3447))),
+        ("🧚🏿\u{200d}♀\u{fe0f}", (EmojiId(1429), EmojiId(1424))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2284), EmojiId(2263))),
+        ("🙍🏻\u{200d}♂\u{fe0f}", (EmojiId(669), EmojiId(668))),
+        ("🧑🏻\u{200d}🦯\u{200d}➡", (EmojiId(1624), EmojiId(1623))),
+        ("🚣🏾", (EmojiId(1872), EmojiId(1868))),
+        ("🫃🏾", (EmojiId(1302), EmojiId(1298))),
+        ("🌠", (EmojiId(2906), EmojiId(2906))),
+        ("🤷🏿\u{200d}♀\u{fe0f}", (EmojiId(841), EmojiId(836))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2244), EmojiId(2237))),
+        ("👒", (EmojiId(3056), EmojiId(3056))),
+        ("🌕", (EmojiId(2891), EmojiId(2891))),
+        ("🤽🏾\u{200d}♂", (EmojiId(2007), EmojiId(2003))),
+        ("😿", (EmojiId(124), EmojiId(124))),
+        ("🧑\u{200d}✈\u{fe0f}", (EmojiId(1076), EmojiId(1076))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", (EmojiId(2196), EmojiId(2185))),
+        ("☣\u{fe0f}", (EmojiId(3306), EmojiId(3306))),
+        ("👴🏽", (EmojiId(653), EmojiId(650))),
+        ("🧑🏽\u{200d}🦲", (EmojiId(629), EmojiId(626))),
+        ("🤹🏼\u{200d}♂", (EmojiId(2041), EmojiId(2039))),
+        ("🦺", (EmojiId(3023), EmojiId(3023))),
+        ("🧑\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1659), EmojiId(1659))),
+        ("🙎🏾\u{200d}♀\u{fe0f}", (EmojiId(696), EmojiId(692))),
+        ("🦉", (EmojiId(2506), EmojiId(2506))),
+        ("🇨🇲", (EmojiId(3559), EmojiId(3559))),
+        ("🧔🏻\u{200d}♀\u{fe0f}", (EmojiId(549), EmojiId(548))),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏼", (EmojiId(2122), EmojiId(2107))),
+        ("🎅🏾", (EmojiId(1344), EmojiId(1340))),
+        ("🧜🏼\u{200d}♂", (EmojiId(1456), EmojiId(1454))),
+        ("👩🏼\u{200d}🍳", (EmojiId(946), EmojiId(944))),
+        ("🇳🇫", (EmojiId(3680), EmojiId(3680))),
+        ("🚲", (EmojiId(2817), EmojiId(2817))),
+        ("😯", (EmojiId(81), EmojiId(81))),
+        ("🧍🏾\u{200d}♀", (EmojiId(1579), EmojiId(1575))),
+        ("🛍\u{fe0f}", (EmojiId(3043), EmojiId(3043))),
+        ("🧑🏽\u{200d}💻", (EmojiId(1025), EmojiId(1022))),
+        ("👷🏼", (EmojiId(1192), EmojiId(1190))),
+        ("🚴🏾\u{200d}♀\u{fe0f}", (EmojiId(1956), EmojiId(1952))),
+        ("🎮", (EmojiId(2995), EmojiId(2995))),
+        ("🤘🏼", (EmojiId(278), EmojiId(276))),
+        ("🦸🏼", (EmojiId(1360), EmojiId(1358))),
+        ("🧛🏼\u{200d}♀\u{fe0f}", (EmojiId(1444), EmojiId(1442))),
+        ("🚣\u{200d}♀\u{fe0f}", (EmojiId(1880), EmojiId(1880))),
+        ("👩🏾\u{200d}🚀", (EmojiId(1110), EmojiId(1106))),
+        ("👩🏿\u{200d}💻", (EmojiId(1039), EmojiId(1034))),
+        ("🪑", (EmojiId(3253), EmojiId(3253))),
+        ("👱🏾", (EmojiId(528), EmojiId(524))),
+        ("🚣\u{200d}♂", (EmojiId(1874), EmojiId(1874))),
+        ("🚶🏼\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1553), EmojiId(1551))),
+        ("🏃🏿\u{200d}♀\u{fe0f}", (EmojiId(1742), EmojiId(1737))),
+        ("⛹🏾\u{200d}♀\u{fe0f}", (EmojiId(1920), EmojiId(1916))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2385), EmojiId(2367))),
+        ("🥜", (EmojiId(2613), EmojiId(2613))),
+        ("🏋🏽\u{200d}♀\u{fe0f}", (EmojiId(1937), EmojiId(1934))),
+        ("👸🏼", (EmojiId(1222), EmojiId(1220))),
+        ("👟", (EmojiId(3047), EmojiId(3047))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏾", (EmojiId(2301), EmojiId(2289))),
+        ("🧑🏽\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1626), EmojiId(1623))),
+        ("🇲🇲", (EmojiId(3663), EmojiId(3663))),
+        ("😫", (EmojiId(99), EmojiId(99))),
+        ("☣", (EmojiId(3306), EmojiId(3306))),
+        ("💆\u{200d}♂\u{fe0f}", (EmojiId(1497), EmojiId(1497))),
+        ("🦴", (EmojiId(488), EmojiId(488))),
+        ("🪻", (EmojiId(2564), EmojiId(2564))),
+        ("🔷", (EmojiId(3495), EmojiId(3495))),
+        ("⬅", (EmojiId(3313), EmojiId(3313))),
+        ("👖", (EmojiId(3026), EmojiId(3026))),
+        ("🚾", (EmojiId(3289), EmojiId(3289))),
+        ("🧙🏻\u{200d}♂\u{fe0f}", (EmojiId(1401), EmojiId(1400))),
+        ("🧑\u{200d}🦲", (EmojiId(626), EmojiId(626))),
+        ("🌪\u{fe0f}", (EmojiId(2917), EmojiId(2917))),
+        ("⏹\u{fe0f}", (EmojiId(3369), EmojiId(3369))),
+        ("🧎🏽\u{200d}➡\u{fe0f}", (EmojiId(1602), EmojiId(1599))),
+        ("❤\u{fe0f}\u{200d}🔥", (EmojiId(140), EmojiId(140))),
+        ("💪🏽", (EmojiId(449), EmojiId(446))),
+        ("💆\u{200d}♂", (EmojiId(1497), EmojiId(1497))),
+        ("🧎🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1615), EmojiId(1611))),
+        ("🙏🏾", (EmojiId(426), EmojiId(422))),
+        ("👲🏽", (EmojiId(1247), EmojiId(1244))),
+        ("🦪", (EmojiId(2674), EmojiId(2674))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2375), EmojiId(2367))),
+        ("🧑🏻\u{200d}🦲", (EmojiId(627), EmojiId(626))),
+        ("🏌\u{200d}♀", (EmojiId(1844), EmojiId(1844))),
+        ("🤴", (EmojiId(1214), EmojiId(1214))),
+        ("🧛🏿\u{200d}♂\u{fe0f}", (EmojiId(1441), EmojiId(1436))),
+        ("🏓", (EmojiId(2975), EmojiId(2975))),
+        ("👩🏾\u{200d}💼", (EmojiId(1002), EmojiId(998))),
+        ("👩🏻\u{200d}🏫", (EmojiId(891), EmojiId(890))),
+        ("👊🏻", (EmojiId(349), EmojiId(348))),
+        ("🤾🏿", (EmojiId(2020), EmojiId(2015))),
+        ("👁\u{200d}🗨\u{fe0f}", (EmojiId(163), EmojiId(163))),
+        ("🤷🏽", (EmojiId(827), EmojiId(824))),
+        ("🫓", (EmojiId(2622), EmojiId(2622))),
+        ("🦸\u{200d}♀", (EmojiId(1370), EmojiId(1370))),
+        ("🐨", (EmojiId(2486), EmojiId(2486))),
+        ("🤷🏼\u{200d}♂\u{fe0f}", (EmojiId(832), EmojiId(830))),
+        ("🙋🏼\u{200d}♂\u{fe0f}", (EmojiId(760), EmojiId(758))),
+        ("💂🏼\u{200d}♀", (EmojiId(1180), EmojiId(1178))),
+        ("🇹🇼", (EmojiId(3746), EmojiId(3746))),
+        ("🇨🇳", (EmojiId(3560), EmojiId(3560))),
+        ("🌉", (EmojiId(2774), EmojiId(2774))),
+        ("🥤", (EmojiId(2704), EmojiId(2704))),
+        ("🧝", (EmojiId(1466), EmojiId(1466))),
+        ("👨\u{200d}👨\u{200d}👦\u{200d}👦", (EmojiId(2401), EmojiId(2401))),
+        ("🫷", (EmojiId(222), EmojiId(222))),
+        ("🦋", (EmojiId(2538), EmojiId(2538))),
+        ("🤶🏻", (EmojiId(1347), EmojiId(1346))),
+        ("🖖🏼", (EmojiId(194), EmojiId(192))),
+        ("☝🏾", (EmojiId(322), EmojiId(318))),
+        ("🩷", (EmojiId(143), EmojiId(143))),
+        ("🧗🏿\u{200d}♂\u{fe0f}", (EmojiId(1811), EmojiId(1806))),
+        ("🥥", (EmojiId(2600), EmojiId(2600))),
+        ("🧚🏻\u{200d}♂\u{fe0f}", (EmojiId(1419), EmojiId(1418))),
+        ("🙍\u{200d}♀", (EmojiId(674), EmojiId(674))),
+        ("🕵\u{fe0f}", (EmojiId(1148), EmojiId(1148))),
+        ("🏊🏼\u{200d}♀\u{fe0f}", (EmojiId(1900), EmojiId(1898))),
+        ("🦹\u{200d}♂\u{fe0f}", (EmojiId(1382), EmojiId(1382))),
+        ("💝", (EmojiId(131), EmojiId(131))),
+        ("🙆\u{200d}♀\u{fe0f}", (EmojiId(728), EmojiId(728))),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏿", (EmojiId(2120), EmojiId(2107))),
+        ("🧑🏻\u{200d}🎨", (EmojiId(1059), EmojiId(1058))),
+        ("🧗🏾", (EmojiId(1804), EmojiId(1800))),
+        ("🇵🇸", (EmojiId(3700), EmojiId(3700))),
+        ("🏪", (EmojiId(2750), EmojiId(2750))),
+        ("🙅🏽\u{200d}♀\u{fe0f}", (EmojiId(713), EmojiId(710))),
+        ("👩🏾\u{200d}⚕", (EmojiId(858), EmojiId(854))),
+        ("🤦🏽\u{200d}♀\u{fe0f}", (EmojiId(821), EmojiId(818))),
+        ("👩🏾\u{200d}🦽", (EmojiId(1717), EmojiId(1713))),
+        ("🖌\u{fe0f}", (EmojiId(3175), EmojiId(3175))),
+        ("🫳🏾", (EmojiId(214), EmojiId(210))),
+        ("🚵\u{200d}♂", (EmojiId(1964), EmojiId(1964))),
+        ("⛳", (EmojiId(2980), EmojiId(2980))),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏿", (EmojiId(2154), EmojiId(2133))),
+        ("🗯", (EmojiId(165), EmojiId(165))),
+        ("👸🏻", (EmojiId(1221), EmojiId(1220))),
+        ("🈴", (EmojiId(3464), EmojiId(3464))),
+        ("👮🏻\u{200d}♂", (EmojiId(1137), EmojiId(1136))),
+        ("🏂🏼", (EmojiId(1828), EmojiId(1826))),
+        ("🍺", (EmojiId(2699), EmojiId(2699))),
+        ("🇮🇸", (EmojiId(3626), EmojiId(3626))),
+        ("👨", (EmojiId(530), EmojiId(530))),
+        ("💐", (EmojiId(2553), EmojiId(2553))),
+        ("🚶🏼\u{200d}♂\u{fe0f}", (EmojiId(1535), EmojiId(1533))),
+        ("👨🏿\u{200d}💼", (EmojiId(997), EmojiId(992))),
+        ("👩🏿\u{200d}🦼\u{200d}➡", (EmojiId(1688), EmojiId(1683))),
+        ("🏃🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1757), EmojiId(1755))),
+        ("🧑🏻\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1624), EmojiId(1623))),
+        ("👼🏻", (EmojiId(1335), EmojiId(1334))),
+        ("🧍🏾\u{200d}♂", (EmojiId(1573), EmojiId(1569))),
+        ("🍮", (EmojiId(2687), EmojiId(2687))),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏼", (EmojiId(2113), EmojiId(2107))),
+        ("🙅", (EmojiId(698), EmojiId(698))),
+        ("🇮🇴", (EmojiId(3623), EmojiId(3623))),
+        ("🧖🏼\u{200d}♂\u{fe0f}", (EmojiId(1790), EmojiId(1788))),
+        ("🦡", (EmojiId(2492), EmojiId(2492))),
+        ("🧖🏼\u{200d}♀", (EmojiId(1796), EmojiId(1794))),
+        ("👩🏿\u{200d}🦰", (EmojiId(589), EmojiId(584))),
+        ("👨🏼\u{200d}🦳", (EmojiId(568), EmojiId(566))),
+        ("👩🏽\u{200d}✈", (EmojiId(1091), EmojiId(1088))),
+        ("👩🏿\u{200d}⚕\u{fe0f}", (EmojiId(859), EmojiId(854))),
+        ("👳🏻\u{200d}♂\u{fe0f}", (EmojiId(1233), EmojiId(1232))),
+        ("💒", (EmojiId(2756), EmojiId(2756))),
+        ("🧑🏾\u{200d}🦽\u{200d}➡", (EmojiId(1699), EmojiId(1695))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏻", (EmojiId(2333), EmojiId(2315))),
+        ("👣", (EmojiId(2427), EmojiId(2427))),
+        ("😬", (EmojiId(46), EmojiId(46))),
+        ("🕟", (EmojiId(2872), EmojiId(2872))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2348), EmojiId(2341))),
+        ("🧑🏼\u{200d}✈", (EmojiId(1078), EmojiId(1076))),
+        ("💾", (EmojiId(3111), EmojiId(3111))),
+        ("🤵🏿\u{200d}♂\u{fe0f}", (EmojiId(1267), EmojiId(1262))),
+        ("🏚", (EmojiId(2740), EmojiId(2740))),
+    ],
+};
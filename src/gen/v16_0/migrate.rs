@@ -0,0 +1,19 @@
+// Code generated by `cargo run --package generate`. DO NOT EDIT.
+
+use crate::UnicodeVersion;
+
+/// The Unicode version of the previous release.
+pub const PREVIOUS: UnicodeVersion = UnicodeVersion { major: 15, minor: 1 };
+
+/// Names from the previous version that were renamed, sorted by name.
+pub static NAMES: &[(&str, usize)] = &[
+    ("mx claus", 1353),
+    ("mx claus: dark skin tone", 1358),
+    ("mx claus: light skin tone", 1354),
+    ("mx claus: medium skin tone", 1356),
+    ("mx claus: medium-dark skin tone", 1357),
+    ("mx claus: medium-light skin tone", 1355),
+];
+
+/// Emojis from the previous version whose encoding changed, sorted.
+pub static ENCODINGS: &[(&str, usize)] = &[];
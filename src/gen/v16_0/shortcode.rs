@@ -0,0 +1,4225 @@
+// Code generated by `cargo run --package generate`. DO NOT EDIT.
+
+/// All shortcodes and their index into `EMOJIS` ordered by shortcode.
+pub const SORTED: &[(&str, u16)] = &[
+    ("+1", 331),
+    ("-1", 337),
+    ("100", 156),
+    ("1234", 3440),
+    ("1st_place_medal", 2962),
+    ("2nd_place_medal", 2963),
+    ("3rd_place_medal", 2964),
+    ("8ball", 2996),
+    ("a", 3443),
+    ("ab", 3444),
+    ("abacus", 3119),
+    ("abc", 3442),
+    ("abcd", 3439),
+    ("accept", 3469),
+    ("accordion", 3089),
+    ("adhesive_bandage", 3249),
+    ("adult", 519),
+    ("aerial_tramway", 2854),
+    ("afghanistan", 3522),
+    ("airplane", 2845),
+    ("aland_islands", 3534),
+    ("alarm_clock", 2863),
+    ("albania", 3525),
+    ("alembic", 3239),
+    ("algeria", 3584),
+    ("alien", 115),
+    ("ambulance", 2802),
+    ("american_samoa", 3530),
+    ("amphora", 2719),
+    ("anatomical_heart", 486),
+    ("anchor", 2836),
+    ("andorra", 3520),
+    ("angel", 1335),
+    ("anger", 157),
+    ("angola", 3527),
+    ("angry", 104),
+    ("anguilla", 3524),
+    ("anguished", 88),
+    ("ant", 2547),
+    ("antarctica", 3528),
+    ("antigua_barbuda", 3523),
+    ("apple", 2598),
+    ("aquarius", 3357),
+    ("argentina", 3529),
+    ("aries", 3347),
+    ("armenia", 3526),
+    ("arrow_backward", 3367),
+    ("arrow_double_down", 3373),
+    ("arrow_double_up", 3371),
+    ("arrow_down", 3317),
+    ("arrow_down_small", 3372),
+    ("arrow_forward", 3363),
+    ("arrow_heading_down", 3326),
+    ("arrow_heading_up", 3325),
+    ("arrow_left", 3319),
+    ("arrow_lower_left", 3318),
+    ("arrow_lower_right", 3316),
+    ("arrow_right", 3315),
+    ("arrow_right_hook", 3324),
+    ("arrow_up", 3313),
+    ("arrow_up_down", 3321),
+    ("arrow_up_small", 3370),
+    ("arrow_upper_left", 3320),
+    ("arrow_upper_right", 3314),
+    ("arrows_clockwise", 3327),
+    ("arrows_counterclockwise", 3328),
+    ("art", 3018),
+    ("articulated_lorry", 2813),
+    ("artificial_satellite", 2855),
+    ("artist", 1059),
+    ("aruba", 3533),
+    ("ascension_island", 3519),
+    ("asterisk", 3426),
+    ("astonished", 83),
+    ("astronaut", 1095),
+    ("athletic_shoe", 3051),
+    ("atm", 3287),
+    ("atom_symbol", 3335),
+    ("australia", 3532),
+    ("austria", 3531),
+    ("auto_rickshaw", 2820),
+    ("avocado", 2609),
+    ("axe", 3213),
+    ("azerbaijan", 3535),
+    ("b", 3445),
+    ("baby", 495),
+    ("baby_bottle", 2693),
+    ("baby_chick", 2500),
+    ("baby_symbol", 3294),
+    ("back", 3329),
+    ("bacon", 2640),
+    ("badger", 2494),
+    ("badminton", 2980),
+    ("bagel", 2633),
+    ("baggage_claim", 3298),
+    ("baguette_bread", 2630),
+    ("bahamas", 3551),
+    ("bahrain", 3542),
+    ("balance_scale", 3229),
+    ("bald_man", 573),
+    ("bald_woman", 621),
+    ("ballet_shoes", 3056),
+    ("balloon", 2944),
+    ("ballot_box", 3175),
+    ("ballot_box_with_check", 3411),
+    ("bamboo", 2948),
+    ("banana", 2595),
+    ("bangbang", 3394),
+    ("bangladesh", 3538),
+    ("banjo", 3094),
+    ("bank", 2751),
+    ("bar_chart", 3194),
+    ("barbados", 3537),
+    ("barber", 2784),
+    ("baseball", 2966),
+    ("basket", 3269),
+    ("basketball", 2968),
+    ("basketball_man", 1911),
+    ("basketball_woman", 1917),
+    ("bat", 2485),
+    ("bath", 2070),
+    ("bathtub", 3263),
+    ("battery", 3106),
+    ("beach_umbrella", 2732),
+    ("beans", 2622),
+    ("bear", 2486),
+    ("bearded_person", 537),
+    ("beaver", 2483),
+    ("bed", 3257),
+    ("bee", 2548),
+    ("beer", 2703),
+    ("beers", 2704),
+    ("beetle", 2549),
+    ("beginner", 3408),
+    ("belarus", 3555),
+    ("belgium", 3539),
+    ("belize", 3556),
+    ("bell", 3077),
+    ("bell_pepper", 2615),
+    ("bellhop_bell", 2858),
+    ("benin", 3544),
+    ("bento", 2662),
+    ("bermuda", 3546),
+    ("beverage_box", 2710),
+    ("bhutan", 3552),
+    ("bicyclist", 1941),
+    ("bike", 2821),
+    ("biking_man", 1947),
+    ("biking_woman", 1953),
+    ("bikini", 3041),
+    ("billed_cap", 3063),
+    ("biohazard", 3312),
+    ("bird", 2502),
+    ("birthday", 2684),
+    ("bison", 2456),
+    ("biting_lip", 494),
+    ("black_bird", 2515),
+    ("black_cat", 2444),
+    ("black_circle", 3484),
+    ("black_flag", 3514),
+    ("black_heart", 152),
+    ("black_joker", 3013),
+    ("black_large_square", 3493),
+    ("black_medium_small_square", 3497),
+    ("black_medium_square", 3495),
+    ("black_nib", 3177),
+    ("black_small_square", 3499),
+    ("black_square_button", 3510),
+    ("blond_haired_man", 639),
+    ("blond_haired_person", 525),
+    ("blond_haired_woman", 633),
+    ("blonde_woman", 633),
+    ("blossom", 2569),
+    ("blowfish", 2533),
+    ("blue_book", 3140),
+    ("blue_car", 2810),
+    ("blue_heart", 148),
+    ("blue_square", 3490),
+    ("blueberries", 2604),
+    ("blush", 12),
+    ("boar", 2463),
+    ("boat", 2838),
+    ("bolivia", 3548),
+    ("bomb", 3219),
+    ("bone", 489),
+    ("book", 3138),
+    ("bookmark", 3151),
+    ("bookmark_tabs", 3150),
+    ("books", 3142),
+    ("boom", 158),
+    ("boomerang", 3220),
+    ("boot", 3057),
+    ("bosnia_herzegovina", 3536),
+    ("botswana", 3554),
+    ("bouncing_ball_man", 1911),
+    ("bouncing_ball_person", 1905),
+    ("bouncing_ball_woman", 1917),
+    ("bouquet", 2560),
+    ("bouvet_island", 3553),
+    ("bow", 789),
+    ("bow_and_arrow", 3221),
+    ("bowing_man", 795),
+    ("bowing_woman", 801),
+    ("bowl_with_spoon", 2656),
+    ("bowling", 2974),
+    ("boxing_glove", 2981),
+    ("boy", 507),
+    ("brain", 485),
+    ("brazil", 3550),
+    ("bread", 2628),
+    ("breast_feeding", 1311),
+    ("bricks", 2739),
+    ("bride_with_veil", 1287),
+    ("bridge_at_night", 2778),
+    ("briefcase", 3183),
+    ("british_indian_ocean_territory", 3631),
+    ("british_virgin_islands", 3766),
+    ("broccoli", 2618),
+    ("broken_heart", 140),
+    ("broom", 3268),
+    ("brown_circle", 3483),
+    ("brown_heart", 151),
+    ("brown_square", 3492),
+    ("brunei", 3547),
+    ("bubble_tea", 2709),
+    ("bubbles", 3273),
+    ("bucket", 3271),
+    ("bug", 2546),
+    ("building_construction", 2738),
+    ("bulb", 3132),
+    ("bulgaria", 3541),
+    ("bullettrain_front", 2789),
+    ("bullettrain_side", 2788),
+    ("burkina_faso", 3540),
+    ("burrito", 2647),
+    ("burundi", 3543),
+    ("bus", 2798),
+    ("business_suit_levitating", 1774),
+    ("busstop", 2825),
+    ("bust_in_silhouette", 2420),
+    ("busts_in_silhouette", 2421),
+    ("butter", 2659),
+    ("butterfly", 2545),
+    ("cactus", 2577),
+    ("cake", 2685),
+    ("calendar", 3188),
+    ("call_me_hand", 283),
+    ("calling", 3101),
+    ("cambodia", 3642),
+    ("camel", 2469),
+    ("camera", 3125),
+    ("camera_flash", 3126),
+    ("cameroon", 3566),
+    ("camping", 2731),
+    ("canada", 3557),
+    ("canary_islands", 3625),
+    ("cancer", 3350),
+    ("candle", 3131),
+    ("candy", 2689),
+    ("canned_food", 2661),
+    ("canoe", 2839),
+    ("cape_verde", 3573),
+    ("capital_abcd", 3438),
+    ("capricorn", 3356),
+    ("car", 2808),
+    ("card_file_box", 3203),
+    ("card_index", 3191),
+    ("card_index_dividers", 3186),
+    ("caribbean_netherlands", 3549),
+    ("carousel_horse", 2780),
+    ("carpentry_saw", 3223),
+    ("carrot", 2612),
+    ("cartwheeling", 1977),
+    ("cat", 2442),
+    ("cat2", 2443),
+    ("cayman_islands", 3649),
+    ("cd", 3117),
+    ("central_african_republic", 3560),
+    ("ceuta_melilla", 3585),
+    ("chad", 3741),
+    ("chains", 3233),
+    ("chair", 3259),
+    ("champagne", 2699),
+    ("chart", 3162),
+    ("chart_with_downwards_trend", 3193),
+    ("chart_with_upwards_trend", 3192),
+    ("checkered_flag", 3511),
+    ("cheese", 2636),
+    ("cherries", 2602),
+    ("cherry_blossom", 2561),
+    ("chess_pawn", 3012),
+    ("chestnut", 2623),
+    ("chicken", 2497),
+    ("child", 501),
+    ("children_crossing", 3301),
+    ("chile", 3565),
+    ("chipmunk", 2482),
+    ("chocolate_bar", 2688),
+    ("chopsticks", 2713),
+    ("christmas_island", 3575),
+    ("christmas_tree", 2939),
+    ("church", 2763),
+    ("cinema", 3378),
+    ("circus_tent", 2785),
+    ("city_sunrise", 2777),
+    ("city_sunset", 2776),
+    ("cityscape", 2773),
+    ("cl", 3446),
+    ("clamp", 3228),
+    ("clap", 367),
+    ("clapper", 3123),
+    ("classical_building", 2737),
+    ("climbing", 1801),
+    ("climbing_man", 1807),
+    ("climbing_woman", 1813),
+    ("clinking_glasses", 2705),
+    ("clipboard", 3195),
+    ("clipperton_island", 3569),
+    ("clock1", 2869),
+    ("clock10", 2887),
+    ("clock1030", 2888),
+    ("clock11", 2889),
+    ("clock1130", 2890),
+    ("clock12", 2867),
+    ("clock1230", 2868),
+    ("clock130", 2870),
+    ("clock2", 2871),
+    ("clock230", 2872),
+    ("clock3", 2873),
+    ("clock330", 2874),
+    ("clock4", 2875),
+    ("clock430", 2876),
+    ("clock5", 2877),
+    ("clock530", 2878),
+    ("clock6", 2879),
+    ("clock630", 2880),
+    ("clock7", 2881),
+    ("clock730", 2882),
+    ("clock8", 2883),
+    ("clock830", 2884),
+    ("clock9", 2885),
+    ("clock930", 2886),
+    ("closed_book", 3137),
+    ("closed_lock_with_key", 3209),
+    ("closed_umbrella", 2926),
+    ("cloud", 2912),
+    ("cloud_with_lightning", 2920),
+    ("cloud_with_lightning_and_rain", 2914),
+    ("cloud_with_rain", 2918),
+    ("cloud_with_snow", 2919),
+    ("clown_face", 111),
+    ("clubs", 3011),
+    ("cn", 3567),
+    ("coat", 3033),
+    ("cockroach", 2552),
+    ("cocktail", 2701),
+    ("coconut", 2608),
+    ("cocos_islands", 3558),
+    ("coffee", 2695),
+    ("coffin", 3279),
+    ("coin", 3154),
+    ("cold_face", 65),
+    ("cold_sweat", 90),
+    ("collision", 158),
+    ("colombia", 3568),
+    ("comet", 2934),
+    ("comoros", 3644),
+    ("compass", 2726),
+    ("computer", 3109),
+    ("computer_mouse", 3113),
+    ("confetti_ball", 2946),
+    ("confounded", 95),
+    ("confused", 76),
+    ("congo_brazzaville", 3561),
+    ("congo_kinshasa", 3559),
+    ("congratulations", 3473),
+    ("construction", 2835),
+    ("construction_worker", 1191),
+    ("construction_worker_man", 1197),
+    ("construction_worker_woman", 1203),
+    ("control_knobs", 3084),
+    ("convenience_store", 2754),
+    ("cook", 933),
+    ("cook_islands", 3564),
+    ("cookie", 2683),
+    ("cool", 3447),
+    ("cop", 1131),
+    ("copyright", 3421),
+    ("coral", 2537),
+    ("corn", 2613),
+    ("costa_rica", 3571),
+    ("cote_divoire", 3563),
+    ("couch_and_lamp", 3258),
+    ("couple", 2134),
+    ("couple_with_heart", 2290),
+    ("couple_with_heart_man_man", 2342),
+    ("couple_with_heart_woman_man", 2316),
+    ("couple_with_heart_woman_woman", 2368),
+    ("couplekiss", 2186),
+    ("couplekiss_man_man", 2238),
+    ("couplekiss_man_woman", 2212),
+    ("couplekiss_woman_woman", 2264),
+    ("cow", 2457),
+    ("cow2", 2460),
+    ("cowboy_hat_face", 70),
+    ("crab", 2539),
+    ("crayon", 3181),
+    ("credit_card", 3160),
+    ("crescent_moon", 2899),
+    ("cricket", 2551),
+    ("cricket_game", 2975),
+    ("croatia", 3622),
+    ("crocodile", 2519),
+    ("croissant", 2629),
+    ("crossed_fingers", 259),
+    ("crossed_flags", 3513),
+    ("crossed_swords", 3218),
+    ("crown", 3059),
+    ("crutch", 3250),
+    ("cry", 92),
+    ("crying_cat_face", 125),
+    ("crystal_ball", 2997),
+    ("cuba", 3572),
+    ("cucumber", 2616),
+    ("cup_with_straw", 2708),
+    ("cupcake", 2686),
+    ("cupid", 131),
+    ("curacao", 3574),
+    ("curling_stone", 2991),
+    ("curly_haired_man", 561),
+    ("curly_haired_woman", 597),
+    ("curly_loop", 3415),
+    ("currency_exchange", 3401),
+    ("curry", 2666),
+    ("cursing_face", 105),
+    ("custard", 2691),
+    ("customs", 3297),
+    ("cut_of_meat", 2639),
+    ("cyclone", 2924),
+    ("cyprus", 3576),
+    ("czech_republic", 3577),
+    ("dagger", 3217),
+    ("dancer", 1762),
+    ("dancers", 1780),
+    ("dancing_men", 1781),
+    ("dancing_women", 1782),
+    ("dango", 2675),
+    ("dark_sunglasses", 3024),
+    ("dart", 2992),
+    ("dash", 161),
+    ("date", 3187),
+    ("de", 3578),
+    ("deaf_man", 777),
+    ("deaf_person", 771),
+    ("deaf_woman", 783),
+    ("deciduous_tree", 2575),
+    ("deer", 2455),
+    ("denmark", 3581),
+    ("department_store", 2756),
+    ("derelict_house", 2744),
+    ("desert", 2733),
+    ("desert_island", 2734),
+    ("desktop_computer", 3110),
+    ("detective", 1149),
+    ("diamond_shape_with_a_dot_inside", 3507),
+    ("diamonds", 3010),
+    ("diego_garcia", 3579),
+    ("disappointed", 97),
+    ("disappointed_relieved", 91),
+    ("disguised_face", 72),
+    ("diving_mask", 2987),
+    ("diya_lamp", 3135),
+    ("dizzy", 159),
+    ("dizzy_face", 67),
+    ("djibouti", 3580),
+    ("dna", 3242),
+    ("do_not_litter", 3306),
+    ("dodo", 2509),
+    ("dog", 2434),
+    ("dog2", 2435),
+    ("dollar", 3156),
+    ("dolls", 2949),
+    ("dolphin", 2529),
+    ("dominica", 3582),
+    ("dominican_republic", 3583),
+    ("donkey", 2451),
+    ("door", 3253),
+    ("dotted_line_face", 41),
+    ("doughnut", 2682),
+    ("dove", 2504),
+    ("dragon", 2524),
+    ("dragon_face", 2523),
+    ("dress", 3035),
+    ("dromedary_camel", 2468),
+    ("drooling_face", 55),
+    ("drop_of_blood", 3247),
+    ("droplet", 2936),
+    ("drum", 3095),
+    ("duck", 2506),
+    ("dumpling", 2676),
+    ("dvd", 3118),
+    ("e-mail", 3164),
+    ("eagle", 2505),
+    ("ear", 467),
+    ("ear_of_rice", 2578),
+    ("ear_with_hearing_aid", 473),
+    ("earth_africa", 2720),
+    ("earth_americas", 2721),
+    ("earth_asia", 2722),
+    ("ecuador", 3586),
+    ("egg", 2651),
+    ("eggplant", 2610),
+    ("egypt", 3588),
+    ("eight", 3435),
+    ("eight_pointed_black_star", 3419),
+    ("eight_spoked_asterisk", 3418),
+    ("eject_button", 3377),
+    ("el_salvador", 3735),
+    ("electric_plug", 3108),
+    ("elephant", 2472),
+    ("elevator", 3254),
+    ("elf", 1467),
+    ("elf_man", 1473),
+    ("elf_woman", 1479),
+    ("email", 3164),
+    ("empty_nest", 2585),
+    ("end", 3330),
+    ("england", 3778),
+    ("envelope", 3163),
+    ("envelope_with_arrow", 3166),
+    ("equatorial_guinea", 3612),
+    ("eritrea", 3590),
+    ("es", 3591),
+    ("estonia", 3587),
+    ("ethiopia", 3592),
+    ("eu", 3593),
+    ("euro", 3157),
+    ("european_castle", 2759),
+    ("european_post_office", 2749),
+    ("european_union", 3593),
+    ("evergreen_tree", 2574),
+    ("exclamation", 3399),
+    ("exploding_head", 69),
+    ("expressionless", 39),
+    ("eye", 491),
+    ("eye_speech_bubble", 164),
+    ("eyeglasses", 3023),
+    ("eyes", 490),
+    ("face_exhaling", 47),
+    ("face_holding_back_tears", 86),
+    ("face_in_clouds", 42),
+    ("face_with_diagonal_mouth", 77),
+    ("face_with_head_bandage", 60),
+    ("face_with_open_eyes_and_hand_over_mouth", 31),
+    ("face_with_peeking_eye", 32),
+    ("face_with_spiral_eyes", 68),
+    ("face_with_thermometer", 59),
+    ("facepalm", 807),
+    ("facepunch", 349),
+    ("factory", 2757),
+    ("factory_worker", 969),
+    ("fairy", 1413),
+    ("fairy_man", 1419),
+    ("fairy_woman", 1425),
+    ("falafel", 2650),
+    ("falkland_islands", 3596),
+    ("fallen_leaf", 2583),
+    ("family", 2423),
+    ("family_man_boy", 2409),
+    ("family_man_boy_boy", 2410),
+    ("family_man_girl", 2411),
+    ("family_man_girl_boy", 2412),
+    ("family_man_girl_girl", 2413),
+    ("family_man_man_boy", 2399),
+    ("family_man_man_boy_boy", 2402),
+    ("family_man_man_girl", 2400),
+    ("family_man_man_girl_boy", 2401),
+    ("family_man_man_girl_girl", 2403),
+    ("family_man_woman_boy", 2394),
+    ("family_man_woman_boy_boy", 2397),
+    ("family_man_woman_girl", 2395),
+    ("family_man_woman_girl_boy", 2396),
+    ("family_man_woman_girl_girl", 2398),
+    ("family_woman_boy", 2414),
+    ("family_woman_boy_boy", 2415),
+    ("family_woman_girl", 2416),
+    ("family_woman_girl_boy", 2417),
+    ("family_woman_girl_girl", 2418),
+    ("family_woman_woman_boy", 2404),
+    ("family_woman_woman_boy_boy", 2407),
+    ("family_woman_woman_girl", 2405),
+    ("family_woman_woman_girl_boy", 2406),
+    ("family_woman_woman_girl_girl", 2408),
+    ("farmer", 915),
+    ("faroe_islands", 3598),
+    ("fast_forward", 3364),
+    ("fax", 3105),
+    ("fearful", 89),
+    ("feather", 2510),
+    ("feet", 2495),
+    ("female_detective", 1161),
+    ("female_sign", 3385),
+    ("ferris_wheel", 2782),
+    ("ferry", 2842),
+    ("field_hockey", 2976),
+    ("fiji", 3595),
+    ("file_cabinet", 3204),
+    ("file_folder", 3184),
+    ("film_projector", 3122),
+    ("film_strip", 3121),
+    ("finland", 3594),
+    ("fire", 2935),
+    ("fire_engine", 2803),
+    ("fire_extinguisher", 3276),
+    ("firecracker", 2942),
+    ("firefighter", 1113),
+    ("fireworks", 2940),
+    ("first_quarter_moon", 2893),
+    ("first_quarter_moon_with_face", 2901),
+    ("fish", 2531),
+    ("fish_cake", 2673),
+    ("fishing_pole_and_fish", 2986),
+    ("fist", 343),
+    ("fist_left", 355),
+    ("fist_oncoming", 349),
+    ("fist_raised", 343),
+    ("fist_right", 361),
+    ("five", 3432),
+    ("flags", 2950),
+    ("flamingo", 2511),
+    ("flashlight", 3133),
+    ("flat_shoe", 3053),
+    ("flatbread", 2631),
+    ("fleur_de_lis", 3405),
+    ("flight_arrival", 2848),
+    ("flight_departure", 2847),
+    ("flipper", 2529),
+    ("floppy_disk", 3116),
+    ("flower_playing_cards", 3015),
+    ("flushed", 84),
+    ("flute", 3098),
+    ("fly", 2557),
+    ("flying_disc", 2973),
+    ("flying_saucer", 2857),
+    ("fog", 2922),
+    ("foggy", 2771),
+    ("folding_hand_fan", 3043),
+    ("fondue", 2655),
+    ("foot", 461),
+    ("football", 2970),
+    ("footprints", 2428),
+    ("fork_and_knife", 2715),
+    ("fortune_cookie", 2677),
+    ("fountain", 2769),
+    ("fountain_pen", 3178),
+    ("four", 3431),
+    ("four_leaf_clover", 2581),
+    ("fox_face", 2440),
+    ("fr", 3599),
+    ("framed_picture", 3017),
+    ("free", 3448),
+    ("french_guiana", 3604),
+    ("french_polynesia", 3700),
+    ("french_southern_territories", 3742),
+    ("fried_egg", 2652),
+    ("fried_shrimp", 2672),
+    ("fries", 2642),
+    ("frog", 2518),
+    ("frowning", 87),
+    ("frowning_face", 80),
+    ("frowning_man", 669),
+    ("frowning_person", 663),
+    ("frowning_woman", 675),
+    ("fu", 307),
+    ("fuelpump", 2829),
+    ("full_moon", 2895),
+    ("full_moon_with_face", 2905),
+    ("funeral_urn", 3281),
+    ("gabon", 3600),
+    ("gambia", 3609),
+    ("game_die", 3002),
+    ("garlic", 2619),
+    ("gb", 3601),
+    ("gear", 3227),
+    ("gem", 3069),
+    ("gemini", 3349),
+    ("genie", 1485),
+    ("genie_man", 1486),
+    ("genie_woman", 1487),
+    ("georgia", 3603),
+    ("ghana", 3606),
+    ("ghost", 114),
+    ("gibraltar", 3607),
+    ("gift", 2955),
+    ("gift_heart", 132),
+    ("ginger_root", 2624),
+    ("giraffe", 2471),
+    ("girl", 513),
+    ("globe_with_meridians", 2723),
+    ("gloves", 3032),
+    ("goal_net", 2983),
+    ("goat", 2467),
+    ("goggles", 3025),
+    ("golf", 2984),
+    ("golfing", 1833),
+    ("golfing_man", 1839),
+    ("golfing_woman", 1845),
+    ("goose", 2516),
+    ("gorilla", 2432),
+    ("grapes", 2589),
+    ("greece", 3613),
+    ("green_apple", 2599),
+    ("green_book", 3139),
+    ("green_circle", 3480),
+    ("green_heart", 147),
+    ("green_salad", 2657),
+    ("green_square", 3489),
+    ("greenland", 3608),
+    ("grenada", 3602),
+    ("grey_exclamation", 3398),
+    ("grey_heart", 153),
+    ("grey_question", 3397),
+    ("grimacing", 46),
+    ("grin", 3),
+    ("grinning", 0),
+    ("guadeloupe", 3611),
+    ("guam", 3616),
+    ("guard", 1167),
+    ("guardsman", 1173),
+    ("guardswoman", 1179),
+    ("guatemala", 3615),
+    ("guernsey", 3605),
+    ("guide_dog", 2436),
+    ("guinea", 3610),
+    ("guinea_bissau", 3617),
+    ("guitar", 3090),
+    ("gun", 2995),
+    ("guyana", 3618),
+    ("hair_pick", 3058),
+    ("haircut", 1510),
+    ("haircut_man", 1516),
+    ("haircut_woman", 1522),
+    ("haiti", 3623),
+    ("hamburger", 2641),
+    ("hammer", 3212),
+    ("hammer_and_pick", 3215),
+    ("hammer_and_wrench", 3216),
+    ("hamsa", 3283),
+    ("hamster", 2479),
+    ("hand", 187),
+    ("hand_over_mouth", 30),
+    ("hand_with_index_finger_and_thumb_crossed", 265),
+    ("handbag", 3045),
+    ("handball_person", 2016),
+    ("handshake", 397),
+    ("hankey", 110),
+    ("hash", 3425),
+    ("hatched_chick", 2501),
+    ("hatching_chick", 2499),
+    ("headphones", 3086),
+    ("headstone", 3280),
+    ("health_worker", 843),
+    ("hear_no_evil", 128),
+    ("heard_mcdonald_islands", 3620),
+    ("heart", 143),
+    ("heart_decoration", 138),
+    ("heart_eyes", 15),
+    ("heart_eyes_cat", 121),
+    ("heart_hands", 379),
+    ("heart_on_fire", 141),
+    ("heartbeat", 135),
+    ("heartpulse", 134),
+    ("hearts", 3009),
+    ("heavy_check_mark", 3412),
+    ("heavy_division_sign", 3391),
+    ("heavy_dollar_sign", 3402),
+    ("heavy_equals_sign", 3392),
+    ("heavy_exclamation_mark", 3399),
+    ("heavy_heart_exclamation", 139),
+    ("heavy_minus_sign", 3390),
+    ("heavy_multiplication_x", 3388),
+    ("heavy_plus_sign", 3389),
+    ("hedgehog", 2484),
+    ("helicopter", 2851),
+    ("herb", 2579),
+    ("hibiscus", 2567),
+    ("high_brightness", 3380),
+    ("high_heel", 3054),
+    ("hiking_boot", 3052),
+    ("hindu_temple", 2765),
+    ("hippopotamus", 2475),
+    ("hocho", 2717),
+    ("hole", 162),
+    ("honduras", 3621),
+    ("honey_pot", 2692),
+    ("honeybee", 2548),
+    ("hong_kong", 3619),
+    ("hook", 3234),
+    ("horse", 2449),
+    ("horse_racing", 1820),
+    ("hospital", 2750),
+    ("hot_face", 64),
+    ("hot_pepper", 2614),
+    ("hotdog", 2644),
+    ("hotel", 2752),
+    ("hotsprings", 2779),
+    ("hourglass", 2860),
+    ("hourglass_flowing_sand", 2861),
+    ("house", 2745),
+    ("house_with_garden", 2746),
+    ("houses", 2743),
+    ("hugs", 29),
+    ("hungary", 3624),
+    ("hushed", 82),
+    ("hut", 2742),
+    ("hyacinth", 2571),
+    ("ice_cream", 2681),
+    ("ice_cube", 2712),
+    ("ice_hockey", 2977),
+    ("ice_skate", 2985),
+    ("icecream", 2679),
+    ("iceland", 3634),
+    ("id", 3450),
+    ("identification_card", 3286),
+    ("ideograph_advantage", 3465),
+    ("imp", 107),
+    ("inbox_tray", 3168),
+    ("incoming_envelope", 3165),
+    ("index_pointing_at_the_viewer", 325),
+    ("india", 3630),
+    ("indonesia", 3626),
+    ("infinity", 3393),
+    ("information_desk_person", 735),
+    ("information_source", 3449),
+    ("innocent", 13),
+    ("interrobang", 3395),
+    ("iphone", 3100),
+    ("iran", 3633),
+    ("iraq", 3632),
+    ("ireland", 3627),
+    ("isle_of_man", 3629),
+    ("israel", 3628),
+    ("it", 3635),
+    ("izakaya_lantern", 3134),
+    ("jack_o_lantern", 2938),
+    ("jamaica", 3637),
+    ("japan", 2725),
+    ("japanese_castle", 2758),
+    ("japanese_goblin", 113),
+    ("japanese_ogre", 112),
+    ("jar", 2718),
+    ("jeans", 3030),
+    ("jellyfish", 2538),
+    ("jersey", 3636),
+    ("jigsaw", 3003),
+    ("jordan", 3638),
+    ("joy", 7),
+    ("joy_cat", 120),
+    ("joystick", 3000),
+    ("jp", 3639),
+    ("judge", 897),
+    ("juggling_person", 2034),
+    ("kaaba", 2768),
+    ("kangaroo", 2493),
+    ("kazakhstan", 3650),
+    ("kenya", 3640),
+    ("key", 3210),
+    ("keyboard", 3112),
+    ("keycap_ten", 3437),
+    ("khanda", 3346),
+    ("kick_scooter", 2822),
+    ("kimono", 3036),
+    ("kiribati", 3643),
+    ("kiss", 155),
+    ("kissing", 18),
+    ("kissing_cat", 123),
+    ("kissing_closed_eyes", 20),
+    ("kissing_heart", 17),
+    ("kissing_smiling_eyes", 21),
+    ("kite", 2994),
+    ("kiwi_fruit", 2605),
+    ("kneeling_man", 1588),
+    ("kneeling_person", 1582),
+    ("kneeling_woman", 1594),
+    ("knife", 2717),
+    ("knot", 3022),
+    ("koala", 2488),
+    ("koko", 3460),
+    ("kosovo", 3772),
+    ("kr", 3647),
+    ("kuwait", 3648),
+    ("kyrgyzstan", 3641),
+    ("lab_coat", 3026),
+    ("label", 3152),
+    ("lacrosse", 2978),
+    ("ladder", 3237),
+    ("lady_beetle", 2550),
+    ("lantern", 3134),
+    ("laos", 3651),
+    ("large_blue_circle", 3481),
+    ("large_blue_diamond", 3502),
+    ("large_orange_diamond", 3501),
+    ("last_quarter_moon", 2897),
+    ("last_quarter_moon_with_face", 2902),
+    ("latin_cross", 3340),
+    ("latvia", 3660),
+    ("laughing", 4),
+    ("leafy_green", 2617),
+    ("leaves", 2584),
+    ("lebanon", 3652),
+    ("ledger", 3144),
+    ("left_luggage", 3299),
+    ("left_right_arrow", 3322),
+    ("left_speech_bubble", 165),
+    ("leftwards_arrow_with_hook", 3323),
+    ("leftwards_hand", 205),
+    ("leftwards_pushing_hand", 223),
+    ("leg", 455),
+    ("lemon", 2593),
+    ("leo", 3351),
+    ("leopard", 2448),
+    ("lesotho", 3657),
+    ("level_slider", 3083),
+    ("liberia", 3656),
+    ("libra", 3353),
+    ("libya", 3661),
+    ("liechtenstein", 3654),
+    ("light_blue_heart", 149),
+    ("light_rail", 2792),
+    ("link", 3231),
+    ("lion", 2445),
+    ("lips", 493),
+    ("lipstick", 3067),
+    ("lithuania", 3658),
+    ("lizard", 2521),
+    ("llama", 2470),
+    ("lobster", 2540),
+    ("lock", 3206),
+    ("lock_with_ink_pen", 3208),
+    ("lollipop", 2690),
+    ("long_drum", 3096),
+    ("loop", 3416),
+    ("lotion_bottle", 3266),
+    ("lotus", 2563),
+    ("lotus_position", 2052),
+    ("lotus_position_man", 2058),
+    ("lotus_position_woman", 2064),
+    ("loud_sound", 3073),
+    ("loudspeaker", 3074),
+    ("love_hotel", 2753),
+    ("love_letter", 130),
+    ("love_you_gesture", 271),
+    ("low_battery", 3107),
+    ("low_brightness", 3379),
+    ("luggage", 2859),
+    ("lungs", 487),
+    ("luxembourg", 3659),
+    ("lying_face", 48),
+    ("m", 3451),
+    ("macau", 3673),
+    ("macedonia", 3669),
+    ("madagascar", 3667),
+    ("mag", 3129),
+    ("mag_right", 3130),
+    ("mage", 1395),
+    ("mage_man", 1401),
+    ("mage_woman", 1407),
+    ("magic_wand", 2998),
+    ("magnet", 3236),
+    ("mahjong", 3014),
+    ("mailbox", 3170),
+    ("mailbox_closed", 3171),
+    ("mailbox_with_mail", 3172),
+    ("mailbox_with_no_mail", 3173),
+    ("malawi", 3681),
+    ("malaysia", 3683),
+    ("maldives", 3680),
+    ("male_detective", 1155),
+    ("male_sign", 3386),
+    ("mali", 3670),
+    ("malta", 3678),
+    ("mammoth", 2473),
+    ("man", 531),
+    ("man_artist", 1065),
+    ("man_astronaut", 1101),
+    ("man_beard", 543),
+    ("man_cartwheeling", 1983),
+    ("man_cook", 939),
+    ("man_dancing", 1768),
+    ("man_facepalming", 813),
+    ("man_factory_worker", 975),
+    ("man_farmer", 921),
+    ("man_feeding_baby", 1323),
+    ("man_firefighter", 1119),
+    ("man_health_worker", 849),
+    ("man_in_manual_wheelchair", 1702),
+    ("man_in_motorized_wheelchair", 1666),
+    ("man_in_tuxedo", 1263),
+    ("man_judge", 903),
+    ("man_juggling", 2040),
+    ("man_mechanic", 957),
+    ("man_office_worker", 993),
+    ("man_pilot", 1083),
+    ("man_playing_handball", 2022),
+    ("man_playing_water_polo", 2004),
+    ("man_scientist", 1011),
+    ("man_shrugging", 831),
+    ("man_singer", 1047),
+    ("man_student", 867),
+    ("man_teacher", 885),
+    ("man_technologist", 1029),
+    ("man_with_gua_pi_mao", 1245),
+    ("man_with_probing_cane", 1630),
+    ("man_with_turban", 1233),
+    ("man_with_veil", 1281),
+    ("mandarin", 2592),
+    ("mango", 2597),
+    ("mans_shoe", 3050),
+    ("mantelpiece_clock", 2866),
+    ("manual_wheelchair", 2818),
+    ("maple_leaf", 2582),
+    ("maracas", 3097),
+    ("marshall_islands", 3668),
+    ("martial_arts_uniform", 2982),
+    ("martinique", 3675),
+    ("mask", 58),
+    ("massage", 1492),
+    ("massage_man", 1498),
+    ("massage_woman", 1504),
+    ("mate", 2711),
+    ("mauritania", 3676),
+    ("mauritius", 3679),
+    ("mayotte", 3774),
+    ("meat_on_bone", 2637),
+    ("mechanic", 951),
+    ("mechanical_arm", 453),
+    ("mechanical_leg", 454),
+    ("medal_military", 2959),
+    ("medal_sports", 2961),
+    ("medical_symbol", 3403),
+    ("mega", 3075),
+    ("melon", 2590),
+    ("melting_face", 10),
+    ("memo", 3182),
+    ("men_wrestling", 1996),
+    ("mending_heart", 142),
+    ("menorah", 3344),
+    ("mens", 3291),
+    ("mermaid", 1461),
+    ("merman", 1455),
+    ("merperson", 1449),
+    ("metal", 277),
+    ("metro", 2791),
+    ("mexico", 3682),
+    ("microbe", 2559),
+    ("micronesia", 3597),
+    ("microphone", 3085),
+    ("microscope", 3243),
+    ("middle_finger", 307),
+    ("military_helmet", 3064),
+    ("milk_glass", 2694),
+    ("milky_way", 2911),
+    ("minibus", 2801),
+    ("minidisc", 3115),
+    ("mirror", 3255),
+    ("mirror_ball", 3006),
+    ("mobile_phone_off", 3384),
+    ("moldova", 3664),
+    ("monaco", 3663),
+    ("money_mouth_face", 28),
+    ("money_with_wings", 3159),
+    ("moneybag", 3153),
+    ("mongolia", 3672),
+    ("monkey", 2431),
+    ("monkey_face", 2430),
+    ("monocle_face", 75),
+    ("monorail", 2795),
+    ("montenegro", 3665),
+    ("montserrat", 3677),
+    ("moon", 2894),
+    ("moon_cake", 2674),
+    ("moose", 2450),
+    ("morocco", 3662),
+    ("mortar_board", 3062),
+    ("mosque", 2764),
+    ("mosquito", 2556),
+    ("motor_boat", 2843),
+    ("motor_scooter", 2817),
+    ("motorcycle", 2816),
+    ("motorized_wheelchair", 2819),
+    ("motorway", 2826),
+    ("mount_fuji", 2730),
+    ("mountain", 2728),
+    ("mountain_bicyclist", 1959),
+    ("mountain_biking_man", 1965),
+    ("mountain_biking_woman", 1971),
+    ("mountain_cableway", 2853),
+    ("mountain_railway", 2796),
+    ("mountain_snow", 2727),
+    ("mouse", 2476),
+    ("mouse2", 2477),
+    ("mouse_trap", 3264),
+    ("movie_camera", 3120),
+    ("moyai", 3284),
+    ("mozambique", 3684),
+    ("mrs_claus", 1347),
+    ("muscle", 447),
+    ("mushroom", 2587),
+    ("musical_keyboard", 3091),
+    ("musical_note", 3080),
+    ("musical_score", 3079),
+    ("mute", 3070),
+    ("mx_claus", 1353),
+    ("myanmar", 3671),
+    ("nail_care", 435),
+    ("name_badge", 3407),
+    ("namibia", 3685),
+    ("national_park", 2735),
+    ("nauru", 3694),
+    ("nauseated_face", 61),
+    ("nazar_amulet", 3282),
+    ("necktie", 3028),
+    ("negative_squared_cross_mark", 3414),
+    ("nepal", 3693),
+    ("nerd_face", 74),
+    ("nest_with_eggs", 2586),
+    ("nesting_dolls", 3007),
+    ("netherlands", 3691),
+    ("neutral_face", 38),
+    ("new", 3452),
+    ("new_caledonia", 3686),
+    ("new_moon", 2891),
+    ("new_moon_with_face", 2900),
+    ("new_zealand", 3696),
+    ("newspaper", 3148),
+    ("newspaper_roll", 3149),
+    ("next_track_button", 3365),
+    ("ng", 3453),
+    ("ng_man", 705),
+    ("ng_woman", 711),
+    ("nicaragua", 3690),
+    ("niger", 3687),
+    ("nigeria", 3689),
+    ("night_with_stars", 2772),
+    ("nine", 3436),
+    ("ninja", 1185),
+    ("niue", 3695),
+    ("no_bell", 3078),
+    ("no_bicycles", 3304),
+    ("no_entry", 3302),
+    ("no_entry_sign", 3303),
+    ("no_good", 699),
+    ("no_good_man", 705),
+    ("no_good_woman", 711),
+    ("no_mobile_phones", 3309),
+    ("no_mouth", 40),
+    ("no_pedestrians", 3308),
+    ("no_smoking", 3305),
+    ("non-potable_water", 3307),
+    ("norfolk_island", 3688),
+    ("north_korea", 3646),
+    ("northern_mariana_islands", 3674),
+    ("norway", 3692),
+    ("nose", 479),
+    ("notebook", 3143),
+    ("notebook_with_decorative_cover", 3136),
+    ("notes", 3081),
+    ("nut_and_bolt", 3226),
+    ("o", 3409),
+    ("o2", 3454),
+    ("ocean", 2937),
+    ("octopus", 2535),
+    ("oden", 2670),
+    ("office", 2747),
+    ("office_worker", 987),
+    ("oil_drum", 2828),
+    ("ok", 3455),
+    ("ok_hand", 235),
+    ("ok_man", 723),
+    ("ok_person", 717),
+    ("ok_woman", 729),
+    ("old_key", 3211),
+    ("older_adult", 645),
+    ("older_man", 651),
+    ("older_woman", 657),
+    ("olive", 2607),
+    ("om", 3336),
+    ("oman", 3697),
+    ("on", 3331),
+    ("oncoming_automobile", 2809),
+    ("oncoming_bus", 2799),
+    ("oncoming_police_car", 2805),
+    ("oncoming_taxi", 2807),
+    ("one", 3428),
+    ("one_piece_swimsuit", 3038),
+    ("onion", 2620),
+    ("open_book", 3138),
+    ("open_file_folder", 3185),
+    ("open_hands", 385),
+    ("open_mouth", 81),
+    ("open_umbrella", 2927),
+    ("ophiuchus", 3359),
+    ("orange", 2592),
+    ("orange_book", 3141),
+    ("orange_circle", 3478),
+    ("orange_heart", 145),
+    ("orange_square", 3487),
+    ("orangutan", 2433),
+    ("orthodox_cross", 3341),
+    ("otter", 2491),
+    ("outbox_tray", 3167),
+    ("owl", 2508),
+    ("ox", 2458),
+    ("oyster", 2543),
+    ("package", 3169),
+    ("page_facing_up", 3147),
+    ("page_with_curl", 3145),
+    ("pager", 3104),
+    ("paintbrush", 3180),
+    ("pakistan", 3703),
+    ("palau", 3710),
+    ("palestinian_territories", 3708),
+    ("palm_down_hand", 211),
+    ("palm_tree", 2576),
+    ("palm_up_hand", 217),
+    ("palms_up_together", 391),
+    ("panama", 3698),
+    ("pancakes", 2634),
+    ("panda_face", 2489),
+    ("paperclip", 3198),
+    ("paperclips", 3199),
+    ("papua_new_guinea", 3701),
+    ("parachute", 2849),
+    ("paraguay", 3711),
+    ("parasol_on_ground", 2929),
+    ("parking", 3456),
+    ("parrot", 2513),
+    ("part_alternation_mark", 3417),
+    ("partly_sunny", 2913),
+    ("partying_face", 71),
+    ("passenger_ship", 2841),
+    ("passport_control", 3296),
+    ("pause_button", 3374),
+    ("paw_prints", 2495),
+    ("pea_pod", 2625),
+    ("peace_symbol", 3343),
+    ("peach", 2601),
+    ("peacock", 2512),
+    ("peanuts", 2621),
+    ("pear", 2600),
+    ("pen", 3179),
+    ("pencil", 3182),
+    ("pencil2", 3176),
+    ("penguin", 2503),
+    ("pensive", 53),
+    ("people_holding_hands", 2082),
+    ("people_hugging", 2422),
+    ("performing_arts", 3016),
+    ("persevere", 96),
+    ("person_bald", 627),
+    ("person_curly_hair", 603),
+    ("person_feeding_baby", 1329),
+    ("person_fencing", 1819),
+    ("person_in_manual_wheelchair", 1690),
+    ("person_in_motorized_wheelchair", 1654),
+    ("person_in_tuxedo", 1257),
+    ("person_red_hair", 591),
+    ("person_white_hair", 615),
+    ("person_with_crown", 1209),
+    ("person_with_probing_cane", 1618),
+    ("person_with_turban", 1227),
+    ("person_with_veil", 1275),
+    ("peru", 3699),
+    ("petri_dish", 3241),
+    ("philippines", 3702),
+    ("phone", 3102),
+    ("pick", 3214),
+    ("pickup_truck", 2811),
+    ("pie", 2687),
+    ("pig", 2461),
+    ("pig2", 2462),
+    ("pig_nose", 2464),
+    ("pill", 3248),
+    ("pilot", 1077),
+    ("pinata", 3005),
+    ("pinched_fingers", 241),
+    ("pinching_hand", 247),
+    ("pineapple", 2596),
+    ("ping_pong", 2979),
+    ("pink_heart", 144),
+    ("pirate_flag", 3518),
+    ("pisces", 3358),
+    ("pitcairn_islands", 3706),
+    ("pizza", 2643),
+    ("placard", 3285),
+    ("place_of_worship", 3334),
+    ("plate_with_cutlery", 2714),
+    ("play_or_pause_button", 3366),
+    ("playground_slide", 2781),
+    ("pleading_face", 85),
+    ("plunger", 3261),
+    ("point_down", 313),
+    ("point_left", 289),
+    ("point_right", 295),
+    ("point_up", 319),
+    ("point_up_2", 301),
+    ("poland", 3704),
+    ("polar_bear", 2487),
+    ("police_car", 2804),
+    ("police_officer", 1131),
+    ("policeman", 1137),
+    ("policewoman", 1143),
+    ("poodle", 2438),
+    ("poop", 110),
+    ("popcorn", 2658),
+    ("portugal", 3709),
+    ("post_office", 2748),
+    ("postal_horn", 3076),
+    ("postbox", 3174),
+    ("potable_water", 3289),
+    ("potato", 2611),
+    ("potted_plant", 2573),
+    ("pouch", 3046),
+    ("poultry_leg", 2638),
+    ("pound", 3158),
+    ("pouring_liquid", 2707),
+    ("pout", 103),
+    ("pouting_cat", 126),
+    ("pouting_face", 681),
+    ("pouting_man", 687),
+    ("pouting_woman", 693),
+    ("pray", 423),
+    ("prayer_beads", 3066),
+    ("pregnant_man", 1299),
+    ("pregnant_person", 1305),
+    ("pregnant_woman", 1293),
+    ("pretzel", 2632),
+    ("previous_track_button", 3369),
+    ("prince", 1215),
+    ("princess", 1221),
+    ("printer", 3111),
+    ("probing_cane", 3230),
+    ("puerto_rico", 3707),
+    ("punch", 349),
+    ("purple_circle", 3482),
+    ("purple_heart", 150),
+    ("purple_square", 3491),
+    ("purse", 3044),
+    ("pushpin", 3196),
+    ("put_litter_in_its_place", 3288),
+    ("qatar", 3712),
+    ("question", 3396),
+    ("rabbit", 2480),
+    ("rabbit2", 2481),
+    ("raccoon", 2441),
+    ("racehorse", 2452),
+    ("racing_car", 2815),
+    ("radio", 3087),
+    ("radio_button", 3508),
+    ("radioactive", 3311),
+    ("rage", 103),
+    ("railway_car", 2787),
+    ("railway_track", 2827),
+    ("rainbow", 2925),
+    ("rainbow_flag", 3516),
+    ("raised_back_of_hand", 175),
+    ("raised_eyebrow", 37),
+    ("raised_hand", 187),
+    ("raised_hand_with_fingers_splayed", 181),
+    ("raised_hands", 373),
+    ("raising_hand", 753),
+    ("raising_hand_man", 759),
+    ("raising_hand_woman", 765),
+    ("ram", 2465),
+    ("ramen", 2667),
+    ("rat", 2478),
+    ("razor", 3265),
+    ("receipt", 3161),
+    ("record_button", 3376),
+    ("recycle", 3404),
+    ("red_car", 2808),
+    ("red_circle", 3477),
+    ("red_envelope", 2953),
+    ("red_haired_man", 555),
+    ("red_haired_woman", 585),
+    ("red_square", 3486),
+    ("registered", 3422),
+    ("relaxed", 19),
+    ("relieved", 52),
+    ("reminder_ribbon", 2956),
+    ("repeat", 3361),
+    ("repeat_one", 3362),
+    ("rescue_worker_helmet", 3065),
+    ("restroom", 3293),
+    ("reunion", 3713),
+    ("revolving_hearts", 136),
+    ("rewind", 3368),
+    ("rhinoceros", 2474),
+    ("ribbon", 2954),
+    ("rice", 2665),
+    ("rice_ball", 2664),
+    ("rice_cracker", 2663),
+    ("rice_scene", 2952),
+    ("right_anger_bubble", 166),
+    ("rightwards_hand", 199),
+    ("rightwards_pushing_hand", 229),
+    ("ring", 3068),
+    ("ring_buoy", 2837),
+    ("ringed_planet", 2907),
+    ("robot", 117),
+    ("rock", 2740),
+    ("rocket", 2856),
+    ("rofl", 6),
+    ("roll_eyes", 45),
+    ("roll_of_paper", 3270),
+    ("roller_coaster", 2783),
+    ("roller_skate", 2824),
+    ("romania", 3714),
+    ("rooster", 2498),
+    ("rose", 2565),
+    ("rosette", 2564),
+    ("rotating_light", 2831),
+    ("round_pushpin", 3197),
+    ("rowboat", 1869),
+    ("rowing_man", 1875),
+    ("rowing_woman", 1881),
+    ("ru", 3716),
+    ("rugby_football", 2971),
+    ("runner", 1726),
+    ("running", 1726),
+    ("running_man", 1732),
+    ("running_shirt_with_sash", 2988),
+    ("running_woman", 1738),
+    ("rwanda", 3717),
+    ("sa", 3461),
+    ("safety_pin", 3267),
+    ("safety_vest", 3027),
+    ("sagittarius", 3355),
+    ("sailboat", 2838),
+    ("sake", 2698),
+    ("salt", 2660),
+    ("saluting_face", 35),
+    ("samoa", 3771),
+    ("san_marino", 3729),
+    ("sandal", 3055),
+    ("sandwich", 2645),
+    ("santa", 1341),
+    ("sao_tome_principe", 3734),
+    ("sari", 3037),
+    ("sassy_man", 741),
+    ("sassy_woman", 747),
+    ("satellite", 3245),
+    ("satisfied", 4),
+    ("saudi_arabia", 3718),
+    ("sauna_man", 1789),
+    ("sauna_person", 1783),
+    ("sauna_woman", 1795),
+    ("sauropod", 2525),
+    ("saxophone", 3088),
+    ("scarf", 3031),
+    ("school", 2755),
+    ("school_satchel", 3048),
+    ("scientist", 1005),
+    ("scissors", 3202),
+    ("scorpion", 2555),
+    ("scorpius", 3354),
+    ("scotland", 3779),
+    ("scream", 94),
+    ("scream_cat", 124),
+    ("screwdriver", 3225),
+    ("scroll", 3146),
+    ("seal", 2530),
+    ("seat", 2850),
+    ("secret", 3474),
+    ("see_no_evil", 127),
+    ("seedling", 2572),
+    ("selfie", 441),
+    ("senegal", 3730),
+    ("serbia", 3715),
+    ("service_dog", 2437),
+    ("seven", 3434),
+    ("sewing_needle", 3020),
+    ("seychelles", 3720),
+    ("shaking_face", 49),
+    ("shallow_pan_of_food", 2653),
+    ("shamrock", 2580),
+    ("shark", 2534),
+    ("shaved_ice", 2680),
+    ("sheep", 2466),
+    ("shell", 2536),
+    ("shield", 3222),
+    ("shinto_shrine", 2767),
+    ("ship", 2844),
+    ("shirt", 3029),
+    ("shit", 110),
+    ("shoe", 3050),
+    ("shopping", 3047),
+    ("shopping_cart", 3277),
+    ("shorts", 3040),
+    ("shower", 3262),
+    ("shrimp", 2541),
+    ("shrug", 825),
+    ("shushing_face", 33),
+    ("sierra_leone", 3728),
+    ("signal_strength", 3381),
+    ("singapore", 3723),
+    ("singer", 1041),
+    ("sint_maarten", 3736),
+    ("six", 3433),
+    ("six_pointed_star", 3345),
+    ("skateboard", 2823),
+    ("ski", 2989),
+    ("skier", 1826),
+    ("skull", 108),
+    ("skull_and_crossbones", 109),
+    ("skunk", 2492),
+    ("sled", 2990),
+    ("sleeping", 56),
+    ("sleeping_bed", 2076),
+    ("sleepy", 54),
+    ("slightly_frowning_face", 79),
+    ("slightly_smiling_face", 8),
+    ("slot_machine", 3001),
+    ("sloth", 2490),
+    ("slovakia", 3727),
+    ("slovenia", 3725),
+    ("small_airplane", 2846),
+    ("small_blue_diamond", 3504),
+    ("small_orange_diamond", 3503),
+    ("small_red_triangle", 3505),
+    ("small_red_triangle_down", 3506),
+    ("smile", 2),
+    ("smile_cat", 119),
+    ("smiley", 1),
+    ("smiley_cat", 118),
+    ("smiling_face_with_tear", 22),
+    ("smiling_face_with_three_hearts", 14),
+    ("smiling_imp", 106),
+    ("smirk", 43),
+    ("smirk_cat", 122),
+    ("smoking", 3278),
+    ("snail", 2544),
+    ("snake", 2522),
+    ("sneezing_face", 63),
+    ("snowboarder", 1827),
+    ("snowflake", 2931),
+    ("snowman", 2933),
+    ("snowman_with_snow", 2932),
+    ("soap", 3272),
+    ("sob", 93),
+    ("soccer", 2965),
+    ("socks", 3034),
+    ("softball", 2967),
+    ("solomon_islands", 3719),
+    ("somalia", 3731),
+    ("soon", 3332),
+    ("sos", 3457),
+    ("sound", 3072),
+    ("south_africa", 3775),
+    ("south_georgia_south_sandwich_islands", 3614),
+    ("south_sudan", 3733),
+    ("space_invader", 116),
+    ("spades", 3008),
+    ("spaghetti", 2668),
+    ("sparkle", 3420),
+    ("sparkler", 2941),
+    ("sparkles", 2943),
+    ("sparkling_heart", 133),
+    ("speak_no_evil", 129),
+    ("speaker", 3071),
+    ("speaking_head", 2419),
+    ("speech_balloon", 163),
+    ("speedboat", 2840),
+    ("spider", 2553),
+    ("spider_web", 2554),
+    ("spiral_calendar", 3190),
+    ("spiral_notepad", 3189),
+    ("sponge", 3275),
+    ("spoon", 2716),
+    ("squid", 2542),
+    ("sri_lanka", 3655),
+    ("st_barthelemy", 3545),
+    ("st_helena", 3724),
+    ("st_kitts_nevis", 3645),
+    ("st_lucia", 3653),
+    ("st_martin", 3666),
+    ("st_pierre_miquelon", 3705),
+    ("st_vincent_grenadines", 3764),
+    ("stadium", 2736),
+    ("standing_man", 1570),
+    ("standing_person", 1564),
+    ("standing_woman", 1576),
+    ("star", 2908),
+    ("star2", 2909),
+    ("star_and_crescent", 3342),
+    ("star_of_david", 3337),
+    ("star_struck", 16),
+    ("stars", 2910),
+    ("station", 2793),
+    ("statue_of_liberty", 2762),
+    ("steam_locomotive", 2786),
+    ("stethoscope", 3251),
+    ("stew", 2654),
+    ("stop_button", 3375),
+    ("stop_sign", 2834),
+    ("stopwatch", 2864),
+    ("straight_ruler", 3200),
+    ("strawberry", 2603),
+    ("stuck_out_tongue", 24),
+    ("stuck_out_tongue_closed_eyes", 27),
+    ("stuck_out_tongue_winking_eye", 25),
+    ("student", 861),
+    ("studio_microphone", 3082),
+    ("stuffed_flatbread", 2649),
+    ("sudan", 3721),
+    ("sun_behind_large_cloud", 2916),
+    ("sun_behind_rain_cloud", 2917),
+    ("sun_behind_small_cloud", 2915),
+    ("sun_with_face", 2906),
+    ("sunflower", 2568),
+    ("sunglasses", 73),
+    ("sunny", 2904),
+    ("sunrise", 2775),
+    ("sunrise_over_mountains", 2774),
+    ("superhero", 1359),
+    ("superhero_man", 1365),
+    ("superhero_woman", 1371),
+    ("supervillain", 1377),
+    ("supervillain_man", 1383),
+    ("supervillain_woman", 1389),
+    ("surfer", 1851),
+    ("surfing_man", 1857),
+    ("surfing_woman", 1863),
+    ("suriname", 3732),
+    ("sushi", 2671),
+    ("suspension_railway", 2852),
+    ("svalbard_jan_mayen", 3726),
+    ("swan", 2507),
+    ("swaziland", 3738),
+    ("sweat", 98),
+    ("sweat_drops", 160),
+    ("sweat_smile", 5),
+    ("sweden", 3722),
+    ("sweet_potato", 2669),
+    ("swim_brief", 3039),
+    ("swimmer", 1887),
+    ("swimming_man", 1893),
+    ("swimming_woman", 1899),
+    ("switzerland", 3562),
+    ("symbols", 3441),
+    ("synagogue", 2766),
+    ("syria", 3737),
+    ("syringe", 3246),
+    ("t-rex", 2526),
+    ("taco", 2646),
+    ("tada", 2945),
+    ("taiwan", 3754),
+    ("tajikistan", 3745),
+    ("takeout_box", 2678),
+    ("tamale", 2648),
+    ("tanabata_tree", 2947),
+    ("tangerine", 2592),
+    ("tanzania", 3755),
+    ("taurus", 3348),
+    ("taxi", 2806),
+    ("tea", 2697),
+    ("teacher", 879),
+    ("teapot", 2696),
+    ("technologist", 1023),
+    ("teddy_bear", 3004),
+    ("telephone", 3102),
+    ("telephone_receiver", 3103),
+    ("telescope", 3244),
+    ("tennis", 2972),
+    ("tent", 2770),
+    ("test_tube", 3240),
+    ("thailand", 3744),
+    ("thermometer", 2903),
+    ("thinking", 34),
+    ("thong_sandal", 3049),
+    ("thought_balloon", 167),
+    ("thread", 3019),
+    ("three", 3430),
+    ("thumbsdown", 337),
+    ("thumbsup", 331),
+    ("ticket", 2958),
+    ("tickets", 2957),
+    ("tiger", 2446),
+    ("tiger2", 2447),
+    ("timer_clock", 2865),
+    ("timor_leste", 3747),
+    ("tipping_hand_man", 741),
+    ("tipping_hand_person", 735),
+    ("tipping_hand_woman", 747),
+    ("tired_face", 100),
+    ("tm", 3423),
+    ("togo", 3743),
+    ("toilet", 3260),
+    ("tokelau", 3746),
+    ("tokyo_tower", 2761),
+    ("tomato", 2606),
+    ("tonga", 3750),
+    ("tongue", 492),
+    ("toolbox", 3235),
+    ("tooth", 488),
+    ("toothbrush", 3274),
+    ("top", 3333),
+    ("tophat", 3061),
+    ("tornado", 2921),
+    ("tr", 3751),
+    ("trackball", 3114),
+    ("tractor", 2814),
+    ("traffic_light", 2832),
+    ("train", 2797),
+    ("train2", 2790),
+    ("tram", 2794),
+    ("transgender_flag", 3517),
+    ("transgender_symbol", 3387),
+    ("triangular_flag_on_post", 3512),
+    ("triangular_ruler", 3201),
+    ("trident", 3406),
+    ("trinidad_tobago", 3752),
+    ("tristan_da_cunha", 3739),
+    ("triumph", 102),
+    ("troll", 1491),
+    ("trolleybus", 2800),
+    ("trophy", 2960),
+    ("tropical_drink", 2702),
+    ("tropical_fish", 2532),
+    ("truck", 2812),
+    ("trumpet", 3092),
+    ("tshirt", 3029),
+    ("tulip", 2570),
+    ("tumbler_glass", 2706),
+    ("tunisia", 3749),
+    ("turkey", 2496),
+    ("turkmenistan", 3748),
+    ("turks_caicos_islands", 3740),
+    ("turtle", 2520),
+    ("tuvalu", 3753),
+    ("tv", 3124),
+    ("twisted_rightwards_arrows", 3360),
+    ("two", 3429),
+    ("two_hearts", 137),
+    ("two_men_holding_hands", 2160),
+    ("two_women_holding_hands", 2108),
+    ("u5272", 3466),
+    ("u5408", 3471),
+    ("u55b6", 3475),
+    ("u6307", 3464),
+    ("u6708", 3462),
+    ("u6709", 3463),
+    ("u6e80", 3476),
+    ("u7121", 3467),
+    ("u7533", 3470),
+    ("u7981", 3468),
+    ("u7a7a", 3472),
+    ("uganda", 3757),
+    ("uk", 3601),
+    ("ukraine", 3756),
+    ("umbrella", 2928),
+    ("unamused", 44),
+    ("underage", 3310),
+    ("unicorn", 2453),
+    ("united_arab_emirates", 3521),
+    ("united_nations", 3759),
+    ("unlock", 3207),
+    ("up", 3458),
+    ("upside_down_face", 9),
+    ("uruguay", 3761),
+    ("us", 3760),
+    ("us_outlying_islands", 3758),
+    ("us_virgin_islands", 3767),
+    ("uzbekistan", 3762),
+    ("v", 253),
+    ("vampire", 1431),
+    ("vampire_man", 1437),
+    ("vampire_woman", 1443),
+    ("vanuatu", 3769),
+    ("vatican_city", 3763),
+    ("venezuela", 3765),
+    ("vertical_traffic_light", 2833),
+    ("vhs", 3128),
+    ("vibration_mode", 3383),
+    ("video_camera", 3127),
+    ("video_game", 2999),
+    ("vietnam", 3768),
+    ("violin", 3093),
+    ("virgo", 3352),
+    ("volcano", 2729),
+    ("volleyball", 2969),
+    ("vomiting_face", 62),
+    ("vs", 3459),
+    ("vulcan_salute", 193),
+    ("waffle", 2635),
+    ("wales", 3780),
+    ("walking", 1528),
+    ("walking_man", 1534),
+    ("walking_woman", 1540),
+    ("wallis_futuna", 3770),
+    ("waning_crescent_moon", 2898),
+    ("waning_gibbous_moon", 2896),
+    ("warning", 3300),
+    ("wastebasket", 3205),
+    ("watch", 2862),
+    ("water_buffalo", 2459),
+    ("water_polo", 1998),
+    ("watermelon", 2591),
+    ("wave", 169),
+    ("wavy_dash", 3400),
+    ("waxing_crescent_moon", 2892),
+    ("waxing_gibbous_moon", 2894),
+    ("wc", 3295),
+    ("weary", 99),
+    ("wedding", 2760),
+    ("weight_lifting", 1923),
+    ("weight_lifting_man", 1929),
+    ("weight_lifting_woman", 1935),
+    ("western_sahara", 3589),
+    ("whale", 2527),
+    ("whale2", 2528),
+    ("wheel", 2830),
+    ("wheel_of_dharma", 3338),
+    ("wheelchair", 3290),
+    ("white_check_mark", 3410),
+    ("white_circle", 3485),
+    ("white_flag", 3515),
+    ("white_flower", 2562),
+    ("white_haired_man", 567),
+    ("white_haired_woman", 609),
+    ("white_heart", 154),
+    ("white_large_square", 3494),
+    ("white_medium_small_square", 3498),
+    ("white_medium_square", 3496),
+    ("white_small_square", 3500),
+    ("white_square_button", 3509),
+    ("wilted_flower", 2566),
+    ("wind_chime", 2951),
+    ("wind_face", 2923),
+    ("window", 3256),
+    ("wine_glass", 2700),
+    ("wing", 2514),
+    ("wink", 11),
+    ("wireless", 3382),
+    ("wolf", 2439),
+    ("woman", 579),
+    ("woman_artist", 1071),
+    ("woman_astronaut", 1107),
+    ("woman_beard", 549),
+    ("woman_cartwheeling", 1989),
+    ("woman_cook", 945),
+    ("woman_dancing", 1762),
+    ("woman_facepalming", 819),
+    ("woman_factory_worker", 981),
+    ("woman_farmer", 927),
+    ("woman_feeding_baby", 1317),
+    ("woman_firefighter", 1125),
+    ("woman_health_worker", 855),
+    ("woman_in_manual_wheelchair", 1714),
+    ("woman_in_motorized_wheelchair", 1678),
+    ("woman_in_tuxedo", 1269),
+    ("woman_judge", 909),
+    ("woman_juggling", 2046),
+    ("woman_mechanic", 963),
+    ("woman_office_worker", 999),
+    ("woman_pilot", 1089),
+    ("woman_playing_handball", 2028),
+    ("woman_playing_water_polo", 2010),
+    ("woman_scientist", 1017),
+    ("woman_shrugging", 837),
+    ("woman_singer", 1053),
+    ("woman_student", 873),
+    ("woman_teacher", 891),
+    ("woman_technologist", 1035),
+    ("woman_with_headscarf", 1251),
+    ("woman_with_probing_cane", 1642),
+    ("woman_with_turban", 1239),
+    ("woman_with_veil", 1287),
+    ("womans_clothes", 3042),
+    ("womans_hat", 3060),
+    ("women_wrestling", 1997),
+    ("womens", 3292),
+    ("wood", 2741),
+    ("woozy_face", 66),
+    ("world_map", 2724),
+    ("worm", 2558),
+    ("worried", 78),
+    ("wrench", 3224),
+    ("wrestling", 1995),
+    ("writing_hand", 429),
+    ("x", 3413),
+    ("x_ray", 3252),
+    ("yarn", 3021),
+    ("yawning_face", 101),
+    ("yellow_circle", 3479),
+    ("yellow_heart", 146),
+    ("yellow_square", 3488),
+    ("yemen", 3773),
+    ("yen", 3155),
+    ("yin_yang", 3339),
+    ("yo_yo", 2993),
+    ("yum", 23),
+    ("zambia", 3776),
+    ("zany_face", 26),
+    ("zap", 2930),
+    ("zebra", 2454),
+    ("zero", 3427),
+    ("zimbabwe", 3777),
+    ("zipper_mouth_face", 36),
+    ("zombie", 1488),
+    ("zombie_man", 1489),
+    ("zombie_woman", 1490),
+    ("zzz", 168),
+];
+
+/// The maximum length in bytes of any shortcode.
+pub const MAX_LEN: usize = 40;
+
+pub static MAP: ::phf::Map<&'static str, usize> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 627),
+        (0, 16),
+        (0, 313),
+        (0, 20),
+        (0, 70),
+        (0, 0),
+        (0, 1),
+        (0, 0),
+        (0, 91),
+        (0, 8),
+        (0, 378),
+        (0, 14),
+        (0, 0),
+        (0, 236),
+        (0, 781),
+        (0, 7),
+        (0, 412),
+        (0, 0),
+        (0, 1),
+        (0, 62),
+        (0, 7),
+        (0, 57),
+        (0, 262),
+        (0, 0),
+        (0, 312),
+        (0, 2),
+        (0, 1),
+        (0, 203),
+        (0, 59),
+        (0, 133),
+        (0, 3),
+        (0, 68),
+        (0, 3),
+        (0, 6),
+        (0, 64),
+        (0, 4),
+        (0, 522),
+        (0, 72),
+        (0, 63),
+        (0, 7),
+        (0, 128),
+        (0, 2),
+        (0, 1),
+        (1, 740),
+        (0, 8),
+        (0, 5),
+        (0, 6),
+        (0, 27),
+        (0, 46),
+        (0, 236),
+        (0, 24),
+        (0, 0),
+        (0, 80),
+        (0, 10),
+        (0, 3),
+        (0, 255),
+        (0, 88),
+        (0, 123),
+        (0, 0),
+        (0, 54),
+        (0, 10),
+        (0, 1241),
+        (0, 330),
+        (0, 1),
+        (0, 1787),
+        (0, 112),
+        (0, 5),
+        (0, 48),
+        (0, 292),
+        (0, 5),
+        (0, 452),
+        (0, 0),
+        (0, 9),
+        (0, 3),
+        (0, 229),
+        (0, 358),
+        (0, 1237),
+        (0, 23),
+        (0, 315),
+        (0, 0),
+        (0, 78),
+        (0, 218),
+        (0, 902),
+        (0, 0),
+        (0, 3),
+        (0, 38),
+        (0, 1686),
+        (0, 148),
+        (0, 155),
+        (0, 29),
+        (0, 744),
+        (0, 5),
+        (0, 110),
+        (0, 2),
+        (0, 674),
+        (0, 0),
+        (0, 5),
+        (0, 707),
+        (0, 7),
+        (0, 4),
+        (0, 164),
+        (0, 86),
+        (0, 144),
+        (0, 392),
+        (0, 97),
+        (0, 286),
+        (0, 554),
+        (0, 25),
+        (0, 16),
+        (0, 323),
+        (0, 8),
+        (0, 559),
+        (0, 304),
+        (0, 0),
+        (0, 7),
+        (0, 73),
+        (0, 1001),
+        (0, 459),
+        (0, 5),
+        (0, 1),
+        (1, 50),
+        (0, 1742),
+        (0, 14),
+        (0, 10),
+        (0, 673),
+        (0, 120),
+        (0, 0),
+        (0, 145),
+        (0, 7),
+        (0, 8),
+        (0, 187),
+        (0, 11),
+        (0, 2),
+        (0, 5),
+        (0, 8),
+        (0, 195),
+        (0, 442),
+        (0, 54),
+        (0, 106),
+        (0, 726),
+        (0, 2),
+        (0, 722),
+        (0, 398),
+        (0, 3),
+        (0, 69),
+        (0, 0),
+        (0, 8),
+        (0, 578),
+        (1, 1201),
+        (0, 1486),
+        (0, 531),
+        (0, 220),
+        (0, 68),
+        (0, 3),
+        (0, 64),
+        (0, 48),
+        (0, 298),
+        (0, 14),
+        (0, 25),
+        (0, 47),
+        (0, 13),
+        (0, 0),
+        (0, 874),
+        (0, 156),
+        (0, 1401),
+        (0, 265),
+        (1, 1301),
+        (0, 11),
+        (0, 14),
+        (0, 15),
+        (0, 186),
+        (0, 835),
+        (0, 180),
+        (0, 99),
+        (0, 0),
+        (0, 82),
+        (0, 167),
+        (0, 37),
+        (0, 245),
+        (0, 465),
+        (0, 37),
+        (0, 40),
+        (0, 89),
+        (0, 42),
+        (2, 82),
+        (0, 38),
+        (0, 240),
+        (0, 1451),
+        (0, 471),
+        (0, 22),
+        (0, 546),
+        (0, 1),
+        (0, 178),
+        (0, 0),
+        (0, 376),
+        (0, 0),
+        (0, 107),
+        (1, 428),
+        (0, 509),
+        (0, 7),
+        (0, 581),
+        (0, 1),
+        (0, 1),
+        (0, 3),
+        (0, 99),
+        (0, 61),
+        (1, 395),
+        (0, 56),
+        (0, 1025),
+        (0, 81),
+        (0, 75),
+        (0, 117),
+        (0, 268),
+        (0, 23),
+        (0, 0),
+        (0, 382),
+        (0, 3),
+        (0, 808),
+        (0, 70),
+        (0, 51),
+        (0, 5),
+        (0, 1474),
+        (1, 850),
+        (0, 1544),
+        (0, 25),
+        (0, 611),
+        (0, 36),
+        (0, 16),
+        (0, 15),
+        (0, 418),
+        (0, 20),
+        (0, 103),
+        (0, 1138),
+        (4, 202),
+        (0, 31),
+        (0, 2),
+        (0, 30),
+        (0, 3),
+        (0, 1010),
+        (0, 2),
+        (0, 157),
+        (3, 1452),
+        (0, 827),
+        (1, 9),
+        (0, 1036),
+        (0, 185),
+        (0, 1),
+        (0, 145),
+        (0, 872),
+        (0, 11),
+        (1, 223),
+        (0, 22),
+        (2, 504),
+        (0, 495),
+        (0, 37),
+        (0, 51),
+        (3, 1189),
+        (0, 918),
+        (0, 21),
+        (0, 800),
+        (0, 181),
+        (0, 167),
+        (0, 131),
+        (0, 1),
+        (1, 593),
+        (0, 0),
+        (0, 1489),
+        (0, 51),
+        (0, 1012),
+        (0, 6),
+        (0, 0),
+        (0, 77),
+        (0, 67),
+        (0, 0),
+        (0, 1),
+        (0, 5),
+        (4, 714),
+        (0, 1),
+        (0, 115),
+        (0, 10),
+        (0, 170),
+        (0, 397),
+        (0, 114),
+        (0, 1182),
+        (0, 822),
+        (1, 985),
+        (0, 159),
+        (0, 39),
+        (0, 71),
+        (0, 1313),
+        (1, 1172),
+        (0, 471),
+        (0, 11),
+        (1, 538),
+        (0, 150),
+        (0, 0),
+        (0, 97),
+        (0, 62),
+        (0, 16),
+        (0, 824),
+        (0, 1),
+        (0, 15),
+        (0, 558),
+        (0, 1),
+        (0, 49),
+        (0, 434),
+        (0, 170),
+        (1, 854),
+        (1, 1529),
+        (0, 406),
+        (1, 651),
+        (0, 1910),
+        (0, 34),
+        (0, 47),
+        (0, 377),
+        (0, 0),
+        (0, 23),
+        (0, 44),
+        (0, 156),
+        (0, 4),
+        (0, 809),
+        (0, 46),
+        (5, 1381),
+        (1, 1215),
+        (0, 50),
+        (7, 1361),
+        (2, 294),
+        (0, 465),
+        (0, 6),
+        (10, 1122),
+        (0, 560),
+        (0, 1545),
+        (0, 6),
+        (1, 919),
+        (0, 3),
+        (0, 15),
+        (0, 3),
+        (3, 815),
+        (0, 66),
+        (0, 19),
+        (0, 831),
+        (1, 989),
+        (2, 1334),
+        (0, 45),
+        (0, 1570),
+        (0, 17),
+        (0, 937),
+        (0, 5),
+        (0, 296),
+        (0, 2),
+        (0, 0),
+        (4, 896),
+        (0, 9),
+        (5, 277),
+        (0, 4),
+        (2, 931),
+        (0, 671),
+        (1, 376),
+        (0, 2),
+        (0, 93),
+        (2, 1644),
+        (0, 68),
+        (3, 319),
+        (3, 1881),
+        (0, 1254),
+        (0, 1566),
+        (0, 34),
+        (0, 197),
+        (3, 1524),
+        (0, 746),
+        (0, 35),
+        (0, 230),
+        (0, 58),
+        (2, 1343),
+        (1, 1466),
+        (0, 391),
+        (3, 569),
+        (0, 581),
+        (0, 6),
+        (0, 1542),
+        (0, 269),
+        (0, 1),
+        (1, 1707),
+    ],
+    entries: &[
+        ("algeria", 3584),
+        ("pouting_face", 681),
+        ("waning_crescent_moon", 2898),
+        ("swan", 2507),
+        ("family_man_man_girl", 2400),
+        ("large_blue_diamond", 3502),
+        ("house", 2745),
+        ("factory", 2757),
+        ("myanmar", 3671),
+        ("bat", 2485),
+        ("family_man_woman_boy", 2394),
+        ("moyai", 3284),
+        ("water_buffalo", 2459),
+        ("malaysia", 3683),
+        ("tiger", 2446),
+        ("birthday", 2684),
+        ("speaker", 3071),
+        ("guitar", 3090),
+        ("bird", 2502),
+        ("pregnant_woman", 1293),
+        ("arrow_backward", 3367),
+        ("nicaragua", 3690),
+        ("kneeling_person", 1582),
+        ("man_cartwheeling", 1983),
+        ("south_africa", 3775),
+        ("iceland", 3634),
+        ("radioactive", 3311),
+        ("ghost", 114),
+        ("hook", 3234),
+        ("turkey", 2496),
+        ("white_haired_man", 567),
+        ("u5272", 3466),
+        ("sierra_leone", 3728),
+        ("chains", 3233),
+        ("purple_circle", 3482),
+        ("sweat_drops", 160),
+        ("tent", 2770),
+        ("mammoth", 2473),
+        ("haircut_man", 1516),
+        ("es", 3591),
+        ("diamond_shape_with_a_dot_inside", 3507),
+        ("newspaper", 3148),
+        ("star", 2908),
+        ("people_hugging", 2422),
+        ("climbing", 1801),
+        ("student", 861),
+        ("phone", 3102),
+        ("nail_care", 435),
+        ("new_moon", 2891),
+        ("man_pilot", 1083),
+        ("money_mouth_face", 28),
+        ("costa_rica", 3571),
+        ("clock9", 2885),
+        ("dango", 2675),
+        ("bread", 2628),
+        ("meat_on_bone", 2637),
+        ("motor_scooter", 2817),
+        ("golfing_woman", 1845),
+        ("fox_face", 2440),
+        ("pencil", 3182),
+        ("cook", 933),
+        ("robot", 117),
+        ("benin", 3544),
+        ("computer_mouse", 3113),
+        ("england", 3778),
+        ("donkey", 2451),
+        ("statue_of_liberty", 2762),
+        ("man_farmer", 921),
+        ("eye_speech_bubble", 164),
+        ("person_feeding_baby", 1329),
+        ("biking_man", 1947),
+        ("family_woman_boy", 2414),
+        ("page_facing_up", 3147),
+        ("uk", 3601),
+        ("northern_mariana_islands", 3674),
+        ("australia", 3532),
+        ("teddy_bear", 3004),
+        ("oncoming_automobile", 2809),
+        ("sauna_man", 1789),
+        ("mermaid", 1461),
+        ("skull_and_crossbones", 109),
+        ("thread", 3019),
+        ("flatbread", 2631),
+        ("baggage_claim", 3298),
+        ("art", 3018),
+        ("last_quarter_moon", 2897),
+        ("tipping_hand_woman", 747),
+        ("bouncing_ball_man", 1911),
+        ("diving_mask", 2987),
+        ("crutch", 3250),
+        ("index_pointing_at_the_viewer", 325),
+        ("100", 156),
+        ("aquarius", 3357),
+        ("rainbow_flag", 3516),
+        ("umbrella", 2928),
+        ("sauna_person", 1783),
+        ("scotland", 3779),
+        ("parasol_on_ground", 2929),
+        ("non-potable_water", 3307),
+        ("open_umbrella", 2927),
+        ("ping_pong", 2979),
+        ("sheep", 2466),
+        ("wc", 3295),
+        ("st_kitts_nevis", 3645),
+        ("woman_in_tuxedo", 1269),
+        ("guinea", 3610),
+        ("lemon", 2593),
+        ("woman_teacher", 891),
+        ("mayotte", 3774),
+        ("egypt", 3588),
+        ("afghanistan", 3522),
+        ("orange", 2592),
+        ("peacock", 2512),
+        ("part_alternation_mark", 3417),
+        ("crown", 3059),
+        ("nine", 3436),
+        ("telescope", 3244),
+        ("anger", 157),
+        ("arrow_down_small", 3372),
+        ("ideograph_advantage", 3465),
+        ("accept", 3469),
+        ("massage_woman", 1504),
+        ("fearful", 89),
+        ("blue_car", 2810),
+        ("white_square_button", 3509),
+        ("camping", 2731),
+        ("bowl_with_spoon", 2656),
+        ("flute", 3098),
+        ("no_pedestrians", 3308),
+        ("heavy_exclamation_mark", 3399),
+        ("trophy", 2960),
+        ("family_man_man_boy", 2399),
+        ("older_man", 651),
+        ("white_heart", 154),
+        ("seedling", 2572),
+        ("gloves", 3032),
+        ("papua_new_guinea", 3701),
+        ("lantern", 3134),
+        ("penguin", 2503),
+        ("asterisk", 3426),
+        ("manual_wheelchair", 2818),
+        ("put_litter_in_its_place", 3288),
+        ("bosnia_herzegovina", 3536),
+        ("kite", 2994),
+        ("pig", 2461),
+        ("wrench", 3224),
+        ("imp", 107),
+        ("o", 3409),
+        ("flashlight", 3133),
+        ("mount_fuji", 2730),
+        ("adhesive_bandage", 3249),
+        ("red_square", 3486),
+        ("denmark", 3581),
+        ("cloud_with_snow", 2919),
+        ("tickets", 2957),
+        ("brain", 485),
+        ("desert_island", 2734),
+        ("woman_cook", 945),
+        ("us_virgin_islands", 3767),
+        ("tomato", 2606),
+        ("bee", 2548),
+        ("man_singer", 1047),
+        ("fist_left", 355),
+        ("dotted_line_face", 41),
+        ("fog", 2922),
+        ("chart_with_upwards_trend", 3192),
+        ("soccer", 2965),
+        ("no_bicycles", 3304),
+        ("clock630", 2880),
+        ("liberia", 3656),
+        ("axe", 3213),
+        ("shinto_shrine", 2767),
+        ("feather", 2510),
+        ("scream_cat", 124),
+        ("tristan_da_cunha", 3739),
+        ("t-rex", 2526),
+        ("turtle", 2520),
+        ("mango", 2597),
+        ("droplet", 2936),
+        ("bullettrain_front", 2789),
+        ("no_good", 699),
+        ("screwdriver", 3225),
+        ("biohazard", 3312),
+        ("french_southern_territories", 3742),
+        ("u6307", 3464),
+        ("clamp", 3228),
+        ("yellow_circle", 3479),
+        ("gun", 2995),
+        ("clock10", 2887),
+        ("blowfish", 2533),
+        ("family_woman_boy_boy", 2415),
+        ("abcd", 3439),
+        ("family_woman_woman_girl_girl", 2408),
+        ("heavy_plus_sign", 3389),
+        ("golf", 2984),
+        ("goose", 2516),
+        ("negative_squared_cross_mark", 3414),
+        ("rowing_man", 1875),
+        ("star_of_david", 3337),
+        ("chipmunk", 2482),
+        ("hotsprings", 2779),
+        ("botswana", 3554),
+        ("running_shirt_with_sash", 2988),
+        ("saxophone", 3088),
+        ("hamsa", 3283),
+        ("wheelchair", 3290),
+        ("clock430", 2876),
+        ("wilted_flower", 2566),
+        ("probing_cane", 3230),
+        ("cape_verde", 3573),
+        ("guardsman", 1173),
+        ("clock230", 2872),
+        ("water_polo", 1998),
+        ("guyana", 3618),
+        ("sauropod", 2525),
+        ("cloud", 2912),
+        ("tm", 3423),
+        ("kenya", 3640),
+        ("bubbles", 3273),
+        ("film_strip", 3121),
+        ("elephant", 2472),
+        ("milk_glass", 2694),
+        ("man_health_worker", 849),
+        ("truck", 2812),
+        ("innocent", 13),
+        ("point_up_2", 301),
+        ("broom", 3268),
+        ("nauseated_face", 61),
+        ("sunrise", 2775),
+        ("mauritius", 3679),
+        ("bison", 2456),
+        ("telephone", 3102),
+        ("melon", 2590),
+        ("radio_button", 3508),
+        ("disappointed", 97),
+        ("control_knobs", 3084),
+        ("nesting_dolls", 3007),
+        ("postal_horn", 3076),
+        ("wallis_futuna", 3770),
+        ("seat", 2850),
+        ("cn", 3567),
+        ("brown_square", 3492),
+        ("pinata", 3005),
+        ("framed_picture", 3017),
+        ("beginner", 3408),
+        ("ok_man", 723),
+        ("peach", 2601),
+        ("face_with_head_bandage", 60),
+        ("flat_shoe", 3053),
+        ("family_man_woman_girl", 2395),
+        ("bouquet", 2560),
+        ("couch_and_lamp", 3258),
+        ("smile_cat", 119),
+        ("loud_sound", 3073),
+        ("guadeloupe", 3611),
+        ("vampire_man", 1437),
+        ("o2", 3454),
+        ("alarm_clock", 2863),
+        ("love_letter", 130),
+        ("cat2", 2443),
+        ("running_woman", 1738),
+        ("wood", 2741),
+        ("family_man_woman_boy_boy", 2397),
+        ("mate", 2711),
+        ("pancakes", 2634),
+        ("medal_sports", 2961),
+        ("weight_lifting_man", 1929),
+        ("moon", 2894),
+        ("superhero_man", 1365),
+        ("woman_mechanic", 963),
+        ("person_in_tuxedo", 1257),
+        ("film_projector", 3122),
+        ("light_blue_heart", 149),
+        ("armenia", 3526),
+        ("superhero", 1359),
+        ("flower_playing_cards", 3015),
+        ("clock1", 2869),
+        ("building_construction", 2738),
+        ("thermometer", 2903),
+        ("face_in_clouds", 42),
+        ("middle_finger", 307),
+        ("rice_ball", 2664),
+        ("hungary", 3624),
+        ("potable_water", 3289),
+        ("twisted_rightwards_arrows", 3360),
+        ("walking_man", 1534),
+        ("svalbard_jan_mayen", 3726),
+        ("video_game", 2999),
+        ("no_good_man", 705),
+        ("hiking_boot", 3052),
+        ("chair", 3259),
+        ("lithuania", 3658),
+        ("candy", 2689),
+        ("fist_right", 361),
+        ("pie", 2687),
+        ("guide_dog", 2436),
+        ("cl", 3446),
+        ("u7981", 3468),
+        ("lock_with_ink_pen", 3208),
+        ("sri_lanka", 3655),
+        ("bow", 789),
+        ("vomiting_face", 62),
+        ("partying_face", 71),
+        ("exploding_head", 69),
+        ("sunflower", 2568),
+        ("hourglass_flowing_sand", 2861),
+        ("melting_face", 10),
+        ("latin_cross", 3340),
+        ("mahjong", 3014),
+        ("champagne", 2699),
+        ("palm_up_hand", 217),
+        ("rowing_woman", 1881),
+        ("on", 3331),
+        ("croatia", 3622),
+        ("gorilla", 2432),
+        ("fist_oncoming", 349),
+        ("clap", 367),
+        ("arrow_up_down", 3321),
+        ("united_arab_emirates", 3521),
+        ("fax", 3105),
+        ("mage_man", 1401),
+        ("ocean", 2937),
+        ("roll_eyes", 45),
+        ("jar", 2718),
+        ("supervillain", 1377),
+        ("disappointed_relieved", 91),
+        ("blond_haired_woman", 633),
+        ("mouse_trap", 3264),
+        ("man_in_motorized_wheelchair", 1666),
+        ("wastebasket", 3205),
+        ("bouvet_island", 3553),
+        ("gear", 3227),
+        ("globe_with_meridians", 2723),
+        ("spiral_notepad", 3189),
+        ("envelope_with_arrow", 3166),
+        ("eyeglasses", 3023),
+        ("french_polynesia", 3700),
+        ("potato", 2611),
+        ("tamale", 2648),
+        ("dog", 2434),
+        ("left_right_arrow", 3322),
+        ("kissing_closed_eyes", 20),
+        ("heartpulse", 134),
+        ("female_sign", 3385),
+        ("old_key", 3211),
+        ("couplekiss", 2186),
+        ("turks_caicos_islands", 3740),
+        ("fuelpump", 2829),
+        ("bangladesh", 3538),
+        ("couplekiss_man_man", 2238),
+        ("czech_republic", 3577),
+        ("azerbaijan", 3535),
+        ("medal_military", 2959),
+        ("do_not_litter", 3306),
+        ("shower", 3262),
+        ("chestnut", 2623),
+        ("chad", 3741),
+        ("kissing", 18),
+        ("lotus", 2563),
+        ("mailbox", 3170),
+        ("boot", 3057),
+        ("joystick", 3000),
+        ("cry", 92),
+        ("post_office", 2748),
+        ("rotating_light", 2831),
+        ("six", 3433),
+        ("mechanical_leg", 454),
+        ("flamingo", 2511),
+        ("plunger", 3261),
+        ("woman_in_motorized_wheelchair", 1678),
+        ("tajikistan", 3745),
+        ("hamburger", 2641),
+        ("cricket", 2551),
+        ("andorra", 3520),
+        ("libra", 3353),
+        ("cool", 3447),
+        ("comoros", 3644),
+        ("tornado", 2921),
+        ("arrow_heading_up", 3325),
+        ("fairy", 1413),
+        ("billed_cap", 3063),
+        ("nest_with_eggs", 2586),
+        ("lady_beetle", 2550),
+        ("stuck_out_tongue_closed_eyes", 27),
+        ("peace_symbol", 3343),
+        ("zipper_mouth_face", 36),
+        ("lesotho", 3657),
+        ("toothbrush", 3274),
+        ("safety_pin", 3267),
+        ("sunny", 2904),
+        ("man_scientist", 1011),
+        ("heavy_dollar_sign", 3402),
+        ("broken_heart", 140),
+        ("construction_worker", 1191),
+        ("mobile_phone_off", 3384),
+        ("child", 501),
+        ("shaking_face", 49),
+        ("duck", 2506),
+        ("razor", 3265),
+        ("musical_note", 3080),
+        ("british_virgin_islands", 3766),
+        ("zap", 2930),
+        ("u6e80", 3476),
+        ("climbing_woman", 1813),
+        ("eritrea", 3590),
+        ("farmer", 915),
+        ("biking_woman", 1953),
+        ("madagascar", 3667),
+        ("taxi", 2806),
+        ("ok_person", 717),
+        ("cockroach", 2552),
+        ("guernsey", 3605),
+        ("black_nib", 3177),
+        ("woman_firefighter", 1125),
+        ("worm", 2558),
+        ("arrow_left", 3319),
+        ("clock530", 2878),
+        ("fries", 2642),
+        ("belgium", 3539),
+        ("e-mail", 3164),
+        ("lotus_position_woman", 2064),
+        ("pick", 3214),
+        ("bow_and_arrow", 3221),
+        ("vampire_woman", 1443),
+        ("family", 2423),
+        ("bahrain", 3542),
+        ("timor_leste", 3747),
+        ("policewoman", 1143),
+        ("eggplant", 2610),
+        ("bowling", 2974),
+        ("mechanical_arm", 453),
+        ("headphones", 3086),
+        ("notebook", 3143),
+        ("kaaba", 2768),
+        ("anchor", 2836),
+        ("family_woman_girl_girl", 2418),
+        ("coconut", 2608),
+        ("hand", 187),
+        ("unicorn", 2453),
+        ("tunisia", 3749),
+        ("greenland", 3608),
+        ("laos", 3651),
+        ("calendar", 3188),
+        ("stuck_out_tongue", 24),
+        ("wing", 2514),
+        ("toilet", 3260),
+        ("motorway", 2826),
+        ("woman", 579),
+        ("abacus", 3119),
+        ("chile", 3565),
+        ("full_moon_with_face", 2905),
+        ("ginger_root", 2624),
+        ("game_die", 3002),
+        ("stethoscope", 3251),
+        ("headstone", 3280),
+        ("togo", 3743),
+        ("dna", 3242),
+        ("dollar", 3156),
+        ("teapot", 2696),
+        ("new", 3452),
+        ("libya", 3661),
+        ("woman_with_turban", 1239),
+        ("fountain_pen", 3178),
+        ("triangular_flag_on_post", 3512),
+        ("maracas", 3097),
+        ("floppy_disk", 3116),
+        ("earth_africa", 2720),
+        ("no_entry_sign", 3303),
+        ("world_map", 2724),
+        ("crystal_ball", 2997),
+        ("night_with_stars", 2772),
+        ("chess_pawn", 3012),
+        ("shirt", 3029),
+        ("playground_slide", 2781),
+        ("black_medium_small_square", 3497),
+        ("india", 3630),
+        ("camera_flash", 3126),
+        ("banjo", 3094),
+        ("carousel_horse", 2780),
+        ("next_track_button", 3365),
+        ("hotdog", 2644),
+        ("camera", 3125),
+        ("lollipop", 2690),
+        ("family_man_girl", 2411),
+        ("clock930", 2886),
+        ("woman_student", 873),
+        ("ring", 3068),
+        ("skull", 108),
+        ("person_red_hair", 591),
+        ("ethiopia", 3592),
+        ("grinning", 0),
+        ("baby_bottle", 2693),
+        ("clipboard", 3195),
+        ("woman_factory_worker", 981),
+        ("zero", 3427),
+        ("fondue", 2655),
+        ("mirror", 3255),
+        ("pisces", 3358),
+        ("baby", 495),
+        ("bermuda", 3546),
+        ("hash", 3425),
+        ("placard", 3285),
+        ("cat", 2442),
+        ("wireless", 3382),
+        ("suspension_railway", 2852),
+        ("pleading_face", 85),
+        ("runner", 1726),
+        ("face_holding_back_tears", 86),
+        ("hearts", 3009),
+        ("blond_haired_man", 639),
+        ("iraq", 3632),
+        ("no_good_woman", 711),
+        ("two_men_holding_hands", 2160),
+        ("bath", 2070),
+        ("man", 531),
+        ("martial_arts_uniform", 2982),
+        ("laughing", 4),
+        ("blonde_woman", 633),
+        ("koala", 2488),
+        ("argentina", 3529),
+        ("orangutan", 2433),
+        ("children_crossing", 3301),
+        ("unlock", 3207),
+        ("hot_face", 64),
+        ("hospital", 2750),
+        ("elf_man", 1473),
+        ("avocado", 2609),
+        ("green_square", 3489),
+        ("cookie", 2683),
+        ("shamrock", 2580),
+        ("mongolia", 3672),
+        ("ledger", 3144),
+        ("standing_woman", 1576),
+        ("classical_building", 2737),
+        ("flipper", 2529),
+        ("greece", 3613),
+        ("drum", 3095),
+        ("custard", 2691),
+        ("pencil2", 3176),
+        ("zebra", 2454),
+        ("large_blue_circle", 3481),
+        ("family_woman_woman_girl", 2405),
+        ("heard_mcdonald_islands", 3620),
+        ("straight_ruler", 3200),
+        ("lotion_bottle", 3266),
+        ("parking", 3456),
+        ("flags", 2950),
+        ("record_button", 3376),
+        ("colombia", 3568),
+        ("smiling_face_with_three_hearts", 14),
+        ("mountain_biking_man", 1965),
+        ("man_facepalming", 813),
+        ("face_exhaling", 47),
+        ("hatched_chick", 2501),
+        ("luxembourg", 3659),
+        ("pen", 3179),
+        ("u5408", 3471),
+        ("smile", 2),
+        ("mask", 58),
+        ("speech_balloon", 163),
+        ("plate_with_cutlery", 2714),
+        ("hotel", 2752),
+        ("cambodia", 3642),
+        ("factory_worker", 969),
+        ("clock11", 2889),
+        ("earth_americas", 2721),
+        ("solomon_islands", 3719),
+        ("waxing_gibbous_moon", 2894),
+        ("collision", 158),
+        ("leftwards_arrow_with_hook", 3323),
+        ("orange_circle", 3478),
+        ("ring_buoy", 2837),
+        ("rosette", 2564),
+        ("black_cat", 2444),
+        ("persevere", 96),
+        ("whale", 2527),
+        ("man_with_gua_pi_mao", 1245),
+        ("musical_score", 3079),
+        ("green_circle", 3480),
+        ("grey_exclamation", 3398),
+        ("philippines", 3702),
+        ("racehorse", 2452),
+        ("black_circle", 3484),
+        ("shrimp", 2541),
+        ("cherries", 2602),
+        ("fortune_cookie", 2677),
+        ("georgia", 3603),
+        ("gem", 3069),
+        ("green_apple", 2599),
+        ("top", 3333),
+        ("finland", 3594),
+        ("poland", 3704),
+        ("worried", 78),
+        ("tanzania", 3755),
+        ("face_with_thermometer", 59),
+        ("womans_hat", 3060),
+        ("sled", 2990),
+        ("microbe", 2559),
+        ("couple_with_heart_man_man", 2342),
+        ("foot", 461),
+        ("four_leaf_clover", 2581),
+        ("hand_over_mouth", 30),
+        ("man_technologist", 1029),
+        ("mailbox_with_no_mail", 3173),
+        ("family_man_boy_boy", 2410),
+        ("heavy_check_mark", 3412),
+        ("ireland", 3627),
+        ("kneeling_man", 1588),
+        ("raised_back_of_hand", 175),
+        ("chicken", 2497),
+        ("red_envelope", 2953),
+        ("wedding", 2760),
+        ("minibus", 2801),
+        ("call_me_hand", 283),
+        ("crying_cat_face", 125),
+        ("couplekiss_man_woman", 2212),
+        ("de", 3578),
+        ("indonesia", 3626),
+        ("bahamas", 3551),
+        ("dizzy_face", 67),
+        ("kiss", 155),
+        ("x", 3413),
+        ("vibration_mode", 3383),
+        ("woman_scientist", 1017),
+        ("orange_square", 3487),
+        ("khanda", 3346),
+        ("leaves", 2584),
+        ("montserrat", 3677),
+        ("pickup_truck", 2811),
+        ("office_worker", 987),
+        ("guard", 1167),
+        ("tumbler_glass", 2706),
+        ("convenience_store", 2754),
+        ("identification_card", 3286),
+        ("st_pierre_miquelon", 3705),
+        ("orange_heart", 145),
+        ("falafel", 2650),
+        ("prince", 1215),
+        ("weight_lifting", 1923),
+        ("frowning", 87),
+        ("male_sign", 3386),
+        ("writing_hand", 429),
+        ("heavy_equals_sign", 3392),
+        ("book", 3138),
+        ("face_with_spiral_eyes", 68),
+        ("monocle_face", 75),
+        ("family_man_girl_girl", 2413),
+        ("registered", 3422),
+        ("no_mouth", 40),
+        ("soon", 3332),
+        ("namibia", 3685),
+        ("toolbox", 3235),
+        ("card_index_dividers", 3186),
+        ("monkey_face", 2430),
+        ("man_dancing", 1768),
+        ("smirk_cat", 122),
+        ("latvia", 3660),
+        ("shield", 3222),
+        ("fallen_leaf", 2583),
+        ("woman_technologist", 1035),
+        ("sassy_woman", 747),
+        ("station", 2793),
+        ("boy", 507),
+        ("golfing", 1833),
+        ("woman_judge", 909),
+        ("confounded", 95),
+        ("british_indian_ocean_territory", 3631),
+        ("merman", 1455),
+        ("burundi", 3543),
+        ("construction_worker_woman", 1203),
+        ("wrestling", 1995),
+        ("st_vincent_grenadines", 3764),
+        ("+1", 331),
+        ("skateboard", 2823),
+        ("star_struck", 16),
+        ("weight_lifting_woman", 1935),
+        ("flying_saucer", 2857),
+        ("yellow_heart", 146),
+        ("samoa", 3771),
+        ("superhero_woman", 1371),
+        ("fried_shrimp", 2672),
+        ("man_with_probing_cane", 1630),
+        ("woman_in_manual_wheelchair", 1714),
+        ("bulgaria", 3541),
+        ("deer", 2455),
+        ("butterfly", 2545),
+        ("vs", 3459),
+        ("barbados", 3537),
+        ("shark", 2534),
+        ("european_post_office", 2749),
+        ("brown_circle", 3483),
+        ("feet", 2495),
+        ("repeat_one", 3362),
+        ("socks", 3034),
+        ("person_white_hair", 615),
+        ("u55b6", 3475),
+        ("brazil", 3550),
+        ("key", 3210),
+        ("shopping", 3047),
+        ("dolphin", 2529),
+        ("sailboat", 2838),
+        ("arrows_counterclockwise", 3328),
+        ("congo_kinshasa", 3559),
+        ("symbols", 3441),
+        ("busstop", 2825),
+        ("face_with_diagonal_mouth", 77),
+        ("cocos_islands", 3558),
+        ("roller_skate", 2824),
+        ("lungs", 487),
+        ("girl", 513),
+        ("window", 3256),
+        ("white_haired_woman", 609),
+        ("qatar", 3712),
+        ("tokelau", 3746),
+        ("dvd", 3118),
+        ("maple_leaf", 2582),
+        ("jellyfish", 2538),
+        ("couplekiss_woman_woman", 2264),
+        ("man_artist", 1065),
+        ("facepunch", 349),
+        ("balloon", 2944),
+        ("police_car", 2804),
+        ("man_astronaut", 1101),
+        ("tada", 2945),
+        ("slovenia", 3725),
+        ("syria", 3737),
+        ("belize", 3556),
+        ("trumpet", 3092),
+        ("elf", 1467),
+        ("lion", 2445),
+        ("closed_lock_with_key", 3209),
+        ("scientist", 1005),
+        ("jack_o_lantern", 2938),
+        ("department_store", 2756),
+        ("ukraine", 3756),
+        ("clinking_glasses", 2705),
+        ("zimbabwe", 3777),
+        ("bhutan", 3552),
+        ("ok_woman", 729),
+        ("cup_with_straw", 2708),
+        ("seychelles", 3720),
+        ("palm_tree", 2576),
+        ("ng_man", 705),
+        ("hugs", 29),
+        ("orange_book", 3141),
+        ("oden", 2670),
+        ("nauru", 3694),
+        ("clubs", 3011),
+        ("eight_pointed_black_star", 3419),
+        ("notebook_with_decorative_cover", 3136),
+        ("card_index", 3191),
+        ("punch", 349),
+        ("fish_cake", 2673),
+        ("honeybee", 2548),
+        ("bald_man", 573),
+        ("man_juggling", 2040),
+        ("raising_hand_woman", 765),
+        ("bikini", 3041),
+        ("scream", 94),
+        ("red_haired_woman", 585),
+        ("canoe", 2839),
+        ("swimming_woman", 1899),
+        ("romania", 3714),
+        ("boat", 2838),
+        ("genie_woman", 1487),
+        ("haircut_woman", 1522),
+        ("hippopotamus", 2475),
+        ("tr", 3751),
+        ("frowning_woman", 675),
+        ("stuffed_flatbread", 2649),
+        ("heart_hands", 379),
+        ("bicyclist", 1941),
+        ("netherlands", 3691),
+        ("bathtub", 3263),
+        ("snowflake", 2931),
+        ("speak_no_evil", 129),
+        ("monaco", 3663),
+        ("owl", 2508),
+        ("woman_farmer", 927),
+        ("vertical_traffic_light", 2833),
+        ("football", 2970),
+        ("smiley_cat", 118),
+        ("sandwich", 2645),
+        ("kiribati", 3643),
+        ("portugal", 3709),
+        ("bride_with_veil", 1287),
+        ("inbox_tray", 3168),
+        ("woozy_face", 66),
+        ("chopsticks", 2713),
+        ("green_salad", 2657),
+        ("arrow_double_up", 3371),
+        ("outbox_tray", 3167),
+        ("car", 2808),
+        ("micronesia", 3597),
+        ("free", 3448),
+        ("wink", 11),
+        ("man_with_turban", 1233),
+        ("x_ray", 3252),
+        ("person_bald", 627),
+        ("level_slider", 3083),
+        ("passport_control", 3296),
+        ("thailand", 3744),
+        ("hedgehog", 2484),
+        ("prayer_beads", 3066),
+        ("lotus_position_man", 2058),
+        ("beans", 2622),
+        ("shrug", 825),
+        ("frog", 2518),
+        ("joy_cat", 120),
+        ("hatching_chick", 2499),
+        ("see_no_evil", 127),
+        ("closed_umbrella", 2926),
+        ("neutral_face", 38),
+        ("ab", 3444),
+        ("ferris_wheel", 2782),
+        ("leftwards_hand", 205),
+        ("bagel", 2633),
+        ("shallow_pan_of_food", 2653),
+        ("cold_sweat", 90),
+        ("bouncing_ball_person", 1905),
+        ("information_desk_person", 735),
+        ("question", 3396),
+        ("ferry", 2842),
+        ("cherry_blossom", 2561),
+        ("mega", 3075),
+        ("knife", 2717),
+        ("medical_symbol", 3403),
+        ("open_hands", 385),
+        ("person_curly_hair", 603),
+        ("norfolk_island", 3688),
+        ("supervillain_man", 1383),
+        ("clock2", 2871),
+        ("mailbox_with_mail", 3172),
+        ("fr", 3599),
+        ("gemini", 3349),
+        ("small_red_triangle", 3505),
+        ("heartbeat", 135),
+        ("information_source", 3449),
+        ("cote_divoire", 3563),
+        ("open_mouth", 81),
+        ("roll_of_paper", 3270),
+        ("swim_brief", 3039),
+        ("snowman", 2933),
+        ("black_square_button", 3510),
+        ("boomerang", 3220),
+        ("yawning_face", 101),
+        ("angry", 104),
+        ("volcano", 2729),
+        ("love_you_gesture", 271),
+        ("ok", 3455),
+        ("auto_rickshaw", 2820),
+        ("kick_scooter", 2822),
+        ("fire_engine", 2803),
+        ("shit", 110),
+        ("point_down", 313),
+        ("leftwards_pushing_hand", 223),
+        ("curly_haired_woman", 597),
+        ("mountain_snow", 2727),
+        ("cityscape", 2773),
+        ("singapore", 3723),
+        ("montenegro", 3665),
+        ("ice_skate", 2985),
+        ("lips", 493),
+        ("ear", 467),
+        ("weary", 99),
+        ("telephone_receiver", 3103),
+        ("rhinoceros", 2474),
+        ("construction_worker_man", 1197),
+        ("boxing_glove", 2981),
+        ("palau", 3710),
+        ("peru", 3699),
+        ("green_book", 3139),
+        ("paw_prints", 2495),
+        ("blueberries", 2604),
+        ("tulip", 2570),
+        ("snail", 2544),
+        ("man_mechanic", 957),
+        ("sari", 3037),
+        ("bubble_tea", 2709),
+        ("woman_facepalming", 819),
+        ("sagittarius", 3355),
+        ("fire", 2935),
+        ("white_medium_square", 3496),
+        ("lotus_position", 2052),
+        ("flying_disc", 2973),
+        ("beers", 2704),
+        ("bouncing_ball_woman", 1917),
+        ("saluting_face", 35),
+        ("astronaut", 1095),
+        ("norway", 3692),
+        ("knot", 3022),
+        ("thumbsup", 331),
+        ("mountain", 2728),
+        ("flight_arrival", 2848),
+        ("family_woman_girl", 2416),
+        ("oyster", 2543),
+        ("woman_with_probing_cane", 1642),
+        ("fishing_pole_and_fish", 2986),
+        ("swimmer", 1887),
+        ("womens", 3292),
+        ("heavy_division_sign", 3391),
+        ("maldives", 3680),
+        ("shell", 2536),
+        ("cut_of_meat", 2639),
+        ("tongue", 492),
+        ("national_park", 2735),
+        ("tipping_hand_person", 735),
+        ("point_left", 289),
+        ("3rd_place_medal", 2964),
+        ("tuvalu", 3753),
+        ("wavy_dash", 3400),
+        ("leo", 3351),
+        ("black_small_square", 3499),
+        ("mushroom", 2587),
+        ("heart_eyes", 15),
+        ("eye", 491),
+        ("eagle", 2505),
+        ("grenada", 3602),
+        ("church", 2763),
+        ("no_entry", 3302),
+        ("mag_right", 3130),
+        ("honey_pot", 2692),
+        ("iran", 3633),
+        ("pirate_flag", 3518),
+        ("salt", 2660),
+        ("rabbit2", 2481),
+        ("martinique", 3675),
+        ("card_file_box", 3203),
+        ("venezuela", 3765),
+        ("woman_with_headscarf", 1251),
+        ("stars", 2910),
+        ("pouch", 3046),
+        ("vanuatu", 3769),
+        ("lying_face", 48),
+        ("rose", 2565),
+        ("liechtenstein", 3654),
+        ("mexico", 3682),
+        ("estonia", 3587),
+        ("raised_eyebrow", 37),
+        ("troll", 1491),
+        ("olive", 2607),
+        ("police_officer", 1131),
+        ("izakaya_lantern", 3134),
+        ("hot_pepper", 2614),
+        ("wheel", 2830),
+        ("raised_hand", 187),
+        ("standing_person", 1564),
+        ("hand_with_index_finger_and_thumb_crossed", 265),
+        ("policeman", 1137),
+        ("zombie", 1488),
+        ("rescue_worker_helmet", 3065),
+        ("mouse2", 2477),
+        ("busts_in_silhouette", 2421),
+        ("tonga", 3750),
+        ("kazakhstan", 3650),
+        ("u6708", 3462),
+        ("ear_of_rice", 2578),
+        ("anguished", 88),
+        ("man_judge", 903),
+        ("polar_bear", 2487),
+        ("garlic", 2619),
+        ("arrow_right_hook", 3324),
+        ("firecracker", 2942),
+        ("space_invader", 116),
+        ("sweden", 3722),
+        ("customs", 3297),
+        ("shopping_cart", 3277),
+        ("arrow_heading_down", 3326),
+        ("dancing_men", 1781),
+        ("red_car", 2808),
+        ("fairy_woman", 1425),
+        ("aruba", 3533),
+        ("purple_square", 3491),
+        ("safety_vest", 3027),
+        ("no_bell", 3078),
+        ("racing_car", 2815),
+        ("popcorn", 2658),
+        ("singer", 1041),
+        ("cursing_face", 105),
+        ("pink_heart", 144),
+        ("dancer", 1762),
+        ("family_man_woman_girl_boy", 2396),
+        ("waxing_crescent_moon", 2892),
+        ("older_adult", 645),
+        ("oncoming_police_car", 2805),
+        ("scarf", 3031),
+        ("man_factory_worker", 975),
+        ("newspaper_roll", 3149),
+        ("man_beard", 543),
+        ("three", 3430),
+        ("repeat", 3361),
+        ("no_smoking", 3305),
+        ("central_african_republic", 3560),
+        ("checkered_flag", 3511),
+        ("takeout_box", 2678),
+        ("woman_juggling", 2046),
+        ("military_helmet", 3064),
+        ("cricket_game", 2975),
+        ("ballot_box_with_check", 3411),
+        ("cupid", 131),
+        ("surfer", 1851),
+        ("mandarin", 2592),
+        ("heavy_minus_sign", 3390),
+        ("white_check_mark", 3410),
+        ("swimming_man", 1893),
+        ("ghana", 3606),
+        ("transgender_flag", 3517),
+        ("sweat_smile", 5),
+        ("saudi_arabia", 3718),
+        ("raised_hands", 373),
+        ("test_tube", 3240),
+        ("empty_nest", 2585),
+        ("one", 3428),
+        ("thought_balloon", 167),
+        ("coral", 2537),
+        ("kneeling_woman", 1594),
+        ("clock12", 2867),
+        ("incoming_envelope", 3165),
+        ("palestinian_territories", 3708),
+        ("hammer_and_wrench", 3216),
+        ("pager", 3104),
+        ("uzbekistan", 3762),
+        ("fire_extinguisher", 3276),
+        ("oncoming_taxi", 2807),
+        ("zambia", 3776),
+        ("2nd_place_medal", 2963),
+        ("koko", 3460),
+        ("rooster", 2498),
+        ("page_with_curl", 3145),
+        ("deaf_woman", 783),
+        ("bangbang", 3394),
+        ("pregnant_man", 1299),
+        ("white_medium_small_square", 3498),
+        ("onion", 2620),
+        ("smiley", 1),
+        ("purple_heart", 150),
+        ("smoking", 3278),
+        ("tea", 2697),
+        ("clock730", 2882),
+        ("mending_heart", 142),
+        ("bulb", 3132),
+        ("ng", 3453),
+        ("footprints", 2428),
+        ("1234", 3440),
+        ("capital_abcd", 3438),
+        ("dizzy", 159),
+        ("nigeria", 3689),
+        ("confused", 76),
+        ("earth_asia", 2722),
+        ("south_sudan", 3733),
+        ("receipt", 3161),
+        ("memo", 3182),
+        ("pouring_liquid", 2707),
+        ("cowboy_hat_face", 70),
+        ("flight_departure", 2847),
+        ("paintbrush", 3180),
+        ("uganda", 3757),
+        ("bearded_person", 537),
+        ("underage", 3310),
+        ("video_camera", 3127),
+        ("spaghetti", 2668),
+        ("keyboard", 3112),
+        ("san_marino", 3729),
+        ("fist_raised", 343),
+        ("trolleybus", 2800),
+        ("woman_astronaut", 1107),
+        ("ceuta_melilla", 3585),
+        ("family_woman_woman_boy", 2404),
+        ("train", 2797),
+        ("disguised_face", 72),
+        ("face_with_open_eyes_and_hand_over_mouth", 31),
+        ("man_with_veil", 1281),
+        ("ambulance", 2802),
+        ("badminton", 2980),
+        ("scorpion", 2555),
+        ("woman_singer", 1053),
+        ("person_in_manual_wheelchair", 1690),
+        ("cd", 3117),
+        ("gb", 3601),
+        ("coffee", 2695),
+        ("adult", 519),
+        ("monorail", 2795),
+        ("person_with_turban", 1227),
+        ("tangerine", 2592),
+        ("bacon", 2640),
+        ("nut_and_bolt", 3226),
+        ("small_blue_diamond", 3504),
+        ("western_sahara", 3589),
+        ("two_women_holding_hands", 2108),
+        ("m", 3451),
+        ("genie", 1485),
+        ("deciduous_tree", 2575),
+        ("hair_pick", 3058),
+        ("hammer_and_pick", 3215),
+        ("mozambique", 3684),
+        ("id", 3450),
+        ("expressionless", 39),
+        ("antarctica", 3528),
+        ("woman_feeding_baby", 1317),
+        ("man_firefighter", 1119),
+        ("pretzel", 2632),
+        ("monkey", 2431),
+        ("sun_behind_small_cloud", 2915),
+        ("sparkler", 2941),
+        ("sweet_potato", 2669),
+        ("bookmark_tabs", 3150),
+        ("dumpling", 2676),
+        ("black_flag", 3514),
+        ("bomb", 3219),
+        ("briefcase", 3183),
+        ("sparkle", 3420),
+        ("paperclip", 3198),
+        ("family_man_woman_girl_girl", 2398),
+        ("canned_food", 2661),
+        ("wind_face", 2923),
+        ("sunrise_over_mountains", 2774),
+        ("fountain", 2769),
+        ("luggage", 2859),
+        ("guardswoman", 1179),
+        ("fish", 2531),
+        ("rowboat", 1869),
+        ("sao_tome_principe", 3734),
+        ("thong_sandal", 3049),
+        ("princess", 1221),
+        ("surfing_woman", 1863),
+        ("serbia", 3715),
+        ("athletic_shoe", 3051),
+        ("man_teacher", 885),
+        ("oman", 3697),
+        ("scissors", 3202),
+        ("bike", 2821),
+        ("japanese_goblin", 113),
+        ("beer", 2703),
+        ("egg", 2651),
+        ("musical_keyboard", 3091),
+        ("family_man_man_girl_girl", 2403),
+        ("curling_stone", 2991),
+        ("atm", 3287),
+        ("bug", 2546),
+        ("mouse", 2476),
+        ("american_samoa", 3530),
+        ("petri_dish", 3241),
+        ("white_flower", 2562),
+        ("carrot", 2612),
+        ("walking_woman", 1540),
+        ("artist", 1059),
+        ("equatorial_guinea", 3612),
+        ("upside_down_face", 9),
+        ("anguilla", 3524),
+        ("herb", 2579),
+        ("signal_strength", 3381),
+        ("currency_exchange", 3401),
+        ("hourglass", 2860),
+        ("oil_drum", 2828),
+        ("purse", 3044),
+        ("metro", 2791),
+        ("arrow_up", 3313),
+        ("crescent_moon", 2899),
+        ("bus", 2798),
+        ("mx_claus", 1353),
+        ("standing_man", 1570),
+        ("person_with_probing_cane", 1618),
+        ("cucumber", 2616),
+        ("slightly_smiling_face", 8),
+        ("blossom", 2569),
+        ("drop_of_blood", 3247),
+        ("wales", 3780),
+        ("artificial_satellite", 2855),
+        ("heart", 143),
+        ("dog2", 2435),
+        ("pitcairn_islands", 3706),
+        ("peanuts", 2621),
+        ("new_zealand", 3696),
+        ("juggling_person", 2034),
+        ("mali", 3670),
+        ("sandal", 3055),
+        ("smiling_imp", 106),
+        ("canada", 3557),
+        ("guatemala", 3615),
+        ("spiral_calendar", 3190),
+        ("tshirt", 3029),
+        ("pouting_man", 687),
+        ("rat", 2478),
+        ("spider_web", 2554),
+        ("wind_chime", 2951),
+        ("pakistan", 3703),
+        ("sake", 2698),
+        ("-1", 337),
+        ("rice_cracker", 2663),
+        ("back", 3329),
+        ("heart_eyes_cat", 121),
+        ("airplane", 2845),
+        ("st_helena", 3724),
+        ("clock1130", 2890),
+        ("lizard", 2521),
+        ("mens", 3291),
+        ("ox", 2458),
+        ("south_georgia_south_sandwich_islands", 3614),
+        ("pilot", 1077),
+        ("ice_cream", 2681),
+        ("service_dog", 2437),
+        ("bowing_woman", 801),
+        ("black_bird", 2515),
+        ("six_pointed_star", 3345),
+        ("cyclone", 2924),
+        ("hankey", 110),
+        ("crossed_flags", 3513),
+        ("trident", 3406),
+        ("mage", 1395),
+        ("sun_with_face", 2906),
+        ("black_joker", 3013),
+        ("desert", 2733),
+        ("man_playing_handball", 2022),
+        ("horse_racing", 1820),
+        ("burrito", 2647),
+        ("joy", 7),
+        ("switzerland", 3562),
+        ("dominica", 3582),
+        ("rightwards_pushing_hand", 229),
+        ("lacrosse", 2978),
+        ("triangular_ruler", 3201),
+        ("couple", 2134),
+        ("woman_with_veil", 1287),
+        ("pig_nose", 2464),
+        ("new_moon_with_face", 2900),
+        ("rightwards_hand", 199),
+        ("waffle", 2635),
+        ("jamaica", 3637),
+        ("keycap_ten", 3437),
+        ("hear_no_evil", 128),
+        ("softball", 2967),
+        ("small_red_triangle_down", 3506),
+        ("white_flag", 3515),
+        ("jordan", 3638),
+        ("atom_symbol", 3335),
+        ("broccoli", 2618),
+        ("tram", 2794),
+        ("leafy_green", 2617),
+        ("cyprus", 3576),
+        ("woman_playing_water_polo", 2010),
+        ("microscope", 3243),
+        ("field_hockey", 2976),
+        ("palm_down_hand", 211),
+        ("uruguay", 3761),
+        ("blond_haired_person", 525),
+        ("woman_dancing", 1762),
+        ("rugby_football", 2971),
+        ("helicopter", 2851),
+        ("round_pushpin", 3197),
+        ("ophiuchus", 3359),
+        ("clock830", 2884),
+        ("right_anger_bubble", 166),
+        ("diego_garcia", 3579),
+        ("sudan", 3721),
+        ("eyes", 490),
+        ("clock3", 2873),
+        ("grey_heart", 153),
+        ("japan", 2725),
+        ("man_playing_water_polo", 2004),
+        ("high_brightness", 3380),
+        ("chart_with_downwards_trend", 3193),
+        ("pill", 3248),
+        ("stew", 2654),
+        ("two", 3429),
+        ("sponge", 3275),
+        ("kissing_heart", 17),
+        ("kosovo", 3772),
+        ("gibraltar", 3607),
+        ("tennis", 2972),
+        ("bookmark", 3151),
+        ("low_battery", 3107),
+        ("mosquito", 2556),
+        ("jeans", 3030),
+        ("technologist", 1023),
+        ("shoe", 3050),
+        ("dancing_women", 1782),
+        ("fleur_de_lis", 3405),
+        ("timer_clock", 2865),
+        ("breast_feeding", 1311),
+        ("movie_camera", 3120),
+        ("black_heart", 152),
+        ("teacher", 879),
+        ("female_detective", 1161),
+        ("family_man_man_boy_boy", 2402),
+        ("bamboo", 2948),
+        ("ship", 2844),
+        ("woman_artist", 1071),
+        ("niger", 3687),
+        ("thinking", 34),
+        ("bellhop_bell", 2858),
+        ("arrow_down", 3317),
+        ("green_heart", 147),
+        ("a", 3443),
+        ("dart", 2992),
+        ("high_heel", 3054),
+        ("family_woman_girl_boy", 2417),
+        ("arrows_clockwise", 3327),
+        ("menorah", 3344),
+        ("play_or_pause_button", 3366),
+        ("diamonds", 3010),
+        ("watermelon", 2591),
+        ("slot_machine", 3001),
+        ("hushed", 82),
+        ("mosque", 2764),
+        ("up", 3458),
+        ("macedonia", 3669),
+        ("mirror_ball", 3006),
+        ("leopard", 2448),
+        ("sun_behind_rain_cloud", 2917),
+        ("radio", 3087),
+        ("yemen", 3773),
+        ("transgender_symbol", 3387),
+        ("eu", 3593),
+        ("sleepy", 54),
+        ("clock7", 2881),
+        ("fly", 2557),
+        ("magnet", 3236),
+        ("dress", 3035),
+        ("secret", 3474),
+        ("whale2", 2528),
+        ("sleeping", 56),
+        ("woman_playing_handball", 2028),
+        ("grin", 3),
+        ("evergreen_tree", 2574),
+        ("books", 3142),
+        ("dagger", 3217),
+        ("detective", 1149),
+        ("blush", 12),
+        ("bucket", 3271),
+        ("crocodile", 2519),
+        ("frowning_face", 80),
+        ("mute", 3070),
+        ("family_man_man_girl_boy", 2401),
+        ("male_detective", 1155),
+        ("rocket", 2856),
+        ("baby_chick", 2500),
+        ("sound", 3072),
+        ("north_korea", 3646),
+        ("banana", 2595),
+        ("sos", 3457),
+        ("alembic", 3239),
+        ("bricks", 2739),
+        ("blue_heart", 148),
+        ("folding_hand_fan", 3043),
+        ("jp", 3639),
+        ("traffic_light", 2832),
+        ("health_worker", 843),
+        ("bust_in_silhouette", 2420),
+        ("u7533", 3470),
+        ("fireworks", 2940),
+        ("hyacinth", 2571),
+        ("panda_face", 2489),
+        ("tropical_drink", 2702),
+        ("fast_forward", 3364),
+        ("bed", 3257),
+        ("zombie_woman", 1490),
+        ("full_moon", 2895),
+        ("supervillain_woman", 1389),
+        ("blue_square", 3490),
+        ("stopwatch", 2864),
+        ("taiwan", 3754),
+        ("sob", 93),
+        ("kiwi_fruit", 2605),
+        ("heart_on_fire", 141),
+        ("pig2", 2462),
+        ("ice_cube", 2712),
+        ("rwanda", 3717),
+        ("seal", 2530),
+        ("djibouti", 3580),
+        ("paraguay", 3711),
+        ("cloud_with_lightning", 2920),
+        ("tv", 3124),
+        ("vatican_city", 3763),
+        ("face_with_peeking_eye", 32),
+        ("bone", 489),
+        ("reunion", 3713),
+        ("last_quarter_moon_with_face", 2902),
+        ("file_folder", 3184),
+        ("women_wrestling", 1997),
+        ("notes", 3081),
+        ("smiling_face_with_tear", 22),
+        ("hole", 162),
+        ("genie_man", 1486),
+        ("file_cabinet", 3204),
+        ("railway_track", 2827),
+        ("jigsaw", 3003),
+        ("belarus", 3555),
+        ("bullettrain_side", 2788),
+        ("hut", 2742),
+        ("loop", 3416),
+        ("point_up", 319),
+        ("white_circle", 3485),
+        ("walking", 1528),
+        ("studio_microphone", 3082),
+        ("passenger_ship", 2841),
+        ("handball_person", 2016),
+        ("senegal", 3730),
+        ("kuwait", 3648),
+        ("golfing_man", 1839),
+        ("rice_scene", 2952),
+        ("school_satchel", 3048),
+        ("speedboat", 2840),
+        ("ant", 2547),
+        ("eject_button", 3377),
+        ("office", 2747),
+        ("dove", 2504),
+        ("goggles", 3025),
+        ("antigua_barbuda", 3523),
+        ("gabon", 3600),
+        ("horse", 2449),
+        ("heart_decoration", 138),
+        ("first_quarter_moon", 2893),
+        ("palms_up_together", 391),
+        ("roller_coaster", 2783),
+        ("foggy", 2771),
+        ("relaxed", 19),
+        ("man_in_manual_wheelchair", 1702),
+        ("dolls", 2949),
+        ("united_nations", 3759),
+        ("person_with_veil", 1275),
+        ("eight", 3435),
+        ("coffin", 3279),
+        ("hong_kong", 3619),
+        ("slovakia", 3727),
+        ("place_of_worship", 3334),
+        ("black_large_square", 3493),
+        ("confetti_ball", 2946),
+        ("ticket", 2958),
+        ("hindu_temple", 2765),
+        ("ear_with_hearing_aid", 473),
+        ("amphora", 2719),
+        ("angel", 1335),
+        ("speaking_head", 2419),
+        ("money_with_wings", 3159),
+        ("moon_cake", 2674),
+        ("shaved_ice", 2680),
+        ("cow2", 2460),
+        ("grimacing", 46),
+        ("strawberry", 2603),
+        ("basketball", 2968),
+        ("arrow_right", 3315),
+        ("u6709", 3463),
+        ("it", 3635),
+        ("watch", 2862),
+        ("jersey", 3636),
+        ("running_man", 1732),
+        ("smirk", 43),
+        ("fairy_man", 1419),
+        ("microphone", 3085),
+        ("beaver", 2483),
+        ("people_holding_hands", 2082),
+        ("motor_boat", 2843),
+        ("malawi", 3681),
+        ("fist", 343),
+        ("compass", 2726),
+        ("syringe", 3246),
+        ("point_right", 295),
+        ("sewing_needle", 3020),
+        ("cartwheeling", 1977),
+        ("lebanon", 3652),
+        ("spider", 2553),
+        ("cop", 1131),
+        ("ru", 3716),
+        ("previous_track_button", 3369),
+        ("b", 3445),
+        ("ballet_shoes", 3056),
+        ("heavy_heart_exclamation", 139),
+        ("dash", 161),
+        ("christmas_tree", 2939),
+        ("sloth", 2490),
+        ("pineapple", 2596),
+        ("sweat", 98),
+        ("el_salvador", 3735),
+        ("tiger2", 2447),
+        ("carpentry_saw", 3223),
+        ("spoon", 2716),
+        ("man_in_tuxedo", 1263),
+        ("sneezing_face", 63),
+        ("alien", 115),
+        ("taurus", 3348),
+        ("small_orange_diamond", 3503),
+        ("mantelpiece_clock", 2866),
+        ("basketball_woman", 1917),
+        ("copyright", 3421),
+        ("faroe_islands", 3598),
+        ("derelict_house", 2744),
+        ("st_barthelemy", 3545),
+        ("tophat", 3061),
+        ("mountain_cableway", 2853),
+        ("elf_woman", 1479),
+        ("stop_sign", 2834),
+        ("aland_islands", 3534),
+        ("loudspeaker", 3074),
+        ("massage_man", 1498),
+        ("date", 3187),
+        ("star2", 2909),
+        ("moose", 2450),
+        ("rofl", 6),
+        ("niue", 3695),
+        ("house_with_garden", 2746),
+        ("cloud_with_rain", 2918),
+        ("low_brightness", 3379),
+        ("blue_book", 3140),
+        ("kimono", 3036),
+        ("mag", 3129),
+        ("small_airplane", 2846),
+        ("otter", 2491),
+        ("virgo", 3352),
+        ("handshake", 397),
+        ("clock6", 2879),
+        ("woman_shrugging", 837),
+        ("st_martin", 3666),
+        ("dominican_republic", 3583),
+        ("austria", 3531),
+        ("tractor", 2814),
+        ("om", 3336),
+        ("milky_way", 2911),
+        ("bald_woman", 621),
+        ("oncoming_bus", 2799),
+        ("stadium", 2736),
+        ("unamused", 44),
+        ("cow", 2457),
+        ("aries", 3347),
+        ("rage", 103),
+        ("mountain_biking_woman", 1971),
+        ("city_sunset", 2776),
+        ("deaf_person", 771),
+        ("kissing_cat", 123),
+        ("pound", 3158),
+        ("llama", 2470),
+        ("falkland_islands", 3596),
+        ("malta", 3678),
+        ("sparkling_heart", 133),
+        ("cook_islands", 3564),
+        ("arrow_forward", 3363),
+        ("selfie", 441),
+        ("rainbow", 2925),
+        ("judge", 897),
+        ("japanese_ogre", 112),
+        ("bowing_man", 795),
+        ("guam", 3616),
+        ("grapes", 2589),
+        ("ng_woman", 711),
+        ("zombie_man", 1489),
+        ("boar", 2463),
+        ("ringed_planet", 2907),
+        ("pouting_cat", 126),
+        ("badger", 2494),
+        ("basketball_man", 1911),
+        ("man_shrugging", 831),
+        ("grey_question", 3397),
+        ("arrow_lower_right", 3316),
+        ("bear", 2486),
+        ("ascension_island", 3519),
+        ("mortar_board", 3062),
+        ("restroom", 3293),
+        ("giraffe", 2471),
+        ("cancer", 3350),
+        ("euro", 3157),
+        ("moneybag", 3153),
+        ("motorized_wheelchair", 2819),
+        ("snowman_with_snow", 2932),
+        ("haircut", 1510),
+        ("person_with_crown", 1209),
+        ("five", 3432),
+        ("u7a7a", 3472),
+        ("ballot_box", 3175),
+        ("crab", 2539),
+        ("camel", 2469),
+        ("crossed_fingers", 259),
+        ("ice_hockey", 2977),
+        ("performing_arts", 3016),
+        ("macau", 3673),
+        ("name_badge", 3407),
+        ("sparkles", 2943),
+        ("abc", 3442),
+        ("barber", 2784),
+        ("family_woman_woman_boy_boy", 2407),
+        ("large_orange_diamond", 3501),
+        ("trackball", 3114),
+        ("vietnam", 3768),
+        ("family_man_boy", 2409),
+        ("candle", 3131),
+        ("cinema", 3378),
+        ("articulated_lorry", 2813),
+        ("crossed_swords", 3218),
+        ("desktop_computer", 3110),
+        ("albania", 3525),
+        ("baguette_bread", 2630),
+        ("brunei", 3547),
+        ("parrot", 2513),
+        ("new_caledonia", 3686),
+        ("cayman_islands", 3649),
+        ("1st_place_medal", 2962),
+        ("one_piece_swimsuit", 3038),
+        ("vulcan_salute", 193),
+        ("poodle", 2438),
+        ("two_hearts", 137),
+        ("motorcycle", 2816),
+        ("kangaroo", 2493),
+        ("postbox", 3174),
+        ("closed_book", 3137),
+        ("lock", 3206),
+        ("curry", 2666),
+        ("tanabata_tree", 2947),
+        ("ram", 2465),
+        ("warning", 3300),
+        ("pause_button", 3374),
+        ("tooth", 488),
+        ("clock330", 2874),
+        ("skunk", 2492),
+        ("lab_coat", 3026),
+        ("yellow_square", 3488),
+        ("nepal", 3693),
+        ("croissant", 2629),
+        ("ramen", 2667),
+        ("older_woman", 657),
+        ("partly_sunny", 2913),
+        ("wave", 169),
+        ("fried_egg", 2652),
+        ("beverage_box", 2710),
+        ("dromedary_camel", 2468),
+        ("french_guiana", 3604),
+        ("apple", 2598),
+        ("woman_cartwheeling", 1989),
+        ("us", 3760),
+        ("funeral_urn", 3281),
+        ("satisfied", 4),
+        ("orthodox_cross", 3341),
+        ("comet", 2934),
+        ("spades", 3008),
+        ("raising_hand_man", 759),
+        ("mauritania", 3676),
+        ("school", 2755),
+        ("raising_hand", 753),
+        ("stuck_out_tongue_winking_eye", 25),
+        ("balance_scale", 3229),
+        ("cuba", 3572),
+        ("exclamation", 3399),
+        ("ladder", 3237),
+        ("sunglasses", 73),
+        ("japanese_castle", 2758),
+        ("battery", 3106),
+        ("cloud_with_lightning_and_rain", 2914),
+        ("mans_shoe", 3050),
+        ("clapper", 3123),
+        ("congo_brazzaville", 3561),
+        ("8ball", 2996),
+        ("coat", 3033),
+        ("ok_hand", 235),
+        ("arrow_lower_left", 3318),
+        ("capricorn", 3356),
+        ("leg", 455),
+        ("four", 3431),
+        ("circus_tent", 2785),
+        ("cheese", 2636),
+        ("man_office_worker", 993),
+        ("taco", 2646),
+        ("pout", 103),
+        ("arrow_upper_left", 3320),
+        ("frowning_man", 669),
+        ("pouting_woman", 693),
+        ("pray", 423),
+        ("long_drum", 3096),
+        ("interrobang", 3395),
+        ("metal", 277),
+        ("caribbean_netherlands", 3549),
+        ("email", 3164),
+        ("sassy_man", 741),
+        ("bolivia", 3548),
+        ("merperson", 1449),
+        ("heavy_multiplication_x", 3388),
+        ("revolving_hearts", 136),
+        ("beach_umbrella", 2732),
+        ("man_cook", 939),
+        ("moldova", 3664),
+        ("lobster", 2540),
+        ("icecream", 2679),
+        ("surfing_man", 1857),
+        ("curly_loop", 3415),
+        ("paperclips", 3199),
+        ("open_file_folder", 3185),
+        ("clipperton_island", 3569),
+        ("canary_islands", 3625),
+        ("goat", 2467),
+        ("white_small_square", 3500),
+        ("mailbox_closed", 3171),
+        ("gift", 2955),
+        ("clock5", 2877),
+        ("congratulations", 3473),
+        ("waning_gibbous_moon", 2896),
+        ("printer", 3111),
+        ("seven", 3434),
+        ("angola", 3527),
+        ("volleyball", 2969),
+        ("zany_face", 26),
+        ("muscle", 447),
+        ("couple_with_heart", 2290),
+        ("nerd_face", 74),
+        ("kissing_smiling_eyes", 21),
+        ("chocolate_bar", 2688),
+        ("pizza", 2643),
+        ("mrs_claus", 1347),
+        ("cameroon", 3566),
+        ("couple_with_heart_woman_man", 2316),
+        ("chart", 3162),
+        ("sauna_woman", 1795),
+        ("goal_net", 2983),
+        ("burkina_faso", 3540),
+        ("mechanic", 951),
+        ("coin", 3154),
+        ("arrow_double_down", 3373),
+        ("brown_heart", 151),
+        ("hamster", 2479),
+        ("love_hotel", 2753),
+        ("suriname", 3732),
+        ("raccoon", 2441),
+        ("dragon_face", 2523),
+        ("shorts", 3040),
+        ("crayon", 3181),
+        ("woman_office_worker", 999),
+        ("business_suit_levitating", 1774),
+        ("light_rail", 2792),
+        ("link", 3231),
+        ("drooling_face", 55),
+        ("triumph", 102),
+        ("wheel_of_dharma", 3338),
+        ("first_quarter_moon_with_face", 2901),
+        ("hocho", 2717),
+        ("white_large_square", 3494),
+        ("soap", 3272),
+        ("vhs", 3128),
+        ("raised_hand_with_fingers_splayed", 181),
+        ("ribbon", 2954),
+        ("tropical_fish", 2532),
+        ("railway_car", 2787),
+        ("sleeping_bed", 2076),
+        ("morocco", 3662),
+        ("swaziland", 3738),
+        ("nazar_amulet", 3282),
+        ("gift_heart", 132),
+        ("open_book", 3138),
+        ("poultry_leg", 2638),
+        ("bank", 2751),
+        ("clock130", 2870),
+        ("wine_glass", 2700),
+        ("iphone", 3100),
+        ("rice", 2665),
+        ("wolf", 2439),
+        ("clock8", 2883),
+        ("bento", 2662),
+        ("puerto_rico", 3707),
+        ("pinched_fingers", 241),
+        ("potted_plant", 2573),
+        ("slightly_frowning_face", 79),
+        ("bell_pepper", 2615),
+        ("baseball", 2966),
+        ("ninja", 1185),
+        ("gambia", 3609),
+        ("vampire", 1431),
+        ("scroll", 3146),
+        ("kyrgyzstan", 3641),
+        ("sint_maarten", 3736),
+        ("baby_symbol", 3294),
+        ("tired_face", 100),
+        ("bell", 3077),
+        ("corn", 2613),
+        ("mountain_railway", 2796),
+        ("fork_and_knife", 2715),
+        ("rabbit", 2480),
+        ("family_woman_woman_girl_boy", 2406),
+        ("doughnut", 2682),
+        ("dragon", 2524),
+        ("fiji", 3595),
+        ("stop_button", 3375),
+        ("lipstick", 3067),
+        ("butter", 2659),
+        ("panama", 3698),
+        ("left_luggage", 3299),
+        ("diya_lamp", 3135),
+        ("sun_behind_large_cloud", 2916),
+        ("elevator", 3254),
+        ("woman_health_worker", 855),
+        ("red_circle", 3477),
+        ("accordion", 3089),
+        ("yo_yo", 2993),
+        ("electric_plug", 3108),
+        ("pea_pod", 2625),
+        ("man_student", 867),
+        ("santa", 1341),
+        ("reminder_ribbon", 2956),
+        ("cupcake", 2686),
+        ("hammer", 3212),
+        ("aerial_tramway", 2854),
+        ("massage", 1492),
+        ("person_fencing", 1819),
+        ("european_castle", 2759),
+        ("guinea_bissau", 3617),
+        ("computer", 3109),
+        ("cake", 2685),
+        ("black_medium_square", 3495),
+        ("womans_clothes", 3042),
+        ("curacao", 3574),
+        ("us_outlying_islands", 3758),
+        ("yen", 3155),
+        ("synagogue", 2766),
+        ("rock", 2740),
+        ("st_lucia", 3653),
+        ("anatomical_heart", 486),
+        ("honduras", 3621),
+        ("dancers", 1780),
+        ("pensive", 53),
+        ("eight_spoked_asterisk", 3418),
+        ("facepalm", 807),
+        ("beetle", 2549),
+        ("rewind", 3368),
+        ("package", 3169),
+        ("bridge_at_night", 2778),
+        ("sushi", 2671),
+        ("kr", 3647),
+        ("clock1030", 2888),
+        ("octopus", 2535),
+        ("ecuador", 3586),
+        ("sa", 3461),
+        ("u7121", 3467),
+        ("man_feeding_baby", 1323),
+        ("satellite", 3245),
+        ("door", 3253),
+        ("end", 3330),
+        ("shushing_face", 33),
+        ("steam_locomotive", 2786),
+        ("christmas_island", 3575),
+        ("magic_wand", 2998),
+        ("clock1230", 2868),
+        ("skier", 1826),
+        ("handbag", 3045),
+        ("houses", 2743),
+        ("somalia", 3731),
+        ("trinidad_tobago", 3752),
+        ("cold_face", 65),
+        ("cactus", 2577),
+        ("relieved", 52),
+        ("turkmenistan", 3748),
+        ("hibiscus", 2567),
+        ("envelope", 3163),
+        ("dark_sunglasses", 3024),
+        ("pregnant_person", 1305),
+        ("deaf_man", 777),
+        ("dodo", 2509),
+        ("thumbsdown", 337),
+        ("person_in_motorized_wheelchair", 1654),
+        ("yarn", 3021),
+        ("squid", 2542),
+        ("woman_pilot", 1089),
+        ("biting_lip", 494),
+        ("fu", 307),
+        ("minidisc", 3115),
+        ("arrow_up_small", 3370),
+        ("credit_card", 3160),
+        ("frowning_person", 663),
+        ("firefighter", 1113),
+        ("yin_yang", 3339),
+        ("woman_beard", 549),
+        ("pinching_hand", 247),
+        ("european_union", 3593),
+        ("city_sunrise", 2777),
+        ("men_wrestling", 1996),
+        ("family_man_girl_boy", 2412),
+        ("pear", 2600),
+        ("nose", 479),
+        ("tokyo_tower", 2761),
+        ("curly_haired_man", 561),
+        ("boom", 158),
+        ("couple_with_heart_woman_woman", 2368),
+        ("v", 253),
+        ("parachute", 2849),
+        ("haiti", 3623),
+        ("infinity", 3393),
+        ("israel", 3628),
+        ("snowboarder", 1827),
+        ("mage_woman", 1407),
+        ("train2", 2790),
+        ("marshall_islands", 3668),
+        ("clown_face", 111),
+        ("poop", 110),
+        ("calling", 3101),
+        ("tipping_hand_man", 741),
+        ("astonished", 83),
+        ("basket", 3269),
+        ("necktie", 3028),
+        ("arrow_upper_right", 3314),
+        ("isle_of_man", 3629),
+        ("label", 3152),
+        ("bar_chart", 3194),
+        ("snake", 2522),
+        ("clock4", 2875),
+        ("construction", 2835),
+        ("cocktail", 2701),
+        ("no_mobile_phones", 3309),
+        ("scorpius", 3354),
+        ("flushed", 84),
+        ("running", 1726),
+        ("zzz", 168),
+        ("yum", 23),
+        ("red_haired_man", 555),
+        ("ski", 2989),
+        ("climbing_man", 1807),
+        ("pushpin", 3196),
+        ("mountain_bicyclist", 1959),
+        ("violin", 3093),
+        ("left_speech_bubble", 165),
+        ("star_and_crescent", 3342),
+        ("recycle", 3404),
+    ],
+};
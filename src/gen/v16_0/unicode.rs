@@ -0,0 +1,6051 @@
+// Code generated by `cargo run --package generate`. DO NOT EDIT.
+
+use crate::EmojiId;
+
+pub static MAP: ::phf::Map<&'static str, (EmojiId, EmojiId)> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 11),
+        (0, 4),
+        (0, 196),
+        (0, 243),
+        (0, 8),
+        (0, 0),
+        (0, 121),
+        (0, 2),
+        (0, 61),
+        (0, 67),
+        (0, 36),
+        (0, 416),
+        (0, 0),
+        (0, 33),
+        (0, 216),
+        (0, 22),
+        (0, 139),
+        (0, 265),
+        (0, 328),
+        (0, 44),
+        (0, 250),
+        (0, 11),
+        (0, 3),
+        (0, 69),
+        (0, 282),
+        (0, 189),
+        (0, 8),
+        (0, 0),
+        (0, 0),
+        (0, 1),
+        (0, 8),
+        (0, 3),
+        (0, 16),
+        (0, 11),
+        (0, 3),
+        (0, 2579),
+        (0, 287),
+        (0, 1),
+        (0, 556),
+        (0, 397),
+        (0, 370),
+        (0, 3),
+        (0, 237),
+        (0, 157),
+        (0, 0),
+        (0, 41),
+        (0, 0),
+        (0, 1103),
+        (0, 109),
+        (0, 1),
+        (0, 208),
+        (0, 0),
+        (0, 499),
+        (0, 747),
+        (0, 46),
+        (0, 1),
+        (0, 646),
+        (0, 7),
+        (0, 0),
+        (0, 20),
+        (0, 402),
+        (0, 2),
+        (0, 0),
+        (0, 137),
+        (0, 20),
+        (0, 612),
+        (0, 490),
+        (0, 251),
+        (0, 41),
+        (0, 278),
+        (0, 893),
+        (0, 50),
+        (0, 670),
+        (0, 0),
+        (0, 9),
+        (0, 106),
+        (0, 187),
+        (0, 579),
+        (0, 341),
+        (0, 1067),
+        (0, 23),
+        (0, 134),
+        (0, 2),
+        (0, 62),
+        (0, 65),
+        (0, 1154),
+        (0, 3),
+        (0, 457),
+        (0, 3),
+        (0, 102),
+        (0, 3),
+        (0, 132),
+        (0, 90),
+        (0, 44),
+        (0, 0),
+        (0, 0),
+        (0, 44),
+        (0, 42),
+        (0, 487),
+        (0, 755),
+        (0, 30),
+        (0, 488),
+        (0, 1265),
+        (0, 64),
+        (0, 4),
+        (0, 66),
+        (0, 834),
+        (0, 115),
+        (0, 12),
+        (0, 39),
+        (0, 94),
+        (0, 231),
+        (0, 1),
+        (0, 20),
+        (0, 121),
+        (0, 47),
+        (0, 83),
+        (0, 1232),
+        (0, 2304),
+        (0, 123),
+        (0, 0),
+        (0, 2),
+        (0, 104),
+        (0, 150),
+        (0, 14),
+        (0, 38),
+        (0, 10),
+        (0, 245),
+        (0, 149),
+        (0, 889),
+        (0, 195),
+        (0, 462),
+        (0, 742),
+        (0, 0),
+        (0, 11),
+        (0, 77),
+        (0, 7),
+        (0, 272),
+        (0, 16),
+        (0, 39),
+        (0, 16),
+        (0, 0),
+        (0, 1),
+        (0, 228),
+        (0, 17),
+        (0, 6),
+        (0, 1545),
+        (0, 63),
+        (0, 12),
+        (0, 24),
+        (0, 87),
+        (0, 22),
+        (0, 24),
+        (0, 20),
+        (0, 192),
+        (0, 14),
+        (0, 204),
+        (0, 20),
+        (0, 30),
+        (0, 32),
+        (0, 11),
+        (0, 599),
+        (0, 97),
+        (0, 53),
+        (0, 8),
+        (0, 1),
+        (0, 6),
+        (0, 85),
+        (0, 79),
+        (0, 1),
+        (0, 7),
+        (0, 1),
+        (0, 9),
+        (0, 170),
+        (0, 91),
+        (0, 9),
+        (0, 361),
+        (0, 11),
+        (0, 53),
+        (0, 142),
+        (0, 24),
+        (0, 268),
+        (0, 23),
+        (0, 1238),
+        (0, 1),
+        (0, 1206),
+        (0, 6),
+        (0, 126),
+        (0, 1),
+        (0, 69),
+        (0, 38),
+        (0, 0),
+        (0, 0),
+        (0, 317),
+        (0, 4),
+        (0, 145),
+        (0, 2),
+        (0, 1418),
+        (0, 192),
+        (0, 4),
+        (0, 15),
+        (0, 1793),
+        (0, 62),
+        (0, 19),
+        (0, 26),
+        (0, 2),
+        (0, 133),
+        (0, 4),
+        (0, 578),
+        (0, 92),
+        (0, 473),
+        (0, 209),
+        (0, 0),
+        (0, 3),
+        (0, 0),
+        (0, 1246),
+        (0, 16),
+        (0, 8),
+        (0, 2091),
+        (0, 94),
+        (0, 5),
+        (0, 669),
+        (0, 38),
+        (0, 31),
+        (0, 2),
+        (0, 962),
+        (0, 1364),
+        (0, 1507),
+        (0, 4),
+        (0, 132),
+        (0, 138),
+        (0, 389),
+        (0, 57),
+        (0, 1587),
+        (0, 154),
+        (0, 109),
+        (0, 0),
+        (0, 15),
+        (0, 44),
+        (0, 41),
+        (0, 3),
+        (0, 15),
+        (0, 363),
+        (0, 846),
+        (0, 10),
+        (0, 2819),
+        (0, 4),
+        (0, 29),
+        (0, 126),
+        (0, 301),
+        (0, 17),
+        (0, 78),
+        (0, 0),
+        (0, 107),
+        (0, 95),
+        (0, 16),
+        (0, 370),
+        (0, 508),
+        (0, 289),
+        (0, 228),
+        (0, 4),
+        (0, 163),
+        (0, 215),
+        (0, 21),
+        (0, 48),
+        (0, 362),
+        (0, 160),
+        (0, 20),
+        (0, 92),
+        (0, 15),
+        (0, 1180),
+        (0, 586),
+        (0, 10),
+        (0, 0),
+        (0, 9),
+        (0, 459),
+        (0, 47),
+        (0, 16),
+        (0, 1245),
+        (0, 712),
+        (0, 9),
+        (0, 1841),
+        (0, 437),
+        (0, 356),
+        (0, 107),
+        (0, 62),
+        (0, 82),
+        (0, 92),
+        (0, 3),
+        (0, 36),
+        (0, 2225),
+        (0, 133),
+        (0, 2),
+        (0, 323),
+        (0, 5),
+        (0, 13),
+        (0, 10),
+        (0, 58),
+        (0, 7),
+        (0, 159),
+        (0, 398),
+        (0, 317),
+        (0, 49),
+        (0, 2),
+        (0, 69),
+        (0, 161),
+        (0, 3278),
+        (0, 0),
+        (0, 31),
+        (0, 1),
+        (0, 3),
+        (0, 14),
+        (0, 5),
+        (0, 254),
+        (0, 9),
+        (0, 38),
+        (0, 607),
+        (0, 2654),
+        (0, 3),
+        (0, 16),
+        (0, 1209),
+        (0, 730),
+        (0, 983),
+        (0, 1016),
+        (0, 185),
+        (0, 382),
+        (0, 455),
+        (0, 127),
+        (0, 1344),
+        (0, 706),
+        (0, 1),
+        (0, 100),
+        (0, 758),
+        (0, 0),
+        (0, 203),
+        (0, 3),
+        (0, 4),
+        (0, 41),
+        (0, 304),
+        (0, 15),
+        (0, 4),
+        (0, 1),
+        (0, 281),
+        (0, 1356),
+        (0, 5),
+        (0, 0),
+        (0, 173),
+        (0, 671),
+        (0, 61),
+        (0, 84),
+        (0, 1182),
+        (0, 9),
+        (0, 96),
+        (0, 177),
+        (0, 253),
+        (0, 1),
+        (0, 197),
+        (0, 83),
+        (0, 15),
+        (0, 0),
+        (0, 273),
+        (0, 101),
+        (0, 423),
+        (0, 118),
+        (0, 529),
+        (0, 66),
+        (0, 1331),
+        (0, 25),
+        (0, 50),
+        (0, 9),
+        (0, 156),
+        (0, 33),
+        (0, 176),
+        (0, 1),
+        (0, 179),
+        (0, 6),
+        (0, 3538),
+        (0, 323),
+        (0, 47),
+        (0, 917),
+        (0, 536),
+        (0, 32),
+        (0, 24),
+        (0, 366),
+        (0, 5),
+        (0, 466),
+        (0, 0),
+        (0, 724),
+        (0, 955),
+        (0, 0),
+        (0, 248),
+        (0, 63),
+        (0, 139),
+        (0, 117),
+        (0, 4),
+        (0, 706),
+        (0, 2),
+        (0, 7),
+        (0, 329),
+        (0, 49),
+        (0, 691),
+        (0, 179),
+        (0, 23),
+        (0, 1),
+        (0, 462),
+        (0, 1),
+        (0, 2185),
+        (0, 2080),
+        (0, 9),
+        (0, 2935),
+        (0, 188),
+        (0, 562),
+        (0, 4814),
+        (0, 9),
+        (0, 42),
+        (0, 21),
+        (0, 0),
+        (0, 22),
+        (0, 3),
+        (0, 99),
+        (0, 0),
+        (0, 504),
+        (0, 18),
+        (0, 1),
+        (0, 639),
+        (0, 44),
+        (0, 90),
+        (0, 1075),
+        (0, 16),
+        (0, 16),
+        (0, 62),
+        (0, 269),
+        (0, 1),
+        (0, 1036),
+        (0, 35),
+        (0, 21),
+        (1, 1412),
+        (0, 395),
+        (0, 129),
+        (0, 73),
+        (0, 4),
+        (0, 3),
+        (0, 1206),
+        (0, 3),
+        (0, 1117),
+        (0, 87),
+        (0, 359),
+        (0, 404),
+        (0, 2),
+        (0, 11),
+        (0, 145),
+        (1, 390),
+        (0, 1473),
+        (0, 853),
+        (0, 0),
+        (0, 1),
+        (0, 0),
+        (0, 214),
+        (0, 12),
+        (0, 3),
+        (0, 80),
+        (0, 1467),
+        (0, 1361),
+        (0, 509),
+        (0, 641),
+        (0, 1125),
+        (0, 48),
+        (0, 8),
+        (0, 27),
+        (0, 44),
+        (0, 17),
+        (0, 12),
+        (0, 56),
+        (0, 3),
+        (0, 50),
+        (0, 2),
+        (0, 22),
+        (0, 0),
+        (0, 320),
+        (0, 605),
+        (0, 116),
+        (0, 136),
+        (0, 7),
+        (0, 127),
+        (0, 235),
+        (0, 69),
+        (0, 24),
+        (0, 39),
+        (0, 10),
+        (0, 55),
+        (0, 226),
+        (0, 202),
+        (0, 4),
+        (0, 0),
+        (0, 16),
+        (0, 56),
+        (0, 280),
+        (0, 0),
+        (0, 1),
+        (0, 0),
+        (0, 315),
+        (0, 90),
+        (0, 207),
+        (0, 540),
+        (0, 252),
+        (0, 180),
+        (0, 226),
+        (0, 1582),
+        (0, 70),
+        (0, 2),
+        (0, 67),
+        (0, 442),
+        (0, 5),
+        (0, 216),
+        (0, 3),
+        (0, 1276),
+        (0, 1409),
+        (0, 557),
+        (0, 552),
+        (0, 18),
+        (0, 20),
+        (0, 55),
+        (0, 50),
+        (0, 4672),
+        (0, 2674),
+        (0, 201),
+        (0, 0),
+        (0, 127),
+        (0, 16),
+        (0, 26),
+        (0, 1998),
+        (1, 610),
+        (0, 73),
+        (0, 1014),
+        (0, 4),
+        (0, 0),
+        (0, 0),
+        (0, 2378),
+        (0, 1477),
+        (0, 105),
+        (0, 11),
+        (0, 1650),
+        (0, 4425),
+        (0, 292),
+        (0, 107),
+        (0, 1037),
+        (0, 312),
+        (0, 8),
+        (0, 86),
+        (0, 4),
+        (0, 1779),
+        (0, 43),
+        (0, 271),
+        (0, 1345),
+        (0, 449),
+        (0, 480),
+        (0, 399),
+        (0, 6),
+        (0, 209),
+        (0, 378),
+        (0, 13),
+        (0, 4168),
+        (0, 621),
+        (0, 55),
+        (0, 18),
+        (0, 79),
+        (0, 617),
+        (0, 4640),
+        (0, 1706),
+        (0, 273),
+        (0, 859),
+        (0, 7),
+        (0, 114),
+        (0, 706),
+        (0, 2),
+        (0, 21),
+        (0, 16),
+        (0, 54),
+        (0, 43),
+        (0, 13),
+        (0, 136),
+        (0, 35),
+        (0, 16),
+        (0, 820),
+        (0, 1098),
+        (0, 6),
+        (0, 1),
+        (0, 4),
+        (0, 129),
+        (0, 0),
+        (0, 249),
+        (0, 22),
+        (0, 203),
+        (0, 74),
+        (0, 10),
+        (0, 933),
+        (0, 493),
+        (0, 1228),
+        (0, 1814),
+        (0, 446),
+        (0, 62),
+        (0, 100),
+        (0, 3406),
+        (0, 289),
+        (0, 268),
+        (0, 2),
+        (0, 306),
+        (0, 92),
+        (0, 140),
+        (0, 890),
+        (0, 47),
+        (0, 5),
+        (0, 8),
+        (0, 868),
+        (0, 2546),
+        (0, 127),
+        (0, 1941),
+        (0, 124),
+        (0, 300),
+        (0, 2),
+        (0, 11),
+        (0, 861),
+        (0, 40),
+        (0, 1419),
+        (0, 1629),
+        (0, 237),
+        (0, 962),
+        (0, 2129),
+        (0, 22),
+        (0, 0),
+        (0, 0),
+        (0, 18),
+        (0, 8),
+        (0, 6),
+        (0, 40),
+        (0, 265),
+        (0, 2),
+        (0, 10),
+        (0, 9),
+        (0, 20),
+        (0, 7),
+        (0, 2288),
+        (0, 15),
+        (0, 41),
+        (0, 4696),
+        (0, 169),
+        (0, 16),
+        (0, 284),
+        (0, 3),
+        (0, 2827),
+        (0, 592),
+        (0, 3),
+        (2, 1328),
+        (0, 614),
+        (0, 96),
+        (0, 699),
+        (0, 545),
+        (0, 271),
+        (0, 2),
+        (0, 1494),
+        (1, 3270),
+        (0, 50),
+        (0, 473),
+        (0, 3),
+        (0, 13),
+        (0, 1293),
+        (0, 3),
+        (0, 38),
+        (0, 160),
+        (0, 3785),
+        (1, 3422),
+        (0, 201),
+        (1, 943),
+        (0, 1389),
+        (0, 908),
+        (0, 334),
+        (0, 12),
+        (0, 17),
+        (0, 9),
+        (0, 4),
+        (0, 1),
+        (0, 3002),
+        (0, 428),
+        (0, 1750),
+        (0, 1),
+        (0, 88),
+        (0, 90),
+        (0, 12),
+        (0, 183),
+        (0, 573),
+        (0, 3972),
+        (0, 6),
+        (0, 18),
+        (0, 194),
+        (0, 1534),
+        (0, 7),
+        (0, 6),
+        (0, 1622),
+        (1, 2246),
+        (0, 26),
+        (1, 1055),
+        (1, 40),
+        (0, 2404),
+        (0, 822),
+        (0, 1),
+        (0, 0),
+        (0, 5),
+        (0, 215),
+        (0, 1056),
+        (0, 310),
+        (0, 4),
+        (0, 35),
+        (0, 120),
+        (0, 55),
+        (0, 550),
+        (0, 1546),
+        (0, 228),
+        (0, 72),
+        (0, 83),
+        (0, 1400),
+        (0, 2136),
+        (0, 0),
+        (0, 518),
+        (0, 2905),
+        (0, 403),
+        (1, 626),
+        (0, 177),
+        (0, 14),
+        (0, 12),
+        (0, 7),
+        (0, 1214),
+        (0, 1677),
+        (0, 1),
+        (0, 8),
+        (1, 1037),
+        (0, 14),
+        (0, 58),
+        (0, 33),
+        (0, 130),
+        (0, 2250),
+        (0, 3),
+        (0, 651),
+        (1, 1329),
+        (0, 0),
+        (1, 1974),
+        (0, 794),
+        (0, 0),
+        (0, 372),
+        (0, 5),
+        (0, 986),
+        (0, 1302),
+        (1, 1024),
+        (0, 2),
+        (0, 1121),
+        (0, 1487),
+        (1, 2574),
+        (0, 451),
+        (0, 4920),
+        (0, 715),
+        (0, 41),
+        (0, 0),
+        (0, 137),
+        (0, 324),
+        (0, 34),
+        (0, 0),
+        (0, 8),
+        (4, 467),
+        (0, 2152),
+        (0, 36),
+        (0, 44),
+        (1, 2164),
+        (0, 4),
+        (0, 1),
+        (0, 0),
+        (0, 336),
+        (0, 2),
+        (0, 1),
+        (0, 2044),
+        (0, 966),
+        (0, 438),
+        (0, 0),
+        (0, 3),
+        (0, 592),
+        (0, 6),
+        (0, 390),
+        (0, 48),
+        (0, 0),
+        (0, 33),
+        (0, 1703),
+        (1, 786),
+        (0, 4260),
+        (0, 2868),
+        (0, 8),
+        (0, 0),
+        (1, 2689),
+        (0, 0),
+        (0, 11),
+        (0, 3),
+        (0, 611),
+        (0, 484),
+        (0, 0),
+        (0, 2004),
+        (0, 11),
+        (1, 5),
+        (0, 3858),
+        (0, 4568),
+        (0, 7),
+        (0, 3835),
+        (0, 1510),
+        (0, 392),
+        (0, 7),
+        (0, 119),
+        (0, 739),
+        (0, 1359),
+        (0, 8),
+        (0, 2),
+        (0, 1364),
+        (0, 107),
+        (0, 1967),
+        (1, 59),
+        (1, 287),
+        (0, 0),
+        (0, 13),
+        (0, 2042),
+        (0, 1257),
+        (0, 48),
+        (0, 1783),
+        (0, 1292),
+        (0, 1865),
+        (0, 891),
+        (0, 0),
+        (0, 38),
+        (0, 52),
+        (0, 576),
+        (0, 4589),
+        (0, 0),
+        (0, 2),
+        (0, 265),
+        (0, 1529),
+        (0, 7),
+        (0, 2),
+        (0, 470),
+        (0, 143),
+        (0, 1),
+        (0, 19),
+        (0, 343),
+        (0, 252),
+        (0, 1181),
+        (0, 9),
+        (0, 150),
+        (0, 4446),
+        (0, 679),
+        (0, 173),
+        (0, 87),
+        (0, 555),
+        (0, 1693),
+        (0, 982),
+        (0, 17),
+        (0, 984),
+        (0, 2922),
+        (0, 43),
+        (0, 3670),
+        (0, 209),
+        (0, 1201),
+        (0, 1),
+        (0, 37),
+        (0, 482),
+        (0, 3210),
+        (0, 591),
+        (0, 74),
+        (0, 47),
+        (0, 337),
+        (1, 76),
+        (0, 6),
+        (0, 9),
+        (2, 2099),
+        (0, 39),
+        (0, 17),
+        (0, 1),
+        (0, 274),
+        (0, 4),
+        (2, 2370),
+        (1, 1024),
+        (0, 4801),
+        (0, 22),
+        (5, 188),
+        (0, 45),
+        (0, 8),
+        (0, 656),
+        (0, 26),
+        (0, 7),
+        (3, 556),
+        (0, 80),
+        (0, 3261),
+        (0, 39),
+        (0, 1048),
+        (0, 1212),
+        (0, 209),
+        (0, 865),
+        (0, 316),
+        (0, 6),
+        (0, 24),
+        (0, 862),
+        (0, 87),
+        (0, 2968),
+        (0, 14),
+        (0, 82),
+        (0, 2185),
+        (0, 2108),
+        (0, 0),
+        (0, 1),
+        (0, 565),
+        (0, 160),
+        (0, 68),
+        (0, 2),
+        (0, 31),
+        (0, 996),
+        (0, 4701),
+        (0, 788),
+        (3, 1509),
+        (0, 4109),
+        (0, 54),
+        (0, 3130),
+        (0, 3343),
+        (1, 3582),
+        (0, 2663),
+        (2, 2782),
+        (0, 4),
+        (0, 3894),
+        (0, 36),
+        (0, 337),
+        (0, 14),
+        (0, 22),
+        (0, 726),
+        (1, 3861),
+        (0, 0),
+        (0, 8),
+        (0, 88),
+        (0, 866),
+        (0, 7),
+        (0, 3),
+        (0, 60),
+        (0, 433),
+        (0, 2143),
+        (1, 1902),
+        (0, 0),
+        (15, 1567),
+        (0, 0),
+        (0, 3612),
+        (5, 2702),
+        (1, 3903),
+        (0, 1315),
+        (1, 2888),
+        (1, 758),
+        (3, 1749),
+        (0, 37),
+        (0, 105),
+        (0, 10),
+        (11, 3302),
+        (0, 3686),
+        (0, 4735),
+        (0, 1206),
+        (0, 2929),
+        (0, 0),
+        (0, 1158),
+        (4, 4638),
+        (0, 213),
+        (0, 67),
+        (0, 2492),
+        (0, 671),
+        (13, 134),
+        (0, 100),
+        (0, 210),
+        (0, 37),
+        (2, 3715),
+        (0, 1259),
+        (0, 1638),
+        (0, 226),
+        (0, 5),
+        (13, 205),
+        (0, 2492),
+        (0, 4428),
+        (0, 18),
+        (0, 228),
+        (0, 2817),
+        (0, 3726),
+        (0, 152),
+        (0, 2420),
+        (15, 1342),
+        (0, 4829),
+        (6, 4139),
+        (1, 867),
+        (0, 73),
+        (0, 72),
+        (0, 1),
+        (0, 141),
+        (0, 352),
+        (0, 2),
+        (2, 4169),
+        (0, 0),
+        (0, 2308),
+        (0, 57),
+        (0, 1626),
+        (0, 0),
+        (0, 392),
+        (0, 193),
+        (0, 355),
+    ],
+    entries: &[
+        ("🏌🏻\u{200d}♀\u{fe0f}", (EmojiId(1846), EmojiId(1845))),
+        ("🖋\u{fe0f}", (EmojiId(3178), EmojiId(3178))),
+        ("🧖\u{200d}♂", (EmojiId(1789), EmojiId(1789))),
+        ("💁🏾", (EmojiId(739), EmojiId(735))),
+        ("❗", (EmojiId(3399), EmojiId(3399))),
+        ("🧑🏼\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1626), EmojiId(1624))),
+        ("🧮", (EmojiId(3119), EmojiId(3119))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2275), EmojiId(2264))),
+        ("💅🏼", (EmojiId(437), EmojiId(435))),
+        ("👨\u{200d}🏫", (EmojiId(885), EmojiId(885))),
+        ("👨\u{200d}👨\u{200d}👦\u{200d}👦", (EmojiId(2402), EmojiId(2402))),
+        ("👐🏿", (EmojiId(390), EmojiId(385))),
+        ("🧑🏿\u{200d}🍳", (EmojiId(938), EmojiId(933))),
+        ("▪\u{fe0f}", (EmojiId(3499), EmojiId(3499))),
+        ("🙋🏻\u{200d}♂", (EmojiId(760), EmojiId(759))),
+        ("👨🏿\u{200d}🔧", (EmojiId(962), EmojiId(957))),
+        ("👩🏽\u{200d}🍼", (EmojiId(1320), EmojiId(1317))),
+        ("🧩", (EmojiId(3003), EmojiId(3003))),
+        ("🥧", (EmojiId(2687), EmojiId(2687))),
+        ("⏺", (EmojiId(3376), EmojiId(3376))),
+        ("⚪", (EmojiId(3485), EmojiId(3485))),
+        ("😁", (EmojiId(3), EmojiId(3))),
+        ("💨", (EmojiId(161), EmojiId(161))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2229), EmojiId(2212))),
+        ("🧎🏽\u{200d}➡\u{fe0f}", (EmojiId(1603), EmojiId(1600))),
+        ("🛸", (EmojiId(2857), EmojiId(2857))),
+        ("🕛", (EmojiId(2867), EmojiId(2867))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2289), EmojiId(2264))),
+        ("👩\u{200d}✈\u{fe0f}", (EmojiId(1089), EmojiId(1089))),
+        ("🧑\u{200d}🦲", (EmojiId(627), EmojiId(627))),
+        ("🧘🏼\u{200d}♂", (EmojiId(2060), EmojiId(2058))),
+        ("👩🏿\u{200d}🎓", (EmojiId(878), EmojiId(873))),
+        ("👐", (EmojiId(385), EmojiId(385))),
+        ("🤰🏾", (EmojiId(1297), EmojiId(1293))),
+        ("⛹🏻\u{200d}♀\u{fe0f}", (EmojiId(1918), EmojiId(1917))),
+        ("🫷🏽", (EmojiId(226), EmojiId(223))),
+        ("🙇🏻\u{200d}♀", (EmojiId(802), EmojiId(801))),
+        ("🛟", (EmojiId(2837), EmojiId(2837))),
+        ("🈯", (EmojiId(3464), EmojiId(3464))),
+        ("🤽🏾\u{200d}♂\u{fe0f}", (EmojiId(2008), EmojiId(2004))),
+        ("🫕", (EmojiId(2655), EmojiId(2655))),
+        ("🇸🇻", (EmojiId(3735), EmojiId(3735))),
+        ("🚶🏿\u{200d}➡", (EmojiId(1551), EmojiId(1546))),
+        ("🔉", (EmojiId(3072), EmojiId(3072))),
+        ("🚣🏼", (EmojiId(1871), EmojiId(1869))),
+        ("🇮🇹", (EmojiId(3635), EmojiId(3635))),
+        ("👮🏿\u{200d}♀", (EmojiId(1148), EmojiId(1143))),
+        ("🚶🏾\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1556), EmojiId(1552))),
+        ("😊", (EmojiId(12), EmojiId(12))),
+        ("⛹🏾", (EmojiId(1909), EmojiId(1905))),
+        ("🧖🏼\u{200d}♀", (EmojiId(1797), EmojiId(1795))),
+        ("🧘🏾\u{200d}♂\u{fe0f}", (EmojiId(2062), EmojiId(2058))),
+        ("🤳🏽", (EmojiId(444), EmojiId(441))),
+        ("🇨🇺", (EmojiId(3572), EmojiId(3572))),
+        ("🇮🇴", (EmojiId(3631), EmojiId(3631))),
+        ("🚶🏻\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1559), EmojiId(1558))),
+        ("💅🏿", (EmojiId(440), EmojiId(435))),
+        ("🗜\u{fe0f}", (EmojiId(3228), EmojiId(3228))),
+        ("🧏🏻\u{200d}♂", (EmojiId(778), EmojiId(777))),
+        ("🤴🏻", (EmojiId(1216), EmojiId(1215))),
+        ("👳🏼\u{200d}♀\u{fe0f}", (EmojiId(1241), EmojiId(1239))),
+        ("🦶🏿", (EmojiId(466), EmojiId(461))),
+        ("🇰🇾", (EmojiId(3649), EmojiId(3649))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2260), EmojiId(2238))),
+        ("🎺", (EmojiId(3092), EmojiId(3092))),
+        ("🧑🏿\u{200d}🦯\u{200d}➡", (EmojiId(1629), EmojiId(1624))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏼", (EmojiId(2352), EmojiId(2342))),
+        ("⏹\u{fe0f}", (EmojiId(3375), EmojiId(3375))),
+        ("🧖", (EmojiId(1783), EmojiId(1783))),
+        ("👎🏿", (EmojiId(342), EmojiId(337))),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏼", (EmojiId(2192), EmojiId(2186))),
+        ("🏌", (EmojiId(1833), EmojiId(1833))),
+        ("💂🏾\u{200d}♀\u{fe0f}", (EmojiId(1183), EmojiId(1179))),
+        ("👏", (EmojiId(367), EmojiId(367))),
+        ("🇸🇴", (EmojiId(3731), EmojiId(3731))),
+        ("🕸\u{fe0f}", (EmojiId(2554), EmojiId(2554))),
+        ("🧎\u{200d}♂", (EmojiId(1588), EmojiId(1588))),
+        ("📙", (EmojiId(3141), EmojiId(3141))),
+        ("🇷🇸", (EmojiId(3715), EmojiId(3715))),
+        ("🌯", (EmojiId(2647), EmojiId(2647))),
+        ("👟", (EmojiId(3051), EmojiId(3051))),
+        ("🇩🇪", (EmojiId(3578), EmojiId(3578))),
+        ("👿", (EmojiId(107), EmojiId(107))),
+        ("📃", (EmojiId(3145), EmojiId(3145))),
+        ("🥑", (EmojiId(2609), EmojiId(2609))),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏽", (EmojiId(2167), EmojiId(2160))),
+        ("🙎🏿\u{200d}♀", (EmojiId(698), EmojiId(693))),
+        ("3\u{fe0f}\u{20e3}", (EmojiId(3430), EmojiId(3430))),
+        ("🚶🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1561), EmojiId(1558))),
+        ("🇧🇴", (EmojiId(3548), EmojiId(3548))),
+        ("⛓\u{fe0f}\u{200d}💥", (EmojiId(3232), EmojiId(3232))),
+        ("⚜\u{fe0f}", (EmojiId(3405), EmojiId(3405))),
+        ("🧑\u{200d}🍼", (EmojiId(1329), EmojiId(1329))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏼", (EmojiId(2331), EmojiId(2316))),
+        ("🧑\u{200d}🎤", (EmojiId(1041), EmojiId(1041))),
+        ("🦇", (EmojiId(2485), EmojiId(2485))),
+        ("🙎🏿\u{200d}♂\u{fe0f}", (EmojiId(692), EmojiId(687))),
+        ("🟩", (EmojiId(3489), EmojiId(3489))),
+        ("👨🏻\u{200d}🍼", (EmojiId(1324), EmojiId(1323))),
+        ("🤝🏻", (EmojiId(398), EmojiId(397))),
+        ("🤏🏻", (EmojiId(248), EmojiId(247))),
+        ("🏦", (EmojiId(2751), EmojiId(2751))),
+        ("🧗🏼\u{200d}♂\u{fe0f}", (EmojiId(1809), EmojiId(1807))),
+        ("🇬🇪", (EmojiId(3603), EmojiId(3603))),
+        ("🎞", (EmojiId(3121), EmojiId(3121))),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏿", (EmojiId(2203), EmojiId(2186))),
+        ("💑🏻", (EmojiId(2291), EmojiId(2290))),
+        ("🧑🏽\u{200d}🦼\u{200d}➡", (EmojiId(1663), EmojiId(1660))),
+        ("🏊🏿", (EmojiId(1892), EmojiId(1887))),
+        ("🔈", (EmojiId(3071), EmojiId(3071))),
+        ("🤦🏽", (EmojiId(810), EmojiId(807))),
+        ("♉", (EmojiId(3348), EmojiId(3348))),
+        ("🛌", (EmojiId(2076), EmojiId(2076))),
+        ("📩", (EmojiId(3166), EmojiId(3166))),
+        ("🇸🇳", (EmojiId(3730), EmojiId(3730))),
+        ("🕴🏽", (EmojiId(1777), EmojiId(1774))),
+        ("🐓", (EmojiId(2498), EmojiId(2498))),
+        ("🫴🏾", (EmojiId(221), EmojiId(217))),
+        ("🗾", (EmojiId(2725), EmojiId(2725))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏽", (EmojiId(2379), EmojiId(2368))),
+        ("👴🏾", (EmojiId(655), EmojiId(651))),
+        ("🇳🇴", (EmojiId(3692), EmojiId(3692))),
+        ("👉🏻", (EmojiId(296), EmojiId(295))),
+        ("👨🏼\u{200d}🍼", (EmojiId(1325), EmojiId(1323))),
+        ("🍩", (EmojiId(2682), EmojiId(2682))),
+        ("🤰🏻", (EmojiId(1294), EmojiId(1293))),
+        ("👨🏼\u{200d}🔬", (EmojiId(1013), EmojiId(1011))),
+        ("👨🏽\u{200d}💼", (EmojiId(996), EmojiId(993))),
+        ("⏮", (EmojiId(3369), EmojiId(3369))),
+        ("🔕", (EmojiId(3078), EmojiId(3078))),
+        ("🧙🏼", (EmojiId(1397), EmojiId(1395))),
+        ("‼\u{fe0f}", (EmojiId(3394), EmojiId(3394))),
+        ("🌥\u{fe0f}", (EmojiId(2916), EmojiId(2916))),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏼", (EmojiId(2123), EmojiId(2108))),
+        ("👨🏻\u{200d}🦼\u{200d}➡", (EmojiId(1673), EmojiId(1672))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2237), EmojiId(2212))),
+        ("🧑🏻\u{200d}💻", (EmojiId(1024), EmojiId(1023))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2326), EmojiId(2316))),
+        ("🇬🇾", (EmojiId(3618), EmojiId(3618))),
+        ("🇧🇮", (EmojiId(3543), EmojiId(3543))),
+        ("👩\u{200d}👦\u{200d}👦", (EmojiId(2415), EmojiId(2415))),
+        ("👮🏼\u{200d}♂\u{fe0f}", (EmojiId(1139), EmojiId(1137))),
+        ("📍", (EmojiId(3197), EmojiId(3197))),
+        ("🇰🇼", (EmojiId(3648), EmojiId(3648))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2263), EmojiId(2238))),
+        ("👨🏿\u{200d}🍳", (EmojiId(944), EmojiId(939))),
+        ("🙍🏿\u{200d}♂\u{fe0f}", (EmojiId(674), EmojiId(669))),
+        ("⛹\u{200d}♀\u{fe0f}", (EmojiId(1917), EmojiId(1917))),
+        ("👨🏿\u{200d}⚖", (EmojiId(908), EmojiId(903))),
+        ("🤾🏼\u{200d}♂", (EmojiId(2024), EmojiId(2022))),
+        ("🤨", (EmojiId(37), EmojiId(37))),
+        ("😬", (EmojiId(46), EmojiId(46))),
+        ("🇰🇪", (EmojiId(3640), EmojiId(3640))),
+        ("🇷🇴", (EmojiId(3714), EmojiId(3714))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2372), EmojiId(2368))),
+        ("👨🏿\u{200d}🦼\u{200d}➡", (EmojiId(1677), EmojiId(1672))),
+        ("💇🏻\u{200d}♂", (EmojiId(1517), EmojiId(1516))),
+        ("🏃🏻", (EmojiId(1727), EmojiId(1726))),
+        ("🅰\u{fe0f}", (EmojiId(3443), EmojiId(3443))),
+        ("🤵🏻", (EmojiId(1258), EmojiId(1257))),
+        ("🇦🇫", (EmojiId(3522), EmojiId(3522))),
+        ("🦹🏾\u{200d}♀", (EmojiId(1393), EmojiId(1389))),
+        ("🚥", (EmojiId(2832), EmojiId(2832))),
+        ("🤚🏼", (EmojiId(177), EmojiId(175))),
+        ("〰", (EmojiId(3400), EmojiId(3400))),
+        ("📋", (EmojiId(3195), EmojiId(3195))),
+        ("🥭", (EmojiId(2597), EmojiId(2597))),
+        ("🏋🏻", (EmojiId(1924), EmojiId(1923))),
+        ("🚵🏾\u{200d}♂\u{fe0f}", (EmojiId(1969), EmojiId(1965))),
+        ("⚕", (EmojiId(3403), EmojiId(3403))),
+        ("🙍🏽\u{200d}♀\u{fe0f}", (EmojiId(678), EmojiId(675))),
+        ("🚶\u{200d}♂\u{200d}➡", (EmojiId(1558), EmojiId(1558))),
+        ("🚿", (EmojiId(3262), EmojiId(3262))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2273), EmojiId(2264))),
+        ("📰", (EmojiId(3148), EmojiId(3148))),
+        ("💂🏻\u{200d}♂\u{fe0f}", (EmojiId(1174), EmojiId(1173))),
+        ("🇫🇷", (EmojiId(3599), EmojiId(3599))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2385), EmojiId(2368))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", (EmojiId(2192), EmojiId(2186))),
+        ("🏋🏾", (EmojiId(1927), EmojiId(1923))),
+        ("👮🏿\u{200d}♂\u{fe0f}", (EmojiId(1142), EmojiId(1137))),
+        ("🕷\u{fe0f}", (EmojiId(2553), EmojiId(2553))),
+        ("🏃\u{200d}♀", (EmojiId(1738), EmojiId(1738))),
+        ("🧓🏼", (EmojiId(647), EmojiId(645))),
+        ("🛖", (EmojiId(2742), EmojiId(2742))),
+        ("🏙\u{fe0f}", (EmojiId(2773), EmojiId(2773))),
+        ("🙅🏻\u{200d}♀", (EmojiId(712), EmojiId(711))),
+        ("🍒", (EmojiId(2602), EmojiId(2602))),
+        ("⛩", (EmojiId(2767), EmojiId(2767))),
+        ("📗", (EmojiId(3139), EmojiId(3139))),
+        ("🏰", (EmojiId(2759), EmojiId(2759))),
+        ("👨🏽\u{200d}🦼\u{200d}➡", (EmojiId(1675), EmojiId(1672))),
+        ("🇺🇳", (EmojiId(3759), EmojiId(3759))),
+        ("🍧", (EmojiId(2680), EmojiId(2680))),
+        ("👩🏻\u{200d}💼", (EmojiId(1000), EmojiId(999))),
+        ("🈵", (EmojiId(3476), EmojiId(3476))),
+        ("🧑🏾\u{200d}🦼\u{200d}➡", (EmojiId(1664), EmojiId(1660))),
+        ("🎂", (EmojiId(2684), EmojiId(2684))),
+        ("❄", (EmojiId(2931), EmojiId(2931))),
+        ("😦", (EmojiId(87), EmojiId(87))),
+        ("👨🏿\u{200d}🦯\u{200d}➡", (EmojiId(1641), EmojiId(1636))),
+        ("📧", (EmojiId(3164), EmojiId(3164))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2374), EmojiId(2368))),
+        ("🧑\u{200d}🚒", (EmojiId(1113), EmojiId(1113))),
+        ("👨\u{200d}👩\u{200d}👧\u{200d}👦", (EmojiId(2396), EmojiId(2396))),
+        ("🤱", (EmojiId(1311), EmojiId(1311))),
+        ("🔨", (EmojiId(3212), EmojiId(3212))),
+        ("👨🏻\u{200d}🎓", (EmojiId(868), EmojiId(867))),
+        ("🍊", (EmojiId(2592), EmojiId(2592))),
+        ("👰🏽\u{200d}♀\u{fe0f}", (EmojiId(1290), EmojiId(1287))),
+        ("🙅🏻\u{200d}♂", (EmojiId(706), EmojiId(705))),
+        ("🇮🇸", (EmojiId(3634), EmojiId(3634))),
+        ("💁\u{200d}♀\u{fe0f}", (EmojiId(747), EmojiId(747))),
+        ("🕞", (EmojiId(2874), EmojiId(2874))),
+        ("🐕", (EmojiId(2435), EmojiId(2435))),
+        ("🏄🏻\u{200d}♀\u{fe0f}", (EmojiId(1864), EmojiId(1863))),
+        ("🟠", (EmojiId(3478), EmojiId(3478))),
+        ("😻", (EmojiId(121), EmojiId(121))),
+        ("👷🏿\u{200d}♀\u{fe0f}", (EmojiId(1208), EmojiId(1203))),
+        ("🏃\u{200d}♂", (EmojiId(1732), EmojiId(1732))),
+        ("🇧🇱", (EmojiId(3545), EmojiId(3545))),
+        ("👨\u{200d}🎨", (EmojiId(1065), EmojiId(1065))),
+        ("🦈", (EmojiId(2534), EmojiId(2534))),
+        ("🚸", (EmojiId(3301), EmojiId(3301))),
+        ("🍈", (EmojiId(2590), EmojiId(2590))),
+        ("😢", (EmojiId(92), EmojiId(92))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", (EmojiId(2198), EmojiId(2186))),
+        ("🌾", (EmojiId(2578), EmojiId(2578))),
+        ("♠\u{fe0f}", (EmojiId(3008), EmojiId(3008))),
+        ("❤", (EmojiId(143), EmojiId(143))),
+        ("👮🏻\u{200d}♀", (EmojiId(1144), EmojiId(1143))),
+        ("🌗", (EmojiId(2897), EmojiId(2897))),
+        ("🧏🏼\u{200d}♀", (EmojiId(785), EmojiId(783))),
+        ("▫", (EmojiId(3500), EmojiId(3500))),
+        ("✍🏽", (EmojiId(432), EmojiId(429))),
+        ("↖", (EmojiId(3320), EmojiId(3320))),
+        ("🩷", (EmojiId(144), EmojiId(144))),
+        ("🦹🏽\u{200d}♀\u{fe0f}", (EmojiId(1392), EmojiId(1389))),
+        ("🦸\u{200d}♀", (EmojiId(1371), EmojiId(1371))),
+        ("🐂", (EmojiId(2458), EmojiId(2458))),
+        ("#\u{fe0f}\u{20e3}", (EmojiId(3425), EmojiId(3425))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏾", (EmojiId(2384), EmojiId(2368))),
+        ("❤\u{200d}🩹", (EmojiId(142), EmojiId(142))),
+        ("🫱🏼\u{200d}🫲🏾", (EmojiId(409), EmojiId(397))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2362), EmojiId(2342))),
+        ("🧡", (EmojiId(145), EmojiId(145))),
+        ("🧎🏼\u{200d}♂", (EmojiId(1590), EmojiId(1588))),
+        ("🤲🏻", (EmojiId(392), EmojiId(391))),
+        ("🧑🏾\u{200d}🦽", (EmojiId(1694), EmojiId(1690))),
+        ("👩🏾", (EmojiId(583), EmojiId(579))),
+        ("🙍\u{200d}♂", (EmojiId(669), EmojiId(669))),
+        ("🙋🏼\u{200d}♀\u{fe0f}", (EmojiId(767), EmojiId(765))),
+        ("🇵🇫", (EmojiId(3700), EmojiId(3700))),
+        ("😀", (EmojiId(0), EmojiId(0))),
+        ("👩🏻\u{200d}🦲", (EmojiId(622), EmojiId(621))),
+        ("🧔🏿\u{200d}♂", (EmojiId(548), EmojiId(543))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2369), EmojiId(2368))),
+        ("😴", (EmojiId(56), EmojiId(56))),
+        ("🚎", (EmojiId(2800), EmojiId(2800))),
+        ("🌚", (EmojiId(2900), EmojiId(2900))),
+        ("⛸", (EmojiId(2985), EmojiId(2985))),
+        ("☮\u{fe0f}", (EmojiId(3343), EmojiId(3343))),
+        ("🛢", (EmojiId(2828), EmojiId(2828))),
+        ("💣", (EmojiId(3219), EmojiId(3219))),
+        ("🧑\u{200d}🧒", (EmojiId(2426), EmojiId(2426))),
+        ("🩲", (EmojiId(3039), EmojiId(3039))),
+        ("💢", (EmojiId(157), EmojiId(157))),
+        ("🤙🏻", (EmojiId(284), EmojiId(283))),
+        ("🧍🏼", (EmojiId(1566), EmojiId(1564))),
+        ("🧑🏻\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1661), EmojiId(1660))),
+        ("👨\u{200d}🦯\u{200d}➡", (EmojiId(1636), EmojiId(1636))),
+        ("👩🏾\u{200d}💻", (EmojiId(1039), EmojiId(1035))),
+        ("🌼", (EmojiId(2569), EmojiId(2569))),
+        ("🙇🏾", (EmojiId(793), EmojiId(789))),
+        ("💇🏾\u{200d}♂\u{fe0f}", (EmojiId(1520), EmojiId(1516))),
+        ("🇬🇭", (EmojiId(3606), EmojiId(3606))),
+        ("🛎", (EmojiId(2858), EmojiId(2858))),
+        ("🧞\u{200d}♂\u{fe0f}", (EmojiId(1486), EmojiId(1486))),
+        ("🙆", (EmojiId(717), EmojiId(717))),
+        ("🇫🇴", (EmojiId(3598), EmojiId(3598))),
+        ("🥲", (EmojiId(22), EmojiId(22))),
+        ("🧑🏽\u{200d}🔬", (EmojiId(1008), EmojiId(1005))),
+        ("🗣", (EmojiId(2419), EmojiId(2419))),
+        ("✋🏼", (EmojiId(189), EmojiId(187))),
+        ("🇲🇶", (EmojiId(3675), EmojiId(3675))),
+        ("2\u{20e3}", (EmojiId(3429), EmojiId(3429))),
+        ("🕔", (EmojiId(2877), EmojiId(2877))),
+        ("🙇\u{200d}♂\u{fe0f}", (EmojiId(795), EmojiId(795))),
+        ("👩🏻\u{200d}🏫", (EmojiId(892), EmojiId(891))),
+        ("🧞\u{200d}♀", (EmojiId(1487), EmojiId(1487))),
+        ("🏡", (EmojiId(2746), EmojiId(2746))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2375), EmojiId(2368))),
+        ("🫷🏾", (EmojiId(227), EmojiId(223))),
+        ("🏵\u{fe0f}", (EmojiId(2564), EmojiId(2564))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2091), EmojiId(2082))),
+        ("🍸", (EmojiId(2701), EmojiId(2701))),
+        ("🙇🏿", (EmojiId(794), EmojiId(789))),
+        ("👩\u{200d}🏭", (EmojiId(981), EmojiId(981))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏻", (EmojiId(2369), EmojiId(2368))),
+        ("🎻", (EmojiId(3093), EmojiId(3093))),
+        ("🫃🏽", (EmojiId(1302), EmojiId(1299))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2100), EmojiId(2082))),
+        ("👩🏻\u{200d}🦽", (EmojiId(1715), EmojiId(1714))),
+        ("👨🏼\u{200d}💼", (EmojiId(995), EmojiId(993))),
+        ("🤝🏽", (EmojiId(400), EmojiId(397))),
+        ("🏃🏻\u{200d}♂\u{fe0f}", (EmojiId(1733), EmojiId(1732))),
+        ("🍞", (EmojiId(2628), EmojiId(2628))),
+        ("💆🏻\u{200d}♂\u{fe0f}", (EmojiId(1499), EmojiId(1498))),
+        ("👩🏾\u{200d}🦳", (EmojiId(613), EmojiId(609))),
+        ("⛑", (EmojiId(3065), EmojiId(3065))),
+        ("⛴", (EmojiId(2842), EmojiId(2842))),
+        ("🪩", (EmojiId(3006), EmojiId(3006))),
+        ("🇧🇶", (EmojiId(3549), EmojiId(3549))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏾", (EmojiId(2350), EmojiId(2342))),
+        ("⚙\u{fe0f}", (EmojiId(3227), EmojiId(3227))),
+        ("✝\u{fe0f}", (EmojiId(3340), EmojiId(3340))),
+        ("🇧🇸", (EmojiId(3551), EmojiId(3551))),
+        ("🏢", (EmojiId(2747), EmojiId(2747))),
+        ("🏋\u{200d}♀", (EmojiId(1935), EmojiId(1935))),
+        ("🧛🏼\u{200d}♀\u{fe0f}", (EmojiId(1445), EmojiId(1443))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2284), EmojiId(2264))),
+        ("🦹🏼", (EmojiId(1379), EmojiId(1377))),
+        ("☂\u{fe0f}", (EmojiId(2927), EmojiId(2927))),
+        ("▶\u{fe0f}", (EmojiId(3363), EmojiId(3363))),
+        ("🧑🏽\u{200d}🦱", (EmojiId(606), EmojiId(603))),
+        ("🏜", (EmojiId(2733), EmojiId(2733))),
+        ("👨🏽", (EmojiId(534), EmojiId(531))),
+        ("👍🏾", (EmojiId(335), EmojiId(331))),
+        ("🥃", (EmojiId(2706), EmojiId(2706))),
+        ("🎏", (EmojiId(2950), EmojiId(2950))),
+        ("👂🏽", (EmojiId(470), EmojiId(467))),
+        ("🦻🏼", (EmojiId(475), EmojiId(473))),
+        ("🇦🇴", (EmojiId(3527), EmojiId(3527))),
+        ("🧕🏽", (EmojiId(1254), EmojiId(1251))),
+        ("👩\u{200d}🔧", (EmojiId(963), EmojiId(963))),
+        ("🗡", (EmojiId(3217), EmojiId(3217))),
+        ("◻\u{fe0f}", (EmojiId(3496), EmojiId(3496))),
+        ("👩🏼\u{200d}🦱", (EmojiId(599), EmojiId(597))),
+        ("💇\u{200d}♀", (EmojiId(1522), EmojiId(1522))),
+        ("👨\u{200d}🎓", (EmojiId(867), EmojiId(867))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2288), EmojiId(2264))),
+        ("🧷", (EmojiId(3267), EmojiId(3267))),
+        ("👮🏼\u{200d}♀", (EmojiId(1145), EmojiId(1143))),
+        ("🐊", (EmojiId(2519), EmojiId(2519))),
+        ("🤔", (EmojiId(34), EmojiId(34))),
+        ("💪🏼", (EmojiId(449), EmojiId(447))),
+        ("🧟\u{200d}♀", (EmojiId(1490), EmojiId(1490))),
+        ("👨🏻\u{200d}🦼", (EmojiId(1667), EmojiId(1666))),
+        ("🙉", (EmojiId(128), EmojiId(128))),
+        ("🧍", (EmojiId(1564), EmojiId(1564))),
+        ("👩🏽\u{200d}🏭", (EmojiId(984), EmojiId(981))),
+        ("🤵🏽", (EmojiId(1260), EmojiId(1257))),
+        ("💁🏾\u{200d}♀", (EmojiId(751), EmojiId(747))),
+        ("🇳🇿", (EmojiId(3696), EmojiId(3696))),
+        ("🔪", (EmojiId(2717), EmojiId(2717))),
+        ("👃", (EmojiId(479), EmojiId(479))),
+        ("🧑\u{200d}🎨", (EmojiId(1059), EmojiId(1059))),
+        ("🥾", (EmojiId(3052), EmojiId(3052))),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏼", (EmojiId(2149), EmojiId(2134))),
+        ("👳🏾\u{200d}♂", (EmojiId(1237), EmojiId(1233))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2258), EmojiId(2238))),
+        ("😎", (EmojiId(73), EmojiId(73))),
+        ("🧑🏾\u{200d}🦳", (EmojiId(619), EmojiId(615))),
+        ("🧎🏿\u{200d}♀\u{200d}➡", (EmojiId(1611), EmojiId(1606))),
+        ("🤽🏾\u{200d}♂", (EmojiId(2008), EmojiId(2004))),
+        ("👮🏻\u{200d}♂", (EmojiId(1138), EmojiId(1137))),
+        ("🎌", (EmojiId(3513), EmojiId(3513))),
+        ("🐩", (EmojiId(2438), EmojiId(2438))),
+        ("🤦🏻\u{200d}♀", (EmojiId(820), EmojiId(819))),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏾", (EmojiId(2159), EmojiId(2134))),
+        ("🤷🏼\u{200d}♀\u{fe0f}", (EmojiId(839), EmojiId(837))),
+        ("👰🏾", (EmojiId(1279), EmojiId(1275))),
+        ("🏴\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}", (EmojiId(3780), EmojiId(3780))),
+        ("🌄", (EmojiId(2774), EmojiId(2774))),
+        ("🧎\u{200d}♂\u{fe0f}", (EmojiId(1588), EmojiId(1588))),
+        ("📉", (EmojiId(3193), EmojiId(3193))),
+        ("🥷🏾", (EmojiId(1189), EmojiId(1185))),
+        ("♓", (EmojiId(3358), EmojiId(3358))),
+        ("🚶🏻", (EmojiId(1529), EmojiId(1528))),
+        ("🧑🏻\u{200d}⚖", (EmojiId(898), EmojiId(897))),
+        ("➖", (EmojiId(3390), EmojiId(3390))),
+        ("↕", (EmojiId(3321), EmojiId(3321))),
+        ("👨🏻\u{200d}🎤", (EmojiId(1048), EmojiId(1047))),
+        ("👨🏻\u{200d}🏭", (EmojiId(976), EmojiId(975))),
+        ("👨🏻", (EmojiId(532), EmojiId(531))),
+        ("👏🏾", (EmojiId(371), EmojiId(367))),
+        ("👱🏽\u{200d}♂\u{fe0f}", (EmojiId(642), EmojiId(639))),
+        ("👃🏻", (EmojiId(480), EmojiId(479))),
+        ("🙋🏿\u{200d}♀\u{fe0f}", (EmojiId(770), EmojiId(765))),
+        ("🇧🇦", (EmojiId(3536), EmojiId(3536))),
+        ("👩🏽\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1651), EmojiId(1648))),
+        ("🚶🏻\u{200d}♂\u{200d}➡", (EmojiId(1559), EmojiId(1558))),
+        ("4\u{fe0f}\u{20e3}", (EmojiId(3431), EmojiId(3431))),
+        ("🧸", (EmojiId(3004), EmojiId(3004))),
+        ("🍎", (EmojiId(2598), EmojiId(2598))),
+        ("🧎🏼\u{200d}♂\u{fe0f}", (EmojiId(1590), EmojiId(1588))),
+        ("👯\u{200d}♂\u{fe0f}", (EmojiId(1781), EmojiId(1781))),
+        ("👩🏽\u{200d}🎓", (EmojiId(876), EmojiId(873))),
+        ("🇧🇳", (EmojiId(3547), EmojiId(3547))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2359), EmojiId(2342))),
+        ("🏀", (EmojiId(2968), EmojiId(2968))),
+        ("🔞", (EmojiId(3310), EmojiId(3310))),
+        ("👨\u{200d}👧\u{200d}👧", (EmojiId(2413), EmojiId(2413))),
+        ("🚲", (EmojiId(2821), EmojiId(2821))),
+        ("🧑🏻\u{200d}🚀", (EmojiId(1096), EmojiId(1095))),
+        ("🧑\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1624), EmojiId(1624))),
+        ("🧖🏽\u{200d}♀\u{fe0f}", (EmojiId(1798), EmojiId(1795))),
+        ("🤵🏿\u{200d}♂", (EmojiId(1268), EmojiId(1263))),
+        ("👉🏿", (EmojiId(300), EmojiId(295))),
+        ("🚰", (EmojiId(3289), EmojiId(3289))),
+        ("👷🏾", (EmojiId(1195), EmojiId(1191))),
+        ("📫", (EmojiId(3170), EmojiId(3170))),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏼", (EmojiId(2309), EmojiId(2290))),
+        ("🧍\u{200d}♂\u{fe0f}", (EmojiId(1570), EmojiId(1570))),
+        ("🧑🏾\u{200d}⚖", (EmojiId(901), EmojiId(897))),
+        ("💑🏿", (EmojiId(2295), EmojiId(2290))),
+        ("🇵🇦", (EmojiId(3698), EmojiId(3698))),
+        ("🖌\u{fe0f}", (EmojiId(3180), EmojiId(3180))),
+        ("🕊\u{fe0f}", (EmojiId(2504), EmojiId(2504))),
+        ("🧜🏼\u{200d}♂", (EmojiId(1457), EmojiId(1455))),
+        ("🤵🏽\u{200d}♀\u{fe0f}", (EmojiId(1272), EmojiId(1269))),
+        ("👩🏼\u{200d}🦰", (EmojiId(587), EmojiId(585))),
+        ("👨🏼\u{200d}🍳", (EmojiId(941), EmojiId(939))),
+        ("🍺", (EmojiId(2703), EmojiId(2703))),
+        ("🇮🇷", (EmojiId(3633), EmojiId(3633))),
+        ("🤾🏼\u{200d}♀\u{fe0f}", (EmojiId(2030), EmojiId(2028))),
+        ("🧑🏾\u{200d}🦱", (EmojiId(607), EmojiId(603))),
+        ("☯", (EmojiId(3339), EmojiId(3339))),
+        ("🤦\u{200d}♂", (EmojiId(813), EmojiId(813))),
+        ("🧑🏻\u{200d}🎓", (EmojiId(862), EmojiId(861))),
+        ("🕊", (EmojiId(2504), EmojiId(2504))),
+        ("💄", (EmojiId(3067), EmojiId(3067))),
+        ("👨🏽\u{200d}🔬", (EmojiId(1014), EmojiId(1011))),
+        ("👨🏽\u{200d}🦯\u{200d}➡", (EmojiId(1639), EmojiId(1636))),
+        ("🕟", (EmojiId(2876), EmojiId(2876))),
+        ("🏋\u{200d}♂\u{fe0f}", (EmojiId(1929), EmojiId(1929))),
+        ("🤾🏼\u{200d}♂\u{fe0f}", (EmojiId(2024), EmojiId(2022))),
+        ("🧏🏿\u{200d}♂\u{fe0f}", (EmojiId(782), EmojiId(777))),
+        ("🤷🏽\u{200d}♂", (EmojiId(834), EmojiId(831))),
+        ("👩🏻\u{200d}🦼\u{200d}➡", (EmojiId(1685), EmojiId(1684))),
+        ("👩🏿\u{200d}🚀", (EmojiId(1112), EmojiId(1107))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", (EmojiId(2210), EmojiId(2186))),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏿", (EmojiId(2155), EmojiId(2134))),
+        ("🇬🇳", (EmojiId(3610), EmojiId(3610))),
+        ("👨🏽\u{200d}⚕\u{fe0f}", (EmojiId(852), EmojiId(849))),
+        ("🪲", (EmojiId(2549), EmojiId(2549))),
+        ("🍭", (EmojiId(2690), EmojiId(2690))),
+        ("👩🏾\u{200d}🏭", (EmojiId(985), EmojiId(981))),
+        ("👨🏼\u{200d}🚀", (EmojiId(1103), EmojiId(1101))),
+        ("👸", (EmojiId(1221), EmojiId(1221))),
+        ("🐋", (EmojiId(2528), EmojiId(2528))),
+        ("👩🏽\u{200d}🎤", (EmojiId(1056), EmojiId(1053))),
+        ("🪪", (EmojiId(3286), EmojiId(3286))),
+        ("🇬🇦", (EmojiId(3600), EmojiId(3600))),
+        ("🇼🇸", (EmojiId(3771), EmojiId(3771))),
+        ("💪🏿", (EmojiId(452), EmojiId(447))),
+        ("🕵🏻\u{200d}♀", (EmojiId(1162), EmojiId(1161))),
+        ("🛳", (EmojiId(2841), EmojiId(2841))),
+        ("👩🏾\u{200d}🎨", (EmojiId(1075), EmojiId(1071))),
+        ("👵🏾", (EmojiId(661), EmojiId(657))),
+        ("🕵🏿\u{200d}♀\u{fe0f}", (EmojiId(1166), EmojiId(1161))),
+        ("🫄🏽", (EmojiId(1308), EmojiId(1305))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2274), EmojiId(2264))),
+        ("🧑\u{200d}⚖", (EmojiId(897), EmojiId(897))),
+        ("🤟🏼", (EmojiId(273), EmojiId(271))),
+        ("🕯", (EmojiId(3131), EmojiId(3131))),
+        ("🏂🏽", (EmojiId(1830), EmojiId(1827))),
+        ("🌌", (EmojiId(2911), EmojiId(2911))),
+        ("🏌\u{200d}♂\u{fe0f}", (EmojiId(1839), EmojiId(1839))),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏼", (EmojiId(2305), EmojiId(2290))),
+        ("💚", (EmojiId(147), EmojiId(147))),
+        ("🧵", (EmojiId(3019), EmojiId(3019))),
+        ("👨🏾\u{200d}🏭", (EmojiId(979), EmojiId(975))),
+        ("🏃🏿\u{200d}♂\u{fe0f}", (EmojiId(1737), EmojiId(1732))),
+        ("🧗🏿", (EmojiId(1806), EmojiId(1801))),
+        ("🖕🏽", (EmojiId(310), EmojiId(307))),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏿", (EmojiId(2129), EmojiId(2108))),
+        ("‼", (EmojiId(3394), EmojiId(3394))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2324), EmojiId(2316))),
+        ("💆🏼\u{200d}♂", (EmojiId(1500), EmojiId(1498))),
+        ("🧣", (EmojiId(3031), EmojiId(3031))),
+        ("☢", (EmojiId(3311), EmojiId(3311))),
+        ("🚶🏽\u{200d}♀", (EmojiId(1543), EmojiId(1540))),
+        ("🧙🏽\u{200d}♀", (EmojiId(1410), EmojiId(1407))),
+        ("🫰🏾", (EmojiId(269), EmojiId(265))),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏿", (EmojiId(2151), EmojiId(2134))),
+        ("🚔", (EmojiId(2805), EmojiId(2805))),
+        ("🦹\u{200d}♂", (EmojiId(1383), EmojiId(1383))),
+        ("🪘", (EmojiId(3096), EmojiId(3096))),
+        ("🟪", (EmojiId(3491), EmojiId(3491))),
+        ("🚴🏿\u{200d}♂", (EmojiId(1952), EmojiId(1947))),
+        ("💁🏾\u{200d}♂", (EmojiId(745), EmojiId(741))),
+        ("🖖🏽", (EmojiId(196), EmojiId(193))),
+        ("🙇🏽", (EmojiId(792), EmojiId(789))),
+        ("🛼", (EmojiId(2824), EmojiId(2824))),
+        ("🎛", (EmojiId(3084), EmojiId(3084))),
+        ("🇹🇨", (EmojiId(3740), EmojiId(3740))),
+        ("🕵\u{200d}♂\u{fe0f}", (EmojiId(1155), EmojiId(1155))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2272), EmojiId(2264))),
+        ("🤵🏻\u{200d}♂", (EmojiId(1264), EmojiId(1263))),
+        ("☂", (EmojiId(2927), EmojiId(2927))),
+        ("🧖🏽", (EmojiId(1786), EmojiId(1783))),
+        ("8\u{20e3}", (EmojiId(3435), EmojiId(3435))),
+        ("🔼", (EmojiId(3370), EmojiId(3370))),
+        ("🙅", (EmojiId(699), EmojiId(699))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2279), EmojiId(2264))),
+        ("🏋🏿\u{200d}♀", (EmojiId(1940), EmojiId(1935))),
+        ("🧎🏾\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1610), EmojiId(1606))),
+        ("🥗", (EmojiId(2657), EmojiId(2657))),
+        ("🤹\u{200d}♂\u{fe0f}", (EmojiId(2040), EmojiId(2040))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2095), EmojiId(2082))),
+        ("🧑🏿\u{200d}🎓", (EmojiId(866), EmojiId(861))),
+        ("🛜", (EmojiId(3382), EmojiId(3382))),
+        ("♀", (EmojiId(3385), EmojiId(3385))),
+        ("🤹🏾\u{200d}♂\u{fe0f}", (EmojiId(2044), EmojiId(2040))),
+        ("🚵🏿\u{200d}♀\u{fe0f}", (EmojiId(1976), EmojiId(1971))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2083), EmojiId(2082))),
+        ("🧑🏽\u{200d}🏫", (EmojiId(882), EmojiId(879))),
+        ("🦹🏿\u{200d}♀", (EmojiId(1394), EmojiId(1389))),
+        ("👮🏻\u{200d}♀\u{fe0f}", (EmojiId(1144), EmojiId(1143))),
+        ("🕵🏼\u{200d}♂", (EmojiId(1157), EmojiId(1155))),
+        ("🦠", (EmojiId(2559), EmojiId(2559))),
+        ("👨🏼\u{200d}⚖", (EmojiId(905), EmojiId(903))),
+        ("🚴🏾\u{200d}♂\u{fe0f}", (EmojiId(1951), EmojiId(1947))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏼", (EmojiId(2348), EmojiId(2342))),
+        ("🇵🇼", (EmojiId(3710), EmojiId(3710))),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏾", (EmojiId(2146), EmojiId(2134))),
+        ("🖌", (EmojiId(3180), EmojiId(3180))),
+        ("🧎🏿\u{200d}♀", (EmojiId(1599), EmojiId(1594))),
+        ("🙇🏽\u{200d}♀\u{fe0f}", (EmojiId(804), EmojiId(801))),
+        ("👁\u{fe0f}\u{200d}🗨", (EmojiId(164), EmojiId(164))),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏿", (EmojiId(2143), EmojiId(2134))),
+        ("🛌🏼", (EmojiId(2078), EmojiId(2076))),
+        ("👩🏼\u{200d}🎤", (EmojiId(1055), EmojiId(1053))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2355), EmojiId(2342))),
+        ("🧑🏽\u{200d}🎓", (EmojiId(864), EmojiId(861))),
+        ("🗄", (EmojiId(3204), EmojiId(3204))),
+        ("🧑🏼", (EmojiId(521), EmojiId(519))),
+        ("🚬", (EmojiId(3278), EmojiId(3278))),
+        ("🤵🏿", (EmojiId(1262), EmojiId(1257))),
+        ("🕴🏻", (EmojiId(1775), EmojiId(1774))),
+        ("™", (EmojiId(3423), EmojiId(3423))),
+        ("🫸🏿", (EmojiId(234), EmojiId(229))),
+        ("🧑🏾\u{200d}🏫", (EmojiId(883), EmojiId(879))),
+        ("🤸🏾\u{200d}♀\u{fe0f}", (EmojiId(1993), EmojiId(1989))),
+        ("👷🏼\u{200d}♂", (EmojiId(1199), EmojiId(1197))),
+        ("🏌🏻\u{200d}♂", (EmojiId(1840), EmojiId(1839))),
+        ("🇸🇬", (EmojiId(3723), EmojiId(3723))),
+        ("🧘🏿\u{200d}♀\u{fe0f}", (EmojiId(2069), EmojiId(2064))),
+        ("😣", (EmojiId(96), EmojiId(96))),
+        ("🏌🏿\u{200d}♀", (EmojiId(1850), EmojiId(1845))),
+        ("🥒", (EmojiId(2616), EmojiId(2616))),
+        ("🧑\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1660), EmojiId(1660))),
+        ("🏃\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1756), EmojiId(1756))),
+        ("🏇🏿", (EmojiId(1825), EmojiId(1820))),
+        ("🌀", (EmojiId(2924), EmojiId(2924))),
+        ("♥\u{fe0f}", (EmojiId(3009), EmojiId(3009))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2233), EmojiId(2212))),
+        ("🥜", (EmojiId(2621), EmojiId(2621))),
+        ("🤴🏼", (EmojiId(1217), EmojiId(1215))),
+        ("🚣🏻\u{200d}♂", (EmojiId(1876), EmojiId(1875))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2087), EmojiId(2082))),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏻", (EmojiId(2126), EmojiId(2108))),
+        ("🏃🏻\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1751), EmojiId(1750))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2244), EmojiId(2238))),
+        ("📂", (EmojiId(3185), EmojiId(3185))),
+        ("🪃", (EmojiId(3220), EmojiId(3220))),
+        ("🐟", (EmojiId(2531), EmojiId(2531))),
+        ("👨🏻\u{200d}⚖", (EmojiId(904), EmojiId(903))),
+        ("👩🏽\u{200d}💼", (EmojiId(1002), EmojiId(999))),
+        ("🧎🏿\u{200d}♂\u{fe0f}", (EmojiId(1593), EmojiId(1588))),
+        ("🪜", (EmojiId(3237), EmojiId(3237))),
+        ("🚶🏻\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1553), EmojiId(1552))),
+        ("🇵🇷", (EmojiId(3707), EmojiId(3707))),
+        ("👢", (EmojiId(3057), EmojiId(3057))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2285), EmojiId(2264))),
+        ("🐱", (EmojiId(2442), EmojiId(2442))),
+        ("👩🏼\u{200d}🦲", (EmojiId(623), EmojiId(621))),
+        ("👨🏻\u{200d}💻", (EmojiId(1030), EmojiId(1029))),
+        ("🇸🇩", (EmojiId(3721), EmojiId(3721))),
+        ("🍉", (EmojiId(2591), EmojiId(2591))),
+        ("🏋\u{fe0f}", (EmojiId(1923), EmojiId(1923))),
+        ("🤷\u{200d}♀\u{fe0f}", (EmojiId(837), EmojiId(837))),
+        ("💜", (EmojiId(150), EmojiId(150))),
+        ("🌙", (EmojiId(2899), EmojiId(2899))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2378), EmojiId(2368))),
+        ("🧗🏻\u{200d}♂\u{fe0f}", (EmojiId(1808), EmojiId(1807))),
+        ("📯", (EmojiId(3076), EmojiId(3076))),
+        ("🤦🏿", (EmojiId(812), EmojiId(807))),
+        ("🧎🏿\u{200d}➡", (EmojiId(1605), EmojiId(1600))),
+        ("👨🏾\u{200d}🏫", (EmojiId(889), EmojiId(885))),
+        ("🇹🇯", (EmojiId(3745), EmojiId(3745))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏻", (EmojiId(2304), EmojiId(2290))),
+        ("👨\u{200d}🔧", (EmojiId(957), EmojiId(957))),
+        ("🤽🏻", (EmojiId(1999), EmojiId(1998))),
+        ("🤷🏻\u{200d}♂\u{fe0f}", (EmojiId(832), EmojiId(831))),
+        ("👨\u{200d}👧", (EmojiId(2411), EmojiId(2411))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2358), EmojiId(2342))),
+        ("👨🏽\u{200d}💻", (EmojiId(1032), EmojiId(1029))),
+        ("🚶🏻\u{200d}♀\u{200d}➡", (EmojiId(1553), EmojiId(1552))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2247), EmojiId(2238))),
+        ("🤱🏿", (EmojiId(1316), EmojiId(1311))),
+        ("🗂", (EmojiId(3186), EmojiId(3186))),
+        ("🛳\u{fe0f}", (EmojiId(2841), EmojiId(2841))),
+        ("💇🏽\u{200d}♂", (EmojiId(1519), EmojiId(1516))),
+        ("💇🏽\u{200d}♀\u{fe0f}", (EmojiId(1525), EmojiId(1522))),
+        ("🧕🏻", (EmojiId(1252), EmojiId(1251))),
+        ("🧑🏾\u{200d}🍼", (EmojiId(1333), EmojiId(1329))),
+        ("💦", (EmojiId(160), EmojiId(160))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2377), EmojiId(2368))),
+        ("🐚", (EmojiId(2536), EmojiId(2536))),
+        ("🧑🏾\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1628), EmojiId(1624))),
+        ("🧑🏽\u{200d}🍳", (EmojiId(936), EmojiId(933))),
+        ("👩\u{200d}🎨", (EmojiId(1071), EmojiId(1071))),
+        ("👝", (EmojiId(3046), EmojiId(3046))),
+        ("🧑🏻\u{200d}🔧", (EmojiId(952), EmojiId(951))),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏿", (EmojiId(2199), EmojiId(2186))),
+        ("🇩🇴", (EmojiId(3583), EmojiId(3583))),
+        ("🍄\u{200d}🟫", (EmojiId(2626), EmojiId(2626))),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏽", (EmojiId(2145), EmojiId(2134))),
+        ("🤚🏽", (EmojiId(178), EmojiId(175))),
+        ("🤹🏿", (EmojiId(2039), EmojiId(2034))),
+        ("🙌🏾", (EmojiId(377), EmojiId(373))),
+        ("👨🏾\u{200d}✈", (EmojiId(1087), EmojiId(1083))),
+        ("🃏", (EmojiId(3013), EmojiId(3013))),
+        ("🏃🏻\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1757), EmojiId(1756))),
+        ("🤵🏽\u{200d}♂", (EmojiId(1266), EmojiId(1263))),
+        ("🛗", (EmojiId(3254), EmojiId(3254))),
+        ("🇦🇪", (EmojiId(3521), EmojiId(3521))),
+        ("👩🏾\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1724), EmojiId(1720))),
+        ("🎣", (EmojiId(2986), EmojiId(2986))),
+        ("🇵🇱", (EmojiId(3704), EmojiId(3704))),
+        ("😔", (EmojiId(53), EmojiId(53))),
+        ("🚵🏿\u{200d}♂", (EmojiId(1970), EmojiId(1965))),
+        ("🧗🏿\u{200d}♂", (EmojiId(1812), EmojiId(1807))),
+        ("👩\u{200d}🦼\u{200d}➡", (EmojiId(1684), EmojiId(1684))),
+        ("🏃🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1758), EmojiId(1756))),
+        ("🧛🏻\u{200d}♀", (EmojiId(1444), EmojiId(1443))),
+        ("🫆", (EmojiId(2429), EmojiId(2429))),
+        ("🙍🏽\u{200d}♂", (EmojiId(672), EmojiId(669))),
+        ("👥", (EmojiId(2421), EmojiId(2421))),
+        ("👮🏻\u{200d}♂\u{fe0f}", (EmojiId(1138), EmojiId(1137))),
+        ("🚣🏼\u{200d}♀\u{fe0f}", (EmojiId(1883), EmojiId(1881))),
+        ("🥥", (EmojiId(2608), EmojiId(2608))),
+        ("🤽\u{200d}♂\u{fe0f}", (EmojiId(2004), EmojiId(2004))),
+        ("🪻", (EmojiId(2571), EmojiId(2571))),
+        ("🙍", (EmojiId(663), EmojiId(663))),
+        ("🤦\u{200d}♀", (EmojiId(819), EmojiId(819))),
+        ("🫰", (EmojiId(265), EmojiId(265))),
+        ("🧧", (EmojiId(2953), EmojiId(2953))),
+        ("👯\u{200d}♂", (EmojiId(1781), EmojiId(1781))),
+        ("👨🏾\u{200d}🦽\u{200d}➡", (EmojiId(1712), EmojiId(1708))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2253), EmojiId(2238))),
+        ("🕵\u{200d}♀\u{fe0f}", (EmojiId(1161), EmojiId(1161))),
+        ("🈂\u{fe0f}", (EmojiId(3461), EmojiId(3461))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏾", (EmojiId(2336), EmojiId(2316))),
+        ("🏃🏽\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1759), EmojiId(1756))),
+        ("👈🏾", (EmojiId(293), EmojiId(289))),
+        ("🚶🏼\u{200d}♀\u{200d}➡", (EmojiId(1554), EmojiId(1552))),
+        ("🧚🏻", (EmojiId(1414), EmojiId(1413))),
+        ("🧎🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1614), EmojiId(1612))),
+        ("🧞", (EmojiId(1485), EmojiId(1485))),
+        ("🧑🏽\u{200d}🍼", (EmojiId(1332), EmojiId(1329))),
+        ("🤜", (EmojiId(361), EmojiId(361))),
+        ("😖", (EmojiId(95), EmojiId(95))),
+        ("🇳🇨", (EmojiId(3686), EmojiId(3686))),
+        ("🧗🏼\u{200d}♂", (EmojiId(1809), EmojiId(1807))),
+        ("🫄🏾", (EmojiId(1309), EmojiId(1305))),
+        ("🔑", (EmojiId(3210), EmojiId(3210))),
+        ("💖", (EmojiId(133), EmojiId(133))),
+        ("📆", (EmojiId(3188), EmojiId(3188))),
+        ("👈🏻", (EmojiId(290), EmojiId(289))),
+        ("💏🏽", (EmojiId(2189), EmojiId(2186))),
+        ("♨\u{fe0f}", (EmojiId(2779), EmojiId(2779))),
+        ("🗄\u{fe0f}", (EmojiId(3204), EmojiId(3204))),
+        ("📒", (EmojiId(3144), EmojiId(3144))),
+        ("🛐", (EmojiId(3334), EmojiId(3334))),
+        ("🖖🏻", (EmojiId(194), EmojiId(193))),
+        ("🇫🇲", (EmojiId(3597), EmojiId(3597))),
+        ("🧍🏿", (EmojiId(1569), EmojiId(1564))),
+        ("🗝", (EmojiId(3211), EmojiId(3211))),
+        ("🧎🏼\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1608), EmojiId(1606))),
+        ("🧎🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1607), EmojiId(1606))),
+        ("👳🏽", (EmojiId(1230), EmojiId(1227))),
+        ("🕗", (EmojiId(2883), EmojiId(2883))),
+        ("🦨", (EmojiId(2492), EmojiId(2492))),
+        ("🤷🏽\u{200d}♀", (EmojiId(840), EmojiId(837))),
+        ("🕵🏾\u{200d}♀", (EmojiId(1165), EmojiId(1161))),
+        ("🤾", (EmojiId(2016), EmojiId(2016))),
+        ("🇬🇧", (EmojiId(3601), EmojiId(3601))),
+        ("🇲🇹", (EmojiId(3678), EmojiId(3678))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2092), EmojiId(2082))),
+        ("🏟", (EmojiId(2736), EmojiId(2736))),
+        ("♾\u{fe0f}", (EmojiId(3393), EmojiId(3393))),
+        ("🧛\u{200d}♂\u{fe0f}", (EmojiId(1437), EmojiId(1437))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2379), EmojiId(2368))),
+        ("🙆🏻\u{200d}♂", (EmojiId(724), EmojiId(723))),
+        ("👩🏿\u{200d}⚕\u{fe0f}", (EmojiId(860), EmojiId(855))),
+        ("👩🏻\u{200d}🦰", (EmojiId(586), EmojiId(585))),
+        ("🏄\u{200d}♂", (EmojiId(1857), EmojiId(1857))),
+        ("🤸🏾\u{200d}♂\u{fe0f}", (EmojiId(1987), EmojiId(1983))),
+        ("👨🏿\u{200d}🍼", (EmojiId(1328), EmojiId(1323))),
+        ("🪦", (EmojiId(3280), EmojiId(3280))),
+        ("🏌🏽", (EmojiId(1836), EmojiId(1833))),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏻", (EmojiId(2200), EmojiId(2186))),
+        ("🧑\u{200d}🦽\u{200d}➡", (EmojiId(1696), EmojiId(1696))),
+        ("🇱🇷", (EmojiId(3656), EmojiId(3656))),
+        ("⛹\u{fe0f}\u{200d}♀", (EmojiId(1917), EmojiId(1917))),
+        ("🗽", (EmojiId(2762), EmojiId(2762))),
+        ("🪳", (EmojiId(2552), EmojiId(2552))),
+        ("👩🏻\u{200d}🏭", (EmojiId(982), EmojiId(981))),
+        ("👨🏻\u{200d}✈\u{fe0f}", (EmojiId(1084), EmojiId(1083))),
+        ("🚶\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1558), EmojiId(1558))),
+        ("🕵🏽", (EmojiId(1152), EmojiId(1149))),
+        ("🧖🏿\u{200d}♀\u{fe0f}", (EmojiId(1800), EmojiId(1795))),
+        ("🧎\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1612), EmojiId(1612))),
+        ("🚫", (EmojiId(3303), EmojiId(3303))),
+        ("🧙🏽", (EmojiId(1398), EmojiId(1395))),
+        ("🇬🇼", (EmojiId(3617), EmojiId(3617))),
+        ("🫄🏿", (EmojiId(1310), EmojiId(1305))),
+        ("🤹\u{200d}♀\u{fe0f}", (EmojiId(2046), EmojiId(2046))),
+        ("🙅🏻\u{200d}♀\u{fe0f}", (EmojiId(712), EmojiId(711))),
+        ("7\u{20e3}", (EmojiId(3434), EmojiId(3434))),
+        ("🏋🏻\u{200d}♀", (EmojiId(1936), EmojiId(1935))),
+        ("👰🏿\u{200d}♀\u{fe0f}", (EmojiId(1292), EmojiId(1287))),
+        ("🏳\u{fe0f}\u{200d}⚧\u{fe0f}", (EmojiId(3517), EmojiId(3517))),
+        ("💇🏻", (EmojiId(1511), EmojiId(1510))),
+        ("🏄🏻\u{200d}♂", (EmojiId(1858), EmojiId(1857))),
+        ("🧝\u{200d}♀\u{fe0f}", (EmojiId(1479), EmojiId(1479))),
+        ("🗻", (EmojiId(2730), EmojiId(2730))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2257), EmojiId(2238))),
+        ("🦕", (EmojiId(2525), EmojiId(2525))),
+        ("🥺", (EmojiId(85), EmojiId(85))),
+        ("❌", (EmojiId(3413), EmojiId(3413))),
+        ("🤸🏽\u{200d}♀", (EmojiId(1992), EmojiId(1989))),
+        ("🧍🏾\u{200d}♂", (EmojiId(1574), EmojiId(1570))),
+        ("🙆🏼\u{200d}♂", (EmojiId(725), EmojiId(723))),
+        ("🤹🏼\u{200d}♂\u{fe0f}", (EmojiId(2042), EmojiId(2040))),
+        ("👊🏽", (EmojiId(352), EmojiId(349))),
+        ("🧚🏾\u{200d}♂\u{fe0f}", (EmojiId(1423), EmojiId(1419))),
+        ("🐗", (EmojiId(2463), EmojiId(2463))),
+        ("🧑🏼\u{200d}🦳", (EmojiId(617), EmojiId(615))),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏽", (EmojiId(2310), EmojiId(2290))),
+        ("🚶🏽\u{200d}♀\u{fe0f}", (EmojiId(1543), EmojiId(1540))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏿", (EmojiId(2299), EmojiId(2290))),
+        ("🙆🏿\u{200d}♀\u{fe0f}", (EmojiId(734), EmojiId(729))),
+        ("🧙🏿\u{200d}♂\u{fe0f}", (EmojiId(1406), EmojiId(1401))),
+        ("💪🏽", (EmojiId(450), EmojiId(447))),
+        ("🙆🏿", (EmojiId(722), EmojiId(717))),
+        ("🛅", (EmojiId(3299), EmojiId(3299))),
+        ("🪗", (EmojiId(3089), EmojiId(3089))),
+        ("🤵🏼", (EmojiId(1259), EmojiId(1257))),
+        ("🟦", (EmojiId(3490), EmojiId(3490))),
+        ("🚣🏻\u{200d}♀\u{fe0f}", (EmojiId(1882), EmojiId(1881))),
+        ("🏌🏿", (EmojiId(1838), EmojiId(1833))),
+        ("👈🏼", (EmojiId(291), EmojiId(289))),
+        ("🌠", (EmojiId(2910), EmojiId(2910))),
+        ("👋🏿", (EmojiId(174), EmojiId(169))),
+        ("🎑", (EmojiId(2952), EmojiId(2952))),
+        ("👩\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1684), EmojiId(1684))),
+        ("🌓", (EmojiId(2893), EmojiId(2893))),
+        ("🇻🇮", (EmojiId(3767), EmojiId(3767))),
+        ("🧋", (EmojiId(2709), EmojiId(2709))),
+        ("🙆🏼\u{200d}♀", (EmojiId(731), EmojiId(729))),
+        ("👩🏿\u{200d}🦽", (EmojiId(1719), EmojiId(1714))),
+        ("🫶🏾", (EmojiId(383), EmojiId(379))),
+        ("🚍", (EmojiId(2799), EmojiId(2799))),
+        ("🏐", (EmojiId(2969), EmojiId(2969))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", (EmojiId(2194), EmojiId(2186))),
+        ("🏃🏾\u{200d}♀", (EmojiId(1742), EmojiId(1738))),
+        ("🏃🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1755), EmojiId(1750))),
+        ("🤾🏿", (EmojiId(2021), EmojiId(2016))),
+        ("👰🏼", (EmojiId(1277), EmojiId(1275))),
+        ("🤟🏾", (EmojiId(275), EmojiId(271))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2221), EmojiId(2212))),
+        ("🧍🏻\u{200d}♀", (EmojiId(1577), EmojiId(1576))),
+        ("🏃🏽\u{200d}♀\u{200d}➡", (EmojiId(1753), EmojiId(1750))),
+        ("🧚", (EmojiId(1413), EmojiId(1413))),
+        ("🏌🏽\u{200d}♂", (EmojiId(1842), EmojiId(1839))),
+        ("💁🏻\u{200d}♂", (EmojiId(742), EmojiId(741))),
+        ("🧛\u{200d}♀", (EmojiId(1443), EmojiId(1443))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏼", (EmojiId(2387), EmojiId(2368))),
+        ("👲🏾", (EmojiId(1249), EmojiId(1245))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2287), EmojiId(2264))),
+        ("👷🏿\u{200d}♀", (EmojiId(1208), EmojiId(1203))),
+        ("🦣", (EmojiId(2473), EmojiId(2473))),
+        ("🧒🏻", (EmojiId(502), EmojiId(501))),
+        ("🚜", (EmojiId(2814), EmojiId(2814))),
+        ("👨\u{200d}👨\u{200d}👧\u{200d}👦", (EmojiId(2401), EmojiId(2401))),
+        ("🥎", (EmojiId(2967), EmojiId(2967))),
+        ("🕵\u{200d}♂", (EmojiId(1155), EmojiId(1155))),
+        ("🧑🏻\u{200d}💼", (EmojiId(988), EmojiId(987))),
+        ("🇺🇲", (EmojiId(3758), EmojiId(3758))),
+        ("🔇", (EmojiId(3070), EmojiId(3070))),
+        ("🔸", (EmojiId(3503), EmojiId(3503))),
+        ("👴🏽", (EmojiId(654), EmojiId(651))),
+        ("🔂", (EmojiId(3362), EmojiId(3362))),
+        ("🧑🏿\u{200d}🎨", (EmojiId(1064), EmojiId(1059))),
+        ("☑", (EmojiId(3411), EmojiId(3411))),
+        ("🇺🇿", (EmojiId(3762), EmojiId(3762))),
+        ("👮🏽\u{200d}♀", (EmojiId(1146), EmojiId(1143))),
+        ("🧑\u{200d}🧒\u{200d}🧒", (EmojiId(2427), EmojiId(2427))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏽", (EmojiId(2323), EmojiId(2316))),
+        ("🧈", (EmojiId(2659), EmojiId(2659))),
+        ("💪", (EmojiId(447), EmojiId(447))),
+        ("⏯", (EmojiId(3366), EmojiId(3366))),
+        ("👩🏽\u{200d}🦳", (EmojiId(612), EmojiId(609))),
+        ("🇧🇭", (EmojiId(3542), EmojiId(3542))),
+        ("🧛🏾\u{200d}♀\u{fe0f}", (EmojiId(1447), EmojiId(1443))),
+        ("💆\u{200d}♂\u{fe0f}", (EmojiId(1498), EmojiId(1498))),
+        ("🕵🏽\u{200d}♂", (EmojiId(1158), EmojiId(1155))),
+        ("👲🏼", (EmojiId(1247), EmojiId(1245))),
+        ("🏃🏾\u{200d}♂\u{fe0f}", (EmojiId(1736), EmojiId(1732))),
+        ("🫃🏻", (EmojiId(1300), EmojiId(1299))),
+        ("🚶🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1562), EmojiId(1558))),
+        ("👦🏽", (EmojiId(510), EmojiId(507))),
+        ("5\u{20e3}", (EmojiId(3432), EmojiId(3432))),
+        ("🤢", (EmojiId(61), EmojiId(61))),
+        ("▪", (EmojiId(3499), EmojiId(3499))),
+        ("🇲🇲", (EmojiId(3671), EmojiId(3671))),
+        ("👇🏾", (EmojiId(317), EmojiId(313))),
+        ("📸", (EmojiId(3126), EmojiId(3126))),
+        ("⚒\u{fe0f}", (EmojiId(3215), EmojiId(3215))),
+        ("🧝🏿\u{200d}♂", (EmojiId(1478), EmojiId(1473))),
+        ("👩🏽\u{200d}✈", (EmojiId(1092), EmojiId(1089))),
+        ("🚅", (EmojiId(2789), EmojiId(2789))),
+        ("🇹🇦", (EmojiId(3739), EmojiId(3739))),
+        ("🦵🏿", (EmojiId(460), EmojiId(455))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2106), EmojiId(2082))),
+        ("🤰🏽", (EmojiId(1296), EmojiId(1293))),
+        ("🤽🏼\u{200d}♂", (EmojiId(2006), EmojiId(2004))),
+        ("👨🏽\u{200d}🍳", (EmojiId(942), EmojiId(939))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2320), EmojiId(2316))),
+        ("🦵🏽", (EmojiId(458), EmojiId(455))),
+        ("🧑🏻\u{200d}🦱", (EmojiId(604), EmojiId(603))),
+        ("🤾🏻", (EmojiId(2017), EmojiId(2016))),
+        ("↗", (EmojiId(3314), EmojiId(3314))),
+        ("🐒", (EmojiId(2431), EmojiId(2431))),
+        ("🏄🏽", (EmojiId(1854), EmojiId(1851))),
+        ("☦", (EmojiId(3341), EmojiId(3341))),
+        ("🧘🏻\u{200d}♂\u{fe0f}", (EmojiId(2059), EmojiId(2058))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2218), EmojiId(2212))),
+        ("👩🏿\u{200d}🦯\u{200d}➡", (EmojiId(1653), EmojiId(1648))),
+        ("🙅\u{200d}♀\u{fe0f}", (EmojiId(711), EmojiId(711))),
+        ("🍨", (EmojiId(2681), EmojiId(2681))),
+        ("🫘", (EmojiId(2622), EmojiId(2622))),
+        ("❓", (EmojiId(3396), EmojiId(3396))),
+        ("🧝🏾\u{200d}♂\u{fe0f}", (EmojiId(1477), EmojiId(1473))),
+        ("🕹\u{fe0f}", (EmojiId(3000), EmojiId(3000))),
+        ("🙆🏾\u{200d}♂\u{fe0f}", (EmojiId(727), EmojiId(723))),
+        ("🙋🏼\u{200d}♂", (EmojiId(761), EmojiId(759))),
+        ("☹", (EmojiId(80), EmojiId(80))),
+        ("🌤", (EmojiId(2915), EmojiId(2915))),
+        ("👩🏿\u{200d}🔧", (EmojiId(968), EmojiId(963))),
+        ("🧝\u{200d}♂\u{fe0f}", (EmojiId(1473), EmojiId(1473))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2274), EmojiId(2264))),
+        ("👨🏽\u{200d}🍼", (EmojiId(1326), EmojiId(1323))),
+        ("👨\u{200d}🌾", (EmojiId(921), EmojiId(921))),
+        ("👨🏾\u{200d}🔬", (EmojiId(1015), EmojiId(1011))),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏼", (EmojiId(2153), EmojiId(2134))),
+        ("👩🏾\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1688), EmojiId(1684))),
+        ("🧔🏻\u{200d}♀", (EmojiId(550), EmojiId(549))),
+        ("🚣🏽\u{200d}♀", (EmojiId(1884), EmojiId(1881))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2214), EmojiId(2212))),
+        ("🚴\u{200d}♂", (EmojiId(1947), EmojiId(1947))),
+        ("🌨\u{fe0f}", (EmojiId(2919), EmojiId(2919))),
+        ("🌡", (EmojiId(2903), EmojiId(2903))),
+        ("🛄", (EmojiId(3298), EmojiId(3298))),
+        ("🏄🏿", (EmojiId(1856), EmojiId(1851))),
+        ("🧚🏻\u{200d}♂", (EmojiId(1420), EmojiId(1419))),
+        ("⛳", (EmojiId(2984), EmojiId(2984))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2230), EmojiId(2212))),
+        ("👃🏼", (EmojiId(481), EmojiId(479))),
+        ("🧑\u{200d}💻", (EmojiId(1023), EmojiId(1023))),
+        ("🙂\u{200d}↕", (EmojiId(51), EmojiId(51))),
+        ("🟨", (EmojiId(3488), EmojiId(3488))),
+        ("👵", (EmojiId(657), EmojiId(657))),
+        ("💇🏻\u{200d}♀\u{fe0f}", (EmojiId(1523), EmojiId(1522))),
+        ("©", (EmojiId(3421), EmojiId(3421))),
+        ("🧎🏽", (EmojiId(1585), EmojiId(1582))),
+        ("🈂", (EmojiId(3461), EmojiId(3461))),
+        ("⛴\u{fe0f}", (EmojiId(2842), EmojiId(2842))),
+        ("🤘", (EmojiId(277), EmojiId(277))),
+        ("🤯", (EmojiId(69), EmojiId(69))),
+        ("🧲", (EmojiId(3236), EmojiId(3236))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏿", (EmojiId(2307), EmojiId(2290))),
+        ("👩🏾\u{200d}✈", (EmojiId(1093), EmojiId(1089))),
+        ("🤸🏼\u{200d}♀\u{fe0f}", (EmojiId(1991), EmojiId(1989))),
+        ("👳🏼\u{200d}♀", (EmojiId(1241), EmojiId(1239))),
+        ("⏱\u{fe0f}", (EmojiId(2864), EmojiId(2864))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2245), EmojiId(2238))),
+        ("👩\u{200d}👩\u{200d}👦", (EmojiId(2404), EmojiId(2404))),
+        ("🧎🏾\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1610), EmojiId(1606))),
+        ("🫱🏽\u{200d}🫲🏿", (EmojiId(414), EmojiId(397))),
+        ("👴🏿", (EmojiId(656), EmojiId(651))),
+        ("🫶🏽", (EmojiId(382), EmojiId(379))),
+        ("🍝", (EmojiId(2668), EmojiId(2668))),
+        ("⬇\u{fe0f}", (EmojiId(3317), EmojiId(3317))),
+        ("🧑🏿\u{200d}⚖\u{fe0f}", (EmojiId(902), EmojiId(897))),
+        ("🤦🏾\u{200d}♀", (EmojiId(823), EmojiId(819))),
+        ("👩🏾\u{200d}🦽\u{200d}➡", (EmojiId(1724), EmojiId(1720))),
+        ("👦🏼", (EmojiId(509), EmojiId(507))),
+        ("🚶🏽", (EmojiId(1531), EmojiId(1528))),
+        ("👨🏻\u{200d}⚕\u{fe0f}", (EmojiId(850), EmojiId(849))),
+        ("🏃🏽\u{200d}♂", (EmojiId(1735), EmojiId(1732))),
+        ("🌐", (EmojiId(2723), EmojiId(2723))),
+        ("🤛🏼", (EmojiId(357), EmojiId(355))),
+        ("🛌🏾", (EmojiId(2080), EmojiId(2076))),
+        ("🧝🏾\u{200d}♀", (EmojiId(1483), EmojiId(1479))),
+        ("📹", (EmojiId(3127), EmojiId(3127))),
+        ("🐖", (EmojiId(2462), EmojiId(2462))),
+        ("👨\u{200d}❤\u{200d}💋\u{200d}👨", (EmojiId(2238), EmojiId(2238))),
+        ("👰🏼\u{200d}♂\u{fe0f}", (EmojiId(1283), EmojiId(1281))),
+        ("👳🏽\u{200d}♀", (EmojiId(1242), EmojiId(1239))),
+        ("🏳\u{fe0f}\u{200d}⚧", (EmojiId(3517), EmojiId(3517))),
+        ("💆🏽\u{200d}♀", (EmojiId(1507), EmojiId(1504))),
+        ("👱", (EmojiId(525), EmojiId(525))),
+        ("🧑🏾\u{200d}🌾", (EmojiId(919), EmojiId(915))),
+        ("🚵🏽\u{200d}♀", (EmojiId(1974), EmojiId(1971))),
+        ("🇺🇬", (EmojiId(3757), EmojiId(3757))),
+        ("🚵🏻\u{200d}♂\u{fe0f}", (EmojiId(1966), EmojiId(1965))),
+        ("🙌🏼", (EmojiId(375), EmojiId(373))),
+        ("🤳🏾", (EmojiId(445), EmojiId(441))),
+        ("⚜", (EmojiId(3405), EmojiId(3405))),
+        ("🏋🏻\u{200d}♂\u{fe0f}", (EmojiId(1930), EmojiId(1929))),
+        ("🔘", (EmojiId(3508), EmojiId(3508))),
+        ("🙄", (EmojiId(45), EmojiId(45))),
+        ("🪏", (EmojiId(3238), EmojiId(3238))),
+        ("🧑\u{200d}✈", (EmojiId(1077), EmojiId(1077))),
+        ("🛝", (EmojiId(2781), EmojiId(2781))),
+        ("👫🏻", (EmojiId(2135), EmojiId(2134))),
+        ("🙅\u{200d}♂", (EmojiId(705), EmojiId(705))),
+        ("🗡\u{fe0f}", (EmojiId(3217), EmojiId(3217))),
+        ("🎉", (EmojiId(2945), EmojiId(2945))),
+        ("♂", (EmojiId(3386), EmojiId(3386))),
+        ("🔲", (EmojiId(3510), EmojiId(3510))),
+        ("🦹\u{200d}♀\u{fe0f}", (EmojiId(1389), EmojiId(1389))),
+        ("🗒\u{fe0f}", (EmojiId(3189), EmojiId(3189))),
+        ("🚶🏻\u{200d}➡\u{fe0f}", (EmojiId(1547), EmojiId(1546))),
+        ("🦜", (EmojiId(2513), EmojiId(2513))),
+        ("🤸🏼\u{200d}♀", (EmojiId(1991), EmojiId(1989))),
+        ("🧟", (EmojiId(1488), EmojiId(1488))),
+        ("👨🏻\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1673), EmojiId(1672))),
+        ("🤩", (EmojiId(16), EmojiId(16))),
+        ("🤷🏿\u{200d}♂\u{fe0f}", (EmojiId(836), EmojiId(831))),
+        ("🚶🏼\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1560), EmojiId(1558))),
+        ("👨\u{200d}🔬", (EmojiId(1011), EmojiId(1011))),
+        ("🔢", (EmojiId(3440), EmojiId(3440))),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏽", (EmojiId(2171), EmojiId(2160))),
+        ("🛻", (EmojiId(2811), EmojiId(2811))),
+        ("👨\u{200d}🦼\u{200d}➡", (EmojiId(1672), EmojiId(1672))),
+        ("🇲🇺", (EmojiId(3679), EmojiId(3679))),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏽", (EmojiId(2180), EmojiId(2160))),
+        ("🏋🏽\u{200d}♂\u{fe0f}", (EmojiId(1932), EmojiId(1929))),
+        ("👩🏽\u{200d}🎨", (EmojiId(1074), EmojiId(1071))),
+        ("🇿🇦", (EmojiId(3775), EmojiId(3775))),
+        ("🙇🏾\u{200d}♂\u{fe0f}", (EmojiId(799), EmojiId(795))),
+        ("👨\u{200d}🍼", (EmojiId(1323), EmojiId(1323))),
+        ("🧝🏿\u{200d}♀", (EmojiId(1484), EmojiId(1479))),
+        ("☝🏾", (EmojiId(323), EmojiId(319))),
+        ("💐", (EmojiId(2560), EmojiId(2560))),
+        ("🏂🏿", (EmojiId(1832), EmojiId(1827))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2322), EmojiId(2316))),
+        ("👨🏿\u{200d}🦲", (EmojiId(578), EmojiId(573))),
+        ("🤽🏾", (EmojiId(2002), EmojiId(1998))),
+        ("〽\u{fe0f}", (EmojiId(3417), EmojiId(3417))),
+        ("😙", (EmojiId(21), EmojiId(21))),
+        ("🧏", (EmojiId(771), EmojiId(771))),
+        ("🏜\u{fe0f}", (EmojiId(2733), EmojiId(2733))),
+        ("3\u{20e3}", (EmojiId(3430), EmojiId(3430))),
+        ("👩🏾\u{200d}🔧", (EmojiId(967), EmojiId(963))),
+        ("🇷🇪", (EmojiId(3713), EmojiId(3713))),
+        ("⏪", (EmojiId(3368), EmojiId(3368))),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏽", (EmojiId(2154), EmojiId(2134))),
+        ("👎🏾", (EmojiId(341), EmojiId(337))),
+        ("👨🏽\u{200d}🎓", (EmojiId(870), EmojiId(867))),
+        ("👷🏽\u{200d}♂\u{fe0f}", (EmojiId(1200), EmojiId(1197))),
+        ("🕙", (EmojiId(2887), EmojiId(2887))),
+        ("👨🏾\u{200d}🚀", (EmojiId(1105), EmojiId(1101))),
+        ("🧗🏽", (EmojiId(1804), EmojiId(1801))),
+        ("👮\u{200d}♀\u{fe0f}", (EmojiId(1143), EmojiId(1143))),
+        ("👨🏿\u{200d}🏭", (EmojiId(980), EmojiId(975))),
+        ("🏌🏼\u{200d}♀", (EmojiId(1847), EmojiId(1845))),
+        ("🇵🇾", (EmojiId(3711), EmojiId(3711))),
+        ("🧝🏽\u{200d}♂", (EmojiId(1476), EmojiId(1473))),
+        ("📳", (EmojiId(3383), EmojiId(3383))),
+        ("🚵🏼\u{200d}♀\u{fe0f}", (EmojiId(1973), EmojiId(1971))),
+        ("🖤", (EmojiId(152), EmojiId(152))),
+        ("🎾", (EmojiId(2972), EmojiId(2972))),
+        ("👨🏻\u{200d}🎨", (EmojiId(1066), EmojiId(1065))),
+        ("👨\u{200d}👩\u{200d}👧", (EmojiId(2395), EmojiId(2395))),
+        ("👩🏿\u{200d}🦲", (EmojiId(626), EmojiId(621))),
+        ("🏚\u{fe0f}", (EmojiId(2744), EmojiId(2744))),
+        ("🇧🇯", (EmojiId(3544), EmojiId(3544))),
+        ("🤼\u{200d}♀", (EmojiId(1997), EmojiId(1997))),
+        ("🚵🏽", (EmojiId(1962), EmojiId(1959))),
+        ("🤵\u{200d}♀", (EmojiId(1269), EmojiId(1269))),
+        ("☄\u{fe0f}", (EmojiId(2934), EmojiId(2934))),
+        ("🆔", (EmojiId(3450), EmojiId(3450))),
+        ("🚣\u{200d}♀\u{fe0f}", (EmojiId(1881), EmojiId(1881))),
+        ("↗\u{fe0f}", (EmojiId(3314), EmojiId(3314))),
+        ("👱🏽\u{200d}♀\u{fe0f}", (EmojiId(636), EmojiId(633))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2107), EmojiId(2082))),
+        ("🚴🏼\u{200d}♀", (EmojiId(1955), EmojiId(1953))),
+        ("👨🏾", (EmojiId(535), EmojiId(531))),
+        ("📊", (EmojiId(3194), EmojiId(3194))),
+        ("✳", (EmojiId(3418), EmojiId(3418))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2276), EmojiId(2264))),
+        ("💟", (EmojiId(138), EmojiId(138))),
+        ("🥘", (EmojiId(2653), EmojiId(2653))),
+        ("🏖\u{fe0f}", (EmojiId(2732), EmojiId(2732))),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏼", (EmojiId(2179), EmojiId(2160))),
+        ("👳\u{200d}♂\u{fe0f}", (EmojiId(1233), EmojiId(1233))),
+        ("🤷🏼\u{200d}♀", (EmojiId(839), EmojiId(837))),
+        ("🪨", (EmojiId(2740), EmojiId(2740))),
+        ("🏳\u{fe0f}", (EmojiId(3515), EmojiId(3515))),
+        ("🩵", (EmojiId(149), EmojiId(149))),
+        ("🌉", (EmojiId(2778), EmojiId(2778))),
+        ("🤽🏻\u{200d}♀\u{fe0f}", (EmojiId(2011), EmojiId(2010))),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏿", (EmojiId(2311), EmojiId(2290))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏽", (EmojiId(2357), EmojiId(2342))),
+        ("🧑\u{200d}🌾", (EmojiId(915), EmojiId(915))),
+        ("🛠", (EmojiId(3216), EmojiId(3216))),
+        ("🏌🏾\u{200d}♂", (EmojiId(1843), EmojiId(1839))),
+        ("🖕🏼", (EmojiId(309), EmojiId(307))),
+        ("✴\u{fe0f}", (EmojiId(3419), EmojiId(3419))),
+        ("🖖", (EmojiId(193), EmojiId(193))),
+        ("👩🏿\u{200d}🦯", (EmojiId(1647), EmojiId(1642))),
+        ("👬", (EmojiId(2160), EmojiId(2160))),
+        ("💑", (EmojiId(2290), EmojiId(2290))),
+        ("💃🏾", (EmojiId(1766), EmojiId(1762))),
+        ("🛋\u{fe0f}", (EmojiId(3258), EmojiId(3258))),
+        ("👩🏾\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1652), EmojiId(1648))),
+        ("🍱", (EmojiId(2662), EmojiId(2662))),
+        ("👨🏻\u{200d}🔧", (EmojiId(958), EmojiId(957))),
+        ("🏍", (EmojiId(2816), EmojiId(2816))),
+        ("🛡\u{fe0f}", (EmojiId(3222), EmojiId(3222))),
+        ("🧑🏿\u{200d}🦯", (EmojiId(1623), EmojiId(1618))),
+        ("♂\u{fe0f}", (EmojiId(3386), EmojiId(3386))),
+        ("🙎🏻\u{200d}♀", (EmojiId(694), EmojiId(693))),
+        ("🕓", (EmojiId(2875), EmojiId(2875))),
+        ("🏋\u{fe0f}\u{200d}♀", (EmojiId(1935), EmojiId(1935))),
+        ("😝", (EmojiId(27), EmojiId(27))),
+        ("♑", (EmojiId(3356), EmojiId(3356))),
+        ("🏄\u{200d}♀\u{fe0f}", (EmojiId(1863), EmojiId(1863))),
+        ("🏌\u{fe0f}\u{200d}♂\u{fe0f}", (EmojiId(1839), EmojiId(1839))),
+        ("🏌🏾\u{200d}♀", (EmojiId(1849), EmojiId(1845))),
+        ("👮🏻", (EmojiId(1132), EmojiId(1131))),
+        ("⌛", (EmojiId(2860), EmojiId(2860))),
+        ("📬", (EmojiId(3172), EmojiId(3172))),
+        ("💿", (EmojiId(3117), EmojiId(3117))),
+        ("🕵🏿\u{200d}♂", (EmojiId(1160), EmojiId(1155))),
+        ("🚴🏻\u{200d}♂\u{fe0f}", (EmojiId(1948), EmojiId(1947))),
+        ("🍿", (EmojiId(2658), EmojiId(2658))),
+        ("👩🏼\u{200d}🦯\u{200d}➡", (EmojiId(1650), EmojiId(1648))),
+        ("🙅🏼\u{200d}♀\u{fe0f}", (EmojiId(713), EmojiId(711))),
+        ("🦹🏿\u{200d}♀\u{fe0f}", (EmojiId(1394), EmojiId(1389))),
+        ("6\u{fe0f}\u{20e3}", (EmojiId(3433), EmojiId(3433))),
+        ("👩🏾\u{200d}🎤", (EmojiId(1057), EmojiId(1053))),
+        ("🏊🏽\u{200d}♂", (EmojiId(1896), EmojiId(1893))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2333), EmojiId(2316))),
+        ("🧑\u{200d}🦳", (EmojiId(615), EmojiId(615))),
+        ("🚶", (EmojiId(1528), EmojiId(1528))),
+        ("🛵", (EmojiId(2817), EmojiId(2817))),
+        ("🧘🏼", (EmojiId(2054), EmojiId(2052))),
+        ("👨\u{200d}👦", (EmojiId(2409), EmojiId(2409))),
+        ("🧺", (EmojiId(3269), EmojiId(3269))),
+        ("🤸\u{200d}♂\u{fe0f}", (EmojiId(1983), EmojiId(1983))),
+        ("↪", (EmojiId(3324), EmojiId(3324))),
+        ("🤝🏿", (EmojiId(402), EmojiId(397))),
+        ("👮🏼", (EmojiId(1133), EmojiId(1131))),
+        ("👰🏼\u{200d}♀", (EmojiId(1289), EmojiId(1287))),
+        ("🥅", (EmojiId(2983), EmojiId(2983))),
+        ("👨🏾\u{200d}🦽", (EmojiId(1706), EmojiId(1702))),
+        ("🧟\u{200d}♂", (EmojiId(1489), EmojiId(1489))),
+        ("👵🏻", (EmojiId(658), EmojiId(657))),
+        ("🐦\u{200d}⬛", (EmojiId(2515), EmojiId(2515))),
+        ("👨🏾\u{200d}🦯", (EmojiId(1634), EmojiId(1630))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2230), EmojiId(2212))),
+        ("🅾", (EmojiId(3454), EmojiId(3454))),
+        ("🖥", (EmojiId(3110), EmojiId(3110))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2255), EmojiId(2238))),
+        ("🧜🏽\u{200d}♀", (EmojiId(1464), EmojiId(1461))),
+        ("🦹🏼\u{200d}♂", (EmojiId(1385), EmojiId(1383))),
+        ("🫏", (EmojiId(2451), EmojiId(2451))),
+        ("🍳", (EmojiId(2652), EmojiId(2652))),
+        ("🙎🏼\u{200d}♂", (EmojiId(689), EmojiId(687))),
+        ("👩🏻\u{200d}🦼", (EmojiId(1679), EmojiId(1678))),
+        ("👩🏼\u{200d}🔬", (EmojiId(1019), EmojiId(1017))),
+        ("📖", (EmojiId(3138), EmojiId(3138))),
+        ("🐏", (EmojiId(2465), EmojiId(2465))),
+        ("🧚🏽", (EmojiId(1416), EmojiId(1413))),
+        ("♦\u{fe0f}", (EmojiId(3010), EmojiId(3010))),
+        ("🧛🏾\u{200d}♂\u{fe0f}", (EmojiId(1441), EmojiId(1437))),
+        ("🤙🏾", (EmojiId(287), EmojiId(283))),
+        ("🌷", (EmojiId(2570), EmojiId(2570))),
+        ("🚻", (EmojiId(3293), EmojiId(3293))),
+        ("🚞", (EmojiId(2796), EmojiId(2796))),
+        ("🕘", (EmojiId(2885), EmojiId(2885))),
+        ("👩\u{200d}🦼", (EmojiId(1678), EmojiId(1678))),
+        ("🧎🏼\u{200d}♀", (EmojiId(1596), EmojiId(1594))),
+        ("🪆", (EmojiId(3007), EmojiId(3007))),
+        ("🌦\u{fe0f}", (EmojiId(2917), EmojiId(2917))),
+        ("👷🏾\u{200d}♀\u{fe0f}", (EmojiId(1207), EmojiId(1203))),
+        ("✊🏽", (EmojiId(346), EmojiId(343))),
+        ("🐡", (EmojiId(2533), EmojiId(2533))),
+        ("🏃\u{200d}➡", (EmojiId(1744), EmojiId(1744))),
+        ("💁🏾\u{200d}♂\u{fe0f}", (EmojiId(745), EmojiId(741))),
+        ("💂🏼", (EmojiId(1169), EmojiId(1167))),
+        ("🧏🏼\u{200d}♂", (EmojiId(779), EmojiId(777))),
+        ("🏋🏿\u{200d}♂", (EmojiId(1934), EmojiId(1929))),
+        ("🧏🏼\u{200d}♀\u{fe0f}", (EmojiId(785), EmojiId(783))),
+        ("😹", (EmojiId(120), EmojiId(120))),
+        ("🦂", (EmojiId(2555), EmojiId(2555))),
+        ("🪈", (EmojiId(3098), EmojiId(3098))),
+        ("👨🏽\u{200d}🔧", (EmojiId(960), EmojiId(957))),
+        ("👩🏽\u{200d}🦽\u{200d}➡", (EmojiId(1723), EmojiId(1720))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏽", (EmojiId(2297), EmojiId(2290))),
+        ("👱🏽\u{200d}♂", (EmojiId(642), EmojiId(639))),
+        ("⛷\u{fe0f}", (EmojiId(1826), EmojiId(1826))),
+        ("🏄", (EmojiId(1851), EmojiId(1851))),
+        ("✏", (EmojiId(3176), EmojiId(3176))),
+        ("💂🏽\u{200d}♀\u{fe0f}", (EmojiId(1182), EmojiId(1179))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2262), EmojiId(2238))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2254), EmojiId(2238))),
+        ("🦮", (EmojiId(2436), EmojiId(2436))),
+        ("🧑🏼\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1698), EmojiId(1696))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏿", (EmojiId(2329), EmojiId(2316))),
+        ("♊", (EmojiId(3349), EmojiId(3349))),
+        ("🤵🏿\u{200d}♀\u{fe0f}", (EmojiId(1274), EmojiId(1269))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏽", (EmojiId(2388), EmojiId(2368))),
+        ("👩🏾\u{200d}🍼", (EmojiId(1321), EmojiId(1317))),
+        ("🩰", (EmojiId(3056), EmojiId(3056))),
+        ("👨🏽\u{200d}🏫", (EmojiId(888), EmojiId(885))),
+        ("🧔🏼", (EmojiId(539), EmojiId(537))),
+        ("✳\u{fe0f}", (EmojiId(3418), EmojiId(3418))),
+        ("🚡", (EmojiId(2854), EmojiId(2854))),
+        ("🦞", (EmojiId(2540), EmojiId(2540))),
+        ("🇶🇦", (EmojiId(3712), EmojiId(3712))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏻", (EmojiId(2378), EmojiId(2368))),
+        ("🙋🏿\u{200d}♂", (EmojiId(764), EmojiId(759))),
+        ("🏋\u{fe0f}\u{200d}♂\u{fe0f}", (EmojiId(1929), EmojiId(1929))),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏼", (EmojiId(2140), EmojiId(2134))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏾", (EmojiId(2362), EmojiId(2342))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏾", (EmojiId(2354), EmojiId(2342))),
+        ("🧖🏾\u{200d}♂\u{fe0f}", (EmojiId(1793), EmojiId(1789))),
+        ("🧛🏼\u{200d}♂", (EmojiId(1439), EmojiId(1437))),
+        ("🇽🇰", (EmojiId(3772), EmojiId(3772))),
+        ("🤵🏿\u{200d}♂\u{fe0f}", (EmojiId(1268), EmojiId(1263))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2338), EmojiId(2316))),
+        ("🚴🏽", (EmojiId(1944), EmojiId(1941))),
+        ("🤽🏾\u{200d}♀", (EmojiId(2014), EmojiId(2010))),
+        ("🧗\u{200d}♀", (EmojiId(1813), EmojiId(1813))),
+        ("👸🏾", (EmojiId(1225), EmojiId(1221))),
+        ("🙍🏽\u{200d}♂\u{fe0f}", (EmojiId(672), EmojiId(669))),
+        ("🇧🇼", (EmojiId(3554), EmojiId(3554))),
+        ("🧑🏻\u{200d}🦽", (EmojiId(1691), EmojiId(1690))),
+        ("🏃🏾\u{200d}➡\u{fe0f}", (EmojiId(1748), EmojiId(1744))),
+        ("🦚", (EmojiId(2512), EmojiId(2512))),
+        ("👂🏼", (EmojiId(469), EmojiId(467))),
+        ("🖱\u{fe0f}", (EmojiId(3113), EmojiId(3113))),
+        ("🧜🏻", (EmojiId(1450), EmojiId(1449))),
+        ("🚶\u{200d}♀\u{200d}➡", (EmojiId(1552), EmojiId(1552))),
+        ("🧜🏿\u{200d}♀\u{fe0f}", (EmojiId(1466), EmojiId(1461))),
+        ("🧑\u{200d}🤝\u{200d}🧑", (EmojiId(2082), EmojiId(2082))),
+        ("🚵🏼\u{200d}♂", (EmojiId(1967), EmojiId(1965))),
+        ("☺\u{fe0f}", (EmojiId(19), EmojiId(19))),
+        ("👨🏿\u{200d}⚕", (EmojiId(854), EmojiId(849))),
+        ("🥷🏿", (EmojiId(1190), EmojiId(1185))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏻", (EmojiId(2390), EmojiId(2368))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2251), EmojiId(2238))),
+        ("🅰", (EmojiId(3443), EmojiId(3443))),
+        ("🧑🏾\u{200d}⚕", (EmojiId(847), EmojiId(843))),
+        ("🥳", (EmojiId(71), EmojiId(71))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2280), EmojiId(2264))),
+        ("🤵🏾", (EmojiId(1261), EmojiId(1257))),
+        ("🤷🏼\u{200d}♂", (EmojiId(833), EmojiId(831))),
+        ("🛶", (EmojiId(2839), EmojiId(2839))),
+        ("☃\u{fe0f}", (EmojiId(2932), EmojiId(2932))),
+        ("🪰", (EmojiId(2557), EmojiId(2557))),
+        ("😄", (EmojiId(2), EmojiId(2))),
+        ("🥓", (EmojiId(2640), EmojiId(2640))),
+        ("✴", (EmojiId(3419), EmojiId(3419))),
+        ("🟧", (EmojiId(3487), EmojiId(3487))),
+        ("🪽", (EmojiId(2514), EmojiId(2514))),
+        ("🧍🏾\u{200d}♂\u{fe0f}", (EmojiId(1574), EmojiId(1570))),
+        ("🇬🇷", (EmojiId(3613), EmojiId(3613))),
+        ("🦹🏾", (EmojiId(1381), EmojiId(1377))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", (EmojiId(2205), EmojiId(2186))),
+        ("👬🏽", (EmojiId(2163), EmojiId(2160))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏽", (EmojiId(2349), EmojiId(2342))),
+        ("🧑🏼\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1662), EmojiId(1660))),
+        ("🧑🏽\u{200d}⚖\u{fe0f}", (EmojiId(900), EmojiId(897))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2232), EmojiId(2212))),
+        ("💂🏼\u{200d}♀", (EmojiId(1181), EmojiId(1179))),
+        ("🔟", (EmojiId(3437), EmojiId(3437))),
+        ("💂🏽", (EmojiId(1170), EmojiId(1167))),
+        ("🎍", (EmojiId(2948), EmojiId(2948))),
+        ("🙅🏽\u{200d}♂\u{fe0f}", (EmojiId(708), EmojiId(705))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2232), EmojiId(2212))),
+        ("🧎\u{200d}♀\u{200d}➡", (EmojiId(1606), EmojiId(1606))),
+        ("🧑\u{200d}🧑\u{200d}🧒\u{200d}🧒", (EmojiId(2425), EmojiId(2425))),
+        ("🧶", (EmojiId(3021), EmojiId(3021))),
+        ("🖐🏻", (EmojiId(182), EmojiId(181))),
+        ("🖐🏾", (EmojiId(185), EmojiId(181))),
+        ("🧑🏿\u{200d}✈", (EmojiId(1082), EmojiId(1077))),
+        ("🌸", (EmojiId(2561), EmojiId(2561))),
+        ("🏃🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1760), EmojiId(1756))),
+        ("🤧", (EmojiId(63), EmojiId(63))),
+        ("🤝🏾", (EmojiId(401), EmojiId(397))),
+        ("🧚🏼", (EmojiId(1415), EmojiId(1413))),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏼", (EmojiId(2131), EmojiId(2108))),
+        ("👨🏽\u{200d}🎨", (EmojiId(1068), EmojiId(1065))),
+        ("👁\u{200d}🗨\u{fe0f}", (EmojiId(164), EmojiId(164))),
+        ("🥉", (EmojiId(2964), EmojiId(2964))),
+        ("🎟", (EmojiId(2957), EmojiId(2957))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏿", (EmojiId(2377), EmojiId(2368))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏼", (EmojiId(2309), EmojiId(2290))),
+        ("👩🏾\u{200d}🦱", (EmojiId(601), EmojiId(597))),
+        ("😌", (EmojiId(52), EmojiId(52))),
+        ("🪫", (EmojiId(3107), EmojiId(3107))),
+        ("👷🏿\u{200d}♂\u{fe0f}", (EmojiId(1202), EmojiId(1197))),
+        ("🦸🏽", (EmojiId(1362), EmojiId(1359))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2227), EmojiId(2212))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏻", (EmojiId(2360), EmojiId(2342))),
+        ("⏹", (EmojiId(3375), EmojiId(3375))),
+        ("🙍🏻\u{200d}♂\u{fe0f}", (EmojiId(670), EmojiId(669))),
+        ("🦺", (EmojiId(3027), EmojiId(3027))),
+        ("🗒", (EmojiId(3189), EmojiId(3189))),
+        ("🤐", (EmojiId(36), EmojiId(36))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏻", (EmojiId(2338), EmojiId(2316))),
+        ("🧎🏾\u{200d}➡", (EmojiId(1604), EmojiId(1600))),
+        ("🇧🇷", (EmojiId(3550), EmojiId(3550))),
+        ("🖐", (EmojiId(181), EmojiId(181))),
+        ("🚶🏼\u{200d}♂", (EmojiId(1536), EmojiId(1534))),
+        ("🧝🏿", (EmojiId(1472), EmojiId(1467))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2381), EmojiId(2368))),
+        ("🇴🇲", (EmojiId(3697), EmojiId(3697))),
+        ("🧬", (EmojiId(3242), EmojiId(3242))),
+        ("🏋🏼\u{200d}♀", (EmojiId(1937), EmojiId(1935))),
+        ("🇸🇱", (EmojiId(3728), EmojiId(3728))),
+        ("🗨\u{fe0f}", (EmojiId(165), EmojiId(165))),
+        ("🤛🏽", (EmojiId(358), EmojiId(355))),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏻", (EmojiId(2196), EmojiId(2186))),
+        ("🧘🏾\u{200d}♂", (EmojiId(2062), EmojiId(2058))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2223), EmojiId(2212))),
+        ("🇲🇭", (EmojiId(3668), EmojiId(3668))),
+        ("🧝🏻\u{200d}♂", (EmojiId(1474), EmojiId(1473))),
+        ("👩🏾\u{200d}💼", (EmojiId(1003), EmojiId(999))),
+        ("🦸🏿\u{200d}♀", (EmojiId(1376), EmojiId(1371))),
+        ("🐪", (EmojiId(2468), EmojiId(2468))),
+        ("🧖🏻\u{200d}♂\u{fe0f}", (EmojiId(1790), EmojiId(1789))),
+        ("🧍🏾\u{200d}♀", (EmojiId(1580), EmojiId(1576))),
+        ("🇦🇿", (EmojiId(3535), EmojiId(3535))),
+        ("🧙\u{200d}♀", (EmojiId(1407), EmojiId(1407))),
+        ("🎴", (EmojiId(3015), EmojiId(3015))),
+        ("🧗🏽\u{200d}♂\u{fe0f}", (EmojiId(1810), EmojiId(1807))),
+        ("🧚\u{200d}♂", (EmojiId(1419), EmojiId(1419))),
+        ("🫳🏻", (EmojiId(212), EmojiId(211))),
+        ("🧎🏼\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1614), EmojiId(1612))),
+        ("🕺🏽", (EmojiId(1771), EmojiId(1768))),
+        ("🚣🏼\u{200d}♀", (EmojiId(1883), EmojiId(1881))),
+        ("🧖🏼\u{200d}♂", (EmojiId(1791), EmojiId(1789))),
+        ("🏌🏽\u{200d}♀\u{fe0f}", (EmojiId(1848), EmojiId(1845))),
+        ("🚴\u{200d}♀\u{fe0f}", (EmojiId(1953), EmojiId(1953))),
+        ("👧", (EmojiId(513), EmojiId(513))),
+        ("🫵🏿", (EmojiId(330), EmojiId(325))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2387), EmojiId(2368))),
+        ("Ⓜ", (EmojiId(3451), EmojiId(3451))),
+        ("🙍🏻\u{200d}♀", (EmojiId(676), EmojiId(675))),
+        ("🇦🇱", (EmojiId(3525), EmojiId(3525))),
+        ("🏄🏿\u{200d}♀\u{fe0f}", (EmojiId(1868), EmojiId(1863))),
+        ("✝", (EmojiId(3340), EmojiId(3340))),
+        ("👧🏼", (EmojiId(515), EmojiId(513))),
+        ("👨🏽\u{200d}🌾", (EmojiId(924), EmojiId(921))),
+        ("🚴🏿\u{200d}♂\u{fe0f}", (EmojiId(1952), EmojiId(1947))),
+        ("🙆🏾", (EmojiId(721), EmojiId(717))),
+        ("👯\u{200d}♀\u{fe0f}", (EmojiId(1782), EmojiId(1782))),
+        ("🅱", (EmojiId(3445), EmojiId(3445))),
+        ("👨🏼\u{200d}🎤", (EmojiId(1049), EmojiId(1047))),
+        ("👶🏽", (EmojiId(498), EmojiId(495))),
+        ("〽", (EmojiId(3417), EmojiId(3417))),
+        ("👮🏽", (EmojiId(1134), EmojiId(1131))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2222), EmojiId(2212))),
+        ("💇🏼\u{200d}♂\u{fe0f}", (EmojiId(1518), EmojiId(1516))),
+        ("🤾🏽\u{200d}♂\u{fe0f}", (EmojiId(2025), EmojiId(2022))),
+        ("👈", (EmojiId(289), EmojiId(289))),
+        ("👩\u{200d}💻", (EmojiId(1035), EmojiId(1035))),
+        ("🦅", (EmojiId(2505), EmojiId(2505))),
+        ("🚴🏽\u{200d}♀", (EmojiId(1956), EmojiId(1953))),
+        ("⏱", (EmojiId(2864), EmojiId(2864))),
+        ("🎅🏿", (EmojiId(1346), EmojiId(1341))),
+        ("🦾", (EmojiId(453), EmojiId(453))),
+        ("💆🏿\u{200d}♂\u{fe0f}", (EmojiId(1503), EmojiId(1498))),
+        ("🚶🏼\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1560), EmojiId(1558))),
+        ("👳🏿\u{200d}♂\u{fe0f}", (EmojiId(1238), EmojiId(1233))),
+        ("🧔\u{200d}♂", (EmojiId(543), EmojiId(543))),
+        ("🤷\u{200d}♂", (EmojiId(831), EmojiId(831))),
+        ("🏃\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1750), EmojiId(1750))),
+        ("🐎", (EmojiId(2452), EmojiId(2452))),
+        ("💁🏻", (EmojiId(736), EmojiId(735))),
+        ("🤕", (EmojiId(60), EmojiId(60))),
+        ("💃🏽", (EmojiId(1765), EmojiId(1762))),
+        ("🌍", (EmojiId(2720), EmojiId(2720))),
+        ("🫱🏼\u{200d}🫲🏿", (EmojiId(410), EmojiId(397))),
+        ("🚶🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1557), EmojiId(1552))),
+        ("💔", (EmojiId(140), EmojiId(140))),
+        ("👩\u{200d}❤\u{200d}👨", (EmojiId(2316), EmojiId(2316))),
+        ("👨🏽\u{200d}✈", (EmojiId(1086), EmojiId(1083))),
+        ("🧗\u{200d}♂\u{fe0f}", (EmojiId(1807), EmojiId(1807))),
+        ("🌈", (EmojiId(2925), EmojiId(2925))),
+        ("👱🏿", (EmojiId(530), EmojiId(525))),
+        ("🧘", (EmojiId(2052), EmojiId(2052))),
+        ("🤳", (EmojiId(441), EmojiId(441))),
+        ("🚆", (EmojiId(2790), EmojiId(2790))),
+        ("🏇🏽", (EmojiId(1823), EmojiId(1820))),
+        ("⚒", (EmojiId(3215), EmojiId(3215))),
+        ("❤\u{200d}🔥", (EmojiId(141), EmojiId(141))),
+        ("☝🏽", (EmojiId(322), EmojiId(319))),
+        ("🙎🏾\u{200d}♀", (EmojiId(697), EmojiId(693))),
+        ("👩🏻\u{200d}🍼", (EmojiId(1318), EmojiId(1317))),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏾", (EmojiId(2124), EmojiId(2108))),
+        ("⚫", (EmojiId(3484), EmojiId(3484))),
+        ("🚵🏽\u{200d}♂\u{fe0f}", (EmojiId(1968), EmojiId(1965))),
+        ("💌", (EmojiId(130), EmojiId(130))),
+        ("🧑\u{200d}🦽", (EmojiId(1690), EmojiId(1690))),
+        ("🧖🏾\u{200d}♂", (EmojiId(1793), EmojiId(1789))),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}👨", (EmojiId(2316), EmojiId(2316))),
+        ("👳🏿\u{200d}♂", (EmojiId(1238), EmojiId(1233))),
+        ("🫵🏽", (EmojiId(328), EmojiId(325))),
+        ("🇸🇲", (EmojiId(3729), EmojiId(3729))),
+        ("🧑🏿\u{200d}🦲", (EmojiId(632), EmojiId(627))),
+        ("🕺🏻", (EmojiId(1769), EmojiId(1768))),
+        ("🧛🏽\u{200d}♀", (EmojiId(1446), EmojiId(1443))),
+        ("🕴", (EmojiId(1774), EmojiId(1774))),
+        ("🐌", (EmojiId(2544), EmojiId(2544))),
+        ("🔵", (EmojiId(3481), EmojiId(3481))),
+        ("👍🏼", (EmojiId(333), EmojiId(331))),
+        ("🗺\u{fe0f}", (EmojiId(2724), EmojiId(2724))),
+        ("🧑🏽\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1663), EmojiId(1660))),
+        ("6\u{20e3}", (EmojiId(3433), EmojiId(3433))),
+        ("⛺", (EmojiId(2770), EmojiId(2770))),
+        ("🔡", (EmojiId(3439), EmojiId(3439))),
+        ("👡", (EmojiId(3055), EmojiId(3055))),
+        ("8\u{fe0f}\u{20e3}", (EmojiId(3435), EmojiId(3435))),
+        ("🦖", (EmojiId(2526), EmojiId(2526))),
+        ("🚣🏾", (EmojiId(1873), EmojiId(1869))),
+        ("🏋\u{200d}♂", (EmojiId(1929), EmojiId(1929))),
+        ("🥩", (EmojiId(2639), EmojiId(2639))),
+        ("👉🏾", (EmojiId(299), EmojiId(295))),
+        ("👨🏻\u{200d}🦱", (EmojiId(562), EmojiId(561))),
+        ("💠", (EmojiId(3507), EmojiId(3507))),
+        ("💇🏼\u{200d}♀", (EmojiId(1524), EmojiId(1522))),
+        ("👨🏾\u{200d}🚒", (EmojiId(1123), EmojiId(1119))),
+        ("🚶🏿\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1563), EmojiId(1558))),
+        ("🤳🏿", (EmojiId(446), EmojiId(441))),
+        ("🫱🏾\u{200d}🫲🏼", (EmojiId(416), EmojiId(397))),
+        ("🚵🏻\u{200d}♀\u{fe0f}", (EmojiId(1972), EmojiId(1971))),
+        ("7\u{fe0f}\u{20e3}", (EmojiId(3434), EmojiId(3434))),
+        ("🏊🏿\u{200d}♂\u{fe0f}", (EmojiId(1898), EmojiId(1893))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2328), EmojiId(2316))),
+        ("🫅", (EmojiId(1209), EmojiId(1209))),
+        ("📵", (EmojiId(3309), EmojiId(3309))),
+        ("🌘", (EmojiId(2898), EmojiId(2898))),
+        ("🔊", (EmojiId(3073), EmojiId(3073))),
+        ("🧔", (EmojiId(537), EmojiId(537))),
+        ("🧑🏾\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1700), EmojiId(1696))),
+        ("↩\u{fe0f}", (EmojiId(3323), EmojiId(3323))),
+        ("✖\u{fe0f}", (EmojiId(3388), EmojiId(3388))),
+        ("💇🏻\u{200d}♂\u{fe0f}", (EmojiId(1517), EmojiId(1516))),
+        ("🤞🏼", (EmojiId(261), EmojiId(259))),
+        ("🗳\u{fe0f}", (EmojiId(3175), EmojiId(3175))),
+        ("🙌🏽", (EmojiId(376), EmojiId(373))),
+        ("🗞", (EmojiId(3149), EmojiId(3149))),
+        ("🫩", (EmojiId(57), EmojiId(57))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏿", (EmojiId(2303), EmojiId(2290))),
+        ("👩🏿", (EmojiId(584), EmojiId(579))),
+        ("👨🏾\u{200d}🦰", (EmojiId(559), EmojiId(555))),
+        ("👩\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1720), EmojiId(1720))),
+        ("👨🏽\u{200d}🦼", (EmojiId(1669), EmojiId(1666))),
+        ("📏", (EmojiId(3200), EmojiId(3200))),
+        ("🚵\u{200d}♂\u{fe0f}", (EmojiId(1965), EmojiId(1965))),
+        ("🤷🏿\u{200d}♀\u{fe0f}", (EmojiId(842), EmojiId(837))),
+        ("🤵🏾\u{200d}♀\u{fe0f}", (EmojiId(1273), EmojiId(1269))),
+        ("🧜🏿\u{200d}♂\u{fe0f}", (EmojiId(1460), EmojiId(1455))),
+        ("🚴🏽\u{200d}♂", (EmojiId(1950), EmojiId(1947))),
+        ("👩🏿\u{200d}🍳", (EmojiId(950), EmojiId(945))),
+        ("🇧🇲", (EmojiId(3546), EmojiId(3546))),
+        ("🫗", (EmojiId(2707), EmojiId(2707))),
+        ("🤽🏿\u{200d}♀", (EmojiId(2015), EmojiId(2010))),
+        ("🐥", (EmojiId(2501), EmojiId(2501))),
+        ("🇮🇪", (EmojiId(3627), EmojiId(3627))),
+        ("📘", (EmojiId(3140), EmojiId(3140))),
+        ("🧗🏾\u{200d}♀", (EmojiId(1817), EmojiId(1813))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏻", (EmojiId(2386), EmojiId(2368))),
+        ("😧", (EmojiId(88), EmojiId(88))),
+        ("⛹\u{fe0f}\u{200d}♂", (EmojiId(1911), EmojiId(1911))),
+        ("🚵🏼\u{200d}♀", (EmojiId(1973), EmojiId(1971))),
+        ("🇲🇳", (EmojiId(3672), EmojiId(3672))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2339), EmojiId(2316))),
+        ("💁🏼\u{200d}♀", (EmojiId(749), EmojiId(747))),
+        ("🧑🏻\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1697), EmojiId(1696))),
+        ("🚶🏾\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1562), EmojiId(1558))),
+        ("🦸🏽\u{200d}♀", (EmojiId(1374), EmojiId(1371))),
+        ("🧑🏿\u{200d}🚒", (EmojiId(1118), EmojiId(1113))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2356), EmojiId(2342))),
+        ("👰🏾\u{200d}♂", (EmojiId(1285), EmojiId(1281))),
+        ("⁉\u{fe0f}", (EmojiId(3395), EmojiId(3395))),
+        ("🫑", (EmojiId(2615), EmojiId(2615))),
+        ("🚴🏼\u{200d}♀\u{fe0f}", (EmojiId(1955), EmojiId(1953))),
+        ("👨🏿", (EmojiId(536), EmojiId(531))),
+        ("🚙", (EmojiId(2810), EmojiId(2810))),
+        ("✋🏽", (EmojiId(190), EmojiId(187))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏿", (EmojiId(2341), EmojiId(2316))),
+        ("🇦🇼", (EmojiId(3533), EmojiId(3533))),
+        ("📷", (EmojiId(3125), EmojiId(3125))),
+        ("🤽🏼\u{200d}♀", (EmojiId(2012), EmojiId(2010))),
+        ("🧚\u{200d}♀\u{fe0f}", (EmojiId(1425), EmojiId(1425))),
+        ("🗝\u{fe0f}", (EmojiId(3211), EmojiId(3211))),
+        ("🫵🏾", (EmojiId(329), EmojiId(325))),
+        ("🙎🏻\u{200d}♂", (EmojiId(688), EmojiId(687))),
+        ("☣\u{fe0f}", (EmojiId(3312), EmojiId(3312))),
+        ("💵", (EmojiId(3156), EmojiId(3156))),
+        ("🙅🏽\u{200d}♀", (EmojiId(714), EmojiId(711))),
+        ("👴", (EmojiId(651), EmojiId(651))),
+        ("🦸🏾\u{200d}♀", (EmojiId(1375), EmojiId(1371))),
+        ("👰🏽\u{200d}♂", (EmojiId(1284), EmojiId(1281))),
+        ("🐢", (EmojiId(2520), EmojiId(2520))),
+        ("👮\u{200d}♂", (EmojiId(1137), EmojiId(1137))),
+        ("👩🏽\u{200d}🌾", (EmojiId(930), EmojiId(927))),
+        ("😭", (EmojiId(93), EmojiId(93))),
+        ("👨🏽\u{200d}🏭", (EmojiId(978), EmojiId(975))),
+        ("🀄", (EmojiId(3014), EmojiId(3014))),
+        ("👩🏻\u{200d}🌾", (EmojiId(928), EmojiId(927))),
+        ("👷🏾\u{200d}♂", (EmojiId(1201), EmojiId(1197))),
+        ("🏊🏽\u{200d}♀\u{fe0f}", (EmojiId(1902), EmojiId(1899))),
+        ("🚶🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1554), EmojiId(1552))),
+        ("🧘🏽\u{200d}♂", (EmojiId(2061), EmojiId(2058))),
+        ("🧔🏼\u{200d}♂", (EmojiId(545), EmojiId(543))),
+        ("🚀", (EmojiId(2856), EmojiId(2856))),
+        ("💁🏽\u{200d}♀\u{fe0f}", (EmojiId(750), EmojiId(747))),
+        ("🧏🏾", (EmojiId(775), EmojiId(771))),
+        ("🦻", (EmojiId(473), EmojiId(473))),
+        ("♿", (EmojiId(3290), EmojiId(3290))),
+        ("🤼\u{200d}♂\u{fe0f}", (EmojiId(1996), EmojiId(1996))),
+        ("🤜🏼", (EmojiId(363), EmojiId(361))),
+        ("🎞\u{fe0f}", (EmojiId(3121), EmojiId(3121))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2263), EmojiId(2238))),
+        ("❕", (EmojiId(3398), EmojiId(3398))),
+        ("👰🏻\u{200d}♀\u{fe0f}", (EmojiId(1288), EmojiId(1287))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏿", (EmojiId(2311), EmojiId(2290))),
+        ("🇻🇪", (EmojiId(3765), EmojiId(3765))),
+        ("🤡", (EmojiId(111), EmojiId(111))),
+        ("🦫", (EmojiId(2483), EmojiId(2483))),
+        ("🥷🏻", (EmojiId(1186), EmojiId(1185))),
+        ("🕖", (EmojiId(2881), EmojiId(2881))),
+        ("📿", (EmojiId(3066), EmojiId(3066))),
+        ("📐", (EmojiId(3201), EmojiId(3201))),
+        ("👵🏽", (EmojiId(660), EmojiId(657))),
+        ("🧔🏾\u{200d}♀", (EmojiId(553), EmojiId(549))),
+        ("🙅🏾", (EmojiId(703), EmojiId(699))),
+        ("🙃", (EmojiId(9), EmojiId(9))),
+        ("🇱🇸", (EmojiId(3657), EmojiId(3657))),
+        ("🏕", (EmojiId(2731), EmojiId(2731))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2289), EmojiId(2264))),
+        ("🤦🏻", (EmojiId(808), EmojiId(807))),
+        ("👨🏿\u{200d}🚒", (EmojiId(1124), EmojiId(1119))),
+        ("👨🏼\u{200d}⚖\u{fe0f}", (EmojiId(905), EmojiId(903))),
+        ("💒", (EmojiId(2760), EmojiId(2760))),
+        ("🖋", (EmojiId(3178), EmojiId(3178))),
+        ("🧖\u{200d}♀\u{fe0f}", (EmojiId(1795), EmojiId(1795))),
+        ("🥍", (EmojiId(2978), EmojiId(2978))),
+        ("👐🏽", (EmojiId(388), EmojiId(385))),
+        ("💁🏿\u{200d}♂", (EmojiId(746), EmojiId(741))),
+        ("🐃", (EmojiId(2459), EmojiId(2459))),
+        ("👵🏿", (EmojiId(662), EmojiId(657))),
+        ("4\u{20e3}", (EmojiId(3431), EmojiId(3431))),
+        ("🕺🏾", (EmojiId(1772), EmojiId(1768))),
+        ("🔳", (EmojiId(3509), EmojiId(3509))),
+        ("🤵🏾\u{200d}♀", (EmojiId(1273), EmojiId(1269))),
+        ("🎅🏻", (EmojiId(1342), EmojiId(1341))),
+        ("🏌🏾", (EmojiId(1837), EmojiId(1833))),
+        ("⛹🏽\u{200d}♂", (EmojiId(1914), EmojiId(1911))),
+        ("✊🏼", (EmojiId(345), EmojiId(343))),
+        ("🙆🏿\u{200d}♀", (EmojiId(734), EmojiId(729))),
+        ("🖖🏿", (EmojiId(198), EmojiId(193))),
+        ("👨🏼\u{200d}⚕", (EmojiId(851), EmojiId(849))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", (EmojiId(2204), EmojiId(2186))),
+        ("🩴", (EmojiId(3049), EmojiId(3049))),
+        ("⚙", (EmojiId(3227), EmojiId(3227))),
+        ("🪥", (EmojiId(3274), EmojiId(3274))),
+        ("🌳", (EmojiId(2575), EmojiId(2575))),
+        ("🏊\u{200d}♂", (EmojiId(1893), EmojiId(1893))),
+        ("🚶🏽\u{200d}♀\u{200d}➡", (EmojiId(1555), EmojiId(1552))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2289), EmojiId(2264))),
+        ("🧙🏿\u{200d}♀\u{fe0f}", (EmojiId(1412), EmojiId(1407))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2273), EmojiId(2264))),
+        ("🤏🏾", (EmojiId(251), EmojiId(247))),
+        ("🧑🏽\u{200d}🚒", (EmojiId(1116), EmojiId(1113))),
+        ("😅", (EmojiId(5), EmojiId(5))),
+        ("🧑🏻", (EmojiId(520), EmojiId(519))),
+        ("🤹🏼", (EmojiId(2036), EmojiId(2034))),
+        ("🇫🇯", (EmojiId(3595), EmojiId(3595))),
+        ("🙆🏾\u{200d}♀", (EmojiId(733), EmojiId(729))),
+        ("🧙🏿\u{200d}♀", (EmojiId(1412), EmojiId(1407))),
+        ("🐸", (EmojiId(2518), EmojiId(2518))),
+        ("🏸", (EmojiId(2980), EmojiId(2980))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏽", (EmojiId(2310), EmojiId(2290))),
+        ("👩🏽", (EmojiId(582), EmojiId(579))),
+        ("🥤", (EmojiId(2708), EmojiId(2708))),
+        ("🏋🏾\u{200d}♂", (EmojiId(1933), EmojiId(1929))),
+        ("🫱🏾", (EmojiId(203), EmojiId(199))),
+        ("🦹🏾\u{200d}♂\u{fe0f}", (EmojiId(1387), EmojiId(1383))),
+        ("👊🏻", (EmojiId(350), EmojiId(349))),
+        ("🧑🏾\u{200d}🎤", (EmojiId(1045), EmojiId(1041))),
+        ("👩🏿\u{200d}⚖", (EmojiId(914), EmojiId(909))),
+        ("🇨🇫", (EmojiId(3560), EmojiId(3560))),
+        ("🧎🏽\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1609), EmojiId(1606))),
+        ("🙋🏾", (EmojiId(757), EmojiId(753))),
+        ("🧑🏾\u{200d}🔧", (EmojiId(955), EmojiId(951))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2353), EmojiId(2342))),
+        ("🫷🏻", (EmojiId(224), EmojiId(223))),
+        ("🐞", (EmojiId(2550), EmojiId(2550))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2254), EmojiId(2238))),
+        ("🕯\u{fe0f}", (EmojiId(3131), EmojiId(3131))),
+        ("👌🏼", (EmojiId(237), EmojiId(235))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏼", (EmojiId(2365), EmojiId(2342))),
+        ("🦸🏼\u{200d}♂", (EmojiId(1367), EmojiId(1365))),
+        ("🏊🏼\u{200d}♀\u{fe0f}", (EmojiId(1901), EmojiId(1899))),
+        ("👨🏽\u{200d}🦲", (EmojiId(576), EmojiId(573))),
+        ("👨🏾\u{200d}🦳", (EmojiId(571), EmojiId(567))),
+        ("😶\u{200d}🌫\u{fe0f}", (EmojiId(42), EmojiId(42))),
+        ("🤷🏼", (EmojiId(827), EmojiId(825))),
+        ("🏇🏻", (EmojiId(1821), EmojiId(1820))),
+        ("🧑🏻\u{200d}⚕", (EmojiId(844), EmojiId(843))),
+        ("🧑🏼\u{200d}🦯", (EmojiId(1620), EmojiId(1618))),
+        ("👩🏼\u{200d}⚖", (EmojiId(911), EmojiId(909))),
+        ("💇🏼\u{200d}♀\u{fe0f}", (EmojiId(1524), EmojiId(1522))),
+        ("🧑🏻\u{200d}⚖\u{fe0f}", (EmojiId(898), EmojiId(897))),
+        ("🏃🏽\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1759), EmojiId(1756))),
+        ("🤷\u{200d}♀", (EmojiId(837), EmojiId(837))),
+        ("🦌", (EmojiId(2455), EmojiId(2455))),
+        ("⛹\u{fe0f}\u{200d}♂\u{fe0f}", (EmojiId(1911), EmojiId(1911))),
+        ("🅿\u{fe0f}", (EmojiId(3456), EmojiId(3456))),
+        ("⬛", (EmojiId(3493), EmojiId(3493))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2255), EmojiId(2238))),
+        ("🪵", (EmojiId(2741), EmojiId(2741))),
+        ("👌🏻", (EmojiId(236), EmojiId(235))),
+        ("👨\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1636), EmojiId(1636))),
+        ("🚣🏾\u{200d}♀\u{fe0f}", (EmojiId(1885), EmojiId(1881))),
+        ("🇬🇱", (EmojiId(3608), EmojiId(3608))),
+        ("👩🏾\u{200d}🦽", (EmojiId(1718), EmojiId(1714))),
+        ("🧏🏻\u{200d}♂\u{fe0f}", (EmojiId(778), EmojiId(777))),
+        ("🤹\u{200d}♂", (EmojiId(2040), EmojiId(2040))),
+        ("🛣", (EmojiId(2826), EmojiId(2826))),
+        ("📕", (EmojiId(3137), EmojiId(3137))),
+        ("🏣", (EmojiId(2748), EmojiId(2748))),
+        ("🇮🇨", (EmojiId(3625), EmojiId(3625))),
+        ("⛵", (EmojiId(2838), EmojiId(2838))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏽", (EmojiId(2353), EmojiId(2342))),
+        ("🦸\u{200d}♂", (EmojiId(1365), EmojiId(1365))),
+        ("👳", (EmojiId(1227), EmojiId(1227))),
+        ("🙅🏽\u{200d}♂", (EmojiId(708), EmojiId(705))),
+        ("🥏", (EmojiId(2973), EmojiId(2973))),
+        ("🙁", (EmojiId(79), EmojiId(79))),
+        ("🦵", (EmojiId(455), EmojiId(455))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2370), EmojiId(2368))),
+        ("🤸🏾\u{200d}♀", (EmojiId(1993), EmojiId(1989))),
+        ("🐣", (EmojiId(2499), EmojiId(2499))),
+        ("🚶🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1555), EmojiId(1552))),
+        ("👇🏻", (EmojiId(314), EmojiId(313))),
+        ("🇰🇮", (EmojiId(3643), EmojiId(3643))),
+        ("🧑🏽\u{200d}🏭", (EmojiId(972), EmojiId(969))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏿", (EmojiId(2367), EmojiId(2342))),
+        ("✍\u{fe0f}", (EmojiId(429), EmojiId(429))),
+        ("♻\u{fe0f}", (EmojiId(3404), EmojiId(3404))),
+        ("🏛", (EmojiId(2737), EmojiId(2737))),
+        ("👫🏽", (EmojiId(2137), EmojiId(2134))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2244), EmojiId(2238))),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏾", (EmojiId(2120), EmojiId(2108))),
+        ("🤹🏾\u{200d}♀\u{fe0f}", (EmojiId(2050), EmojiId(2046))),
+        ("⛹🏼\u{200d}♀", (EmojiId(1919), EmojiId(1917))),
+        ("🪀", (EmojiId(2993), EmojiId(2993))),
+        ("👍", (EmojiId(331), EmojiId(331))),
+        ("🧑\u{200d}🚀", (EmojiId(1095), EmojiId(1095))),
+        ("🌱", (EmojiId(2572), EmojiId(2572))),
+        ("🕵🏿", (EmojiId(1154), EmojiId(1149))),
+        ("👱🏿\u{200d}♀", (EmojiId(638), EmojiId(633))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2261), EmojiId(2238))),
+        ("👨\u{200d}👨\u{200d}👧\u{200d}👧", (EmojiId(2403), EmojiId(2403))),
+        ("👩\u{200d}👧\u{200d}👧", (EmojiId(2418), EmojiId(2418))),
+        ("🧝🏻", (EmojiId(1468), EmojiId(1467))),
+        ("🌺", (EmojiId(2567), EmojiId(2567))),
+        ("👳🏻\u{200d}♀\u{fe0f}", (EmojiId(1240), EmojiId(1239))),
+        ("🧎🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1615), EmojiId(1612))),
+        ("👳\u{200d}♀", (EmojiId(1239), EmojiId(1239))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏾", (EmojiId(2380), EmojiId(2368))),
+        ("🏃🏿\u{200d}♀\u{200d}➡", (EmojiId(1755), EmojiId(1750))),
+        ("🧑🏿\u{200d}🦰", (EmojiId(596), EmojiId(591))),
+        ("🍍", (EmojiId(2596), EmojiId(2596))),
+        ("🧘\u{200d}♂\u{fe0f}", (EmojiId(2058), EmojiId(2058))),
+        ("🏌🏻\u{200d}♂\u{fe0f}", (EmojiId(1840), EmojiId(1839))),
+        ("👩🏼\u{200d}🦯", (EmojiId(1644), EmojiId(1642))),
+        ("🏝", (EmojiId(2734), EmojiId(2734))),
+        ("👷🏽\u{200d}♂", (EmojiId(1200), EmojiId(1197))),
+        ("🫲🏾", (EmojiId(209), EmojiId(205))),
+        ("🧎🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1617), EmojiId(1612))),
+        ("💇🏾", (EmojiId(1514), EmojiId(1510))),
+        ("💳", (EmojiId(3160), EmojiId(3160))),
+        ("🐶", (EmojiId(2434), EmojiId(2434))),
+        ("🧑🏻\u{200d}🦯", (EmojiId(1619), EmojiId(1618))),
+        ("🚐", (EmojiId(2801), EmojiId(2801))),
+        ("🧝🏽\u{200d}♀\u{fe0f}", (EmojiId(1482), EmojiId(1479))),
+        ("🚏", (EmojiId(2825), EmojiId(2825))),
+        ("🏄🏼\u{200d}♀", (EmojiId(1865), EmojiId(1863))),
+        ("🧛🏻", (EmojiId(1432), EmojiId(1431))),
+        ("🧜🏻\u{200d}♀\u{fe0f}", (EmojiId(1462), EmojiId(1461))),
+        ("💆🏼\u{200d}♀", (EmojiId(1506), EmojiId(1504))),
+        ("🧑🏾\u{200d}🍳", (EmojiId(937), EmojiId(933))),
+        ("👲🏿", (EmojiId(1250), EmojiId(1245))),
+        ("🪠", (EmojiId(3261), EmojiId(3261))),
+        ("💛", (EmojiId(146), EmojiId(146))),
+        ("🆚", (EmojiId(3459), EmojiId(3459))),
+        ("🧑🏼\u{200d}🦱", (EmojiId(605), EmojiId(603))),
+        ("🏇🏼", (EmojiId(1822), EmojiId(1820))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏿", (EmojiId(2325), EmojiId(2316))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2281), EmojiId(2264))),
+        ("🙅🏾\u{200d}♀\u{fe0f}", (EmojiId(715), EmojiId(711))),
+        ("👩🏽\u{200d}🍳", (EmojiId(948), EmojiId(945))),
+        ("👨🏿\u{200d}🦱", (EmojiId(566), EmojiId(561))),
+        ("◻", (EmojiId(3496), EmojiId(3496))),
+        ("🪅", (EmojiId(3005), EmojiId(3005))),
+        ("🏊🏿\u{200d}♀", (EmojiId(1904), EmojiId(1899))),
+        ("🚶🏻\u{200d}♂\u{fe0f}", (EmojiId(1535), EmojiId(1534))),
+        ("🚵🏿\u{200d}♂\u{fe0f}", (EmojiId(1970), EmojiId(1965))),
+        ("🤵🏾\u{200d}♂\u{fe0f}", (EmojiId(1267), EmojiId(1263))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏼", (EmojiId(2339), EmojiId(2316))),
+        ("👩🏼\u{200d}💼", (EmojiId(1001), EmojiId(999))),
+        ("🚣", (EmojiId(1869), EmojiId(1869))),
+        ("🌫", (EmojiId(2922), EmojiId(2922))),
+        ("👬🏾", (EmojiId(2164), EmojiId(2160))),
+        ("🧝🏿\u{200d}♂\u{fe0f}", (EmojiId(1478), EmojiId(1473))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2257), EmojiId(2238))),
+        ("1\u{fe0f}\u{20e3}", (EmojiId(3428), EmojiId(3428))),
+        ("🍾", (EmojiId(2699), EmojiId(2699))),
+        ("⏭", (EmojiId(3365), EmojiId(3365))),
+        ("🚶🏾\u{200d}♀\u{200d}➡", (EmojiId(1556), EmojiId(1552))),
+        ("🧒", (EmojiId(501), EmojiId(501))),
+        ("🧘🏽\u{200d}♀", (EmojiId(2067), EmojiId(2064))),
+        ("🟥", (EmojiId(3486), EmojiId(3486))),
+        ("🔙", (EmojiId(3329), EmojiId(3329))),
+        ("🧑🏾", (EmojiId(523), EmojiId(519))),
+        ("⛩\u{fe0f}", (EmojiId(2767), EmojiId(2767))),
+        ("🧰", (EmojiId(3235), EmojiId(3235))),
+        ("🖊", (EmojiId(3179), EmojiId(3179))),
+        ("🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}", (EmojiId(3778), EmojiId(3778))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2220), EmojiId(2212))),
+        ("🏄🏽\u{200d}♀\u{fe0f}", (EmojiId(1866), EmojiId(1863))),
+        ("🎚", (EmojiId(3083), EmojiId(3083))),
+        ("👨🏿\u{200d}⚖\u{fe0f}", (EmojiId(908), EmojiId(903))),
+        ("❤\u{fe0f}\u{200d}🩹", (EmojiId(142), EmojiId(142))),
+        ("🔷", (EmojiId(3502), EmojiId(3502))),
+        ("🏌🏻\u{200d}♀", (EmojiId(1846), EmojiId(1845))),
+        ("🇪🇹", (EmojiId(3592), EmojiId(3592))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏿", (EmojiId(2385), EmojiId(2368))),
+        ("🎖\u{fe0f}", (EmojiId(2959), EmojiId(2959))),
+        ("🧗🏻\u{200d}♀\u{fe0f}", (EmojiId(1814), EmojiId(1813))),
+        ("🧛🏼\u{200d}♀", (EmojiId(1445), EmojiId(1443))),
+        ("🥷🏽", (EmojiId(1188), EmojiId(1185))),
+        ("⛲", (EmojiId(2769), EmojiId(2769))),
+        ("🧎🏽\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1615), EmojiId(1612))),
+        ("🍫", (EmojiId(2688), EmojiId(2688))),
+        ("👦🏾", (EmojiId(511), EmojiId(507))),
+        ("🧘🏼\u{200d}♀", (EmojiId(2066), EmojiId(2064))),
+        ("🧑🏼\u{200d}⚖", (EmojiId(899), EmojiId(897))),
+        ("🧒🏾", (EmojiId(505), EmojiId(501))),
+        ("🇮🇳", (EmojiId(3630), EmojiId(3630))),
+        ("🫱🏽\u{200d}🫲🏼", (EmojiId(412), EmojiId(397))),
+        ("🕵🏽\u{200d}♀\u{fe0f}", (EmojiId(1164), EmojiId(1161))),
+        ("🇧🇿", (EmojiId(3556), EmojiId(3556))),
+        ("🇸🇦", (EmojiId(3718), EmojiId(3718))),
+        ("🙏🏽", (EmojiId(426), EmojiId(423))),
+        ("🐈\u{200d}⬛", (EmojiId(2444), EmojiId(2444))),
+        ("🦸🏽\u{200d}♂", (EmojiId(1368), EmojiId(1365))),
+        ("🏌\u{fe0f}\u{200d}♀\u{fe0f}", (EmojiId(1845), EmojiId(1845))),
+        ("🫨", (EmojiId(49), EmojiId(49))),
+        ("🇨🇳", (EmojiId(3567), EmojiId(3567))),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏼", (EmojiId(2175), EmojiId(2160))),
+        ("💂🏼\u{200d}♂", (EmojiId(1175), EmojiId(1173))),
+        ("🏳\u{fe0f}\u{200d}🌈", (EmojiId(3516), EmojiId(3516))),
+        ("🧑🏾\u{200d}🎄", (EmojiId(1357), EmojiId(1353))),
+        ("☃", (EmojiId(2932), EmojiId(2932))),
+        ("🤸🏻\u{200d}♂", (EmojiId(1984), EmojiId(1983))),
+        ("👄", (EmojiId(493), EmojiId(493))),
+        ("💂\u{200d}♂\u{fe0f}", (EmojiId(1173), EmojiId(1173))),
+        ("㊗\u{fe0f}", (EmojiId(3473), EmojiId(3473))),
+        ("💹", (EmojiId(3162), EmojiId(3162))),
+        ("🫸🏻", (EmojiId(230), EmojiId(229))),
+        ("🏤", (EmojiId(2749), EmojiId(2749))),
+        ("☁", (EmojiId(2912), EmojiId(2912))),
+        ("🏋\u{fe0f}\u{200d}♀\u{fe0f}", (EmojiId(1935), EmojiId(1935))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2101), EmojiId(2082))),
+        ("🚶\u{200d}♀", (EmojiId(1540), EmojiId(1540))),
+        ("➰", (EmojiId(3415), EmojiId(3415))),
+        ("🤿", (EmojiId(2987), EmojiId(2987))),
+        ("😈", (EmojiId(106), EmojiId(106))),
+        ("🇳🇫", (EmojiId(3688), EmojiId(3688))),
+        ("🧑🏼\u{200d}🔧", (EmojiId(953), EmojiId(951))),
+        ("👆🏻", (EmojiId(302), EmojiId(301))),
+        ("🛤", (EmojiId(2827), EmojiId(2827))),
+        ("🧚🏾\u{200d}♀\u{fe0f}", (EmojiId(1429), EmojiId(1425))),
+        ("🤾🏽\u{200d}♀", (EmojiId(2031), EmojiId(2028))),
+        ("👋", (EmojiId(169), EmojiId(169))),
+        ("🤶", (EmojiId(1347), EmojiId(1347))),
+        ("👲🏻", (EmojiId(1246), EmojiId(1245))),
+        ("🧎\u{200d}♂\u{200d}➡", (EmojiId(1612), EmojiId(1612))),
+        ("🙆🏽\u{200d}♀\u{fe0f}", (EmojiId(732), EmojiId(729))),
+        ("🙇🏻\u{200d}♂", (EmojiId(796), EmojiId(795))),
+        ("🌴", (EmojiId(2576), EmojiId(2576))),
+        ("🏞", (EmojiId(2735), EmojiId(2735))),
+        ("🪔", (EmojiId(3135), EmojiId(3135))),
+        ("🧆", (EmojiId(2650), EmojiId(2650))),
+        ("💁🏽\u{200d}♀", (EmojiId(750), EmojiId(747))),
+        ("🚶🏼\u{200d}♂\u{200d}➡", (EmojiId(1560), EmojiId(1558))),
+        ("🧑🏽\u{200d}✈", (EmojiId(1080), EmojiId(1077))),
+        ("😩", (EmojiId(99), EmojiId(99))),
+        ("🗯\u{fe0f}", (EmojiId(166), EmojiId(166))),
+        ("💍", (EmojiId(3068), EmojiId(3068))),
+        ("🔩", (EmojiId(3226), EmojiId(3226))),
+        ("🎈", (EmojiId(2944), EmojiId(2944))),
+        ("👨\u{200d}👩\u{200d}👧\u{200d}👧", (EmojiId(2398), EmojiId(2398))),
+        ("👩🏿\u{200d}🦱", (EmojiId(602), EmojiId(597))),
+        ("🧑🏽\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1627), EmojiId(1624))),
+        ("✡\u{fe0f}", (EmojiId(3337), EmojiId(3337))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏽", (EmojiId(2340), EmojiId(2316))),
+        ("🚃", (EmojiId(2787), EmojiId(2787))),
+        ("🎙\u{fe0f}", (EmojiId(3082), EmojiId(3082))),
+        ("🚹", (EmojiId(3291), EmojiId(3291))),
+        ("🚶🏾\u{200d}➡\u{fe0f}", (EmojiId(1550), EmojiId(1546))),
+        ("👮🏾\u{200d}♂\u{fe0f}", (EmojiId(1141), EmojiId(1137))),
+        ("🇭🇹", (EmojiId(3623), EmojiId(3623))),
+        ("🈳", (EmojiId(3472), EmojiId(3472))),
+        ("🐁", (EmojiId(2477), EmojiId(2477))),
+        ("🚣🏽\u{200d}♀\u{fe0f}", (EmojiId(1884), EmojiId(1881))),
+        ("🚴🏿", (EmojiId(1946), EmojiId(1941))),
+        ("📶", (EmojiId(3381), EmojiId(3381))),
+        ("🕵\u{fe0f}", (EmojiId(1149), EmojiId(1149))),
+        ("🫱🏿\u{200d}🫲🏼", (EmojiId(420), EmojiId(397))),
+        ("🏃🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1761), EmojiId(1756))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏽", (EmojiId(2366), EmojiId(2342))),
+        ("🤽🏽\u{200d}♂\u{fe0f}", (EmojiId(2007), EmojiId(2004))),
+        ("🇲🇨", (EmojiId(3663), EmojiId(3663))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2258), EmojiId(2238))),
+        ("⛱", (EmojiId(2929), EmojiId(2929))),
+        ("🤟🏻", (EmojiId(272), EmojiId(271))),
+        ("😼", (EmojiId(122), EmojiId(122))),
+        ("🧗🏼\u{200d}♀\u{fe0f}", (EmojiId(1815), EmojiId(1813))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏿", (EmojiId(2355), EmojiId(2342))),
+        ("🤹🏾", (EmojiId(2038), EmojiId(2034))),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏿", (EmojiId(2207), EmojiId(2186))),
+        ("👳🏻\u{200d}♀", (EmojiId(1240), EmojiId(1239))),
+        ("🦟", (EmojiId(2556), EmojiId(2556))),
+        ("🌏", (EmojiId(2722), EmojiId(2722))),
+        ("👠", (EmojiId(3054), EmojiId(3054))),
+        ("🤭", (EmojiId(30), EmojiId(30))),
+        ("😛", (EmojiId(24), EmojiId(24))),
+        ("🤸🏾", (EmojiId(1981), EmojiId(1977))),
+        ("🇲🇪", (EmojiId(3665), EmojiId(3665))),
+        ("🚴🏾\u{200d}♀\u{fe0f}", (EmojiId(1957), EmojiId(1953))),
+        ("🇦🇩", (EmojiId(3520), EmojiId(3520))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2389), EmojiId(2368))),
+        ("🗿", (EmojiId(3284), EmojiId(3284))),
+        ("👩🏿\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1689), EmojiId(1684))),
+        ("🛑", (EmojiId(2834), EmojiId(2834))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2243), EmojiId(2238))),
+        ("🙋🏾\u{200d}♂", (EmojiId(763), EmojiId(759))),
+        ("👂🏻", (EmojiId(468), EmojiId(467))),
+        ("🙎🏼\u{200d}♀", (EmojiId(695), EmojiId(693))),
+        ("🫖", (EmojiId(2696), EmojiId(2696))),
+        ("👆🏾", (EmojiId(305), EmojiId(301))),
+        ("🙆🏾\u{200d}♂", (EmojiId(727), EmojiId(723))),
+        ("👬🏼", (EmojiId(2162), EmojiId(2160))),
+        ("🏌🏼", (EmojiId(1835), EmojiId(1833))),
+        ("🧚🏽\u{200d}♀\u{fe0f}", (EmojiId(1428), EmojiId(1425))),
+        ("🏌🏽\u{200d}♂\u{fe0f}", (EmojiId(1842), EmojiId(1839))),
+        ("🏖", (EmojiId(2732), EmojiId(2732))),
+        ("🏁", (EmojiId(3511), EmojiId(3511))),
+        ("🧝🏼\u{200d}♂\u{fe0f}", (EmojiId(1475), EmojiId(1473))),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏻", (EmojiId(2174), EmojiId(2160))),
+        ("👩🏼\u{200d}🏫", (EmojiId(893), EmojiId(891))),
+        ("👧🏾", (EmojiId(517), EmojiId(513))),
+        ("👨🏿\u{200d}✈\u{fe0f}", (EmojiId(1088), EmojiId(1083))),
+        ("🏯", (EmojiId(2758), EmojiId(2758))),
+        ("🤵🏼\u{200d}♀\u{fe0f}", (EmojiId(1271), EmojiId(1269))),
+        ("🇰🇵", (EmojiId(3646), EmojiId(3646))),
+        ("2\u{fe0f}\u{20e3}", (EmojiId(3429), EmojiId(3429))),
+        ("💏🏾", (EmojiId(2190), EmojiId(2186))),
+        ("🕥", (EmojiId(2888), EmojiId(2888))),
+        ("😸", (EmojiId(119), EmojiId(119))),
+        ("🙏🏿", (EmojiId(428), EmojiId(423))),
+        ("🧘🏾", (EmojiId(2056), EmojiId(2052))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏽", (EmojiId(2314), EmojiId(2290))),
+        ("🚶🏽\u{200d}♂", (EmojiId(1537), EmojiId(1534))),
+        ("🥋", (EmojiId(2982), EmojiId(2982))),
+        ("🏋🏽\u{200d}♂", (EmojiId(1932), EmojiId(1929))),
+        ("🤪", (EmojiId(26), EmojiId(26))),
+        ("🇾🇪", (EmojiId(3773), EmojiId(3773))),
+        ("👦", (EmojiId(507), EmojiId(507))),
+        ("🧜🏾\u{200d}♀\u{fe0f}", (EmojiId(1465), EmojiId(1461))),
+        ("👳🏻\u{200d}♂\u{fe0f}", (EmojiId(1234), EmojiId(1233))),
+        ("👶", (EmojiId(495), EmojiId(495))),
+        ("🛁", (EmojiId(3263), EmojiId(3263))),
+        ("👨🏿\u{200d}🦳", (EmojiId(572), EmojiId(567))),
+        ("📇", (EmojiId(3191), EmojiId(3191))),
+        ("🤹🏿\u{200d}♀", (EmojiId(2051), EmojiId(2046))),
+        ("♎", (EmojiId(3353), EmojiId(3353))),
+        ("🙎🏽\u{200d}♀\u{fe0f}", (EmojiId(696), EmojiId(693))),
+        ("🔫", (EmojiId(2995), EmojiId(2995))),
+        ("🤱🏽", (EmojiId(1314), EmojiId(1311))),
+        ("🕵🏾", (EmojiId(1153), EmojiId(1149))),
+        ("🚶\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1552), EmojiId(1552))),
+        ("✉", (EmojiId(3163), EmojiId(3163))),
+        ("🧛🏼", (EmojiId(1433), EmojiId(1431))),
+        ("🧟\u{200d}♀\u{fe0f}", (EmojiId(1490), EmojiId(1490))),
+        ("🧚🏽\u{200d}♂", (EmojiId(1422), EmojiId(1419))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2242), EmojiId(2238))),
+        ("🇳🇮", (EmojiId(3690), EmojiId(3690))),
+        ("🏆", (EmojiId(2960), EmojiId(2960))),
+        ("🙆🏼\u{200d}♀\u{fe0f}", (EmojiId(731), EmojiId(729))),
+        ("🥹", (EmojiId(86), EmojiId(86))),
+        ("1\u{20e3}", (EmojiId(3428), EmojiId(3428))),
+        ("🪝", (EmojiId(3234), EmojiId(3234))),
+        ("🤟🏿", (EmojiId(276), EmojiId(271))),
+        ("👰🏽", (EmojiId(1278), EmojiId(1275))),
+        ("☝🏻", (EmojiId(320), EmojiId(319))),
+        ("🕵\u{200d}♀", (EmojiId(1161), EmojiId(1161))),
+        ("👨\u{200d}⚖\u{fe0f}", (EmojiId(903), EmojiId(903))),
+        ("🧑🏽", (EmojiId(522), EmojiId(519))),
+        ("👞", (EmojiId(3050), EmojiId(3050))),
+        ("👬🏿", (EmojiId(2165), EmojiId(2160))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏻", (EmojiId(2317), EmojiId(2316))),
+        ("👩🏼\u{200d}🦼", (EmojiId(1680), EmojiId(1678))),
+        ("🔋", (EmojiId(3106), EmojiId(3106))),
+        ("🚶🏿\u{200d}♂", (EmojiId(1539), EmojiId(1534))),
+        ("🏌\u{fe0f}\u{200d}♀", (EmojiId(1845), EmojiId(1845))),
+        ("🤺", (EmojiId(1819), EmojiId(1819))),
+        ("👳🏾\u{200d}♂\u{fe0f}", (EmojiId(1237), EmojiId(1233))),
+        ("🪷", (EmojiId(2563), EmojiId(2563))),
+        ("💁🏻\u{200d}♀\u{fe0f}", (EmojiId(748), EmojiId(747))),
+        ("🌥", (EmojiId(2916), EmojiId(2916))),
+        ("🧎🏿\u{200d}♂\u{200d}➡", (EmojiId(1617), EmojiId(1612))),
+        ("🏈", (EmojiId(2970), EmojiId(2970))),
+        ("🇭🇷", (EmojiId(3622), EmojiId(3622))),
+        ("👱🏼", (EmojiId(527), EmojiId(525))),
+        ("🏃🏼\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1758), EmojiId(1756))),
+        ("🧑🏻\u{200d}🦲", (EmojiId(628), EmojiId(627))),
+        ("🧎🏿\u{200d}♀\u{fe0f}", (EmojiId(1599), EmojiId(1594))),
+        ("🐬", (EmojiId(2529), EmojiId(2529))),
+        ("▫\u{fe0f}", (EmojiId(3500), EmojiId(3500))),
+        ("👒", (EmojiId(3060), EmojiId(3060))),
+        ("⚰\u{fe0f}", (EmojiId(3279), EmojiId(3279))),
+        ("⚠\u{fe0f}", (EmojiId(3300), EmojiId(3300))),
+        ("🙅🏼\u{200d}♂", (EmojiId(707), EmojiId(705))),
+        ("🏃🏼\u{200d}♂", (EmojiId(1734), EmojiId(1732))),
+        ("🏌\u{200d}♀\u{fe0f}", (EmojiId(1845), EmojiId(1845))),
+        ("🍁", (EmojiId(2582), EmojiId(2582))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏾", (EmojiId(2358), EmojiId(2342))),
+        ("😘", (EmojiId(17), EmojiId(17))),
+        ("👗", (EmojiId(3035), EmojiId(3035))),
+        ("🏋\u{fe0f}\u{200d}♂", (EmojiId(1929), EmojiId(1929))),
+        ("🚶🏻\u{200d}♀", (EmojiId(1541), EmojiId(1540))),
+        ("🕵🏿\u{200d}♂\u{fe0f}", (EmojiId(1160), EmojiId(1155))),
+        ("🫵🏻", (EmojiId(326), EmojiId(325))),
+        ("👩\u{200d}👩\u{200d}👧\u{200d}👧", (EmojiId(2408), EmojiId(2408))),
+        ("👱🏻", (EmojiId(526), EmojiId(525))),
+        ("🎖", (EmojiId(2959), EmojiId(2959))),
+        ("🧑🏼\u{200d}✈\u{fe0f}", (EmojiId(1079), EmojiId(1077))),
+        ("🍷", (EmojiId(2700), EmojiId(2700))),
+        ("🧑🏿\u{200d}🦼", (EmojiId(1659), EmojiId(1654))),
+        ("🙆\u{200d}♂\u{fe0f}", (EmojiId(723), EmojiId(723))),
+        ("⚱", (EmojiId(3281), EmojiId(3281))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", (EmojiId(2195), EmojiId(2186))),
+        ("🚣🏽\u{200d}♂", (EmojiId(1878), EmojiId(1875))),
+        ("🦹\u{200d}♂\u{fe0f}", (EmojiId(1383), EmojiId(1383))),
+        ("🤦🏼", (EmojiId(809), EmojiId(807))),
+        ("🧙🏼\u{200d}♂", (EmojiId(1403), EmojiId(1401))),
+        ("👰🏿\u{200d}♂\u{fe0f}", (EmojiId(1286), EmojiId(1281))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2249), EmojiId(2238))),
+        ("🤵🏼\u{200d}♂", (EmojiId(1265), EmojiId(1263))),
+        ("🤦🏽\u{200d}♀\u{fe0f}", (EmojiId(822), EmojiId(819))),
+        ("👩🏼\u{200d}⚖\u{fe0f}", (EmojiId(911), EmojiId(909))),
+        ("🤽\u{200d}♀", (EmojiId(2010), EmojiId(2010))),
+        ("⬅", (EmojiId(3319), EmojiId(3319))),
+        ("🦵🏼", (EmojiId(457), EmojiId(455))),
+        ("🎠", (EmojiId(2780), EmojiId(2780))),
+        ("🥮", (EmojiId(2674), EmojiId(2674))),
+        ("🧗🏾\u{200d}♂\u{fe0f}", (EmojiId(1811), EmojiId(1807))),
+        ("💆🏻\u{200d}♀", (EmojiId(1505), EmojiId(1504))),
+        ("🥫", (EmojiId(2661), EmojiId(2661))),
+        ("🧘🏻\u{200d}♀", (EmojiId(2065), EmojiId(2064))),
+        ("🏃🏽\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1759), EmojiId(1756))),
+        ("😱", (EmojiId(94), EmojiId(94))),
+        ("🤸🏽", (EmojiId(1980), EmojiId(1977))),
+        ("◾", (EmojiId(3497), EmojiId(3497))),
+        ("💇🏾\u{200d}♀\u{fe0f}", (EmojiId(1526), EmojiId(1522))),
+        ("🏅", (EmojiId(2961), EmojiId(2961))),
+        ("👮🏽\u{200d}♀\u{fe0f}", (EmojiId(1146), EmojiId(1143))),
+        ("🤘🏽", (EmojiId(280), EmojiId(277))),
+        ("🧑🏿\u{200d}🏫", (EmojiId(884), EmojiId(879))),
+        ("🧛", (EmojiId(1431), EmojiId(1431))),
+        ("🏊🏾\u{200d}♂", (EmojiId(1897), EmojiId(1893))),
+        ("🚵🏾\u{200d}♀\u{fe0f}", (EmojiId(1975), EmojiId(1971))),
+        ("🍰", (EmojiId(2685), EmojiId(2685))),
+        ("🧎🏾\u{200d}♀\u{fe0f}", (EmojiId(1598), EmojiId(1594))),
+        ("🇪🇭", (EmojiId(3589), EmojiId(3589))),
+        ("👰🏾\u{200d}♀", (EmojiId(1291), EmojiId(1287))),
+        ("🚵\u{200d}♀", (EmojiId(1971), EmojiId(1971))),
+        ("👨🏿\u{200d}🎨", (EmojiId(1070), EmojiId(1065))),
+        ("🌭", (EmojiId(2644), EmojiId(2644))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2216), EmojiId(2212))),
+        ("👩🏾\u{200d}🍳", (EmojiId(949), EmojiId(945))),
+        ("👮🏾\u{200d}♀\u{fe0f}", (EmojiId(1147), EmojiId(1143))),
+        ("🧕🏾", (EmojiId(1255), EmojiId(1251))),
+        ("🕢", (EmojiId(2882), EmojiId(2882))),
+        ("🥄", (EmojiId(2716), EmojiId(2716))),
+        ("🇦🇹", (EmojiId(3531), EmojiId(3531))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2247), EmojiId(2238))),
+        ("🕸", (EmojiId(2554), EmojiId(2554))),
+        ("🚵🏽\u{200d}♂", (EmojiId(1968), EmojiId(1965))),
+        ("👰🏾\u{200d}♂\u{fe0f}", (EmojiId(1285), EmojiId(1281))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2284), EmojiId(2264))),
+        ("🧍🏽\u{200d}♂\u{fe0f}", (EmojiId(1573), EmojiId(1570))),
+        ("👨🏿\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1677), EmojiId(1672))),
+        ("👨\u{200d}👨\u{200d}👦", (EmojiId(2399), EmojiId(2399))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏽", (EmojiId(2331), EmojiId(2316))),
+        ("🧌", (EmojiId(1491), EmojiId(1491))),
+        ("👩🏽\u{200d}🦱", (EmojiId(600), EmojiId(597))),
+        ("🇹🇹", (EmojiId(3752), EmojiId(3752))),
+        ("🚴🏾\u{200d}♂", (EmojiId(1951), EmojiId(1947))),
+        ("👩🏼\u{200d}🎓", (EmojiId(875), EmojiId(873))),
+        ("🥽", (EmojiId(3025), EmojiId(3025))),
+        ("🌇", (EmojiId(2777), EmojiId(2777))),
+        ("🙍🏼\u{200d}♀", (EmojiId(677), EmojiId(675))),
+        ("🦸\u{200d}♂\u{fe0f}", (EmojiId(1365), EmojiId(1365))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2090), EmojiId(2082))),
+        ("🕵🏾\u{200d}♀\u{fe0f}", (EmojiId(1165), EmojiId(1161))),
+        ("🇦🇬", (EmojiId(3523), EmojiId(3523))),
+        ("🫜", (EmojiId(2627), EmojiId(2627))),
+        ("🦀", (EmojiId(2539), EmojiId(2539))),
+        ("🙈", (EmojiId(127), EmojiId(127))),
+        ("⛹🏿", (EmojiId(1910), EmojiId(1905))),
+        ("🥈", (EmojiId(2963), EmojiId(2963))),
+        ("🇨🇶", (EmojiId(3570), EmojiId(3570))),
+        ("🤵\u{200d}♀\u{fe0f}", (EmojiId(1269), EmojiId(1269))),
+        ("🙇🏻\u{200d}♀\u{fe0f}", (EmojiId(802), EmojiId(801))),
+        ("🧑🏼\u{200d}✈", (EmojiId(1079), EmojiId(1077))),
+        ("🧖🏼\u{200d}♀\u{fe0f}", (EmojiId(1797), EmojiId(1795))),
+        ("👨\u{200d}🍳", (EmojiId(939), EmojiId(939))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2088), EmojiId(2082))),
+        ("🌔", (EmojiId(2894), EmojiId(2894))),
+        ("🚶\u{200d}➡", (EmojiId(1546), EmojiId(1546))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", (EmojiId(2200), EmojiId(2186))),
+        ("🏃🏼\u{200d}♂\u{fe0f}", (EmojiId(1734), EmojiId(1732))),
+        ("👨🏾\u{200d}🦯\u{200d}➡", (EmojiId(1640), EmojiId(1636))),
+        ("🇺🇾", (EmojiId(3761), EmojiId(3761))),
+        ("⁉", (EmojiId(3395), EmojiId(3395))),
+        ("🫱🏻\u{200d}🫲🏽", (EmojiId(404), EmojiId(397))),
+        ("🧔\u{200d}♂\u{fe0f}", (EmojiId(543), EmojiId(543))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2331), EmojiId(2316))),
+        ("🫱🏼", (EmojiId(201), EmojiId(199))),
+        ("🤞🏿", (EmojiId(264), EmojiId(259))),
+        ("👚", (EmojiId(3042), EmojiId(3042))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2380), EmojiId(2368))),
+        ("⬆", (EmojiId(3313), EmojiId(3313))),
+        ("🏃🏼\u{200d}♀\u{200d}➡", (EmojiId(1752), EmojiId(1750))),
+        ("🏃🏾\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1754), EmojiId(1750))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏽", (EmojiId(2301), EmojiId(2290))),
+        ("🧍🏽\u{200d}♀", (EmojiId(1579), EmojiId(1576))),
+        ("🇹🇫", (EmojiId(3742), EmojiId(3742))),
+        ("🌃", (EmojiId(2772), EmojiId(2772))),
+        ("🐆", (EmojiId(2448), EmojiId(2448))),
+        ("🚁", (EmojiId(2851), EmojiId(2851))),
+        ("🤏🏽", (EmojiId(250), EmojiId(247))),
+        ("🏃🏾\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1760), EmojiId(1756))),
+        ("🖇\u{fe0f}", (EmojiId(3199), EmojiId(3199))),
+        ("💯", (EmojiId(156), EmojiId(156))),
+        ("🤷🏿\u{200d}♀", (EmojiId(842), EmojiId(837))),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏽", (EmojiId(2206), EmojiId(2186))),
+        ("🧚🏾\u{200d}♀", (EmojiId(1429), EmojiId(1425))),
+        ("🧜🏾\u{200d}♀", (EmojiId(1465), EmojiId(1461))),
+        ("😺", (EmojiId(118), EmojiId(118))),
+        ("👨🏿\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1713), EmojiId(1708))),
+        ("☄", (EmojiId(2934), EmojiId(2934))),
+        ("👨🏻\u{200d}🦳", (EmojiId(568), EmojiId(567))),
+        ("👋🏾", (EmojiId(173), EmojiId(169))),
+        ("➡", (EmojiId(3315), EmojiId(3315))),
+        ("👨🏿\u{200d}🚀", (EmojiId(1106), EmojiId(1101))),
+        ("🤝", (EmojiId(397), EmojiId(397))),
+        ("🇰🇳", (EmojiId(3645), EmojiId(3645))),
+        ("🇪🇬", (EmojiId(3588), EmojiId(3588))),
+        ("🐝", (EmojiId(2548), EmojiId(2548))),
+        ("🎎", (EmojiId(2949), EmojiId(2949))),
+        ("👩🏼\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1650), EmojiId(1648))),
+        ("⛹🏾\u{200d}♀\u{fe0f}", (EmojiId(1921), EmojiId(1917))),
+        ("🧎🏽\u{200d}♀", (EmojiId(1597), EmojiId(1594))),
+        ("👳🏿", (EmojiId(1232), EmojiId(1227))),
+        ("🧏\u{200d}♂\u{fe0f}", (EmojiId(777), EmojiId(777))),
+        ("🧑🏽\u{200d}🦲", (EmojiId(630), EmojiId(627))),
+        ("🧝🏼\u{200d}♂", (EmojiId(1475), EmojiId(1473))),
+        ("🟫", (EmojiId(3492), EmojiId(3492))),
+        ("🧞\u{200d}♂", (EmojiId(1486), EmojiId(1486))),
+        ("🛴", (EmojiId(2822), EmojiId(2822))),
+        ("🚶\u{200d}♂\u{fe0f}", (EmojiId(1534), EmojiId(1534))),
+        ("🙎\u{200d}♀", (EmojiId(693), EmojiId(693))),
+        ("👨🏾\u{200d}🌾", (EmojiId(925), EmojiId(921))),
+        ("👸🏼", (EmojiId(1223), EmojiId(1221))),
+        ("🏷", (EmojiId(3152), EmojiId(3152))),
+        ("🚶🏻\u{200d}♀\u{fe0f}", (EmojiId(1541), EmojiId(1540))),
+        ("🧑🏼\u{200d}🎤", (EmojiId(1043), EmojiId(1041))),
+        ("💆🏾\u{200d}♂\u{fe0f}", (EmojiId(1502), EmojiId(1498))),
+        ("🕺🏼", (EmojiId(1770), EmojiId(1768))),
+        ("🇧🇪", (EmojiId(3539), EmojiId(3539))),
+        ("🔆", (EmojiId(3380), EmojiId(3380))),
+        ("🧥", (EmojiId(3033), EmojiId(3033))),
+        ("📨", (EmojiId(3165), EmojiId(3165))),
+        ("🕵🏼\u{200d}♀", (EmojiId(1163), EmojiId(1161))),
+        ("👩🏽\u{200d}✈\u{fe0f}", (EmojiId(1092), EmojiId(1089))),
+        ("👳🏾\u{200d}♀\u{fe0f}", (EmojiId(1243), EmojiId(1239))),
+        ("🇦🇷", (EmojiId(3529), EmojiId(3529))),
+        ("🤽🏻\u{200d}♂", (EmojiId(2005), EmojiId(2004))),
+        ("🇻🇨", (EmojiId(3764), EmojiId(3764))),
+        ("🔴", (EmojiId(3477), EmojiId(3477))),
+        ("🚴", (EmojiId(1941), EmojiId(1941))),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏼", (EmojiId(2114), EmojiId(2108))),
+        ("🛥", (EmojiId(2843), EmojiId(2843))),
+        ("🧑🏿\u{200d}🎄", (EmojiId(1358), EmojiId(1353))),
+        ("🧍🏽\u{200d}♂", (EmojiId(1573), EmojiId(1570))),
+        ("🧑🏾\u{200d}🦼", (EmojiId(1658), EmojiId(1654))),
+        ("🙍🏻\u{200d}♂", (EmojiId(670), EmojiId(669))),
+        ("⚗", (EmojiId(3239), EmojiId(3239))),
+        ("🧑🏾\u{200d}🚀", (EmojiId(1099), EmojiId(1095))),
+        ("🐲", (EmojiId(2523), EmojiId(2523))),
+        ("⏮\u{fe0f}", (EmojiId(3369), EmojiId(3369))),
+        ("🙅🏼\u{200d}♂\u{fe0f}", (EmojiId(707), EmojiId(705))),
+        ("🈷", (EmojiId(3462), EmojiId(3462))),
+        ("♋", (EmojiId(3350), EmojiId(3350))),
+        ("👷🏽\u{200d}♀", (EmojiId(1206), EmojiId(1203))),
+        ("👩\u{200d}🦯\u{200d}➡", (EmojiId(1648), EmojiId(1648))),
+        ("🌻", (EmojiId(2568), EmojiId(2568))),
+        ("🧛🏾\u{200d}♂", (EmojiId(1441), EmojiId(1437))),
+        ("👳🏾\u{200d}♀", (EmojiId(1243), EmojiId(1239))),
+        ("🤸\u{200d}♀\u{fe0f}", (EmojiId(1989), EmojiId(1989))),
+        ("👩🏾\u{200d}🦼\u{200d}➡", (EmojiId(1688), EmojiId(1684))),
+        ("👩🏼\u{200d}🦽", (EmojiId(1716), EmojiId(1714))),
+        ("🏋🏻\u{200d}♀\u{fe0f}", (EmojiId(1936), EmojiId(1935))),
+        ("🚶🏼\u{200d}➡", (EmojiId(1548), EmojiId(1546))),
+        ("👩🏽\u{200d}🚒", (EmojiId(1128), EmojiId(1125))),
+        ("🏃🏽\u{200d}➡\u{fe0f}", (EmojiId(1747), EmojiId(1744))),
+        ("😇", (EmojiId(13), EmojiId(13))),
+        ("🛀🏿", (EmojiId(2075), EmojiId(2070))),
+        ("🫳🏿", (EmojiId(216), EmojiId(211))),
+        ("✊🏻", (EmojiId(344), EmojiId(343))),
+        ("☢\u{fe0f}", (EmojiId(3311), EmojiId(3311))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2317), EmojiId(2316))),
+        ("🖐🏿", (EmojiId(186), EmojiId(181))),
+        ("💸", (EmojiId(3159), EmojiId(3159))),
+        ("🤹🏽\u{200d}♂", (EmojiId(2043), EmojiId(2040))),
+        ("🚶🏼\u{200d}♀", (EmojiId(1542), EmojiId(1540))),
+        ("🖼", (EmojiId(3017), EmojiId(3017))),
+        ("🚪", (EmojiId(3253), EmojiId(3253))),
+        ("🙍🏾\u{200d}♂", (EmojiId(673), EmojiId(669))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏽", (EmojiId(2392), EmojiId(2368))),
+        ("🩻", (EmojiId(3252), EmojiId(3252))),
+        ("🤹🏼\u{200d}♂", (EmojiId(2042), EmojiId(2040))),
+        ("🙎🏾", (EmojiId(685), EmojiId(681))),
+        ("🐛", (EmojiId(2546), EmojiId(2546))),
+        ("🇰🇬", (EmojiId(3641), EmojiId(3641))),
+        ("⛹\u{200d}♂\u{fe0f}", (EmojiId(1911), EmojiId(1911))),
+        ("👉", (EmojiId(295), EmojiId(295))),
+        ("🏋🏽\u{200d}♀\u{fe0f}", (EmojiId(1938), EmojiId(1935))),
+        ("💂🏾\u{200d}♂", (EmojiId(1177), EmojiId(1173))),
+        ("👩🏾\u{200d}🔬", (EmojiId(1021), EmojiId(1017))),
+        ("🙇🏼", (EmojiId(791), EmojiId(789))),
+        ("🇾🇹", (EmojiId(3774), EmojiId(3774))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏻", (EmojiId(2312), EmojiId(2290))),
+        ("🚾", (EmojiId(3295), EmojiId(3295))),
+        ("⛄", (EmojiId(2933), EmojiId(2933))),
+        ("🧎🏽\u{200d}♀\u{fe0f}", (EmojiId(1597), EmojiId(1594))),
+        ("🚴🏾", (EmojiId(1945), EmojiId(1941))),
+        ("👩🏼\u{200d}✈\u{fe0f}", (EmojiId(1091), EmojiId(1089))),
+        ("🇬🇩", (EmojiId(3602), EmojiId(3602))),
+        ("🏄🏾\u{200d}♀\u{fe0f}", (EmojiId(1867), EmojiId(1863))),
+        ("🧁", (EmojiId(2686), EmojiId(2686))),
+        ("🎫", (EmojiId(2958), EmojiId(2958))),
+        ("👮\u{200d}♀", (EmojiId(1143), EmojiId(1143))),
+        ("🧎🏼\u{200d}♀\u{fe0f}", (EmojiId(1596), EmojiId(1594))),
+        ("🧑🏼\u{200d}⚖\u{fe0f}", (EmojiId(899), EmojiId(897))),
+        ("✨", (EmojiId(2943), EmojiId(2943))),
+        ("🚴🏻\u{200d}♀\u{fe0f}", (EmojiId(1954), EmojiId(1953))),
+        ("💗", (EmojiId(134), EmojiId(134))),
+        ("0\u{20e3}", (EmojiId(3427), EmojiId(3427))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2388), EmojiId(2368))),
+        ("👷🏾\u{200d}♂\u{fe0f}", (EmojiId(1201), EmojiId(1197))),
+        ("💎", (EmojiId(3069), EmojiId(3069))),
+        ("💁🏼\u{200d}♂\u{fe0f}", (EmojiId(743), EmojiId(741))),
+        ("🧝🏼\u{200d}♀\u{fe0f}", (EmojiId(1481), EmojiId(1479))),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏿", (EmojiId(2147), EmojiId(2134))),
+        ("🌨", (EmojiId(2919), EmojiId(2919))),
+        ("🤘🏾", (EmojiId(281), EmojiId(277))),
+        ("🧏🏻\u{200d}♀", (EmojiId(784), EmojiId(783))),
+        ("🏋🏿\u{200d}♀\u{fe0f}", (EmojiId(1940), EmojiId(1935))),
+        ("🏃🏾\u{200d}♀\u{200d}➡", (EmojiId(1754), EmojiId(1750))),
+        ("🏚", (EmojiId(2744), EmojiId(2744))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2266), EmojiId(2264))),
+        ("🦼", (EmojiId(2819), EmojiId(2819))),
+        ("🤹🏻\u{200d}♂\u{fe0f}", (EmojiId(2041), EmojiId(2040))),
+        ("👩🏾\u{200d}⚕\u{fe0f}", (EmojiId(859), EmojiId(855))),
+        ("🙋\u{200d}♀", (EmojiId(765), EmojiId(765))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2228), EmojiId(2212))),
+        ("🧍🏼\u{200d}♂", (EmojiId(1572), EmojiId(1570))),
+        ("🖖🏾", (EmojiId(197), EmojiId(193))),
+        ("🍀", (EmojiId(2581), EmojiId(2581))),
+        ("🖥\u{fe0f}", (EmojiId(3110), EmojiId(3110))),
+        ("📮", (EmojiId(3174), EmojiId(3174))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2323), EmojiId(2316))),
+        ("💇\u{200d}♀\u{fe0f}", (EmojiId(1522), EmojiId(1522))),
+        ("🙅🏼", (EmojiId(701), EmojiId(699))),
+        ("♣\u{fe0f}", (EmojiId(3011), EmojiId(3011))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2259), EmojiId(2238))),
+        ("🤤", (EmojiId(55), EmojiId(55))),
+        ("🇸🇭", (EmojiId(3724), EmojiId(3724))),
+        ("✍🏿", (EmojiId(434), EmojiId(429))),
+        ("🪒", (EmojiId(3265), EmojiId(3265))),
+        ("🍖", (EmojiId(2637), EmojiId(2637))),
+        ("🏊\u{200d}♀", (EmojiId(1899), EmojiId(1899))),
+        ("🤾🏾\u{200d}♀\u{fe0f}", (EmojiId(2032), EmojiId(2028))),
+        ("👩\u{200d}👧", (EmojiId(2416), EmojiId(2416))),
+        ("🎛\u{fe0f}", (EmojiId(3084), EmojiId(3084))),
+        ("🧙\u{200d}♂", (EmojiId(1401), EmojiId(1401))),
+        ("🤹\u{200d}♀", (EmojiId(2046), EmojiId(2046))),
+        ("💆🏾", (EmojiId(1496), EmojiId(1492))),
+        ("🐀", (EmojiId(2478), EmojiId(2478))),
+        ("👁\u{fe0f}", (EmojiId(491), EmojiId(491))),
+        ("🕉\u{fe0f}", (EmojiId(3336), EmojiId(3336))),
+        ("🉑", (EmojiId(3469), EmojiId(3469))),
+        ("🧖🏼\u{200d}♂\u{fe0f}", (EmojiId(1791), EmojiId(1789))),
+        ("🧎\u{200d}➡\u{fe0f}", (EmojiId(1600), EmojiId(1600))),
+        ("🦙", (EmojiId(2470), EmojiId(2470))),
+        ("🧔\u{200d}♀", (EmojiId(549), EmojiId(549))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2281), EmojiId(2264))),
+        ("🚚", (EmojiId(2812), EmojiId(2812))),
+        ("🇱🇾", (EmojiId(3661), EmojiId(3661))),
+        ("⚛", (EmojiId(3335), EmojiId(3335))),
+        ("🚯", (EmojiId(3306), EmojiId(3306))),
+        ("🙆🏻\u{200d}♀", (EmojiId(730), EmojiId(729))),
+        ("😟", (EmojiId(78), EmojiId(78))),
+        ("👃🏽", (EmojiId(482), EmojiId(479))),
+        ("🏌🏼\u{200d}♀\u{fe0f}", (EmojiId(1847), EmojiId(1845))),
+        ("☸\u{fe0f}", (EmojiId(3338), EmojiId(3338))),
+        ("🤾🏾\u{200d}♂\u{fe0f}", (EmojiId(2026), EmojiId(2022))),
+        ("🤽🏻\u{200d}♂\u{fe0f}", (EmojiId(2005), EmojiId(2004))),
+        ("😓", (EmojiId(98), EmojiId(98))),
+        ("💡", (EmojiId(3132), EmojiId(3132))),
+        ("🈷\u{fe0f}", (EmojiId(3462), EmojiId(3462))),
+        ("👩🏿\u{200d}🦽\u{200d}➡", (EmojiId(1725), EmojiId(1720))),
+        ("👌🏾", (EmojiId(239), EmojiId(235))),
+        ("🧑🏼\u{200d}🎓", (EmojiId(863), EmojiId(861))),
+        ("⛑\u{fe0f}", (EmojiId(3065), EmojiId(3065))),
+        ("🏃🏻\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1757), EmojiId(1756))),
+        ("↙\u{fe0f}", (EmojiId(3318), EmojiId(3318))),
+        ("🤴🏽", (EmojiId(1218), EmojiId(1215))),
+        ("🧘🏻", (EmojiId(2053), EmojiId(2052))),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏾", (EmojiId(2116), EmojiId(2108))),
+        ("🫲", (EmojiId(205), EmojiId(205))),
+        ("🇮🇲", (EmojiId(3629), EmojiId(3629))),
+        ("✍🏾", (EmojiId(433), EmojiId(429))),
+        ("👁", (EmojiId(491), EmojiId(491))),
+        ("🧝🏽\u{200d}♂\u{fe0f}", (EmojiId(1476), EmojiId(1473))),
+        ("🛬", (EmojiId(2848), EmojiId(2848))),
+        ("↖\u{fe0f}", (EmojiId(3320), EmojiId(3320))),
+        ("🤜🏽", (EmojiId(364), EmojiId(361))),
+        ("🚶🏼\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1560), EmojiId(1558))),
+        ("👨🏼\u{200d}🦰", (EmojiId(557), EmojiId(555))),
+        ("🧛🏼\u{200d}♂\u{fe0f}", (EmojiId(1439), EmojiId(1437))),
+        ("🕦", (EmojiId(2890), EmojiId(2890))),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏻", (EmojiId(2300), EmojiId(2290))),
+        ("🦸🏾", (EmojiId(1363), EmojiId(1359))),
+        ("🏃🏾\u{200d}➡", (EmojiId(1748), EmojiId(1744))),
+        ("👙", (EmojiId(3041), EmojiId(3041))),
+        ("🚶\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1558), EmojiId(1558))),
+        ("©\u{fe0f}", (EmojiId(3421), EmojiId(3421))),
+        ("🙎🏻\u{200d}♂\u{fe0f}", (EmojiId(688), EmojiId(687))),
+        ("🧎\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1606), EmojiId(1606))),
+        ("👨\u{200d}🦱", (EmojiId(561), EmojiId(561))),
+        ("👨🏽\u{200d}✈\u{fe0f}", (EmojiId(1086), EmojiId(1083))),
+        ("🎐", (EmojiId(2951), EmojiId(2951))),
+        ("🧏🏽\u{200d}♀\u{fe0f}", (EmojiId(786), EmojiId(783))),
+        ("🤾\u{200d}♂\u{fe0f}", (EmojiId(2022), EmojiId(2022))),
+        ("🧔🏽", (EmojiId(540), EmojiId(537))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏼", (EmojiId(2296), EmojiId(2290))),
+        ("🤹🏽", (EmojiId(2037), EmojiId(2034))),
+        ("🧎🏾\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1616), EmojiId(1612))),
+        ("🤲🏼", (EmojiId(393), EmojiId(391))),
+        ("🔱", (EmojiId(3406), EmojiId(3406))),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏾", (EmojiId(2298), EmojiId(2290))),
+        ("🧎🏻", (EmojiId(1583), EmojiId(1582))),
+        ("🧑\u{200d}🎓", (EmojiId(861), EmojiId(861))),
+        ("⏏", (EmojiId(3377), EmojiId(3377))),
+        ("🎡", (EmojiId(2782), EmojiId(2782))),
+        ("🧑🏻\u{200d}🦼", (EmojiId(1655), EmojiId(1654))),
+        ("🤽\u{200d}♂", (EmojiId(2004), EmojiId(2004))),
+        ("💇🏼\u{200d}♂", (EmojiId(1518), EmojiId(1516))),
+        ("💇🏼", (EmojiId(1512), EmojiId(1510))),
+        ("🧙🏽\u{200d}♀\u{fe0f}", (EmojiId(1410), EmojiId(1407))),
+        ("👨\u{200d}💼", (EmojiId(993), EmojiId(993))),
+        ("🫅🏿", (EmojiId(1214), EmojiId(1209))),
+        ("🏌🏼\u{200d}♂\u{fe0f}", (EmojiId(1841), EmojiId(1839))),
+        ("🚴🏽\u{200d}♂\u{fe0f}", (EmojiId(1950), EmojiId(1947))),
+        ("🫴🏻", (EmojiId(218), EmojiId(217))),
+        ("👴🏼", (EmojiId(653), EmojiId(651))),
+        ("🚶🏼\u{200d}➡\u{fe0f}", (EmojiId(1548), EmojiId(1546))),
+        ("👂🏿", (EmojiId(472), EmojiId(467))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2357), EmojiId(2342))),
+        ("🧖🏽\u{200d}♂\u{fe0f}", (EmojiId(1792), EmojiId(1789))),
+        ("🍘", (EmojiId(2663), EmojiId(2663))),
+        ("😷", (EmojiId(58), EmojiId(58))),
+        ("⛰", (EmojiId(2728), EmojiId(2728))),
+        ("🏎\u{fe0f}", (EmojiId(2815), EmojiId(2815))),
+        ("🔮", (EmojiId(2997), EmojiId(2997))),
+        ("🧖\u{200d}♀", (EmojiId(1795), EmojiId(1795))),
+        ("🦹🏿\u{200d}♂\u{fe0f}", (EmojiId(1388), EmojiId(1383))),
+        ("👨\u{200d}🦲", (EmojiId(573), EmojiId(573))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2272), EmojiId(2264))),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏽", (EmojiId(2210), EmojiId(2186))),
+        ("🟣", (EmojiId(3482), EmojiId(3482))),
+        ("🧑\u{200d}🦼\u{200d}➡", (EmojiId(1660), EmojiId(1660))),
+        ("🙎🏻", (EmojiId(682), EmojiId(681))),
+        ("🧜🏽\u{200d}♂\u{fe0f}", (EmojiId(1458), EmojiId(1455))),
+        ("🇱🇻", (EmojiId(3660), EmojiId(3660))),
+        ("👩🏻\u{200d}💻", (EmojiId(1036), EmojiId(1035))),
+        ("🧑\u{200d}🦱", (EmojiId(603), EmojiId(603))),
+        ("🧑🏾\u{200d}💼", (EmojiId(991), EmojiId(987))),
+        ("➡\u{fe0f}", (EmojiId(3315), EmojiId(3315))),
+        ("🙇🏻\u{200d}♂\u{fe0f}", (EmojiId(796), EmojiId(795))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2237), EmojiId(2212))),
+        ("🧻", (EmojiId(3270), EmojiId(3270))),
+        ("🚣🏿\u{200d}♂\u{fe0f}", (EmojiId(1880), EmojiId(1875))),
+        ("⛹🏾\u{200d}♀", (EmojiId(1921), EmojiId(1917))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏽", (EmojiId(2375), EmojiId(2368))),
+        ("🖖🏼", (EmojiId(195), EmojiId(193))),
+        ("🙍🏼", (EmojiId(665), EmojiId(663))),
+        ("🚕", (EmojiId(2806), EmojiId(2806))),
+        ("🇮🇶", (EmojiId(3632), EmojiId(3632))),
+        ("🇨🇵", (EmojiId(3569), EmojiId(3569))),
+        ("🧏🏽", (EmojiId(774), EmojiId(771))),
+        ("🫰🏽", (EmojiId(268), EmojiId(265))),
+        ("🦔", (EmojiId(2484), EmojiId(2484))),
+        ("🧘\u{200d}♀\u{fe0f}", (EmojiId(2064), EmojiId(2064))),
+        ("🍻", (EmojiId(2704), EmojiId(2704))),
+        ("🕤", (EmojiId(2886), EmojiId(2886))),
+        ("❣\u{fe0f}", (EmojiId(139), EmojiId(139))),
+        ("👩🏼\u{200d}🍼", (EmojiId(1319), EmojiId(1317))),
+        ("🦹🏻\u{200d}♂", (EmojiId(1384), EmojiId(1383))),
+        ("🐕\u{200d}🦺", (EmojiId(2437), EmojiId(2437))),
+        ("💆🏿", (EmojiId(1497), EmojiId(1492))),
+        ("🙋🏻\u{200d}♀\u{fe0f}", (EmojiId(766), EmojiId(765))),
+        ("🈸", (EmojiId(3470), EmojiId(3470))),
+        ("💆🏿\u{200d}♀", (EmojiId(1509), EmojiId(1504))),
+        ("🧑🏻\u{200d}✈", (EmojiId(1078), EmojiId(1077))),
+        ("🤽🏽", (EmojiId(2001), EmojiId(1998))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏿", (EmojiId(2393), EmojiId(2368))),
+        ("👨🏻\u{200d}🦰", (EmojiId(556), EmojiId(555))),
+        ("🇪🇪", (EmojiId(3587), EmojiId(3587))),
+        ("🦸🏾\u{200d}♂", (EmojiId(1369), EmojiId(1365))),
+        ("🚶🏿\u{200d}➡\u{fe0f}", (EmojiId(1551), EmojiId(1546))),
+        ("🈁", (EmojiId(3460), EmojiId(3460))),
+        ("🔐", (EmojiId(3209), EmojiId(3209))),
+        ("👩🏿\u{200d}🏭", (EmojiId(986), EmojiId(981))),
+        ("🌟", (EmojiId(2909), EmojiId(2909))),
+        ("🪯", (EmojiId(3346), EmojiId(3346))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2256), EmojiId(2238))),
+        ("🚣🏻\u{200d}♀", (EmojiId(1882), EmojiId(1881))),
+        ("🧑🏽\u{200d}🦽\u{200d}➡", (EmojiId(1699), EmojiId(1696))),
+        ("💇🏿\u{200d}♂\u{fe0f}", (EmojiId(1521), EmojiId(1516))),
+        ("🛃", (EmojiId(3297), EmojiId(3297))),
+        ("🧝", (EmojiId(1467), EmojiId(1467))),
+        ("📟", (EmojiId(3104), EmojiId(3104))),
+        ("🤵🏻\u{200d}♀", (EmojiId(1270), EmojiId(1269))),
+        ("🙌🏿", (EmojiId(378), EmojiId(373))),
+        ("🈹", (EmojiId(3466), EmojiId(3466))),
+        ("👳\u{200d}♂", (EmojiId(1233), EmojiId(1233))),
+        ("🧑🏿\u{200d}⚖", (EmojiId(902), EmojiId(897))),
+        ("🔤", (EmojiId(3442), EmojiId(3442))),
+        ("📄", (EmojiId(3147), EmojiId(3147))),
+        ("🧓🏻", (EmojiId(646), EmojiId(645))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2225), EmojiId(2212))),
+        ("👍🏿", (EmojiId(336), EmojiId(331))),
+        ("🧜🏾", (EmojiId(1453), EmojiId(1449))),
+        ("⚾", (EmojiId(2966), EmojiId(2966))),
+        ("🗃\u{fe0f}", (EmojiId(3203), EmojiId(3203))),
+        ("⚰", (EmojiId(3279), EmojiId(3279))),
+        ("🧑🏽\u{200d}🦰", (EmojiId(594), EmojiId(591))),
+        ("👨🏽\u{200d}⚖", (EmojiId(906), EmojiId(903))),
+        ("🤟🏽", (EmojiId(274), EmojiId(271))),
+        ("♌", (EmojiId(3351), EmojiId(3351))),
+        ("🧑🏽\u{200d}🦼", (EmojiId(1657), EmojiId(1654))),
+        ("🅾\u{fe0f}", (EmojiId(3454), EmojiId(3454))),
+        ("🆕", (EmojiId(3452), EmojiId(3452))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2248), EmojiId(2238))),
+        ("🧖🏿\u{200d}♂\u{fe0f}", (EmojiId(1794), EmojiId(1789))),
+        ("👽", (EmojiId(115), EmojiId(115))),
+        ("🤷🏽", (EmojiId(828), EmojiId(825))),
+        ("🥔", (EmojiId(2611), EmojiId(2611))),
+        ("👼", (EmojiId(1335), EmojiId(1335))),
+        ("🆗", (EmojiId(3455), EmojiId(3455))),
+        ("🚵🏾", (EmojiId(1963), EmojiId(1959))),
+        ("🦹🏻\u{200d}♀", (EmojiId(1390), EmojiId(1389))),
+        ("🦏", (EmojiId(2474), EmojiId(2474))),
+        ("🚣🏿\u{200d}♀\u{fe0f}", (EmojiId(1886), EmojiId(1881))),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏻", (EmojiId(2304), EmojiId(2290))),
+        ("👩🏾\u{200d}🦲", (EmojiId(625), EmojiId(621))),
+        ("🧚🏽\u{200d}♀", (EmojiId(1428), EmojiId(1425))),
+        ("🙍🏽", (EmojiId(666), EmojiId(663))),
+        ("🤸🏿\u{200d}♀", (EmojiId(1994), EmojiId(1989))),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏽", (EmojiId(2197), EmojiId(2186))),
+        ("🧙🏻\u{200d}♀\u{fe0f}", (EmojiId(1408), EmojiId(1407))),
+        ("🏋🏽\u{200d}♀", (EmojiId(1938), EmojiId(1935))),
+        ("💇🏿", (EmojiId(1515), EmojiId(1510))),
+        ("👨🏾\u{200d}💼", (EmojiId(997), EmojiId(993))),
+        ("🎥", (EmojiId(3120), EmojiId(3120))),
+        ("🍤", (EmojiId(2672), EmojiId(2672))),
+        ("🤦🏼\u{200d}♂\u{fe0f}", (EmojiId(815), EmojiId(813))),
+        ("🏃🏾\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1760), EmojiId(1756))),
+        ("🪡", (EmojiId(3020), EmojiId(3020))),
+        ("🧎🏼\u{200d}♂\u{200d}➡", (EmojiId(1614), EmojiId(1612))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2226), EmojiId(2212))),
+        ("🫱🏻\u{200d}🫲🏾", (EmojiId(405), EmojiId(397))),
+        ("🧜🏻\u{200d}♀", (EmojiId(1462), EmojiId(1461))),
+        ("🇻🇺", (EmojiId(3769), EmojiId(3769))),
+        ("🦻🏽", (EmojiId(476), EmojiId(473))),
+        ("🌤\u{fe0f}", (EmojiId(2915), EmojiId(2915))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2219), EmojiId(2212))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2220), EmojiId(2212))),
+        ("🤴🏾", (EmojiId(1219), EmojiId(1215))),
+        ("🧕🏼", (EmojiId(1253), EmojiId(1251))),
+        ("⚔", (EmojiId(3218), EmojiId(3218))),
+        ("👩🏽\u{200d}🦯\u{200d}➡", (EmojiId(1651), EmojiId(1648))),
+        ("💁🏿\u{200d}♂\u{fe0f}", (EmojiId(746), EmojiId(741))),
+        ("🕚", (EmojiId(2889), EmojiId(2889))),
+        ("🩼", (EmojiId(3250), EmojiId(3250))),
+        ("👩\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1648), EmojiId(1648))),
+        ("🤦🏻\u{200d}♀\u{fe0f}", (EmojiId(820), EmojiId(819))),
+        ("👋🏻", (EmojiId(170), EmojiId(169))),
+        ("🇨🇷", (EmojiId(3571), EmojiId(3571))),
+        ("🇪🇸", (EmojiId(3591), EmojiId(3591))),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏻", (EmojiId(2148), EmojiId(2134))),
+        ("🧎🏾\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1616), EmojiId(1612))),
+        ("🚶🏿\u{200d}♀\u{fe0f}", (EmojiId(1545), EmojiId(1540))),
+        ("⚛\u{fe0f}", (EmojiId(3335), EmojiId(3335))),
+        ("🧑🏾\u{200d}⚕\u{fe0f}", (EmojiId(847), EmojiId(843))),
+        ("👩🏼\u{200d}✈", (EmojiId(1091), EmojiId(1089))),
+        ("®\u{fe0f}", (EmojiId(3422), EmojiId(3422))),
+        ("👨🏽\u{200d}🎤", (EmojiId(1050), EmojiId(1047))),
+        ("🕋", (EmojiId(2768), EmojiId(2768))),
+        ("🏌\u{200d}♀", (EmojiId(1845), EmojiId(1845))),
+        ("🌶\u{fe0f}", (EmojiId(2614), EmojiId(2614))),
+        ("🤷🏽\u{200d}♂\u{fe0f}", (EmojiId(834), EmojiId(831))),
+        ("↔\u{fe0f}", (EmojiId(3322), EmojiId(3322))),
+        ("🧎🏼\u{200d}➡", (EmojiId(1602), EmojiId(1600))),
+        ("💤", (EmojiId(168), EmojiId(168))),
+        ("🚵🏿", (EmojiId(1964), EmojiId(1959))),
+        ("👩🏿\u{200d}🍼", (EmojiId(1322), EmojiId(1317))),
+        ("〰\u{fe0f}", (EmojiId(3400), EmojiId(3400))),
+        ("🌋", (EmojiId(2729), EmojiId(2729))),
+        ("👨🏻\u{200d}🦽\u{200d}➡", (EmojiId(1709), EmojiId(1708))),
+        ("👌", (EmojiId(235), EmojiId(235))),
+        ("👨🏻\u{200d}🦲", (EmojiId(574), EmojiId(573))),
+        ("🧑🏾\u{200d}🎓", (EmojiId(865), EmojiId(861))),
+        ("◼", (EmojiId(3495), EmojiId(3495))),
+        ("🏺", (EmojiId(2719), EmojiId(2719))),
+        ("👰\u{200d}♂", (EmojiId(1281), EmojiId(1281))),
+        ("🤦\u{200d}♀\u{fe0f}", (EmojiId(819), EmojiId(819))),
+        ("🏳\u{200d}⚧\u{fe0f}", (EmojiId(3517), EmojiId(3517))),
+        ("ℹ", (EmojiId(3449), EmojiId(3449))),
+        ("🤵🏽\u{200d}♂\u{fe0f}", (EmojiId(1266), EmojiId(1263))),
+        ("👨🏾\u{200d}🎤", (EmojiId(1051), EmojiId(1047))),
+        ("🛣\u{fe0f}", (EmojiId(2826), EmojiId(2826))),
+        ("🤹🏿\u{200d}♂", (EmojiId(2045), EmojiId(2040))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2345), EmojiId(2342))),
+        ("🏴\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", (EmojiId(3779), EmojiId(3779))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2231), EmojiId(2212))),
+        ("🙋🏽", (EmojiId(756), EmojiId(753))),
+        ("🐧", (EmojiId(2503), EmojiId(2503))),
+        ("👩\u{200d}🏫", (EmojiId(891), EmojiId(891))),
+        ("🙏🏻", (EmojiId(424), EmojiId(423))),
+        ("🍑", (EmojiId(2601), EmojiId(2601))),
+        ("🇨🇮", (EmojiId(3563), EmojiId(3563))),
+        ("🤙🏼", (EmojiId(285), EmojiId(283))),
+        ("✔\u{fe0f}", (EmojiId(3412), EmojiId(3412))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2224), EmojiId(2212))),
+        ("🧑🏾\u{200d}🦯", (EmojiId(1622), EmojiId(1618))),
+        ("👩🏻\u{200d}🦳", (EmojiId(610), EmojiId(609))),
+        ("👼🏽", (EmojiId(1338), EmojiId(1335))),
+        ("🧑🏼\u{200d}🚀", (EmojiId(1097), EmojiId(1095))),
+        ("🚘", (EmojiId(2809), EmojiId(2809))),
+        ("🤸🏿\u{200d}♀\u{fe0f}", (EmojiId(1994), EmojiId(1989))),
+        ("🕴\u{fe0f}", (EmojiId(1774), EmojiId(1774))),
+        ("👩🏿\u{200d}🦳", (EmojiId(614), EmojiId(609))),
+        ("🪧", (EmojiId(3285), EmojiId(3285))),
+        ("🌛", (EmojiId(2901), EmojiId(2901))),
+        ("🤹🏽\u{200d}♀\u{fe0f}", (EmojiId(2049), EmojiId(2046))),
+        ("🧔🏽\u{200d}♀", (EmojiId(552), EmojiId(549))),
+        ("🧏\u{200d}♂", (EmojiId(777), EmojiId(777))),
+        ("👌🏿", (EmojiId(240), EmojiId(235))),
+        ("💾", (EmojiId(3116), EmojiId(3116))),
+        ("🕌", (EmojiId(2764), EmojiId(2764))),
+        ("🧑🏿\u{200d}🦽", (EmojiId(1695), EmojiId(1690))),
+        ("🛂", (EmojiId(3296), EmojiId(3296))),
+        ("👩🏻\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1721), EmojiId(1720))),
+        ("👩\u{200d}❤\u{200d}💋\u{200d}👨", (EmojiId(2212), EmojiId(2212))),
+        ("🧑\u{200d}💼", (EmojiId(987), EmojiId(987))),
+        ("👨🏼\u{200d}🎨", (EmojiId(1067), EmojiId(1065))),
+        ("9\u{20e3}", (EmojiId(3436), EmojiId(3436))),
+        ("👾", (EmojiId(116), EmojiId(116))),
+        ("🏗", (EmojiId(2738), EmojiId(2738))),
+        ("👩\u{200d}🌾", (EmojiId(927), EmojiId(927))),
+        ("👩🏻\u{200d}⚖", (EmojiId(910), EmojiId(909))),
+        ("🚶🏻\u{200d}➡", (EmojiId(1547), EmojiId(1546))),
+        ("🧖🏾\u{200d}♀\u{fe0f}", (EmojiId(1799), EmojiId(1795))),
+        ("💁🏽", (EmojiId(738), EmojiId(735))),
+        ("💂🏻\u{200d}♀", (EmojiId(1180), EmojiId(1179))),
+        ("🧍\u{200d}♀", (EmojiId(1576), EmojiId(1576))),
+        ("📈", (EmojiId(3192), EmojiId(3192))),
+        ("🧏🏾\u{200d}♀\u{fe0f}", (EmojiId(787), EmojiId(783))),
+        ("🛌🏿", (EmojiId(2081), EmojiId(2076))),
+        ("🏃\u{200d}➡\u{fe0f}", (EmojiId(1744), EmojiId(1744))),
+        ("🧝🏼\u{200d}♀", (EmojiId(1481), EmojiId(1479))),
+        ("👩\u{200d}❤\u{200d}👩", (EmojiId(2368), EmojiId(2368))),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏿", (EmojiId(2195), EmojiId(2186))),
+        ("🇫🇮", (EmojiId(3594), EmojiId(3594))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2229), EmojiId(2212))),
+        ("🙍🏾", (EmojiId(667), EmojiId(663))),
+        ("🧚🏿\u{200d}♀", (EmojiId(1430), EmojiId(1425))),
+        ("🦩", (EmojiId(2511), EmojiId(2511))),
+        ("👩🏿\u{200d}🎤", (EmojiId(1058), EmojiId(1053))),
+        ("🏃\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1756), EmojiId(1756))),
+        ("🙆🏾\u{200d}♀\u{fe0f}", (EmojiId(733), EmojiId(729))),
+        ("🏌🏿\u{200d}♂\u{fe0f}", (EmojiId(1844), EmojiId(1839))),
+        ("🏋🏾\u{200d}♂\u{fe0f}", (EmojiId(1933), EmojiId(1929))),
+        ("🧇", (EmojiId(2635), EmojiId(2635))),
+        ("🔧", (EmojiId(3224), EmojiId(3224))),
+        ("🧝🏻\u{200d}♀\u{fe0f}", (EmojiId(1480), EmojiId(1479))),
+        ("🌎", (EmojiId(2721), EmojiId(2721))),
+        ("🧑🏿\u{200d}🍼", (EmojiId(1334), EmojiId(1329))),
+        ("🤎", (EmojiId(151), EmojiId(151))),
+        ("🧃", (EmojiId(2710), EmojiId(2710))),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏼", (EmojiId(2296), EmojiId(2290))),
+        ("🏃🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1752), EmojiId(1750))),
+        ("🏭", (EmojiId(2757), EmojiId(2757))),
+        ("👧🏽", (EmojiId(516), EmojiId(513))),
+        ("🚶🏽\u{200d}➡", (EmojiId(1549), EmojiId(1546))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2236), EmojiId(2212))),
+        ("🏃🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1751), EmojiId(1750))),
+        ("🧖🏻\u{200d}♂", (EmojiId(1790), EmojiId(1789))),
+        ("㊙\u{fe0f}", (EmojiId(3474), EmojiId(3474))),
+        ("🤜🏾", (EmojiId(365), EmojiId(361))),
+        ("👨\u{200d}❤\u{fe0f}\u{200d}👨", (EmojiId(2342), EmojiId(2342))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2234), EmojiId(2212))),
+        ("👨🏾\u{200d}🦼\u{200d}➡", (EmojiId(1676), EmojiId(1672))),
+        ("🤾🏿\u{200d}♂\u{fe0f}", (EmojiId(2027), EmojiId(2022))),
+        ("💅", (EmojiId(435), EmojiId(435))),
+        ("🙆🏽\u{200d}♂\u{fe0f}", (EmojiId(726), EmojiId(723))),
+        ("💑🏽", (EmojiId(2293), EmojiId(2290))),
+        ("🖐\u{fe0f}", (EmojiId(181), EmojiId(181))),
+        ("🦗", (EmojiId(2551), EmojiId(2551))),
+        ("🧗🏼\u{200d}♀", (EmojiId(1815), EmojiId(1813))),
+        ("👩🏿\u{200d}🌾", (EmojiId(932), EmojiId(927))),
+        ("👩🏿\u{200d}⚖\u{fe0f}", (EmojiId(914), EmojiId(909))),
+        ("🇲🇸", (EmojiId(3677), EmojiId(3677))),
+        ("🛥\u{fe0f}", (EmojiId(2843), EmojiId(2843))),
+        ("🐜", (EmojiId(2547), EmojiId(2547))),
+        ("🏳", (EmojiId(3515), EmojiId(3515))),
+        ("⛹\u{fe0f}", (EmojiId(1905), EmojiId(1905))),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2089), EmojiId(2082))),
+        ("🐴", (EmojiId(2449), EmojiId(2449))),
+        ("⌨", (EmojiId(3112), EmojiId(3112))),
+        ("🚊", (EmojiId(2794), EmojiId(2794))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏿", (EmojiId(2363), EmojiId(2342))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2340), EmojiId(2316))),
+        ("👳🏻\u{200d}♂", (EmojiId(1234), EmojiId(1233))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2286), EmojiId(2264))),
+        ("🎰", (EmojiId(3001), EmojiId(3001))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2318), EmojiId(2316))),
+        ("🧝🏻\u{200d}♀", (EmojiId(1480), EmojiId(1479))),
+        ("🧎🏼", (EmojiId(1584), EmojiId(1582))),
+        ("🚴🏿\u{200d}♀\u{fe0f}", (EmojiId(1958), EmojiId(1953))),
+        ("🍋", (EmojiId(2593), EmojiId(2593))),
+        ("🥂", (EmojiId(2705), EmojiId(2705))),
+        ("🧏🏽\u{200d}♀", (EmojiId(786), EmojiId(783))),
+        ("👩\u{200d}🚀", (EmojiId(1107), EmojiId(1107))),
+        ("⚠", (EmojiId(3300), EmojiId(3300))),
+        ("👨🏾\u{200d}🦱", (EmojiId(565), EmojiId(561))),
+        ("👊🏼", (EmojiId(351), EmojiId(349))),
+        ("🦢", (EmojiId(2507), EmojiId(2507))),
+        ("🧾", (EmojiId(3161), EmojiId(3161))),
+        ("🦸🏻\u{200d}♂\u{fe0f}", (EmojiId(1366), EmojiId(1365))),
+        ("🍣", (EmojiId(2671), EmojiId(2671))),
+        ("🕵🏿\u{200d}♀", (EmojiId(1166), EmojiId(1161))),
+        ("🧎🏻\u{200d}♂\u{fe0f}", (EmojiId(1589), EmojiId(1588))),
+        ("🦆", (EmojiId(2506), EmojiId(2506))),
+        ("👷\u{200d}♂\u{fe0f}", (EmojiId(1197), EmojiId(1197))),
+        ("🧏🏿\u{200d}♀", (EmojiId(788), EmojiId(783))),
+        ("🏫", (EmojiId(2755), EmojiId(2755))),
+        ("⚗\u{fe0f}", (EmojiId(3239), EmojiId(3239))),
+        ("🇧🇧", (EmojiId(3537), EmojiId(3537))),
+        ("📲", (EmojiId(3101), EmojiId(3101))),
+        ("👇🏽", (EmojiId(316), EmojiId(313))),
+        ("▶", (EmojiId(3363), EmojiId(3363))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2227), EmojiId(2212))),
+        ("🧙🏻\u{200d}♂", (EmojiId(1402), EmojiId(1401))),
+        ("👱🏽\u{200d}♀", (EmojiId(636), EmojiId(633))),
+        ("🩸", (EmojiId(3247), EmojiId(3247))),
+        ("🕉", (EmojiId(3336), EmojiId(3336))),
+        ("👩🏽\u{200d}🦲", (EmojiId(624), EmojiId(621))),
+        ("🏄🏼\u{200d}♂\u{fe0f}", (EmojiId(1859), EmojiId(1857))),
+        ("👩🏽\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1723), EmojiId(1720))),
+        ("🙎🏼", (EmojiId(683), EmojiId(681))),
+        ("🇩🇲", (EmojiId(3582), EmojiId(3582))),
+        ("🧑🏻\u{200d}🌾", (EmojiId(916), EmojiId(915))),
+        ("♟\u{fe0f}", (EmojiId(3012), EmojiId(3012))),
+        ("📪", (EmojiId(3171), EmojiId(3171))),
+        ("🚌", (EmojiId(2798), EmojiId(2798))),
+        ("👰", (EmojiId(1275), EmojiId(1275))),
+        ("📭", (EmojiId(3173), EmojiId(3173))),
+        ("🤵", (EmojiId(1257), EmojiId(1257))),
+        ("🕧", (EmojiId(2868), EmojiId(2868))),
+        ("🛀🏾", (EmojiId(2074), EmojiId(2070))),
+        ("🎲", (EmojiId(3002), EmojiId(3002))),
+        ("🔝", (EmojiId(3333), EmojiId(3333))),
+        ("🧗🏿\u{200d}♀", (EmojiId(1818), EmojiId(1813))),
+        ("🧔🏽\u{200d}♂", (EmojiId(546), EmojiId(543))),
+        ("👎🏻", (EmojiId(338), EmojiId(337))),
+        ("🏋🏽", (EmojiId(1926), EmojiId(1923))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2329), EmojiId(2316))),
+        ("👨🏾\u{200d}🦼", (EmojiId(1670), EmojiId(1666))),
+        ("🤛🏻", (EmojiId(356), EmojiId(355))),
+        ("🚖", (EmojiId(2807), EmojiId(2807))),
+        ("↩", (EmojiId(3323), EmojiId(3323))),
+        ("📽", (EmojiId(3122), EmojiId(3122))),
+        ("🇸🇿", (EmojiId(3738), EmojiId(3738))),
+        ("🏌\u{fe0f}", (EmojiId(1833), EmojiId(1833))),
+        ("🥡", (EmojiId(2678), EmojiId(2678))),
+        ("👤", (EmojiId(2420), EmojiId(2420))),
+        ("🙌", (EmojiId(373), EmojiId(373))),
+        ("🇹🇳", (EmojiId(3749), EmojiId(3749))),
+        ("🧏🏻", (EmojiId(772), EmojiId(771))),
+        ("🧘🏾\u{200d}♀\u{fe0f}", (EmojiId(2068), EmojiId(2064))),
+        ("👆🏿", (EmojiId(306), EmojiId(301))),
+        ("👈🏽", (EmojiId(292), EmojiId(289))),
+        ("🏋", (EmojiId(1923), EmojiId(1923))),
+        ("🔏", (EmojiId(3208), EmojiId(3208))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2213), EmojiId(2212))),
+        ("🚴🏼\u{200d}♂\u{fe0f}", (EmojiId(1949), EmojiId(1947))),
+        ("😍", (EmojiId(15), EmojiId(15))),
+        ("🚣🏼\u{200d}♂", (EmojiId(1877), EmojiId(1875))),
+        ("🕵🏻\u{200d}♂\u{fe0f}", (EmojiId(1156), EmojiId(1155))),
+        ("🙋\u{200d}♀\u{fe0f}", (EmojiId(765), EmojiId(765))),
+        ("🫱🏿\u{200d}🫲🏽", (EmojiId(421), EmojiId(397))),
+        ("🎙", (EmojiId(3082), EmojiId(3082))),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}👩", (EmojiId(2368), EmojiId(2368))),
+        ("🏄🏽\u{200d}♂\u{fe0f}", (EmojiId(1860), EmojiId(1857))),
+        ("🇨🇦", (EmojiId(3557), EmojiId(3557))),
+        ("🧛🏻\u{200d}♂\u{fe0f}", (EmojiId(1438), EmojiId(1437))),
+        ("🚵🏽\u{200d}♀\u{fe0f}", (EmojiId(1974), EmojiId(1971))),
+        ("🧝🏾\u{200d}♂", (EmojiId(1477), EmojiId(1473))),
+        ("🛩\u{fe0f}", (EmojiId(2846), EmojiId(2846))),
+        ("♀\u{fe0f}", (EmojiId(3385), EmojiId(3385))),
+        ("👶🏻", (EmojiId(496), EmojiId(495))),
+        ("🙇🏾\u{200d}♂", (EmojiId(799), EmojiId(795))),
+        ("🤹🏻\u{200d}♂", (EmojiId(2041), EmojiId(2040))),
+        ("🦘", (EmojiId(2493), EmojiId(2493))),
+        ("🤸", (EmojiId(1977), EmojiId(1977))),
+        ("🧑🏿\u{200d}🦱", (EmojiId(608), EmojiId(603))),
+        ("👩🏼\u{200d}🚀", (EmojiId(1109), EmojiId(1107))),
+        ("🐐", (EmojiId(2467), EmojiId(2467))),
+        ("💘", (EmojiId(131), EmojiId(131))),
+        ("👖", (EmojiId(3030), EmojiId(3030))),
+        ("🍇", (EmojiId(2589), EmojiId(2589))),
+        ("🧎🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1610), EmojiId(1606))),
+        ("🏊🏼\u{200d}♀", (EmojiId(1901), EmojiId(1899))),
+        ("🇵🇸", (EmojiId(3708), EmojiId(3708))),
+        ("🏋🏾\u{200d}♀", (EmojiId(1939), EmojiId(1935))),
+        ("🧑🏼\u{200d}⚕", (EmojiId(845), EmojiId(843))),
+        ("🫱🏿", (EmojiId(204), EmojiId(199))),
+        ("👱🏻\u{200d}♂\u{fe0f}", (EmojiId(640), EmojiId(639))),
+        ("🖐🏼", (EmojiId(183), EmojiId(181))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2231), EmojiId(2212))),
+        ("🤼", (EmojiId(1995), EmojiId(1995))),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏼", (EmojiId(2183), EmojiId(2160))),
+        ("👨🏾\u{200d}⚕", (EmojiId(853), EmojiId(849))),
+        ("✋🏻", (EmojiId(188), EmojiId(187))),
+        ("🗳", (EmojiId(3175), EmojiId(3175))),
+        ("💁🏽\u{200d}♂", (EmojiId(744), EmojiId(741))),
+        ("🧝\u{200d}♂", (EmojiId(1473), EmojiId(1473))),
+        ("👩🏼\u{200d}⚕", (EmojiId(857), EmojiId(855))),
+        ("❎", (EmojiId(3414), EmojiId(3414))),
+        ("🧚🏾\u{200d}♂", (EmojiId(1423), EmojiId(1419))),
+        ("🔥", (EmojiId(2935), EmojiId(2935))),
+        ("🧎🏻\u{200d}♀\u{fe0f}", (EmojiId(1595), EmojiId(1594))),
+        ("🤌🏿", (EmojiId(246), EmojiId(241))),
+        ("🎆", (EmojiId(2940), EmojiId(2940))),
+        ("🕵🏻\u{200d}♀\u{fe0f}", (EmojiId(1162), EmojiId(1161))),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏽", (EmojiId(2314), EmojiId(2290))),
+        ("🧎🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1609), EmojiId(1606))),
+        ("🇦🇽", (EmojiId(3534), EmojiId(3534))),
+        ("💇🏿\u{200d}♀", (EmojiId(1527), EmojiId(1522))),
+        ("🧙🏻", (EmojiId(1396), EmojiId(1395))),
+        ("🧚🏻\u{200d}♂\u{fe0f}", (EmojiId(1420), EmojiId(1419))),
+        ("🏊🏻", (EmojiId(1888), EmojiId(1887))),
+        ("↘", (EmojiId(3316), EmojiId(3316))),
+        ("🇱🇹", (EmojiId(3658), EmojiId(3658))),
+        ("🧍🏿\u{200d}♀\u{fe0f}", (EmojiId(1581), EmojiId(1576))),
+        ("🧗🏻", (EmojiId(1802), EmojiId(1801))),
+        ("🇸🇮", (EmojiId(3725), EmojiId(3725))),
+        ("🚟", (EmojiId(2852), EmojiId(2852))),
+        ("👫🏿", (EmojiId(2139), EmojiId(2134))),
+        ("🧑🏻\u{200d}🍼", (EmojiId(1330), EmojiId(1329))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2277), EmojiId(2264))),
+        ("♣", (EmojiId(3011), EmojiId(3011))),
+        ("😆", (EmojiId(4), EmojiId(4))),
+        ("☎", (EmojiId(3102), EmojiId(3102))),
+        ("💆🏽\u{200d}♀\u{fe0f}", (EmojiId(1507), EmojiId(1504))),
+        ("👩\u{200d}👩\u{200d}👧", (EmojiId(2405), EmojiId(2405))),
+        ("🫣", (EmojiId(32), EmojiId(32))),
+        ("👏🏽", (EmojiId(370), EmojiId(367))),
+        ("🚵🏾\u{200d}♂", (EmojiId(1969), EmojiId(1965))),
+        ("🫅🏽", (EmojiId(1212), EmojiId(1209))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2246), EmojiId(2238))),
+        ("🏊🏾\u{200d}♀\u{fe0f}", (EmojiId(1903), EmojiId(1899))),
+        ("🤙", (EmojiId(283), EmojiId(283))),
+        ("👨🏼\u{200d}🏫", (EmojiId(887), EmojiId(885))),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏾", (EmojiId(2150), EmojiId(2134))),
+        ("🤹🏻\u{200d}♀", (EmojiId(2047), EmojiId(2046))),
+        ("🦿", (EmojiId(454), EmojiId(454))),
+        ("🤚🏾", (EmojiId(179), EmojiId(175))),
+        ("🙅\u{200d}♀", (EmojiId(711), EmojiId(711))),
+        ("👅", (EmojiId(492), EmojiId(492))),
+        ("😵", (EmojiId(67), EmojiId(67))),
+        ("👨\u{200d}🦯", (EmojiId(1630), EmojiId(1630))),
+        ("🚩", (EmojiId(3512), EmojiId(3512))),
+        ("🧑🏽\u{200d}🎄", (EmojiId(1356), EmojiId(1353))),
+        ("📁", (EmojiId(3184), EmojiId(3184))),
+        ("🙎🏼\u{200d}♀\u{fe0f}", (EmojiId(695), EmojiId(693))),
+        ("🧔🏾\u{200d}♂\u{fe0f}", (EmojiId(547), EmojiId(543))),
+        ("👳🏼\u{200d}♂", (EmojiId(1235), EmojiId(1233))),
+        ("🤾🏽\u{200d}♀\u{fe0f}", (EmojiId(2031), EmojiId(2028))),
+        ("📥", (EmojiId(3168), EmojiId(3168))),
+        ("😃", (EmojiId(1), EmojiId(1))),
+        ("🖍\u{fe0f}", (EmojiId(3181), EmojiId(3181))),
+        ("🦤", (EmojiId(2509), EmojiId(2509))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏻", (EmojiId(2356), EmojiId(2342))),
+        ("🧜🏼", (EmojiId(1451), EmojiId(1449))),
+        ("🩱", (EmojiId(3038), EmojiId(3038))),
+        ("👩🏿\u{200d}🦰", (EmojiId(590), EmojiId(585))),
+        ("💆🏿\u{200d}♀\u{fe0f}", (EmojiId(1509), EmojiId(1504))),
+        ("🦽", (EmojiId(2818), EmojiId(2818))),
+        ("🙋🏽\u{200d}♀", (EmojiId(768), EmojiId(765))),
+        ("👨🏽\u{200d}🦽\u{200d}➡", (EmojiId(1711), EmojiId(1708))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2228), EmojiId(2212))),
+        ("💁🏻\u{200d}♀", (EmojiId(748), EmojiId(747))),
+        ("🐺", (EmojiId(2439), EmojiId(2439))),
+        ("🦭", (EmojiId(2530), EmojiId(2530))),
+        ("🤷🏿\u{200d}♂", (EmojiId(836), EmojiId(831))),
+        ("👕", (EmojiId(3029), EmojiId(3029))),
+        ("🎢", (EmojiId(2783), EmojiId(2783))),
+        ("🙋🏻", (EmojiId(754), EmojiId(753))),
+        ("📌", (EmojiId(3196), EmojiId(3196))),
+        ("🦻🏾", (EmojiId(477), EmojiId(473))),
+        ("💂🏻", (EmojiId(1168), EmojiId(1167))),
+        ("🪞", (EmojiId(3255), EmojiId(3255))),
+        ("👱\u{200d}♀", (EmojiId(633), EmojiId(633))),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏿", (EmojiId(2303), EmojiId(2290))),
+        ("🤦🏿\u{200d}♀\u{fe0f}", (EmojiId(824), EmojiId(819))),
+        ("✂\u{fe0f}", (EmojiId(3202), EmojiId(3202))),
+        ("🙅🏿\u{200d}♂", (EmojiId(710), EmojiId(705))),
+        ("🖨\u{fe0f}", (EmojiId(3111), EmojiId(3111))),
+        ("👨🏻\u{200d}🔬", (EmojiId(1012), EmojiId(1011))),
+        ("🇸🇷", (EmojiId(3732), EmojiId(3732))),
+        ("💀", (EmojiId(108), EmojiId(108))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2248), EmojiId(2238))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏾", (EmojiId(2302), EmojiId(2290))),
+        ("🧭", (EmojiId(2726), EmojiId(2726))),
+        ("👍🏽", (EmojiId(334), EmojiId(331))),
+        ("⚔\u{fe0f}", (EmojiId(3218), EmojiId(3218))),
+        ("🛀", (EmojiId(2070), EmojiId(2070))),
+        ("🙋🏽\u{200d}♀\u{fe0f}", (EmojiId(768), EmojiId(765))),
+        ("🧑🏽\u{200d}✈\u{fe0f}", (EmojiId(1080), EmojiId(1077))),
+        ("🛍", (EmojiId(3047), EmojiId(3047))),
+        ("🚶🏽\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1561), EmojiId(1558))),
+        ("👩🏽\u{200d}⚕\u{fe0f}", (EmojiId(858), EmojiId(855))),
+        ("🎹", (EmojiId(3091), EmojiId(3091))),
+        ("🧚🏾", (EmojiId(1417), EmojiId(1413))),
+        ("🤦", (EmojiId(807), EmojiId(807))),
+        ("👩🏽\u{200d}🚀", (EmojiId(1110), EmojiId(1107))),
+        ("🧎🏾\u{200d}♂\u{200d}➡", (EmojiId(1616), EmojiId(1612))),
+        ("👩🏼\u{200d}⚕\u{fe0f}", (EmojiId(857), EmojiId(855))),
+        ("🙂\u{200d}↔\u{fe0f}", (EmojiId(50), EmojiId(50))),
+        ("🫱🏽\u{200d}🫲🏻", (EmojiId(411), EmojiId(397))),
+        ("🌬", (EmojiId(2923), EmojiId(2923))),
+        ("🇳🇵", (EmojiId(3693), EmojiId(3693))),
+        ("🐇", (EmojiId(2481), EmojiId(2481))),
+        ("🚴🏼\u{200d}♂", (EmojiId(1949), EmojiId(1947))),
+        ("🧔🏾", (EmojiId(541), EmojiId(537))),
+        ("👩\u{200d}🚒", (EmojiId(1125), EmojiId(1125))),
+        ("🙇🏼\u{200d}♀\u{fe0f}", (EmojiId(803), EmojiId(801))),
+        ("🏋🏼\u{200d}♀\u{fe0f}", (EmojiId(1937), EmojiId(1935))),
+        ("🗺", (EmojiId(2724), EmojiId(2724))),
+        ("🧝🏼", (EmojiId(1469), EmojiId(1467))),
+        ("🫴🏿", (EmojiId(222), EmojiId(217))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", (EmojiId(2211), EmojiId(2186))),
+        ("☯\u{fe0f}", (EmojiId(3339), EmojiId(3339))),
+        ("✊🏾", (EmojiId(347), EmojiId(343))),
+        ("🥨", (EmojiId(2632), EmojiId(2632))),
+        ("💂🏾\u{200d}♀", (EmojiId(1183), EmojiId(1179))),
+        ("🏌🏾\u{200d}♀\u{fe0f}", (EmojiId(1849), EmojiId(1845))),
+        ("🏄🏼", (EmojiId(1853), EmojiId(1851))),
+        ("🈴", (EmojiId(3471), EmojiId(3471))),
+        ("😉", (EmojiId(11), EmojiId(11))),
+        ("🚣🏿\u{200d}♀", (EmojiId(1886), EmojiId(1881))),
+        ("🚵\u{200d}♂", (EmojiId(1965), EmojiId(1965))),
+        ("🇬🇸", (EmojiId(3614), EmojiId(3614))),
+        ("👰🏿\u{200d}♂", (EmojiId(1286), EmojiId(1281))),
+        ("🕷", (EmojiId(2553), EmojiId(2553))),
+        ("🧎\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1606), EmojiId(1606))),
+        ("👏🏼", (EmojiId(369), EmojiId(367))),
+        ("🤽🏼", (EmojiId(2000), EmojiId(1998))),
+        ("💊", (EmojiId(3248), EmojiId(3248))),
+        ("👲", (EmojiId(1245), EmojiId(1245))),
+        ("💆\u{200d}♀", (EmojiId(1504), EmojiId(1504))),
+        ("👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", (EmojiId(2238), EmojiId(2238))),
+        ("🧑🏼\u{200d}🍼", (EmojiId(1331), EmojiId(1329))),
+        ("🚤", (EmojiId(2840), EmojiId(2840))),
+        ("🚴🏽\u{200d}♀\u{fe0f}", (EmojiId(1956), EmojiId(1953))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏻", (EmojiId(2308), EmojiId(2290))),
+        ("🍙", (EmojiId(2664), EmojiId(2664))),
+        ("👨🏾\u{200d}✈\u{fe0f}", (EmojiId(1087), EmojiId(1083))),
+        ("🧘🏾\u{200d}♀", (EmojiId(2068), EmojiId(2064))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2268), EmojiId(2264))),
+        ("🤶🏻", (EmojiId(1348), EmojiId(1347))),
+        ("🧏🏾\u{200d}♂\u{fe0f}", (EmojiId(781), EmojiId(777))),
+        ("🧚🏻\u{200d}♀", (EmojiId(1426), EmojiId(1425))),
+        ("🧍🏻", (EmojiId(1565), EmojiId(1564))),
+        ("👩🏻\u{200d}🦯\u{200d}➡", (EmojiId(1649), EmojiId(1648))),
+        ("👨🏾\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1640), EmojiId(1636))),
+        ("🦸🏿\u{200d}♂", (EmojiId(1370), EmojiId(1365))),
+        ("🇸🇰", (EmojiId(3727), EmojiId(3727))),
+        ("🤞", (EmojiId(259), EmojiId(259))),
+        ("🦃", (EmojiId(2496), EmojiId(2496))),
+        ("👨🏾\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1676), EmojiId(1672))),
+        ("🧘🏻\u{200d}♂", (EmojiId(2059), EmojiId(2058))),
+        ("🖼\u{fe0f}", (EmojiId(3017), EmojiId(3017))),
+        ("🤹🏽\u{200d}♀", (EmojiId(2049), EmojiId(2046))),
+        ("🇲🇴", (EmojiId(3673), EmojiId(3673))),
+        ("🤛🏿", (EmojiId(360), EmojiId(355))),
+        ("🌜", (EmojiId(2902), EmojiId(2902))),
+        ("👩\u{200d}👧\u{200d}👦", (EmojiId(2417), EmojiId(2417))),
+        ("🤸🏻\u{200d}♀\u{fe0f}", (EmojiId(1990), EmojiId(1989))),
+        ("🦸\u{200d}♀\u{fe0f}", (EmojiId(1371), EmojiId(1371))),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", (EmojiId(2212), EmojiId(2212))),
+        ("🇹🇭", (EmojiId(3744), EmojiId(3744))),
+        ("👩🏼\u{200d}🏭", (EmojiId(983), EmojiId(981))),
+        ("🧑\u{200d}🔬", (EmojiId(1005), EmojiId(1005))),
+        ("🫂", (EmojiId(2422), EmojiId(2422))),
+        ("🧚🏼\u{200d}♀\u{fe0f}", (EmojiId(1427), EmojiId(1425))),
+        ("💆🏾\u{200d}♂", (EmojiId(1502), EmojiId(1498))),
+        ("🈚", (EmojiId(3467), EmojiId(3467))),
+        ("🐤", (EmojiId(2500), EmojiId(2500))),
+        ("🎶", (EmojiId(3081), EmojiId(3081))),
+        ("🔗", (EmojiId(3231), EmojiId(3231))),
+        ("🧘🏿\u{200d}♀", (EmojiId(2069), EmojiId(2064))),
+        ("↔", (EmojiId(3322), EmojiId(3322))),
+        ("🚶🏻\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1559), EmojiId(1558))),
+        ("⚕\u{fe0f}", (EmojiId(3403), EmojiId(3403))),
+        ("🐰", (EmojiId(2480), EmojiId(2480))),
+        ("🔬", (EmojiId(3243), EmojiId(3243))),
+        ("🧛🏾", (EmojiId(1435), EmojiId(1431))),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏻", (EmojiId(2152), EmojiId(2134))),
+        ("5\u{fe0f}\u{20e3}", (EmojiId(3432), EmojiId(3432))),
+        ("💂🏼\u{200d}♀\u{fe0f}", (EmojiId(1181), EmojiId(1179))),
+        ("🧑🏻\u{200d}🦳", (EmojiId(616), EmojiId(615))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2258), EmojiId(2238))),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏻", (EmojiId(2204), EmojiId(2186))),
+        ("🫲🏿", (EmojiId(210), EmojiId(205))),
+        ("🇨🇴", (EmojiId(3568), EmojiId(3568))),
+        ("⏺\u{fe0f}", (EmojiId(3376), EmojiId(3376))),
+        ("🎸", (EmojiId(3090), EmojiId(3090))),
+        ("🧕", (EmojiId(1251), EmojiId(1251))),
+        ("👨🏼\u{200d}❤\u{200d}👨🏻", (EmojiId(2352), EmojiId(2342))),
+        ("👨🏼\u{200d}🦼", (EmojiId(1668), EmojiId(1666))),
+        ("🍡", (EmojiId(2675), EmojiId(2675))),
+        ("🫶🏼", (EmojiId(381), EmojiId(379))),
+        ("🧜🏽", (EmojiId(1452), EmojiId(1449))),
+        ("🕶", (EmojiId(3024), EmojiId(3024))),
+        ("👩🏼\u{200d}🌾", (EmojiId(929), EmojiId(927))),
+        ("⭐", (EmojiId(2908), EmojiId(2908))),
+        ("🎩", (EmojiId(3061), EmojiId(3061))),
+        ("🇸🇧", (EmojiId(3719), EmojiId(3719))),
+        ("🤸🏿\u{200d}♂", (EmojiId(1988), EmojiId(1983))),
+        ("🏇🏾", (EmojiId(1824), EmojiId(1820))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2336), EmojiId(2316))),
+        ("🧚🏿\u{200d}♂", (EmojiId(1424), EmojiId(1419))),
+        ("🎽", (EmojiId(2988), EmojiId(2988))),
+        ("🇲🇩", (EmojiId(3664), EmojiId(3664))),
+        ("🧖🏼", (EmojiId(1785), EmojiId(1783))),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏾", (EmojiId(2185), EmojiId(2160))),
+        ("🏋🏾\u{200d}♀\u{fe0f}", (EmojiId(1939), EmojiId(1935))),
+        ("🦸🏻\u{200d}♂", (EmojiId(1366), EmojiId(1365))),
+        ("❇", (EmojiId(3420), EmojiId(3420))),
+        ("🏊🏼", (EmojiId(1889), EmojiId(1887))),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏾", (EmojiId(2202), EmojiId(2186))),
+        ("🤏🏿", (EmojiId(252), EmojiId(247))),
+        ("🙎🏽\u{200d}♂\u{fe0f}", (EmojiId(690), EmojiId(687))),
+        ("🍜", (EmojiId(2667), EmojiId(2667))),
+        ("🧑🏼\u{200d}🏭", (EmojiId(971), EmojiId(969))),
+        ("🧎🏿\u{200d}♂", (EmojiId(1593), EmojiId(1588))),
+        ("🖕🏿", (EmojiId(312), EmojiId(307))),
+        ("🤞🏻", (EmojiId(260), EmojiId(259))),
+        ("⛈\u{fe0f}", (EmojiId(2914), EmojiId(2914))),
+        ("🇰🇭", (EmojiId(3642), EmojiId(3642))),
+        ("🏔", (EmojiId(2727), EmojiId(2727))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏼", (EmojiId(2383), EmojiId(2368))),
+        ("🧜🏼\u{200d}♀", (EmojiId(1463), EmojiId(1461))),
+        ("🧴", (EmojiId(3266), EmojiId(3266))),
+        ("⌚", (EmojiId(2862), EmojiId(2862))),
+        ("💩", (EmojiId(110), EmojiId(110))),
+        ("🧏🏽\u{200d}♂\u{fe0f}", (EmojiId(780), EmojiId(777))),
+        ("🏃🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1754), EmojiId(1750))),
+        ("🌩\u{fe0f}", (EmojiId(2920), EmojiId(2920))),
+        ("🏂🏻", (EmojiId(1828), EmojiId(1827))),
+        ("🛫", (EmojiId(2847), EmojiId(2847))),
+        ("🙍\u{200d}♂\u{fe0f}", (EmojiId(669), EmojiId(669))),
+        ("👨🏻\u{200d}🚒", (EmojiId(1120), EmojiId(1119))),
+        ("👨🏼\u{200d}🔧", (EmojiId(959), EmojiId(957))),
+        ("0\u{fe0f}\u{20e3}", (EmojiId(3427), EmojiId(3427))),
+        ("🤾🏽", (EmojiId(2019), EmojiId(2016))),
+        ("🚣🏿", (EmojiId(1874), EmojiId(1869))),
+        ("🇸🇽", (EmojiId(3736), EmojiId(3736))),
+        ("🎋", (EmojiId(2947), EmojiId(2947))),
+        ("🔌", (EmojiId(3108), EmojiId(3108))),
+        ("🕵🏽\u{200d}♂\u{fe0f}", (EmojiId(1158), EmojiId(1155))),
+        ("🇦🇺", (EmojiId(3532), EmojiId(3532))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏼", (EmojiId(2322), EmojiId(2316))),
+        ("🏊🏽\u{200d}♀", (EmojiId(1902), EmojiId(1899))),
+        ("🏃🏼\u{200d}♀\u{fe0f}", (EmojiId(1740), EmojiId(1738))),
+        ("🪉", (EmojiId(3099), EmojiId(3099))),
+        ("💁\u{200d}♂", (EmojiId(741), EmojiId(741))),
+        ("👨🏾\u{200d}🍳", (EmojiId(943), EmojiId(939))),
+        ("🤏🏼", (EmojiId(249), EmojiId(247))),
+        ("📞", (EmojiId(3103), EmojiId(3103))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏼", (EmojiId(2391), EmojiId(2368))),
+        ("🤷\u{200d}♂\u{fe0f}", (EmojiId(831), EmojiId(831))),
+        ("🏄🏼\u{200d}♂", (EmojiId(1859), EmojiId(1857))),
+        ("⛱\u{fe0f}", (EmojiId(2929), EmojiId(2929))),
+        ("🐿\u{fe0f}", (EmojiId(2482), EmojiId(2482))),
+        ("🚼", (EmojiId(3294), EmojiId(3294))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2259), EmojiId(2238))),
+        ("💁\u{200d}♂\u{fe0f}", (EmojiId(741), EmojiId(741))),
+        ("🎓", (EmojiId(3062), EmojiId(3062))),
+        ("🧚🏼\u{200d}♀", (EmojiId(1427), EmojiId(1425))),
+        ("🧎🏻\u{200d}➡", (EmojiId(1601), EmojiId(1600))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2386), EmojiId(2368))),
+        ("🇧🇫", (EmojiId(3540), EmojiId(3540))),
+        ("🏊🏽", (EmojiId(1890), EmojiId(1887))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2384), EmojiId(2368))),
+        ("🤸🏼\u{200d}♂\u{fe0f}", (EmojiId(1985), EmojiId(1983))),
+        ("🧎🏻\u{200d}➡\u{fe0f}", (EmojiId(1601), EmojiId(1600))),
+        ("💂🏻\u{200d}♀\u{fe0f}", (EmojiId(1180), EmojiId(1179))),
+        ("🤷🏽\u{200d}♀\u{fe0f}", (EmojiId(840), EmojiId(837))),
+        ("🪟", (EmojiId(3256), EmojiId(3256))),
+        ("🫢", (EmojiId(31), EmojiId(31))),
+        ("🇵🇰", (EmojiId(3703), EmojiId(3703))),
+        ("🔻", (EmojiId(3506), EmojiId(3506))),
+        ("🫃🏼", (EmojiId(1301), EmojiId(1299))),
+        ("🧑🏻\u{200d}🏭", (EmojiId(970), EmojiId(969))),
+        ("👦🏿", (EmojiId(512), EmojiId(507))),
+        ("🖱", (EmojiId(3113), EmojiId(3113))),
+        ("🧎\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1606), EmojiId(1606))),
+        ("✌\u{fe0f}", (EmojiId(253), EmojiId(253))),
+        ("👮🏾\u{200d}♀", (EmojiId(1147), EmojiId(1143))),
+        ("🫛", (EmojiId(2625), EmojiId(2625))),
+        ("🫷🏿", (EmojiId(228), EmojiId(223))),
+        ("🫸", (EmojiId(229), EmojiId(229))),
+        ("☔", (EmojiId(2928), EmojiId(2928))),
+        ("✋🏾", (EmojiId(191), EmojiId(187))),
+        ("🚋", (EmojiId(2797), EmojiId(2797))),
+        ("🚴\u{200d}♀", (EmojiId(1953), EmojiId(1953))),
+        ("🧛🏿\u{200d}♀\u{fe0f}", (EmojiId(1448), EmojiId(1443))),
+        ("🤵\u{200d}♂\u{fe0f}", (EmojiId(1263), EmojiId(1263))),
+        ("🧑🏼\u{200d}🦽\u{200d}➡", (EmojiId(1698), EmojiId(1696))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2364), EmojiId(2342))),
+        ("✋", (EmojiId(187), EmojiId(187))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2249), EmojiId(2238))),
+        ("🥬", (EmojiId(2617), EmojiId(2617))),
+        ("💼", (EmojiId(3183), EmojiId(3183))),
+        ("💂🏿\u{200d}♀\u{fe0f}", (EmojiId(1184), EmojiId(1179))),
+        ("🏊🏿\u{200d}♀\u{fe0f}", (EmojiId(1904), EmojiId(1899))),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏾", (EmojiId(2176), EmojiId(2160))),
+        ("🦐", (EmojiId(2541), EmojiId(2541))),
+        ("🤚🏿", (EmojiId(180), EmojiId(175))),
+        ("🪛", (EmojiId(3225), EmojiId(3225))),
+        ("👩\u{200d}🦽\u{200d}➡", (EmojiId(1720), EmojiId(1720))),
+        ("🦶🏼", (EmojiId(463), EmojiId(461))),
+        ("🐵", (EmojiId(2430), EmojiId(2430))),
+        ("🧎🏽\u{200d}➡", (EmojiId(1603), EmojiId(1600))),
+        ("🧝🏾", (EmojiId(1471), EmojiId(1467))),
+        ("📦", (EmojiId(3169), EmojiId(3169))),
+        ("🧜🏽\u{200d}♂", (EmojiId(1458), EmojiId(1455))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏻", (EmojiId(2300), EmojiId(2290))),
+        ("🕎", (EmojiId(3344), EmojiId(3344))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏾", (EmojiId(2332), EmojiId(2316))),
+        ("👳🏼\u{200d}♂\u{fe0f}", (EmojiId(1235), EmojiId(1233))),
+        ("🙇🏼\u{200d}♂", (EmojiId(797), EmojiId(795))),
+        ("🧫", (EmojiId(3241), EmojiId(3241))),
+        ("🇩🇰", (EmojiId(3581), EmojiId(3581))),
+        ("🦧", (EmojiId(2433), EmojiId(2433))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2346), EmojiId(2342))),
+        ("🙀", (EmojiId(124), EmojiId(124))),
+        ("🧙🏽\u{200d}♂\u{fe0f}", (EmojiId(1404), EmojiId(1401))),
+        ("🦹🏿", (EmojiId(1382), EmojiId(1377))),
+        ("🇨🇩", (EmojiId(3559), EmojiId(3559))),
+        ("🗑", (EmojiId(3205), EmojiId(3205))),
+        ("👨🏾\u{200d}⚕\u{fe0f}", (EmojiId(853), EmojiId(849))),
+        ("🐯", (EmojiId(2446), EmojiId(2446))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2278), EmojiId(2264))),
+        ("🚶🏼\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1554), EmojiId(1552))),
+        ("📱", (EmojiId(3100), EmojiId(3100))),
+        ("☎\u{fe0f}", (EmojiId(3102), EmojiId(3102))),
+        ("👩\u{200d}🔬", (EmojiId(1017), EmojiId(1017))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2354), EmojiId(2342))),
+        ("⏸\u{fe0f}", (EmojiId(3374), EmojiId(3374))),
+        ("♥", (EmojiId(3009), EmojiId(3009))),
+        ("👉🏽", (EmojiId(298), EmojiId(295))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2084), EmojiId(2082))),
+        ("👩\u{200d}🦽", (EmojiId(1714), EmojiId(1714))),
+        ("🛹", (EmojiId(2823), EmojiId(2823))),
+        ("🧛🏽\u{200d}♂", (EmojiId(1440), EmojiId(1437))),
+        ("🧑\u{200d}🏫", (EmojiId(879), EmojiId(879))),
+        ("🔚", (EmojiId(3330), EmojiId(3330))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2279), EmojiId(2264))),
+        ("🛰", (EmojiId(2855), EmojiId(2855))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2265), EmojiId(2264))),
+        ("👩🏾\u{200d}🌾", (EmojiId(931), EmojiId(927))),
+        ("🙍🏿\u{200d}♂", (EmojiId(674), EmojiId(669))),
+        ("🧑🏾\u{200d}✈\u{fe0f}", (EmojiId(1081), EmojiId(1077))),
+        ("🛍\u{fe0f}", (EmojiId(3047), EmojiId(3047))),
+        ("🚴🏻", (EmojiId(1942), EmojiId(1941))),
+        ("✍🏼", (EmojiId(431), EmojiId(429))),
+        ("🪄", (EmojiId(2998), EmojiId(2998))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", (EmojiId(2208), EmojiId(2186))),
+        ("🧱", (EmojiId(2739), EmojiId(2739))),
+        ("👩🏻\u{200d}🔬", (EmojiId(1018), EmojiId(1017))),
+        ("✔", (EmojiId(3412), EmojiId(3412))),
+        ("👩🏻\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1649), EmojiId(1648))),
+        ("👩🏻\u{200d}⚕\u{fe0f}", (EmojiId(856), EmojiId(855))),
+        ("🕴🏿", (EmojiId(1779), EmojiId(1774))),
+        ("🧖🏾", (EmojiId(1787), EmojiId(1783))),
+        ("⤴", (EmojiId(3325), EmojiId(3325))),
+        ("👂", (EmojiId(467), EmojiId(467))),
+        ("🙆🏿\u{200d}♂", (EmojiId(728), EmojiId(723))),
+        ("🚶🏼\u{200d}♀\u{fe0f}", (EmojiId(1542), EmojiId(1540))),
+        ("🧎\u{200d}➡", (EmojiId(1600), EmojiId(1600))),
+        ("💺", (EmojiId(2850), EmojiId(2850))),
+        ("🇳🇺", (EmojiId(3695), EmojiId(3695))),
+        ("🔖", (EmojiId(3151), EmojiId(3151))),
+        ("👩🏻\u{200d}🎓", (EmojiId(874), EmojiId(873))),
+        ("♨", (EmojiId(2779), EmojiId(2779))),
+        ("🦸🏻\u{200d}♀", (EmojiId(1372), EmojiId(1371))),
+        ("🇬🇺", (EmojiId(3616), EmojiId(3616))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2234), EmojiId(2212))),
+        ("💆\u{200d}♀\u{fe0f}", (EmojiId(1504), EmojiId(1504))),
+        ("🔭", (EmojiId(3244), EmojiId(3244))),
+        ("🏃\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1750), EmojiId(1750))),
+        ("🎅🏼", (EmojiId(1343), EmojiId(1341))),
+        ("👭🏿", (EmojiId(2113), EmojiId(2108))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2270), EmojiId(2264))),
+        ("🧑🏼\u{200d}🦼\u{200d}➡", (EmojiId(1662), EmojiId(1660))),
+        ("🙍🏻\u{200d}♀\u{fe0f}", (EmojiId(676), EmojiId(675))),
+        ("🇲🇫", (EmojiId(3666), EmojiId(3666))),
+        ("🙍🏼\u{200d}♂\u{fe0f}", (EmojiId(671), EmojiId(669))),
+        ("⛎", (EmojiId(3359), EmojiId(3359))),
+        ("🏌🏾\u{200d}♂\u{fe0f}", (EmojiId(1843), EmojiId(1839))),
+        ("🚣🏿\u{200d}♂", (EmojiId(1880), EmojiId(1875))),
+        ("💇🏽\u{200d}♀", (EmojiId(1525), EmojiId(1522))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏽", (EmojiId(2327), EmojiId(2316))),
+        ("🔺", (EmojiId(3505), EmojiId(3505))),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏿", (EmojiId(2125), EmojiId(2108))),
+        ("👰🏻", (EmojiId(1276), EmojiId(1275))),
+        ("🥝", (EmojiId(2605), EmojiId(2605))),
+        ("🏴\u{200d}☠\u{fe0f}", (EmojiId(3518), EmojiId(3518))),
+        ("💂🏿\u{200d}♀", (EmojiId(1184), EmojiId(1179))),
+        ("🧛🏻\u{200d}♀\u{fe0f}", (EmojiId(1444), EmojiId(1443))),
+        ("👇🏼", (EmojiId(315), EmojiId(313))),
+        ("🏊🏾\u{200d}♂\u{fe0f}", (EmojiId(1897), EmojiId(1893))),
+        ("🔯", (EmojiId(3345), EmojiId(3345))),
+        ("🖍", (EmojiId(3181), EmojiId(3181))),
+        ("🇵🇹", (EmojiId(3709), EmojiId(3709))),
+        ("🏳\u{200d}🌈", (EmojiId(3516), EmojiId(3516))),
+        ("🧍🏻\u{200d}♀\u{fe0f}", (EmojiId(1577), EmojiId(1576))),
+        ("💽", (EmojiId(3115), EmojiId(3115))),
+        ("🌽", (EmojiId(2613), EmojiId(2613))),
+        ("👩🏽\u{200d}⚖\u{fe0f}", (EmojiId(912), EmojiId(909))),
+        ("🧑\u{200d}⚖\u{fe0f}", (EmojiId(897), EmojiId(897))),
+        ("🍓", (EmojiId(2603), EmojiId(2603))),
+        ("🪓", (EmojiId(3213), EmojiId(3213))),
+        ("🤷🏻\u{200d}♀", (EmojiId(838), EmojiId(837))),
+        ("🤰🏼", (EmojiId(1295), EmojiId(1293))),
+        ("🦷", (EmojiId(488), EmojiId(488))),
+        ("🚴🏾\u{200d}♀", (EmojiId(1957), EmojiId(1953))),
+        ("😫", (EmojiId(100), EmojiId(100))),
+        ("🚣🏾\u{200d}♂", (EmojiId(1879), EmojiId(1875))),
+        ("🧝🏻\u{200d}♂\u{fe0f}", (EmojiId(1474), EmojiId(1473))),
+        ("🙇🏾\u{200d}♀\u{fe0f}", (EmojiId(805), EmojiId(801))),
+        ("🔹", (EmojiId(3504), EmojiId(3504))),
+        ("🥼", (EmojiId(3026), EmojiId(3026))),
+        ("👋🏽", (EmojiId(172), EmojiId(169))),
+        ("🤽🏿\u{200d}♂\u{fe0f}", (EmojiId(2009), EmojiId(2004))),
+        ("🧑🏽\u{200d}🦽", (EmojiId(1693), EmojiId(1690))),
+        ("👩🏿\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1725), EmojiId(1720))),
+        ("⛹🏻\u{200d}♂", (EmojiId(1912), EmojiId(1911))),
+        ("🇸🇸", (EmojiId(3733), EmojiId(3733))),
+        ("🧖🏻\u{200d}♀\u{fe0f}", (EmojiId(1796), EmojiId(1795))),
+        ("🧑🏼\u{200d}🍳", (EmojiId(935), EmojiId(933))),
+        ("🇪🇷", (EmojiId(3590), EmojiId(3590))),
+        ("🖲", (EmojiId(3114), EmojiId(3114))),
+        ("👨🏾\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1712), EmojiId(1708))),
+        ("🕐", (EmojiId(2869), EmojiId(2869))),
+        ("👰🏽\u{200d}♂\u{fe0f}", (EmojiId(1284), EmojiId(1281))),
+        ("👨🏿\u{200d}💼", (EmojiId(998), EmojiId(993))),
+        ("👩🏼", (EmojiId(581), EmojiId(579))),
+        ("🏊🏻\u{200d}♂", (EmojiId(1894), EmojiId(1893))),
+        ("🇺🇦", (EmojiId(3756), EmojiId(3756))),
+        ("👫🏼", (EmojiId(2136), EmojiId(2134))),
+        ("🏃🏻\u{200d}➡", (EmojiId(1745), EmojiId(1744))),
+        ("👨🏿\u{200d}🦰", (EmojiId(560), EmojiId(555))),
+        ("🧹", (EmojiId(3268), EmojiId(3268))),
+        ("💭", (EmojiId(167), EmojiId(167))),
+        ("⏳", (EmojiId(2861), EmojiId(2861))),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏼", (EmojiId(2205), EmojiId(2186))),
+        ("🏃", (EmojiId(1726), EmojiId(1726))),
+        ("🧑\u{200d}🔧", (EmojiId(951), EmojiId(951))),
+        ("🇱🇺", (EmojiId(3659), EmojiId(3659))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2382), EmojiId(2368))),
+        ("🫧", (EmojiId(3273), EmojiId(3273))),
+        ("🫓", (EmojiId(2631), EmojiId(2631))),
+        ("🥻", (EmojiId(3037), EmojiId(3037))),
+        ("🔰", (EmojiId(3408), EmojiId(3408))),
+        ("🏃🏿\u{200d}♀\u{fe0f}", (EmojiId(1743), EmojiId(1738))),
+        ("🚵🏾\u{200d}♀", (EmojiId(1975), EmojiId(1971))),
+        ("🏄🏻\u{200d}♀", (EmojiId(1864), EmojiId(1863))),
+        ("✌🏽", (EmojiId(256), EmojiId(253))),
+        ("👨🏼\u{200d}🦯", (EmojiId(1632), EmojiId(1630))),
+        ("🖲\u{fe0f}", (EmojiId(3114), EmojiId(3114))),
+        ("🧂", (EmojiId(2660), EmojiId(2660))),
+        ("🏄🏽\u{200d}♂", (EmojiId(1860), EmojiId(1857))),
+        ("🙍🏼\u{200d}♂", (EmojiId(671), EmojiId(669))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2222), EmojiId(2212))),
+        ("🧑🏻\u{200d}🏫", (EmojiId(880), EmojiId(879))),
+        ("👭🏽", (EmojiId(2111), EmojiId(2108))),
+        ("⛈", (EmojiId(2914), EmojiId(2914))),
+        ("🧑🏻\u{200d}🎄", (EmojiId(1354), EmojiId(1353))),
+        ("🏏", (EmojiId(2975), EmojiId(2975))),
+        ("🧨", (EmojiId(2942), EmojiId(2942))),
+        ("🕝", (EmojiId(2872), EmojiId(2872))),
+        ("👳🏾", (EmojiId(1231), EmojiId(1227))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏼", (EmojiId(2374), EmojiId(2368))),
+        ("🇱🇦", (EmojiId(3651), EmojiId(3651))),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏽", (EmojiId(2297), EmojiId(2290))),
+        ("🚶🏿\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1563), EmojiId(1558))),
+        ("🪐", (EmojiId(2907), EmojiId(2907))),
+        ("♒", (EmojiId(3357), EmojiId(3357))),
+        ("🖊\u{fe0f}", (EmojiId(3179), EmojiId(3179))),
+        ("🇷🇼", (EmojiId(3717), EmojiId(3717))),
+        ("🤞🏾", (EmojiId(263), EmojiId(259))),
+        ("👊🏿", (EmojiId(354), EmojiId(349))),
+        ("✌🏼", (EmojiId(255), EmojiId(253))),
+        ("👩🏿\u{200d}⚕", (EmojiId(860), EmojiId(855))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2096), EmojiId(2082))),
+        ("👩🏽\u{200d}🔧", (EmojiId(966), EmojiId(963))),
+        ("🤙🏿", (EmojiId(288), EmojiId(283))),
+        ("⬜", (EmojiId(3494), EmojiId(3494))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2252), EmojiId(2238))),
+        ("🕺", (EmojiId(1768), EmojiId(1768))),
+        ("🫄🏻", (EmojiId(1306), EmojiId(1305))),
+        ("🪭", (EmojiId(3043), EmojiId(3043))),
+        ("🫱🏾\u{200d}🫲🏿", (EmojiId(418), EmojiId(397))),
+        ("🇹🇰", (EmojiId(3746), EmojiId(3746))),
+        ("🤥", (EmojiId(48), EmojiId(48))),
+        ("🙅🏻\u{200d}♂\u{fe0f}", (EmojiId(706), EmojiId(705))),
+        ("👨🏽\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1711), EmojiId(1708))),
+        ("🚴🏼", (EmojiId(1943), EmojiId(1941))),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏽", (EmojiId(2184), EmojiId(2160))),
+        ("🤾\u{200d}♀\u{fe0f}", (EmojiId(2028), EmojiId(2028))),
+        ("🧙🏾\u{200d}♀\u{fe0f}", (EmojiId(1411), EmojiId(1407))),
+        ("🍌", (EmojiId(2595), EmojiId(2595))),
+        ("🇯🇲", (EmojiId(3637), EmojiId(3637))),
+        ("🤰🏿", (EmojiId(1298), EmojiId(1293))),
+        ("🇨🇼", (EmojiId(3574), EmojiId(3574))),
+        ("👨🏻\u{200d}🦯", (EmojiId(1631), EmojiId(1630))),
+        ("🐽", (EmojiId(2464), EmojiId(2464))),
+        ("🚶🏾\u{200d}♀\u{fe0f}", (EmojiId(1544), EmojiId(1540))),
+        ("💁🏿", (EmojiId(740), EmojiId(735))),
+        ("🚑", (EmojiId(2802), EmojiId(2802))),
+        ("🙇🏿\u{200d}♂", (EmojiId(800), EmojiId(795))),
+        ("⛹\u{200d}♂", (EmojiId(1911), EmojiId(1911))),
+        ("🧑\u{200d}🏭", (EmojiId(969), EmojiId(969))),
+        ("🪼", (EmojiId(2538), EmojiId(2538))),
+        ("🇹🇻", (EmojiId(3753), EmojiId(3753))),
+        ("💇🏿\u{200d}♀\u{fe0f}", (EmojiId(1527), EmojiId(1522))),
+        ("🙋", (EmojiId(753), EmojiId(753))),
+        ("🧜\u{200d}♂\u{fe0f}", (EmojiId(1455), EmojiId(1455))),
+        ("📢", (EmojiId(3074), EmojiId(3074))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏼", (EmojiId(2313), EmojiId(2290))),
+        ("🪢", (EmojiId(3022), EmojiId(3022))),
+        ("🐅", (EmojiId(2447), EmojiId(2447))),
+        ("👨🏼\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1710), EmojiId(1708))),
+        ("⬆\u{fe0f}", (EmojiId(3313), EmojiId(3313))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2262), EmojiId(2238))),
+        ("👰\u{200d}♀", (EmojiId(1287), EmojiId(1287))),
+        ("🦉", (EmojiId(2508), EmojiId(2508))),
+        ("📝", (EmojiId(3182), EmojiId(3182))),
+        ("👨", (EmojiId(531), EmojiId(531))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", (EmojiId(2201), EmojiId(2186))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏻", (EmojiId(2330), EmojiId(2316))),
+        ("😵\u{200d}💫", (EmojiId(68), EmojiId(68))),
+        ("🚶\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1552), EmojiId(1552))),
+        ("🤲🏾", (EmojiId(395), EmojiId(391))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏾", (EmojiId(2280), EmojiId(2264))),
+        ("🏋🏻\u{200d}♂", (EmojiId(1930), EmojiId(1929))),
+        ("🗼", (EmojiId(2761), EmojiId(2761))),
+        ("🔀", (EmojiId(3360), EmojiId(3360))),
+        ("☣", (EmojiId(3312), EmojiId(3312))),
+        ("🧑\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1696), EmojiId(1696))),
+        ("🏃🏾\u{200d}♂", (EmojiId(1736), EmojiId(1732))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏾", (EmojiId(2315), EmojiId(2290))),
+        ("💬", (EmojiId(163), EmojiId(163))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2327), EmojiId(2316))),
+        ("💁🏻\u{200d}♂\u{fe0f}", (EmojiId(742), EmojiId(741))),
+        ("🐭", (EmojiId(2476), EmojiId(2476))),
+        ("💂\u{200d}♀", (EmojiId(1179), EmojiId(1179))),
+        ("🆓", (EmojiId(3448), EmojiId(3448))),
+        ("🤷🏻", (EmojiId(826), EmojiId(825))),
+        ("👭🏼", (EmojiId(2110), EmojiId(2108))),
+        ("◀", (EmojiId(3367), EmojiId(3367))),
+        ("🦸🏼", (EmojiId(1361), EmojiId(1359))),
+        ("😏", (EmojiId(43), EmojiId(43))),
+        ("🔅", (EmojiId(3379), EmojiId(3379))),
+        ("🏳\u{200d}⚧", (EmojiId(3517), EmojiId(3517))),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏼", (EmojiId(2209), EmojiId(2186))),
+        ("🧑\u{200d}🍳", (EmojiId(933), EmojiId(933))),
+        ("🤼\u{200d}♂", (EmojiId(1996), EmojiId(1996))),
+        ("🫱🏻\u{200d}🫲🏿", (EmojiId(406), EmojiId(397))),
+        ("🧗🏿\u{200d}♂\u{fe0f}", (EmojiId(1812), EmojiId(1807))),
+        ("🔶", (EmojiId(3501), EmojiId(3501))),
+        ("🫱🏻\u{200d}🫲🏼", (EmojiId(403), EmojiId(397))),
+        ("👐🏾", (EmojiId(389), EmojiId(385))),
+        ("🗑\u{fe0f}", (EmojiId(3205), EmojiId(3205))),
+        ("🍵", (EmojiId(2697), EmojiId(2697))),
+        ("⛹🏿\u{200d}♀", (EmojiId(1922), EmojiId(1917))),
+        ("🐼", (EmojiId(2489), EmojiId(2489))),
+        ("💃🏿", (EmojiId(1767), EmojiId(1762))),
+        ("🚛", (EmojiId(2813), EmojiId(2813))),
+        ("🇳🇷", (EmojiId(3694), EmojiId(3694))),
+        ("👨🏻\u{200d}🦯\u{200d}➡", (EmojiId(1637), EmojiId(1636))),
+        ("⛽", (EmojiId(2829), EmojiId(2829))),
+        ("🐦\u{200d}🔥", (EmojiId(2517), EmojiId(2517))),
+        ("🧠", (EmojiId(485), EmojiId(485))),
+        ("🧎🏻\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1607), EmojiId(1606))),
+        ("👨\u{200d}🏭", (EmojiId(975), EmojiId(975))),
+        ("🕕", (EmojiId(2879), EmojiId(2879))),
+        ("👨🏾\u{200d}🎓", (EmojiId(871), EmojiId(867))),
+        ("🫔", (EmojiId(2648), EmojiId(2648))),
+        ("👨🏻\u{200d}⚕", (EmojiId(850), EmojiId(849))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2213), EmojiId(2212))),
+        ("🫱🏼\u{200d}🫲🏽", (EmojiId(408), EmojiId(397))),
+        ("💮", (EmojiId(2562), EmojiId(2562))),
+        ("🧎🏽\u{200d}♂", (EmojiId(1591), EmojiId(1588))),
+        ("🫲🏽", (EmojiId(208), EmojiId(205))),
+        ("⛹🏼\u{200d}♂\u{fe0f}", (EmojiId(1913), EmojiId(1911))),
+        ("👨\u{200d}👧\u{200d}👦", (EmojiId(2412), EmojiId(2412))),
+        ("🦹🏽", (EmojiId(1380), EmojiId(1377))),
+        ("⏬", (EmojiId(3373), EmojiId(3373))),
+        ("🧎\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1612), EmojiId(1612))),
+        ("👩\u{200d}⚖\u{fe0f}", (EmojiId(909), EmojiId(909))),
+        ("🧚🏿", (EmojiId(1418), EmojiId(1413))),
+        ("👨🏽\u{200d}🚒", (EmojiId(1122), EmojiId(1119))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2086), EmojiId(2082))),
+        ("🫳", (EmojiId(211), EmojiId(211))),
+        ("🇹🇼", (EmojiId(3754), EmojiId(3754))),
+        ("📻", (EmojiId(3087), EmojiId(3087))),
+        ("🙊", (EmojiId(129), EmojiId(129))),
+        ("👨🏻\u{200d}💼", (EmojiId(994), EmojiId(993))),
+        ("🤛", (EmojiId(355), EmojiId(355))),
+        ("🧔🏿\u{200d}♂\u{fe0f}", (EmojiId(548), EmojiId(543))),
+        ("🙆🏻", (EmojiId(718), EmojiId(717))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏿", (EmojiId(2381), EmojiId(2368))),
+        ("👩\u{200d}❤\u{200d}💋\u{200d}👩", (EmojiId(2264), EmojiId(2264))),
+        ("🤦🏿\u{200d}♀", (EmojiId(824), EmojiId(819))),
+        ("🌫\u{fe0f}", (EmojiId(2922), EmojiId(2922))),
+        ("☪\u{fe0f}", (EmojiId(3342), EmojiId(3342))),
+        ("🤱🏻", (EmojiId(1312), EmojiId(1311))),
+        ("🏋🏼\u{200d}♂\u{fe0f}", (EmojiId(1931), EmojiId(1929))),
+        ("🔃", (EmojiId(3327), EmojiId(3327))),
+        ("🖇", (EmojiId(3199), EmojiId(3199))),
+        ("🐠", (EmojiId(2532), EmojiId(2532))),
+        ("🤾🏿\u{200d}♀\u{fe0f}", (EmojiId(2033), EmojiId(2028))),
+        ("🧗🏻\u{200d}♂", (EmojiId(1808), EmojiId(1807))),
+        ("🧑🏻\u{200d}🍳", (EmojiId(934), EmojiId(933))),
+        ("🦁", (EmojiId(2445), EmojiId(2445))),
+        ("⌨\u{fe0f}", (EmojiId(3112), EmojiId(3112))),
+        ("💂🏿\u{200d}♂\u{fe0f}", (EmojiId(1178), EmojiId(1173))),
+        ("🇹🇴", (EmojiId(3750), EmojiId(3750))),
+        ("🐘", (EmojiId(2472), EmojiId(2472))),
+        ("👨\u{200d}🦽", (EmojiId(1702), EmojiId(1702))),
+        ("🤱🏼", (EmojiId(1313), EmojiId(1311))),
+        ("🧑🏿\u{200d}🚀", (EmojiId(1100), EmojiId(1095))),
+        ("✌🏾", (EmojiId(257), EmojiId(253))),
+        ("🙇\u{200d}♂", (EmojiId(795), EmojiId(795))),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏿", (EmojiId(2181), EmojiId(2160))),
+        ("👨🏻\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1637), EmojiId(1636))),
+        ("✌🏻", (EmojiId(254), EmojiId(253))),
+        ("🇨🇽", (EmojiId(3575), EmojiId(3575))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2235), EmojiId(2212))),
+        ("🕍", (EmojiId(2766), EmojiId(2766))),
+        ("👩🏿\u{200d}💼", (EmojiId(1004), EmojiId(999))),
+        ("🙍🏿\u{200d}♀\u{fe0f}", (EmojiId(680), EmojiId(675))),
+        ("🤛🏾", (EmojiId(359), EmojiId(355))),
+        ("🏃🏾\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1754), EmojiId(1750))),
+        ("🇯🇵", (EmojiId(3639), EmojiId(3639))),
+        ("🚶🏾\u{200d}➡", (EmojiId(1550), EmojiId(1546))),
+        ("🧎🏻\u{200d}♂", (EmojiId(1589), EmojiId(1588))),
+        ("⛔", (EmojiId(3302), EmojiId(3302))),
+        ("😪", (EmojiId(54), EmojiId(54))),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏼", (EmojiId(2157), EmojiId(2134))),
+        ("👨🏼\u{200d}✈", (EmojiId(1085), EmojiId(1083))),
+        ("👨🏽\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1639), EmojiId(1636))),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏻", (EmojiId(2130), EmojiId(2108))),
+        ("🤴🏿", (EmojiId(1220), EmojiId(1215))),
+        ("💆🏻", (EmojiId(1493), EmojiId(1492))),
+        ("🇲🇾", (EmojiId(3683), EmojiId(3683))),
+        ("🧑🏿\u{200d}🔧", (EmojiId(956), EmojiId(951))),
+        ("🙍🏻", (EmojiId(664), EmojiId(663))),
+        ("🗂\u{fe0f}", (EmojiId(3186), EmojiId(3186))),
+        ("🏗\u{fe0f}", (EmojiId(2738), EmojiId(2738))),
+        ("🆙", (EmojiId(3458), EmojiId(3458))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2261), EmojiId(2238))),
+        ("🍯", (EmojiId(2692), EmojiId(2692))),
+        ("👘", (EmojiId(3036), EmojiId(3036))),
+        ("🙅🏾\u{200d}♀", (EmojiId(715), EmojiId(711))),
+        ("🇱🇰", (EmojiId(3655), EmojiId(3655))),
+        ("👩🏼\u{200d}🎨", (EmojiId(1073), EmojiId(1071))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2226), EmojiId(2212))),
+        ("🧏🏼\u{200d}♂\u{fe0f}", (EmojiId(779), EmojiId(777))),
+        ("🫒", (EmojiId(2607), EmojiId(2607))),
+        ("👼🏾", (EmojiId(1339), EmojiId(1335))),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏿", (EmojiId(2117), EmojiId(2108))),
+        ("🤘🏻", (EmojiId(278), EmojiId(277))),
+        ("🏕\u{fe0f}", (EmojiId(2731), EmojiId(2731))),
+        ("🤶🏼", (EmojiId(1349), EmojiId(1347))),
+        ("🧛🏾\u{200d}♀", (EmojiId(1447), EmojiId(1443))),
+        ("🪇", (EmojiId(3097), EmojiId(3097))),
+        ("🌩", (EmojiId(2920), EmojiId(2920))),
+        ("🥖", (EmojiId(2630), EmojiId(2630))),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏾", (EmojiId(2168), EmojiId(2160))),
+        ("📽\u{fe0f}", (EmojiId(3122), EmojiId(3122))),
+        ("🇨🇨", (EmojiId(3558), EmojiId(3558))),
+        ("🏌🏻", (EmojiId(1834), EmojiId(1833))),
+        ("🖕🏻", (EmojiId(308), EmojiId(307))),
+        ("👪", (EmojiId(2423), EmojiId(2423))),
+        ("👰🏽\u{200d}♀", (EmojiId(1290), EmojiId(1287))),
+        ("🇩🇯", (EmojiId(3580), EmojiId(3580))),
+        ("◽", (EmojiId(3498), EmojiId(3498))),
+        ("🏄🏿\u{200d}♀", (EmojiId(1868), EmojiId(1863))),
+        ("🌊", (EmojiId(2937), EmojiId(2937))),
+        ("🦄", (EmojiId(2453), EmojiId(2453))),
+        ("🥷", (EmojiId(1185), EmojiId(1185))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2250), EmojiId(2238))),
+        ("🕡", (EmojiId(2880), EmojiId(2880))),
+        ("🙇🏼\u{200d}♂\u{fe0f}", (EmojiId(797), EmojiId(795))),
+        ("🤶🏾", (EmojiId(1351), EmojiId(1347))),
+        ("🎇", (EmojiId(2941), EmojiId(2941))),
+        ("👩🏻\u{200d}✈", (EmojiId(1090), EmojiId(1089))),
+        ("💓", (EmojiId(135), EmojiId(135))),
+        ("🐉", (EmojiId(2524), EmojiId(2524))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2274), EmojiId(2264))),
+        ("🙋🏾\u{200d}♀\u{fe0f}", (EmojiId(769), EmojiId(765))),
+        ("🏄🏿\u{200d}♂", (EmojiId(1862), EmojiId(1857))),
+        ("🕵🏽\u{200d}♀", (EmojiId(1164), EmojiId(1161))),
+        ("🧑🏾\u{200d}🦲", (EmojiId(631), EmojiId(627))),
+        ("🏃\u{200d}♀\u{200d}➡", (EmojiId(1750), EmojiId(1750))),
+        ("👮🏼\u{200d}♀\u{fe0f}", (EmojiId(1145), EmojiId(1143))),
+        ("🏃🏿\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1755), EmojiId(1750))),
+        ("🕶\u{fe0f}", (EmojiId(3024), EmojiId(3024))),
+        ("🧎🏽\u{200d}♂\u{fe0f}", (EmojiId(1591), EmojiId(1588))),
+        ("🙇", (EmojiId(789), EmojiId(789))),
+        ("💃🏼", (EmojiId(1764), EmojiId(1762))),
+        ("🙅🏽", (EmojiId(702), EmojiId(699))),
+        ("ℹ\u{fe0f}", (EmojiId(3449), EmojiId(3449))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2383), EmojiId(2368))),
+        ("🙅🏿\u{200d}♂\u{fe0f}", (EmojiId(710), EmojiId(705))),
+        ("Ⓜ\u{fe0f}", (EmojiId(3451), EmojiId(3451))),
+        ("🦝", (EmojiId(2441), EmojiId(2441))),
+        ("🚶🏿\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1557), EmojiId(1552))),
+        ("🙍🏾\u{200d}♀\u{fe0f}", (EmojiId(679), EmojiId(675))),
+        ("✊", (EmojiId(343), EmojiId(343))),
+        ("🦸🏼\u{200d}♀\u{fe0f}", (EmojiId(1373), EmojiId(1371))),
+        ("🙍🏽\u{200d}♀", (EmojiId(678), EmojiId(675))),
+        ("🚣\u{200d}♂", (EmojiId(1875), EmojiId(1875))),
+        ("🏃🏻\u{200d}♂\u{200d}➡", (EmojiId(1757), EmojiId(1756))),
+        ("🦹🏾\u{200d}♂", (EmojiId(1387), EmojiId(1383))),
+        ("👨🏿\u{200d}🦼", (EmojiId(1671), EmojiId(1666))),
+        ("🏃🏿\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1761), EmojiId(1756))),
+        ("⛓\u{200d}💥", (EmojiId(3232), EmojiId(3232))),
+        ("🧑🏾\u{200d}🚒", (EmojiId(1117), EmojiId(1113))),
+        ("👮🏽\u{200d}♂", (EmojiId(1140), EmojiId(1137))),
+        ("🤞🏽", (EmojiId(262), EmojiId(259))),
+        ("🤾🏿\u{200d}♂", (EmojiId(2027), EmojiId(2022))),
+        ("🧍🏿\u{200d}♂", (EmojiId(1575), EmojiId(1570))),
+        ("🇩🇿", (EmojiId(3584), EmojiId(3584))),
+        ("🧎", (EmojiId(1582), EmojiId(1582))),
+        ("⏫", (EmojiId(3371), EmojiId(3371))),
+        ("👷\u{200d}♀\u{fe0f}", (EmojiId(1203), EmojiId(1203))),
+        ("🧑🏻\u{200d}🦼\u{200d}➡", (EmojiId(1661), EmojiId(1660))),
+        ("👰🏼\u{200d}♀\u{fe0f}", (EmojiId(1289), EmojiId(1287))),
+        ("📜", (EmojiId(3146), EmojiId(3146))),
+        ("🎭", (EmojiId(3016), EmojiId(3016))),
+        ("💁", (EmojiId(735), EmojiId(735))),
+        ("🧑🏿\u{200d}🏭", (EmojiId(974), EmojiId(969))),
+        ("🤽🏼\u{200d}♀\u{fe0f}", (EmojiId(2012), EmojiId(2010))),
+        ("🤏", (EmojiId(247), EmojiId(247))),
+        ("🫰🏻", (EmojiId(266), EmojiId(265))),
+        ("👨\u{200d}🚒", (EmojiId(1119), EmojiId(1119))),
+        ("💙", (EmojiId(148), EmojiId(148))),
+        ("🍃", (EmojiId(2584), EmojiId(2584))),
+        ("🤴", (EmojiId(1215), EmojiId(1215))),
+        ("👶🏿", (EmojiId(500), EmojiId(495))),
+        ("🤲🏿", (EmojiId(396), EmojiId(391))),
+        ("📀", (EmojiId(3118), EmojiId(3118))),
+        ("🆒", (EmojiId(3447), EmojiId(3447))),
+        ("👨🏻\u{200d}⚖\u{fe0f}", (EmojiId(904), EmojiId(903))),
+        ("🇭🇳", (EmojiId(3621), EmojiId(3621))),
+        ("👩🏻\u{200d}❤\u{200d}👨🏾", (EmojiId(2324), EmojiId(2316))),
+        ("🏊\u{200d}♂\u{fe0f}", (EmojiId(1893), EmojiId(1893))),
+        ("🙎🏿\u{200d}♀\u{fe0f}", (EmojiId(698), EmojiId(693))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2392), EmojiId(2368))),
+        ("☝🏼", (EmojiId(321), EmojiId(319))),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏻", (EmojiId(2308), EmojiId(2290))),
+        ("🥠", (EmojiId(2677), EmojiId(2677))),
+        ("💋", (EmojiId(155), EmojiId(155))),
+        ("🔠", (EmojiId(3438), EmojiId(3438))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2251), EmojiId(2238))),
+        ("👨\u{200d}🦼", (EmojiId(1666), EmojiId(1666))),
+        ("🛀🏻", (EmojiId(2071), EmojiId(2070))),
+        ("👩🏽\u{200d}🦰", (EmojiId(588), EmojiId(585))),
+        ("🫅🏻", (EmojiId(1210), EmojiId(1209))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2105), EmojiId(2082))),
+        ("🧎🏿\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1611), EmojiId(1606))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2271), EmojiId(2264))),
+        ("🦊", (EmojiId(2440), EmojiId(2440))),
+        ("🚶\u{200d}♀\u{fe0f}", (EmojiId(1540), EmojiId(1540))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2240), EmojiId(2238))),
+        ("🦸🏿\u{200d}♂\u{fe0f}", (EmojiId(1370), EmojiId(1365))),
+        ("🏟\u{fe0f}", (EmojiId(2736), EmojiId(2736))),
+        ("👨\u{200d}🎤", (EmojiId(1047), EmojiId(1047))),
+        ("🙅🏽\u{200d}♀\u{fe0f}", (EmojiId(714), EmojiId(711))),
+        ("⛹\u{200d}♀", (EmojiId(1917), EmojiId(1917))),
+        ("🧑🏻\u{200d}🦰", (EmojiId(592), EmojiId(591))),
+        ("☑\u{fe0f}", (EmojiId(3411), EmojiId(3411))),
+        ("🤦🏾", (EmojiId(811), EmojiId(807))),
+        ("👩\u{200d}⚖", (EmojiId(909), EmojiId(909))),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏽", (EmojiId(2158), EmojiId(2134))),
+        ("❇\u{fe0f}", (EmojiId(3420), EmojiId(3420))),
+        ("☝", (EmojiId(319), EmojiId(319))),
+        ("☹\u{fe0f}", (EmojiId(80), EmojiId(80))),
+        ("🤸🏻", (EmojiId(1978), EmojiId(1977))),
+        ("🫄", (EmojiId(1305), EmojiId(1305))),
+        ("🙍🏾\u{200d}♀", (EmojiId(679), EmojiId(675))),
+        ("👩🏻\u{200d}🦱", (EmojiId(598), EmojiId(597))),
+        ("🏊🏼\u{200d}♂", (EmojiId(1895), EmojiId(1893))),
+        ("🤽🏾\u{200d}♀\u{fe0f}", (EmojiId(2014), EmojiId(2010))),
+        ("👩🏻\u{200d}🚀", (EmojiId(1108), EmojiId(1107))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2237), EmojiId(2212))),
+        ("💏🏻", (EmojiId(2187), EmojiId(2186))),
+        ("💆🏽", (EmojiId(1495), EmojiId(1492))),
+        ("🫱🏻", (EmojiId(200), EmojiId(199))),
+        ("🧎🏼\u{200d}➡\u{fe0f}", (EmojiId(1602), EmojiId(1600))),
+        ("🍬", (EmojiId(2689), EmojiId(2689))),
+        ("👩🏾\u{200d}🚒", (EmojiId(1129), EmojiId(1125))),
+        ("🚳", (EmojiId(3304), EmojiId(3304))),
+        ("👩🏾\u{200d}⚕", (EmojiId(859), EmojiId(855))),
+        ("🛒", (EmojiId(3277), EmojiId(3277))),
+        ("🧖\u{200d}♂\u{fe0f}", (EmojiId(1789), EmojiId(1789))),
+        ("🧏🏿\u{200d}♂", (EmojiId(782), EmojiId(777))),
+        ("⛓", (EmojiId(3233), EmojiId(3233))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏽", (EmojiId(2383), EmojiId(2368))),
+        ("👨🏻\u{200d}🌾", (EmojiId(922), EmojiId(921))),
+        ("👨🏽\u{200d}🦯", (EmojiId(1633), EmojiId(1630))),
+        ("🧑🏿\u{200d}💼", (EmojiId(992), EmojiId(987))),
+        ("👶🏼", (EmojiId(497), EmojiId(495))),
+        ("👭🏻", (EmojiId(2109), EmojiId(2108))),
+        ("🧍🏾", (EmojiId(1568), EmojiId(1564))),
+        ("📤", (EmojiId(3167), EmojiId(3167))),
+        ("🎟\u{fe0f}", (EmojiId(2957), EmojiId(2957))),
+        ("😡", (EmojiId(103), EmojiId(103))),
+        ("🤚🏻", (EmojiId(176), EmojiId(175))),
+        ("🤜🏿", (EmojiId(366), EmojiId(361))),
+        ("👫", (EmojiId(2134), EmojiId(2134))),
+        ("🦹🏻\u{200d}♀\u{fe0f}", (EmojiId(1390), EmojiId(1389))),
+        ("🙇🏻", (EmojiId(790), EmojiId(789))),
+        ("🕠", (EmojiId(2878), EmojiId(2878))),
+        ("🇳🇪", (EmojiId(3687), EmojiId(3687))),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏿", (EmojiId(2173), EmojiId(2160))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏼", (EmojiId(2305), EmojiId(2290))),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏾", (EmojiId(2194), EmojiId(2186))),
+        ("💞", (EmojiId(136), EmojiId(136))),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", (EmojiId(2209), EmojiId(2186))),
+        ("🧏🏽\u{200d}♂", (EmojiId(780), EmojiId(777))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2265), EmojiId(2264))),
+        ("🫦", (EmojiId(494), EmojiId(494))),
+        ("🐻\u{200d}❄\u{fe0f}", (EmojiId(2487), EmojiId(2487))),
+        ("😳", (EmojiId(84), EmojiId(84))),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏾", (EmojiId(2315), EmojiId(2290))),
+        ("👨\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1708), EmojiId(1708))),
+        ("👺", (EmojiId(113), EmojiId(113))),
+        ("🌰", (EmojiId(2623), EmojiId(2623))),
+        ("🤦🏿\u{200d}♂", (EmojiId(818), EmojiId(813))),
+        ("👨🏼\u{200d}🎓", (EmojiId(869), EmojiId(867))),
+        ("👰🏼\u{200d}♂", (EmojiId(1283), EmojiId(1281))),
+        ("🙆🏽", (EmojiId(720), EmojiId(717))),
+        ("🪁", (EmojiId(2994), EmojiId(2994))),
+        ("🧜🏻\u{200d}♂\u{fe0f}", (EmojiId(1456), EmojiId(1455))),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏽", (EmojiId(2128), EmojiId(2108))),
+        ("👋🏼", (EmojiId(171), EmojiId(169))),
+        ("🖕", (EmojiId(307), EmojiId(307))),
+        ("🏌🏿\u{200d}♂", (EmojiId(1844), EmojiId(1839))),
+        ("🚶🏿\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1557), EmojiId(1552))),
+        ("🧑🏽\u{200d}🌾", (EmojiId(918), EmojiId(915))),
+        ("🧞\u{200d}♀\u{fe0f}", (EmojiId(1487), EmojiId(1487))),
+        ("🙍🏿", (EmojiId(668), EmojiId(663))),
+        ("👵🏼", (EmojiId(659), EmojiId(657))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2221), EmojiId(2212))),
+        ("📔", (EmojiId(3136), EmojiId(3136))),
+        ("🧑\u{200d}⚕", (EmojiId(843), EmojiId(843))),
+        ("🚶🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1559), EmojiId(1558))),
+        ("🔦", (EmojiId(3133), EmojiId(3133))),
+        ("💆🏽\u{200d}♂", (EmojiId(1501), EmojiId(1498))),
+        ("👩🏻\u{200d}🎤", (EmojiId(1054), EmojiId(1053))),
+        ("🙎\u{200d}♂\u{fe0f}", (EmojiId(687), EmojiId(687))),
+        ("👐🏻", (EmojiId(386), EmojiId(385))),
+        ("👧🏻", (EmojiId(514), EmojiId(513))),
+        ("💂🏽\u{200d}♂", (EmojiId(1176), EmojiId(1173))),
+        ("🙇\u{200d}♀", (EmojiId(801), EmojiId(801))),
+        ("🧑🏿\u{200d}⚕", (EmojiId(848), EmojiId(843))),
+        ("🇵🇭", (EmojiId(3702), EmojiId(3702))),
+        ("👨🏽\u{200d}🦽", (EmojiId(1705), EmojiId(1702))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", (EmojiId(2217), EmojiId(2212))),
+        ("💇", (EmojiId(1510), EmojiId(1510))),
+        ("🚣🏾\u{200d}♀", (EmojiId(1885), EmojiId(1881))),
+        ("🙇🏿\u{200d}♀\u{fe0f}", (EmojiId(806), EmojiId(801))),
+        ("🧍\u{200d}♀\u{fe0f}", (EmojiId(1576), EmojiId(1576))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2275), EmojiId(2264))),
+        ("🤵🏼\u{200d}♂\u{fe0f}", (EmojiId(1265), EmojiId(1263))),
+        ("🚣🏽", (EmojiId(1872), EmojiId(1869))),
+        ("🇬🇵", (EmojiId(3611), EmojiId(3611))),
+        ("🚶🏿\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1563), EmojiId(1558))),
+        ("🤷", (EmojiId(825), EmojiId(825))),
+        ("🧔\u{200d}♀\u{fe0f}", (EmojiId(549), EmojiId(549))),
+        ("🫃🏾", (EmojiId(1303), EmojiId(1299))),
+        ("🇹🇩", (EmojiId(3741), EmojiId(3741))),
+        ("🎗\u{fe0f}", (EmojiId(2956), EmojiId(2956))),
+        ("⛹🏻\u{200d}♂\u{fe0f}", (EmojiId(1912), EmojiId(1911))),
+        ("🕜", (EmojiId(2870), EmojiId(2870))),
+        ("🙅🏾\u{200d}♂", (EmojiId(709), EmojiId(705))),
+        ("🏘\u{fe0f}", (EmojiId(2743), EmojiId(2743))),
+        ("🗞\u{fe0f}", (EmojiId(3149), EmojiId(3149))),
+        ("🕵🏻", (EmojiId(1150), EmojiId(1149))),
+        ("🤣", (EmojiId(6), EmojiId(6))),
+        ("👩🏻\u{200d}🦯", (EmojiId(1643), EmojiId(1642))),
+        ("🎿", (EmojiId(2989), EmojiId(2989))),
+        ("🧍🏿\u{200d}♀", (EmojiId(1581), EmojiId(1576))),
+        ("🧜🏻\u{200d}♂", (EmojiId(1456), EmojiId(1455))),
+        ("🏃🏽\u{200d}♂\u{200d}➡", (EmojiId(1759), EmojiId(1756))),
+        ("⏭\u{fe0f}", (EmojiId(3365), EmojiId(3365))),
+        ("🧓", (EmojiId(645), EmojiId(645))),
+        ("🧗🏼", (EmojiId(1803), EmojiId(1801))),
+        ("👔", (EmojiId(3028), EmojiId(3028))),
+        ("👸🏿", (EmojiId(1226), EmojiId(1221))),
+        ("🔁", (EmojiId(3361), EmojiId(3361))),
+        ("🎊", (EmojiId(2946), EmojiId(2946))),
+        ("🧎🏼\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1614), EmojiId(1612))),
+        ("🧑🏿\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1629), EmojiId(1624))),
+        ("🎱", (EmojiId(2996), EmojiId(2996))),
+        ("🫱🏿\u{200d}🫲🏾", (EmojiId(422), EmojiId(397))),
+        ("🚠", (EmojiId(2853), EmojiId(2853))),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏾", (EmojiId(2306), EmojiId(2290))),
+        ("🗓", (EmojiId(3190), EmojiId(3190))),
+        ("💂🏽\u{200d}♂\u{fe0f}", (EmojiId(1176), EmojiId(1173))),
+        ("🤦🏾\u{200d}♂", (EmojiId(817), EmojiId(813))),
+        ("🏌\u{fe0f}\u{200d}♂", (EmojiId(1839), EmojiId(1839))),
+        ("🚵🏼", (EmojiId(1961), EmojiId(1959))),
+        ("↘\u{fe0f}", (EmojiId(3316), EmojiId(3316))),
+        ("👩🏿\u{200d}❤\u{200d}👩🏾", (EmojiId(2393), EmojiId(2368))),
+        ("🪕", (EmojiId(3094), EmojiId(3094))),
+        ("🛏\u{fe0f}", (EmojiId(3257), EmojiId(3257))),
+        ("👨🏼\u{200d}🦳", (EmojiId(569), EmojiId(567))),
+        ("🧚🏿\u{200d}♀\u{fe0f}", (EmojiId(1430), EmojiId(1425))),
+        ("🏂🏾", (EmojiId(1831), EmojiId(1827))),
+        ("🕳\u{fe0f}", (EmojiId(162), EmojiId(162))),
+        ("🚶🏿\u{200d}♀\u{200d}➡", (EmojiId(1557), EmojiId(1552))),
+        ("💕", (EmojiId(137), EmojiId(137))),
+        ("👩🏽\u{200d}🦯", (EmojiId(1645), EmojiId(1642))),
+        ("🌪", (EmojiId(2921), EmojiId(2921))),
+        ("✍🏻", (EmojiId(430), EmojiId(429))),
+        ("👩🏽\u{200d}❤\u{200d}👨🏿", (EmojiId(2333), EmojiId(2316))),
+        ("🇦🇮", (EmojiId(3524), EmojiId(3524))),
+        ("😤", (EmojiId(102), EmojiId(102))),
+        ("🤾🏼", (EmojiId(2018), EmojiId(2016))),
+        ("🧘\u{200d}♂", (EmojiId(2058), EmojiId(2058))),
+        ("🤦🏽\u{200d}♀", (EmojiId(822), EmojiId(819))),
+        ("👰\u{200d}♂\u{fe0f}", (EmojiId(1281), EmojiId(1281))),
+        ("🫟", (EmojiId(3424), EmojiId(3424))),
+        ("🤷🏾", (EmojiId(829), EmojiId(825))),
+        ("🖐🏽", (EmojiId(184), EmojiId(181))),
+        ("💪🏻", (EmojiId(448), EmojiId(447))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", (EmojiId(2207), EmojiId(2186))),
+        ("🈲", (EmojiId(3468), EmojiId(3468))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2246), EmojiId(2238))),
+        ("🙇🏽\u{200d}♂", (EmojiId(798), EmojiId(795))),
+        ("🧍🏼\u{200d}♂\u{fe0f}", (EmojiId(1572), EmojiId(1570))),
+        ("🗨", (EmojiId(165), EmojiId(165))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2253), EmojiId(2238))),
+        ("♻", (EmojiId(3404), EmojiId(3404))),
+        ("™\u{fe0f}", (EmojiId(3423), EmojiId(3423))),
+        ("🤦🏼\u{200d}♀", (EmojiId(821), EmojiId(819))),
+        ("👩🏾\u{200d}⚖\u{fe0f}", (EmojiId(913), EmojiId(909))),
+        ("🫲🏼", (EmojiId(207), EmojiId(205))),
+        ("🕹", (EmojiId(3000), EmojiId(3000))),
+        ("🧔🏿\u{200d}♀\u{fe0f}", (EmojiId(554), EmojiId(549))),
+        ("🟢", (EmojiId(3480), EmojiId(3480))),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏿", (EmojiId(2177), EmojiId(2160))),
+        ("👦🏻", (EmojiId(508), EmojiId(507))),
+        ("💂\u{200d}♂", (EmojiId(1173), EmojiId(1173))),
+        ("👨🏼\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1674), EmojiId(1672))),
+        ("🪮", (EmojiId(3058), EmojiId(3058))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2236), EmojiId(2212))),
+        ("⚧", (EmojiId(3387), EmojiId(3387))),
+        ("🧏\u{200d}♀", (EmojiId(783), EmojiId(783))),
+        ("💇🏿\u{200d}♂", (EmojiId(1521), EmojiId(1516))),
+        ("🤸🏼", (EmojiId(1979), EmojiId(1977))),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2270), EmojiId(2264))),
+        ("🧑🏽\u{200d}⚕\u{fe0f}", (EmojiId(846), EmojiId(843))),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏽", (EmojiId(2115), EmojiId(2108))),
+        ("🌆", (EmojiId(2776), EmojiId(2776))),
+        ("🧎\u{200d}♀\u{fe0f}", (EmojiId(1594), EmojiId(1594))),
+        ("👨\u{200d}🚀", (EmojiId(1101), EmojiId(1101))),
+        ("🧓🏿", (EmojiId(650), EmojiId(645))),
+        ("🌿", (EmojiId(2579), EmojiId(2579))),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏾", (EmojiId(2142), EmojiId(2134))),
+        ("🙎🏿\u{200d}♂", (EmojiId(692), EmojiId(687))),
+        ("👯", (EmojiId(1780), EmojiId(1780))),
+        ("🏌🏿\u{200d}♀\u{fe0f}", (EmojiId(1850), EmojiId(1845))),
+        ("👨🏿\u{200d}🎤", (EmojiId(1052), EmojiId(1047))),
+        ("💂🏾\u{200d}♂\u{fe0f}", (EmojiId(1177), EmojiId(1173))),
+        ("®", (EmojiId(3422), EmojiId(3422))),
+        ("🫀", (EmojiId(486), EmojiId(486))),
+        ("🙅🏼\u{200d}♀", (EmojiId(713), EmojiId(711))),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏿", (EmojiId(2307), EmojiId(2290))),
+        ("🏍\u{fe0f}", (EmojiId(2816), EmojiId(2816))),
+        ("😽", (EmojiId(123), EmojiId(123))),
+        ("🏃\u{200d}♂\u{200d}➡", (EmojiId(1756), EmojiId(1756))),
+        ("👈🏿", (EmojiId(294), EmojiId(289))),
+        ("🤾🏿\u{200d}♀", (EmojiId(2033), EmojiId(2028))),
+        ("🚵🏼\u{200d}♂\u{fe0f}", (EmojiId(1967), EmojiId(1965))),
+        ("👩\u{200d}⚕\u{fe0f}", (EmojiId(855), EmojiId(855))),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏿", (EmojiId(2299), EmojiId(2290))),
+        ("🧑🏼\u{200d}💻", (EmojiId(1025), EmojiId(1023))),
+        ("🤦🏽\u{200d}♂", (EmojiId(816), EmojiId(813))),
+        ("👨\u{200d}✈", (EmojiId(1083), EmojiId(1083))),
+        ("🥁", (EmojiId(3095), EmojiId(3095))),
+        ("👨🏻\u{200d}🦽", (EmojiId(1703), EmojiId(1702))),
+        ("🏃🏿\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1761), EmojiId(1756))),
+        ("🧪", (EmojiId(3240), EmojiId(3240))),
+        ("🐿", (EmojiId(2482), EmojiId(2482))),
+        ("👩\u{200d}🎓", (EmojiId(873), EmojiId(873))),
+        ("👱\u{200d}♂\u{fe0f}", (EmojiId(639), EmojiId(639))),
+        ("🦍", (EmojiId(2432), EmojiId(2432))),
+        ("🧏🏿", (EmojiId(776), EmojiId(771))),
+        ("📴", (EmojiId(3384), EmojiId(3384))),
+        ("🇨🇱", (EmojiId(3565), EmojiId(3565))),
+        ("💆\u{200d}♂", (EmojiId(1498), EmojiId(1498))),
+        ("🫠", (EmojiId(10), EmojiId(10))),
+        ("🥌", (EmojiId(2991), EmojiId(2991))),
+        ("🍢", (EmojiId(2670), EmojiId(2670))),
+        ("🧙\u{200d}♂\u{fe0f}", (EmojiId(1401), EmojiId(1401))),
+        ("🥊", (EmojiId(2981), EmojiId(2981))),
+        ("🤫", (EmojiId(33), EmojiId(33))),
+        ("🏪", (EmojiId(2754), EmojiId(2754))),
+        ("🧎🏻\u{200d}♀\u{200d}➡", (EmojiId(1607), EmojiId(1606))),
+        ("👩🏼\u{200d}❤\u{200d}👩🏼", (EmojiId(2378), EmojiId(2368))),
+        ("🍟", (EmojiId(2642), EmojiId(2642))),
+        ("🧎🏻\u{200d}♀", (EmojiId(1595), EmojiId(1594))),
+        ("🙋🏽\u{200d}♂", (EmojiId(762), EmojiId(759))),
+        ("➿", (EmojiId(3416), EmojiId(3416))),
+        ("⤵\u{fe0f}", (EmojiId(3326), EmojiId(3326))),
+        ("😜", (EmojiId(25), EmojiId(25))),
+        ("🏄🏾\u{200d}♀", (EmojiId(1867), EmojiId(1863))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2239), EmojiId(2238))),
+        ("🏄🏾", (EmojiId(1855), EmojiId(1851))),
+        ("⛹🏿\u{200d}♂", (EmojiId(1916), EmojiId(1911))),
+        ("👓", (EmojiId(3023), EmojiId(3023))),
+        ("🫸🏽", (EmojiId(232), EmojiId(229))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2223), EmojiId(2212))),
+        ("👏🏿", (EmojiId(372), EmojiId(367))),
+        ("☝\u{fe0f}", (EmojiId(319), EmojiId(319))),
+        ("🦸🏾\u{200d}♂\u{fe0f}", (EmojiId(1369), EmojiId(1365))),
+        ("👎", (EmojiId(337), EmojiId(337))),
+        ("🫴🏽", (EmojiId(220), EmojiId(217))),
+        ("💆🏿\u{200d}♂", (EmojiId(1503), EmojiId(1498))),
+        ("🚣🏻", (EmojiId(1870), EmojiId(1869))),
+        ("✊🏿", (EmojiId(348), EmojiId(343))),
+        ("🧛🏽", (EmojiId(1434), EmojiId(1431))),
+        ("👩🏻\u{200d}⚕", (EmojiId(856), EmojiId(855))),
+        ("🧔🏼\u{200d}♀", (EmojiId(551), EmojiId(549))),
+        ("❤\u{fe0f}\u{200d}🔥", (EmojiId(141), EmojiId(141))),
+        ("🚭", (EmojiId(3305), EmojiId(3305))),
+        ("🇧🇻", (EmojiId(3553), EmojiId(3553))),
+        ("🧓🏽", (EmojiId(648), EmojiId(645))),
+        ("🤸🏿\u{200d}♂\u{fe0f}", (EmojiId(1988), EmojiId(1983))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2350), EmojiId(2342))),
+        ("👩🏾\u{200d}🚀", (EmojiId(1111), EmojiId(1107))),
+        ("👳🏿\u{200d}♀", (EmojiId(1244), EmojiId(1239))),
+        ("🔜", (EmojiId(3332), EmojiId(3332))),
+        ("🙋🏿", (EmojiId(758), EmojiId(753))),
+        ("💧", (EmojiId(2936), EmojiId(2936))),
+        ("⚓", (EmojiId(2836), EmojiId(2836))),
+        ("🚷", (EmojiId(3308), EmojiId(3308))),
+        ("🏄🏽\u{200d}♀", (EmojiId(1866), EmojiId(1863))),
+        ("🈶", (EmojiId(3463), EmojiId(3463))),
+        ("👩🏿\u{200d}❤\u{200d}👨🏾", (EmojiId(2341), EmojiId(2316))),
+        ("😠", (EmojiId(104), EmojiId(104))),
+        ("✈\u{fe0f}", (EmojiId(2845), EmojiId(2845))),
+        ("🏃\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1750), EmojiId(1750))),
+        ("💑🏼", (EmojiId(2292), EmojiId(2290))),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩", (EmojiId(2264), EmojiId(2264))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2360), EmojiId(2342))),
+        ("💂", (EmojiId(1167), EmojiId(1167))),
+        ("🫶🏻", (EmojiId(380), EmojiId(379))),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏻", (EmojiId(2208), EmojiId(2186))),
+        ("🎮", (EmojiId(2999), EmojiId(2999))),
+        ("⚡", (EmojiId(2930), EmojiId(2930))),
+        ("🧑🏽\u{200d}🔧", (EmojiId(954), EmojiId(951))),
+        ("👷🏽", (EmojiId(1194), EmojiId(1191))),
+        ("🧎🏻\u{200d}♂\u{200d}➡", (EmojiId(1613), EmojiId(1612))),
+        ("🎧", (EmojiId(3086), EmojiId(3086))),
+        ("🧒🏿", (EmojiId(506), EmojiId(501))),
+        ("🧘🏽", (EmojiId(2055), EmojiId(2052))),
+        ("💂🏿\u{200d}♂", (EmojiId(1178), EmojiId(1173))),
+        ("🧑🏻\u{200d}🔬", (EmojiId(1006), EmojiId(1005))),
+        ("🤌🏼", (EmojiId(243), EmojiId(241))),
+        ("🚢", (EmojiId(2844), EmojiId(2844))),
+        ("🧜🏿\u{200d}♂", (EmojiId(1460), EmojiId(1455))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏻", (EmojiId(2326), EmojiId(2316))),
+        ("🤸🏽\u{200d}♀\u{fe0f}", (EmojiId(1992), EmojiId(1989))),
+        ("🚶🏽\u{200d}♂\u{200d}➡", (EmojiId(1561), EmojiId(1558))),
+        ("🚴🏻\u{200d}♂", (EmojiId(1948), EmojiId(1947))),
+        ("🧎🏽\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1609), EmojiId(1606))),
+        ("✏\u{fe0f}", (EmojiId(3176), EmojiId(3176))),
+        ("🚣🏾\u{200d}♂\u{fe0f}", (EmojiId(1879), EmojiId(1875))),
+        ("🧑🏽\u{200d}⚕", (EmojiId(846), EmojiId(843))),
+        ("🫱🏿\u{200d}🫲🏻", (EmojiId(419), EmojiId(397))),
+        ("🦹🏻", (EmojiId(1378), EmojiId(1377))),
+        ("🤷🏾\u{200d}♀", (EmojiId(841), EmojiId(837))),
+        ("🚵🏻\u{200d}♀", (EmojiId(1972), EmojiId(1971))),
+        ("🚶🏿\u{200d}♂\u{fe0f}", (EmojiId(1539), EmojiId(1534))),
+        ("🦸🏽\u{200d}♂\u{fe0f}", (EmojiId(1368), EmojiId(1365))),
+        ("🖨", (EmojiId(3111), EmojiId(3111))),
+        ("🪿", (EmojiId(2516), EmojiId(2516))),
+        ("🇭🇲", (EmojiId(3620), EmojiId(3620))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2363), EmojiId(2342))),
+        ("👩🏻\u{200d}⚖\u{fe0f}", (EmojiId(910), EmojiId(909))),
+        ("📓", (EmojiId(3143), EmojiId(3143))),
+        ("🚽", (EmojiId(3260), EmojiId(3260))),
+        ("👩🏼\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1686), EmojiId(1684))),
+        ("🍄", (EmojiId(2587), EmojiId(2587))),
+        ("🦸🏻\u{200d}♀\u{fe0f}", (EmojiId(1372), EmojiId(1371))),
+        ("🤘🏼", (EmojiId(279), EmojiId(277))),
+        ("👷🏿\u{200d}♂", (EmojiId(1202), EmojiId(1197))),
+        ("👱🏿\u{200d}♂\u{fe0f}", (EmojiId(644), EmojiId(639))),
+        ("👮🏿", (EmojiId(1136), EmojiId(1131))),
+        ("👩\u{200d}🍼", (EmojiId(1317), EmojiId(1317))),
+        ("🏋🏼\u{200d}♂", (EmojiId(1931), EmojiId(1929))),
+        ("🤽🏿\u{200d}♂", (EmojiId(2009), EmojiId(2004))),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2361), EmojiId(2342))),
+        ("🙏🏾", (EmojiId(427), EmojiId(423))),
+        ("🦯", (EmojiId(3230), EmojiId(3230))),
+        ("👳🏽\u{200d}♂\u{fe0f}", (EmojiId(1236), EmojiId(1233))),
+        ("👰🏾\u{200d}♀\u{fe0f}", (EmojiId(1291), EmojiId(1287))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2235), EmojiId(2212))),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏿", (EmojiId(2169), EmojiId(2160))),
+        ("🇪🇺", (EmojiId(3593), EmojiId(3593))),
+        ("🧑🏻\u{200d}🦯\u{200d}➡", (EmojiId(1625), EmojiId(1624))),
+        ("🧝\u{200d}♀", (EmojiId(1479), EmojiId(1479))),
+        ("🪂", (EmojiId(2849), EmojiId(2849))),
+        ("◼\u{fe0f}", (EmojiId(3495), EmojiId(3495))),
+        ("🍏", (EmojiId(2599), EmojiId(2599))),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏽", (EmojiId(2132), EmojiId(2108))),
+        ("💁\u{200d}♀", (EmojiId(747), EmojiId(747))),
+        ("🧑🏾\u{200d}🦽\u{200d}➡", (EmojiId(1700), EmojiId(1696))),
+        ("🏑", (EmojiId(2976), EmojiId(2976))),
+        ("🙋🏼", (EmojiId(755), EmojiId(753))),
+        ("🤹🏿\u{200d}♂\u{fe0f}", (EmojiId(2045), EmojiId(2040))),
+        ("🙆🏼\u{200d}♂\u{fe0f}", (EmojiId(725), EmojiId(723))),
+        ("🏃🏻\u{200d}♀\u{fe0f}", (EmojiId(1739), EmojiId(1738))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2278), EmojiId(2264))),
+        ("🧑🏽\u{200d}🎤", (EmojiId(1044), EmojiId(1041))),
+        ("🕵🏼\u{200d}♀\u{fe0f}", (EmojiId(1163), EmojiId(1161))),
+        ("🥣", (EmojiId(2656), EmojiId(2656))),
+        ("👎🏼", (EmojiId(339), EmojiId(337))),
+        ("🔓", (EmojiId(3207), EmojiId(3207))),
+        ("👩🏻\u{200d}🔧", (EmojiId(964), EmojiId(963))),
+        ("🧑🏽\u{200d}🦳", (EmojiId(618), EmojiId(615))),
+        ("🧎🏾", (EmojiId(1586), EmojiId(1582))),
+        ("👷🏻\u{200d}♂\u{fe0f}", (EmojiId(1198), EmojiId(1197))),
+        ("🧑\u{200d}🦯", (EmojiId(1618), EmojiId(1618))),
+        ("😒", (EmojiId(44), EmojiId(44))),
+        ("🏞\u{fe0f}", (EmojiId(2735), EmojiId(2735))),
+        ("🚴🏿\u{200d}♀", (EmojiId(1958), EmojiId(1953))),
+        ("🧝🏽", (EmojiId(1470), EmojiId(1467))),
+        ("👩\u{200d}🎤", (EmojiId(1053), EmojiId(1053))),
+        ("🐔", (EmojiId(2497), EmojiId(2497))),
+        ("🧏🏾\u{200d}♀", (EmojiId(787), EmojiId(783))),
+        ("💰", (EmojiId(3153), EmojiId(3153))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2215), EmojiId(2212))),
+        ("🙎🏽\u{200d}♀", (EmojiId(696), EmojiId(693))),
+        ("🤹", (EmojiId(2034), EmojiId(2034))),
+        ("👴🏻", (EmojiId(652), EmojiId(651))),
+        ("⏲", (EmojiId(2865), EmojiId(2865))),
+        ("🧖🏽\u{200d}♂", (EmojiId(1792), EmojiId(1789))),
+        ("🫥", (EmojiId(41), EmojiId(41))),
+        ("❔", (EmojiId(3397), EmojiId(3397))),
+        ("🫙", (EmojiId(2718), EmojiId(2718))),
+        ("⏏\u{fe0f}", (EmojiId(3377), EmojiId(3377))),
+        ("🧔🏻", (EmojiId(538), EmojiId(537))),
+        ("🧎🏻\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1607), EmojiId(1606))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2352), EmojiId(2342))),
+        ("🇬🇶", (EmojiId(3612), EmojiId(3612))),
+        ("🔛", (EmojiId(3331), EmojiId(3331))),
+        ("🛕", (EmojiId(2765), EmojiId(2765))),
+        ("🤓", (EmojiId(74), EmojiId(74))),
+        ("🚶🏾\u{200d}♂", (EmojiId(1538), EmojiId(1534))),
+        ("🐑", (EmojiId(2466), EmojiId(2466))),
+        ("👨🏻\u{200d}🚀", (EmojiId(1102), EmojiId(1101))),
+        ("👩\u{200d}🦱", (EmojiId(597), EmojiId(597))),
+        ("🧙🏿\u{200d}♂", (EmojiId(1406), EmojiId(1401))),
+        ("🧍🏾\u{200d}♀\u{fe0f}", (EmojiId(1580), EmojiId(1576))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", (EmojiId(2276), EmojiId(2264))),
+        ("🙋🏼\u{200d}♂\u{fe0f}", (EmojiId(761), EmojiId(759))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", (EmojiId(2199), EmojiId(2186))),
+        ("🚶\u{200d}➡\u{fe0f}", (EmojiId(1546), EmojiId(1546))),
+        ("🦹", (EmojiId(1377), EmojiId(1377))),
+        ("🧙🏾", (EmojiId(1399), EmojiId(1395))),
+        ("🧍🏼\u{200d}♀", (EmojiId(1578), EmojiId(1576))),
+        ("🫤", (EmojiId(77), EmojiId(77))),
+        ("🧑🏼\u{200d}🚒", (EmojiId(1115), EmojiId(1113))),
+        ("🤬", (EmojiId(105), EmojiId(105))),
+        ("🦸🏼\u{200d}♂\u{fe0f}", (EmojiId(1367), EmojiId(1365))),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏼", (EmojiId(2313), EmojiId(2290))),
+        ("🇷🇺", (EmojiId(3716), EmojiId(3716))),
+        ("🧎🏿\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1617), EmojiId(1612))),
+        ("👮\u{200d}♂\u{fe0f}", (EmojiId(1137), EmojiId(1137))),
+        ("🩺", (EmojiId(3251), EmojiId(3251))),
+        ("🧎🏿\u{200d}➡\u{fe0f}", (EmojiId(1605), EmojiId(1600))),
+        ("🏊🏼\u{200d}♂\u{fe0f}", (EmojiId(1895), EmojiId(1893))),
+        ("🔽", (EmojiId(3372), EmojiId(3372))),
+        ("👨🏼\u{200d}💻", (EmojiId(1031), EmojiId(1029))),
+        ("🧑🏾\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1664), EmojiId(1660))),
+        ("💂🏽\u{200d}♀", (EmojiId(1182), EmojiId(1179))),
+        ("🧗", (EmojiId(1801), EmojiId(1801))),
+        ("👩\u{200d}👩\u{200d}👧\u{200d}👦", (EmojiId(2406), EmojiId(2406))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2218), EmojiId(2212))),
+        ("🇵🇳", (EmojiId(3706), EmojiId(3706))),
+        ("🧤", (EmojiId(3032), EmojiId(3032))),
+        ("🧑🏾\u{200d}✈", (EmojiId(1081), EmojiId(1077))),
+        ("🧔🏼\u{200d}♂\u{fe0f}", (EmojiId(545), EmojiId(543))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏿", (EmojiId(2351), EmojiId(2342))),
+        ("↪\u{fe0f}", (EmojiId(3324), EmojiId(3324))),
+        ("🏃🏿\u{200d}➡", (EmojiId(1749), EmojiId(1744))),
+        ("⛅", (EmojiId(2913), EmojiId(2913))),
+        ("⛹🏼", (EmojiId(1907), EmojiId(1905))),
+        ("🧙🏻\u{200d}♀", (EmojiId(1408), EmojiId(1407))),
+        ("🌡\u{fe0f}", (EmojiId(2903), EmojiId(2903))),
+        ("⛹🏿\u{200d}♂\u{fe0f}", (EmojiId(1916), EmojiId(1911))),
+        ("🧜🏼\u{200d}♂\u{fe0f}", (EmojiId(1457), EmojiId(1455))),
+        ("🤦🏽\u{200d}♂\u{fe0f}", (EmojiId(816), EmojiId(813))),
+        ("👮🏾", (EmojiId(1135), EmojiId(1131))),
+        ("🤵🏾\u{200d}♂", (EmojiId(1267), EmojiId(1263))),
+        ("🏃🏿\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1755), EmojiId(1750))),
+        ("🇲🇦", (EmojiId(3662), EmojiId(3662))),
+        ("🇦🇨", (EmojiId(3519), EmojiId(3519))),
+        ("🚶🏼\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1554), EmojiId(1552))),
+        ("🚗", (EmojiId(2808), EmojiId(2808))),
+        ("👨🏻\u{200d}❤\u{200d}👨🏻", (EmojiId(2343), EmojiId(2342))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏼", (EmojiId(2391), EmojiId(2368))),
+        ("🍅", (EmojiId(2606), EmojiId(2606))),
+        ("🪾", (EmojiId(2588), EmojiId(2588))),
+        ("👨🏾\u{200d}⚖\u{fe0f}", (EmojiId(907), EmojiId(903))),
+        ("🧖🏿\u{200d}♂", (EmojiId(1794), EmojiId(1789))),
+        ("🏋\u{200d}♀\u{fe0f}", (EmojiId(1935), EmojiId(1935))),
+        ("🧔🏾\u{200d}♂", (EmojiId(547), EmojiId(543))),
+        ("🫅🏼", (EmojiId(1211), EmojiId(1209))),
+        ("🤶🏿", (EmojiId(1352), EmojiId(1347))),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏿", (EmojiId(2121), EmojiId(2108))),
+        ("🇸🇹", (EmojiId(3734), EmojiId(3734))),
+        ("👨🏾\u{200d}⚖", (EmojiId(907), EmojiId(903))),
+        ("🤹🏾\u{200d}♂", (EmojiId(2044), EmojiId(2040))),
+        ("🧏\u{200d}♀\u{fe0f}", (EmojiId(783), EmojiId(783))),
+        ("👌🏽", (EmojiId(238), EmojiId(235))),
+        ("👱🏼\u{200d}♂\u{fe0f}", (EmojiId(641), EmojiId(639))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2222), EmojiId(2212))),
+        ("👨\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1672), EmojiId(1672))),
+        ("🦡", (EmojiId(2494), EmojiId(2494))),
+        ("🇨🇾", (EmojiId(3576), EmojiId(3576))),
+        ("🏴\u{200d}☠", (EmojiId(3518), EmojiId(3518))),
+        ("🐈", (EmojiId(2443), EmojiId(2443))),
+        ("🙎🏽", (EmojiId(684), EmojiId(681))),
+        ("🏩", (EmojiId(2753), EmojiId(2753))),
+        ("🤦🏼\u{200d}♀\u{fe0f}", (EmojiId(821), EmojiId(819))),
+        ("👭🏾", (EmojiId(2112), EmojiId(2108))),
+        ("🦹\u{200d}♀", (EmojiId(1389), EmojiId(1389))),
+        ("🍥", (EmojiId(2673), EmojiId(2673))),
+        ("🏊", (EmojiId(1887), EmojiId(1887))),
+        ("🍶", (EmojiId(2698), EmojiId(2698))),
+        ("🛏", (EmojiId(3257), EmojiId(3257))),
+        ("🦸🏽\u{200d}♀\u{fe0f}", (EmojiId(1374), EmojiId(1371))),
+        ("🉐", (EmojiId(3465), EmojiId(3465))),
+        ("🧗🏾", (EmojiId(1805), EmojiId(1801))),
+        ("🇳🇦", (EmojiId(3685), EmojiId(3685))),
+        ("🍛", (EmojiId(2666), EmojiId(2666))),
+        ("💃🏻", (EmojiId(1763), EmojiId(1762))),
+        ("🇨🇬", (EmojiId(3561), EmojiId(3561))),
+        ("🇹🇬", (EmojiId(3743), EmojiId(3743))),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏻", (EmojiId(2170), EmojiId(2160))),
+        ("🧏🏼", (EmojiId(773), EmojiId(771))),
+        ("🙅🏿", (EmojiId(704), EmojiId(699))),
+        ("👨\u{200d}👩\u{200d}👦", (EmojiId(2394), EmojiId(2394))),
+        ("🛞", (EmojiId(2830), EmojiId(2830))),
+        ("🧘🏼\u{200d}♂\u{fe0f}", (EmojiId(2060), EmojiId(2058))),
+        ("💆🏽\u{200d}♂\u{fe0f}", (EmojiId(1501), EmojiId(1498))),
+        ("🏃🏽\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1753), EmojiId(1750))),
+        ("🐷", (EmojiId(2461), EmojiId(2461))),
+        ("🌦", (EmojiId(2917), EmojiId(2917))),
+        ("👨🏿\u{200d}✈", (EmojiId(1088), EmojiId(1083))),
+        ("👰🏻\u{200d}♂\u{fe0f}", (EmojiId(1282), EmojiId(1281))),
+        ("👨\u{200d}⚕\u{fe0f}", (EmojiId(849), EmojiId(849))),
+        ("📑", (EmojiId(3150), EmojiId(3150))),
+        ("🧗🏻\u{200d}♀", (EmojiId(1814), EmojiId(1813))),
+        ("🧎🏽\u{200d}♀\u{200d}➡", (EmojiId(1609), EmojiId(1606))),
+        ("🙋🏽\u{200d}♂\u{fe0f}", (EmojiId(762), EmojiId(759))),
+        ("🙎🏻\u{200d}♀\u{fe0f}", (EmojiId(694), EmojiId(693))),
+        ("🎵", (EmojiId(3080), EmojiId(3080))),
+        ("💻", (EmojiId(3109), EmojiId(3109))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2351), EmojiId(2342))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", (EmojiId(2202), EmojiId(2186))),
+        ("🫵", (EmojiId(325), EmojiId(325))),
+        ("🏄🏻", (EmojiId(1852), EmojiId(1851))),
+        ("🧑🏿\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1665), EmojiId(1660))),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏻", (EmojiId(2144), EmojiId(2134))),
+        ("🇬🇹", (EmojiId(3615), EmojiId(3615))),
+        ("👷\u{200d}♂", (EmojiId(1197), EmojiId(1197))),
+        ("💆🏼", (EmojiId(1494), EmojiId(1492))),
+        ("👩\u{200d}🦲", (EmojiId(621), EmojiId(621))),
+        ("🤹🏼\u{200d}♀", (EmojiId(2048), EmojiId(2046))),
+        ("💁🏾\u{200d}♀\u{fe0f}", (EmojiId(751), EmojiId(747))),
+        ("➗", (EmojiId(3391), EmojiId(3391))),
+        ("✒", (EmojiId(3177), EmojiId(3177))),
+        ("🧎🏾\u{200d}♀\u{200d}➡", (EmojiId(1610), EmojiId(1606))),
+        ("🤙🏽", (EmojiId(286), EmojiId(283))),
+        ("👨🏿\u{200d}🏫", (EmojiId(890), EmojiId(885))),
+        ("🔣", (EmojiId(3441), EmojiId(3441))),
+        ("🪤", (EmojiId(3264), EmojiId(3264))),
+        ("🪱", (EmojiId(2558), EmojiId(2558))),
+        ("👩🏻\u{200d}🚒", (EmojiId(1126), EmojiId(1125))),
+        ("👰\u{200d}♀\u{fe0f}", (EmojiId(1287), EmojiId(1287))),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏽", (EmojiId(2193), EmojiId(2186))),
+        ("🤷🏾\u{200d}♂", (EmojiId(835), EmojiId(831))),
+        ("🤷🏻\u{200d}♀\u{fe0f}", (EmojiId(838), EmojiId(837))),
+        ("🆑", (EmojiId(3446), EmojiId(3446))),
+        ("🎤", (EmojiId(3085), EmojiId(3085))),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏼", (EmojiId(2127), EmojiId(2108))),
+        ("⛷", (EmojiId(1826), EmojiId(1826))),
+        ("🫱🏽", (EmojiId(202), EmojiId(199))),
+        ("🪹", (EmojiId(2585), EmojiId(2585))),
+        ("#\u{20e3}", (EmojiId(3425), EmojiId(3425))),
+        ("🍆", (EmojiId(2610), EmojiId(2610))),
+        ("🇳🇬", (EmojiId(3689), EmojiId(3689))),
+        ("🤽🏽\u{200d}♀", (EmojiId(2013), EmojiId(2010))),
+        ("🤸🏽\u{200d}♂", (EmojiId(1986), EmojiId(1983))),
+        ("🧑🏿\u{200d}🦽\u{200d}➡", (EmojiId(1701), EmojiId(1696))),
+        ("🅿", (EmojiId(3456), EmojiId(3456))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏻", (EmojiId(2390), EmojiId(2368))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2335), EmojiId(2316))),
+        ("🙇🏿\u{200d}♂\u{fe0f}", (EmojiId(800), EmojiId(795))),
+        ("♾", (EmojiId(3393), EmojiId(3393))),
+        ("🧛\u{200d}♀\u{fe0f}", (EmojiId(1443), EmojiId(1443))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2330), EmojiId(2316))),
+        ("🇵🇬", (EmojiId(3701), EmojiId(3701))),
+        ("🧗🏽\u{200d}♀\u{fe0f}", (EmojiId(1816), EmojiId(1813))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2284), EmojiId(2264))),
+        ("🥦", (EmojiId(2618), EmojiId(2618))),
+        ("🎪", (EmojiId(2785), EmojiId(2785))),
+        ("👨\u{200d}👨\u{200d}👧", (EmojiId(2400), EmojiId(2400))),
+        ("🥿", (EmojiId(3053), EmojiId(3053))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", (EmojiId(2203), EmojiId(2186))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2366), EmojiId(2342))),
+        ("🏊🏾", (EmojiId(1891), EmojiId(1887))),
+        ("🤽🏿\u{200d}♀\u{fe0f}", (EmojiId(2015), EmojiId(2010))),
+        ("🏒", (EmojiId(2977), EmojiId(2977))),
+        ("🇰🇿", (EmojiId(3650), EmojiId(3650))),
+        ("🤌🏻", (EmojiId(242), EmojiId(241))),
+        ("🕑", (EmojiId(2871), EmojiId(2871))),
+        ("🤸🏾\u{200d}♂", (EmojiId(1987), EmojiId(1983))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2283), EmojiId(2264))),
+        ("🏃🏽", (EmojiId(1729), EmojiId(1726))),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏼", (EmojiId(2201), EmojiId(2186))),
+        ("🙆🏿\u{200d}♂\u{fe0f}", (EmojiId(728), EmojiId(723))),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏼", (EmojiId(2166), EmojiId(2160))),
+        ("🇲🇬", (EmojiId(3667), EmojiId(3667))),
+        ("🤵🏼\u{200d}♀", (EmojiId(1271), EmojiId(1269))),
+        ("⛹🏽\u{200d}♂\u{fe0f}", (EmojiId(1914), EmojiId(1911))),
+        ("🤽🏽\u{200d}♂", (EmojiId(2007), EmojiId(2004))),
+        ("🤌", (EmojiId(241), EmojiId(241))),
+        ("🥸", (EmojiId(72), EmojiId(72))),
+        ("⛹🏼\u{200d}♀\u{fe0f}", (EmojiId(1919), EmojiId(1917))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2325), EmojiId(2316))),
+        ("👩\u{200d}👦", (EmojiId(2414), EmojiId(2414))),
+        ("👷🏿", (EmojiId(1196), EmojiId(1191))),
+        ("🇧🇹", (EmojiId(3552), EmojiId(3552))),
+        ("🤾🏽\u{200d}♂", (EmojiId(2025), EmojiId(2022))),
+        ("🧍🏽", (EmojiId(1567), EmojiId(1564))),
+        ("👨\u{200d}🦳", (EmojiId(567), EmojiId(567))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾", (EmojiId(2224), EmojiId(2212))),
+        ("🗓\u{fe0f}", (EmojiId(3190), EmojiId(3190))),
+        ("🤦🏾\u{200d}♀\u{fe0f}", (EmojiId(823), EmojiId(819))),
+        ("🕳", (EmojiId(162), EmojiId(162))),
+        ("🇮🇩", (EmojiId(3626), EmojiId(3626))),
+        ("🏃🏼", (EmojiId(1728), EmojiId(1726))),
+        ("🇼🇫", (EmojiId(3770), EmojiId(3770))),
+        ("🇹🇷", (EmojiId(3751), EmojiId(3751))),
+        ("👨\u{200d}🦽\u{200d}➡", (EmojiId(1708), EmojiId(1708))),
+        ("🏃🏿\u{200d}♂", (EmojiId(1737), EmojiId(1732))),
+        ("🧓🏾", (EmojiId(649), EmojiId(645))),
+        ("⛹🏽", (EmojiId(1908), EmojiId(1905))),
+        ("🌂", (EmojiId(2926), EmojiId(2926))),
+        ("🚵\u{200d}♀\u{fe0f}", (EmojiId(1971), EmojiId(1971))),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏾", (EmojiId(2133), EmojiId(2108))),
+        ("👆🏽", (EmojiId(304), EmojiId(301))),
+        ("🙇🏼\u{200d}♀", (EmojiId(803), EmojiId(801))),
+        ("👱🏿\u{200d}♀\u{fe0f}", (EmojiId(638), EmojiId(633))),
+        ("💏🏼", (EmojiId(2188), EmojiId(2186))),
+        ("👩🏿\u{200d}✈", (EmojiId(1094), EmojiId(1089))),
+        ("🇯🇪", (EmojiId(3636), EmojiId(3636))),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏾", (EmojiId(2198), EmojiId(2186))),
+        ("🇬🇫", (EmojiId(3604), EmojiId(3604))),
+        ("🐫", (EmojiId(2469), EmojiId(2469))),
+        ("💇🏽\u{200d}♂\u{fe0f}", (EmojiId(1519), EmojiId(1516))),
+        ("🛌🏻", (EmojiId(2077), EmojiId(2076))),
+        ("🇬🇮", (EmojiId(3607), EmojiId(3607))),
+        ("🤸🏽\u{200d}♂\u{fe0f}", (EmojiId(1986), EmojiId(1983))),
+        ("🚶🏽\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1561), EmojiId(1558))),
+        ("🚶🏾\u{200d}♀", (EmojiId(1544), EmojiId(1540))),
+        ("🧎🏽\u{200d}♂\u{200d}➡", (EmojiId(1615), EmojiId(1612))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", (EmojiId(2196), EmojiId(2186))),
+        ("🏊🏿\u{200d}♂", (EmojiId(1898), EmojiId(1893))),
+        ("🙋🏾\u{200d}♀", (EmojiId(769), EmojiId(765))),
+        ("♍", (EmojiId(3352), EmojiId(3352))),
+        ("🤦🏿\u{200d}♂\u{fe0f}", (EmojiId(818), EmojiId(813))),
+        ("💷", (EmojiId(3158), EmojiId(3158))),
+        ("👨🏻\u{200d}🏫", (EmojiId(886), EmojiId(885))),
+        ("☕", (EmojiId(2695), EmojiId(2695))),
+        ("👭", (EmojiId(2108), EmojiId(2108))),
+        ("🏂🏼", (EmojiId(1829), EmojiId(1827))),
+        ("🤑", (EmojiId(28), EmojiId(28))),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏻", (EmojiId(2104), EmojiId(2082))),
+        ("👨\u{200d}❤\u{200d}👨", (EmojiId(2342), EmojiId(2342))),
+        ("🎬", (EmojiId(3123), EmojiId(3123))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏽", (EmojiId(2336), EmojiId(2316))),
+        ("📎", (EmojiId(3198), EmojiId(3198))),
+        ("🚶🏼\u{200d}♂\u{fe0f}", (EmojiId(1536), EmojiId(1534))),
+        ("⚧\u{fe0f}", (EmojiId(3387), EmojiId(3387))),
+        ("🚵", (EmojiId(1959), EmojiId(1959))),
+        ("🧘🏻\u{200d}♀\u{fe0f}", (EmojiId(2065), EmojiId(2064))),
+        ("🤦\u{200d}♂\u{fe0f}", (EmojiId(813), EmojiId(813))),
+        ("👱🏻\u{200d}♀\u{fe0f}", (EmojiId(634), EmojiId(633))),
+        ("🥟", (EmojiId(2676), EmojiId(2676))),
+        ("🍽", (EmojiId(2714), EmojiId(2714))),
+        ("🚶🏼", (EmojiId(1530), EmojiId(1528))),
+        ("🇱🇮", (EmojiId(3654), EmojiId(3654))),
+        ("🏄🏼\u{200d}♀\u{fe0f}", (EmojiId(1865), EmojiId(1863))),
+        ("🛀🏼", (EmojiId(2072), EmojiId(2070))),
+        ("🚒", (EmojiId(2803), EmojiId(2803))),
+        ("🧙🏾\u{200d}♂", (EmojiId(1405), EmojiId(1401))),
+        ("🧐", (EmojiId(75), EmojiId(75))),
+        ("🌖", (EmojiId(2896), EmojiId(2896))),
+        ("👐🏼", (EmojiId(387), EmojiId(385))),
+        ("👨🏿\u{200d}🌾", (EmojiId(926), EmojiId(921))),
+        ("🎦", (EmojiId(3378), EmojiId(3378))),
+        ("👩🏼\u{200d}🍳", (EmojiId(947), EmojiId(945))),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2252), EmojiId(2238))),
+        ("🏃🏼\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1758), EmojiId(1756))),
+        ("🤾🏻\u{200d}♀\u{fe0f}", (EmojiId(2029), EmojiId(2028))),
+        ("🤹🏿\u{200d}♀\u{fe0f}", (EmojiId(2051), EmojiId(2046))),
+        ("👬🏻", (EmojiId(2161), EmojiId(2160))),
+        ("🏄🏾\u{200d}♂", (EmojiId(1861), EmojiId(1857))),
+        ("🏴", (EmojiId(3514), EmojiId(3514))),
+        ("🫡", (EmojiId(35), EmojiId(35))),
+        ("🚣🏽\u{200d}♂\u{fe0f}", (EmojiId(1878), EmojiId(1875))),
+        ("⛹🏿\u{200d}♀\u{fe0f}", (EmojiId(1922), EmojiId(1917))),
+        ("🙇🏽\u{200d}♀", (EmojiId(804), EmojiId(801))),
+        ("🧑🏾\u{200d}🦰", (EmojiId(595), EmojiId(591))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2286), EmojiId(2264))),
+        ("🥛", (EmojiId(2694), EmojiId(2694))),
+        ("👩🏼\u{200d}🦼\u{200d}➡", (EmojiId(1686), EmojiId(1684))),
+        ("🫶🏿", (EmojiId(384), EmojiId(379))),
+        ("🙋🏻\u{200d}♀", (EmojiId(766), EmojiId(765))),
+        ("🤒", (EmojiId(59), EmojiId(59))),
+        ("🫴", (EmojiId(217), EmojiId(217))),
+        ("🚣\u{200d}♂\u{fe0f}", (EmojiId(1875), EmojiId(1875))),
+        ("🏃🏼\u{200d}♂\u{200d}➡", (EmojiId(1758), EmojiId(1756))),
+        ("🦋", (EmojiId(2545), EmojiId(2545))),
+        ("😶", (EmojiId(40), EmojiId(40))),
+        ("🧑🏾\u{200d}🏭", (EmojiId(973), EmojiId(969))),
+        ("🏄🏾\u{200d}♂\u{fe0f}", (EmojiId(1861), EmojiId(1857))),
+        ("🏃🏽\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1753), EmojiId(1750))),
+        ("👩🏾\u{200d}🦯", (EmojiId(1646), EmojiId(1642))),
+        ("☀", (EmojiId(2904), EmojiId(2904))),
+        ("🏠", (EmojiId(2745), EmojiId(2745))),
+        ("👱🏼\u{200d}♀\u{fe0f}", (EmojiId(635), EmojiId(633))),
+        ("🌶", (EmojiId(2614), EmojiId(2614))),
+        ("🙅🏻", (EmojiId(700), EmojiId(699))),
+        ("👩🏼\u{200d}💻", (EmojiId(1037), EmojiId(1035))),
+        ("🛷", (EmojiId(2990), EmojiId(2990))),
+        ("🏃🏻\u{200d}♀", (EmojiId(1739), EmojiId(1738))),
+        ("🤵🏿\u{200d}♀", (EmojiId(1274), EmojiId(1269))),
+        ("👱\u{200d}♂", (EmojiId(639), EmojiId(639))),
+        ("🚶🏻\u{200d}♂", (EmojiId(1535), EmojiId(1534))),
+        ("💆🏼\u{200d}♀\u{fe0f}", (EmojiId(1506), EmojiId(1504))),
+        ("🧑🏽\u{200d}🦯", (EmojiId(1621), EmojiId(1618))),
+        ("🤸🏼\u{200d}♂", (EmojiId(1985), EmojiId(1983))),
+        ("🍮", (EmojiId(2691), EmojiId(2691))),
+        ("🏃🏾\u{200d}♂\u{200d}➡", (EmojiId(1760), EmojiId(1756))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏼", (EmojiId(2097), EmojiId(2082))),
+        ("🧔🏾\u{200d}♀\u{fe0f}", (EmojiId(553), EmojiId(549))),
+        ("💆", (EmojiId(1492), EmojiId(1492))),
+        ("🚺", (EmojiId(3292), EmojiId(3292))),
+        ("㊙", (EmojiId(3474), EmojiId(3474))),
+        ("🛺", (EmojiId(2820), EmojiId(2820))),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏾", (EmojiId(2302), EmojiId(2290))),
+        ("🧑🏽\u{200d}⚖", (EmojiId(900), EmojiId(897))),
+        ("🫳🏽", (EmojiId(214), EmojiId(211))),
+        ("🏬", (EmojiId(2756), EmojiId(2756))),
+        ("🫃🏿", (EmojiId(1304), EmojiId(1299))),
+        ("🧜🏼\u{200d}♀\u{fe0f}", (EmojiId(1463), EmojiId(1461))),
+        ("🙅🏾\u{200d}♂\u{fe0f}", (EmojiId(709), EmojiId(705))),
+        ("🩹", (EmojiId(3249), EmojiId(3249))),
+        ("🏘", (EmojiId(2743), EmojiId(2743))),
+        ("🏃🏽\u{200d}♀", (EmojiId(1741), EmojiId(1738))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2227), EmojiId(2212))),
+        ("🧑🏼\u{200d}🌾", (EmojiId(917), EmojiId(915))),
+        ("🈺", (EmojiId(3475), EmojiId(3475))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏾", (EmojiId(2367), EmojiId(2342))),
+        ("💴", (EmojiId(3155), EmojiId(3155))),
+        ("🧑🏽\u{200d}💼", (EmojiId(990), EmojiId(987))),
+        ("🔔", (EmojiId(3077), EmojiId(3077))),
+        ("👨🏿\u{200d}🦯", (EmojiId(1635), EmojiId(1630))),
+        ("🧑🏼\u{200d}🔬", (EmojiId(1007), EmojiId(1005))),
+        ("🚶🏽\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1555), EmojiId(1552))),
+        ("👨🏼\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1638), EmojiId(1636))),
+        ("⛹🏻", (EmojiId(1906), EmojiId(1905))),
+        ("🏎", (EmojiId(2815), EmojiId(2815))),
+        ("🐄", (EmojiId(2460), EmojiId(2460))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2376), EmojiId(2368))),
+        ("🏄🏻\u{200d}♂\u{fe0f}", (EmojiId(1858), EmojiId(1857))),
+        ("🎅🏾", (EmojiId(1345), EmojiId(1341))),
+        ("🧙🏼\u{200d}♀", (EmojiId(1409), EmojiId(1407))),
+        ("🧘🏽\u{200d}♂\u{fe0f}", (EmojiId(2061), EmojiId(2058))),
+        ("🇲🇼", (EmojiId(3681), EmojiId(3681))),
+        ("👸🏻", (EmojiId(1222), EmojiId(1221))),
+        ("🕒", (EmojiId(2873), EmojiId(2873))),
+        ("🎯", (EmojiId(2992), EmojiId(2992))),
+        ("🤱🏾", (EmojiId(1315), EmojiId(1311))),
+        ("🚵🏻", (EmojiId(1960), EmojiId(1959))),
+        ("🦥", (EmojiId(2490), EmojiId(2490))),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2245), EmojiId(2238))),
+        ("👻", (EmojiId(114), EmojiId(114))),
+        ("🏃🏼\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1752), EmojiId(1750))),
+        ("🏂", (EmojiId(1827), EmojiId(1827))),
+        ("🧍🏻\u{200d}♂\u{fe0f}", (EmojiId(1571), EmojiId(1570))),
+        ("🪑", (EmojiId(3259), EmojiId(3259))),
+        ("⏩", (EmojiId(3364), EmojiId(3364))),
+        ("🚶🏽\u{200d}♂\u{fe0f}", (EmojiId(1537), EmojiId(1534))),
+        ("💇🏻\u{200d}♀", (EmojiId(1523), EmojiId(1522))),
+        ("🏃🏻\u{200d}♂", (EmojiId(1733), EmojiId(1732))),
+        ("🧄", (EmojiId(2619), EmojiId(2619))),
+        ("👎🏽", (EmojiId(340), EmojiId(337))),
+        ("🧑🏻\u{200d}🎤", (EmojiId(1042), EmojiId(1041))),
+        ("💂🏾", (EmojiId(1171), EmojiId(1167))),
+        ("🚣🏼\u{200d}♂\u{fe0f}", (EmojiId(1877), EmojiId(1875))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2260), EmojiId(2238))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏼", (EmojiId(2357), EmojiId(2342))),
+        ("🧍🏻\u{200d}♂", (EmojiId(1571), EmojiId(1570))),
+        ("🦶🏽", (EmojiId(464), EmojiId(461))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏾", (EmojiId(2388), EmojiId(2368))),
+        ("👳🏽\u{200d}♀\u{fe0f}", (EmojiId(1242), EmojiId(1239))),
+        ("✌", (EmojiId(253), EmojiId(253))),
+        ("✒\u{fe0f}", (EmojiId(3177), EmojiId(3177))),
+        ("🧘\u{200d}♀", (EmojiId(2064), EmojiId(2064))),
+        ("🇬🇬", (EmojiId(3605), EmojiId(3605))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", (EmojiId(2239), EmojiId(2238))),
+        ("🧎🏾\u{200d}♂", (EmojiId(1592), EmojiId(1588))),
+        ("🧑🏾\u{200d}🔬", (EmojiId(1009), EmojiId(1005))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏻", (EmojiId(2282), EmojiId(2264))),
+        ("👨🏾\u{200d}🍼", (EmojiId(1327), EmojiId(1323))),
+        ("🧑🏽\u{200d}🚀", (EmojiId(1098), EmojiId(1095))),
+        ("🧍🏼\u{200d}♀\u{fe0f}", (EmojiId(1578), EmojiId(1576))),
+        ("🥕", (EmojiId(2612), EmojiId(2612))),
+        ("🧑🏼\u{200d}🦰", (EmojiId(593), EmojiId(591))),
+        ("🕴🏼", (EmojiId(1776), EmojiId(1774))),
+        ("🕵\u{fe0f}\u{200d}♂", (EmojiId(1155), EmojiId(1155))),
+        ("🙆🏽\u{200d}♀", (EmojiId(732), EmojiId(729))),
+        ("🚦", (EmojiId(2833), EmojiId(2833))),
+        ("🧛🏿", (EmojiId(1436), EmojiId(1431))),
+        ("🇸🇪", (EmojiId(3722), EmojiId(3722))),
+        ("🕵\u{fe0f}\u{200d}♀", (EmojiId(1161), EmojiId(1161))),
+        ("📅", (EmojiId(3187), EmojiId(3187))),
+        ("🧑🏼\u{200d}🏫", (EmojiId(881), EmojiId(879))),
+        ("🥰", (EmojiId(14), EmojiId(14))),
+        ("🙋🏻\u{200d}♂\u{fe0f}", (EmojiId(760), EmojiId(759))),
+        ("💁🏼", (EmojiId(737), EmojiId(735))),
+        ("👩🏽\u{200d}🦽", (EmojiId(1717), EmojiId(1714))),
+        ("💱", (EmojiId(3401), EmojiId(3401))),
+        ("👨🏿\u{200d}🔬", (EmojiId(1016), EmojiId(1011))),
+        ("🤽🏽\u{200d}♀\u{fe0f}", (EmojiId(2013), EmojiId(2010))),
+        ("🧑🏻\u{200d}🚒", (EmojiId(1114), EmojiId(1113))),
+        ("👮🏿\u{200d}♂", (EmojiId(1142), EmojiId(1137))),
+        ("👨\u{200d}⚖", (EmojiId(903), EmojiId(903))),
+        ("👩🏿\u{200d}🦼\u{200d}➡", (EmojiId(1689), EmojiId(1684))),
+        ("🎅🏽", (EmojiId(1344), EmojiId(1341))),
+        ("🪣", (EmojiId(3271), EmojiId(3271))),
+        ("👷🏼\u{200d}♂\u{fe0f}", (EmojiId(1199), EmojiId(1197))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏾", (EmojiId(2298), EmojiId(2290))),
+        ("➕", (EmojiId(3389), EmojiId(3389))),
+        ("🙅\u{200d}♂\u{fe0f}", (EmojiId(705), EmojiId(705))),
+        ("⛏\u{fe0f}", (EmojiId(3214), EmojiId(3214))),
+        ("👨🏾\u{200d}🎨", (EmojiId(1069), EmojiId(1065))),
+        ("◀\u{fe0f}", (EmojiId(3367), EmojiId(3367))),
+        ("🤵🏻\u{200d}♀\u{fe0f}", (EmojiId(1270), EmojiId(1269))),
+        ("🚶🏽\u{200d}➡\u{fe0f}", (EmojiId(1549), EmojiId(1546))),
+        ("👨\u{200d}⚕", (EmojiId(849), EmojiId(849))),
+        ("😥", (EmojiId(91), EmojiId(91))),
+        ("👩🏻\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1685), EmojiId(1684))),
+        ("🧗🏽\u{200d}♂", (EmojiId(1810), EmojiId(1807))),
+        ("🪖", (EmojiId(3064), EmojiId(3064))),
+        ("☪", (EmojiId(3342), EmojiId(3342))),
+        ("💆🏻\u{200d}♂", (EmojiId(1499), EmojiId(1498))),
+        ("🧑🏻\u{200d}✈\u{fe0f}", (EmojiId(1078), EmojiId(1077))),
+        ("💂\u{200d}♀\u{fe0f}", (EmojiId(1179), EmojiId(1179))),
+        ("🐮", (EmojiId(2457), EmojiId(2457))),
+        ("🤲", (EmojiId(391), EmojiId(391))),
+        ("🎼", (EmojiId(3079), EmojiId(3079))),
+        ("🧛🏽\u{200d}♂\u{fe0f}", (EmojiId(1440), EmojiId(1437))),
+        ("🙎🏾\u{200d}♂", (EmojiId(691), EmojiId(687))),
+        ("🧑🏾\u{200d}🦯\u{200d}➡", (EmojiId(1628), EmojiId(1624))),
+        ("🧖🏾\u{200d}♀", (EmojiId(1799), EmojiId(1795))),
+        ("🕵🏼", (EmojiId(1151), EmojiId(1149))),
+        ("🚶🏾", (EmojiId(1532), EmojiId(1528))),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻", (EmojiId(2256), EmojiId(2238))),
+        ("🚶🏻\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1553), EmojiId(1552))),
+        ("🥪", (EmojiId(2645), EmojiId(2645))),
+        ("🏨", (EmojiId(2752), EmojiId(2752))),
+        ("🦹🏿\u{200d}♂", (EmojiId(1388), EmojiId(1383))),
+        ("👩🏾\u{200d}⚖", (EmojiId(913), EmojiId(909))),
+        ("🕴🏾", (EmojiId(1778), EmojiId(1774))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2332), EmojiId(2316))),
+        ("🌹", (EmojiId(2565), EmojiId(2565))),
+        ("🏃\u{200d}♀\u{fe0f}", (EmojiId(1738), EmojiId(1738))),
+        ("🧎\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1612), EmojiId(1612))),
+        ("🧖🏽\u{200d}♀", (EmojiId(1798), EmojiId(1795))),
+        ("*\u{20e3}", (EmojiId(3426), EmojiId(3426))),
+        ("🙏", (EmojiId(423), EmojiId(423))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏼", (EmojiId(2361), EmojiId(2342))),
+        ("👀", (EmojiId(490), EmojiId(490))),
+        ("🏃🏿\u{200d}♀", (EmojiId(1743), EmojiId(1738))),
+        ("🍲", (EmojiId(2654), EmojiId(2654))),
+        ("🪬", (EmojiId(3283), EmojiId(3283))),
+        ("🤵\u{200d}♂", (EmojiId(1263), EmojiId(1263))),
+        ("🧑🏽\u{200d}💻", (EmojiId(1026), EmojiId(1023))),
+        ("👰🏻\u{200d}♀", (EmojiId(1288), EmojiId(1287))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2267), EmojiId(2264))),
+        ("🥙", (EmojiId(2649), EmojiId(2649))),
+        ("🫐", (EmojiId(2604), EmojiId(2604))),
+        ("🦹🏾\u{200d}♀\u{fe0f}", (EmojiId(1393), EmojiId(1389))),
+        ("🦶🏾", (EmojiId(465), EmojiId(461))),
+        ("🏃🏻\u{200d}➡\u{fe0f}", (EmojiId(1745), EmojiId(1744))),
+        ("🧎🏿", (EmojiId(1587), EmojiId(1582))),
+        ("👱🏾\u{200d}♂\u{fe0f}", (EmojiId(643), EmojiId(639))),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2277), EmojiId(2264))),
+        ("🧍🏽\u{200d}♀\u{fe0f}", (EmojiId(1579), EmojiId(1576))),
+        ("🧑🏿\u{200d}⚕\u{fe0f}", (EmojiId(848), EmojiId(843))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2288), EmojiId(2264))),
+        ("👸🏽", (EmojiId(1224), EmojiId(1221))),
+        ("👩🏽\u{200d}💻", (EmojiId(1038), EmojiId(1035))),
+        ("👊", (EmojiId(349), EmojiId(349))),
+        ("👳🏽\u{200d}♂", (EmojiId(1236), EmojiId(1233))),
+        ("👨\u{200d}🦰", (EmojiId(555), EmojiId(555))),
+        ("🪚", (EmojiId(3223), EmojiId(3223))),
+        ("🦶🏻", (EmojiId(462), EmojiId(461))),
+        ("🇦🇸", (EmojiId(3530), EmojiId(3530))),
+        ("🍦", (EmojiId(2679), EmojiId(2679))),
+        ("🤽", (EmojiId(1998), EmojiId(1998))),
+        ("🧜🏾\u{200d}♂", (EmojiId(1459), EmojiId(1455))),
+        ("🛰\u{fe0f}", (EmojiId(2855), EmojiId(2855))),
+        ("🚱", (EmojiId(3307), EmojiId(3307))),
+        ("🤽\u{200d}♀\u{fe0f}", (EmojiId(2010), EmojiId(2010))),
+        ("🧔🏿", (EmojiId(542), EmojiId(537))),
+        ("🦸", (EmojiId(1359), EmojiId(1359))),
+        ("👨🏼", (EmojiId(533), EmojiId(531))),
+        ("👩🏽\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1687), EmojiId(1684))),
+        ("👣", (EmojiId(2428), EmojiId(2428))),
+        ("🧚🏼\u{200d}♂\u{fe0f}", (EmojiId(1421), EmojiId(1419))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2241), EmojiId(2238))),
+        ("♐", (EmojiId(3355), EmojiId(3355))),
+        ("🚶\u{200d}♂", (EmojiId(1534), EmojiId(1534))),
+        ("👆", (EmojiId(301), EmojiId(301))),
+        ("🧖🏿\u{200d}♀", (EmojiId(1800), EmojiId(1795))),
+        ("🗣\u{fe0f}", (EmojiId(2419), EmojiId(2419))),
+        ("🧘🏿\u{200d}♂", (EmojiId(2063), EmojiId(2058))),
+        ("👷🏻\u{200d}♀", (EmojiId(1204), EmojiId(1203))),
+        ("🦹🏼\u{200d}♀", (EmojiId(1391), EmojiId(1389))),
+        ("🦸🏿\u{200d}♀\u{fe0f}", (EmojiId(1376), EmojiId(1371))),
+        ("🏄\u{200d}♂\u{fe0f}", (EmojiId(1857), EmojiId(1857))),
+        ("🏃🏿\u{200d}♂\u{200d}➡", (EmojiId(1761), EmojiId(1756))),
+        ("🙎", (EmojiId(681), EmojiId(681))),
+        ("🏄\u{200d}♀", (EmojiId(1863), EmojiId(1863))),
+        ("🦪", (EmojiId(2543), EmojiId(2543))),
+        ("🤾\u{200d}♀", (EmojiId(2028), EmojiId(2028))),
+        ("📚", (EmojiId(3142), EmojiId(3142))),
+        ("🧙\u{200d}♀\u{fe0f}", (EmojiId(1407), EmojiId(1407))),
+        ("🕵🏼\u{200d}♂\u{fe0f}", (EmojiId(1157), EmojiId(1155))),
+        ("🧿", (EmojiId(3282), EmojiId(3282))),
+        ("🙅🏿\u{200d}♀\u{fe0f}", (EmojiId(716), EmojiId(711))),
+        ("🧘🏿", (EmojiId(2057), EmojiId(2052))),
+        ("😯", (EmojiId(82), EmojiId(82))),
+        ("🚉", (EmojiId(2793), EmojiId(2793))),
+        ("😲", (EmojiId(83), EmojiId(83))),
+        ("🧑🏻\u{200d}⚕\u{fe0f}", (EmojiId(844), EmojiId(843))),
+        ("👳🏻", (EmojiId(1228), EmojiId(1227))),
+        ("🇮🇱", (EmojiId(3628), EmojiId(3628))),
+        ("💂🏼\u{200d}♂\u{fe0f}", (EmojiId(1175), EmojiId(1173))),
+        ("👶🏾", (EmojiId(499), EmojiId(495))),
+        ("🔄", (EmojiId(3328), EmojiId(3328))),
+        ("🙎🏾\u{200d}♀\u{fe0f}", (EmojiId(697), EmojiId(693))),
+        ("🧚\u{200d}♂\u{fe0f}", (EmojiId(1419), EmojiId(1419))),
+        ("👊🏾", (EmojiId(353), EmojiId(349))),
+        ("👩🏼\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1722), EmojiId(1720))),
+        ("👨🏼\u{200d}🦽", (EmojiId(1704), EmojiId(1702))),
+        ("🤹🏾\u{200d}♀", (EmojiId(2050), EmojiId(2046))),
+        ("😚", (EmojiId(20), EmojiId(20))),
+        ("☺", (EmojiId(19), EmojiId(19))),
+        ("🏮", (EmojiId(3134), EmojiId(3134))),
+        ("🇸🇾", (EmojiId(3737), EmojiId(3737))),
+        ("🧑🏻\u{200d}🦽\u{200d}➡", (EmojiId(1697), EmojiId(1696))),
+        ("🧏🏾\u{200d}♂", (EmojiId(781), EmojiId(777))),
+        ("👩\u{200d}💼", (EmojiId(999), EmojiId(999))),
+        ("🧑🏽\u{200d}🎨", (EmojiId(1062), EmojiId(1059))),
+        ("🫁", (EmojiId(487), EmojiId(487))),
+        ("🦸🏾\u{200d}♀\u{fe0f}", (EmojiId(1375), EmojiId(1371))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏻", (EmojiId(2334), EmojiId(2316))),
+        ("💶", (EmojiId(3157), EmojiId(3157))),
+        ("🚶🏾\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1556), EmojiId(1552))),
+        ("🧳", (EmojiId(2859), EmojiId(2859))),
+        ("🚵🏿\u{200d}♀", (EmojiId(1976), EmojiId(1971))),
+        ("🆖", (EmojiId(3453), EmojiId(3453))),
+        ("👩", (EmojiId(579), EmojiId(579))),
+        ("🙋\u{200d}♂\u{fe0f}", (EmojiId(759), EmojiId(759))),
+        ("🕰\u{fe0f}", (EmojiId(2866), EmojiId(2866))),
+        ("🏌\u{200d}♂", (EmojiId(1839), EmojiId(1839))),
+        ("🏃🏼\u{200d}➡\u{fe0f}", (EmojiId(1746), EmojiId(1744))),
+        ("🙆🏽\u{200d}♂", (EmojiId(726), EmojiId(723))),
+        ("☮", (EmojiId(3343), EmojiId(3343))),
+        ("👩\u{200d}✈", (EmojiId(1089), EmojiId(1089))),
+        ("🤍", (EmojiId(154), EmojiId(154))),
+        ("🙎🏾\u{200d}♂\u{fe0f}", (EmojiId(691), EmojiId(687))),
+        ("🇸🇨", (EmojiId(3720), EmojiId(3720))),
+        ("🙆\u{200d}♀\u{fe0f}", (EmojiId(729), EmojiId(729))),
+        ("🧑", (EmojiId(519), EmojiId(519))),
+        ("🎚\u{fe0f}", (EmojiId(3083), EmojiId(3083))),
+        ("⛹🏻\u{200d}♀", (EmojiId(1918), EmojiId(1917))),
+        ("🇯🇴", (EmojiId(3638), EmojiId(3638))),
+        ("🫰🏼", (EmojiId(267), EmojiId(265))),
+        ("🫎", (EmojiId(2450), EmojiId(2450))),
+        ("👨🏿\u{200d}⚕\u{fe0f}", (EmojiId(854), EmojiId(849))),
+        ("⛸\u{fe0f}", (EmojiId(2985), EmojiId(2985))),
+        ("🧙🏾\u{200d}♀", (EmojiId(1411), EmojiId(1407))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2102), EmojiId(2082))),
+        ("🤦🏻\u{200d}♂\u{fe0f}", (EmojiId(814), EmojiId(813))),
+        ("🙎🏿", (EmojiId(686), EmojiId(681))),
+        ("🧀", (EmojiId(2636), EmojiId(2636))),
+        ("🫅🏾", (EmojiId(1213), EmojiId(1209))),
+        ("🧘🏼\u{200d}♀\u{fe0f}", (EmojiId(2066), EmojiId(2064))),
+        ("🧦", (EmojiId(3034), EmojiId(3034))),
+        ("😕", (EmojiId(76), EmojiId(76))),
+        ("🧖🏻\u{200d}♀", (EmojiId(1796), EmojiId(1795))),
+        ("🚶🏾\u{200d}♂\u{200d}➡", (EmojiId(1562), EmojiId(1558))),
+        ("🌧\u{fe0f}", (EmojiId(2918), EmojiId(2918))),
+        ("🤾🏾\u{200d}♀", (EmojiId(2032), EmojiId(2028))),
+        ("🇿🇼", (EmojiId(3777), EmojiId(3777))),
+        ("👉🏼", (EmojiId(297), EmojiId(295))),
+        ("🇲🇱", (EmojiId(3670), EmojiId(3670))),
+        ("👨🏼\u{200d}🌾", (EmojiId(923), EmojiId(921))),
+        ("👨🏽\u{200d}🦱", (EmojiId(564), EmojiId(561))),
+        ("🚴\u{200d}♂\u{fe0f}", (EmojiId(1947), EmojiId(1947))),
+        ("💃", (EmojiId(1762), EmojiId(1762))),
+        ("🇱🇨", (EmojiId(3653), EmojiId(3653))),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼", (EmojiId(2248), EmojiId(2238))),
+        ("🙍🏾\u{200d}♂\u{fe0f}", (EmojiId(673), EmojiId(669))),
+        ("📛", (EmojiId(3407), EmojiId(3407))),
+        ("😮", (EmojiId(81), EmojiId(81))),
+        ("🇻🇳", (EmojiId(3768), EmojiId(3768))),
+        ("🏃🏾", (EmojiId(1730), EmojiId(1726))),
+        ("🧜🏾\u{200d}♂\u{fe0f}", (EmojiId(1459), EmojiId(1455))),
+        ("🙍🏼\u{200d}♀\u{fe0f}", (EmojiId(677), EmojiId(675))),
+        ("🇬🇲", (EmojiId(3609), EmojiId(3609))),
+        ("😿", (EmojiId(125), EmojiId(125))),
+        ("🤸🏻\u{200d}♂\u{fe0f}", (EmojiId(1984), EmojiId(1983))),
+        ("☁\u{fe0f}", (EmojiId(2912), EmojiId(2912))),
+        ("👩🏽\u{200d}⚕", (EmojiId(858), EmojiId(855))),
+        ("🤦🏾\u{200d}♂\u{fe0f}", (EmojiId(817), EmojiId(813))),
+        ("👨🏼\u{200d}✈\u{fe0f}", (EmojiId(1085), EmojiId(1083))),
+        ("👩🏿\u{200d}🦼", (EmojiId(1683), EmojiId(1678))),
+        ("👩🏼\u{200d}🔧", (EmojiId(965), EmojiId(963))),
+        ("🙋🏾\u{200d}♂\u{fe0f}", (EmojiId(763), EmojiId(759))),
+        ("👩🏾\u{200d}🏫", (EmojiId(895), EmojiId(891))),
+        ("🙆🏼", (EmojiId(719), EmojiId(717))),
+        ("🦵🏻", (EmojiId(456), EmojiId(455))),
+        ("⏲\u{fe0f}", (EmojiId(2865), EmojiId(2865))),
+        ("🦒", (EmojiId(2471), EmojiId(2471))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏿", (EmojiId(2337), EmojiId(2316))),
+        ("🎗", (EmojiId(2956), EmojiId(2956))),
+        ("💈", (EmojiId(2784), EmojiId(2784))),
+        ("🇸🇯", (EmojiId(3726), EmojiId(3726))),
+        ("🤹🏼\u{200d}♀\u{fe0f}", (EmojiId(2048), EmojiId(2046))),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏽", (EmojiId(2119), EmojiId(2108))),
+        ("🧅", (EmojiId(2620), EmojiId(2620))),
+        ("🏧", (EmojiId(3287), EmojiId(3287))),
+        ("🧑🏿\u{200d}✈\u{fe0f}", (EmojiId(1082), EmojiId(1077))),
+        ("⚽", (EmojiId(2965), EmojiId(2965))),
+        ("☘", (EmojiId(2580), EmojiId(2580))),
+        ("🩶", (EmojiId(153), EmojiId(153))),
+        ("🇪🇦", (EmojiId(3585), EmojiId(3585))),
+        ("🧛\u{200d}♂", (EmojiId(1437), EmojiId(1437))),
+        ("🧑\u{200d}⚕\u{fe0f}", (EmojiId(843), EmojiId(843))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2348), EmojiId(2342))),
+        ("🙍\u{200d}♀", (EmojiId(675), EmojiId(675))),
+        ("🧜\u{200d}♀", (EmojiId(1461), EmojiId(1461))),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2263), EmojiId(2238))),
+        ("🙇\u{200d}♀\u{fe0f}", (EmojiId(801), EmojiId(801))),
+        ("🛌🏽", (EmojiId(2079), EmojiId(2076))),
+        ("🐦", (EmojiId(2502), EmojiId(2502))),
+        ("🤷🏾\u{200d}♀\u{fe0f}", (EmojiId(841), EmojiId(837))),
+        ("🧝🏾\u{200d}♀\u{fe0f}", (EmojiId(1483), EmojiId(1479))),
+        ("👮🏾\u{200d}♂", (EmojiId(1141), EmojiId(1137))),
+        ("🌲", (EmojiId(2574), EmojiId(2574))),
+        ("🧑🏿\u{200d}🎤", (EmojiId(1046), EmojiId(1041))),
+        ("✍", (EmojiId(429), EmojiId(429))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2341), EmojiId(2316))),
+        ("👨🏼\u{200d}🦱", (EmojiId(563), EmojiId(561))),
+        ("🔒", (EmojiId(3206), EmojiId(3206))),
+        ("🇪🇨", (EmojiId(3586), EmojiId(3586))),
+        ("🤳🏼", (EmojiId(443), EmojiId(441))),
+        ("🧔🏿\u{200d}♀", (EmojiId(554), EmojiId(549))),
+        ("🇨🇻", (EmojiId(3573), EmojiId(3573))),
+        ("🫸🏼", (EmojiId(231), EmojiId(229))),
+        ("🇱🇧", (EmojiId(3652), EmojiId(3652))),
+        ("🧝🏽\u{200d}♀", (EmojiId(1482), EmojiId(1479))),
+        ("👩🏽\u{200d}🦼", (EmojiId(1681), EmojiId(1678))),
+        ("🧎🏽\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1615), EmojiId(1612))),
+        ("☝🏿", (EmojiId(324), EmojiId(319))),
+        ("⤵", (EmojiId(3326), EmojiId(3326))),
+        ("🍪", (EmojiId(2683), EmojiId(2683))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏾", (EmojiId(2393), EmojiId(2368))),
+        ("😨", (EmojiId(89), EmojiId(89))),
+        ("🤽🏿", (EmojiId(2003), EmojiId(1998))),
+        ("🇲🇽", (EmojiId(3682), EmojiId(3682))),
+        ("🏃🏽\u{200d}➡", (EmojiId(1747), EmojiId(1744))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2094), EmojiId(2082))),
+        ("✡", (EmojiId(3337), EmojiId(3337))),
+        ("🕵\u{fe0f}\u{200d}♂\u{fe0f}", (EmojiId(1155), EmojiId(1155))),
+        ("👼🏼", (EmojiId(1337), EmojiId(1335))),
+        ("🙅🏿\u{200d}♀", (EmojiId(716), EmojiId(711))),
+        ("🛤\u{fe0f}", (EmojiId(2827), EmojiId(2827))),
+        ("🚣🏻\u{200d}♂\u{fe0f}", (EmojiId(1876), EmojiId(1875))),
+        ("🫰🏿", (EmojiId(270), EmojiId(265))),
+        ("🦎", (EmojiId(2521), EmojiId(2521))),
+        ("↕\u{fe0f}", (EmojiId(3321), EmojiId(3321))),
+        ("⛪", (EmojiId(2763), EmojiId(2763))),
+        ("🛠\u{fe0f}", (EmojiId(3216), EmojiId(3216))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", (EmojiId(2269), EmojiId(2264))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏾", (EmojiId(2098), EmojiId(2082))),
+        ("🏓", (EmojiId(2979), EmojiId(2979))),
+        ("🇦🇶", (EmojiId(3528), EmojiId(3528))),
+        ("🥀", (EmojiId(2566), EmojiId(2566))),
+        ("🟤", (EmojiId(3483), EmojiId(3483))),
+        ("💁🏼\u{200d}♀\u{fe0f}", (EmojiId(749), EmojiId(747))),
+        ("🐹", (EmojiId(2479), EmojiId(2479))),
+        ("🌕", (EmojiId(2895), EmojiId(2895))),
+        ("🏃🏾\u{200d}♀\u{fe0f}", (EmojiId(1742), EmojiId(1738))),
+        ("🙎🏽\u{200d}♂", (EmojiId(690), EmojiId(687))),
+        ("👨🏽\u{200d}⚖\u{fe0f}", (EmojiId(906), EmojiId(903))),
+        ("🕵🏻\u{200d}♂", (EmojiId(1156), EmojiId(1155))),
+        ("👩🏼\u{200d}🦽\u{200d}➡", (EmojiId(1722), EmojiId(1720))),
+        ("🏄🏿\u{200d}♂\u{fe0f}", (EmojiId(1862), EmojiId(1857))),
+        ("🇲🇵", (EmojiId(3674), EmojiId(3674))),
+        ("🫵🏼", (EmojiId(327), EmojiId(325))),
+        ("⤴\u{fe0f}", (EmojiId(3325), EmojiId(3325))),
+        ("🍋\u{200d}🟩", (EmojiId(2594), EmojiId(2594))),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏽", (EmojiId(2279), EmojiId(2264))),
+        ("👱\u{200d}♀\u{fe0f}", (EmojiId(633), EmojiId(633))),
+        ("🇧🇬", (EmojiId(3541), EmojiId(3541))),
+        ("🕵🏾\u{200d}♂", (EmojiId(1159), EmojiId(1155))),
+        ("🧜\u{200d}♂", (EmojiId(1455), EmojiId(1455))),
+        ("🤚", (EmojiId(175), EmojiId(175))),
+        ("👨🏿\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1641), EmojiId(1636))),
+        ("🙋🏼\u{200d}♀", (EmojiId(767), EmojiId(765))),
+        ("🫚", (EmojiId(2624), EmojiId(2624))),
+        ("🧜🏿\u{200d}♀", (EmojiId(1466), EmojiId(1461))),
+        ("👩🏿\u{200d}🚒", (EmojiId(1130), EmojiId(1125))),
+        ("🙌🏻", (EmojiId(374), EmojiId(373))),
+        ("👇", (EmojiId(313), EmojiId(313))),
+        ("🟡", (EmojiId(3479), EmojiId(3479))),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏻", (EmojiId(2182), EmojiId(2160))),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏽", (EmojiId(2141), EmojiId(2134))),
+        ("🍼", (EmojiId(2693), EmojiId(2693))),
+        ("👨🏿\u{200d}🎓", (EmojiId(872), EmojiId(867))),
+        ("🍴", (EmojiId(2715), EmojiId(2715))),
+        ("👩🏻\u{200d}🦽\u{200d}➡", (EmojiId(1721), EmojiId(1720))),
+        ("🧛🏻\u{200d}♂", (EmojiId(1438), EmojiId(1437))),
+        ("🧑🏿\u{200d}💻", (EmojiId(1028), EmojiId(1023))),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏻", (EmojiId(2122), EmojiId(2108))),
+        ("🖕🏾", (EmojiId(311), EmojiId(307))),
+        ("🧑🏽\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1699), EmojiId(1696))),
+        ("💇🏾\u{200d}♂", (EmojiId(1520), EmojiId(1516))),
+        ("✅", (EmojiId(3410), EmojiId(3410))),
+        ("🩳", (EmojiId(3040), EmojiId(3040))),
+        ("🍐", (EmojiId(2600), EmojiId(2600))),
+        ("🙆\u{200d}♀", (EmojiId(729), EmojiId(729))),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏻", (EmojiId(2178), EmojiId(2160))),
+        ("🌅", (EmojiId(2775), EmojiId(2775))),
+        ("⛓\u{fe0f}", (EmojiId(3233), EmojiId(3233))),
+        ("🎄", (EmojiId(2939), EmojiId(2939))),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", (EmojiId(2193), EmojiId(2186))),
+        ("🇦🇲", (EmojiId(3526), EmojiId(3526))),
+        ("🙎🏼\u{200d}♂\u{fe0f}", (EmojiId(689), EmojiId(687))),
+        ("😶\u{200d}🌫", (EmojiId(42), EmojiId(42))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏼", (EmojiId(2326), EmojiId(2316))),
+        ("🏃🏽\u{200d}♂\u{fe0f}", (EmojiId(1735), EmojiId(1732))),
+        ("🧒🏽", (EmojiId(504), EmojiId(501))),
+        ("👼🏿", (EmojiId(1340), EmojiId(1335))),
+        ("🙋\u{200d}♂", (EmojiId(759), EmojiId(759))),
+        ("🤷🏿", (EmojiId(830), EmojiId(825))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2343), EmojiId(2342))),
+        ("🧑\u{200d}🦰", (EmojiId(591), EmojiId(591))),
+        ("🏉", (EmojiId(2971), EmojiId(2971))),
+        ("👛", (EmojiId(3044), EmojiId(3044))),
+        ("🐨", (EmojiId(2488), EmojiId(2488))),
+        ("🦸🏻", (EmojiId(1360), EmojiId(1359))),
+        ("♏", (EmojiId(3354), EmojiId(3354))),
+        ("🦻🏿", (EmojiId(478), EmojiId(473))),
+        ("⬅\u{fe0f}", (EmojiId(3319), EmojiId(3319))),
+        ("👨🏿\u{200d}💻", (EmojiId(1034), EmojiId(1029))),
+        ("🏌🏼\u{200d}♂", (EmojiId(1841), EmojiId(1839))),
+        ("👱🏾\u{200d}♀", (EmojiId(637), EmojiId(633))),
+        ("🤵🏻\u{200d}♂\u{fe0f}", (EmojiId(1264), EmojiId(1263))),
+        ("🇲🇰", (EmojiId(3669), EmojiId(3669))),
+        ("💁🏽\u{200d}♂\u{fe0f}", (EmojiId(744), EmojiId(741))),
+        ("🧜", (EmojiId(1449), EmojiId(1449))),
+        ("🦹🏼\u{200d}♀\u{fe0f}", (EmojiId(1391), EmojiId(1389))),
+        ("⭕", (EmojiId(3409), EmojiId(3409))),
+        ("🇺🇸", (EmojiId(3760), EmojiId(3760))),
+        ("🫸🏾", (EmojiId(233), EmojiId(229))),
+        ("🦑", (EmojiId(2542), EmojiId(2542))),
+        ("🏵", (EmojiId(2564), EmojiId(2564))),
+        ("🗜", (EmojiId(3228), EmojiId(3228))),
+        ("🤽🏼\u{200d}♂\u{fe0f}", (EmojiId(2006), EmojiId(2004))),
+        ("⛹🏾\u{200d}♂", (EmojiId(1915), EmojiId(1911))),
+        ("🙆🏻\u{200d}♀\u{fe0f}", (EmojiId(730), EmojiId(729))),
+        ("👩🏾\u{200d}🎓", (EmojiId(877), EmojiId(873))),
+        ("👳🏼", (EmojiId(1229), EmojiId(1227))),
+        ("🧕🏿", (EmojiId(1256), EmojiId(1251))),
+        ("🧚🏽\u{200d}♂\u{fe0f}", (EmojiId(1422), EmojiId(1419))),
+        ("👱🏿\u{200d}♂", (EmojiId(644), EmojiId(639))),
+        ("⛹🏽\u{200d}♀\u{fe0f}", (EmojiId(1920), EmojiId(1917))),
+        ("👨🏻\u{200d}✈", (EmojiId(1084), EmojiId(1083))),
+        ("🧑🏼\u{200d}🦯\u{200d}➡", (EmojiId(1626), EmojiId(1624))),
+        ("🌁", (EmojiId(2771), EmojiId(2771))),
+        ("🧎🏻\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1613), EmojiId(1612))),
+        ("👷🏻\u{200d}♂", (EmojiId(1198), EmojiId(1197))),
+        ("🧔🏻\u{200d}♂\u{fe0f}", (EmojiId(544), EmojiId(543))),
+        ("🏛\u{fe0f}", (EmojiId(2737), EmojiId(2737))),
+        ("⬇", (EmojiId(3317), EmojiId(3317))),
+        ("🧊", (EmojiId(2712), EmojiId(2712))),
+        ("🤠", (EmojiId(70), EmojiId(70))),
+        ("🧛🏿\u{200d}♂\u{fe0f}", (EmojiId(1442), EmojiId(1437))),
+        ("🫲🏻", (EmojiId(206), EmojiId(205))),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏾", (EmojiId(2211), EmojiId(2186))),
+        ("📡", (EmojiId(3245), EmojiId(3245))),
+        ("🥴", (EmojiId(66), EmojiId(66))),
+        ("🧎🏼\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1608), EmojiId(1606))),
+        ("🫱🏼\u{200d}🫲🏻", (EmojiId(407), EmojiId(397))),
+        ("🤸🏿", (EmojiId(1982), EmojiId(1977))),
+        ("🛢\u{fe0f}", (EmojiId(2828), EmojiId(2828))),
+        ("😮\u{200d}💨", (EmojiId(47), EmojiId(47))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", (EmojiId(2271), EmojiId(2264))),
+        ("🏊🏽\u{200d}♂\u{fe0f}", (EmojiId(1896), EmojiId(1893))),
+        ("🧑🏼\u{200d}🦲", (EmojiId(629), EmojiId(627))),
+        ("🧚🏻\u{200d}♀\u{fe0f}", (EmojiId(1426), EmojiId(1425))),
+        ("👳\u{200d}♀\u{fe0f}", (EmojiId(1239), EmojiId(1239))),
+        ("🫱🏽\u{200d}🫲🏾", (EmojiId(413), EmojiId(397))),
+        ("🧑\u{200d}✈\u{fe0f}", (EmojiId(1077), EmojiId(1077))),
+        ("🧑🏻\u{200d}🎨", (EmojiId(1060), EmojiId(1059))),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏾", (EmojiId(2306), EmojiId(2290))),
+        ("☘\u{fe0f}", (EmojiId(2580), EmojiId(2580))),
+        ("💁🏼\u{200d}♂", (EmojiId(743), EmojiId(741))),
+        ("👩🏼\u{200d}❤\u{200d}👨🏾", (EmojiId(2328), EmojiId(2316))),
+        ("🥞", (EmojiId(2634), EmojiId(2634))),
+        ("🐾", (EmojiId(2495), EmojiId(2495))),
+        ("🧑🏿\u{200d}🦼\u{200d}➡", (EmojiId(1665), EmojiId(1660))),
+        ("🚶🏿\u{200d}♂\u{200d}➡", (EmojiId(1563), EmojiId(1558))),
+        ("🥱", (EmojiId(101), EmojiId(101))),
+        ("🫳🏾", (EmojiId(215), EmojiId(211))),
+        ("🦸🏼\u{200d}♀", (EmojiId(1373), EmojiId(1371))),
+        ("🚶🏽\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1555), EmojiId(1552))),
+        ("🍚", (EmojiId(2665), EmojiId(2665))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏿", (EmojiId(2373), EmojiId(2368))),
+        ("👨🏿\u{200d}🦽\u{200d}➡", (EmojiId(1713), EmojiId(1708))),
+        ("👩🏿\u{200d}✈\u{fe0f}", (EmojiId(1094), EmojiId(1089))),
+        ("👰🏿\u{200d}♀", (EmojiId(1292), EmojiId(1287))),
+        ("🦵🏾", (EmojiId(459), EmojiId(455))),
+        ("🍗", (EmojiId(2638), EmojiId(2638))),
+        ("👩🏽\u{200d}❤\u{200d}👩🏻", (EmojiId(2382), EmojiId(2368))),
+        ("🧛🏿\u{200d}♀", (EmojiId(1448), EmojiId(1443))),
+        ("🚶\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1552), EmojiId(1552))),
+        ("👷🏼", (EmojiId(1193), EmojiId(1191))),
+        ("🏃🏼\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1752), EmojiId(1750))),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏻", (EmojiId(2312), EmojiId(2290))),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", (EmojiId(2197), EmojiId(2186))),
+        ("🙋🏿\u{200d}♀", (EmojiId(770), EmojiId(765))),
+        ("🚄", (EmojiId(2788), EmojiId(2788))),
+        ("👷🏻", (EmojiId(1192), EmojiId(1191))),
+        ("💆🏻\u{200d}♀\u{fe0f}", (EmojiId(1505), EmojiId(1504))),
+        ("🫷", (EmojiId(223), EmojiId(223))),
+        ("🚓", (EmojiId(2804), EmojiId(2804))),
+        ("⛏", (EmojiId(3214), EmojiId(3214))),
+        ("🧑🏾\u{200d}🎨", (EmojiId(1063), EmojiId(1059))),
+        ("🇨🇭", (EmojiId(3562), EmojiId(3562))),
+        ("👩🏿\u{200d}🎨", (EmojiId(1076), EmojiId(1071))),
+        ("🤾🏾\u{200d}♂", (EmojiId(2026), EmojiId(2022))),
+        ("🇻🇦", (EmojiId(3763), EmojiId(3763))),
+        ("🇭🇺", (EmojiId(3624), EmojiId(3624))),
+        ("🇲🇻", (EmojiId(3680), EmojiId(3680))),
+        ("🧎🏾\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1616), EmojiId(1612))),
+        ("🎁", (EmojiId(2955), EmojiId(2955))),
+        ("👳🏿\u{200d}♀\u{fe0f}", (EmojiId(1244), EmojiId(1239))),
+        ("🐻", (EmojiId(2486), EmojiId(2486))),
+        ("⛰\u{fe0f}", (EmojiId(2728), EmojiId(2728))),
+        ("🧎🏾\u{200d}♂\u{fe0f}", (EmojiId(1592), EmojiId(1588))),
+        ("😾", (EmojiId(126), EmojiId(126))),
+        ("🏊🏻\u{200d}♀\u{fe0f}", (EmojiId(1900), EmojiId(1899))),
+        ("🐻\u{200d}❄", (EmojiId(2487), EmojiId(2487))),
+        ("🙎\u{200d}♂", (EmojiId(687), EmojiId(687))),
+        ("🍂", (EmojiId(2583), EmojiId(2583))),
+        ("👂🏾", (EmojiId(471), EmojiId(467))),
+        ("🙇🏽\u{200d}♂\u{fe0f}", (EmojiId(798), EmojiId(795))),
+        ("🤹🏽\u{200d}♂\u{fe0f}", (EmojiId(2043), EmojiId(2040))),
+        ("㊗", (EmojiId(3473), EmojiId(3473))),
+        ("🤌🏽", (EmojiId(244), EmojiId(241))),
+        ("👨🏾\u{200d}💻", (EmojiId(1033), EmojiId(1029))),
+        ("😰", (EmojiId(90), EmojiId(90))),
+        ("✉\u{fe0f}", (EmojiId(3163), EmojiId(3163))),
+        ("👷\u{200d}♀", (EmojiId(1203), EmojiId(1203))),
+        ("🧑🏿\u{200d}🦳", (EmojiId(620), EmojiId(615))),
+        ("🫳🏼", (EmojiId(213), EmojiId(211))),
+        ("🎅", (EmojiId(1341), EmojiId(1341))),
+        ("👨🏼\u{200d}🦯\u{200d}➡", (EmojiId(1638), EmojiId(1636))),
+        ("🤝🏼", (EmojiId(399), EmojiId(397))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", (EmojiId(2283), EmojiId(2264))),
+        ("🚝", (EmojiId(2795), EmojiId(2795))),
+        ("🤜🏻", (EmojiId(362), EmojiId(361))),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", (EmojiId(2219), EmojiId(2212))),
+        ("🦴", (EmojiId(489), EmojiId(489))),
+        ("🇧🇾", (EmojiId(3555), EmojiId(3555))),
+        ("💇\u{200d}♂", (EmojiId(1516), EmojiId(1516))),
+        ("☸", (EmojiId(3338), EmojiId(3338))),
+        ("👑", (EmojiId(3059), EmojiId(3059))),
+        ("🚶🏾\u{200d}♂\u{fe0f}", (EmojiId(1538), EmojiId(1534))),
+        ("🤶🏽", (EmojiId(1350), EmojiId(1347))),
+        ("🙇🏾\u{200d}♀", (EmojiId(805), EmojiId(801))),
+        ("🙍🏿\u{200d}♀", (EmojiId(680), EmojiId(675))),
+        ("👨🏾\u{200d}❤\u{200d}👨🏽", (EmojiId(2362), EmojiId(2342))),
+        ("🍽\u{fe0f}", (EmojiId(2714), EmojiId(2714))),
+        ("👜", (EmojiId(3045), EmojiId(3045))),
+        ("🧙🏼\u{200d}♂\u{fe0f}", (EmojiId(1403), EmojiId(1401))),
+        ("🇲🇿", (EmojiId(3684), EmojiId(3684))),
+        ("👨🏿\u{200d}🦽", (EmojiId(1707), EmojiId(1702))),
+        ("🐍", (EmojiId(2522), EmojiId(2522))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2365), EmojiId(2342))),
+        ("🦹🏽\u{200d}♂\u{fe0f}", (EmojiId(1386), EmojiId(1383))),
+        ("🤽🏻\u{200d}♀", (EmojiId(2011), EmojiId(2010))),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2321), EmojiId(2316))),
+        ("🤹🏻", (EmojiId(2035), EmojiId(2034))),
+        ("⏸", (EmojiId(3374), EmojiId(3374))),
+        ("🙎\u{200d}♀\u{fe0f}", (EmojiId(693), EmojiId(693))),
+        ("👷🏻\u{200d}♀\u{fe0f}", (EmojiId(1204), EmojiId(1203))),
+        ("🧖🏻", (EmojiId(1784), EmojiId(1783))),
+        ("👩🏼\u{200d}🚒", (EmojiId(1127), EmojiId(1125))),
+        ("🙇🏿\u{200d}♀", (EmojiId(806), EmojiId(801))),
+        ("🚵🏻\u{200d}♂", (EmojiId(1966), EmojiId(1965))),
+        ("🧎🏿\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1611), EmojiId(1606))),
+        ("💏", (EmojiId(2186), EmojiId(2186))),
+        ("🌑", (EmojiId(2891), EmojiId(2891))),
+        ("💇\u{200d}♂\u{fe0f}", (EmojiId(1516), EmojiId(1516))),
+        ("*\u{fe0f}\u{20e3}", (EmojiId(3426), EmojiId(3426))),
+        ("🧍🏿\u{200d}♂\u{fe0f}", (EmojiId(1575), EmojiId(1570))),
+        ("🧗🏾\u{200d}♂", (EmojiId(1811), EmojiId(1807))),
+        ("☀\u{fe0f}", (EmojiId(2904), EmojiId(2904))),
+        ("👩🏾\u{200d}❤\u{200d}👨🏼", (EmojiId(2335), EmojiId(2316))),
+        ("🫷🏼", (EmojiId(225), EmojiId(223))),
+        ("🦓", (EmojiId(2454), EmojiId(2454))),
+        ("🧗🏾\u{200d}♀\u{fe0f}", (EmojiId(1817), EmojiId(1813))),
+        ("🎨", (EmojiId(3018), EmojiId(3018))),
+        ("👩🏾\u{200d}🦯\u{200d}➡", (EmojiId(1652), EmojiId(1648))),
+        ("🧑🏿", (EmojiId(524), EmojiId(519))),
+        ("👨🏽\u{200d}🦳", (EmojiId(570), EmojiId(567))),
+        ("🧗\u{200d}♀\u{fe0f}", (EmojiId(1813), EmojiId(1813))),
+        ("🇲🇷", (EmojiId(3676), EmojiId(3676))),
+        ("👩🏾\u{200d}🦼", (EmojiId(1682), EmojiId(1678))),
+        ("🤷🏻\u{200d}♂", (EmojiId(832), EmojiId(831))),
+        ("🏋🏼", (EmojiId(1925), EmojiId(1923))),
+        ("🎃", (EmojiId(2938), EmojiId(2938))),
+        ("🫄🏼", (EmojiId(1307), EmojiId(1305))),
+        ("🚈", (EmojiId(2792), EmojiId(2792))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", (EmojiId(2367), EmojiId(2342))),
+        ("🧙🏿", (EmojiId(1400), EmojiId(1395))),
+        ("🧑\u{200d}🎄", (EmojiId(1353), EmojiId(1353))),
+        ("👨🏼\u{200d}🦼\u{200d}➡", (EmojiId(1674), EmojiId(1672))),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2349), EmojiId(2342))),
+        ("💉", (EmojiId(3246), EmojiId(3246))),
+        ("🇫🇰", (EmojiId(3596), EmojiId(3596))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", (EmojiId(2250), EmojiId(2238))),
+        ("🤟", (EmojiId(271), EmojiId(271))),
+        ("💇🏾\u{200d}♀", (EmojiId(1526), EmojiId(1522))),
+        ("🧎🏾\u{200d}♀", (EmojiId(1598), EmojiId(1594))),
+        ("🧎🏾\u{200d}➡\u{fe0f}", (EmojiId(1604), EmojiId(1600))),
+        ("👰🏻\u{200d}♂", (EmojiId(1282), EmojiId(1281))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2233), EmojiId(2212))),
+        ("👨🏾\u{200d}🔧", (EmojiId(961), EmojiId(957))),
+        ("🫴🏼", (EmojiId(219), EmojiId(217))),
+        ("🧍\u{200d}♂", (EmojiId(1570), EmojiId(1570))),
+        ("⛹\u{fe0f}\u{200d}♀\u{fe0f}", (EmojiId(1917), EmojiId(1917))),
+        ("🇻🇬", (EmojiId(3766), EmojiId(3766))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", (EmojiId(2282), EmojiId(2264))),
+        ("🧝🏿\u{200d}♀\u{fe0f}", (EmojiId(1484), EmojiId(1479))),
+        ("🚨", (EmojiId(2831), EmojiId(2831))),
+        ("🏃🏻\u{200d}♀\u{200d}➡", (EmojiId(1751), EmojiId(1750))),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", (EmojiId(2253), EmojiId(2238))),
+        ("🧎🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1613), EmojiId(1612))),
+        ("🏔\u{fe0f}", (EmojiId(2727), EmojiId(2727))),
+        ("🛎\u{fe0f}", (EmojiId(2858), EmojiId(2858))),
+        ("🧎🏼\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1608), EmojiId(1606))),
+        ("🏃🏽\u{200d}♀\u{fe0f}", (EmojiId(1741), EmojiId(1738))),
+        ("🧑🏿\u{200d}🌾", (EmojiId(920), EmojiId(915))),
+        ("🧔🏽\u{200d}♂\u{fe0f}", (EmojiId(546), EmojiId(543))),
+        ("👁\u{200d}🗨", (EmojiId(164), EmojiId(164))),
+        ("👩🏿\u{200d}💻", (EmojiId(1040), EmojiId(1035))),
+        ("👇🏿", (EmojiId(318), EmojiId(313))),
+        ("♦", (EmojiId(3010), EmojiId(3010))),
+        ("💂🏿", (EmojiId(1172), EmojiId(1167))),
+        ("🧎🏿\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1617), EmojiId(1612))),
+        ("🌪\u{fe0f}", (EmojiId(2921), EmojiId(2921))),
+        ("🧉", (EmojiId(2711), EmojiId(2711))),
+        ("👩🏾\u{200d}✈\u{fe0f}", (EmojiId(1093), EmojiId(1089))),
+        ("🍕", (EmojiId(2643), EmojiId(2643))),
+        ("🤾🏻\u{200d}♂", (EmojiId(2023), EmojiId(2022))),
+        ("🪴", (EmojiId(2573), EmojiId(2573))),
+        ("🧔🏽\u{200d}♀\u{fe0f}", (EmojiId(552), EmojiId(549))),
+        ("🎀", (EmojiId(2954), EmojiId(2954))),
+        ("👩🏿\u{200d}🔬", (EmojiId(1022), EmojiId(1017))),
+        ("💑🏾", (EmojiId(2294), EmojiId(2290))),
+        ("🧙🏾\u{200d}♂\u{fe0f}", (EmojiId(1405), EmojiId(1401))),
+        ("🏃\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1756), EmojiId(1756))),
+        ("🍠", (EmojiId(2669), EmojiId(2669))),
+        ("⛹🏽\u{200d}♀", (EmojiId(1920), EmojiId(1917))),
+        ("🥢", (EmojiId(2713), EmojiId(2713))),
+        ("🦹🏽\u{200d}♀", (EmojiId(1392), EmojiId(1389))),
+        ("🚧", (EmojiId(2835), EmojiId(2835))),
+        ("🦹🏽\u{200d}♂", (EmojiId(1386), EmojiId(1383))),
+        ("🤖", (EmojiId(117), EmojiId(117))),
+        ("👩🏾\u{200d}🦰", (EmojiId(589), EmojiId(585))),
+        ("💁🏿\u{200d}♀", (EmojiId(752), EmojiId(747))),
+        ("🌵", (EmojiId(2577), EmojiId(2577))),
+        ("⚖", (EmojiId(3229), EmojiId(3229))),
+        ("👼🏻", (EmojiId(1336), EmojiId(1335))),
+        ("⏰", (EmojiId(2863), EmojiId(2863))),
+        ("👆🏼", (EmojiId(303), EmojiId(301))),
+        ("🧜🏿", (EmojiId(1454), EmojiId(1449))),
+        ("🆎", (EmojiId(3444), EmojiId(3444))),
+        ("💁🏿\u{200d}♀\u{fe0f}", (EmojiId(752), EmojiId(747))),
+        ("👩🏻\u{200d}✈\u{fe0f}", (EmojiId(1090), EmojiId(1089))),
+        ("🧽", (EmojiId(3275), EmojiId(3275))),
+        ("❄\u{fe0f}", (EmojiId(2931), EmojiId(2931))),
+        ("🧎🏻\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1613), EmojiId(1612))),
+        ("👷🏼\u{200d}♀", (EmojiId(1205), EmojiId(1203))),
+        ("👷🏾\u{200d}♀", (EmojiId(1207), EmojiId(1203))),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2093), EmojiId(2082))),
+        ("♈", (EmojiId(3347), EmojiId(3347))),
+        ("👮", (EmojiId(1131), EmojiId(1131))),
+        ("👩🏼\u{200d}🦳", (EmojiId(611), EmojiId(609))),
+        ("👷", (EmojiId(1191), EmojiId(1191))),
+        ("💆🏼\u{200d}♂\u{fe0f}", (EmojiId(1500), EmojiId(1498))),
+        ("🤷🏼\u{200d}♂\u{fe0f}", (EmojiId(833), EmojiId(831))),
+        ("👹", (EmojiId(112), EmojiId(112))),
+        ("👨\u{200d}👦\u{200d}👦", (EmojiId(2410), EmojiId(2410))),
+        ("💲", (EmojiId(3402), EmojiId(3402))),
+        ("🤦🏻\u{200d}♂", (EmojiId(814), EmojiId(813))),
+        ("👫🏾", (EmojiId(2138), EmojiId(2134))),
+        ("🫱🏾\u{200d}🫲🏻", (EmojiId(415), EmojiId(397))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2337), EmojiId(2316))),
+        ("🚴🏻\u{200d}♀", (EmojiId(1954), EmojiId(1953))),
+        ("👱🏽", (EmojiId(528), EmojiId(525))),
+        ("🚶🏾\u{200d}♂\u{200d}➡\u{fe0f}", (EmojiId(1562), EmojiId(1558))),
+        ("👃🏿", (EmojiId(484), EmojiId(479))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2099), EmojiId(2082))),
+        ("🅱\u{fe0f}", (EmojiId(3445), EmojiId(3445))),
+        ("🏊\u{200d}♀\u{fe0f}", (EmojiId(1899), EmojiId(1899))),
+        ("🏃🏿", (EmojiId(1731), EmojiId(1726))),
+        ("🥷🏼", (EmojiId(1187), EmojiId(1185))),
+        ("🧎🏿\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1611), EmojiId(1606))),
+        ("🚶🏿", (EmojiId(1533), EmojiId(1528))),
+        ("👮🏼\u{200d}♂", (EmojiId(1139), EmojiId(1137))),
+        ("🏃\u{200d}♂\u{fe0f}", (EmojiId(1732), EmojiId(1732))),
+        ("👨🏼\u{200d}🚒", (EmojiId(1121), EmojiId(1119))),
+        ("💅🏽", (EmojiId(438), EmojiId(435))),
+        ("👨\u{200d}👩\u{200d}👦\u{200d}👦", (EmojiId(2397), EmojiId(2397))),
+        ("🍹", (EmojiId(2702), EmojiId(2702))),
+        ("🧑\u{200d}🧑\u{200d}🧒", (EmojiId(2424), EmojiId(2424))),
+        ("🧑🏾\u{200d}⚖\u{fe0f}", (EmojiId(901), EmojiId(897))),
+        ("🤹🏻\u{200d}♀\u{fe0f}", (EmojiId(2047), EmojiId(2046))),
+        ("🕣", (EmojiId(2884), EmojiId(2884))),
+        ("🤸\u{200d}♀", (EmojiId(1989), EmojiId(1989))),
+        ("🏹", (EmojiId(3221), EmojiId(3221))),
+        ("🎷", (EmojiId(3088), EmojiId(3088))),
+        ("🧑🏾\u{200d}💻", (EmojiId(1027), EmojiId(1023))),
+        ("🧑\u{200d}🦼", (EmojiId(1654), EmojiId(1654))),
+        ("📼", (EmojiId(3128), EmojiId(3128))),
+        ("👩🏿\u{200d}🏫", (EmojiId(896), EmojiId(891))),
+        ("🧙🏼\u{200d}♀\u{fe0f}", (EmojiId(1409), EmojiId(1407))),
+        ("👩🏽\u{200d}⚖", (EmojiId(912), EmojiId(909))),
+        ("🏃🏼\u{200d}➡", (EmojiId(1746), EmojiId(1744))),
+        ("🇰🇲", (EmojiId(3644), EmojiId(3644))),
+        ("🛡", (EmojiId(3222), EmojiId(3222))),
+        ("💂🏻\u{200d}♂", (EmojiId(1174), EmojiId(1173))),
+        ("🪸", (EmojiId(2537), EmojiId(2537))),
+        ("🧖🏿", (EmojiId(1788), EmojiId(1783))),
+        ("🤗", (EmojiId(29), EmojiId(29))),
+        ("🌮", (EmojiId(2646), EmojiId(2646))),
+        ("👱🏾\u{200d}♂", (EmojiId(643), EmojiId(639))),
+        ("🫱🏾\u{200d}🫲🏽", (EmojiId(417), EmojiId(397))),
+        ("🧎\u{200d}♀", (EmojiId(1594), EmojiId(1594))),
+        ("📺", (EmojiId(3124), EmojiId(3124))),
+        ("👩🏽\u{200d}🏫", (EmojiId(894), EmojiId(891))),
+        ("🛋", (EmojiId(3258), EmojiId(3258))),
+        ("🤾🏻\u{200d}♂\u{fe0f}", (EmojiId(2023), EmojiId(2022))),
+        ("🤾🏾", (EmojiId(2020), EmojiId(2016))),
+        ("⛹🏾\u{200d}♂\u{fe0f}", (EmojiId(1915), EmojiId(1911))),
+        ("👮🏽\u{200d}♂\u{fe0f}", (EmojiId(1140), EmojiId(1137))),
+        ("🐳", (EmojiId(2527), EmojiId(2527))),
+        ("👍🏻", (EmojiId(332), EmojiId(331))),
+        ("🔍", (EmojiId(3129), EmojiId(3129))),
+        ("👩🏾\u{200d}❤\u{200d}👩🏿", (EmojiId(2389), EmojiId(2368))),
+        ("🏇", (EmojiId(1820), EmojiId(1820))),
+        ("🤦🏼\u{200d}♂", (EmojiId(815), EmojiId(813))),
+        ("🧯", (EmojiId(3276), EmojiId(3276))),
+        ("🤘🏿", (EmojiId(282), EmojiId(277))),
+        ("🌝", (EmojiId(2905), EmojiId(2905))),
+        ("✂", (EmojiId(3202), EmojiId(3202))),
+        ("💆🏾\u{200d}♀\u{fe0f}", (EmojiId(1508), EmojiId(1504))),
+        ("🗯", (EmojiId(166), EmojiId(166))),
+        ("😂", (EmojiId(7), EmojiId(7))),
+        ("🚶🏻\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1553), EmojiId(1552))),
+        ("🧟\u{200d}♂\u{fe0f}", (EmojiId(1489), EmojiId(1489))),
+        ("💆🏾\u{200d}♀", (EmojiId(1508), EmojiId(1504))),
+        ("🇰🇷", (EmojiId(3647), EmojiId(3647))),
+        ("💫", (EmojiId(159), EmojiId(159))),
+        ("🙆🏻\u{200d}♂\u{fe0f}", (EmojiId(724), EmojiId(723))),
+        ("👃🏾", (EmojiId(483), EmojiId(479))),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏾", (EmojiId(2172), EmojiId(2160))),
+        ("⏯\u{fe0f}", (EmojiId(3366), EmojiId(3366))),
+        ("👩🏽\u{200d}🔬", (EmojiId(1020), EmojiId(1017))),
+        ("👨🏽\u{200d}❤\u{200d}👨🏿", (EmojiId(2359), EmojiId(2342))),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", (EmojiId(2334), EmojiId(2316))),
+        ("💝", (EmojiId(132), EmojiId(132))),
+        ("💅🏻", (EmojiId(436), EmojiId(435))),
+        ("🙂\u{200d}↕\u{fe0f}", (EmojiId(51), EmojiId(51))),
+        ("🇩🇬", (EmojiId(3579), EmojiId(3579))),
+        ("🧑🏼\u{200d}🎨", (EmojiId(1061), EmojiId(1059))),
+        ("🧏🏻\u{200d}♀\u{fe0f}", (EmojiId(784), EmojiId(783))),
+        ("🇵🇪", (EmojiId(3699), EmojiId(3699))),
+        ("🕰", (EmojiId(2866), EmojiId(2866))),
+        ("👨🏼\u{200d}🦽\u{200d}➡", (EmojiId(1710), EmojiId(1708))),
+        ("🧗🏽\u{200d}♀", (EmojiId(1816), EmojiId(1813))),
+        ("🐙", (EmojiId(2535), EmojiId(2535))),
+        ("🧢", (EmojiId(3063), EmojiId(3063))),
+        ("↙", (EmojiId(3318), EmojiId(3318))),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏿", (EmojiId(2103), EmojiId(2082))),
+        ("🥵", (EmojiId(64), EmojiId(64))),
+        ("🧜🏽\u{200d}♀\u{fe0f}", (EmojiId(1464), EmojiId(1461))),
+        ("🏋🏿\u{200d}♂\u{fe0f}", (EmojiId(1934), EmojiId(1929))),
+        ("🦶", (EmojiId(461), EmojiId(461))),
+        ("👩\u{200d}⚕", (EmojiId(855), EmojiId(855))),
+        ("☠", (EmojiId(109), EmojiId(109))),
+        ("🙍\u{200d}♀\u{fe0f}", (EmojiId(675), EmojiId(675))),
+        ("❣", (EmojiId(139), EmojiId(139))),
+        ("🏃🏻\u{200d}♀\u{200d}➡\u{fe0f}", (EmojiId(1751), EmojiId(1750))),
+        ("🚣\u{200d}♀", (EmojiId(1881), EmojiId(1881))),
+        ("♟", (EmojiId(3012), EmojiId(3012))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏽", (EmojiId(2371), EmojiId(2368))),
+        ("🦛", (EmojiId(2475), EmojiId(2475))),
+        ("👩🏻\u{200d}🍳", (EmojiId(946), EmojiId(945))),
+        ("👨🏻\u{200d}🍳", (EmojiId(940), EmojiId(939))),
+        ("🤮", (EmojiId(62), EmojiId(62))),
+        ("🇹🇿", (EmojiId(3755), EmojiId(3755))),
+        ("🧎🏼\u{200d}♀\u{200d}➡", (EmojiId(1608), EmojiId(1606))),
+        ("🟰", (EmojiId(3392), EmojiId(3392))),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏽", (EmojiId(2085), EmojiId(2082))),
+        ("👨🏿\u{200d}❤\u{200d}👨🏻", (EmojiId(2364), EmojiId(2342))),
+        ("🏃🏿\u{200d}➡\u{fe0f}", (EmojiId(1749), EmojiId(1744))),
+        ("🏋🏿", (EmojiId(1928), EmojiId(1923))),
+        ("🧜\u{200d}♀\u{fe0f}", (EmojiId(1461), EmojiId(1461))),
+        ("🤵🏽\u{200d}♀", (EmojiId(1272), EmojiId(1269))),
+        ("🧑🏿\u{200d}🔬", (EmojiId(1010), EmojiId(1005))),
+        ("☦\u{fe0f}", (EmojiId(3341), EmojiId(3341))),
+        ("👩\u{200d}🦳", (EmojiId(609), EmojiId(609))),
+        ("🤼\u{200d}♀\u{fe0f}", (EmojiId(1997), EmojiId(1997))),
+        ("⛹", (EmojiId(1905), EmojiId(1905))),
+        ("🤌🏾", (EmojiId(245), EmojiId(241))),
+        ("💅🏾", (EmojiId(439), EmojiId(435))),
+        ("👨🏾\u{200d}🦲", (EmojiId(577), EmojiId(573))),
+        ("👩\u{200d}🦰", (EmojiId(585), EmojiId(585))),
+        ("🙏🏼", (EmojiId(425), EmojiId(423))),
+        ("⛹🏼\u{200d}♂", (EmojiId(1913), EmojiId(1911))),
+        ("🧙🏽\u{200d}♂", (EmojiId(1404), EmojiId(1401))),
+        ("🧑🏽\u{200d}🦯\u{200d}➡", (EmojiId(1627), EmojiId(1624))),
+        ("🥚", (EmojiId(2651), EmojiId(2651))),
+        ("🤲🏽", (EmojiId(394), EmojiId(391))),
+        ("🏥", (EmojiId(2750), EmojiId(2750))),
+        ("🧛🏿\u{200d}♂", (EmojiId(1442), EmojiId(1437))),
+        ("🇳🇱", (EmojiId(3691), EmojiId(3691))),
+        ("👯\u{200d}♀", (EmojiId(1782), EmojiId(1782))),
+        ("🦦", (EmojiId(2491), EmojiId(2491))),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿", (EmojiId(2225), EmojiId(2212))),
+        ("🤷🏾\u{200d}♂\u{fe0f}", (EmojiId(835), EmojiId(831))),
+        ("🕵🏾\u{200d}♂\u{fe0f}", (EmojiId(1159), EmojiId(1155))),
+        ("👨🏻\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1709), EmojiId(1708))),
+        ("🤸\u{200d}♂", (EmojiId(1983), EmojiId(1983))),
+        ("🪶", (EmojiId(2510), EmojiId(2510))),
+        ("🚶🏿\u{200d}♀", (EmojiId(1545), EmojiId(1540))),
+        ("👁\u{fe0f}\u{200d}🗨\u{fe0f}", (EmojiId(164), EmojiId(164))),
+        ("🏝\u{fe0f}", (EmojiId(2734), EmojiId(2734))),
+        ("🧙", (EmojiId(1395), EmojiId(1395))),
+        ("🍔", (EmojiId(2641), EmojiId(2641))),
+        ("🏃🏽\u{200d}♀\u{fe0f}\u{200d}➡", (EmojiId(1753), EmojiId(1750))),
+        ("👨🏽\u{200d}🚀", (EmojiId(1104), EmojiId(1101))),
+        ("👨🏼\u{200d}🏭", (EmojiId(977), EmojiId(975))),
+        ("🦸🏿", (EmojiId(1364), EmojiId(1359))),
+        ("🧔🏼\u{200d}♀\u{fe0f}", (EmojiId(551), EmojiId(549))),
+        ("🧑🏼\u{200d}🦼", (EmojiId(1656), EmojiId(1654))),
+        ("🕺🏿", (EmojiId(1773), EmojiId(1768))),
+        ("😗", (EmojiId(18), EmojiId(18))),
+        ("🇨🇿", (EmojiId(3577), EmojiId(3577))),
+        ("🤾🏻\u{200d}♀", (EmojiId(2029), EmojiId(2028))),
+        ("👷🏼\u{200d}♀\u{fe0f}", (EmojiId(1205), EmojiId(1203))),
+        ("🚶🏾\u{200d}♀\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1556), EmojiId(1552))),
+        ("👩🏻\u{200d}🎨", (EmojiId(1072), EmojiId(1071))),
+        ("🧏🏿\u{200d}♀\u{fe0f}", (EmojiId(788), EmojiId(783))),
+        ("🫶", (EmojiId(379), EmojiId(379))),
+        ("👨🏽\u{200d}⚕", (EmojiId(852), EmojiId(849))),
+        ("🕵", (EmojiId(1149), EmojiId(1149))),
+        ("🧑\u{200d}🦯\u{200d}➡", (EmojiId(1624), EmojiId(1624))),
+        ("🧑🏿\u{200d}🦽\u{200d}➡\u{fe0f}", (EmojiId(1701), EmojiId(1696))),
+        ("🇨🇰", (EmojiId(3564), EmojiId(3564))),
+        ("🌧", (EmojiId(2918), EmojiId(2918))),
+        ("🌒", (EmojiId(2892), EmojiId(2892))),
+        ("🇭🇰", (EmojiId(3619), EmojiId(3619))),
+        ("👨\u{200d}💻", (EmojiId(1029), EmojiId(1029))),
+        ("🇹🇱", (EmojiId(3747), EmojiId(3747))),
+        ("👩\u{200d}🍳", (EmojiId(945), EmojiId(945))),
+        ("😞", (EmojiId(97), EmojiId(97))),
+        ("🏌🏽\u{200d}♀", (EmojiId(1848), EmojiId(1845))),
+        ("🌞", (EmojiId(2906), EmojiId(2906))),
+        ("🏙", (EmojiId(2773), EmojiId(2773))),
+        ("🧚\u{200d}♀", (EmojiId(1425), EmojiId(1425))),
+        ("🇿🇲", (EmojiId(3776), EmojiId(3776))),
+        ("🛩", (EmojiId(2846), EmojiId(2846))),
+        ("⚖\u{fe0f}", (EmojiId(3229), EmojiId(3229))),
+        ("🏊🏻\u{200d}♀", (EmojiId(1900), EmojiId(1899))),
+        ("🧘🏿\u{200d}♂\u{fe0f}", (EmojiId(2063), EmojiId(2058))),
+        ("🙋🏿\u{200d}♂\u{fe0f}", (EmojiId(764), EmojiId(759))),
+        ("♠", (EmojiId(3008), EmojiId(3008))),
+        ("👱🏾\u{200d}♀\u{fe0f}", (EmojiId(637), EmojiId(633))),
+        ("👱🏼\u{200d}♂", (EmojiId(641), EmojiId(639))),
+        ("🧑🏼\u{200d}🎄", (EmojiId(1355), EmojiId(1353))),
+        ("🇨🇲", (EmojiId(3566), EmojiId(3566))),
+        ("🦹🏻\u{200d}♂\u{fe0f}", (EmojiId(1384), EmojiId(1383))),
+        ("👱🏻\u{200d}♂", (EmojiId(640), EmojiId(639))),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", (EmojiId(2206), EmojiId(2186))),
+        ("🗃", (EmojiId(3203), EmojiId(3203))),
+        ("🧔🏻\u{200d}♀\u{fe0f}", (EmojiId(550), EmojiId(549))),
+        ("🤳🏻", (EmojiId(442), EmojiId(441))),
+        ("✋🏿", (EmojiId(192), EmojiId(187))),
+        ("🦻🏻", (EmojiId(474), EmojiId(473))),
+        ("🎳", (EmojiId(2974), EmojiId(2974))),
+        ("👩🏻\u{200d}❤\u{200d}👩🏾", (EmojiId(2376), EmojiId(2368))),
+        ("🕵\u{fe0f}\u{200d}♀\u{fe0f}", (EmojiId(1161), EmojiId(1161))),
+        ("🧼", (EmojiId(3272), EmojiId(3272))),
+        ("👩\u{200d}🦯", (EmojiId(1642), EmojiId(1642))),
+        ("🇧🇩", (EmojiId(3538), EmojiId(3538))),
+        ("🏷\u{fe0f}", (EmojiId(3152), EmojiId(3152))),
+        ("💥", (EmojiId(158), EmojiId(158))),
+        ("🥇", (EmojiId(2962), EmojiId(2962))),
+        ("👨🏼\u{200d}🦲", (EmojiId(575), EmojiId(573))),
+        ("✈", (EmojiId(2845), EmojiId(2845))),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏻", (EmojiId(2118), EmojiId(2108))),
+        ("🧑🏻\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1625), EmojiId(1624))),
+        ("👰🏿", (EmojiId(1280), EmojiId(1275))),
+        ("🧗\u{200d}♂", (EmojiId(1807), EmojiId(1807))),
+        ("🙂", (EmojiId(8), EmojiId(8))),
+        ("9\u{fe0f}\u{20e3}", (EmojiId(3436), EmojiId(3436))),
+        ("🌬\u{fe0f}", (EmojiId(2923), EmojiId(2923))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏿", (EmojiId(2285), EmojiId(2264))),
+        ("❤\u{fe0f}", (EmojiId(143), EmojiId(143))),
+        ("🚮", (EmojiId(3288), EmojiId(3288))),
+        ("🧔🏻\u{200d}♂", (EmojiId(544), EmojiId(543))),
+        ("🪺", (EmojiId(2586), EmojiId(2586))),
+        ("👧🏿", (EmojiId(518), EmojiId(513))),
+        ("🫱", (EmojiId(199), EmojiId(199))),
+        ("👱🏻\u{200d}♀", (EmojiId(634), EmojiId(633))),
+        ("🤾\u{200d}♂", (EmojiId(2022), EmojiId(2022))),
+        ("🧚🏿\u{200d}♂\u{fe0f}", (EmojiId(1424), EmojiId(1419))),
+        ("👱🏼\u{200d}♀", (EmojiId(635), EmojiId(633))),
+        ("🎒", (EmojiId(3048), EmojiId(3048))),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏻", (EmojiId(2156), EmojiId(2134))),
+        ("🔎", (EmojiId(3130), EmojiId(3130))),
+        ("👲🏽", (EmojiId(1248), EmojiId(1245))),
+        ("🤰", (EmojiId(1293), EmojiId(1293))),
+        ("🦬", (EmojiId(2456), EmojiId(2456))),
+        ("🙆\u{200d}♂", (EmojiId(723), EmojiId(723))),
+        ("🥐", (EmojiId(2629), EmojiId(2629))),
+        ("⚱\u{fe0f}", (EmojiId(3281), EmojiId(3281))),
+        ("🧑🏼\u{200d}🦽", (EmojiId(1692), EmojiId(1690))),
+        ("🥶", (EmojiId(65), EmojiId(65))),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", (EmojiId(2344), EmojiId(2342))),
+        ("👨\u{200d}✈\u{fe0f}", (EmojiId(1083), EmojiId(1083))),
+        ("👩🏽\u{200d}🦼\u{200d}➡", (EmojiId(1687), EmojiId(1684))),
+        ("🤸🏻\u{200d}♀", (EmojiId(1990), EmojiId(1989))),
+        ("🧚🏼\u{200d}♂", (EmojiId(1421), EmojiId(1419))),
+        ("😐", (EmojiId(38), EmojiId(38))),
+        ("💏🏿", (EmojiId(2191), EmojiId(2186))),
+        ("📣", (EmojiId(3075), EmojiId(3075))),
+        ("✖", (EmojiId(3388), EmojiId(3388))),
+        ("🪙", (EmojiId(3154), EmojiId(3154))),
+        ("🇹🇲", (EmojiId(3748), EmojiId(3748))),
+        ("🚶\u{200d}♂\u{fe0f}\u{200d}➡", (EmojiId(1558), EmojiId(1558))),
+        ("🇵🇲", (EmojiId(3705), EmojiId(3705))),
+        ("🦹🏼\u{200d}♂\u{fe0f}", (EmojiId(1385), EmojiId(1383))),
+        ("🏃🏼\u{200d}♀", (EmojiId(1740), EmojiId(1738))),
+        ("🏊🏾\u{200d}♀", (EmojiId(1903), EmojiId(1899))),
+        ("🧛🏽\u{200d}♀\u{fe0f}", (EmojiId(1446), EmojiId(1443))),
+        ("👷🏽\u{200d}♀\u{fe0f}", (EmojiId(1206), EmojiId(1203))),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", (EmojiId(2319), EmojiId(2316))),
+        ("🏊🏻\u{200d}♂\u{fe0f}", (EmojiId(1894), EmojiId(1893))),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏽", (EmojiId(2301), EmojiId(2290))),
+        ("👮🏿\u{200d}♀\u{fe0f}", (EmojiId(1148), EmojiId(1143))),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", (EmojiId(2347), EmojiId(2342))),
+        ("🥯", (EmojiId(2633), EmojiId(2633))),
+        ("🙂\u{200d}↔", (EmojiId(50), EmojiId(50))),
+        ("🧘🏽\u{200d}♀\u{fe0f}", (EmojiId(2067), EmojiId(2064))),
+        ("👏🏻", (EmojiId(368), EmojiId(367))),
+        ("✌🏿", (EmojiId(258), EmojiId(253))),
+        ("🫃", (EmojiId(1299), EmojiId(1299))),
+        ("👱🏾", (EmojiId(529), EmojiId(525))),
+        ("🧗🏿\u{200d}♀\u{fe0f}", (EmojiId(1818), EmojiId(1813))),
+        ("👩🏻", (EmojiId(580), EmojiId(579))),
+        ("📠", (EmojiId(3105), EmojiId(3105))),
+        ("💇🏽", (EmojiId(1513), EmojiId(1510))),
+        ("🆘", (EmojiId(3457), EmojiId(3457))),
+        ("🚂", (EmojiId(2786), EmojiId(2786))),
+        ("🏃🏻\u{200d}♂\u{fe0f}\u{200d}➡\u{fe0f}", (EmojiId(1757), EmojiId(1756))),
+        ("🧒🏼", (EmojiId(503), EmojiId(501))),
+        ("🧑🏼\u{200d}💼", (EmojiId(989), EmojiId(987))),
+        ("👨🏽\u{200d}🦼\u{200d}➡\u{fe0f}", (EmojiId(1675), EmojiId(1672))),
+        ("🧙🏻\u{200d}♂\u{fe0f}", (EmojiId(1402), EmojiId(1401))),
+        ("😋", (EmojiId(23), EmojiId(23))),
+        ("👨🏽\u{200d}🦰", (EmojiId(558), EmojiId(555))),
+        ("🚇", (EmojiId(2791), EmojiId(2791))),
+        ("🤾🏼\u{200d}♀", (EmojiId(2030), EmojiId(2028))),
+        ("👩\u{200d}👩\u{200d}👦\u{200d}👦", (EmojiId(2407), EmojiId(2407))),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽", (EmojiId(2232), EmojiId(2212))),
+        ("👨🏼\u{200d}⚕\u{fe0f}", (EmojiId(851), EmojiId(849))),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏼", (EmojiId(2287), EmojiId(2264))),
+        ("🧑🏼\u{200d}⚕\u{fe0f}", (EmojiId(845), EmojiId(843))),
+        ("🛀🏽", (EmojiId(2073), EmojiId(2070))),
+        ("☠\u{fe0f}", (EmojiId(109), EmojiId(109))),
+        ("👩🏿\u{200d}🦯\u{200d}➡\u{fe0f}", (EmojiId(1653), EmojiId(1648))),
+        ("😑", (EmojiId(39), EmojiId(39))),
+        ("💪🏾", (EmojiId(451), EmojiId(447))),
+    ],
+};
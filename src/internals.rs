@@ -0,0 +1,53 @@
+//! Raw access to the generated emoji tables.
+//!
+//! **This module is not covered by semver.** The layout of these tables may
+//! change in any release, including patch releases. It exists so that code
+//! generators and FFI layers can build their own structures from the same
+//! data as this crate without re-parsing Unicode data.
+//!
+//! Emojis are identified by their index into [`EMOJIS`], all other tables
+//! refer to emojis using these indices.
+
+/// Every emoji including all skin tones, ordered by Unicode CLDR data.
+pub use crate::gen::EMOJIS;
+
+/// The indices into [`EMOJIS`] of the default skin tone emojis ordered by
+/// name.
+pub use crate::gen::BY_NAME;
+
+/// The indices into [`EMOJIS`] of the default skin tone emojis ordered by
+/// Unicode version.
+pub use crate::gen::BY_VERSION;
+
+/// The maximum length in bytes of any emoji, including variations.
+pub use crate::gen::MAX_LEN;
+
+/// A map of every emoji, including variations, to its id and the id of its
+/// default skin tone emoji.
+///
+/// The [`EmojiId`][crate::EmojiId] of an emoji is its index into [`EMOJIS`].
+pub use crate::gen::unicode::MAP as UNICODE_MAP;
+
+/// A map of every GitHub shortcode to its index into [`EMOJIS`].
+pub use crate::gen::shortcode::MAP as SHORTCODE_MAP;
+
+/// Every GitHub shortcode and its index into [`EMOJIS`] ordered by shortcode.
+pub use crate::gen::shortcode::SORTED as SORTED_SHORTCODES;
+
+use crate::Emoji;
+
+/// Returns the index of the emoji into [`EMOJIS`].
+///
+/// This is the same as [`Emoji::cldr_order()`].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::internals::{index_of, EMOJIS};
+///
+/// let rocket = emojis::get("🚀").unwrap();
+/// assert_eq!(&EMOJIS[index_of(rocket)], rocket);
+/// ```
+pub fn index_of(emoji: &Emoji) -> usize {
+    emoji.cldr_order()
+}
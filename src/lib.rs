@@ -10,7 +10,7 @@
 //! - Iterate over emojis in an emoji group, e.g. "Smileys & Emotion" or "Flags"
 //! - Iterate over the skin tones for an emoji
 //! - Select a specific skin tone for an emoji
-//! - Uses [Unicode v15.1](https://unicode.org/emoji/charts-15.1/emoji-released.html) emoji specification
+//! - Uses [Unicode v16.0](https://unicode.org/emoji/charts-16.0/emoji-released.html) emoji specification
 //!
 //! [gemoji]: https://github.com/github/gemoji
 //!
@@ -41,12 +41,40 @@
 //! on [`phf`]. The policy of this crate is to only increase the MSRV in a
 //! breaking release.
 //!
+//! # `no_std` support
+//!
+//! This crate is `no_std` and the features are tiered by what they require
+//! from the platform, so it is clear which capabilities are available.
+//!
+//! - No features (`core`): lookup, iteration, metadata, and the non-allocating
+//!   text APIs such as `tokenize()` and `expand_shortcodes()`.
+//! - `alloc`: text APIs that return owned or `Cow` values such as `describe()`,
+//!   `escape_for_logs()`, `tally()`, and `truncate_with_ellipsis()`.
+//! - `std`: implies `alloc`, adds I/O streaming with `replace_stream()` and
+//!   implements `std::error::Error` for `Error`.
+//!
+//! The `pulldown-cmark`, `schemars`, and `wasm` features imply `alloc`, and the
+//! `rayon` feature implies `std`.
+//!
 //! # Breaking changes
 //!
 //! When [gemoji] or the Unicode version is upgraded this is not considered a
 //! breaking change, instead you should make sure to use
+//! [`iter_up_to()`][iter_up_to] or
 //! [`unicode_version()`][Emoji::unicode_version] to filter out newer versions.
 //!
+//! Emojis renamed or re-encoded since the previous release can be resolved
+//! using the [`migrate`] module.
+//!
+//! # Unicode versions
+//!
+//! By default the latest supported Unicode emoji version is used. To match the
+//! emoji set of an older platform exactly, select a specific dataset with one of
+//! the following features. If more than one is enabled the newest wins.
+//!
+//! - `unicode-15-1`: [Unicode v15.1](https://unicode.org/emoji/charts-15.1/emoji-released.html)
+//! - `unicode-16-0`: [Unicode v16.0](https://unicode.org/emoji/charts-16.0/emoji-released.html) (default)
+//!
 //! # Examples
 //!
 //! See [examples/replace.rs] for an example that replaces `:gemoji:` names with
@@ -85,12 +113,10 @@
 //! assert_eq!(faces, ["😀", "😃", "😄", "😁", "😆"]);
 //! ```
 //!
-//! It is recommended to filter the list by the maximum Unicode version that you
-//! wish to support.
+//! It is recommended to limit the list to the maximum Unicode version that you
+//! wish to support using the [`iter_up_to()`][iter_up_to] function.
 //! ```
-//! let iter = emojis::iter().filter(|e| {
-//!     e.unicode_version() < emojis::UnicodeVersion::new(13, 0)
-//! });
+//! let iter = emojis::iter_up_to(emojis::UnicodeVersion::new(13, 0));
 //! ```
 //!
 //! Using the [`Group`] enum you can iterate over all emojis in a group.
@@ -104,17 +130,137 @@
 
 #![no_std]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod base;
+mod block;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod category;
+pub mod components;
+mod error;
+mod family;
+#[cfg(all(feature = "unicode-15-1", not(feature = "unicode-16-0")))]
+#[path = "gen/v15_1/mod.rs"]
 mod gen;
+#[cfg(any(not(feature = "unicode-15-1"), feature = "unicode-16-0"))]
+#[path = "gen/v16_0/mod.rs"]
+mod gen;
+#[cfg(feature = "internals")]
+pub mod internals;
+#[cfg(feature = "pulldown-cmark")]
+pub mod markdown;
+pub mod migrate;
+pub mod parse;
+mod person;
+pub mod picker;
+pub mod presets;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+#[cfg(feature = "unicode-segmentation")]
+pub mod segmentation;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod shortcodes;
+#[cfg(feature = "soa")]
+pub mod soa;
+#[cfg(feature = "std")]
+mod stream;
+mod subgroup;
+mod suggest;
+mod text;
+#[cfg(feature = "wasm")]
+mod wasm;
 
+use core::borrow;
 use core::cmp;
 use core::convert;
 use core::fmt;
 use core::hash;
+use core::iter;
+
+pub use crate::base::{get_base, get_closest, Closest, Components};
+pub use crate::block::Block;
+pub use crate::category::Category;
+pub use crate::error::Error;
+pub use crate::family::Family;
+pub use crate::gen::{Group, COUNT, COUNT_WITH_SKIN_TONES};
+pub use crate::person::{Gender, Person, Role};
+#[cfg(feature = "std")]
+pub use crate::stream::{replace_stream, ReplaceMode};
+pub use crate::subgroup::Subgroup;
+#[cfg(feature = "alloc")]
+pub use crate::suggest::Searcher;
+pub use crate::suggest::{names_with_prefix, suggest, suggest_with, SearchOptions};
+#[cfg(feature = "alloc")]
+pub use crate::text::{describe, escape_for_logs, tally, truncate_with_ellipsis};
+pub use crate::text::{
+    display_list, expand_shortcodes, expand_shortcodes_with, line_breaks, tokenize, tokenize_with,
+    truncate, truncate_chars, weighted_len, DisplayList, EmojiStrExt, Emojis, Token, Tokens,
+};
+
+/// The maximum length in bytes of any emoji, including variations.
+///
+/// Longer strings can be rejected without a lookup.
+///
+/// ```
+/// let mut buf = [0; emojis::MAX_EMOJI_LEN];
+/// for emoji in emojis::iter() {
+///     emoji.write_to(&mut buf).unwrap();
+/// }
+/// ```
+pub use crate::gen::MAX_LEN as MAX_EMOJI_LEN;
+
+/// The maximum length in bytes of any GitHub shortcode, not including the
+/// colons.
+///
+/// ```
+/// assert!(emojis::iter()
+///     .flat_map(|e| e.shortcodes())
+///     .all(|s| s.len() <= emojis::MAX_SHORTCODE_LEN));
+/// ```
+pub use crate::gen::shortcode::MAX_LEN as MAX_SHORTCODE_LEN;
+
+/// The sorted, non-overlapping, inclusive ranges of characters that any emoji,
+/// including variations, can start with.
+///
+/// Text that contains no character in these ranges contains no emoji, so
+/// external scanners and regex sets can use this to cheaply pre-filter input
+/// before calling [`get()`] or [`tokenize()`].
+///
+/// ```
+/// let could_start = |c: char| {
+///     emojis::EMOJI_START_RANGES
+///         .binary_search_by(|&(lo, hi)| {
+///             if hi < c {
+///                 core::cmp::Ordering::Less
+///             } else if lo > c {
+///                 core::cmp::Ordering::Greater
+///             } else {
+///                 core::cmp::Ordering::Equal
+///             }
+///         })
+///         .is_ok()
+/// };
+/// assert!(could_start('🚀'));
+/// assert!(could_start('1'));
+/// assert!(!could_start('a'));
+/// ```
+pub use crate::gen::START_RANGES as EMOJI_START_RANGES;
 
-pub use crate::gen::Group;
+/// Re-exports the [`EmojiStrExt`] trait.
+///
+/// ```
+/// use emojis::prelude::*;
+///
+/// assert!("launch 🚀".contains_emoji());
+/// ```
+pub mod prelude {
+    pub use crate::EmojiStrExt;
+}
 
 /// Represents an emoji.
 ///
@@ -123,7 +269,13 @@ pub use crate::gen::Group;
 #[derive(Debug)]
 pub struct Emoji {
     emoji: &'static str,
+    id: EmojiId,
     name: &'static str,
+    // The words in the name, see `Emoji::short_name_words()`.
+    name_words: &'static [&'static str],
+    // The name folded to lowercase ASCII, if it is different from the name.
+    folded_name: Option<&'static str>,
+    unicode_name: Option<&'static str>,
     unicode_version: UnicodeVersion,
     group: Group,
 
@@ -132,11 +284,148 @@ pub struct Emoji {
     //
     //     (<id>, <n>, <skin_tone>)
     //
-    skin_tone: Option<(u16, u8, SkinTone)>,
+    skin_tone: Option<(EmojiId, u8, SkinTone)>,
+
+    // The number of people depicted by the emoji.
+    person_count: u8,
+
+    // The length of the emoji in UTF-16 code units.
+    utf16_len: u8,
+
+    // The compact id of the emoji, see `Emoji::to_compact_id()`.
+    compact_id: u16,
+
+    // The color of the emoji and the index of its family in `COLOR_FAMILIES`.
+    color: Option<(Color, u8)>,
 
     aliases: Option<&'static [&'static str]>,
 }
 
+/// Identifies an emoji, including skin tone variants, by its position in the
+/// Unicode CLDR ordering.
+///
+/// Ids are ordered the same as [`Emoji::cldr_order()`] and the emoji tables
+/// guarantee the following for every release:
+///
+/// - The skin tone variants of an emoji immediately follow the emoji with the
+///   default skin tone, in the order returned by [`Emoji::skin_tones()`].
+/// - The emojis in each [`Group`] and each [`Subgroup`] have consecutive ids.
+///
+/// Unlike [compact ids][Emoji::to_compact_id] these are not stable across
+/// releases.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::EmojiId;
+///
+/// let wave = emojis::get("👋").unwrap();
+/// let id = wave.id();
+/// assert_eq!(EmojiId::new(id.index() + 3).unwrap().emoji(), "👋🏽");
+/// assert!(EmojiId::new(emojis::COUNT_WITH_SKIN_TONES).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EmojiId(u16);
+
+impl EmojiId {
+    /// Returns the id of the emoji at the given position in the Unicode CLDR
+    /// ordering, or `None` if it is out of range.
+    #[inline]
+    pub fn new(index: usize) -> Option<Self> {
+        if index < crate::gen::EMOJIS.len() {
+            Some(Self(index as u16))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position of the emoji in the Unicode CLDR ordering.
+    #[inline]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Returns the emoji with this id.
+    #[inline]
+    pub fn emoji(self) -> &'static Emoji {
+        &crate::gen::EMOJIS[self.index()]
+    }
+
+    /// Returns the `len` consecutive emojis starting with this one.
+    pub(crate) fn slice(self, len: usize) -> &'static [Emoji] {
+        &crate::gen::EMOJIS[self.index()..][..len]
+    }
+}
+
+/// How a character is displayed when it is not followed by a variation
+/// selector.
+///
+/// See [`default_presentation()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Presentation {
+    /// Displayed as a colorful emoji, e.g. 😀.
+    Emoji,
+    /// Displayed as text, e.g. ☺. Append [`VS16`][components::VS16] to display
+    /// it as an emoji.
+    Text,
+}
+
+/// Which emoji presentation selectors an emoji is written with.
+///
+/// See [`Emoji::qualified()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Qualification {
+    /// Every emoji presentation selector is included, this is the form
+    /// returned by [`Emoji::as_str()`].
+    Fully,
+    /// Only the emoji presentation selector following the first character is
+    /// included.
+    Minimal,
+    /// No emoji presentation selectors are included. Some legacy systems
+    /// reject emojis with selectors.
+    Unqualified,
+}
+
+impl Default for Qualification {
+    #[inline]
+    fn default() -> Self {
+        Self::Fully
+    }
+}
+
+/// An emoji written with a specific [`Qualification`].
+///
+/// This struct is created by [`Emoji::qualified()`].
+#[derive(Debug, Clone, Copy)]
+pub struct Qualified<'a> {
+    emoji: &'a str,
+    qualification: Qualification,
+}
+
+impl fmt::Display for Qualified<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        let mut chars = self.emoji.chars();
+        if let Some(c) = chars.next() {
+            f.write_char(c)?;
+        }
+        let mut first = true;
+        for c in chars {
+            let keep = match self.qualification {
+                Qualification::Fully => true,
+                Qualification::Minimal => first,
+                Qualification::Unqualified => false,
+            };
+            if c != components::VS16 || keep {
+                f.write_char(c)?;
+            }
+            first = false;
+        }
+        Ok(())
+    }
+}
+
 /// A Unicode version.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct UnicodeVersion {
@@ -144,36 +433,139 @@ pub struct UnicodeVersion {
     minor: u32,
 }
 
+/// The color of an emoji.
+///
+/// Only emojis that come in more than one color have a color, see
+/// [`Emoji::color()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Color {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    LightBlue,
+    Purple,
+    Brown,
+    Black,
+    White,
+    Grey,
+    Pink,
+}
+
 /// The skin tone of an emoji.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
+#[repr(u8)]
 pub enum SkinTone {
-    Default,
-    Light,
-    MediumLight,
-    Medium,
-    MediumDark,
-    Dark,
-    LightAndMediumLight,
-    LightAndMedium,
-    LightAndMediumDark,
-    LightAndDark,
-    MediumLightAndLight,
-    MediumLightAndMedium,
-    MediumLightAndMediumDark,
-    MediumLightAndDark,
-    MediumAndLight,
-    MediumAndMediumLight,
-    MediumAndMediumDark,
-    MediumAndDark,
-    MediumDarkAndLight,
-    MediumDarkAndMediumLight,
-    MediumDarkAndMedium,
-    MediumDarkAndDark,
-    DarkAndLight,
-    DarkAndMediumLight,
-    DarkAndMedium,
-    DarkAndMediumDark,
+    Default = 0,
+    Light = 1,
+    MediumLight = 2,
+    Medium = 3,
+    MediumDark = 4,
+    Dark = 5,
+    LightAndMediumLight = 6,
+    LightAndMedium = 7,
+    LightAndMediumDark = 8,
+    LightAndDark = 9,
+    MediumLightAndLight = 10,
+    MediumLightAndMedium = 11,
+    MediumLightAndMediumDark = 12,
+    MediumLightAndDark = 13,
+    MediumAndLight = 14,
+    MediumAndMediumLight = 15,
+    MediumAndMediumDark = 16,
+    MediumAndDark = 17,
+    MediumDarkAndLight = 18,
+    MediumDarkAndMediumLight = 19,
+    MediumDarkAndMedium = 20,
+    MediumDarkAndDark = 21,
+    DarkAndLight = 22,
+    DarkAndMediumLight = 23,
+    DarkAndMedium = 24,
+    DarkAndMediumDark = 25,
+}
+
+impl SkinTone {
+    const ALL: &'static [Self] = &[
+        Self::Default,
+        Self::Light,
+        Self::MediumLight,
+        Self::Medium,
+        Self::MediumDark,
+        Self::Dark,
+        Self::LightAndMediumLight,
+        Self::LightAndMedium,
+        Self::LightAndMediumDark,
+        Self::LightAndDark,
+        Self::MediumLightAndLight,
+        Self::MediumLightAndMedium,
+        Self::MediumLightAndMediumDark,
+        Self::MediumLightAndDark,
+        Self::MediumAndLight,
+        Self::MediumAndMediumLight,
+        Self::MediumAndMediumDark,
+        Self::MediumAndDark,
+        Self::MediumDarkAndLight,
+        Self::MediumDarkAndMediumLight,
+        Self::MediumDarkAndMedium,
+        Self::MediumDarkAndDark,
+        Self::DarkAndLight,
+        Self::DarkAndMediumLight,
+        Self::DarkAndMedium,
+        Self::DarkAndMediumDark,
+    ];
+
+    /// Returns an iterator over all skin tones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::SkinTone;
+    ///
+    /// let mut iter = SkinTone::iter();
+    /// assert_eq!(iter.next(), Some(SkinTone::Default));
+    /// assert_eq!(iter.next(), Some(SkinTone::Light));
+    /// ```
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = SkinTone> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Returns the stable numeric code of this skin tone.
+    ///
+    /// Codes never change between releases and new skin tones are given new
+    /// codes, so they can be stored in place of the skin tone. Use
+    /// [`SkinTone::try_from()`] to convert back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use emojis::SkinTone;
+    ///
+    /// assert_eq!(SkinTone::Medium.as_u8(), 3);
+    /// assert_eq!(SkinTone::try_from(3), Ok(SkinTone::Medium));
+    /// ```
+    #[inline]
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Lookup a skin tone by its [stable code][SkinTone::as_u8].
+impl convert::TryFrom<u8> for SkinTone {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .get(code as usize)
+            .copied()
+            .ok_or(Error::UnknownCode)
+    }
 }
 
 impl UnicodeVersion {
@@ -194,6 +586,35 @@ impl UnicodeVersion {
     }
 }
 
+impl fmt::Display for UnicodeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The release that an emoji was introduced in.
+///
+/// This struct is created by [`Emoji::release_cohort()`]. It displays as a
+/// label like "Emoji 15.1".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReleaseCohort {
+    version: UnicodeVersion,
+}
+
+impl ReleaseCohort {
+    /// Returns the Unicode version of this release.
+    #[inline]
+    pub const fn unicode_version(&self) -> UnicodeVersion {
+        self.version
+    }
+}
+
+impl fmt::Display for ReleaseCohort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Emoji {}", self.version)
+    }
+}
+
 impl Emoji {
     /// Returns this emoji as a string.
     ///
@@ -208,184 +629,794 @@ impl Emoji {
         self.emoji
     }
 
-    /// Returns this emoji as slice of UTF-8 encoded bytes.
+    /// Returns this emoji written with the given qualification.
+    ///
+    /// Unicode lists minimally qualified and unqualified forms for most emojis,
+    /// which [`get()`] accepts. The forms written here follow the same rules
+    /// but may not be listed for a few long sequences.
     ///
     /// # Examples
     ///
     /// ```
-    /// let rocket = emojis::get("🚀").unwrap();
-    /// assert_eq!(rocket.as_bytes(), &[0xf0, 0x9f, 0x9a, 0x80]);
+    /// use emojis::Qualification;
+    ///
+    /// let eye = emojis::get("👁️‍🗨️").unwrap();
+    /// assert_eq!(eye.qualified(Qualification::Fully).to_string(), "👁\u{fe0f}\u{200d}🗨\u{fe0f}");
+    /// assert_eq!(eye.qualified(Qualification::Minimal).to_string(), "👁\u{fe0f}\u{200d}🗨");
+    /// assert_eq!(eye.qualified(Qualification::Unqualified).to_string(), "👁\u{200d}🗨");
     /// ```
     #[inline]
-    pub const fn as_bytes(&self) -> &[u8] {
-        self.emoji.as_bytes()
+    pub fn qualified(&self, qualification: Qualification) -> Qualified<'_> {
+        Qualified {
+            emoji: self.emoji,
+            qualification,
+        }
     }
 
-    /// Returns the CLDR name for this emoji.
+    /// Returns this emoji as slice of UTF-8 encoded bytes.
     ///
     /// # Examples
     ///
     /// ```
-    /// let cool = emojis::get("😎").unwrap();
-    /// assert_eq!(cool.name(), "smiling face with sunglasses");
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.as_bytes(), &[0xf0, 0x9f, 0x9a, 0x80]);
     /// ```
     #[inline]
-    pub const fn name(&self) -> &str {
-        self.name
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.emoji.as_bytes()
     }
 
-    /// Returns the Unicode version this emoji first appeared in.
+    /// Returns an iterator over the UTF-16 code units of this emoji.
     ///
     /// # Examples
     ///
     /// ```
-    /// use emojis::UnicodeVersion;
-    ///
-    /// let villain = emojis::get("🦹").unwrap();
-    /// assert_eq!(villain.unicode_version(), UnicodeVersion::new(11, 0));
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// let units: Vec<u16> = rocket.encode_utf16().collect();
+    /// assert_eq!(units, [0xd83d, 0xde80]);
     /// ```
     #[inline]
-    pub const fn unicode_version(&self) -> UnicodeVersion {
-        self.unicode_version
+    pub fn encode_utf16(&self) -> core::str::EncodeUtf16<'static> {
+        self.emoji.encode_utf16()
     }
 
-    /// Returns the group this emoji belongs to.
+    /// Returns the length of this emoji in UTF-8 bytes.
     ///
     /// # Examples
     ///
     /// ```
-    /// use emojis::Group;
-    ///
-    /// let flag = emojis::get("🇿🇦").unwrap();
-    /// assert_eq!(flag.group(), Group::Flags);
+    /// let astronaut = emojis::get("🧑‍🚀").unwrap();
+    /// assert_eq!(astronaut.utf8_len(), 11);
     /// ```
     #[inline]
-    pub const fn group(&self) -> Group {
-        self.group
+    pub const fn utf8_len(&self) -> usize {
+        self.emoji.len()
     }
 
-    /// Returns the skin tone of this emoji.
+    /// Copies this emoji as UTF-8 to the start of the buffer and returns the
+    /// number of bytes written.
+    ///
+    /// The buffer is left unchanged if the emoji does not fit. A buffer of
+    /// [`MAX_EMOJI_LEN`] bytes fits every emoji.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::BufferTooSmall`] if the buffer is shorter than
+    /// [`utf8_len()`][Emoji::utf8_len].
     ///
     /// # Examples
     ///
     /// ```
-    /// use emojis::SkinTone;
+    /// use emojis::Error;
     ///
-    /// let peace = emojis::get("✌️").unwrap();
-    /// assert_eq!(peace.skin_tone(), Some(SkinTone::Default));
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// let mut buf = [0; 8];
+    /// let n = rocket.write_to(&mut buf).unwrap();
+    /// assert_eq!(&buf[..n], rocket.as_bytes());
     ///
-    /// let peace = emojis::get("✌🏽").unwrap();
-    /// assert_eq!(peace.skin_tone(), Some(SkinTone::Medium));
+    /// assert_eq!(rocket.write_to(&mut [0; 3]), Err(Error::BufferTooSmall));
     /// ```
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, Error> {
+        let dst = buf
+            .get_mut(..self.emoji.len())
+            .ok_or(Error::BufferTooSmall)?;
+        dst.copy_from_slice(self.emoji.as_bytes());
+        Ok(self.emoji.len())
+    }
+
+    /// Returns the length of this emoji in UTF-16 code units.
     ///
-    /// For emojis where skin tones are not applicable this will be `None`.
+    /// This is precomputed, so it is useful for working with lengths and
+    /// indices in UTF-16 based environments such as JavaScript and Windows.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// let cool = emojis::get("😎").unwrap();
-    /// assert!(cool.skin_tone().is_none());
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.utf16_len(), 2);
+    ///
+    /// let astronaut = emojis::get("🧑‍🚀").unwrap();
+    /// assert_eq!(astronaut.utf16_len(), 5);
     /// ```
     #[inline]
-    pub fn skin_tone(&self) -> Option<SkinTone> {
-        self.skin_tone.map(|(_, _, v)| v)
+    pub const fn utf16_len(&self) -> usize {
+        self.utf16_len as usize
     }
 
-    /// Returns an iterator over the emoji and all the related skin tone emojis.
+    /// Returns a type that displays this emoji percent-encoded.
+    ///
+    /// Every byte of the UTF-8 encoding is percent-encoded except for the
+    /// unreserved ASCII characters, so the result is safe to use in URL paths
+    /// and query parameters.
     ///
     /// # Examples
     ///
     /// ```
-    /// use emojis::Emoji;
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.percent_encoded().to_string(), "%F0%9F%9A%80");
     ///
-    /// let luck = emojis::get("🤞🏼").unwrap();
-    /// let skin_tones: Vec<_> = luck.skin_tones().unwrap().map(Emoji::as_str).collect();
-    /// assert_eq!(skin_tones, ["🤞", "🤞🏻", "🤞🏼", "🤞🏽", "🤞🏾", "🤞🏿"]);
+    /// let keycap = emojis::get("#️⃣").unwrap();
+    /// assert_eq!(keycap.percent_encoded().to_string(), "%23%EF%B8%8F%E2%83%A3");
     /// ```
+    #[inline]
+    pub const fn percent_encoded(&self) -> PercentEncoded<'_> {
+        PercentEncoded { emoji: self }
+    }
+
+    /// Returns whether the string is this emoji, ignoring whether either is
+    /// fully qualified.
     ///
-    /// Some emojis have 26 skin tones!
+    /// Unqualified and minimally-qualified emojis omit the emoji presentation
+    /// selector, so for example "☹" and "☹️" are considered equal.
     ///
-    /// ```
-    /// use emojis::SkinTone;
+    /// # Examples
     ///
-    /// let couple = emojis::get("👩🏿‍❤️‍👨🏼").unwrap();
-    /// let skin_tones = couple.skin_tones().unwrap().count();
-    /// assert_eq!(skin_tones, 26);
     /// ```
+    /// let frowning = emojis::get("☹️").unwrap();
+    /// assert!(frowning.eq_ignore_qualification("☹"));
+    /// assert!(frowning.eq_ignore_qualification("☹️"));
+    /// assert!(frowning != "☹");
+    /// assert!(!frowning.eq_ignore_qualification("🙁"));
+    /// ```
+    #[inline]
+    pub fn eq_ignore_qualification(&self, s: &str) -> bool {
+        crate::get(s) == Some(self)
+    }
+
+    /// Returns the CLDR name for this emoji.
     ///
-    /// For emojis where skin tones are not applicable this will return `None`.
+    /// # Examples
     ///
     /// ```
     /// let cool = emojis::get("😎").unwrap();
-    /// assert!(cool.skin_tones().is_none());
+    /// assert_eq!(cool.name(), "smiling face with sunglasses");
     /// ```
     #[inline]
-    pub fn skin_tones(&self) -> Option<impl Iterator<Item = &Self>> {
-        let (i, n, _) = self.skin_tone?;
-        Some(crate::gen::EMOJIS[i as usize..].iter().take(n as usize))
+    pub const fn name(&self) -> &str {
+        self.name
     }
 
-    /// Returns a version of this emoji that has the given skin tone.
+    /// Returns the words in the CLDR name for this emoji.
     ///
-    /// # Examples
+    /// The name is split on whitespace and punctuation around each word, such
+    /// as colons, commas, parentheses, and quotation marks, is removed. The
+    /// words are precomputed so this does not allocate.
     ///
-    /// ```
-    /// use emojis::SkinTone;
+    /// # Examples
     ///
-    /// let raised_hands = emojis::get("🙌🏼")
-    ///     .unwrap()
-    ///     .with_skin_tone(SkinTone::MediumDark)
-    ///     .unwrap();
-    /// assert_eq!(raised_hands, emojis::get("🙌🏾").unwrap());
     /// ```
+    /// let flag = emojis::get("🇨🇮").unwrap();
+    /// assert_eq!(flag.short_name_words(), ["flag", "Côte", "d’Ivoire"]);
     ///
+    /// let here = emojis::get("🈁").unwrap();
+    /// assert_eq!(here.short_name_words(), ["Japanese", "here", "button"]);
     /// ```
-    /// use emojis::SkinTone;
+    #[inline]
+    pub const fn short_name_words(&self) -> &'static [&'static str] {
+        self.name_words
+    }
+
+    /// Returns the CLDR name for this emoji folded to lowercase ASCII.
     ///
-    /// let couple = emojis::get("👩‍❤️‍👨")
-    ///     .unwrap()
-    ///     .with_skin_tone(SkinTone::DarkAndMediumLight)
-    ///     .unwrap();
-    /// assert_eq!(couple, emojis::get("👩🏿‍❤️‍👨🏼").unwrap());
-    /// ```
+    /// Diacritics are removed and typographic punctuation is replaced with its
+    /// ASCII equivalent. This is precomputed, so it is useful for matching
+    /// search queries regardless of case or diacritics.
     ///
-    /// For emojis where the skin tone is not applicable this will return
-    /// `None`.
+    /// # Examples
     ///
     /// ```
-    /// use emojis::SkinTone;
+    /// let pinata = emojis::get("🪅").unwrap();
+    /// assert_eq!(pinata.name(), "piñata");
+    /// assert_eq!(pinata.folded_name(), "pinata");
     ///
-    /// let cool = emojis::get("😎").unwrap();
-    /// assert!(cool.with_skin_tone(SkinTone::Medium).is_none());
+    /// let flag = emojis::get("🇦🇽").unwrap();
+    /// assert_eq!(flag.folded_name(), "flag: aland islands");
     /// ```
     #[inline]
-    pub fn with_skin_tone(&self, skin_tone: SkinTone) -> Option<&Self> {
-        self.skin_tones()?
-            .find(|emoji| emoji.skin_tone().unwrap() == skin_tone)
+    pub fn folded_name(&self) -> &str {
+        self.folded_name.unwrap_or(self.name)
     }
 
-    /// Returns the first GitHub shortcode for this emoji.
-    ///
-    /// Most emojis only have zero or one shortcode but for a few there are
-    /// multiple. Use the [`shortcodes()`][Emoji::shortcodes] method to return
-    /// all the shortcodes. See [gemoji] for more information.
+    /// Returns the formal Unicode character name for this emoji.
     ///
-    /// For emojis that have zero shortcodes this will return `None`.
+    /// This is only available for emojis that are a single character, not
+    /// counting an emoji presentation selector, and is `None` for sequences
+    /// such as flags, keycaps, and ZWJ sequences.
     ///
     /// # Examples
     ///
     /// ```
-    /// let thinking = emojis::get("🤔").unwrap();
-    /// assert_eq!(thinking.shortcode().unwrap(), "thinking");
-    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.unicode_name(), Some("ROCKET"));
     ///
-    /// [gemoji]: https://github.com/github/gemoji
+    /// let frowning = emojis::get("☹️").unwrap();
+    /// assert_eq!(frowning.unicode_name(), Some("WHITE FROWNING FACE"));
+    ///
+    /// let astronaut = emojis::get("🧑‍🚀").unwrap();
+    /// assert_eq!(astronaut.unicode_name(), None);
+    /// ```
     #[inline]
-    pub fn shortcode(&self) -> Option<&str> {
-        self.aliases.and_then(|aliases| aliases.first().copied())
+    pub const fn unicode_name(&self) -> Option<&str> {
+        self.unicode_name
     }
 
-    /// Returns an iterator over the GitHub shortcodes for this emoji.
+    /// Returns the Unicode version this emoji first appeared in.
     ///
-    /// Most emojis only have zero or one shortcode but for a few there are
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::UnicodeVersion;
+    ///
+    /// let villain = emojis::get("🦹").unwrap();
+    /// assert_eq!(villain.unicode_version(), UnicodeVersion::new(11, 0));
+    /// ```
+    #[inline]
+    pub const fn unicode_version(&self) -> UnicodeVersion {
+        self.unicode_version
+    }
+
+    /// Returns whether this emoji was introduced in the given Unicode version
+    /// or later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::UnicodeVersion;
+    ///
+    /// let shaking = emojis::get("🫨").unwrap();
+    /// assert!(shaking.is_new_in(UnicodeVersion::new(15, 0)));
+    /// assert!(!emojis::get("😀").unwrap().is_new_in(UnicodeVersion::new(15, 0)));
+    /// ```
+    #[inline]
+    pub fn is_new_in(&self, version: UnicodeVersion) -> bool {
+        self.unicode_version >= version
+    }
+
+    /// Returns the release this emoji was introduced in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let phoenix = emojis::get("🐦‍🔥").unwrap();
+    /// assert_eq!(phoenix.release_cohort().to_string(), "Emoji 15.1");
+    /// ```
+    #[inline]
+    pub const fn release_cohort(&self) -> ReleaseCohort {
+        ReleaseCohort {
+            version: self.unicode_version,
+        }
+    }
+
+    /// Returns the group this emoji belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Group;
+    ///
+    /// let flag = emojis::get("🇿🇦").unwrap();
+    /// assert_eq!(flag.group(), Group::Flags);
+    /// ```
+    #[inline]
+    pub const fn group(&self) -> Group {
+        self.group
+    }
+
+    /// Returns whether this emoji is in the [`Group::SmileysAndEmotion`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("😀").unwrap().is_smiley());
+    /// assert!(!emojis::get("👋").unwrap().is_smiley());
+    /// ```
+    #[inline]
+    pub const fn is_smiley(&self) -> bool {
+        matches!(self.group, Group::SmileysAndEmotion)
+    }
+
+    /// Returns whether this emoji is in the [`Group::PeopleAndBody`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("👋").unwrap().is_people());
+    /// assert!(!emojis::get("😀").unwrap().is_people());
+    /// ```
+    #[inline]
+    pub const fn is_people(&self) -> bool {
+        matches!(self.group, Group::PeopleAndBody)
+    }
+
+    /// Returns whether this emoji is in the [`Group::AnimalsAndNature`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🦉").unwrap().is_nature());
+    /// assert!(!emojis::get("🍇").unwrap().is_nature());
+    /// ```
+    #[inline]
+    pub const fn is_nature(&self) -> bool {
+        matches!(self.group, Group::AnimalsAndNature)
+    }
+
+    /// Returns whether this emoji is in the [`Group::FoodAndDrink`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🍇").unwrap().is_food());
+    /// assert!(!emojis::get("🦉").unwrap().is_food());
+    /// ```
+    #[inline]
+    pub const fn is_food(&self) -> bool {
+        matches!(self.group, Group::FoodAndDrink)
+    }
+
+    /// Returns whether this emoji is in the [`Group::TravelAndPlaces`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🚀").unwrap().is_travel());
+    /// assert!(!emojis::get("⚽").unwrap().is_travel());
+    /// ```
+    #[inline]
+    pub const fn is_travel(&self) -> bool {
+        matches!(self.group, Group::TravelAndPlaces)
+    }
+
+    /// Returns whether this emoji is in the [`Group::Activities`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("⚽").unwrap().is_activity());
+    /// assert!(!emojis::get("🚀").unwrap().is_activity());
+    /// ```
+    #[inline]
+    pub const fn is_activity(&self) -> bool {
+        matches!(self.group, Group::Activities)
+    }
+
+    /// Returns whether this emoji is in the [`Group::Objects`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🔑").unwrap().is_object());
+    /// assert!(!emojis::get("⚽").unwrap().is_object());
+    /// ```
+    #[inline]
+    pub const fn is_object(&self) -> bool {
+        matches!(self.group, Group::Objects)
+    }
+
+    /// Returns whether this emoji is in the [`Group::Symbols`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("♻️").unwrap().is_symbol());
+    /// assert!(!emojis::get("🔑").unwrap().is_symbol());
+    /// ```
+    #[inline]
+    pub const fn is_symbol(&self) -> bool {
+        matches!(self.group, Group::Symbols)
+    }
+
+    /// Returns whether this emoji is in the [`Group::Flags`] group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🇿🇦").unwrap().is_flag());
+    /// assert!(!emojis::get("♻️").unwrap().is_flag());
+    /// ```
+    #[inline]
+    pub const fn is_flag(&self) -> bool {
+        matches!(self.group, Group::Flags)
+    }
+
+    /// Returns the position of this emoji in the Unicode CLDR ordering.
+    ///
+    /// Every emoji including each skin tone has a distinct position, so this
+    /// can be used as a sort key to put a list of emojis back into the order
+    /// of the [Unicode emoji charts](https://unicode.org/emoji/charts/emoji-list.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut list: Vec<_> = ["🚀", "👋🏽", "😀", "👋"]
+    ///     .into_iter()
+    ///     .map(|s| emojis::get(s).unwrap())
+    ///     .collect();
+    /// list.sort_by_key(|e| e.cldr_order());
+    /// assert_eq!(list, ["😀", "👋", "👋🏽", "🚀"]);
+    /// ```
+    #[inline]
+    pub const fn cldr_order(&self) -> usize {
+        self.id.index()
+    }
+
+    /// Returns the id of this emoji.
+    ///
+    /// See [`EmojiId`] for the ordering guarantees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.id().emoji(), rocket);
+    /// assert_eq!(rocket.id().index(), rocket.cldr_order());
+    /// ```
+    #[inline]
+    pub const fn id(&self) -> EmojiId {
+        self.id
+    }
+
+    /// Returns a compact id for this emoji.
+    ///
+    /// Every emoji, including skin tone variants, has a unique id that fits in
+    /// two bytes. This is useful for storing large numbers of emojis, for
+    /// example reactions in a database. Use
+    /// [`from_compact_id()`][Emoji::from_compact_id] to get the emoji back.
+    ///
+    /// # Stability
+    ///
+    /// Ids are assigned in order of Unicode version and then by code points.
+    /// Each data release only adds emojis with a newer Unicode version, so ids
+    /// are only ever appended and an id refers to the same emoji in every
+    /// release that contains it. No translation is needed when upgrading.
+    ///
+    /// Ids are not stable across the rare Unicode changes to the fully
+    /// qualified form of an existing emoji. Any such change will be noted in
+    /// the release notes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Emoji;
+    ///
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// let id = rocket.to_compact_id();
+    /// assert_eq!(Emoji::from_compact_id(id), Some(rocket));
+    /// ```
+    #[inline]
+    pub const fn to_compact_id(&self) -> u16 {
+        self.compact_id
+    }
+
+    /// Returns a 64-bit fingerprint of this emoji.
+    ///
+    /// The fingerprint is the [FNV-1a] hash of the UTF-8 bytes of
+    /// [`as_str()`][Emoji::as_str]. It only depends on the code points of the
+    /// emoji, so unlike [compact ids][Emoji::to_compact_id] or [`EmojiId`] it
+    /// is stable across all releases of this crate and can be recomputed by
+    /// other implementations. This makes it useful as a database or cache key.
+    ///
+    /// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.fingerprint(), 0xff06d33875097bda);
+    /// ```
+    pub const fn fingerprint(&self) -> u64 {
+        let bytes = self.emoji.as_bytes();
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+            i += 1;
+        }
+        hash
+    }
+
+    /// Returns an iterator over the Unicode blocks of the code points in this
+    /// emoji, without duplicates and in the order they first appear.
+    ///
+    /// This includes the blocks of components such as the zero width joiner
+    /// and variation selectors. It is useful for deciding which fonts are
+    /// needed to render a set of emojis, see [`Block::plane()`] for the plane
+    /// of each block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let heart = emojis::get("❤️‍🔥").unwrap();
+    /// let blocks: Vec<_> = heart.blocks().map(|b| b.name()).collect();
+    /// assert_eq!(
+    ///     blocks,
+    ///     [
+    ///         "Dingbats",
+    ///         "Variation Selectors",
+    ///         "General Punctuation",
+    ///         "Miscellaneous Symbols and Pictographs",
+    ///     ]
+    /// );
+    /// ```
+    pub fn blocks(&self) -> impl Iterator<Item = Block> + '_ {
+        let emoji = self.emoji;
+        emoji.char_indices().filter_map(move |(i, c)| {
+            let block = Block::of(c)?;
+            let seen = emoji[..i].chars().any(|c| block.range().contains(&c));
+            if seen {
+                None
+            } else {
+                Some(block)
+            }
+        })
+    }
+
+    /// Lookup an emoji by its compact id.
+    ///
+    /// Returns `None` if the id is not known to this release, see
+    /// [`to_compact_id()`][Emoji::to_compact_id].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Emoji;
+    ///
+    /// assert_eq!(Emoji::from_compact_id(0).unwrap(), "#️⃣");
+    /// assert!(Emoji::from_compact_id(u16::MAX).is_none());
+    /// ```
+    #[inline]
+    pub fn from_compact_id(id: u16) -> Option<&'static Emoji> {
+        crate::gen::BY_COMPACT_ID
+            .get(id as usize)
+            .map(|&i| &crate::gen::EMOJIS[i as usize])
+    }
+
+    /// Returns the subgroup this emoji belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.subgroup().name(), "transport-air");
+    /// ```
+    #[inline]
+    pub fn subgroup(&self) -> Subgroup {
+        crate::subgroup::of(self.id())
+    }
+
+    /// Returns the skin tone of this emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::SkinTone;
+    ///
+    /// let peace = emojis::get("✌️").unwrap();
+    /// assert_eq!(peace.skin_tone(), Some(SkinTone::Default));
+    ///
+    /// let peace = emojis::get("✌🏽").unwrap();
+    /// assert_eq!(peace.skin_tone(), Some(SkinTone::Medium));
+    /// ```
+    ///
+    /// For emojis where skin tones are not applicable this will be `None`.
+    ///
+    /// ```
+    /// let cool = emojis::get("😎").unwrap();
+    /// assert!(cool.skin_tone().is_none());
+    /// ```
+    #[inline]
+    pub fn skin_tone(&self) -> Option<SkinTone> {
+        self.skin_tone.map(|(_, _, v)| v)
+    }
+
+    /// Returns an iterator over the emoji and all the related skin tone emojis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Emoji;
+    ///
+    /// let luck = emojis::get("🤞🏼").unwrap();
+    /// let skin_tones: Vec<_> = luck.skin_tones().unwrap().map(Emoji::as_str).collect();
+    /// assert_eq!(skin_tones, ["🤞", "🤞🏻", "🤞🏼", "🤞🏽", "🤞🏾", "🤞🏿"]);
+    /// ```
+    ///
+    /// Some emojis have 26 skin tones!
+    ///
+    /// ```
+    /// use emojis::SkinTone;
+    ///
+    /// let couple = emojis::get("👩🏿‍❤️‍👨🏼").unwrap();
+    /// let skin_tones = couple.skin_tones().unwrap().count();
+    /// assert_eq!(skin_tones, 26);
+    /// ```
+    ///
+    /// For emojis where skin tones are not applicable this will return `None`.
+    ///
+    /// ```
+    /// let cool = emojis::get("😎").unwrap();
+    /// assert!(cool.skin_tones().is_none());
+    /// ```
+    #[inline]
+    pub fn skin_tones(&self) -> Option<impl Iterator<Item = &Self>> {
+        let (id, n, _) = self.skin_tone?;
+        Some(id.slice(n as usize).iter())
+    }
+
+    /// Returns a version of this emoji that has the given skin tone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::SkinTone;
+    ///
+    /// let raised_hands = emojis::get("🙌🏼")
+    ///     .unwrap()
+    ///     .with_skin_tone(SkinTone::MediumDark)
+    ///     .unwrap();
+    /// assert_eq!(raised_hands, emojis::get("🙌🏾").unwrap());
+    /// ```
+    ///
+    /// ```
+    /// use emojis::SkinTone;
+    ///
+    /// let couple = emojis::get("👩‍❤️‍👨")
+    ///     .unwrap()
+    ///     .with_skin_tone(SkinTone::DarkAndMediumLight)
+    ///     .unwrap();
+    /// assert_eq!(couple, emojis::get("👩🏿‍❤️‍👨🏼").unwrap());
+    /// ```
+    ///
+    /// For emojis where the skin tone is not applicable this will return
+    /// `None`.
+    ///
+    /// ```
+    /// use emojis::SkinTone;
+    ///
+    /// let cool = emojis::get("😎").unwrap();
+    /// assert!(cool.with_skin_tone(SkinTone::Medium).is_none());
+    /// ```
+    #[inline]
+    pub fn with_skin_tone(&self, skin_tone: SkinTone) -> Option<&Self> {
+        self.skin_tones()?
+            .find(|emoji| emoji.skin_tone().unwrap() == skin_tone)
+    }
+
+    /// Returns an iterator over the skin tone modifiers in this emoji and their
+    /// byte offsets in [`as_str()`][Emoji::as_str].
+    ///
+    /// For emojis depicting more than one person the modifiers are in the same
+    /// order as the people in the sequence, so this can be used to map a
+    /// position in the text to the person at that position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::SkinTone;
+    ///
+    /// let couple = emojis::get("👩🏿‍❤️‍👨🏼").unwrap();
+    /// let modifiers: Vec<_> = couple.skin_tone_modifiers().collect();
+    /// assert_eq!(modifiers, [(4, SkinTone::Dark), (24, SkinTone::MediumLight)]);
+    ///
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.skin_tone_modifiers().count(), 0);
+    /// ```
+    pub fn skin_tone_modifiers(&self) -> impl Iterator<Item = (usize, SkinTone)> {
+        self.emoji.char_indices().filter_map(|(i, c)| {
+            let skin_tone = match c {
+                '\u{1f3fb}' => SkinTone::Light,
+                '\u{1f3fc}' => SkinTone::MediumLight,
+                '\u{1f3fd}' => SkinTone::Medium,
+                '\u{1f3fe}' => SkinTone::MediumDark,
+                '\u{1f3ff}' => SkinTone::Dark,
+                _ => return None,
+            };
+            Some((i, skin_tone))
+        })
+    }
+
+    /// Returns the gender-neutral form of this emoji.
+    ///
+    /// Men and women are replaced with a person, boys and girls with a child,
+    /// and gender signs are removed, keeping the skin tones. Gendered emojis
+    /// with a separate neutral emoji map to it, e.g. 🎅 to 🧑‍🎄, 🤰 to 🫄, and
+    /// 👩‍❤️‍👨 to 💑. Returns this emoji if it is already neutral or there is
+    /// no neutral form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let doctor = emojis::get("👩🏽‍⚕️").unwrap();
+    /// assert_eq!(doctor.neutral_form(), "🧑🏽‍⚕️");
+    ///
+    /// let kiss = emojis::get("👩‍❤️‍💋‍👨").unwrap();
+    /// assert_eq!(kiss.neutral_form(), "💏");
+    ///
+    /// let runner = emojis::get("🏃‍♂️").unwrap();
+    /// assert_eq!(runner.neutral_form(), "🏃");
+    ///
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.neutral_form(), "🚀");
+    /// ```
+    pub fn neutral_form(&self) -> &Self {
+        crate::base::neutral_form(self.as_str()).unwrap_or(self)
+    }
+
+    /// Returns the number of people depicted by this emoji.
+    ///
+    /// This is derived from the structure of the emoji's ZWJ sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(emojis::get("🚀").unwrap().person_count(), 0);
+    /// assert_eq!(emojis::get("🙋").unwrap().person_count(), 1);
+    /// assert_eq!(emojis::get("🧑‍🤝‍🧑").unwrap().person_count(), 2);
+    /// assert_eq!(emojis::get("👨‍👩‍👧‍👦").unwrap().person_count(), 4);
+    /// ```
+    #[inline]
+    pub const fn person_count(&self) -> usize {
+        self.person_count as usize
+    }
+
+    /// Returns whether this emoji depicts more than one person.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("👩🏽‍🤝‍👨🏻").unwrap().is_multi_person());
+    /// assert!(!emojis::get("🧑‍🚀").unwrap().is_multi_person());
+    /// ```
+    #[inline]
+    pub const fn is_multi_person(&self) -> bool {
+        self.person_count > 1
+    }
+
+    /// Returns the first GitHub shortcode for this emoji.
+    ///
+    /// Most emojis only have zero or one shortcode but for a few there are
+    /// multiple. Use the [`shortcodes()`][Emoji::shortcodes] method to return
+    /// all the shortcodes. See [gemoji] for more information.
+    ///
+    /// For emojis that have zero shortcodes this will return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let thinking = emojis::get("🤔").unwrap();
+    /// assert_eq!(thinking.shortcode().unwrap(), "thinking");
+    /// ```
+    ///
+    /// [gemoji]: https://github.com/github/gemoji
+    #[inline]
+    pub fn shortcode(&self) -> Option<&str> {
+        self.aliases.and_then(|aliases| aliases.first().copied())
+    }
+
+    /// Returns an iterator over the GitHub shortcodes for this emoji.
+    ///
+    /// Most emojis only have zero or one shortcode but for a few there are
     /// multiple. Use the [`shortcode()`][Emoji::shortcode] method to return the
     /// first shortcode. See [gemoji] for more information.
     ///
@@ -406,6 +1437,268 @@ impl Emoji {
     pub fn shortcodes(&self) -> impl Iterator<Item = &str> {
         self.aliases.into_iter().flatten().copied()
     }
+
+    /// Returns the GitHub shortcodes for this emoji as a slice.
+    ///
+    /// This is the same as [`shortcodes()`][Emoji::shortcodes] but allows the
+    /// shortcodes to be indexed or passed on without collecting them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let laughing = emojis::get("😆").unwrap();
+    /// assert_eq!(laughing.shortcodes_slice(), ["laughing", "satisfied"]);
+    ///
+    /// let head = emojis::get("🙂‍↔️").unwrap();
+    /// assert!(head.shortcodes_slice().is_empty());
+    /// ```
+    #[inline]
+    pub const fn shortcodes_slice(&self) -> &'static [&'static str] {
+        match self.aliases {
+            Some(aliases) => aliases,
+            None => &[],
+        }
+    }
+
+    /// Returns whether this emoji is a country or region flag.
+    ///
+    /// These are made up of two regional indicator symbols that spell out an
+    /// ISO 3166-1 region code, e.g. 🇿🇦 is "ZA".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🇿🇦").unwrap().is_country_flag());
+    /// assert!(!emojis::get("🏴󠁧󠁢󠁳󠁣󠁴󠁿").unwrap().is_country_flag());
+    /// assert!(!emojis::get("🏳️‍🌈").unwrap().is_country_flag());
+    /// ```
+    #[inline]
+    pub fn is_country_flag(&self) -> bool {
+        let mut chars = self.emoji.chars();
+        matches!(
+            (chars.next(), chars.next(), chars.next()),
+            (Some(a), Some(b), None) if is_regional_indicator(a) && is_regional_indicator(b)
+        )
+    }
+
+    /// Returns whether this emoji is a subdivision flag.
+    ///
+    /// These are a black flag followed by tag characters that spell out an
+    /// ISO 3166-2 subdivision code, e.g. 🏴󠁧󠁢󠁳󠁣󠁴󠁿 is "gbsct".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🏴󠁧󠁢󠁳󠁣󠁴󠁿").unwrap().is_subdivision_flag());
+    /// assert!(!emojis::get("🏴").unwrap().is_subdivision_flag());
+    /// assert!(!emojis::get("🇿🇦").unwrap().is_subdivision_flag());
+    /// ```
+    #[inline]
+    pub fn is_subdivision_flag(&self) -> bool {
+        let mut chars = self.emoji.chars();
+        chars.next() == Some('\u{1f3f4}')
+            && chars.next_back() == Some('\u{e007f}')
+            && chars.all(|c| ('\u{e0020}'..='\u{e007e}').contains(&c))
+    }
+
+    /// Returns whether this emoji is a flag that is neither a country nor a
+    /// subdivision flag.
+    ///
+    /// This includes flags like 🏳️‍🌈, 🏳️‍⚧️, 🏴‍☠️, and 🏁.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🏳️‍🌈").unwrap().is_pride_or_other_flag());
+    /// assert!(emojis::get("🏁").unwrap().is_pride_or_other_flag());
+    /// assert!(!emojis::get("🇿🇦").unwrap().is_pride_or_other_flag());
+    /// assert!(!emojis::get("🚀").unwrap().is_pride_or_other_flag());
+    /// ```
+    #[inline]
+    pub fn is_pride_or_other_flag(&self) -> bool {
+        self.group == Group::Flags && !self.is_country_flag() && !self.is_subdivision_flag()
+    }
+
+    /// Returns whether this emoji is a keycap, like #️⃣ or 1️⃣.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("#️⃣").unwrap().is_keycap());
+    /// assert!(!emojis::get("🔟").unwrap().is_keycap());
+    /// ```
+    #[inline]
+    pub fn is_keycap(&self) -> bool {
+        self.emoji.ends_with('\u{20e3}')
+    }
+
+    /// Returns whether this emoji is a single character that has text
+    /// presentation by default.
+    ///
+    /// These are mostly characters from legacy symbol blocks, like ☹️, ❤️,
+    /// and ™️, which are only displayed as an emoji when followed by an emoji
+    /// presentation selector. Renderers may choose to display them with a text
+    /// font when the selector is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("❤️").unwrap().is_symbol_like());
+    /// assert!(emojis::get("™️").unwrap().is_symbol_like());
+    /// assert!(!emojis::get("🚀").unwrap().is_symbol_like());
+    /// assert!(!emojis::get("#️⃣").unwrap().is_symbol_like());
+    /// ```
+    #[inline]
+    pub fn is_symbol_like(&self) -> bool {
+        let mut chars = self.emoji.chars();
+        chars.next().is_some() && chars.next() == Some(components::VS16) && chars.next().is_none()
+    }
+
+    /// Returns whether this emoji is a ZWJ sequence.
+    ///
+    /// These are only displayed as a single glyph by fonts that support the
+    /// sequence, otherwise they are displayed as their components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("🧑‍🚀").unwrap().requires_zwj());
+    /// assert!(!emojis::get("🚀").unwrap().requires_zwj());
+    /// ```
+    #[inline]
+    pub fn requires_zwj(&self) -> bool {
+        self.emoji.contains(components::ZWJ)
+    }
+
+    /// Returns whether this emoji contains an emoji presentation selector.
+    ///
+    /// These are displayed as text by fonts or renderers that do not support
+    /// the selector, see also [`is_symbol_like()`][Emoji::is_symbol_like].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("❤️").unwrap().requires_vs16());
+    /// assert!(emojis::get("🏳️‍🌈").unwrap().requires_vs16());
+    /// assert!(!emojis::get("🚀").unwrap().requires_vs16());
+    /// ```
+    #[inline]
+    pub fn requires_vs16(&self) -> bool {
+        self.emoji.contains(components::VS16)
+    }
+
+    /// Returns the semantic category of this emoji.
+    ///
+    /// Only emojis depicting animals, plants, fruit, vegetables, and weather
+    /// have a category, see [`Category`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Category;
+    ///
+    /// assert_eq!(emojis::get("🐘").unwrap().category(), Some(Category::Mammal));
+    /// assert_eq!(emojis::get("🌧️").unwrap().category(), Some(Category::Weather));
+    /// assert_eq!(emojis::get("🚀").unwrap().category(), None);
+    /// ```
+    #[inline]
+    pub fn category(&self) -> Option<Category> {
+        crate::category::category(self)
+    }
+
+    /// Returns the color of this emoji.
+    ///
+    /// Only emojis that are the same symbol in different colors have a color,
+    /// e.g. the hearts, circles, and squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Color;
+    ///
+    /// assert_eq!(emojis::get("💜").unwrap().color(), Some(Color::Purple));
+    /// assert_eq!(emojis::get("🔷").unwrap().color(), Some(Color::Blue));
+    /// assert_eq!(emojis::get("🚀").unwrap().color(), None);
+    /// ```
+    #[inline]
+    pub fn color(&self) -> Option<Color> {
+        self.color.map(|(color, _)| color)
+    }
+
+    /// Returns an iterator over the emojis that are the same symbol as this
+    /// emoji in different colors, including this emoji.
+    ///
+    /// The emojis are in Unicode CLDR order. For emojis without a
+    /// [`color()`][Emoji::color] this will return an empty iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let squares: String = emojis::get("🟥")
+    ///     .unwrap()
+    ///     .color_variants()
+    ///     .map(|e| e.as_str())
+    ///     .collect();
+    /// assert_eq!(squares, "🟥🟧🟨🟩🟦🟪🟫");
+    /// ```
+    pub fn color_variants(&self) -> impl Iterator<Item = &'static Emoji> {
+        let family: &[u16] = match self.color {
+            Some((_, i)) => crate::gen::COLOR_FAMILIES[i as usize],
+            None => &[],
+        };
+        family.iter().map(|&i| &crate::gen::EMOJIS[i as usize])
+    }
+
+    /// Returns an iterator over the other emojis that look alike this emoji.
+    ///
+    /// This is a hand curated list of emojis that are easily mistaken for one
+    /// another on common platforms, e.g. ✅ and ✔️, or flags with the same
+    /// design. It is useful for canonicalizing reactions, e.g. for spam
+    /// detection. Within each set the most common emoji is first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let check = emojis::get("✔️").unwrap();
+    /// let confusables: Vec<_> = check.confusables().map(|e| e.as_str()).collect();
+    /// assert_eq!(confusables, ["✅", "☑️"]);
+    ///
+    /// assert_eq!(emojis::get("🚀").unwrap().confusables().count(), 0);
+    /// ```
+    pub fn confusables(&self) -> impl Iterator<Item = &'static Emoji> {
+        let i = self.cldr_order() as u16;
+        let set: &[u16] = crate::gen::CONFUSABLES
+            .iter()
+            .find(|set| set.contains(&i))
+            .map_or(&[], |set| set);
+        set.iter()
+            .filter(move |&&j| j != i)
+            .map(|&j| &crate::gen::EMOJIS[j as usize])
+    }
+
+    /// Returns a plain ASCII fallback for this emoji, if it has one.
+    ///
+    /// This is a hand curated list of the traditional emoticons and symbols
+    /// for common emojis, e.g. 🙂 is `:)` and ➡️ is `->`. It is useful for SMS
+    /// and legacy terminals where even shortcodes are too verbose. Skin tone
+    /// variants have the same fallback as the default skin tone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(emojis::get("🙂").unwrap().ascii_fallback(), Some(":)"));
+    /// assert_eq!(emojis::get("❤️").unwrap().ascii_fallback(), Some("<3"));
+    /// assert_eq!(emojis::get("👍🏽").unwrap().ascii_fallback(), Some("+1"));
+    /// assert_eq!(emojis::get("🚀").unwrap().ascii_fallback(), None);
+    /// ```
+    pub fn ascii_fallback(&self) -> Option<&'static str> {
+        let (_, base) = crate::gen::unicode::MAP[self.as_str()];
+        let fallbacks = crate::gen::ASCII_FALLBACKS;
+        fallbacks
+            .binary_search_by_key(&base.0, |&(i, _)| i)
+            .ok()
+            .map(|j| fallbacks[j].1)
+    }
 }
 
 impl cmp::PartialEq<Emoji> for Emoji {
@@ -417,49 +1710,285 @@ impl cmp::PartialEq<Emoji> for Emoji {
 
 impl cmp::PartialEq<str> for Emoji {
     #[inline]
-    fn eq(&self, s: &str) -> bool {
-        self.as_str() == s
+    fn eq(&self, s: &str) -> bool {
+        self.as_str() == s
+    }
+}
+
+impl cmp::PartialEq<&str> for Emoji {
+    #[inline]
+    fn eq(&self, s: &&str) -> bool {
+        self.as_str() == *s
+    }
+}
+
+impl cmp::PartialEq<char> for Emoji {
+    #[inline]
+    fn eq(&self, c: &char) -> bool {
+        let mut chars = self.as_str().chars();
+        chars.next() == Some(*c) && chars.next().is_none()
+    }
+}
+
+impl cmp::Eq for Emoji {}
+
+// The `Hash` implementation must be the same as `str` for the `Borrow<str>`
+// implementations below.
+impl hash::Hash for Emoji {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.emoji.hash(state);
+    }
+}
+
+/// Allows collections of emojis to be probed using a string.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// let set: HashSet<&emojis::Emoji> = emojis::iter().take(3).collect();
+/// assert!(set.contains("😀"));
+/// assert!(!set.contains("🚀"));
+/// ```
+impl borrow::Borrow<str> for &Emoji {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl borrow::Borrow<str> for Emoji {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl convert::AsRef<str> for Emoji {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl convert::AsRef<[u8]> for Emoji {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Lookup a group by its [stable code][Group::as_u8].
+impl convert::TryFrom<u8> for Group {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        Self::iter().nth(code as usize).ok_or(Error::UnknownCode)
+    }
+}
+
+/// Lookup an emoji by its Unicode representation, the same as [`get()`].
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use emojis::{Emoji, Error};
+///
+/// assert_eq!(<&Emoji>::try_from("🚀").unwrap(), "🚀");
+/// assert_eq!(<&Emoji>::try_from("rocket"), Err(Error::NotAnEmoji));
+/// ```
+impl convert::TryFrom<&str> for &'static Emoji {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        get(s).ok_or(Error::NotAnEmoji)
+    }
+}
+
+impl fmt::Display for Emoji {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Displays an emoji percent-encoded.
+///
+/// This struct is created by [`Emoji::percent_encoded()`].
+#[derive(Debug, Clone, Copy)]
+pub struct PercentEncoded<'a> {
+    emoji: &'a Emoji,
+}
+
+impl fmt::Display for PercentEncoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        for &b in self.emoji.as_bytes() {
+            if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+                f.write_char(b as char)?;
+            } else {
+                write!(f, "%{:02X}", b)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An emoji that is compared and hashed by its default skin tone emoji.
+///
+/// This makes it easy to aggregate emojis regardless of skin tone, for
+/// example when counting reactions in a `HashMap`.
+/// Emojis that do not support skin tones are compared as is.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use emojis::BaseEmoji;
+///
+/// let mut counts = HashMap::new();
+/// for reaction in ["👍", "👍🏽", "🚀", "👍🏿"] {
+///     let emoji = BaseEmoji::from(emojis::get(reaction).unwrap());
+///     *counts.entry(emoji).or_insert(0) += 1;
+/// }
+/// assert_eq!(counts[&BaseEmoji::from(emojis::get("👍🏻").unwrap())], 3);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BaseEmoji {
+    emoji: &'static Emoji,
+}
+
+impl BaseEmoji {
+    /// Returns the wrapped emoji.
+    #[inline]
+    pub fn emoji(self) -> &'static Emoji {
+        self.emoji
+    }
+
+    /// Returns the emoji with the default skin tone that this emoji is
+    /// compared by.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::BaseEmoji;
+    ///
+    /// let emoji = BaseEmoji::from(emojis::get("👋🏽").unwrap());
+    /// assert_eq!(emoji.base(), "👋");
+    /// ```
+    #[inline]
+    pub fn base(self) -> &'static Emoji {
+        match self.emoji.skin_tone {
+            Some((id, _, _)) => id.emoji(),
+            None => self.emoji,
+        }
+    }
+}
+
+impl From<&'static Emoji> for BaseEmoji {
+    #[inline]
+    fn from(emoji: &'static Emoji) -> Self {
+        Self { emoji }
     }
 }
 
-impl cmp::PartialEq<&str> for Emoji {
+impl cmp::PartialEq for BaseEmoji {
     #[inline]
-    fn eq(&self, s: &&str) -> bool {
-        self.as_str() == *s
+    fn eq(&self, other: &Self) -> bool {
+        self.base() == other.base()
     }
 }
 
-impl cmp::Eq for Emoji {}
+impl cmp::Eq for BaseEmoji {}
 
-impl hash::Hash for Emoji {
+impl hash::Hash for BaseEmoji {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
-        self.emoji.hash(state);
+        self.base().hash(state);
     }
 }
 
-impl convert::AsRef<str> for Emoji {
+impl fmt::Display for BaseEmoji {
     #[inline]
-    fn as_ref(&self) -> &str {
-        self.as_str()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.emoji.fmt(f)
     }
 }
 
-impl convert::AsRef<[u8]> for Emoji {
-    #[inline]
-    fn as_ref(&self) -> &[u8] {
-        self.as_bytes()
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Emoji {
+    fn schema_name() -> alloc::string::String {
+        "Emoji".into()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            ..Default::default()
+        }
+        .into()
     }
 }
 
-impl fmt::Display for Emoji {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.as_str().fmt(f)
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Group {
+    fn schema_name() -> alloc::string::String {
+        "Group".into()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        unit_enum_schema(Group::iter())
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for SkinTone {
+    fn schema_name() -> alloc::string::String {
+        "SkinTone".into()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        unit_enum_schema(SkinTone::iter())
+    }
+}
+
+/// Returns a schema for a string that is one of the given variant names.
+#[cfg(feature = "schemars")]
+fn unit_enum_schema<T: fmt::Debug>(variants: impl Iterator<Item = T>) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+        instance_type: Some(schemars::schema::InstanceType::String.into()),
+        enum_values: Some(variants.map(|v| alloc::format!("{v:?}").into()).collect()),
+        ..Default::default()
     }
+    .into()
 }
 
 impl Group {
+    /// Returns the stable numeric code of this group.
+    ///
+    /// Codes never change between releases, so they can be stored in place of
+    /// the group. Use [`Group::try_from()`] to convert back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    ///
+    /// use emojis::Group;
+    ///
+    /// assert_eq!(Group::SmileysAndEmotion.as_u8(), 0);
+    /// assert_eq!(Group::try_from(Group::Flags.as_u8()), Ok(Group::Flags));
+    /// ```
+    #[inline]
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
     /// Returns an iterator over all groups.
     ///
     /// # Examples
@@ -501,6 +2030,134 @@ impl Group {
             .skip_while(move |emoji| emoji.group != group)
             .take_while(move |emoji| emoji.group == group)
     }
+
+    /// Returns the emojis in this group, including skin tone variants.
+    ///
+    /// The emojis are contiguous, so this does not iterate or allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Group;
+    ///
+    /// let flags = Group::Flags.as_slice();
+    /// assert_eq!(flags.len(), Group::Flags.len_with_skin_tones());
+    /// assert_eq!(flags[0], "🏁");
+    /// ```
+    #[inline]
+    pub fn as_slice(self) -> &'static [Emoji] {
+        crate::subgroup::group_slice(self)
+    }
+
+    /// Returns an iterator over the subgroups in this group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Group;
+    ///
+    /// let first = Group::Flags.subgroups().next().unwrap();
+    /// assert_eq!(first.name(), "flag");
+    /// ```
+    #[inline]
+    pub fn subgroups(self) -> impl Iterator<Item = Subgroup> {
+        Subgroup::iter().filter(move |s| s.group() == self)
+    }
+
+    /// Returns the number of emojis in this group.
+    ///
+    /// This does not include skin tone variants, so it is the same as the
+    /// number of emojis returned by [`emojis()`][Group::emojis].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Group;
+    ///
+    /// assert_eq!(Group::Flags.len(), Group::Flags.emojis().count());
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    #[inline]
+    pub const fn len(self) -> usize {
+        crate::gen::GROUP_COUNTS[self as usize].0
+    }
+
+    /// Returns the number of emojis in this group including skin tone
+    /// variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Group;
+    ///
+    /// assert!(Group::PeopleAndBody.len_with_skin_tones() > Group::PeopleAndBody.len());
+    /// assert_eq!(Group::Flags.len_with_skin_tones(), Group::Flags.len());
+    /// ```
+    #[inline]
+    pub const fn len_with_skin_tones(self) -> usize {
+        crate::gen::GROUP_COUNTS[self as usize].1
+    }
+
+    /// Returns the number of emojis in this group introduced in each Unicode
+    /// version.
+    ///
+    /// The versions are in ascending order and only versions that introduced
+    /// at least one emoji in this group are included. This does not include
+    /// skin tone variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Group, UnicodeVersion};
+    ///
+    /// let (version, count) = Group::Flags.version_histogram().last().unwrap();
+    /// println!("Flags: {} emojis ({count} new in {version})", Group::Flags.len());
+    ///
+    /// let total: usize = Group::Flags.version_histogram().map(|(_, n)| n).sum();
+    /// assert_eq!(total, Group::Flags.len());
+    /// ```
+    #[inline]
+    pub fn version_histogram(self) -> impl Iterator<Item = (UnicodeVersion, usize)> {
+        crate::gen::GROUP_VERSIONS[self as usize].iter().copied()
+    }
+
+    /// Returns the number of emojis in this group up to and including the
+    /// given Unicode version.
+    ///
+    /// This does not include skin tone variants, so it is the same as the
+    /// number of emojis returned by [`emojis_up_to()`][Group::emojis_up_to].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Group, UnicodeVersion};
+    ///
+    /// let v = UnicodeVersion::new(13, 0);
+    /// assert_eq!(Group::Flags.len_up_to(v), Group::Flags.emojis_up_to(v).count());
+    /// ```
+    pub fn len_up_to(self, version: UnicodeVersion) -> usize {
+        self.version_histogram()
+            .take_while(|&(v, _)| v <= version)
+            .map(|(_, n)| n)
+            .sum()
+    }
+
+    /// Returns an iterator over the emojis in this group up to and including
+    /// the given Unicode version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Group, UnicodeVersion};
+    ///
+    /// let v = UnicodeVersion::new(1, 0);
+    /// assert!(Group::Flags.emojis_up_to(v).all(|e| e.unicode_version() <= v));
+    /// ```
+    #[inline]
+    pub fn emojis_up_to(self, version: UnicodeVersion) -> impl Iterator<Item = &'static Emoji> {
+        self.emojis()
+            .filter(move |emoji| emoji.unicode_version <= version)
+    }
 }
 
 /// Returns an iterator over all emojis.
@@ -521,6 +2178,109 @@ pub fn iter() -> impl Iterator<Item = &'static Emoji> {
         .filter(|emoji| matches!(emoji.skin_tone(), Some(SkinTone::Default) | None))
 }
 
+/// Returns an iterator over each group and the emojis in that group.
+///
+/// This walks the emoji table once, so it is more efficient than calling
+/// [`Group::emojis()`] for each group.
+///
+/// - Groups are ordered by Unicode CLDR data.
+/// - Excludes non-default skin tones.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Group;
+///
+/// for (group, mut emojis) in emojis::grouped() {
+///     assert!(emojis.all(|e| e.group() == group));
+/// }
+///
+/// let (group, mut emojis) = emojis::grouped().last().unwrap();
+/// assert_eq!(group, Group::Flags);
+/// assert_eq!(emojis.next().unwrap(), "🏁");
+/// ```
+pub fn grouped() -> impl Iterator<Item = (Group, impl Iterator<Item = &'static Emoji>)> {
+    let mut rest = crate::gen::EMOJIS;
+    iter::from_fn(move || {
+        let group = rest.first()?.group;
+        let n = rest
+            .iter()
+            .position(|emoji| emoji.group != group)
+            .unwrap_or(rest.len());
+        let (span, tail) = rest.split_at(n);
+        rest = tail;
+        let emojis = span
+            .iter()
+            .filter(|emoji| matches!(emoji.skin_tone(), Some(SkinTone::Default) | None));
+        Some((group, emojis))
+    })
+}
+
+/// Returns an iterator over all emojis ordered alphabetically by name.
+///
+/// - Names are compared ignoring case.
+/// - Excludes non-default skin tones.
+///
+/// This uses a precomputed index so no sorting or allocation is needed.
+///
+/// # Examples
+///
+/// ```
+/// let names: Vec<_> = emojis::iter_by_name().map(|e| e.name()).take(3).collect();
+/// assert_eq!(names, ["1st place medal", "2nd place medal", "3rd place medal"]);
+/// ```
+#[inline]
+pub fn iter_by_name() -> impl Iterator<Item = &'static Emoji> {
+    crate::gen::BY_NAME
+        .iter()
+        .map(|&i| &crate::gen::EMOJIS[i as usize])
+}
+
+/// Returns an iterator over all emojis ordered by Unicode version.
+///
+/// - Ordered from the oldest to the newest version, emojis introduced in the
+///   same version are ordered by Unicode CLDR data.
+/// - Excludes non-default skin tones.
+///
+/// This uses a precomputed index so no sorting or allocation is needed.
+///
+/// # Examples
+///
+/// ```
+/// let newest = emojis::iter_by_version().last().unwrap();
+/// assert!(emojis::iter().all(|e| e.unicode_version() <= newest.unicode_version()));
+/// ```
+#[inline]
+pub fn iter_by_version() -> impl Iterator<Item = &'static Emoji> {
+    crate::gen::BY_VERSION
+        .iter()
+        .map(|&i| &crate::gen::EMOJIS[i as usize])
+}
+
+/// Returns an iterator over all emojis introduced in or before the given
+/// Unicode version.
+///
+/// Use this to pin the emoji set to the fonts or assets your application
+/// ships, so that upgrading this crate to newer Unicode data does not
+/// introduce emojis that can't be rendered.
+///
+/// - Ordered by Unicode CLDR data.
+/// - Excludes non-default skin tones.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::UnicodeVersion;
+///
+/// let version = UnicodeVersion::new(15, 1);
+/// assert!(emojis::iter_up_to(version).all(|e| e.unicode_version() <= version));
+/// assert!(emojis::iter_up_to(version).all(|e| e != "🫩"));
+/// ```
+#[inline]
+pub fn iter_up_to(version: UnicodeVersion) -> impl Iterator<Item = &'static Emoji> {
+    iter().filter(move |emoji| emoji.unicode_version() <= version)
+}
+
 /// Lookup an emoji by Unicode value.
 ///
 /// This take *Ο(1)* time.
@@ -530,6 +2290,14 @@ pub fn iter() -> impl Iterator<Item = &'static Emoji> {
 /// If passed a minimally qualified or unqualified emoji this will return the
 /// emoji struct containing the fully qualified version.
 ///
+/// # Normalization
+///
+/// No emoji is changed by the canonical normalization forms, so text that has
+/// been NFC or NFD normalized can be looked up as is. The compatibility forms
+/// NFKC and NFKD replace a few legacy symbols with plain text, for example ™️
+/// becomes "TM" followed by a variation selector. These cannot be recovered
+/// reliably and are not found.
+///
 /// # Examples
 ///
 /// In the ordinary case.
@@ -552,9 +2320,162 @@ pub fn iter() -> impl Iterator<Item = &'static Emoji> {
 /// ```
 #[inline]
 pub fn get(s: &str) -> Option<&'static Emoji> {
+    crate::gen::unicode::MAP.get(s).map(|&(id, _)| id.emoji())
+}
+
+/// Lookup an emoji by its fully qualified Unicode representation in a const
+/// context.
+///
+/// This is a linear search, so prefer [`get()`] outside of const contexts.
+/// Unlike [`get()`] only the fully qualified form is accepted.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Emoji;
+///
+/// const ROCKET: &Emoji = match emojis::get_const("🚀") {
+///     Some(emoji) => emoji,
+///     None => panic!("not an emoji"),
+/// };
+/// assert_eq!(ROCKET.name(), "rocket");
+///
+/// assert!(emojis::get_const("\u{263a}").is_none());
+/// ```
+pub const fn get_const(s: &str) -> Option<&'static Emoji> {
+    const fn eq(a: &[u8], b: &[u8]) -> bool {
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < a.len() {
+            if a[i] != b[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    let emojis = crate::gen::EMOJIS;
+    let mut i = 0;
+    while i < emojis.len() {
+        if eq(emojis[i].emoji.as_bytes(), s.as_bytes()) {
+            return Some(&emojis[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Lookup an emoji and its default skin tone emoji by its Unicode
+/// representation.
+///
+/// This is the same as [`get()`] but also returns the emoji with the default
+/// skin tone in the same lookup. For emojis that do not support skin tones
+/// both emojis are the same.
+///
+/// # Examples
+///
+/// ```
+/// let (emoji, base) = emojis::get_with_base("👋🏽").unwrap();
+/// assert_eq!(emoji, "👋🏽");
+/// assert_eq!(base, "👋");
+///
+/// let (emoji, base) = emojis::get_with_base("🚀").unwrap();
+/// assert_eq!(emoji, base);
+/// ```
+#[inline]
+pub fn get_with_base(s: &str) -> Option<(&'static Emoji, &'static Emoji)> {
     crate::gen::unicode::MAP
         .get(s)
-        .map(|&i| &crate::gen::EMOJIS[i])
+        .map(|&(id, base)| (id.emoji(), base.emoji()))
+}
+
+/// Returns the fully qualified form of an emoji.
+///
+/// This is the same as [`get()`] followed by [`Emoji::as_str()`], so
+/// minimally qualified and unqualified emojis are mapped to their fully
+/// qualified form and fully qualified emojis are returned as is. Returns
+/// `None` if the string is not an emoji.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::qualify("\u{1f43f}"), Some("\u{1f43f}\u{fe0f}"));
+/// assert_eq!(emojis::qualify("🚀"), Some("🚀"));
+/// assert_eq!(emojis::qualify("rocket"), None);
+/// ```
+#[inline]
+pub fn qualify(s: &str) -> Option<&'static str> {
+    get(s).map(|e| e.as_str())
+}
+
+/// Returns whether the string is a minimally qualified or unqualified emoji.
+///
+/// This is `true` exactly when [`qualify()`] would change the string.
+///
+/// # Examples
+///
+/// ```
+/// assert!(emojis::is_unqualified("\u{1f43f}"));
+/// assert!(emojis::is_unqualified("👁\u{fe0f}\u{200d}🗨"));
+/// assert!(!emojis::is_unqualified("\u{1f43f}\u{fe0f}"));
+/// assert!(!emojis::is_unqualified("rocket"));
+/// ```
+#[inline]
+pub fn is_unqualified(s: &str) -> bool {
+    matches!(qualify(s), Some(q) if q != s)
+}
+
+/// Returns the default presentation of a character that is an emoji on its
+/// own.
+///
+/// Characters with [`Presentation::Text`] must be followed by the emoji
+/// presentation selector to be displayed as an emoji, this is the same as
+/// [`Emoji::is_symbol_like()`]. Returns `None` for characters that are not an
+/// emoji on their own, for example digits or regional indicators.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Presentation;
+///
+/// assert_eq!(emojis::default_presentation('😀'), Some(Presentation::Emoji));
+/// assert_eq!(emojis::default_presentation('☺'), Some(Presentation::Text));
+/// assert_eq!(emojis::default_presentation('1'), None);
+/// ```
+pub fn default_presentation(c: char) -> Option<Presentation> {
+    let emoji = get(c.encode_utf8(&mut [0; 4]))?;
+    if emoji.as_str().len() == c.len_utf8() {
+        Some(Presentation::Emoji)
+    } else if emoji.is_symbol_like() {
+        Some(Presentation::Text)
+    } else {
+        None
+    }
+}
+
+/// Lookup an emoji by its UTF-8 bytes.
+///
+/// This is the same as [`get()`] but only validates the bytes as UTF-8 if they
+/// could be an emoji, so it can be used directly on byte buffers without
+/// validating the whole input first.
+///
+/// # Examples
+///
+/// ```
+/// let body = b"launch \xf0\x9f\x9a\x80 now \xff";
+/// let rocket = emojis::get_bytes(&body[7..11]).unwrap();
+/// assert_eq!(rocket, "🚀");
+/// assert!(emojis::get_bytes(&body[..]).is_none());
+/// ```
+#[inline]
+pub fn get_bytes(bytes: &[u8]) -> Option<&'static Emoji> {
+    if bytes.is_empty() || bytes.len() > crate::gen::MAX_LEN {
+        return None;
+    }
+    get(core::str::from_utf8(bytes).ok()?)
 }
 
 /// Lookup an emoji by GitHub shortcode.
@@ -573,3 +2494,76 @@ pub fn get_by_shortcode(s: &str) -> Option<&'static Emoji> {
         .get(s)
         .map(|&i| &crate::gen::EMOJIS[i])
 }
+
+/// Lookup an emoji by its code points in `U+XXXX` notation.
+///
+/// The code points are separated by whitespace, as used in the Unicode emoji
+/// charts. The `U+` prefix is optional and hexadecimal digits may be upper or
+/// lower case.
+///
+/// # Examples
+///
+/// ```
+/// let astronaut = emojis::get_by_codepoints("U+1F469 U+200D U+1F680").unwrap();
+/// assert_eq!(astronaut, "👩‍🚀");
+///
+/// let rocket = emojis::get_by_codepoints("1f680").unwrap();
+/// assert_eq!(rocket, "🚀");
+/// ```
+///
+/// Returns [`Error::InvalidCodepoint`] if a code point could not be parsed
+/// and [`Error::NotAnEmoji`] if the code points are not an emoji.
+///
+/// ```
+/// use emojis::Error;
+///
+/// assert_eq!(emojis::get_by_codepoints("U+ZZZZ"), Err(Error::InvalidCodepoint));
+/// assert_eq!(emojis::get_by_codepoints("U+0041"), Err(Error::NotAnEmoji));
+/// ```
+pub fn get_by_codepoints(s: &str) -> Result<&'static Emoji, Error> {
+    let mut buf = crate::base::Buf::new();
+    for code_point in s.split_whitespace() {
+        let hex = code_point
+            .strip_prefix("U+")
+            .or_else(|| code_point.strip_prefix("u+"))
+            .unwrap_or(code_point);
+        if hex.is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Error::InvalidCodepoint);
+        }
+        let c = u32::from_str_radix(hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or(Error::InvalidCodepoint)?;
+        buf.push(c).ok_or(Error::NotAnEmoji)?;
+    }
+    buf.get().ok_or(Error::NotAnEmoji)
+}
+
+/// Returns an iterator over the GitHub shortcodes that start with the given
+/// prefix and their emojis.
+///
+/// The shortcodes are ordered lexicographically. This uses a precomputed
+/// sorted table so finding the first match takes *Ο(log n)* time.
+///
+/// # Examples
+///
+/// ```
+/// let matches: Vec<_> = emojis::shortcodes_with_prefix("rock")
+///     .map(|(shortcode, emoji)| (shortcode, emoji.as_str()))
+///     .collect();
+/// assert_eq!(matches, [("rock", "🪨"), ("rocket", "🚀")]);
+/// ```
+pub fn shortcodes_with_prefix(
+    prefix: &str,
+) -> impl Iterator<Item = (&'static str, &'static Emoji)> + '_ {
+    let sorted = crate::gen::shortcode::SORTED;
+    let start = sorted.partition_point(|&(shortcode, _)| shortcode < prefix);
+    sorted[start..]
+        .iter()
+        .take_while(move |(shortcode, _)| shortcode.starts_with(prefix))
+        .map(|&(shortcode, i)| (shortcode, &crate::gen::EMOJIS[i as usize]))
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
@@ -0,0 +1,105 @@
+//! Expand shortcodes in [`pulldown_cmark`] events.
+//!
+//! Markdown pipelines can enable GitHub-style emoji by passing the parser's
+//! events through [`expand_shortcodes()`] before rendering. Only text events
+//! are rewritten, so shortcodes in inline code, code blocks, links, and raw
+//! HTML are left as is.
+//!
+//! # Examples
+//!
+//! ```
+//! use pulldown_cmark::{Event, Parser};
+//!
+//! let events: Vec<_> = emojis::markdown::expand_shortcodes(Parser::new("Ship it :rocket:"))
+//!     .filter_map(|event| match event {
+//!         Event::Text(text) => Some(text.into_string()),
+//!         _ => None,
+//!     })
+//!     .collect();
+//! assert_eq!(events, ["Ship it 🚀"]);
+//! ```
+
+use alloc::string::String;
+
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd, TextMergeStream};
+
+use crate::shortcodes::{Builtin, ShortcodeResolver};
+
+/// Returns an iterator over the events with the GitHub shortcodes in text
+/// events replaced with their emojis.
+///
+/// Consecutive text events are merged first, since the parser can split a
+/// shortcode over several of them. Text in code blocks is not changed.
+///
+/// # Examples
+///
+/// ```
+/// use pulldown_cmark::{Event, Parser};
+///
+/// let markdown = "_:tada:_ `:tada:`";
+/// let events: Vec<_> = emojis::markdown::expand_shortcodes(Parser::new(markdown)).collect();
+/// assert!(events.contains(&Event::Text("🎉".into())));
+/// assert!(events.contains(&Event::Code(":tada:".into())));
+/// ```
+pub fn expand_shortcodes<'a, I>(events: I) -> impl Iterator<Item = Event<'a>>
+where
+    I: Iterator<Item = Event<'a>>,
+{
+    expand_shortcodes_with(events, Builtin)
+}
+
+/// Returns an iterator over the events with the shortcodes in text events
+/// replaced using a custom resolver.
+///
+/// See [`expand_shortcodes()`] and [`ShortcodeResolver`].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::shortcodes::{Builtin, ShortcodeResolver};
+/// use pulldown_cmark::{Event, Parser};
+///
+/// struct Aliases;
+///
+/// impl ShortcodeResolver for Aliases {
+///     fn resolve(&self, name: &str) -> Option<&str> {
+///         (name == "ship_it").then(|| "🚀")
+///     }
+/// }
+///
+/// let parser = Parser::new(":ship_it: :tada:");
+/// let events: Vec<_> = emojis::markdown::expand_shortcodes_with(parser, (Aliases, Builtin)).collect();
+/// assert!(events.contains(&Event::Text("🚀 🎉".into())));
+/// ```
+pub fn expand_shortcodes_with<'a, I, R>(events: I, resolver: R) -> impl Iterator<Item = Event<'a>>
+where
+    I: Iterator<Item = Event<'a>>,
+    R: ShortcodeResolver,
+{
+    let mut in_code_block = false;
+    TextMergeStream::new(events).map(move |event| match event {
+        Event::Start(Tag::CodeBlock(_)) => {
+            in_code_block = true;
+            event
+        }
+        Event::End(TagEnd::CodeBlock) => {
+            in_code_block = false;
+            event
+        }
+        Event::Text(text) if !in_code_block && text.contains(':') => {
+            Event::Text(expand(text, &resolver))
+        }
+        event => event,
+    })
+}
+
+fn expand<'a, R: ShortcodeResolver>(text: CowStr<'a>, resolver: &R) -> CowStr<'a> {
+    let mut out = String::new();
+    // Writing to a `String` never fails.
+    crate::expand_shortcodes_with(&text, resolver, &mut out).unwrap();
+    if out == *text {
+        text
+    } else {
+        out.into()
+    }
+}
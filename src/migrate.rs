@@ -0,0 +1,60 @@
+//! Migrate data stored using a previous release of the emoji data.
+//!
+//! When the Unicode version is upgraded some emojis may be renamed or have
+//! their fully qualified encoding changed. These functions resolve names and
+//! encodings from the previous Unicode version supported by this crate to the
+//! current emojis. Anything that did not change is resolved as normal, but
+//! emojis introduced after the previous version are not resolved. When this
+//! crate is built with the oldest supported Unicode version nothing changed,
+//! so every current emoji is resolved.
+//!
+//! # Examples
+//!
+//! ```
+//! // Stored with a previous release.
+//! let rocket = emojis::migrate::name("rocket").unwrap();
+//! assert_eq!(rocket, "🚀");
+//! ```
+
+use crate::gen::migrate::{ENCODINGS, NAMES, PREVIOUS};
+use crate::gen::EMOJIS;
+use crate::Emoji;
+
+/// Returns whether the emoji existed in the previous release.
+fn is_previous(emoji: &&Emoji) -> bool {
+    emoji.unicode_version() <= PREVIOUS
+}
+
+fn lookup(table: &[(&str, usize)], key: &str) -> Option<&'static Emoji> {
+    let i = table.binary_search_by_key(&key, |&(k, _)| k).ok()?;
+    Some(&EMOJIS[table[i].1])
+}
+
+/// Lookup an emoji by its CLDR name in the previous release.
+///
+/// Returns `None` if there was no emoji with this name.
+///
+/// # Examples
+///
+/// ```
+/// let grinning = emojis::migrate::name("grinning face").unwrap();
+/// assert_eq!(grinning, "😀");
+/// assert!(emojis::migrate::name("not an emoji").is_none());
+/// ```
+pub fn name(old: &str) -> Option<&'static Emoji> {
+    lookup(NAMES, old).or_else(|| EMOJIS.iter().filter(is_previous).find(|e| e.name() == old))
+}
+
+/// Lookup an emoji by its encoding in the previous release.
+///
+/// Returns `None` if there was no emoji with this encoding.
+///
+/// # Examples
+///
+/// ```
+/// let rocket = emojis::migrate::emoji("🚀").unwrap();
+/// assert_eq!(rocket, "🚀");
+/// ```
+pub fn emoji(old: &str) -> Option<&'static Emoji> {
+    lookup(ENCODINGS, old).or_else(|| crate::get(old).filter(is_previous))
+}
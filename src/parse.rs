@@ -0,0 +1,158 @@
+//! Parse emojis from the start of some input.
+//!
+//! On success these functions return the remaining input followed by the
+//! parsed emoji, so they can be embedded in a hand-written parser. They return
+//! this crate's [`Error`] rather than a [nom] `IResult`. To use them with nom
+//! combinators, enable the `nom` feature and use the parsers in the
+//! `parse::nom` module instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::parse;
+//!
+//! let (rest, emoji) = parse::emoji("🚀 launch").unwrap();
+//! assert_eq!(emoji, "🚀");
+//! assert_eq!(rest, " launch");
+//!
+//! let (rest, emoji) = parse::shortcode(":rocket: launch").unwrap();
+//! assert_eq!(emoji, "🚀");
+//! assert_eq!(rest, " launch");
+//! ```
+//!
+//! [nom]: https://docs.rs/nom
+
+use crate::{Emoji, Error};
+
+/// Parse the longest emoji at the start of the input.
+///
+/// ZWJ sequences are matched as a whole rather than as their components, the
+/// same as [`EmojiStrExt::emojis()`][crate::EmojiStrExt::emojis].
+///
+/// # Errors
+///
+/// Returns [`Error::NotAnEmoji`] if the input does not start with an emoji.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{parse, Error};
+///
+/// let (rest, emoji) = parse::emoji("👩‍🚀!").unwrap();
+/// assert_eq!(emoji, "👩‍🚀");
+/// assert_eq!(rest, "!");
+///
+/// assert_eq!(parse::emoji("launch 🚀"), Err(Error::NotAnEmoji));
+/// ```
+pub fn emoji(input: &str) -> Result<(&str, &'static Emoji), Error> {
+    let (len, emoji) = crate::text::longest_match(input).ok_or(Error::NotAnEmoji)?;
+    Ok((&input[len..], emoji))
+}
+
+/// Parse a `:shortcode:` at the start of the input.
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownShortcode`] if the input does not start with a
+/// known GitHub shortcode.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{parse, Error};
+///
+/// let (rest, emoji) = parse::shortcode(":+1:!").unwrap();
+/// assert_eq!(emoji, "👍");
+/// assert_eq!(rest, "!");
+///
+/// assert_eq!(parse::shortcode(":custom:"), Err(Error::UnknownShortcode));
+/// assert_eq!(parse::shortcode("rocket"), Err(Error::UnknownShortcode));
+/// ```
+pub fn shortcode(input: &str) -> Result<(&str, &'static Emoji), Error> {
+    let (len, name) = crate::text::shortcode_at(input).ok_or(Error::UnknownShortcode)?;
+    let emoji = crate::get_by_shortcode(name).ok_or(Error::UnknownShortcode)?;
+    Ok((&input[len..], emoji))
+}
+
+/// Parse an emoji or a `:shortcode:` at the start of the input.
+///
+/// # Errors
+///
+/// Returns [`Error::NotAnEmoji`] if the input starts with neither.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::parse;
+///
+/// assert_eq!(parse::emoji_or_shortcode(":rocket:").unwrap().1, "🚀");
+/// assert_eq!(parse::emoji_or_shortcode("🚀").unwrap().1, "🚀");
+/// ```
+pub fn emoji_or_shortcode(input: &str) -> Result<(&str, &'static Emoji), Error> {
+    shortcode(input)
+        .or_else(|_| emoji(input))
+        .map_err(|_| Error::NotAnEmoji)
+}
+
+/// Parsers that can be used with [nom] combinators.
+///
+/// These are the same as the parsers in the parent module but return an
+/// [`IResult`][::nom::IResult] with any [`ParseError`][::nom::error::ParseError].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::parse::nom::emoji_or_shortcode;
+/// use nom::bytes::complete::tag;
+/// use nom::sequence::separated_pair;
+/// use nom::{IResult, Parser};
+///
+/// let mut pair = separated_pair(emoji_or_shortcode, tag(","), emoji_or_shortcode);
+/// let result: IResult<_, _> = pair.parse("🚀,:tada:!");
+/// let (rest, (first, second)) = result.unwrap();
+/// assert_eq!((first.as_str(), second.as_str()), ("🚀", "🎉"));
+/// assert_eq!(rest, "!");
+/// ```
+///
+/// [nom]: https://docs.rs/nom
+#[cfg(feature = "nom")]
+pub mod nom {
+    use ::nom::error::{ErrorKind, ParseError};
+    use ::nom::{Err, IResult};
+
+    use crate::Emoji;
+
+    fn adapt<'a, E: ParseError<&'a str>>(
+        input: &'a str,
+        result: Result<(&'a str, &'static Emoji), crate::Error>,
+    ) -> IResult<&'a str, &'static Emoji, E> {
+        result.map_err(|_| Err::Error(E::from_error_kind(input, ErrorKind::Tag)))
+    }
+
+    /// Parse the longest emoji at the start of the input.
+    ///
+    /// See [`parse::emoji()`][super::emoji].
+    pub fn emoji<'a, E: ParseError<&'a str>>(
+        input: &'a str,
+    ) -> IResult<&'a str, &'static Emoji, E> {
+        adapt(input, super::emoji(input))
+    }
+
+    /// Parse a `:shortcode:` at the start of the input.
+    ///
+    /// See [`parse::shortcode()`][super::shortcode].
+    pub fn shortcode<'a, E: ParseError<&'a str>>(
+        input: &'a str,
+    ) -> IResult<&'a str, &'static Emoji, E> {
+        adapt(input, super::shortcode(input))
+    }
+
+    /// Parse an emoji or a `:shortcode:` at the start of the input.
+    ///
+    /// See [`parse::emoji_or_shortcode()`][super::emoji_or_shortcode].
+    pub fn emoji_or_shortcode<'a, E: ParseError<&'a str>>(
+        input: &'a str,
+    ) -> IResult<&'a str, &'static Emoji, E> {
+        adapt(input, super::emoji_or_shortcode(input))
+    }
+}
@@ -0,0 +1,218 @@
+//! Compose RGI person emojis.
+
+use crate::components::{VS16, ZWJ};
+use crate::{Emoji, SkinTone};
+
+/// The gender of a person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    /// A gender-neutral person.
+    Neutral,
+    /// A man.
+    Man,
+    /// A woman.
+    Woman,
+}
+
+impl Default for Gender {
+    #[inline]
+    fn default() -> Self {
+        Self::Neutral
+    }
+}
+
+/// A role or profession of a person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Role {
+    HealthWorker,
+    Student,
+    Teacher,
+    Judge,
+    Farmer,
+    Cook,
+    Mechanic,
+    FactoryWorker,
+    OfficeWorker,
+    Scientist,
+    Technologist,
+    Singer,
+    Artist,
+    Pilot,
+    Astronaut,
+    Firefighter,
+    FeedingBaby,
+    PoliceOfficer,
+    Detective,
+    Guard,
+    ConstructionWorker,
+    WearingTurban,
+    InTuxedo,
+    WithVeil,
+}
+
+/// How a role is combined with a person.
+enum Sequence {
+    /// A person followed by a ZWJ and an object, e.g. 🧑‍🚀.
+    Object(&'static str),
+    /// A role character optionally followed by a ZWJ and a gender sign, e.g.
+    /// 👮‍♀️. The flag is set if the character needs an emoji presentation
+    /// selector.
+    Sign(char, bool),
+}
+
+impl Role {
+    fn sequence(self) -> Sequence {
+        use Sequence::*;
+        match self {
+            Self::HealthWorker => Object("⚕\u{fe0f}"),
+            Self::Student => Object("🎓"),
+            Self::Teacher => Object("🏫"),
+            Self::Judge => Object("⚖\u{fe0f}"),
+            Self::Farmer => Object("🌾"),
+            Self::Cook => Object("🍳"),
+            Self::Mechanic => Object("🔧"),
+            Self::FactoryWorker => Object("🏭"),
+            Self::OfficeWorker => Object("💼"),
+            Self::Scientist => Object("🔬"),
+            Self::Technologist => Object("💻"),
+            Self::Singer => Object("🎤"),
+            Self::Artist => Object("🎨"),
+            Self::Pilot => Object("✈\u{fe0f}"),
+            Self::Astronaut => Object("🚀"),
+            Self::Firefighter => Object("🚒"),
+            Self::FeedingBaby => Object("🍼"),
+            Self::PoliceOfficer => Sign('👮', false),
+            Self::Detective => Sign('🕵', true),
+            Self::Guard => Sign('💂', false),
+            Self::ConstructionWorker => Sign('👷', false),
+            Self::WearingTurban => Sign('👳', false),
+            Self::InTuxedo => Sign('🤵', false),
+            Self::WithVeil => Sign('👰', false),
+        }
+    }
+}
+
+/// A builder for the RGI person emojis.
+///
+/// Set the gender, skin tone, and role of the person, then use
+/// [`build()`][Person::build] to lookup the emoji.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{Gender, Person, Role, SkinTone};
+///
+/// let astronaut = Person::new()
+///     .gender(Gender::Woman)
+///     .skin_tone(SkinTone::Medium)
+///     .role(Role::Astronaut)
+///     .build()
+///     .unwrap();
+/// assert_eq!(astronaut, "👩🏽‍🚀");
+///
+/// let detective = Person::new().role(Role::Detective).build().unwrap();
+/// assert_eq!(detective, "🕵️");
+///
+/// let person = Person::new().gender(Gender::Man).build().unwrap();
+/// assert_eq!(person, "👨");
+/// ```
+///
+/// Combinations that are not standard return `None`.
+///
+/// ```
+/// use emojis::{Person, SkinTone};
+///
+/// let person = Person::new().skin_tone(SkinTone::LightAndDark);
+/// assert!(person.build().is_none());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Person {
+    gender: Gender,
+    skin_tone: Option<SkinTone>,
+    role: Option<Role>,
+}
+
+impl Person {
+    /// Returns a new gender-neutral person with the default skin tone.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the gender of the person.
+    #[inline]
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.gender = gender;
+        self
+    }
+
+    /// Sets the skin tone of the person.
+    #[inline]
+    pub fn skin_tone(mut self, skin_tone: SkinTone) -> Self {
+        self.skin_tone = Some(skin_tone);
+        self
+    }
+
+    /// Sets the role of the person.
+    #[inline]
+    pub fn role(mut self, role: Role) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Builds the person emoji.
+    ///
+    /// Returns `None` if this combination is not an RGI emoji.
+    pub fn build(&self) -> Option<&'static Emoji> {
+        let modifier = match self.skin_tone {
+            None | Some(SkinTone::Default) => None,
+            Some(SkinTone::Light) => Some('\u{1f3fb}'),
+            Some(SkinTone::MediumLight) => Some('\u{1f3fc}'),
+            Some(SkinTone::Medium) => Some('\u{1f3fd}'),
+            Some(SkinTone::MediumDark) => Some('\u{1f3fe}'),
+            Some(SkinTone::Dark) => Some('\u{1f3ff}'),
+            Some(_) => return None,
+        };
+        let person = match self.gender {
+            Gender::Neutral => '🧑',
+            Gender::Man => '👨',
+            Gender::Woman => '👩',
+        };
+
+        let mut buf = crate::base::Buf::new();
+        match self.role.map(Role::sequence) {
+            None => {
+                buf.push(person)?;
+                buf.push_opt(modifier)?;
+            }
+            Some(Sequence::Object(object)) => {
+                buf.push(person)?;
+                buf.push_opt(modifier)?;
+                buf.push(ZWJ)?;
+                for c in object.chars() {
+                    buf.push(c)?;
+                }
+            }
+            Some(Sequence::Sign(c, vs16)) => {
+                buf.push(c)?;
+                match modifier {
+                    Some(m) => buf.push(m)?,
+                    None if vs16 => buf.push(VS16)?,
+                    None => {}
+                }
+                let sign = match self.gender {
+                    Gender::Neutral => None,
+                    Gender::Man => Some('♂'),
+                    Gender::Woman => Some('♀'),
+                };
+                if let Some(sign) = sign {
+                    buf.push(ZWJ)?;
+                    buf.push(sign)?;
+                    buf.push(VS16)?;
+                }
+            }
+        }
+        buf.get()
+    }
+}
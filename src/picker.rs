@@ -0,0 +1,193 @@
+//! Lay out emojis in a grid for an emoji picker.
+//!
+//! Emojis are laid out in Unicode CLDR order in rows of a fixed number of
+//! columns. Each row contains emojis from a single subgroup, so every subgroup
+//! starts on a new row, and pages never span more than one group. Only the
+//! default skin tone emojis are included, use
+//! [`Emoji::skin_tones()`][crate::Emoji::skin_tones] to offer the other skin
+//! tones.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::{picker, Group};
+//!
+//! let mut rows = picker::rows(8);
+//! let row = rows.next().unwrap();
+//! assert_eq!(row.group(), Group::SmileysAndEmotion);
+//! assert_eq!(row.subgroup(), "face-smiling");
+//! let emojis: String = row.emojis().map(|e| e.as_str()).collect();
+//! assert_eq!(emojis, "😀😃😄😁😆😅🤣😂");
+//!
+//! for page in picker::pages(8, 4) {
+//!     assert!(page.rows().count() <= 4);
+//!     assert!(page.rows().all(|row| row.group() == page.group()));
+//! }
+//! ```
+
+use crate::gen::{EMOJIS, SUBGROUPS};
+use crate::{Emoji, Group, SkinTone};
+
+fn is_default(emoji: &Emoji) -> bool {
+    matches!(emoji.skin_tone(), None | Some(SkinTone::Default))
+}
+
+/// A row of emojis from a single subgroup.
+#[derive(Debug, Clone)]
+pub struct Row {
+    subgroup: usize,
+    start: usize,
+    end: usize,
+}
+
+impl Row {
+    /// Returns the group of the emojis in this row.
+    #[inline]
+    pub fn group(&self) -> Group {
+        SUBGROUPS[self.subgroup].0
+    }
+
+    /// Returns the CLDR name of the subgroup of the emojis in this row, e.g.
+    /// "face-smiling".
+    #[inline]
+    pub fn subgroup(&self) -> &'static str {
+        SUBGROUPS[self.subgroup].1
+    }
+
+    /// Returns whether this is the first row of its subgroup.
+    #[inline]
+    pub fn is_subgroup_start(&self) -> bool {
+        self.start == SUBGROUPS[self.subgroup].2.index()
+    }
+
+    /// Returns an iterator over the emojis in this row.
+    #[inline]
+    pub fn emojis(&self) -> impl Iterator<Item = &'static Emoji> {
+        EMOJIS[self.start..self.end]
+            .iter()
+            .filter(|e| is_default(e))
+    }
+}
+
+/// An iterator over the rows of a picker.
+///
+/// This struct is created by [`rows()`].
+#[derive(Debug, Clone)]
+pub struct Rows {
+    columns: usize,
+    subgroup: usize,
+    pos: usize,
+}
+
+/// Returns an iterator over the rows of a picker with the given number of
+/// columns.
+///
+/// # Panics
+///
+/// If `columns` is zero.
+pub fn rows(columns: usize) -> Rows {
+    assert!(columns > 0, "a picker must have at least one column");
+    Rows {
+        columns,
+        subgroup: 0,
+        pos: 0,
+    }
+}
+
+impl Iterator for Rows {
+    type Item = Row;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, _, start, len) = *SUBGROUPS.get(self.subgroup)?;
+        let subgroup_end = start.index() + len as usize;
+        let start = self.pos;
+        let mut count = 0;
+        let mut end = start;
+        while end < subgroup_end {
+            if is_default(&EMOJIS[end]) {
+                if count == self.columns {
+                    break;
+                }
+                count += 1;
+            }
+            end += 1;
+        }
+        let row = Row {
+            subgroup: self.subgroup,
+            start,
+            end,
+        };
+        self.pos = end;
+        if end == subgroup_end {
+            self.subgroup += 1;
+        }
+        Some(row)
+    }
+}
+
+/// A page of rows from a single group.
+#[derive(Debug, Clone)]
+pub struct Page {
+    rows: Rows,
+    len: usize,
+}
+
+impl Page {
+    /// Returns the group of the emojis in this page.
+    #[inline]
+    pub fn group(&self) -> Group {
+        SUBGROUPS[self.rows.subgroup].0
+    }
+
+    /// Returns an iterator over the rows in this page.
+    #[inline]
+    pub fn rows(&self) -> impl Iterator<Item = Row> {
+        self.rows.clone().take(self.len)
+    }
+}
+
+/// An iterator over the pages of a picker.
+///
+/// This struct is created by [`pages()`].
+#[derive(Debug, Clone)]
+pub struct Pages {
+    rows: Rows,
+    rows_per_page: usize,
+}
+
+/// Returns an iterator over the pages of a picker with the given number of
+/// columns and rows per page.
+///
+/// A new page is started for each group, so the last page of a group may have
+/// fewer rows.
+///
+/// # Panics
+///
+/// If `columns` or `rows_per_page` is zero.
+pub fn pages(columns: usize, rows_per_page: usize) -> Pages {
+    assert!(rows_per_page > 0, "a page must have at least one row");
+    Pages {
+        rows: rows(columns),
+        rows_per_page,
+    }
+}
+
+impl Iterator for Pages {
+    type Item = Page;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page = self.rows.clone();
+        let group = self.rows.clone().next()?.group();
+        let mut len = 0;
+        while len < self.rows_per_page {
+            match self.rows.clone().next() {
+                Some(row) if row.group() == group => {
+                    self.rows.next();
+                    len += 1;
+                }
+                _ => break,
+            }
+        }
+        Some(Page { rows: page, len })
+    }
+}
@@ -0,0 +1,22 @@
+//! Curated sets of emojis that are commonly used together.
+//!
+//! The presets are generated along with the rest of the emoji tables, so
+//! every product using the same release of this crate agrees on them. They
+//! may be extended in a future release if the data changes, but existing
+//! emojis are never reordered.
+//!
+//! # Examples
+//!
+//! ```
+//! let reactions: String = emojis::presets::REACTIONS.iter().map(|e| e.as_str()).collect();
+//! assert_eq!(reactions, "👍❤️😂😮😢😡");
+//! ```
+
+/// The standard six message reactions: 👍 ❤️ 😂 😮 😢 😡.
+pub use crate::gen::presets::REACTIONS;
+
+/// Emojis commonly used to show the status of a task: ✅ ❌ ⚠️ ⏳ 🚧 ℹ️.
+///
+/// These mean done, failed, warning, in progress, under construction, and
+/// information.
+pub use crate::gen::presets::STATUS;
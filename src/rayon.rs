@@ -0,0 +1,89 @@
+//! Parallel iteration using [`rayon`].
+//!
+//! [`par_iter()`] is the parallel version of [`emojis::iter()`][crate::iter()],
+//! and [`par_emojis()`] finds the emojis in large documents using multiple
+//! threads.
+//!
+//! # Examples
+//!
+//! ```
+//! use rayon::prelude::*;
+//!
+//! let count = emojis::rayon::par_iter().filter(|e| e.name().contains("face")).count();
+//! assert_eq!(count, emojis::iter().filter(|e| e.name().contains("face")).count());
+//! ```
+
+use core::ops::Range;
+
+use ::rayon::iter::{self, IntoParallelRefIterator, ParallelIterator};
+
+use crate::{Emoji, SkinTone};
+
+/// Texts shorter than this are scanned on a single thread.
+const MIN_SPLIT_LEN: usize = 16 * 1024;
+
+/// Returns a parallel iterator over all emojis.
+///
+/// - Excludes non-default skin tones.
+/// - Collecting the iterator keeps the Unicode CLDR order of
+///   [`emojis::iter()`][crate::iter()].
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+///
+/// let emojis: Vec<_> = emojis::rayon::par_iter().collect();
+/// assert!(emojis.iter().copied().eq(emojis::iter()));
+/// ```
+pub fn par_iter() -> impl ParallelIterator<Item = &'static Emoji> {
+    crate::gen::EMOJIS
+        .par_iter()
+        .filter(|emoji| matches!(emoji.skin_tone(), Some(SkinTone::Default) | None))
+}
+
+/// Returns a parallel iterator over the emojis in some text and their byte
+/// ranges.
+///
+/// The emojis are matched the same way as
+/// [`EmojiStrExt::emojis()`][crate::EmojiStrExt::emojis]. The text is split
+/// after whitespace, which never occurs in an emoji, so each part can be
+/// scanned independently. Collecting the iterator keeps the order of the
+/// emojis in the text.
+///
+/// # Examples
+///
+/// ```
+/// use rayon::prelude::*;
+///
+/// let text = "🚀 launch 👩🏽‍🚀\n".repeat(10_000);
+/// let found: Vec<_> = emojis::rayon::par_emojis(&text).collect();
+/// assert_eq!(found.len(), 20_000);
+/// assert_eq!(found[1].0, 12..27);
+/// assert_eq!(found[1].1, "👩🏽‍🚀");
+/// ```
+pub fn par_emojis(text: &str) -> impl ParallelIterator<Item = (Range<usize>, &'static Emoji)> + '_ {
+    iter::split((0, text), split).flat_map_iter(|(offset, part)| {
+        crate::text::matches(part)
+            .map(move |(range, emoji)| (offset + range.start..offset + range.end, emoji))
+    })
+}
+
+/// Splits the text after the first whitespace following its middle.
+fn split((offset, text): (usize, &str)) -> ((usize, &str), Option<(usize, &str)>) {
+    if text.len() < MIN_SPLIT_LEN {
+        return ((offset, text), None);
+    }
+    let mut mid = text.len() / 2;
+    while !text.is_char_boundary(mid) {
+        mid += 1;
+    }
+    match text[mid..].find(char::is_whitespace) {
+        Some(i) => {
+            let end = mid + i + text[mid + i..].chars().next().unwrap().len_utf8();
+            let (left, right) = text.split_at(end);
+            ((offset, left), Some((offset + end, right)))
+        }
+        None => ((offset, text), None),
+    }
+}
@@ -0,0 +1,78 @@
+//! Classify grapheme clusters from the [`unicode_segmentation`] crate.
+//!
+//! Applications that already split text into extended grapheme clusters can
+//! use these helpers to find the emojis in the same clusters, instead of
+//! mixing two segmentation models. Each cluster is resolved using
+//! [`get_closest()`][crate::get_closest], so sequences that are not part of
+//! the RGI set are still recognized.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::Closest;
+//!
+//! let text = "hi 👋🏽!";
+//! let emojis: Vec<_> = emojis::segmentation::graphemes(text)
+//!     .filter_map(|(_, closest)| match closest? {
+//!         Closest::Exact(emoji) => Some(emoji.as_str()),
+//!         _ => None,
+//!     })
+//!     .collect();
+//! assert_eq!(emojis, ["👋🏽"]);
+//! ```
+
+use core::ops::Range;
+
+use unicode_segmentation::{GraphemeCursor, UnicodeSegmentation};
+
+use crate::Closest;
+
+/// Returns an iterator over the extended grapheme clusters in the text and
+/// the closest emoji for each cluster, if any.
+///
+/// # Examples
+///
+/// ```
+/// let clusters: Vec<_> = emojis::segmentation::graphemes("a🇳🇿")
+///     .map(|(g, closest)| (g, closest.is_some()))
+///     .collect();
+/// assert_eq!(clusters, [("a", false), ("🇳🇿", true)]);
+/// ```
+pub fn graphemes(text: &str) -> impl Iterator<Item = (&str, Option<Closest<'_>>)> {
+    text.graphemes(true).map(|g| (g, crate::get_closest(g)))
+}
+
+/// Returns the byte range of the extended grapheme cluster containing the
+/// byte offset and its closest emoji.
+///
+/// This is useful for finding the emoji under a text cursor. Returns `None` if
+/// the offset is not a character boundary inside the text, or if the cluster
+/// is not an emoji.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Closest;
+///
+/// let text = "go 👩‍🚀 now";
+/// let (range, closest) = emojis::segmentation::emoji_at(text, 7).unwrap();
+/// assert_eq!(&text[range], "👩‍🚀");
+/// assert!(matches!(closest, Closest::Exact(e) if e == "👩‍🚀"));
+///
+/// assert!(emojis::segmentation::emoji_at(text, 0).is_none());
+/// ```
+pub fn emoji_at(text: &str, offset: usize) -> Option<(Range<usize>, Closest<'_>)> {
+    if offset >= text.len() || !text.is_char_boundary(offset) {
+        return None;
+    }
+    let mut cursor = GraphemeCursor::new(offset, text.len(), true);
+    let start = if cursor.is_boundary(text, 0).ok()? {
+        offset
+    } else {
+        cursor.prev_boundary(text, 0).ok()??
+    };
+    let mut cursor = GraphemeCursor::new(start, text.len(), true);
+    let end = cursor.next_boundary(text, 0).ok()??;
+    let closest = crate::get_closest(&text[start..end])?;
+    Some((start..end, closest))
+}
@@ -0,0 +1,127 @@
+//! Helpers for using emojis in [`serde`] data structures.
+//!
+//! These modules are intended to be used with serde's `with` attribute on
+//! `&'static Emoji` fields. Use [`as_unicode`] to represent an emoji using the
+//! emoji itself, e.g. `"🚀"`, or [`as_shortcode`] to represent it using its
+//! GitHub shortcode, e.g. `":rocket:"`.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::Emoji;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Config {
+//!     #[serde(with = "emojis::serde::as_shortcode")]
+//!     success: &'static Emoji,
+//!     #[serde(with = "emojis::serde::as_unicode")]
+//!     failure: &'static Emoji,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"success":":rocket:","failure":"💥"}"#).unwrap();
+//! assert_eq!(config.success, "🚀");
+//! assert_eq!(config.failure, "💥");
+//! ```
+
+use core::fmt;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::ser::{self, Serializer};
+
+use crate::Emoji;
+
+/// (De)serialize an emoji as the emoji itself, e.g. `"🚀"`.
+///
+/// Deserializing accepts minimally qualified and unqualified emojis, see
+/// [`get()`][crate::get].
+pub mod as_unicode {
+    use super::*;
+
+    /// Serialize an emoji as the emoji itself.
+    pub fn serialize<S>(emoji: &&'static Emoji, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(emoji.as_str())
+    }
+
+    /// Deserialize an emoji from the emoji itself.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<&'static Emoji, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct UnicodeVisitor;
+
+        impl<'de> Visitor<'de> for UnicodeVisitor {
+            type Value = &'static Emoji;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string representing an emoji")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                crate::get(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(UnicodeVisitor)
+    }
+}
+
+/// (De)serialize an emoji as its GitHub shortcode, e.g. `":rocket:"`.
+///
+/// Serializing uses the first shortcode, see
+/// [`Emoji::shortcode()`][crate::Emoji::shortcode], and fails for emojis that
+/// do not have a shortcode. Deserializing accepts any of the emoji's
+/// shortcodes, with or without the surrounding colons.
+pub mod as_shortcode {
+    use super::*;
+
+    /// Serialize an emoji as its GitHub shortcode.
+    pub fn serialize<S>(emoji: &&'static Emoji, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match emoji.shortcode() {
+            Some(shortcode) => serializer.collect_str(&format_args!(":{shortcode}:")),
+            None => Err(ser::Error::custom(format_args!(
+                "emoji `{}` does not have a shortcode",
+                emoji.as_str()
+            ))),
+        }
+    }
+
+    /// Deserialize an emoji from its GitHub shortcode.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<&'static Emoji, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ShortcodeVisitor;
+
+        impl<'de> Visitor<'de> for ShortcodeVisitor {
+            type Value = &'static Emoji;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string representing an emoji shortcode")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let shortcode = value
+                    .strip_prefix(':')
+                    .and_then(|s| s.strip_suffix(':'))
+                    .unwrap_or(value);
+                crate::get_by_shortcode(shortcode)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(ShortcodeVisitor)
+    }
+}
@@ -0,0 +1,103 @@
+//! Work with GitHub shortcodes.
+
+use crate::Emoji;
+
+/// Returns an iterator over every GitHub shortcode and its emoji.
+///
+/// The shortcodes are ordered lexicographically. Emojis with more than one
+/// shortcode are yielded once for each shortcode.
+///
+/// # Examples
+///
+/// ```
+/// let (shortcode, emoji) = emojis::shortcodes::iter()
+///     .find(|(shortcode, _)| *shortcode == "rocket")
+///     .unwrap();
+/// assert_eq!(emoji, "🚀");
+/// ```
+#[inline]
+pub fn iter() -> impl Iterator<Item = (&'static str, &'static Emoji)> {
+    crate::gen::shortcode::SORTED
+        .iter()
+        .map(|&(shortcode, i)| (shortcode, &crate::gen::EMOJIS[i as usize]))
+}
+
+/// Resolve a shortcode name to its emoji string.
+///
+/// This has the signature expected by Markdown renderers that accept a
+/// shortcode callback. To rewrite the text events of a [`pulldown_cmark`]
+/// parser instead, enable the `pulldown-cmark` feature and use
+/// `emojis::markdown::expand_shortcodes()`.
+///
+/// [`pulldown_cmark`]: https://docs.rs/pulldown-cmark
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::shortcodes::resolve("rocket"), Some("🚀"));
+/// assert_eq!(emojis::shortcodes::resolve("custom"), None);
+///
+/// let resolver: fn(&str) -> Option<&'static str> = emojis::shortcodes::resolve;
+/// ```
+#[inline]
+pub fn resolve(name: &str) -> Option<&'static str> {
+    crate::get_by_shortcode(name).map(Emoji::as_str)
+}
+
+/// Resolves shortcode names to their replacement text.
+///
+/// This is used by [`expand_shortcodes_with()`][crate::expand_shortcodes_with]
+/// and [`tokenize_with()`][crate::tokenize_with] to support custom emojis or
+/// aliases on top of, or instead of, the GitHub shortcodes. A pair of resolvers
+/// tries the first resolver and then falls back to the second.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::shortcodes::{Builtin, ShortcodeResolver};
+///
+/// struct Aliases;
+///
+/// impl ShortcodeResolver for Aliases {
+///     fn resolve(&self, name: &str) -> Option<&str> {
+///         match name {
+///             "ship_it" => Some("🚀"),
+///             "party_parrot" => Some("🦜"),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let mut out = String::new();
+/// emojis::expand_shortcodes_with(":ship_it: :tada:", &(Aliases, Builtin), &mut out).unwrap();
+/// assert_eq!(out, "🚀 🎉");
+/// ```
+pub trait ShortcodeResolver {
+    /// Returns the replacement text for the shortcode name, or `None` if the
+    /// name is not known.
+    fn resolve(&self, name: &str) -> Option<&str>;
+}
+
+/// The default [`ShortcodeResolver`] which resolves GitHub shortcodes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Builtin;
+
+impl ShortcodeResolver for Builtin {
+    #[inline]
+    fn resolve(&self, name: &str) -> Option<&str> {
+        resolve(name)
+    }
+}
+
+impl<R: ShortcodeResolver + ?Sized> ShortcodeResolver for &R {
+    #[inline]
+    fn resolve(&self, name: &str) -> Option<&str> {
+        (**self).resolve(name)
+    }
+}
+
+impl<A: ShortcodeResolver, B: ShortcodeResolver> ShortcodeResolver for (A, B) {
+    fn resolve(&self, name: &str) -> Option<&str> {
+        self.0.resolve(name).or_else(|| self.1.resolve(name))
+    }
+}
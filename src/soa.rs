@@ -0,0 +1,32 @@
+//! The emoji tables as a struct of arrays.
+//!
+//! Each slice has one entry per emoji, in the same order as [`iter()`] with
+//! all skin tones included, i.e. the index of an emoji in each slice is its
+//! [`cldr_order()`][crate::Emoji::cldr_order]. Scanning a single property this
+//! way only touches the data for that property.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::{soa, Group};
+//!
+//! let flags = soa::GROUPS.iter().filter(|&&g| g == Group::Flags).count();
+//! assert_eq!(flags, Group::Flags.len());
+//!
+//! let i = soa::AS_STR.iter().position(|&s| s == "🚀").unwrap();
+//! assert_eq!(soa::NAMES[i], "rocket");
+//! ```
+//!
+//! [`iter()`]: crate::iter
+
+/// The string of each emoji.
+pub use crate::gen::soa::AS_STR;
+
+/// The CLDR name of each emoji.
+pub use crate::gen::soa::NAMES;
+
+/// The group of each emoji.
+pub use crate::gen::soa::GROUPS;
+
+/// The Unicode version each emoji first appeared in.
+pub use crate::gen::soa::UNICODE_VERSIONS;
@@ -0,0 +1,175 @@
+//! Replace emojis and shortcodes in streams.
+
+use alloc::vec::Vec;
+use core::str;
+use std::io;
+
+use crate::shortcodes::Builtin;
+use crate::text::{matches, replace_shortcodes_with};
+use crate::{Emoji, Qualification};
+
+/// The size of the buffer used to read from the reader.
+const BUF_SIZE: usize = 8 * 1024;
+
+/// The conversion performed by [`replace_stream()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReplaceMode {
+    /// Replace `:shortcode:` with the emoji, e.g. `:rocket:` becomes 🚀.
+    ///
+    /// Unknown shortcodes are left as is.
+    ShortcodeToEmoji,
+    /// Replace emojis with `:shortcode:`, e.g. 🚀 becomes `:rocket:`.
+    ///
+    /// Emojis without a shortcode are left as is.
+    EmojiToShortcode,
+    /// Replace emojis with their [ASCII fallback][Emoji::ascii_fallback], e.g.
+    /// 🙂 becomes `:)`.
+    ///
+    /// Emojis without a fallback are replaced with `:shortcode:`, or left as
+    /// is if they do not have a shortcode either.
+    EmojiToAscii,
+    /// Rewrite emojis with the given qualification, e.g. ☺️ becomes ☺ with
+    /// [`Qualification::Unqualified`].
+    Qualify(Qualification),
+}
+
+/// Converts shortcodes to emojis or emojis to shortcodes while copying a
+/// reader to a writer.
+///
+/// The input is processed in small chunks, so this can be used on text of any
+/// size. Shortcodes and emojis that span chunk boundaries are handled
+/// correctly. Returns an error with [`io::ErrorKind::InvalidData`] if the
+/// input is not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::ReplaceMode;
+///
+/// let mut out = Vec::new();
+/// emojis::replace_stream(&b"launch :rocket:"[..], &mut out, ReplaceMode::ShortcodeToEmoji)?;
+/// assert_eq!(out, "launch 🚀".as_bytes());
+///
+/// let mut out = Vec::new();
+/// emojis::replace_stream("launch 🚀".as_bytes(), &mut out, ReplaceMode::EmojiToShortcode)?;
+/// assert_eq!(out, b"launch :rocket:");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn replace_stream<R, W>(mut reader: R, mut writer: W, mode: ReplaceMode) -> io::Result<()>
+where
+    R: io::Read,
+    W: io::Write,
+{
+    let mut pending = Vec::with_capacity(BUF_SIZE);
+    let mut buf = [0; BUF_SIZE];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(n) => n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        let eof = n == 0;
+        pending.extend_from_slice(&buf[..n]);
+
+        // Only process complete characters, an incomplete one at the end is
+        // kept until the next read.
+        let valid = match str::from_utf8(&pending) {
+            Ok(s) => s.len(),
+            Err(err) if err.error_len().is_none() && !eof => err.valid_up_to(),
+            Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        };
+        let text = str::from_utf8(&pending[..valid])
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let consumed = match mode {
+            ReplaceMode::ShortcodeToEmoji => shortcodes_to_emojis(text, eof, &mut writer)?,
+            ReplaceMode::EmojiToShortcode => {
+                replace_emojis(text, eof, &mut writer, write_shortcode)?
+            }
+            ReplaceMode::EmojiToAscii => replace_emojis(text, eof, &mut writer, write_ascii)?,
+            ReplaceMode::Qualify(q) => replace_emojis(text, eof, &mut writer, |w, emoji| {
+                write!(w, "{}", emoji.qualified(q))
+            })?,
+        };
+        pending.drain(..consumed);
+
+        if eof {
+            return writer.flush();
+        }
+    }
+}
+
+/// Writes the text with shortcodes replaced, returning the number of bytes
+/// consumed.
+///
+/// Unless this is the end of the input, a trailing `:` followed by text that
+/// could still be a shortcode is not consumed.
+fn shortcodes_to_emojis<W: io::Write>(s: &str, eof: bool, w: &mut W) -> io::Result<usize> {
+    let rest = replace_shortcodes_with(s, Builtin, |s| w.write_all(s.as_bytes()))?;
+    if !eof && rest.len() <= crate::gen::shortcode::MAX_LEN + 1 {
+        return Ok(s.len() - rest.len());
+    }
+    w.write_all(rest.as_bytes())?;
+    Ok(s.len())
+}
+
+/// Writes an emoji as its shortcode, or as is if it does not have one.
+fn write_shortcode<W: io::Write>(w: &mut W, emoji: &Emoji) -> io::Result<()> {
+    match emoji.shortcode() {
+        Some(shortcode) => {
+            w.write_all(b":")?;
+            w.write_all(shortcode.as_bytes())?;
+            w.write_all(b":")
+        }
+        None => w.write_all(emoji.as_bytes()),
+    }
+}
+
+/// Writes an emoji as its ASCII fallback, or its shortcode if it does not
+/// have one.
+fn write_ascii<W: io::Write>(w: &mut W, emoji: &Emoji) -> io::Result<()> {
+    match emoji.ascii_fallback() {
+        Some(fallback) => w.write_all(fallback.as_bytes()),
+        None => write_shortcode(w, emoji),
+    }
+}
+
+/// Writes the text with each emoji replaced, returning the number of bytes
+/// consumed.
+///
+/// Unless this is the end of the input, the last [`MAX_LEN`] bytes are not
+/// consumed since they could be the start of a longer emoji.
+///
+/// [`MAX_LEN`]: crate::gen::MAX_LEN
+// Slicing the `str` rather than its bytes asserts that each slice is on a
+// char boundary.
+#[allow(clippy::sliced_string_as_bytes)]
+fn replace_emojis<W: io::Write>(
+    s: &str,
+    eof: bool,
+    w: &mut W,
+    mut replace: impl FnMut(&mut W, &'static Emoji) -> io::Result<()>,
+) -> io::Result<usize> {
+    let mut safe = if eof {
+        s.len()
+    } else {
+        s.len().saturating_sub(crate::gen::MAX_LEN)
+    };
+    while !s.is_char_boundary(safe) {
+        safe -= 1;
+    }
+    let mut last = 0;
+    for (range, emoji) in matches(s) {
+        // An emoji starting here is at most `MAX_LEN` bytes so it is
+        // completely contained in the text.
+        if range.start >= safe {
+            break;
+        }
+        w.write_all(s[last..range.start].as_bytes())?;
+        replace(w, emoji)?;
+        last = range.end;
+    }
+    let end = last.max(safe);
+    w.write_all(s[last..end].as_bytes())?;
+    Ok(end)
+}
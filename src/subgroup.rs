@@ -0,0 +1,95 @@
+//! Subgroups of emojis.
+
+use crate::gen::{EMOJIS, SUBGROUPS};
+use crate::{Emoji, EmojiId, Group};
+
+/// A subcategory of a [`Group`], e.g. "face-smiling".
+///
+/// Based on Unicode CLDR data. Unlike [`Group`] the set of subgroups changes
+/// between Unicode versions, so they are identified by their name.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Group;
+///
+/// let subgroup = emojis::get("🦉").unwrap().subgroup();
+/// assert_eq!(subgroup.name(), "animal-bird");
+/// assert_eq!(subgroup.group(), Group::AnimalsAndNature);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Subgroup {
+    index: usize,
+}
+
+impl Subgroup {
+    /// Returns an iterator over all subgroups in Unicode CLDR order.
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Subgroup> {
+        (0..SUBGROUPS.len()).map(|index| Self { index })
+    }
+
+    /// Lookup a subgroup by its CLDR name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Subgroup;
+    ///
+    /// assert!(Subgroup::from_name("face-smiling").is_some());
+    /// assert!(Subgroup::from_name("not-a-subgroup").is_none());
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::iter().find(|s| s.name() == name)
+    }
+
+    /// Returns the CLDR name of this subgroup.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        SUBGROUPS[self.index].1
+    }
+
+    /// Returns the group this subgroup belongs to.
+    #[inline]
+    pub fn group(self) -> Group {
+        SUBGROUPS[self.index].0
+    }
+
+    /// Returns the emojis in this subgroup, including skin tone variants.
+    ///
+    /// The emojis are contiguous, so this does not iterate or allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Subgroup;
+    ///
+    /// let birds = Subgroup::from_name("animal-bird").unwrap().as_slice();
+    /// assert!(birds.iter().any(|e| e == "🦉"));
+    /// ```
+    #[inline]
+    pub fn as_slice(self) -> &'static [Emoji] {
+        let (_, _, start, len) = SUBGROUPS[self.index];
+        start.slice(len as usize)
+    }
+}
+
+/// Returns the subgroup that contains the emoji with the given id.
+pub(crate) fn of(id: EmojiId) -> Subgroup {
+    let index = SUBGROUPS
+        .iter()
+        .position(|&(_, _, start, len)| id.index() < start.index() + len as usize)
+        .expect("every emoji belongs to a subgroup");
+    Subgroup { index }
+}
+
+/// Returns the emojis in the group, including skin tone variants.
+pub(crate) fn group_slice(group: Group) -> &'static [Emoji] {
+    let mut ranges = SUBGROUPS
+        .iter()
+        .filter(|&&(g, _, _, _)| g == group)
+        .map(|&(_, _, start, len)| (start.index(), start.index() + len as usize));
+    let (start, end) = ranges.next().unwrap_or((0, 0));
+    let end = ranges.next_back().map_or(end, |(_, end)| end);
+    &EMOJIS[start..end]
+}
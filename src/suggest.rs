@@ -0,0 +1,328 @@
+//! Suggest emojis for plain words.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::cmp;
+use core::iter;
+
+use crate::Emoji;
+
+/// How well a field of an emoji matches a word.
+#[derive(Debug, Clone, Copy)]
+enum Match {
+    /// A word in the field starts with the word.
+    Prefix = 1,
+    /// A word in the field is the word.
+    Word = 2,
+    /// The whole field is the word.
+    Exact = 4,
+}
+
+/// Returns the best match of the word against the values of a field.
+fn best_match<'a>(
+    values: impl Iterator<Item = &'a str>,
+    word: &str,
+    is_separator: fn(char) -> bool,
+) -> Option<Match> {
+    let mut best = None;
+    for value in values {
+        if value.eq_ignore_ascii_case(word) {
+            return Some(Match::Exact);
+        }
+        for w in value.split(is_separator).filter(|w| !w.is_empty()) {
+            if w.eq_ignore_ascii_case(word) {
+                best = Some(Match::Word);
+            } else if best.is_none() && starts_with_ignore_ascii_case(w, word) {
+                best = Some(Match::Prefix);
+            }
+        }
+    }
+    best
+}
+
+fn starts_with_ignore_ascii_case(s: &str, prefix: &str) -> bool {
+    s.len() >= prefix.len() && s.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+}
+
+/// Options for [`suggest_with()`] and [`Searcher`].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::SearchOptions;
+///
+/// let options = SearchOptions::new().skin_tones(true);
+/// let waves: Vec<_> = emojis::suggest_with("wave", options).map(|e| e.as_str()).collect();
+/// assert_eq!(waves[..6], ["👋", "👋🏻", "👋🏼", "👋🏽", "👋🏾", "👋🏿"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SearchOptions {
+    skin_tones: bool,
+    name_weight: u8,
+    shortcode_weight: u8,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            skin_tones: false,
+            name_weight: 1,
+            shortcode_weight: 1,
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Returns the default options, the same as used by [`suggest()`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the weight of matches against the name and the [folded
+    /// name][Emoji::folded_name].
+    ///
+    /// The score of a match is multiplied by the weight of the field, see
+    /// [`suggest()`]. A weight of zero ignores the field. The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::SearchOptions;
+    ///
+    /// assert_eq!(emojis::suggest("cat").next().unwrap(), "🐱");
+    ///
+    /// let options = SearchOptions::new().name_weight(3);
+    /// assert_eq!(emojis::suggest_with("cat", options).next().unwrap(), "🐈");
+    /// ```
+    #[inline]
+    pub fn name_weight(mut self, weight: u8) -> Self {
+        self.name_weight = weight;
+        self
+    }
+
+    /// Sets the weight of matches against the GitHub shortcodes.
+    ///
+    /// The score of a match is multiplied by the weight of the field, see
+    /// [`suggest()`]. A weight of zero ignores the field. The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::SearchOptions;
+    ///
+    /// let options = SearchOptions::new().shortcode_weight(3);
+    /// let suggestions: Vec<_> = emojis::suggest_with("party", options).map(|e| e.as_str()).collect();
+    /// assert_eq!(suggestions, ["🥳", "🎉"]);
+    /// ```
+    #[inline]
+    pub fn shortcode_weight(mut self, weight: u8) -> Self {
+        self.shortcode_weight = weight;
+        self
+    }
+
+    /// Sets whether skin tone variants are included in the results.
+    ///
+    /// By default results are collapsed to the default skin tone so each emoji
+    /// appears at most once. When enabled each emoji is immediately followed
+    /// by its skin tone variants.
+    #[inline]
+    pub fn skin_tones(mut self, skin_tones: bool) -> Self {
+        self.skin_tones = skin_tones;
+        self
+    }
+
+    /// Returns how well the emoji matches the word, higher is better and zero
+    /// means it does not match.
+    fn score(self, emoji: &Emoji, word: &str) -> u16 {
+        let names = [emoji.name(), emoji.folded_name()];
+        let name = best_match(names.into_iter(), word, |c| !c.is_alphanumeric());
+        let shortcode = best_match(emoji.shortcodes(), word, |c| c == '_' || c == '-');
+        let weighted = |m: Option<Match>, weight: u8| m.map_or(0, |m| m as u16 * weight as u16);
+        cmp::max(
+            weighted(name, self.name_weight),
+            weighted(shortcode, self.shortcode_weight),
+        )
+    }
+
+    /// Returns an iterator over every possible non-zero score, from highest
+    /// to lowest.
+    fn scores(self) -> impl Iterator<Item = u16> {
+        let mut scores = [0; 6];
+        for (i, m) in [Match::Exact, Match::Word, Match::Prefix]
+            .into_iter()
+            .enumerate()
+        {
+            scores[2 * i] = m as u16 * self.name_weight as u16;
+            scores[2 * i + 1] = m as u16 * self.shortcode_weight as u16;
+        }
+        scores.sort_unstable_by(|a, b| b.cmp(a));
+        let mut prev = 0;
+        scores.into_iter().filter(move |&score| {
+            let first = score != prev;
+            prev = score;
+            first && score != 0
+        })
+    }
+
+    /// Returns the emoji followed by its skin tone variants if they are
+    /// included.
+    fn expand(self, emoji: &'static Emoji) -> impl Iterator<Item = &'static Emoji> {
+        let variants = emoji.skin_tones().filter(|_| self.skin_tones);
+        iter::once(emoji).chain(variants.into_iter().flatten().skip(1))
+    }
+}
+
+/// Returns an iterator over emojis that match a plain word.
+///
+/// This is intended for predicting emojis while typing. Emojis are matched
+/// using their name, their [folded name][Emoji::folded_name], and their GitHub
+/// shortcodes, ignoring ASCII case. Each of these fields is scored as follows
+/// and the best score is used:
+///
+/// - 4 if the whole field is exactly the word.
+/// - 2 if a word in the field is exactly the word.
+/// - 1 if a word in the field starts with the word.
+///
+/// Emojis with higher scores come first and emojis with the same score are
+/// ordered by Unicode CLDR data. Non-default skin tones are excluded. Use
+/// [`suggest_with()`] to include them or to weight the fields differently.
+///
+/// # Examples
+///
+/// ```
+/// let mut suggestions = emojis::suggest("rocket");
+/// assert_eq!(suggestions.next().unwrap(), "🚀");
+///
+/// let suggestions: Vec<_> = emojis::suggest("tada").map(|e| e.as_str()).collect();
+/// assert_eq!(suggestions, ["🎉"]);
+/// ```
+pub fn suggest(word: &str) -> impl Iterator<Item = &'static Emoji> + '_ {
+    suggest_with(word, SearchOptions::new())
+}
+
+/// Returns an iterator over emojis that match a plain word using the given
+/// options.
+///
+/// See [`suggest()`] for how emojis are matched and ranked.
+pub fn suggest_with(
+    word: &str,
+    options: SearchOptions,
+) -> impl Iterator<Item = &'static Emoji> + '_ {
+    let scores = options.scores().filter(move |_| !word.is_empty());
+    scores
+        .flat_map(move |s| crate::iter().filter(move |emoji| options.score(emoji, word) == s))
+        .flat_map(move |emoji| options.expand(emoji))
+}
+
+/// Incrementally suggests emojis for a word as it is typed.
+///
+/// This returns the same results as [`suggest_with()`] but remembers the emojis
+/// that matched the previous word. Every emoji that matches a word also
+/// matches each of its prefixes, so when the new word extends the previous
+/// one only the previous matches are ranked again instead of every emoji.
+///
+/// # Examples
+///
+/// ```
+/// let mut searcher = emojis::Searcher::new();
+/// for word in ["r", "ro", "roc", "rock"] {
+///     let results: Vec<_> = searcher.search(word).collect();
+///     assert!(results.iter().copied().eq(emojis::suggest(word)));
+/// }
+/// assert_eq!(searcher.search("rocket").next().unwrap(), "🚀");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default)]
+pub struct Searcher {
+    options: SearchOptions,
+    word: String,
+    // The emojis matching the word and their score, in Unicode CLDR order.
+    matches: Vec<(&'static Emoji, u16)>,
+}
+
+#[cfg(feature = "alloc")]
+impl Searcher {
+    /// Returns a new searcher with no previous word.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new searcher with no previous word that uses the given
+    /// options.
+    #[inline]
+    pub fn with_options(options: SearchOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Returns an iterator over emojis that match a plain word, ranked the
+    /// same as [`suggest_with()`].
+    pub fn search(&mut self, word: &str) -> impl Iterator<Item = &'static Emoji> + '_ {
+        if word.is_empty() {
+            self.matches.clear();
+        } else if !self.word.is_empty() && starts_with_ignore_ascii_case(word, &self.word) {
+            for (emoji, score) in &mut self.matches {
+                *score = self.options.score(emoji, word);
+            }
+            self.matches.retain(|&(_, score)| score != 0);
+        } else {
+            let options = self.options;
+            self.matches.clear();
+            self.matches.extend(
+                crate::iter()
+                    .map(|emoji| (emoji, options.score(emoji, word)))
+                    .filter(|&(_, score)| score != 0),
+            );
+        }
+        self.word.clear();
+        self.word.push_str(word);
+
+        let options = self.options;
+        let matches = &self.matches;
+        options
+            .scores()
+            .flat_map(move |s| {
+                matches
+                    .iter()
+                    .filter(move |&&(_, score)| score == s)
+                    .map(|&(emoji, _)| emoji)
+            })
+            .flat_map(move |emoji| options.expand(emoji))
+    }
+}
+
+/// Returns an iterator over emojis whose name starts with the given prefix.
+///
+/// Unlike [`suggest()`] this only matches the start of the whole name, or the
+/// [folded name][Emoji::folded_name], ignoring ASCII case. Emojis are always
+/// ordered by Unicode CLDR data so results are stable as the prefix grows.
+/// Non-default skin tones are excluded.
+///
+/// # Examples
+///
+/// ```
+/// let names: Vec<_> = emojis::names_with_prefix("grinning face with")
+///     .map(|e| e.name())
+///     .collect();
+/// assert_eq!(
+///     names,
+///     [
+///         "grinning face with big eyes",
+///         "grinning face with smiling eyes",
+///         "grinning face with sweat",
+///     ]
+/// );
+/// ```
+pub fn names_with_prefix(prefix: &str) -> impl Iterator<Item = &'static Emoji> + '_ {
+    crate::iter().filter(move |emoji| {
+        starts_with_ignore_ascii_case(emoji.name(), prefix)
+            || starts_with_ignore_ascii_case(emoji.folded_name(), prefix)
+    })
+}
@@ -0,0 +1,740 @@
+//! Find and process emojis in text.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::shortcodes::{Builtin, ShortcodeResolver};
+use crate::Emoji;
+
+/// Returns the longest emoji at the start of the string and its length in
+/// bytes.
+pub(crate) fn longest_match(s: &str) -> Option<(usize, &'static Emoji)> {
+    let first = s.chars().next()?;
+    // The only emojis that start with an ASCII character are the keycaps.
+    if first.is_ascii() && !matches!(first, '#' | '*' | '0'..='9') {
+        return None;
+    }
+    let max = s.len().min(crate::gen::MAX_LEN);
+    (1..=max)
+        .rev()
+        .filter(|&i| s.is_char_boundary(i))
+        .find_map(|i| crate::get(&s[..i]).map(|emoji| (i, emoji)))
+}
+
+/// An iterator over the emojis in some text and their byte ranges.
+///
+/// At each position the longest possible emoji is matched, so for example a
+/// ZWJ sequence is matched as a whole rather than as its components.
+#[derive(Debug)]
+pub(crate) struct Matches<'t> {
+    text: &'t str,
+    pos: usize,
+}
+
+/// Returns an iterator over the emojis in some text and their byte ranges.
+pub(crate) fn matches(text: &str) -> Matches<'_> {
+    Matches { text, pos: 0 }
+}
+
+impl Iterator for Matches<'_> {
+    type Item = (Range<usize>, &'static Emoji);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.text[self.pos..].chars().next() {
+            let start = self.pos;
+            if let Some((len, emoji)) = longest_match(&self.text[start..]) {
+                self.pos += len;
+                return Some((start..self.pos, emoji));
+            }
+            self.pos += c.len_utf8();
+        }
+        None
+    }
+}
+
+/// Writes the text with each `:shortcode:` replaced with its emoji, except for
+/// any trailing text starting with a colon that is not closed.
+///
+/// The trailing text is returned so that streaming callers can wait for more
+/// input before deciding whether it is a shortcode.
+pub(crate) fn replace_shortcodes_with<E>(
+    mut s: &str,
+    resolver: impl ShortcodeResolver,
+    mut write: impl FnMut(&str) -> Result<(), E>,
+) -> Result<&str, E> {
+    while let Some(i) = s.find(':') {
+        let n = match s[i + 1..].find(':') {
+            Some(j) => i + 1 + j,
+            None => {
+                write(&s[..i])?;
+                return Ok(&s[i..]);
+            }
+        };
+        match resolver.resolve(&s[i + 1..n]) {
+            Some(replacement) => {
+                write(&s[..i])?;
+                write(replacement)?;
+                s = &s[n + 1..];
+            }
+            None => {
+                // The closing colon might be the start of another shortcode.
+                write(&s[..n])?;
+                s = &s[n..];
+            }
+        }
+    }
+    write(s)?;
+    Ok("")
+}
+
+/// Writes some text with each `:shortcode:` replaced with its emoji.
+///
+/// Unknown shortcodes are left as is. This does not allocate, so it can be
+/// used to write directly to a display or other [`fmt::Write`] implementation
+/// in `no_std` environments.
+///
+/// # Examples
+///
+/// ```
+/// let mut out = String::new();
+/// emojis::expand_shortcodes("launch :rocket: :unknown:", &mut out).unwrap();
+/// assert_eq!(out, "launch 🚀 :unknown:");
+/// ```
+pub fn expand_shortcodes<W: fmt::Write + ?Sized>(text: &str, w: &mut W) -> fmt::Result {
+    expand_shortcodes_with(text, &Builtin, w)
+}
+
+/// Writes some text with each `:shortcode:` replaced using a custom resolver.
+///
+/// This is the same as [`expand_shortcodes()`] except the replacement text
+/// for each shortcode is provided by the [`ShortcodeResolver`].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::shortcodes::ShortcodeResolver;
+///
+/// struct Tenant;
+///
+/// impl ShortcodeResolver for Tenant {
+///     fn resolve(&self, name: &str) -> Option<&str> {
+///         (name == "logo").then(|| "[logo]")
+///     }
+/// }
+///
+/// let mut out = String::new();
+/// emojis::expand_shortcodes_with(":logo: :rocket:", &Tenant, &mut out).unwrap();
+/// assert_eq!(out, "[logo] :rocket:");
+/// ```
+pub fn expand_shortcodes_with<R, W>(text: &str, resolver: &R, w: &mut W) -> fmt::Result
+where
+    R: ShortcodeResolver + ?Sized,
+    W: fmt::Write + ?Sized,
+{
+    let rest = replace_shortcodes_with(text, resolver, |s| w.write_str(s))?;
+    w.write_str(rest)
+}
+
+/// Replaces each emoji in some text with a description using its name.
+///
+/// Every occurrence of `{name}` in the template is replaced with the CLDR
+/// name of the emoji. This is useful for generating alt-text, screen-reader
+/// transcripts, and plain-text fallbacks.
+///
+/// # Examples
+///
+/// ```
+/// let text = "We have liftoff 🚀!";
+/// assert_eq!(emojis::describe(text, "({name})"), "We have liftoff (rocket)!");
+/// assert_eq!(
+///     emojis::describe(text, "[emoji: {name}]"),
+///     "We have liftoff [emoji: rocket]!"
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn describe(text: &str, template: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (range, emoji) in matches(text) {
+        out.push_str(&text[last..range.start]);
+        for (i, part) in template.split("{name}").enumerate() {
+            if i > 0 {
+                out.push_str(emoji.name());
+            }
+            out.push_str(part);
+        }
+        last = range.end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Replaces each emoji in some text with `\u{...}` escapes.
+///
+/// Every character of the emoji is escaped, including any joiners and
+/// variation selectors, so the result only contains characters from the
+/// original text that are not part of an emoji. This is useful for log
+/// pipelines that cannot handle emojis. Use [`describe()`] to replace emojis
+/// with their names instead. The text is only copied if it contains an emoji.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::escape_for_logs("liftoff 🚀"), "liftoff \\u{1f680}");
+/// assert_eq!(emojis::escape_for_logs("❤️"), "\\u{2764}\\u{fe0f}");
+/// assert!(matches!(emojis::escape_for_logs("liftoff"), std::borrow::Cow::Borrowed(_)));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn escape_for_logs(text: &str) -> Cow<'_, str> {
+    let mut matches = matches(text).peekable();
+    if matches.peek().is_none() {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len() * 2);
+    let mut last = 0;
+    for (range, _) in matches {
+        out.push_str(&text[last..range.start]);
+        out.extend(text[range.clone()].chars().flat_map(char::escape_unicode));
+        last = range.end;
+    }
+    out.push_str(&text[last..]);
+    Cow::Owned(out)
+}
+
+/// Counts the occurrences of each distinct emoji in some text.
+///
+/// Variations of the same emoji, such as the unqualified "☹" and fully
+/// qualified "☹️" forms, are counted as the same emoji. Different skin tones
+/// are counted as different emojis.
+///
+/// The counts are returned in the order that each emoji first occurs.
+///
+/// # Examples
+///
+/// ```
+/// let counts = emojis::tally("🚀 ☹ 🚀 ☹️ 👍🏽");
+/// let counts: Vec<_> = counts.iter().map(|(e, n)| (e.as_str(), *n)).collect();
+/// assert_eq!(counts, [("🚀", 2), ("☹️", 2), ("👍🏽", 1)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn tally(text: &str) -> Vec<(&'static Emoji, usize)> {
+    let mut counts: Vec<(&'static Emoji, usize)> = Vec::new();
+    // The index into `counts` of each emoji, keyed by its CLDR order.
+    let mut indices = BTreeMap::new();
+    for (_, emoji) in matches(text) {
+        let i = *indices.entry(emoji.cldr_order()).or_insert_with(|| {
+            counts.push((emoji, 0));
+            counts.len() - 1
+        });
+        counts[i].1 += 1;
+    }
+    counts
+}
+
+/// Returns the length of some text counting each emoji as the given weight and
+/// every other character as one.
+///
+/// Emojis are matched the same way as [`EmojiStrExt::emojis()`], so a ZWJ
+/// sequence, flag, or emoji with a skin tone counts as a single emoji. This is
+/// useful for character counters, for example a weight of two gives the same
+/// count as many social networks.
+///
+/// # Examples
+///
+/// ```
+/// let text = "hi 👨‍👩‍👧‍👦!";
+/// assert_eq!(text.chars().count(), 11);
+/// assert_eq!(emojis::weighted_len(text, 1), 5);
+/// assert_eq!(emojis::weighted_len(text, 2), 6);
+/// ```
+pub fn weighted_len(text: &str, weight: usize) -> usize {
+    let mut len = 0;
+    let mut last = 0;
+    for (range, _) in matches(text) {
+        len += text[last..range.start].chars().count() + weight;
+        last = range.end;
+    }
+    len + text[last..].chars().count()
+}
+
+/// Returns the longest prefix of the text for which the total length of each
+/// emoji and other character, as given by `measure`, is at most `max`.
+fn truncate_by(text: &str, max: usize, measure: impl Fn(&str) -> usize) -> &str {
+    let mut end = 0;
+    let mut total = 0;
+    while let Some(c) = text[end..].chars().next() {
+        let len = longest_match(&text[end..]).map_or(c.len_utf8(), |(len, _)| len);
+        total += measure(&text[end..end + len]);
+        if total > max {
+            break;
+        }
+        end += len;
+    }
+    &text[..end]
+}
+
+/// Truncates some text to at most `max_bytes` bytes without splitting an
+/// emoji.
+///
+/// Emojis are matched the same way as [`EmojiStrExt::emojis()`], so a ZWJ
+/// sequence, flag, or emoji with a skin tone is either kept or removed as a
+/// whole. Other characters are never split either.
+///
+/// # Examples
+///
+/// ```
+/// let text = "hi 👨‍👩‍👧‍👦!";
+/// assert_eq!(emojis::truncate(text, 10), "hi ");
+/// assert_eq!(emojis::truncate(text, 28), "hi 👨‍👩‍👧‍👦");
+/// assert_eq!(emojis::truncate(text, 100), text);
+/// ```
+pub fn truncate(text: &str, max_bytes: usize) -> &str {
+    truncate_by(text, max_bytes, str::len)
+}
+
+/// Truncates some text to at most `max_chars` characters without splitting an
+/// emoji.
+///
+/// This is the same as [`truncate()`] except the length is measured in
+/// characters. Every character of an emoji counts, including any joiners and
+/// variation selectors.
+///
+/// # Examples
+///
+/// ```
+/// let text = "hi 👋🏽!";
+/// assert_eq!(emojis::truncate_chars(text, 4), "hi ");
+/// assert_eq!(emojis::truncate_chars(text, 5), "hi 👋🏽");
+/// ```
+pub fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    truncate_by(text, max_chars, |s| s.chars().count())
+}
+
+/// Truncates some text to at most `max_bytes` bytes without splitting an
+/// emoji, appending an ellipsis if anything was removed.
+///
+/// The length of the ellipsis is included in `max_bytes`. If the ellipsis
+/// itself is longer than `max_bytes` the text is truncated without it. The
+/// text is only copied if an ellipsis was appended.
+///
+/// # Examples
+///
+/// ```
+/// let text = "launch 🚀🚀🚀";
+/// assert_eq!(emojis::truncate_with_ellipsis(text, 17, "…"), "launch 🚀…");
+/// assert_eq!(emojis::truncate_with_ellipsis(text, 19, "…"), text);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn truncate_with_ellipsis<'t>(text: &'t str, max_bytes: usize, ellipsis: &str) -> Cow<'t, str> {
+    if text.len() <= max_bytes {
+        return Cow::Borrowed(text);
+    }
+    if ellipsis.len() > max_bytes {
+        return Cow::Borrowed(truncate(text, max_bytes));
+    }
+    let mut out = String::from(truncate(text, max_bytes - ellipsis.len()));
+    out.push_str(ellipsis);
+    Cow::Owned(out)
+}
+
+/// Returns whether UAX #14 prohibits a line break after the character when it
+/// is followed by an emoji.
+fn no_break_after(c: char) -> bool {
+    matches!(
+        c,
+        // Opening punctuation (OP) and quotations (QU).
+        '(' | '[' | '{' | '¡' | '¿' | '"' | '\'' | '«' | '‘' | '“'
+        // Prefix numeric (PR).
+        | '$' | '+' | '\\' | '£' | '¥' | '€' | '₹'
+        // Non-breaking glue (GL) and word joiner (WJ).
+        | '\u{a0}' | '\u{202f}' | '\u{2060}' | '\u{feff}'
+    )
+}
+
+/// Returns whether UAX #14 prohibits a line break before the character when
+/// it follows an emoji.
+fn no_break_before(c: char) -> bool {
+    matches!(
+        c,
+        // Closing punctuation (CL, CP), exclamation (EX), infix separators
+        // (IS), and symbols allowing break after (SY).
+        ')' | ']' | '}' | '!' | '?' | ',' | '.' | ':' | ';' | '/'
+        // Quotations (QU).
+        | '"' | '\'' | '»' | '’' | '”'
+        // Postfix numeric (PO).
+        | '%' | '‰' | '°' | '¢'
+        // Hyphens (HY, BA) and inseparable characters (IN).
+        | '-' | '‐' | '–' | '…'
+        // Non-breaking glue (GL), word joiner (WJ), and zero width joiner (ZWJ).
+        | '\u{a0}' | '\u{202f}' | '\u{2060}' | '\u{feff}' | '\u{200d}'
+    ) || matches!(c, '\u{300}'..='\u{36f}')
+}
+
+/// Returns whether the emoji is a pictograph, i.e. its first character has the
+/// UAX #14 class ID, EB, or EM.
+///
+/// Emojis that default to text presentation such as ©️, ™️, ‼️, and ↩️, as
+/// well as keycaps, have other classes and behave like the surrounding text.
+fn is_pictograph(emoji: &Emoji) -> bool {
+    let c = emoji.as_str().chars().next().unwrap_or_default();
+    !matches!(
+        c,
+        // Keycap bases and Latin-1 symbols such as © and ®.
+        '\0'..='\u{ff}'
+        // General punctuation such as ‼ and ⁉, letterlike symbols such as ™
+        // and ℹ, and arrows such as ↔ and ↩.
+        | '\u{2000}'..='\u{21ff}'
+        // Enclosed alphanumerics and supplemental arrows such as Ⓜ, ⤴, and ⬅.
+        | '\u{24c2}' | '\u{2934}' | '\u{2935}' | '\u{2b05}'..='\u{2b07}'
+    )
+}
+
+/// Returns whether a line break is permitted at the boundary between an emoji
+/// and the text around it.
+fn is_break_opportunity(text: &str, i: usize) -> bool {
+    match (text[..i].chars().next_back(), text[i..].chars().next()) {
+        (Some(before), Some(after)) => {
+            !before.is_whitespace()
+                && !after.is_whitespace()
+                && !no_break_after(before)
+                && !no_break_before(after)
+        }
+        _ => false,
+    }
+}
+
+/// Returns an iterator over the byte offsets where a line break is permitted
+/// next to an emoji.
+///
+/// Emojis are matched the same way as [`EmojiStrExt::emojis()`] and are never
+/// broken, so a ZWJ sequence or emoji with a skin tone always stays on one
+/// line. Following the behavior of pictographs (class ID) in [UAX #14], a break
+/// is permitted between two emojis and between an emoji and other text, except
+/// next to punctuation, quotes, and glue characters that prohibit it, such as
+/// after an opening parenthesis or before a closing one.
+///
+/// Emojis that are not pictographs, such as ©️, ™️, ‼️, and keycaps, are
+/// treated like letters and yield no break opportunities of their own. The
+/// classes are approximated from the first character of the emoji rather than
+/// looked up in the full Unicode line breaking data.
+///
+/// Only offsets at the start or end of an emoji are yielded. Breaks around
+/// whitespace, and between other text, are left to the caller's own line
+/// breaking.
+///
+/// [UAX #14]: https://www.unicode.org/reports/tr14/
+///
+/// # Examples
+///
+/// ```
+/// let text = "go🚀🚀 (👋🏽)";
+/// let breaks: Vec<_> = emojis::line_breaks(text).collect();
+/// assert_eq!(breaks, [2, 6]);
+/// ```
+pub fn line_breaks(text: &str) -> impl Iterator<Item = usize> + '_ {
+    let mut last = None;
+    matches(text)
+        .filter(|(_, emoji)| is_pictograph(emoji))
+        .flat_map(|(range, _)| [range.start, range.end])
+        .filter(move |&i| {
+            let new = last != Some(i);
+            last = Some(i);
+            new && is_break_opportunity(text, i)
+        })
+}
+
+/// A token in some text, see [`tokenize()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'t> {
+    /// An emoji.
+    Emoji(&'static Emoji),
+    /// A `:name:` shortcode.
+    ///
+    /// The emoji is `None` if the name is not a known shortcode, or if the
+    /// resolver does not resolve it to an emoji, for example if it refers to a
+    /// custom image.
+    Shortcode {
+        /// The name between the colons.
+        name: &'t str,
+        /// The emoji for the shortcode.
+        emoji: Option<&'static Emoji>,
+    },
+    /// Any other text.
+    Text(&'t str),
+}
+
+/// An iterator over the tokens in some text and their byte ranges.
+///
+/// This struct is created by [`tokenize()`].
+#[derive(Debug, Clone)]
+pub struct Tokens<'t, R = Builtin> {
+    text: &'t str,
+    pos: usize,
+    resolver: R,
+}
+
+/// Returns the shortcode at the start of the string and its length in bytes.
+///
+/// A shortcode name is made up of ASCII alphanumeric characters, `_`, `+`, and
+/// `-`.
+pub(crate) fn shortcode_at(s: &str) -> Option<(usize, &str)> {
+    let rest = s.strip_prefix(':')?;
+    let end = rest.find(':')?;
+    let name = &rest[..end];
+    let valid = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'+' | b'-'));
+    valid.then(|| (end + 2, name))
+}
+
+/// Returns the emoji or shortcode token at the start of the string and its
+/// length in bytes.
+fn token_at<'t>(s: &'t str, resolver: &impl ShortcodeResolver) -> Option<(usize, Token<'t>)> {
+    if let Some((len, name)) = shortcode_at(s) {
+        let emoji = resolver.resolve(name).and_then(crate::get);
+        return Some((len, Token::Shortcode { name, emoji }));
+    }
+    longest_match(s).map(|(len, emoji)| (len, Token::Emoji(emoji)))
+}
+
+/// Returns an iterator over the emojis, shortcodes, and other text in some
+/// text, along with their byte ranges.
+///
+/// Literal emojis are matched the same way as [`EmojiStrExt::emojis()`]. A
+/// shortcode is a name between two colons, even if the name is not known. All
+/// other text is returned as [`Token::Text`], with adjacent text merged into a
+/// single token.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Token;
+///
+/// let rocket = emojis::get("🚀").unwrap();
+/// let tokens: Vec<_> = emojis::tokenize("🚀 to :rocket: :custom:").collect();
+/// assert_eq!(
+///     tokens,
+///     [
+///         (0..4, Token::Emoji(rocket)),
+///         (4..8, Token::Text(" to ")),
+///         (8..16, Token::Shortcode { name: "rocket", emoji: Some(rocket) }),
+///         (16..17, Token::Text(" ")),
+///         (17..25, Token::Shortcode { name: "custom", emoji: None }),
+///     ]
+/// );
+/// ```
+pub fn tokenize(text: &str) -> Tokens<'_> {
+    tokenize_with(text, Builtin)
+}
+
+/// Returns an iterator over the emojis, shortcodes, and other text in some
+/// text using a custom shortcode resolver.
+///
+/// This is the same as [`tokenize()`] except the emoji for each shortcode is
+/// looked up from the replacement text provided by the
+/// [`ShortcodeResolver`].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::shortcodes::{Builtin, ShortcodeResolver};
+/// use emojis::Token;
+///
+/// struct Aliases;
+///
+/// impl ShortcodeResolver for Aliases {
+///     fn resolve(&self, name: &str) -> Option<&str> {
+///         (name == "ship_it").then(|| "🚀")
+///     }
+/// }
+///
+/// let rocket = emojis::get("🚀").unwrap();
+/// let mut tokens = emojis::tokenize_with(":ship_it:", (Aliases, Builtin));
+/// assert_eq!(
+///     tokens.next(),
+///     Some((0..9, Token::Shortcode { name: "ship_it", emoji: Some(rocket) }))
+/// );
+/// ```
+pub fn tokenize_with<R: ShortcodeResolver>(text: &str, resolver: R) -> Tokens<'_, R> {
+    Tokens {
+        text,
+        pos: 0,
+        resolver,
+    }
+}
+
+impl<'t, R: ShortcodeResolver> Iterator for Tokens<'t, R> {
+    type Item = (Range<usize>, Token<'t>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.pos;
+        let rest = &self.text[start..];
+        if rest.is_empty() {
+            return None;
+        }
+        if let Some((len, token)) = token_at(rest, &self.resolver) {
+            self.pos += len;
+            return Some((start..self.pos, token));
+        }
+        let len = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(i, _)| token_at(&rest[i..], &self.resolver).is_some())
+            .map_or(rest.len(), |(i, _)| i);
+        self.pos += len;
+        Some((start..self.pos, Token::Text(&rest[..len])))
+    }
+}
+
+/// An iterator over the emojis in a string.
+///
+/// This struct is created by [`EmojiStrExt::emojis()`].
+#[derive(Debug)]
+pub struct Emojis<'t> {
+    matches: Matches<'t>,
+}
+
+impl Iterator for Emojis<'_> {
+    type Item = &'static Emoji;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.matches.next().map(|(_, emoji)| emoji)
+    }
+}
+
+/// Returns a value that displays the emojis separated by the separator.
+///
+/// The emojis are written directly to the formatter, so unlike collecting
+/// into a `String` and joining this does not allocate. The iterator is cloned
+/// each time the value is displayed, so it should be cheap to clone, e.g. a
+/// slice iterator.
+///
+/// # Examples
+///
+/// ```
+/// let emojis = [emojis::get("🚀").unwrap(), emojis::get("🌕").unwrap()];
+/// assert_eq!(emojis::display_list(&emojis, " -> ").to_string(), "🚀 -> 🌕");
+///
+/// let flags = &emojis::Group::Flags.as_slice()[..3];
+/// assert_eq!(emojis::display_list(flags, " ").to_string(), "🏁 🚩 🎌");
+/// ```
+pub fn display_list<I>(emojis: I, separator: &str) -> DisplayList<'_, I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: fmt::Display,
+{
+    DisplayList {
+        emojis: emojis.into_iter(),
+        separator,
+    }
+}
+
+/// A list of emojis displayed with a separator.
+///
+/// This struct is created by [`display_list()`].
+#[derive(Debug, Clone)]
+pub struct DisplayList<'s, I> {
+    emojis: I,
+    separator: &'s str,
+}
+
+impl<I> fmt::Display for DisplayList<'_, I>
+where
+    I: Iterator + Clone,
+    I::Item: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut emojis = self.emojis.clone();
+        if let Some(emoji) = emojis.next() {
+            emoji.fmt(f)?;
+        }
+        for emoji in emojis {
+            f.write_str(self.separator)?;
+            emoji.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for str {}
+}
+
+/// Extension methods for working with emojis in string slices.
+///
+/// This trait is sealed and implemented for [`str`]. Import it using the
+/// [`prelude`][crate::prelude].
+pub trait EmojiStrExt: private::Sealed {
+    /// Lookup the emoji that this entire string represents.
+    ///
+    /// This is the same as [`emojis::get()`][crate::get].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::prelude::*;
+    ///
+    /// assert_eq!("🚀".to_emoji().unwrap().name(), "rocket");
+    /// assert!("rocket".to_emoji().is_none());
+    /// ```
+    fn to_emoji(&self) -> Option<&'static Emoji>;
+
+    /// Returns whether this string contains any emojis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::prelude::*;
+    ///
+    /// assert!("launch 🚀".contains_emoji());
+    /// assert!(!"launch".contains_emoji());
+    /// ```
+    fn contains_emoji(&self) -> bool;
+
+    /// Returns an iterator over the emojis in this string.
+    ///
+    /// At each position the longest possible emoji is matched, so for example
+    /// a ZWJ sequence is returned as a whole rather than as its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::prelude::*;
+    ///
+    /// let names: Vec<_> = "👋🏽 hi 🧑‍🚀!".emojis().map(|e| e.name()).collect();
+    /// assert_eq!(names, ["waving hand: medium skin tone", "astronaut"]);
+    /// ```
+    fn emojis(&self) -> Emojis<'_>;
+}
+
+impl EmojiStrExt for str {
+    #[inline]
+    fn to_emoji(&self) -> Option<&'static Emoji> {
+        crate::get(self)
+    }
+
+    #[inline]
+    fn contains_emoji(&self) -> bool {
+        matches(self).next().is_some()
+    }
+
+    #[inline]
+    fn emojis(&self) -> Emojis<'_> {
+        Emojis {
+            matches: matches(self),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! JavaScript bindings using [`wasm-bindgen`].
+//!
+//! Groups and skin tones are represented as strings using the same names as
+//! the Rust enum variants, e.g. `"SmileysAndEmotion"` or `"MediumDark"`.
+//!
+//! [`wasm-bindgen`]: https://github.com/rustwasm/wasm-bindgen
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{Emoji, Group};
+
+/// An emoji, see [`Emoji`].
+#[wasm_bindgen(js_name = Emoji)]
+pub struct JsEmoji(&'static Emoji);
+
+#[wasm_bindgen(js_class = Emoji)]
+impl JsEmoji {
+    /// The emoji as a string.
+    #[wasm_bindgen(getter)]
+    pub fn emoji(&self) -> String {
+        self.0.as_str().to_string()
+    }
+
+    /// The CLDR name for the emoji.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.0.name().to_string()
+    }
+
+    /// The Unicode version the emoji first appeared in, e.g. `"13.0"`.
+    #[wasm_bindgen(getter, js_name = unicodeVersion)]
+    pub fn unicode_version(&self) -> String {
+        let v = self.0.unicode_version();
+        format!("{}.{}", v.major(), v.minor())
+    }
+
+    /// The group the emoji belongs to.
+    #[wasm_bindgen(getter)]
+    pub fn group(&self) -> String {
+        format!("{:?}", self.0.group())
+    }
+
+    /// The skin tone of the emoji, or `undefined` if not applicable.
+    #[wasm_bindgen(getter, js_name = skinTone)]
+    pub fn skin_tone(&self) -> Option<String> {
+        self.0.skin_tone().map(|st| format!("{st:?}"))
+    }
+
+    /// The first GitHub shortcode for the emoji, or `undefined` if it has
+    /// none.
+    #[wasm_bindgen(getter)]
+    pub fn shortcode(&self) -> Option<String> {
+        self.0.shortcode().map(ToString::to_string)
+    }
+
+    /// All the GitHub shortcodes for the emoji.
+    #[wasm_bindgen(getter)]
+    pub fn shortcodes(&self) -> Vec<String> {
+        self.0.shortcodes().map(ToString::to_string).collect()
+    }
+
+    /// The emoji and all the related skin tone emojis, or `undefined` if not
+    /// applicable.
+    #[wasm_bindgen(js_name = skinTones)]
+    pub fn skin_tones(&self) -> Option<Vec<JsEmoji>> {
+        Some(self.0.skin_tones()?.map(JsEmoji).collect())
+    }
+}
+
+/// Lookup an emoji by Unicode value, see [`get()`][crate::get].
+#[wasm_bindgen]
+pub fn get(s: &str) -> Option<JsEmoji> {
+    crate::get(s).map(JsEmoji)
+}
+
+/// Lookup an emoji by GitHub shortcode, see
+/// [`get_by_shortcode()`][crate::get_by_shortcode].
+#[wasm_bindgen(js_name = getByShortcode)]
+pub fn get_by_shortcode(s: &str) -> Option<JsEmoji> {
+    crate::get_by_shortcode(s).map(JsEmoji)
+}
+
+/// Returns all emojis, see [`iter()`][crate::iter].
+#[wasm_bindgen]
+pub fn iter() -> Vec<JsEmoji> {
+    crate::iter().map(JsEmoji).collect()
+}
+
+/// Returns all emojis in the given group, or `undefined` if the group is not
+/// recognized, see [`Group::emojis()`].
+#[wasm_bindgen(js_name = groupEmojis)]
+pub fn group_emojis(group: &str) -> Option<Vec<JsEmoji>> {
+    let group = Group::iter().find(|g| format!("{g:?}") == group)?;
+    Some(group.emojis().map(JsEmoji).collect())
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "capi")]
+
+use std::{ptr, slice, str};
+
+use emojis::capi::*;
+
+unsafe fn to_str<'a>(s: EmojisStr) -> Option<&'a str> {
+    if s.ptr.is_null() {
+        return None;
+    }
+    Some(str::from_utf8(slice::from_raw_parts(s.ptr, s.len)).unwrap())
+}
+
+#[test]
+fn get_and_metadata() {
+    unsafe {
+        let s = "🚀";
+        let emoji = emojis_get(s.as_ptr(), s.len());
+        assert!(!emoji.is_null());
+        assert_eq!(to_str(emojis_emoji_as_str(emoji)), Some("🚀"));
+        assert_eq!(to_str(emojis_emoji_name(emoji)), Some("rocket"));
+        assert_eq!(to_str(emojis_emoji_shortcode(emoji)), Some("rocket"));
+        assert_eq!(emojis_emoji_unicode_version_major(emoji), 0);
+        assert_eq!(emojis_emoji_unicode_version_minor(emoji), 6);
+        assert_eq!(emojis_emoji_group(emoji), 4);
+        assert_eq!(emojis_emoji_skin_tone(emoji), -1);
+
+        let s = "👍🏽";
+        let emoji = emojis_get(s.as_ptr(), s.len());
+        assert_eq!(emojis_emoji_skin_tone(emoji), 3);
+    }
+}
+
+#[test]
+fn get_not_found() {
+    unsafe {
+        let s = "nope";
+        assert!(emojis_get(s.as_ptr(), s.len()).is_null());
+        assert!(emojis_get_by_shortcode(s.as_ptr(), s.len()).is_null());
+        assert!(emojis_get(ptr::null(), 0).is_null());
+        let invalid = [0xff, 0xfe];
+        assert!(emojis_get(invalid.as_ptr(), invalid.len()).is_null());
+    }
+}
+
+#[test]
+fn get_by_shortcode() {
+    unsafe {
+        let s = "rocket";
+        let emoji = emojis_get_by_shortcode(s.as_ptr(), s.len());
+        assert_eq!(to_str(emojis_emoji_as_str(emoji)), Some("🚀"));
+    }
+}
+
+#[test]
+fn next() {
+    let mut state = 0;
+    let mut left = Vec::new();
+    unsafe {
+        loop {
+            let emoji = emojis_next(&mut state);
+            if emoji.is_null() {
+                break;
+            }
+            left.push(&*emoji);
+        }
+        assert!(emojis_next(&mut state).is_null());
+    }
+    let right: Vec<_> = emojis::iter().collect();
+    assert_eq!(left, right);
+}
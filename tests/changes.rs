@@ -0,0 +1,57 @@
+//! Check that the generated change report matches the generated data.
+//!
+//! Unicode 15.1 is the oldest supported version so it has no change report.
+
+#![cfg(any(not(feature = "unicode-15-1"), feature = "unicode-16-0"))]
+
+const CHANGES: &str = include_str!("../src/gen/v16_0/changes.txt");
+
+#[test]
+fn changes_header() {
+    assert!(CHANGES.starts_with("# Changes from Unicode "));
+}
+
+#[test]
+fn changes_match_data() {
+    for line in CHANGES.lines().filter(|l| !l.starts_with('#')) {
+        let fields: Vec<_> = line.split('\t').collect();
+        match fields.as_slice() {
+            ["added", emoji, name] => {
+                assert_eq!(emojis::get(emoji).unwrap().name(), *name);
+            }
+            ["removed", emoji, _] => {
+                assert!(emojis::get(emoji).is_none(), "{emoji} was removed");
+            }
+            ["renamed", emoji, _, name] => {
+                assert_eq!(emojis::get(emoji).unwrap().name(), *name);
+            }
+            ["aliases", emoji, _, aliases] => {
+                let shortcodes: Vec<_> = emojis::get(emoji).unwrap().shortcodes().collect();
+                let expected: Vec<_> = aliases.split_whitespace().collect();
+                assert_eq!(shortcodes, expected);
+            }
+            _ => panic!("unrecognized change `{line}`"),
+        }
+    }
+}
+
+#[test]
+fn migrate_renamed() {
+    for line in CHANGES.lines().filter(|l| !l.starts_with('#')) {
+        let fields: Vec<_> = line.split('\t').collect();
+        if let ["renamed", emoji, old, _] = fields.as_slice() {
+            assert_eq!(emojis::migrate::name(old).unwrap(), *emoji);
+        }
+    }
+}
+
+#[test]
+fn migrate_skips_added() {
+    for line in CHANGES.lines().filter(|l| !l.starts_with('#')) {
+        let fields: Vec<_> = line.split('\t').collect();
+        if let ["added", emoji, name] = fields.as_slice() {
+            assert!(emojis::migrate::name(name).is_none(), "{name}");
+            assert!(emojis::migrate::emoji(emoji).is_none(), "{emoji}");
+        }
+    }
+}
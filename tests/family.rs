@@ -0,0 +1,42 @@
+use emojis::Family;
+
+#[test]
+fn family_builds_every_rgi_family() {
+    let families: Vec<_> = emojis::iter()
+        .filter(|e| e.name().starts_with("family: "))
+        .collect();
+    assert_eq!(families.len(), 29);
+    for emoji in families {
+        let family =
+            emoji.name()["family: ".len()..]
+                .split(", ")
+                .fold(Family::new(), |family, member| match member {
+                    "man" => family.man(),
+                    "woman" => family.woman(),
+                    "adult" => family.adult(),
+                    "girl" => family.girl(),
+                    "boy" => family.boy(),
+                    "child" => family.child(),
+                    m => panic!("unexpected family member `{m}`"),
+                });
+        assert_eq!(family.build(), Some(emoji));
+    }
+}
+
+#[test]
+fn family_member_order_does_not_matter() {
+    assert_eq!(
+        Family::new().boy().woman().girl().man().build(),
+        emojis::get("👨‍👩‍👧‍👦")
+    );
+}
+
+#[test]
+fn family_non_standard() {
+    assert_eq!(Family::new().build(), None);
+    assert_eq!(Family::new().man().build(), None);
+    assert_eq!(Family::new().girl().build(), None);
+    assert_eq!(Family::new().man().adult().child().build(), None);
+    assert_eq!(Family::new().man().woman().adult().boy().build(), None);
+    assert_eq!(Family::new().man().boy().boy().boy().build(), None);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "pulldown-cmark")]
+
+use pulldown_cmark::{Event, Parser};
+
+fn text_events(markdown: &str) -> Vec<String> {
+    emojis::markdown::expand_shortcodes(Parser::new(markdown))
+        .filter_map(|event| match event {
+            Event::Text(text) => Some(text.into_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn markdown_expand_shortcodes() {
+    assert_eq!(
+        text_events(":+1: :thumbs_up: :rocket:"),
+        ["👍 :thumbs_up: 🚀"]
+    );
+    assert_eq!(text_events("**:tada:** :custom:"), ["🎉", " :custom:"]);
+    assert_eq!(text_events("no shortcodes"), ["no shortcodes"]);
+}
+
+#[test]
+fn markdown_skips_code() {
+    assert_eq!(text_events("```\n:rocket:\n```"), [":rocket:\n"]);
+    let events: Vec<_> = emojis::markdown::expand_shortcodes(Parser::new("`:rocket:`")).collect();
+    assert!(events.contains(&Event::Code(":rocket:".into())));
+}
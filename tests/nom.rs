@@ -0,0 +1,30 @@
+#![cfg(feature = "nom")]
+
+use emojis::parse::nom::{emoji, emoji_or_shortcode, shortcode};
+use nom::bytes::complete::tag;
+use nom::error::{Error, ErrorKind};
+use nom::sequence::delimited;
+use nom::{Err, IResult, Parser};
+
+#[test]
+fn nom_parsers() {
+    let result: IResult<_, _> = delimited(tag("("), emoji, tag(")")).parse("(👩🏽‍🚀)!");
+    assert_eq!(result.unwrap(), ("!", emojis::get("👩🏽‍🚀").unwrap()));
+
+    let result: IResult<_, _> = shortcode(":+1: ok");
+    assert_eq!(result.unwrap(), (" ok", emojis::get("👍").unwrap()));
+
+    let result: IResult<_, _> = emoji_or_shortcode("🚀");
+    assert_eq!(result.unwrap().1, "🚀");
+}
+
+#[test]
+fn nom_errors() {
+    let result: IResult<_, _> = emoji("launch");
+    assert_eq!(
+        result.unwrap_err(),
+        Err::Error(Error::new("launch", ErrorKind::Tag))
+    );
+    let result: IResult<_, _> = shortcode(":custom:");
+    assert!(matches!(result, Err(Err::Error(_))));
+}
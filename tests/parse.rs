@@ -0,0 +1,40 @@
+use emojis::{parse, Error};
+
+#[test]
+fn parse_sequence() {
+    // A tiny grammar: a list of emojis or shortcodes separated by commas.
+    let mut input = "🚀,:tada:,👩🏽‍🚀";
+    let mut parsed = Vec::new();
+    loop {
+        let (rest, emoji) = parse::emoji_or_shortcode(input).unwrap();
+        parsed.push(emoji.as_str());
+        match rest.strip_prefix(',') {
+            Some(rest) => input = rest,
+            None => {
+                assert_eq!(rest, "");
+                break;
+            }
+        }
+    }
+    assert_eq!(parsed, ["🚀", "🎉", "👩🏽‍🚀"]);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse::emoji(""), Err(Error::NotAnEmoji));
+    assert_eq!(parse::shortcode(""), Err(Error::UnknownShortcode));
+    assert_eq!(parse::shortcode("::"), Err(Error::UnknownShortcode));
+    assert_eq!(parse::emoji_or_shortcode("x"), Err(Error::NotAnEmoji));
+}
+
+#[test]
+fn parse_every_emoji() {
+    for emoji in emojis::iter() {
+        let input = format!("{emoji}x");
+        assert_eq!(parse::emoji(&input), Ok(("x", emoji)));
+        for shortcode in emoji.shortcodes() {
+            let input = format!(":{shortcode}:x");
+            assert_eq!(parse::shortcode(&input), Ok(("x", emoji)));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use emojis::{Gender, Person, Role, SkinTone};
+
+const ROLES: &[Role] = &[
+    Role::HealthWorker,
+    Role::Student,
+    Role::Teacher,
+    Role::Judge,
+    Role::Farmer,
+    Role::Cook,
+    Role::Mechanic,
+    Role::FactoryWorker,
+    Role::OfficeWorker,
+    Role::Scientist,
+    Role::Technologist,
+    Role::Singer,
+    Role::Artist,
+    Role::Pilot,
+    Role::Astronaut,
+    Role::Firefighter,
+    Role::FeedingBaby,
+    Role::PoliceOfficer,
+    Role::Detective,
+    Role::Guard,
+    Role::ConstructionWorker,
+    Role::WearingTurban,
+    Role::InTuxedo,
+    Role::WithVeil,
+];
+
+const SKIN_TONES: &[SkinTone] = &[
+    SkinTone::Default,
+    SkinTone::Light,
+    SkinTone::MediumLight,
+    SkinTone::Medium,
+    SkinTone::MediumDark,
+    SkinTone::Dark,
+];
+
+#[test]
+fn person_every_role() {
+    for &role in ROLES {
+        for gender in [Gender::Neutral, Gender::Man, Gender::Woman] {
+            for &skin_tone in SKIN_TONES {
+                let emoji = Person::new()
+                    .gender(gender)
+                    .skin_tone(skin_tone)
+                    .role(role)
+                    .build()
+                    .unwrap_or_else(|| panic!("{role:?} {gender:?} {skin_tone:?}"));
+                assert_eq!(emoji.skin_tone(), Some(skin_tone));
+                let name = emoji.name();
+                match gender {
+                    Gender::Man => assert!(name.starts_with("man "), "{name}"),
+                    Gender::Woman => assert!(name.starts_with("woman "), "{name}"),
+                    Gender::Neutral => {
+                        assert!(!name.starts_with("man ") && !name.starts_with("woman "))
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn person_without_role() {
+    assert_eq!(Person::new().build().unwrap(), "🧑");
+    assert_eq!(
+        Person::new()
+            .gender(Gender::Woman)
+            .skin_tone(SkinTone::Dark)
+            .build()
+            .unwrap(),
+        "👩🏿"
+    );
+}
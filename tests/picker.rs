@@ -0,0 +1,56 @@
+use emojis::picker;
+
+#[test]
+fn rows_cover_every_emoji_once() {
+    for columns in [1, 5, 8, 13] {
+        let mut all = Vec::new();
+        let mut subgroups = Vec::new();
+        for row in picker::rows(columns) {
+            let emojis: Vec<_> = row.emojis().collect();
+            assert!(!emojis.is_empty() && emojis.len() <= columns);
+            assert!(emojis.iter().all(|e| e.group() == row.group()));
+            if row.is_subgroup_start() {
+                subgroups.push(row.subgroup());
+            } else {
+                assert_eq!(subgroups.last(), Some(&row.subgroup()));
+            }
+            all.extend(emojis);
+        }
+        assert!(all.iter().copied().eq(emojis::iter()));
+        let mut unique = subgroups.clone();
+        unique.dedup();
+        assert_eq!(unique, subgroups);
+    }
+}
+
+#[test]
+fn rows_are_full_except_at_subgroup_end() {
+    let rows: Vec<_> = picker::rows(8).collect();
+    for pair in rows.windows(2) {
+        if !pair[1].is_subgroup_start() {
+            assert_eq!(pair[0].emojis().count(), 8);
+        }
+    }
+}
+
+#[test]
+fn pages_split_at_groups() {
+    let pages: Vec<_> = picker::pages(8, 3).collect();
+    let rows: Vec<_> = pages.iter().flat_map(|p| p.rows()).collect();
+    assert_eq!(rows.len(), picker::rows(8).count());
+    for page in &pages {
+        let n = page.rows().count();
+        assert!((1..=3).contains(&n));
+        assert!(page.rows().all(|row| row.group() == page.group()));
+    }
+    let groups = pages.iter().map(|p| p.group());
+    let mut groups: Vec<_> = groups.collect();
+    groups.dedup();
+    assert!(groups.into_iter().eq(emojis::Group::iter()));
+}
+
+#[test]
+#[should_panic]
+fn rows_zero_columns() {
+    picker::rows(0);
+}
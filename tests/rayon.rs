@@ -0,0 +1,32 @@
+#![cfg(feature = "rayon")]
+
+use emojis::prelude::*;
+use rayon::prelude::*;
+
+#[test]
+fn rayon_par_iter_matches_iter() {
+    let emojis: Vec<_> = emojis::rayon::par_iter().collect();
+    assert!(emojis.into_iter().eq(emojis::iter()));
+}
+
+#[test]
+fn rayon_par_emojis_matches_emojis() {
+    let all: Vec<_> = emojis::iter().collect();
+    let text: String = all
+        .iter()
+        .cycle()
+        .take(50_000)
+        .enumerate()
+        .map(|(i, e)| match i % 3 {
+            0 => format!("{e} "),
+            1 => format!("{e}\n"),
+            _ => format!("{e}x"),
+        })
+        .collect();
+    let found: Vec<_> = emojis::rayon::par_emojis(&text).collect();
+    assert!(found.iter().map(|(_, e)| *e).eq(text.emojis()));
+    for (range, emoji) in found {
+        assert_eq!(&text[range], emoji.as_str());
+    }
+    assert_eq!(emojis::rayon::par_emojis("").count(), 0);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "schemars")]
+
+use emojis::{Emoji, Group, SkinTone};
+use serde_json::json;
+
+#[test]
+fn emoji_schema() {
+    let schema = schemars::schema_for!(Emoji);
+    assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "Emoji",
+            "type": "string",
+        })
+    );
+}
+
+#[test]
+fn group_schema() {
+    let schema = schemars::schema_for!(Group);
+    assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "Group",
+            "type": "string",
+            "enum": [
+                "SmileysAndEmotion",
+                "PeopleAndBody",
+                "AnimalsAndNature",
+                "FoodAndDrink",
+                "TravelAndPlaces",
+                "Activities",
+                "Objects",
+                "Symbols",
+                "Flags",
+            ],
+        })
+    );
+}
+
+#[test]
+fn skin_tone_schema() {
+    let schema = serde_json::to_value(schemars::schema_for!(SkinTone)).unwrap();
+    let variants = schema["enum"].as_array().unwrap();
+    assert_eq!(variants.len(), 26);
+    assert_eq!(variants[0], "Default");
+    assert_eq!(variants[25], "DarkAndMediumDark");
+}
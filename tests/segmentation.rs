@@ -0,0 +1,41 @@
+#![cfg(feature = "unicode-segmentation")]
+
+use emojis::segmentation::{emoji_at, graphemes};
+use emojis::Closest;
+
+#[test]
+fn graphemes_match_get() {
+    let text: String = emojis::iter().flat_map(|e| [e.as_str(), " "]).collect();
+    let found: Vec<_> = graphemes(&text)
+        .filter_map(|(g, closest)| match closest {
+            Some(Closest::Exact(emoji)) => Some((g, emoji)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(found.len(), emojis::iter().count());
+    for (g, emoji) in found {
+        assert_eq!(g, emoji.as_str());
+    }
+}
+
+#[test]
+fn emoji_at_every_offset() {
+    let text = "a 👨‍👩‍👧 b 👍🏽🏿";
+    let family = text.find('👨').unwrap();
+    for offset in (0..text.len()).filter(|&i| text.is_char_boundary(i)) {
+        match emoji_at(text, offset) {
+            Some((range, Closest::Exact(emoji))) => {
+                assert_eq!(range.start, family);
+                assert_eq!(emoji, "👨‍👩‍👧");
+            }
+            Some((range, Closest::Base(emoji))) => {
+                assert_eq!(&text[range], "👍🏽🏿");
+                assert_eq!(emoji, "👍");
+            }
+            Some((_, Closest::Components(_))) => panic!("unexpected components"),
+            None => assert!(text[offset..].starts_with([' ', 'a', 'b'])),
+        }
+    }
+    assert!(emoji_at(text, text.len()).is_none());
+    assert!(emoji_at(text, family + 1).is_none());
+}
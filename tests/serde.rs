@@ -0,0 +1,57 @@
+#![cfg(feature = "serde")]
+
+use emojis::Emoji;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
+struct Test {
+    #[serde(with = "emojis::serde::as_unicode")]
+    unicode: &'static Emoji,
+    #[serde(with = "emojis::serde::as_shortcode")]
+    shortcode: &'static Emoji,
+}
+
+#[test]
+fn roundtrip_json() {
+    let test = Test {
+        unicode: emojis::get("🚀").unwrap(),
+        shortcode: emojis::get("🚀").unwrap(),
+    };
+    let serialized = serde_json::to_string(&test).unwrap();
+    assert_eq!(serialized, r#"{"unicode":"🚀","shortcode":":rocket:"}"#);
+    let deserialized: Test = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, test);
+}
+
+#[test]
+fn deserialize_lenient() {
+    let test: Test = serde_json::from_str(r#"{"unicode":"☹","shortcode":"satisfied"}"#).unwrap();
+    assert_eq!(test.unicode, "☹️");
+    assert_eq!(test.shortcode, "😆");
+}
+
+#[test]
+fn deserialize_invalid() {
+    let err = serde_json::from_str::<Test>(r#"{"unicode":"invalid","shortcode":":rocket:"}"#)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"invalid\", expected a string representing an emoji at line 1 column 20"
+    );
+
+    let err = serde_json::from_str::<Test>(r#"{"unicode":"🚀","shortcode":":nope:"}"#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \":nope:\", expected a string representing an emoji shortcode at line 1 column 38"
+    );
+}
+
+#[test]
+fn serialize_no_shortcode() {
+    let test = Test {
+        unicode: emojis::get("🚀").unwrap(),
+        shortcode: emojis::get("🙂‍↔️").unwrap(),
+    };
+    let err = serde_json::to_string(&test).unwrap_err();
+    assert_eq!(err.to_string(), "emoji `🙂‍↔️` does not have a shortcode");
+}
@@ -16,6 +16,22 @@ fn iter_only_default_skin_tones() {
     );
 }
 
+#[test]
+fn iter_up_to_excludes_newer_versions() {
+    let version = UnicodeVersion::new(15, 1);
+    assert!(emojis::iter_up_to(version).all(|emoji| emoji.unicode_version() <= version));
+    assert_eq!(
+        emojis::iter_up_to(version).count(),
+        emojis::iter()
+            .filter(|emoji| emoji.unicode_version() <= version)
+            .count()
+    );
+    assert_eq!(
+        emojis::iter_up_to(UnicodeVersion::new(u32::MAX, 0)).count(),
+        emojis::iter().count()
+    );
+}
+
 #[test]
 fn unicode_version_partial_ord() {
     assert!(UnicodeVersion::new(13, 0) >= UnicodeVersion::new(12, 0));
@@ -103,3 +119,997 @@ fn group_iter_and_emojis() {
     let right: Vec<_> = emojis::iter().collect();
     assert_eq!(left, right);
 }
+
+#[test]
+fn flag_classification() {
+    for emoji in emojis::iter() {
+        let kinds = [
+            emoji.is_country_flag(),
+            emoji.is_subdivision_flag(),
+            emoji.is_pride_or_other_flag(),
+        ];
+        let count = kinds.iter().filter(|&&k| k).count();
+        if emoji.group() == emojis::Group::Flags {
+            assert_eq!(count, 1, "{emoji} should be exactly one kind of flag");
+        } else {
+            assert_eq!(count, 0, "{emoji} is not a flag");
+        }
+    }
+    assert_eq!(
+        emojis::iter().filter(|e| e.is_subdivision_flag()).count(),
+        3
+    );
+}
+
+#[test]
+fn person_count_is_the_same_for_all_skin_tones() {
+    for emoji in emojis::iter() {
+        for skin_tone in emoji.skin_tones().into_iter().flatten() {
+            assert_eq!(skin_tone.person_count(), emoji.person_count());
+        }
+    }
+    assert!(
+        emojis::iter().all(|e| e.group() == emojis::Group::PeopleAndBody || e.person_count() == 0)
+    );
+}
+
+#[test]
+fn suggest_ranks_exact_matches_first() {
+    let suggestions: Vec<_> = emojis::suggest("heart").map(|e| e.as_str()).collect();
+    assert_eq!(suggestions[0], "❤️");
+    assert!(suggestions.contains(&"💔"));
+    assert!(emojis::suggest("Rocket").any(|e| e == "🚀"));
+    assert_eq!(emojis::suggest("").count(), 0);
+    assert_eq!(emojis::suggest("xyzzy").count(), 0);
+}
+
+#[test]
+fn iter_by_name_is_sorted() {
+    let names: Vec<_> = emojis::iter_by_name()
+        .map(|e| e.name().to_lowercase())
+        .collect();
+    assert!(names.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(names.len(), emojis::iter().count());
+}
+
+#[test]
+fn iter_by_version_is_sorted() {
+    let emojis: Vec<_> = emojis::iter_by_version().collect();
+    assert!(emojis
+        .windows(2)
+        .all(|w| w[0].unicode_version() <= w[1].unicode_version()));
+    assert_eq!(emojis.len(), emojis::iter().count());
+    assert_eq!(emojis[0], "😃");
+}
+
+#[test]
+fn grouped_matches_group_emojis() {
+    let groups: Vec<_> = emojis::grouped().map(|(group, _)| group).collect();
+    assert_eq!(groups, emojis::Group::iter().collect::<Vec<_>>());
+    for (group, emojis) in emojis::grouped() {
+        assert!(emojis.eq(group.emojis()));
+    }
+}
+
+#[test]
+fn emoji_str_ext() {
+    use emojis::prelude::*;
+
+    assert_eq!("☹".to_emoji(), emojis::get("☹️"));
+    assert!("#️⃣".contains_emoji());
+    assert!(!"# 1 *".contains_emoji());
+    let emojis: Vec<_> = "🇿🇦🇬🇧👩‍👩‍👧 🤌".emojis().map(|e| e.as_str()).collect();
+    assert_eq!(emojis, ["🇿🇦", "🇬🇧", "👩‍👩‍👧", "🤌"]);
+}
+
+#[test]
+fn shortcodes_with_prefix() {
+    assert!(emojis::shortcodes_with_prefix("").eq(emojis::shortcodes::iter()));
+    assert!(emojis::shortcodes_with_prefix("he").all(|(s, _)| s.starts_with("he")));
+    assert!(emojis::shortcodes_with_prefix("he").any(|(s, _)| s == "heart"));
+    assert_eq!(emojis::shortcodes_with_prefix("zzzz").count(), 0);
+}
+
+#[test]
+fn shortcodes_iter() {
+    assert_eq!(
+        emojis::shortcodes::iter().count(),
+        emojis::iter()
+            .flat_map(|e| {
+                let skin_tones = e.skin_tones();
+                let default = skin_tones.is_none().then(|| e);
+                skin_tones.into_iter().flatten().chain(default)
+            })
+            .map(|e| e.shortcodes().count())
+            .sum::<usize>()
+    );
+    for (shortcode, emoji) in emojis::shortcodes::iter() {
+        assert_eq!(emojis::get_by_shortcode(shortcode), Some(emoji));
+    }
+}
+
+#[test]
+fn expand_shortcodes() {
+    let tests = [
+        ("launch nothing", "launch nothing"),
+        ("launch :rocket: something", "launch 🚀 something"),
+        ("? :unknown: emoji", "? :unknown: emoji"),
+        ("::very:naughty::", "::very:naughty::"),
+        (":maybe:rocket:", ":maybe🚀"),
+        (":rocket::rocket:", "🚀🚀"),
+        ("trailing :rocket", "trailing :rocket"),
+    ];
+    for (input, expected) in tests {
+        let mut out = String::new();
+        emojis::expand_shortcodes(input, &mut out).unwrap();
+        assert_eq!(out, expected);
+    }
+}
+
+#[test]
+fn expand_shortcodes_with() {
+    use emojis::shortcodes::{Builtin, ShortcodeResolver};
+
+    struct Custom;
+
+    impl ShortcodeResolver for Custom {
+        fn resolve(&self, name: &str) -> Option<&str> {
+            match name {
+                "rocket" => Some("[rocket]"),
+                "parrot" => Some("🦜"),
+                _ => None,
+            }
+        }
+    }
+
+    let tests = [
+        (
+            ":rocket: :parrot: :tada:",
+            &Custom as &dyn ShortcodeResolver,
+            "[rocket] 🦜 :tada:",
+        ),
+        (
+            ":rocket: :parrot: :tada:",
+            &(Custom, Builtin),
+            "[rocket] 🦜 🎉",
+        ),
+        (":rocket: :parrot: :tada:", &(Builtin, Custom), "🚀 🦜 🎉"),
+    ];
+    for (input, resolver, expected) in tests {
+        let mut out = String::new();
+        emojis::expand_shortcodes_with(input, resolver, &mut out).unwrap();
+        assert_eq!(out, expected);
+    }
+
+    let tokens: Vec<_> = emojis::tokenize_with(":rocket:", Custom).collect();
+    assert_eq!(
+        tokens,
+        [(
+            0..8,
+            emojis::Token::Shortcode {
+                name: "rocket",
+                emoji: None
+            }
+        )]
+    );
+}
+
+#[test]
+fn tokenize() {
+    use emojis::Token;
+
+    assert_eq!(emojis::tokenize("").next(), None);
+
+    let text = "hi 👋🏽:wave::x y: ::#️⃣";
+    let wave = emojis::get_by_shortcode("wave").unwrap();
+    let tokens: Vec<_> = emojis::tokenize(text).collect();
+    assert_eq!(
+        tokens,
+        [
+            (0..3, Token::Text("hi ")),
+            (3..11, Token::Emoji(emojis::get("👋🏽").unwrap())),
+            (
+                11..17,
+                Token::Shortcode {
+                    name: "wave",
+                    emoji: Some(wave)
+                }
+            ),
+            (17..25, Token::Text(":x y: ::")),
+            (25..32, Token::Emoji(emojis::get("#️⃣").unwrap())),
+        ]
+    );
+    for (range, token) in tokens {
+        match token {
+            Token::Text(s) => assert_eq!(&text[range], s),
+            Token::Emoji(e) => assert_eq!(&text[range], e.as_str()),
+            Token::Shortcode { name, .. } => assert_eq!(text[range], format!(":{name}:")),
+        }
+    }
+}
+
+#[test]
+fn cldr_order() {
+    let all: Vec<_> = emojis::iter()
+        .flat_map(|e| {
+            e.skin_tones()
+                .into_iter()
+                .flatten()
+                .chain(e.skin_tones().is_none().then(|| e))
+        })
+        .collect();
+    for (i, emoji) in all.iter().enumerate() {
+        assert_eq!(emoji.cldr_order(), i, "{}", emoji.name());
+    }
+}
+
+#[test]
+fn get_base() {
+    let tests = [
+        ("🚀", "🚀"),
+        ("☹", "☹️"),
+        ("👋🏿", "👋"),
+        ("👋🏿🏿", "👋"),
+        ("🧑🏼‍🦳", "🧑"),
+        ("👨‍🦲", "👨"),
+        ("🤷🏾‍♂️", "🤷"),
+        ("🤷‍♂", "🤷"),
+        ("👩🏽‍❤️‍💋‍👨🏿", "👩‍❤️‍💋‍👨"),
+        ("🏳️‍🌈", "🏳️‍🌈"),
+    ];
+    for (input, expected) in tests {
+        assert_eq!(emojis::get_base(input).unwrap(), expected, "{input}");
+    }
+    assert!(emojis::get_base("").is_none());
+    assert!(emojis::get_base(&"🚀".repeat(20)).is_none());
+}
+
+#[test]
+fn get_closest() {
+    use emojis::Closest;
+
+    let components = |s| match emojis::get_closest(s) {
+        Some(Closest::Components(c)) => c.map(|e| e.as_str()).collect::<Vec<_>>(),
+        _ => panic!("expected components for {s}"),
+    };
+    assert_eq!(components("👩🏽‍👦‍👦‍🐈"), ["👩🏽", "👦", "👦", "🐈"]);
+    assert_eq!(components("rocket‍🚀"), ["🚀"]);
+    assert!(emojis::get_closest("").is_none());
+    assert!(emojis::get_closest("a‍b").is_none());
+}
+
+#[test]
+fn unicode_name() {
+    for emoji in emojis::iter() {
+        let single = emoji.as_str().trim_end_matches('\u{fe0f}').chars().count() == 1;
+        assert_eq!(emoji.unicode_name().is_some(), single, "{}", emoji.name());
+    }
+    assert_eq!(
+        emojis::get("😂").unwrap().unicode_name(),
+        Some("FACE WITH TEARS OF JOY")
+    );
+}
+
+#[test]
+fn components() {
+    use emojis::components::{self, Kind};
+
+    for component in components::iter() {
+        assert!(emojis::get(&component.as_char().to_string()).is_none());
+        assert_eq!(components::get(component.as_char()), Some(component));
+    }
+    let skin_tones: String = components::iter()
+        .filter(|c| c.kind() == Kind::SkinTone)
+        .map(|c| c.as_char())
+        .collect();
+    assert_eq!(skin_tones, "🏻🏼🏽🏾🏿");
+}
+
+#[test]
+fn utf16_len() {
+    let all = emojis::iter().flat_map(|e| {
+        e.skin_tones()
+            .into_iter()
+            .flatten()
+            .chain(e.skin_tones().is_none().then(|| e))
+    });
+    for emoji in all {
+        assert_eq!(emoji.utf16_len(), emoji.encode_utf16().count());
+    }
+}
+
+#[test]
+fn percent_encoded() {
+    let encoded = emojis::get("0️⃣").unwrap().percent_encoded().to_string();
+    assert_eq!(encoded, "0%EF%B8%8F%E2%83%A3");
+    let encoded = emojis::get("*️⃣").unwrap().percent_encoded().to_string();
+    assert_eq!(encoded, "%2A%EF%B8%8F%E2%83%A3");
+}
+
+#[test]
+fn partial_eq_char() {
+    assert!(*emojis::get("🚀").unwrap() == '🚀');
+    assert!(*emojis::get("🚀").unwrap() != '🛸');
+    assert!(*emojis::get("☹️").unwrap() != '☹');
+    assert!(*emojis::get("👋🏽").unwrap() != '👋');
+}
+
+#[test]
+fn borrow_str() {
+    use std::collections::HashMap;
+
+    let map: HashMap<&emojis::Emoji, usize> = emojis::iter().zip(0..).collect();
+    assert_eq!(map.get("😀"), Some(&0));
+    assert_eq!(map.get("☹"), None);
+}
+
+#[test]
+fn counts() {
+    assert_eq!(emojis::COUNT, emojis::iter().count());
+    let with_skin_tones: usize = emojis::iter()
+        .map(|e| e.skin_tones().map_or(1, |t| t.count()))
+        .sum();
+    assert_eq!(emojis::COUNT_WITH_SKIN_TONES, with_skin_tones);
+    assert_eq!(
+        emojis::Group::iter().map(|g| g.len()).sum::<usize>(),
+        emojis::COUNT
+    );
+    assert_eq!(
+        emojis::Group::iter()
+            .map(|g| g.len_with_skin_tones())
+            .sum::<usize>(),
+        emojis::COUNT_WITH_SKIN_TONES
+    );
+}
+
+#[test]
+fn keycaps_and_symbols() {
+    let keycaps: Vec<_> = emojis::iter().filter(|e| e.is_keycap()).collect();
+    assert_eq!(keycaps.len(), 12);
+    let symbols: Vec<_> = emojis::iter().filter(|e| e.is_symbol_like()).collect();
+    assert!(symbols.iter().any(|e| *e == "☺️"));
+    for emoji in symbols {
+        assert!(emoji.unicode_name().is_some(), "{}", emoji.name());
+    }
+}
+
+#[test]
+fn get_by_codepoints() {
+    use emojis::Error;
+
+    let tests = [
+        ("U+1F680", Ok("🚀")),
+        ("u+1f680", Ok("🚀")),
+        ("  U+2639\tU+FE0F ", Ok("☹️")),
+        ("U+2639", Ok("☹️")),
+        ("1F3F4 E0067 E0062 E0073 E0063 E0074 E007F", Ok("🏴󠁧󠁢󠁳󠁣󠁴󠁿")),
+        ("", Err(Error::NotAnEmoji)),
+        ("U+", Err(Error::InvalidCodepoint)),
+        ("U++1F680", Err(Error::InvalidCodepoint)),
+        ("U+D800", Err(Error::InvalidCodepoint)),
+        ("U+110000", Err(Error::InvalidCodepoint)),
+        ("U+1F680 U+1F680", Err(Error::NotAnEmoji)),
+    ];
+    for (input, expected) in tests {
+        assert_eq!(
+            emojis::get_by_codepoints(input).map(|e| e.as_str()),
+            expected,
+            "{input}"
+        );
+    }
+    for emoji in emojis::iter() {
+        let code_points: Vec<_> = emoji
+            .as_str()
+            .chars()
+            .map(|c| format!("U+{:04X}", c as u32))
+            .collect();
+        assert_eq!(emojis::get_by_codepoints(&code_points.join(" ")), Ok(emoji));
+    }
+}
+
+#[test]
+fn folded_name() {
+    for emoji in emojis::iter() {
+        let folded = emoji.folded_name();
+        assert!(folded.is_ascii(), "{folded}");
+        assert_eq!(folded, folded.to_lowercase());
+    }
+    let e = emojis::get("🕛").unwrap();
+    assert_eq!(e.folded_name(), "twelve o'clock");
+    assert_eq!(emojis::suggest("pinata").next().unwrap(), "🪅");
+    assert_eq!(emojis::suggest("piñata").next().unwrap(), "🪅");
+    assert_eq!(emojis::suggest("reunion").next().unwrap(), "🇷🇪");
+}
+
+#[test]
+fn canonical_normalization() {
+    use unicode_normalization::UnicodeNormalization;
+
+    let all = emojis::iter().flat_map(|e| {
+        e.skin_tones()
+            .into_iter()
+            .flatten()
+            .chain(e.skin_tones().is_none().then(|| e))
+    });
+    for emoji in all {
+        let s = emoji.as_str();
+        assert_eq!(s.nfc().collect::<String>(), s);
+        assert_eq!(s.nfd().collect::<String>(), s);
+    }
+}
+
+#[test]
+fn get_bytes() {
+    for emoji in emojis::iter() {
+        assert_eq!(emojis::get_bytes(emoji.as_bytes()), Some(emoji));
+    }
+    assert_eq!(emojis::get_bytes("☹".as_bytes()).unwrap(), "☹️");
+    assert!(emojis::get_bytes(b"").is_none());
+    assert!(emojis::get_bytes(b"\xf0\x9f\x9a").is_none());
+    assert!(emojis::get_bytes(&[0xf0; 1024]).is_none());
+}
+
+#[test]
+fn skin_tone_modifiers() {
+    for emoji in emojis::iter().filter_map(|e| e.skin_tones()).flatten() {
+        let modifiers: Vec<_> = emoji.skin_tone_modifiers().collect();
+        for (i, _) in &modifiers {
+            assert!(emoji.as_str()[*i..].starts_with(|c| ('🏻'..='🏿').contains(&c)));
+        }
+        if emoji.skin_tone() == Some(emojis::SkinTone::Default) {
+            assert!(modifiers.is_empty());
+        } else {
+            assert!(!modifiers.is_empty(), "{}", emoji.name());
+        }
+    }
+}
+
+#[test]
+fn neutral_form() {
+    let tests = [
+        ("👨‍⚕️", "🧑‍⚕️"),
+        ("👩🏿‍🦰", "🧑🏿‍🦰"),
+        ("🧔‍♀️", "🧔"),
+        ("👸🏼", "🫅🏼"),
+        ("👭🏾", "🧑🏾‍🤝‍🧑🏾"),
+        ("👩🏻‍🤝‍👨🏿", "🧑🏻‍🤝‍🧑🏿"),
+        ("👨‍👩‍👧‍👦", "🧑‍🧑‍🧒‍🧒"),
+        ("♀️", "♀️"),
+        ("🧑‍🚀", "🧑‍🚀"),
+        ("👩‍❤️‍👨", "💑"),
+        ("👨🏽‍❤️‍👨🏽", "💑🏽"),
+        ("👩🏻‍❤️‍👨🏿", "🧑🏻‍❤️‍🧑🏿"),
+        ("👩‍❤️‍💋‍👩", "💏"),
+        ("👩🏾‍❤️‍💋‍👨🏾", "💏🏾"),
+        ("🎅", "🧑‍🎄"),
+        ("🤶🏼", "🧑🏼‍🎄"),
+        ("🤰", "🫄"),
+        ("🫃🏿", "🫄🏿"),
+    ];
+    for (input, expected) in tests {
+        assert_eq!(
+            emojis::get(input).unwrap().neutral_form(),
+            expected,
+            "{input}"
+        );
+    }
+    let all = emojis::iter().flat_map(|e| {
+        e.skin_tones()
+            .into_iter()
+            .flatten()
+            .chain(e.skin_tones().is_none().then(|| e))
+    });
+    for emoji in all {
+        let neutral = emoji.neutral_form();
+        assert_eq!(neutral.neutral_form(), neutral);
+        assert_eq!(
+            neutral.skin_tone().is_some(),
+            emoji.skin_tone().is_some(),
+            "{}",
+            emoji.name()
+        );
+    }
+}
+
+#[test]
+fn color_variants() {
+    let variants = |s: &str| -> String {
+        emojis::get(s)
+            .unwrap()
+            .color_variants()
+            .map(|e| e.as_str())
+            .collect()
+    };
+    assert_eq!(variants("❤️"), "❤️🩷🧡💛💚💙🩵💜🤎🖤🩶🤍");
+    assert_eq!(variants("🔶"), "🔶🔷");
+    assert_eq!(variants("⚫"), "🔴🟠🟡🟢🔵🟣🟤⚫⚪");
+    assert_eq!(variants("🚀"), "");
+
+    for emoji in emojis::iter() {
+        for variant in emoji.color_variants() {
+            assert!(variant.color().is_some());
+            assert!(variant.color_variants().any(|e| e == emoji));
+        }
+    }
+}
+
+#[test]
+fn category() {
+    for category in emojis::Category::iter() {
+        assert!(category.emojis().count() > 0, "{category:?}");
+        for emoji in category.emojis() {
+            assert_eq!(emoji.category(), Some(category));
+        }
+    }
+    assert_eq!(
+        emojis::get("🐸").unwrap().category(),
+        Some(emojis::Category::Amphibian)
+    );
+    assert_eq!(emojis::get("😺").unwrap().category(), None);
+}
+
+#[test]
+fn release_cohort() {
+    let v = emojis::UnicodeVersion::new(13, 0);
+    let cohort = emojis::get("🥲").unwrap().release_cohort();
+    assert_eq!(cohort.unicode_version(), v);
+    assert_eq!(cohort.to_string(), "Emoji 13.0");
+    for emoji in emojis::iter() {
+        assert_eq!(
+            emoji.is_new_in(v),
+            emoji.release_cohort().unicode_version() >= v
+        );
+    }
+}
+
+#[test]
+fn get_with_base() {
+    for emoji in emojis::iter().flat_map(|e| e.skin_tones().into_iter().flatten()) {
+        let (e, base) = emojis::get_with_base(emoji.as_str()).unwrap();
+        assert_eq!(e, emoji);
+        assert_eq!(Some(base), emoji.with_skin_tone(emojis::SkinTone::Default));
+    }
+    let (e, base) = emojis::get_with_base("\u{263a}").unwrap();
+    assert_eq!(e, "☺️");
+    assert_eq!(base, "☺️");
+    assert!(emojis::get_with_base("rocket").is_none());
+}
+
+#[test]
+fn compact_id() {
+    let mut count = 0;
+    for emoji in
+        emojis::iter().flat_map(|e| e.skin_tones().map_or_else(|| vec![e], Iterator::collect))
+    {
+        let id = emoji.to_compact_id();
+        assert_eq!(emojis::Emoji::from_compact_id(id), Some(emoji));
+        count += 1;
+    }
+    assert!(emojis::Emoji::from_compact_id(count).is_none());
+
+    // Ids must never change between releases.
+    let pinned = [("🚀", 685), ("👋🏽", 1020), ("🥲", 3228), ("🫨", 3642)];
+    for (s, id) in pinned {
+        assert_eq!(emojis::get(s).unwrap().to_compact_id(), id, "{s}");
+    }
+}
+
+#[test]
+fn default_presentation() {
+    use emojis::Presentation;
+
+    for emoji in emojis::iter() {
+        let mut chars = emoji.as_str().chars();
+        let c = chars.next().unwrap();
+        let expected = match chars.as_str() {
+            "" => Some(Presentation::Emoji),
+            "\u{fe0f}" => Some(Presentation::Text),
+            _ => continue,
+        };
+        assert_eq!(emojis::default_presentation(c), expected, "{emoji}");
+    }
+    assert_eq!(emojis::default_presentation('#'), None);
+    assert_eq!(emojis::default_presentation('🇿'), None);
+}
+
+#[test]
+fn group_version_histogram() {
+    for group in emojis::Group::iter() {
+        let mut prev = None;
+        for (version, count) in group.version_histogram() {
+            assert!(prev < Some(version));
+            prev = Some(version);
+            let expected = group
+                .emojis()
+                .filter(|e| e.unicode_version() == version)
+                .count();
+            assert_eq!(count, expected, "{group:?} {version}");
+            assert_eq!(
+                group.len_up_to(version),
+                group.emojis_up_to(version).count()
+            );
+        }
+    }
+}
+
+#[test]
+fn error_display() {
+    use emojis::Error;
+
+    assert_eq!(Error::NotAnEmoji.to_string(), "not an emoji");
+    assert_eq!(Error::InvalidCodepoint.to_string(), "invalid code point");
+    assert_eq!(Error::UnknownShortcode.to_string(), "unknown shortcode");
+    assert_eq!(Error::BufferTooSmall.to_string(), "buffer too small");
+    assert_eq!(Error::UnknownCode.to_string(), "unknown code");
+}
+
+#[test]
+fn qualified() {
+    use emojis::Qualification;
+
+    for emoji in emojis::iter() {
+        assert_eq!(
+            emoji.qualified(Qualification::Fully).to_string(),
+            emoji.as_str()
+        );
+        let unqualified = emoji.qualified(Qualification::Unqualified).to_string();
+        assert!(!unqualified.contains('\u{fe0f}'));
+        for q in [Qualification::Minimal, Qualification::Unqualified] {
+            if let Some(e) = emojis::get(&emoji.qualified(q).to_string()) {
+                assert_eq!(e, emoji);
+            }
+        }
+    }
+}
+
+#[test]
+fn write_to() {
+    let mut buf = [0xff; 64];
+    for emoji in emojis::iter() {
+        let len = emoji.utf8_len();
+        assert_eq!(len, emoji.as_str().len());
+        assert_eq!(emoji.write_to(&mut buf), Ok(len));
+        assert_eq!(&buf[..len], emoji.as_bytes());
+        assert_eq!(
+            emoji.write_to(&mut buf[..len - 1]),
+            Err(emojis::Error::BufferTooSmall)
+        );
+    }
+}
+
+#[test]
+fn group_codes() {
+    use std::convert::TryFrom;
+
+    use emojis::Group;
+
+    // These codes must never change.
+    let codes = [
+        (Group::SmileysAndEmotion, 0),
+        (Group::PeopleAndBody, 1),
+        (Group::AnimalsAndNature, 2),
+        (Group::FoodAndDrink, 3),
+        (Group::TravelAndPlaces, 4),
+        (Group::Activities, 5),
+        (Group::Objects, 6),
+        (Group::Symbols, 7),
+        (Group::Flags, 8),
+    ];
+    for (group, code) in codes {
+        assert_eq!(group.as_u8(), code);
+        assert_eq!(Group::try_from(code), Ok(group));
+    }
+    assert_eq!(Group::try_from(9), Err(emojis::Error::UnknownCode));
+    assert_eq!(Group::iter().count(), codes.len());
+}
+
+#[test]
+fn skin_tone_codes() {
+    use std::convert::TryFrom;
+
+    use emojis::SkinTone;
+
+    for (i, tone) in SkinTone::iter().enumerate() {
+        assert_eq!(tone.as_u8() as usize, i);
+        assert_eq!(SkinTone::try_from(tone.as_u8()), Ok(tone));
+    }
+    // These codes must never change.
+    assert_eq!(SkinTone::Default.as_u8(), 0);
+    assert_eq!(SkinTone::Dark.as_u8(), 5);
+    assert_eq!(SkinTone::DarkAndMediumDark.as_u8(), 25);
+    assert_eq!(SkinTone::try_from(26), Err(emojis::Error::UnknownCode));
+
+    let tones: Vec<_> = emojis::get("🧑‍🤝‍🧑")
+        .unwrap()
+        .skin_tones()
+        .unwrap()
+        .map(|e| e.skin_tone().unwrap())
+        .collect();
+    assert_eq!(tones, SkinTone::iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn subgroup_slices() {
+    use emojis::{Group, Subgroup};
+
+    let mut total = 0;
+    for group in Group::iter() {
+        let slice = group.as_slice();
+        assert_eq!(slice.len(), group.len_with_skin_tones());
+        assert!(slice.iter().all(|e| e.group() == group));
+        let len: usize = group.subgroups().map(|s| s.as_slice().len()).sum();
+        assert_eq!(len, slice.len());
+        total += slice.len();
+    }
+    assert_eq!(total, emojis::COUNT_WITH_SKIN_TONES);
+    for subgroup in Subgroup::iter() {
+        assert_eq!(Subgroup::from_name(subgroup.name()), Some(subgroup));
+        for emoji in subgroup.as_slice() {
+            assert_eq!(emoji.subgroup(), subgroup);
+            assert_eq!(emoji.group(), subgroup.group());
+        }
+    }
+}
+
+#[test]
+fn get_const() {
+    const LAST: Option<&emojis::Emoji> = emojis::get_const("🏴󠁧󠁢󠁷󠁬󠁳󠁿");
+    assert_eq!(LAST, emojis::get("🏴󠁧󠁢󠁷󠁬󠁳󠁿"));
+
+    for emoji in emojis::iter() {
+        assert_eq!(emojis::get_const(emoji.as_str()), Some(emoji));
+    }
+    assert!(emojis::get_const("").is_none());
+    assert!(emojis::get_const("rocket").is_none());
+}
+
+#[test]
+fn line_breaks() {
+    let breaks = |text| emojis::line_breaks(text).collect::<Vec<usize>>();
+    assert_eq!(breaks("").len(), 0);
+    assert_eq!(breaks("no emojis").len(), 0);
+    assert_eq!(breaks("👨‍👩‍👧‍👦").len(), 0);
+    assert_eq!(breaks("a👨‍👩‍👧‍👦b"), [1, 26]);
+    assert_eq!(breaks("🇿🇦#️⃣1"), [8]);
+    assert_eq!(breaks("a©️b").len(), 0);
+    assert_eq!(breaks("a™️‼️b").len(), 0);
+    assert_eq!(breaks("©️🚀"), [5]);
+    assert_eq!(breaks("\"🚀\" $🚀 🚀% 🚀, 🚀-").len(), 0);
+    assert_eq!(breaks("🚀\u{2060}🚀").len(), 0);
+}
+
+#[test]
+fn confusables() {
+    for emoji in emojis::iter() {
+        for other in emoji.confusables() {
+            assert_ne!(other, emoji);
+            assert!(other.confusables().any(|e| e == emoji));
+        }
+    }
+    let flag = emojis::get("🇲🇨").unwrap();
+    assert!(flag.confusables().eq([emojis::get("🇮🇩").unwrap()]));
+}
+
+#[test]
+fn base_emoji() {
+    use emojis::BaseEmoji;
+
+    for emoji in emojis::iter() {
+        let base = BaseEmoji::from(emoji);
+        assert_eq!(base.emoji(), emoji);
+        assert_eq!(base.base(), emoji);
+        for tone in emoji.skin_tones().into_iter().flatten() {
+            assert_eq!(BaseEmoji::from(tone), base);
+            assert_eq!(BaseEmoji::from(tone).emoji(), tone);
+        }
+    }
+    let thumbs = BaseEmoji::from(emojis::get("👍🏽").unwrap());
+    assert_ne!(thumbs, BaseEmoji::from(emojis::get("👎🏽").unwrap()));
+}
+
+#[test]
+fn emoji_start_ranges() {
+    let ranges = emojis::EMOJI_START_RANGES;
+    assert!(ranges
+        .windows(2)
+        .all(|w| (w[0].1 as u32) + 1 < w[1].0 as u32));
+    for emoji in emojis::iter() {
+        for s in core::iter::once(emoji).chain(emoji.skin_tones().into_iter().flatten()) {
+            let c = s.as_str().chars().next().unwrap();
+            assert!(ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi));
+        }
+    }
+}
+
+#[test]
+fn names_with_prefix() {
+    let all: Vec<_> = emojis::names_with_prefix("").collect();
+    assert!(all.iter().copied().eq(emojis::iter()));
+    let smiling: Vec<_> = emojis::names_with_prefix("Smiling").collect();
+    assert!(!smiling.is_empty());
+    assert!(smiling.iter().all(|e| e.name().starts_with("smiling")));
+    assert!(emojis::names_with_prefix("pinata").any(|e| e == "🪅"));
+    assert_eq!(emojis::names_with_prefix("xyzzy").count(), 0);
+}
+
+#[test]
+fn qualify() {
+    use emojis::Qualification;
+
+    for emoji in emojis::iter() {
+        assert_eq!(emojis::qualify(emoji.as_str()), Some(emoji.as_str()));
+        assert!(!emojis::is_unqualified(emoji.as_str()));
+        for q in [Qualification::Minimal, Qualification::Unqualified] {
+            let s = emoji.qualified(q).to_string();
+            if let Some(qualified) = emojis::qualify(&s) {
+                assert_eq!(qualified, emoji.as_str());
+                assert_eq!(emojis::is_unqualified(&s), s != emoji.as_str());
+            }
+        }
+    }
+}
+
+#[test]
+fn ascii_fallback() {
+    for emoji in emojis::iter() {
+        if let Some(fallback) = emoji.ascii_fallback() {
+            assert!(fallback.is_ascii());
+        }
+        for tone in emoji.skin_tones().into_iter().flatten() {
+            assert_eq!(tone.ascii_fallback(), emoji.ascii_fallback());
+        }
+    }
+    assert_eq!(emojis::get("☹").unwrap().ascii_fallback(), Some(":("));
+}
+
+#[test]
+fn emoji_id() {
+    use emojis::EmojiId;
+
+    let mut all = Vec::new();
+    for emoji in emojis::iter() {
+        match emoji.skin_tones() {
+            Some(tones) => all.extend(tones),
+            None => all.push(emoji),
+        }
+    }
+    assert_eq!(all.len(), emojis::COUNT_WITH_SKIN_TONES);
+    for (i, emoji) in all.into_iter().enumerate() {
+        let id = EmojiId::new(i).unwrap();
+        assert_eq!(emoji.id(), id);
+        assert_eq!(id.emoji(), emoji);
+    }
+    for group in emojis::Group::iter() {
+        let ids: Vec<_> = group.as_slice().iter().map(|e| e.id().index()).collect();
+        assert!(ids.windows(2).all(|w| w[0] + 1 == w[1]));
+    }
+}
+
+#[test]
+fn suggest_with_skin_tones() {
+    use emojis::SearchOptions;
+
+    let collapsed: Vec<_> = emojis::suggest_with("hand", SearchOptions::new()).collect();
+    assert!(collapsed.iter().copied().eq(emojis::suggest("hand")));
+    let all: Vec<_> = emojis::suggest_with("hand", SearchOptions::new().skin_tones(true)).collect();
+    let expected: Vec<_> = collapsed
+        .iter()
+        .flat_map(|e| match e.skin_tones() {
+            Some(tones) => tones.collect(),
+            None => vec![*e],
+        })
+        .collect();
+    assert_eq!(all, expected);
+    assert!(all.len() > collapsed.len());
+}
+
+#[test]
+fn suggest_with_weights() {
+    use emojis::SearchOptions;
+
+    let shortcodes_only = SearchOptions::new().name_weight(0);
+    assert!(emojis::suggest_with("party", shortcodes_only).eq([emojis::get("🥳").unwrap()]));
+    let names_only = SearchOptions::new().shortcode_weight(0);
+    assert!(emojis::suggest_with("tada", names_only).next().is_none());
+    let none = SearchOptions::new().name_weight(0).shortcode_weight(0);
+    assert_eq!(emojis::suggest_with("rocket", none).count(), 0);
+    assert_eq!(emojis::suggest("+1").next().unwrap(), "👍");
+}
+
+#[test]
+fn short_name_words() {
+    for emoji in emojis::iter() {
+        let words = emoji.short_name_words();
+        assert!(!words.is_empty());
+        assert!(words
+            .iter()
+            .all(|w| !w.is_empty() && emoji.name().contains(w)));
+        assert_eq!(words.len(), emoji.name().split_whitespace().count());
+    }
+}
+
+#[test]
+fn fingerprint() {
+    use std::collections::HashMap;
+
+    let mut seen = HashMap::new();
+    for emoji in emojis::iter() {
+        for e in emoji.skin_tones().into_iter().flatten().chain([emoji]) {
+            let prev = seen.insert(e.fingerprint(), e);
+            assert!(prev.map_or(true, |p| p == e), "{e}");
+        }
+    }
+    assert_eq!(seen.len(), emojis::COUNT_WITH_SKIN_TONES);
+    assert_eq!(emojis::get("👋🏽").unwrap().fingerprint(), 0x8782eb13afa32c09);
+    let unqualified = emojis::get("\u{1f43f}").unwrap();
+    assert_eq!(
+        unqualified.fingerprint(),
+        emojis::get("🐿️").unwrap().fingerprint()
+    );
+}
+
+#[test]
+fn display_list() {
+    let emojis: Vec<_> = ["😀", "🚀", "👋🏽"]
+        .iter()
+        .map(|s| emojis::get(s).unwrap())
+        .collect();
+    let list = emojis::display_list(&emojis, ", ");
+    assert_eq!(list.to_string(), "😀, 🚀, 👋🏽");
+    assert_eq!(list.to_string(), "😀, 🚀, 👋🏽");
+    assert_eq!(
+        format!("[{:}]", emojis::display_list(&emojis[..1], ", ")),
+        "[😀]"
+    );
+    assert_eq!(emojis::display_list(&emojis[..0], " ").to_string(), "");
+}
+
+#[test]
+fn group_predicates() {
+    use emojis::Group;
+
+    for emoji in emojis::iter() {
+        let predicates = [
+            (emoji.is_smiley(), Group::SmileysAndEmotion),
+            (emoji.is_people(), Group::PeopleAndBody),
+            (emoji.is_nature(), Group::AnimalsAndNature),
+            (emoji.is_food(), Group::FoodAndDrink),
+            (emoji.is_travel(), Group::TravelAndPlaces),
+            (emoji.is_activity(), Group::Activities),
+            (emoji.is_object(), Group::Objects),
+            (emoji.is_symbol(), Group::Symbols),
+            (emoji.is_flag(), Group::Flags),
+        ];
+        for (is, group) in predicates {
+            assert_eq!(is, emoji.group() == group, "{emoji}");
+        }
+    }
+}
+
+#[test]
+fn presets() {
+    for preset in [emojis::presets::REACTIONS, emojis::presets::STATUS] {
+        assert!(!preset.is_empty());
+        for emoji in preset {
+            assert_eq!(emojis::get(emoji.as_str()), Some(*emoji));
+        }
+    }
+    assert!(emojis::presets::STATUS[0] == "✅");
+}
+
+#[test]
+fn blocks() {
+    use emojis::Block;
+
+    for emoji in emojis::iter().flat_map(|e| e.skin_tones().into_iter().flatten().chain([e])) {
+        let blocks: Vec<_> = emoji.blocks().collect();
+        for c in emoji.as_str().chars() {
+            let block = Block::of(c).unwrap();
+            assert!(block.range().contains(&c));
+            assert_eq!(blocks.iter().filter(|&&b| b == block).count(), 1, "{emoji}");
+        }
+    }
+    assert!(Block::iter().all(|b| b.plane() <= 14));
+    assert_eq!(Block::of('🏴').unwrap().plane(), 1);
+    assert_eq!(Block::of('\u{E0067}').unwrap().name(), "Tags");
+}
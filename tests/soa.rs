@@ -0,0 +1,21 @@
+#![cfg(feature = "soa")]
+
+use emojis::soa;
+
+#[test]
+fn soa_matches_emojis() {
+    let emojis: Vec<_> = emojis::iter()
+        .flat_map(|e| e.skin_tones().map_or_else(|| vec![e], Iterator::collect))
+        .collect();
+    assert_eq!(soa::AS_STR.len(), emojis.len());
+    assert_eq!(soa::NAMES.len(), emojis.len());
+    assert_eq!(soa::GROUPS.len(), emojis.len());
+    assert_eq!(soa::UNICODE_VERSIONS.len(), emojis.len());
+    for (i, emoji) in emojis.into_iter().enumerate() {
+        assert_eq!(emoji.cldr_order(), i);
+        assert_eq!(soa::AS_STR[i], emoji.as_str());
+        assert_eq!(soa::NAMES[i], emoji.name());
+        assert_eq!(soa::GROUPS[i], emoji.group());
+        assert_eq!(soa::UNICODE_VERSIONS[i], emoji.unicode_version());
+    }
+}
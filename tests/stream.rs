@@ -0,0 +1,127 @@
+#![cfg(feature = "std")]
+
+use std::io;
+
+use emojis::{Qualification, ReplaceMode};
+
+/// A reader that returns at most `n` bytes at a time.
+struct Chunked<'a> {
+    data: &'a [u8],
+    n: usize,
+}
+
+impl io::Read for Chunked<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.n.min(buf.len()).min(self.data.len());
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+        Ok(len)
+    }
+}
+
+fn replace(input: &str, mode: ReplaceMode, n: usize) -> io::Result<String> {
+    let mut out = Vec::new();
+    let reader = Chunked {
+        data: input.as_bytes(),
+        n,
+    };
+    emojis::replace_stream(reader, &mut out, mode)?;
+    Ok(String::from_utf8(out).unwrap())
+}
+
+fn check(mode: ReplaceMode, cases: &[(&str, &str)]) {
+    for (input, expected) in cases {
+        for n in [1, 2, 3, 7, 8192] {
+            assert_eq!(
+                &replace(input, mode, n).unwrap(),
+                expected,
+                "chunk size {n}"
+            );
+        }
+    }
+}
+
+#[test]
+fn replace_stream_shortcode_to_emoji() {
+    check(
+        ReplaceMode::ShortcodeToEmoji,
+        &[
+            ("", ""),
+            ("launch nothing", "launch nothing"),
+            ("launch :rocket: something", "launch 🚀 something"),
+            ("? :unknown: emoji", "? :unknown: emoji"),
+            ("::very:naughty::", "::very:naughty::"),
+            (":maybe:rocket:", ":maybe🚀"),
+            (":rocket::rocket:", "🚀🚀"),
+            ("trailing :rocket", "trailing :rocket"),
+            ("🚀 :+1: :woman_astronaut:", "🚀 👍 👩‍🚀"),
+        ],
+    );
+}
+
+#[test]
+fn replace_stream_emoji_to_shortcode() {
+    check(
+        ReplaceMode::EmojiToShortcode,
+        &[
+            ("", ""),
+            ("launch nothing", "launch nothing"),
+            ("launch 🚀 something", "launch :rocket: something"),
+            ("🚀🚀", ":rocket::rocket:"),
+            ("👩‍🚀 and 👩", ":woman_astronaut: and :woman:"),
+            ("👋🏽", "👋🏽"),
+        ],
+    );
+}
+
+#[test]
+fn replace_stream_emoji_to_ascii() {
+    check(
+        ReplaceMode::EmojiToAscii,
+        &[
+            ("", ""),
+            ("ok 🙂 ❤️", "ok :) <3"),
+            ("👍🏽 ➡️ 🚀", "+1 -> :rocket:"),
+            ("👋🏽", "👋🏽"),
+        ],
+    );
+}
+
+#[test]
+fn replace_stream_qualify() {
+    check(
+        ReplaceMode::Qualify(Qualification::Unqualified),
+        &[
+            ("", ""),
+            ("smile ☺️ ☺", "smile ☺ ☺"),
+            ("👁️‍🗨️ 🚀", "👁\u{200d}🗨 🚀"),
+        ],
+    );
+    check(
+        ReplaceMode::Qualify(Qualification::Fully),
+        &[("smile ☺ 👁‍🗨", "smile ☺️ 👁️‍🗨️")],
+    );
+}
+
+#[test]
+fn replace_stream_long_input() {
+    let input = "lift off :rocket: ".repeat(1000);
+    let expected = "lift off 🚀 ".repeat(1000);
+    assert_eq!(
+        replace(&input, ReplaceMode::ShortcodeToEmoji, 8192).unwrap(),
+        expected
+    );
+    assert_eq!(
+        replace(&expected, ReplaceMode::EmojiToShortcode, 8192).unwrap(),
+        input
+    );
+}
+
+#[test]
+fn replace_stream_invalid_utf8() {
+    for bytes in [&b"launch \xff"[..], &b"launch \xf0\x9f"[..]] {
+        let err =
+            emojis::replace_stream(bytes, io::sink(), ReplaceMode::ShortcodeToEmoji).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
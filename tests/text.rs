@@ -0,0 +1,97 @@
+#![cfg(feature = "alloc")]
+
+#[test]
+fn describe_replaces_emojis() {
+    assert_eq!(emojis::describe("", "({name})"), "");
+    assert_eq!(emojis::describe("no emojis", "({name})"), "no emojis");
+    assert_eq!(emojis::describe("🚀🚀", "({name})"), "(rocket)(rocket)");
+    assert_eq!(emojis::describe("x🚀y", ""), "xy");
+}
+
+#[test]
+fn describe_matches_longest_sequence() {
+    assert_eq!(
+        emojis::describe("hi 👋🏽 👩‍❤️‍💋‍👨 🏴󠁧󠁢󠁳󠁣󠁴󠁿", "<{name}>"),
+        "hi <waving hand: medium skin tone> <kiss: woman, man> <flag: Scotland>"
+    );
+}
+
+#[test]
+fn describe_keycaps_and_variations() {
+    assert_eq!(
+        emojis::describe("1 #️⃣ ☹", "<{name}>"),
+        "1 <keycap: #> <frowning face>"
+    );
+}
+
+#[test]
+fn tally_merges_variations() {
+    assert!(emojis::tally("").is_empty());
+    let counts = emojis::tally("\u{1f43f} \u{1f43f}\u{fe0f} 🐿️ 👍 👍🏿");
+    let counts: Vec<_> = counts.iter().map(|(e, n)| (e.as_str(), *n)).collect();
+    assert_eq!(counts, [("🐿️", 3), ("👍", 1), ("👍🏿", 1)]);
+}
+
+#[test]
+fn weighted_len() {
+    assert_eq!(emojis::weighted_len("", 2), 0);
+    assert_eq!(emojis::weighted_len("plain", 2), 5);
+    assert_eq!(emojis::weighted_len("🏴󠁧󠁢󠁳󠁣󠁴󠁿🇿🇦👋🏽", 1), 3);
+    assert_eq!(emojis::weighted_len("1 #️⃣ ☹ é", 0), 5);
+}
+
+#[test]
+fn escape_for_logs_only_escapes_emojis() {
+    let text = "é 👩🏽‍🚀 ok ☹";
+    let escaped = emojis::escape_for_logs(text);
+    assert_eq!(
+        escaped,
+        "é \\u{1f469}\\u{1f3fd}\\u{200d}\\u{1f680} ok \\u{2639}"
+    );
+    assert!(escaped.chars().all(|c| (c as u32) < 0x10000));
+}
+
+#[test]
+fn truncate_never_splits_emojis() {
+    let text = "a👨‍👩‍👧‍👦🇿🇦#️⃣";
+    for max in 0..=text.len() + 1 {
+        let truncated = emojis::truncate(text, max);
+        assert!(truncated.len() <= max);
+        assert!(matches!(truncated.len(), 0 | 1 | 26 | 34 | 41));
+    }
+    assert_eq!(emojis::truncate_chars(text, 8), "a👨‍👩‍👧‍👦");
+    assert_eq!(emojis::truncate_chars(text, 10), "a👨‍👩‍👧‍👦🇿🇦");
+    assert_eq!(emojis::truncate_chars("", 10), "");
+}
+
+#[test]
+fn truncate_with_ellipsis() {
+    assert_eq!(emojis::truncate_with_ellipsis("🇿🇦🇿🇦", 10, "..."), "...");
+    assert_eq!(emojis::truncate_with_ellipsis("🇿🇦🇿🇦", 11, "..."), "🇿🇦...");
+    assert_eq!(emojis::truncate_with_ellipsis("ab", 1, "..."), "a");
+    assert_eq!(emojis::truncate_with_ellipsis("🚀🚀", 1, "…"), "");
+    assert_eq!(emojis::truncate_with_ellipsis("🚀🚀", 7, "…"), "🚀…");
+}
+
+#[test]
+fn searcher_matches_suggest() {
+    let mut searcher = emojis::Searcher::new();
+    for word in [
+        "h", "he", "hea", "heart", "Heart", "hearts", "", "x", "party",
+    ] {
+        let results: Vec<_> = searcher.search(word).collect();
+        assert!(results.into_iter().eq(emojis::suggest(word)), "{word}");
+    }
+}
+
+#[test]
+fn searcher_with_options() {
+    use emojis::SearchOptions;
+
+    let options = SearchOptions::new().skin_tones(true);
+    let mut searcher = emojis::Searcher::with_options(options);
+    for word in ["t", "th", "thumbs", "thumbsup"] {
+        let results: Vec<_> = searcher.search(word).collect();
+        assert!(results.into_iter().eq(emojis::suggest_with(word, options)));
+    }
+}